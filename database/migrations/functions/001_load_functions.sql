@@ -7,19 +7,51 @@
 {{ template "auth/resolve_unique_username.sql" }} -- Dependency for signup and pre-registration activation
 {{ template "auth/activate_pre_registered_user_email_password.sql" }}
 {{ template "auth/activate_pre_registered_user_external_provider.sql" }}
+{{ template "auth/add_user_ldap_community_roles.sql" }}
+{{ template "auth/add_user_magic_link.sql" }}
+{{ template "auth/add_user_passkey.sql" }}
+{{ template "auth/add_user_password_reset.sql" }}
+{{ template "auth/community_email_domain_allowed.sql" }}
+{{ template "auth/community_passkey_required.sql" }}
+{{ template "auth/delete_user_passkey.sql" }}
+{{ template "auth/ensure_user_email_domain_allowed.sql" }}
+{{ template "auth/get_community_required_oidc_provider.sql" }}
+{{ template "auth/get_community_required_oidc_provider_by_id.sql" }}
+{{ template "auth/get_hosted_event_group.sql" }}
 {{ template "auth/get_user_by_email.sql" }}
 {{ template "auth/get_user_by_email_for_external_auth.sql" }}
 {{ template "auth/get_user_by_id_verified.sql" }}
 {{ template "auth/get_user_by_linuxfoundation_identity_for_external_auth.sql" }}
+{{ template "auth/get_user_by_oauth2_identity_for_external_auth.sql" }}
 {{ template "auth/get_user_by_username.sql" }}
+{{ template "auth/get_user_passkeys.sql" }}
+{{ template "auth/is_log_in_locked.sql" }} -- Dependency for register_failed_log_in
+{{ template "auth/link_user_provider.sql" }}
+{{ template "auth/list_user_passkeys.sql" }}
+{{ template "auth/list_user_sessions.sql" }}
+{{ template "auth/redeem_user_magic_link.sql" }}
+{{ template "auth/register_failed_log_in.sql" }}
+{{ template "auth/resend_email_verification.sql" }}
+{{ template "auth/reset_failed_log_ins.sql" }}
+{{ template "auth/reset_user_password.sql" }}
+{{ template "auth/revoke_user_session.sql" }}
+{{ template "auth/revoke_user_sessions.sql" }}
 {{ template "auth/sign_up_user.sql" }}
+{{ template "auth/unlink_user_provider.sql" }}
+{{ template "auth/update_user_calendar_feed.sql" }}
 {{ template "auth/update_user_details.sql" }}
 {{ template "auth/update_user_external_auth.sql" }}
+{{ template "auth/update_user_passkey.sql" }}
 {{ template "auth/update_user_password.sql" }}
+{{ template "auth/update_user_phone_number.sql" }}
 {{ template "auth/update_user_provider.sql" }}
+{{ template "auth/update_user_sms_notifications.sql" }}
+{{ template "auth/update_user_webhook.sql" }}
 {{ template "auth/user_has_community_permission.sql" }}
 {{ template "auth/user_has_group_permission.sql" }}
+{{ template "auth/user_is_event_host.sql" }}
 {{ template "auth/verify_email.sql" }}
+{{ template "auth/verify_user_phone_number.sql" }}
 
 {{ template "common/escape_ilike_pattern.sql" }}
 {{ template "common/generate_slug.sql" }}
@@ -27,8 +59,10 @@
 {{ template "common/get_community_full.sql" }}
 {{ template "common/get_community_summary.sql" }} -- Do not sort alphabetically, has dependency
 {{ template "common/get_event_occupied_seat_count.sql" }} -- Dependency for event capacity counts
+{{ template "common/get_user_group_attendance_record.sql" }} -- Dependency for attend_event and list_group_members
 {{ template "common/is_registration_window_open.sql" }} -- Dependency for attendee registration flows
 {{ template "common/list_event_discount_codes.sql" }} -- Dependency for get_event_full and payments
+{{ template "common/list_event_resources.sql" }} -- Dependency for get_event_full
 {{ template "common/list_event_ticket_types.sql" }} -- Dependency for get_event_full and payments
 {{ template "common/get_group_summary.sql" }} -- Do not sort alphabetically, has dependency
 {{ template "common/get_public_user_provider.sql" }} -- Dependency for public user profile payloads
@@ -45,51 +79,88 @@
 {{ template "common/get_event_summary.sql" }}
 {{ template "common/get_event_registration_questions.sql" }} -- Do not sort alphabetically, dependency for dashboard-user/list_user_events
 {{ template "common/get_group_full.sql" }}
+{{ template "common/get_notification_community_id.sql" }} -- Dependency for community failed notifications
 {{ template "common/insert_audit_log.sql" }}
 {{ template "common/is_open_graph_image.sql" }}
 {{ template "common/list_event_cfs_labels.sql" }}
+{{ template "common/list_group_gallery_photos.sql" }}
 {{ template "common/list_redirect_communities.sql" }}
 {{ template "common/list_redirects.sql" }}
 {{ template "common/search_events.sql" }}
 {{ template "common/search_groups.sql" }}
 
 {{ template "community/get_community_id_by_name.sql" }}
+{{ template "community/get_community_name_by_domain.sql" }}
 {{ template "community/get_community_name_by_id.sql" }}
+{{ template "community/get_community_newsletter_archive_issue.sql" }}
 {{ template "community/get_community_recently_added_groups.sql" }}
 {{ template "community/get_community_site_stats.sql" }}
 {{ template "community/get_community_upcoming_events.sql" }}
+{{ template "community/is_community_newsletter_subscriber.sql" }}
+{{ template "community/list_community_newsletter_archive.sql" }}
+{{ template "community/subscribe_community_newsletter.sql" }}
+{{ template "community/unsubscribe_community_newsletter.sql" }}
 {{ template "community/update_community_views.sql" }}
 
 {{ template "dashboard-common/group_has_active_subgroups.sql" }}
 {{ template "dashboard-common/group_has_child_links.sql" }}
 {{ template "dashboard-common/list_group_parent_options.sql" }}
 {{ template "dashboard-common/search_user.sql" }}
+{{ template "dashboard-common/sync_group_topics.sql" }} -- Dependency for add/update_group
 {{ template "dashboard-common/update_group.sql" }}
 
 {{ template "dashboard-community/activate_group.sql" }}
+{{ template "dashboard-community/add_community_domain.sql" }}
+{{ template "dashboard-community/add_community_newsletter_issue.sql" }}
 {{ template "dashboard-community/add_community_team_member.sql" }}
 {{ template "dashboard-community/add_event_category.sql" }}
 {{ template "dashboard-community/add_group.sql" }}
 {{ template "dashboard-community/add_group_category.sql" }}
 {{ template "dashboard-community/add_region.sql" }}
+{{ template "dashboard-community/add_topic.sql" }}
+{{ template "dashboard-community/approve_event.sql" }}
+{{ template "dashboard-community/archive_group.sql" }}
+{{ template "dashboard-community/count_community_newsletter_subscribers.sql" }}
 {{ template "dashboard-community/deactivate_group.sql" }}
+{{ template "dashboard-community/delete_community_domain.sql" }}
+{{ template "dashboard-community/delete_community_newsletter_issue.sql" }}
 {{ template "dashboard-community/delete_community_team_member.sql" }}
 {{ template "dashboard-community/delete_event_category.sql" }}
 {{ template "dashboard-community/delete_group.sql" }}
 {{ template "dashboard-community/delete_group_category.sql" }}
 {{ template "dashboard-community/delete_region.sql" }}
+{{ template "dashboard-community/delete_topic.sql" }}
+{{ template "dashboard-community/get_community_domain.sql" }}
+{{ template "dashboard-community/get_community_newsletter_issue.sql" }}
 {{ template "dashboard-community/get_community_stats.sql" }}
 {{ template "dashboard-community/list_community_audit_logs.sql" }}
+{{ template "dashboard-community/list_community_custom_notifications.sql" }}
+{{ template "dashboard-community/list_community_domains.sql" }}
+{{ template "dashboard-community/list_community_failed_notifications.sql" }}
+{{ template "dashboard-community/list_community_group_bans.sql" }}
+{{ template "dashboard-community/list_community_newsletter_issues.sql" }}
+{{ template "dashboard-community/list_community_pending_events.sql" }}
 {{ template "dashboard-community/list_community_roles.sql" }}
 {{ template "dashboard-community/list_community_team_members.sql" }}
 {{ template "dashboard-community/list_group_categories.sql" }}
+{{ template "dashboard-community/list_group_merge_targets.sql" }}
 {{ template "dashboard-community/list_regions.sql" }}
+{{ template "dashboard-community/list_topics.sql" }}
 {{ template "dashboard-community/list_user_communities.sql" }}
+{{ template "dashboard-community/merge_groups.sql" }}
+{{ template "dashboard-community/reject_event.sql" }}
+{{ template "dashboard-community/requeue_community_notification.sql" }}
+{{ template "dashboard-community/restore_group.sql" }}
+{{ template "dashboard-community/send_community_newsletter_issue.sql" }}
 {{ template "dashboard-community/update_community.sql" }}
 {{ template "dashboard-community/update_community_team_member_role.sql" }}
+{{ template "dashboard-community/update_dormant_groups.sql" }}
 {{ template "dashboard-community/update_event_category.sql" }}
+{{ template "dashboard-community/update_group_owner.sql" }}
 {{ template "dashboard-community/update_group_category.sql" }}
 {{ template "dashboard-community/update_region.sql" }}
+{{ template "dashboard-community/update_topic.sql" }}
+{{ template "dashboard-community/verify_community_domain.sql" }}
 
 {{ template "dashboard-group/get_event_ticket_capacity.sql" }} -- Dependency for add/update_event
 {{ template "dashboard-group/list_payment_currency_codes.sql" }} -- Dependency for payment currency validation and dashboard forms
@@ -111,41 +182,77 @@
 {{ template "dashboard-group/sync_event_cfs_labels.sql" }} -- Dependency for add/update_event
 {{ template "dashboard-group/sync_event_hosts_speakers_sponsors.sql" }} -- Dependency for add/update_event
 {{ template "dashboard-group/sync_event_sessions.sql" }} -- Dependency for add/update_event
+{{ template "dashboard-group/sync_event_topics.sql" }} -- Dependency for add/update_event
 {{ template "dashboard-group/accept_event_invitation_request.sql" }}
+{{ template "dashboard-group/accept_group_join_request.sql" }}
 {{ template "dashboard-group/add_event.sql" }}
+{{ template "dashboard-group/add_event_resource.sql" }}
 {{ template "dashboard-group/add_event_series.sql" }}
+{{ template "dashboard-group/add_event_template.sql" }}
+{{ template "dashboard-group/add_group_announcement.sql" }}
 {{ template "dashboard-group/add_group_sponsor.sql" }}
+{{ template "dashboard-group/add_group_sponsor_tier.sql" }}
 {{ template "dashboard-group/add_group_team_member.sql" }}
+{{ template "dashboard-group/approve_event_photo.sql" }}
+{{ template "dashboard-group/ban_group_member.sql" }}
 {{ template "dashboard-group/cancel_event.sql" }}
 {{ template "dashboard-group/cancel_event_attendee_attendance.sql" }}
 {{ template "dashboard-group/cancel_event_attendee_invitation.sql" }}
 {{ template "dashboard-group/cancel_event_series_events.sql" }}
+{{ template "dashboard-group/cancel_group_ownership_transfer.sql" }}
+{{ template "dashboard-group/count_group_custom_notifications_since.sql" }}
 {{ template "dashboard-group/delete_event.sql" }}
+{{ template "dashboard-group/delete_event_comment.sql" }}
+{{ template "dashboard-group/delete_event_resource.sql" }}
 {{ template "dashboard-group/delete_event_series_events.sql" }}
+{{ template "dashboard-group/delete_event_template.sql" }}
+{{ template "dashboard-group/delete_group_announcement.sql" }}
+{{ template "dashboard-group/delete_group_discussion_reply.sql" }}
+{{ template "dashboard-group/delete_group_discussion_topic.sql" }}
 {{ template "dashboard-group/delete_group_sponsor.sql" }}
+{{ template "dashboard-group/delete_group_sponsor_tier.sql" }}
 {{ template "dashboard-group/delete_group_team_member.sql" }}
+{{ template "dashboard-group/delete_group_zoom_credentials.sql" }}
 {{ template "dashboard-group/get_cfs_submission_notification_data.sql" }}
+{{ template "dashboard-group/get_event_attendee_by_ticket_code.sql" }}
 {{ template "dashboard-group/get_event_delete_eligibility.sql" }} -- Dependency for event summaries and deletion
 {{ template "dashboard-group/get_event_summary_dashboard.sql" }} -- Dependency for list_group_events
+{{ template "dashboard-group/get_event_template_details.sql" }}
+{{ template "dashboard-group/get_group_engagement_stats.sql" }}
+{{ template "dashboard-group/get_group_ownership.sql" }}
 {{ template "dashboard-group/get_group_sponsor.sql" }}
 {{ template "dashboard-group/get_group_stats.sql" }}
+{{ template "dashboard-group/get_group_zoom_account.sql" }}
 {{ template "dashboard-group/invite_event_attendee.sql" }}
+{{ template "dashboard-group/invite_group_team_member.sql" }}
 {{ template "dashboard-group/list_cfs_submission_statuses_for_review.sql" }}
 {{ template "dashboard-group/list_event_approved_cfs_submissions.sql" }}
+{{ template "dashboard-group/list_event_attendee_badges.sql" }}
 {{ template "dashboard-group/list_event_attendees_ids.sql" }}
 {{ template "dashboard-group/list_event_categories.sql" }}
 {{ template "dashboard-group/list_event_cfs_submissions.sql" }}
+{{ template "dashboard-group/list_event_conflicts.sql" }}
 {{ template "dashboard-group/list_event_kinds.sql" }}
+{{ template "dashboard-group/list_event_photos.sql" }}
 {{ template "dashboard-group/list_event_series_cancelable_event_ids.sql" }}
 {{ template "dashboard-group/list_event_series_event_ids.sql" }}
 {{ template "dashboard-group/list_event_series_publishable_event_ids.sql" }}
+{{ template "dashboard-group/list_event_templates.sql" }}
 {{ template "dashboard-group/list_event_waitlist_ids.sql" }}
+{{ template "dashboard-group/list_group_announcements.sql" }}
 {{ template "dashboard-group/list_group_audit_logs.sql" }}
+{{ template "dashboard-group/list_group_bans.sql" }}
+{{ template "dashboard-group/list_group_custom_notifications.sql" }}
 {{ template "dashboard-group/list_group_events.sql" }}
+{{ template "dashboard-group/list_group_join_requests.sql" }}
+{{ template "dashboard-group/list_group_member_leaderboard.sql" }}
+{{ template "dashboard-group/list_group_member_roles.sql" }}
 {{ template "dashboard-group/list_group_members.sql" }}
+{{ template "dashboard-group/list_group_members_export.sql" }}
 {{ template "dashboard-group/list_group_members_ids.sql" }}
 {{ template "dashboard-group/list_group_refunds.sql" }}
 {{ template "dashboard-group/list_group_roles.sql" }}
+{{ template "dashboard-group/list_group_sponsor_tiers.sql" }}
 {{ template "dashboard-group/list_group_sponsors.sql" }}
 {{ template "dashboard-group/list_group_team_members.sql" }}
 {{ template "dashboard-group/list_group_team_members_ids.sql" }}
@@ -153,39 +260,67 @@
 {{ template "dashboard-group/list_user_groups.sql" }}
 {{ template "dashboard-group/lock_events_for_cancellation.sql" }}
 {{ template "dashboard-group/manual_check_in_event.sql" }}
+{{ template "dashboard-group/publish_due_scheduled_event.sql" }}
 {{ template "dashboard-group/publish_event.sql" }}
 {{ template "dashboard-group/publish_event_series_events.sql" }}
 {{ template "dashboard-group/reject_event_invitation_request.sql" }}
+{{ template "dashboard-group/reject_event_photo.sql" }}
+{{ template "dashboard-group/reject_group_join_request.sql" }}
+{{ template "dashboard-group/remove_event_waitlist_user.sql" }}
+{{ template "dashboard-group/request_group_ownership_transfer.sql" }}
 {{ template "dashboard-group/resolve_event_custom_notification_recipient_ids.sql" }}
+{{ template "dashboard-group/resolve_group_custom_notification_recipient_ids.sql" }}
 {{ template "dashboard-group/search_event_attendees.sql" }}
 {{ template "dashboard-group/search_event_invitation_requests.sql" }}
 {{ template "dashboard-group/search_event_waitlist.sql" }}
+{{ template "dashboard-group/unban_group_member.sql" }}
 {{ template "dashboard-group/unpublish_event.sql" }}
 {{ template "dashboard-group/unpublish_event_series_events.sql" }}
 {{ template "dashboard-group/update_cfs_submission.sql" }}
 {{ template "dashboard-group/validate_update_event_dates.sql" }} -- Dependency for update_event
 {{ template "dashboard-group/update_event.sql" }}
+{{ template "dashboard-group/update_event_comment_pinned.sql" }}
+{{ template "dashboard-group/update_event_comments_locked.sql" }}
+{{ template "dashboard-group/update_group_announcement_pinned.sql" }}
+{{ template "dashboard-group/update_group_discord_webhook.sql" }}
+{{ template "dashboard-group/update_group_discussion_topic_locked.sql" }}
+{{ template "dashboard-group/update_group_discussion_topic_pinned.sql" }}
+{{ template "dashboard-group/update_group_gallery_cover.sql" }}
+{{ template "dashboard-group/update_group_gallery_photo_hidden.sql" }}
+{{ template "dashboard-group/update_group_gallery_photos.sql" }}
+{{ template "dashboard-group/update_group_join_questions.sql" }}
+{{ template "dashboard-group/update_group_member_role.sql" }}
 {{ template "dashboard-group/update_group_sponsor.sql" }}
 {{ template "dashboard-group/update_group_sponsor_featured.sql" }}
+{{ template "dashboard-group/update_group_sponsor_tier.sql" }}
 {{ template "dashboard-group/update_group_team_member_role.sql" }}
+{{ template "dashboard-group/update_group_welcome_message.sql" }}
+{{ template "dashboard-group/update_group_zoom_credentials.sql" }}
 
 {{ template "dashboard-user/accept_community_team_invitation.sql" }}
 {{ template "dashboard-user/accept_event_attendee_invitation.sql" }}
+{{ template "dashboard-user/accept_group_ownership_transfer.sql" }}
 {{ template "dashboard-user/accept_group_team_invitation.sql" }}
+{{ template "dashboard-user/accept_group_team_invitation_link.sql" }}
 {{ template "dashboard-user/accept_session_proposal_co_speaker_invitation.sql" }}
 {{ template "dashboard-user/add_session_proposal.sql" }}
 {{ template "dashboard-user/delete_session_proposal.sql" }}
+{{ template "dashboard-user/get_user_attendance_certificate.sql" }}
 {{ template "dashboard-user/list_session_proposal_levels.sql" }}
+{{ template "dashboard-user/list_user_attendance_certificates.sql" }}
 {{ template "dashboard-user/list_user_audit_logs.sql" }}
 {{ template "dashboard-user/list_user_cfs_submissions.sql" }}
 {{ template "dashboard-user/list_user_community_team_invitations.sql" }}
 {{ template "dashboard-user/list_user_event_invitations.sql" }}
 {{ template "dashboard-user/list_user_events.sql" }}
+{{ template "dashboard-user/list_user_group_ownership_transfers.sql" }}
 {{ template "dashboard-user/list_user_group_team_invitations.sql" }}
 {{ template "dashboard-user/list_user_pending_session_proposal_co_speaker_invitations.sql" }}
 {{ template "dashboard-user/list_user_session_proposals.sql" }}
+{{ template "dashboard-user/list_user_suggested_events.sql" }}
 {{ template "dashboard-user/reject_community_team_invitation.sql" }}
 {{ template "dashboard-user/reject_event_attendee_invitation.sql" }}
+{{ template "dashboard-user/reject_group_ownership_transfer.sql" }}
 {{ template "dashboard-user/reject_group_team_invitation.sql" }}
 {{ template "dashboard-user/reject_session_proposal_co_speaker_invitation.sql" }}
 {{ template "dashboard-user/resubmit_cfs_submission.sql" }}
@@ -194,48 +329,98 @@
 {{ template "dashboard-user/withdraw_cfs_submission.sql" }}
 
 {{ template "event/add_cfs_submission.sql" }}
+{{ template "event/add_event_comment.sql" }}
+{{ template "event/add_event_photo.sql" }}
 {{ template "event/attend_event.sql" }}
 {{ template "event/check_in_event.sql" }}
 {{ template "event/ensure_event_is_active.sql" }}
 {{ template "event/get_event_attendance.sql" }}
+{{ template "event/get_event_check_in_code.sql" }}
+{{ template "event/get_event_discussion.sql" }}
 {{ template "event/get_event_full_by_slug.sql" }}
+{{ template "event/get_event_resource.sql" }}
+{{ template "event/get_event_series.sql" }}
 {{ template "event/get_event_summary_by_id.sql" }}
+{{ template "event/get_event_ticket_code.sql" }}
+{{ template "event/get_speaker_profile.sql" }}
+{{ template "event/get_user_calendar_events.sql" }}
+{{ template "event/is_event_check_in_code_valid.sql" }}
 {{ template "event/is_event_check_in_window_open.sql" }}
 {{ template "payments/release_event_discount_code_availability.sql" }} -- Dependency for event and payments flows
 {{ template "payments/release_event_checkout_attendee_hold.sql" }} -- Dependency for checkout expiration flows
 {{ template "payments/refund_free_event_purchase.sql" }} -- Dependency for leave_event
 {{ template "event/leave_event.sql" }}
 {{ template "event/list_user_session_proposals_for_cfs_event.sql" }}
+{{ template "event/update_event_reminder_preference.sql" }}
 {{ template "event/update_event_views.sql" }}
 
+{{ template "google-calendar/add_user_google_calendar.sql" }}
+{{ template "google-calendar/claim_google_calendar_entry_out_of_sync.sql" }}
+{{ template "google-calendar/delete_user_google_calendar.sql" }}
+{{ template "google-calendar/disconnect_user_google_calendar.sql" }}
+{{ template "google-calendar/is_user_google_calendar_connected.sql" }}
+{{ template "google-calendar/release_google_calendar_entry_sync_claim.sql" }}
+{{ template "google-calendar/set_google_calendar_entry_synced.sql" }}
+
+{{ template "group/is_group_member.sql" }} -- Dependency for add_group_discussion_*, get_group_membership_status and user_can_view_group_members
+{{ template "group/add_group_contact_message.sql" }}
+{{ template "group/add_group_discussion_reply.sql" }}
+{{ template "group/add_group_discussion_topic.sql" }}
+{{ template "group/ensure_user_is_not_banned_from_group.sql" }}
+{{ template "group/get_group_discussion.sql" }}
 {{ template "group/get_group_full_by_slug.sql" }}
+{{ template "group/get_group_join_questions.sql" }}
+{{ template "group/get_group_membership_status.sql" }}
+{{ template "group/get_merged_group_slug.sql" }}
 {{ template "group/get_group_past_events.sql" }}
 {{ template "group/get_group_upcoming_events.sql" }}
-{{ template "group/is_group_member.sql" }}
 {{ template "group/join_group.sql" }}
 {{ template "group/leave_group.sql" }}
+{{ template "group/list_group_pinned_announcements.sql" }}
 {{ template "group/update_group_views.sql" }}
+{{ template "group/user_can_view_group_members.sql" }}
 
 {{ template "meetings/get_event_meeting_sync_state_hash.sql" }} -- Dependency for meeting sync completion functions
 {{ template "meetings/get_session_meeting_sync_state_hash.sql" }} -- Dependency for meeting sync completion functions
 {{ template "meetings/add_meeting.sql" }}
 {{ template "meetings/append_meeting_recording_url.sql" }}
 {{ template "meetings/assign_zoom_host_user.sql" }}
+{{ template "meetings/claim_meeting_for_attendance_sync.sql" }}
 {{ template "meetings/claim_meeting_for_auto_end.sql" }}
+{{ template "meetings/claim_meeting_for_recording_sync.sql" }}
 {{ template "meetings/claim_meeting_out_of_sync.sql" }}
 {{ template "meetings/delete_meeting.sql" }}
+{{ template "meetings/get_group_zoom_credentials.sql" }}
+{{ template "meetings/list_zoom_webhook_secret_tokens.sql" }}
 {{ template "meetings/mark_stale_meeting_auto_end_checks_unknown.sql" }}
 {{ template "meetings/mark_stale_meeting_syncs_unknown.sql" }}
+{{ template "meetings/record_meeting_participant_joined.sql" }}
+{{ template "meetings/release_meeting_attendance_sync_claim.sql" }}
 {{ template "meetings/release_meeting_auto_end_check_claim.sql" }}
+{{ template "meetings/release_meeting_recording_sync_claim.sql" }}
 {{ template "meetings/release_meeting_sync_claim.sql" }}
+{{ template "meetings/set_meeting_attendance_synced.sql" }}
 {{ template "meetings/set_meeting_auto_end_check_outcome.sql" }}
 {{ template "meetings/set_meeting_error.sql" }}
+{{ template "meetings/set_meeting_recordings_synced.sql" }}
 {{ template "meetings/update_meeting.sql" }}
+{{ template "meetings/update_meeting_provider_status.sql" }}
 
 {{ template "notifications/claim_pending_notification.sql" }}
+{{ template "notifications/enqueue_community_notification.sql" }}
+{{ template "notifications/enqueue_due_community_newsletter_batches.sql" }}
+{{ template "notifications/enqueue_due_dormant_groups_reports.sql" }}
+{{ template "notifications/enqueue_due_event_attendance_certificates.sql" }}
+{{ template "notifications/enqueue_due_event_follow_ups.sql" }}
 {{ template "notifications/enqueue_due_event_reminders.sql" }}
+{{ template "notifications/enqueue_due_event_waitlist_promotions.sql" }}
+{{ template "notifications/enqueue_due_weekly_digests.sql" }}
+{{ template "notifications/enqueue_group_notification.sql" }}
 {{ template "notifications/enqueue_notification.sql" }} -- Dependency for downstream notification-producing functions
+{{ template "notifications/get_event_reminder_preference_offset.sql" }}
+{{ template "notifications/is_event_reminder_due.sql" }}
 {{ template "notifications/manual_requeue_notifications.sql" }}
+{{ template "notifications/mark_email_undeliverable.sql" }}
 {{ template "notifications/mark_notification_delivery_unknown.sql" }}
 {{ template "notifications/mark_stale_processing_notifications_unknown.sql" }}
 {{ template "notifications/requeue_notification.sql" }}
@@ -283,6 +468,8 @@
 {{ template "site/list_communities.sql" }}
 
 {{ template "triggers/check_session_within_event_bounds.sql" }}
+{{ template "triggers/mark_google_calendar_entries_out_of_sync.sql" }}
+{{ template "triggers/notify_google_calendar_entry_out_of_sync.sql" }}
 {{ template "triggers/prevent_audit_log_mutation.sql" }}
 
 ---- create above / drop below ----
@@ -77,7 +77,8 @@ begin
             'name', u.name,
             'provider', u.provider,
             'user_id', u.user_id,
-            'username', u.username
+            'username', u.username,
+            'weekly_digest_enabled', u.weekly_digest_enabled
         )),
         v_verification_code
     from "user" u
@@ -0,0 +1,42 @@
+-- Adds a user to the community teams their directory groups are mapped to.
+create or replace function add_user_ldap_community_roles(
+    p_user_id uuid,
+    p_roles jsonb
+) returns void as $$
+declare
+    v_community_id uuid;
+    v_role jsonb;
+begin
+    for v_role in select jsonb_array_elements(p_roles)
+    loop
+        -- Skip mappings referencing unknown communities
+        select community_id
+        into v_community_id
+        from community
+        where name = v_role->>'community_name';
+
+        if not found then
+            continue;
+        end if;
+
+        -- Create an accepted membership, keeping existing ones untouched
+        insert into community_team (community_id, user_id, accepted, role)
+        values (v_community_id, p_user_id, true, v_role->>'role')
+        on conflict (community_id, user_id) do nothing;
+
+        -- Track the created membership
+        if found then
+            perform insert_audit_log(
+                'community_team_member_added',
+                null,
+                'user',
+                p_user_id,
+                v_community_id,
+                null,
+                null,
+                jsonb_build_object('role', v_role->>'role')
+            );
+        end if;
+    end loop;
+end;
+$$ language plpgsql;
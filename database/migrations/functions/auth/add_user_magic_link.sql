@@ -0,0 +1,38 @@
+-- add_user_magic_link stores a log in link for the verified user with the
+-- email provided, returning the user's identifier when the link was stored.
+create or replace function add_user_magic_link(
+    p_email text,
+    p_token_hash text
+) returns uuid as $$
+declare
+    v_user_id uuid;
+begin
+    -- Find the verified user the link is for
+    select u.user_id
+    into v_user_id
+    from "user" u
+    where lower(u.email) = lower(btrim(p_email))
+    and u.email_verified = true
+    and u.registration_status = 'registered';
+
+    if v_user_id is null then
+        return null;
+    end if;
+
+    -- Discard the links of the user that have already expired
+    delete from user_magic_link
+    where user_id = v_user_id
+    and expires_at <= current_timestamp;
+
+    -- Limit the number of links pending to be used
+    if (select count(*) from user_magic_link where user_id = v_user_id) >= 3 then
+        return null;
+    end if;
+
+    -- Store the link, valid for 15 minutes
+    insert into user_magic_link (expires_at, token_hash, user_id)
+    values (current_timestamp + interval '15 minutes', p_token_hash, v_user_id);
+
+    return v_user_id;
+end;
+$$ language plpgsql;
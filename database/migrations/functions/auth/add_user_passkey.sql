@@ -0,0 +1,26 @@
+-- add_user_passkey stores a passkey registered by a user.
+create or replace function add_user_passkey(
+    p_actor_user_id uuid,
+    p_credential_id text,
+    p_name text,
+    p_passkey jsonb
+) returns uuid as $$
+declare
+    v_user_passkey_id uuid;
+begin
+    -- Store the passkey
+    insert into user_passkey (credential_id, name, passkey, user_id)
+    values (p_credential_id, btrim(p_name), p_passkey, p_actor_user_id)
+    returning user_passkey_id into v_user_passkey_id;
+
+    -- Track the added passkey
+    perform insert_audit_log(
+        'user_passkey_added',
+        p_actor_user_id,
+        'user_passkey',
+        v_user_passkey_id
+    );
+
+    return v_user_passkey_id;
+end;
+$$ language plpgsql;
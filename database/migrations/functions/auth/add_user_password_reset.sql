@@ -0,0 +1,38 @@
+-- add_user_password_reset stores a password reset code for the verified user
+-- with the email provided, returning the user's identifier when it was stored.
+create or replace function add_user_password_reset(
+    p_email text,
+    p_code_hash text
+) returns uuid as $$
+declare
+    v_user_id uuid;
+begin
+    -- Find the verified user the code is for
+    select u.user_id
+    into v_user_id
+    from "user" u
+    where lower(u.email) = lower(btrim(p_email))
+    and u.email_verified = true
+    and u.registration_status = 'registered';
+
+    if v_user_id is null then
+        return null;
+    end if;
+
+    -- Discard the codes of the user that have already expired
+    delete from user_password_reset
+    where user_id = v_user_id
+    and expires_at <= current_timestamp;
+
+    -- Limit the number of codes pending to be used
+    if (select count(*) from user_password_reset where user_id = v_user_id) >= 3 then
+        return null;
+    end if;
+
+    -- Store the code, valid for 1 hour
+    insert into user_password_reset (code_hash, expires_at, user_id)
+    values (p_code_hash, current_timestamp + interval '1 hour', v_user_id);
+
+    return v_user_id;
+end;
+$$ language plpgsql;
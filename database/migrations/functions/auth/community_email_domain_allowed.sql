@@ -0,0 +1,20 @@
+-- Checks whether an email address can be used to sign up from a community.
+create or replace function community_email_domain_allowed(
+    p_community_name text,
+    p_email text
+) returns boolean as $$
+    select coalesce(
+        (
+            select
+                coalesce(cardinality(allowed_email_domains), 0) = 0
+                or exists (
+                    select 1
+                    from unnest(allowed_email_domains) as allowed_email_domain
+                    where lower(allowed_email_domain) = lower(substring(p_email from '@([^@]+)$'))
+                )
+            from community
+            where name = p_community_name
+        ),
+        true
+    );
+$$ language sql;
@@ -0,0 +1,8 @@
+-- Checks whether a community requires passkey sign-in for its dashboard.
+create or replace function community_passkey_required(p_community_id uuid)
+returns boolean as $$
+    select coalesce(
+        (select passkey_required from community where community_id = p_community_id),
+        false
+    );
+$$ language sql;
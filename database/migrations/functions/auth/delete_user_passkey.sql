@@ -0,0 +1,24 @@
+-- delete_user_passkey removes a passkey registered by a user.
+create or replace function delete_user_passkey(
+    p_actor_user_id uuid,
+    p_user_passkey_id uuid
+) returns void as $$
+begin
+    -- Delete the passkey, making sure it belongs to the user
+    delete from user_passkey
+    where user_passkey_id = p_user_passkey_id
+    and user_id = p_actor_user_id;
+
+    if not found then
+        raise exception 'passkey not found';
+    end if;
+
+    -- Track the deleted passkey
+    perform insert_audit_log(
+        'user_passkey_deleted',
+        p_actor_user_id,
+        'user_passkey',
+        p_user_passkey_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,14 @@
+-- Validates that a user's email domain is allowed by a community.
+create or replace function ensure_user_email_domain_allowed(p_community_id uuid, p_user_id uuid)
+returns void as $$
+begin
+    -- Users with an email domain not allowed cannot join the community groups
+    -- or attend its events
+    if not community_email_domain_allowed(
+        (select name from community where community_id = p_community_id),
+        (select email from "user" where user_id = p_user_id)
+    ) then
+        raise exception 'your email domain is not allowed in this community';
+    end if;
+end;
+$$ language plpgsql;
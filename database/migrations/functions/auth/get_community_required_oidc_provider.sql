@@ -0,0 +1,7 @@
+-- Returns the OIDC provider a community requires users to log in with, if any.
+create or replace function get_community_required_oidc_provider(p_community_name text)
+returns text as $$
+    select required_oidc_provider
+    from community
+    where name = p_community_name;
+$$ language sql;
@@ -0,0 +1,8 @@
+-- Returns the OIDC provider a community requires users to log in with, if any,
+-- looking the community up by its id.
+create or replace function get_community_required_oidc_provider_by_id(p_community_id uuid)
+returns text as $$
+    select required_oidc_provider
+    from community
+    where community_id = p_community_id;
+$$ language sql;
@@ -0,0 +1,15 @@
+-- Returns the community and group of an event hosted by the user provided.
+create or replace function get_hosted_event_group(
+    p_event_id uuid,
+    p_user_id uuid
+) returns table (community_id uuid, group_id uuid) as $$
+    select g.community_id, g.group_id
+    from event_host eh
+    join event e on e.event_id = eh.event_id
+    join "group" g on g.group_id = e.group_id
+    where eh.event_id = p_event_id
+      and eh.user_id = p_user_id
+      and e.deleted = false
+      and g.active = true
+      and g.deleted = false;
+$$ language sql;
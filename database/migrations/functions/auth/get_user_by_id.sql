@@ -15,6 +15,7 @@ returns json as $$
         'name', name,
         'user_id', user_id,
         'username', username,
+        'weekly_digest_enabled', weekly_digest_enabled,
 
         -- Include optional profile fields
         'bio', bio,
@@ -22,10 +23,12 @@ returns json as $$
         'city', city,
         'company', company,
         'country', country,
+        'event_reminder_preference', event_reminder_preference,
         'facebook_url', facebook_url,
         'github_url', github_url,
         'has_password', case when password is not null then true else null end,
         'interests', interests,
+        'language', language,
         'linkedin_url', linkedin_url,
         'password', case when p_include_password then password else null end,
         'photo_url', photo_url,
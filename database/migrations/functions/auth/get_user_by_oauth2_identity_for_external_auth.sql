@@ -0,0 +1,30 @@
+-- Returns a verified registered user by OAuth2 identity for external authentication.
+create or replace function get_user_by_oauth2_identity_for_external_auth(
+    p_provider text,
+    p_id text
+)
+returns json as $$
+declare
+    v_id text := nullif(p_id, '');
+begin
+    -- Ignore incomplete identities and unsupported providers
+    if v_id is null or p_provider not in ('github', 'gitlab', 'google') then
+        return null;
+    end if;
+
+    -- Return an external-auth payload with required defaults
+    return (
+        select (
+            get_user_by_id(u.user_id, false)::jsonb
+            || jsonb_build_object(
+                'name', coalesce(u.name, ''),
+                'registration_status', u.registration_status
+            )
+        )::json
+        from "user" u
+        where u.provider #>> array[p_provider, 'id'] = v_id
+        and u.registration_status = 'registered'
+        and u.email_verified = true
+    );
+end;
+$$ language plpgsql;
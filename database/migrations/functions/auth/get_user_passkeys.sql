@@ -0,0 +1,8 @@
+-- get_user_passkeys returns the stored passkeys of a user, used to verify
+-- passkey sign-ins and to exclude already registered authenticators.
+create or replace function get_user_passkeys(p_user_id uuid)
+returns jsonb as $$
+    select coalesce(jsonb_agg(passkey order by created_at), '[]'::jsonb)
+    from user_passkey
+    where user_id = p_user_id;
+$$ language sql;
@@ -0,0 +1,20 @@
+-- is_log_in_locked checks whether password log ins are temporarily locked for
+-- the username or address provided after too many recent failed attempts.
+create or replace function is_log_in_locked(
+    p_username text,
+    p_ip_address inet
+) returns boolean as $$
+    select
+        (
+            select count(*)
+            from log_in_failure
+            where lower(username) = lower(btrim(p_username))
+            and created_at > current_timestamp - interval '15 minutes'
+        ) >= 5
+        or (
+            select count(*)
+            from log_in_failure
+            where ip_address = p_ip_address
+            and created_at > current_timestamp - interval '15 minutes'
+        ) >= 20;
+$$ language sql;
@@ -0,0 +1,62 @@
+-- link_user_provider links an external provider identity to a user's account.
+create or replace function link_user_provider(
+    p_user_id uuid,
+    p_provider jsonb
+) returns void as $$
+declare
+    v_github_id text := nullif(p_provider #>> '{github,id}', '');
+    v_gitlab_id text := nullif(p_provider #>> '{gitlab,id}', '');
+    v_google_id text := nullif(p_provider #>> '{google,id}', '');
+    v_lf_issuer text := nullif(p_provider #>> '{linuxfoundation,issuer}', '');
+    v_lf_subject text := nullif(p_provider #>> '{linuxfoundation,subject}', '');
+begin
+    -- Require an identity that can be used to log in later
+    if v_github_id is null
+    and v_gitlab_id is null
+    and v_google_id is null
+    and (v_lf_issuer is null or v_lf_subject is null) then
+        raise exception 'external auth identity is required';
+    end if;
+
+    -- Reject identities owned by another user
+    if exists (
+        select 1
+        from "user" u
+        where u.user_id <> p_user_id
+        and (
+            (v_github_id is not null and u.provider #>> '{github,id}' = v_github_id)
+            or (v_gitlab_id is not null and u.provider #>> '{gitlab,id}' = v_gitlab_id)
+            or (v_google_id is not null and u.provider #>> '{google,id}' = v_google_id)
+            or (
+                v_lf_issuer is not null
+                and v_lf_subject is not null
+                and u.provider #>> '{linuxfoundation,issuer}' = v_lf_issuer
+                and u.provider #>> '{linuxfoundation,subject}' = v_lf_subject
+            )
+        )
+    ) then
+        raise exception 'external auth identity belongs to another user';
+    end if;
+
+    -- Link the identity, replacing any previous one from the same provider
+    update "user"
+    set provider = coalesce(provider, '{}'::jsonb) || p_provider
+    where user_id = p_user_id
+    and registration_status = 'registered';
+
+    if not found then
+        raise exception 'registered user not found';
+    end if;
+
+    -- Track the linked provider
+    perform insert_audit_log(
+        'user_provider_linked',
+        p_user_id,
+        'user',
+        p_user_id,
+        p_details => jsonb_build_object(
+            'providers', (select jsonb_agg(key order by key) from jsonb_object_keys(p_provider) key)
+        )
+    );
+end;
+$$ language plpgsql;
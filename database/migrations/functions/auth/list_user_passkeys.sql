@@ -0,0 +1,16 @@
+-- list_user_passkeys returns the passkeys registered by a user.
+create or replace function list_user_passkeys(p_user_id uuid)
+returns json as $$
+    select coalesce(json_agg(row_to_json(passkey)), '[]'::json)
+    from (
+        select
+            up.name,
+            up.user_passkey_id,
+
+            extract(epoch from up.created_at)::bigint as created_at,
+            extract(epoch from up.last_used_at)::bigint as last_used_at
+        from user_passkey up
+        where up.user_id = p_user_id
+        order by up.created_at desc
+    ) passkey;
+$$ language sql;
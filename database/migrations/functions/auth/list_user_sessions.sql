@@ -0,0 +1,24 @@
+-- list_user_sessions returns the active sessions of a user, flagging the one
+-- used by the current request.
+create or replace function list_user_sessions(
+    p_user_id uuid,
+    p_current_auth_session_id text
+)
+returns json as $$
+    select coalesce(json_agg(row_to_json(user_session)), '[]'::json)
+    from (
+        select
+            coalesce(s.auth_session_id = p_current_auth_session_id, false) as current,
+            s.auth_session_ref as session_id,
+
+            s.data -> 'session_client' ->> 'ip_address' as ip_address,
+            s.data -> 'session_client' ->> 'user_agent' as user_agent,
+
+            extract(epoch from s.created_at)::bigint as created_at,
+            extract(epoch from s.last_seen_at)::bigint as last_seen_at
+        from auth_session s
+        where s.user_id = p_user_id
+        and s.expires_at > current_timestamp
+        order by s.last_seen_at desc, s.created_at desc
+    ) user_session;
+$$ language sql;
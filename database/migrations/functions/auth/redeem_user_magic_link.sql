@@ -0,0 +1,10 @@
+-- redeem_user_magic_link consumes a log in link that has not expired yet,
+-- returning the identifier of the user it was issued to.
+create or replace function redeem_user_magic_link(
+    p_token_hash text
+) returns uuid as $$
+    delete from user_magic_link
+    where token_hash = p_token_hash
+    and expires_at > current_timestamp
+    returning user_id;
+$$ language sql;
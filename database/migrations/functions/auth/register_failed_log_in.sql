@@ -0,0 +1,22 @@
+-- register_failed_log_in records a failed password log in attempt, returning
+-- whether it locked the username or address provided.
+create or replace function register_failed_log_in(
+    p_username text,
+    p_ip_address inet
+) returns boolean as $$
+declare
+    v_locked_before boolean;
+begin
+    -- Discard the attempts that no longer count towards any lockout
+    delete from log_in_failure
+    where created_at <= current_timestamp - interval '1 day';
+
+    -- Record the attempt, checking the lockout state before it
+    v_locked_before := is_log_in_locked(p_username, p_ip_address);
+
+    insert into log_in_failure (ip_address, username)
+    values (p_ip_address, btrim(p_username));
+
+    return not v_locked_before and is_log_in_locked(p_username, p_ip_address);
+end;
+$$ language plpgsql;
@@ -0,0 +1,51 @@
+-- resend_email_verification replaces the email verification code of the
+-- unverified user with the email provided and enqueues a new verification
+-- email, returning whether it was sent. Resends are limited to one every 10
+-- minutes per address.
+create or replace function resend_email_verification(
+    p_email text,
+    p_verification_code uuid,
+    p_verification_template_data jsonb
+) returns boolean as $$
+declare
+    v_user_id uuid;
+begin
+    -- Find the unverified user the email is for
+    select u.user_id
+    into v_user_id
+    from "user" u
+    where lower(u.email) = lower(btrim(p_email))
+    and u.email_verified = false
+    and u.registration_status = 'registered'
+    for update;
+
+    if v_user_id is null then
+        return false;
+    end if;
+
+    -- Limit how often a new verification email can be requested
+    if exists (
+        select 1
+        from email_verification_code
+        where user_id = v_user_id
+        and created_at > current_timestamp - interval '10 minutes'
+    ) then
+        return false;
+    end if;
+
+    -- Replace the previous code, which can no longer be used
+    delete from email_verification_code where user_id = v_user_id;
+    insert into email_verification_code (email_verification_code_id, user_id)
+    values (p_verification_code, v_user_id);
+
+    -- Enqueue the new verification email
+    perform enqueue_notification(
+        'email-verification',
+        p_verification_template_data,
+        '[]'::jsonb,
+        array[v_user_id]
+    );
+
+    return true;
+end;
+$$ language plpgsql;
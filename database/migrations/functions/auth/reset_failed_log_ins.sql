@@ -0,0 +1,8 @@
+-- reset_failed_log_ins discards the failed log in attempts of the username
+-- provided once the user logs in successfully.
+create or replace function reset_failed_log_ins(
+    p_username text
+) returns void as $$
+    delete from log_in_failure
+    where lower(username) = lower(btrim(p_username));
+$$ language sql;
@@ -0,0 +1,41 @@
+-- reset_user_password sets a new password for the user a valid reset code was
+-- issued to, returning the user's identifier when the password was reset.
+create or replace function reset_user_password(
+    p_code_hash text,
+    p_password text
+) returns uuid as $$
+declare
+    v_user_id uuid;
+begin
+    -- Consume the code, which must not have expired
+    delete from user_password_reset
+    where code_hash = p_code_hash
+    and expires_at > current_timestamp
+    returning user_id into v_user_id;
+
+    if v_user_id is null then
+        return null;
+    end if;
+
+    -- Discard any other codes issued to the user
+    delete from user_password_reset
+    where user_id = v_user_id;
+
+    -- Update the password and rotate the auth hash to end existing sessions
+    update "user"
+    set
+        auth_hash = encode(gen_random_bytes(32), 'hex'),
+        password = p_password
+    where user_id = v_user_id;
+
+    -- Track the password reset
+    perform insert_audit_log(
+        'user_password_reset',
+        v_user_id,
+        'user',
+        v_user_id
+    );
+
+    return v_user_id;
+end;
+$$ language plpgsql;
@@ -0,0 +1,24 @@
+-- revoke_user_session deletes one of the sessions of a user.
+create or replace function revoke_user_session(
+    p_user_id uuid,
+    p_session_id uuid
+) returns void as $$
+begin
+    -- Delete the session, making sure it belongs to the user
+    delete from auth_session
+    where auth_session_ref = p_session_id
+    and user_id = p_user_id;
+
+    if not found then
+        raise exception 'session not found';
+    end if;
+
+    -- Track the revoked session
+    perform insert_audit_log(
+        'user_session_revoked',
+        p_user_id,
+        'user',
+        p_user_id
+    );
+end;
+$$ language plpgsql;
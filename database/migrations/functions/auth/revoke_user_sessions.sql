@@ -0,0 +1,17 @@
+-- revoke_user_sessions deletes all the sessions of a user.
+create or replace function revoke_user_sessions(p_user_id uuid)
+returns void as $$
+begin
+    -- Delete all the sessions of the user
+    delete from auth_session
+    where user_id = p_user_id;
+
+    -- Track the revoked sessions
+    perform insert_audit_log(
+        'user_sessions_revoked',
+        p_user_id,
+        'user',
+        p_user_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,45 @@
+-- unlink_user_provider removes an external provider identity from a user's account.
+create or replace function unlink_user_provider(
+    p_user_id uuid,
+    p_provider text
+) returns void as $$
+declare
+    v_password text;
+    v_provider jsonb;
+begin
+    -- Get the user's current log in methods
+    select password, coalesce(provider, '{}'::jsonb)
+    into v_password, v_provider
+    from "user"
+    where user_id = p_user_id
+    for update;
+
+    if not found then
+        raise exception 'user not found';
+    end if;
+
+    -- Check the provider is linked to the account
+    if not v_provider ? p_provider then
+        raise exception 'provider not linked';
+    end if;
+
+    -- Keep at least one way for the user to log in
+    if v_password is null and (v_provider - p_provider) = '{}'::jsonb then
+        raise exception 'cannot unlink the only log in method';
+    end if;
+
+    -- Unlink the provider
+    update "user"
+    set provider = nullif(v_provider - p_provider, '{}'::jsonb)
+    where user_id = p_user_id;
+
+    -- Track the unlinked provider
+    perform insert_audit_log(
+        'user_provider_unlinked',
+        p_user_id,
+        'user',
+        p_user_id,
+        p_details => jsonb_build_object('provider', p_provider)
+    );
+end;
+$$ language plpgsql;
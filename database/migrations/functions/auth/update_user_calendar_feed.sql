@@ -0,0 +1,23 @@
+-- update_user_calendar_feed enables or disables a user's personal calendar feed.
+create or replace function update_user_calendar_feed(
+    p_actor_user_id uuid,
+    p_enabled boolean
+) returns void as $$
+begin
+    -- Generate a new feed token, invalidating any previous feed URL
+    update "user"
+    set calendar_token = case
+        when p_enabled then encode(gen_random_bytes(32), 'hex')
+        else null
+    end
+    where user_id = p_actor_user_id;
+
+    -- Track the calendar feed update
+    perform insert_audit_log(
+        'user_calendar_feed_updated',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id
+    );
+end;
+$$ language plpgsql;
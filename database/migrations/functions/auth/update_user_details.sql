@@ -13,9 +13,11 @@ begin
         city = nullif(p_user->>'city', ''),
         company = nullif(p_user->>'company', ''),
         country = nullif(p_user->>'country', ''),
+        event_reminder_preference = nullif(p_user->>'event_reminder_preference', ''),
         facebook_url = nullif(p_user->>'facebook_url', ''),
         github_url = nullif(p_user->>'github_url', ''),
         interests = jsonb_text_array(p_user->'interests'),
+        language = nullif(p_user->>'language', ''),
         linkedin_url = nullif(p_user->>'linkedin_url', ''),
         optional_notifications_enabled = coalesce(
             (p_user->>'optional_notifications_enabled')::boolean,
@@ -25,7 +27,11 @@ begin
         timezone = nullif(p_user->>'timezone', ''),
         title = nullif(p_user->>'title', ''),
         twitter_url = nullif(p_user->>'twitter_url', ''),
-        website_url = nullif(p_user->>'website_url', '')
+        website_url = nullif(p_user->>'website_url', ''),
+        weekly_digest_enabled = coalesce(
+            (p_user->>'weekly_digest_enabled')::boolean,
+            weekly_digest_enabled
+        )
     where user_id = p_actor_user_id;
 
     -- Track the profile update
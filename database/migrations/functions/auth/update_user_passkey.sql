@@ -0,0 +1,13 @@
+-- update_user_passkey stores the updated state of a passkey after a sign-in.
+create or replace function update_user_passkey(
+    p_user_id uuid,
+    p_credential_id text,
+    p_passkey jsonb
+) returns void as $$
+    update user_passkey
+    set
+        last_used_at = current_timestamp,
+        passkey = p_passkey
+    where credential_id = p_credential_id
+    and user_id = p_user_id;
+$$ language sql;
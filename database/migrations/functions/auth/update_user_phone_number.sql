@@ -0,0 +1,77 @@
+-- update_user_phone_number sets or clears a user's phone number, sending a verification code by SMS.
+create or replace function update_user_phone_number(
+    p_actor_user_id uuid,
+    p_phone_number text
+) returns void as $$
+declare
+    v_code text;
+    v_notification_template_data_id uuid;
+    v_phone_number text := nullif(btrim(p_phone_number), '');
+    v_template_data jsonb;
+begin
+    if v_phone_number is null then
+        -- Clear the phone number, which also disables SMS notifications
+        update "user"
+        set
+            phone_number = null,
+            phone_number_verified = false,
+            sms_notifications_enabled = false
+        where user_id = p_actor_user_id;
+
+        delete from phone_verification_code
+        where user_id = p_actor_user_id;
+    else
+        -- Limit how often verification codes can be sent to the user
+        if exists (
+            select 1
+            from phone_verification_code
+            where user_id = p_actor_user_id
+            and created_at > current_timestamp - interval '1 minute'
+        ) then
+            raise exception 'phone verification code requested too recently';
+        end if;
+
+        -- Store the phone number, which must be verified before receiving notifications
+        update "user"
+        set
+            phone_number = v_phone_number,
+            phone_number_verified = false,
+            sms_notifications_enabled = false
+        where user_id = p_actor_user_id;
+
+        -- Replace any pending verification code with a new one
+        v_code := lpad(
+            ((('x' || encode(gen_random_bytes(4), 'hex'))::bit(32)::bigint) % 1000000)::text,
+            6,
+            '0'
+        );
+        insert into phone_verification_code (user_id, code_hash)
+        values (p_actor_user_id, encode(digest(v_code, 'sha256'), 'hex'))
+        on conflict (user_id) do update set
+            attempts = 0,
+            code_hash = excluded.code_hash,
+            created_at = current_timestamp;
+
+        -- Send the verification code by SMS
+        v_template_data := jsonb_build_object('code', v_code);
+        insert into notification_template_data (data, hash)
+        values (
+            v_template_data,
+            encode(digest(convert_to(v_template_data::text, 'utf8'), 'sha256'), 'hex')
+        )
+        on conflict (hash) do update set hash = notification_template_data.hash
+        returning notification_template_data_id into v_notification_template_data_id;
+
+        insert into notification (channel, kind, notification_template_data_id, user_id)
+        values ('sms', 'phone-verification', v_notification_template_data_id, p_actor_user_id);
+    end if;
+
+    -- Track the phone number update
+    perform insert_audit_log(
+        'user_phone_number_updated',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id
+    );
+end;
+$$ language plpgsql;
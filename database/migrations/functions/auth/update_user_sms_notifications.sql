@@ -0,0 +1,30 @@
+-- update_user_sms_notifications enables or disables the SMS notifications of a user.
+create or replace function update_user_sms_notifications(
+    p_actor_user_id uuid,
+    p_enabled boolean
+) returns void as $$
+begin
+    -- Only verified phone numbers can receive notifications
+    if p_enabled and not exists (
+        select 1
+        from "user"
+        where user_id = p_actor_user_id
+        and phone_number_verified = true
+    ) then
+        raise exception 'phone number not verified';
+    end if;
+
+    -- Update the SMS notifications preference
+    update "user"
+    set sms_notifications_enabled = p_enabled
+    where user_id = p_actor_user_id;
+
+    -- Track the SMS notifications preference update
+    perform insert_audit_log(
+        'user_sms_notifications_updated',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id
+    );
+end;
+$$ language plpgsql;
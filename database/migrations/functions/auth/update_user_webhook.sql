@@ -0,0 +1,27 @@
+-- update_user_webhook sets or clears the webhook a user's notifications are posted to.
+create or replace function update_user_webhook(
+    p_actor_user_id uuid,
+    p_webhook_url text
+) returns void as $$
+declare
+    v_webhook_url text := nullif(btrim(p_webhook_url), '');
+begin
+    -- Update the webhook, generating a signing secret the first time it is set
+    update "user"
+    set
+        webhook_secret = case
+            when v_webhook_url is null then null
+            else coalesce(webhook_secret, encode(gen_random_bytes(32), 'hex'))
+        end,
+        webhook_url = v_webhook_url
+    where user_id = p_actor_user_id;
+
+    -- Track the webhook update
+    perform insert_audit_log(
+        'user_webhook_updated',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id
+    );
+end;
+$$ language plpgsql;
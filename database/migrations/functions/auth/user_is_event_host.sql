@@ -0,0 +1,21 @@
+-- Checks whether a user hosts an event of a group.
+create or replace function user_is_event_host(
+    p_community_id uuid,
+    p_group_id uuid,
+    p_event_id uuid,
+    p_user_id uuid
+) returns boolean as $$
+    select exists (
+        select 1
+        from event_host eh
+        join event e on e.event_id = eh.event_id
+        join "group" g on g.group_id = e.group_id
+        where g.community_id = p_community_id
+          and g.group_id = p_group_id
+          and g.active = true
+          and g.deleted = false
+          and e.deleted = false
+          and eh.event_id = p_event_id
+          and eh.user_id = p_user_id
+    );
+$$ language sql;
@@ -17,9 +17,12 @@ begin
         raise exception 'email verification failed: invalid code';
     end if;
 
-    -- Mark the user's email as verified
+    -- Mark the user's email as verified, which also proves it is deliverable
     update "user"
-    set email_verified = true
+    set
+        email_undeliverable_at = null,
+        email_undeliverable_reason = null,
+        email_verified = true
     where user_id = v_user_id;
 end;
 $$ language plpgsql;
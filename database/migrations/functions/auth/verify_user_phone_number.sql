@@ -0,0 +1,49 @@
+-- verify_user_phone_number verifies a user's phone number using the code sent by SMS.
+create or replace function verify_user_phone_number(
+    p_actor_user_id uuid,
+    p_code text
+) returns boolean as $$
+declare
+    v_code_hash text;
+begin
+    -- Get the pending verification code, which expires after some time or failed attempts
+    select code_hash
+    into v_code_hash
+    from phone_verification_code
+    where user_id = p_actor_user_id
+    and attempts < 5
+    and created_at > current_timestamp - interval '10 minutes'
+    for update;
+
+    if v_code_hash is null then
+        return false;
+    end if;
+
+    -- Count failed attempts so codes cannot be guessed
+    if v_code_hash <> encode(digest(btrim(p_code), 'sha256'), 'hex') then
+        update phone_verification_code
+        set attempts = attempts + 1
+        where user_id = p_actor_user_id;
+
+        return false;
+    end if;
+
+    -- Mark the phone number as verified
+    delete from phone_verification_code
+    where user_id = p_actor_user_id;
+
+    update "user"
+    set phone_number_verified = true
+    where user_id = p_actor_user_id;
+
+    -- Track the phone number verification
+    perform insert_audit_log(
+        'user_phone_number_verified',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id
+    );
+
+    return true;
+end;
+$$ language plpgsql;
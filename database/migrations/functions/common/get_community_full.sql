@@ -12,13 +12,18 @@ returns json as $$
         'created_at', floor(extract(epoch from created_at)*1000),
         'description', description,
         'display_name', display_name,
+        'dormant_group_months', dormant_group_months,
+        'dormant_groups_report_enabled', dormant_groups_report_enabled,
+        'event_approval_required', event_approval_required,
         'group_team_management_restricted', group_team_management_restricted,
         'logo_url', logo_url,
         'name', name,
+        'passkey_required', passkey_required,
 
         -- Include optional community profile fields
         'ad_banner_link_url', ad_banner_link_url,
         'ad_banner_url', ad_banner_url,
+        'allowed_email_domains', allowed_email_domains,
         'bluesky_url', bluesky_url,
         'extra_links', extra_links,
         'facebook_url', facebook_url,
@@ -29,6 +34,7 @@ returns json as $$
         'new_group_details', new_group_details,
         'og_image_url', og_image_url,
         'photos_urls', photos_urls,
+        'required_oidc_provider', required_oidc_provider,
         'slack_url', slack_url,
         'twitter_url', twitter_url,
         'website_url', website_url,
@@ -26,7 +26,15 @@ returns json as $$
         )
         -- Include optional fields and nested related collections
         || jsonb_build_object(
+            'attendance_certificate_emails_enabled', e.attendance_certificate_emails_enabled,
             'attendee_approval_required', e.attendee_approval_required,
+            'attendee_photos_enabled', e.attendee_photos_enabled,
+            'attendee_photos_urls', (
+                select array_agg(ep.url order by ep.reviewed_at asc, ep.event_photo_id asc)
+                from event_photo ep
+                where ep.event_id = e.event_id
+                and ep.status = 'approved'
+            ),
             'banner_mobile_url', e.banner_mobile_url,
             'banner_url', e.banner_url,
             'capacity', e.capacity,
@@ -48,6 +56,7 @@ returns json as $$
             'ends_at', floor(extract(epoch from e.ends_at)),
             'event_reminder_enabled', e.event_reminder_enabled,
             'event_series_id', e.event_series_id,
+            'feedback_url', e.feedback_url,
             'has_related_events', exists (
                 select 1
                 from event related_event
@@ -64,8 +73,10 @@ returns json as $$
             'meeting_in_sync', e.meeting_in_sync,
             'meeting_join_instructions', e.meeting_join_instructions,
             'meeting_join_url', coalesce(m_event.join_url, e.meeting_join_url),
+            'meeting_participants_joined_count', nullif(m_event.participants_joined_count, 0),
             'meeting_password', m_event.password,
             'meeting_provider', e.meeting_provider_id,
+            'meeting_provider_status', m_event.provider_status,
             'meeting_recording_public_url', case
                 when e.meeting_recording_published
                 then e.meeting_recording_url
@@ -76,7 +87,9 @@ returns json as $$
             'meeting_recording_requested', e.meeting_recording_requested,
             'meeting_recording_url', e.meeting_recording_url,
             'meeting_requested', e.meeting_requested,
+            'meeting_webinar', e.meeting_webinar,
             'meetup_url', e.meetup_url,
+            'no_show_waitlist_threshold', e.no_show_waitlist_threshold,
             'payment_currency_code', e.payment_currency_code
         )
         || jsonb_build_object(
@@ -87,13 +100,26 @@ returns json as $$
                 where ett.event_id = e.event_id
             ),
             'photos_urls', e.photos_urls,
+            'publish_scheduled_at', floor(extract(epoch from e.publish_scheduled_at)),
             'published_at', floor(extract(epoch from e.published_at)),
             'registration_ends_at', floor(extract(epoch from e.registration_ends_at)),
             'registration_required', e.registration_required,
             'registration_starts_at', floor(extract(epoch from e.registration_starts_at)),
+            'resources', list_event_resources(e.event_id),
             'starts_at', floor(extract(epoch from e.starts_at)),
+            'streaming_url', e.streaming_url,
             'tags', e.tags,
             'ticket_types', list_event_ticket_types(e.event_id),
+            'topics', (
+                select json_agg(json_build_object(
+                    'name', t.name,
+                    'normalized_name', t.normalized_name,
+                    'topic_id', t.topic_id
+                ) order by t.name)
+                from event_topic et
+                join topic t using (topic_id)
+                where et.event_id = e.event_id
+            ),
             'venue_address', e.venue_address,
             'venue_city', e.venue_city,
             'venue_country_code', e.venue_country_code,
@@ -101,6 +127,7 @@ returns json as $$
             'venue_name', e.venue_name,
             'venue_state', e.venue_state,
             'venue_zip_code', e.venue_zip_code,
+            'waitlist_confirmation_hours', e.waitlist_confirmation_hours,
             'waitlist_count', coalesce(ew.waitlist_count, 0),
             'waitlist_enabled', e.waitlist_enabled
         )
@@ -208,8 +235,10 @@ returns json as $$
                             'meeting_in_sync', s.meeting_in_sync,
                             'meeting_join_instructions', s.meeting_join_instructions,
                             'meeting_join_url', coalesce(m_session.join_url, s.meeting_join_url),
+                            'meeting_participants_joined_count', nullif(m_session.participants_joined_count, 0),
                             'meeting_password', m_session.password,
                             'meeting_provider', s.meeting_provider_id,
+                            'meeting_provider_status', m_session.provider_status,
                             'meeting_recording_public_url', case
                                 when s.meeting_recording_published
                                 then s.meeting_recording_url
@@ -219,6 +248,7 @@ returns json as $$
                             'meeting_recording_raw_urls', nullif(m_session.recording_urls, array[]::text[]),
                             'meeting_recording_url', s.meeting_recording_url,
                             'meeting_requested', s.meeting_requested,
+                            'track', s.track,
 
                             'speakers', coalesce(
                                 (
@@ -321,10 +351,14 @@ returns json as $$
                     'logo_url', gs.logo_url,
                     'name', gs.name,
 
+                    'logo_size', gst.logo_size,
                     'website_url', gs.website_url
-                )) order by gs.name), '[]')
+                )) order by gst."order" asc nulls last, gs.name), '[]')
                 from event_sponsor es
                 join group_sponsor gs on gs.group_sponsor_id = es.group_sponsor_id
+                left join group_sponsor_tier gst
+                    on gst.group_id = gs.group_id
+                    and lower(gst.name) = lower(btrim(es.level))
                 where es.event_id = e.event_id
             )
         )
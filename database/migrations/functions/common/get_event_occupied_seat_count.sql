@@ -6,6 +6,11 @@ returns int as $$
     where ea.event_id = p_event_id
     and (
         ea.status = 'confirmed'
+        -- Count seats reserved for promoted waitlist users until their confirmation expires
+        or (
+            ea.status = 'waitlist-confirmation-pending'
+            and ea.waitlist_confirmation_expires_at > current_timestamp
+        )
         or (
             ea.status = 'registration-questions-pending'
             and (
@@ -31,6 +31,7 @@ returns json as $$
         'timezone', e.timezone,
 
         -- Include optional event details
+        'approval_status', e.approval_status,
         'attendee_approval_required', e.attendee_approval_required,
         'capacity', e.capacity,
         'description_short', e.description_short,
@@ -55,6 +56,7 @@ returns json as $$
         'venue_country_name', e.venue_country_name,
         'venue_name', e.venue_name,
         'venue_state', e.venue_state,
+        'waitlist_confirmation_hours', e.waitlist_confirmation_hours,
         'waitlist_count', coalesce(ew.waitlist_count, 0),
         'waitlist_enabled', e.waitlist_enabled,
         'zip_code', e.venue_zip_code,
@@ -22,9 +22,11 @@ returns json as $$
             where group_id = g.group_id
         ),
         'name', g.name,
+        'private', g.private,
         'slug', g.slug,
 
         -- Include optional group profile fields
+        'archived_at', floor(extract(epoch from g.archived_at)),
         'banner_mobile_url', g.banner_mobile_url,
         'banner_url', g.banner_url,
         'bluesky_url', g.bluesky_url,
@@ -38,6 +40,7 @@ returns json as $$
         'flickr_url', g.flickr_url,
         'github_url', g.github_url,
         'instagram_url', g.instagram_url,
+        'join_questions', nullif(g.join_questions, '[]'::jsonb),
         'latitude', st_y(g.location::geometry),
         'linkedin_url', g.linkedin_url,
         'logo_url', coalesce(g.logo_url, c.logo_url),
@@ -57,6 +60,16 @@ returns json as $$
         'slug_pretty', g.slug_pretty,
         'state', g.state,
         'tags', g.tags,
+        'topics', (
+            select json_agg(json_build_object(
+                'name', t.name,
+                'normalized_name', t.normalized_name,
+                'topic_id', t.topic_id
+            ) order by t.name)
+            from group_topic gt
+            join topic t using (topic_id)
+            where gt.group_id = g.group_id
+        ),
         'twitter_url', g.twitter_url,
         'wechat_url', g.wechat_url,
         'website_url', g.website_url,
@@ -121,11 +134,14 @@ returns json as $$
                         'logo_url', gs.logo_url,
                         'name', gs.name,
 
+                        'logo_size', gst.logo_size,
+                        'tier_name', gst.name,
                         'website_url', gs.website_url
-                    )) order by gs.name
+                    )) order by gst."order" asc nulls last, gst.name, gs.name
                 ), '[]'::json
             )
             from group_sponsor gs
+            left join group_sponsor_tier gst using (group_sponsor_tier_id)
             where gs.group_id = g.group_id
         )
     )) as json_data
@@ -22,12 +22,14 @@ returns json as $$
         'slug', g.slug,
 
         -- Include optional group profile fields
+        'archived_at', floor(extract(epoch from g.archived_at)),
         'banner_mobile_url', g.banner_mobile_url,
         'banner_url', g.banner_url,
         'city', g.city,
         'country_code', g.country_code,
         'country_name', g.country_name,
         'description_short', g.description_short,
+        'dormant_since', floor(extract(epoch from g.dormant_since)),
         'latitude', st_y(g.location::geometry),
         'logo_url', coalesce(g.logo_url, c.logo_url),
         'longitude', st_x(g.location::geometry),
@@ -0,0 +1,18 @@
+-- Returns the community a notification was sent on behalf of, if any.
+create or replace function get_notification_community_id(p_notification_id uuid)
+returns uuid as $$
+    select coalesce(
+        n.community_id,
+        cni.community_id,
+        g.community_id,
+        cng.community_id
+    )
+    from notification n
+    left join community_newsletter_issue cni
+        on cni.community_newsletter_issue_id = n.community_newsletter_issue_id
+    left join "group" g on g.group_id = n.group_id
+    left join custom_notification cn on cn.custom_notification_id = n.custom_notification_id
+    left join event cne on cne.event_id = cn.event_id
+    left join "group" cng on cng.group_id = coalesce(cn.group_id, cne.group_id)
+    where n.notification_id = p_notification_id;
+$$ language sql stable;
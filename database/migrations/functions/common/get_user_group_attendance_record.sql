@@ -0,0 +1,25 @@
+-- Returns how many of the group's past events the user attended or missed.
+--
+-- Only events where organizers checked in attendees are considered, so events
+-- that did not use check-in do not count as no-shows.
+create or replace function get_user_group_attendance_record(p_group_id uuid, p_user_id uuid)
+returns table(events_attended int, events_missed int) as $$
+    select
+        count(*) filter (where ea.checked_in)::int as events_attended,
+        count(*) filter (where not ea.checked_in)::int as events_missed
+    from event_attendee ea
+    join event e using (event_id)
+    where e.group_id = p_group_id
+    and ea.user_id = p_user_id
+    and ea.status = 'confirmed'
+    and e.canceled = false
+    and e.deleted = false
+    and e.published = true
+    and coalesce(e.ends_at, e.starts_at) < current_timestamp
+    and exists (
+        select 1
+        from event_attendee checked_in_attendee
+        where checked_in_attendee.event_id = e.event_id
+        and checked_in_attendee.checked_in = true
+    );
+$$ language sql;
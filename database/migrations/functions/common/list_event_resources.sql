@@ -0,0 +1,22 @@
+-- list_event_resources returns the files attached to an event as JSON.
+create or replace function list_event_resources(p_event_id uuid)
+returns jsonb as $$
+    select nullif(
+        coalesce(
+            jsonb_agg(
+                jsonb_build_object(
+                    'content_type', er.content_type,
+                    'created_at', floor(extract(epoch from er.created_at)),
+                    'event_resource_id', er.event_resource_id,
+                    'name', er.name,
+                    'size_bytes', er.size_bytes
+                )
+                order by er.created_at asc, er.event_resource_id asc
+            ),
+            '[]'::jsonb
+        ),
+        '[]'::jsonb
+    )
+    from event_resource er
+    where er.event_id = p_event_id;
+$$ language sql;
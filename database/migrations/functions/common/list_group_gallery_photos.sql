@@ -0,0 +1,88 @@
+-- Returns the photos of the group gallery, cover first.
+--
+-- The gallery aggregates the photos uploaded by the organizers, the photos of
+-- the published events of the group and the approved photos shared by their
+-- attendees. Photos hidden by the organizers are only included on request.
+create or replace function list_group_gallery_photos(
+    p_group_id uuid,
+    p_include_hidden boolean
+)
+returns json as $$
+    with photos as (
+        -- Photos uploaded by the organizers
+        select
+            u.url,
+            null::text as event_name,
+            null::timestamptz as event_starts_at,
+            'group' as source,
+            0 as source_order,
+            u.position
+        from "group" g
+        cross join unnest(g.photos_urls) with ordinality as u(url, position)
+        where g.group_id = p_group_id
+
+        union all
+
+        -- Photos added to the events by the organizers
+        select
+            u.url,
+            e.name,
+            e.starts_at,
+            'event',
+            1,
+            u.position
+        from event e
+        cross join unnest(e.photos_urls) with ordinality as u(url, position)
+        where e.group_id = p_group_id
+        and e.published = true
+        and e.canceled = false
+        and e.deleted = false
+
+        union all
+
+        -- Approved photos shared by the events attendees
+        select
+            ep.url,
+            e.name,
+            e.starts_at,
+            'event',
+            1,
+            coalesce(cardinality(e.photos_urls), 0) + row_number() over (
+                partition by ep.event_id
+                order by ep.reviewed_at asc, ep.event_photo_id asc
+            )
+        from event_photo ep
+        join event e using (event_id)
+        where e.group_id = p_group_id
+        and e.published = true
+        and e.canceled = false
+        and e.deleted = false
+        and ep.status = 'approved'
+    ),
+    unique_photos as (
+        select distinct on (url) *
+        from photos
+        order by url, source_order, event_starts_at desc nulls first, position
+    )
+    select coalesce(
+        json_agg(
+            json_strip_nulls(json_build_object(
+                'cover', coalesce(p.url = g.gallery_cover_url, false),
+                'event_name', p.event_name,
+                'hidden', h.url is not null,
+                'source', p.source,
+                'url', p.url
+            ))
+            order by
+                coalesce(p.url = g.gallery_cover_url, false) desc,
+                p.source_order asc,
+                p.event_starts_at desc nulls first,
+                p.position asc
+        ),
+        '[]'::json
+    )
+    from unique_photos p
+    join "group" g on g.group_id = p_group_id
+    left join group_gallery_hidden_photo h on h.group_id = p_group_id and h.url = p.url
+    where p_include_hidden or h.url is null;
+$$ language sql;
@@ -19,6 +19,7 @@ declare
         when 'desc' then 'desc'
         else 'asc'
     end;
+    v_topic text[];
     v_tsquery_with_prefix_matching tsquery;
     v_user_location geography;
 begin
@@ -66,6 +67,10 @@ begin
         select array_agg(lower(e::text)) into v_region
         from jsonb_array_elements_text(p_filters->'region') e;
     end if;
+    if p_filters ? 'topic' then
+        select array_agg(lower(e::text)) into v_topic
+        from jsonb_array_elements_text(p_filters->'topic') e;
+    end if;
     if p_filters ? 'ts_query' then
         select ts_rewrite(
             websearch_to_tsquery(p_filters->>'ts_query'),
@@ -126,6 +131,15 @@ begin
         and
             case when cardinality(v_region) > 0 then
             r.normalized_name = any(v_region) else true end
+        and
+            case when cardinality(v_topic) > 0 then
+            exists (
+                select 1
+                from event_topic x
+                join topic t using (topic_id)
+                where x.event_id = e.event_id
+                and t.normalized_name = any(v_topic)
+            ) else true end
         and
             case when v_date_from is not null then
             e.starts_at >= v_date_from else true end
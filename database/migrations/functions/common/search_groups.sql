@@ -4,6 +4,7 @@ returns json as $$
 declare
     v_bbox geometry;
     v_community_ids uuid[];
+    v_dormant boolean := coalesce((p_filters->>'dormant')::boolean, false);
     v_group_category text[];
     v_include_inactive boolean := coalesce((p_filters->>'include_inactive')::boolean, false);
     v_limit int := (p_filters->>'limit')::int;
@@ -11,6 +12,7 @@ declare
     v_offset int := (p_filters->>'offset')::int;
     v_region text[];
     v_sort_by text := coalesce(p_filters->>'sort_by', 'name');
+    v_topic text[];
     v_tsquery_with_prefix_matching tsquery;
     v_user_location geography;
 begin
@@ -43,6 +45,10 @@ begin
         select array_agg(lower(e::text)) into v_region
         from jsonb_array_elements_text(p_filters->'region') e;
     end if;
+    if p_filters ? 'topic' then
+        select array_agg(lower(e::text)) into v_topic
+        from jsonb_array_elements_text(p_filters->'topic') e;
+    end if;
     if p_filters ? 'ts_query' then
         select ts_rewrite(
             websearch_to_tsquery(p_filters->>'ts_query'),
@@ -78,6 +84,7 @@ begin
         where c.active = true
         and (g.active = true or v_include_inactive)
         and g.deleted = false
+        and (g.dormant_since is not null or not v_dormant)
         and
             case when v_bbox is not null then
             st_intersects(g.location, v_bbox) else true end
@@ -93,6 +100,15 @@ begin
         and
             case when cardinality(v_region) > 0 then
             r.normalized_name = any(v_region) else true end
+        and
+            case when cardinality(v_topic) > 0 then
+            exists (
+                select 1
+                from group_topic x
+                join topic t using (topic_id)
+                where x.group_id = g.group_id
+                and t.normalized_name = any(v_topic)
+            ) else true end
         and
             case when v_tsquery_with_prefix_matching is not null then
                 v_tsquery_with_prefix_matching @@ g.tsdoc
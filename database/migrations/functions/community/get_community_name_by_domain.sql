@@ -0,0 +1,11 @@
+-- Returns the name of the community served from the given verified custom
+-- domain.
+create or replace function get_community_name_by_domain(p_domain text)
+returns text as $$
+    select c.name
+    from community_domain cd
+    join community c on c.community_id = cd.community_id
+    where cd.domain = lower(p_domain)
+    and cd.verified_at is not null
+    and c.active = true;
+$$ language sql;
@@ -0,0 +1,17 @@
+-- Returns a newsletter issue sent by a community.
+create or replace function get_community_newsletter_archive_issue(
+    p_community_id uuid,
+    p_community_newsletter_issue_id uuid
+)
+returns json as $$
+    select json_build_object(
+        'body', cni.body,
+        'community_newsletter_issue_id', cni.community_newsletter_issue_id,
+        'sent_at', extract(epoch from cni.sent_at)::bigint,
+        'subject', cni.subject
+    )
+    from community_newsletter_issue cni
+    where cni.community_id = p_community_id
+    and cni.community_newsletter_issue_id = p_community_newsletter_issue_id
+    and cni.sent_at is not null;
+$$ language sql;
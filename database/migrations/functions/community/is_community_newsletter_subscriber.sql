@@ -0,0 +1,12 @@
+-- Check if a user is subscribed to the newsletter of a community.
+create or replace function is_community_newsletter_subscriber(
+    p_community_id uuid,
+    p_user_id uuid
+) returns boolean as $$
+    select exists (
+        select 1
+        from community_newsletter_subscriber cns
+        where cns.community_id = p_community_id
+        and cns.user_id = p_user_id
+    );
+$$ language sql;
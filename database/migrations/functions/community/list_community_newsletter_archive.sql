@@ -0,0 +1,18 @@
+-- Returns the newsletter issues sent by a community, newest first.
+create or replace function list_community_newsletter_archive(p_community_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_build_object(
+                'community_newsletter_issue_id', cni.community_newsletter_issue_id,
+                'sent_at', extract(epoch from cni.sent_at)::bigint,
+                'subject', cni.subject
+            )
+            order by cni.sent_at desc, cni.community_newsletter_issue_id
+        ),
+        '[]'::json
+    )
+    from community_newsletter_issue cni
+    where cni.community_id = p_community_id
+    and cni.sent_at is not null;
+$$ language sql;
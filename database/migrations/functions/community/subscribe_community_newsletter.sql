@@ -0,0 +1,10 @@
+-- Subscribes a user to the newsletter of a community.
+create or replace function subscribe_community_newsletter(
+    p_community_id uuid,
+    p_user_id uuid
+)
+returns void as $$
+    insert into community_newsletter_subscriber (community_id, user_id)
+    values (p_community_id, p_user_id)
+    on conflict (community_id, user_id) do nothing;
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Unsubscribes a user from the newsletter of a community.
+create or replace function unsubscribe_community_newsletter(
+    p_community_id uuid,
+    p_user_id uuid
+)
+returns void as $$
+    -- Remove the subscription
+    delete from community_newsletter_subscriber
+    where community_id = p_community_id
+    and user_id = p_user_id;
+
+    -- Skip the user in the issues whose delivery is still in progress
+    delete from community_newsletter_issue_recipient cnir
+    using community_newsletter_issue cni
+    where cni.community_newsletter_issue_id = cnir.community_newsletter_issue_id
+    and cni.community_id = p_community_id
+    and cnir.user_id = p_user_id
+    and cnir.enqueued_at is null;
+$$ language sql;
@@ -0,0 +1,41 @@
+-- sync_group_topics replaces the topics assigned to a group.
+create or replace function sync_group_topics(
+    p_group_id uuid,
+    p_topic_ids jsonb
+)
+returns void as $$
+declare
+    v_topic_ids uuid[];
+begin
+    -- Collect the requested topics, deduplicating repeated IDs
+    select coalesce(array_agg(distinct e::uuid), '{}')
+    into v_topic_ids
+    from jsonb_array_elements_text(coalesce(nullif(p_topic_ids, 'null'::jsonb), '[]'::jsonb)) e;
+
+    -- Ensure all the topics belong to the group's community
+    if exists (
+        select 1
+        from unnest(v_topic_ids) as input_topic(topic_id)
+        where not exists (
+            select 1
+            from topic t
+            join "group" g on g.community_id = t.community_id
+            where g.group_id = p_group_id
+            and t.topic_id = input_topic.topic_id
+        )
+    ) then
+        raise exception 'topic not found in community';
+    end if;
+
+    -- Remove topics omitted from the payload
+    delete from group_topic
+    where group_id = p_group_id
+    and not (topic_id = any(v_topic_ids));
+
+    -- Assign the new topics
+    insert into group_topic (group_id, topic_id)
+    select p_group_id, input_topic.topic_id
+    from unnest(v_topic_ids) as input_topic(topic_id)
+    on conflict do nothing;
+end;
+$$ language plpgsql;
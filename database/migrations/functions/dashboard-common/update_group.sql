@@ -64,14 +64,15 @@ begin
     v_payment_recipient_changed := p_group ? 'payment_recipient'
         and v_previous_payment_recipient is distinct from v_new_payment_recipient;
 
-    -- Prevent clearing the recipient from breaking checkout for active ticketed events
+    -- Prevent clearing the recipient from breaking checkout for active paid
+    -- ticketed events (events offering only free ticket types have no currency)
     if v_payment_recipient_changed
        and v_new_payment_recipient is null
        and exists (
            select 1
            from event e
-           join event_ticket_type ett on ett.event_id = e.event_id
            where e.group_id = p_group_id
+           and e.payment_currency_code is not null
            and e.canceled = false
            and e.deleted = false
            and e.published = true
@@ -114,6 +115,7 @@ begin
             else payment_recipient
         end,
         photos_urls = jsonb_text_array(p_group->'photos_urls'),
+        private = coalesce((p_group->>'private')::boolean, private),
         region_id = case when p_group->>'region_id' <> '' then (p_group->>'region_id')::uuid else null end,
         slack_url = nullif(p_group->>'slack_url', ''),
         slug_pretty = nullif(btrim(p_group->>'slug_pretty'), ''),
@@ -132,6 +134,9 @@ begin
         raise exception 'group not found or inactive';
     end if;
 
+    -- Synchronize the group topics
+    perform sync_group_topics(p_group_id, p_group->'topic_ids');
+
     -- Track the update
     perform insert_audit_log(
         'group_updated',
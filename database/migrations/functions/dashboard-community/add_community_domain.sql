@@ -0,0 +1,36 @@
+-- Adds a custom domain to a community, pending its DNS verification.
+create or replace function add_community_domain(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_domain text
+)
+returns uuid as $$
+declare
+    v_community_domain_id uuid;
+begin
+    -- Insert the domain record
+    insert into community_domain (
+        community_id,
+        created_by,
+        domain
+    ) values (
+        p_community_id,
+        p_actor_user_id,
+        lower(btrim(p_domain))
+    )
+    returning community_domain_id into v_community_domain_id;
+
+    -- Track the added domain
+    perform insert_audit_log(
+        'community_domain_added',
+        p_actor_user_id,
+        'community_domain',
+        v_community_domain_id,
+        p_community_id
+    );
+
+    return v_community_domain_id;
+exception when unique_violation then
+    raise exception 'domain already in use';
+end;
+$$ language plpgsql;
@@ -0,0 +1,36 @@
+-- Adds a newsletter issue draft to a community.
+create or replace function add_community_newsletter_issue(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_issue jsonb
+)
+returns uuid as $$
+declare
+    v_community_newsletter_issue_id uuid;
+begin
+    -- Insert the issue draft
+    insert into community_newsletter_issue (
+        body,
+        community_id,
+        created_by,
+        subject
+    ) values (
+        btrim(p_issue->>'body'),
+        p_community_id,
+        p_actor_user_id,
+        btrim(p_issue->>'subject')
+    )
+    returning community_newsletter_issue_id into v_community_newsletter_issue_id;
+
+    -- Track the added issue
+    perform insert_audit_log(
+        'community_newsletter_issue_added',
+        p_actor_user_id,
+        'community_newsletter_issue',
+        v_community_newsletter_issue_id,
+        p_community_id
+    );
+
+    return v_community_newsletter_issue_id;
+end;
+$$ language plpgsql;
@@ -117,6 +117,9 @@ begin
             )
             returning group_id into v_group_id;
 
+            -- Assign the group topics
+            perform sync_group_topics(v_group_id, p_group->'topic_ids');
+
             -- Track the created group
             perform insert_audit_log(
                 'group_added',
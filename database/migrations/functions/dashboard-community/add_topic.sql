@@ -0,0 +1,34 @@
+-- Adds a new topic to a community.
+create or replace function add_topic(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_topic jsonb
+)
+returns uuid as $$
+declare
+    v_topic_id uuid;
+begin
+    -- Insert the topic record
+    insert into topic (
+        community_id,
+        name
+    ) values (
+        p_community_id,
+        p_topic->>'name'
+    )
+    returning topic_id into v_topic_id;
+
+    -- Track the created topic
+    perform insert_audit_log(
+        'topic_added',
+        p_actor_user_id,
+        'topic',
+        v_topic_id,
+        p_community_id
+    );
+
+    return v_topic_id;
+exception when unique_violation then
+    raise exception 'topic already exists';
+end;
+$$ language plpgsql;
@@ -0,0 +1,45 @@
+-- approve_event approves an event waiting for the community approval and
+-- publishes it, returning the event group id.
+create or replace function approve_event(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_event_id uuid,
+    p_configured_provider text
+)
+returns uuid as $$
+declare
+    v_group_id uuid;
+begin
+    -- Approve the pending event
+    update event e set
+        approval_status = 'approved'
+    from "group" g
+    where g.group_id = e.group_id
+    and g.community_id = p_community_id
+    and e.event_id = p_event_id
+    and e.approval_status = 'pending'
+    and e.deleted = false
+    and e.canceled = false
+    returning e.group_id into v_group_id;
+
+    if v_group_id is null then
+        raise exception 'event not found or not pending approval';
+    end if;
+
+    -- Track the approval
+    perform insert_audit_log(
+        'event_approved',
+        p_actor_user_id,
+        'event',
+        p_event_id,
+        p_community_id,
+        v_group_id,
+        p_event_id
+    );
+
+    -- Publish the approved event
+    perform publish_event(p_actor_user_id, v_group_id, p_event_id, p_configured_provider);
+
+    return v_group_id;
+end;
+$$ language plpgsql;
@@ -0,0 +1,33 @@
+-- archive_group marks an active group as archived, keeping its page readable.
+create or replace function archive_group(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_group_id uuid
+)
+returns void as $$
+begin
+    -- Archive the target group
+    update "group" set
+        archived_at = current_timestamp
+    where group_id = p_group_id
+    and community_id = p_community_id
+    and active = true
+    and archived_at is null
+    and deleted = false;
+
+    -- Ensure the target group exists and can be archived
+    if not found then
+        raise exception 'group not found, inactive or already archived';
+    end if;
+
+    -- Track the archival
+    perform insert_audit_log(
+        'group_archived',
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        p_community_id,
+        p_group_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,9 @@
+-- Returns the number of users subscribed to the newsletter of a community.
+create or replace function count_community_newsletter_subscribers(p_community_id uuid)
+returns int as $$
+    select count(*)::int
+    from community_newsletter_subscriber cns
+    join "user" u using (user_id)
+    where cns.community_id = p_community_id
+    and u.email_verified = true;
+$$ language sql;
@@ -0,0 +1,33 @@
+-- Deletes a custom domain from a community.
+create or replace function delete_community_domain(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_community_domain_id uuid
+)
+returns void as $$
+declare
+    v_domain text;
+begin
+    -- Delete the domain, snapshotting it so the audit row remains readable
+    delete from community_domain cd
+    where cd.community_id = p_community_id
+    and cd.community_domain_id = p_community_domain_id
+    returning cd.domain into v_domain;
+
+    if not found then
+        raise exception 'domain not found';
+    end if;
+
+    -- Track the deletion
+    perform insert_audit_log(
+        'community_domain_deleted',
+        p_actor_user_id,
+        'community_domain',
+        p_community_domain_id,
+        p_community_id,
+        null,
+        null,
+        jsonb_build_object('name', v_domain)
+    );
+end;
+$$ language plpgsql;
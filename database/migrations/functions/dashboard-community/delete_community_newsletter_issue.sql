@@ -0,0 +1,43 @@
+-- Deletes a newsletter issue draft from a community.
+create or replace function delete_community_newsletter_issue(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_community_newsletter_issue_id uuid
+)
+returns void as $$
+declare
+    v_sent_at timestamptz;
+    v_subject text;
+begin
+    -- Check the issue exists and has not been sent yet
+    select cni.sent_at, cni.subject
+    into v_sent_at, v_subject
+    from community_newsletter_issue cni
+    where cni.community_id = p_community_id
+    and cni.community_newsletter_issue_id = p_community_newsletter_issue_id
+    for update;
+
+    if not found then
+        raise exception 'newsletter issue not found';
+    end if;
+    if v_sent_at is not null then
+        raise exception 'sent newsletter issues cannot be deleted';
+    end if;
+
+    -- Delete the issue
+    delete from community_newsletter_issue
+    where community_newsletter_issue_id = p_community_newsletter_issue_id;
+
+    -- Track the deletion, snapshotting the subject so the audit row remains readable
+    perform insert_audit_log(
+        'community_newsletter_issue_deleted',
+        p_actor_user_id,
+        'community_newsletter_issue',
+        p_community_newsletter_issue_id,
+        p_community_id,
+        null,
+        null,
+        jsonb_build_object('name', v_subject)
+    );
+end;
+$$ language plpgsql;
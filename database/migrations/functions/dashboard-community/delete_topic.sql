@@ -0,0 +1,34 @@
+-- Deletes a topic from a community, unassigning it from groups and events.
+create or replace function delete_topic(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_topic_id uuid
+)
+returns void as $$
+declare
+    v_name text;
+begin
+    -- Delete the topic record, snapshotting its name so the audit row remains
+    -- readable after deletion (assignments are removed by cascade)
+    delete from topic t
+    where t.community_id = p_community_id
+      and t.topic_id = p_topic_id
+    returning t.name into v_name;
+
+    if not found then
+        raise exception 'topic not found';
+    end if;
+
+    -- Track the deletion
+    perform insert_audit_log(
+        'topic_deleted',
+        p_actor_user_id,
+        'topic',
+        p_topic_id,
+        p_community_id,
+        null,
+        null,
+        jsonb_build_object('name', v_name)
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,18 @@
+-- Returns a custom domain of a community.
+create or replace function get_community_domain(
+    p_community_id uuid,
+    p_community_domain_id uuid
+)
+returns json as $$
+    select json_strip_nulls(json_build_object(
+        'community_domain_id', cd.community_domain_id,
+        'created_at', extract(epoch from cd.created_at)::bigint,
+        'domain', cd.domain,
+        'verification_token', cd.verification_token,
+
+        'verified_at', extract(epoch from cd.verified_at)::bigint
+    ))
+    from community_domain cd
+    where cd.community_id = p_community_id
+    and cd.community_domain_id = p_community_domain_id;
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Returns a newsletter issue of a community.
+create or replace function get_community_newsletter_issue(
+    p_community_id uuid,
+    p_community_newsletter_issue_id uuid
+)
+returns json as $$
+    select json_strip_nulls(json_build_object(
+        'body', cni.body,
+        'community_newsletter_issue_id', cni.community_newsletter_issue_id,
+        'created_at', extract(epoch from cni.created_at)::bigint,
+        'subject', cni.subject,
+
+        'recipient_count', cni.recipient_count,
+        'sent_at', extract(epoch from cni.sent_at)::bigint
+    ))
+    from community_newsletter_issue cni
+    where cni.community_id = p_community_id
+    and cni.community_newsletter_issue_id = p_community_newsletter_issue_id;
+$$ language sql;
@@ -24,27 +24,46 @@ returns json as $$
             cross join filters f
             where al.community_id = p_community_id
             and al.action = any(array[
+                'community_domain_added',
+                'community_domain_deleted',
+                'community_domain_verified',
+                'community_newsletter_issue_added',
+                'community_newsletter_issue_deleted',
+                'community_newsletter_issue_sent',
                 'community_team_invitation_accepted',
                 'community_team_invitation_rejected',
                 'community_team_member_added',
                 'community_team_member_removed',
                 'community_team_member_role_updated',
                 'community_updated',
+                'event_approval_requested',
+                'event_approved',
                 'event_category_added',
                 'event_category_deleted',
                 'event_category_updated',
+                'event_rejected',
                 'group_activated',
                 'group_added',
+                'group_archived',
                 'group_category_added',
                 'group_category_deleted',
                 'group_category_updated',
                 'group_deactivated',
                 'group_deleted',
+                'group_member_banned',
+                'group_member_unbanned',
+                'group_merged',
+                'group_owner_updated',
                 'group_payment_recipient_updated',
+                'group_restored',
                 'group_updated',
+                'notification_requeued',
                 'region_added',
                 'region_deleted',
-                'region_updated'
+                'region_updated',
+                'topic_added',
+                'topic_deleted',
+                'topic_updated'
             ]::text[])
             and (f.action_value is null or al.action = f.action_value)
             and (
@@ -91,6 +110,16 @@ returns json as $$
                             from community c
                             where c.community_id = fl.resource_id
                         )
+                        when 'community_domain' then (
+                            select cd.domain
+                            from community_domain cd
+                            where cd.community_domain_id = fl.resource_id
+                        )
+                        when 'community_newsletter_issue' then (
+                            select cni.subject
+                            from community_newsletter_issue cni
+                            where cni.community_newsletter_issue_id = fl.resource_id
+                        )
                         when 'event' then (
                             select e.name
                             from event e
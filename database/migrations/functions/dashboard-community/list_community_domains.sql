@@ -0,0 +1,20 @@
+-- Returns the custom domains of a community sorted by domain.
+create or replace function list_community_domains(p_community_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_strip_nulls(json_build_object(
+                'community_domain_id', cd.community_domain_id,
+                'created_at', extract(epoch from cd.created_at)::bigint,
+                'domain', cd.domain,
+                'verification_token', cd.verification_token,
+
+                'verified_at', extract(epoch from cd.verified_at)::bigint
+            ))
+            order by cd.domain asc
+        ),
+        '[]'::json
+    )
+    from community_domain cd
+    where cd.community_id = p_community_id;
+$$ language sql;
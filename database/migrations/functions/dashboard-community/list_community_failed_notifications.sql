@@ -0,0 +1,74 @@
+-- Returns paginated notifications sent on behalf of the community whose
+-- delivery failed after exhausting their retries.
+create or replace function list_community_failed_notifications(
+    p_community_id uuid,
+    p_filters jsonb
+)
+returns json as $$
+    with
+        -- Parse pagination filters
+        filters as (
+            select
+                (p_filters->>'limit')::int as limit_value,
+                (p_filters->>'offset')::int as offset_value
+        ),
+        -- Select the failed notifications sent on behalf of the community
+        community_failed_notifications as (
+            select n.*
+            from notification n
+            left join community_newsletter_issue cni
+                on cni.community_newsletter_issue_id = n.community_newsletter_issue_id
+            left join "group" g on g.group_id = n.group_id
+            left join custom_notification cn on cn.custom_notification_id = n.custom_notification_id
+            left join event cne on cne.event_id = cn.event_id
+            left join "group" cng on cng.group_id = coalesce(cn.group_id, cne.group_id)
+            where n.delivery_status in ('delivery-unknown', 'failed')
+            and p_community_id in (
+                n.community_id,
+                cni.community_id,
+                g.community_id,
+                cng.community_id
+            )
+        ),
+        -- Select the paginated failed notifications list
+        failed_notifications as (
+            select
+                cfn.channel,
+                extract(epoch from cfn.created_at)::bigint as created_at,
+                cfn.delivery_attempts,
+                cfn.delivery_status,
+                cfn.kind,
+                cfn.notification_id,
+
+                cfn.error,
+                extract(epoch from cfn.processed_at)::bigint as processed_at,
+                u.username
+            from community_failed_notifications cfn
+            left join "user" u on u.user_id = cfn.user_id
+            order by cfn.processed_at desc nulls last, cfn.notification_id asc
+            offset (select offset_value from filters)
+            limit (select limit_value from filters)
+        ),
+        -- Count total failed notifications before pagination
+        totals as (
+            select count(*)::int as total
+            from community_failed_notifications
+        ),
+        -- Render failed notifications as JSON
+        failed_notifications_json as (
+            select coalesce(
+                json_agg(
+                    row_to_json(failed_notifications)
+                    order by processed_at desc nulls last, notification_id asc
+                ),
+                '[]'::json
+            ) as notifications
+            from failed_notifications
+        )
+    -- Build final payload
+    select json_build_object(
+        'notifications', failed_notifications_json.notifications,
+        'total', totals.total
+    )
+    from totals, failed_notifications_json;
+$$ language sql;
@@ -0,0 +1,28 @@
+-- Returns the users banned from the groups of a community, most recent first.
+create or replace function list_community_group_bans(p_community_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_strip_nulls(json_build_object(
+                'created_at', extract(epoch from gb.created_at)::bigint,
+                'group_id', g.group_id,
+                'group_name', g.name,
+                'reason', gb.reason,
+                'user_id', u.user_id,
+                'username', u.username,
+
+                'banned_by_username', bu.username,
+                'name', u.name,
+                'photo_url', u.photo_url
+            ))
+            order by gb.created_at desc, g.name asc, u.username asc
+        ),
+        '[]'::json
+    )
+    from group_ban gb
+    join "group" g on g.group_id = gb.group_id
+    join "user" u on u.user_id = gb.user_id
+    left join "user" bu on bu.user_id = gb.created_by
+    where g.community_id = p_community_id
+    and g.deleted = false;
+$$ language sql;
@@ -0,0 +1,58 @@
+-- Returns the newsletter issues of a community, drafts first and then the sent
+-- ones newest first, with the delivery status of their emails.
+create or replace function list_community_newsletter_issues(p_community_id uuid)
+returns json as $$
+    with
+        -- Count the emails of each issue by delivery status
+        issues as (
+            select
+                cni.community_newsletter_issue_id,
+                cni.body,
+                cni.created_at,
+                cni.recipient_count,
+                cni.sent_at,
+                cni.subject,
+                count(n.notification_id) filter (where n.delivery_status = 'bounced')::int as bounced,
+                count(n.notification_id) filter (where n.delivery_status = 'failed')::int as failed,
+                count(n.notification_id) filter (
+                    where n.delivery_status in ('pending', 'processing')
+                )::int as queued,
+                count(n.notification_id) filter (where n.delivery_status = 'processed')::int as sent,
+                count(n.notification_id) filter (
+                    where n.delivery_status = 'delivery-unknown'
+                )::int as unknown,
+                u.username as created_by_username
+            from community_newsletter_issue cni
+            left join notification n
+                on n.community_newsletter_issue_id = cni.community_newsletter_issue_id
+                and n.channel = 'email'
+            left join "user" u on u.user_id = cni.created_by
+            where cni.community_id = p_community_id
+            group by cni.community_newsletter_issue_id, u.username
+        )
+    select coalesce(
+        json_agg(
+            json_strip_nulls(json_build_object(
+                'body', i.body,
+                'bounced', i.bounced,
+                'community_newsletter_issue_id', i.community_newsletter_issue_id,
+                'created_at', extract(epoch from i.created_at)::bigint,
+                'failed', i.failed,
+                'queued', i.queued,
+                'sent', i.sent,
+                'subject', i.subject,
+                'unknown', i.unknown,
+
+                'created_by_username', i.created_by_username,
+                'recipient_count', i.recipient_count,
+                'sent_at', extract(epoch from i.sent_at)::bigint
+            ))
+            order by
+                i.sent_at desc nulls first,
+                i.created_at desc,
+                i.community_newsletter_issue_id asc
+        ),
+        '[]'::json
+    )
+    from issues i;
+$$ language sql;
@@ -0,0 +1,25 @@
+-- list_community_pending_events returns the events waiting for the community
+-- approval, oldest requests first.
+create or replace function list_community_pending_events(
+    p_community_id uuid
+)
+returns json as $$
+    select coalesce(json_agg(
+        json_build_object(
+            'event', get_event_summary(p_community_id, e.group_id, e.event_id),
+            'requested_at', floor(extract(epoch from e.approval_requested_at)),
+
+            'requested_by_display_name', coalesce(u.name, u.username),
+            'requested_by_username', u.username
+        ) order by e.approval_requested_at asc, e.event_id asc
+    ), '[]')
+    from event e
+    join "group" g on g.group_id = e.group_id
+    left join "user" u on u.user_id = e.approval_requested_by
+    where g.community_id = p_community_id
+    and g.deleted = false
+    and e.approval_status = 'pending'
+    and e.published = false
+    and e.deleted = false
+    and e.canceled = false;
+$$ language sql;
@@ -0,0 +1,22 @@
+-- Returns the active groups of the community a group can be merged into.
+create or replace function list_group_merge_targets(p_community_id uuid, p_group_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_strip_nulls(json_build_object(
+                'active', g.active,
+                'group_id', g.group_id,
+                'name', g.name,
+                'slug', g.slug,
+                'slug_pretty', g.slug_pretty
+            ))
+            order by g.name asc
+        ),
+        '[]'::json
+    )
+    from "group" g
+    where g.community_id = p_community_id
+    and g.group_id <> p_group_id
+    and g.active = true
+    and g.deleted = false;
+$$ language sql;
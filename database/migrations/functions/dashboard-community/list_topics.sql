@@ -0,0 +1,29 @@
+-- list_topics returns all topics for a community.
+create or replace function list_topics(
+    p_community_id uuid
+)
+returns json as $$
+    select coalesce(json_agg(
+        json_build_object(
+            'events_count', (
+                select count(*)
+                from event_topic et
+                join event e using (event_id)
+                where et.topic_id = t.topic_id
+                and e.deleted = false
+            ),
+            'groups_count', (
+                select count(*)
+                from group_topic gt
+                join "group" g using (group_id)
+                where gt.topic_id = t.topic_id
+                and g.deleted = false
+            ),
+            'name', t.name,
+            'normalized_name', t.normalized_name,
+            'topic_id', t.topic_id
+        ) order by t.name
+    ), '[]')
+    from topic t
+    where t.community_id = p_community_id;
+$$ language sql;
@@ -0,0 +1,206 @@
+-- merge_groups folds a source group into a target group of the same community.
+-- Members, pending join requests, events, team and sponsors are moved to the
+-- target group, bans are carried over, and the source group is deactivated,
+-- keeping its slug to redirect to the target.
+create or replace function merge_groups(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_source_group_id uuid,
+    p_target_group_id uuid
+)
+returns void as $$
+declare
+    v_source_group_name text;
+begin
+    -- A group cannot be merged into itself
+    if p_source_group_id = p_target_group_id then
+        raise exception 'a group cannot be merged into itself';
+    end if;
+
+    -- Lock both groups in a stable order to serialize concurrent merges
+    perform 1
+    from "group"
+    where group_id in (p_source_group_id, p_target_group_id)
+    order by group_id
+    for update;
+
+    -- Ensure the source group exists and has not been merged yet
+    select name
+    into v_source_group_name
+    from "group"
+    where group_id = p_source_group_id
+    and community_id = p_community_id
+    and deleted = false
+    and merged_into_group_id is null;
+
+    if not found then
+        raise exception 'group not found';
+    end if;
+
+    -- Ensure the target group exists and is active
+    if not exists (
+        select 1
+        from "group"
+        where group_id = p_target_group_id
+        and community_id = p_community_id
+        and active = true
+        and deleted = false
+    ) then
+        raise exception 'target group not found or inactive';
+    end if;
+
+    -- Carry the bans over, keeping the target ban when already banned there
+    insert into group_ban (group_id, user_id, created_at, created_by, reason)
+    select p_target_group_id, user_id, created_at, created_by, reason
+    from group_ban
+    where group_id = p_source_group_id
+    on conflict (group_id, user_id) do nothing;
+
+    -- Move the members, keeping the target membership when already a member
+    -- and skipping the users banned from the target group
+    insert into group_member (group_id, user_id, created_at, join_answers, member_role)
+    select p_target_group_id, user_id, created_at, join_answers, member_role
+    from group_member
+    where group_id = p_source_group_id
+    and not exists (
+        select 1
+        from group_ban gb
+        where gb.group_id = p_target_group_id
+        and gb.user_id = group_member.user_id
+    )
+    on conflict (group_id, user_id) do nothing;
+
+    delete from group_member
+    where group_id = p_source_group_id;
+
+    -- Move the pending join requests, skipping the users who are already
+    -- members of the target group, banned from it or with a request there
+    insert into group_join_request (group_id, user_id, created_at, join_answers)
+    select p_target_group_id, user_id, created_at, join_answers
+    from group_join_request jr
+    where jr.group_id = p_source_group_id
+    and jr.status = 'pending'
+    and not exists (
+        select 1
+        from group_member gm
+        where gm.group_id = p_target_group_id
+        and gm.user_id = jr.user_id
+    )
+    and not exists (
+        select 1
+        from group_ban gb
+        where gb.group_id = p_target_group_id
+        and gb.user_id = jr.user_id
+    )
+    on conflict (group_id, user_id) do nothing;
+
+    delete from group_join_request
+    where group_id = p_source_group_id
+    and status = 'pending';
+
+    -- Move the team, keeping the target role when already on its team and
+    -- skipping the users banned from the target group
+    insert into group_team (group_id, user_id, accepted, created_at, role)
+    select p_target_group_id, user_id, accepted, created_at, role
+    from group_team
+    where group_id = p_source_group_id
+    and not exists (
+        select 1
+        from group_ban gb
+        where gb.group_id = p_target_group_id
+        and gb.user_id = group_team.user_id
+    )
+    on conflict (group_id, user_id) do update set
+        accepted = group_team.accepted or excluded.accepted;
+
+    delete from group_team
+    where group_id = p_source_group_id;
+
+    -- Move the sponsors, reusing the target tiers with the same name
+    update group_sponsor gs set
+        group_sponsor_tier_id = tt.group_sponsor_tier_id
+    from group_sponsor_tier st
+    join group_sponsor_tier tt on tt.group_id = p_target_group_id
+        and lower(tt.name) = lower(st.name)
+    where gs.group_sponsor_tier_id = st.group_sponsor_tier_id
+    and st.group_id = p_source_group_id;
+
+    update group_sponsor_tier st set
+        group_id = p_target_group_id
+    where st.group_id = p_source_group_id
+    and not exists (
+        select 1
+        from group_sponsor_tier tt
+        where tt.group_id = p_target_group_id
+        and lower(tt.name) = lower(st.name)
+    );
+
+    delete from group_sponsor_tier
+    where group_id = p_source_group_id;
+
+    update group_sponsor set
+        group_id = p_target_group_id
+    where group_id = p_source_group_id;
+
+    -- Add the source topics to the target group
+    insert into group_topic (group_id, topic_id)
+    select p_target_group_id, topic_id
+    from group_topic
+    where group_id = p_source_group_id
+    on conflict do nothing;
+
+    delete from group_topic
+    where group_id = p_source_group_id;
+
+    -- Move the events, regenerating the slugs already used in the target group
+    update event e set
+        slug = generate_slug(7)
+    where e.group_id = p_source_group_id
+    and exists (
+        select 1
+        from event t
+        where t.group_id = p_target_group_id
+        and t.slug = e.slug
+    );
+
+    update event set
+        group_id = p_target_group_id
+    where group_id = p_source_group_id;
+
+    update event_series set
+        group_id = p_target_group_id
+    where group_id = p_source_group_id;
+
+    -- Archive the source group, redirecting it to the target one
+    update "group" set
+        active = false,
+        merged_at = current_timestamp,
+        merged_into_group_id = p_target_group_id,
+        parent_group_id = null
+    where group_id = p_source_group_id;
+
+    -- Clear child links pointing to the merged group
+    update "group" set
+        parent_group_id = null
+    where community_id = p_community_id
+    and deleted = false
+    and parent_group_id = p_source_group_id;
+
+    -- Keep redirecting groups previously merged into the source group
+    update "group" set
+        merged_into_group_id = p_target_group_id
+    where merged_into_group_id = p_source_group_id;
+
+    -- Track the merge
+    perform insert_audit_log(
+        'group_merged',
+        p_actor_user_id,
+        'group',
+        p_source_group_id,
+        p_community_id,
+        p_target_group_id,
+        null,
+        jsonb_build_object('source_group_name', v_source_group_name)
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,43 @@
+-- reject_event rejects an event waiting for the community approval,
+-- returning the event group id.
+create or replace function reject_event(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_event_id uuid,
+    p_reason text
+)
+returns uuid as $$
+declare
+    v_group_id uuid;
+begin
+    -- Reject the pending event
+    update event e set
+        approval_rejection_reason = nullif(p_reason, ''),
+        approval_status = 'rejected'
+    from "group" g
+    where g.group_id = e.group_id
+    and g.community_id = p_community_id
+    and e.event_id = p_event_id
+    and e.approval_status = 'pending'
+    and e.deleted = false
+    returning e.group_id into v_group_id;
+
+    if v_group_id is null then
+        raise exception 'event not found or not pending approval';
+    end if;
+
+    -- Track the rejection
+    perform insert_audit_log(
+        'event_rejected',
+        p_actor_user_id,
+        'event',
+        p_event_id,
+        p_community_id,
+        v_group_id,
+        p_event_id,
+        jsonb_strip_nulls(jsonb_build_object('reason', nullif(p_reason, '')))
+    );
+
+    return v_group_id;
+end;
+$$ language plpgsql;
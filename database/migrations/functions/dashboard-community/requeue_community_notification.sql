@@ -0,0 +1,54 @@
+-- Requeues a failed notification sent on behalf of the community.
+create or replace function requeue_community_notification(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_notification_id uuid
+)
+returns void as $$
+declare
+    v_notification record;
+begin
+    -- Lock the failed notification, ensuring it belongs to the community
+    select
+        n.delivery_status,
+        n.error,
+        n.kind
+    into v_notification
+    from notification n
+    where n.notification_id = p_notification_id
+    and n.delivery_status in ('delivery-unknown', 'failed')
+    and get_notification_community_id(n.notification_id) = p_community_id
+    for update;
+
+    if not found then
+        raise exception 'notification not found';
+    end if;
+
+    -- Return the notification to the immediate delivery queue
+    update notification
+    set
+        delivery_attempts = 0,
+        delivery_claimed_at = null,
+        delivery_status = 'pending',
+        error = null,
+        next_delivery_attempt_at = null,
+        processed_at = null
+    where notification_id = p_notification_id;
+
+    -- Track the requeued notification and its previous outcome
+    perform insert_audit_log(
+        'notification_requeued',
+        p_actor_user_id,
+        'notification',
+        p_notification_id,
+        p_community_id,
+        null,
+        null,
+        jsonb_build_object(
+            'name', v_notification.kind,
+            'previous_delivery_status', v_notification.delivery_status,
+            'previous_error', v_notification.error
+        )
+    );
+end;
+$$ language plpgsql;
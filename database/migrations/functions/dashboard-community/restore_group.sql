@@ -0,0 +1,32 @@
+-- restore_group clears the archived state of a group.
+create or replace function restore_group(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_group_id uuid
+)
+returns void as $$
+begin
+    -- Restore the target group
+    update "group" set
+        archived_at = null
+    where group_id = p_group_id
+    and community_id = p_community_id
+    and archived_at is not null
+    and deleted = false;
+
+    -- Ensure the target group exists and is archived
+    if not found then
+        raise exception 'group not found or not archived';
+    end if;
+
+    -- Track the restoration
+    perform insert_audit_log(
+        'group_restored',
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        p_community_id,
+        p_group_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,66 @@
+-- Sends a newsletter issue draft to the community newsletter subscribers.
+--
+-- The subscribers are snapshotted as the issue recipients, which are enqueued
+-- for delivery in batches by enqueue_due_community_newsletter_batches. Returns
+-- the number of recipients.
+create or replace function send_community_newsletter_issue(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_community_newsletter_issue_id uuid
+)
+returns int as $$
+declare
+    v_recipient_count int;
+    v_sent_at timestamptz;
+begin
+    -- Lock the issue and check it has not been sent yet
+    select cni.sent_at
+    into v_sent_at
+    from community_newsletter_issue cni
+    where cni.community_id = p_community_id
+    and cni.community_newsletter_issue_id = p_community_newsletter_issue_id
+    for update;
+
+    if not found then
+        raise exception 'newsletter issue not found';
+    end if;
+    if v_sent_at is not null then
+        raise exception 'newsletter issue already sent';
+    end if;
+
+    -- Snapshot the subscribers with a verified email as the issue recipients
+    insert into community_newsletter_issue_recipient (community_newsletter_issue_id, user_id)
+    select p_community_newsletter_issue_id, cns.user_id
+    from community_newsletter_subscriber cns
+    join "user" u using (user_id)
+    where cns.community_id = p_community_id
+    and u.email_verified = true;
+
+    get diagnostics v_recipient_count = row_count;
+
+    if v_recipient_count = 0 then
+        raise exception 'the newsletter has no subscribers yet';
+    end if;
+
+    -- Mark the issue as sent
+    update community_newsletter_issue set
+        recipient_count = v_recipient_count,
+        sent_at = current_timestamp,
+        sent_by = p_actor_user_id
+    where community_newsletter_issue_id = p_community_newsletter_issue_id;
+
+    -- Track the sent issue
+    perform insert_audit_log(
+        'community_newsletter_issue_sent',
+        p_actor_user_id,
+        'community_newsletter_issue',
+        p_community_newsletter_issue_id,
+        p_community_id,
+        null,
+        null,
+        jsonb_build_object('recipient_count', v_recipient_count)
+    );
+
+    return v_recipient_count;
+end;
+$$ language plpgsql;
@@ -12,15 +12,36 @@ begin
         banner_url = coalesce(p_data->>'banner_url', banner_url),
         description = coalesce(p_data->>'description', description),
         display_name = coalesce(p_data->>'display_name', display_name),
+        dormant_group_months = coalesce(
+            (p_data->>'dormant_group_months')::int,
+            dormant_group_months
+        ),
+        dormant_groups_report_enabled = coalesce(
+            (p_data->>'dormant_groups_report_enabled')::boolean,
+            dormant_groups_report_enabled
+        ),
+        event_approval_required = coalesce(
+            (p_data->>'event_approval_required')::boolean,
+            event_approval_required
+        ),
         group_team_management_restricted = coalesce(
             (p_data->>'group_team_management_restricted')::boolean,
             group_team_management_restricted
         ),
         logo_url = coalesce(p_data->>'logo_url', logo_url),
+        passkey_required = coalesce(
+            (p_data->>'passkey_required')::boolean,
+            passkey_required
+        ),
 
         ad_banner_link_url = nullif(p_data->>'ad_banner_link_url', ''),
         ad_banner_url = nullif(p_data->>'ad_banner_url', ''),
+        allowed_email_domains = jsonb_text_array(p_data->'allowed_email_domains'),
         bluesky_url = nullif(p_data->>'bluesky_url', ''),
+        email_footer_text = nullif(p_data->>'email_footer_text', ''),
+        email_from_name = nullif(p_data->>'email_from_name', ''),
+        email_logo_url = nullif(p_data->>'email_logo_url', ''),
+        email_primary_color = nullif(p_data->>'email_primary_color', ''),
         extra_links = nullif(p_data->'extra_links', 'null'::jsonb),
         facebook_url = nullif(p_data->>'facebook_url', ''),
         flickr_url = nullif(p_data->>'flickr_url', ''),
@@ -30,7 +51,13 @@ begin
         new_group_details = nullif(p_data->>'new_group_details', ''),
         og_image_url = nullif(p_data->>'og_image_url', ''),
         photos_urls = jsonb_text_array(p_data->'photos_urls'),
+        required_oidc_provider = nullif(p_data->>'required_oidc_provider', ''),
         slack_url = nullif(p_data->>'slack_url', ''),
+        slack_webhook_url = nullif(p_data->>'slack_webhook_url', ''),
+        theme_favicon_url = nullif(p_data->>'theme_favicon_url', ''),
+        theme_font_family = nullif(p_data->>'theme_font_family', ''),
+        theme_header_logo_url = nullif(p_data->>'theme_header_logo_url', ''),
+        theme_primary_color = nullif(p_data->>'theme_primary_color', ''),
         twitter_url = nullif(p_data->>'twitter_url', ''),
         website_url = nullif(p_data->>'website_url', ''),
         wechat_url = nullif(p_data->>'wechat_url', ''),
@@ -0,0 +1,39 @@
+-- update_dormant_groups flags the groups that have not held any event in the
+-- number of months configured by their community, and clears the flag of the
+-- groups that are not dormant anymore. Returns the number of groups updated.
+create or replace function update_dormant_groups()
+returns int as $$
+    with group_dormancy as (
+        select
+            g.group_id,
+            case
+                when g.active = true
+                and g.archived_at is null
+                and la.last_activity_at + make_interval(months => c.dormant_group_months)
+                    <= current_timestamp
+                then la.last_activity_at + make_interval(months => c.dormant_group_months)
+            end as dormant_since
+        from "group" g
+        join community c on c.community_id = g.community_id
+        cross join lateral (
+            -- Groups without events are dormant since they were created
+            select coalesce(max(e.starts_at), g.created_at) as last_activity_at
+            from event e
+            where e.group_id = g.group_id
+            and e.canceled = false
+            and e.deleted = false
+            and e.published = true
+            and e.test_event = false
+        ) la
+        where g.deleted = false
+    ),
+    updated_groups as (
+        update "group" g
+        set dormant_since = gd.dormant_since
+        from group_dormancy gd
+        where g.group_id = gd.group_id
+        and g.dormant_since is distinct from gd.dormant_since
+        returning g.group_id
+    )
+    select count(*)::int from updated_groups;
+$$ language sql;
@@ -0,0 +1,66 @@
+-- update_group_owner assigns a new owner to a group, adding them to the group
+-- team as an admin. Used by community admins to recover abandoned groups.
+create or replace function update_group_owner(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_group_id uuid,
+    p_username text
+)
+returns void as $$
+declare
+    v_previous_owner_user_id uuid;
+    v_user_id uuid;
+begin
+    -- Lock the target group to serialize ownership changes
+    select owner_user_id
+    into v_previous_owner_user_id
+    from "group"
+    where group_id = p_group_id
+    and community_id = p_community_id
+    and deleted = false
+    for update;
+
+    if not found then
+        raise exception 'group not found';
+    end if;
+
+    -- Find the new owner
+    select user_id
+    into v_user_id
+    from "user"
+    where username = btrim(p_username);
+
+    if v_user_id is null then
+        raise exception 'user not found';
+    end if;
+
+    -- Make the new owner an accepted group admin
+    insert into group_team (group_id, user_id, accepted, role)
+    values (p_group_id, v_user_id, true, 'admin')
+    on conflict (group_id, user_id) do update set
+        accepted = true,
+        role = 'admin';
+
+    -- Assign the ownership, discarding any pending transfer
+    update "group" set
+        owner_user_id = v_user_id
+    where group_id = p_group_id;
+
+    delete from group_ownership_transfer
+    where group_id = p_group_id;
+
+    -- Track the ownership update
+    perform insert_audit_log(
+        'group_owner_updated',
+        p_actor_user_id,
+        'user',
+        v_user_id,
+        p_community_id,
+        p_group_id,
+        null,
+        jsonb_strip_nulls(jsonb_build_object(
+            'previous_owner_user_id', v_previous_owner_user_id
+        ))
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,32 @@
+-- Updates a topic in a community.
+create or replace function update_topic(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_topic_id uuid,
+    p_topic jsonb
+)
+returns void as $$
+begin
+    -- Update the topic record
+    update topic set
+        name = p_topic->>'name'
+    where community_id = p_community_id
+      and topic_id = p_topic_id;
+
+    -- Ensure the target topic exists in the selected community
+    if not found then
+        raise exception 'topic not found';
+    end if;
+
+    -- Track the updated topic
+    perform insert_audit_log(
+        'topic_updated',
+        p_actor_user_id,
+        'topic',
+        p_topic_id,
+        p_community_id
+    );
+exception when unique_violation then
+    raise exception 'topic already exists';
+end;
+$$ language plpgsql;
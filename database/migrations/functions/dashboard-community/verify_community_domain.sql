@@ -0,0 +1,29 @@
+-- Marks a custom domain of a community as verified once its DNS TXT record
+-- has been checked, so the community can be served from it.
+create or replace function verify_community_domain(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_community_domain_id uuid
+)
+returns void as $$
+begin
+    -- Mark the domain as verified
+    update community_domain cd
+    set verified_at = current_timestamp
+    where cd.community_id = p_community_id
+    and cd.community_domain_id = p_community_domain_id;
+
+    if not found then
+        raise exception 'domain not found';
+    end if;
+
+    -- Track the verification
+    perform insert_audit_log(
+        'community_domain_verified',
+        p_actor_user_id,
+        'community_domain',
+        p_community_domain_id,
+        p_community_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,54 @@
+-- Accepts a pending group join request and adds the user as a member.
+create or replace function accept_group_join_request(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_user_id uuid
+)
+returns void as $$
+declare
+    v_community_id uuid;
+    v_join_answers jsonb;
+begin
+    -- Verify the group is active
+    select community_id
+    into v_community_id
+    from "group"
+    where group_id = p_group_id
+    and active = true
+    and deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Mark the request accepted
+    update group_join_request
+    set
+        reviewed_at = current_timestamp,
+        reviewed_by = p_actor_user_id,
+        status = 'accepted'
+    where group_id = p_group_id
+    and user_id = p_user_id
+    and status = 'pending'
+    returning join_answers into v_join_answers;
+
+    if not found then
+        raise exception 'pending join request not found';
+    end if;
+
+    -- Add the user to the group, keeping the answers sent with the request
+    insert into group_member (group_id, user_id, join_answers)
+    values (p_group_id, p_user_id, v_join_answers)
+    on conflict (group_id, user_id) do nothing;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        'group_join_request_accepted',
+        p_actor_user_id,
+        'user',
+        p_user_id,
+        v_community_id,
+        p_group_id
+    );
+end;
+$$ language plpgsql;
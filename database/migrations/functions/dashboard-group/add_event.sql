@@ -18,6 +18,16 @@ declare
     v_ticket_types jsonb := nullif(p_event->'ticket_types', 'null'::jsonb);
     v_ticket_capacity int := get_event_ticket_capacity(nullif(p_event->'ticket_types', 'null'::jsonb));
 begin
+    -- Archived groups do not accept new events
+    if exists (
+        select 1
+        from "group"
+        where group_id = p_group_id
+        and archived_at is not null
+    ) then
+        raise exception 'group is archived';
+    end if;
+
     -- Validate registration questions before writing the event
     perform validate_questionnaire_questions_payload(coalesce(p_event->'registration_questions', '[]'::jsonb));
 
@@ -64,7 +74,9 @@ begin
                 event_category_id,
                 event_kind_id,
 
+                attendance_certificate_emails_enabled,
                 attendee_approval_required,
+                attendee_photos_enabled,
                 banner_mobile_url,
                 banner_url,
                 capacity,
@@ -76,6 +88,7 @@ begin
                 description_short,
                 ends_at,
                 event_reminder_enabled,
+                feedback_url,
                 location,
                 logo_url,
                 luma_url,
@@ -88,14 +101,19 @@ begin
                 meeting_recording_requested,
                 meeting_recording_url,
                 meeting_requested,
+                meeting_webinar,
                 meetup_url,
+                no_show_waitlist_threshold,
                 payment_currency_code,
                 photos_urls,
+                publish_scheduled_at,
+                publish_scheduled_by,
                 registration_ends_at,
                 registration_required,
                 registration_questions,
                 registration_starts_at,
                 starts_at,
+                streaming_url,
                 tags,
                 venue_address,
                 venue_city,
@@ -104,6 +122,7 @@ begin
                 venue_name,
                 venue_state,
                 venue_zip_code,
+                waitlist_confirmation_hours,
                 waitlist_enabled
             ) values (
                 p_group_id,
@@ -115,7 +134,9 @@ begin
                 (p_event->>'category_id')::uuid,
                 p_event->>'kind_id',
 
+                coalesce((p_event->>'attendance_certificate_emails_enabled')::boolean, false),
                 v_event_attendee_approval_required,
+                coalesce((p_event->>'attendee_photos_enabled')::boolean, false),
                 nullif(p_event->>'banner_mobile_url', ''),
                 nullif(p_event->>'banner_url', ''),
                 v_effective_capacity,
@@ -127,6 +148,7 @@ begin
                 nullif(p_event->>'description_short', ''),
                 (p_event->>'ends_at')::timestamp at time zone (p_event->>'timezone'),
                 coalesce((p_event->>'event_reminder_enabled')::boolean, true),
+                nullif(p_event->>'feedback_url', ''),
                 jsonb_geography_point(p_event),
                 nullif(p_event->>'logo_url', ''),
                 nullif(p_event->>'luma_url', ''),
@@ -142,14 +164,22 @@ begin
                 coalesce((p_event->>'meeting_recording_requested')::boolean, true),
                 nullif(p_event->>'meeting_recording_url', ''),
                 (p_event->>'meeting_requested')::boolean,
+                coalesce((p_event->>'meeting_webinar')::boolean, false),
                 nullif(p_event->>'meetup_url', ''),
+                (p_event->>'no_show_waitlist_threshold')::int,
                 v_payment_currency_code,
                 jsonb_text_array(p_event->'photos_urls'),
+                (p_event->>'publish_scheduled_at')::timestamp at time zone (p_event->>'timezone'),
+                case
+                    when p_event->>'publish_scheduled_at' is not null then p_actor_user_id
+                    else null
+                end,
                 (p_event->>'registration_ends_at')::timestamp at time zone (p_event->>'timezone'),
                 (p_event->>'registration_required')::boolean,
                 coalesce(p_event->'registration_questions', '[]'::jsonb),
                 (p_event->>'registration_starts_at')::timestamp at time zone (p_event->>'timezone'),
                 (p_event->>'starts_at')::timestamp at time zone (p_event->>'timezone'),
+                nullif(p_event->>'streaming_url', ''),
                 jsonb_text_array(p_event->'tags'),
                 nullif(p_event->>'venue_address', ''),
                 nullif(p_event->>'venue_city', ''),
@@ -158,6 +188,7 @@ begin
                 nullif(p_event->>'venue_name', ''),
                 nullif(p_event->>'venue_state', ''),
                 nullif(p_event->>'venue_zip_code', ''),
+                (p_event->>'waitlist_confirmation_hours')::int,
                 case
                     when v_ticket_types is not null then false
                     else coalesce((p_event->>'waitlist_enabled')::boolean, false)
@@ -188,6 +219,9 @@ begin
     -- Insert CFS labels
     perform sync_event_cfs_labels(v_event_id, p_event->'cfs_labels');
 
+    -- Assign the event topics
+    perform sync_event_topics(v_event_id, p_event->'topic_ids');
+
     -- Insert event hosts, speakers, and sponsors
     perform sync_event_hosts_speakers_sponsors(v_event_id, p_event);
 
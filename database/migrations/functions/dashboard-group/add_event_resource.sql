@@ -0,0 +1,71 @@
+-- Attaches a file uploaded by an organizer to an event.
+create or replace function add_event_resource(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_event_id uuid,
+    p_resource jsonb
+)
+returns uuid as $$
+declare
+    v_community_id uuid;
+    v_event_resource_id uuid;
+begin
+    -- Verify the event belongs to the selected group and lock it
+    select g.community_id
+    into v_community_id
+    from event e
+    join "group" g on g.group_id = e.group_id
+    where e.event_id = p_event_id
+    and e.group_id = p_group_id
+    and e.deleted = false
+    for update of e;
+
+    if not found then
+        raise exception 'event not found';
+    end if;
+
+    -- Validate the event has not reached the resources limit
+    if (
+        select count(*)
+        from event_resource er
+        where er.event_id = p_event_id
+    ) >= 10 then
+        raise exception 'you can add up to 10 resources per event';
+    end if;
+
+    -- Add the resource
+    insert into event_resource (
+        content_type,
+        created_by,
+        event_id,
+        file_name,
+        name,
+        size_bytes
+    ) values (
+        p_resource->>'content_type',
+        p_actor_user_id,
+        p_event_id,
+        p_resource->>'file_name',
+        p_resource->>'name',
+        (p_resource->>'size_bytes')::int
+    )
+    returning event_resource_id into v_event_resource_id;
+
+    -- Track the change
+    perform insert_audit_log(
+        'event_resource_added',
+        p_actor_user_id,
+        'event',
+        p_event_id,
+        v_community_id,
+        p_group_id,
+        p_event_id,
+        jsonb_build_object(
+            'event_resource_id', v_event_resource_id,
+            'name', p_resource->>'name'
+        )
+    );
+
+    return v_event_resource_id;
+end;
+$$ language plpgsql;
@@ -58,7 +58,8 @@ begin
         recurrence_pattern,
         timezone,
 
-        created_by
+        created_by,
+        name
     ) values (
         p_group_id,
         v_additional_occurrences,
@@ -66,7 +67,8 @@ begin
         v_pattern,
         v_timezone,
 
-        p_actor_user_id
+        p_actor_user_id,
+        nullif(btrim(p_recurrence->>'name'), '')
     )
     returning event_series_id into v_event_series_id;
 
@@ -0,0 +1,98 @@
+-- Saves an event of the group as a reusable event template.
+--
+-- The template keeps the reusable event details (description, agenda
+-- skeleton, venue and settings), leaving out dates, meeting links and other
+-- details that only apply to the saved event. Saving an event with the same
+-- name as an existing template replaces it.
+create or replace function add_event_template(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_group_id uuid,
+    p_event_id uuid
+)
+returns uuid as $$
+declare
+    v_event jsonb;
+    v_event_template_id uuid;
+begin
+    -- Get the event details
+    v_event := get_event_full(p_community_id, p_group_id, p_event_id)::jsonb;
+    if v_event is null or exists (
+        select 1 from event where event_id = p_event_id and deleted = true
+    ) then
+        raise exception 'event not found';
+    end if;
+
+    -- Insert or replace the template
+    insert into event_template (
+        created_by,
+        details,
+        group_id,
+        name
+    ) values (
+        p_actor_user_id,
+        jsonb_strip_nulls(jsonb_build_object(
+            'attendance_certificate_emails_enabled', v_event->'attendance_certificate_emails_enabled',
+            'attendee_approval_required', v_event->'attendee_approval_required',
+            'attendee_photos_enabled', v_event->'attendee_photos_enabled',
+            'banner_mobile_url', v_event->'banner_mobile_url',
+            'banner_url', v_event->'banner_url',
+            'capacity', v_event->'capacity',
+            'category_name', v_event->'category_name',
+            'description', v_event->'description',
+            'description_short', v_event->'description_short',
+            'event_reminder_enabled', v_event->'event_reminder_enabled',
+            'hosts', v_event->'hosts',
+            'kind', v_event->'kind',
+            'logo_url', v_event->'logo_url',
+            'name', v_event->'name',
+            'payment_currency_code', v_event->'payment_currency_code',
+            'registration_questions', v_event->'registration_questions',
+            'registration_required', v_event->'registration_required',
+            'sessions', (
+                select coalesce(jsonb_agg(jsonb_strip_nulls(jsonb_build_object(
+                    'description', s.description,
+                    'kind', s.session_kind_id,
+                    'location', s.location,
+                    'name', s.name
+                )) order by s.starts_at, s.name), '[]'::jsonb)
+                from session s
+                where s.event_id = p_event_id
+            ),
+            'sponsors', v_event->'sponsors',
+            'tags', v_event->'tags',
+            'ticket_types', v_event->'ticket_types',
+            'timezone', v_event->'timezone',
+            'venue_address', v_event->'venue_address',
+            'venue_city', v_event->'venue_city',
+            'venue_country_code', v_event->'venue_country_code',
+            'venue_country_name', v_event->'venue_country_name',
+            'venue_name', v_event->'venue_name',
+            'venue_state', v_event->'venue_state',
+            'venue_zip_code', v_event->'venue_zip_code',
+            'waitlist_enabled', v_event->'waitlist_enabled'
+        )),
+        p_group_id,
+        v_event->>'name'
+    )
+    on conflict (group_id, lower(name)) do update set
+        created_at = current_timestamp,
+        created_by = excluded.created_by,
+        details = excluded.details,
+        name = excluded.name
+    returning event_template_id into v_event_template_id;
+
+    -- Track the template creation
+    perform insert_audit_log(
+        'event_template_added',
+        p_actor_user_id,
+        'event_template',
+        v_event_template_id,
+        p_community_id,
+        p_group_id,
+        p_event_id
+    );
+
+    return v_event_template_id;
+end;
+$$ language plpgsql;
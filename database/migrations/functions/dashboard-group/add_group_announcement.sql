@@ -0,0 +1,37 @@
+-- Adds a new announcement to the group.
+create or replace function add_group_announcement(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_announcement jsonb
+)
+returns uuid as $$
+declare
+    v_group_announcement_id uuid;
+begin
+    -- Insert the announcement for the group
+    insert into group_announcement (
+        content,
+        created_by,
+        group_id,
+        title
+    ) values (
+        btrim(p_announcement->>'content'),
+        p_actor_user_id,
+        p_group_id,
+        btrim(p_announcement->>'title')
+    )
+    returning group_announcement_id into v_group_announcement_id;
+
+    -- Track the announcement creation
+    perform insert_audit_log(
+        'group_announcement_added',
+        p_actor_user_id,
+        'group_announcement',
+        v_group_announcement_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+
+    return v_group_announcement_id;
+end;
+$$ language plpgsql;
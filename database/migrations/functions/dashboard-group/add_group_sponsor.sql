@@ -12,6 +12,7 @@ begin
     insert into group_sponsor (
         featured,
         group_id,
+        group_sponsor_tier_id,
         logo_url,
         name,
 
@@ -19,6 +20,12 @@ begin
     ) values (
         coalesce((p_sponsor->>'featured')::boolean, true),
         p_group_id,
+        (
+            select gst.group_sponsor_tier_id
+            from group_sponsor_tier gst
+            where gst.group_id = p_group_id
+            and gst.group_sponsor_tier_id = (p_sponsor->>'group_sponsor_tier_id')::uuid
+        ),
         p_sponsor->>'logo_url',
         p_sponsor->>'name',
 
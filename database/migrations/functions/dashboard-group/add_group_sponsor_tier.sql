@@ -0,0 +1,39 @@
+-- Adds a new sponsor tier to the group.
+create or replace function add_group_sponsor_tier(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_tier jsonb
+)
+returns uuid as $$
+declare
+    v_group_sponsor_tier_id uuid;
+begin
+    -- Insert the tier for the group
+    insert into group_sponsor_tier (
+        group_id,
+        logo_size,
+        name,
+        "order"
+    ) values (
+        p_group_id,
+        coalesce(p_tier->>'logo_size', 'medium'),
+        btrim(p_tier->>'name'),
+        coalesce((p_tier->>'order')::int, 0)
+    )
+    returning group_sponsor_tier_id into v_group_sponsor_tier_id;
+
+    -- Track the tier creation
+    perform insert_audit_log(
+        'group_sponsor_tier_added',
+        p_actor_user_id,
+        'group_sponsor_tier',
+        v_group_sponsor_tier_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+
+    return v_group_sponsor_tier_id;
+exception when unique_violation then
+    raise exception 'sponsor tier already exists';
+end;
+$$ language plpgsql;
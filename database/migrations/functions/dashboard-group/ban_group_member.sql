@@ -0,0 +1,88 @@
+-- Bans a user from a group, removing their membership, pending requests and
+-- upcoming events attendance.
+create or replace function ban_group_member(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_group_id uuid,
+    p_user_id uuid,
+    p_reason text
+) returns void as $$
+declare
+    v_reason text := btrim(p_reason);
+begin
+    -- Verify the group exists in the community
+    if not exists (
+        select 1
+        from "group"
+        where group_id = p_group_id
+        and community_id = p_community_id
+        and deleted = false
+    ) then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Team members must be removed from the group team before being banned
+    if exists (
+        select 1
+        from group_team
+        where group_id = p_group_id
+        and user_id = p_user_id
+    ) then
+        raise exception 'group team members cannot be banned';
+    end if;
+
+    -- Record the ban
+    insert into group_ban (group_id, user_id, created_by, reason)
+    values (p_group_id, p_user_id, p_actor_user_id, v_reason)
+    on conflict (group_id, user_id) do nothing;
+
+    if not found then
+        raise exception 'user is already banned from this group';
+    end if;
+
+    -- Remove the membership and the pending join request, if any
+    delete from group_member
+    where group_id = p_group_id
+    and user_id = p_user_id;
+
+    delete from group_join_request
+    where group_id = p_group_id
+    and user_id = p_user_id
+    and status = 'pending';
+
+    -- Remove the attendance and pending invitations to the upcoming group events
+    delete from event_attendee ea
+    using event e
+    where e.event_id = ea.event_id
+    and e.group_id = p_group_id
+    and ea.user_id = p_user_id
+    and ea.status in ('confirmed', 'invitation-pending')
+    and (
+        coalesce(e.ends_at, e.starts_at) is null
+        or coalesce(e.ends_at, e.starts_at) >= current_timestamp
+    );
+
+    -- Remove the user from the waiting lists of the upcoming group events
+    delete from event_waitlist ew
+    using event e
+    where e.event_id = ew.event_id
+    and e.group_id = p_group_id
+    and ew.user_id = p_user_id
+    and (
+        coalesce(e.ends_at, e.starts_at) is null
+        or coalesce(e.ends_at, e.starts_at) >= current_timestamp
+    );
+
+    -- Track the ban
+    perform insert_audit_log(
+        'group_member_banned',
+        p_actor_user_id,
+        'user',
+        p_user_id,
+        p_community_id,
+        p_group_id,
+        null,
+        jsonb_build_object('reason', v_reason)
+    );
+end;
+$$ language plpgsql;
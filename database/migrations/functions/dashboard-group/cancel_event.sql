@@ -55,9 +55,10 @@ begin
         attendance_canceled_by_user_id = p_actor_user_id,
         checked_in = false,
         checked_in_at = null,
-        status = 'attendance-canceled'
+        status = 'attendance-canceled',
+        waitlist_confirmation_expires_at = null
     where event_id = p_event_id
-    and status in ('confirmed', 'registration-questions-pending');
+    and status in ('confirmed', 'registration-questions-pending', 'waitlist-confirmation-pending');
 
     update event_attendee
     set status = 'invitation-canceled'
@@ -0,0 +1,29 @@
+-- Cancels the pending ownership transfer of a group.
+create or replace function cancel_group_ownership_transfer(
+    p_actor_user_id uuid,
+    p_group_id uuid
+)
+returns void as $$
+declare
+    v_user_id uuid;
+begin
+    -- Remove the pending transfer
+    delete from group_ownership_transfer
+    where group_id = p_group_id
+    returning user_id into v_user_id;
+
+    if not found then
+        raise exception 'no pending group ownership transfer found';
+    end if;
+
+    -- Track the canceled transfer
+    perform insert_audit_log(
+        'group_ownership_transfer_canceled',
+        p_actor_user_id,
+        'user',
+        v_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
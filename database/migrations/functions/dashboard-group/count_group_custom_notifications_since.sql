@@ -0,0 +1,11 @@
+-- Returns the number of custom notifications sent to a group's members since the date provided.
+create or replace function count_group_custom_notifications_since(
+    p_group_id uuid,
+    p_since timestamptz
+)
+returns bigint as $$
+    select count(*)
+    from custom_notification
+    where group_id = p_group_id
+    and created_at >= p_since;
+$$ language sql;
@@ -0,0 +1,51 @@
+-- Removes a file attached to an event.
+create or replace function delete_event_resource(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_event_id uuid,
+    p_event_resource_id uuid
+)
+returns void as $$
+declare
+    v_community_id uuid;
+    v_name text;
+begin
+    -- Verify the event belongs to the selected group
+    select g.community_id
+    into v_community_id
+    from event e
+    join "group" g on g.group_id = e.group_id
+    where e.event_id = p_event_id
+    and e.group_id = p_group_id
+    and e.deleted = false;
+
+    if not found then
+        raise exception 'event not found';
+    end if;
+
+    -- Delete the resource
+    delete from event_resource
+    where event_resource_id = p_event_resource_id
+    and event_id = p_event_id
+    returning name into v_name;
+
+    if not found then
+        raise exception 'resource not found';
+    end if;
+
+    -- Track the change
+    perform insert_audit_log(
+        'event_resource_deleted',
+        p_actor_user_id,
+        'event',
+        p_event_id,
+        v_community_id,
+        p_group_id,
+        p_event_id,
+        jsonb_build_object(
+            'event_resource_id', p_event_resource_id,
+            'name', v_name
+        )
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,33 @@
+-- Deletes an event template from the group.
+create or replace function delete_event_template(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_event_template_id uuid
+)
+returns void as $$
+declare
+    v_name text;
+begin
+    -- Delete the template
+    delete from event_template
+    where event_template_id = p_event_template_id
+    and group_id = p_group_id
+    returning name into v_name;
+
+    if not found then
+        raise exception 'event template not found';
+    end if;
+
+    -- Track the template deletion
+    perform insert_audit_log(
+        'event_template_deleted',
+        p_actor_user_id,
+        'event_template',
+        p_event_template_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object('name', v_name)
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,33 @@
+-- Deletes an announcement from the group.
+create or replace function delete_group_announcement(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_group_announcement_id uuid
+)
+returns void as $$
+declare
+    v_title text;
+begin
+    -- Delete the announcement
+    delete from group_announcement
+    where group_announcement_id = p_group_announcement_id
+    and group_id = p_group_id
+    returning title into v_title;
+
+    if not found then
+        raise exception 'announcement not found';
+    end if;
+
+    -- Track the announcement deletion
+    perform insert_audit_log(
+        'group_announcement_deleted',
+        p_actor_user_id,
+        'group_announcement',
+        p_group_announcement_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object('title', v_title)
+    );
+end;
+$$ language plpgsql;
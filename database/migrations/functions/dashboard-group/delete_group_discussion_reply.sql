@@ -0,0 +1,41 @@
+-- Deletes a reply from a topic in a group's discussion board.
+create or replace function delete_group_discussion_reply(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_group_discussion_reply_id uuid
+)
+returns void as $$
+declare
+    v_group_discussion_topic_id uuid;
+    v_user_id uuid;
+begin
+    -- Delete the reply
+    delete from group_discussion_reply r
+    using group_discussion_topic t
+    where r.group_discussion_reply_id = p_group_discussion_reply_id
+    and t.group_discussion_topic_id = r.group_discussion_topic_id
+    and t.group_id = p_group_id
+    returning r.group_discussion_topic_id, r.user_id
+    into v_group_discussion_topic_id, v_user_id;
+
+    if not found then
+        raise exception 'reply not found';
+    end if;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        'group_discussion_reply_deleted',
+        p_actor_user_id,
+        'user',
+        v_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object(
+            'group_discussion_reply_id', p_group_discussion_reply_id,
+            'group_discussion_topic_id', v_group_discussion_topic_id,
+            'user_id', v_user_id
+        )
+    );
+end;
+$$ language plpgsql;
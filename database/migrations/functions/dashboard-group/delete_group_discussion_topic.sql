@@ -0,0 +1,38 @@
+-- Deletes a topic, and its replies, from a group's discussion board.
+create or replace function delete_group_discussion_topic(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_group_discussion_topic_id uuid
+)
+returns void as $$
+declare
+    v_title text;
+    v_user_id uuid;
+begin
+    -- Delete the topic
+    delete from group_discussion_topic
+    where group_discussion_topic_id = p_group_discussion_topic_id
+    and group_id = p_group_id
+    returning title, user_id into v_title, v_user_id;
+
+    if not found then
+        raise exception 'topic not found';
+    end if;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        'group_discussion_topic_deleted',
+        p_actor_user_id,
+        'user',
+        v_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object(
+            'group_discussion_topic_id', p_group_discussion_topic_id,
+            'title', v_title,
+            'user_id', v_user_id
+        )
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,33 @@
+-- Deletes a sponsor tier from the group, leaving its sponsors without a tier.
+create or replace function delete_group_sponsor_tier(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_group_sponsor_tier_id uuid
+)
+returns void as $$
+declare
+    v_name text;
+begin
+    -- Delete the tier for the group
+    delete from group_sponsor_tier
+    where group_sponsor_tier_id = p_group_sponsor_tier_id
+    and group_id = p_group_id
+    returning name into v_name;
+
+    if not found then
+        raise exception 'sponsor tier not found';
+    end if;
+
+    -- Track the tier deletion
+    perform insert_audit_log(
+        'group_sponsor_tier_deleted',
+        p_actor_user_id,
+        'group_sponsor_tier',
+        p_group_sponsor_tier_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object('name', v_name)
+    );
+end;
+$$ language plpgsql;
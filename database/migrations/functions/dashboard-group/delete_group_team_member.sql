@@ -32,6 +32,16 @@ begin
         raise exception 'user is not a group team member';
     end if;
 
+    -- Prevent removing the group owner
+    if exists (
+        select 1
+        from "group"
+        where group_id = p_group_id
+        and owner_user_id = p_user_id
+    ) then
+        raise exception 'cannot remove the group owner, transfer the ownership first';
+    end if;
+
     -- Prevent removing the last accepted group admin
     if v_is_accepted and v_role = 'admin' then
         select count(*)::int
@@ -0,0 +1,24 @@
+-- Deletes the Zoom credentials configured for the group.
+create or replace function delete_group_zoom_credentials(
+    p_actor_user_id uuid,
+    p_group_id uuid
+)
+returns void as $$
+begin
+    -- Delete the credentials
+    delete from group_zoom_credentials
+    where group_id = p_group_id;
+
+    if found then
+        -- Track the deleted credentials
+        perform insert_audit_log(
+            'group_zoom_credentials_deleted',
+            p_actor_user_id,
+            'group',
+            p_group_id,
+            (select community_id from "group" where group_id = p_group_id),
+            p_group_id
+        );
+    end if;
+end;
+$$ language plpgsql;
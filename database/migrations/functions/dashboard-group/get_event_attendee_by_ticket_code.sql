@@ -0,0 +1,31 @@
+-- Returns the confirmed attendee of a group event that owns the given ticket code.
+create or replace function get_event_attendee_by_ticket_code(
+    p_group_id uuid,
+    p_event_id uuid,
+    p_ticket_code uuid
+) returns json as $$
+    select json_build_object(
+        'checked_in', ea.checked_in,
+        'user_id', u.user_id,
+        'username', u.username,
+        'name', u.name,
+        'ticket_title', ep.ticket_title
+    )
+    from event_attendee ea
+    join event e on e.event_id = ea.event_id
+    join "user" u on u.user_id = ea.user_id
+    left join lateral (
+        select ticket_title
+        from event_purchase
+        where event_id = ea.event_id
+        and user_id = ea.user_id
+        and status = 'completed'
+        order by created_at desc, event_purchase_id desc
+        limit 1
+    ) ep on true
+    where ea.event_id = p_event_id
+    and ea.ticket_code = p_ticket_code
+    and ea.status = 'confirmed'
+    and e.group_id = p_group_id
+    and e.deleted = false;
+$$ language sql;
@@ -0,0 +1,11 @@
+-- Returns the event details saved in a template of the group.
+create or replace function get_event_template_details(
+    p_group_id uuid,
+    p_event_template_id uuid
+)
+returns json as $$
+    select et.details::json
+    from event_template et
+    where et.event_template_id = p_event_template_id
+    and et.group_id = p_group_id;
+$$ language sql;
@@ -0,0 +1,169 @@
+-- Returns group engagement statistics as a JSON object.
+--
+-- The function complements get_group_stats with engagement metrics scoped to
+-- a single group or to a group and its active subgroups:
+--   - check_ins: checked in attendees (total, running_total, per_month)
+--   - retention: returning attendees per month (last 2 years) and the number
+--     of unique and repeat attendees
+--   - top_events: events with the most attendees (top 5)
+--
+-- Returning attendees are those who had already attended an earlier event of
+-- the group. Time series data is returned as arrays of [timestamp/value] pairs
+-- where timestamps are Unix milliseconds.
+create or replace function get_group_engagement_stats(
+    p_community_id uuid,
+    p_group_id uuid,
+    p_include_subgroups boolean
+)
+returns json as $$
+with params as (
+    select
+        p_community_id as community_id,
+        p_group_id as group_id,
+        p_include_subgroups as include_subgroups,
+        current_date - interval '2 years' as period_start
+),
+target_group as (
+    select g.group_id, g.community_id
+    from "group" g
+    join params p on g.group_id = p.group_id and g.community_id = p.community_id
+    where g.active = true
+        and g.deleted = false
+),
+scoped_groups as (
+    select tg.group_id, tg.community_id
+    from target_group tg
+
+    union all
+
+    select child.group_id, child.community_id
+    from "group" child
+    join target_group tg on child.parent_group_id = tg.group_id
+    join params p on p.community_id = child.community_id
+    where p.include_subgroups = true
+        and child.active = true
+        and child.deleted = false
+),
+events as (
+    select
+        e.event_id,
+        e.name,
+        e.starts_at,
+        g.name as group_name
+    from event e
+    join scoped_groups sg on sg.group_id = e.group_id
+    join "group" g on g.group_id = e.group_id
+    where e.published = true
+        and e.canceled = false
+        and e.deleted = false
+        and e.test_event = false
+),
+attendees as (
+    select
+        ea.event_id,
+        ea.user_id,
+        ea.checked_in,
+        coalesce(ea.checked_in_at, e.starts_at, ea.created_at) as checked_in_at,
+        coalesce(e.starts_at, ea.created_at) as attended_at
+    from event_attendee ea
+    join events e on e.event_id = ea.event_id
+    where ea.status = 'confirmed'
+),
+check_ins as (
+    select
+        a.checked_in_at,
+        timezone('UTC', date_trunc('month', a.checked_in_at at time zone 'UTC')) as checked_in_month
+    from attendees a
+    where a.checked_in = true
+),
+attendances as (
+    select
+        a.user_id,
+        a.attended_at,
+        timezone('UTC', date_trunc('month', a.attended_at at time zone 'UTC')) as attended_month,
+        row_number() over (
+            partition by a.user_id
+            order by a.attended_at asc, a.event_id asc
+        ) as attendance_number
+    from attendees a
+),
+check_ins_running_total_counts as (
+    select
+        ci.checked_in_month as bucket_start,
+        count(*)::int as count
+    from check_ins ci
+    group by ci.checked_in_month
+),
+check_ins_monthly_counts as (
+    select
+        to_char(ci.checked_in_month, 'YYYY-MM') as label,
+        count(*)::int as count
+    from check_ins ci
+    join params p on ci.checked_in_at >= p.period_start
+    group by to_char(ci.checked_in_month, 'YYYY-MM')
+),
+returning_attendees_monthly_counts as (
+    select
+        to_char(at.attended_month, 'YYYY-MM') as label,
+        count(distinct at.user_id)::int as count
+    from attendances at
+    join params p on at.attended_at >= p.period_start
+    where at.attendance_number > 1
+    group by to_char(at.attended_month, 'YYYY-MM')
+),
+attendees_per_user as (
+    select
+        a.user_id,
+        count(*) as attendances
+    from attendees a
+    group by a.user_id
+),
+top_events as (
+    select
+        e.event_id,
+        e.group_name,
+        e.name,
+        e.starts_at,
+        count(*)::int as attendees,
+        count(*) filter (where a.checked_in = true)::int as check_ins
+    from events e
+    join attendees a on a.event_id = e.event_id
+    group by e.event_id, e.group_name, e.name, e.starts_at
+    order by attendees desc, e.starts_at desc nulls last, e.name asc
+    limit 5
+)
+select json_build_object(
+    'check_ins', json_build_object(
+        'total', (select count(*)::int from check_ins),
+        'running_total', stats_running_total_series((
+            select jsonb_agg(to_jsonb(counts))
+            from check_ins_running_total_counts counts
+        )),
+        'per_month', stats_label_count_series((
+            select jsonb_agg(to_jsonb(counts))
+            from check_ins_monthly_counts counts
+        ))
+    ),
+    'retention', json_build_object(
+        'per_month', stats_label_count_series((
+            select jsonb_agg(to_jsonb(counts))
+            from returning_attendees_monthly_counts counts
+        )),
+        'repeat_attendees', (
+            select count(*)::int from attendees_per_user where attendances > 1
+        ),
+        'unique_attendees', (select count(*)::int from attendees_per_user)
+    ),
+    'top_events', (
+        select coalesce(json_agg(json_strip_nulls(json_build_object(
+            'attendees', te.attendees,
+            'check_ins', te.check_ins,
+            'event_id', te.event_id,
+            'group_name', te.group_name,
+            'name', te.name,
+            'starts_at', extract(epoch from te.starts_at)::bigint
+        )) order by te.attendees desc, te.starts_at desc nulls last, te.name asc), '[]'::json)
+        from top_events te
+    )
+);
+$$ language sql stable;
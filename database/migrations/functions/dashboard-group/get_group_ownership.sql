@@ -0,0 +1,46 @@
+-- Returns the owner of a group, its pending ownership transfer and the team
+-- members the ownership can be transferred to.
+create or replace function get_group_ownership(p_group_id uuid)
+returns json as $$
+    select json_strip_nulls(json_build_object(
+        'candidates', (
+            select coalesce(json_agg(json_build_object(
+                'name', u.name,
+                'photo_url', u.photo_url,
+                'user_id', u.user_id,
+                'username', u.username
+            ) order by coalesce(u.name, u.username), u.username), '[]'::json)
+            from group_team gt
+            join "user" u using (user_id)
+            where gt.group_id = g.group_id
+            and gt.accepted = true
+            and gt.user_id is distinct from g.owner_user_id
+        ),
+        'owner', (
+            select json_build_object(
+                'name', u.name,
+                'photo_url', u.photo_url,
+                'user_id', u.user_id,
+                'username', u.username
+            )
+            from "user" u
+            where u.user_id = g.owner_user_id
+        ),
+        'pending_transfer', (
+            select json_build_object(
+                'created_at', extract(epoch from t.created_at)::bigint,
+                'user', json_build_object(
+                    'name', u.name,
+                    'photo_url', u.photo_url,
+                    'user_id', u.user_id,
+                    'username', u.username
+                )
+            )
+            from group_ownership_transfer t
+            join "user" u using (user_id)
+            where t.group_id = g.group_id
+        )
+    ))
+    from "group" g
+    where g.group_id = p_group_id;
+$$ language sql;
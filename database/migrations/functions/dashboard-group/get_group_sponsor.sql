@@ -10,9 +10,13 @@ returns json as $$
         'logo_url', gs.logo_url,
         'name', gs.name,
 
+        'group_sponsor_tier_id', gs.group_sponsor_tier_id,
+        'logo_size', gst.logo_size,
+        'tier_name', gst.name,
         'website_url', gs.website_url
     ))
     from group_sponsor gs
+    left join group_sponsor_tier gst using (group_sponsor_tier_id)
     where gs.group_sponsor_id = p_group_sponsor_id
     and gs.group_id = p_group_id;
 $$ language sql;
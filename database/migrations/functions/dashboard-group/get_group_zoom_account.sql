@@ -0,0 +1,12 @@
+-- Returns the non-secret details of the Zoom account configured for a group.
+create or replace function get_group_zoom_account(p_group_id uuid)
+returns json as $$
+    select json_build_object(
+        'account_id', gzc.account_id,
+        'client_id', gzc.client_id,
+        'host_user', gzc.host_user,
+        'webhook_secret_token_set', gzc.webhook_secret_token is not null
+    )
+    from group_zoom_credentials gzc
+    where gzc.group_id = p_group_id;
+$$ language sql;
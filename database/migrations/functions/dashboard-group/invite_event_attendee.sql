@@ -103,6 +103,9 @@ begin
     -- Serialize movement between waitlist and attendee invitation state
     perform pg_advisory_xact_lock(hashtext(p_event_id::text), hashtext(v_target_user_id::text));
 
+    -- Users banned from the group cannot be invited to its events
+    perform ensure_user_is_not_banned_from_group(p_group_id, v_target_user_id);
+
     -- Reject statuses that should not be invited again
     select ea.status
     into v_existing_status
@@ -122,6 +125,10 @@ begin
         raise exception 'user already has a pending event registration';
     end if;
 
+    if v_existing_status = 'waitlist-confirmation-pending' then
+        raise exception 'user already has a seat pending waitlist confirmation';
+    end if;
+
     if v_existing_status = 'invitation-rejected' then
         raise exception 'user rejected an invitation for this event';
     end if;
@@ -0,0 +1,82 @@
+-- Invites a registered or pre-registered user to the group team by email,
+-- storing the link they can use to accept the invitation.
+create or replace function invite_group_team_member(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_email text,
+    p_role text,
+    p_token_hash text
+)
+returns uuid as $$
+declare
+    v_existing_user_email_verified boolean;
+    v_existing_user_registration_status text;
+    v_normalized_email text := lower(nullif(btrim(p_email), ''));
+    v_target_user_id uuid;
+begin
+    -- Validate the email provided
+    if v_normalized_email is null then
+        raise exception 'email is required';
+    end if;
+
+    -- Resolve the invitee, pre-registering emails without an account
+    select
+        u.email_verified,
+        u.registration_status,
+        u.user_id
+    into
+        v_existing_user_email_verified,
+        v_existing_user_registration_status,
+        v_target_user_id
+    from "user" u
+    where lower(u.email) = v_normalized_email;
+
+    if not found then
+        insert into "user" (
+            auth_hash,
+            email,
+            email_verified,
+            registration_status,
+            username
+        ) values (
+            encode(gen_random_bytes(32), 'hex'),
+            v_normalized_email,
+            false,
+            'pre-registered',
+            'invited-' || substr(encode(digest(convert_to(v_normalized_email, 'utf8'), 'sha256'), 'hex'), 1, 24)
+        )
+        returning user_id into v_target_user_id;
+    elsif v_existing_user_registration_status = 'registered'
+        and v_existing_user_email_verified = false then
+        raise exception 'registered user email is not verified';
+    end if;
+
+    -- Create a pending group team membership
+    begin
+        insert into group_team (group_id, user_id, role, accepted)
+        values (p_group_id, v_target_user_id, p_role, false);
+    exception
+        when unique_violation then
+            -- Reject duplicate group team memberships
+            raise exception 'user is already a group team member';
+    end;
+
+    -- Store the link to accept the invitation, valid for 7 days
+    insert into group_team_invitation_link (expires_at, group_id, token_hash, user_id)
+    values (current_timestamp + interval '7 days', p_group_id, p_token_hash, v_target_user_id);
+
+    -- Track the created membership
+    perform insert_audit_log(
+        'group_team_member_added',
+        p_actor_user_id,
+        'user',
+        v_target_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object('role', p_role)
+    );
+
+    return v_target_user_id;
+end;
+$$ language plpgsql;
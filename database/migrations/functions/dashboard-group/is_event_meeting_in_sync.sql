@@ -12,6 +12,7 @@ declare
     v_before_meeting_provider_id text := p_before_event->>'meeting_provider_id';
     v_before_meeting_recording_requested boolean := coalesce((p_before_event->>'meeting_recording_requested')::boolean, true);
     v_before_meeting_requested boolean := coalesce((p_before_event->>'meeting_requested')::boolean, false);
+    v_before_meeting_webinar boolean := coalesce((p_before_event->>'meeting_webinar')::boolean, false);
     v_before_name text := p_before_event->>'name';
     v_before_speaker_ids uuid[];
     v_before_starts_at timestamptz := to_timestamp((p_before_event->>'starts_at')::double precision);
@@ -23,6 +24,7 @@ declare
     v_after_meeting_provider_id text := p_after_event->>'meeting_provider_id';
     v_after_meeting_recording_requested boolean := coalesce((p_after_event->>'meeting_recording_requested')::boolean, true);
     v_after_meeting_requested boolean := (p_after_event->>'meeting_requested')::boolean;
+    v_after_meeting_webinar boolean := coalesce((p_after_event->>'meeting_webinar')::boolean, false);
     v_after_name text := p_after_event->>'name';
     v_after_speaker_ids uuid[];
     v_after_starts_at timestamptz;
@@ -79,6 +81,7 @@ begin
         and v_before_meeting_hosts is not distinct from v_after_meeting_hosts
         and v_before_meeting_provider_id is not distinct from v_after_meeting_provider_id
         and v_before_meeting_recording_requested = v_after_meeting_recording_requested
+        and v_before_meeting_webinar = v_after_meeting_webinar
         and v_before_name = v_after_name
         and v_before_speaker_ids is not distinct from v_after_speaker_ids
         and v_before_starts_at is not distinct from v_after_starts_at
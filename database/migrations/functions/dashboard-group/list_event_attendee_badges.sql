@@ -0,0 +1,27 @@
+-- Returns the details printed on the badges of a group's event attendees.
+create or replace function list_event_attendee_badges(p_group_id uuid, p_event_id uuid)
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'name', coalesce(u.name, u.username),
+        'ticket_code', ea.ticket_code,
+
+        'company', u.company,
+        'ticket_title', ep.ticket_title
+    )) order by lower(coalesce(u.name, u.username)) asc, u.user_id asc), '[]')
+    from event_attendee ea
+    join event e on e.event_id = ea.event_id
+    join "user" u on u.user_id = ea.user_id
+    left join lateral (
+        select ticket_title
+        from event_purchase
+        where event_id = ea.event_id
+        and user_id = ea.user_id
+        and status = 'completed'
+        order by created_at desc, event_purchase_id desc
+        limit 1
+    ) ep on true
+    where e.group_id = p_group_id
+    and ea.event_id = p_event_id
+    and ea.status = 'confirmed'
+    and e.deleted = false;
+$$ language sql;
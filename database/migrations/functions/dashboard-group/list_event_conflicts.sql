@@ -0,0 +1,58 @@
+-- Returns the events overlapping in time with the provided event payload.
+--
+-- Events from the same group conflict when they overlap in time. Published
+-- events from other groups in the community only conflict when they are held
+-- at the same venue.
+create or replace function list_event_conflicts(
+    p_group_id uuid,
+    p_event_id uuid,
+    p_event jsonb
+) returns json as $$
+    with candidate as (
+        select
+            g.community_id,
+            (p_event->>'starts_at')::timestamp at time zone (p_event->>'timezone') as starts_at,
+            coalesce(
+                (p_event->>'ends_at')::timestamp at time zone (p_event->>'timezone'),
+                (p_event->>'starts_at')::timestamp at time zone (p_event->>'timezone')
+            ) as ends_at,
+            lower(trim(nullif(p_event->>'venue_name', ''))) as venue_name,
+            lower(trim(coalesce(p_event->>'venue_city', ''))) as venue_city
+        from "group" g
+        where g.group_id = p_group_id
+    )
+    select coalesce(
+        json_agg(
+            json_build_object(
+                'event_id', e.event_id,
+                'group_name', g.name,
+                'name', e.name,
+                'same_group', e.group_id = p_group_id,
+                'starts_at', extract(epoch from e.starts_at)::bigint
+            )
+            order by e.starts_at asc, e.name asc
+        ),
+        '[]'::json
+    )
+    from candidate c
+    join "group" g on g.community_id = c.community_id
+    join event e on e.group_id = g.group_id
+    where c.starts_at is not null
+    and e.starts_at is not null
+    and e.event_id is distinct from p_event_id
+    and e.canceled = false
+    and e.deleted = false
+    and g.active = true
+    and g.deleted = false
+    and tstzrange(e.starts_at, coalesce(e.ends_at, e.starts_at), '[]')
+        && tstzrange(c.starts_at, c.ends_at, '[]')
+    and (
+        e.group_id = p_group_id
+        or (
+            e.published = true
+            and c.venue_name is not null
+            and lower(trim(e.venue_name)) = c.venue_name
+            and lower(trim(coalesce(e.venue_city, ''))) = c.venue_city
+        )
+    );
+$$ language sql;
@@ -0,0 +1,34 @@
+-- Returns the photos submitted by attendees to a group's event.
+create or replace function list_event_photos(p_group_id uuid, p_event_id uuid)
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'created_at', floor(extract(epoch from ep.created_at)),
+        'event_photo_id', ep.event_photo_id,
+        'status', ep.status,
+        'url', ep.url,
+        'user', json_strip_nulls(json_build_object(
+            'user_id', u.user_id,
+            'username', u.username,
+
+            'bio', u.bio,
+            'bluesky_url', u.bluesky_url,
+            'company', u.company,
+            'facebook_url', u.facebook_url,
+            'github_url', u.github_url,
+            'linkedin_url', u.linkedin_url,
+            'name', u.name,
+            'photo_url', u.photo_url,
+            'provider', get_public_user_provider(u.provider),
+            'title', u.title,
+            'twitter_url', u.twitter_url,
+            'website_url', u.website_url
+        )),
+
+        'reviewed_at', floor(extract(epoch from ep.reviewed_at))
+    )) order by ep.status = 'pending' desc, ep.created_at desc, ep.event_photo_id asc), '[]')
+    from event_photo ep
+    join event e on e.event_id = ep.event_id
+    join "user" u on u.user_id = ep.user_id
+    where e.group_id = p_group_id
+    and ep.event_id = p_event_id;
+$$ language sql;
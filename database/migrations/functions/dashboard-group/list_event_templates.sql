@@ -0,0 +1,22 @@
+-- Returns all the event templates of the group, sorted by name.
+create or replace function list_event_templates(p_group_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_strip_nulls(json_build_object(
+                'created_at', floor(extract(epoch from et.created_at)),
+                'event_template_id', et.event_template_id,
+                'name', et.name,
+                'sessions_count', jsonb_array_length(coalesce(et.details->'sessions', '[]'::jsonb)),
+
+                'created_by_username', u.username,
+                'kind', et.details->>'kind'
+            ))
+            order by lower(et.name), et.event_template_id
+        ),
+        '[]'::json
+    )
+    from event_template et
+    left join "user" u on u.user_id = et.created_by
+    where et.group_id = p_group_id;
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Returns all the announcements of the group, newest first.
+create or replace function list_group_announcements(p_group_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_build_object(
+                'content', ga.content,
+                'created_at', floor(extract(epoch from ga.created_at)),
+                'group_announcement_id', ga.group_announcement_id,
+                'pinned', ga.pinned,
+                'title', ga.title
+            )
+            order by ga.created_at desc, ga.group_announcement_id
+        ),
+        '[]'::json
+    )
+    from group_announcement ga
+    where ga.group_id = p_group_id;
+$$ language sql;
@@ -26,6 +26,8 @@ returns json as $$
             and al.action = any(array[
                 'cfs_submission_updated',
                 'event_added',
+                'event_approval_requested',
+                'event_approved',
                 'event_attendee_attendance_canceled',
                 'event_attendee_checked_in',
                 'event_attendee_invitation_accepted',
@@ -33,27 +35,73 @@ returns json as $$
                 'event_attendee_invitation_rejected',
                 'event_attendee_invitation_sent',
                 'event_canceled',
+                'event_comment_deleted',
+                'event_comment_pinned',
+                'event_comment_unpinned',
+                'event_comments_locked',
+                'event_comments_unlocked',
                 'event_custom_notification_sent',
                 'event_deleted',
                 'event_invitation_request_accepted',
                 'event_invitation_request_rejected',
+                'event_photo_approved',
+                'event_photo_rejected',
                 'event_published',
                 'event_refund_approved',
                 'event_refund_recovery_completed',
                 'event_refund_rejected',
                 'event_refund_requested',
                 'event_refunded',
+                'event_rejected',
+                'event_resource_added',
+                'event_resource_deleted',
+                'event_template_added',
+                'event_template_deleted',
                 'event_unpublished',
                 'event_updated',
+                'event_waitlist_user_removed',
+                'group_announcement_added',
+                'group_announcement_deleted',
+                'group_announcement_pinned',
+                'group_announcement_unpinned',
                 'group_custom_notification_sent',
+                'group_discord_webhook_updated',
+                'group_discussion_reply_deleted',
+                'group_discussion_topic_deleted',
+                'group_discussion_topic_locked',
+                'group_discussion_topic_pinned',
+                'group_discussion_topic_unlocked',
+                'group_discussion_topic_unpinned',
+                'group_gallery_cover_updated',
+                'group_gallery_photo_hidden',
+                'group_gallery_photo_shown',
+                'group_gallery_photos_updated',
+                'group_join_questions_updated',
+                'group_join_request_accepted',
+                'group_join_request_rejected',
+                'group_member_banned',
+                'group_member_role_updated',
+                'group_member_unbanned',
+                'group_merged',
+                'group_owner_updated',
+                'group_ownership_transfer_accepted',
+                'group_ownership_transfer_canceled',
+                'group_ownership_transfer_rejected',
+                'group_ownership_transfer_requested',
                 'group_payment_recipient_updated',
                 'group_sponsor_added',
                 'group_sponsor_deleted',
+                'group_sponsor_tier_added',
+                'group_sponsor_tier_deleted',
+                'group_sponsor_tier_updated',
                 'group_sponsor_updated',
                 'group_team_member_added',
                 'group_team_member_removed',
                 'group_team_member_role_updated',
-                'group_updated'
+                'group_updated',
+                'group_welcome_message_updated',
+                'group_zoom_credentials_deleted',
+                'group_zoom_credentials_updated'
             ]::text[])
             and (f.action_value is null or al.action = f.action_value)
             and (
@@ -109,11 +157,21 @@ returns json as $$
                         from event_category ec
                         where ec.event_category_id = fl.resource_id
                     )
+                    when 'event_template' then (
+                        select et.name
+                        from event_template et
+                        where et.event_template_id = fl.resource_id
+                    )
                     when 'group' then (
                         select g.name
                         from "group" g
                         where g.group_id = fl.resource_id
                     )
+                    when 'group_announcement' then (
+                        select ga.title
+                        from group_announcement ga
+                        where ga.group_announcement_id = fl.resource_id
+                    )
                     when 'group_category' then (
                         select gc.name
                         from group_category gc
@@ -124,6 +182,11 @@ returns json as $$
                         from group_sponsor gs
                         where gs.group_sponsor_id = fl.resource_id
                     )
+                    when 'group_sponsor_tier' then (
+                        select gst.name
+                        from group_sponsor_tier gst
+                        where gst.group_sponsor_tier_id = fl.resource_id
+                    )
                     when 'region' then (
                         select r.name
                         from region r
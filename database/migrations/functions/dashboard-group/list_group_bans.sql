@@ -0,0 +1,24 @@
+-- Returns the users banned from a group, most recent first.
+create or replace function list_group_bans(p_group_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_strip_nulls(json_build_object(
+                'created_at', extract(epoch from gb.created_at)::bigint,
+                'reason', gb.reason,
+                'user_id', u.user_id,
+                'username', u.username,
+
+                'banned_by_username', bu.username,
+                'name', u.name,
+                'photo_url', u.photo_url
+            ))
+            order by gb.created_at desc, u.username asc
+        ),
+        '[]'::json
+    )
+    from group_ban gb
+    join "user" u on u.user_id = gb.user_id
+    left join "user" bu on bu.user_id = gb.created_by
+    where gb.group_id = p_group_id;
+$$ language sql;
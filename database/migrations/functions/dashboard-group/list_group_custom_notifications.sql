@@ -0,0 +1,83 @@
+-- Returns paginated custom notifications sent by the group, with the delivery
+-- status of their emails.
+create or replace function list_group_custom_notifications(
+    p_group_id uuid,
+    p_filters jsonb
+)
+returns json as $$
+    with
+        -- Parse pagination filters
+        filters as (
+            select
+                (p_filters->>'limit')::int as limit_value,
+                (p_filters->>'offset')::int as offset_value
+        ),
+        -- Select the custom notifications sent by the group or for its events
+        group_custom_notifications as (
+            select
+                cn.custom_notification_id,
+                cn.created_at,
+                cn.created_by,
+                cn.subject,
+                e.name as event_name,
+                g.name as group_name
+            from custom_notification cn
+            left join event e on e.event_id = cn.event_id
+            join "group" g on g.group_id = coalesce(cn.group_id, e.group_id)
+            where g.group_id = p_group_id
+        ),
+        -- Select the paginated custom notifications list
+        custom_notifications as (
+            select
+                gcn.custom_notification_id,
+                extract(epoch from gcn.created_at)::bigint as created_at,
+                gcn.group_name,
+                gcn.subject,
+                count(*) filter (where n.delivery_status = 'bounced')::int as bounced,
+                count(*) filter (where n.delivery_status = 'failed')::int as failed,
+                count(*) filter (
+                    where n.delivery_status in ('pending', 'processing')
+                )::int as queued,
+                count(*) filter (where n.delivery_status = 'processed')::int as sent,
+                count(*) filter (where n.delivery_status = 'delivery-unknown')::int as unknown,
+                u.username as created_by_username,
+                gcn.event_name
+            from group_custom_notifications gcn
+            left join notification n
+                on n.custom_notification_id = gcn.custom_notification_id
+                and n.channel = 'email'
+            left join "user" u on u.user_id = gcn.created_by
+            group by
+                gcn.custom_notification_id,
+                gcn.created_at,
+                gcn.event_name,
+                gcn.group_name,
+                gcn.subject,
+                u.username
+            order by gcn.created_at desc, gcn.custom_notification_id asc
+            offset (select offset_value from filters)
+            limit (select limit_value from filters)
+        ),
+        -- Count total custom notifications before pagination
+        totals as (
+            select count(*)::int as total
+            from group_custom_notifications
+        ),
+        -- Render custom notifications as JSON
+        custom_notifications_json as (
+            select coalesce(
+                json_agg(
+                    row_to_json(custom_notifications)
+                    order by created_at desc, custom_notification_id asc
+                ),
+                '[]'::json
+            ) as notifications
+            from custom_notifications
+        )
+    -- Build final payload
+    select json_build_object(
+        'notifications', custom_notifications_json.notifications,
+        'total', totals.total
+    )
+    from totals, custom_notifications_json;
+$$ language sql;
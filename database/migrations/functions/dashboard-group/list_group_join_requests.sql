@@ -0,0 +1,25 @@
+-- Returns the pending join requests of a group, oldest first.
+create or replace function list_group_join_requests(p_group_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_strip_nulls(json_build_object(
+                'created_at', extract(epoch from gjr.created_at)::bigint,
+                'user_id', u.user_id,
+                'username', u.username,
+
+                'company', u.company,
+                'join_answers', gjr.join_answers,
+                'name', u.name,
+                'photo_url', u.photo_url,
+                'title', u.title
+            ))
+            order by gjr.created_at asc, u.user_id asc
+        ),
+        '[]'::json
+    )
+    from group_join_request gjr
+    join "user" u using (user_id)
+    where gjr.group_id = p_group_id
+    and gjr.status = 'pending';
+$$ language sql;
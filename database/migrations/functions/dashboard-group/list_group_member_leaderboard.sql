@@ -0,0 +1,54 @@
+-- Returns the group members ranked by the past group events they were checked
+-- in to and registered for during the last 12 months.
+create or replace function list_group_member_leaderboard(p_group_id uuid)
+returns json as $$
+    with
+        -- Select the registrations of the members to past group events
+        attendances as (
+            select
+                ea.user_id,
+                ea.checked_in,
+                e.starts_at
+            from event_attendee ea
+            join event e on e.event_id = ea.event_id
+            join group_member gm on gm.group_id = e.group_id and gm.user_id = ea.user_id
+            where e.group_id = p_group_id
+            and ea.status = 'confirmed'
+            and e.published = true
+            and e.canceled = false
+            and e.deleted = false
+            and e.starts_at >= current_timestamp - interval '12 months'
+            and e.starts_at <= current_timestamp
+        ),
+        -- Rank the members by check-ins and registrations
+        leaderboard as (
+            select
+                count(*) filter (where a.checked_in = true)::int as check_ins,
+                row_number() over (
+                    order by
+                        count(*) filter (where a.checked_in = true) desc,
+                        count(*) desc,
+                        max(a.starts_at) desc,
+                        lower(coalesce(u.name, u.username)) asc,
+                        u.user_id asc
+                )::int as rank,
+                count(*)::int as rsvps,
+                u.user_id,
+                u.username,
+
+                extract(epoch from max(a.starts_at) filter (where a.checked_in = true))::bigint
+                    as last_attended_at,
+                u.name,
+                u.photo_url
+            from attendances a
+            join "user" u on u.user_id = a.user_id
+            group by u.user_id, u.username, u.name, u.photo_url
+        )
+    -- Render the top ten members as JSON
+    select coalesce(
+        json_agg(json_strip_nulls(row_to_json(leaderboard)) order by leaderboard.rank),
+        '[]'::json
+    )
+    from leaderboard
+    where leaderboard.rank <= 10;
+$$ language sql;
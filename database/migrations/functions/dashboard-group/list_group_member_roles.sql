@@ -0,0 +1,11 @@
+-- Returns the distinct roles assigned to the members of a group.
+create or replace function list_group_member_roles(p_group_id uuid)
+returns json as $$
+    select coalesce(json_agg(roles.member_role order by lower(roles.member_role) asc), '[]'::json)
+    from (
+        select distinct gm.member_role
+        from group_member gm
+        where gm.group_id = p_group_id
+        and gm.member_role is not null
+    ) roles;
+$$ language sql;
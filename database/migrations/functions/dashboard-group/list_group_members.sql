@@ -1,4 +1,5 @@
--- Returns paginated group members with join date and basic profile info.
+-- Returns paginated group members with join date, role, join answers and basic
+-- profile info.
 create or replace function list_group_members(p_group_id uuid, p_filters jsonb)
 returns json as $$
     with
@@ -12,14 +13,21 @@ returns json as $$
         members as (
             select
                 extract(epoch from gm.created_at)::bigint as created_at,
+                u.email_undeliverable_at is not null as email_undeliverable,
+                ar.events_attended,
+                ar.events_missed,
+                gm.user_id,
                 u.username,
 
                 u.company,
+                gm.join_answers,
+                gm.member_role,
                 u.name,
                 u.photo_url,
                 u.title
             from group_member gm
             join "user" u using (user_id)
+            cross join lateral get_user_group_attendance_record(gm.group_id, gm.user_id) ar
             where gm.group_id = p_group_id
             order by (u.name is not null) desc, lower(u.name) asc, lower(u.username) asc, u.user_id asc
             offset (select offset_value from filters)
@@ -0,0 +1,26 @@
+-- Returns all group members with the information included in the CSV export.
+create or replace function list_group_members_export(p_group_id uuid)
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'created_at', extract(epoch from gm.created_at)::bigint,
+        'email', u.email,
+        'username', u.username,
+
+        'join_answers', gm.join_answers,
+        'last_attended_at', (
+            select extract(epoch from max(e.starts_at))::bigint
+            from event_attendee ea
+            join event e using (event_id)
+            where e.group_id = gm.group_id
+            and ea.user_id = gm.user_id
+            and ea.checked_in = true
+            and e.canceled = false
+            and e.deleted = false
+        ),
+        'member_role', gm.member_role,
+        'name', u.name
+    )) order by (u.name is not null) desc, lower(u.name) asc, lower(u.username) asc, u.user_id asc), '[]')
+    from group_member gm
+    join "user" u using (user_id)
+    where gm.group_id = p_group_id;
+$$ language sql;
@@ -0,0 +1,18 @@
+-- Returns the sponsor tiers of the group in display order.
+create or replace function list_group_sponsor_tiers(p_group_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_build_object(
+                'group_sponsor_tier_id', gst.group_sponsor_tier_id,
+                'logo_size', gst.logo_size,
+                'name', gst.name,
+                'order', gst."order"
+            )
+            order by gst."order" asc, gst.name asc
+        ),
+        '[]'::json
+    )
+    from group_sponsor_tier gst
+    where gst.group_id = p_group_id;
+$$ language sql;
@@ -16,8 +16,12 @@ returns json as $$
                 gs.logo_url,
                 gs.name,
 
+                gs.group_sponsor_tier_id,
+                gst.logo_size,
+                gst.name as tier_name,
                 gs.website_url
             from group_sponsor gs
+            left join group_sponsor_tier gst using (group_sponsor_tier_id)
             where gs.group_id = p_group_id
             order by gs.name asc, gs.group_sponsor_id asc
             offset case when p_full_list then 0 else (select offset_value from filters) end
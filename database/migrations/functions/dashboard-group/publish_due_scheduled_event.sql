@@ -0,0 +1,59 @@
+-- publish_due_scheduled_event publishes the next draft event whose scheduled
+-- publication date is due, returning its identifiers.
+create or replace function publish_due_scheduled_event(p_configured_provider text)
+returns jsonb as $$
+declare
+    v_event record;
+begin
+    for v_event in
+        select
+            e.event_id,
+            e.group_id,
+            e.publish_scheduled_by,
+            g.community_id
+        from event e
+        join "group" g on g.group_id = e.group_id
+        where e.published = false
+        and e.publish_scheduled_at is not null
+        and e.publish_scheduled_at <= current_timestamp
+        and e.deleted = false
+        and e.canceled = false
+        order by e.publish_scheduled_at asc
+        for update of e skip locked
+    loop
+        begin
+            -- Publish the event on behalf of the organizer who scheduled it
+            perform publish_event(
+                v_event.publish_scheduled_by,
+                v_event.group_id,
+                v_event.event_id,
+                p_configured_provider
+            );
+
+            -- Skip events sent for approval instead of being published
+            if not exists (
+                select 1
+                from event
+                where event_id = v_event.event_id
+                and published = true
+            ) then
+                continue;
+            end if;
+
+            return jsonb_build_object(
+                'community_id', v_event.community_id,
+                'event_id', v_event.event_id,
+                'group_id', v_event.group_id
+            );
+        exception when others then
+            -- Drop schedules that cannot be honored so they are not retried forever
+            update event set
+                publish_scheduled_at = null,
+                publish_scheduled_by = null
+            where event_id = v_event.event_id;
+        end;
+    end loop;
+
+    return null;
+end;
+$$ language plpgsql;
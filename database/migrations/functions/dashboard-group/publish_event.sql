@@ -7,7 +7,9 @@ create or replace function publish_event(
 )
 returns void as $$
 declare
-    v_has_ticket_types boolean;
+    v_approval_required boolean;
+    v_approval_status text;
+    v_community_id uuid;
     v_payment_currency_code text;
     v_payment_recipient jsonb;
     v_published boolean;
@@ -15,23 +17,24 @@ declare
 begin
     -- Check if the event is active, load ticketing state, and lock it for update
     select
+        c.event_approval_required,
+        e.approval_status,
+        g.community_id,
         e.published,
         e.payment_currency_code,
         g.payment_recipient,
-        e.starts_at,
-        exists (
-            select 1
-            from event_ticket_type ett
-            where ett.event_id = e.event_id
-        )
+        e.starts_at
     into
+        v_approval_required,
+        v_approval_status,
+        v_community_id,
         v_published,
         v_payment_currency_code,
         v_payment_recipient,
-        v_starts_at,
-        v_has_ticket_types
+        v_starts_at
     from event e
     join "group" g on g.group_id = e.group_id
+    join community c on c.community_id = g.community_id
     where event_id = p_event_id
     and e.group_id = p_group_id
     and e.deleted = false
@@ -47,8 +50,9 @@ begin
         return;
     end if;
 
-    -- Require checkout-critical ticketing configuration before publishing
-    if v_has_ticket_types then
+    -- Require checkout-critical payments configuration before publishing paid
+    -- ticketed events (events offering only free ticket types have no currency)
+    if v_payment_currency_code is not null then
         if p_configured_provider is null then
             raise exception 'payments are not configured on this server';
         end if;
@@ -61,10 +65,6 @@ begin
             raise exception 'ticketed events require a payment recipient for the server payments provider';
         end if;
 
-        if v_payment_currency_code is null then
-            raise exception 'ticketed events require payment_currency_code';
-        end if;
-
         perform validate_payment_currency_code(v_payment_currency_code);
     end if;
 
@@ -73,6 +73,36 @@ begin
         raise exception 'event must have a start date to be published';
     end if;
 
+    -- Send the event for approval when the community requires it
+    if v_approval_required and v_approval_status is distinct from 'approved' then
+        -- Return early when the event is already waiting for approval
+        if v_approval_status = 'pending' then
+            return;
+        end if;
+
+        update event set
+            approval_rejection_reason = null,
+            approval_requested_at = current_timestamp,
+            approval_requested_by = p_actor_user_id,
+            approval_status = 'pending',
+            publish_scheduled_at = null,
+            publish_scheduled_by = null
+        where event_id = p_event_id;
+
+        -- Track the approval request
+        perform insert_audit_log(
+            'event_approval_requested',
+            p_actor_user_id,
+            'event',
+            p_event_id,
+            v_community_id,
+            p_group_id,
+            p_event_id
+        );
+
+        return;
+    end if;
+
     -- Update event to mark as published
     -- Also set meeting_in_sync to false to trigger meeting setup when applicable
     update event set
@@ -83,15 +113,10 @@ begin
         published = true,
         published_at = now(),
         published_by = p_actor_user_id,
-        -- Mark reminder as evaluated when publish happens inside the 24-hour window
-        event_reminder_evaluated_for_starts_at = case
-            when event_reminder_enabled = true
-                 and event_reminder_sent_at is null
-                 and starts_at > current_timestamp
-                 and starts_at <= current_timestamp + interval '24 hours'
-            then starts_at
-            else event_reminder_evaluated_for_starts_at
-        end
+        publish_scheduled_at = null,
+        publish_scheduled_by = null,
+        -- Skip reminders that were due before the event was published
+        event_reminder_not_before = current_timestamp
     where event_id = p_event_id
     and group_id = p_group_id
     and deleted = false
@@ -108,7 +133,7 @@ begin
         p_actor_user_id,
         'event',
         p_event_id,
-        (select community_id from "group" where group_id = p_group_id),
+        v_community_id,
         p_group_id,
         p_event_id
     );
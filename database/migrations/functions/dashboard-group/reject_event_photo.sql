@@ -0,0 +1,57 @@
+-- Rejects a photo submitted by an attendee to an event's gallery.
+create or replace function reject_event_photo(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_event_id uuid,
+    p_event_photo_id uuid
+)
+returns void as $$
+declare
+    v_community_id uuid;
+    v_user_id uuid;
+begin
+    -- Verify the event belongs to the selected group
+    select g.community_id
+    into v_community_id
+    from event e
+    join "group" g on g.group_id = e.group_id
+    where e.event_id = p_event_id
+    and e.group_id = p_group_id
+    and e.deleted = false;
+
+    if not found then
+        raise exception 'event not found';
+    end if;
+
+    -- Mark the photo as rejected
+    update event_photo
+    set
+        reviewed_at = current_timestamp,
+        reviewed_by = p_actor_user_id,
+        status = 'rejected'
+    where event_photo_id = p_event_photo_id
+    and event_id = p_event_id
+    and status <> 'rejected'
+    returning user_id into v_user_id;
+
+    if not found then
+        raise exception 'photo not found or already rejected';
+    end if;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        'event_photo_rejected',
+        p_actor_user_id,
+        'user',
+        v_user_id,
+        v_community_id,
+        p_group_id,
+        p_event_id,
+        jsonb_build_object(
+            'event_id', p_event_id,
+            'event_photo_id', p_event_photo_id,
+            'user_id', v_user_id
+        )
+    );
+end;
+$$ language plpgsql;
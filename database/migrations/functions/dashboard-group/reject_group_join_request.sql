@@ -0,0 +1,46 @@
+-- Rejects a pending group join request.
+create or replace function reject_group_join_request(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_user_id uuid
+)
+returns void as $$
+declare
+    v_community_id uuid;
+begin
+    -- Verify the group exists
+    select community_id
+    into v_community_id
+    from "group"
+    where group_id = p_group_id
+    and deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Mark only pending requests as rejected
+    update group_join_request
+    set
+        reviewed_at = current_timestamp,
+        reviewed_by = p_actor_user_id,
+        status = 'rejected'
+    where group_id = p_group_id
+    and user_id = p_user_id
+    and status = 'pending';
+
+    if not found then
+        raise exception 'pending join request not found';
+    end if;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        'group_join_request_rejected',
+        p_actor_user_id,
+        'user',
+        p_user_id,
+        v_community_id,
+        p_group_id
+    );
+end;
+$$ language plpgsql;
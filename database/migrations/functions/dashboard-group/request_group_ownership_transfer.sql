@@ -0,0 +1,55 @@
+-- Requests the transfer of the group ownership to another team member, who
+-- must accept it before becoming the new owner.
+create or replace function request_group_ownership_transfer(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_user_id uuid
+)
+returns void as $$
+begin
+    -- Lock the group to serialize ownership changes
+    perform 1
+    from "group"
+    where group_id = p_group_id
+    for update;
+
+    -- Only the current owner can transfer the ownership
+    if not exists (
+        select 1
+        from "group"
+        where group_id = p_group_id
+        and owner_user_id = p_actor_user_id
+    ) then
+        raise exception 'only the group owner can transfer its ownership';
+    end if;
+
+    -- The new owner must be another accepted team member
+    if p_user_id = p_actor_user_id or not exists (
+        select 1
+        from group_team
+        where group_id = p_group_id
+        and user_id = p_user_id
+        and accepted = true
+    ) then
+        raise exception 'the new owner must be another accepted group team member';
+    end if;
+
+    -- Register the transfer, replacing any other pending one
+    insert into group_ownership_transfer (group_id, created_by, user_id)
+    values (p_group_id, p_actor_user_id, p_user_id)
+    on conflict (group_id) do update set
+        created_at = current_timestamp,
+        created_by = excluded.created_by,
+        user_id = excluded.user_id;
+
+    -- Track the requested transfer
+    perform insert_audit_log(
+        'group_ownership_transfer_requested',
+        p_actor_user_id,
+        'user',
+        p_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
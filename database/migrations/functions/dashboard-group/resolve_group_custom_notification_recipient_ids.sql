@@ -0,0 +1,72 @@
+-- Resolves group custom email recipient user ids for the audience provided.
+create or replace function resolve_group_custom_notification_recipient_ids(
+    p_group_id uuid,
+    p_audience text,
+    p_event_id uuid,
+    p_inactive_months int,
+    p_member_role text
+)
+returns uuid[] as $$
+    select coalesce(array_agg(recipients.user_id order by recipients.user_id asc), array[]::uuid[])
+    from (
+        -- Group members
+        select gm.user_id
+        from group_member gm
+        where p_audience = 'all-members'
+        and gm.group_id = p_group_id
+
+        union
+
+        -- Accepted group team members
+        select gt.user_id
+        from group_team gt
+        where p_audience = 'all-members'
+        and gt.group_id = p_group_id
+        and gt.accepted = true
+
+        union
+
+        -- Attendees of one of the group's events, optionally only checked in
+        select ea.user_id
+        from event_attendee ea
+        join event e using (event_id)
+        where p_audience in ('checked-in-attendees', 'event-attendees')
+        and ea.event_id = p_event_id
+        and e.group_id = p_group_id
+        and e.deleted = false
+        and ea.status in ('confirmed', 'registration-questions-pending')
+        and (p_audience = 'event-attendees' or ea.checked_in = true)
+
+        union
+
+        -- Members holding the role provided
+        select gm.user_id
+        from group_member gm
+        where p_audience = 'members-with-role'
+        and gm.group_id = p_group_id
+        and gm.member_role = p_member_role
+
+        union
+
+        -- Members that have not attended any group event in the last months
+        select gm.user_id
+        from group_member gm
+        where p_audience = 'inactive-members'
+        and p_inactive_months > 0
+        and gm.group_id = p_group_id
+        and gm.created_at < current_timestamp - make_interval(months => p_inactive_months)
+        and not exists (
+            select 1
+            from event_attendee ea
+            join event e using (event_id)
+            where e.group_id = p_group_id
+            and ea.user_id = gm.user_id
+            and ea.status in ('confirmed', 'registration-questions-pending')
+            and e.canceled = false
+            and e.deleted = false
+            and e.starts_at >= current_timestamp - make_interval(months => p_inactive_months)
+        )
+    ) recipients
+    join "user" u using (user_id)
+    where u.email_verified = true;
+$$ language sql;
@@ -174,7 +174,8 @@ returns json as $$
                 'confirmed',
                 'invitation-pending',
                 'invitation-rejected',
-                'registration-questions-pending'
+                'registration-questions-pending',
+                'waitlist-confirmation-pending'
             )
         ),
         -- Apply table filters while retaining internal search data
@@ -63,7 +63,8 @@ begin
                     meeting_requested = (v_session->>'meeting_requested')::boolean,
                     name = v_session->>'name',
                     session_kind_id = v_session->>'kind',
-                    starts_at = v_session_starts_at
+                    starts_at = v_session_starts_at,
+                    track = nullif(v_session->>'track', '')
                 where session_id = v_session_id
                 and event_id = p_event_id;
 
@@ -89,7 +90,8 @@ begin
                     meeting_provider_id,
                     meeting_recording_published,
                     meeting_recording_url,
-                    meeting_requested
+                    meeting_requested,
+                    track
                 ) values (
                     p_event_id,
                     v_session->>'name',
@@ -109,7 +111,8 @@ begin
                     nullif(v_session->>'meeting_provider_id', ''),
                     coalesce((v_session->>'meeting_recording_published')::boolean, false),
                     nullif(v_session->>'meeting_recording_url', ''),
-                    (v_session->>'meeting_requested')::boolean
+                    (v_session->>'meeting_requested')::boolean,
+                    nullif(v_session->>'track', '')
                 )
                 returning session_id into v_session_id;
             end if;
@@ -0,0 +1,42 @@
+-- sync_event_topics replaces the topics assigned to an event.
+create or replace function sync_event_topics(
+    p_event_id uuid,
+    p_topic_ids jsonb
+)
+returns void as $$
+declare
+    v_topic_ids uuid[];
+begin
+    -- Collect the requested topics, deduplicating repeated IDs
+    select coalesce(array_agg(distinct e::uuid), '{}')
+    into v_topic_ids
+    from jsonb_array_elements_text(coalesce(nullif(p_topic_ids, 'null'::jsonb), '[]'::jsonb)) e;
+
+    -- Ensure all the topics belong to the event's community
+    if exists (
+        select 1
+        from unnest(v_topic_ids) as input_topic(topic_id)
+        where not exists (
+            select 1
+            from topic t
+            join "group" g on g.community_id = t.community_id
+            join event ev on ev.group_id = g.group_id
+            where ev.event_id = p_event_id
+            and t.topic_id = input_topic.topic_id
+        )
+    ) then
+        raise exception 'topic not found in community';
+    end if;
+
+    -- Remove topics omitted from the payload
+    delete from event_topic
+    where event_id = p_event_id
+    and not (topic_id = any(v_topic_ids));
+
+    -- Assign the new topics
+    insert into event_topic (event_id, topic_id)
+    select p_event_id, input_topic.topic_id
+    from unnest(v_topic_ids) as input_topic(topic_id)
+    on conflict do nothing;
+end;
+$$ language plpgsql;
@@ -0,0 +1,39 @@
+-- Lifts the ban of a user from a group.
+create or replace function unban_group_member(
+    p_actor_user_id uuid,
+    p_community_id uuid,
+    p_group_id uuid,
+    p_user_id uuid
+) returns void as $$
+begin
+    -- Verify the group exists in the community
+    if not exists (
+        select 1
+        from "group"
+        where group_id = p_group_id
+        and community_id = p_community_id
+        and deleted = false
+    ) then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Remove the ban
+    delete from group_ban
+    where group_id = p_group_id
+    and user_id = p_user_id;
+
+    if not found then
+        raise exception 'user is not banned from this group';
+    end if;
+
+    -- Track the ban removal
+    perform insert_audit_log(
+        'group_member_unbanned',
+        p_actor_user_id,
+        'user',
+        p_user_id,
+        p_community_id,
+        p_group_id
+    );
+end;
+$$ language plpgsql;
@@ -29,6 +29,7 @@ declare
     v_new_starts_at timestamptz;
     v_payment_currency_code text;
     v_promoted_user_ids uuid[] := array[]::uuid[];
+    v_publish_scheduled_at timestamptz;
     v_registration_ends_at timestamptz;
     v_registration_questions jsonb;
     v_registration_starts_at timestamptz;
@@ -113,7 +114,8 @@ begin
             'confirmed',
             'invitation-pending',
             'invitation-rejected',
-            'registration-questions-pending'
+            'registration-questions-pending',
+            'waitlist-confirmation-pending'
         )
     );
     v_has_pending_invitation_requests := exists(
@@ -139,6 +141,14 @@ begin
         raise exception 'approval-required events cannot have existing waitlist entries';
     end if;
 
+    -- Webinars and meetings are different provider resources
+    if coalesce((p_event->>'meeting_webinar')::boolean, false)
+       <> coalesce((v_event_before->>'meeting_webinar')::boolean, false)
+       and exists (select 1 from meeting where event_id = p_event_id)
+    then
+        raise exception 'webinar mode cannot be changed after the meeting has been created';
+    end if;
+
     -- Enforce ticketing transition rules
     if v_ticket_types is not null and not v_was_ticketed and v_has_existing_attendees then
         raise exception 'ticketed events require an empty attendee list';
@@ -171,6 +181,12 @@ begin
         v_new_starts_at := (p_event->>'starts_at')::timestamp at time zone v_timezone;
     end if;
 
+    -- Published events cannot be scheduled for publication again
+    if p_event->>'publish_scheduled_at' is not null
+       and (v_event_before->>'published')::boolean is not true then
+        v_publish_scheduled_at := (p_event->>'publish_scheduled_at')::timestamp at time zone v_timezone;
+    end if;
+
     if p_event->>'registration_ends_at' is not null then
         v_registration_ends_at := (p_event->>'registration_ends_at')::timestamp at time zone v_timezone;
     end if;
@@ -231,7 +247,9 @@ begin
         event_category_id = (p_event->>'category_id')::uuid,
         event_kind_id = p_event->>'kind_id',
 
+        attendance_certificate_emails_enabled = coalesce((p_event->>'attendance_certificate_emails_enabled')::boolean, false),
         attendee_approval_required = v_event_attendee_approval_required,
+        attendee_photos_enabled = coalesce((p_event->>'attendee_photos_enabled')::boolean, false),
         banner_mobile_url = nullif(p_event->>'banner_mobile_url', ''),
         banner_url = nullif(p_event->>'banner_url', ''),
         capacity = v_effective_capacity,
@@ -242,21 +260,11 @@ begin
         description_short = nullif(p_event->>'description_short', ''),
         ends_at = v_new_ends_at,
         event_reminder_enabled = v_event_reminder_enabled,
-        -- Mark reminder as evaluated when update moves start time inside the 24-hour window
-        event_reminder_evaluated_for_starts_at = case
-            when v_event_reminder_enabled = true
-                 and event_reminder_sent_at is null
-                 and starts_at is distinct from v_new_starts_at
-                 and (
-                     starts_at is null
-                     or starts_at <= current_timestamp
-                     or starts_at > current_timestamp + interval '24 hours'
-                 )
-                 and v_new_starts_at is not null
-                 and v_new_starts_at > current_timestamp
-                 and v_new_starts_at <= current_timestamp + interval '24 hours'
-            then v_new_starts_at
-            else event_reminder_evaluated_for_starts_at
+        feedback_url = nullif(p_event->>'feedback_url', ''),
+        -- Skip reminders that were due before the start time was changed
+        event_reminder_not_before = case
+            when starts_at is distinct from v_new_starts_at then current_timestamp
+            else event_reminder_not_before
         end,
         location = v_event_location,
         logo_url = nullif(p_event->>'logo_url', ''),
@@ -279,14 +287,23 @@ begin
         meeting_recording_requested = coalesce((p_event->>'meeting_recording_requested')::boolean, true),
         meeting_recording_url = nullif(p_event->>'meeting_recording_url', ''),
         meeting_requested = (p_event->>'meeting_requested')::boolean,
+        meeting_webinar = coalesce((p_event->>'meeting_webinar')::boolean, false),
         meetup_url = nullif(p_event->>'meetup_url', ''),
+        no_show_waitlist_threshold = (p_event->>'no_show_waitlist_threshold')::int,
         payment_currency_code = v_payment_currency_code,
         photos_urls = v_event_photos_urls,
+        publish_scheduled_at = v_publish_scheduled_at,
+        publish_scheduled_by = case
+            when v_publish_scheduled_at is null then null
+            when publish_scheduled_at is not distinct from v_publish_scheduled_at then publish_scheduled_by
+            else p_actor_user_id
+        end,
         registration_ends_at = v_registration_ends_at,
         registration_required = (p_event->>'registration_required')::boolean,
         registration_questions = v_registration_questions,
         registration_starts_at = v_registration_starts_at,
         starts_at = v_new_starts_at,
+        streaming_url = nullif(p_event->>'streaming_url', ''),
         tags = v_event_tags,
         venue_address = nullif(p_event->>'venue_address', ''),
         venue_city = nullif(p_event->>'venue_city', ''),
@@ -295,6 +312,7 @@ begin
         venue_name = nullif(p_event->>'venue_name', ''),
         venue_state = nullif(p_event->>'venue_state', ''),
         venue_zip_code = nullif(p_event->>'venue_zip_code', ''),
+        waitlist_confirmation_hours = (p_event->>'waitlist_confirmation_hours')::int,
         waitlist_enabled = case
             when v_ticket_types is not null then false
             else v_event_waitlist_enabled
@@ -317,6 +335,9 @@ begin
     -- Synchronize event CFS labels
     perform sync_event_cfs_labels(p_event_id, p_event->'cfs_labels');
 
+    -- Synchronize event topics
+    perform sync_event_topics(p_event_id, p_event->'topic_ids');
+
     -- Synchronize event hosts, speakers, and sponsors
     perform sync_event_hosts_speakers_sponsors(p_event_id, p_event);
 
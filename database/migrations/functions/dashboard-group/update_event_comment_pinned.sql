@@ -0,0 +1,55 @@
+-- Updates the pinned flag of a comment in an event's discussion.
+create or replace function update_event_comment_pinned(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_event_id uuid,
+    p_event_comment_id uuid,
+    p_pinned bool
+)
+returns void as $$
+declare
+    v_community_id uuid;
+    v_user_id uuid;
+begin
+    -- Verify the event belongs to the selected group
+    select g.community_id
+    into v_community_id
+    from event e
+    join "group" g on g.group_id = e.group_id
+    where e.event_id = p_event_id
+    and e.group_id = p_group_id
+    and e.deleted = false;
+
+    if not found then
+        raise exception 'event not found';
+    end if;
+
+    -- Update the comment pinned flag
+    update event_comment set
+        pinned = p_pinned
+    where event_comment_id = p_event_comment_id
+    and event_id = p_event_id
+    and pinned <> p_pinned
+    returning user_id into v_user_id;
+
+    if not found then
+        raise exception 'comment not found or already updated';
+    end if;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        case when p_pinned then 'event_comment_pinned' else 'event_comment_unpinned' end,
+        p_actor_user_id,
+        'user',
+        v_user_id,
+        v_community_id,
+        p_group_id,
+        p_event_id,
+        jsonb_build_object(
+            'event_comment_id', p_event_comment_id,
+            'event_id', p_event_id,
+            'user_id', v_user_id
+        )
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,32 @@
+-- Updates the locked flag of an event's discussion.
+create or replace function update_event_comments_locked(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_event_id uuid,
+    p_locked bool
+)
+returns void as $$
+begin
+    -- Update the event discussion locked flag
+    update event set
+        comments_locked = p_locked
+    where event_id = p_event_id
+    and group_id = p_group_id
+    and deleted = false;
+
+    if not found then
+        raise exception 'event not found';
+    end if;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        case when p_locked then 'event_comments_locked' else 'event_comments_unlocked' end,
+        p_actor_user_id,
+        'event',
+        p_event_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        p_event_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,31 @@
+-- Updates the pinned flag of a group announcement.
+create or replace function update_group_announcement_pinned(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_group_announcement_id uuid,
+    p_pinned bool
+)
+returns void as $$
+begin
+    -- Update the announcement pinned flag
+    update group_announcement set
+        pinned = p_pinned
+    where group_announcement_id = p_group_announcement_id
+    and group_id = p_group_id
+    and pinned <> p_pinned;
+
+    if not found then
+        raise exception 'announcement not found or already updated';
+    end if;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        case when p_pinned then 'group_announcement_pinned' else 'group_announcement_unpinned' end,
+        p_actor_user_id,
+        'group_announcement',
+        p_group_announcement_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,29 @@
+-- Sets or clears the Discord webhook used to post group notifications.
+create or replace function update_group_discord_webhook(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_webhook_url text
+)
+returns void as $$
+begin
+    -- Store the webhook, clearing it when none is provided
+    update "group" set
+        discord_webhook_url = nullif(btrim(p_webhook_url), '')
+    where group_id = p_group_id
+    and deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Track the webhook update
+    perform insert_audit_log(
+        'group_discord_webhook_updated',
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,39 @@
+-- Updates the pinned flag of a topic in a group's discussion board.
+create or replace function update_group_discussion_topic_pinned(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_group_discussion_topic_id uuid,
+    p_pinned bool
+)
+returns void as $$
+declare
+    v_user_id uuid;
+begin
+    -- Update the topic pinned flag
+    update group_discussion_topic set
+        pinned = p_pinned
+    where group_discussion_topic_id = p_group_discussion_topic_id
+    and group_id = p_group_id
+    and pinned <> p_pinned
+    returning user_id into v_user_id;
+
+    if not found then
+        raise exception 'topic not found or already updated';
+    end if;
+
+    -- Track the organizer decision
+    perform insert_audit_log(
+        case when p_pinned then 'group_discussion_topic_pinned' else 'group_discussion_topic_unpinned' end,
+        p_actor_user_id,
+        'user',
+        v_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object(
+            'group_discussion_topic_id', p_group_discussion_topic_id,
+            'user_id', v_user_id
+        )
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,40 @@
+-- Sets or clears the photo highlighted as the cover of the group gallery.
+create or replace function update_group_gallery_cover(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_url text
+)
+returns void as $$
+begin
+    -- Check the cover is one of the visible photos of the gallery
+    if p_url is not null and not exists (
+        select 1
+        from json_array_elements(list_group_gallery_photos(p_group_id, false)) photo
+        where photo->>'url' = p_url
+    ) then
+        raise exception 'photo not found in the group gallery';
+    end if;
+
+    -- Store the cover, clearing it when none is provided
+    update "group" set
+        gallery_cover_url = p_url
+    where group_id = p_group_id
+    and deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Track the cover update
+    perform insert_audit_log(
+        'group_gallery_cover_updated',
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_strip_nulls(jsonb_build_object('url', p_url))
+    );
+end;
+$$ language plpgsql;
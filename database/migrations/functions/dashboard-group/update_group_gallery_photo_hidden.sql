@@ -0,0 +1,48 @@
+-- Hides a photo from the group gallery or shows it again.
+create or replace function update_group_gallery_photo_hidden(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_url text,
+    p_hidden boolean
+)
+returns void as $$
+begin
+    -- Check the photo belongs to the gallery
+    if not exists (
+        select 1
+        from json_array_elements(list_group_gallery_photos(p_group_id, true)) photo
+        where photo->>'url' = p_url
+    ) then
+        raise exception 'photo not found in the group gallery';
+    end if;
+
+    if p_hidden then
+        -- Hide the photo, clearing the cover when it was the hidden photo
+        insert into group_gallery_hidden_photo (group_id, url)
+        values (p_group_id, p_url)
+        on conflict do nothing;
+
+        update "group" set
+            gallery_cover_url = null
+        where group_id = p_group_id
+        and gallery_cover_url = p_url;
+    else
+        -- Show the photo again
+        delete from group_gallery_hidden_photo
+        where group_id = p_group_id
+        and url = p_url;
+    end if;
+
+    -- Track the photo visibility update
+    perform insert_audit_log(
+        case when p_hidden then 'group_gallery_photo_hidden' else 'group_gallery_photo_shown' end,
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object('url', p_url)
+    );
+end;
+$$ language plpgsql;
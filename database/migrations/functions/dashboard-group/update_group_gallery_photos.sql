@@ -0,0 +1,29 @@
+-- Updates the photos uploaded by the organizers to the group gallery.
+create or replace function update_group_gallery_photos(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_gallery jsonb
+)
+returns void as $$
+begin
+    -- Store the uploaded photos
+    update "group" set
+        photos_urls = jsonb_text_array(p_gallery->'photos_urls')
+    where group_id = p_group_id
+    and deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Track the photos update
+    perform insert_audit_log(
+        'group_gallery_photos_updated',
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
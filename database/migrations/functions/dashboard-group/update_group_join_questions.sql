@@ -0,0 +1,29 @@
+-- Replaces the questions asked to users joining a group.
+create or replace function update_group_join_questions(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_join_questions jsonb
+)
+returns void as $$
+begin
+    -- Store the questions, clearing them when none are provided
+    update "group" set
+        join_questions = coalesce(p_join_questions, '[]'::jsonb)
+    where group_id = p_group_id
+    and deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Track the join questions update
+    perform insert_audit_log(
+        'group_join_questions_updated',
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
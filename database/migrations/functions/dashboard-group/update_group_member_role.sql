@@ -0,0 +1,34 @@
+-- Updates the role displayed for a group member.
+create or replace function update_group_member_role(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_user_id uuid,
+    p_member_role text
+) returns void as $$
+declare
+    v_member_role text := nullif(btrim(p_member_role), '');
+begin
+    -- Update the role of an existing group member
+    update group_member
+    set member_role = v_member_role
+    where group_id = p_group_id
+      and user_id = p_user_id;
+
+    -- Ensure membership exists
+    if not found then
+        raise exception 'user is not a member of this group';
+    end if;
+
+    -- Track the role update
+    perform insert_audit_log(
+        'group_member_role_updated',
+        p_actor_user_id,
+        'user',
+        p_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_build_object('member_role', v_member_role)
+    );
+end;
+$$ language plpgsql;
@@ -10,6 +10,12 @@ begin
     -- Update the sponsor for the group
     update group_sponsor set
         featured = coalesce((p_sponsor->>'featured')::boolean, false),
+        group_sponsor_tier_id = (
+            select gst.group_sponsor_tier_id
+            from group_sponsor_tier gst
+            where gst.group_id = p_group_id
+            and gst.group_sponsor_tier_id = (p_sponsor->>'group_sponsor_tier_id')::uuid
+        ),
         logo_url = p_sponsor->>'logo_url',
         name = p_sponsor->>'name',
         website_url = nullif(p_sponsor->>'website_url', '')
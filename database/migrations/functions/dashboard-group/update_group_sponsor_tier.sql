@@ -0,0 +1,34 @@
+-- Updates an existing sponsor tier in the group.
+create or replace function update_group_sponsor_tier(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_group_sponsor_tier_id uuid,
+    p_tier jsonb
+)
+returns void as $$
+begin
+    -- Update the tier for the group
+    update group_sponsor_tier set
+        logo_size = coalesce(p_tier->>'logo_size', 'medium'),
+        name = btrim(p_tier->>'name'),
+        "order" = coalesce((p_tier->>'order')::int, 0)
+    where group_sponsor_tier_id = p_group_sponsor_tier_id
+    and group_id = p_group_id;
+
+    if not found then
+        raise exception 'sponsor tier not found';
+    end if;
+
+    -- Track the tier update
+    perform insert_audit_log(
+        'group_sponsor_tier_updated',
+        p_actor_user_id,
+        'group_sponsor_tier',
+        p_group_sponsor_tier_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+exception when unique_violation then
+    raise exception 'sponsor tier already exists';
+end;
+$$ language plpgsql;
@@ -40,6 +40,16 @@ begin
     where group_id = p_group_id
       and user_id = p_user_id;
 
+    -- Prevent demoting the group owner
+    if p_role <> 'admin' and exists (
+        select 1
+        from "group"
+        where group_id = p_group_id
+        and owner_user_id = p_user_id
+    ) then
+        raise exception 'cannot change role for the group owner, transfer the ownership first';
+    end if;
+
     -- Prevent demoting the last accepted group admin
     if v_current_accepted and v_current_role = 'admin' and p_role <> 'admin' then
         select count(*)::int
@@ -0,0 +1,29 @@
+-- Sets or clears the message included in the welcome email sent to new members.
+create or replace function update_group_welcome_message(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_welcome_message text
+)
+returns void as $$
+begin
+    -- Store the message, clearing it when none is provided
+    update "group" set
+        welcome_message = nullif(btrim(p_welcome_message), '')
+    where group_id = p_group_id
+    and deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Track the welcome message update
+    perform insert_audit_log(
+        'group_welcome_message_updated',
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
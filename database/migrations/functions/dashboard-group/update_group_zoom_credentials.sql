@@ -0,0 +1,63 @@
+-- Creates or updates the Zoom credentials used by the group's meetings.
+create or replace function update_group_zoom_credentials(
+    p_actor_user_id uuid,
+    p_group_id uuid,
+    p_credentials jsonb
+)
+returns void as $$
+declare
+    v_client_secret text := nullif(btrim(p_credentials->>'client_secret'), '');
+    v_webhook_secret_token text := nullif(btrim(p_credentials->>'webhook_secret_token'), '');
+begin
+    -- Keep the stored secrets when new ones are not provided
+    v_client_secret := coalesce(
+        v_client_secret,
+        (select client_secret from group_zoom_credentials where group_id = p_group_id)
+    );
+    v_webhook_secret_token := coalesce(
+        v_webhook_secret_token,
+        (select webhook_secret_token from group_zoom_credentials where group_id = p_group_id)
+    );
+
+    if v_client_secret is null then
+        raise exception 'zoom client secret is required';
+    end if;
+    if v_webhook_secret_token is null then
+        raise exception 'zoom webhook secret token is required';
+    end if;
+
+    -- Store the credentials for the group
+    insert into group_zoom_credentials (
+        group_id,
+        account_id,
+        client_id,
+        client_secret,
+        host_user,
+        webhook_secret_token
+    ) values (
+        p_group_id,
+        p_credentials->>'account_id',
+        p_credentials->>'client_id',
+        v_client_secret,
+        p_credentials->>'host_user',
+        v_webhook_secret_token
+    )
+    on conflict (group_id) do update set
+        account_id = excluded.account_id,
+        client_id = excluded.client_id,
+        client_secret = excluded.client_secret,
+        host_user = excluded.host_user,
+        webhook_secret_token = excluded.webhook_secret_token,
+        updated_at = current_timestamp;
+
+    -- Track the credentials update
+    perform insert_audit_log(
+        'group_zoom_credentials_updated',
+        p_actor_user_id,
+        'group',
+        p_group_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
@@ -3,6 +3,7 @@ create or replace function validate_add_event_dates(p_event jsonb)
 returns void as $$
 declare
     v_ends_at timestamptz;
+    v_publish_scheduled_at timestamptz;
     v_registration_ends_at timestamptz;
     v_registration_starts_at timestamptz;
     v_session jsonb;
@@ -26,6 +27,18 @@ begin
         end if;
     end if;
 
+    -- Scheduled publications must happen in the future and before the event starts
+    if p_event->>'publish_scheduled_at' is not null then
+        v_publish_scheduled_at := (p_event->>'publish_scheduled_at')::timestamp at time zone v_timezone;
+        if v_publish_scheduled_at < current_timestamp then
+            raise exception 'event publish_scheduled_at cannot be in the past';
+        end if;
+
+        if v_starts_at is not null and v_publish_scheduled_at >= v_starts_at then
+            raise exception 'event publish_scheduled_at must be before event starts_at';
+        end if;
+    end if;
+
     -- Parse optional registration window bounds using the event timezone
     if p_event->>'registration_starts_at' is not null then
         v_registration_starts_at := (p_event->>'registration_starts_at')::timestamp at time zone v_timezone;
@@ -18,8 +18,11 @@ begin
         raise exception 'waitlist enabled events must define a capacity';
     end if;
 
-    -- Validate event capacity against provider limits for meetings
-    if (p_event->>'meeting_requested')::boolean = true then
+    -- Validate event capacity against provider limits for meetings, webinars
+    -- are limited by the provider webinar license instead
+    if (p_event->>'meeting_requested')::boolean = true
+       and coalesce((p_event->>'meeting_webinar')::boolean, false) = false
+    then
         v_provider_max_participants := (p_cfg_max_participants->>(p_event->>'meeting_provider_id'))::int;
 
         if v_provider_max_participants is not null
@@ -14,10 +14,6 @@ begin
         raise exception 'waitlist cannot be enabled for ticketed events';
     end if;
 
-    if p_ticket_types is not null and p_payment_currency_code is null then
-        raise exception 'ticketed events require payment_currency_code';
-    end if;
-
     if p_ticket_types is null and p_discount_codes is not null then
         raise exception 'discount_codes require ticket_types';
     end if;
@@ -33,6 +29,20 @@ begin
     perform validate_event_discount_codes_payload(p_discount_codes);
     perform validate_event_ticket_types_payload(p_ticket_types);
 
+    -- Only ticket types or discounts that can charge attendees require a currency
+    if p_payment_currency_code is null
+       and (
+           p_discount_codes is not null
+           or exists (
+               select 1
+               from jsonb_array_elements(p_ticket_types) as ticket_types(ticket_type)
+               cross join jsonb_array_elements(ticket_type->'price_windows') as price_windows(price_window)
+               where (price_window->>'amount_minor')::bigint > 0
+           )
+       ) then
+        raise exception 'paid ticket types require payment_currency_code';
+    end if;
+
     -- Validate charge amount limits for every configured price window
     if p_ticket_types is not null and p_payment_currency_code is not null then
         for v_amount_minor in
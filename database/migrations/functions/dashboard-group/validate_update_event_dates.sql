@@ -10,6 +10,7 @@ declare
     v_is_past_event boolean;
     v_new_ends_at timestamptz;
     v_new_starts_at timestamptz;
+    v_publish_scheduled_at timestamptz;
     v_registration_ends_at timestamptz;
     v_registration_starts_at timestamptz;
     v_session jsonb;
@@ -45,6 +46,20 @@ begin
         raise exception 'published event must have a start date';
     end if;
 
+    -- Scheduled publications only apply to drafts, must happen in the future
+    -- and before the event starts
+    if p_event->>'publish_scheduled_at' is not null
+       and (p_event_before->>'published')::boolean is not true then
+        v_publish_scheduled_at := (p_event->>'publish_scheduled_at')::timestamp at time zone v_timezone;
+        if v_publish_scheduled_at < current_timestamp then
+            raise exception 'event publish_scheduled_at cannot be in the past';
+        end if;
+
+        if v_new_starts_at is not null and v_publish_scheduled_at >= v_new_starts_at then
+            raise exception 'event publish_scheduled_at must be before event starts_at';
+        end if;
+    end if;
+
     -- Require configured registration openings to leave time before close
     if v_registration_starts_at is not null
        and v_registration_ends_at is not null
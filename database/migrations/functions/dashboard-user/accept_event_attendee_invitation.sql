@@ -32,6 +32,9 @@ begin
         raise exception 'event not found or inactive';
     end if;
 
+    -- Users banned from the group cannot attend its events
+    perform ensure_user_is_not_banned_from_group(v_group_id, p_actor_user_id);
+
     -- Confirm the pending invitation
     update event_attendee
     set status = 'confirmed'
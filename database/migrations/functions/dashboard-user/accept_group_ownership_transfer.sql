@@ -0,0 +1,51 @@
+-- Accepts a pending group ownership transfer, making the user the new owner.
+create or replace function accept_group_ownership_transfer(
+    p_actor_user_id uuid,
+    p_group_id uuid
+)
+returns void as $$
+declare
+    v_previous_owner_user_id uuid;
+begin
+    -- Lock the group to serialize ownership changes
+    select owner_user_id
+    into v_previous_owner_user_id
+    from "group"
+    where group_id = p_group_id
+    for update;
+
+    -- Remove the pending transfer
+    delete from group_ownership_transfer
+    where group_id = p_group_id
+    and user_id = p_actor_user_id;
+
+    if not found then
+        raise exception 'no pending group ownership transfer found';
+    end if;
+
+    -- Make sure the new owner can manage the group
+    update group_team set
+        role = 'admin'
+    where group_id = p_group_id
+    and user_id = p_actor_user_id;
+
+    -- Transfer the ownership
+    update "group" set
+        owner_user_id = p_actor_user_id
+    where group_id = p_group_id;
+
+    -- Track the accepted transfer
+    perform insert_audit_log(
+        'group_ownership_transfer_accepted',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id,
+        null,
+        jsonb_strip_nulls(jsonb_build_object(
+            'previous_owner_user_id', v_previous_owner_user_id
+        ))
+    );
+end;
+$$ language plpgsql;
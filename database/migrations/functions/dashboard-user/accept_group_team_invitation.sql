@@ -14,6 +14,19 @@ begin
         raise exception 'no pending group invitation found';
     end if;
 
+    -- Make the first accepted admin the owner of groups without one
+    update "group" g set
+        owner_user_id = p_actor_user_id
+    where g.group_id = p_group_id
+    and g.owner_user_id is null
+    and exists (
+        select 1
+        from group_team gt
+        where gt.group_id = p_group_id
+        and gt.user_id = p_actor_user_id
+        and gt.role = 'admin'
+    );
+
     -- Track the accepted invitation
     perform insert_audit_log(
         'group_team_invitation_accepted',
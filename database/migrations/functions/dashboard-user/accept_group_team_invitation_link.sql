@@ -0,0 +1,34 @@
+-- accept_group_team_invitation_link accepts the pending group team invitation
+-- a link sent by email was issued for, returning whether it was accepted.
+create or replace function accept_group_team_invitation_link(
+    p_actor_user_id uuid,
+    p_token_hash text
+)
+returns boolean as $$
+declare
+    v_group_id uuid;
+begin
+    -- Find the pending invitation the link was issued for
+    select l.group_id
+    into v_group_id
+    from group_team_invitation_link l
+    join group_team gt using (group_id, user_id)
+    where l.token_hash = p_token_hash
+    and l.user_id = p_actor_user_id
+    and l.expires_at > current_timestamp
+    and gt.accepted = false;
+
+    if v_group_id is null then
+        return false;
+    end if;
+
+    -- Accept the invitation and discard the links issued for it
+    perform accept_group_team_invitation(p_actor_user_id, v_group_id);
+
+    delete from group_team_invitation_link
+    where group_id = v_group_id
+    and user_id = p_actor_user_id;
+
+    return true;
+end;
+$$ language plpgsql;
@@ -0,0 +1,39 @@
+-- Returns the certificate of attendance of a user for an event, if the user
+-- checked in to the event and it has already ended.
+create or replace function get_user_attendance_certificate(p_user_id uuid, p_event_id uuid)
+returns json as $$
+    select json_strip_nulls(json_build_object(
+        'attendee_name', coalesce(nullif(btrim(u.name), ''), u.username),
+        'community_display_name', c.display_name,
+        'event_id', e.event_id,
+        'event_name', e.name,
+        'event_slug', e.slug,
+        'group_name', g.name,
+        'primary_color', coalesce(c.email_primary_color, s.theme->>'primary_color'),
+        'starts_at', floor(extract(epoch from e.starts_at)),
+        'timezone', e.timezone,
+
+        'ends_at', floor(extract(epoch from e.ends_at)),
+        'logo_url', c.logo_url
+    ))
+    from event_attendee ea
+    join "user" u using (user_id)
+    join event e using (event_id)
+    join "group" g using (group_id)
+    join community c on c.community_id = g.community_id
+    left join lateral (
+        select site.theme
+        from site
+        order by site.created_at desc
+        limit 1
+    ) s on true
+    where ea.user_id = p_user_id
+    and ea.event_id = p_event_id
+    and ea.checked_in = true
+    and ea.status = 'confirmed'
+    and e.canceled = false
+    and e.deleted = false
+    and e.published = true
+    and e.starts_at is not null
+    and coalesce(e.ends_at, e.starts_at) <= current_timestamp;
+$$ language sql;
@@ -0,0 +1,15 @@
+-- Returns the certificates of attendance available to a user, most recent first.
+create or replace function list_user_attendance_certificates(p_user_id uuid)
+returns json as $$
+    select coalesce(
+        json_agg(certificate order by (certificate->>'starts_at')::bigint desc),
+        '[]'::json
+    )
+    from (
+        select get_user_attendance_certificate(p_user_id, ea.event_id)::jsonb as certificate
+        from event_attendee ea
+        where ea.user_id = p_user_id
+        and ea.checked_in = true
+    ) user_certificate
+    where certificate is not null;
+$$ language sql;
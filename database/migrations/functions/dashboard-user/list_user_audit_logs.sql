@@ -27,6 +27,8 @@ returns json as $$
                 'community_team_invitation_rejected',
                 'event_attendee_invitation_accepted',
                 'event_attendee_invitation_rejected',
+                'group_ownership_transfer_accepted',
+                'group_ownership_transfer_rejected',
                 'group_team_invitation_accepted',
                 'group_team_invitation_rejected',
                 'session_proposal_added',
@@ -36,8 +38,22 @@ returns json as $$
                 'session_proposal_updated',
                 'submission_resubmitted',
                 'submission_withdrawn',
+                'user_calendar_feed_updated',
                 'user_details_updated',
-                'user_password_updated'
+                'user_google_calendar_connected',
+                'user_google_calendar_disconnected',
+                'user_passkey_added',
+                'user_passkey_deleted',
+                'user_password_reset',
+                'user_password_updated',
+                'user_phone_number_updated',
+                'user_phone_number_verified',
+                'user_provider_linked',
+                'user_provider_unlinked',
+                'user_session_revoked',
+                'user_sessions_revoked',
+                'user_sms_notifications_updated',
+                'user_webhook_updated'
             ]::text[])
             and (f.action_value is null or al.action = f.action_value)
             and (
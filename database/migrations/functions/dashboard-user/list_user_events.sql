@@ -26,6 +26,7 @@ returns json as $$
                     when ea.status = 'registration-questions-pending'
                         and pending_purchase.event_purchase_id is not null then 'pending-payment'
                     when ea.status = 'registration-questions-pending' then 'registration-questions-pending'
+                    when ea.status = 'waitlist-confirmation-pending' then 'waitlist-confirmation-pending'
                     else 'attendee'
                 end as attendance_status,
                 ea.event_id,
@@ -35,7 +36,8 @@ returns json as $$
                 case
                     when ea.status = 'registration-questions-pending' then pending_purchase.provider_checkout_url
                     else null
-                end as resume_checkout_url
+                end as resume_checkout_url,
+                ea.waitlist_confirmation_expires_at
             from event_attendee ea
             left join lateral (
                 select
@@ -50,7 +52,13 @@ returns json as $$
                 limit 1
             ) pending_purchase on true
             where ea.user_id = p_user_id
-            and ea.status in ('confirmed', 'registration-questions-pending')
+            and (
+                ea.status in ('confirmed', 'registration-questions-pending')
+                or (
+                    ea.status = 'waitlist-confirmation-pending'
+                    and ea.waitlist_confirmation_expires_at > current_timestamp
+                )
+            )
 
             union all
 
@@ -61,7 +69,8 @@ returns json as $$
                 false as manually_invited,
                 null::jsonb as registration_answers,
                 'host'::text as role,
-                null::text as resume_checkout_url
+                null::text as resume_checkout_url,
+                null::timestamptz as waitlist_confirmation_expires_at
             from event_host eh
             where eh.user_id = p_user_id
 
@@ -74,7 +83,8 @@ returns json as $$
                 false as manually_invited,
                 null::jsonb as registration_answers,
                 'speaker'::text as role,
-                null::text as resume_checkout_url
+                null::text as resume_checkout_url,
+                null::timestamptz as waitlist_confirmation_expires_at
             from event_speaker es
             where es.user_id = p_user_id
 
@@ -87,7 +97,8 @@ returns json as $$
                 false as manually_invited,
                 null::jsonb as registration_answers,
                 'speaker'::text as role,
-                null::text as resume_checkout_url
+                null::text as resume_checkout_url,
+                null::timestamptz as waitlist_confirmation_expires_at
             from session_speaker ss
             join session s using (session_id)
             where ss.user_id = p_user_id
@@ -104,7 +115,8 @@ returns json as $$
                     as registration_answers,
                 max(rr.resume_checkout_url) as resume_checkout_url,
                 array_agg(distinct rr.role order by rr.role asc) as roles,
-                ve.starts_at
+                ve.starts_at,
+                max(rr.waitlist_confirmation_expires_at) as waitlist_confirmation_expires_at
             from visible_events ve
             join role_rows rr using (event_id)
             group by ve.community_id, ve.event_id, ve.group_id, ve.starts_at
@@ -120,7 +132,8 @@ returns json as $$
                 er.registration_answers,
                 er.resume_checkout_url,
                 er.roles,
-                er.starts_at
+                er.starts_at,
+                er.waitlist_confirmation_expires_at
             from event_rows er
             order by er.starts_at asc, er.event_id asc
             offset (p_filters->>'offset')::int
@@ -158,7 +171,9 @@ returns json as $$
                             'registration_answers',
                             erp.registration_answers,
                             'resume_checkout_url',
-                            erp.resume_checkout_url
+                            erp.resume_checkout_url,
+                            'waitlist_confirmation_expires_at',
+                            extract(epoch from erp.waitlist_confirmation_expires_at)::bigint
                         )
                         || case
                             when erp.manually_invited then jsonb_build_object('manually_invited', true)
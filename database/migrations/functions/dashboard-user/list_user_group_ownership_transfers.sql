@@ -0,0 +1,19 @@
+-- Returns all pending group ownership transfers for a user.
+create or replace function list_user_group_ownership_transfers(p_user_id uuid)
+returns json as $$
+    select coalesce(json_agg(row_to_json(transfer)), '[]'::json)
+    from (
+        select
+            c.name as community_name,
+            g.group_id,
+            g.name as group_name,
+
+            extract(epoch from t.created_at)::bigint as created_at
+        from group_ownership_transfer t
+        join "group" g using (group_id)
+        join community c using (community_id)
+        where t.user_id = p_user_id
+        and g.deleted = false
+        order by t.created_at desc
+    ) transfer;
+$$ language sql;
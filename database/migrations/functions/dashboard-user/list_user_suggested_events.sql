@@ -0,0 +1,54 @@
+-- Returns upcoming events tagged with topics matching the user interests.
+create or replace function list_user_suggested_events(p_user_id uuid)
+returns json as $$
+    with
+        -- Normalize the user interests like topic names are normalized.
+        user_interests as (
+            select distinct regexp_replace(lower(i), '[^\w]+', '-', 'g') as normalized_name
+            from "user" u
+            cross join unnest(u.interests) as i
+            where u.user_id = p_user_id
+        ),
+        -- Collect upcoming visible events with a matching topic.
+        suggested_events as (
+            select
+                g.community_id,
+                e.event_id,
+                e.group_id,
+                e.starts_at
+            from event e
+            join "group" g using (group_id)
+            join community c on c.community_id = g.community_id
+            where c.active = true
+            and g.active = true
+            and g.deleted = false
+            and e.canceled = false
+            and e.deleted = false
+            and e.published = true
+            and e.test_event = false
+            and e.starts_at > now()
+            and exists (
+                select 1
+                from event_topic et
+                join topic t using (topic_id)
+                join user_interests ui using (normalized_name)
+                where et.event_id = e.event_id
+            )
+            and not exists (
+                select 1
+                from event_attendee ea
+                where ea.event_id = e.event_id
+                and ea.user_id = p_user_id
+            )
+            order by e.starts_at asc, e.event_id asc
+            limit 6
+        )
+    select coalesce(
+        json_agg(
+            get_event_summary(se.community_id, se.group_id, se.event_id)
+            order by se.starts_at asc, se.event_id asc
+        ),
+        '[]'::json
+    )
+    from suggested_events se;
+$$ language sql;
@@ -0,0 +1,27 @@
+-- Rejects a pending group ownership transfer.
+create or replace function reject_group_ownership_transfer(
+    p_actor_user_id uuid,
+    p_group_id uuid
+)
+returns void as $$
+begin
+    -- Remove the pending transfer
+    delete from group_ownership_transfer
+    where group_id = p_group_id
+    and user_id = p_actor_user_id;
+
+    if not found then
+        raise exception 'no pending group ownership transfer found';
+    end if;
+
+    -- Track the rejected transfer
+    perform insert_audit_log(
+        'group_ownership_transfer_rejected',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id,
+        (select community_id from "group" where group_id = p_group_id),
+        p_group_id
+    );
+end;
+$$ language plpgsql;
@@ -18,6 +18,7 @@ declare
     v_registration_window_open boolean;
     v_starts_at timestamptz;
     v_updated_status text;
+    v_waitlist_confirmation_expires_at timestamptz;
 begin
     -- Load active event context before validating answer edits
     select
@@ -87,23 +88,33 @@ begin
     -- Lock the attendee row before storing answers
     select
         ea.manually_invited,
-        ea.status
+        ea.status,
+        ea.waitlist_confirmation_expires_at
     into
         v_manually_invited,
-        v_previous_status
+        v_previous_status,
+        v_waitlist_confirmation_expires_at
     from event_attendee ea
     where ea.event_id = p_event_id
     and ea.user_id = p_actor_user_id
-    and ea.status in ('confirmed', 'registration-questions-pending')
+    and ea.status in ('confirmed', 'registration-questions-pending', 'waitlist-confirmation-pending')
     for update of ea;
 
     if not found then
         raise exception 'event registration not found';
     end if;
 
-    -- Only manual invitations and active checkout holds can answer outside the public window
+    -- Seats reserved for promoted waitlist users are only held until the deadline
+    if v_previous_status = 'waitlist-confirmation-pending'
+       and v_waitlist_confirmation_expires_at <= current_timestamp then
+        raise exception 'waitlist confirmation window has expired';
+    end if;
+
+    -- Only manual invitations, active checkout holds, and waitlist confirmations can
+    -- answer outside the public window
     if not coalesce(v_manually_invited, false)
        and not v_has_active_checkout_hold
+       and v_previous_status <> 'waitlist-confirmation-pending'
        and not v_registration_window_open then
         raise exception 'event registration is not open';
     end if;
@@ -115,10 +126,11 @@ begin
         status = case
             when v_has_ticket_types and status = 'registration-questions-pending' then status
             else 'confirmed'
-        end
+        end,
+        waitlist_confirmation_expires_at = null
     where event_id = p_event_id
     and user_id = p_actor_user_id
-    and status in ('confirmed', 'registration-questions-pending')
+    and status in ('confirmed', 'registration-questions-pending', 'waitlist-confirmation-pending')
     returning status into v_updated_status;
 
     -- Track the answer submission
@@ -133,7 +145,7 @@ begin
         jsonb_build_object('event_id', p_event_id, 'user_id', p_actor_user_id)
     );
 
-    return v_previous_status = 'registration-questions-pending'
+    return v_previous_status in ('registration-questions-pending', 'waitlist-confirmation-pending')
         and v_updated_status = 'confirmed';
 end;
 $$ language plpgsql;
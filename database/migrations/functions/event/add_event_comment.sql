@@ -0,0 +1,82 @@
+-- Adds a comment or question to an event's discussion.
+create or replace function add_event_comment(
+    p_community_id uuid,
+    p_event_id uuid,
+    p_user_id uuid,
+    p_content text,
+    p_parent_event_comment_id uuid
+)
+returns uuid as $$
+declare
+    v_comments_locked boolean;
+    v_event_comment_id uuid;
+    v_group_id uuid;
+begin
+    -- Fetch event discussion settings
+    select e.comments_locked, e.group_id
+    into v_comments_locked, v_group_id
+    from event e
+    join "group" g on g.group_id = e.group_id
+    where e.event_id = p_event_id
+    and g.community_id = p_community_id
+    and g.active = true
+    and e.canceled = false
+    and e.deleted = false
+    and e.published = true;
+
+    if not found then
+        raise exception 'event not found or inactive';
+    end if;
+
+    -- Validate the discussion is open
+    if v_comments_locked = true then
+        raise exception 'the discussion for this event is locked';
+    end if;
+
+    -- Validate the user attends the event or belongs to the group team
+    if not exists (
+        select 1
+        from event_attendee ea
+        where ea.event_id = p_event_id
+        and ea.user_id = p_user_id
+        and ea.status = 'confirmed'
+    ) and not exists (
+        select 1
+        from group_team gt
+        where gt.group_id = v_group_id
+        and gt.user_id = p_user_id
+        and gt.accepted = true
+    ) then
+        raise exception 'only event attendees can post comments';
+    end if;
+
+    -- Validate replies target a top level comment of the same event
+    if p_parent_event_comment_id is not null then
+        perform 1
+        from event_comment ec
+        where ec.event_comment_id = p_parent_event_comment_id
+        and ec.event_id = p_event_id
+        and ec.parent_event_comment_id is null;
+
+        if not found then
+            raise exception 'comment not found';
+        end if;
+    end if;
+
+    -- Add comment
+    insert into event_comment (
+        content,
+        event_id,
+        parent_event_comment_id,
+        user_id
+    ) values (
+        btrim(p_content),
+        p_event_id,
+        p_parent_event_comment_id,
+        p_user_id
+    )
+    returning event_comment_id into v_event_comment_id;
+
+    return v_event_comment_id;
+end;
+$$ language plpgsql;
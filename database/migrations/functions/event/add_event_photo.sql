@@ -0,0 +1,81 @@
+-- Adds a photo submitted by an attendee to a past event's gallery.
+create or replace function add_event_photo(
+    p_community_id uuid,
+    p_event_id uuid,
+    p_user_id uuid,
+    p_url text
+)
+returns uuid as $$
+declare
+    v_attendee_photos_enabled boolean;
+    v_ends_at timestamptz;
+    v_event_photo_id uuid;
+begin
+    -- Fetch event photo settings
+    select e.attendee_photos_enabled, coalesce(e.ends_at, e.starts_at)
+    into v_attendee_photos_enabled, v_ends_at
+    from event e
+    join "group" g on g.group_id = e.group_id
+    where e.event_id = p_event_id
+    and g.community_id = p_community_id
+    and g.active = true
+    and e.canceled = false
+    and e.deleted = false
+    and e.published = true;
+
+    if not found then
+        raise exception 'event not found or inactive';
+    end if;
+
+    -- Validate attendees can submit photos
+    if v_attendee_photos_enabled = false then
+        raise exception 'photo submissions are not enabled for this event';
+    end if;
+
+    -- Validate the event has already taken place
+    if v_ends_at is null or v_ends_at >= current_timestamp then
+        raise exception 'photos can only be submitted once the event has ended';
+    end if;
+
+    -- Validate the user attended the event
+    perform 1
+    from event_attendee ea
+    where ea.event_id = p_event_id
+    and ea.user_id = p_user_id
+    and ea.status = 'confirmed';
+
+    if not found then
+        raise exception 'only event attendees can submit photos';
+    end if;
+
+    -- Validate the user has not reached the photos limit
+    if (
+        select count(*)
+        from event_photo ep
+        where ep.event_id = p_event_id
+        and ep.user_id = p_user_id
+        and ep.status <> 'rejected'
+    ) >= 10 then
+        raise exception 'you can submit up to 10 photos per event';
+    end if;
+
+    -- Add photo pending review
+    insert into event_photo (
+        event_id,
+        url,
+        user_id
+    ) values (
+        p_event_id,
+        p_url,
+        p_user_id
+    )
+    on conflict (event_id, url) do nothing
+    returning event_photo_id into v_event_photo_id;
+
+    if v_event_photo_id is null then
+        raise exception 'photo already submitted';
+    end if;
+
+    return v_event_photo_id;
+end;
+$$ language plpgsql;
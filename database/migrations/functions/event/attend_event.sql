@@ -10,10 +10,13 @@ declare
     v_attendee_count int;
     v_attendee_manually_invited boolean;
     v_attendee_status text;
+    v_attendee_waitlist_confirmation_expires_at timestamptz;
     v_capacity int;
+    v_frequent_no_show boolean := false;
     v_group_id uuid;
     v_has_registration_questions boolean;
     v_invitation_request_status text;
+    v_no_show_waitlist_threshold int;
     v_registration_answers jsonb;
     v_registration_ends_at timestamptz;
     v_registration_questions jsonb;
@@ -27,6 +30,7 @@ begin
         e.attendee_approval_required,
         e.capacity,
         e.group_id,
+        e.no_show_waitlist_threshold,
         e.registration_ends_at,
         e.registration_questions,
         e.registration_starts_at,
@@ -36,6 +40,7 @@ begin
         v_attendee_approval_required,
         v_capacity,
         v_group_id,
+        v_no_show_waitlist_threshold,
         v_registration_ends_at,
         v_registration_questions,
         v_registration_starts_at,
@@ -58,6 +63,12 @@ begin
         raise exception 'event not found or inactive';
     end if;
 
+    -- Users banned from the group cannot attend its events
+    perform ensure_user_is_not_banned_from_group(v_group_id, p_user_id);
+
+    -- Users must have an email domain allowed by the community
+    perform ensure_user_email_domain_allowed(p_community_id, p_user_id);
+
     -- Track question requirements so waitlist joins can skip answer validation
     -- until promotion, while attendee and invitation paths still enforce answers.
     v_has_registration_questions := jsonb_array_length(coalesce(v_registration_questions, '[]'::jsonb)) > 0;
@@ -70,10 +81,12 @@ begin
     -- Lock any existing attendee lifecycle row before deciding the RSVP path
     select
         ea.manually_invited,
-        ea.status
+        ea.status,
+        ea.waitlist_confirmation_expires_at
     into
         v_attendee_manually_invited,
-        v_attendee_status
+        v_attendee_status,
+        v_attendee_waitlist_confirmation_expires_at
     from event_attendee ea
     where ea.event_id = p_event_id
     and ea.user_id = p_user_id
@@ -88,10 +101,19 @@ begin
     if v_attendee_status in (
         'invitation-pending',
         'invitation-rejected',
-        'registration-questions-pending'
+        'registration-questions-pending',
+        'waitlist-confirmation-pending'
     ) then
-        -- Only manual invitation rows can be accepted after public registration closes
+        -- Seats reserved for promoted waitlist users are only held until the deadline
+        if v_attendee_status = 'waitlist-confirmation-pending'
+           and v_attendee_waitlist_confirmation_expires_at <= current_timestamp then
+            raise exception 'waitlist confirmation window has expired';
+        end if;
+
+        -- Only manual invitations and waitlist confirmations can be accepted after
+        -- public registration closes
         if not coalesce(v_attendee_manually_invited, false)
+           and v_attendee_status <> 'waitlist-confirmation-pending'
            and not v_registration_window_open then
             raise exception 'event registration is not open';
         end if;
@@ -108,7 +130,8 @@ begin
             attendance_canceled_at = null,
             attendance_canceled_by_user_id = null,
             registration_answers = v_registration_answers,
-            status = 'confirmed'
+            status = 'confirmed',
+            waitlist_confirmation_expires_at = null
         where event_id = p_event_id
         and user_id = p_user_id
         and status = v_attendee_status;
@@ -204,7 +227,15 @@ begin
     if v_capacity is not null then
         select get_event_occupied_seat_count(p_event_id) into v_attendee_count;
 
-        if v_attendee_count >= v_capacity then
+        -- Send users who often miss the group's events to the waiting list, even
+        -- when seats are still available
+        if v_waitlist_enabled and v_no_show_waitlist_threshold is not null then
+            select events_missed >= v_no_show_waitlist_threshold
+            into v_frequent_no_show
+            from get_user_group_attendance_record(v_group_id, p_user_id);
+        end if;
+
+        if v_attendee_count >= v_capacity or v_frequent_no_show then
             if v_waitlist_enabled then
                 -- Remove inactive attendance rows before moving the user into the waitlist
                 delete from event_attendee
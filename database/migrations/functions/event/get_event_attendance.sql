@@ -46,6 +46,14 @@ create or replace function get_event_attendance(
                 ),
                 false
             ) as manually_invited,
+            (
+                select ea.reminder_preference
+                from event_attendee ea
+                where ea.event_id = p_event_id
+                and ea.user_id = p_user_id
+                and ea.status = 'confirmed'
+                and exists (select 1 from scoped_event)
+            ) as reminder_preference,
             case
                 when exists (
                     select 1
@@ -73,6 +81,15 @@ create or replace function get_event_attendance(
                     and ea.status = 'invitation-pending'
                     and exists (select 1 from scoped_event)
                 ) then 'invitation-approved'
+                when exists (
+                    select 1
+                    from event_attendee ea
+                    where ea.event_id = p_event_id
+                    and ea.user_id = p_user_id
+                    and ea.status = 'waitlist-confirmation-pending'
+                    and ea.waitlist_confirmation_expires_at > current_timestamp
+                    and exists (select 1 from scoped_event)
+                ) then 'waitlist-confirmation-pending'
                 when exists (
                     select 1
                     from event_attendee ea
@@ -169,6 +186,11 @@ create or replace function get_event_attendance(
                 when manually_invited then jsonb_build_object('manually_invited', true)
                 else '{}'::jsonb
             end
+            || case
+                when reminder_preference is not null
+                    then jsonb_build_object('reminder_preference', reminder_preference)
+                else '{}'::jsonb
+            end
         )::json
     from attendance_state;
 $$ language sql;
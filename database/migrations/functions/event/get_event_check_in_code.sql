@@ -0,0 +1,20 @@
+-- Returns the kiosk check-in code of an event for the window containing the given time.
+create or replace function get_event_check_in_code(
+    p_event_id uuid,
+    p_at timestamptz
+) returns text as $$
+    select substr(
+        encode(
+            hmac(
+                e.event_id::text || ':' || floor(extract(epoch from p_at) / 60)::bigint::text,
+                e.check_in_secret::text,
+                'sha256'
+            ),
+            'hex'
+        ),
+        1,
+        16
+    )
+    from event e
+    where e.event_id = p_event_id;
+$$ language sql;
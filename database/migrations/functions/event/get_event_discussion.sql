@@ -0,0 +1,73 @@
+-- Returns the discussion of an event, with replies nested under each comment.
+create or replace function get_event_discussion(p_community_id uuid, p_event_id uuid)
+returns json as $$
+    with comments as (
+        select
+            ec.content,
+            ec.created_at,
+            ec.event_comment_id,
+            ec.parent_event_comment_id,
+            ec.pinned,
+            exists (
+                select 1
+                from group_team gt
+                where gt.group_id = e.group_id
+                and gt.user_id = ec.user_id
+                and gt.accepted = true
+            ) as organizer,
+            json_strip_nulls(json_build_object(
+                'user_id', u.user_id,
+                'username', u.username,
+
+                'bio', u.bio,
+                'bluesky_url', u.bluesky_url,
+                'company', u.company,
+                'facebook_url', u.facebook_url,
+                'github_url', u.github_url,
+                'linkedin_url', u.linkedin_url,
+                'name', u.name,
+                'photo_url', u.photo_url,
+                'provider', get_public_user_provider(u.provider),
+                'title', u.title,
+                'twitter_url', u.twitter_url,
+                'website_url', u.website_url
+            )) as "user"
+        from event_comment ec
+        join event e on e.event_id = ec.event_id
+        join "user" u on u.user_id = ec.user_id
+        where ec.event_id = p_event_id
+    )
+    select json_build_object(
+        'comments', coalesce((
+            select json_agg(json_build_object(
+                'content', c.content,
+                'created_at', floor(extract(epoch from c.created_at)),
+                'event_comment_id', c.event_comment_id,
+                'organizer', c.organizer,
+                'pinned', c.pinned,
+                'replies', coalesce((
+                    select json_agg(json_build_object(
+                        'content', r.content,
+                        'created_at', floor(extract(epoch from r.created_at)),
+                        'event_comment_id', r.event_comment_id,
+                        'organizer', r.organizer,
+                        'pinned', r.pinned,
+                        'replies', '[]'::json,
+                        'user', r."user"
+                    ) order by r.pinned desc, r.created_at asc, r.event_comment_id asc)
+                    from comments r
+                    where r.parent_event_comment_id = c.event_comment_id
+                ), '[]'),
+                'user', c."user"
+            ) order by c.pinned desc, c.created_at asc, c.event_comment_id asc)
+            from comments c
+            where c.parent_event_comment_id is null
+        ), '[]'),
+        'locked', e.comments_locked
+    )
+    from event e
+    join "group" g on g.group_id = e.group_id
+    where e.event_id = p_event_id
+    and g.community_id = p_community_id
+    and e.deleted = false;
+$$ language sql;
@@ -0,0 +1,22 @@
+-- Returns the details needed to download a file attached to a published event.
+create or replace function get_event_resource(
+    p_community_id uuid,
+    p_event_id uuid,
+    p_event_resource_id uuid
+)
+returns json as $$
+    select json_build_object(
+        'content_type', er.content_type,
+        'file_name', er.file_name,
+        'name', er.name
+    )
+    from event_resource er
+    join event e on e.event_id = er.event_id
+    join "group" g on g.group_id = e.group_id
+    where er.event_resource_id = p_event_resource_id
+    and er.event_id = p_event_id
+    and g.community_id = p_community_id
+    and g.active = true
+    and e.deleted = false
+    and e.published = true;
+$$ language sql;
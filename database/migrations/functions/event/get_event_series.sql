@@ -0,0 +1,45 @@
+-- Returns the published events of a linked event series with their combined attendance.
+create or replace function get_event_series(p_community_id uuid, p_event_series_id uuid)
+returns json as $$
+    with series_events as (
+        select e.event_id, e.group_id, e.starts_at
+        from event_series es
+        join event e on e.event_series_id = es.event_series_id
+        join "group" g on g.group_id = e.group_id
+        where es.event_series_id = p_event_series_id
+        and g.community_id = p_community_id
+        and g.active = true
+        and e.deleted = false
+        and e.published = true
+    )
+    select json_strip_nulls(json_build_object(
+        'attendee_count', (
+            select count(*)
+            from event_attendee ea
+            join series_events se using (event_id)
+            where ea.status = 'confirmed'
+        ),
+        'checked_in_count', (
+            select count(*)
+            from event_attendee ea
+            join series_events se using (event_id)
+            where ea.status = 'confirmed'
+            and ea.checked_in = true
+        ),
+        'community', get_community_summary(p_community_id),
+        'event_series_id', es.event_series_id,
+        'events', (
+            select json_agg(
+                get_event_summary(p_community_id, se.group_id, se.event_id)
+                order by se.starts_at asc nulls last, se.event_id asc
+            )
+            from series_events se
+        ),
+        'group', get_group_summary(p_community_id, es.group_id),
+
+        'name', es.name
+    ))
+    from event_series es
+    where es.event_series_id = p_event_series_id
+    and exists (select 1 from series_events);
+$$ language sql;
@@ -0,0 +1,15 @@
+-- Returns the ticket code of a confirmed attendee of an event.
+create or replace function get_event_ticket_code(
+    p_community_id uuid,
+    p_event_id uuid,
+    p_user_id uuid
+) returns uuid as $$
+    select ea.ticket_code
+    from event_attendee ea
+    join event e on e.event_id = ea.event_id
+    join "group" g on g.group_id = e.group_id
+    where ea.event_id = p_event_id
+    and ea.user_id = p_user_id
+    and ea.status = 'confirmed'
+    and g.community_id = p_community_id;
+$$ language sql;
@@ -0,0 +1,94 @@
+-- Returns a speaker's public profile with their talks in a community's events.
+create or replace function get_speaker_profile(p_community_id uuid, p_username text)
+returns json as $$
+    with
+    -- Resolve the speaker from the username
+    speaker as (
+        select u.*
+        from "user" u
+        where u.username = p_username
+    ),
+    -- Published events of active groups in the community
+    community_events as (
+        select e.event_id, e.group_id, e.starts_at
+        from event e
+        join "group" g on g.group_id = e.group_id
+        where g.community_id = p_community_id
+        and g.active = true
+        and g.deleted = false
+        and e.canceled = false
+        and e.deleted = false
+        and e.published = true
+        and e.test_event = false
+    ),
+    -- Sessions presented by the speaker, falling back to the linked CFS proposal speakers
+    session_talks as (
+        select ce.event_id, ce.group_id, s.name as session_name, s.starts_at
+        from session s
+        join community_events ce on ce.event_id = s.event_id
+        cross join speaker sk
+        left join cfs_submission cs on cs.cfs_submission_id = s.cfs_submission_id
+        left join session_proposal sp on sp.session_proposal_id = cs.session_proposal_id
+        where exists (
+            select 1
+            from session_speaker ss
+            where ss.session_id = s.session_id
+            and ss.user_id = sk.user_id
+        )
+        or (
+            not exists (
+                select 1
+                from session_speaker ss
+                where ss.session_id = s.session_id
+            )
+            and sk.user_id in (sp.user_id, sp.co_speaker_user_id)
+        )
+    ),
+    -- Events where the speaker is listed without a specific session
+    event_talks as (
+        select ce.event_id, ce.group_id, null::text as session_name, ce.starts_at
+        from event_speaker es
+        join community_events ce on ce.event_id = es.event_id
+        join speaker sk on sk.user_id = es.user_id
+        where not exists (
+            select 1
+            from session_talks st
+            where st.event_id = ce.event_id
+        )
+    ),
+    talks as (
+        select * from session_talks
+        union all
+        select * from event_talks
+    )
+    select json_strip_nulls(json_build_object(
+        'community', get_community_summary(p_community_id),
+        'talks', (
+            select json_agg(json_strip_nulls(json_build_object(
+                'event', get_event_summary(p_community_id, t.group_id, t.event_id),
+
+                'session_name', t.session_name
+            )) order by t.starts_at desc nulls last, t.session_name asc)
+            from talks t
+        ),
+        'user', json_strip_nulls(json_build_object(
+            'user_id', sk.user_id,
+            'username', sk.username,
+
+            'bio', sk.bio,
+            'bluesky_url', sk.bluesky_url,
+            'company', sk.company,
+            'facebook_url', sk.facebook_url,
+            'github_url', sk.github_url,
+            'linkedin_url', sk.linkedin_url,
+            'name', sk.name,
+            'photo_url', sk.photo_url,
+            'provider', get_public_user_provider(sk.provider),
+            'title', sk.title,
+            'twitter_url', sk.twitter_url,
+            'website_url', sk.website_url
+        ))
+    ))
+    from speaker sk
+    where exists (select 1 from talks);
+$$ language sql;
@@ -0,0 +1,23 @@
+-- Returns the events in a user's personal calendar feed, identified by its token.
+create or replace function get_user_calendar_events(p_calendar_token text)
+returns json as $$
+    select (
+        select coalesce(json_agg(
+            get_event_summary(g.community_id, e.group_id, e.event_id)
+            order by e.starts_at asc, e.event_id asc
+        ), '[]')
+        from event_attendee ea
+        join event e on e.event_id = ea.event_id
+        join "group" g on g.group_id = e.group_id
+        where ea.user_id = u.user_id
+        and ea.status = 'confirmed'
+        and e.deleted = false
+        and e.published = true
+        and e.starts_at is not null
+        and e.starts_at > now() - interval '30 days'
+        and g.active = true
+        and g.deleted = false
+    )
+    from "user" u
+    where u.calendar_token = p_calendar_token;
+$$ language sql;
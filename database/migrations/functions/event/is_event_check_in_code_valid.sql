@@ -0,0 +1,18 @@
+-- Checks if a kiosk check-in code matches the current or previous code of an event.
+create or replace function is_event_check_in_code_valid(
+    p_community_id uuid,
+    p_event_id uuid,
+    p_code text
+) returns boolean as $$
+    select exists (
+        select 1
+        from event e
+        join "group" g on g.group_id = e.group_id
+        where e.event_id = p_event_id
+        and g.community_id = p_community_id
+        and p_code in (
+            get_event_check_in_code(e.event_id, current_timestamp),
+            get_event_check_in_code(e.event_id, current_timestamp - interval '1 minute')
+        )
+    );
+$$ language sql;
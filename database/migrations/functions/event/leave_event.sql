@@ -91,9 +91,9 @@ begin
         );
     end if;
 
-    -- Otherwise release a pending registration-questions seat, since these
-    -- rows occupy capacity until answered; ticketed pending rows are owned
-    -- by the checkout hold flow instead
+    -- Otherwise release a pending registration-questions or waitlist confirmation
+    -- seat, since these rows occupy capacity until answered or confirmed; ticketed
+    -- pending rows are owned by the checkout hold flow instead
     if not v_is_ticketed then
         -- Declining a manual invitation keeps the rejection on record
         update event_attendee
@@ -109,11 +109,12 @@ begin
             set
                 attendance_canceled_at = current_timestamp,
                 attendance_canceled_by_user_id = p_user_id,
-                status = 'attendance-canceled'
+                status = 'attendance-canceled',
+                waitlist_confirmation_expires_at = null
             where event_id = p_event_id
             and user_id = p_user_id
             and manually_invited = false
-            and status = 'registration-questions-pending';
+            and status in ('registration-questions-pending', 'waitlist-confirmation-pending');
         end if;
 
         if found then
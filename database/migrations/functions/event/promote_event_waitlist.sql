@@ -13,7 +13,10 @@ declare
     v_registration_ends_at timestamptz;
     v_registration_questions jsonb;
     v_registration_starts_at timestamptz;
+    v_promoted_status text;
     v_starts_at timestamptz;
+    v_waitlist_confirmation_expires_at timestamptz;
+    v_waitlist_confirmation_hours int;
     v_waitlist_entry record;
     v_waitlist_count int;
 begin
@@ -28,13 +31,15 @@ begin
         e.registration_ends_at,
         e.registration_questions,
         e.registration_starts_at,
-        e.starts_at
+        e.starts_at,
+        e.waitlist_confirmation_hours
     into
         v_capacity,
         v_registration_ends_at,
         v_registration_questions,
         v_registration_starts_at,
-        v_starts_at
+        v_starts_at,
+        v_waitlist_confirmation_hours
     from event e
     where e.event_id = p_event_id
     for update of e;
@@ -55,6 +60,17 @@ begin
     -- Route promoted attendees through the questions flow when any registration questions exist
     v_has_registration_questions := jsonb_array_length(coalesce(v_registration_questions, '[]'::jsonb)) > 0;
 
+    -- Reserve seats until promoted users confirm them when the event requires it
+    if v_waitlist_confirmation_hours is not null then
+        v_promoted_status := 'waitlist-confirmation-pending';
+        v_waitlist_confirmation_expires_at := current_timestamp
+            + make_interval(hours => v_waitlist_confirmation_hours);
+    elsif v_has_registration_questions then
+        v_promoted_status := 'registration-questions-pending';
+    else
+        v_promoted_status := 'confirmed';
+    end if;
+
     -- Compute how many seats can be filled from the waitlist
     if v_capacity is null then
         select count(*) into v_waitlist_count
@@ -98,14 +114,17 @@ begin
         end if;
 
         -- Insert the promoted user as an attendee or refresh an existing pending question row
-        insert into event_attendee (event_id, user_id, status)
+        insert into event_attendee (event_id, user_id, status, waitlist_confirmation_expires_at)
         values (
             p_event_id,
             v_waitlist_entry.user_id,
-            case when v_has_registration_questions then 'registration-questions-pending' else 'confirmed' end
+            v_promoted_status,
+            v_waitlist_confirmation_expires_at
         )
         on conflict (event_id, user_id) do update
-        set status = case when v_has_registration_questions then 'registration-questions-pending' else 'confirmed' end
+        set
+            status = v_promoted_status,
+            waitlist_confirmation_expires_at = v_waitlist_confirmation_expires_at
         where event_attendee.status in ('invitation-canceled', 'registration-questions-pending');
 
         -- Record only users successfully moved into attendees during this execution
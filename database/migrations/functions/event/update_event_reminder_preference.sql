@@ -0,0 +1,27 @@
+-- Update when an attendee wants to be reminded about an event.
+create or replace function update_event_reminder_preference(
+    p_community_id uuid,
+    p_event_id uuid,
+    p_user_id uuid,
+    p_reminder_preference text
+) returns void as $$
+begin
+    -- Check if event exists in the community and is active
+    perform ensure_event_is_active(p_community_id, p_event_id);
+
+    -- Update the attendee preference, allowing a new reminder when it changes
+    update event_attendee set
+        reminder_preference = p_reminder_preference,
+        reminder_sent_for_starts_at = case
+            when reminder_preference is distinct from p_reminder_preference then null
+            else reminder_sent_for_starts_at
+        end
+    where event_id = p_event_id
+    and user_id = p_user_id
+    and status = 'confirmed';
+
+    if not found then
+        raise exception 'user is not attending this event';
+    end if;
+end;
+$$ language plpgsql;
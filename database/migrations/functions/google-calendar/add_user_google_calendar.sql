@@ -0,0 +1,38 @@
+-- add_user_google_calendar connects a user's Google Calendar, queueing the
+-- upcoming events they attend to be added to it.
+create or replace function add_user_google_calendar(
+    p_actor_user_id uuid,
+    p_refresh_token text
+) returns void as $$
+begin
+    -- Store the connection, replacing the credentials of a previous one
+    insert into user_google_calendar (user_id, refresh_token)
+    values (p_actor_user_id, p_refresh_token)
+    on conflict (user_id) do update
+    set refresh_token = excluded.refresh_token;
+
+    -- Queue the upcoming events the user attends
+    insert into google_calendar_entry (event_id, user_id)
+    select ea.event_id, ea.user_id
+    from event_attendee ea
+    join event e on e.event_id = ea.event_id
+    where ea.user_id = p_actor_user_id
+    and ea.status = 'confirmed'
+    and e.canceled = false
+    and e.deleted = false
+    and e.published = true
+    and coalesce(e.ends_at, e.starts_at) > current_timestamp
+    on conflict (event_id, user_id) do update
+    set
+        in_sync = false,
+        sync_version = google_calendar_entry.sync_version + 1;
+
+    -- Track the Google Calendar connection
+    perform insert_audit_log(
+        'user_google_calendar_connected',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,58 @@
+-- claim_google_calendar_entry_out_of_sync claims one Google Calendar entry
+-- that needs to be synchronized. The event is only included when it should be
+-- in the user's calendar, otherwise the entry must be removed from it. Claims
+-- that are not completed expire after 15 minutes.
+create or replace function claim_google_calendar_entry_out_of_sync()
+returns jsonb as $$
+    with next_entry as (
+        select
+            gce.event_id,
+            gce.user_id
+        from google_calendar_entry gce
+        where gce.in_sync = false
+          and (
+              gce.sync_claimed_at is null
+              or gce.sync_claimed_at < current_timestamp - interval '15 minutes'
+          )
+        order by gce.sync_claimed_at asc nulls first
+        for update of gce skip locked
+        limit 1
+    ),
+    claimed_entry as (
+        update google_calendar_entry gce
+        set sync_claimed_at = current_timestamp
+        from next_entry ne
+        where gce.event_id = ne.event_id
+          and gce.user_id = ne.user_id
+        returning
+            gce.event_id,
+            gce.google_event_id,
+            gce.sync_version,
+            gce.user_id
+    )
+    select jsonb_build_object(
+        'event_id', ce.event_id,
+        'refresh_token', ugc.refresh_token,
+        'sync_version', ce.sync_version,
+        'user_id', ce.user_id,
+
+        'event', (
+            select get_event_summary(g.community_id, e.group_id, e.event_id)::jsonb
+            from event e
+            join "group" g on g.group_id = e.group_id
+            join event_attendee ea on ea.event_id = e.event_id
+            where e.event_id = ce.event_id
+              and ea.user_id = ce.user_id
+              and ea.status = 'confirmed'
+              and e.canceled = false
+              and e.deleted = false
+              and e.published = true
+              and e.starts_at is not null
+              and g.active = true
+              and g.deleted = false
+        ),
+        'google_event_id', ce.google_event_id
+    )
+    from claimed_entry ce
+    join user_google_calendar ugc on ugc.user_id = ce.user_id;
+$$ language sql;
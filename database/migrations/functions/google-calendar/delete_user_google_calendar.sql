@@ -0,0 +1,7 @@
+-- delete_user_google_calendar removes a Google Calendar connection whose
+-- access has been revoked.
+create or replace function delete_user_google_calendar(p_user_id uuid)
+returns void as $$
+    delete from user_google_calendar
+    where user_id = p_user_id;
+$$ language sql;
@@ -0,0 +1,17 @@
+-- disconnect_user_google_calendar disconnects a user's Google Calendar.
+create or replace function disconnect_user_google_calendar(p_actor_user_id uuid)
+returns void as $$
+begin
+    -- Remove the connection and the entries synchronized with it
+    delete from user_google_calendar
+    where user_id = p_actor_user_id;
+
+    -- Track the Google Calendar disconnection
+    perform insert_audit_log(
+        'user_google_calendar_disconnected',
+        p_actor_user_id,
+        'user',
+        p_actor_user_id
+    );
+end;
+$$ language plpgsql;
@@ -0,0 +1,9 @@
+-- is_user_google_calendar_connected checks if a user connected their Google Calendar.
+create or replace function is_user_google_calendar_connected(p_user_id uuid)
+returns boolean as $$
+    select exists (
+        select 1
+        from user_google_calendar
+        where user_id = p_user_id
+    );
+$$ language sql;
@@ -0,0 +1,11 @@
+-- release_google_calendar_entry_sync_claim releases a retryable Google
+-- Calendar entry sync claim.
+create or replace function release_google_calendar_entry_sync_claim(
+    p_event_id uuid,
+    p_user_id uuid
+) returns void as $$
+    update google_calendar_entry
+    set sync_claimed_at = null
+    where event_id = p_event_id
+      and user_id = p_user_id;
+$$ language sql;
@@ -0,0 +1,23 @@
+-- set_google_calendar_entry_synced completes a Google Calendar entry sync
+-- claim. Entries changed while they were being synchronized stay out of sync,
+-- and entries no longer in the user's calendar are removed.
+create or replace function set_google_calendar_entry_synced(
+    p_event_id uuid,
+    p_user_id uuid,
+    p_google_event_id text,
+    p_sync_version bigint
+) returns void as $$
+    update google_calendar_entry
+    set
+        google_event_id = p_google_event_id,
+        in_sync = (sync_version = p_sync_version),
+        sync_claimed_at = null
+    where event_id = p_event_id
+      and user_id = p_user_id;
+
+    delete from google_calendar_entry
+    where event_id = p_event_id
+      and user_id = p_user_id
+      and google_event_id is null
+      and in_sync = true;
+$$ language sql;
@@ -0,0 +1,60 @@
+-- Records a message sent by a user to the organizers of a group, unless the
+-- user already sent the maximum number of messages allowed since the date
+-- provided. Returns the message id, or null when the message was not accepted.
+create or replace function add_group_contact_message(
+    p_community_id uuid,
+    p_group_id uuid,
+    p_user_id uuid,
+    p_subject text,
+    p_body text,
+    p_since timestamptz,
+    p_max_messages int
+)
+returns uuid as $$
+declare
+    v_group_contact_message_id uuid;
+begin
+    -- Validate the group is active
+    perform 1
+    from "group" g
+    where g.group_id = p_group_id
+    and g.community_id = p_community_id
+    and g.active = true
+    and g.deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Serialize the messages sent by the user so the limit cannot be exceeded
+    perform pg_advisory_xact_lock(
+        hashtextextended('ocg:group-contact-message:' || p_user_id::text, 0)
+    );
+
+    -- Reject the message when the user reached the limit
+    if (
+        select count(*)
+        from group_contact_message
+        where user_id = p_user_id
+        and created_at >= p_since
+    ) >= p_max_messages then
+        return null;
+    end if;
+
+    -- Add message
+    insert into group_contact_message (
+        body,
+        group_id,
+        subject,
+        user_id
+    ) values (
+        btrim(p_body),
+        p_group_id,
+        btrim(p_subject),
+        p_user_id
+    )
+    returning group_contact_message_id into v_group_contact_message_id;
+
+    return v_group_contact_message_id;
+end;
+$$ language plpgsql;
@@ -0,0 +1,59 @@
+-- Adds a reply to a topic in a group's discussion board.
+create or replace function add_group_discussion_reply(
+    p_community_id uuid,
+    p_group_id uuid,
+    p_user_id uuid,
+    p_group_discussion_topic_id uuid,
+    p_content text
+)
+returns uuid as $$
+declare
+    v_group_discussion_reply_id uuid;
+    v_locked boolean;
+begin
+    -- Fetch the topic from the active group
+    select t.locked
+    into v_locked
+    from group_discussion_topic t
+    join "group" g on g.group_id = t.group_id
+    where t.group_discussion_topic_id = p_group_discussion_topic_id
+    and t.group_id = p_group_id
+    and g.community_id = p_community_id
+    and g.active = true
+    and g.deleted = false;
+
+    if not found then
+        raise exception 'topic not found';
+    end if;
+
+    -- Validate the topic is open
+    if v_locked = true then
+        raise exception 'this topic is locked';
+    end if;
+
+    -- Validate the user is a group member or belongs to the group team
+    if not is_group_member(p_community_id, p_group_id, p_user_id) and not exists (
+        select 1
+        from group_team gt
+        where gt.group_id = p_group_id
+        and gt.user_id = p_user_id
+        and gt.accepted = true
+    ) then
+        raise exception 'only group members can take part in the discussion';
+    end if;
+
+    -- Add reply
+    insert into group_discussion_reply (
+        content,
+        group_discussion_topic_id,
+        user_id
+    ) values (
+        btrim(p_content),
+        p_group_discussion_topic_id,
+        p_user_id
+    )
+    returning group_discussion_reply_id into v_group_discussion_reply_id;
+
+    return v_group_discussion_reply_id;
+end;
+$$ language plpgsql;
@@ -0,0 +1,52 @@
+-- Starts a new topic in a group's discussion board.
+create or replace function add_group_discussion_topic(
+    p_community_id uuid,
+    p_group_id uuid,
+    p_user_id uuid,
+    p_title text,
+    p_content text
+)
+returns uuid as $$
+declare
+    v_group_discussion_topic_id uuid;
+begin
+    -- Validate the group is active
+    perform 1
+    from "group" g
+    where g.group_id = p_group_id
+    and g.community_id = p_community_id
+    and g.active = true
+    and g.deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Validate the user is a group member or belongs to the group team
+    if not is_group_member(p_community_id, p_group_id, p_user_id) and not exists (
+        select 1
+        from group_team gt
+        where gt.group_id = p_group_id
+        and gt.user_id = p_user_id
+        and gt.accepted = true
+    ) then
+        raise exception 'only group members can take part in the discussion';
+    end if;
+
+    -- Add topic
+    insert into group_discussion_topic (
+        content,
+        group_id,
+        title,
+        user_id
+    ) values (
+        btrim(p_content),
+        p_group_id,
+        btrim(p_title),
+        p_user_id
+    )
+    returning group_discussion_topic_id into v_group_discussion_topic_id;
+
+    return v_group_discussion_topic_id;
+end;
+$$ language plpgsql;
@@ -0,0 +1,15 @@
+-- Validates that a user has not been banned from a group.
+create or replace function ensure_user_is_not_banned_from_group(p_group_id uuid, p_user_id uuid)
+returns void as $$
+begin
+    -- Banned users cannot join the group or attend its events
+    if exists (
+        select 1
+        from group_ban
+        where group_id = p_group_id
+        and user_id = p_user_id
+    ) then
+        raise exception 'user is banned from this group';
+    end if;
+end;
+$$ language plpgsql;
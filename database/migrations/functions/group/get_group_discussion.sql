@@ -0,0 +1,76 @@
+-- Returns the discussion board of a group, with replies nested under each topic.
+create or replace function get_group_discussion(p_community_id uuid, p_group_id uuid)
+returns json as $$
+    with authors as (
+        select
+            u.user_id,
+            exists (
+                select 1
+                from group_team gt
+                where gt.group_id = p_group_id
+                and gt.user_id = u.user_id
+                and gt.accepted = true
+            ) as organizer,
+            json_strip_nulls(json_build_object(
+                'user_id', u.user_id,
+                'username', u.username,
+
+                'bio', u.bio,
+                'bluesky_url', u.bluesky_url,
+                'company', u.company,
+                'facebook_url', u.facebook_url,
+                'github_url', u.github_url,
+                'linkedin_url', u.linkedin_url,
+                'name', u.name,
+                'photo_url', u.photo_url,
+                'provider', get_public_user_provider(u.provider),
+                'title', u.title,
+                'twitter_url', u.twitter_url,
+                'website_url', u.website_url
+            )) as "user"
+        from "user" u
+        where u.user_id in (
+            select t.user_id
+            from group_discussion_topic t
+            where t.group_id = p_group_id
+            union
+            select r.user_id
+            from group_discussion_reply r
+            join group_discussion_topic t using (group_discussion_topic_id)
+            where t.group_id = p_group_id
+        )
+    )
+    select json_build_object(
+        'topics', coalesce((
+            select json_agg(json_build_object(
+                'content', t.content,
+                'created_at', floor(extract(epoch from t.created_at)),
+                'group_discussion_topic_id', t.group_discussion_topic_id,
+                'locked', t.locked,
+                'organizer', ta.organizer,
+                'pinned', t.pinned,
+                'replies', coalesce((
+                    select json_agg(json_build_object(
+                        'content', r.content,
+                        'created_at', floor(extract(epoch from r.created_at)),
+                        'group_discussion_reply_id', r.group_discussion_reply_id,
+                        'organizer', ra.organizer,
+                        'user', ra."user"
+                    ) order by r.created_at asc, r.group_discussion_reply_id asc)
+                    from group_discussion_reply r
+                    join authors ra on ra.user_id = r.user_id
+                    where r.group_discussion_topic_id = t.group_discussion_topic_id
+                ), '[]'),
+                'title', t.title,
+                'user', ta."user"
+            ) order by t.pinned desc, t.created_at desc, t.group_discussion_topic_id asc)
+            from group_discussion_topic t
+            join authors ta on ta.user_id = t.user_id
+            where t.group_id = g.group_id
+        ), '[]')
+    )
+    from "group" g
+    where g.group_id = p_group_id
+    and g.community_id = p_community_id
+    and g.deleted = false;
+$$ language sql;
@@ -0,0 +1,8 @@
+-- Returns the questions asked to users joining a group.
+create or replace function get_group_join_questions(p_community_id uuid, p_group_id uuid)
+returns json as $$
+    select coalesce(g.join_questions, '[]'::jsonb)::json
+    from "group" g
+    where g.group_id = p_group_id
+    and g.community_id = p_community_id;
+$$ language sql;
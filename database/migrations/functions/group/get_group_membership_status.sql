@@ -0,0 +1,21 @@
+-- Returns the membership status of a user in a group.
+create or replace function get_group_membership_status(
+    p_community_id uuid,
+    p_group_id uuid,
+    p_user_id uuid
+) returns text as $$
+    select case
+        when is_group_member(p_community_id, p_group_id, p_user_id) then 'member'
+        when exists (
+            select 1
+            from group_join_request gjr
+            join "group" g using (group_id)
+            where g.community_id = p_community_id
+            and gjr.group_id = p_group_id
+            and gjr.user_id = p_user_id
+            and gjr.status = 'pending'
+            and g.active = true
+        ) then 'pending-approval'
+        else 'none'
+    end;
+$$ language sql;
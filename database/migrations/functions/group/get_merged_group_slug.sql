@@ -0,0 +1,12 @@
+-- Returns the public slug of the group the group with the provided slug was
+-- merged into, if any.
+create or replace function get_merged_group_slug(p_community_id uuid, p_group_slug text)
+returns text as $$
+    select coalesce(t.slug_pretty, t.slug)
+    from "group" g
+    join "group" t on t.group_id = g.merged_into_group_id
+    where g.community_id = p_community_id
+    and (g.slug = p_group_slug or g.slug_pretty = p_group_slug)
+    and t.active = true
+    and t.deleted = false;
+$$ language sql;
@@ -1,29 +1,78 @@
--- Join a group as a member.
+-- Join a group as a member, or request to join when the group is private,
+-- storing the answers to the group join questions.
 create or replace function join_group(
     p_community_id uuid,
     p_group_id uuid,
-    p_user_id uuid
-) returns void as $$
+    p_user_id uuid,
+    p_join_answers jsonb
+) returns text as $$
+declare
+    v_archived boolean;
+    v_private boolean;
 begin
     -- Check if group exists, is active and not deleted
-    if not exists (
+    select archived_at is not null, private
+    into v_archived, v_private
+    from "group"
+    where group_id = p_group_id
+    and community_id = p_community_id
+    and active = true
+    and deleted = false;
+
+    if not found then
+        raise exception 'group not found or inactive';
+    end if;
+
+    -- Archived groups do not accept new members
+    if v_archived then
+        raise exception 'group is archived';
+    end if;
+
+    -- Banned users cannot rejoin the group
+    perform ensure_user_is_not_banned_from_group(p_group_id, p_user_id);
+
+    -- Users must have an email domain allowed by the community
+    perform ensure_user_email_domain_allowed(p_community_id, p_user_id);
+
+    -- Make sure the user is not a member already
+    if exists (
         select 1
-        from "group"
+        from group_member
         where group_id = p_group_id
-        and community_id = p_community_id
-        and active = true
-        and deleted = false
+        and user_id = p_user_id
     ) then
-        raise exception 'group not found or inactive';
+        raise exception 'user is already a member of this group';
+    end if;
+
+    -- Queue a join request for the organizers to review in private groups
+    if v_private then
+        insert into group_join_request (group_id, user_id, join_answers)
+        values (p_group_id, p_user_id, p_join_answers)
+        on conflict (group_id, user_id) do update
+        set
+            created_at = current_timestamp,
+            join_answers = excluded.join_answers,
+            reviewed_at = null,
+            reviewed_by = null,
+            status = 'pending'
+        where group_join_request.status <> 'pending';
+
+        if not found then
+            raise exception 'user has already requested to join this group';
+        end if;
+
+        return 'pending-approval';
     end if;
 
     -- Add user to group
     begin
-        insert into group_member (group_id, user_id)
-        values (p_group_id, p_user_id);
+        insert into group_member (group_id, user_id, join_answers)
+        values (p_group_id, p_user_id, p_join_answers);
     exception
         when unique_violation then
             raise exception 'user is already a member of this group';
     end;
+
+    return 'member';
 end;
 $$ language plpgsql;
@@ -1,4 +1,4 @@
--- Leave a group as a member.
+-- Leave a group as a member, or withdraw a pending join request.
 create or replace function leave_group(
     p_community_id uuid,
     p_group_id uuid,
@@ -22,6 +22,16 @@ begin
     where group_id = p_group_id
     and user_id = p_user_id;
 
+    if found then
+        return;
+    end if;
+
+    -- Withdraw the pending join request, if any
+    delete from group_join_request
+    where group_id = p_group_id
+    and user_id = p_user_id
+    and status = 'pending';
+
     if not found then
         raise exception 'user is not a member of this group';
     end if;
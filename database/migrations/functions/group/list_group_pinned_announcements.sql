@@ -0,0 +1,26 @@
+-- Returns the announcements pinned on the group page, newest first.
+create or replace function list_group_pinned_announcements(
+    p_community_id uuid,
+    p_group_id uuid
+)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_build_object(
+                'content', ga.content,
+                'created_at', floor(extract(epoch from ga.created_at)),
+                'group_announcement_id', ga.group_announcement_id,
+                'pinned', ga.pinned,
+                'title', ga.title
+            )
+            order by ga.created_at desc, ga.group_announcement_id
+        ),
+        '[]'::json
+    )
+    from group_announcement ga
+    join "group" g using (group_id)
+    where ga.group_id = p_group_id
+    and ga.pinned = true
+    and g.community_id = p_community_id
+    and g.deleted = false;
+$$ language sql;
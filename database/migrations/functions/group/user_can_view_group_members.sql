@@ -0,0 +1,24 @@
+-- Checks whether a user can see the members content of a group.
+--
+-- Content about who takes part in a private group, like the members and
+-- attendees counts, is only available to its members and team.
+create or replace function user_can_view_group_members(
+    p_community_id uuid,
+    p_group_id uuid,
+    p_user_id uuid
+) returns boolean as $$
+    select exists (
+        select 1
+        from "group" g
+        where g.community_id = p_community_id
+        and g.group_id = p_group_id
+        and g.private = false
+    )
+    or (
+        p_user_id is not null
+        and (
+            is_group_member(p_community_id, p_group_id, p_user_id)
+            or user_has_group_permission(p_community_id, p_group_id, p_user_id, 'group.read')
+        )
+    );
+$$ language sql;
@@ -60,6 +60,7 @@ begin
             join_url,
             password,
             event_id,
+            group_id,
             session_id
         )
         values (
@@ -69,6 +70,14 @@ begin
             p_url,
             p_password,
             p_event_id,
+            (
+                select e.group_id
+                from event e
+                left join session s on s.event_id = e.event_id
+                where e.event_id = p_event_id
+                   or s.session_id = p_session_id
+                limit 1
+            ),
             p_session_id
         );
     end if;
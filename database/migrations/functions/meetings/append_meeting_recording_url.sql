@@ -1,7 +1,9 @@
--- Appends a raw recording URL for a meeting.
+-- Appends a raw recording URL for a meeting. When a group is provided, only
+-- meetings of that group are updated.
 create or replace function append_meeting_recording_url(
     p_meeting_provider_id text,
     p_provider_meeting_id text,
+    p_group_id uuid,
     p_recording_url text
 ) returns void as $$
     -- Normalize input and skip blanks
@@ -14,6 +16,7 @@ create or replace function append_meeting_recording_url(
     from input
     where meeting_provider_id = p_meeting_provider_id
       and provider_meeting_id = p_provider_meeting_id
+      and (p_group_id is null or group_id = p_group_id)
       and input.recording_url is not null
       -- Keep raw recording URLs unique per meeting
       and array_position(recording_urls, input.recording_url) is null;
@@ -8,8 +8,9 @@ begin
     -- Lock and claim one eligible overdue event-backed meeting first
     with next_meeting as (
         select
-            m.meeting_id,
-            e.ends_at
+            e.ends_at,
+            e.group_id,
+            m.meeting_id
         from meeting m
         join event e on e.event_id = m.event_id
         where m.auto_end_check_at is null
@@ -37,11 +38,13 @@ begin
             m.auto_end_check_claimed_at,
             m.meeting_id,
             m.meeting_provider_id,
-            m.provider_meeting_id
+            m.provider_meeting_id,
+            nm.group_id
     )
     select
         jsonb_build_object(
             'auto_end_check_claimed_at', cm.auto_end_check_claimed_at,
+            'group_id', cm.group_id,
             'meeting_id', cm.meeting_id,
             'meeting_provider_id', cm.meeting_provider_id,
             'provider_meeting_id', cm.provider_meeting_id
@@ -56,6 +59,7 @@ begin
     -- If no event-backed meeting is eligible, claim one session-backed meeting
     with next_meeting as (
         select
+            e.group_id,
             m.meeting_id,
             s.ends_at
         from meeting m
@@ -86,11 +90,13 @@ begin
             m.auto_end_check_claimed_at,
             m.meeting_id,
             m.meeting_provider_id,
-            m.provider_meeting_id
+            m.provider_meeting_id,
+            nm.group_id
     )
     select
         jsonb_build_object(
             'auto_end_check_claimed_at', cm.auto_end_check_claimed_at,
+            'group_id', cm.group_id,
             'meeting_id', cm.meeting_id,
             'meeting_provider_id', cm.meeting_provider_id,
             'provider_meeting_id', cm.provider_meeting_id
@@ -0,0 +1,126 @@
+-- claim_meeting_for_recording_sync claims one ended meeting whose cloud
+-- recordings should be fetched from the provider. It currently filters
+-- candidates to Zoom meetings. Claims that are not completed expire after 30
+-- minutes, which also sets the polling interval while recordings are processed.
+create or replace function claim_meeting_for_recording_sync()
+returns jsonb as $$
+declare
+    v_claimed_meeting jsonb;
+begin
+    -- Lock and claim one eligible ended event-backed meeting first
+    with next_meeting as (
+        select
+            e.event_id,
+            e.group_id,
+            m.meeting_id
+        from meeting m
+        join event e on e.event_id = m.event_id
+        where m.recording_synced_at is null
+          and (
+              m.recording_sync_claimed_at is null
+              or m.recording_sync_claimed_at < current_timestamp - interval '30 minutes'
+          )
+          and m.meeting_provider_id = 'zoom'
+          and e.canceled = false
+          and e.deleted = false
+          and e.ends_at is not null
+          and e.meeting_recording_requested = true
+          and e.published = true
+          -- Give the provider time to process the recording, but stop polling old meetings
+          and e.ends_at + interval '15 minutes' <= current_timestamp
+          and e.ends_at > current_timestamp - interval '3 days'
+        order by e.ends_at desc
+        for update of m skip locked
+        limit 1
+    ),
+    claimed_meeting as (
+        update meeting m
+        set
+            recording_sync_claimed_at = current_timestamp,
+            updated_at = current_timestamp
+        from next_meeting nm
+        where m.meeting_id = nm.meeting_id
+        returning
+            m.meeting_id,
+            m.meeting_provider_id,
+            m.provider_meeting_id,
+            m.recording_sync_claimed_at,
+            nm.event_id,
+            nm.group_id
+    )
+    select
+        jsonb_build_object(
+            'event_id', cm.event_id,
+            'group_id', cm.group_id,
+            'meeting_id', cm.meeting_id,
+            'meeting_provider_id', cm.meeting_provider_id,
+            'provider_meeting_id', cm.provider_meeting_id,
+            'recording_sync_claimed_at', cm.recording_sync_claimed_at
+        )
+    into v_claimed_meeting
+    from claimed_meeting cm;
+
+    if v_claimed_meeting is not null then
+        return v_claimed_meeting;
+    end if;
+
+    -- If no event-backed meeting is eligible, claim one session-backed meeting
+    with next_meeting as (
+        select
+            e.event_id,
+            e.group_id,
+            m.meeting_id,
+            s.session_id
+        from meeting m
+        join session s on s.session_id = m.session_id
+        join event e on e.event_id = s.event_id
+        where m.recording_synced_at is null
+          and (
+              m.recording_sync_claimed_at is null
+              or m.recording_sync_claimed_at < current_timestamp - interval '30 minutes'
+          )
+          and m.meeting_provider_id = 'zoom'
+          and s.ends_at is not null
+          and e.canceled = false
+          and e.deleted = false
+          and e.meeting_recording_requested = true
+          and e.published = true
+          -- Give the provider time to process the recording, but stop polling old meetings
+          and s.ends_at + interval '15 minutes' <= current_timestamp
+          and s.ends_at > current_timestamp - interval '3 days'
+        order by s.ends_at desc
+        for update of m skip locked
+        limit 1
+    ),
+    claimed_meeting as (
+        update meeting m
+        set
+            recording_sync_claimed_at = current_timestamp,
+            updated_at = current_timestamp
+        from next_meeting nm
+        where m.meeting_id = nm.meeting_id
+        returning
+            m.meeting_id,
+            m.meeting_provider_id,
+            m.provider_meeting_id,
+            m.recording_sync_claimed_at,
+            nm.event_id,
+            nm.group_id,
+            nm.session_id
+    )
+    select
+        jsonb_build_object(
+            'event_id', cm.event_id,
+            'group_id', cm.group_id,
+            'meeting_id', cm.meeting_id,
+            'meeting_provider_id', cm.meeting_provider_id,
+            'provider_meeting_id', cm.provider_meeting_id,
+            'recording_sync_claimed_at', cm.recording_sync_claimed_at,
+            'session_id', cm.session_id
+        )
+    into v_claimed_meeting
+    from claimed_meeting cm;
+
+    return v_claimed_meeting;
+end;
+$$ language plpgsql;
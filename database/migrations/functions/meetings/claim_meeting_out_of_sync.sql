@@ -1,5 +1,6 @@
--- claim_meeting_out_of_sync claims one meeting that needs synchronization.
-create or replace function claim_meeting_out_of_sync()
+-- claim_meeting_out_of_sync claims one meeting that needs synchronization,
+-- skipping meetings handled by the paused providers provided.
+create or replace function claim_meeting_out_of_sync(p_paused_providers text[] default '{}')
 returns jsonb as $$
 declare
     v_claimed_meeting jsonb;
@@ -15,6 +16,7 @@ begin
           and e.canceled = false
           and e.published = true
           and e.starts_at > current_timestamp
+          and e.meeting_provider_id <> all(p_paused_providers)
         for update skip locked
         limit 1
     ),
@@ -31,6 +33,7 @@ begin
         'delete', false,
         'duration_secs', extract(epoch from ce.ends_at - ce.starts_at)::double precision,
         'event_id', ce.event_id,
+        'group_id', ce.group_id,
         'hosts', (
             select array_agg(distinct email order by email) filter (where email is not null)
             from (
@@ -52,7 +55,8 @@ begin
         'sync_claimed_at', ce.meeting_sync_claimed_at,
         'sync_state_hash', get_event_meeting_sync_state_hash(ce.event_id),
         'timezone', ce.timezone,
-        'topic', ce.name
+        'topic', ce.name,
+        'webinar', ce.meeting_webinar
     ))
     into v_claimed_meeting
     from claimed_event ce
@@ -74,6 +78,7 @@ begin
           and e.canceled = false
           and e.published = true
           and s.starts_at > current_timestamp
+          and s.meeting_provider_id <> all(p_paused_providers)
         for update of s skip locked
         limit 1
     ),
@@ -89,6 +94,7 @@ begin
     select jsonb_strip_nulls(jsonb_build_object(
         'delete', false,
         'duration_secs', extract(epoch from cs.ends_at - cs.starts_at)::double precision,
+        'group_id', e.group_id,
         'hosts', (
             select array_agg(distinct email order by email) filter (where email is not null)
             from (
@@ -132,6 +138,12 @@ begin
               (e.meeting_requested = true and (e.deleted = true or e.canceled = true or e.published = false))
               or e.meeting_requested = false
           )
+          and not exists (
+              select 1
+              from meeting m
+              where m.event_id = e.event_id
+                and m.meeting_provider_id = any(p_paused_providers)
+          )
         for update of e skip locked
         limit 1
     ),
@@ -147,6 +159,7 @@ begin
     select jsonb_strip_nulls(jsonb_build_object(
         'delete', true,
         'event_id', ce.event_id,
+        'group_id', ce.group_id,
         'join_url', m.join_url,
         'meeting_id', m.meeting_id,
         'meeting_provider_id', m.meeting_provider_id,
@@ -175,6 +188,12 @@ begin
             (s.meeting_requested = true and (e.deleted = true or e.canceled = true or e.published = false))
             or s.meeting_requested = false
           )
+          and not exists (
+              select 1
+              from meeting m
+              where m.session_id = s.session_id
+                and m.meeting_provider_id = any(p_paused_providers)
+          )
         for update of s skip locked
         limit 1
     ),
@@ -189,6 +208,7 @@ begin
     )
     select jsonb_strip_nulls(jsonb_build_object(
         'delete', true,
+        'group_id', e.group_id,
         'join_url', m.join_url,
         'meeting_id', m.meeting_id,
         'meeting_provider_id', m.meeting_provider_id,
@@ -201,6 +221,7 @@ begin
     ))
     into v_claimed_meeting
     from claimed_session cs
+    join event e on e.event_id = cs.event_id
     left join meeting m on m.session_id = cs.session_id;
 
     if v_claimed_meeting is not null then
@@ -214,6 +235,7 @@ begin
         where m.event_id is null
           and m.session_id is null
           and m.sync_claimed_at is null
+          and m.meeting_provider_id <> all(p_paused_providers)
         for update skip locked
         limit 1
     ),
@@ -228,6 +250,7 @@ begin
     )
     select jsonb_strip_nulls(jsonb_build_object(
         'delete', true,
+        'group_id', cm.group_id,
         'join_url', cm.join_url,
         'meeting_id', cm.meeting_id,
         'meeting_provider_id', cm.meeting_provider_id,
@@ -26,6 +26,7 @@ create or replace function get_event_meeting_sync_state_hash(
                 'meeting_provider_id', e.meeting_provider_id,
                 'meeting_recording_requested', e.meeting_recording_requested,
                 'meeting_requested', e.meeting_requested,
+                'meeting_webinar', e.meeting_webinar,
                 'name', e.name,
                 'published', e.published,
                 'starts_at', e.starts_at,
@@ -0,0 +1,12 @@
+-- Returns the Zoom credentials configured for a group, if any.
+create or replace function get_group_zoom_credentials(p_group_id uuid)
+returns json as $$
+    select json_build_object(
+        'account_id', gzc.account_id,
+        'client_id', gzc.client_id,
+        'client_secret', gzc.client_secret,
+        'host_user', gzc.host_user
+    )
+    from group_zoom_credentials gzc
+    where gzc.group_id = p_group_id;
+$$ language sql;
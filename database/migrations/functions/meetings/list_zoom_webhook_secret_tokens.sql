@@ -0,0 +1,19 @@
+-- Returns the webhook secret tokens of the group Zoom apps in an account, or
+-- of all of them when no account is provided, along with the group owning
+-- each app.
+create or replace function list_zoom_webhook_secret_tokens(p_account_id text)
+returns json as $$
+    select coalesce(
+        json_agg(
+            json_build_object(
+                'group_id', gzc.group_id,
+                'secret_token', gzc.webhook_secret_token
+            )
+            order by gzc.group_id
+        ),
+        '[]'::json
+    )
+    from group_zoom_credentials gzc
+    where gzc.webhook_secret_token is not null
+    and (p_account_id is null or gzc.account_id = p_account_id);
+$$ language sql;
@@ -0,0 +1,14 @@
+-- record_meeting_participant_joined counts a participant joining a meeting.
+-- When a group is provided, only meetings of that group are updated.
+create or replace function record_meeting_participant_joined(
+    p_meeting_provider_id text,
+    p_provider_meeting_id text,
+    p_group_id uuid
+) returns void as $$
+    update meeting
+    set participants_joined_count = participants_joined_count + 1,
+        updated_at = current_timestamp
+    where meeting_provider_id = p_meeting_provider_id
+      and provider_meeting_id = p_provider_meeting_id
+      and (p_group_id is null or group_id = p_group_id);
+$$ language sql;
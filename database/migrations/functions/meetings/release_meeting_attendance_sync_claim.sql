@@ -0,0 +1,13 @@
+-- release_meeting_attendance_sync_claim releases a retryable attendance sync claim.
+create or replace function release_meeting_attendance_sync_claim(
+    p_claimed_at timestamptz,
+    p_meeting_id uuid
+) returns void as $$
+    update meeting
+    set
+        attendance_sync_claimed_at = null,
+        updated_at = current_timestamp
+    where meeting_id = p_meeting_id
+      and attendance_synced_at is null
+      and attendance_sync_claimed_at = p_claimed_at;
+$$ language sql;
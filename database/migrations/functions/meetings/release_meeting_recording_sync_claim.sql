@@ -0,0 +1,13 @@
+-- release_meeting_recording_sync_claim releases a retryable recording sync claim.
+create or replace function release_meeting_recording_sync_claim(
+    p_claimed_at timestamptz,
+    p_meeting_id uuid
+) returns void as $$
+    update meeting
+    set
+        recording_sync_claimed_at = null,
+        updated_at = current_timestamp
+    where meeting_id = p_meeting_id
+      and recording_synced_at is null
+      and recording_sync_claimed_at = p_claimed_at;
+$$ language sql;
@@ -0,0 +1,49 @@
+-- set_meeting_attendance_synced checks in the event attendees that joined the
+-- meeting and completes the attendance sync claim. Returns the number of
+-- attendees newly checked in.
+create or replace function set_meeting_attendance_synced(
+    p_claimed_at timestamptz,
+    p_meeting_id uuid,
+    p_event_id uuid,
+    p_participants_emails text[]
+) returns integer as $$
+declare
+    v_checked_in_count integer := 0;
+begin
+    -- Complete the claim only when the worker still holds it
+    update meeting
+    set
+        attendance_sync_claimed_at = null,
+        attendance_synced_at = current_timestamp,
+        updated_at = current_timestamp
+    where meeting_id = p_meeting_id
+      and attendance_synced_at is null
+      and attendance_sync_claimed_at = p_claimed_at;
+    if not found then
+        return 0;
+    end if;
+
+    -- Check in confirmed attendees whose email matches a meeting participant
+    with checked_in_attendees as (
+        update event_attendee ea
+        set
+            checked_in = true,
+            checked_in_at = current_timestamp
+        from "user" u
+        where u.user_id = ea.user_id
+          and ea.event_id = p_event_id
+          and ea.status = 'confirmed'
+          and ea.checked_in = false
+          and lower(u.email) in (
+              select lower(btrim(participant_email))
+              from unnest(p_participants_emails) as participant_email
+          )
+        returning 1
+    )
+    select count(*)::integer
+    into v_checked_in_count
+    from checked_in_attendees;
+
+    return v_checked_in_count;
+end;
+$$ language plpgsql;
@@ -0,0 +1,62 @@
+-- set_meeting_recordings_synced stores the provider recording URLs of a meeting
+-- and completes the recording sync claim. The first URL becomes the event or
+-- session recording URL when organizers have not set one yet.
+create or replace function set_meeting_recordings_synced(
+    p_claimed_at timestamptz,
+    p_meeting_id uuid,
+    p_recording_urls text[]
+) returns void as $$
+declare
+    v_event_id uuid;
+    v_recording_url text;
+    v_session_id uuid;
+begin
+    -- Complete the claim and store new raw recording URLs while the worker still holds it
+    update meeting m
+    set
+        recording_sync_claimed_at = null,
+        recording_synced_at = current_timestamp,
+        recording_urls = m.recording_urls || array(
+            select distinct u.recording_url
+            from (
+                select nullif(btrim(recording_url), '') as recording_url
+                from unnest(p_recording_urls) as recording_url
+            ) u
+            where u.recording_url is not null
+              and array_position(m.recording_urls, u.recording_url) is null
+        ),
+        updated_at = current_timestamp
+    where m.meeting_id = p_meeting_id
+      and m.recording_synced_at is null
+      and m.recording_sync_claimed_at = p_claimed_at
+    returning m.event_id, m.session_id
+    into v_event_id, v_session_id;
+    if not found then
+        return;
+    end if;
+
+    -- Pick the first provided recording URL
+    select nullif(btrim(recording_url), '')
+    into v_recording_url
+    from unnest(p_recording_urls) with ordinality as r(recording_url, position)
+    where nullif(btrim(recording_url), '') is not null
+    order by position
+    limit 1;
+    if v_recording_url is null then
+        return;
+    end if;
+
+    -- Keep any recording URL already set by organizers
+    if v_event_id is not null then
+        update event
+        set meeting_recording_url = v_recording_url
+        where event_id = v_event_id
+          and meeting_recording_url is null;
+    elsif v_session_id is not null then
+        update session
+        set meeting_recording_url = v_recording_url
+        where session_id = v_session_id
+          and meeting_recording_url is null;
+    end if;
+end;
+$$ language plpgsql;
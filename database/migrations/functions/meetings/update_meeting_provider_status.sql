@@ -0,0 +1,44 @@
+-- update_meeting_provider_status records a lifecycle status reported by the
+-- meetings provider. When a group is provided, only meetings of that group are
+-- updated.
+create or replace function update_meeting_provider_status(
+    p_meeting_provider_id text,
+    p_provider_meeting_id text,
+    p_group_id uuid,
+    p_status text,
+    p_occurred_at timestamptz
+) returns void as $$
+declare
+    v_event_id uuid;
+    v_session_id uuid;
+begin
+    -- Update the status, ignoring notifications older than the current one
+    update meeting
+    set provider_status = p_status,
+        provider_status_updated_at = coalesce(p_occurred_at, current_timestamp),
+        updated_at = current_timestamp
+    where meeting_provider_id = p_meeting_provider_id
+      and provider_meeting_id = p_provider_meeting_id
+      and (p_group_id is null or group_id = p_group_id)
+      and (
+          provider_status_updated_at is null
+          or provider_status_updated_at <= coalesce(p_occurred_at, current_timestamp)
+      )
+    returning event_id, session_id into v_event_id, v_session_id;
+
+    -- Surface meetings deleted on the provider side to organizers
+    if found and p_status = 'deleted' then
+        if v_event_id is not null then
+            update event
+            set meeting_error = 'Meeting was deleted in the meetings provider'
+            where event_id = v_event_id;
+        end if;
+
+        if v_session_id is not null then
+            update session
+            set meeting_error = 'Meeting was deleted in the meetings provider'
+            where session_id = v_session_id;
+        end if;
+    end if;
+end;
+$$ language plpgsql;
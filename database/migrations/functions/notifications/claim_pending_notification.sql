@@ -5,12 +5,19 @@ create or replace function claim_pending_notification(
 )
 returns table (
     attachment_ids uuid[],
+    channel text,
     delivery_claimed_at timestamptz,
+    discord_webhook_url text,
     email text,
+    email_branding jsonb,
     kind text,
+    language text,
     notification_id uuid,
-
-    template_data jsonb
+    phone_number text,
+    slack_webhook_url text,
+    template_data jsonb,
+    webhook_secret text,
+    webhook_url text
 ) as $$
 begin
     -- Check rate limit params are valid
@@ -40,16 +47,20 @@ begin
     with next_notification as (
         select n.notification_id
         from notification n
-        join "user" u using (user_id)
+        left join "user" u using (user_id)
         where n.delivery_status = 'pending'
         and (
             n.next_delivery_attempt_at is null
             or n.next_delivery_attempt_at <= current_timestamp
         )
         and (
-            (u.registration_status = 'registered' and u.email_verified = true)
+            n.channel <> 'email'
+            or (u.registration_status = 'registered' and u.email_verified = true)
             or n.kind = 'email-verification'
-            or (n.kind = 'event-invitation' and u.registration_status = 'pre-registered')
+            or (
+                n.kind in ('event-invitation', 'group-team-invitation')
+                and u.registration_status = 'pre-registered'
+            )
         )
         order by n.created_at asc
         limit 1
@@ -68,7 +79,10 @@ begin
         from next_notification nn
         where n.notification_id = nn.notification_id
         returning
+            n.channel,
+            n.community_id,
             n.delivery_claimed_at,
+            n.group_id,
             n.kind,
             n.notification_id,
             n.notification_template_data_id,
@@ -81,14 +95,59 @@ begin
             from notification_attachment na
             where na.notification_id = cn.notification_id
         ) as attachment_ids,
+        cn.channel,
         cn.delivery_claimed_at,
+        g.discord_webhook_url,
         u.email,
+        -- Emails are rendered with the branding of the community they belong to
+        case
+            when cn.channel = 'email' then nullif(
+                jsonb_strip_nulls(
+                    jsonb_build_object(
+                        'footer_text', b.email_footer_text,
+                        'from_name', b.email_from_name,
+                        'logo_url', b.email_logo_url,
+                        'primary_color', b.email_primary_color
+                    )
+                ),
+                '{}'::jsonb
+            )
+        end as email_branding,
         cn.kind,
+        u.language,
         cn.notification_id,
-
-        ntd.data as template_data
+        -- Codes are sent to unverified numbers, everything else requires opting in
+        case
+            when cn.channel = 'sms' and (
+                cn.kind = 'phone-verification'
+                or (u.phone_number_verified and u.sms_notifications_enabled)
+            ) then u.phone_number
+        end as phone_number,
+        c.slack_webhook_url,
+        ntd.data as template_data,
+        u.webhook_secret,
+        u.webhook_url
     from claimed_notification cn
-    join "user" u using (user_id)
-    left join notification_template_data ntd using (notification_template_data_id);
+    left join "user" u using (user_id)
+    left join community c on c.community_id = cn.community_id
+    left join "group" g on g.group_id = cn.group_id
+    left join notification_template_data ntd using (notification_template_data_id)
+    left join lateral (
+        select
+            bc.email_footer_text,
+            bc.email_from_name,
+            bc.email_logo_url,
+            bc.email_primary_color
+        from community bc
+        where bc.community_id = coalesce(cn.community_id, g.community_id)
+        or (
+            coalesce(cn.community_id, g.community_id) is null
+            and bc.name = coalesce(
+                ntd.data->'event'->>'community_name',
+                ntd.data->'group'->>'community_name',
+                ntd.data->'events'->0->'event'->>'community_name'
+            )
+        )
+    ) b on true;
 end;
 $$ language plpgsql;
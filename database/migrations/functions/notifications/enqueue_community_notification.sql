@@ -0,0 +1,36 @@
+-- enqueue_community_notification inserts notifications for the community channels.
+create or replace function enqueue_community_notification(
+    p_community_id uuid,
+    p_kind text,
+    p_template_data jsonb
+)
+returns void as $$
+declare
+    v_notification_template_data_id uuid;
+    v_template_hash text;
+begin
+    -- Skip communities without any notification channel configured
+    if not exists (
+        select 1
+        from community
+        where community_id = p_community_id
+        and slack_webhook_url is not null
+    ) then
+        return;
+    end if;
+
+    -- Insert or reuse template data and get its ID
+    if p_template_data is not null then
+        v_template_hash := encode(digest(convert_to(p_template_data::text, 'utf8'), 'sha256'), 'hex');
+
+        insert into notification_template_data (data, hash)
+        values (p_template_data, v_template_hash)
+        on conflict (hash) do update set hash = notification_template_data.hash
+        returning notification_template_data_id into v_notification_template_data_id;
+    end if;
+
+    -- Insert the Slack channel notification
+    insert into notification (channel, community_id, kind, notification_template_data_id)
+    values ('slack', p_community_id, p_kind, v_notification_template_data_id);
+end;
+$$ language plpgsql;
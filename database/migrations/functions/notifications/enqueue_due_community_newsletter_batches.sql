@@ -0,0 +1,118 @@
+-- enqueue_due_community_newsletter_batches enqueues the next batch of pending
+-- recipients of the sent community newsletter issues, and returns the number
+-- of notifications created. Each call enqueues up to p_batch_size recipients
+-- across all issues, oldest issues first.
+create or replace function enqueue_due_community_newsletter_batches(
+    p_base_url text,
+    p_batch_size int
+)
+returns int as $$
+declare
+    v_base_url text;
+    v_issue record;
+    v_notification_ids uuid[];
+    v_notifications_enqueued int := 0;
+    v_recipients uuid[];
+    v_remaining int := p_batch_size;
+begin
+    -- Check the batch size is valid
+    if p_batch_size <= 0 then
+        raise exception 'batch size must be positive';
+    end if;
+
+    -- Ensure only one worker enqueues newsletter batches per transaction window
+    if not pg_try_advisory_xact_lock(hashtextextended('ocg:community-newsletter-enqueue', 0)) then
+        return 0;
+    end if;
+
+    -- Normalize base URL used to build links
+    v_base_url := regexp_replace(coalesce(p_base_url, ''), '/+$', '');
+
+    -- Fetch and lock the sent issues that still have recipients to enqueue
+    for v_issue in
+        select
+            cni.body,
+            cni.community_id,
+            cni.community_newsletter_issue_id,
+            cni.subject,
+            c.display_name as community_display_name,
+            c.name as community_name,
+            s.theme
+        from community_newsletter_issue cni
+        join community c on c.community_id = cni.community_id
+        left join lateral (
+            select site.theme
+            from site
+            order by site.created_at desc
+            limit 1
+        ) s on true
+        where cni.sent_at is not null
+        and exists (
+            select 1
+            from community_newsletter_issue_recipient cnir
+            where cnir.community_newsletter_issue_id = cni.community_newsletter_issue_id
+            and cnir.enqueued_at is null
+        )
+        order by cni.sent_at, cni.community_newsletter_issue_id
+        for update of cni skip locked
+    loop
+        exit when v_remaining <= 0;
+
+        -- Take the next batch of pending recipients of the issue
+        with batch as (
+            select cnir.user_id
+            from community_newsletter_issue_recipient cnir
+            where cnir.community_newsletter_issue_id = v_issue.community_newsletter_issue_id
+            and cnir.enqueued_at is null
+            order by cnir.user_id
+            limit v_remaining
+            for update
+        ),
+        enqueued as (
+            update community_newsletter_issue_recipient cnir
+            set enqueued_at = current_timestamp
+            from batch b
+            where cnir.community_newsletter_issue_id = v_issue.community_newsletter_issue_id
+            and cnir.user_id = b.user_id
+            returning cnir.user_id
+        )
+        select array_agg(e.user_id order by e.user_id)
+        into v_recipients
+        from enqueued e;
+
+        v_remaining := v_remaining - cardinality(v_recipients);
+
+        -- Enqueue the issue to the batch recipients
+        v_notification_ids := enqueue_notification(
+            'community-newsletter',
+            jsonb_strip_nulls(
+                jsonb_build_object(
+                    'body', v_issue.body,
+                    'community_display_name', v_issue.community_display_name,
+                    'link', format('%s/%s', v_base_url, v_issue.community_name),
+                    'newsletter_link', format(
+                        '%s/%s/newsletter/%s',
+                        v_base_url,
+                        v_issue.community_name,
+                        v_issue.community_newsletter_issue_id
+                    ),
+                    'subject', v_issue.subject,
+                    'theme', v_issue.theme
+                )
+            ),
+            '[]'::jsonb,
+            v_recipients
+        );
+
+        -- Link the notifications to the community and the issue for branding and stats
+        update notification set
+            community_id = v_issue.community_id,
+            community_newsletter_issue_id = v_issue.community_newsletter_issue_id
+        where notification_id = any(v_notification_ids);
+
+        v_notifications_enqueued := v_notifications_enqueued + cardinality(v_notification_ids);
+    end loop;
+
+    return v_notifications_enqueued;
+end;
+$$ language plpgsql;
@@ -0,0 +1,106 @@
+-- enqueue_due_dormant_groups_reports enqueues the monthly report of dormant
+-- groups to the admins of the communities that have it enabled.
+create or replace function enqueue_due_dormant_groups_reports(p_base_url text)
+returns int as $$
+declare
+    v_base_url text;
+    v_community record;
+    v_groups jsonb;
+    v_recipients uuid[];
+    v_reports_enqueued int := 0;
+begin
+    -- Ensure only one worker enqueues due reports per transaction window
+    if not pg_try_advisory_xact_lock(hashtextextended('ocg:dormant-groups-report-enqueue', 0)) then
+        return 0;
+    end if;
+
+    -- Normalize base URL used to build links
+    v_base_url := regexp_replace(coalesce(p_base_url, ''), '/+$', '');
+
+    -- Fetch and lock communities whose dormant groups report is due
+    for v_community in
+        select
+            c.community_id,
+            c.display_name,
+            c.dormant_group_months,
+            c.name,
+            s.theme
+        from community c
+        left join lateral (
+            select site.theme
+            from site
+            order by site.created_at desc
+            limit 1
+        ) s on true
+        where c.active = true
+        and c.dormant_groups_report_enabled = true
+        and (
+            c.dormant_groups_report_sent_at is null
+            or c.dormant_groups_report_sent_at <= current_timestamp - interval '1 month'
+        )
+        order by c.community_id
+        for update of c skip locked
+    loop
+        -- Build the report items of the community dormant groups
+        select coalesce(
+            jsonb_agg(
+                jsonb_build_object(
+                    'dormant_since', floor(extract(epoch from g.dormant_since)),
+                    'link', format(
+                        '%s/%s/group/%s',
+                        v_base_url,
+                        v_community.name,
+                        coalesce(g.slug_pretty, g.slug)
+                    ),
+                    'name', g.name
+                )
+                order by g.dormant_since, g.name
+            ),
+            '[]'::jsonb
+        )
+        into v_groups
+        from "group" g
+        where g.community_id = v_community.community_id
+        and g.active = true
+        and g.archived_at is null
+        and g.deleted = false
+        and g.dormant_since is not null;
+
+        -- Enqueue the report to the community admins when there is something to report
+        if jsonb_array_length(v_groups) > 0 then
+            select array_agg(ct.user_id order by ct.user_id)
+            into v_recipients
+            from community_team ct
+            join "user" u using (user_id)
+            where ct.community_id = v_community.community_id
+            and ct.accepted = true
+            and ct.role = 'admin'
+            and u.email_verified = true;
+
+            if cardinality(v_recipients) > 0 then
+                perform enqueue_notification(
+                    'dormant-groups-report',
+                    jsonb_strip_nulls(
+                        jsonb_build_object(
+                            'community_display_name', v_community.display_name,
+                            'dormant_group_months', v_community.dormant_group_months,
+                            'groups', v_groups,
+                            'link', format('%s/dashboard/community?tab=groups&dormant=true', v_base_url),
+                            'theme', v_community.theme
+                        )
+                    ),
+                    '[]'::jsonb,
+                    v_recipients
+                );
+                v_reports_enqueued := v_reports_enqueued + 1;
+            end if;
+        end if;
+
+        -- Mark the community report as sent, even when there was nothing to send
+        update community set dormant_groups_report_sent_at = current_timestamp
+        where community_id = v_community.community_id;
+    end loop;
+
+    return v_reports_enqueued;
+end;
+$$ language plpgsql;
@@ -0,0 +1,98 @@
+-- enqueue_due_event_attendance_certificates enqueues an email with the
+-- certificate of attendance for checked-in attendees of the events that
+-- ended recently and have certificate emails enabled.
+create or replace function enqueue_due_event_attendance_certificates(p_base_url text)
+returns int as $$
+declare
+    v_base_url text;
+    v_certificates_enqueued int := 0;
+    v_event record;
+    v_recipient uuid;
+    v_template_data jsonb;
+begin
+    -- Ensure only one worker enqueues due certificates per transaction window
+    if not pg_try_advisory_xact_lock(
+        hashtextextended('ocg:event-attendance-certificate-enqueue', 0)
+    ) then
+        return 0;
+    end if;
+
+    -- Normalize base URL used to build dashboard links
+    v_base_url := regexp_replace(coalesce(p_base_url, ''), '/+$', '');
+
+    -- Fetch and lock events that ended in the last week and still need certificates
+    for v_event in
+        select
+            c.community_id,
+            e.event_id,
+            g.group_id,
+            s.theme
+        from event e
+        join "group" g using (group_id)
+        join community c on c.community_id = g.community_id
+        left join lateral (
+            select site.theme
+            from site
+            order by site.created_at desc
+            limit 1
+        ) s on true
+        where c.active = true
+        and e.deleted = false
+        and e.canceled = false
+        and e.published = true
+        and e.test_event = false
+        and g.active = true
+        and g.deleted = false
+        and e.attendance_certificate_emails_enabled = true
+        and e.attendance_certificates_sent_at is null
+        and e.starts_at is not null
+        and coalesce(e.ends_at, e.starts_at) <= current_timestamp
+        and coalesce(e.ends_at, e.starts_at) > current_timestamp - interval '7 days'
+        order by coalesce(e.ends_at, e.starts_at) asc, e.event_id asc
+        for update of e skip locked
+    loop
+        -- Enqueue one certificate per verified attendee who checked in
+        for v_recipient in
+            select ea.user_id
+            from event_attendee ea
+            join "user" u using (user_id)
+            where ea.event_id = v_event.event_id
+            and ea.status = 'confirmed'
+            and ea.checked_in = true
+            and u.email_verified = true
+            order by ea.user_id
+        loop
+            v_template_data := jsonb_strip_nulls(
+                jsonb_build_object(
+                    'certificate',
+                        get_user_attendance_certificate(v_recipient, v_event.event_id)::jsonb,
+                    'event',
+                        get_event_summary(
+                            v_event.community_id,
+                            v_event.group_id,
+                            v_event.event_id
+                        )::jsonb,
+                    'link', format('%s/dashboard/user?tab=certificates', v_base_url),
+                    'theme', v_event.theme
+                )
+            );
+
+            perform enqueue_notification(
+                'event-attendance-certificate',
+                v_template_data,
+                '[]'::jsonb,
+                array[v_recipient]
+            );
+
+            v_certificates_enqueued := v_certificates_enqueued + 1;
+        end loop;
+
+        -- Mark the certificates as processed so the event is only evaluated once
+        update event
+        set attendance_certificates_sent_at = current_timestamp
+        where event_id = v_event.event_id;
+    end loop;
+
+    return v_certificates_enqueued;
+end;
+$$ language plpgsql;
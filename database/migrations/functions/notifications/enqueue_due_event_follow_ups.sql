@@ -0,0 +1,111 @@
+-- enqueue_due_event_follow_ups enqueues a follow-up email for checked-in
+-- attendees of the events with a feedback link that ended recently.
+create or replace function enqueue_due_event_follow_ups(p_base_url text)
+returns int as $$
+declare
+    v_base_url text;
+    v_event record;
+    v_follow_ups_enqueued int := 0;
+    v_recipients uuid[];
+    v_template_data jsonb;
+begin
+    -- Ensure only one worker enqueues due follow-ups per transaction window
+    if not pg_try_advisory_xact_lock(hashtextextended('ocg:event-follow-up-enqueue', 0)) then
+        return 0;
+    end if;
+
+    -- Normalize base URL used to build event links
+    v_base_url := regexp_replace(coalesce(p_base_url, ''), '/+$', '');
+
+    -- Fetch and lock events that ended in the last week and still need a follow-up
+    for v_event in
+        select
+            c.community_id,
+            c.name as community_name,
+            e.event_id,
+            e.feedback_url,
+            e.meeting_recording_published,
+            e.meeting_recording_url,
+            e.slug as event_slug,
+            g.group_id,
+            g.slug as group_slug,
+            g.slug_pretty as group_slug_pretty,
+            s.theme
+        from event e
+        join "group" g using (group_id)
+        join community c on c.community_id = g.community_id
+        left join lateral (
+            select site.theme
+            from site
+            order by site.created_at desc
+            limit 1
+        ) s on true
+        where c.active = true
+        and e.deleted = false
+        and e.canceled = false
+        and e.published = true
+        and e.test_event = false
+        and g.active = true
+        and g.deleted = false
+        and e.feedback_url is not null
+        and e.follow_up_sent_at is null
+        and coalesce(e.ends_at, e.starts_at) <= current_timestamp
+        and coalesce(e.ends_at, e.starts_at) > current_timestamp - interval '7 days'
+        order by coalesce(e.ends_at, e.starts_at) asc, e.event_id asc
+        for update of e skip locked
+    loop
+        -- Collect verified attendees who checked in to the event
+        select coalesce(array_agg(ea.user_id order by ea.user_id), '{}')
+        into v_recipients
+        from event_attendee ea
+        join "user" u using (user_id)
+        where ea.event_id = v_event.event_id
+        and ea.status = 'confirmed'
+        and ea.checked_in = true
+        and u.email_verified = true;
+
+        -- Enqueue the follow-up when there are recipients
+        if cardinality(v_recipients) > 0 then
+            v_template_data := jsonb_strip_nulls(
+                jsonb_build_object(
+                    'event',
+                        get_event_summary(
+                            v_event.community_id,
+                            v_event.group_id,
+                            v_event.event_id
+                        )::jsonb,
+                    'feedback_link', v_event.feedback_url,
+                    'link', format(
+                        '%s/%s/group/%s/event/%s',
+                        v_base_url,
+                        v_event.community_name,
+                        coalesce(v_event.group_slug_pretty, v_event.group_slug),
+                        v_event.event_slug
+                    ),
+                    'recording_link', case
+                        when v_event.meeting_recording_published
+                        then v_event.meeting_recording_url
+                    end,
+                    'theme', v_event.theme
+                )
+            );
+
+            perform enqueue_notification(
+                'event-follow-up',
+                v_template_data,
+                '[]'::jsonb,
+                v_recipients
+            );
+
+            v_follow_ups_enqueued := v_follow_ups_enqueued + cardinality(v_recipients);
+        end if;
+
+        -- Mark the follow-up as processed so it is only evaluated once
+        update event
+        set follow_up_sent_at = current_timestamp
+        where event_id = v_event.event_id;
+    end loop;
+
+    return v_follow_ups_enqueued;
+end;
+$$ language plpgsql;
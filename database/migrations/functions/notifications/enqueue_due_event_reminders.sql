@@ -1,10 +1,17 @@
--- enqueue_due_event_reminders enqueues reminders for events starting within 24h.
-create or replace function enqueue_due_event_reminders(p_base_url text)
+-- enqueue_due_event_reminders enqueues reminders for events starting within any
+-- of the offsets provided (in minutes before the event starts). Attendees who
+-- chose when to be reminded get their reminder at their own offset instead.
+create or replace function enqueue_due_event_reminders(
+    p_base_url text,
+    p_offsets_minutes int[]
+)
 returns int as $$
 declare
     v_attendee_recipients uuid[];
     v_base_url text;
+    v_due_offsets int[];
     v_event record;
+    v_personal_reminder record;
     v_recipient_count int;
     v_reminders_enqueued int := 0;
     v_speaker_only_recipients uuid[];
@@ -25,6 +32,7 @@ begin
             c.name as community_name,
             e.event_id,
             e.slug as event_slug,
+            e.event_reminder_not_before,
             e.starts_at,
             g.group_id,
             g.slug as group_slug,
@@ -48,21 +56,41 @@ begin
         and g.deleted = false
         and e.starts_at is not null
         and e.event_reminder_enabled = true
-        and e.event_reminder_sent_at is null
         and e.starts_at > current_timestamp
-        and e.starts_at <= current_timestamp + interval '24 hours'
-        and e.event_reminder_evaluated_for_starts_at is distinct from e.starts_at
+        and exists (
+            select 1
+            from unnest(p_offsets_minutes) as o(offset_minutes)
+            where is_event_reminder_due(
+                e.event_id,
+                e.starts_at,
+                e.event_reminder_not_before,
+                o.offset_minutes
+            )
+        )
         order by e.starts_at asc, e.event_id asc
         for update of e skip locked
     loop
-        -- Collect verified attendees who should see attendance cancellation copy
+        -- Collect the offsets due, a single reminder is sent when several are
+        select array_agg(o.offset_minutes order by o.offset_minutes)
+        into v_due_offsets
+        from unnest(p_offsets_minutes) as o(offset_minutes)
+        where is_event_reminder_due(
+            v_event.event_id,
+            v_event.starts_at,
+            v_event.event_reminder_not_before,
+            o.offset_minutes
+        );
+
+        -- Collect verified attendees who should see attendance cancellation copy,
+        -- skipping those who chose their own reminder preference
         select coalesce(array_agg(ea.user_id order by ea.user_id), '{}')
         into v_attendee_recipients
         from event_attendee ea
         join "user" u using (user_id)
         where ea.event_id = v_event.event_id
         and ea.status = 'confirmed'
-        and u.email_verified = true;
+        and u.email_verified = true
+        and coalesce(ea.reminder_preference, u.event_reminder_preference) is null;
 
         -- Collect verified speaker-only users who cannot cancel attendance
         select coalesce(array_agg(es.user_id order by es.user_id), '{}')
@@ -81,29 +109,37 @@ begin
         v_recipient_count :=
             cardinality(v_attendee_recipients) + cardinality(v_speaker_only_recipients);
 
+        -- Build base template data shared by all reminder recipients
+        v_template_data := jsonb_strip_nulls(
+            jsonb_build_object(
+                'event',
+                    get_event_summary(
+                        v_event.community_id,
+                        v_event.group_id,
+                        v_event.event_id
+                    )::jsonb,
+                'link', format(
+                    '%s/%s/group/%s/event/%s',
+                    v_base_url,
+                    v_event.community_name,
+                    coalesce(v_event.group_slug_pretty, v_event.group_slug),
+                    v_event.event_slug
+                ),
+                'dashboard_link', format('%s/dashboard/user?tab=events', v_base_url),
+                'reminder_offset_minutes', v_due_offsets[1],
+                'theme', v_event.theme
+            )
+        );
+
+        -- Post the reminder to the group channels, regardless of recipients
+        perform enqueue_group_notification(
+            v_event.group_id,
+            'event-reminder',
+            v_template_data || jsonb_build_object('show_attendance_cancellation_copy', false)
+        );
+
         -- Enqueue reminder notifications when recipients exist
         if v_recipient_count > 0 then
-            -- Build base template data shared by all reminder recipients
-            v_template_data := jsonb_strip_nulls(
-                jsonb_build_object(
-                    'event',
-                        get_event_summary(
-                            v_event.community_id,
-                            v_event.group_id,
-                            v_event.event_id
-                        )::jsonb,
-                    'link', format(
-                        '%s/%s/group/%s/event/%s',
-                        v_base_url,
-                        v_event.community_name,
-                        coalesce(v_event.group_slug_pretty, v_event.group_slug),
-                        v_event.event_slug
-                    ),
-                    'dashboard_link', format('%s/dashboard/user?tab=events', v_base_url),
-                    'theme', v_event.theme
-                )
-            );
-
             -- Enqueue attendee reminders with attendance cancellation copy enabled
             if cardinality(v_attendee_recipients) > 0 then
                 perform enqueue_notification(
@@ -124,20 +160,126 @@ begin
                 );
             end if;
 
-            -- Mark the current start time as evaluated and reminders as sent
-            update event set
-                event_reminder_evaluated_for_starts_at = starts_at,
-                event_reminder_sent_at = now()
-            where event_id = v_event.event_id;
-
             -- Track notifications created for both reminder recipient groups
             v_reminders_enqueued := v_reminders_enqueued + v_recipient_count;
-        else
-            -- Mark the current start time as evaluated when there are no recipients
-            update event set
-                event_reminder_evaluated_for_starts_at = starts_at
-            where event_id = v_event.event_id;
         end if;
+
+        -- Mark the due offsets as evaluated for the current start time, and
+        -- as sent when there were recipients
+        insert into event_reminder (event_id, offset_minutes, starts_at, sent_at)
+        select
+            v_event.event_id,
+            o.offset_minutes,
+            v_event.starts_at,
+            case when v_recipient_count > 0 then current_timestamp end
+        from unnest(v_due_offsets) as o(offset_minutes)
+        on conflict (event_id, offset_minutes) do update set
+            starts_at = excluded.starts_at,
+            sent_at = excluded.sent_at;
+    end loop;
+
+    -- Fetch the reminders due for attendees who chose their own reminder
+    -- preference, grouped by event and offset
+    for v_personal_reminder in
+        select
+            c.community_id,
+            c.name as community_name,
+            e.event_id,
+            e.slug as event_slug,
+            e.starts_at,
+            g.group_id,
+            g.slug as group_slug,
+            g.slug_pretty as group_slug_pretty,
+            p.offset_minutes,
+            s.theme,
+            array_agg(ea.user_id order by ea.user_id) as user_ids
+        from event_attendee ea
+        join "user" u on u.user_id = ea.user_id
+        join event e on e.event_id = ea.event_id
+        join "group" g on g.group_id = e.group_id
+        join community c on c.community_id = g.community_id
+        cross join lateral (
+            select get_event_reminder_preference_offset(
+                coalesce(ea.reminder_preference, u.event_reminder_preference)
+            ) as offset_minutes
+        ) p
+        left join lateral (
+            select site.theme
+            from site
+            order by site.created_at desc
+            limit 1
+        ) s on true
+        where c.active = true
+        and e.deleted = false
+        and e.canceled = false
+        and e.published = true
+        and e.test_event = false
+        and g.active = true
+        and g.deleted = false
+        and e.starts_at is not null
+        and e.event_reminder_enabled = true
+        and e.starts_at > current_timestamp
+        and ea.status = 'confirmed'
+        and u.email_verified = true
+        and p.offset_minutes is not null
+        and ea.reminder_sent_for_starts_at is distinct from e.starts_at
+        and e.starts_at <= current_timestamp + make_interval(mins => p.offset_minutes)
+        and ea.created_at <= e.starts_at - make_interval(mins => p.offset_minutes)
+        and (
+            e.event_reminder_not_before is null
+            or e.starts_at - make_interval(mins => p.offset_minutes) >= e.event_reminder_not_before
+        )
+        group by
+            c.community_id,
+            c.name,
+            e.event_id,
+            e.slug,
+            e.starts_at,
+            g.group_id,
+            g.slug,
+            g.slug_pretty,
+            p.offset_minutes,
+            s.theme
+        order by e.starts_at asc, e.event_id asc, p.offset_minutes asc
+    loop
+        -- Enqueue the reminder with attendance cancellation copy enabled
+        perform enqueue_notification(
+            'event-reminder',
+            jsonb_strip_nulls(
+                jsonb_build_object(
+                    'event',
+                        get_event_summary(
+                            v_personal_reminder.community_id,
+                            v_personal_reminder.group_id,
+                            v_personal_reminder.event_id
+                        )::jsonb,
+                    'link', format(
+                        '%s/%s/group/%s/event/%s',
+                        v_base_url,
+                        v_personal_reminder.community_name,
+                        coalesce(
+                            v_personal_reminder.group_slug_pretty,
+                            v_personal_reminder.group_slug
+                        ),
+                        v_personal_reminder.event_slug
+                    ),
+                    'dashboard_link', format('%s/dashboard/user?tab=events', v_base_url),
+                    'reminder_offset_minutes', v_personal_reminder.offset_minutes,
+                    'show_attendance_cancellation_copy', true,
+                    'theme', v_personal_reminder.theme
+                )
+            ),
+            '[]'::jsonb,
+            v_personal_reminder.user_ids
+        );
+
+        -- Mark the reminder as sent for the current start time
+        update event_attendee set
+            reminder_sent_for_starts_at = v_personal_reminder.starts_at
+        where event_id = v_personal_reminder.event_id
+        and user_id = any(v_personal_reminder.user_ids);
+
+        v_reminders_enqueued := v_reminders_enqueued + cardinality(v_personal_reminder.user_ids);
     end loop;
 
     return v_reminders_enqueued;
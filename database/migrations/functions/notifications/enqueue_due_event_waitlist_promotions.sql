@@ -0,0 +1,127 @@
+-- enqueue_due_event_waitlist_promotions releases the seats of promoted waitlist
+-- users who did not confirm them in time, promotes the next users on the waiting
+-- list and enqueues their promotion emails.
+create or replace function enqueue_due_event_waitlist_promotions(p_base_url text)
+returns int as $$
+declare
+    v_base_url text;
+    v_event record;
+    v_promoted_user_ids uuid[];
+    v_promotions_enqueued int := 0;
+    v_released_count int;
+    v_template_data jsonb;
+begin
+    -- Ensure only one worker processes expired confirmations per transaction window
+    if not pg_try_advisory_xact_lock(hashtextextended('ocg:event-waitlist-promotion-enqueue', 0)) then
+        return 0;
+    end if;
+
+    -- Normalize base URL used to build event links
+    v_base_url := regexp_replace(coalesce(p_base_url, ''), '/+$', '');
+
+    -- Fetch and lock active events with expired waitlist confirmations
+    for v_event in
+        select
+            c.community_id,
+            c.name as community_name,
+            e.capacity,
+            e.event_id,
+            e.registration_questions,
+            e.slug as event_slug,
+            e.test_event,
+            e.waitlist_confirmation_hours,
+            g.group_id,
+            g.slug as group_slug,
+            g.slug_pretty as group_slug_pretty,
+            s.theme
+        from event e
+        join "group" g using (group_id)
+        join community c on c.community_id = g.community_id
+        left join lateral (
+            select site.theme
+            from site
+            order by site.created_at desc
+            limit 1
+        ) s on true
+        where c.active = true
+        and e.deleted = false
+        and e.canceled = false
+        and e.published = true
+        and g.active = true
+        and g.deleted = false
+        and exists (
+            select 1
+            from event_attendee ea
+            where ea.event_id = e.event_id
+            and ea.status = 'waitlist-confirmation-pending'
+            and ea.waitlist_confirmation_expires_at <= current_timestamp
+        )
+        order by e.event_id asc
+        for update of e skip locked
+    loop
+        -- Release the expired seats while keeping the attendance history
+        update event_attendee
+        set
+            attendance_canceled_at = current_timestamp,
+            status = 'attendance-canceled',
+            waitlist_confirmation_expires_at = null
+        where event_id = v_event.event_id
+        and status = 'waitlist-confirmation-pending'
+        and waitlist_confirmation_expires_at <= current_timestamp;
+
+        get diagnostics v_released_count = row_count;
+
+        -- Offer the released seats to the next users on the waiting list
+        select promote_event_waitlist(
+            v_event.event_id,
+            case when v_event.capacity is null then null else v_released_count end
+        )
+        into v_promoted_user_ids;
+
+        -- Test events are reachable by direct link but should not send promotion emails
+        if cardinality(v_promoted_user_ids) = 0 or v_event.test_event then
+            continue;
+        end if;
+
+        -- Enqueue the promotion email for the users promoted
+        v_template_data := jsonb_strip_nulls(
+            jsonb_build_object(
+                'confirmation_expires_at', case
+                    when v_event.waitlist_confirmation_hours is not null
+                    then extract(epoch from (
+                        current_timestamp + make_interval(hours => v_event.waitlist_confirmation_hours)
+                    ))::bigint
+                end,
+                'dashboard_link', format('%s/dashboard/user?tab=events', v_base_url),
+                'event',
+                    get_event_summary(
+                        v_event.community_id,
+                        v_event.group_id,
+                        v_event.event_id
+                    )::jsonb,
+                'has_registration_questions',
+                    jsonb_array_length(coalesce(v_event.registration_questions, '[]'::jsonb)) > 0,
+                'link', format(
+                    '%s/%s/group/%s/event/%s',
+                    v_base_url,
+                    v_event.community_name,
+                    coalesce(v_event.group_slug_pretty, v_event.group_slug),
+                    v_event.event_slug
+                ),
+                'theme', v_event.theme
+            )
+        );
+
+        perform enqueue_notification(
+            'event-waitlist-promoted',
+            v_template_data,
+            '[]'::jsonb,
+            v_promoted_user_ids
+        );
+
+        v_promotions_enqueued := v_promotions_enqueued + cardinality(v_promoted_user_ids);
+    end loop;
+
+    return v_promotions_enqueued;
+end;
+$$ language plpgsql;
@@ -0,0 +1,115 @@
+-- enqueue_due_weekly_digests enqueues the weekly digest of upcoming events of
+-- the communities that have not sent one in the last week.
+create or replace function enqueue_due_weekly_digests(p_base_url text)
+returns int as $$
+declare
+    v_base_url text;
+    v_community record;
+    v_digests_enqueued int := 0;
+    v_events jsonb;
+    v_recipient record;
+begin
+    -- Ensure only one worker enqueues due digests per transaction window
+    if not pg_try_advisory_xact_lock(hashtextextended('ocg:weekly-digest-enqueue', 0)) then
+        return 0;
+    end if;
+
+    -- Normalize base URL used to build links
+    v_base_url := regexp_replace(coalesce(p_base_url, ''), '/+$', '');
+
+    -- Fetch and lock communities whose weekly digest is due
+    for v_community in
+        select
+            c.community_id,
+            c.display_name,
+            c.name,
+            s.theme
+        from community c
+        left join lateral (
+            select site.theme
+            from site
+            order by site.created_at desc
+            limit 1
+        ) s on true
+        where c.active = true
+        and (
+            c.weekly_digest_sent_at is null
+            or c.weekly_digest_sent_at <= current_timestamp - interval '7 days'
+        )
+        order by c.community_id
+        for update of c skip locked
+    loop
+        -- Build the digest items of the community events starting next week
+        select coalesce(
+            jsonb_object_agg(
+                e.event_id,
+                jsonb_build_object(
+                    'event', get_event_summary(c.community_id, g.group_id, e.event_id)::jsonb,
+                    'link', format(
+                        '%s/%s/group/%s/event/%s',
+                        v_base_url,
+                        c.name,
+                        coalesce(g.slug_pretty, g.slug),
+                        e.slug
+                    )
+                )
+            ),
+            '{}'::jsonb
+        )
+        into v_events
+        from event e
+        join "group" g using (group_id)
+        join community c on c.community_id = g.community_id
+        where c.community_id = v_community.community_id
+        and g.active = true
+        and g.deleted = false
+        and e.canceled = false
+        and e.deleted = false
+        and e.published = true
+        and e.test_event = false
+        and e.starts_at > current_timestamp
+        and e.starts_at <= current_timestamp + interval '7 days';
+
+        -- Enqueue one digest per member with the upcoming events of their groups
+        for v_recipient in
+            select
+                ue.user_id,
+                jsonb_agg(v_events->(ue.event_id::text) order by ue.starts_at, ue.event_id) as events
+            from (
+                select distinct gm.user_id, e.event_id, e.starts_at
+                from group_member gm
+                join "user" u using (user_id)
+                join event e on e.group_id = gm.group_id
+                where v_events ? e.event_id::text
+                and u.email_verified = true
+                and u.optional_notifications_enabled = true
+                and u.weekly_digest_enabled = true
+            ) ue
+            group by ue.user_id
+            order by ue.user_id
+        loop
+            perform enqueue_notification(
+                'weekly-digest',
+                jsonb_strip_nulls(
+                    jsonb_build_object(
+                        'community_display_name', v_community.display_name,
+                        'events', v_recipient.events,
+                        'link', format('%s/%s', v_base_url, v_community.name),
+                        'preferences_link', format('%s/dashboard/user?tab=account', v_base_url),
+                        'theme', v_community.theme
+                    )
+                ),
+                '[]'::jsonb,
+                array[v_recipient.user_id]
+            );
+            v_digests_enqueued := v_digests_enqueued + 1;
+        end loop;
+
+        -- Mark the community digest as sent, even when there was nothing to send
+        update community set weekly_digest_sent_at = current_timestamp
+        where community_id = v_community.community_id;
+    end loop;
+
+    return v_digests_enqueued;
+end;
+$$ language plpgsql;
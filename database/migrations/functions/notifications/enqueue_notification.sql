@@ -1,23 +1,30 @@
--- enqueue_notification inserts notifications, templates, and attachments.
+-- enqueue_notification inserts notifications, templates, and attachments, and
+-- returns the identifiers of the notifications created.
 create or replace function enqueue_notification(
     p_kind text,
     p_template_data jsonb,
     p_attachments jsonb,
     p_recipients uuid[]
 )
-returns void as $$
+returns uuid[] as $$
 declare
     v_attachment jsonb;
     v_attachment_id uuid;
+    v_channel_notification_ids uuid[];
     v_data bytea;
     v_notification_ids uuid[];
     v_notification_template_data_id uuid;
     v_optional_notification boolean;
     v_recipients uuid[];
+    v_sms_notification boolean;
     v_template_hash text;
+    v_webhook_notification boolean;
 begin
     -- Resolve notification kind metadata before creating notification data
-    select optional_notification into v_optional_notification from notification_kind where name = p_kind;
+    select optional_notification, sms_notification, webhook_notification
+    into v_optional_notification, v_sms_notification, v_webhook_notification
+    from notification_kind
+    where name = p_kind;
 
     if not found then
         raise exception 'notification kind does not exist: %', p_kind
@@ -33,7 +40,7 @@ begin
         where coalesce(u.optional_notifications_enabled, true) = true;
 
         if cardinality(v_recipients) = 0 then
-            return;
+            return '{}';
         end if;
     else
         v_recipients := p_recipients;
@@ -49,16 +56,52 @@ begin
         returning notification_template_data_id into v_notification_template_data_id;
     end if;
 
-    -- Insert one notification per recipient and collect IDs
+    -- Insert one email notification per deliverable recipient and collect IDs
+    -- Verification emails are still sent so users can confirm a working address
     with inserted as (
         insert into notification (kind, notification_template_data_id, user_id)
-        select p_kind, v_notification_template_data_id, unnest(v_recipients)
+        select p_kind, v_notification_template_data_id, recipient_id
+        from unnest(v_recipients) as recipient_id
+        left join "user" u on u.user_id = recipient_id
+        where u.email_undeliverable_at is null
+        or p_kind = 'email-verification'
         returning notification_id
     )
     select coalesce(array_agg(notification_id order by notification_id), '{}')
     into v_notification_ids
     from inserted;
 
+    -- Forward the notification to the recipients' webhooks, when supported
+    if v_webhook_notification then
+        with inserted as (
+            insert into notification (channel, kind, notification_template_data_id, user_id)
+            select 'webhook', p_kind, v_notification_template_data_id, u.user_id
+            from "user" u
+            where u.user_id = any(v_recipients)
+            and u.webhook_url is not null
+            returning notification_id
+        )
+        select coalesce(array_agg(notification_id), '{}')
+        into v_channel_notification_ids
+        from inserted;
+    end if;
+
+    -- Send the notification by SMS to the recipients who opted in, when supported
+    if v_sms_notification then
+        with inserted as (
+            insert into notification (channel, kind, notification_template_data_id, user_id)
+            select 'sms', p_kind, v_notification_template_data_id, u.user_id
+            from "user" u
+            where u.user_id = any(v_recipients)
+            and u.phone_number_verified = true
+            and u.sms_notifications_enabled = true
+            returning notification_id
+        )
+        select coalesce(v_channel_notification_ids, '{}') || coalesce(array_agg(notification_id), '{}')
+        into v_channel_notification_ids
+        from inserted;
+    end if;
+
     -- Insert or reuse attachments and link each to all notifications
     for v_attachment in
         select value
@@ -80,5 +123,7 @@ begin
         insert into notification_attachment (notification_id, attachment_id)
         select unnest(v_notification_ids), v_attachment_id;
     end loop;
+
+    return v_notification_ids || coalesce(v_channel_notification_ids, '{}');
 end;
 $$ language plpgsql;
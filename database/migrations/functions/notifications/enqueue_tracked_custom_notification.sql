@@ -12,9 +12,12 @@ create or replace function enqueue_tracked_custom_notification(
     p_body text
 )
 returns void as $$
+declare
+    v_custom_notification_id uuid;
+    v_notification_ids uuid[];
 begin
     -- Create notification rows first so enqueue failures prevent tracking
-    perform enqueue_notification(
+    v_notification_ids := enqueue_notification(
         p_kind,
         p_template_data,
         p_attachments,
@@ -22,7 +25,7 @@ begin
     );
 
     -- Track the custom notification after enqueue succeeds
-    perform track_custom_notification(
+    v_custom_notification_id := track_custom_notification(
         p_created_by,
         p_event_id,
         p_group_id,
@@ -30,5 +33,10 @@ begin
         p_subject,
         p_body
     );
+
+    -- Link the notifications created so their delivery status can be tracked
+    update notification
+    set custom_notification_id = v_custom_notification_id
+    where notification_id = any(v_notification_ids);
 end;
 $$ language plpgsql;
@@ -0,0 +1,10 @@
+-- get_event_reminder_preference_offset returns the offset, in minutes before
+-- the event starts, of the reminder preference provided (null when disabled).
+create or replace function get_event_reminder_preference_offset(p_reminder_preference text)
+returns int as $$
+    select case p_reminder_preference
+        when '1-hour' then 60
+        when '1-day' then 1440
+        when '1-week' then 10080
+    end;
+$$ language sql immutable;
@@ -0,0 +1,22 @@
+-- is_event_reminder_due checks if the reminder for the offset provided is due.
+create or replace function is_event_reminder_due(
+    p_event_id uuid,
+    p_starts_at timestamptz,
+    p_not_before timestamptz,
+    p_offset_minutes int
+)
+returns boolean as $$
+    select
+        p_starts_at <= current_timestamp + make_interval(mins => p_offset_minutes)
+        and (
+            p_not_before is null
+            or p_starts_at - make_interval(mins => p_offset_minutes) >= p_not_before
+        )
+        and not exists (
+            select 1
+            from event_reminder er
+            where er.event_id = p_event_id
+            and er.offset_minutes = p_offset_minutes
+            and (er.sent_at is not null or er.starts_at = p_starts_at)
+        );
+$$ language sql stable;
@@ -0,0 +1,49 @@
+-- mark_email_undeliverable flags the users with the email address provided as
+-- undeliverable, fails their pending email notifications and, for bounces,
+-- flags the emails recently sent to them as bounced.
+create or replace function mark_email_undeliverable(
+    p_email text,
+    p_reason text
+)
+returns int as $$
+declare
+    v_user_ids uuid[];
+begin
+    -- Flag the users using the address, keeping the first report received
+    with flagged as (
+        update "user"
+        set
+            email_undeliverable_at = coalesce(email_undeliverable_at, current_timestamp),
+            email_undeliverable_reason = coalesce(email_undeliverable_reason, p_reason)
+        where lower(email) = lower(p_email)
+        returning user_id
+    )
+    select coalesce(array_agg(user_id), '{}')
+    into v_user_ids
+    from flagged;
+
+    -- Stop delivering the email notifications still pending for those users
+    update notification
+    set
+        delivery_status = 'failed',
+        error = 'email address is undeliverable',
+        next_delivery_attempt_at = null,
+        processed_at = current_timestamp
+    where user_id = any(v_user_ids)
+    and channel = 'email'
+    and delivery_status = 'pending';
+
+    -- Bounces are usually reported shortly after sending, so flag the emails
+    -- sent to those users in the last few days as bounced
+    if p_reason = 'bounce' then
+        update notification
+        set delivery_status = 'bounced'
+        where user_id = any(v_user_ids)
+        and channel = 'email'
+        and delivery_status = 'processed'
+        and processed_at > current_timestamp - interval '3 days';
+    end if;
+
+    return cardinality(v_user_ids);
+end;
+$$ language plpgsql;
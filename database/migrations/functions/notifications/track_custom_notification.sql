@@ -1,4 +1,5 @@
--- track_custom_notification stores a sent custom notification and audit log.
+-- track_custom_notification stores a sent custom notification and audit log,
+-- and returns the identifier of the custom notification.
 create or replace function track_custom_notification(
     p_created_by uuid,
     p_event_id uuid,
@@ -7,21 +8,23 @@ create or replace function track_custom_notification(
     p_subject text,
     p_body text
 )
-returns void as $$
-    with insert_custom_notification as (
-        -- Store the sent custom notification
-        insert into custom_notification (body, created_by, event_id, group_id, subject)
-        values (
-            p_body,
-            p_created_by,
-            p_event_id,
-            case when p_event_id is null then p_group_id else null end,
-            p_subject
-        )
-        returning 1
+returns uuid as $$
+declare
+    v_custom_notification_id uuid;
+begin
+    -- Store the sent custom notification
+    insert into custom_notification (body, created_by, event_id, group_id, subject)
+    values (
+        p_body,
+        p_created_by,
+        p_event_id,
+        case when p_event_id is null then p_group_id else null end,
+        p_subject
     )
+    returning custom_notification_id into v_custom_notification_id;
+
     -- Track the custom notification
-    select insert_audit_log(
+    perform insert_audit_log(
         p_action => case
             when p_event_id is null then 'group_custom_notification_sent'
             else 'event_custom_notification_sent'
@@ -40,6 +43,8 @@ returns void as $$
             'subject',
             p_subject
         )
-    )
-    from insert_custom_notification;
-$$ language sql;
+    );
+
+    return v_custom_notification_id;
+end;
+$$ language plpgsql;
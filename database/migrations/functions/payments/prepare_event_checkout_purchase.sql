@@ -22,6 +22,7 @@ declare
     v_existing_purchase_matches_selection boolean;
     v_existing_purchase_status text;
     v_final_amount_minor bigint;
+    v_group_id uuid;
     v_group_slug text;
     v_group_slug_pretty text;
     v_hold_expires_at timestamptz := current_timestamp + interval '15 minutes';
@@ -50,6 +51,7 @@ begin
         e.registration_starts_at,
         e.slug,
         e.starts_at,
+        g.group_id,
         g.slug,
         g.slug_pretty,
         g.payment_recipient
@@ -60,6 +62,7 @@ begin
         v_event_registration_starts_at,
         v_event_slug,
         v_event_starts_at,
+        v_group_id,
         v_group_slug,
         v_group_slug_pretty,
         v_recipient
@@ -69,6 +72,12 @@ begin
     where e.event_id = p_event_id
     and g.community_id = p_community_id;
 
+    -- Users banned from the group cannot buy tickets for its events
+    perform ensure_user_is_not_banned_from_group(v_group_id, p_user_id);
+
+    -- Users must have an email domain allowed by the community
+    perform ensure_user_email_domain_allowed(p_community_id, p_user_id);
+
     -- Reuse an equivalent purchase or return an active completed purchase
     select
         event_purchase_id,
@@ -141,7 +150,11 @@ begin
     );
 
     -- Validate the final discounted amount before creating a checkout hold
-    perform validate_payment_amount(v_currency_code, v_final_amount_minor);
+    if v_currency_code is not null then
+        perform validate_payment_amount(v_currency_code, v_final_amount_minor);
+    elsif v_final_amount_minor > 0 then
+        raise exception 'paid ticket types require payment_currency_code';
+    end if;
 
     -- Release any replaced pending selection before creating the new hold
     if v_existing_purchase_id is not null and v_existing_purchase_status = 'pending' then
@@ -1,4 +1,4 @@
--- Validates an event for checkout and returns its currency.
+-- Validates an event for checkout and returns its currency, if any.
 create or replace function prepare_event_checkout_validate_event(
     p_community_id uuid,
     p_event_id uuid,
@@ -53,6 +53,12 @@ begin
         raise exception 'event not found or inactive';
     end if;
 
+    -- Events offering only free ticket types are not priced in any currency,
+    -- so attendees can get their tickets without payments being configured
+    if v_currency_code is null then
+        return null;
+    end if;
+
     -- Require any payment recipient before validating provider compatibility
     if v_payment_recipient is null then
         raise exception 'group payments recipient is not configured';
@@ -68,11 +74,6 @@ begin
         raise exception 'group payments recipient is not configured for the server payments provider';
     end if;
 
-    -- Require a payment currency to price the checkout session
-    if v_currency_code is null then
-        raise exception 'ticketed event is missing payment_currency_code';
-    end if;
-
     perform validate_payment_currency_code(v_currency_code);
 
     -- Return the event currency used to price the checkout session
@@ -92,6 +92,19 @@ returns json as $$
                 where c.name = p_community_name
                 order by r."order" asc nulls last
             ) as regions
+        ) end,
+        'topic', case when p_community_name is not null then (
+            select coalesce(json_agg(json_build_object(
+                'name', name,
+                'value', normalized_name
+            )), '[]')
+            from (
+                select t.name, t.normalized_name
+                from topic t
+                join community c using (community_id)
+                where c.name = p_community_name
+                order by t.name asc
+            ) as topics
         ) end
     ));
 $$ language sql;
@@ -0,0 +1,78 @@
+-- Marks the Google Calendar entries affected by attendance and event changes
+-- as out of sync, so that the synchronization workers pick them up.
+create or replace function mark_google_calendar_attendee_out_of_sync()
+returns trigger as $$
+declare
+    v_event_id uuid := case when tg_op = 'DELETE' then old.event_id else new.event_id end;
+    v_user_id uuid := case when tg_op = 'DELETE' then old.user_id else new.user_id end;
+begin
+    -- Only users that connected their Google Calendar are synchronized
+    insert into google_calendar_entry (event_id, user_id)
+    select e.event_id, ugc.user_id
+    from user_google_calendar ugc
+    join event e on e.event_id = v_event_id
+    where ugc.user_id = v_user_id
+    on conflict (event_id, user_id) do update
+    set
+        in_sync = false,
+        sync_version = google_calendar_entry.sync_version + 1;
+
+    return null;
+end;
+$$ language plpgsql;
+
+create or replace function mark_google_calendar_event_out_of_sync()
+returns trigger as $$
+begin
+    update google_calendar_entry
+    set
+        in_sync = false,
+        sync_version = sync_version + 1
+    where event_id = new.event_id;
+
+    return null;
+end;
+$$ language plpgsql;
+
+drop trigger if exists event_attendee_google_calendar_out_of_sync on event_attendee;
+
+create trigger event_attendee_google_calendar_out_of_sync
+after insert or delete or update of status on event_attendee
+for each row
+execute function mark_google_calendar_attendee_out_of_sync();
+
+drop trigger if exists event_google_calendar_out_of_sync on event;
+
+create trigger event_google_calendar_out_of_sync
+after update on event
+for each row
+when ((
+    old.canceled,
+    old.deleted,
+    old.description_short,
+    old.ends_at,
+    old.meeting_join_url,
+    old.name,
+    old.published,
+    old.slug,
+    old.starts_at,
+    old.timezone,
+    old.venue_address,
+    old.venue_city,
+    old.venue_name
+) is distinct from (
+    new.canceled,
+    new.deleted,
+    new.description_short,
+    new.ends_at,
+    new.meeting_join_url,
+    new.name,
+    new.published,
+    new.slug,
+    new.starts_at,
+    new.timezone,
+    new.venue_address,
+    new.venue_city,
+    new.venue_name
+))
+execute function mark_google_calendar_event_out_of_sync();
@@ -0,0 +1,16 @@
+-- Notifies the Google Calendar workers as soon as an entry needs to be synchronized.
+create or replace function notify_google_calendar_entry_out_of_sync()
+returns trigger as $$
+begin
+    perform pg_notify('google_calendar_entry_out_of_sync', '');
+    return null;
+end;
+$$ language plpgsql;
+
+drop trigger if exists google_calendar_entry_out_of_sync_notify on google_calendar_entry;
+
+create trigger google_calendar_entry_out_of_sync_notify
+after insert or update of in_sync on google_calendar_entry
+for each row
+when (new.in_sync = false)
+execute function notify_google_calendar_entry_out_of_sync();
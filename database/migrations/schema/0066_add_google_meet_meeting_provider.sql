@@ -0,0 +1,3 @@
+-- Add Google Meet as a supported meeting provider.
+
+insert into meeting_provider values ('google_meet', 'Google Meet');
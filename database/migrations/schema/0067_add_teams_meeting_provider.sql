@@ -0,0 +1,3 @@
+-- Add Microsoft Teams as a supported meeting provider.
+
+insert into meeting_provider values ('teams', 'Microsoft Teams');
@@ -0,0 +1,3 @@
+-- Add Jitsi as a supported meeting provider.
+
+insert into meeting_provider values ('jitsi', 'Jitsi');
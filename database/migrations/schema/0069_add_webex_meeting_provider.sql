@@ -0,0 +1,3 @@
+-- Add Cisco Webex as a supported meeting provider.
+
+insert into meeting_provider values ('webex', 'Cisco Webex');
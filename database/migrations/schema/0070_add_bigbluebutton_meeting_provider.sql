@@ -0,0 +1,3 @@
+-- Add BigBlueButton as a supported meeting provider.
+
+insert into meeting_provider values ('bigbluebutton', 'BigBlueButton');
@@ -0,0 +1,32 @@
+-- Allow groups to use their own Zoom account for meetings.
+
+-- Store per-group Zoom server-to-server OAuth credentials
+create table group_zoom_credentials (
+    group_id uuid primary key references "group" on delete cascade,
+    account_id text not null check (btrim(account_id) <> ''),
+    client_id text not null check (btrim(client_id) <> ''),
+    client_secret text not null check (btrim(client_secret) <> ''),
+    created_at timestamptz default current_timestamp not null,
+    host_user text not null check (btrim(host_user) <> ''),
+
+    updated_at timestamptz
+);
+
+-- Track the group owning each meeting so orphaned meetings keep their account
+alter table meeting
+add column group_id uuid references "group" on delete set null;
+
+create index meeting_group_id_idx on meeting (group_id);
+
+-- Backfill event-backed meetings
+update meeting m
+set group_id = e.group_id
+from event e
+where e.event_id = m.event_id;
+
+-- Backfill session-backed meetings
+update meeting m
+set group_id = e.group_id
+from session s
+join event e on e.event_id = s.event_id
+where s.session_id = m.session_id;
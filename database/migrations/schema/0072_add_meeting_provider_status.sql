@@ -0,0 +1,28 @@
+-- Track meeting lifecycle status reported by provider webhooks.
+
+-- Lookup values for provider-reported meeting statuses.
+create table meeting_provider_status (
+    meeting_provider_status_id text primary key,
+    display_name text not null unique check (btrim(display_name) <> '')
+);
+
+insert into meeting_provider_status (meeting_provider_status_id, display_name)
+values
+    ('deleted', 'Deleted'),
+    ('ended', 'Ended'),
+    ('started', 'Started');
+
+-- Add provider status and participant tracking to meetings
+alter table meeting
+    add column participants_joined_count integer default 0 not null
+        check (participants_joined_count >= 0),
+    add column provider_status text references meeting_provider_status,
+    add column provider_status_updated_at timestamptz;
+
+-- Require status timestamp and status to be set together
+alter table meeting
+    add constraint meeting_provider_status_pair_chk
+        check (
+            (provider_status is null and provider_status_updated_at is null)
+            or (provider_status is not null and provider_status_updated_at is not null)
+        );
@@ -0,0 +1,12 @@
+-- Track syncing of provider participant reports into event attendance.
+
+-- Add attendance sync tracking and claims to meetings
+alter table meeting
+    add column attendance_sync_claimed_at timestamptz,
+    add column attendance_synced_at timestamptz;
+
+-- Index pending Zoom meetings that still need attendance syncs
+create index meeting_zoom_attendance_sync_pending_idx
+    on meeting (meeting_provider_id, attendance_synced_at)
+    where meeting_provider_id = 'zoom'
+      and attendance_synced_at is null;
@@ -0,0 +1,12 @@
+-- Track polling of provider cloud recordings for ended meetings.
+
+-- Add recording sync tracking and claims to meetings
+alter table meeting
+    add column recording_sync_claimed_at timestamptz,
+    add column recording_synced_at timestamptz;
+
+-- Index pending Zoom meetings that still need recording syncs
+create index meeting_zoom_recording_sync_pending_idx
+    on meeting (meeting_provider_id, recording_synced_at)
+    where meeting_provider_id = 'zoom'
+      and recording_synced_at is null;
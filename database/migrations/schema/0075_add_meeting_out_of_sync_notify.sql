@@ -0,0 +1,24 @@
+-- Notify meetings workers as soon as a meeting needs to be synchronized.
+
+-- Send a notification on the meetings out-of-sync channel
+create or replace function notify_meeting_out_of_sync()
+returns trigger as $$
+begin
+    perform pg_notify('meeting_out_of_sync', '');
+    return null;
+end;
+$$ language plpgsql;
+
+-- Trigger on event INSERT/UPDATE.
+create trigger event_meeting_out_of_sync_notify
+after insert or update of meeting_in_sync on event
+for each row
+when (new.meeting_in_sync = false)
+execute function notify_meeting_out_of_sync();
+
+-- Trigger on session INSERT/UPDATE.
+create trigger session_meeting_out_of_sync_notify
+after insert or update of meeting_in_sync on session
+for each row
+when (new.meeting_in_sync = false)
+execute function notify_meeting_out_of_sync();
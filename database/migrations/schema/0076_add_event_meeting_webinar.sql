@@ -0,0 +1,5 @@
+-- Allow organizers to host automatic Zoom meetings as webinars.
+
+-- Track whether the event meeting should be created as a webinar
+alter table event
+    add column meeting_webinar boolean default false not null;
@@ -0,0 +1,4 @@
+-- Allow meetings sync workers to skip providers that are temporarily paused.
+
+-- The function is recreated with a new paused providers argument
+drop function if exists claim_meeting_out_of_sync();
@@ -0,0 +1,21 @@
+-- Deliver community notifications to external channels, like Slack.
+
+-- Store the Slack incoming webhook used to post community notifications
+alter table community
+    add column slack_webhook_url text check (btrim(slack_webhook_url) <> '');
+
+-- Track the channel each notification is delivered through
+alter table notification
+    add column channel text not null default 'email'
+        constraint notification_channel_chk check (channel in ('email', 'slack')),
+    add column community_id uuid references community on delete cascade,
+    alter column user_id drop not null,
+    add constraint notification_recipient_chk check (
+        (channel = 'email' and user_id is not null)
+        or (channel <> 'email' and community_id is not null)
+    );
+
+create index notification_community_id_idx on notification (community_id);
+
+-- Drop the claim function before extending its returned delivery metadata
+drop function if exists claim_pending_notification(integer, integer);
@@ -0,0 +1,22 @@
+-- Deliver group notifications to Discord channels.
+
+-- Store the Discord webhook used to post group notifications
+alter table "group"
+    add column discord_webhook_url text check (btrim(discord_webhook_url) <> '');
+
+-- Track the group notified through group level channels
+alter table notification
+    add column group_id uuid references "group" on delete cascade,
+    drop constraint notification_channel_chk,
+    add constraint notification_channel_chk check (channel in ('discord', 'email', 'slack')),
+    drop constraint notification_recipient_chk,
+    add constraint notification_recipient_chk check (
+        (channel = 'discord' and group_id is not null)
+        or (channel = 'email' and user_id is not null)
+        or (channel = 'slack' and community_id is not null)
+    );
+
+create index notification_group_id_idx on notification (group_id);
+
+-- Drop the claim function before extending its returned delivery metadata
+drop function if exists claim_pending_notification(integer, integer);
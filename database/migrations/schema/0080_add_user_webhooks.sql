@@ -0,0 +1,36 @@
+-- Deliver user notifications to outgoing webhooks configured by users.
+
+-- Store the webhook notifications are posted to and the secret used to sign them
+alter table "user"
+    add column webhook_url text check (btrim(webhook_url) <> ''),
+    add column webhook_secret text check (btrim(webhook_secret) <> ''),
+    add constraint user_webhook_secret_chk check (webhook_url is null or webhook_secret is not null);
+
+-- Track the notification kinds that can be forwarded to webhooks
+alter table notification_kind
+    add column webhook_notification boolean not null default false;
+
+update notification_kind
+set webhook_notification = true
+where name in (
+    'event-canceled',
+    'event-published',
+    'event-welcome',
+    'group-welcome'
+);
+
+-- Allow notifications to be delivered to user webhooks
+alter table notification
+    drop constraint notification_channel_chk,
+    add constraint notification_channel_chk check (
+        channel in ('discord', 'email', 'slack', 'webhook')
+    ),
+    drop constraint notification_recipient_chk,
+    add constraint notification_recipient_chk check (
+        (channel = 'discord' and group_id is not null)
+        or (channel in ('email', 'webhook') and user_id is not null)
+        or (channel = 'slack' and community_id is not null)
+    );
+
+-- Drop the claim function before extending its returned delivery metadata
+drop function if exists claim_pending_notification(integer, integer);
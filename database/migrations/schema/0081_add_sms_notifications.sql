@@ -0,0 +1,48 @@
+-- Deliver event reminders by SMS to users who verify their phone number and opt in.
+
+-- Store the user phone number and SMS notifications preference
+alter table "user"
+    add column phone_number text check (phone_number ~ '^\+[1-9][0-9]{6,14}$'),
+    add column phone_number_verified boolean not null default false,
+    add column sms_notifications_enabled boolean not null default false,
+    add constraint user_phone_number_verified_chk check (
+        phone_number is not null or not phone_number_verified
+    ),
+    add constraint user_sms_notifications_enabled_chk check (
+        phone_number_verified or not sms_notifications_enabled
+    );
+
+-- Phone number verification codes sent by SMS
+create table phone_verification_code (
+    user_id uuid primary key references "user" on delete cascade,
+    attempts integer not null default 0 check (attempts >= 0),
+    code_hash text not null check (btrim(code_hash) <> ''),
+    created_at timestamptz not null default current_timestamp
+);
+
+-- Track the notification kinds also delivered by SMS when enqueued for users
+alter table notification_kind
+    add column sms_notification boolean not null default false;
+
+update notification_kind
+set sms_notification = true
+where name = 'event-reminder';
+
+-- Phone verification codes are only delivered by SMS
+insert into notification_kind (name) values ('phone-verification');
+
+-- Allow notifications to be delivered by SMS
+alter table notification
+    drop constraint notification_channel_chk,
+    add constraint notification_channel_chk check (
+        channel in ('discord', 'email', 'slack', 'sms', 'webhook')
+    ),
+    drop constraint notification_recipient_chk,
+    add constraint notification_recipient_chk check (
+        (channel = 'discord' and group_id is not null)
+        or (channel in ('email', 'sms', 'webhook') and user_id is not null)
+        or (channel = 'slack' and community_id is not null)
+    );
+
+-- Drop the claim function before extending its returned delivery metadata
+drop function if exists claim_pending_notification(integer, integer);
@@ -0,0 +1,37 @@
+-- Send event reminders at several configurable offsets before the event starts.
+
+-- Track the reminders evaluated and sent for each event and offset
+create table event_reminder (
+    event_id uuid not null references event on delete cascade,
+    offset_minutes integer not null check (offset_minutes > 0),
+    starts_at timestamptz not null,
+
+    sent_at timestamptz,
+
+    primary key (event_id, offset_minutes)
+);
+
+-- Reminders scheduled before this time are skipped (set on publish or reschedule)
+alter table event
+    add column event_reminder_not_before timestamptz;
+
+-- Carry over the state of the existing 24 hours reminders
+insert into event_reminder (event_id, offset_minutes, starts_at, sent_at)
+select
+    event_id,
+    1440,
+    coalesce(event_reminder_evaluated_for_starts_at, starts_at),
+    event_reminder_sent_at
+from event
+where event_reminder_sent_at is not null
+or (
+    event_reminder_evaluated_for_starts_at is not null
+    and event_reminder_evaluated_for_starts_at = starts_at
+);
+
+alter table event
+    drop column event_reminder_evaluated_for_starts_at,
+    drop column event_reminder_sent_at;
+
+-- The reminder offsets are now provided by the caller
+drop function if exists enqueue_due_event_reminders(text);
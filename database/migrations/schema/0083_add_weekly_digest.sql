@@ -0,0 +1,11 @@
+-- Send members a weekly digest of the upcoming events of their groups in each community.
+
+-- Users can opt out of weekly digests
+alter table "user"
+    add column weekly_digest_enabled boolean not null default true;
+
+-- Track when the last weekly digest of each community was sent
+alter table community
+    add column weekly_digest_sent_at timestamptz;
+
+insert into notification_kind (name, optional_notification) values ('weekly-digest', true);
@@ -0,0 +1,8 @@
+-- Track email addresses reported as undeliverable by the email provider.
+
+alter table "user"
+    add column email_undeliverable_at timestamptz,
+    add column email_undeliverable_reason text
+        constraint user_email_undeliverable_reason_chk check (
+            email_undeliverable_reason in ('bounce', 'complaint')
+        );
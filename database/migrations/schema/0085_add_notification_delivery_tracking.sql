@@ -0,0 +1,23 @@
+-- Track the delivery status of the custom notifications sent from the dashboards.
+
+-- Link notifications to the custom notification they were sent for, and allow
+-- flagging sent emails later reported as bounced by the email provider
+alter table notification
+    add column custom_notification_id uuid references custom_notification on delete set null,
+    drop constraint notification_delivery_status_chk,
+    add constraint notification_delivery_status_chk check (
+        delivery_status in (
+            'bounced',
+            'delivery-unknown',
+            'failed',
+            'pending',
+            'processed',
+            'processing'
+        )
+    );
+
+create index notification_custom_notification_id_idx on notification (custom_notification_id);
+
+-- Drop the functions whose return types change to return the created identifiers
+drop function if exists enqueue_notification(text, jsonb, jsonb, uuid[]);
+drop function if exists track_custom_notification(uuid, uuid, uuid, int, text, text);
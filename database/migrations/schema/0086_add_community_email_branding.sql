@@ -0,0 +1,11 @@
+-- Allow communities to customize the branding of the emails they send.
+
+-- Store the community email branding overrides
+alter table community
+    add column email_footer_text text check (btrim(email_footer_text) <> ''),
+    add column email_from_name text check (btrim(email_from_name) <> ''),
+    add column email_logo_url text check (btrim(email_logo_url) <> ''),
+    add column email_primary_color text check (email_primary_color ~ '^#[0-9a-fA-F]{6}$');
+
+-- Drop the claim function before extending its returned delivery metadata
+drop function if exists claim_pending_notification(integer, integer);
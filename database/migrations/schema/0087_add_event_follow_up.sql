@@ -0,0 +1,8 @@
+-- Send checked-in attendees a follow-up email with a feedback survey link after the event ends.
+
+-- Organizers can provide a feedback survey link for each event
+alter table event
+    add column feedback_url text check (btrim(feedback_url) <> ''),
+    add column follow_up_sent_at timestamptz;
+
+insert into notification_kind (name, optional_notification) values ('event-follow-up', true);
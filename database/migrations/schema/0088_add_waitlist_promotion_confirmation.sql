@@ -0,0 +1,30 @@
+-- Require promoted waitlist users to confirm their seat within a configurable window.
+
+-- Organizers can set how many hours promoted waitlist users have to confirm their seat
+alter table event
+    add column waitlist_confirmation_hours int
+        check (waitlist_confirmation_hours between 1 and 168);
+
+-- Track seats reserved for promoted waitlist users until they are confirmed
+alter table event_attendee
+    add column waitlist_confirmation_expires_at timestamptz,
+    drop constraint event_attendee_status_chk,
+    add constraint event_attendee_status_chk check (
+        status in (
+            'attendance-canceled',
+            'confirmed',
+            'invitation-canceled',
+            'invitation-pending',
+            'invitation-rejected',
+            'registration-questions-pending',
+            'waitlist-confirmation-pending'
+        )
+    ),
+    add constraint event_attendee_waitlist_confirmation_chk check (
+        (status = 'waitlist-confirmation-pending') = (waitlist_confirmation_expires_at is not null)
+    );
+
+-- Support the worker that releases expired waitlist confirmations
+create index event_attendee_waitlist_confirmation_expires_at_idx
+    on event_attendee (waitlist_confirmation_expires_at)
+    where status = 'waitlist-confirmation-pending';
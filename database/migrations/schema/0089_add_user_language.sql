@@ -0,0 +1,8 @@
+-- Allow users to choose the language their notifications are delivered in.
+
+-- Store the preferred notification language of each user
+alter table "user"
+    add column language text check (language in ('de', 'en', 'es', 'fr', 'pt'));
+
+-- Drop the claim function before extending its returned delivery metadata
+drop function if exists claim_pending_notification(integer, integer);
@@ -0,0 +1,18 @@
+-- Allow users to sign in with passkeys and communities to require them.
+
+-- Passkeys registered by users
+create table user_passkey (
+    user_passkey_id uuid primary key default gen_random_uuid(),
+    created_at timestamptz default current_timestamp not null,
+    credential_id text not null unique check (btrim(credential_id) <> ''),
+    last_used_at timestamptz,
+    name text not null check (btrim(name) <> ''),
+    passkey jsonb not null,
+    user_id uuid not null references "user" on delete cascade
+);
+
+create index user_passkey_user_id_idx on user_passkey (user_id);
+
+-- Allow communities to require passkey sign-in for their dashboard
+alter table community
+    add column passkey_required boolean default false not null;
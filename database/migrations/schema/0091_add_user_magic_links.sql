@@ -0,0 +1,14 @@
+-- Allow users to log in using short-lived links sent to their email address.
+
+-- Log in links issued to users, identified by the hash of their token
+create table user_magic_link (
+    user_magic_link_id uuid primary key default gen_random_uuid(),
+    created_at timestamptz default current_timestamp not null,
+    expires_at timestamptz not null,
+    token_hash text not null unique check (btrim(token_hash) <> ''),
+    user_id uuid not null references "user" on delete cascade
+);
+
+create index user_magic_link_user_id_idx on user_magic_link (user_id);
+
+insert into notification_kind (name) values ('magic-link');
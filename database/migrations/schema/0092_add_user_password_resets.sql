@@ -0,0 +1,14 @@
+-- Allow users to reset their password using codes sent to their email address.
+
+-- Password reset codes issued to users, identified by their hash
+create table user_password_reset (
+    user_password_reset_id uuid primary key default gen_random_uuid(),
+    code_hash text not null unique check (btrim(code_hash) <> ''),
+    created_at timestamptz default current_timestamp not null,
+    expires_at timestamptz not null,
+    user_id uuid not null references "user" on delete cascade
+);
+
+create index user_password_reset_user_id_idx on user_password_reset (user_id);
+
+insert into notification_kind (name) values ('password-reset');
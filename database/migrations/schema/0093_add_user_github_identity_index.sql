@@ -0,0 +1,5 @@
+-- Add a unique index for GitHub identity reconciliation.
+create unique index user_github_identity_idx
+on "user" ((provider #>> '{github,id}'))
+where provider #>> '{github,id}' is not null
+and provider #>> '{github,id}' <> '';
@@ -0,0 +1,14 @@
+-- Throttle password log ins by tracking the failed attempts.
+
+-- Failed log in attempts, used to lock out usernames and addresses temporarily
+create table log_in_failure (
+    log_in_failure_id uuid primary key default gen_random_uuid(),
+    created_at timestamptz default current_timestamp not null,
+    username text not null check (btrim(username) <> ''),
+
+    ip_address inet
+);
+
+create index log_in_failure_created_at_idx on log_in_failure (created_at);
+create index log_in_failure_ip_address_idx on log_in_failure (ip_address, created_at);
+create index log_in_failure_username_idx on log_in_failure (lower(username), created_at);
@@ -0,0 +1,11 @@
+-- Track the sessions of each user so they can be listed and revoked.
+
+alter table auth_session
+    add column auth_session_ref uuid default gen_random_uuid() not null unique,
+    add column created_at timestamptz default current_timestamp not null,
+    add column last_seen_at timestamptz default current_timestamp not null,
+    add column user_id uuid generated always as (
+        (data -> 'axum-login.data' ->> 'user_id')::uuid
+    ) stored;
+
+create index auth_session_user_id_idx on auth_session (user_id);
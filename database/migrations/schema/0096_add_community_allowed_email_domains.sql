@@ -0,0 +1,3 @@
+-- Allow communities to restrict sign ups to some email domains
+alter table community
+    add column allowed_email_domains text[];
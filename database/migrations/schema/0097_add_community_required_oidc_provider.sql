@@ -0,0 +1,3 @@
+-- Allow communities to require logging in with a specific OIDC provider
+alter table community
+    add column required_oidc_provider text check (btrim(required_oidc_provider) <> '');
@@ -0,0 +1,22 @@
+-- Add a group role limited to checking in event attendees.
+
+-- Permission to check in event attendees.
+insert into group_permission (group_permission_id, display_name)
+values ('group.check-in.write', 'Check-in Write');
+
+-- Group role for volunteers helping with check-in at events.
+insert into group_role (group_role_id, display_name)
+values ('check-in-volunteer', 'Check-in Volunteer');
+
+-- Grant the check-in permission to the roles able to manage events.
+insert into group_role_group_permission (group_role_id, group_permission_id)
+values
+    ('admin', 'group.check-in.write'),
+    ('check-in-volunteer', 'group.check-in.write'),
+    ('check-in-volunteer', 'group.read'),
+    ('events-manager', 'group.check-in.write');
+
+insert into community_role_group_permission (community_role_id, group_permission_id)
+values
+    ('admin', 'group.check-in.write'),
+    ('groups-manager', 'group.check-in.write');
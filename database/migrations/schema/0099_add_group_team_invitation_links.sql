@@ -0,0 +1,17 @@
+-- Allow group teams to invite new members by email using a link to accept.
+
+-- Links sent by email to accept group team invitations, identified by the
+-- hash of their token
+create table group_team_invitation_link (
+    group_team_invitation_link_id uuid primary key default gen_random_uuid(),
+    created_at timestamptz default current_timestamp not null,
+    expires_at timestamptz not null,
+    group_id uuid not null,
+    token_hash text not null unique check (btrim(token_hash) <> ''),
+    user_id uuid not null,
+
+    foreign key (group_id, user_id) references group_team (group_id, user_id) on delete cascade
+);
+
+create index group_team_invitation_link_group_id_user_id_idx
+    on group_team_invitation_link (group_id, user_id);
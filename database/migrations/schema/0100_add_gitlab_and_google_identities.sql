@@ -0,0 +1,13 @@
+-- Add unique indexes for GitLab and Google identity reconciliation.
+create unique index user_gitlab_identity_idx
+on "user" ((provider #>> '{gitlab,id}'))
+where provider #>> '{gitlab,id}' is not null
+and provider #>> '{gitlab,id}' <> '';
+
+create unique index user_google_identity_idx
+on "user" ((provider #>> '{google,id}'))
+where provider #>> '{google,id}' is not null
+and provider #>> '{google,id}' <> '';
+
+-- Replace the GitHub-only identity lookup with a provider-aware one.
+drop function if exists get_user_by_github_identity_for_external_auth(text);
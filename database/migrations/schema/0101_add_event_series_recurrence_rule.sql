@@ -0,0 +1,17 @@
+-- Expose the iCalendar recurrence rule (RRULE) of each linked event series.
+alter table event_series add column recurrence_rule text generated always as (
+    case recurrence_pattern when 'monthly' then 'FREQ=MONTHLY' else 'FREQ=WEEKLY' end
+    || case recurrence_pattern when 'biweekly' then ';INTERVAL=2' else '' end
+    || ';BYDAY='
+    -- Monthly series repeat on the same ordinal weekday, such as the third Monday
+    || case recurrence_pattern
+        when 'monthly' then (
+            (extract(day from recurrence_anchor_starts_at at time zone timezone)::int - 1) / 7 + 1
+        )::text
+        else ''
+    end
+    || (array['MO', 'TU', 'WE', 'TH', 'FR', 'SA', 'SU'])[
+        extract(isodow from recurrence_anchor_starts_at at time zone timezone)::int
+    ]
+    || ';COUNT=' || (recurrence_additional_occurrences + 1)::text
+) stored;
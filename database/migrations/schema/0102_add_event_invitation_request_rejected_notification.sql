@@ -0,0 +1,5 @@
+-- Add notification kind for rejected event invitation requests.
+
+insert into notification_kind (name)
+values ('event-invitation-request-rejected')
+on conflict (name) do nothing;
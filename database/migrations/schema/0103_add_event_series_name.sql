@@ -0,0 +1,2 @@
+-- Allow organizers to name linked event series.
+alter table event_series add column name text check (btrim(name) <> '');
@@ -0,0 +1,2 @@
+-- Allow organizers to group event sessions into tracks.
+alter table session add column track text check (btrim(track) <> '');
@@ -0,0 +1,5 @@
+-- Let users subscribe to the events they attend from their calendar apps.
+
+-- Store the secret token used to access the user's calendar feed
+alter table "user"
+    add column calendar_token text unique check (btrim(calendar_token) <> '');
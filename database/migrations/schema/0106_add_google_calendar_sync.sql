@@ -0,0 +1,25 @@
+-- Let users keep the events they attend synchronized with their Google Calendar.
+
+-- Google Calendar connections authorized by users
+create table user_google_calendar (
+    user_id uuid primary key references "user" on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+    refresh_token text not null check (btrim(refresh_token) <> '')
+);
+
+-- Events synchronized with the users' Google Calendars
+create table google_calendar_entry (
+    event_id uuid not null references event on delete cascade,
+    user_id uuid not null references user_google_calendar on delete cascade,
+    in_sync boolean default false not null,
+    sync_version bigint default 1 not null,
+
+    google_event_id text check (btrim(google_event_id) <> ''),
+    sync_claimed_at timestamptz,
+
+    primary key (event_id, user_id)
+);
+
+create index google_calendar_entry_sync_claim_idx on google_calendar_entry (sync_claimed_at)
+    where in_sync = false;
+create index google_calendar_entry_user_id_idx on google_calendar_entry (user_id);
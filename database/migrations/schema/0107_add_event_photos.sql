@@ -0,0 +1,28 @@
+-- Let attendees share photos of past events, moderated by the event organizers.
+
+-- Organizers can allow attendees to submit photos for each event
+alter table event add column attendee_photos_enabled boolean not null default false;
+
+-- Photos submitted by attendees to the events galleries
+create table event_photo (
+    event_photo_id uuid primary key default gen_random_uuid(),
+    event_id uuid not null references event on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+    status text not null default 'pending' check (status in ('approved', 'pending', 'rejected')),
+    url text not null check (btrim(url) <> ''),
+
+    reviewed_at timestamptz,
+    reviewed_by uuid references "user" on delete set null,
+
+    unique (event_id, url),
+    check (
+        (status = 'pending' and reviewed_at is null and reviewed_by is null)
+        or (status in ('approved', 'rejected') and reviewed_at is not null)
+    )
+);
+
+create index event_photo_event_id_status_created_at_idx
+    on event_photo (event_id, status, created_at);
+create index event_photo_reviewed_by_idx on event_photo (reviewed_by);
+create index event_photo_user_id_idx on event_photo (user_id);
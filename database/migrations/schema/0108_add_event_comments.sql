@@ -0,0 +1,20 @@
+-- Let attendees discuss events and ask questions, moderated by the event organizers.
+
+-- Organizers can lock the discussion to stop new comments from being posted
+alter table event add column comments_locked boolean not null default false;
+
+-- Comments and questions posted by attendees and organizers on the events pages
+create table event_comment (
+    event_comment_id uuid primary key default gen_random_uuid(),
+    event_id uuid not null references event on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+    content text not null check (btrim(content) <> ''),
+    created_at timestamptz default current_timestamp not null,
+    pinned boolean not null default false,
+
+    parent_event_comment_id uuid references event_comment on delete cascade
+);
+
+create index event_comment_event_id_created_at_idx on event_comment (event_id, created_at);
+create index event_comment_parent_event_comment_id_idx on event_comment (parent_event_comment_id);
+create index event_comment_user_id_idx on event_comment (user_id);
@@ -0,0 +1,17 @@
+-- Let organizers share files (slides, documents) with the attendees of their events.
+
+-- Files attached to events by their organizers, stored using the images storage provider
+create table event_resource (
+    event_resource_id uuid primary key default gen_random_uuid(),
+    event_id uuid not null references event on delete cascade,
+    content_type text not null check (btrim(content_type) <> ''),
+    created_at timestamptz default current_timestamp not null,
+    file_name text not null check (btrim(file_name) <> ''),
+    name text not null check (btrim(name) <> ''),
+    size_bytes integer not null check (size_bytes > 0),
+
+    created_by uuid references "user" on delete set null
+);
+
+create index event_resource_created_by_idx on event_resource (created_by);
+create index event_resource_event_id_idx on event_resource (event_id);
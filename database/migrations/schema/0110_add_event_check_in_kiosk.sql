@@ -0,0 +1,9 @@
+-- Support faster door check-in from a kiosk.
+
+-- Store the secret used to sign the rotating kiosk check-in codes
+alter table event
+    add column check_in_secret uuid not null default gen_random_uuid();
+
+-- Store the code attendees show at the door to be checked in by staff
+alter table event_attendee
+    add column ticket_code uuid not null unique default gen_random_uuid();
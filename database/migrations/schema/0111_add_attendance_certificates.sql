@@ -0,0 +1,9 @@
+-- Issue certificates of attendance to the attendees who checked in to an event.
+
+-- Organizers can email the certificates to the checked-in attendees after the event
+alter table event
+    add column attendance_certificate_emails_enabled boolean not null default false,
+    add column attendance_certificates_sent_at timestamptz;
+
+insert into notification_kind (name, optional_notification)
+values ('event-attendance-certificate', true);
@@ -0,0 +1,93 @@
+-- Allow events to offer free ticket types without configuring payments.
+
+-- Purchases of free-only events are not priced in any currency
+alter table event_purchase
+    alter column currency_code drop not null;
+
+-- Require a payment currency only when some ticket type or discount can charge attendees
+create or replace function check_event_ticketing_consistency()
+returns trigger as $$
+declare
+    v_event_id uuid;
+    v_has_discount_codes boolean;
+    v_has_paid_price_windows boolean;
+    v_has_ticket_types boolean;
+    v_payment_currency_code text;
+begin
+    -- Resolve the affected event regardless of which table fired the trigger
+    if tg_table_name = 'event' then
+        v_event_id := coalesce(new.event_id, old.event_id);
+    elsif tg_table_name = 'event_discount_code' then
+        v_event_id := coalesce(new.event_id, old.event_id);
+    elsif tg_table_name = 'event_ticket_price_window' then
+        select event_id
+        into v_event_id
+        from event_ticket_type
+        where event_ticket_type_id = coalesce(
+            new.event_ticket_type_id,
+            old.event_ticket_type_id
+        );
+    elsif tg_table_name = 'event_ticket_type' then
+        v_event_id := coalesce(new.event_id, old.event_id);
+    else
+        raise exception 'unsupported event ticketing consistency trigger table: %', tg_table_name;
+    end if;
+
+    if v_event_id is null then
+        return null;
+    end if;
+
+    -- Skip rows whose parent event no longer exists
+    select
+        exists(
+            select 1
+            from event_discount_code
+            where event_id = e.event_id
+        ),
+        exists(
+            select 1
+            from event_ticket_price_window etpw
+            join event_ticket_type ett using (event_ticket_type_id)
+            where ett.event_id = e.event_id
+            and etpw.amount_minor > 0
+        ),
+        exists(
+            select 1
+            from event_ticket_type
+            where event_id = e.event_id
+        ),
+        e.payment_currency_code
+    into
+        v_has_discount_codes,
+        v_has_paid_price_windows,
+        v_has_ticket_types,
+        v_payment_currency_code
+    from event e
+    where e.event_id = v_event_id;
+
+    if not found then
+        return null;
+    end if;
+
+    -- Enforce the persisted ticketing shape for each event
+    if not v_has_ticket_types and v_has_discount_codes then
+        raise exception 'discount_codes require ticket_types';
+    end if;
+
+    if not v_has_ticket_types and v_payment_currency_code is not null then
+        raise exception 'payment_currency_code requires ticket_types';
+    end if;
+
+    if (v_has_paid_price_windows or v_has_discount_codes) and v_payment_currency_code is null then
+        raise exception 'paid ticket types require payment_currency_code';
+    end if;
+
+    return null;
+end;
+$$ language plpgsql;
+
+create constraint trigger event_ticketing_consistency_on_event_ticket_price_window
+    after insert or update or delete on event_ticket_price_window
+    deferrable initially deferred
+    for each row
+    execute function check_event_ticketing_consistency();
@@ -0,0 +1,4 @@
+-- Let organizers embed the live stream of virtual and hybrid events.
+
+-- URL of the live stream (YouTube, Twitch or Vimeo) embedded on the event page
+alter table event add column streaming_url text check (btrim(streaming_url) <> '');
@@ -0,0 +1,14 @@
+-- Let attendees choose when they are reminded about the events they attend.
+
+-- Default reminder preference for all the events the user attends
+alter table "user"
+    add column event_reminder_preference text check (
+        event_reminder_preference in ('1-day', '1-hour', '1-week', 'none')
+    );
+
+-- Reminder preference for a specific event, overriding the user's default one
+alter table event_attendee
+    add column reminder_preference text check (
+        reminder_preference in ('1-day', '1-hour', '1-week', 'none')
+    ),
+    add column reminder_sent_for_starts_at timestamptz;
@@ -0,0 +1,10 @@
+-- Allow organizers to schedule when a draft event is published.
+
+-- Date the event is scheduled to be published and who scheduled it
+alter table event
+    add column publish_scheduled_at timestamptz,
+    add column publish_scheduled_by uuid references "user" on delete set null;
+
+-- Speed up looking for draft events that are due to be published
+create index event_publish_scheduled_at_idx on event (publish_scheduled_at)
+    where published = false and publish_scheduled_at is not null;
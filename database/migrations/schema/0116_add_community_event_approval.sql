@@ -0,0 +1,22 @@
+-- Let communities require approval before group events are published.
+
+-- Whether group events need a community admin approval to be published
+alter table community
+    add column event_approval_required boolean not null default false;
+
+-- Approval state of the event and who requested it
+alter table event
+    add column approval_status text check (
+        approval_status in ('approved', 'pending', 'rejected')
+    ),
+    add column approval_rejection_reason text,
+    add column approval_requested_at timestamptz,
+    add column approval_requested_by uuid references "user" on delete set null;
+
+-- Speed up listing the events waiting for a community approval
+create index event_approval_requested_at_idx on event (approval_requested_at)
+    where approval_status = 'pending';
+
+insert into notification_kind (name)
+values ('event-approved'), ('event-rejected')
+on conflict (name) do nothing;
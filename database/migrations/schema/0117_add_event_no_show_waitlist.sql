@@ -0,0 +1,5 @@
+-- Let organizers send attendees who often do not show up to the waiting list.
+
+-- Number of no-shows in the group's past events that sends an attendee to the waitlist
+alter table event
+    add column no_show_waitlist_threshold int check (no_show_waitlist_threshold > 0);
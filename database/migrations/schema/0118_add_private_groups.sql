@@ -0,0 +1,28 @@
+-- Let groups require an organizer approval for new members.
+
+-- Whether join requests must be approved and member content is hidden
+alter table "group"
+    add column private boolean not null default false;
+
+create table group_join_request (
+    group_id uuid not null references "group",
+    user_id uuid not null references "user",
+    created_at timestamptz default current_timestamp not null,
+    status text not null default 'pending' check (status in ('accepted', 'pending', 'rejected')),
+
+    reviewed_at timestamptz,
+    reviewed_by uuid references "user",
+
+    primary key (group_id, user_id),
+    check (
+        (status = 'pending' and reviewed_at is null and reviewed_by is null)
+        or (status in ('accepted', 'rejected') and reviewed_at is not null and reviewed_by is not null)
+    )
+);
+
+create index group_join_request_group_id_status_created_at_idx
+    on group_join_request (group_id, status, created_at);
+create index group_join_request_user_id_idx on group_join_request (user_id);
+
+-- Joining a group now reports whether the user became a member
+drop function if exists join_group(uuid, uuid, uuid);
@@ -0,0 +1,14 @@
+-- Let organizers assign roles such as volunteer or mentor to group members.
+
+-- Role displayed for the member in the group, set by the organizers
+alter table group_member
+    add column member_role text;
+
+alter table group_member
+add constraint group_member_member_role_chk check (
+    member_role is null
+    or (btrim(member_role) <> '' and char_length(member_role) <= 50)
+);
+
+-- Group custom emails can now be sent to the members with a given role
+drop function if exists resolve_group_custom_notification_recipient_ids(uuid, text, uuid, int);
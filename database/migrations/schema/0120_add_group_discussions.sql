@@ -0,0 +1,28 @@
+-- Let group members discuss topics between events, moderated by the group organizers.
+
+-- Topics started by members on the group discussion board
+create table group_discussion_topic (
+    group_discussion_topic_id uuid primary key default gen_random_uuid(),
+    group_id uuid not null references "group" on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+    title text not null check (btrim(title) <> ''),
+    content text not null check (btrim(content) <> ''),
+    created_at timestamptz default current_timestamp not null,
+    locked boolean not null default false,
+    pinned boolean not null default false
+);
+
+create index group_discussion_topic_group_id_created_at_idx on group_discussion_topic (group_id, created_at);
+create index group_discussion_topic_user_id_idx on group_discussion_topic (user_id);
+
+-- Replies posted by members to the discussion topics
+create table group_discussion_reply (
+    group_discussion_reply_id uuid primary key default gen_random_uuid(),
+    group_discussion_topic_id uuid not null references group_discussion_topic on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+    content text not null check (btrim(content) <> ''),
+    created_at timestamptz default current_timestamp not null
+);
+
+create index group_discussion_reply_group_discussion_topic_id_created_at_idx on group_discussion_reply (group_discussion_topic_id, created_at);
+create index group_discussion_reply_user_id_idx on group_discussion_reply (user_id);
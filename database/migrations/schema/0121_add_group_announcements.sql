@@ -0,0 +1,15 @@
+-- Let organizers post announcements that are pinned on the group page.
+
+-- Announcements posted by the group organizers
+create table group_announcement (
+    group_announcement_id uuid primary key default gen_random_uuid(),
+    group_id uuid not null references "group" on delete cascade,
+    title text not null check (btrim(title) <> ''),
+    content text not null check (btrim(content) <> ''),
+    created_at timestamptz default current_timestamp not null,
+    created_by uuid references "user" on delete set null,
+    pinned boolean not null default true
+);
+
+create index group_announcement_group_id_created_at_idx on group_announcement (group_id, created_at);
+create index group_announcement_created_by_idx on group_announcement (created_by);
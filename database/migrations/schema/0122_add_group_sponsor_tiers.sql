@@ -0,0 +1,19 @@
+-- Let organizers arrange their sponsors in tiers.
+
+-- Sponsorship tiers defined by the group organizers
+create table group_sponsor_tier (
+    group_sponsor_tier_id uuid primary key default gen_random_uuid(),
+    group_id uuid not null references "group" on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+    logo_size text not null default 'medium' check (logo_size in ('large', 'medium', 'small')),
+    name text not null check (btrim(name) <> ''),
+    "order" integer not null default 0
+);
+
+create unique index group_sponsor_tier_group_id_name_idx on group_sponsor_tier (group_id, lower(name));
+
+-- Tier each sponsor is displayed in on the group page
+alter table group_sponsor
+add column group_sponsor_tier_id uuid references group_sponsor_tier on delete set null;
+
+create index group_sponsor_group_sponsor_tier_id_idx on group_sponsor (group_sponsor_tier_id);
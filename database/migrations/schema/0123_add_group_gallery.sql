@@ -0,0 +1,13 @@
+-- Let organizers curate the group gallery built from their uploads and event photos.
+
+-- Photo highlighted as the cover of the group gallery
+alter table "group" add column gallery_cover_url text;
+
+-- Photos hidden from the group gallery by the organizers
+create table group_gallery_hidden_photo (
+    group_id uuid not null references "group" on delete cascade,
+    url text not null check (btrim(url) <> ''),
+    created_at timestamptz default current_timestamp not null,
+
+    primary key (group_id, url)
+);
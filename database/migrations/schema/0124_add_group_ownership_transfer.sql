@@ -0,0 +1,31 @@
+-- Track group owners and let them transfer the ownership to another organizer.
+
+-- Organizer responsible for the group
+alter table "group" add column owner_user_id uuid references "user" (user_id);
+
+create index group_owner_user_id_idx on "group" (owner_user_id);
+
+-- Existing groups are owned by their earliest accepted admin
+update "group" g
+set owner_user_id = (
+    select gt.user_id
+    from group_team gt
+    where gt.group_id = g.group_id
+    and gt.accepted = true
+    and gt.role = 'admin'
+    order by gt.created_at asc, gt.user_id asc
+    limit 1
+);
+
+-- Pending ownership transfers, awaiting the acceptance of the new owner
+create table group_ownership_transfer (
+    group_id uuid primary key references "group" on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+    created_by uuid not null references "user" (user_id),
+    user_id uuid not null,
+
+    foreign key (group_id, user_id) references group_team (group_id, user_id) on delete cascade
+);
+
+create index group_ownership_transfer_created_by_idx on group_ownership_transfer (created_by);
+create index group_ownership_transfer_user_id_idx on group_ownership_transfer (user_id);
@@ -0,0 +1,8 @@
+-- Let community admins merge duplicate groups into a target group.
+
+-- Group a merged group was folded into, used to redirect its old URLs
+alter table "group"
+    add column merged_at timestamptz,
+    add column merged_into_group_id uuid references "group" (group_id);
+
+create index group_merged_into_group_id_idx on "group" (merged_into_group_id);
@@ -0,0 +1,4 @@
+-- Let community admins archive groups, keeping their page as a historical record.
+
+-- When the group was archived, if it is archived
+alter table "group" add column archived_at timestamptz;
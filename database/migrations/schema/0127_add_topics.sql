@@ -0,0 +1,36 @@
+-- Let communities manage a taxonomy of topics assignable to groups and events.
+
+-- Topics defined by the community team
+create table topic (
+    topic_id uuid primary key default gen_random_uuid(),
+    community_id uuid not null references community,
+    created_at timestamptz default current_timestamp not null,
+    name text not null check (btrim(name) <> ''),
+    normalized_name text not null check (btrim(normalized_name) <> '')
+        generated always as (regexp_replace(lower(name), '[^\w]+', '-', 'g')) stored,
+
+    unique (name, community_id),
+    unique (normalized_name, community_id)
+);
+
+create index topic_community_id_idx on topic (community_id);
+
+-- Topics assigned to each group
+create table group_topic (
+    group_id uuid not null references "group" on delete cascade,
+    topic_id uuid not null references topic on delete cascade,
+
+    primary key (group_id, topic_id)
+);
+
+create index group_topic_topic_id_idx on group_topic (topic_id);
+
+-- Topics assigned to each event
+create table event_topic (
+    event_id uuid not null references event on delete cascade,
+    topic_id uuid not null references topic on delete cascade,
+
+    primary key (event_id, topic_id)
+);
+
+create index event_topic_topic_id_idx on event_topic (topic_id);
@@ -0,0 +1,5 @@
+-- Let organizers customize the message sent to new group members.
+
+-- Store the markdown message included in the group welcome email
+alter table "group"
+    add column welcome_message text check (btrim(welcome_message) <> '');
@@ -0,0 +1,14 @@
+-- Let groups ask questions to users joining them.
+
+-- Add group join question storage.
+alter table "group"
+    add column join_questions jsonb not null default '[]'::jsonb;
+
+-- Add member and join request answers.
+alter table group_member
+    add column join_answers jsonb;
+alter table group_join_request
+    add column join_answers jsonb;
+
+-- Replace the join function with the version accepting answers.
+drop function if exists join_group(uuid, uuid, uuid);
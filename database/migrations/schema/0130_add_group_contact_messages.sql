@@ -0,0 +1,19 @@
+-- Let users contact the organizers of a group from the group page.
+
+-- Messages sent to the group organizers
+create table group_contact_message (
+    group_contact_message_id uuid primary key default gen_random_uuid(),
+    group_id uuid not null references "group" on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+    subject text not null check (btrim(subject) <> ''),
+    body text not null check (btrim(body) <> ''),
+    created_at timestamptz default current_timestamp not null
+);
+
+create index group_contact_message_group_id_idx on group_contact_message (group_id);
+create index group_contact_message_user_id_created_at_idx on group_contact_message (user_id, created_at);
+
+-- Add notification kind for the messages sent to the group organizers.
+insert into notification_kind (name)
+values ('group-contact-message')
+on conflict (name) do nothing;
@@ -0,0 +1,21 @@
+-- Detect groups with no events in a while and report them to community admins.
+
+-- Months without events after which a group is considered dormant, and
+-- whether admins receive a monthly report of the dormant groups
+alter table community
+    add column dormant_group_months int not null default 6,
+    add column dormant_groups_report_enabled boolean not null default false,
+    add column dormant_groups_report_sent_at timestamptz,
+    add constraint community_dormant_group_months_chk check (
+        dormant_group_months between 1 and 60
+    );
+
+-- When the group became dormant (null when it is not)
+alter table "group"
+    add column dormant_since timestamptz;
+
+-- Speed up listing the dormant groups of a community
+create index group_community_id_dormant_since_idx on "group" (community_id)
+    where dormant_since is not null;
+
+insert into notification_kind (name) values ('dormant-groups-report');
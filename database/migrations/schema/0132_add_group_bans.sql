@@ -0,0 +1,16 @@
+-- Let organizers ban users from their groups.
+
+-- Users banned from a group, who cannot rejoin it or attend its events
+create table group_ban (
+    group_id uuid not null references "group" on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+    reason text not null check (btrim(reason) <> ''),
+
+    created_by uuid references "user" on delete set null,
+
+    primary key (group_id, user_id)
+);
+
+create index group_ban_created_by_idx on group_ban (created_by);
+create index group_ban_user_id_idx on group_ban (user_id);
@@ -0,0 +1,14 @@
+-- Let organizers save events as reusable templates for their groups.
+
+-- Event templates saved by the group organizers
+create table event_template (
+    event_template_id uuid primary key default gen_random_uuid(),
+    group_id uuid not null references "group" on delete cascade,
+    name text not null check (btrim(name) <> ''),
+    details jsonb not null,
+    created_at timestamptz default current_timestamp not null,
+    created_by uuid references "user" on delete set null
+);
+
+create unique index event_template_group_id_lower_name_idx on event_template (group_id, lower(name));
+create index event_template_created_by_idx on event_template (created_by);
@@ -0,0 +1,17 @@
+-- Let communities be served from their own custom domains.
+
+-- Custom domains pointing to a community, served once their ownership has been
+-- verified with a DNS TXT record
+create table community_domain (
+    community_domain_id uuid primary key default gen_random_uuid(),
+    community_id uuid not null references community on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+    domain text not null unique check (domain <> '' and domain = lower(btrim(domain))),
+    verification_token text not null default encode(gen_random_bytes(16), 'hex'),
+
+    created_by uuid references "user" on delete set null,
+    verified_at timestamptz
+);
+
+create index community_domain_community_id_idx on community_domain (community_id);
+create index community_domain_created_by_idx on community_domain (created_by);
@@ -0,0 +1,10 @@
+-- Allow communities to customize the theme of their pages.
+
+-- Store the community theme overrides
+alter table community
+    add column theme_favicon_url text check (btrim(theme_favicon_url) <> ''),
+    add column theme_font_family text check (
+        theme_font_family in ('inter', 'monospace', 'serif', 'system')
+    ),
+    add column theme_header_logo_url text check (btrim(theme_header_logo_url) <> ''),
+    add column theme_primary_color text check (theme_primary_color ~ '^#[0-9a-fA-F]{6}$');
@@ -0,0 +1,56 @@
+-- Let communities send newsletters to the users who subscribe to them.
+
+-- Users subscribed to the newsletter of a community
+create table community_newsletter_subscriber (
+    community_id uuid not null references community on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+
+    primary key (community_id, user_id)
+);
+
+create index community_newsletter_subscriber_user_id_idx on community_newsletter_subscriber (user_id);
+
+-- Newsletter issues composed by the community team, drafts until they are sent
+create table community_newsletter_issue (
+    community_newsletter_issue_id uuid primary key default gen_random_uuid(),
+    body text not null check (btrim(body) <> ''),
+    community_id uuid not null references community on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+    subject text not null check (btrim(subject) <> ''),
+
+    created_by uuid references "user" on delete set null,
+    recipient_count int check (recipient_count >= 0),
+    sent_at timestamptz,
+    sent_by uuid references "user" on delete set null,
+
+    check ((sent_at is null) = (recipient_count is null))
+);
+
+create index community_newsletter_issue_community_id_idx on community_newsletter_issue (community_id);
+create index community_newsletter_issue_created_by_idx on community_newsletter_issue (created_by);
+create index community_newsletter_issue_sent_by_idx on community_newsletter_issue (sent_by);
+
+-- Subscribers snapshotted when an issue is sent, enqueued in batches
+create table community_newsletter_issue_recipient (
+    community_newsletter_issue_id uuid not null references community_newsletter_issue on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+
+    enqueued_at timestamptz,
+
+    primary key (community_newsletter_issue_id, user_id)
+);
+
+create index community_newsletter_issue_recipient_pending_idx
+    on community_newsletter_issue_recipient (community_newsletter_issue_id)
+    where enqueued_at is null;
+create index community_newsletter_issue_recipient_user_id_idx
+    on community_newsletter_issue_recipient (user_id);
+
+-- Track the newsletter issue each notification delivers
+alter table notification
+    add column community_newsletter_issue_id uuid references community_newsletter_issue on delete set null;
+
+create index notification_community_newsletter_issue_id_idx on notification (community_newsletter_issue_id);
+
+insert into notification_kind (name, optional_notification) values ('community-newsletter', true);
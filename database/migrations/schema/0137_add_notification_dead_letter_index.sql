@@ -0,0 +1,4 @@
+-- Let community teams review and requeue the notifications whose delivery failed.
+
+create index notification_dead_letter_idx on notification (processed_at desc, notification_id)
+where delivery_status in ('delivery-unknown', 'failed');
@@ -0,0 +1,8 @@
+-- Let groups verify the webhooks sent by their own Zoom app.
+
+-- Store the secret token used to sign the group app webhooks
+alter table group_zoom_credentials
+add column webhook_secret_token text check (btrim(webhook_secret_token) <> '');
+
+-- Resolve the group webhook secrets from the account in the webhook payload
+create index group_zoom_credentials_account_id_idx on group_zoom_credentials (account_id);
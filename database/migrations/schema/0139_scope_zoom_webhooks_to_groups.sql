@@ -0,0 +1,6 @@
+-- Only let group Zoom apps update the meetings of their own group.
+
+-- Replace the webhook functions with the versions accepting a group.
+drop function if exists append_meeting_recording_url(text, text, text);
+drop function if exists record_meeting_participant_joined(text, text);
+drop function if exists update_meeting_provider_status(text, text, text, timestamptz);
@@ -0,0 +1,3 @@
+-- Drop the recurrence rule of event series, as series events are published
+-- individually and the rule is not used by any calendar output.
+alter table event_series drop column recurrence_rule;
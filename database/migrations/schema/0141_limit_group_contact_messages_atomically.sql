@@ -0,0 +1,5 @@
+-- Check the group contact messages limit when adding the message.
+
+-- Replace the contact message function with the version enforcing the limit.
+drop function if exists add_group_contact_message(uuid, uuid, uuid, text, text);
+drop function if exists count_user_group_contact_messages_since(uuid, timestamptz);
@@ -41,7 +41,9 @@ insert into community (
     description,
     display_name,
     logo_url,
-    name
+    name,
+    theme_font_family,
+    theme_primary_color
 ) values (
     'https://example.com/community-ad',
     'https://example.com/community-ad-banner.png',
@@ -52,7 +54,9 @@ insert into community (
     'A community used by Rust database contract tests',
     'Contract Community',
     'https://example.com/community-logo.png',
-    'contract-community'
+    'contract-community',
+    'system',
+    '#1B4F72'
 );
 
 -- ============================================================================
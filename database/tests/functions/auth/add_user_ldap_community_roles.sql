@@ -0,0 +1,133 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0bd0000-0000-0000-0000-000000000001'
+\set otherCommunityID 'c0bd0000-0000-0000-0000-000000000002'
+\set userID 'c0bd0000-0000-0000-0000-000000000011'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- User
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'userID', 'hash', 'user@example.com', true, 'user');
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'directory-community',
+    'Directory Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+), (
+    :'otherCommunityID',
+    'other-community',
+    'Other Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Existing membership
+insert into community_team (community_id, user_id, accepted, role)
+values (:'otherCommunityID', :'userID', false, 'viewer');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not fail when mappings reference unknown communities
+select lives_ok(
+    $$
+        select add_user_ldap_community_roles(
+            'c0bd0000-0000-0000-0000-000000000011'::uuid,
+            '[
+                {"community_name": "directory-community", "role": "groups-manager"},
+                {"community_name": "other-community", "role": "admin"},
+                {"community_name": "unknown-community", "role": "admin"}
+            ]'::jsonb
+        )
+    $$,
+    'Should not fail when mappings reference unknown communities'
+);
+
+-- Should add an accepted membership with the mapped role
+select results_eq(
+    format(
+        $$
+            select accepted, role
+            from community_team
+            where community_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'communityID',
+        :'userID'
+    ),
+    $$ values (true, 'groups-manager') $$,
+    'Should add an accepted membership with the mapped role'
+);
+
+-- Should keep existing memberships untouched
+select results_eq(
+    format(
+        $$
+            select accepted, role
+            from community_team
+            where community_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'otherCommunityID',
+        :'userID'
+    ),
+    $$ values (false, 'viewer') $$,
+    'Should keep existing memberships untouched'
+);
+
+-- Should only audit the created membership
+select results_eq(
+    $$
+        select action, actor_user_id, community_id, resource_id, details
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'community_team_member_added',
+            null::uuid,
+            %L::uuid,
+            %L::uuid,
+            jsonb_build_object('role', 'groups-manager')
+        )
+        $$,
+        :'communityID',
+        :'userID'
+    ),
+    'Should only audit the created membership'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
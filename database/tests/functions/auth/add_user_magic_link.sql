@@ -0,0 +1,86 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set unverifiedUserID 'c0bc0000-0000-0000-0000-000000000002'
+\set userID 'c0bc0000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'userID', 'hash', 'User@Example.com', true, 'user'),
+    (:'unverifiedUserID', 'hash', 'unverified@example.com', false, 'unverified');
+
+-- Expired link
+insert into user_magic_link (expires_at, token_hash, user_id)
+values (current_timestamp - interval '1 minute', 'expired-hash', :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should store the link for the user matching the email case-insensitively
+select is(
+    add_user_magic_link(' user@example.com ', 'hash-1'),
+    :'userID'::uuid,
+    'Should store the link and return the user identifier'
+);
+
+-- Should make the link valid for 15 minutes
+select results_eq(
+    $$
+        select user_id, expires_at = current_timestamp + interval '15 minutes'
+        from user_magic_link
+        where token_hash = 'hash-1'
+    $$,
+    format($$ values (%L::uuid, true) $$, :'userID'),
+    'Should make the link valid for 15 minutes'
+);
+
+-- Should discard the expired links of the user
+select is(
+    (select count(*)::int from user_magic_link where token_hash = 'expired-hash'),
+    0,
+    'Should discard the expired links of the user'
+);
+
+-- Should not store links for unverified users
+select is(
+    add_user_magic_link('unverified@example.com', 'hash-2'),
+    null::uuid,
+    'Should not store links for unverified users'
+);
+
+-- Should not store links for unknown emails
+select is(
+    add_user_magic_link('unknown@example.com', 'hash-3'),
+    null::uuid,
+    'Should not store links for unknown emails'
+);
+
+-- Should limit the number of links pending to be used
+select add_user_magic_link('user@example.com', 'hash-4');
+select add_user_magic_link('user@example.com', 'hash-5');
+select is(
+    add_user_magic_link('user@example.com', 'hash-6'),
+    null::uuid,
+    'Should limit the number of links pending to be used'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,98 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set userID 'c0bb0000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- User
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'userID', 'hash', 'user@example.com', true, 'user');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should store the passkey and return its identifier
+select is(
+    add_user_passkey(:'userID'::uuid, 'credential-1', ' Laptop ', '{"cred": {"counter": 0}}'::jsonb),
+    (select user_passkey_id from user_passkey where credential_id = 'credential-1'),
+    'Should store the passkey and return its identifier'
+);
+
+-- Should store the passkey details for the user
+select results_eq(
+    $$
+        select name, passkey, user_id, last_used_at
+        from user_passkey
+        where credential_id = 'credential-1'
+    $$,
+    format(
+        $$ values ('Laptop'::text, '{"cred": {"counter": 0}}'::jsonb, %L::uuid, null::timestamptz) $$,
+        :'userID'
+    ),
+    'Should store the trimmed name and passkey for the user'
+);
+
+-- Should reject credentials that are already registered
+select throws_ok(
+    format(
+        $$select add_user_passkey(%L::uuid, 'credential-1', 'Phone', '{}'::jsonb)$$,
+        :'userID'
+    ),
+    '23505',
+    null,
+    'Should reject credentials that are already registered'
+);
+
+-- Should reject empty names
+select throws_ok(
+    format(
+        $$select add_user_passkey(%L::uuid, 'credential-2', '  ', '{}'::jsonb)$$,
+        :'userID'
+    ),
+    '23514',
+    null,
+    'Should reject empty names'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'user_passkey_added',
+            %L::uuid,
+            'user_passkey',
+            (select user_passkey_id from user_passkey where credential_id = 'credential-1')
+        )
+        $$,
+        :'userID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,86 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set unverifiedUserID 'c0be0000-0000-0000-0000-000000000002'
+\set userID 'c0be0000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'userID', 'hash', 'User@Example.com', true, 'user'),
+    (:'unverifiedUserID', 'hash', 'unverified@example.com', false, 'unverified');
+
+-- Expired code
+insert into user_password_reset (expires_at, code_hash, user_id)
+values (current_timestamp - interval '1 minute', 'expired-hash', :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should store the code for the user matching the email case-insensitively
+select is(
+    add_user_password_reset(' user@example.com ', 'hash-1'),
+    :'userID'::uuid,
+    'Should store the code and return the user identifier'
+);
+
+-- Should make the code valid for 1 hour
+select results_eq(
+    $$
+        select user_id, expires_at = current_timestamp + interval '1 hour'
+        from user_password_reset
+        where code_hash = 'hash-1'
+    $$,
+    format($$ values (%L::uuid, true) $$, :'userID'),
+    'Should make the code valid for 1 hour'
+);
+
+-- Should discard the expired codes of the user
+select is(
+    (select count(*)::int from user_password_reset where code_hash = 'expired-hash'),
+    0,
+    'Should discard the expired codes of the user'
+);
+
+-- Should not store codes for unverified users
+select is(
+    add_user_password_reset('unverified@example.com', 'hash-2'),
+    null::uuid,
+    'Should not store codes for unverified users'
+);
+
+-- Should not store codes for unknown emails
+select is(
+    add_user_password_reset('unknown@example.com', 'hash-3'),
+    null::uuid,
+    'Should not store codes for unknown emails'
+);
+
+-- Should limit the number of codes pending to be used
+select add_user_password_reset('user@example.com', 'hash-4');
+select add_user_password_reset('user@example.com', 'hash-5');
+select is(
+    add_user_password_reset('user@example.com', 'hash-6'),
+    null::uuid,
+    'Should limit the number of codes pending to be used'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,100 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0c10000-0000-0000-0000-000000000001'
+\set openCommunityID 'c0c10000-0000-0000-0000-000000000002'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    allowed_email_domains
+) values (
+    :'communityID',
+    'corporate-community',
+    'Corporate Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    array['example.com', 'Example.org']
+), (
+    :'openCommunityID',
+    'open-community',
+    'Open Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    null
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should allow emails from an allowed domain
+select is(
+    community_email_domain_allowed('corporate-community', 'user@example.com'),
+    true,
+    'Should allow emails from an allowed domain'
+);
+
+-- Should compare domains case-insensitively
+select is(
+    community_email_domain_allowed('corporate-community', 'user@EXAMPLE.ORG'),
+    true,
+    'Should compare domains case-insensitively'
+);
+
+-- Should reject emails from other domains
+select is(
+    community_email_domain_allowed('corporate-community', 'user@other.com'),
+    false,
+    'Should reject emails from other domains'
+);
+
+-- Should reject subdomains of allowed domains
+select is(
+    community_email_domain_allowed('corporate-community', 'user@mail.example.com'),
+    false,
+    'Should reject subdomains of allowed domains'
+);
+
+-- Should allow any email when the community has no allowed domains
+select is(
+    community_email_domain_allowed('open-community', 'user@other.com'),
+    true,
+    'Should allow any email when the community has no allowed domains'
+);
+
+-- Should allow any email for unknown communities
+select is(
+    community_email_domain_allowed('unknown-community', 'user@other.com'),
+    true,
+    'Should allow any email for unknown communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
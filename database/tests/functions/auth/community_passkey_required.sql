@@ -0,0 +1,80 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0bb0000-0000-0000-0000-000000000051'
+\set otherCommunityID 'c0bb0000-0000-0000-0000-000000000052'
+\set unknownCommunityID 'c0bb0000-0000-0000-0000-000000000053'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    passkey_required
+) values (
+    :'communityID',
+    'passkey-community',
+    'Passkey Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    true
+), (
+    :'otherCommunityID',
+    'other-community',
+    'Other Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    false
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return true when the community requires passkeys
+select is(
+    community_passkey_required(:'communityID'::uuid),
+    true,
+    'Should return true when the community requires passkeys'
+);
+
+-- Should return false when the community does not require passkeys
+select is(
+    community_passkey_required(:'otherCommunityID'::uuid),
+    false,
+    'Should return false when the community does not require passkeys'
+);
+
+-- Should return false for unknown communities
+select is(
+    community_passkey_required(:'unknownCommunityID'::uuid),
+    false,
+    'Should return false for unknown communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,85 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0bb0000-0000-0000-0000-000000000012'
+\set passkeyID 'c0bb0000-0000-0000-0000-000000000013'
+\set userID 'c0bb0000-0000-0000-0000-000000000011'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userID', 'hash', 'user@example.com', true, 'user'),
+    (:'otherUserID', 'hash', 'other@example.com', true, 'other');
+
+-- Passkey
+insert into user_passkey (user_passkey_id, credential_id, name, passkey, user_id)
+values (:'passkeyID', 'credential-1', 'Laptop', '{}'::jsonb, :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not delete passkeys of other users
+select throws_ok(
+    format(
+        $$select delete_user_passkey(%L::uuid, %L::uuid)$$,
+        :'otherUserID',
+        :'passkeyID'
+    ),
+    'passkey not found',
+    'Should not delete passkeys of other users'
+);
+select ok(
+    exists (select 1 from user_passkey where user_passkey_id = :'passkeyID'::uuid),
+    'Should keep the passkey when another user tries to delete it'
+);
+
+-- Should delete the passkey of the user
+select lives_ok(
+    format(
+        $$select delete_user_passkey(%L::uuid, %L::uuid)$$,
+        :'userID',
+        :'passkeyID'
+    ),
+    'Should delete the passkey of the user'
+);
+select ok(
+    not exists (select 1 from user_passkey where user_passkey_id = :'passkeyID'::uuid),
+    'Should remove the deleted passkey'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$ values ('user_passkey_deleted', %L::uuid, 'user_passkey', %L::uuid) $$,
+        :'userID',
+        :'passkeyID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
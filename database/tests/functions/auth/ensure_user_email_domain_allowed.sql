@@ -0,0 +1,94 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set allowedUserID 'c0540000-0000-0000-0000-000000000001'
+\set openCommunityID 'c0540000-0000-0000-0000-000000000002'
+\set otherUserID 'c0540000-0000-0000-0000-000000000003'
+\set restrictedCommunityID 'c0540000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    allowed_email_domains
+) values (
+    :'restrictedCommunityID',
+    'restricted-community',
+    'Restricted Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    array['example.org']
+), (
+    :'openCommunityID',
+    'open-community',
+    'Open Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    null
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'allowedUserID', 'hash1', 'alice@Example.org', true, 'alice'),
+    (:'otherUserID', 'hash2', 'bob@other.test', true, 'bob');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should pass for users with an email domain allowed by the community
+select lives_ok(
+    format(
+        'select ensure_user_email_domain_allowed(%L::uuid, %L::uuid)',
+        :'restrictedCommunityID', :'allowedUserID'
+    ),
+    'Should pass for users with an email domain allowed by the community'
+);
+
+-- Should raise for users with an email domain not allowed by the community
+select throws_ok(
+    format(
+        'select ensure_user_email_domain_allowed(%L::uuid, %L::uuid)',
+        :'restrictedCommunityID', :'otherUserID'
+    ),
+    'your email domain is not allowed in this community',
+    'Should raise for users with an email domain not allowed by the community'
+);
+
+-- Should pass for any user when the community does not restrict domains
+select lives_ok(
+    format(
+        'select ensure_user_email_domain_allowed(%L::uuid, %L::uuid)',
+        :'openCommunityID', :'otherUserID'
+    ),
+    'Should pass for any user when the community does not restrict domains'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,79 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0c20000-0000-0000-0000-000000000001'
+\set openCommunityID 'c0c20000-0000-0000-0000-000000000002'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    required_oidc_provider
+) values (
+    :'communityID',
+    'sso-community',
+    'SSO Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    'linuxfoundation'
+), (
+    :'openCommunityID',
+    'open-community',
+    'Open Community',
+    'Test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    null
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the provider required by the community
+select is(
+    get_community_required_oidc_provider('sso-community'),
+    'linuxfoundation',
+    'Should return the provider required by the community'
+);
+
+-- Should return null when the community does not require a provider
+select is(
+    get_community_required_oidc_provider('open-community'),
+    null,
+    'Should return null when the community does not require a provider'
+);
+
+-- Should return null for unknown communities
+select is(
+    get_community_required_oidc_provider('unknown-community'),
+    null,
+    'Should return null for unknown communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
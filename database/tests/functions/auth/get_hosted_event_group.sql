@@ -0,0 +1,125 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0ef0000-0000-0000-0000-000000000001'
+\set deletedEventID 'c0ef0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0ef0000-0000-0000-0000-000000000003'
+\set eventID 'c0ef0000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0ef0000-0000-0000-0000-000000000005'
+\set groupID 'c0ef0000-0000-0000-0000-000000000006'
+\set hostID 'c0ef0000-0000-0000-0000-000000000007'
+\set userID 'c0ef0000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'hosted-event-community',
+    'Hosted Event Community',
+    'Community for hosted event tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Hosted Event Group', 'hosted-event-group');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'hostID', gen_random_bytes(32), 'host@example.com', true, 'host'),
+    (:'userID', gen_random_bytes(32), 'user@example.com', true, 'user');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone,
+    deleted
+) values
+    (:'eventID', :'groupID', 'Hosted', 'hosted', 'Hosted', :'eventCategoryID', 'in-person', 'UTC', false),
+    (:'deletedEventID', :'groupID', 'Deleted', 'deleted', 'Deleted', :'eventCategoryID', 'in-person', 'UTC', true);
+
+-- Event hosts
+insert into event_host (event_id, user_id) values
+    (:'eventID', :'hostID'),
+    (:'deletedEventID', :'hostID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the community and group of an event hosted by the user
+select results_eq(
+    format(
+        'select community_id, group_id from get_hosted_event_group(%L::uuid, %L::uuid)',
+        :'eventID',
+        :'hostID'
+    ),
+    format(
+        'select %L::uuid, %L::uuid',
+        :'communityID',
+        :'groupID'
+    ),
+    'Should return the community and group of an event hosted by the user'
+);
+
+-- Should return no rows when the user does not host the event
+select is_empty(
+    format(
+        'select * from get_hosted_event_group(%L::uuid, %L::uuid)',
+        :'eventID',
+        :'userID'
+    ),
+    'Should return no rows when the user does not host the event'
+);
+
+-- Should return no rows for deleted events
+select is_empty(
+    format(
+        'select * from get_hosted_event_group(%L::uuid, %L::uuid)',
+        :'deletedEventID',
+        :'hostID'
+    ),
+    'Should return no rows for deleted events'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -170,7 +170,8 @@ select is(
         'optional_notifications_enabled', true,
         'provider', jsonb_build_object('github', jsonb_build_object('username', 'testuser-gh')),
         'user_id', :'userWithTeamsID'::uuid,
-        'username', 'testuser'
+        'username', 'testuser',
+        'weekly_digest_enabled', true
     ),
     'Should return user without password when include_password is false'
 );
@@ -192,7 +193,8 @@ select is(
         'password', 'hashed_password_here',
         'provider', jsonb_build_object('github', jsonb_build_object('username', 'testuser-gh')),
         'user_id', :'userWithTeamsID'::uuid,
-        'username', 'testuser'
+        'username', 'testuser',
+        'weekly_digest_enabled', true
     ),
     'Should return user with password when include_password is true'
 );
@@ -216,7 +218,8 @@ select is(
         'name', 'No Groups User',
         'optional_notifications_enabled', true,
         'user_id', :'userNoTeamsID'::uuid,
-        'username', 'nogroupsuser'
+        'username', 'nogroupsuser',
+        'weekly_digest_enabled', true
     ),
     'Should return false team membership fields when user has no team memberships'
 );
@@ -233,7 +236,8 @@ select is(
         'name', 'Group Only User',
         'optional_notifications_enabled', true,
         'user_id', :'userGroupOnlyID'::uuid,
-        'username', 'grouponlyuser'
+        'username', 'grouponlyuser',
+        'weekly_digest_enabled', true
     ),
     'Should return correct team flags when user is only in group team'
 );
@@ -250,7 +254,8 @@ select is(
         'name', 'Community Only User',
         'optional_notifications_enabled', true,
         'user_id', :'userCommunityOnlyID'::uuid,
-        'username', 'communityonlyuser'
+        'username', 'communityonlyuser',
+        'weekly_digest_enabled', true
     ),
     'Should return belongs_to_any_group_team true when user is in community team'
 );
@@ -267,7 +272,8 @@ select is(
         'name', 'Both Teams User',
         'optional_notifications_enabled', true,
         'user_id', :'userBothTeamsID'::uuid,
-        'username', 'bothuser'
+        'username', 'bothuser',
+        'weekly_digest_enabled', true
     ),
     'Should return both team flags true when user is in both teams'
 );
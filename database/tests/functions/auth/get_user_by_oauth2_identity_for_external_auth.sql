@@ -0,0 +1,122 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(7);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set gitlabUserID 'c0bf0000-0000-0000-0000-000000000003'
+\set googleUserID 'c0bf0000-0000-0000-0000-000000000004'
+\set registeredUserID 'c0bf0000-0000-0000-0000-000000000001'
+\set unverifiedUserID 'c0bf0000-0000-0000-0000-000000000002'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    provider,
+    registration_status,
+    username
+) values (
+    :'registeredUserID',
+    'registered-hash',
+    'registered@example.com',
+    true,
+    jsonb_build_object('github', jsonb_build_object('id', 1001, 'username', 'octocat')),
+    'registered',
+    'registered-user'
+), (
+    :'unverifiedUserID',
+    'unverified-hash',
+    'unverified@example.com',
+    false,
+    jsonb_build_object('github', jsonb_build_object('id', 1002, 'username', 'unverified')),
+    'registered',
+    'unverified-user'
+), (
+    :'gitlabUserID',
+    'gitlab-hash',
+    'gitlab@example.com',
+    true,
+    jsonb_build_object('gitlab', jsonb_build_object('id', 1001, 'username', 'tanuki')),
+    'registered',
+    'gitlab-user'
+), (
+    :'googleUserID',
+    'google-hash',
+    'google@example.com',
+    true,
+    jsonb_build_object('google', jsonb_build_object('id', '108001', 'username', 'google')),
+    'registered',
+    'google-user'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return verified registered users by GitHub identity
+select is(
+    get_user_by_oauth2_identity_for_external_auth('github', '1001')::jsonb->>'user_id',
+    :'registeredUserID',
+    'Should return verified registered users by GitHub identity'
+);
+
+-- Should return verified registered users by GitLab identity
+select is(
+    get_user_by_oauth2_identity_for_external_auth('gitlab', '1001')::jsonb->>'user_id',
+    :'gitlabUserID',
+    'Should return verified registered users by GitLab identity'
+);
+
+-- Should return verified registered users by Google identity
+select is(
+    get_user_by_oauth2_identity_for_external_auth('google', '108001')::jsonb->>'user_id',
+    :'googleUserID',
+    'Should return verified registered users by Google identity'
+);
+
+-- Should not return unverified registered users
+select is(
+    get_user_by_oauth2_identity_for_external_auth('github', '1002')::jsonb,
+    null::jsonb,
+    'Should not return unverified registered users'
+);
+
+-- Should return null when the identity does not exist
+select is(
+    get_user_by_oauth2_identity_for_external_auth('github', '1003')::jsonb,
+    null::jsonb,
+    'Should return null when the identity does not exist'
+);
+
+-- Should return null when the identity is empty
+select is(
+    get_user_by_oauth2_identity_for_external_auth('github', '')::jsonb,
+    null::jsonb,
+    'Should return null when the identity is empty'
+);
+
+-- Should return null for unsupported providers
+select is(
+    get_user_by_oauth2_identity_for_external_auth('linuxfoundation', '1001')::jsonb,
+    null::jsonb,
+    'Should return null for unsupported providers'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
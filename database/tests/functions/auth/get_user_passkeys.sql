@@ -0,0 +1,52 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0bb0000-0000-0000-0000-000000000022'
+\set userID 'c0bb0000-0000-0000-0000-000000000021'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userID', 'hash', 'user@example.com', true, 'user'),
+    (:'otherUserID', 'hash', 'other@example.com', true, 'other');
+
+-- Passkeys
+insert into user_passkey (created_at, credential_id, name, passkey, user_id) values
+    ('2025-01-02 00:00:00+00', 'credential-2', 'Phone', '{"id": 2}'::jsonb, :'userID'),
+    ('2025-01-01 00:00:00+00', 'credential-1', 'Laptop', '{"id": 1}'::jsonb, :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the passkeys of the user ordered by creation date
+select is(
+    get_user_passkeys(:'userID'::uuid),
+    '[{"id": 1}, {"id": 2}]'::jsonb,
+    'Should return the passkeys of the user ordered by creation date'
+);
+
+-- Should return an empty array when the user has no passkeys
+select is(
+    get_user_passkeys(:'otherUserID'::uuid),
+    '[]'::jsonb,
+    'Should return an empty array when the user has no passkeys'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
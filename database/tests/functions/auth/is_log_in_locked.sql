@@ -0,0 +1,71 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Recent failed attempts for a username
+insert into log_in_failure (ip_address, username)
+select '192.0.2.1', 'User'
+from generate_series(1, 5);
+
+-- Old failed attempts for another username
+insert into log_in_failure (created_at, ip_address, username)
+select current_timestamp - interval '16 minutes', '192.0.2.2', 'old'
+from generate_series(1, 5);
+
+-- Recent failed attempts from an address across usernames
+insert into log_in_failure (ip_address, username)
+select '198.51.100.1', 'user-' || i
+from generate_series(1, 20) as i;
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should lock usernames with too many recent failures case-insensitively
+select is(
+    is_log_in_locked(' user ', null),
+    true,
+    'Should lock usernames with too many recent failures'
+);
+
+-- Should not lock usernames whose failures are old
+select is(
+    is_log_in_locked('old', '192.0.2.2'),
+    false,
+    'Should not lock usernames whose failures are old'
+);
+
+-- Should lock addresses with too many recent failures
+select is(
+    is_log_in_locked('another', '198.51.100.1'),
+    true,
+    'Should lock addresses with too many recent failures'
+);
+
+-- Should not lock usernames without failures from other addresses
+select is(
+    is_log_in_locked('another', '203.0.113.1'),
+    false,
+    'Should not lock usernames without failures'
+);
+
+-- Should not lock usernames without failures when the address is unknown
+select is(
+    is_log_in_locked('another', null),
+    false,
+    'Should not lock usernames when the address is unknown'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,103 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0bf0000-0000-0000-0000-000000000012'
+\set userID 'c0bf0000-0000-0000-0000-000000000011'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, password, provider, username)
+values
+    (:'userID', 'hash', 'user@example.com', true, 'password', null, 'user'),
+    (
+        :'otherUserID',
+        'other-hash',
+        'other@example.com',
+        true,
+        null,
+        jsonb_build_object(
+            'github', jsonb_build_object('id', 2002, 'username', 'other'),
+            'gitlab', jsonb_build_object('id', 3002, 'username', 'other')
+        ),
+        'other-user'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should link a GitHub identity to the user
+select lives_ok(
+    format(
+        $$ select link_user_provider(%L::uuid, '{"github": {"id": 2001, "username": "octocat"}}'::jsonb) $$,
+        :'userID'
+    ),
+    'Should link a GitHub identity to the user'
+);
+
+-- Should keep other providers when linking a new one
+select lives_ok(
+    format(
+        $$
+        select link_user_provider(
+            %L::uuid,
+            '{"linuxfoundation": {"issuer": "https://issuer.example.com", "subject": "auth0|user", "username": "lf-user"}}'::jsonb
+        )
+        $$,
+        :'userID'
+    ),
+    'Should link a Linux Foundation identity to the user'
+);
+select is(
+    (select provider #>> '{github,username}' from "user" where user_id = :'userID'),
+    'octocat',
+    'Should keep other providers when linking a new one'
+);
+
+-- Should reject identities owned by another user
+select throws_ok(
+    format(
+        $$ select link_user_provider(%L::uuid, '{"github": {"id": 2002, "username": "other"}}'::jsonb) $$,
+        :'userID'
+    ),
+    'external auth identity belongs to another user',
+    'Should reject identities owned by another user'
+);
+
+-- Should reject GitLab identities owned by another user
+select throws_ok(
+    format(
+        $$ select link_user_provider(%L::uuid, '{"gitlab": {"id": 3002, "username": "other"}}'::jsonb) $$,
+        :'userID'
+    ),
+    'external auth identity belongs to another user',
+    'Should reject GitLab identities owned by another user'
+);
+
+-- Should track the linked providers
+select results_eq(
+    $$ select action, details->'providers' from audit_log order by created_at, details::text $$,
+    $$ values
+        ('user_provider_linked'::text, '["github"]'::jsonb),
+        ('user_provider_linked'::text, '["linuxfoundation"]'::jsonb)
+    $$,
+    'Should track the linked providers'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,69 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0bb0000-0000-0000-0000-000000000032'
+\set passkey1ID 'c0bb0000-0000-0000-0000-000000000033'
+\set passkey2ID 'c0bb0000-0000-0000-0000-000000000034'
+\set userID 'c0bb0000-0000-0000-0000-000000000031'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userID', 'hash', 'user@example.com', true, 'user'),
+    (:'otherUserID', 'hash', 'other@example.com', true, 'other');
+
+-- Passkeys
+insert into user_passkey (
+    user_passkey_id,
+    created_at,
+    credential_id,
+    last_used_at,
+    name,
+    passkey,
+    user_id
+) values
+    (:'passkey1ID', '2025-01-01 00:00:00+00', 'credential-1', '2025-02-01 00:00:00+00', 'Laptop', '{}'::jsonb, :'userID'),
+    (:'passkey2ID', '2025-01-02 00:00:00+00', 'credential-2', null, 'Phone', '{}'::jsonb, :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list the passkeys of the user, newest first
+select is(
+    list_user_passkeys(:'userID'::uuid)::jsonb,
+    format(
+        '[
+            {"created_at": 1735776000, "last_used_at": null, "name": "Phone", "user_passkey_id": "%s"},
+            {"created_at": 1735689600, "last_used_at": 1738368000, "name": "Laptop", "user_passkey_id": "%s"}
+        ]',
+        :'passkey2ID',
+        :'passkey1ID'
+    )::jsonb,
+    'Should list the passkeys of the user, newest first'
+);
+
+-- Should return an empty list when the user has no passkeys
+select is(
+    list_user_passkeys(:'otherUserID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the user has no passkeys'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,122 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0c00000-0000-0000-0000-000000000002'
+\set session1Ref 'c0c00000-0000-0000-0000-000000000003'
+\set session2Ref 'c0c00000-0000-0000-0000-000000000004'
+\set userID 'c0c00000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userID', 'hash', 'user@example.com', true, 'user'),
+    (:'otherUserID', 'hash', 'other@example.com', true, 'other');
+
+-- Sessions
+insert into auth_session (
+    auth_session_id,
+    auth_session_ref,
+    created_at,
+    data,
+    expires_at,
+    last_seen_at
+) values
+    (
+        'session-1',
+        :'session1Ref',
+        '2025-01-01 00:00:00+00',
+        jsonb_build_object(
+            'axum-login.data', jsonb_build_object('user_id', :'userID'),
+            'session_client', jsonb_build_object('ip_address', '192.0.2.1', 'user_agent', 'Firefox')
+        ),
+        current_timestamp + interval '1 day',
+        '2025-01-03 00:00:00+00'
+    ),
+    (
+        'session-2',
+        :'session2Ref',
+        '2025-01-02 00:00:00+00',
+        jsonb_build_object('axum-login.data', jsonb_build_object('user_id', :'userID')),
+        current_timestamp + interval '1 day',
+        '2025-01-02 00:00:00+00'
+    ),
+    (
+        'session-expired',
+        gen_random_uuid(),
+        '2025-01-01 00:00:00+00',
+        jsonb_build_object('axum-login.data', jsonb_build_object('user_id', :'userID')),
+        current_timestamp - interval '1 day',
+        '2025-01-04 00:00:00+00'
+    ),
+    (
+        'session-anonymous',
+        gen_random_uuid(),
+        '2025-01-01 00:00:00+00',
+        '{}'::jsonb,
+        current_timestamp + interval '1 day',
+        '2025-01-04 00:00:00+00'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list the active sessions of the user, most recently seen first
+select is(
+    list_user_sessions(:'userID'::uuid, 'session-2')::jsonb,
+    format(
+        '[
+            {
+                "created_at": 1735689600,
+                "current": false,
+                "ip_address": "192.0.2.1",
+                "last_seen_at": 1735862400,
+                "session_id": "%s",
+                "user_agent": "Firefox"
+            },
+            {
+                "created_at": 1735776000,
+                "current": true,
+                "ip_address": null,
+                "last_seen_at": 1735776000,
+                "session_id": "%s",
+                "user_agent": null
+            }
+        ]',
+        :'session1Ref',
+        :'session2Ref'
+    )::jsonb,
+    'Should list the active sessions of the user, most recently seen first'
+);
+
+-- Should derive the user from the session data
+select is(
+    (select user_id from auth_session where auth_session_id = 'session-1'),
+    :'userID'::uuid,
+    'Should derive the user from the session data'
+);
+
+-- Should return an empty list when the user has no sessions
+select is(
+    list_user_sessions(:'otherUserID'::uuid, 'session-1')::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the user has no sessions'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
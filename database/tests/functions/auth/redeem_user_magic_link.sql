@@ -0,0 +1,65 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set userID 'c0bc0000-0000-0000-0000-000000000011'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- User
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'userID', 'hash', 'user@example.com', true, 'user');
+
+-- Links
+insert into user_magic_link (expires_at, token_hash, user_id)
+values
+    (current_timestamp + interval '10 minutes', 'valid-hash', :'userID'),
+    (current_timestamp - interval '1 minute', 'expired-hash', :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the user the link was issued to
+select is(
+    redeem_user_magic_link('valid-hash'),
+    :'userID'::uuid,
+    'Should return the user the link was issued to'
+);
+
+-- Should not allow using the same link twice
+select is(
+    redeem_user_magic_link('valid-hash'),
+    null::uuid,
+    'Should not allow using the same link twice'
+);
+
+-- Should not redeem expired links
+select is(
+    redeem_user_magic_link('expired-hash'),
+    null::uuid,
+    'Should not redeem expired links'
+);
+
+-- Should not redeem unknown links
+select is(
+    redeem_user_magic_link('unknown-hash'),
+    null::uuid,
+    'Should not redeem unknown links'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,65 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Stale failed attempt
+insert into log_in_failure (created_at, ip_address, username)
+values (current_timestamp - interval '2 days', '192.0.2.9', 'stale');
+
+-- Recent failed attempts for a username
+insert into log_in_failure (ip_address, username)
+select '192.0.2.1', 'user'
+from generate_series(1, 3);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should record failures that do not lock the username
+select is(
+    register_failed_log_in(' user ', '192.0.2.1'),
+    false,
+    'Should record failures that do not lock the username'
+);
+
+-- Should store the trimmed username and the address
+select is(
+    (select count(*)::int from log_in_failure where username = 'user' and ip_address = '192.0.2.1'),
+    4,
+    'Should store the trimmed username and the address'
+);
+
+-- Should report the failure that locks the username
+select is(
+    register_failed_log_in('user', '192.0.2.1'),
+    true,
+    'Should report the failure that locks the username'
+);
+
+-- Should not report failures once the username is already locked
+select is(
+    register_failed_log_in('user', '192.0.2.1'),
+    false,
+    'Should not report failures once the username is already locked'
+);
+
+-- Should discard the stale failures
+select is(
+    (select count(*)::int from log_in_failure where username = 'stale'),
+    0,
+    'Should discard the stale failures'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
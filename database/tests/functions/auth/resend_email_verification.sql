@@ -0,0 +1,107 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set newCodeID 'c0c30000-0000-0000-0000-000000000012'
+\set oldCodeID 'c0c30000-0000-0000-0000-000000000011'
+\set unverifiedUserID 'c0c30000-0000-0000-0000-000000000002'
+\set userID 'c0c30000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'userID', 'hash', 'user@example.com', true, 'user'),
+    (:'unverifiedUserID', 'hash', 'Unverified@Example.com', false, 'unverified');
+
+-- Verification code sent an hour ago
+insert into email_verification_code (email_verification_code_id, created_at, user_id)
+values (:'oldCodeID', current_timestamp - interval '1 hour', :'unverifiedUserID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should resend the verification email matching the email case-insensitively
+select is(
+    resend_email_verification(
+        ' unverified@example.com ',
+        :'newCodeID'::uuid,
+        '{"link": "https://example.test/verify-email/new"}'::jsonb
+    ),
+    true,
+    'Should resend the verification email'
+);
+
+-- Should replace the previous verification code
+select results_eq(
+    format(
+        $$ select email_verification_code_id from email_verification_code where user_id = %L::uuid $$,
+        :'unverifiedUserID'
+    ),
+    format($$ values (%L::uuid) $$, :'newCodeID'),
+    'Should replace the previous verification code'
+);
+
+-- Should enqueue the new verification email
+select ok(
+    exists (
+        select 1
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'email-verification'
+        and n.user_id = :'unverifiedUserID'::uuid
+        and ntd.data = '{"link": "https://example.test/verify-email/new"}'::jsonb
+    ),
+    'Should enqueue the new verification email'
+);
+
+-- Should limit how often a new verification email can be requested
+select is(
+    resend_email_verification(
+        'unverified@example.com',
+        gen_random_uuid(),
+        '{"link": "https://example.test/verify-email/other"}'::jsonb
+    ),
+    false,
+    'Should limit how often a new verification email can be requested'
+);
+
+-- Should not resend the verification email to verified users
+select is(
+    resend_email_verification(
+        'user@example.com',
+        gen_random_uuid(),
+        '{"link": "https://example.test/verify-email/other"}'::jsonb
+    ),
+    false,
+    'Should not resend the verification email to verified users'
+);
+
+-- Should not resend the verification email to unknown emails
+select is(
+    resend_email_verification(
+        'unknown@example.com',
+        gen_random_uuid(),
+        '{"link": "https://example.test/verify-email/other"}'::jsonb
+    ),
+    false,
+    'Should not resend the verification email to unknown emails'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,43 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Failed attempts
+insert into log_in_failure (ip_address, username)
+values
+    ('192.0.2.1', 'User'),
+    ('192.0.2.1', 'user'),
+    ('192.0.2.1', 'other');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should discard the failures of the username case-insensitively
+select reset_failed_log_ins('USER');
+select is(
+    (select count(*)::int from log_in_failure where lower(username) = 'user'),
+    0,
+    'Should discard the failures of the username'
+);
+
+-- Should keep the failures of other usernames
+select is(
+    (select count(*)::int from log_in_failure where username = 'other'),
+    1,
+    'Should keep the failures of other usernames'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
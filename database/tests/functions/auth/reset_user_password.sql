@@ -0,0 +1,83 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set userID 'c0be0000-0000-0000-0000-000000000011'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- User
+insert into "user" (user_id, auth_hash, email, email_verified, password, username)
+values (:'userID', 'hash', 'user@example.com', true, 'old-password', 'user');
+
+-- Codes
+insert into user_password_reset (code_hash, expires_at, user_id)
+values
+    ('valid-hash', current_timestamp + interval '30 minutes', :'userID'),
+    ('other-hash', current_timestamp + interval '45 minutes', :'userID'),
+    ('expired-hash', current_timestamp - interval '1 minute', :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not reset the password using expired codes
+select is(
+    reset_user_password('expired-hash', 'new-password'),
+    null::uuid,
+    'Should not reset the password using expired codes'
+);
+
+-- Should reset the password returning the user identifier
+select is(
+    reset_user_password('valid-hash', 'new-password'),
+    :'userID'::uuid,
+    'Should reset the password returning the user identifier'
+);
+
+-- Should update the password and rotate the auth hash
+select results_eq(
+    format(
+        $$ select password, auth_hash <> 'hash' from "user" where user_id = %L::uuid $$,
+        :'userID'
+    ),
+    $$ values ('new-password'::text, true) $$,
+    'Should update the password and rotate the auth hash'
+);
+
+-- Should discard all the codes issued to the user
+select is(
+    (select count(*)::int from user_password_reset where user_id = :'userID'),
+    0,
+    'Should discard all the codes issued to the user'
+);
+
+-- Should not allow using the same code twice
+select is(
+    reset_user_password('valid-hash', 'another-password'),
+    null::uuid,
+    'Should not allow using the same code twice'
+);
+
+-- Should track the password reset
+select results_eq(
+    $$ select action, actor_user_id, resource_id from audit_log $$,
+    format($$ values ('user_password_reset'::text, %L::uuid, %L::uuid) $$, :'userID', :'userID'),
+    'Should track the password reset'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,90 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0c00000-0000-0000-0000-000000000012'
+\set sessionRef 'c0c00000-0000-0000-0000-000000000013'
+\set userID 'c0c00000-0000-0000-0000-000000000011'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userID', 'hash', 'user@example.com', true, 'user'),
+    (:'otherUserID', 'hash', 'other@example.com', true, 'other');
+
+-- Session
+insert into auth_session (auth_session_id, auth_session_ref, data, expires_at)
+values (
+    'session-1',
+    :'sessionRef',
+    jsonb_build_object('axum-login.data', jsonb_build_object('user_id', :'userID')),
+    current_timestamp + interval '1 day'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not revoke sessions of other users
+select throws_ok(
+    format(
+        $$select revoke_user_session(%L::uuid, %L::uuid)$$,
+        :'otherUserID',
+        :'sessionRef'
+    ),
+    'session not found',
+    'Should not revoke sessions of other users'
+);
+select ok(
+    exists (select 1 from auth_session where auth_session_ref = :'sessionRef'::uuid),
+    'Should keep the session when another user tries to revoke it'
+);
+
+-- Should revoke the session of the user
+select lives_ok(
+    format(
+        $$select revoke_user_session(%L::uuid, %L::uuid)$$,
+        :'userID',
+        :'sessionRef'
+    ),
+    'Should revoke the session of the user'
+);
+select ok(
+    not exists (select 1 from auth_session where auth_session_ref = :'sessionRef'::uuid),
+    'Should remove the revoked session'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$ values ('user_session_revoked', %L::uuid, 'user', %L::uuid) $$,
+        :'userID',
+        :'userID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,80 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0c00000-0000-0000-0000-000000000022'
+\set userID 'c0c00000-0000-0000-0000-000000000021'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userID', 'hash', 'user@example.com', true, 'user'),
+    (:'otherUserID', 'hash', 'other@example.com', true, 'other');
+
+-- Sessions
+insert into auth_session (auth_session_id, data, expires_at) values
+    (
+        'session-1',
+        jsonb_build_object('axum-login.data', jsonb_build_object('user_id', :'userID')),
+        current_timestamp + interval '1 day'
+    ),
+    (
+        'session-2',
+        jsonb_build_object('axum-login.data', jsonb_build_object('user_id', :'userID')),
+        current_timestamp + interval '1 day'
+    ),
+    (
+        'session-other',
+        jsonb_build_object('axum-login.data', jsonb_build_object('user_id', :'otherUserID')),
+        current_timestamp + interval '1 day'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should revoke all the sessions of the user
+select lives_ok(
+    format($$select revoke_user_sessions(%L::uuid)$$, :'userID'),
+    'Should revoke all the sessions of the user'
+);
+select results_eq(
+    $$ select auth_session_id from auth_session order by auth_session_id $$,
+    $$ values ('session-other'::text) $$,
+    'Should keep the sessions of other users'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$ values ('user_sessions_revoked', %L::uuid, 'user', %L::uuid) $$,
+        :'userID',
+        :'userID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -47,7 +47,8 @@ select ok(
                 "username": "verifieduser-gh"
             }
         },
-        "username": "verifieduser"
+        "username": "verifieduser",
+        "weekly_digest_enabled": true
     }'::jsonb)
     and ("user"::jsonb ? 'auth_hash')
     and length(("user"::jsonb->>'auth_hash')) = 64
@@ -78,7 +79,8 @@ select ok(
         "email_verified": false,
         "optional_notifications_enabled": true,
         "name": "Unverified User",
-        "username": "unverifieduser"
+        "username": "unverifieduser",
+        "weekly_digest_enabled": true
     }'::jsonb)
     and ("user"::jsonb ? 'auth_hash')
     and length(("user"::jsonb->>'auth_hash')) = 64
@@ -127,7 +129,8 @@ select ok(
         "email_verified": false,
         "optional_notifications_enabled": true,
         "name": "Default User",
-        "username": "defaultuser"
+        "username": "defaultuser",
+        "weekly_digest_enabled": true
     }'::jsonb)
     and ("user"::jsonb ? 'auth_hash')
     and length(("user"::jsonb->>'auth_hash')) = 64
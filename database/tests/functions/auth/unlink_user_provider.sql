@@ -0,0 +1,82 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set passwordlessUserID 'c0bf0000-0000-0000-0000-000000000022'
+\set userID 'c0bf0000-0000-0000-0000-000000000021'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, password, provider, username)
+values
+    (
+        :'userID',
+        'hash',
+        'user@example.com',
+        true,
+        'password',
+        jsonb_build_object('github', jsonb_build_object('id', 3001, 'username', 'octocat')),
+        'user'
+    ),
+    (
+        :'passwordlessUserID',
+        'passwordless-hash',
+        'passwordless@example.com',
+        true,
+        null,
+        jsonb_build_object('github', jsonb_build_object('id', 3002, 'username', 'passwordless')),
+        'passwordless-user'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should unlink a provider from a user with a password
+select lives_ok(
+    format($$ select unlink_user_provider(%L::uuid, 'github') $$, :'userID'),
+    'Should unlink a provider from a user with a password'
+);
+select is(
+    (select provider from "user" where user_id = :'userID'),
+    null::jsonb,
+    'Should remove the provider from the user'
+);
+
+-- Should reject providers not linked to the user
+select throws_ok(
+    format($$ select unlink_user_provider(%L::uuid, 'github') $$, :'userID'),
+    'provider not linked',
+    'Should reject providers not linked to the user'
+);
+
+-- Should not unlink the only log in method of a user
+select throws_ok(
+    format($$ select unlink_user_provider(%L::uuid, 'github') $$, :'passwordlessUserID'),
+    'cannot unlink the only log in method',
+    'Should not unlink the only log in method of a user'
+);
+
+-- Should track the unlinked provider
+select results_eq(
+    $$ select action, actor_user_id, details->>'provider' from audit_log $$,
+    format($$ values ('user_provider_unlinked'::text, %L::uuid, 'github'::text) $$, :'userID'),
+    'Should track the unlinked provider'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
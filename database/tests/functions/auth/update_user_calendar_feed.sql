@@ -0,0 +1,85 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set userID 'c0c90000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- User
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'userID', 'hash', 'user@example.com', true, 'user');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should generate a calendar token when the feed is enabled
+select lives_ok(
+    format($$select update_user_calendar_feed(%L::uuid, true)$$, :'userID'),
+    'Should enable the calendar feed'
+);
+select ok(
+    (select calendar_token ~ '^[0-9a-f]{64}$' from "user" where user_id = :'userID'::uuid),
+    'Should generate a calendar token when the feed is enabled'
+);
+
+-- Should replace the calendar token when the feed is enabled again
+create temporary table previous_token as
+select calendar_token from "user" where user_id = :'userID'::uuid;
+select update_user_calendar_feed(:'userID'::uuid, true);
+select isnt(
+    (select calendar_token from "user" where user_id = :'userID'::uuid),
+    (select calendar_token from previous_token),
+    'Should replace the calendar token when the feed is enabled again'
+);
+
+-- Should clear the calendar token when the feed is disabled
+select lives_ok(
+    format($$select update_user_calendar_feed(%L::uuid, false)$$, :'userID'),
+    'Should disable the calendar feed'
+);
+select is(
+    (select calendar_token from "user" where user_id = :'userID'::uuid),
+    null,
+    'Should clear the calendar token when the feed is disabled'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('user_calendar_feed_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid),
+            ('user_calendar_feed_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid),
+            ('user_calendar_feed_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid)
+        $$,
+        :'userID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
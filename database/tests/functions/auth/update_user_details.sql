@@ -146,16 +146,19 @@ select lives_ok(
             "city": "San Francisco",
             "company": "Example Corp",
             "country": "USA",
+            "event_reminder_preference": "1-hour",
             "facebook_url": "https://facebook.com/updateduser",
             "github_url": "https://github.com/updateduser",
             "interests": ["programming", "music", "sports"],
+            "language": "es",
             "linkedin_url": "https://linkedin.com/in/updateduser",
             "optional_notifications_enabled": false,
             "photo_url": "https://example.com/photo.jpg",
             "timezone": "America/Los_Angeles",
             "title": "Software Engineer",
             "twitter_url": "https://twitter.com/updateduser",
-            "website_url": "https://example.com/updateduser"
+            "website_url": "https://example.com/updateduser",
+            "weekly_digest_enabled": false
         }$$
     ),
     'Should execute update with all provided user fields'
@@ -180,15 +183,18 @@ select is(
         "city": "San Francisco",
         "company": "Example Corp",
         "country": "USA",
+        "event_reminder_preference": "1-hour",
         "facebook_url": "https://facebook.com/updateduser",
         "github_url": "https://github.com/updateduser",
         "interests": ["programming", "music", "sports"],
+        "language": "es",
         "linkedin_url": "https://linkedin.com/in/updateduser",
         "photo_url": "https://example.com/photo.jpg",
         "timezone": "America/Los_Angeles",
         "title": "Software Engineer",
         "twitter_url": "https://twitter.com/updateduser",
-        "website_url": "https://example.com/updateduser"
+        "website_url": "https://example.com/updateduser",
+        "weekly_digest_enabled": false
     }'::jsonb,
     'Should persist all provided user fields'
 );
@@ -241,7 +247,8 @@ select is(
         "email_verified": true,
         "optional_notifications_enabled": true,
         "name": "Updated Name Only",
-        "username": "testuser2"
+        "username": "testuser2",
+        "weekly_digest_enabled": true
     }'::jsonb,
     'Should clear optional fields when only name is provided'
 );
@@ -285,7 +292,8 @@ select is(
         "email_verified": true,
         "optional_notifications_enabled": true,
         "name": "Explicitly Nulled User",
-        "username": "testuser3"
+        "username": "testuser3",
+        "weekly_digest_enabled": true
     }'::jsonb,
     'Should treat explicit null values the same as omitted fields'
 );
@@ -302,6 +310,7 @@ select lives_ok(
             "city": "",
             "company": "",
             "country": "",
+            "event_reminder_preference": "",
             "facebook_url": "",
             "github_url": "",
             "linkedin_url": "",
@@ -324,6 +333,7 @@ select results_eq(
             city,
             company,
             country,
+            event_reminder_preference,
             facebook_url,
             github_url,
             linkedin_url,
@@ -349,6 +359,7 @@ select results_eq(
             null::text,
             null::text,
             null::text,
+            null::text,
             null::text
         )
     $$,
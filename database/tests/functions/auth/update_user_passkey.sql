@@ -0,0 +1,58 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0bb0000-0000-0000-0000-000000000042'
+\set userID 'c0bb0000-0000-0000-0000-000000000041'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userID', 'hash', 'user@example.com', true, 'user'),
+    (:'otherUserID', 'hash', 'other@example.com', true, 'other');
+
+-- Passkey
+insert into user_passkey (credential_id, name, passkey, user_id)
+values ('credential-1', 'Laptop', '{"counter": 1}'::jsonb, :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not update passkeys of other users
+select update_user_passkey(:'otherUserID'::uuid, 'credential-1', '{"counter": 9}'::jsonb);
+select is(
+    (select passkey from user_passkey where credential_id = 'credential-1'),
+    '{"counter": 1}'::jsonb,
+    'Should not update passkeys of other users'
+);
+
+-- Should store the updated passkey and track its last use
+select update_user_passkey(:'userID'::uuid, 'credential-1', '{"counter": 2}'::jsonb);
+select is(
+    (select passkey from user_passkey where credential_id = 'credential-1'),
+    '{"counter": 2}'::jsonb,
+    'Should store the updated passkey'
+);
+select isnt(
+    (select last_used_at from user_passkey where credential_id = 'credential-1'),
+    null,
+    'Should track when the passkey was last used'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
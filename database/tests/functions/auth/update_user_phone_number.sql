@@ -0,0 +1,177 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(10);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set userID 'c0b10000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- User
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    phone_number,
+    phone_number_verified,
+    sms_notifications_enabled,
+    username
+) values (
+    :'userID',
+    'hash',
+    'user@example.com',
+    true,
+    '+15550000000',
+    true,
+    true,
+    'user'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should store the provided phone number pending verification
+select lives_ok(
+    format(
+        $$select update_user_phone_number(%L::uuid, ' +15551234567 ')$$,
+        :'userID'
+    ),
+    'Should store the provided phone number'
+);
+select results_eq(
+    format(
+        $$
+            select phone_number, phone_number_verified, sms_notifications_enabled
+            from "user"
+            where user_id = %L::uuid
+        $$,
+        :'userID'
+    ),
+    $$ values ('+15551234567'::text, false, false) $$,
+    'Should require verifying the new phone number before sending notifications'
+);
+
+-- Should send the verification code by SMS
+select results_eq(
+    format(
+        $$
+            select n.channel, n.kind, n.user_id
+            from notification n
+            where n.user_id = %L::uuid
+        $$,
+        :'userID'
+    ),
+    format(
+        $$ values ('sms'::text, 'phone-verification'::text, %L::uuid) $$,
+        :'userID'
+    ),
+    'Should enqueue an SMS notification with the verification code'
+);
+select ok(
+    (
+        select ntd.data->>'code' ~ '^[0-9]{6}$'
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.user_id = :'userID'::uuid
+    ),
+    'Should include a six digit code in the notification'
+);
+
+-- Should store only the hash of the verification code
+select is(
+    (select code_hash from phone_verification_code where user_id = :'userID'::uuid),
+    (
+        select encode(digest(ntd.data->>'code', 'sha256'), 'hex')
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.user_id = :'userID'::uuid
+    ),
+    'Should store the hash of the verification code sent'
+);
+
+-- Should not send a new code too soon after the previous one
+select throws_ok(
+    format(
+        $$select update_user_phone_number(%L::uuid, '+15557654321')$$,
+        :'userID'
+    ),
+    'phone verification code requested too recently',
+    'Should not send a new code too soon after the previous one'
+);
+
+-- Should send a new code once the previous one is old enough
+update phone_verification_code
+set created_at = current_timestamp - interval '2 minutes'
+where user_id = :'userID'::uuid;
+select lives_ok(
+    format(
+        $$select update_user_phone_number(%L::uuid, '+15557654321')$$,
+        :'userID'
+    ),
+    'Should send a new code once the previous one is old enough'
+);
+
+-- Should clear the phone number when an empty one is provided
+select lives_ok(
+    format(
+        $$select update_user_phone_number(%L::uuid, '')$$,
+        :'userID'
+    ),
+    'Should clear the phone number when an empty one is provided'
+);
+select results_eq(
+    format(
+        $$
+            select
+                phone_number,
+                phone_number_verified,
+                sms_notifications_enabled,
+                exists (select 1 from phone_verification_code where user_id = %1$L::uuid)
+            from "user"
+            where user_id = %1$L::uuid
+        $$,
+        :'userID'
+    ),
+    $$ values (null::text, false, false, false) $$,
+    'Should discard the phone number and its pending verification code'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('user_phone_number_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid),
+            ('user_phone_number_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid),
+            ('user_phone_number_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid)
+        $$,
+        :'userID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,92 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set user1ID 'c0b10000-0000-0000-0000-000000000021'
+\set user2ID 'c0b10000-0000-0000-0000-000000000022'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    phone_number,
+    phone_number_verified,
+    username
+) values
+    (:'user1ID', 'hash', 'user1@example.com', true, '+15551234567', true, 'user1'),
+    (:'user2ID', 'hash', 'user2@example.com', true, '+15557654321', false, 'user2');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should enable SMS notifications for verified phone numbers
+select lives_ok(
+    format($$select update_user_sms_notifications(%L::uuid, true)$$, :'user1ID'),
+    'Should enable SMS notifications for verified phone numbers'
+);
+select is(
+    (select sms_notifications_enabled from "user" where user_id = :'user1ID'::uuid),
+    true,
+    'Should store the enabled SMS notifications preference'
+);
+
+-- Should disable SMS notifications
+select lives_ok(
+    format($$select update_user_sms_notifications(%L::uuid, false)$$, :'user1ID'),
+    'Should disable SMS notifications'
+);
+select is(
+    (select sms_notifications_enabled from "user" where user_id = :'user1ID'::uuid),
+    false,
+    'Should store the disabled SMS notifications preference'
+);
+
+-- Should not enable SMS notifications for unverified phone numbers
+select throws_ok(
+    format($$select update_user_sms_notifications(%L::uuid, true)$$, :'user2ID'),
+    'phone number not verified',
+    'Should not enable SMS notifications for unverified phone numbers'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('user_sms_notifications_updated', %1$L::uuid, 'user1'::text, 'user', %1$L::uuid),
+            ('user_sms_notifications_updated', %1$L::uuid, 'user1'::text, 'user', %1$L::uuid)
+        $$,
+        :'user1ID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
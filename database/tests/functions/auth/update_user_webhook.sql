@@ -0,0 +1,109 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(9);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set userID 'c0b00000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- User
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'userID', 'hash', 'user@example.com', true, 'user');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should store the provided webhook
+select lives_ok(
+    format(
+        $$select update_user_webhook(%L::uuid, ' https://example.com/webhooks/ocg ')$$,
+        :'userID'
+    ),
+    'Should store the provided webhook'
+);
+select is(
+    (select webhook_url from "user" where user_id = :'userID'::uuid),
+    'https://example.com/webhooks/ocg',
+    'Should trim the stored webhook'
+);
+
+-- Should generate a signing secret for the webhook
+select ok(
+    (select webhook_secret ~ '^[0-9a-f]{64}$' from "user" where user_id = :'userID'::uuid),
+    'Should generate a signing secret for the webhook'
+);
+
+-- Should keep the signing secret when the webhook changes
+create temporary table previous_secret as
+select webhook_secret from "user" where user_id = :'userID'::uuid;
+select lives_ok(
+    format(
+        $$select update_user_webhook(%L::uuid, 'https://example.com/webhooks/other')$$,
+        :'userID'
+    ),
+    'Should update the webhook'
+);
+select is(
+    (select webhook_secret from "user" where user_id = :'userID'::uuid),
+    (select webhook_secret from previous_secret),
+    'Should keep the signing secret when the webhook changes'
+);
+
+-- Should clear the webhook and its secret when an empty one is provided
+select lives_ok(
+    format(
+        $$select update_user_webhook(%L::uuid, '')$$,
+        :'userID'
+    ),
+    'Should clear the webhook when an empty one is provided'
+);
+select is(
+    (select webhook_url from "user" where user_id = :'userID'::uuid),
+    null,
+    'Should store no webhook after clearing it'
+);
+select is(
+    (select webhook_secret from "user" where user_id = :'userID'::uuid),
+    null,
+    'Should discard the signing secret after clearing the webhook'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('user_webhook_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid),
+            ('user_webhook_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid),
+            ('user_webhook_updated', %1$L::uuid, 'user'::text, 'user', %1$L::uuid)
+        $$,
+        :'userID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(131);
+select plan(157);
 
 -- ============================================================================
 -- VARIABLES
@@ -20,6 +20,7 @@ select plan(131);
 \set restrictedCommunityID '0a0e0000-0000-0000-0000-000000000009'
 \set restrictedGroupCategoryID '0a0e0000-0000-0000-0000-000000000010'
 \set restrictedGroupID '0a0e0000-0000-0000-0000-000000000011'
+\set userCheckInVolunteerID '0a0e0000-0000-0000-0000-000000000023'
 \set userCommunityAdminID '0a0e0000-0000-0000-0000-000000000012'
 \set userCommunityGroupsManagerID '0a0e0000-0000-0000-0000-000000000013'
 \set userCommunityPendingGroupsManagerID '0a0e0000-0000-0000-0000-000000000014'
@@ -111,6 +112,13 @@ insert into "user" (
     'group-viewer@example.com',
     true,
     'groupviewer'
+), (
+    :'userCheckInVolunteerID',
+    'Check-in Volunteer',
+    gen_random_bytes(32),
+    'check-in-volunteer@example.com',
+    true,
+    'checkinvolunteer'
 ), (
     :'userCommunityAdminID',
     'Community Admin',
@@ -242,6 +250,11 @@ insert into group_team (
     :'groupID',
     'events-manager',
     :'userEventsManagerID'
+), (
+    true,
+    :'groupID',
+    'check-in-volunteer',
+    :'userCheckInVolunteerID'
 ), (
     true,
     :'groupID',
@@ -331,6 +344,7 @@ with tested_permissions (
     permission
 ) as (
     values
+        ('group.check-in.write'),
         ('group.events.write'),
         ('group.members.write'),
         ('group.read'),
@@ -378,6 +392,7 @@ with actors (
             :'groupID'::uuid,
             :'userCommunityAdminID'::uuid,
             array[
+                'group.check-in.write',
                 'group.events.write',
                 'group.members.write',
                 'group.read',
@@ -392,6 +407,7 @@ with actors (
             :'otherGroupID'::uuid,
             :'userCommunityAdminID'::uuid,
             array[
+                'group.check-in.write',
                 'group.events.write',
                 'group.members.write',
                 'group.read',
@@ -406,6 +422,7 @@ with actors (
             :'groupID'::uuid,
             :'userCommunityGroupsManagerID'::uuid,
             array[
+                'group.check-in.write',
                 'group.events.write',
                 'group.members.write',
                 'group.read',
@@ -450,6 +467,7 @@ with actors (
             :'groupID'::uuid,
             :'userDualRoleID'::uuid,
             array[
+                'group.check-in.write',
                 'group.events.write',
                 'group.members.write',
                 'group.read',
@@ -464,6 +482,7 @@ with actors (
             :'groupID'::uuid,
             :'userGroupAdminID'::uuid,
             array[
+                'group.check-in.write',
                 'group.events.write',
                 'group.members.write',
                 'group.read',
@@ -472,12 +491,23 @@ with actors (
                 'group.team.write'
             ]::text[]
         ),
+        (
+            'group-check-in-volunteer',
+            :'communityID'::uuid,
+            :'groupID'::uuid,
+            :'userCheckInVolunteerID'::uuid,
+            array[
+                'group.check-in.write',
+                'group.read'
+            ]::text[]
+        ),
         (
             'group-events-manager',
             :'communityID'::uuid,
             :'groupID'::uuid,
             :'userEventsManagerID'::uuid,
             array[
+                'group.check-in.write',
                 'group.events.write',
                 'group.read'
             ]::text[]
@@ -504,6 +534,7 @@ with actors (
             :'otherCommunityGroupID'::uuid,
             :'userOtherGroupAdminID'::uuid,
             array[
+                'group.check-in.write',
                 'group.events.write',
                 'group.members.write',
                 'group.read',
@@ -551,6 +582,7 @@ with actors (
     permission
 ) as (
     values
+        ('group.check-in.write'),
         ('group.events.write'),
         ('group.members.write'),
         ('group.read'),
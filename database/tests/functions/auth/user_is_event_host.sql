@@ -0,0 +1,116 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0f00000-0000-0000-0000-000000000001'
+\set deletedEventID 'c0f00000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0f00000-0000-0000-0000-000000000003'
+\set eventID 'c0f00000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0f00000-0000-0000-0000-000000000005'
+\set groupID 'c0f00000-0000-0000-0000-000000000006'
+\set hostID 'c0f00000-0000-0000-0000-000000000007'
+\set otherGroupID 'c0f00000-0000-0000-0000-000000000008'
+\set userID 'c0f00000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'event-host-community',
+    'Event Host Community',
+    'Community for event host tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Event Host Group', 'event-host-group'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Other Group', 'other-group');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'hostID', gen_random_bytes(32), 'host@example.com', true, 'host'),
+    (:'userID', gen_random_bytes(32), 'user@example.com', true, 'user');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone,
+    deleted
+) values
+    (:'eventID', :'groupID', 'Hosted', 'hosted', 'Hosted', :'eventCategoryID', 'in-person', 'UTC', false),
+    (:'deletedEventID', :'groupID', 'Deleted', 'deleted', 'Deleted', :'eventCategoryID', 'in-person', 'UTC', true);
+
+-- Event hosts
+insert into event_host (event_id, user_id) values
+    (:'eventID', :'hostID'),
+    (:'deletedEventID', :'hostID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return true when the user hosts the event
+select ok(
+    user_is_event_host(:'communityID'::uuid, :'groupID'::uuid, :'eventID'::uuid, :'hostID'::uuid),
+    'Should return true when the user hosts the event'
+);
+
+-- Should return false when the user does not host the event
+select ok(
+    not user_is_event_host(:'communityID'::uuid, :'groupID'::uuid, :'eventID'::uuid, :'userID'::uuid),
+    'Should return false when the user does not host the event'
+);
+
+-- Should return false when the event belongs to another group
+select ok(
+    not user_is_event_host(:'communityID'::uuid, :'otherGroupID'::uuid, :'eventID'::uuid, :'hostID'::uuid),
+    'Should return false when the event belongs to another group'
+);
+
+-- Should return false for deleted events
+select ok(
+    not user_is_event_host(:'communityID'::uuid, :'groupID'::uuid, :'deletedEventID'::uuid, :'hostID'::uuid),
+    'Should return false for deleted events'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(8);
+select plan(10);
 
 -- ============================================================================
 -- VARIABLES
@@ -12,6 +12,8 @@ select plan(8);
 \set invalidVerificationCodeID '0a0f0000-0000-0000-0000-000000000001'
 \set expiredUserID '0a0f0000-0000-0000-0000-000000000002'
 \set expiredVerificationCodeID '0a0f0000-0000-0000-0000-000000000003'
+\set undeliverableUserID '0a0f0000-0000-0000-0000-000000000004'
+\set undeliverableVerificationCodeID '0a0f0000-0000-0000-0000-000000000005'
 
 -- ============================================================================
 -- SEED DATA
@@ -34,6 +36,28 @@ values (
     :'expiredUserID'
 );
 
+-- User whose email address was reported as undeliverable
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_undeliverable_at,
+    email_undeliverable_reason,
+    email_verified,
+    username
+) values (
+    :'undeliverableUserID',
+    'undeliverable-hash',
+    'test4@example.com',
+    current_timestamp,
+    'bounce',
+    false,
+    'testuser4'
+);
+
+insert into email_verification_code (email_verification_code_id, user_id)
+values (:'undeliverableVerificationCodeID', :'undeliverableUserID');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -139,6 +163,22 @@ select throws_ok(
     'Should raise exception for already used verification code'
 );
 
+-- Should clear the undeliverable flag when the email is verified
+select lives_ok(
+    format('select verify_email(%L::uuid)', :'undeliverableVerificationCodeID'),
+    'Should verify email for a user with an undeliverable email address'
+);
+
+select is(
+    (
+        select row(email_undeliverable_at, email_undeliverable_reason, email_verified)::text
+        from "user"
+        where user_id = :'undeliverableUserID'::uuid
+    ),
+    row(null::timestamptz, null::text, true)::text,
+    'Should clear the undeliverable flag when the email is verified'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
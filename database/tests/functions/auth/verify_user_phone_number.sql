@@ -0,0 +1,111 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set user1ID 'c0b10000-0000-0000-0000-000000000011'
+\set user2ID 'c0b10000-0000-0000-0000-000000000012'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, phone_number, username)
+values
+    (:'user1ID', 'hash', 'user1@example.com', true, '+15551234567', 'user1'),
+    (:'user2ID', 'hash', 'user2@example.com', true, '+15557654321', 'user2');
+
+-- Verification codes
+insert into phone_verification_code (user_id, attempts, code_hash, created_at)
+values
+    (:'user1ID', 0, encode(digest('123456', 'sha256'), 'hex'), current_timestamp),
+    (
+        :'user2ID',
+        0,
+        encode(digest('654321', 'sha256'), 'hex'),
+        current_timestamp - interval '11 minutes'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject an incorrect code and count the failed attempt
+select is(
+    verify_user_phone_number(:'user1ID'::uuid, '000000'),
+    false,
+    'Should reject an incorrect code'
+);
+select is(
+    (select attempts from phone_verification_code where user_id = :'user1ID'::uuid),
+    1,
+    'Should count the failed attempt'
+);
+
+-- Should reject the correct code after too many failed attempts
+update phone_verification_code set attempts = 5 where user_id = :'user1ID'::uuid;
+select is(
+    verify_user_phone_number(:'user1ID'::uuid, '123456'),
+    false,
+    'Should reject the correct code after too many failed attempts'
+);
+
+-- Should verify the phone number when the correct code is provided
+update phone_verification_code set attempts = 4 where user_id = :'user1ID'::uuid;
+select is(
+    verify_user_phone_number(:'user1ID'::uuid, ' 123456 '),
+    true,
+    'Should accept the correct code'
+);
+select is(
+    (select phone_number_verified from "user" where user_id = :'user1ID'::uuid),
+    true,
+    'Should mark the phone number as verified'
+);
+select is(
+    (select count(*) from phone_verification_code where user_id = :'user1ID'::uuid),
+    0::bigint,
+    'Should discard the used verification code'
+);
+
+-- Should reject expired codes
+select is(
+    verify_user_phone_number(:'user2ID'::uuid, '654321'),
+    false,
+    'Should reject expired codes'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('user_phone_number_verified', %1$L::uuid, 'user1'::text, 'user', %1$L::uuid)
+        $$,
+        :'user1ID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -30,6 +30,7 @@ insert into community (
     active,
     ad_banner_link_url,
     ad_banner_url,
+    allowed_email_domains,
     bluesky_url,
     community_site_layout_id,
     extra_links,
@@ -40,7 +41,9 @@ insert into community (
     instagram_url,
     linkedin_url,
     new_group_details,
+    passkey_required,
     photos_urls,
+    required_oidc_provider,
     slack_url,
     twitter_url,
     website_url,
@@ -58,6 +61,7 @@ insert into community (
     true,
     'https://example.com/banner-link',
     'https://example.com/ad-banner.png',
+    array['example.com'],
     'https://bsky.app/profile/testcommunity',
     'default',
     '{"docs": "https://docs.example.com", "blog": "https://blog.example.com"}'::jsonb,
@@ -68,7 +72,9 @@ insert into community (
     'https://instagram.com/testcommunity',
     'https://linkedin.com/company/testcommunity',
     'To create a new group, please contact team members',
+    true,
     array['https://example.com/photo1.jpg', 'https://example.com/photo2.jpg'],
+    'linuxfoundation',
     'https://testcommunity.slack.com',
     'https://twitter.com/testcommunity',
     'https://example.com',
@@ -106,12 +112,16 @@ select is(
         "active": true,
         "ad_banner_link_url": "https://example.com/banner-link",
         "ad_banner_url": "https://example.com/ad-banner.png",
+        "allowed_email_domains": ["example.com"],
         "banner_mobile_url": "https://example.com/banner_mobile.png",
         "banner_url": "https://example.com/banner.png",
         "bluesky_url": "https://bsky.app/profile/testcommunity",
         "community_site_layout_id": "default",
         "description": "A vibrant community for cloud native technologies and practices in Seattle",
         "display_name": "Cloud Native Seattle",
+        "dormant_group_months": 6,
+        "dormant_groups_report_enabled": false,
+        "event_approval_required": false,
         "group_team_management_restricted": true,
         "extra_links": {"docs": "https://docs.example.com", "blog": "https://blog.example.com"},
         "facebook_url": "https://facebook.com/testcommunity",
@@ -122,7 +132,9 @@ select is(
         "logo_url": "https://example.com/logo.png",
         "name": "cloud-native-seattle",
         "new_group_details": "To create a new group, please contact team members",
+        "passkey_required": true,
         "photos_urls": ["https://example.com/photo1.jpg", "https://example.com/photo2.jpg"],
+        "required_oidc_provider": "linuxfoundation",
         "slack_url": "https://testcommunity.slack.com",
         "twitter_url": "https://twitter.com/testcommunity",
         "website_url": "https://example.com",
@@ -142,9 +154,13 @@ select is(
         "community_site_layout_id": "default",
         "description": "A growing community for cloud native technologies in Portland",
         "display_name": "Cloud Native Portland",
+        "dormant_group_months": 6,
+        "dormant_groups_report_enabled": false,
+        "event_approval_required": false,
         "group_team_management_restricted": false,
         "logo_url": "https://portland.cloudnative.org/logo.png",
-        "name": "cloud-native-portland"
+        "name": "cloud-native-portland",
+        "passkey_required": false
     }'::jsonb,
     'Should return correct data for community with only required fields'
 );
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(15);
+select plan(16);
 
 -- ============================================================================
 -- VARIABLES
@@ -547,7 +547,8 @@ insert into session (
     meeting_provider_id,
     meeting_recording_published,
     meeting_recording_url,
-    meeting_requested
+    meeting_requested,
+    track
 ) values (
     :'session1ID',
     :'eventID',
@@ -564,7 +565,8 @@ insert into session (
     null,
     true,
     'https://youtube.com/watch?v=session1',
-    false
+    false,
+    'Keynotes'
 ),
 (
     :'session2ID',
@@ -582,7 +584,8 @@ insert into session (
     'zoom',
     true,
     null,
-    true
+    true,
+    null
 );
 
 -- Additional session on the same day to verify sorting within the day
@@ -991,7 +994,9 @@ select is(
         "slug": "def5678",
         "test_event": false,
         "timezone": "America/New_York",
+        "attendance_certificate_emails_enabled": false,
         "attendee_approval_required": false,
+        "attendee_photos_enabled": false,
         "attendee_count": 2,
         "banner_url": "https://example.com/event-banner.png",
         "capacity": 500,
@@ -1025,6 +1030,7 @@ select is(
             "https://meeting.example.com/event-recording-late-joiner"
         ],
         "meeting_recording_requested": true,
+        "meeting_webinar": false,
         "meetup_url": "https://meetup.com/event123",
         "photos_urls": ["https://example.com/photo1.jpg", "https://example.com/photo2.jpg"],
         "published_at": 1714564800,
@@ -1220,6 +1226,7 @@ select is(
                     "meeting_recording_published": true,
                     "meeting_recording_url": "https://youtube.com/watch?v=session1",
                     "meeting_requested": false,
+                    "track": "Keynotes",
                     "speakers": [
                         {
                             "user_id": "0c060000-0000-0000-0000-000000000026",
@@ -1728,6 +1735,22 @@ select is(
     'Should keep organizer and raw recording URLs while hiding unpublished public recording URLs'
 );
 
+-- Should include only approved attendee photos
+insert into event_photo (event_id, url, user_id, status, reviewed_at, reviewed_by)
+values
+    (:'eventRelatedID', '/images/approved.png', :'user1ID', 'approved', current_timestamp, :'user2ID'),
+    (:'eventRelatedID', '/images/pending.png', :'user1ID', 'pending', null, null),
+    (:'eventRelatedID', '/images/rejected.png', :'user1ID', 'rejected', current_timestamp, :'user2ID');
+select is(
+    get_event_full(
+        :'communityID'::uuid,
+        :'groupID'::uuid,
+        :'eventRelatedID'::uuid
+    )::jsonb->'attendee_photos_urls',
+    '["/images/approved.png"]'::jsonb,
+    'Should include only approved attendee photos'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
@@ -194,6 +194,7 @@ insert into "group" (
     linkedin_url,
     github_url,
     instagram_url,
+    join_questions,
     youtube_url,
     slack_url,
     flickr_url,
@@ -229,6 +230,7 @@ insert into "group" (
     'https://linkedin.com/company/seattlek8s',
     'https://github.com/seattlek8s',
     'https://instagram.com/seattlek8s',
+    '[{"id": "00000000-0000-0000-0000-000000000101", "kind": "free-text", "prompt": "What topics interest you?", "required": false}]',
     'https://youtube.com/@seattlek8s',
     'https://seattlek8s.slack.com',
     'https://flickr.com/seattlek8s',
@@ -431,6 +433,7 @@ select is(
         "group_id": "0c0a0000-0000-0000-0000-000000000003",
         "members_count": 4,
         "name": "Seattle Kubernetes Meetup",
+        "private": false,
         "slug": "abc1234",
         "banner_url": "https://example.com/group-banner.png",
         "city": "New York",
@@ -444,6 +447,7 @@ select is(
         "flickr_url": "https://flickr.com/seattlek8s",
         "github_url": "https://github.com/seattlek8s",
         "instagram_url": "https://instagram.com/seattlek8s",
+        "join_questions": [{"id": "00000000-0000-0000-0000-000000000101", "kind": "free-text", "prompt": "What topics interest you?", "required": false}],
         "latitude": 40.7128,
         "linkedin_url": "https://linkedin.com/company/seattlek8s",
         "logo_url": "https://example.com/group-logo.png",
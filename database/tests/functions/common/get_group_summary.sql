@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(6);
+select plan(7);
 
 -- ============================================================================
 -- VARIABLES
@@ -200,6 +200,17 @@ select is(
     'Should include pretty slug when available'
 );
 
+-- Should include the dormant date when the group is dormant
+update "group" set dormant_since = '2024-08-15 10:00:00+00' where group_id = :'groupID';
+select is(
+    ((get_group_summary(
+        :'communityID'::uuid,
+        :'groupID'::uuid
+    )::jsonb)->>'dormant_since')::bigint,
+    1723716000::bigint,
+    'Should include the dormant date when the group is dormant'
+);
+
 -- Should return null for non-existent group
 select ok(
     get_group_summary(
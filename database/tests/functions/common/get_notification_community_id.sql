@@ -0,0 +1,171 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0b70000-0000-0000-0000-000000000001'
+\set customNotificationID 'c0b70000-0000-0000-0000-000000000061'
+\set eventCategoryID 'c0b70000-0000-0000-0000-000000000022'
+\set eventID 'c0b70000-0000-0000-0000-000000000051'
+\set groupCategoryID 'c0b70000-0000-0000-0000-000000000021'
+\set groupID 'c0b70000-0000-0000-0000-000000000031'
+\set issueID 'c0b70000-0000-0000-0000-000000000071'
+\set notificationCustomID 'c0b70000-0000-0000-0000-000000000081'
+\set notificationDiscordID 'c0b70000-0000-0000-0000-000000000082'
+\set notificationNewsletterID 'c0b70000-0000-0000-0000-000000000083'
+\set notificationSlackID 'c0b70000-0000-0000-0000-000000000084'
+\set notificationUserID 'c0b70000-0000-0000-0000-000000000085'
+\set userID 'c0b70000-0000-0000-0000-000000000041'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- User
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'userID', gen_random_bytes(32), 'user@example.com', true, 'user');
+
+-- Community
+insert into community (
+    community_id,
+    banner_mobile_url,
+    banner_url,
+    description,
+    display_name,
+    logo_url,
+    name
+) values (
+    :'communityID',
+    'https://e/community-mobile.png',
+    'https://e/community.png',
+    'Community 1',
+    'Community One',
+    'https://e/community-logo.png',
+    'community-one'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Platform', 'platform');
+
+-- Event
+insert into event (
+    description,
+    event_category_id,
+    event_id,
+    event_kind_id,
+    group_id,
+    name,
+    slug,
+    timezone
+) values (
+    'Test event',
+    :'eventCategoryID',
+    :'eventID',
+    'virtual',
+    :'groupID',
+    'Platform Meetup',
+    'platform-meetup',
+    'UTC'
+);
+
+-- Custom notification sent for the event
+insert into custom_notification (custom_notification_id, body, created_by, event_id, subject)
+values (:'customNotificationID', 'Event body', :'userID', :'eventID', 'Event update');
+
+-- Newsletter issue
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    subject
+) values (
+    :'issueID',
+    'Newsletter body',
+    :'communityID',
+    'Newsletter'
+);
+
+-- Notifications
+insert into notification (
+    notification_id,
+    channel,
+    community_id,
+    community_newsletter_issue_id,
+    custom_notification_id,
+    group_id,
+    kind,
+    user_id
+) values
+    (:'notificationCustomID', 'email', null, null, :'customNotificationID', null, 'event-custom', :'userID'),
+    (:'notificationDiscordID', 'discord', null, null, null, :'groupID', 'event-published', null),
+    (:'notificationNewsletterID', 'email', null, :'issueID', null, null, 'community-newsletter', :'userID'),
+    (:'notificationSlackID', 'slack', :'communityID', null, null, null, 'event-published', null),
+    (:'notificationUserID', 'email', null, null, null, null, 'event-welcome', :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should resolve the community of custom notifications sent by its groups
+select is(
+    get_notification_community_id(:'notificationCustomID'::uuid),
+    :'communityID'::uuid,
+    'Should resolve the community of custom notifications sent by its groups'
+);
+
+-- Should resolve the community of group channel notifications
+select is(
+    get_notification_community_id(:'notificationDiscordID'::uuid),
+    :'communityID'::uuid,
+    'Should resolve the community of group channel notifications'
+);
+
+-- Should resolve the community of newsletter notifications
+select is(
+    get_notification_community_id(:'notificationNewsletterID'::uuid),
+    :'communityID'::uuid,
+    'Should resolve the community of newsletter notifications'
+);
+
+-- Should resolve the community of community channel notifications
+select is(
+    get_notification_community_id(:'notificationSlackID'::uuid),
+    :'communityID'::uuid,
+    'Should resolve the community of community channel notifications'
+);
+
+-- Should return null for notifications not sent on behalf of a community
+select is(
+    get_notification_community_id(:'notificationUserID'::uuid),
+    null::uuid,
+    'Should return null for notifications not sent on behalf of a community'
+);
+
+-- Should return null for non-existing notifications
+select is(
+    get_notification_community_id('c0b70000-0000-0000-0000-000000000099'::uuid),
+    null::uuid,
+    'Should return null for non-existing notifications'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
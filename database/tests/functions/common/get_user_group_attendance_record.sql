@@ -0,0 +1,139 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendedEventID 'c0ed0000-0000-0000-0000-000000000001'
+\set attendeeID 'c0ed0000-0000-0000-0000-000000000002'
+\set canceledEventID 'c0ed0000-0000-0000-0000-000000000003'
+\set communityID 'c0ed0000-0000-0000-0000-000000000004'
+\set eventCategoryID 'c0ed0000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0ed0000-0000-0000-0000-000000000006'
+\set groupID 'c0ed0000-0000-0000-0000-000000000007'
+\set missedEventID 'c0ed0000-0000-0000-0000-000000000008'
+\set newcomerID 'c0ed0000-0000-0000-0000-000000000009'
+\set noCheckInEventID 'c0ed0000-0000-0000-0000-00000000000a'
+\set otherAttendeeID 'c0ed0000-0000-0000-0000-00000000000b'
+\set upcomingEventID 'c0ed0000-0000-0000-0000-00000000000c'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'attendance-record-community',
+    'Attendance Record Community',
+    'Community for attendance record tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Attendance Group', 'attendance-group');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'attendeeID', gen_random_bytes(32), 'attendee@example.com', true, 'attendee'),
+    (:'newcomerID', gen_random_bytes(32), 'newcomer@example.com', true, 'newcomer'),
+    (:'otherAttendeeID', gen_random_bytes(32), 'other@example.com', true, 'other');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone,
+    published,
+    canceled,
+    starts_at
+) values
+    (:'attendedEventID', :'groupID', 'Attended', 'attended', 'Attended', :'eventCategoryID', 'in-person', 'UTC', true, false, '2024-01-01 10:00:00+00'),
+    (:'missedEventID', :'groupID', 'Missed', 'missed', 'Missed', :'eventCategoryID', 'in-person', 'UTC', true, false, '2024-02-01 10:00:00+00'),
+    (:'noCheckInEventID', :'groupID', 'No Check-In', 'no-check-in', 'No Check-In', :'eventCategoryID', 'in-person', 'UTC', true, false, '2024-03-01 10:00:00+00'),
+    (:'canceledEventID', :'groupID', 'Canceled', 'canceled', 'Canceled', :'eventCategoryID', 'in-person', 'UTC', false, true, '2024-04-01 10:00:00+00'),
+    (:'upcomingEventID', :'groupID', 'Upcoming', 'upcoming', 'Upcoming', :'eventCategoryID', 'in-person', 'UTC', true, false, '2030-01-01 10:00:00+00');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in) values
+    (:'attendedEventID', :'attendeeID', true),
+    (:'missedEventID', :'attendeeID', false),
+    (:'missedEventID', :'otherAttendeeID', true),
+    (:'noCheckInEventID', :'attendeeID', false),
+    (:'canceledEventID', :'attendeeID', false),
+    (:'canceledEventID', :'otherAttendeeID', true),
+    (:'upcomingEventID', :'attendeeID', false),
+    (:'upcomingEventID', :'otherAttendeeID', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should count attended and missed past events that used check-in
+select results_eq(
+    format(
+        'select events_attended, events_missed from get_user_group_attendance_record(%L::uuid, %L::uuid)',
+        :'groupID',
+        :'attendeeID'
+    ),
+    $$ values (1, 1) $$,
+    'Should count attended and missed past events that used check-in'
+);
+
+-- Should count attendees checked in to every past event
+select results_eq(
+    format(
+        'select events_attended, events_missed from get_user_group_attendance_record(%L::uuid, %L::uuid)',
+        :'groupID',
+        :'otherAttendeeID'
+    ),
+    $$ values (1, 0) $$,
+    'Should count attendees checked in to every past event'
+);
+
+-- Should return zero counts for users without past attendances
+select results_eq(
+    format(
+        'select events_attended, events_missed from get_user_group_attendance_record(%L::uuid, %L::uuid)',
+        :'groupID',
+        :'newcomerID'
+    ),
+    $$ values (0, 0) $$,
+    'Should return zero counts for users without past attendances'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
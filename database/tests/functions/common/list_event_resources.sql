@@ -0,0 +1,157 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0df0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0df0000-0000-0000-0000-000000000002'
+\set eventID 'c0df0000-0000-0000-0000-000000000003'
+\set eventNoResourcesID 'c0df0000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0df0000-0000-0000-0000-000000000005'
+\set groupID 'c0df0000-0000-0000-0000-000000000006'
+\set resourceSlidesID 'c0df0000-0000-0000-0000-000000000007'
+\set resourceWorkshopID 'c0df0000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values
+    (
+        :'eventID',
+        'Event with resources',
+        'event-with-resources',
+        'Event with resources',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    ),
+    (
+        :'eventNoResourcesID',
+        'Event without resources',
+        'event-without-resources',
+        'Event without resources',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    );
+
+-- Event resources
+insert into event_resource (
+    event_resource_id,
+    event_id,
+    content_type,
+    created_at,
+    file_name,
+    name,
+    size_bytes
+) values
+    (
+        :'resourceWorkshopID',
+        :'eventID',
+        'application/vnd.oasis.opendocument.presentation',
+        '2025-06-02 10:00:00+00',
+        'def.odp',
+        'workshop.odp',
+        4096
+    ),
+    (
+        :'resourceSlidesID',
+        :'eventID',
+        'application/pdf',
+        '2025-06-01 10:00:00+00',
+        'abc.pdf',
+        'slides.pdf',
+        2048
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list resources sorted by creation date
+select is(
+    list_event_resources(:'eventID'::uuid),
+    jsonb_build_array(
+        jsonb_build_object(
+            'content_type', 'application/pdf',
+            'created_at', floor(extract(epoch from '2025-06-01 10:00:00+00'::timestamptz)),
+            'event_resource_id', :'resourceSlidesID'::uuid,
+            'name', 'slides.pdf',
+            'size_bytes', 2048
+        ),
+        jsonb_build_object(
+            'content_type', 'application/vnd.oasis.opendocument.presentation',
+            'created_at', floor(extract(epoch from '2025-06-02 10:00:00+00'::timestamptz)),
+            'event_resource_id', :'resourceWorkshopID'::uuid,
+            'name', 'workshop.odp',
+            'size_bytes', 4096
+        )
+    ),
+    'Should list resources sorted by creation date'
+);
+
+-- Should return null for events without resources
+select ok(
+    list_event_resources(:'eventNoResourcesID'::uuid) is null,
+    'Should return null for events without resources'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
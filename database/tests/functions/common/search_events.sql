@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(25);
+select plan(26);
 
 -- ============================================================================
 -- VARIABLES
@@ -32,6 +32,7 @@ select plan(25);
 \set groupCategory3ID '0c160000-0000-0000-0000-000000000015'
 \set groupCategory4ID '0c160000-0000-0000-0000-000000000016'
 \set region1ID '0c160000-0000-0000-0000-000000000017'
+\set topic1ID '0c160000-0000-0000-0000-000000000018'
 
 -- ============================================================================
 -- SEED DATA
@@ -386,6 +387,14 @@ insert into event (
     null
 );
 
+-- Topic
+insert into topic (topic_id, community_id, name)
+values (:'topic1ID', :'community1ID', 'Kubernetes');
+
+-- Event topic
+insert into event_topic (event_id, topic_id)
+values (:'event2ID', :'topic1ID');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -597,6 +606,22 @@ select is(
     'Should filter events by region'
 );
 
+-- Should filter events by topic
+select is(
+    (select search_events(
+        jsonb_build_object(
+            'community', jsonb_build_array('test-community'),
+            'topic', jsonb_build_array('kubernetes'),
+            'limit', 10,
+            'offset', 0
+        )
+    )::jsonb->'events'),
+    jsonb_build_array(
+        get_event_summary(:'community1ID'::uuid, :'group1ID'::uuid, :'event2ID'::uuid)::jsonb
+    ),
+    'Should filter events by topic'
+);
+
 -- Should filter events by text search query
 select is(
     (select search_events(
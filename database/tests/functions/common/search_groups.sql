@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(17);
+select plan(19);
 
 -- ============================================================================
 -- VARIABLES
@@ -26,6 +26,7 @@ select plan(17);
 \set groupCategory4ID '0c170000-0000-0000-0000-00000000000f'
 \set nonExistentCommunityID '0c170000-0000-0000-0000-000000000010'
 \set region1ID '0c170000-0000-0000-0000-000000000011'
+\set topic1ID '0c170000-0000-0000-0000-000000000012'
 
 -- ============================================================================
 -- SEED DATA
@@ -148,6 +149,17 @@ insert into "group" (
      ST_GeogFromText('POINT(-104.9903 39.7392)'), 'This group belongs to an inactive community.',
      'https://example.com/inactive-community-logo.png', true, '2024-01-08 10:00:00+00', false);
 
+-- Dormant group
+update "group" set dormant_since = '2024-07-02 10:00:00+00' where group_id = :'group2ID';
+
+-- Topic
+insert into topic (topic_id, community_id, name)
+values (:'topic1ID', :'community1ID', 'Kubernetes');
+
+-- Group topic
+insert into group_topic (group_id, topic_id)
+values (:'group3ID', :'topic1ID');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -203,6 +215,15 @@ select ok(
     'Should exclude groups from inactive communities even when include_inactive is enabled'
 );
 
+-- Should only return dormant groups when dormant is enabled
+select is(
+    (select search_groups(jsonb_build_object('dormant', true, 'limit', 10, 'offset', 0))::jsonb->'groups'),
+    jsonb_build_array(
+        get_group_summary(:'community1ID'::uuid, :'group2ID'::uuid)::jsonb
+    ),
+    'Should only return dormant groups when dormant is enabled'
+);
+
 -- Should filter groups by community
 select is(
     (select search_groups(
@@ -288,6 +309,22 @@ select is(
     'Should filter groups by region'
 );
 
+-- Should filter groups by topic
+select is(
+    (select search_groups(
+        jsonb_build_object(
+            'community', jsonb_build_array('test-community'),
+            'topic', jsonb_build_array('kubernetes'),
+            'limit', 10,
+            'offset', 0
+        )
+    )::jsonb->'groups'),
+    jsonb_build_array(
+        get_group_summary(:'community1ID'::uuid, :'group3ID'::uuid)::jsonb
+    ),
+    'Should filter groups by topic'
+);
+
 -- Should filter groups by text search query
 select is(
     (select search_groups(
@@ -0,0 +1,92 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set activeCommunityID 'c1340000-0000-0000-0000-000000000001'
+\set inactiveCommunityID 'c1340000-0000-0000-0000-000000000002'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    active,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'activeCommunityID',
+    'domain-lookup',
+    'Domain Lookup',
+    'Community used for domain lookups',
+    true,
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+), (
+    :'inactiveCommunityID',
+    'inactive-domain-lookup',
+    'Inactive Domain Lookup',
+    'Inactive community used for domain lookups',
+    false,
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Domains
+insert into community_domain (community_id, domain, verified_at) values
+    (:'activeCommunityID', 'community.example.org', current_timestamp),
+    (:'activeCommunityID', 'pending.example.org', null),
+    (:'inactiveCommunityID', 'inactive.example.org', current_timestamp);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the community name for a verified domain
+select is(
+    get_community_name_by_domain('community.example.org'),
+    'domain-lookup',
+    'Should return the community name for a verified domain'
+);
+
+-- Should match domains case insensitively
+select is(
+    get_community_name_by_domain('Community.Example.org'),
+    'domain-lookup',
+    'Should match domains case insensitively'
+);
+
+-- Should return null for a domain pending verification
+select is(
+    get_community_name_by_domain('pending.example.org'),
+    null,
+    'Should return null for a domain pending verification'
+);
+
+-- Should return null for a domain of an inactive community
+select is(
+    get_community_name_by_domain('inactive.example.org'),
+    null,
+    'Should return null for a domain of an inactive community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
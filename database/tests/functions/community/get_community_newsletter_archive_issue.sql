@@ -0,0 +1,89 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000081'
+\set draftIssueID 'c1360000-0000-0000-0000-000000000082'
+\set newerIssueID 'c1360000-0000-0000-0000-000000000083'
+\set olderIssueID 'c1360000-0000-0000-0000-000000000084'
+\set otherCommunityID 'c1360000-0000-0000-0000-000000000085'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Newsletter issues
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    subject,
+    recipient_count,
+    sent_at
+) values
+    (:'draftIssueID', 'Draft body', :'communityID', 'Draft issue', null, null),
+    (:'olderIssueID', 'Older body', :'communityID', 'Older issue', 1, '2026-01-01 00:00:00+00'),
+    (:'newerIssueID', 'Newer body', :'communityID', 'Newer issue', 1, '2026-02-01 00:00:00+00');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the sent issue
+select is(
+    get_community_newsletter_archive_issue(:'communityID'::uuid, :'olderIssueID'::uuid)::jsonb,
+    jsonb_build_object(
+        'body', 'Older body',
+        'community_newsletter_issue_id', :'olderIssueID'::uuid,
+        'sent_at', 1767225600,
+        'subject', 'Older issue'
+    ),
+    'Should return the sent issue'
+);
+
+-- Should not return draft issues
+select is(
+    get_community_newsletter_archive_issue(:'communityID'::uuid, :'draftIssueID'::uuid)::jsonb,
+    null,
+    'Should not return draft issues'
+);
+
+-- Should return null for an issue of another community
+select is(
+    get_community_newsletter_archive_issue(:'otherCommunityID'::uuid, :'olderIssueID'::uuid)::jsonb,
+    null,
+    'Should return null for an issue of another community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
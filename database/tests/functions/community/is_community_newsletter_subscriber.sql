@@ -0,0 +1,69 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000091'
+\set subscriberID 'c1360000-0000-0000-0000-000000000092'
+\set userID 'c1360000-0000-0000-0000-000000000093'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, username) values
+    (:'subscriberID', 'hash-1', 'subscriber@example.com', 'subscriber'),
+    (:'userID', 'hash-2', 'user@example.com', 'user');
+
+-- Subscribers
+insert into community_newsletter_subscriber (community_id, user_id)
+values (:'communityID', :'subscriberID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return true for subscribers
+select ok(
+    is_community_newsletter_subscriber(:'communityID'::uuid, :'subscriberID'::uuid),
+    'Should return true for subscribers'
+);
+
+-- Should return false for users not subscribed
+select ok(
+    not is_community_newsletter_subscriber(:'communityID'::uuid, :'userID'::uuid),
+    'Should return false for users not subscribed'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
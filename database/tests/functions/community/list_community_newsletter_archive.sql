@@ -0,0 +1,88 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000071'
+\set draftIssueID 'c1360000-0000-0000-0000-000000000072'
+\set newerIssueID 'c1360000-0000-0000-0000-000000000073'
+\set olderIssueID 'c1360000-0000-0000-0000-000000000074'
+\set otherCommunityID 'c1360000-0000-0000-0000-000000000075'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Newsletter issues
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    subject,
+    recipient_count,
+    sent_at
+) values
+    (:'draftIssueID', 'Draft body', :'communityID', 'Draft issue', null, null),
+    (:'olderIssueID', 'Older body', :'communityID', 'Older issue', 1, '2026-01-01 00:00:00+00'),
+    (:'newerIssueID', 'Newer body', :'communityID', 'Newer issue', 1, '2026-02-01 00:00:00+00');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the sent issues, newest first
+select is(
+    list_community_newsletter_archive(:'communityID'::uuid)::jsonb,
+    jsonb_build_array(
+        jsonb_build_object(
+            'community_newsletter_issue_id', :'newerIssueID'::uuid,
+            'sent_at', 1769904000,
+            'subject', 'Newer issue'
+        ),
+        jsonb_build_object(
+            'community_newsletter_issue_id', :'olderIssueID'::uuid,
+            'sent_at', 1767225600,
+            'subject', 'Older issue'
+        )
+    ),
+    'Should return the sent issues, newest first'
+);
+
+-- Should return an empty list for a community without sent issues
+select is(
+    list_community_newsletter_archive(:'otherCommunityID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for a community without sent issues'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
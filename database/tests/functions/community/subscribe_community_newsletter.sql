@@ -0,0 +1,76 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000101'
+\set userID 'c1360000-0000-0000-0000-000000000102'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- User
+insert into "user" (user_id, auth_hash, email, username)
+values (:'userID', 'hash', 'user@example.com', 'user');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should subscribe the user to the newsletter
+select lives_ok(
+    format(
+        $$ select subscribe_community_newsletter(%L::uuid, %L::uuid) $$,
+        :'communityID',
+        :'userID'
+    ),
+    'Should subscribe the user to the newsletter'
+);
+
+-- Should ignore subscribing the same user twice
+select lives_ok(
+    format(
+        $$ select subscribe_community_newsletter(%L::uuid, %L::uuid) $$,
+        :'communityID',
+        :'userID'
+    ),
+    'Should ignore subscribing the same user twice'
+);
+select results_eq(
+    'select community_id, user_id from community_newsletter_subscriber',
+    format($$ values (%L::uuid, %L::uuid) $$, :'communityID', :'userID'),
+    'Should store a single subscription'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,117 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000111'
+\set enqueuedIssueID 'c1360000-0000-0000-0000-000000000112'
+\set pendingIssueID 'c1360000-0000-0000-0000-000000000113'
+\set subscriberID 'c1360000-0000-0000-0000-000000000114'
+\set userID 'c1360000-0000-0000-0000-000000000115'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, username) values
+    (:'subscriberID', 'hash-1', 'subscriber@example.com', 'subscriber'),
+    (:'userID', 'hash-2', 'user@example.com', 'user');
+
+-- Subscribers
+insert into community_newsletter_subscriber (community_id, user_id) values
+    (:'communityID', :'subscriberID'),
+    (:'communityID', :'userID');
+
+-- Newsletter issues
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    subject,
+    recipient_count,
+    sent_at
+) values
+    (:'enqueuedIssueID', 'Enqueued body', :'communityID', 'Enqueued issue', 2, current_timestamp),
+    (:'pendingIssueID', 'Pending body', :'communityID', 'Pending issue', 2, current_timestamp);
+
+-- Newsletter issue recipients
+insert into community_newsletter_issue_recipient (
+    community_newsletter_issue_id,
+    user_id,
+    enqueued_at
+) values
+    (:'enqueuedIssueID', :'subscriberID', current_timestamp),
+    (:'enqueuedIssueID', :'userID', current_timestamp),
+    (:'pendingIssueID', :'subscriberID', null),
+    (:'pendingIssueID', :'userID', null);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should unsubscribe the user from the newsletter
+select lives_ok(
+    format(
+        $$ select unsubscribe_community_newsletter(%L::uuid, %L::uuid) $$,
+        :'communityID',
+        :'userID'
+    ),
+    'Should unsubscribe the user from the newsletter'
+);
+select results_eq(
+    'select user_id from community_newsletter_subscriber',
+    format($$ values (%L::uuid) $$, :'subscriberID'),
+    'Should keep the other subscriptions'
+);
+
+-- Should skip the user in the issues still being enqueued
+select results_eq(
+    $$
+        select community_newsletter_issue_id, user_id
+        from community_newsletter_issue_recipient
+        order by community_newsletter_issue_id, user_id
+    $$,
+    format(
+        $$ values (%L::uuid, %L::uuid), (%L::uuid, %L::uuid), (%L::uuid, %L::uuid) $$,
+        :'enqueuedIssueID',
+        :'subscriberID',
+        :'enqueuedIssueID',
+        :'userID',
+        :'pendingIssueID',
+        :'subscriberID'
+    ),
+    'Should skip the user in the issues still being enqueued'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
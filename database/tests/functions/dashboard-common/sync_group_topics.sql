@@ -0,0 +1,124 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c11c0000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c11c0000-0000-0000-0000-000000000002'
+\set groupID 'c11c0000-0000-0000-0000-000000000003'
+\set otherCommunityID 'c11c0000-0000-0000-0000-000000000004'
+\set otherTopicID 'c11c0000-0000-0000-0000-000000000005'
+\set topic1ID 'c11c0000-0000-0000-0000-000000000006'
+\set topic2ID 'c11c0000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cncf-seattle',
+    'CNCF Seattle',
+    'Community for topic tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Other community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'otherCommunityID',
+    'cncf-portland',
+    'CNCF Portland',
+    'Other community for topic tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Topic Group', 'topic-group');
+
+-- Topic
+insert into topic (topic_id, community_id, name)
+values
+    (:'otherTopicID', :'otherCommunityID', 'Networking'),
+    (:'topic1ID', :'communityID', 'Kubernetes'),
+    (:'topic2ID', :'communityID', 'Security');
+
+-- Group topic
+insert into group_topic (group_id, topic_id)
+values (:'groupID', :'topic1ID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should replace the group topics, ignoring repeated IDs
+select lives_ok(
+    format(
+        $$ select sync_group_topics(%L::uuid, jsonb_build_array(%L, %L)) $$,
+        :'groupID',
+        :'topic2ID',
+        :'topic2ID'
+    ),
+    'Should replace the group topics'
+);
+select results_eq(
+    format($$ select topic_id from group_topic where group_id = %L::uuid $$, :'groupID'),
+    format($$ values (%L::uuid) $$, :'topic2ID'),
+    'Group should only have the topics provided'
+);
+
+-- Should reject topics from another community
+select throws_ok(
+    format(
+        $$ select sync_group_topics(%L::uuid, jsonb_build_array(%L)) $$,
+        :'groupID',
+        :'otherTopicID'
+    ),
+    'topic not found in community',
+    'Should reject topics from another community'
+);
+
+-- Should clear the group topics when none are provided
+select sync_group_topics(:'groupID'::uuid, null);
+select is_empty(
+    format($$ select 1 from group_topic where group_id = %L::uuid $$, :'groupID'),
+    'Group topics should be cleared when none are provided'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -246,6 +246,7 @@ insert into event (
     event_kind_id,
     group_id,
     name,
+    payment_currency_code,
     published,
     slug,
     timezone
@@ -256,6 +257,7 @@ insert into event (
     'virtual',
     :'group4ID'::uuid,
     'Ticketed Group Event',
+    'USD',
     true,
     'ticketed-group-event',
     'UTC'
@@ -284,6 +286,7 @@ insert into event (
     event_kind_id,
     group_id,
     name,
+    payment_currency_code,
     published,
     slug,
     timezone
@@ -294,6 +297,7 @@ insert into event (
     'virtual',
     :'group5ID'::uuid,
     'Draft Ticketed Group Event',
+    'USD',
     false,
     'draft-ticketed-group-event',
     'UTC'
@@ -341,7 +345,8 @@ select lives_ok(
             "twitter_url": "https://twitter.com/updated",
             "tags": ["updated", "test"],
             "logo_url": "https://example.com/updated-logo.png",
-            "og_image_url": "https://example.com/updated-og.png"
+            "og_image_url": "https://example.com/updated-og.png",
+            "private": true
         }'::jsonb
     )$$,
         :'communityID',
@@ -358,6 +363,7 @@ select is(
         $json$
     {
         "name": "Updated Group",
+        "private": true,
         "slug": "abc1234",
         "slug_pretty": "updated-group",
         "category": {
@@ -514,6 +520,7 @@ select lives_ok(
         %L::uuid,
         '{
             "name": "Updated Group Empty Strings",
+        "private": false,
             "category_id": "%s",
             "description": "",
             "description_short": "",
@@ -581,6 +588,7 @@ select lives_ok(
         %L::uuid,
         '{
             "name": "Updated Group Null Arrays",
+        "private": false,
             "category_id": "%s",
             "description": "Updated description",
             "tags": null,
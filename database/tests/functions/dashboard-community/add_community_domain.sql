@@ -0,0 +1,116 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1340000-0000-0000-0000-000000000011'
+\set userID 'c1340000-0000-0000-0000-000000000012'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'domains-community',
+    'Domains Community',
+    'Community used for custom domains tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- User
+insert into "user" (user_id, auth_hash, email, username)
+values (:'userID', 'hash', 'admin@example.com', 'admin');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add the domain normalized and pending verification
+select lives_ok(
+    format(
+        $$ select add_community_domain(%L::uuid, %L::uuid, '  Community.Example.org ') $$,
+        :'userID',
+        :'communityID'
+    ),
+    'Should add the domain'
+);
+select results_eq(
+    format(
+        $$
+        select domain, created_by, verified_at is null, length(verification_token)
+        from community_domain
+        where community_id = %L::uuid
+        $$,
+        :'communityID'
+    ),
+    format(
+        $$ values ('community.example.org'::text, %L::uuid, true, 32) $$,
+        :'userID'
+    ),
+    'Should store the domain normalized and pending verification'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        select
+            'community_domain_added',
+            %L::uuid,
+            %L::uuid,
+            'community_domain',
+            community_domain_id
+        from community_domain
+        where community_id = %L::uuid
+        $$,
+        :'userID',
+        :'communityID',
+        :'communityID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should reject domains already in use
+select throws_ok(
+    format(
+        $$ select add_community_domain(%L::uuid, %L::uuid, 'community.example.org') $$,
+        :'userID',
+        :'communityID'
+    ),
+    'domain already in use',
+    'Should reject domains already in use'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,119 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000001'
+\set userID 'c1360000-0000-0000-0000-000000000002'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- User
+insert into "user" (user_id, auth_hash, email, username)
+values (:'userID', 'hash', 'admin@example.com', 'admin');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add the issue as a draft
+select lives_ok(
+    format(
+        $$
+        select add_community_newsletter_issue(
+            %L::uuid,
+            %L::uuid,
+            '{"body": " Hello **everyone** ", "subject": " Monthly update "}'::jsonb
+        )
+        $$,
+        :'userID',
+        :'communityID'
+    ),
+    'Should add the issue'
+);
+select results_eq(
+    format(
+        $$
+        select body, created_by, recipient_count, sent_at, subject
+        from community_newsletter_issue
+        where community_id = %L::uuid
+        $$,
+        :'communityID'
+    ),
+    format(
+        $$
+        values (
+            'Hello **everyone**'::text,
+            %L::uuid,
+            null::int,
+            null::timestamptz,
+            'Monthly update'::text
+        )
+        $$,
+        :'userID'
+    ),
+    'Should store the issue trimmed and not sent'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        select
+            'community_newsletter_issue_added',
+            %L::uuid,
+            %L::uuid,
+            'community_newsletter_issue',
+            community_newsletter_issue_id
+        from community_newsletter_issue
+        where community_id = %L::uuid
+        $$,
+        :'userID',
+        :'communityID',
+        :'communityID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -97,6 +97,7 @@ select is(
     format(
         '{
         "name": "Simple Test Group",
+        "private": false,
         "category": {
             "group_category_id": "%s",
             "name": "Technology",
@@ -211,6 +212,7 @@ select is(
     format(
         '{
         "name": "Full Test Group",
+        "private": false,
         "category": {
             "group_category_id": "%s",
             "name": "Technology",
@@ -0,0 +1,117 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1180000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cncf-seattle',
+    'CNCF Seattle',
+    'Community for topic tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should create a new topic and auto-generate normalized name
+select lives_ok(
+    format(
+        $$ select add_topic(
+        null::uuid,
+        %L::uuid,
+        jsonb_build_object('name', 'Cloud Native Security')
+    ) $$,
+        :'communityID'
+    ),
+    'Should create a topic with generated normalized name'
+);
+select results_eq(
+    format(
+        $$
+    select
+        t.name,
+        t.normalized_name
+    from topic t
+    where t.community_id = %L::uuid
+        $$,
+        :'communityID'
+    ),
+    $$ values ('Cloud Native Security'::text, 'cloud-native-security'::text) $$,
+    'Should store topic name and generated normalized name'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        select
+            'topic_added',
+            null::uuid,
+            null::text,
+            %L::uuid,
+            'topic',
+            topic_id
+        from topic
+        where community_id = %L::uuid
+        $$,
+        :'communityID',
+        :'communityID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should not allow duplicate topic normalized name in same community
+select throws_ok(
+    format(
+        $$ select add_topic(
+        null::uuid,
+        %L::uuid,
+        jsonb_build_object('name', 'cloud native security')
+    ) $$,
+        :'communityID'
+    ),
+    'topic already exists',
+    'Should reject duplicate topic names'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
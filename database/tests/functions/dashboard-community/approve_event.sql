@@ -0,0 +1,199 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0ea0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0ea0000-0000-0000-0000-000000000002'
+\set eventDraftID 'c0ea0000-0000-0000-0000-000000000003'
+\set eventPendingID 'c0ea0000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0ea0000-0000-0000-0000-000000000005'
+\set groupID 'c0ea0000-0000-0000-0000-000000000006'
+\set otherCommunityID 'c0ea0000-0000-0000-0000-000000000007'
+\set userID 'c0ea0000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    event_approval_required
+) values
+    (
+        :'communityID',
+        'approve-event-community',
+        'Approve Event Community',
+        'Community for approve event tests',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png',
+        true
+    ),
+    (
+        :'otherCommunityID',
+        'other-community',
+        'Other Community',
+        'Another community',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png',
+        false
+    );
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group');
+
+-- User
+insert into "user" (user_id, auth_hash, email, username)
+values (:'userID', 'admin-hash', 'admin@test.local', 'admin');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    approval_status,
+    published
+) values
+    -- Draft event waiting for approval
+    (
+        :'eventPendingID',
+        :'groupID',
+        'Pending Event',
+        'pending-event',
+        'Event waiting for approval',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        'pending',
+        false
+    ),
+    -- Draft event not sent for approval
+    (
+        :'eventDraftID',
+        :'groupID',
+        'Draft Event',
+        'draft-event',
+        'Event not sent for approval',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        null,
+        false
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should approve the pending event and return its group id
+select is(
+    approve_event(:'userID'::uuid, :'communityID'::uuid, :'eventPendingID'::uuid, null::text),
+    :'groupID'::uuid,
+    'Should approve the pending event and return its group id'
+);
+
+-- Should publish the approved event
+select results_eq(
+    format(
+        $$
+        select approval_status, published, published_by
+        from event
+        where event_id = %L::uuid
+        $$,
+        :'eventPendingID'
+    ),
+    format(
+        $$ values ('approved'::text, true, %L::uuid) $$,
+        :'userID'
+    ),
+    'Should publish the approved event'
+);
+
+-- Should track the approval and the publication
+select results_eq(
+    $$
+        select action, community_id, group_id, event_id
+        from audit_log
+        order by action
+    $$,
+    format(
+        $$
+        values
+            ('event_approved', %L::uuid, %L::uuid, %L::uuid),
+            ('event_published', %L::uuid, %L::uuid, %L::uuid)
+        $$,
+        :'communityID', :'groupID', :'eventPendingID',
+        :'communityID', :'groupID', :'eventPendingID'
+    ),
+    'Should track the approval and the publication'
+);
+
+-- Should throw error when the event is not pending approval
+select throws_ok(
+    format(
+        'select approve_event(%L::uuid, %L::uuid, %L::uuid, null)',
+        :'userID',
+        :'communityID',
+        :'eventDraftID'
+    ),
+    'event not found or not pending approval',
+    'Should throw error when the event is not pending approval'
+);
+
+-- Should throw error when the event belongs to another community
+select throws_ok(
+    format(
+        'select approve_event(%L::uuid, %L::uuid, %L::uuid, null)',
+        :'userID',
+        :'otherCommunityID',
+        :'eventPendingID'
+    ),
+    'event not found or not pending approval',
+    'Should throw error when the event belongs to another community'
+);
+
+-- Should keep events not sent for approval unpublished
+select is(
+    (select published from event where event_id = :'eventDraftID'),
+    false,
+    'Should keep events not sent for approval unpublished'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
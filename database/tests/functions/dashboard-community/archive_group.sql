@@ -0,0 +1,151 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1160000-0000-0000-0000-000000000001'
+\set groupArchivedID 'c1160000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c1160000-0000-0000-0000-000000000003'
+\set groupID 'c1160000-0000-0000-0000-000000000004'
+\set groupInactiveID 'c1160000-0000-0000-0000-000000000005'
+\set unknownCommunityID 'c1160000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'archive-group-community',
+    'Archive Group Community',
+    'Community for archive group tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Groups
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    active,
+    archived_at
+) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Active Group', 'active-group', true, null),
+    (:'groupArchivedID', :'communityID', :'groupCategoryID', 'Archived Group', 'archived-group', true, '2024-01-01 00:00:00+00'),
+    (:'groupInactiveID', :'communityID', :'groupCategoryID', 'Inactive Group', 'inactive-group', false, null);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should execute archive_group successfully
+select lives_ok(
+    format(
+        'select archive_group(null::uuid, %L::uuid, %L::uuid)',
+        :'communityID',
+        :'groupID'
+    ),
+    'Should execute archive_group successfully'
+);
+
+-- Should set the archival timestamp
+select isnt(
+    (select archived_at from "group" where group_id = :'groupID'::uuid),
+    null,
+    'Should set the archival timestamp'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'group_archived',
+            null::uuid,
+            null::text,
+            %L::uuid,
+            %L::uuid,
+            'group',
+            %L::uuid
+        )
+        $$,
+        :'communityID',
+        :'groupID',
+        :'groupID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should throw error for an already archived group
+select throws_ok(
+    format(
+        $$select archive_group(null::uuid, %L::uuid, %L::uuid)$$,
+        :'communityID',
+        :'groupArchivedID'
+    ),
+    'group not found, inactive or already archived',
+    'Should throw error when the group is already archived'
+);
+
+-- Should throw error for an inactive group
+select throws_ok(
+    format(
+        $$select archive_group(null::uuid, %L::uuid, %L::uuid)$$,
+        :'communityID',
+        :'groupInactiveID'
+    ),
+    'group not found, inactive or already archived',
+    'Should throw error when the group is inactive'
+);
+
+-- Should throw error for wrong community_id
+select throws_ok(
+    format(
+        $$select archive_group(null::uuid, %L::uuid, %L::uuid)$$,
+        :'unknownCommunityID',
+        :'groupArchivedID'
+    ),
+    'group not found, inactive or already archived',
+    'Should throw error when community_id does not match'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,76 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000031'
+\set otherCommunityID 'c1360000-0000-0000-0000-000000000032'
+\set user1ID 'c1360000-0000-0000-0000-000000000033'
+\set user2ID 'c1360000-0000-0000-0000-000000000034'
+\set user3ID 'c1360000-0000-0000-0000-000000000035'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'user1ID', 'hash-1', 'user1@example.com', true, 'user1'),
+    (:'user2ID', 'hash-2', 'user2@example.com', true, 'user2'),
+    (:'user3ID', 'hash-3', 'user3@example.com', false, 'user3');
+
+-- Subscribers
+insert into community_newsletter_subscriber (community_id, user_id) values
+    (:'communityID', :'user1ID'),
+    (:'communityID', :'user2ID'),
+    (:'communityID', :'user3ID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should count the subscribers with a verified email
+select is(
+    count_community_newsletter_subscribers(:'communityID'::uuid),
+    2,
+    'Should count the subscribers with a verified email'
+);
+
+-- Should return zero for a community without subscribers
+select is(
+    count_community_newsletter_subscribers(:'otherCommunityID'::uuid),
+    0,
+    'Should return zero for a community without subscribers'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,118 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000011'
+\set draftIssueID 'c1360000-0000-0000-0000-000000000012'
+\set otherCommunityID 'c1360000-0000-0000-0000-000000000013'
+\set sentIssueID 'c1360000-0000-0000-0000-000000000014'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Newsletter issues
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    subject,
+    recipient_count,
+    sent_at
+) values
+    (:'draftIssueID', 'Draft body', :'communityID', 'Draft issue', null, null),
+    (:'sentIssueID', 'Sent body', :'communityID', 'Sent issue', 1, current_timestamp);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject deleting an issue from another community
+select throws_ok(
+    format(
+        $$ select delete_community_newsletter_issue(null::uuid, %L::uuid, %L::uuid) $$,
+        :'otherCommunityID',
+        :'draftIssueID'
+    ),
+    'newsletter issue not found',
+    'Should reject deleting an issue from another community'
+);
+
+-- Should reject deleting a sent issue
+select throws_ok(
+    format(
+        $$ select delete_community_newsletter_issue(null::uuid, %L::uuid, %L::uuid) $$,
+        :'communityID',
+        :'sentIssueID'
+    ),
+    'sent newsletter issues cannot be deleted',
+    'Should reject deleting a sent issue'
+);
+
+-- Should delete the draft issue
+select lives_ok(
+    format(
+        $$ select delete_community_newsletter_issue(null::uuid, %L::uuid, %L::uuid) $$,
+        :'communityID',
+        :'draftIssueID'
+    ),
+    'Should delete the draft issue'
+);
+select results_eq(
+    'select community_newsletter_issue_id from community_newsletter_issue',
+    format($$ values (%L::uuid) $$, :'sentIssueID'),
+    'Should keep only the sent issue'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select action, resource_type, resource_id, details
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'community_newsletter_issue_deleted'::text,
+            'community_newsletter_issue'::text,
+            %L::uuid,
+            '{"name": "Draft issue"}'::jsonb
+        )
+        $$,
+        :'draftIssueID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
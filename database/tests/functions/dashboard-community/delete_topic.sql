@@ -0,0 +1,122 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c11a0000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c11a0000-0000-0000-0000-000000000002'
+\set groupID 'c11a0000-0000-0000-0000-000000000003'
+\set otherCommunityID 'c11a0000-0000-0000-0000-000000000004'
+\set topicID 'c11a0000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cncf-seattle',
+    'CNCF Seattle',
+    'Community for topic tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Topic Group', 'topic-group');
+
+-- Topic
+insert into topic (topic_id, community_id, name)
+values (:'topicID', :'communityID', 'Kubernetes');
+
+-- Group topic
+insert into group_topic (group_id, topic_id)
+values (:'groupID', :'topicID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject topics from another community
+select throws_ok(
+    format(
+        $$ select delete_topic(null::uuid, %L::uuid, %L::uuid) $$,
+        :'otherCommunityID',
+        :'topicID'
+    ),
+    'topic not found',
+    'Should reject topics from another community'
+);
+
+-- Should delete the topic
+select lives_ok(
+    format(
+        $$ select delete_topic(null::uuid, %L::uuid, %L::uuid) $$,
+        :'communityID',
+        :'topicID'
+    ),
+    'Should delete an existing topic'
+);
+select is_empty(
+    format($$ select 1 from topic where topic_id = %L::uuid $$, :'topicID'),
+    'Topic should be deleted'
+);
+
+-- Should remove the topic assignments
+select is_empty(
+    format($$ select 1 from group_topic where topic_id = %L::uuid $$, :'topicID'),
+    'Topic assignments should be removed'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            community_id,
+            resource_type,
+            resource_id,
+            details
+        from audit_log
+    $$,
+    format(
+        $$ values (
+            'topic_deleted'::text,
+            %L::uuid,
+            'topic'::text,
+            %L::uuid,
+            '{"name": "Kubernetes"}'::jsonb
+        ) $$,
+        :'communityID',
+        :'topicID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,82 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityDomainID 'c1340000-0000-0000-0000-000000000031'
+\set communityID 'c1340000-0000-0000-0000-000000000032'
+\set otherCommunityID 'c1340000-0000-0000-0000-000000000033'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'domains-community',
+    'Domains Community',
+    'Community used for custom domains tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Domain
+insert into community_domain (
+    community_domain_id,
+    community_id,
+    created_at,
+    domain,
+    verification_token
+) values (
+    :'communityDomainID',
+    :'communityID',
+    '2026-01-01 00:00:00+00',
+    'community.example.org',
+    'token'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the domain details
+select is(
+    get_community_domain(:'communityID'::uuid, :'communityDomainID'::uuid)::jsonb,
+    jsonb_build_object(
+        'community_domain_id', :'communityDomainID'::uuid,
+        'created_at', 1767225600,
+        'domain', 'community.example.org',
+        'verification_token', 'token'
+    ),
+    'Should return the domain details'
+);
+
+-- Should return null for a domain of another community
+select is(
+    get_community_domain(:'otherCommunityID'::uuid, :'communityDomainID'::uuid)::jsonb,
+    null,
+    'Should return null for a domain of another community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,111 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000021'
+\set draftIssueID 'c1360000-0000-0000-0000-000000000022'
+\set otherCommunityID 'c1360000-0000-0000-0000-000000000023'
+\set sentIssueID 'c1360000-0000-0000-0000-000000000024'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Newsletter issues
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    created_at,
+    subject,
+    recipient_count,
+    sent_at
+) values
+    (
+        :'draftIssueID',
+        'Draft body',
+        :'communityID',
+        '2026-01-01 00:00:00+00',
+        'Draft issue',
+        null,
+        null
+    ),
+    (
+        :'sentIssueID',
+        'Sent body',
+        :'communityID',
+        '2026-01-01 00:00:00+00',
+        'Sent issue',
+        2,
+        '2026-02-01 00:00:00+00'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the draft issue details
+select is(
+    get_community_newsletter_issue(:'communityID'::uuid, :'draftIssueID'::uuid)::jsonb,
+    jsonb_build_object(
+        'body', 'Draft body',
+        'community_newsletter_issue_id', :'draftIssueID'::uuid,
+        'created_at', 1767225600,
+        'subject', 'Draft issue'
+    ),
+    'Should return the draft issue details'
+);
+
+-- Should return the sent issue details
+select is(
+    get_community_newsletter_issue(:'communityID'::uuid, :'sentIssueID'::uuid)::jsonb,
+    jsonb_build_object(
+        'body', 'Sent body',
+        'community_newsletter_issue_id', :'sentIssueID'::uuid,
+        'created_at', 1767225600,
+        'recipient_count', 2,
+        'sent_at', 1769904000,
+        'subject', 'Sent issue'
+    ),
+    'Should return the sent issue details'
+);
+
+-- Should return null for an issue of another community
+select is(
+    get_community_newsletter_issue(:'otherCommunityID'::uuid, :'draftIssueID'::uuid)::jsonb,
+    null,
+    'Should return null for an issue of another community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
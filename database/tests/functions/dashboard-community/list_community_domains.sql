@@ -0,0 +1,101 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityDomain1ID 'c1340000-0000-0000-0000-000000000041'
+\set communityDomain2ID 'c1340000-0000-0000-0000-000000000042'
+\set communityID 'c1340000-0000-0000-0000-000000000043'
+\set emptyCommunityID 'c1340000-0000-0000-0000-000000000044'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'domains-community',
+    'Domains Community',
+    'Community used for custom domains tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Domains
+insert into community_domain (
+    community_domain_id,
+    community_id,
+    created_at,
+    domain,
+    verification_token,
+    verified_at
+) values (
+    :'communityDomain1ID',
+    :'communityID',
+    '2026-01-01 00:00:00+00',
+    'www.example.org',
+    'token-1',
+    null
+), (
+    :'communityDomain2ID',
+    :'communityID',
+    '2026-01-01 00:00:00+00',
+    'community.example.org',
+    'token-2',
+    '2026-01-02 00:00:00+00'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the community domains sorted by domain
+select is(
+    list_community_domains(:'communityID'::uuid)::jsonb,
+    jsonb_build_array(
+        jsonb_build_object(
+            'community_domain_id', :'communityDomain2ID'::uuid,
+            'created_at', 1767225600,
+            'domain', 'community.example.org',
+            'verification_token', 'token-2',
+            'verified_at', 1767312000
+        ),
+        jsonb_build_object(
+            'community_domain_id', :'communityDomain1ID'::uuid,
+            'created_at', 1767225600,
+            'domain', 'www.example.org',
+            'verification_token', 'token-1'
+        )
+    ),
+    'Should return the community domains sorted by domain'
+);
+
+-- Should return an empty list for a community without domains
+select is(
+    list_community_domains(:'emptyCommunityID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for a community without domains'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
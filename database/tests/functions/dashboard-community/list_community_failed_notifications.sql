@@ -0,0 +1,246 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0b80000-0000-0000-0000-000000000001'
+\set customNotificationID 'c0b80000-0000-0000-0000-000000000061'
+\set groupCategoryID 'c0b80000-0000-0000-0000-000000000021'
+\set groupID 'c0b80000-0000-0000-0000-000000000031'
+\set missingCommunityID 'c0b80000-0000-0000-0000-000000000099'
+\set notificationFailedID 'c0b80000-0000-0000-0000-000000000081'
+\set notificationGroupID 'c0b80000-0000-0000-0000-000000000083'
+\set notificationUnknownID 'c0b80000-0000-0000-0000-000000000082'
+\set recipientID 'c0b80000-0000-0000-0000-000000000042'
+\set senderID 'c0b80000-0000-0000-0000-000000000041'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'senderID', gen_random_bytes(32), 'sender@example.com', true, 'sender'),
+    (:'recipientID', gen_random_bytes(32), 'recipient@example.com', true, 'recipient');
+
+-- Community
+insert into community (
+    community_id,
+    banner_mobile_url,
+    banner_url,
+    description,
+    display_name,
+    logo_url,
+    name
+) values (
+    :'communityID',
+    'https://e/community-mobile.png',
+    'https://e/community.png',
+    'Community 1',
+    'Community One',
+    'https://e/community-logo.png',
+    'community-one'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Platform', 'platform');
+
+-- Custom notification
+insert into custom_notification (custom_notification_id, body, created_by, group_id, subject)
+values (:'customNotificationID', 'Group body', :'senderID', :'groupID', 'Group update');
+
+-- Notifications
+insert into notification (
+    notification_id,
+    channel,
+    community_id,
+    created_at,
+    custom_notification_id,
+    delivery_attempts,
+    delivery_status,
+    error,
+    kind,
+    processed_at,
+    user_id
+) values
+    (
+        :'notificationFailedID',
+        'email',
+        null,
+        '2024-03-01 10:00:00+00',
+        :'customNotificationID',
+        5,
+        'failed',
+        'smtp timeout',
+        'group-custom',
+        '2024-03-01 11:00:00+00',
+        :'recipientID'
+    ),
+    (
+        :'notificationUnknownID',
+        'slack',
+        :'communityID',
+        '2024-03-02 10:00:00+00',
+        null,
+        1,
+        'delivery-unknown',
+        'delivery outcome unknown after processing timeout',
+        'event-published',
+        '2024-03-02 11:00:00+00',
+        null
+    ),
+    (
+        gen_random_uuid(),
+        'email',
+        null,
+        '2024-03-03 10:00:00+00',
+        :'customNotificationID',
+        1,
+        'processed',
+        null,
+        'group-custom',
+        '2024-03-03 11:00:00+00',
+        :'recipientID'
+    ),
+    (
+        gen_random_uuid(),
+        'email',
+        null,
+        '2024-03-04 10:00:00+00',
+        :'customNotificationID',
+        1,
+        'bounced',
+        null,
+        'group-custom',
+        '2024-03-04 11:00:00+00',
+        :'recipientID'
+    ),
+    (
+        gen_random_uuid(),
+        'email',
+        null,
+        '2024-03-05 10:00:00+00',
+        null,
+        5,
+        'failed',
+        'smtp timeout',
+        'event-welcome',
+        '2024-03-05 11:00:00+00',
+        :'recipientID'
+    );
+
+-- Group channel notification
+insert into notification (
+    notification_id,
+    channel,
+    created_at,
+    delivery_attempts,
+    delivery_status,
+    error,
+    group_id,
+    kind,
+    processed_at
+) values (
+    :'notificationGroupID',
+    'discord',
+    '2024-02-29 10:00:00+00',
+    5,
+    'failed',
+    'discord webhook returned 404',
+    :'groupID',
+    'event-published',
+    '2024-02-29 11:00:00+00'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the failed notifications sent on behalf of the community
+select is(
+    list_community_failed_notifications(
+        :'communityID'::uuid,
+        '{"limit": 50, "offset": 0}'::jsonb
+    )::jsonb,
+    jsonb_build_object(
+        'notifications', jsonb_build_array(
+            jsonb_build_object(
+                'channel', 'slack',
+                'created_at', 1709373600,
+                'delivery_attempts', 1,
+                'delivery_status', 'delivery-unknown',
+                'error', 'delivery outcome unknown after processing timeout',
+                'kind', 'event-published',
+                'notification_id', :'notificationUnknownID'::uuid,
+                'processed_at', 1709377200,
+                'username', null
+            ),
+            jsonb_build_object(
+                'channel', 'email',
+                'created_at', 1709287200,
+                'delivery_attempts', 5,
+                'delivery_status', 'failed',
+                'error', 'smtp timeout',
+                'kind', 'group-custom',
+                'notification_id', :'notificationFailedID'::uuid,
+                'processed_at', 1709290800,
+                'username', 'recipient'
+            ),
+            jsonb_build_object(
+                'channel', 'discord',
+                'created_at', 1709200800,
+                'delivery_attempts', 5,
+                'delivery_status', 'failed',
+                'error', 'discord webhook returned 404',
+                'kind', 'event-published',
+                'notification_id', :'notificationGroupID'::uuid,
+                'processed_at', 1709204400,
+                'username', null
+            )
+        ),
+        'total', 3
+    ),
+    'Should return the failed notifications sent on behalf of the community'
+);
+
+-- Should return paginated failed notifications when limit and offset are provided
+select is(
+    list_community_failed_notifications(
+        :'communityID'::uuid,
+        '{"limit": 1, "offset": 1}'::jsonb
+    )::jsonb->'notifications'->0->>'notification_id',
+    :'notificationFailedID',
+    'Should return paginated failed notifications when limit and offset are provided'
+);
+
+-- Should return empty list for non-existing community
+select is(
+    list_community_failed_notifications(
+        :'missingCommunityID'::uuid,
+        '{"limit": 50, "offset": 0}'::jsonb
+    )::jsonb,
+    jsonb_build_object(
+        'notifications', '[]'::jsonb,
+        'total', 0
+    ),
+    'Should return empty list for non-existing community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
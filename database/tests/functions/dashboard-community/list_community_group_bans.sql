@@ -0,0 +1,119 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1310000-0000-0000-0000-000000000001'
+\set bannedUserID 'c1310000-0000-0000-0000-000000000002'
+\set communityID 'c1310000-0000-0000-0000-000000000003'
+\set deletedGroupID 'c1310000-0000-0000-0000-000000000004'
+\set emptyCommunityID 'c1310000-0000-0000-0000-000000000005'
+\set group1ID 'c1310000-0000-0000-0000-000000000006'
+\set group2ID 'c1310000-0000-0000-0000-000000000007'
+\set groupCategoryID 'c1310000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+), (
+    :'emptyCommunityID',
+    'empty-community',
+    'Empty Community',
+    'A community without bans',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, name)
+values
+    (:'actorID', 'hash1', 'actor@test.com', true, 'actor', null),
+    (:'bannedUserID', 'hash2', 'banned@test.com', true, 'banned', 'Banned User');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, deleted, active)
+values
+    (:'deletedGroupID', :'communityID', :'groupCategoryID', 'Deleted Group', 'deleted-group', true, false),
+    (:'group1ID', :'communityID', :'groupCategoryID', 'Group One', 'group-one', false, true),
+    (:'group2ID', :'communityID', :'groupCategoryID', 'Group Two', 'group-two', false, true);
+
+-- Group bans
+insert into group_ban (group_id, user_id, created_at, created_by, reason)
+values
+    (:'deletedGroupID', :'bannedUserID', '2024-01-03 00:00:00+00', :'actorID', 'Ignored'),
+    (:'group1ID', :'bannedUserID', '2024-01-01 00:00:00+00', :'actorID', 'Harassment'),
+    (:'group2ID', :'bannedUserID', '2024-01-02 00:00:00+00', null, 'Spam');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the bans of the community groups, most recent first
+select is(
+    list_community_group_bans(:'communityID'::uuid)::jsonb,
+    format('[
+        {
+            "created_at": 1704153600,
+            "group_id": "%s",
+            "group_name": "Group Two",
+            "name": "Banned User",
+            "reason": "Spam",
+            "user_id": "%s",
+            "username": "banned"
+        },
+        {
+            "banned_by_username": "actor",
+            "created_at": 1704067200,
+            "group_id": "%s",
+            "group_name": "Group One",
+            "name": "Banned User",
+            "reason": "Harassment",
+            "user_id": "%s",
+            "username": "banned"
+        }
+    ]', :'group2ID', :'bannedUserID', :'group1ID', :'bannedUserID')::jsonb,
+    'Should return the bans of the community groups, most recent first'
+);
+
+-- Should return an empty list when the community has no bans
+select is(
+    list_community_group_bans(:'emptyCommunityID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the community has no bans'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
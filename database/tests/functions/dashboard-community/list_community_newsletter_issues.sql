@@ -0,0 +1,170 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000041'
+\set draftIssueID 'c1360000-0000-0000-0000-000000000042'
+\set olderSentIssueID 'c1360000-0000-0000-0000-000000000043'
+\set otherCommunityID 'c1360000-0000-0000-0000-000000000044'
+\set recipientID 'c1360000-0000-0000-0000-000000000045'
+\set sentIssueID 'c1360000-0000-0000-0000-000000000046'
+\set userID 'c1360000-0000-0000-0000-000000000047'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, username) values
+    (:'recipientID', 'hash-1', 'recipient@example.com', 'recipient'),
+    (:'userID', 'hash-2', 'admin@example.com', 'admin');
+
+-- Newsletter issues
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    created_at,
+    created_by,
+    subject,
+    recipient_count,
+    sent_at
+) values
+    (
+        :'draftIssueID',
+        'Draft body',
+        :'communityID',
+        '2026-03-01 00:00:00+00',
+        :'userID',
+        'Draft issue',
+        null,
+        null
+    ),
+    (
+        :'olderSentIssueID',
+        'Older body',
+        :'communityID',
+        '2026-01-01 00:00:00+00',
+        null,
+        'Older issue',
+        1,
+        '2026-01-01 00:00:00+00'
+    ),
+    (
+        :'sentIssueID',
+        'Sent body',
+        :'communityID',
+        '2026-01-01 00:00:00+00',
+        :'userID',
+        'Sent issue',
+        6,
+        '2026-02-01 00:00:00+00'
+    );
+
+-- Notifications
+insert into notification (
+    channel,
+    community_id,
+    community_newsletter_issue_id,
+    delivery_status,
+    kind,
+    user_id
+) values
+    ('email', :'communityID', :'sentIssueID', 'bounced', 'community-newsletter', :'recipientID'),
+    ('email', :'communityID', :'sentIssueID', 'delivery-unknown', 'community-newsletter', :'recipientID'),
+    ('email', :'communityID', :'sentIssueID', 'failed', 'community-newsletter', :'recipientID'),
+    ('email', :'communityID', :'sentIssueID', 'pending', 'community-newsletter', :'recipientID'),
+    ('email', :'communityID', :'sentIssueID', 'processed', 'community-newsletter', :'recipientID'),
+    ('email', :'communityID', :'sentIssueID', 'processing', 'community-newsletter', :'recipientID'),
+    ('email', :'communityID', :'olderSentIssueID', 'processed', 'community-newsletter', :'recipientID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the drafts first and then the sent issues with their delivery stats
+select is(
+    list_community_newsletter_issues(:'communityID'::uuid)::jsonb,
+    jsonb_build_array(
+        jsonb_build_object(
+            'body', 'Draft body',
+            'bounced', 0,
+            'community_newsletter_issue_id', :'draftIssueID'::uuid,
+            'created_at', 1772323200,
+            'created_by_username', 'admin',
+            'failed', 0,
+            'queued', 0,
+            'sent', 0,
+            'subject', 'Draft issue',
+            'unknown', 0
+        ),
+        jsonb_build_object(
+            'body', 'Sent body',
+            'bounced', 1,
+            'community_newsletter_issue_id', :'sentIssueID'::uuid,
+            'created_at', 1767225600,
+            'created_by_username', 'admin',
+            'failed', 1,
+            'queued', 2,
+            'recipient_count', 6,
+            'sent', 1,
+            'sent_at', 1769904000,
+            'subject', 'Sent issue',
+            'unknown', 1
+        ),
+        jsonb_build_object(
+            'body', 'Older body',
+            'bounced', 0,
+            'community_newsletter_issue_id', :'olderSentIssueID'::uuid,
+            'created_at', 1767225600,
+            'failed', 0,
+            'queued', 0,
+            'recipient_count', 1,
+            'sent', 1,
+            'sent_at', 1767225600,
+            'subject', 'Older issue',
+            'unknown', 0
+        )
+    ),
+    'Should return the drafts first and then the sent issues with their delivery stats'
+);
+
+-- Should return an empty list for a community without issues
+select is(
+    list_community_newsletter_issues(:'otherCommunityID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for a community without issues'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
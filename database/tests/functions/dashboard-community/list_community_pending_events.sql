@@ -0,0 +1,185 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0ec0000-0000-0000-0000-000000000001'
+\set emptyCommunityID 'c0ec0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0ec0000-0000-0000-0000-000000000003'
+\set eventNewerID 'c0ec0000-0000-0000-0000-000000000004'
+\set eventOlderID 'c0ec0000-0000-0000-0000-000000000005'
+\set eventRejectedID 'c0ec0000-0000-0000-0000-000000000006'
+\set groupCategoryID 'c0ec0000-0000-0000-0000-000000000007'
+\set groupID 'c0ec0000-0000-0000-0000-000000000008'
+\set userID 'c0ec0000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values
+    (
+        :'communityID',
+        'pending-events-community',
+        'Pending Events Community',
+        'Community for pending events tests',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    ),
+    (
+        :'emptyCommunityID',
+        'empty-community',
+        'Empty Community',
+        'Community without pending events',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    );
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group');
+
+-- User
+insert into "user" (user_id, auth_hash, email, name, username)
+values (:'userID', 'organizer-hash', 'organizer@test.local', 'Organizer', 'organizer');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    approval_requested_at,
+    approval_requested_by,
+    approval_status,
+    published
+) values
+    -- Older approval request
+    (
+        :'eventOlderID',
+        :'groupID',
+        'Older Event',
+        'older-event',
+        'Older approval request',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        '2030-01-01 10:00:00+00',
+        :'userID',
+        'pending',
+        false
+    ),
+    -- Newer approval request
+    (
+        :'eventNewerID',
+        :'groupID',
+        'Newer Event',
+        'newer-event',
+        'Newer approval request',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        '2030-01-02 10:00:00+00',
+        null,
+        'pending',
+        false
+    ),
+    -- Event already rejected
+    (
+        :'eventRejectedID',
+        :'groupID',
+        'Rejected Event',
+        'rejected-event',
+        'Event already rejected',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        '2029-12-31 10:00:00+00',
+        :'userID',
+        'rejected',
+        false
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list the pending events, oldest requests first
+select results_eq(
+    format(
+        $$
+        select
+            item->'event'->>'event_id',
+            item->'event'->>'approval_status',
+            (item->>'requested_at')::bigint,
+            item->>'requested_by_display_name',
+            item->>'requested_by_username'
+        from json_array_elements(list_community_pending_events(%L::uuid)) item
+        $$,
+        :'communityID'
+    ),
+    format(
+        $$
+        values
+            (%L::text, 'pending'::text, 1893492000::bigint, 'Organizer'::text, 'organizer'::text),
+            (%L::text, 'pending'::text, 1893578400::bigint, null::text, null::text)
+        $$,
+        :'eventOlderID',
+        :'eventNewerID'
+    ),
+    'Should list the pending events, oldest requests first'
+);
+
+-- Should include the event group name in the summary
+select is(
+    list_community_pending_events(:'communityID'::uuid)->0->'event'->>'group_name',
+    'Test Group',
+    'Should include the event group name in the summary'
+);
+
+-- Should return an empty list when no events are pending
+select is(
+    list_community_pending_events(:'emptyCommunityID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when no events are pending'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
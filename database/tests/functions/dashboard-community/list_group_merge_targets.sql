@@ -0,0 +1,116 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1140000-0000-0000-0000-000000000001'
+\set deletedGroupID 'c1140000-0000-0000-0000-000000000002'
+\set group1ID 'c1140000-0000-0000-0000-000000000003'
+\set group2ID 'c1140000-0000-0000-0000-000000000004'
+\set group3ID 'c1140000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c1140000-0000-0000-0000-000000000006'
+\set inactiveGroupID 'c1140000-0000-0000-0000-000000000007'
+\set lonelyCommunityID 'c1140000-0000-0000-0000-000000000008'
+\set lonelyGroupCategoryID 'c1140000-0000-0000-0000-000000000009'
+\set lonelyGroupID 'c1140000-0000-0000-0000-000000000010'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values
+    (
+        :'communityID',
+        'merge-targets-community',
+        'Merge Targets Community',
+        'Community for listing merge targets tests',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    ),
+    (
+        :'lonelyCommunityID',
+        'lonely-community',
+        'Lonely Community',
+        'Community with a single group',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    );
+
+-- Group categories
+insert into group_category (group_category_id, community_id, name) values
+    (:'groupCategoryID', :'communityID', 'Technology'),
+    (:'lonelyGroupCategoryID', :'lonelyCommunityID', 'Technology');
+
+-- Groups
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    slug_pretty,
+    active,
+    deleted
+) values
+    (:'group1ID', :'communityID', :'groupCategoryID', 'Madrid Chapter', 'abc1234', null, true, false),
+    (:'group2ID', :'communityID', :'groupCategoryID', 'Madrid', 'def5678', 'madrid', true, false),
+    (:'group3ID', :'communityID', :'groupCategoryID', 'Barcelona', 'ghi9012', null, true, false),
+    (:'inactiveGroupID', :'communityID', :'groupCategoryID', 'Inactive', 'jkl3456', null, false, false),
+    (:'deletedGroupID', :'communityID', :'groupCategoryID', 'Deleted', 'mno7890', null, false, true),
+    (:'lonelyGroupID', :'lonelyCommunityID', :'lonelyGroupCategoryID', 'Lonely', 'pqr1234', null, true, false);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list the other active groups of the community ordered by name
+select is(
+    list_group_merge_targets(:'communityID'::uuid, :'group1ID'::uuid)::jsonb,
+    format('[
+        {
+            "active": true,
+            "group_id": "%s",
+            "name": "Barcelona",
+            "slug": "ghi9012"
+        },
+        {
+            "active": true,
+            "group_id": "%s",
+            "name": "Madrid",
+            "slug": "def5678",
+            "slug_pretty": "madrid"
+        }
+    ]', :'group3ID', :'group2ID')::jsonb,
+    'Should list the other active groups of the community ordered by name'
+);
+
+-- Should return an empty list when there are no other groups
+select is(
+    list_group_merge_targets(:'lonelyCommunityID'::uuid, :'lonelyGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when there are no other groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,98 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c11b0000-0000-0000-0000-000000000001'
+\set emptyCommunityID 'c11b0000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c11b0000-0000-0000-0000-000000000003'
+\set groupID 'c11b0000-0000-0000-0000-000000000004'
+\set topic1ID 'c11b0000-0000-0000-0000-000000000005'
+\set topic2ID 'c11b0000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cncf-seattle',
+    'CNCF Seattle',
+    'Community for topic tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Topic Group', 'topic-group');
+
+-- Topic
+insert into topic (topic_id, community_id, name)
+values
+    (:'topic1ID', :'communityID', 'Security'),
+    (:'topic2ID', :'communityID', 'Kubernetes');
+
+-- Group topic
+insert into group_topic (group_id, topic_id)
+values (:'groupID', :'topic1ID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the community topics ordered by name with usage counts
+select is(
+    list_topics(:'communityID'::uuid)::jsonb,
+    format('[
+        {
+            "events_count": 0,
+            "groups_count": 0,
+            "name": "Kubernetes",
+            "normalized_name": "kubernetes",
+            "topic_id": "%s"
+        },
+        {
+            "events_count": 0,
+            "groups_count": 1,
+            "name": "Security",
+            "normalized_name": "security",
+            "topic_id": "%s"
+        }
+    ]', :'topic2ID', :'topic1ID')::jsonb,
+    'Should return the community topics ordered by name with usage counts'
+);
+
+-- Should return an empty list when the community has no topics
+select is(
+    list_topics(:'emptyCommunityID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the community has no topics'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
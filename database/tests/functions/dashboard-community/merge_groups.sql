@@ -0,0 +1,319 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(17);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set bannedUserID 'c1130000-0000-0000-0000-000000000017'
+\set communityAdminID 'c1130000-0000-0000-0000-000000000001'
+\set communityID 'c1130000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c1130000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c1130000-0000-0000-0000-000000000004'
+\set inactiveGroupID 'c1130000-0000-0000-0000-000000000005'
+\set memberID 'c1130000-0000-0000-0000-000000000006'
+\set organizerID 'c1130000-0000-0000-0000-000000000007'
+\set requesterID 'c1130000-0000-0000-0000-000000000019'
+\set sharedBannedUserID 'c1130000-0000-0000-0000-000000000018'
+\set sharedMemberID 'c1130000-0000-0000-0000-000000000008'
+\set sourceEventID 'c1130000-0000-0000-0000-000000000009'
+\set sourceGroupID 'c1130000-0000-0000-0000-000000000010'
+\set sourceSponsorID 'c1130000-0000-0000-0000-000000000011'
+\set sourceTierID 'c1130000-0000-0000-0000-000000000012'
+\set targetEventID 'c1130000-0000-0000-0000-000000000013'
+\set targetGroupID 'c1130000-0000-0000-0000-000000000014'
+\set targetTierID 'c1130000-0000-0000-0000-000000000015'
+\set topicID 'c1130000-0000-0000-0000-000000000016'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'merge-groups-community',
+    'Merge Groups Community',
+    'Community for merging groups tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'communityAdminID', gen_random_bytes(32), 'admin@example.com', true, 'admin'),
+    (:'memberID', gen_random_bytes(32), 'alice@example.com', true, 'alice'),
+    (:'organizerID', gen_random_bytes(32), 'bob@example.com', true, 'bob'),
+    (:'sharedMemberID', gen_random_bytes(32), 'carol@example.com', true, 'carol'),
+    (:'bannedUserID', gen_random_bytes(32), 'dave@example.com', true, 'dave'),
+    (:'sharedBannedUserID', gen_random_bytes(32), 'erin@example.com', true, 'erin'),
+    (:'requesterID', gen_random_bytes(32), 'frank@example.com', true, 'frank');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, active) values
+    (:'inactiveGroupID', :'communityID', :'groupCategoryID', 'Inactive Group', 'inactive-group', false),
+    (:'sourceGroupID', :'communityID', :'groupCategoryID', 'Madrid Chapter', 'madrid-chapter', true),
+    (:'targetGroupID', :'communityID', :'groupCategoryID', 'Madrid', 'madrid', true);
+
+-- Group members
+insert into group_member (group_id, user_id, join_answers) values
+    (:'sourceGroupID', :'memberID', '{"q1": "Madrid"}'),
+    (:'sourceGroupID', :'sharedBannedUserID', null),
+    (:'sourceGroupID', :'sharedMemberID', null),
+    (:'targetGroupID', :'sharedMemberID', null);
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted) values
+    (:'sourceGroupID', :'organizerID', 'organizer', true),
+    (:'sourceGroupID', :'sharedBannedUserID', 'organizer', true);
+
+-- Group join requests
+insert into group_join_request (group_id, user_id, join_answers) values
+    (:'sourceGroupID', :'requesterID', '{"q1": "Madrid"}'),
+    (:'sourceGroupID', :'sharedBannedUserID', null),
+    (:'sourceGroupID', :'sharedMemberID', null);
+
+-- Group bans
+insert into group_ban (group_id, user_id, reason) values
+    (:'sourceGroupID', :'bannedUserID', 'Spam'),
+    (:'sourceGroupID', :'sharedBannedUserID', 'Spam'),
+    (:'targetGroupID', :'sharedBannedUserID', 'Abuse');
+
+-- Sponsor tiers
+insert into group_sponsor_tier (group_sponsor_tier_id, group_id, name) values
+    (:'sourceTierID', :'sourceGroupID', 'Gold'),
+    (:'targetTierID', :'targetGroupID', 'gold');
+
+-- Sponsors
+insert into group_sponsor (group_sponsor_id, group_id, group_sponsor_tier_id, logo_url, name)
+values (:'sourceSponsorID', :'sourceGroupID', :'sourceTierID', 'https://example.com/logo.png', 'Acme');
+
+-- Topics
+insert into topic (topic_id, community_id, name)
+values (:'topicID', :'communityID', 'Kubernetes');
+
+insert into group_topic (group_id, topic_id)
+values (:'sourceGroupID', :'topicID');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone
+) values
+    (:'sourceEventID', :'sourceGroupID', 'Source Meetup', 'meetup1', 'Source event', :'eventCategoryID', 'in-person', 'UTC'),
+    (:'targetEventID', :'targetGroupID', 'Target Meetup', 'meetup1', 'Target event', :'eventCategoryID', 'in-person', 'UTC');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should merge the source group into the target group
+select lives_ok(
+    format(
+        $$ select merge_groups(%L::uuid, %L::uuid, %L::uuid, %L::uuid) $$,
+        :'communityAdminID', :'communityID', :'sourceGroupID', :'targetGroupID'
+    ),
+    'Should merge the source group into the target group'
+);
+select results_eq(
+    format(
+        $$ select user_id from group_member where group_id = %L::uuid order by user_id $$,
+        :'targetGroupID'
+    ),
+    format($$ values (%L::uuid), (%L::uuid) $$, :'memberID', :'sharedMemberID'),
+    'Members should be moved to the target group'
+);
+select results_eq(
+    format(
+        $$ select join_answers from group_member where group_id = %L::uuid and user_id = %L::uuid $$,
+        :'targetGroupID', :'memberID'
+    ),
+    $$ values ('{"q1": "Madrid"}'::jsonb) $$,
+    'Members join answers should be moved to the target group'
+);
+select results_eq(
+    format(
+        $$ select user_id, role, accepted from group_team where group_id = %L::uuid $$,
+        :'targetGroupID'
+    ),
+    format($$ values (%L::uuid, 'organizer'::text, true) $$, :'organizerID'),
+    'Team should be moved to the target group'
+);
+select results_eq(
+    format(
+        $$ select user_id, reason from group_ban where group_id = %L::uuid order by user_id $$,
+        :'targetGroupID'
+    ),
+    format(
+        $$ values (%L::uuid, 'Spam'::text), (%L::uuid, 'Abuse'::text) $$,
+        :'bannedUserID', :'sharedBannedUserID'
+    ),
+    'Bans should be carried over to the target group'
+);
+select is_empty(
+    format(
+        $$
+            select 1 from group_member where group_id = %L::uuid and user_id = %L::uuid
+            union all
+            select 1 from group_team where group_id = %L::uuid and user_id = %L::uuid
+        $$,
+        :'targetGroupID', :'sharedBannedUserID', :'targetGroupID', :'sharedBannedUserID'
+    ),
+    'Users banned from the target group should not be moved to it'
+);
+select results_eq(
+    format(
+        $$ select user_id, status, join_answers from group_join_request where group_id = %L::uuid $$,
+        :'targetGroupID'
+    ),
+    format($$ values (%L::uuid, 'pending'::text, '{"q1": "Madrid"}'::jsonb) $$, :'requesterID'),
+    'Pending join requests should be moved to the target group, skipping members and banned users'
+);
+select is_empty(
+    format(
+        $$ select 1 from group_join_request where group_id = %L::uuid and status = 'pending' $$,
+        :'sourceGroupID'
+    ),
+    'Pending join requests should be removed from the source group'
+);
+select results_eq(
+    format(
+        $$
+            select group_id, slug <> 'meetup1'
+            from event
+            where event_id = %L::uuid
+        $$,
+        :'sourceEventID'
+    ),
+    format($$ values (%L::uuid, true) $$, :'targetGroupID'),
+    'Events should be moved to the target group with unique slugs'
+);
+select results_eq(
+    format(
+        $$ select group_id, group_sponsor_tier_id from group_sponsor where group_sponsor_id = %L::uuid $$,
+        :'sourceSponsorID'
+    ),
+    format($$ values (%L::uuid, %L::uuid) $$, :'targetGroupID', :'targetTierID'),
+    'Sponsors should be moved to the matching target tier'
+);
+select results_eq(
+    format(
+        $$ select group_id from group_topic where topic_id = %L::uuid $$,
+        :'topicID'
+    ),
+    format($$ values (%L::uuid) $$, :'targetGroupID'),
+    'Topics should be moved to the target group'
+);
+select is_empty(
+    format(
+        $$ select 1 from group_sponsor_tier where group_id = %L::uuid $$,
+        :'sourceGroupID'
+    ),
+    'Duplicated source tiers should be removed'
+);
+select results_eq(
+    format(
+        $$
+            select active, merged_at is not null, merged_into_group_id
+            from "group"
+            where group_id = %L::uuid
+        $$,
+        :'sourceGroupID'
+    ),
+    format($$ values (false, true, %L::uuid) $$, :'targetGroupID'),
+    'Source group should be archived and redirected to the target group'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id,
+            details
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'group_merged',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            'group',
+            %L::uuid,
+            jsonb_build_object('source_group_name', 'Madrid Chapter')
+        )
+        $$,
+        :'communityAdminID', :'communityID', :'targetGroupID', :'sourceGroupID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should raise error when the source group was already merged
+select throws_ok(
+    format(
+        $$ select merge_groups(%L::uuid, %L::uuid, %L::uuid, %L::uuid) $$,
+        :'communityAdminID', :'communityID', :'sourceGroupID', :'targetGroupID'
+    ),
+    'group not found',
+    'Should raise error when the source group was already merged'
+);
+
+-- Should raise error when the target group is inactive
+select throws_ok(
+    format(
+        $$ select merge_groups(%L::uuid, %L::uuid, %L::uuid, %L::uuid) $$,
+        :'communityAdminID', :'communityID', :'targetGroupID', :'inactiveGroupID'
+    ),
+    'target group not found or inactive',
+    'Should raise error when the target group is inactive'
+);
+
+-- Should raise error when merging a group into itself
+select throws_ok(
+    format(
+        $$ select merge_groups(%L::uuid, %L::uuid, %L::uuid, %L::uuid) $$,
+        :'communityAdminID', :'communityID', :'targetGroupID', :'targetGroupID'
+    ),
+    'a group cannot be merged into itself',
+    'Should raise error when merging a group into itself'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,181 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0eb0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0eb0000-0000-0000-0000-000000000002'
+\set eventDraftID 'c0eb0000-0000-0000-0000-000000000003'
+\set eventPendingID 'c0eb0000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0eb0000-0000-0000-0000-000000000005'
+\set groupID 'c0eb0000-0000-0000-0000-000000000006'
+\set userID 'c0eb0000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    event_approval_required
+) values (
+    :'communityID',
+    'reject-event-community',
+    'Reject Event Community',
+    'Community for reject event tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    true
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group');
+
+-- User
+insert into "user" (user_id, auth_hash, email, username)
+values (:'userID', 'admin-hash', 'admin@test.local', 'admin');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    approval_status,
+    published
+) values
+    -- Draft event waiting for approval
+    (
+        :'eventPendingID',
+        :'groupID',
+        'Pending Event',
+        'pending-event',
+        'Event waiting for approval',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        'pending',
+        false
+    ),
+    -- Draft event not sent for approval
+    (
+        :'eventDraftID',
+        :'groupID',
+        'Draft Event',
+        'draft-event',
+        'Event not sent for approval',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        null,
+        false
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject the pending event and return its group id
+select is(
+    reject_event(:'userID'::uuid, :'communityID'::uuid, :'eventPendingID'::uuid, 'Missing agenda'),
+    :'groupID'::uuid,
+    'Should reject the pending event and return its group id'
+);
+
+-- Should store the rejection and keep the event unpublished
+select results_eq(
+    format(
+        $$
+        select approval_status, approval_rejection_reason, published
+        from event
+        where event_id = %L::uuid
+        $$,
+        :'eventPendingID'
+    ),
+    $$ values ('rejected'::text, 'Missing agenda'::text, false) $$,
+    'Should store the rejection and keep the event unpublished'
+);
+
+-- Should track the rejection with its reason
+select results_eq(
+    $$
+        select action, actor_user_id, group_id, event_id, details
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'event_rejected',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            '{"reason": "Missing agenda"}'::jsonb
+        )
+        $$,
+        :'userID',
+        :'groupID',
+        :'eventPendingID'
+    ),
+    'Should track the rejection with its reason'
+);
+
+-- Should throw error when the event was already reviewed
+select throws_ok(
+    format(
+        'select reject_event(%L::uuid, %L::uuid, %L::uuid, null)',
+        :'userID',
+        :'communityID',
+        :'eventPendingID'
+    ),
+    'event not found or not pending approval',
+    'Should throw error when the event was already reviewed'
+);
+
+-- Should throw error when the event is not pending approval
+select throws_ok(
+    format(
+        'select reject_event(%L::uuid, %L::uuid, %L::uuid, null)',
+        :'userID',
+        :'communityID',
+        :'eventDraftID'
+    ),
+    'event not found or not pending approval',
+    'Should throw error when the event is not pending approval'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,196 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0b90000-0000-0000-0000-000000000041'
+\set communityID 'c0b90000-0000-0000-0000-000000000001'
+\set notificationFailedID 'c0b90000-0000-0000-0000-000000000081'
+\set notificationOtherID 'c0b90000-0000-0000-0000-000000000082'
+\set notificationProcessedID 'c0b90000-0000-0000-0000-000000000083'
+\set otherCommunityID 'c0b90000-0000-0000-0000-000000000002'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Actor
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'actorID', gen_random_bytes(32), 'actor@example.com', true, 'actor');
+
+-- Communities
+insert into community (
+    community_id,
+    banner_mobile_url,
+    banner_url,
+    description,
+    display_name,
+    logo_url,
+    name
+) values
+    (
+        :'communityID',
+        'https://e/community-mobile.png',
+        'https://e/community.png',
+        'Community 1',
+        'Community One',
+        'https://e/community-logo.png',
+        'community-one'
+    ),
+    (
+        :'otherCommunityID',
+        'https://e/community-mobile.png',
+        'https://e/community.png',
+        'Community 2',
+        'Community Two',
+        'https://e/community-logo.png',
+        'community-two'
+    );
+
+-- Notifications
+insert into notification (
+    notification_id,
+    channel,
+    community_id,
+    delivery_attempts,
+    delivery_claimed_at,
+    delivery_status,
+    error,
+    kind,
+    processed_at
+) values
+    (
+        :'notificationFailedID',
+        'slack',
+        :'communityID',
+        5,
+        current_timestamp - interval '10 minutes',
+        'failed',
+        'webhook timeout',
+        'event-published',
+        current_timestamp - interval '10 minutes'
+    ),
+    (
+        :'notificationOtherID',
+        'slack',
+        :'otherCommunityID',
+        5,
+        current_timestamp - interval '10 minutes',
+        'failed',
+        'webhook timeout',
+        'event-published',
+        current_timestamp - interval '10 minutes'
+    ),
+    (
+        :'notificationProcessedID',
+        'slack',
+        :'communityID',
+        1,
+        current_timestamp - interval '10 minutes',
+        'processed',
+        null,
+        'event-published',
+        current_timestamp - interval '10 minutes'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should requeue failed notifications sent on behalf of the community
+select lives_ok(
+    format(
+        $$select requeue_community_notification(%L, %L, %L)$$,
+        :'actorID', :'communityID', :'notificationFailedID'
+    ),
+    'Should requeue failed notifications sent on behalf of the community'
+);
+
+-- Should reset the notification for immediate delivery
+select results_eq(
+    format(
+        $$
+        select
+            delivery_attempts,
+            delivery_claimed_at,
+            delivery_status,
+            error,
+            next_delivery_attempt_at,
+            processed_at
+        from notification
+        where notification_id = %L
+        $$,
+        :'notificationFailedID'
+    ),
+    $$ values (0, null::timestamptz, 'pending', null::text, null::timestamptz, null::timestamptz) $$,
+    'Should reset the notification for immediate delivery'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    format(
+        $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            details,
+            resource_type,
+            resource_id
+        from audit_log
+        where resource_id = %L
+        $$,
+        :'notificationFailedID'
+    ),
+    format(
+        $$ values (
+            'notification_requeued',
+            %L::uuid,
+            'actor',
+            %L::uuid,
+            jsonb_build_object(
+                'name', 'event-published',
+                'previous_delivery_status', 'failed',
+                'previous_error', 'webhook timeout'
+            ),
+            'notification',
+            %L::uuid
+        ) $$,
+        :'actorID', :'communityID', :'notificationFailedID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should reject notifications sent on behalf of other communities
+select throws_ok(
+    format(
+        $$select requeue_community_notification(%L, %L, %L)$$,
+        :'actorID', :'communityID', :'notificationOtherID'
+    ),
+    'notification not found',
+    'Should reject notifications sent on behalf of other communities'
+);
+
+-- Should reject notifications that were delivered
+select throws_ok(
+    format(
+        $$select requeue_community_notification(%L, %L, %L)$$,
+        :'actorID', :'communityID', :'notificationProcessedID'
+    ),
+    'notification not found',
+    'Should reject notifications that were delivered'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
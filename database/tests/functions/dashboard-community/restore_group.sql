@@ -0,0 +1,137 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1170000-0000-0000-0000-000000000001'
+\set groupArchivedID 'c1170000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c1170000-0000-0000-0000-000000000003'
+\set groupID 'c1170000-0000-0000-0000-000000000004'
+\set unknownCommunityID 'c1170000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'restore-group-community',
+    'Restore Group Community',
+    'Community for restore group tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Groups
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    archived_at
+) values
+    (:'groupArchivedID', :'communityID', :'groupCategoryID', 'Archived Group', 'archived-group', '2024-01-01 00:00:00+00'),
+    (:'groupID', :'communityID', :'groupCategoryID', 'Active Group', 'active-group', null);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should execute restore_group successfully
+select lives_ok(
+    format(
+        'select restore_group(null::uuid, %L::uuid, %L::uuid)',
+        :'communityID',
+        :'groupArchivedID'
+    ),
+    'Should execute restore_group successfully'
+);
+
+-- Should clear the archival timestamp
+select is(
+    (select archived_at from "group" where group_id = :'groupArchivedID'::uuid),
+    null,
+    'Should clear the archival timestamp'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'group_restored',
+            null::uuid,
+            null::text,
+            %L::uuid,
+            %L::uuid,
+            'group',
+            %L::uuid
+        )
+        $$,
+        :'communityID',
+        :'groupArchivedID',
+        :'groupArchivedID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should throw error for a group that is not archived
+select throws_ok(
+    format(
+        $$select restore_group(null::uuid, %L::uuid, %L::uuid)$$,
+        :'communityID',
+        :'groupID'
+    ),
+    'group not found or not archived',
+    'Should throw error when the group is not archived'
+);
+
+-- Should throw error for wrong community_id
+select throws_ok(
+    format(
+        $$select restore_group(null::uuid, %L::uuid, %L::uuid)$$,
+        :'unknownCommunityID',
+        :'groupArchivedID'
+    ),
+    'group not found or not archived',
+    'Should throw error when community_id does not match'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,187 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(7);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000051'
+\set emptyCommunityID 'c1360000-0000-0000-0000-000000000052'
+\set emptyIssueID 'c1360000-0000-0000-0000-000000000053'
+\set issueID 'c1360000-0000-0000-0000-000000000054'
+\set otherCommunityID 'c1360000-0000-0000-0000-000000000055'
+\set subscriber1ID 'c1360000-0000-0000-0000-000000000056'
+\set subscriber2ID 'c1360000-0000-0000-0000-000000000057'
+\set unverifiedSubscriberID 'c1360000-0000-0000-0000-000000000058'
+\set userID 'c1360000-0000-0000-0000-000000000059'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values
+    (
+        :'communityID',
+        'newsletter-community',
+        'Newsletter Community',
+        'Community used for newsletter tests',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    ),
+    (
+        :'emptyCommunityID',
+        'empty-newsletter-community',
+        'Empty Newsletter Community',
+        'Community without newsletter subscribers',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    );
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'subscriber1ID', 'hash-1', 'subscriber1@example.com', true, 'subscriber1'),
+    (:'subscriber2ID', 'hash-2', 'subscriber2@example.com', true, 'subscriber2'),
+    (:'unverifiedSubscriberID', 'hash-3', 'unverified@example.com', false, 'unverified'),
+    (:'userID', 'hash-4', 'admin@example.com', true, 'admin');
+
+-- Subscribers
+insert into community_newsletter_subscriber (community_id, user_id) values
+    (:'communityID', :'subscriber1ID'),
+    (:'communityID', :'subscriber2ID'),
+    (:'communityID', :'unverifiedSubscriberID');
+
+-- Newsletter issues
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    subject
+) values
+    (:'emptyIssueID', 'Empty body', :'emptyCommunityID', 'Empty issue'),
+    (:'issueID', 'Issue body', :'communityID', 'Issue');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject sending an issue from another community
+select throws_ok(
+    format(
+        $$ select send_community_newsletter_issue(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'userID',
+        :'otherCommunityID',
+        :'issueID'
+    ),
+    'newsletter issue not found',
+    'Should reject sending an issue from another community'
+);
+
+-- Should reject sending an issue without subscribers
+select throws_ok(
+    format(
+        $$ select send_community_newsletter_issue(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'userID',
+        :'emptyCommunityID',
+        :'emptyIssueID'
+    ),
+    'the newsletter has no subscribers yet',
+    'Should reject sending an issue without subscribers'
+);
+
+-- Should send the issue to the subscribers with a verified email
+select is(
+    send_community_newsletter_issue(:'userID'::uuid, :'communityID'::uuid, :'issueID'::uuid),
+    2,
+    'Should return the number of recipients'
+);
+select results_eq(
+    format(
+        $$
+        select user_id, enqueued_at
+        from community_newsletter_issue_recipient
+        where community_newsletter_issue_id = %L::uuid
+        order by user_id
+        $$,
+        :'issueID'
+    ),
+    format(
+        $$
+        values
+            (%L::uuid, null::timestamptz),
+            (%L::uuid, null::timestamptz)
+        $$,
+        :'subscriber1ID',
+        :'subscriber2ID'
+    ),
+    'Should snapshot the subscribers as pending recipients'
+);
+select results_eq(
+    format(
+        $$
+        select recipient_count, sent_at is not null, sent_by
+        from community_newsletter_issue
+        where community_newsletter_issue_id = %L::uuid
+        $$,
+        :'issueID'
+    ),
+    format($$ values (2, true, %L::uuid) $$, :'userID'),
+    'Should mark the issue as sent'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select action, actor_user_id, community_id, resource_type, resource_id, details
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'community_newsletter_issue_sent'::text,
+            %L::uuid,
+            %L::uuid,
+            'community_newsletter_issue'::text,
+            %L::uuid,
+            '{"recipient_count": 2}'::jsonb
+        )
+        $$,
+        :'userID',
+        :'communityID',
+        :'issueID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should reject sending an issue twice
+select throws_ok(
+    format(
+        $$ select send_community_newsletter_issue(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'userID',
+        :'communityID',
+        :'issueID'
+    ),
+    'newsletter issue already sent',
+    'Should reject sending an issue twice'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(8);
+select plan(11);
 
 -- ============================================================================
 -- VARIABLES
@@ -101,9 +101,13 @@ select is(
         "community_site_layout_id": "default",
         "description": "Updated description for Seattle cloud native community",
         "display_name": "Cloud Native Seattle Updated",
+        "dormant_group_months": 6,
+        "dormant_groups_report_enabled": false,
+        "event_approval_required": false,
         "group_team_management_restricted": false,
         "logo_url": "https://updated.com/logo.png",
-        "name": "cloud-native-seattle"
+        "name": "cloud-native-seattle",
+        "passkey_required": false
     }'::jsonb,
     'Should persist required fields and clear omitted optional fields'
 );
@@ -149,9 +153,17 @@ select lives_ok(
             "logo_url": "https://new.com/logo.png",
             "ad_banner_url": "https://new.com/banner.png",
             "ad_banner_link_url": "https://new.com/link",
+            "allowed_email_domains": ["example.com", "example.org"],
             "banner_mobile_url": "https://new.com/community-banner_mobile.png",
             "banner_url": "https://new.com/community-banner.png",
             "bluesky_url": "https://bsky.app/profile/new",
+            "dormant_group_months": 12,
+            "dormant_groups_report_enabled": true,
+            "email_footer_text": "Cloud Native Seattle, WA",
+            "email_from_name": "Cloud Native Seattle",
+            "email_logo_url": "https://new.com/email-logo.png",
+            "email_primary_color": "#0A3D62",
+            "event_approval_required": true,
             "extra_links": {"blog": "https://blog.new.com", "forum": "https://forum.new.com"},
             "facebook_url": "https://facebook.com/new",
             "flickr_url": "https://flickr.com/new",
@@ -161,8 +173,15 @@ select lives_ok(
             "linkedin_url": "https://linkedin.com/new",
             "new_group_details": "New groups welcome!",
             "og_image_url": "https://new.com/og-image.png",
+            "passkey_required": true,
             "photos_urls": ["https://new.com/p1.jpg", "https://new.com/p2.jpg", "https://new.com/p3.jpg"],
+            "required_oidc_provider": "linuxfoundation",
             "slack_url": "https://new.slack.com",
+            "slack_webhook_url": "https://hooks.slack.com/services/T0/B0/secret",
+            "theme_favicon_url": "https://new.com/favicon.ico",
+            "theme_font_family": "serif",
+            "theme_header_logo_url": "https://new.com/header-logo.png",
+            "theme_primary_color": "#1B4F72",
             "twitter_url": "https://twitter.com/new",
             "website_url": "https://new.com",
             "wechat_url": "https://wechat.com/new",
@@ -180,12 +199,16 @@ select is(
         "active": true,
         "ad_banner_link_url": "https://new.com/link",
         "ad_banner_url": "https://new.com/banner.png",
+        "allowed_email_domains": ["example.com", "example.org"],
         "banner_mobile_url": "https://new.com/community-banner_mobile.png",
         "banner_url": "https://new.com/community-banner.png",
         "bluesky_url": "https://bsky.app/profile/new",
         "community_site_layout_id": "default",
         "description": "Comprehensive cloud native community in Seattle",
         "display_name": "Cloud Native Seattle Complete",
+        "dormant_group_months": 12,
+        "dormant_groups_report_enabled": true,
+        "event_approval_required": true,
         "extra_links": {"blog": "https://blog.new.com", "forum": "https://forum.new.com"},
         "facebook_url": "https://facebook.com/new",
         "flickr_url": "https://flickr.com/new",
@@ -197,7 +220,9 @@ select is(
         "name": "cloud-native-seattle",
         "new_group_details": "New groups welcome!",
         "og_image_url": "https://new.com/og-image.png",
+        "passkey_required": true,
         "photos_urls": ["https://new.com/p1.jpg", "https://new.com/p2.jpg", "https://new.com/p3.jpg"],
+        "required_oidc_provider": "linuxfoundation",
         "slack_url": "https://new.slack.com",
         "twitter_url": "https://twitter.com/new",
         "website_url": "https://new.com",
@@ -207,6 +232,52 @@ select is(
     'Should update all fields correctly including optional ones'
 );
 
+select is(
+    (select slack_webhook_url from community where community_id = :'communityID'::uuid),
+    'https://hooks.slack.com/services/T0/B0/secret',
+    'Should update the Slack webhook URL'
+);
+
+select is(
+    (
+        select jsonb_build_object(
+            'email_footer_text', email_footer_text,
+            'email_from_name', email_from_name,
+            'email_logo_url', email_logo_url,
+            'email_primary_color', email_primary_color
+        )
+        from community
+        where community_id = :'communityID'::uuid
+    ),
+    '{
+        "email_footer_text": "Cloud Native Seattle, WA",
+        "email_from_name": "Cloud Native Seattle",
+        "email_logo_url": "https://new.com/email-logo.png",
+        "email_primary_color": "#0A3D62"
+    }'::jsonb,
+    'Should update the email branding settings'
+);
+
+select is(
+    (
+        select jsonb_build_object(
+            'theme_favicon_url', theme_favicon_url,
+            'theme_font_family', theme_font_family,
+            'theme_header_logo_url', theme_header_logo_url,
+            'theme_primary_color', theme_primary_color
+        )
+        from community
+        where community_id = :'communityID'::uuid
+    ),
+    '{
+        "theme_favicon_url": "https://new.com/favicon.ico",
+        "theme_font_family": "serif",
+        "theme_header_logo_url": "https://new.com/header-logo.png",
+        "theme_primary_color": "#1B4F72"
+    }'::jsonb,
+    'Should update the theme settings'
+);
+
 -- Should convert empty strings to null for nullable fields
 select lives_ok(
     format(
@@ -217,6 +288,10 @@ select lives_ok(
             "ad_banner_url": "",
             "ad_banner_link_url": "",
             "bluesky_url": "",
+            "email_footer_text": "",
+            "email_from_name": "",
+            "email_logo_url": "",
+            "email_primary_color": "",
             "facebook_url": "",
             "flickr_url": "",
             "github_url": "",
@@ -224,7 +299,13 @@ select lives_ok(
             "linkedin_url": "",
             "new_group_details": "",
             "og_image_url": "",
+            "required_oidc_provider": "",
             "slack_url": "",
+            "slack_webhook_url": "",
+            "theme_favicon_url": "",
+            "theme_font_family": "",
+            "theme_header_logo_url": "",
+            "theme_primary_color": "",
             "twitter_url": "",
             "website_url": "",
             "wechat_url": "",
@@ -244,7 +325,16 @@ select is(
     '{
         "ad_banner_url": null,
         "ad_banner_link_url": null,
+        "allowed_email_domains": null,
         "bluesky_url": null,
+        "dormant_group_months": 12,
+        "dormant_groups_report_enabled": true,
+        "dormant_groups_report_sent_at": null,
+        "email_footer_text": null,
+        "email_from_name": null,
+        "email_logo_url": null,
+        "email_primary_color": null,
+        "event_approval_required": true,
         "facebook_url": null,
         "flickr_url": null,
         "github_url": null,
@@ -253,10 +343,18 @@ select is(
         "linkedin_url": null,
         "new_group_details": null,
         "og_image_url": null,
+        "passkey_required": true,
+        "required_oidc_provider": null,
         "slack_url": null,
+        "slack_webhook_url": null,
+        "theme_favicon_url": null,
+        "theme_font_family": null,
+        "theme_header_logo_url": null,
+        "theme_primary_color": null,
         "twitter_url": null,
         "website_url": null,
         "wechat_url": null,
+        "weekly_digest_sent_at": null,
         "youtube_url": null
     }'::jsonb,
     'Should persist nulls for empty-string nullable fields'
@@ -0,0 +1,162 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1270000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c1270000-0000-0000-0000-000000000002'
+\set eventOldID 'c1270000-0000-0000-0000-000000000003'
+\set eventRecentID 'c1270000-0000-0000-0000-000000000004'
+\set eventUnpublishedID 'c1270000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c1270000-0000-0000-0000-000000000006'
+\set groupDeactivatedID 'c1270000-0000-0000-0000-000000000007'
+\set groupNewID 'c1270000-0000-0000-0000-000000000008'
+\set groupOldEventID 'c1270000-0000-0000-0000-000000000009'
+\set groupRecentEventID 'c1270000-0000-0000-0000-000000000010'
+\set groupUnpublishedEventID 'c1270000-0000-0000-0000-000000000011'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    dormant_group_months
+) values (
+    :'communityID',
+    'dormant-community',
+    'Dormant Community',
+    'Dormant groups tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png',
+    6
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Groups
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    active,
+    created_at,
+    dormant_since
+) values
+    (:'groupDeactivatedID', :'communityID', :'groupCategoryID', 'Deactivated Group', 'deactivated-group',
+        false, '2020-01-01 00:00:00+00', '2020-07-01 00:00:00+00'),
+    (:'groupNewID', :'communityID', :'groupCategoryID', 'New Group', 'new-group',
+        true, current_timestamp - interval '1 month', null),
+    (:'groupOldEventID', :'communityID', :'groupCategoryID', 'Old Event Group', 'old-event-group',
+        true, '2020-01-01 00:00:00+00', null),
+    (:'groupRecentEventID', :'communityID', :'groupCategoryID', 'Recent Event Group', 'recent-event-group',
+        true, '2020-01-01 00:00:00+00', '2021-01-01 00:00:00+00'),
+    (:'groupUnpublishedEventID', :'communityID', :'groupCategoryID', 'Unpublished Event Group', 'unpublished-event-group',
+        true, '2020-01-01 00:00:00+00', null);
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    published
+) values
+    (:'eventOldID', :'groupOldEventID', 'Old Event', 'old-event', 'Old', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp - interval '1 year', true),
+    (:'eventRecentID', :'groupRecentEventID', 'Recent Event', 'recent-event', 'Recent', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '1 week', true),
+    (:'eventUnpublishedID', :'groupUnpublishedEventID', 'Unpublished Event', 'unpublished-event', 'Unpublished', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp - interval '1 week', false);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the number of groups updated
+select is(
+    update_dormant_groups(),
+    4,
+    'Should return the number of groups updated'
+);
+
+-- Should flag groups as dormant since their last event plus the configured months
+select is(
+    (select dormant_since from "group" where group_id = :'groupOldEventID'),
+    (select starts_at + interval '6 months' from event where event_id = :'eventOldID'),
+    'Should flag groups as dormant since their last event plus the configured months'
+);
+
+-- Should ignore unpublished events when looking for the last event
+select is(
+    (select dormant_since from "group" where group_id = :'groupUnpublishedEventID'),
+    '2020-07-01 00:00:00+00'::timestamptz,
+    'Should ignore unpublished events when looking for the last event'
+);
+
+-- Should not flag recent groups or groups with upcoming events
+select results_eq(
+    $$
+        select group_id
+        from "group"
+        where dormant_since is null
+        order by group_id
+    $$,
+    format(
+        $$ values (%L::uuid), (%L::uuid), (%L::uuid) $$,
+        :'groupDeactivatedID',
+        :'groupNewID',
+        :'groupRecentEventID'
+    ),
+    'Should not flag recent groups or groups with upcoming events'
+);
+
+-- Should not update any group when nothing changed
+select is(
+    update_dormant_groups(),
+    0,
+    'Should not update any group when nothing changed'
+);
+
+-- Should use the months configured by the community
+update community set dormant_group_months = 24 where community_id = :'communityID';
+select update_dormant_groups();
+select is(
+    (select dormant_since from "group" where group_id = :'groupOldEventID'),
+    null::timestamptz,
+    'Should use the months configured by the community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,157 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityAdminID 'c1120000-0000-0000-0000-000000000001'
+\set communityID 'c1120000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c1120000-0000-0000-0000-000000000003'
+\set groupID 'c1120000-0000-0000-0000-000000000004'
+\set newOwnerID 'c1120000-0000-0000-0000-000000000005'
+\set otherCommunityID 'c1120000-0000-0000-0000-000000000006'
+\set outsideUserID 'c1120000-0000-0000-0000-000000000007'
+\set ownerID 'c1120000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'update-owner-community',
+    'Update Owner Community',
+    'Community for updating group owners tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'newOwnerID', gen_random_bytes(32), 'bob@example.com', true, 'bob'),
+    (:'communityAdminID', gen_random_bytes(32), 'admin@example.com', true, 'admin'),
+    (:'ownerID', gen_random_bytes(32), 'alice@example.com', true, 'alice'),
+    (:'outsideUserID', gen_random_bytes(32), 'carol@example.com', true, 'carol');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, owner_user_id)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Owned Group', 'owned-group', :'ownerID');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted) values
+    (:'groupID', :'newOwnerID', 'viewer', true),
+    (:'groupID', :'ownerID', 'admin', true);
+
+-- Pending transfer
+insert into group_ownership_transfer (group_id, created_at, created_by, user_id)
+values (:'groupID', '2024-01-01 00:00:00+00', :'ownerID', :'newOwnerID');
+
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should make an outside user the group owner and admin
+select lives_ok(
+    format(
+        $$ select update_group_owner(%L::uuid, %L::uuid, %L::uuid, ' carol ') $$,
+        :'communityAdminID', :'communityID', :'groupID'
+    ),
+    'Should make an outside user the group owner and admin'
+);
+select results_eq(
+    format(
+        $$
+            select g.owner_user_id, gt.role, gt.accepted
+            from "group" g
+            join group_team gt on gt.group_id = g.group_id and gt.user_id = g.owner_user_id
+            where g.group_id = %L::uuid
+        $$,
+        :'groupID'
+    ),
+    format($$ values (%L::uuid, 'admin'::text, true) $$, :'outsideUserID'),
+    'New owner should be an accepted group admin'
+);
+select is_empty(
+    format(
+        $$ select 1 from group_ownership_transfer where group_id = %L::uuid $$,
+        :'groupID'
+    ),
+    'Pending transfer should be discarded'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id,
+            details
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'group_owner_updated',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            'user',
+            %L::uuid,
+            jsonb_build_object('previous_owner_user_id', %L)
+        )
+        $$,
+        :'communityAdminID', :'communityID', :'groupID', :'outsideUserID', :'ownerID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should raise error when the user does not exist
+select throws_ok(
+    format(
+        $$ select update_group_owner(%L::uuid, %L::uuid, %L::uuid, 'missing') $$,
+        :'communityAdminID', :'communityID', :'groupID'
+    ),
+    'user not found',
+    'Should raise error when the user does not exist'
+);
+
+-- Should raise error when the group belongs to another community
+select throws_ok(
+    format(
+        $$ select update_group_owner(%L::uuid, %L::uuid, %L::uuid, 'bob') $$,
+        :'communityAdminID', :'otherCommunityID', :'groupID'
+    ),
+    'group not found',
+    'Should raise error when the group belongs to another community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,128 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1190000-0000-0000-0000-000000000001'
+\set otherCommunityID 'c1190000-0000-0000-0000-000000000002'
+\set topic1ID 'c1190000-0000-0000-0000-000000000003'
+\set topic2ID 'c1190000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cncf-seattle',
+    'CNCF Seattle',
+    'Community for topic tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Topic
+insert into topic (topic_id, community_id, name)
+values
+    (:'topic1ID', :'communityID', 'Kubernetes'),
+    (:'topic2ID', :'communityID', 'Security');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should update the topic name and regenerate the normalized name
+select lives_ok(
+    format(
+        $$ select update_topic(
+        null::uuid,
+        %L::uuid,
+        %L::uuid,
+        jsonb_build_object('name', 'Observability')
+    ) $$,
+        :'communityID',
+        :'topic1ID'
+    ),
+    'Should update an existing topic'
+);
+select results_eq(
+    format(
+        $$ select name, normalized_name from topic where topic_id = %L::uuid $$,
+        :'topic1ID'
+    ),
+    $$ values ('Observability'::text, 'observability'::text) $$,
+    'Should store the updated name and normalized name'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            community_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$ values ('topic_updated'::text, %L::uuid, 'topic'::text, %L::uuid) $$,
+        :'communityID',
+        :'topic1ID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should reject duplicate topic names in the same community
+select throws_ok(
+    format(
+        $$ select update_topic(
+        null::uuid,
+        %L::uuid,
+        %L::uuid,
+        jsonb_build_object('name', 'security')
+    ) $$,
+        :'communityID',
+        :'topic1ID'
+    ),
+    'topic already exists',
+    'Should reject duplicate topic names'
+);
+
+-- Should reject topics from another community
+select throws_ok(
+    format(
+        $$ select update_topic(
+        null::uuid,
+        %L::uuid,
+        %L::uuid,
+        jsonb_build_object('name', 'Networking')
+    ) $$,
+        :'otherCommunityID',
+        :'topic1ID'
+    ),
+    'topic not found',
+    'Should reject topics from another community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
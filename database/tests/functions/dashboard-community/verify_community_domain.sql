@@ -0,0 +1,91 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityDomainID 'c1340000-0000-0000-0000-000000000051'
+\set communityID 'c1340000-0000-0000-0000-000000000052'
+\set otherCommunityID 'c1340000-0000-0000-0000-000000000053'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'domains-community',
+    'Domains Community',
+    'Community used for custom domains tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Domain
+insert into community_domain (community_domain_id, community_id, domain)
+values (:'communityDomainID', :'communityID', 'community.example.org');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject verifying a domain from another community
+select throws_ok(
+    format(
+        $$ select verify_community_domain(null::uuid, %L::uuid, %L::uuid) $$,
+        :'otherCommunityID',
+        :'communityDomainID'
+    ),
+    'domain not found',
+    'Should reject verifying a domain from another community'
+);
+
+-- Should verify the domain
+select lives_ok(
+    format(
+        $$ select verify_community_domain(null::uuid, %L::uuid, %L::uuid) $$,
+        :'communityID',
+        :'communityDomainID'
+    ),
+    'Should verify the domain'
+);
+select isnt(
+    (select verified_at from community_domain where community_domain_id = :'communityDomainID'),
+    null,
+    'Should set the domain verification time'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select action, resource_type, resource_id
+        from audit_log
+    $$,
+    format(
+        $$ values ('community_domain_verified'::text, 'community_domain'::text, %L::uuid) $$,
+        :'communityDomainID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
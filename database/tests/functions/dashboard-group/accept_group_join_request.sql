@@ -0,0 +1,147 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0f40000-0000-0000-0000-000000000001'
+\set communityID 'c0f40000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0f40000-0000-0000-0000-000000000003'
+\set groupID 'c0f40000-0000-0000-0000-000000000004'
+\set requesterID 'c0f40000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'actorID', 'hash1', 'actor@test.com', true, 'actor'),
+    (:'requesterID', 'hash2', 'requester@test.com', true, 'requester');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, private)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Private Group', 'private-group', true);
+
+-- Group join request
+insert into group_join_request (group_id, user_id, join_answers)
+values (
+    :'groupID',
+    :'requesterID',
+    '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]}'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should accept a pending join request
+select lives_ok(
+    format(
+        'select accept_group_join_request(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'requesterID'
+    ),
+    'Should accept a pending join request'
+);
+
+-- Should mark the request accepted
+select results_eq(
+    format(
+        $$
+            select status, reviewed_by, reviewed_at is not null
+            from group_join_request
+            where group_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'groupID', :'requesterID'
+    ),
+    format($$ values ('accepted'::text, %L::uuid, true) $$, :'actorID'),
+    'Should mark the request accepted'
+);
+
+-- Should add the user as a group member with the request answers
+select is(
+    (
+        select join_answers
+        from group_member
+        where group_id = :'groupID'::uuid
+        and user_id = :'requesterID'::uuid
+    ),
+    '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]}'::jsonb,
+    'Should add the user as a group member with the request answers'
+);
+
+-- Should track the decision in the audit log
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            group_id,
+            resource_id,
+            resource_type
+        from audit_log
+        where action = 'group_join_request_accepted'
+    $$,
+    format(
+        $$
+        values (
+            'group_join_request_accepted',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            'user'
+        )
+        $$,
+        :'actorID', :'communityID', :'groupID', :'requesterID'
+    ),
+    'Should track the decision in the audit log'
+);
+
+-- Should not accept a request that was already reviewed
+select throws_ok(
+    format(
+        'select accept_group_join_request(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'requesterID'
+    ),
+    'pending join request not found',
+    'Should not accept a request that was already reviewed'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(38);
+select plan(40);
 
 -- ============================================================================
 -- VARIABLES
@@ -129,9 +129,12 @@ select ok(
         "sessions": {},
         "test_event": false,
         "timezone": "America/New_York",
+        "attendance_certificate_emails_enabled": false,
         "attendee_approval_required": false,
+        "attendee_photos_enabled": false,
         "meeting_recording_published": false,
         "meeting_recording_requested": true,
+        "meeting_webinar": false,
         "registration_questions": [],
         "registration_questions_locked": false,
         "waitlist_count": 0,
@@ -215,11 +218,14 @@ with new_event as (
             "timezone": "America/Los_Angeles",
             "category_id": "3a020000-0000-0000-0000-000000000011",
             "kind_id": "hybrid",
+            "attendance_certificate_emails_enabled": true,
+            "attendee_photos_enabled": true,
             "banner_url": "https://example.com/banner.jpg",
             "capacity": 100,
             "description_short": "Short description",
             "starts_at": "2030-01-01T10:00:00",
             "ends_at": "2030-01-01T12:00:00",
+            "feedback_url": "https://example.com/feedback",
             "logo_url": "https://example.com/logo.png",
             "luma_url": "https://luma.com/event",
             "meeting_hosts": ["host1@example.com", "host2@example.com"],
@@ -229,6 +235,7 @@ with new_event as (
             "meetup_url": "https://meetup.com/event",
             "photos_urls": ["https://example.com/photo1.jpg", "https://example.com/photo2.jpg"],
             "registration_required": true,
+            "streaming_url": "https://www.youtube.com/live/abc123",
             "tags": ["technology", "conference", "networking"],
             "test_event": true,
             "venue_address": "123 Main St",
@@ -251,6 +258,8 @@ with new_event as (
                     "ends_at": "2030-01-01T10:45:00",
                     "kind": "in-person",
                     "location": "Main Hall",
+                "track": "Keynotes",
+                    "track": "Keynotes",
                     "speakers": [{"user_id": "3a020000-0000-0000-0000-000000000022", "featured": true}]
                 },
                 {
@@ -301,7 +310,9 @@ select ok(
         "published": false,
         "timezone": "America/Los_Angeles",
         "test_event": true,
+        "attendance_certificate_emails_enabled": true,
         "attendee_approval_required": false,
+        "attendee_photos_enabled": true,
         "banner_url": "https://example.com/banner.jpg",
         "capacity": 100,
         "remaining_capacity": 100,
@@ -312,6 +323,7 @@ select ok(
         "has_ticket_purchases": false,
         "starts_at": 1893520800,
         "ends_at": 1893528000,
+        "feedback_url": "https://example.com/feedback",
         "logo_url": "https://example.com/logo.png",
         "luma_url": "https://luma.com/event",
         "meeting_hosts": ["host1@example.com", "host2@example.com"],
@@ -319,12 +331,14 @@ select ok(
         "meeting_join_url": "https://youtube.com/live",
         "meeting_recording_published": false,
         "meeting_recording_requested": true,
+        "meeting_webinar": false,
         "meeting_recording_url": "https://youtube.com/recording",
         "meetup_url": "https://meetup.com/event",
         "photos_urls": ["https://example.com/photo1.jpg", "https://example.com/photo2.jpg"],
         "registration_questions": [],
         "registration_questions_locked": false,
         "registration_required": true,
+        "streaming_url": "https://www.youtube.com/live/abc123",
         "tags": ["technology", "conference", "networking"],
         "venue_address": "123 Main St",
         "venue_city": "San Francisco",
@@ -613,6 +627,38 @@ select is(
     'Should persist registration window dates when creating an event'
 );
 
+-- Should create event with a scheduled publication
+select add_event(
+    :'user1ID'::uuid,
+    :'groupID'::uuid,
+    jsonb_build_object(
+        'name', 'Scheduled Publication Event',
+        'description', 'Event published automatically',
+        'timezone', 'America/New_York',
+        'category_id', :'eventCategoryID',
+        'kind_id', 'in-person',
+        'starts_at', '2030-02-01T12:00:00',
+        'publish_scheduled_at', '2030-01-15T09:00:00'
+    )
+) as "scheduledPublicationEventID" \gset
+select is(
+    (
+        select jsonb_build_object(
+            'publish_scheduled_at', floor(extract(epoch from publish_scheduled_at)),
+            'publish_scheduled_by', publish_scheduled_by,
+            'published', published
+        )
+        from event
+        where event_id = :'scheduledPublicationEventID'::uuid
+    ),
+    jsonb_build_object(
+        'publish_scheduled_at', floor(extract(epoch from '2030-01-15 14:00:00+00'::timestamptz)),
+        'publish_scheduled_by', :'user1ID'::uuid,
+        'published', false
+    ),
+    'Should persist the scheduled publication in the event timezone'
+);
+
 -- Should throw error when capacity exceeds max_participants with meeting_requested
 select throws_ok(
     $$select add_event(
@@ -972,6 +1018,18 @@ select throws_ok(
     'Should validate registration questions when creating an event'
 );
 
+-- Should throw error when the group is archived
+update "group" set archived_at = current_timestamp where group_id = :'groupID'::uuid;
+select throws_ok(
+    $$select add_event(
+        null::uuid,
+        '3a020000-0000-0000-0000-000000000002'::uuid,
+        '{"name": "Archived Group Event", "description": "Test", "timezone": "UTC", "category_id": "3a020000-0000-0000-0000-000000000011", "kind_id": "virtual"}'::jsonb
+    )$$,
+    'group is archived',
+    'Should throw error when the group is archived'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
@@ -0,0 +1,173 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0dc0000-0000-0000-0000-000000000001'
+\set communityID 'c0dc0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0dc0000-0000-0000-0000-000000000003'
+\set eventFullID 'c0dc0000-0000-0000-0000-000000000004'
+\set eventID 'c0dc0000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0dc0000-0000-0000-0000-000000000006'
+\set groupID 'c0dc0000-0000-0000-0000-000000000007'
+\set unknownGroupID 'c0dc0000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values
+    (
+        :'eventID',
+        'Event',
+        'event',
+        'Event without resources',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    ),
+    (
+        :'eventFullID',
+        'Event Full',
+        'event-full',
+        'Event with the maximum number of resources',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    );
+
+-- Event resources
+insert into event_resource (event_id, content_type, file_name, name, size_bytes)
+select
+    :'eventFullID',
+    'application/pdf',
+    'hash' || i || '.pdf',
+    'slides-' || i || '.pdf',
+    1024
+from generate_series(1, 10) i;
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject events from other groups
+select throws_ok(
+    format(
+        'select add_event_resource(%L::uuid, %L::uuid, %L::uuid, %L::jsonb)',
+        :'actorID',
+        :'unknownGroupID',
+        :'eventID',
+        '{"content_type": "application/pdf", "file_name": "abc.pdf", "name": "slides.pdf", "size_bytes": 2048}'
+    ),
+    'event not found',
+    'Should reject events from other groups'
+);
+
+-- Should add the resource
+select lives_ok(
+    format(
+        'select add_event_resource(%L::uuid, %L::uuid, %L::uuid, %L::jsonb)',
+        :'actorID',
+        :'groupID',
+        :'eventID',
+        '{"content_type": "application/pdf", "file_name": "abc.pdf", "name": "slides.pdf", "size_bytes": 2048}'
+    ),
+    'Should add the resource'
+);
+select results_eq(
+    format(
+        $$ select content_type, created_by, file_name, name, size_bytes from event_resource where event_id = %L::uuid $$,
+        :'eventID'
+    ),
+    format(
+        $$ values ('application/pdf'::text, %L::uuid, 'abc.pdf'::text, 'slides.pdf'::text, 2048) $$,
+        :'actorID'
+    ),
+    'Should store the resource details'
+);
+
+-- Should track the change in the audit log
+select results_eq(
+    $$ select action, resource_type, resource_id, details->>'name' from audit_log $$,
+    format(
+        $$ values ('event_resource_added'::text, 'event'::text, %L::uuid, 'slides.pdf'::text) $$,
+        :'eventID'
+    ),
+    'Should track the change in the audit log'
+);
+
+-- Should reject events that reached the resources limit
+select throws_ok(
+    format(
+        'select add_event_resource(%L::uuid, %L::uuid, %L::uuid, %L::jsonb)',
+        :'actorID',
+        :'groupID',
+        :'eventFullID',
+        '{"content_type": "application/pdf", "file_name": "abc.pdf", "name": "slides.pdf", "size_bytes": 2048}'
+    ),
+    'you can add up to 10 resources per event',
+    'Should reject events that reached the resources limit'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
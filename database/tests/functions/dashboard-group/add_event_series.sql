@@ -98,7 +98,7 @@ select lives_ok(
                     "ends_at": "2030-01-14T11:00:00"
                 }
             ]'::jsonb,
-            '{"additional_occurrences": 1, "pattern": "weekly"}'::jsonb
+            '{"additional_occurrences": 1, "name": " Study Group Series ", "pattern": "weekly"}'::jsonb
         )
         $$,
         :'userID', :'groupID', :'eventCategoryID', :'eventCategoryID'
@@ -131,11 +131,17 @@ select results_eq(
         select
             recurrence_additional_occurrences,
             recurrence_pattern,
-            timezone
+            timezone,
+            name
         from event_series
     $$,
     $$
-        values (1, 'weekly'::text, 'UTC'::text)
+        values (
+            1,
+            'weekly'::text,
+            'UTC'::text,
+            'Study Group Series'::text
+        )
     $$,
     'Should store recurrence metadata for the series'
 );
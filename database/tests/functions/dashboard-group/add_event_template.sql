@@ -0,0 +1,194 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1330000-0000-0000-0000-000000000001'
+\set communityID 'c1330000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c1330000-0000-0000-0000-000000000003'
+\set eventID 'c1330000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c1330000-0000-0000-0000-000000000005'
+\set groupID 'c1330000-0000-0000-0000-000000000006'
+\set otherGroupID 'c1330000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Event
+insert into event (
+    event_id,
+    group_id,
+    event_category_id,
+    event_kind_id,
+    name,
+    slug,
+    description,
+    description_short,
+    timezone,
+    capacity,
+    starts_at,
+    ends_at,
+    venue_city,
+    venue_name
+) values (
+    :'eventID',
+    :'groupID',
+    :'eventCategoryID',
+    'in-person',
+    'Monthly Meetup',
+    'monthly-meetup',
+    'Our monthly meetup',
+    'Monthly',
+    'Europe/Madrid',
+    50,
+    '2030-01-01 18:00:00+00',
+    '2030-01-01 21:00:00+00',
+    'Madrid',
+    'Community Hub'
+);
+
+-- Sessions
+insert into session (event_id, name, description, session_kind_id, starts_at, ends_at) values
+    (:'eventID', 'Welcome', 'Opening remarks', 'in-person', '2030-01-01 18:00:00+00', '2030-01-01 18:15:00+00'),
+    (:'eventID', 'Lightning talks', null, 'in-person', '2030-01-01 18:15:00+00', '2030-01-01 19:00:00+00');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject events from other groups
+select throws_ok(
+    format(
+        'select add_event_template(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'communityID', :'otherGroupID', :'eventID'
+    ),
+    'event not found',
+    'Should reject events from other groups'
+);
+
+-- Should save the event as a template named after it
+select lives_ok(
+    format(
+        'select add_event_template(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'communityID', :'groupID', :'eventID'
+    ),
+    'Should save the event as a template'
+);
+select results_eq(
+    $$ select name, created_by, group_id from event_template $$,
+    format(
+        $$ values ('Monthly Meetup'::text, %L::uuid, %L::uuid) $$,
+        :'actorID', :'groupID'
+    ),
+    'Should store the template named after the event'
+);
+
+-- Should keep the reusable details and the agenda skeleton
+select is(
+    (
+        select jsonb_build_object(
+            'capacity', details->'capacity',
+            'description', details->'description',
+            'kind', details->'kind',
+            'sessions', details->'sessions',
+            'venue_city', details->'venue_city',
+            'venue_name', details->'venue_name'
+        )
+        from event_template
+    ),
+    '{
+        "capacity": 50,
+        "description": "Our monthly meetup",
+        "kind": "in-person",
+        "sessions": [
+            {"description": "Opening remarks", "kind": "in-person", "name": "Welcome"},
+            {"kind": "in-person", "name": "Lightning talks"}
+        ],
+        "venue_city": "Madrid",
+        "venue_name": "Community Hub"
+    }'::jsonb,
+    'Should keep the reusable details and the agenda skeleton'
+);
+
+-- Should leave out the event dates and identifiers
+select ok(
+    (
+        select not (details ?| array['ends_at', 'event_id', 'slug', 'starts_at'])
+        from event_template
+    ),
+    'Should leave out the event dates and identifiers'
+);
+
+-- Should replace the template when saving the event again
+select lives_ok(
+    format(
+        'select add_event_template(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'communityID', :'groupID', :'eventID'
+    ),
+    'Should save the event again'
+);
+select is(
+    (select count(*)::int from event_template),
+    1,
+    'Should replace the template when saving the event again'
+);
+
+-- Should track the template creation in the audit log
+select results_eq(
+    $$ select distinct action, resource_type, event_id from audit_log $$,
+    format(
+        $$ values ('event_template_added'::text, 'event_template'::text, %L::uuid) $$,
+        :'eventID'
+    ),
+    'Should track the template creation in the audit log'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,87 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0ff0000-0000-0000-0000-000000000001'
+\set communityID 'c0ff0000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0ff0000-0000-0000-0000-000000000003'
+\set groupID 'c0ff0000-0000-0000-0000-000000000004'
+\set otherGroupID 'c0ff0000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add a pinned announcement with trimmed values
+select lives_ok(
+    format(
+        $$ select add_group_announcement(%L::uuid, %L::uuid, '{"title": " Venue change ", "content": " We are moving to a bigger room. "}'::jsonb) $$,
+        :'actorID', :'groupID'
+    ),
+    'Should add the announcement'
+);
+select results_eq(
+    $$ select title, content, created_by, pinned from group_announcement $$,
+    format(
+        $$ values ('Venue change'::text, 'We are moving to a bigger room.'::text, %L::uuid, true) $$,
+        :'actorID'
+    ),
+    'Should store a pinned announcement with trimmed values'
+);
+
+-- Should track the announcement creation in the audit log
+select results_eq(
+    $$ select action, resource_type, resource_id from audit_log $$,
+    $$ select 'group_announcement_added'::text, 'group_announcement'::text, group_announcement_id from group_announcement $$,
+    'Should track the announcement creation in the audit log'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
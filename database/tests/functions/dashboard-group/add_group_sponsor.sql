@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(3);
+select plan(5);
 
 -- ============================================================================
 -- VARIABLES
@@ -12,6 +12,9 @@ select plan(3);
 \set communityID '3a040000-0000-0000-0000-000000000001'
 \set groupCategoryID '3a040000-0000-0000-0000-000000000002'
 \set groupID '3a040000-0000-0000-0000-000000000003'
+\set groupSponsorTierID '3a040000-0000-0000-0000-000000000004'
+\set otherGroupID '3a040000-0000-0000-0000-000000000005'
+\set otherGroupSponsorTierID '3a040000-0000-0000-0000-000000000006'
 
 -- ============================================================================
 -- SEED DATA
@@ -40,9 +43,15 @@ insert into community (
 insert into group_category (group_category_id, community_id, name)
 values (:'groupCategoryID', :'communityID', 'Tech');
 
--- Group
-insert into "group" (group_id, community_id, group_category_id, name, slug)
-values (:'groupID', :'communityID', :'groupCategoryID', 'Group NYC', 'group-nyc');
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group NYC', 'group-nyc'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Group LA', 'group-la');
+
+-- Sponsor tiers
+insert into group_sponsor_tier (group_sponsor_tier_id, group_id, logo_size, name, "order") values
+    (:'groupSponsorTierID', :'groupID', 'large', 'Gold', 1),
+    (:'otherGroupSponsorTierID', :'otherGroupID', 'small', 'Community', 1);
 
 -- ============================================================================
 -- TESTS
@@ -122,6 +131,51 @@ select is(
     'Should default featured to true when omitted'
 );
 
+-- Should attach the sponsor to a tier of the group
+select is(
+    (
+        select (get_group_sponsor(
+            add_group_sponsor(null::uuid, :'groupID'::uuid, format('{
+                "group_sponsor_tier_id": "%s",
+                "name":"Eta",
+                "logo_url":"https://ex.com/eta.png"
+            }', :'groupSponsorTierID')::jsonb
+            ),
+            :'groupID'::uuid
+        )::jsonb - 'group_sponsor_id')
+    ),
+    format('{
+        "featured": true,
+        "group_sponsor_tier_id": "%s",
+        "logo_size": "large",
+        "logo_url":"https://ex.com/eta.png",
+        "name":"Eta",
+        "tier_name": "Gold"
+    }', :'groupSponsorTierID')::jsonb,
+    'Should attach the sponsor to a tier of the group'
+);
+
+-- Should ignore tiers from other groups
+select is(
+    (
+        select (get_group_sponsor(
+            add_group_sponsor(null::uuid, :'groupID'::uuid, format('{
+                "group_sponsor_tier_id": "%s",
+                "name":"Theta",
+                "logo_url":"https://ex.com/theta.png"
+            }', :'otherGroupSponsorTierID')::jsonb
+            ),
+            :'groupID'::uuid
+        )::jsonb - 'group_sponsor_id')
+    ),
+    '{
+        "featured": true,
+        "logo_url":"https://ex.com/theta.png",
+        "name":"Theta"
+    }'::jsonb,
+    'Should ignore tiers from other groups'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
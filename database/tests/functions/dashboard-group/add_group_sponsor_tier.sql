@@ -0,0 +1,92 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1040000-0000-0000-0000-000000000001'
+\set communityID 'c1040000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c1040000-0000-0000-0000-000000000003'
+\set groupID 'c1040000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add the tier with trimmed values
+select lives_ok(
+    format(
+        $$ select add_group_sponsor_tier(%L::uuid, %L::uuid, '{"name": " Platinum ", "logo_size": "large", "order": 1}'::jsonb) $$,
+        :'actorID', :'groupID'
+    ),
+    'Should add the tier'
+);
+select results_eq(
+    $$ select name, logo_size, "order" from group_sponsor_tier $$,
+    $$ values ('Platinum'::text, 'large'::text, 1) $$,
+    'Should store the tier with trimmed values'
+);
+
+-- Should track the tier creation in the audit log
+select results_eq(
+    $$ select action, resource_type, resource_id from audit_log $$,
+    $$ select 'group_sponsor_tier_added'::text, 'group_sponsor_tier'::text, group_sponsor_tier_id from group_sponsor_tier $$,
+    'Should track the tier creation in the audit log'
+);
+
+-- Should reject duplicated tier names in the same group
+select throws_ok(
+    format(
+        $$ select add_group_sponsor_tier(%L::uuid, %L::uuid, '{"name": "platinum"}'::jsonb) $$,
+        :'actorID', :'groupID'
+    ),
+    'sponsor tier already exists',
+    'Should reject duplicated tier names in the same group'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,149 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0d50000-0000-0000-0000-000000000001'
+\set attendeeID 'c0d50000-0000-0000-0000-000000000002'
+\set communityID 'c0d50000-0000-0000-0000-000000000003'
+\set eventCategoryID 'c0d50000-0000-0000-0000-000000000004'
+\set eventID 'c0d50000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0d50000-0000-0000-0000-000000000006'
+\set groupID 'c0d50000-0000-0000-0000-000000000007'
+\set photoID 'c0d50000-0000-0000-0000-000000000008'
+\set unknownGroupID 'c0d50000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor'),
+    ('hash-attendee', 'attendee@example.com', true, 'Attendee', :'attendeeID', 'attendee');
+
+-- Event
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    attendee_photos_enabled,
+    starts_at,
+    ends_at
+) values (
+    :'eventID',
+    'Past Event',
+    'past-event',
+    'Past event accepting photos',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    true,
+    true,
+    current_timestamp - interval '2 days',
+    current_timestamp - interval '2 days' + interval '2 hours'
+);
+
+-- Event photos
+insert into event_photo (event_photo_id, event_id, url, user_id)
+values (:'photoID', :'eventID', '/images/photo.png', :'attendeeID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should mark the photo as approved
+select lives_ok(
+    format(
+        'select approve_event_photo(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventID', :'photoID'
+    ),
+    'Should mark the photo as approved'
+);
+select results_eq(
+    format('select status, reviewed_by from event_photo where event_photo_id = %L::uuid', :'photoID'),
+    format($$ values ('approved'::text, %L::uuid) $$, :'actorID'),
+    'Should record the approved status and the reviewer'
+);
+
+-- Should track the decision in the audit log
+select results_eq(
+    $$ select action, resource_id, details->>'event_photo_id' from audit_log $$,
+    format(
+        $$ values ('event_photo_approved'::text, %L::uuid, %L::text) $$,
+        :'attendeeID', :'photoID'
+    ),
+    'Should track the decision in the audit log'
+);
+
+-- Should reject photos already approved
+select throws_ok(
+    format(
+        'select approve_event_photo(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventID', :'photoID'
+    ),
+    'photo not found or already approved',
+    'Should reject photos already approved'
+);
+
+-- Should reject events from other groups
+select throws_ok(
+    format(
+        'select approve_event_photo(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'unknownGroupID', :'eventID', :'photoID'
+    ),
+    'event not found',
+    'Should reject events from other groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
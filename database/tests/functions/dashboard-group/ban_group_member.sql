@@ -0,0 +1,311 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(13);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1280000-0000-0000-0000-000000000001'
+\set attendedEventID 'c1280000-0000-0000-0000-000000000010'
+\set communityID 'c1280000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c1280000-0000-0000-0000-000000000003'
+\set eventID 'c1280000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c1280000-0000-0000-0000-000000000005'
+\set groupID 'c1280000-0000-0000-0000-000000000006'
+\set invitedEventID 'c1280000-0000-0000-0000-000000000011'
+\set memberID 'c1280000-0000-0000-0000-000000000007'
+\set otherCommunityID 'c1280000-0000-0000-0000-000000000008'
+\set pastEventID 'c1280000-0000-0000-0000-000000000012'
+\set requesterID 'c1280000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+), (
+    :'otherCommunityID',
+    'other-community',
+    'Other Community',
+    'Another test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'actorID', 'hash1', 'actor@test.com', true, 'actor'),
+    (:'memberID', 'hash2', 'member@test.com', true, 'member'),
+    (:'requesterID', 'hash3', 'requester@test.com', true, 'requester');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted)
+values (:'groupID', :'actorID', 'admin', true);
+
+-- Group member
+insert into group_member (group_id, user_id)
+values (:'groupID', :'memberID');
+
+-- Group join request
+insert into group_join_request (group_id, user_id)
+values (:'groupID', :'requesterID');
+
+-- Upcoming event with the member on the waiting list
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    capacity,
+    waitlist_enabled,
+    starts_at
+) values (
+    :'eventID',
+    'Upcoming Event',
+    'upcoming-event',
+    'Test upcoming event',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    true,
+    10,
+    true,
+    now() + interval '7 days'
+);
+insert into event_waitlist (event_id, user_id)
+values (:'eventID', :'memberID');
+
+-- Upcoming events with the member attending and the requester invited, and a
+-- past event attended by the member
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    starts_at
+) values
+    (
+        :'attendedEventID',
+        'Attended Event',
+        'attended-event',
+        'Test attended event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        now() + interval '14 days'
+    ),
+    (
+        :'invitedEventID',
+        'Invited Event',
+        'invited-event',
+        'Test invited event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        now() + interval '21 days'
+    ),
+    (
+        :'pastEventID',
+        'Past Event',
+        'past-event',
+        'Test past event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        now() - interval '7 days'
+    );
+insert into event_attendee (event_id, user_id, manually_invited, status)
+values
+    (:'attendedEventID', :'memberID', false, 'confirmed'),
+    (:'invitedEventID', :'requesterID', true, 'invitation-pending'),
+    (:'pastEventID', :'memberID', false, 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should ban a group member
+select lives_ok(
+    format(
+        'select ban_group_member(%L::uuid, %L::uuid, %L::uuid, %L::uuid, %L)',
+        :'actorID', :'communityID', :'groupID', :'memberID', '  Harassing other members  '
+    ),
+    'Should ban a group member'
+);
+
+-- Should record the ban with the trimmed reason
+select results_eq(
+    format(
+        $$
+            select created_by, reason
+            from group_ban
+            where group_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'groupID', :'memberID'
+    ),
+    format($$ values (%L::uuid, 'Harassing other members'::text) $$, :'actorID'),
+    'Should record the ban with the trimmed reason'
+);
+
+-- Should remove the group membership
+select ok(
+    not exists(select 1 from group_member where group_id = :'groupID'::uuid and user_id = :'memberID'::uuid),
+    'Should remove the group membership'
+);
+
+-- Should remove the user from the upcoming events waiting lists
+select ok(
+    not exists(select 1 from event_waitlist where event_id = :'eventID'::uuid and user_id = :'memberID'::uuid),
+    'Should remove the user from the upcoming events waiting lists'
+);
+
+-- Should remove the user from the upcoming events attendees
+select ok(
+    not exists(select 1 from event_attendee where event_id = :'attendedEventID'::uuid and user_id = :'memberID'::uuid),
+    'Should remove the user from the upcoming events attendees'
+);
+
+-- Should keep the attendance to past events
+select ok(
+    exists(select 1 from event_attendee where event_id = :'pastEventID'::uuid and user_id = :'memberID'::uuid),
+    'Should keep the attendance to past events'
+);
+
+-- Should track the ban in the audit log
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            group_id,
+            resource_id,
+            resource_type,
+            details
+        from audit_log
+        where action = 'group_member_banned'
+    $$,
+    format(
+        $$
+        values (
+            'group_member_banned',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            'user',
+            '{"reason": "Harassing other members"}'::jsonb
+        )
+        $$,
+        :'actorID', :'communityID', :'groupID', :'memberID'
+    ),
+    'Should track the ban in the audit log'
+);
+
+-- Should remove the pending join request of a banned user
+select lives_ok(
+    format(
+        'select ban_group_member(%L::uuid, %L::uuid, %L::uuid, %L::uuid, %L)',
+        :'actorID', :'communityID', :'groupID', :'requesterID', 'Spam'
+    ),
+    'Should ban a user with a pending join request'
+);
+select ok(
+    not exists(select 1 from group_join_request where group_id = :'groupID'::uuid and user_id = :'requesterID'::uuid),
+    'Should remove the pending join request of a banned user'
+);
+
+-- Should remove the pending event invitations of a banned user
+select ok(
+    not exists(select 1 from event_attendee where event_id = :'invitedEventID'::uuid and user_id = :'requesterID'::uuid),
+    'Should remove the pending event invitations of a banned user'
+);
+
+-- Should not ban a user twice
+select throws_ok(
+    format(
+        'select ban_group_member(%L::uuid, %L::uuid, %L::uuid, %L::uuid, %L)',
+        :'actorID', :'communityID', :'groupID', :'memberID', 'Again'
+    ),
+    'user is already banned from this group',
+    'Should not ban a user twice'
+);
+
+-- Should not ban group team members
+select throws_ok(
+    format(
+        'select ban_group_member(%L::uuid, %L::uuid, %L::uuid, %L::uuid, %L)',
+        :'actorID', :'communityID', :'groupID', :'actorID', 'Self ban'
+    ),
+    'group team members cannot be banned',
+    'Should not ban group team members'
+);
+
+-- Should not ban users from groups in other communities
+select throws_ok(
+    format(
+        'select ban_group_member(%L::uuid, %L::uuid, %L::uuid, %L::uuid, %L)',
+        :'actorID', :'otherCommunityID', :'groupID', :'memberID', 'Wrong community'
+    ),
+    'group not found or inactive',
+    'Should not ban users from groups in other communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
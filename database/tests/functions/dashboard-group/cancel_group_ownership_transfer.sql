@@ -0,0 +1,126 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c10e0000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c10e0000-0000-0000-0000-000000000002'
+\set groupID 'c10e0000-0000-0000-0000-000000000003'
+\set newOwnerID 'c10e0000-0000-0000-0000-000000000004'
+\set ownerID 'c10e0000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cancel-transfer-community',
+    'Cancel Transfer Community',
+    'Community for canceling ownership transfers',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'newOwnerID', gen_random_bytes(32), 'bob@example.com', true, 'bob'),
+    (:'ownerID', gen_random_bytes(32), 'alice@example.com', true, 'alice');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, owner_user_id)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Cancel Group', 'cancel-group', :'ownerID');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted) values
+    (:'groupID', :'newOwnerID', 'viewer', true),
+    (:'groupID', :'ownerID', 'admin', true);
+
+-- Pending transfer
+insert into group_ownership_transfer (group_id, created_by, user_id)
+values (:'groupID', :'ownerID', :'newOwnerID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should cancel the pending transfer
+select lives_ok(
+    format(
+        $$ select cancel_group_ownership_transfer(%L::uuid, %L::uuid) $$,
+        :'ownerID', :'groupID'
+    ),
+    'Should cancel the pending transfer'
+);
+select is_empty(
+    format(
+        $$ select 1 from group_ownership_transfer where group_id = %L::uuid $$,
+        :'groupID'
+    ),
+    'Pending transfer should be removed'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'group_ownership_transfer_canceled',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            'user',
+            %L::uuid
+        )
+        $$,
+        :'ownerID', :'communityID', :'groupID', :'newOwnerID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should raise error when there is no pending transfer
+select throws_ok(
+    format(
+        $$ select cancel_group_ownership_transfer(%L::uuid, %L::uuid) $$,
+        :'ownerID', :'groupID'
+    ),
+    'no pending group ownership transfer found',
+    'Should raise error when there is no pending transfer'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,118 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0b80000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0b80000-0000-0000-0000-000000000002'
+\set eventID 'c0b80000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0b80000-0000-0000-0000-000000000004'
+\set groupID 'c0b80000-0000-0000-0000-000000000005'
+\set otherGroupID 'c0b80000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    banner_mobile_url,
+    banner_url,
+    description,
+    display_name,
+    logo_url,
+    name
+) values (
+    :'communityID',
+    'https://e/community-mobile.png',
+    'https://e/community.png',
+    'Community 1',
+    'Community One',
+    'https://e/community-logo.png',
+    'community-one'
+);
+
+-- Categories
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group One', 'group-one'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Other Group', 'other-group');
+
+-- Event
+insert into event (
+    event_id,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone
+) values (
+    :'eventID',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    'Event One',
+    'event-one',
+    'Event one',
+    'UTC'
+);
+
+-- Custom notifications
+insert into custom_notification (body, created_at, event_id, group_id, subject)
+values
+    ('Recent body', current_timestamp - interval '1 hour', null, :'groupID', 'Recent'),
+    ('Other recent body', current_timestamp - interval '2 hours', null, :'groupID', 'Other recent'),
+    ('Old body', current_timestamp - interval '2 days', null, :'groupID', 'Old'),
+    ('Event body', current_timestamp - interval '1 hour', :'eventID', null, 'Event'),
+    ('Other group body', current_timestamp - interval '1 hour', null, :'otherGroupID', 'Other group');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should count the group custom notifications sent since the date provided
+select is(
+    count_group_custom_notifications_since(:'groupID'::uuid, current_timestamp - interval '1 day'),
+    2::bigint,
+    'Should count the group custom notifications sent since the date provided'
+);
+
+-- Should include older group custom notifications when the date is earlier
+select is(
+    count_group_custom_notifications_since(:'groupID'::uuid, current_timestamp - interval '1 week'),
+    3::bigint,
+    'Should include older group custom notifications when the date is earlier'
+);
+
+-- Should return zero when the group has not sent custom notifications
+select is(
+    count_group_custom_notifications_since(
+        'c0b80000-0000-0000-0000-000000000099'::uuid,
+        current_timestamp - interval '1 day'
+    ),
+    0::bigint,
+    'Should return zero when the group has not sent custom notifications'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
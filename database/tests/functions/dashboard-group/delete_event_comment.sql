@@ -0,0 +1,147 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0d70000-0000-0000-0000-000000000001'
+\set attendeeID 'c0d70000-0000-0000-0000-000000000002'
+\set commentID 'c0d70000-0000-0000-0000-000000000003'
+\set communityID 'c0d70000-0000-0000-0000-000000000004'
+\set eventCategoryID 'c0d70000-0000-0000-0000-000000000005'
+\set eventID 'c0d70000-0000-0000-0000-000000000006'
+\set groupCategoryID 'c0d70000-0000-0000-0000-000000000007'
+\set groupID 'c0d70000-0000-0000-0000-000000000008'
+\set replyID 'c0d70000-0000-0000-0000-000000000009'
+\set unknownGroupID 'c0d70000-0000-0000-0000-000000000010'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor'),
+    ('hash-attendee', 'attendee@example.com', true, 'Attendee', :'attendeeID', 'attendee');
+
+-- Event
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    starts_at
+) values (
+    :'eventID',
+    'Upcoming Event',
+    'upcoming-event',
+    'Upcoming event with a discussion',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    true,
+    current_timestamp + interval '2 days'
+);
+
+-- Event comments
+insert into event_comment (event_comment_id, event_id, user_id, content, parent_event_comment_id)
+values
+    (:'commentID', :'eventID', :'attendeeID', 'Will the talks be recorded?', null),
+    (:'replyID', :'eventID', :'actorID', 'Yes, they will.', :'commentID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject events from other groups
+select throws_ok(
+    format(
+        'select delete_event_comment(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'unknownGroupID', :'eventID', :'commentID'
+    ),
+    'event not found',
+    'Should reject events from other groups'
+);
+
+-- Should delete the comment
+select lives_ok(
+    format(
+        'select delete_event_comment(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventID', :'commentID'
+    ),
+    'Should delete the comment'
+);
+select is_empty(
+    format('select 1 from event_comment where event_id = %L::uuid', :'eventID'),
+    'Should delete the comment replies as well'
+);
+
+-- Should track the decision in the audit log
+select results_eq(
+    $$ select action, resource_id, details->>'event_comment_id' from audit_log $$,
+    format(
+        $$ values ('event_comment_deleted'::text, %L::uuid, %L::text) $$,
+        :'attendeeID', :'commentID'
+    ),
+    'Should track the decision in the audit log'
+);
+
+-- Should reject comments already deleted
+select throws_ok(
+    format(
+        'select delete_event_comment(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventID', :'commentID'
+    ),
+    'comment not found',
+    'Should reject comments already deleted'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
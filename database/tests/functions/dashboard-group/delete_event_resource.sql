@@ -0,0 +1,135 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0dd0000-0000-0000-0000-000000000001'
+\set communityID 'c0dd0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0dd0000-0000-0000-0000-000000000003'
+\set eventID 'c0dd0000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0dd0000-0000-0000-0000-000000000005'
+\set groupID 'c0dd0000-0000-0000-0000-000000000006'
+\set resourceID 'c0dd0000-0000-0000-0000-000000000007'
+\set unknownGroupID 'c0dd0000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Event
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values (
+    :'eventID',
+    'Event',
+    'event',
+    'Event with resources',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    true
+);
+
+-- Event resource
+insert into event_resource (event_resource_id, event_id, content_type, file_name, name, size_bytes)
+values (:'resourceID', :'eventID', 'application/pdf', 'abc.pdf', 'slides.pdf', 2048);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject events from other groups
+select throws_ok(
+    format(
+        'select delete_event_resource(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'unknownGroupID', :'eventID', :'resourceID'
+    ),
+    'event not found',
+    'Should reject events from other groups'
+);
+
+-- Should delete the resource
+select lives_ok(
+    format(
+        'select delete_event_resource(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventID', :'resourceID'
+    ),
+    'Should delete the resource'
+);
+
+-- Should track the change in the audit log
+select results_eq(
+    $$ select action, resource_id, details->>'name' from audit_log $$,
+    format(
+        $$ values ('event_resource_deleted'::text, %L::uuid, 'slides.pdf'::text) $$,
+        :'eventID'
+    ),
+    'Should track the change in the audit log'
+);
+
+-- Should reject resources already deleted
+select throws_ok(
+    format(
+        'select delete_event_resource(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventID', :'resourceID'
+    ),
+    'resource not found',
+    'Should reject resources already deleted'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,102 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1330000-0000-0000-0000-000000000011'
+\set communityID 'c1330000-0000-0000-0000-000000000012'
+\set eventTemplateID 'c1330000-0000-0000-0000-000000000013'
+\set groupCategoryID 'c1330000-0000-0000-0000-000000000014'
+\set groupID 'c1330000-0000-0000-0000-000000000015'
+\set otherGroupID 'c1330000-0000-0000-0000-000000000016'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Event template
+insert into event_template (event_template_id, group_id, name, details, created_by)
+values (:'eventTemplateID', :'groupID', 'Monthly Meetup', '{"kind": "in-person"}', :'actorID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject templates from other groups
+select throws_ok(
+    format(
+        'select delete_event_template(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'otherGroupID', :'eventTemplateID'
+    ),
+    'event template not found',
+    'Should reject templates from other groups'
+);
+
+-- Should delete the template
+select lives_ok(
+    format(
+        'select delete_event_template(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventTemplateID'
+    ),
+    'Should delete the template'
+);
+select is(
+    (select count(*)::int from event_template),
+    0,
+    'Should remove the template'
+);
+
+-- Should track the deletion in the audit log
+select results_eq(
+    $$ select action, resource_id, details->>'name' from audit_log $$,
+    format(
+        $$ values ('event_template_deleted'::text, %L::uuid, 'Monthly Meetup'::text) $$,
+        :'eventTemplateID'
+    ),
+    'Should track the deletion in the audit log'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
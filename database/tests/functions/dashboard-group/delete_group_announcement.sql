@@ -0,0 +1,102 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1000000-0000-0000-0000-000000000001'
+\set announcementID 'c1000000-0000-0000-0000-000000000002'
+\set communityID 'c1000000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c1000000-0000-0000-0000-000000000004'
+\set groupID 'c1000000-0000-0000-0000-000000000005'
+\set otherGroupID 'c1000000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Announcement
+insert into group_announcement (group_announcement_id, group_id, title, content, created_by)
+values (:'announcementID', :'groupID', 'Venue change', 'New room', :'actorID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject announcements from other groups
+select throws_ok(
+    format(
+        'select delete_group_announcement(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'otherGroupID', :'announcementID'
+    ),
+    'announcement not found',
+    'Should reject announcements from other groups'
+);
+
+-- Should delete the announcement
+select lives_ok(
+    format(
+        'select delete_group_announcement(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'announcementID'
+    ),
+    'Should delete the announcement'
+);
+select is(
+    (select count(*)::int from group_announcement),
+    0,
+    'Should remove the announcement'
+);
+
+-- Should track the deletion in the audit log
+select results_eq(
+    $$ select action, resource_id, details->>'title' from audit_log $$,
+    format(
+        $$ values ('group_announcement_deleted'::text, %L::uuid, 'Venue change'::text) $$,
+        :'announcementID'
+    ),
+    'Should track the deletion in the audit log'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
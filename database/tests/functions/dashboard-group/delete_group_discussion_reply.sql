@@ -0,0 +1,119 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0fc0000-0000-0000-0000-000000000001'
+\set communityID 'c0fc0000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0fc0000-0000-0000-0000-000000000003'
+\set groupID 'c0fc0000-0000-0000-0000-000000000004'
+\set memberID 'c0fc0000-0000-0000-0000-000000000005'
+\set otherGroupID 'c0fc0000-0000-0000-0000-000000000006'
+\set replyID 'c0fc0000-0000-0000-0000-000000000007'
+\set topicID 'c0fc0000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor'),
+    ('hash-member', 'member@example.com', true, 'Member', :'memberID', 'member');
+
+-- Discussion topic
+insert into group_discussion_topic (group_discussion_topic_id, group_id, user_id, title, content)
+values (:'topicID', :'groupID', :'memberID', 'Meetup venues', 'Any ideas?');
+
+-- Discussion reply
+insert into group_discussion_reply (group_discussion_reply_id, group_discussion_topic_id, user_id, content)
+values (:'replyID', :'topicID', :'memberID', 'Spam');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject replies from other groups
+select throws_ok(
+    format(
+        'select delete_group_discussion_reply(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'otherGroupID', :'replyID'
+    ),
+    'reply not found',
+    'Should reject replies from other groups'
+);
+
+-- Should delete the reply
+select lives_ok(
+    format(
+        'select delete_group_discussion_reply(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'replyID'
+    ),
+    'Should delete the reply'
+);
+select isnt_empty(
+    format('select 1 from group_discussion_topic where group_discussion_topic_id = %L::uuid', :'topicID'),
+    'Should keep the topic'
+);
+
+-- Should track the decision in the audit log
+select results_eq(
+    $$ select action, resource_id, details->>'group_discussion_reply_id' from audit_log $$,
+    format(
+        $$ values ('group_discussion_reply_deleted'::text, %L::uuid, %L::text) $$,
+        :'memberID', :'replyID'
+    ),
+    'Should track the decision in the audit log'
+);
+
+-- Should reject replies already deleted
+select throws_ok(
+    format(
+        'select delete_group_discussion_reply(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'replyID'
+    ),
+    'reply not found',
+    'Should reject replies already deleted'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,107 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1050000-0000-0000-0000-000000000001'
+\set communityID 'c1050000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c1050000-0000-0000-0000-000000000003'
+\set groupID 'c1050000-0000-0000-0000-000000000004'
+\set groupSponsorID 'c1050000-0000-0000-0000-000000000005'
+\set groupSponsorTierID 'c1050000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Sponsor tier
+insert into group_sponsor_tier (group_sponsor_tier_id, group_id, name)
+values (:'groupSponsorTierID', :'groupID', 'Gold');
+
+-- Sponsor
+insert into group_sponsor (group_sponsor_id, group_id, group_sponsor_tier_id, logo_url, name)
+values (:'groupSponsorID', :'groupID', :'groupSponsorTierID', 'https://example.com/sponsor.png', 'Sponsor');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should delete the tier
+select lives_ok(
+    format(
+        $$ select delete_group_sponsor_tier(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'actorID', :'groupID', :'groupSponsorTierID'
+    ),
+    'Should delete the tier'
+);
+
+-- Should keep the sponsors of the deleted tier without a tier
+select results_eq(
+    $$ select name, group_sponsor_tier_id from group_sponsor $$,
+    $$ values ('Sponsor'::text, null::uuid) $$,
+    'Should keep the sponsors of the deleted tier without a tier'
+);
+
+-- Should track the tier deletion in the audit log
+select results_eq(
+    $$ select action, resource_id, details from audit_log $$,
+    format(
+        $$ values ('group_sponsor_tier_deleted'::text, %L::uuid, '{"name": "Gold"}'::jsonb) $$,
+        :'groupSponsorTierID'
+    ),
+    'Should track the tier deletion in the audit log'
+);
+
+-- Should raise an error when the tier does not exist
+select throws_ok(
+    format(
+        $$ select delete_group_sponsor_tier(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'actorID', :'groupID', :'groupSponsorTierID'
+    ),
+    'sponsor tier not found',
+    'Should raise an error when the tier does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(7);
+select plan(8);
 
 -- ============================================================================
 -- VARIABLES
@@ -151,6 +151,18 @@ select lives_ok(
     'Should allow deleting a pending invitation with only one accepted member left'
 );
 
+-- Should block deleting the group owner
+update "group" set owner_user_id = :'user1ID' where group_id = :'groupID';
+select throws_ok(
+    format(
+        $$ select delete_group_team_member(null::uuid, %L::uuid, %L::uuid) $$,
+        :'groupID', :'user1ID'
+    ),
+    'cannot remove the group owner, transfer the ownership first',
+    'Should block deleting the group owner'
+);
+update "group" set owner_user_id = null where group_id = :'groupID';
+
 -- Should block deleting the last accepted member
 select throws_ok(
     format(
@@ -0,0 +1,117 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0a40000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0a40000-0000-0000-0000-000000000002'
+\set groupID 'c0a40000-0000-0000-0000-000000000003'
+\set otherGroupID 'c0a40000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cloud-native-valencia',
+    'Cloud Native Valencia',
+    'Community for cloud native technologies in Valencia',
+    'https://example.com/banner_mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group Valencia', 'group-valencia'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Other Group', 'other-group');
+
+-- Zoom credentials
+insert into group_zoom_credentials (group_id, account_id, client_id, client_secret, host_user)
+values (:'groupID', 'account-1', 'client-1', 'secret-1', 'host@example.com');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not fail when the group has no credentials
+select lives_ok(
+    format(
+        $$select delete_group_zoom_credentials(null::uuid, %L::uuid)$$,
+        :'otherGroupID'
+    ),
+    'Should not fail when the group has no credentials'
+);
+
+-- Should delete the group credentials
+select lives_ok(
+    format(
+        $$select delete_group_zoom_credentials(null::uuid, %L::uuid)$$,
+        :'groupID'
+    ),
+    'Should delete the group credentials without error'
+);
+
+select is(
+    (select count(*) from group_zoom_credentials),
+    0::bigint,
+    'Should remove the group credentials'
+);
+
+-- Should only audit deletions of existing credentials
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'group_zoom_credentials_deleted',
+            null::uuid,
+            null::text,
+            %L::uuid,
+            %L::uuid,
+            'group',
+            %L::uuid
+        )
+        $$,
+        :'communityID', :'groupID', :'groupID'
+    ),
+    'Should only audit deletions of existing credentials'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,149 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendeeID 'c0e30000-0000-0000-0000-000000000001'
+\set communityID 'c0e30000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0e30000-0000-0000-0000-000000000003'
+\set eventID 'c0e30000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0e30000-0000-0000-0000-000000000005'
+\set groupID 'c0e30000-0000-0000-0000-000000000006'
+\set pendingTicketCode 'c0e30000-0000-0000-0000-000000000007'
+\set pendingUserID 'c0e30000-0000-0000-0000-000000000008'
+\set ticketCode 'c0e30000-0000-0000-0000-000000000009'
+\set ticketTypeID 'c0e30000-0000-0000-0000-00000000000b'
+\set unknownGroupID 'c0e30000-0000-0000-0000-00000000000a'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-1', 'attendee@example.com', true, 'Attendee', :'attendeeID', 'attendee'),
+    ('hash-2', 'pending@example.com', true, 'Pending', :'pendingUserID', 'pending');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values
+    (
+        :'eventID',
+        'Event',
+        'event',
+        'Event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    );
+
+-- Attendees
+insert into event_attendee (event_id, user_id, status, checked_in, ticket_code) values
+    (:'eventID', :'attendeeID', 'confirmed', true, :'ticketCode'),
+    (:'eventID', :'pendingUserID', 'invitation-pending', false, :'pendingTicketCode');
+
+-- Free ticket type
+insert into event_ticket_type (event_ticket_type_id, event_id, "order", seats_total, title)
+values (:'ticketTypeID', :'eventID', 1, 10, 'Student');
+
+-- Purchases
+insert into event_purchase (
+    amount_minor,
+    event_id,
+    event_ticket_type_id,
+    status,
+    ticket_title,
+    user_id
+) values (
+    0,
+    :'eventID',
+    :'ticketTypeID',
+    'completed',
+    'Student',
+    :'attendeeID'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the attendee owning the ticket code
+select is(
+    get_event_attendee_by_ticket_code(:'groupID'::uuid, :'eventID'::uuid, :'ticketCode'::uuid)::jsonb,
+    jsonb_build_object(
+        'checked_in', true,
+        'name', 'Attendee',
+        'ticket_title', 'Student',
+        'user_id', :'attendeeID'::uuid,
+        'username', 'attendee'
+    ),
+    'Should return the attendee owning the ticket code'
+);
+
+-- Should return null for tickets of users that are not confirmed attendees
+select ok(
+    get_event_attendee_by_ticket_code(:'groupID'::uuid, :'eventID'::uuid, :'pendingTicketCode'::uuid) is null,
+    'Should return null for tickets of users that are not confirmed attendees'
+);
+
+-- Should return null for events in other groups
+select ok(
+    get_event_attendee_by_ticket_code(:'unknownGroupID'::uuid, :'eventID'::uuid, :'ticketCode'::uuid) is null,
+    'Should return null for events in other groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,88 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1330000-0000-0000-0000-000000000021'
+\set communityID 'c1330000-0000-0000-0000-000000000022'
+\set eventTemplateID 'c1330000-0000-0000-0000-000000000023'
+\set groupCategoryID 'c1330000-0000-0000-0000-000000000024'
+\set groupID 'c1330000-0000-0000-0000-000000000025'
+\set otherGroupID 'c1330000-0000-0000-0000-000000000026'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Event template
+insert into event_template (event_template_id, group_id, name, details, created_by)
+values (
+    :'eventTemplateID',
+    :'groupID',
+    'Monthly Meetup',
+    '{"kind": "in-person", "name": "Monthly Meetup", "sessions": [{"kind": "in-person", "name": "Welcome"}]}',
+    :'actorID'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the template details
+select is(
+    get_event_template_details(:'groupID'::uuid, :'eventTemplateID'::uuid)::jsonb,
+    '{"kind": "in-person", "name": "Monthly Meetup", "sessions": [{"kind": "in-person", "name": "Welcome"}]}'::jsonb,
+    'Should return the template details'
+);
+
+-- Should return null for templates from other groups
+select is(
+    get_event_template_details(:'otherGroupID'::uuid, :'eventTemplateID'::uuid)::jsonb,
+    null,
+    'Should return null for templates from other groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
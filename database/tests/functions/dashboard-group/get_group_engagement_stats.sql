@@ -0,0 +1,308 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set canceledEventID 'c1220000-0000-0000-0000-000000000001'
+\set communityID 'c1220000-0000-0000-0000-000000000002'
+\set event1ID 'c1220000-0000-0000-0000-000000000003'
+\set event2ID 'c1220000-0000-0000-0000-000000000004'
+\set eventCategoryID 'c1220000-0000-0000-0000-000000000005'
+\set group1ID 'c1220000-0000-0000-0000-000000000006'
+\set group2ID 'c1220000-0000-0000-0000-000000000007'
+\set groupCategoryID 'c1220000-0000-0000-0000-000000000008'
+\set nonExistentGroupID 'c1220000-0000-0000-0000-000000000009'
+\set subgroupEventID 'c1220000-0000-0000-0000-000000000010'
+\set user1ID 'c1220000-0000-0000-0000-000000000011'
+\set user2ID 'c1220000-0000-0000-0000-000000000012'
+\set user3ID 'c1220000-0000-0000-0000-000000000013'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'engagement-community',
+    'Engagement Community',
+    'Community used for group engagement stats tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, parent_group_id) values
+    (:'group1ID', :'communityID', :'groupCategoryID', 'Group One', 'group-one', null),
+    (:'group2ID', :'communityID', :'groupCategoryID', 'Group Two', 'group-two', :'group1ID');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, username) values
+    (:'user1ID', 'hash-1', 'user1@example.com', 'user1'),
+    (:'user2ID', 'hash-2', 'user2@example.com', 'user2'),
+    (:'user3ID', 'hash-3', 'user3@example.com', 'user3');
+
+-- Events (month -2 and month -1 relative to current date)
+insert into event (
+    event_id,
+    group_id,
+    event_category_id,
+    event_kind_id,
+    name,
+    slug,
+    description,
+    timezone,
+    published,
+    canceled,
+    starts_at
+) values
+    (
+        :'event1ID',
+        :'group1ID',
+        :'eventCategoryID',
+        'in-person',
+        'Event One',
+        'event-one',
+        'First event',
+        'UTC',
+        true,
+        false,
+        date_trunc('month', current_timestamp at time zone 'UTC') - interval '2 months' + interval '15 days'
+    ), (
+        :'event2ID',
+        :'group1ID',
+        :'eventCategoryID',
+        'in-person',
+        'Event Two',
+        'event-two',
+        'Second event',
+        'UTC',
+        true,
+        false,
+        date_trunc('month', current_timestamp at time zone 'UTC') - interval '1 month' + interval '10 days'
+    ), (
+        :'subgroupEventID',
+        :'group2ID',
+        :'eventCategoryID',
+        'in-person',
+        'Subgroup Event',
+        'subgroup-event',
+        'Subgroup event',
+        'UTC',
+        true,
+        false,
+        date_trunc('month', current_timestamp at time zone 'UTC') - interval '1 month' + interval '20 days'
+    ), (
+        :'canceledEventID',
+        :'group1ID',
+        :'eventCategoryID',
+        'in-person',
+        'Canceled Event',
+        'canceled-event',
+        'Canceled event',
+        'UTC',
+        true,
+        true,
+        date_trunc('month', current_timestamp at time zone 'UTC') - interval '1 month' + interval '5 days'
+    );
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in, checked_in_at)
+select
+    ea.event_id,
+    ea.user_id,
+    ea.checked_in,
+    case when ea.checked_in then e.starts_at end
+from (
+    values
+        (:'event1ID'::uuid, :'user1ID'::uuid, true),
+        (:'event1ID'::uuid, :'user2ID'::uuid, false),
+        (:'event2ID'::uuid, :'user1ID'::uuid, true),
+        (:'event2ID'::uuid, :'user3ID'::uuid, true),
+        (:'subgroupEventID'::uuid, :'user2ID'::uuid, true),
+        (:'canceledEventID'::uuid, :'user3ID'::uuid, true)
+) as ea(event_id, user_id, checked_in)
+join event e on e.event_id = ea.event_id;
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return engagement stats for the group
+select is(
+    get_group_engagement_stats(:'communityID'::uuid, :'group1ID'::uuid, false)::jsonb,
+    (
+        with
+        -- Define the months used in test data relative to current_timestamp at UTC
+        months as (
+            select
+                date_trunc('month', current_timestamp at time zone 'UTC') - interval '1 month' as m1,
+                date_trunc('month', current_timestamp at time zone 'UTC') - interval '2 months' as m2
+        )
+        select jsonb_build_object(
+            'check_ins', jsonb_build_object(
+                'total', 3,
+                'running_total', jsonb_build_array(
+                    jsonb_build_array(
+                        (extract(epoch from m2 at time zone 'UTC') * 1000)::bigint,
+                        1
+                    ),
+                    jsonb_build_array(
+                        (extract(epoch from m1 at time zone 'UTC') * 1000)::bigint,
+                        3
+                    )
+                ),
+                'per_month', jsonb_build_array(
+                    jsonb_build_array(to_char(m2, 'YYYY-MM'), 1),
+                    jsonb_build_array(to_char(m1, 'YYYY-MM'), 2)
+                )
+            ),
+            'retention', jsonb_build_object(
+                'per_month', jsonb_build_array(
+                    jsonb_build_array(to_char(m1, 'YYYY-MM'), 1)
+                ),
+                'repeat_attendees', 1,
+                'unique_attendees', 3
+            ),
+            'top_events', jsonb_build_array(
+                jsonb_build_object(
+                    'attendees', 2,
+                    'check_ins', 2,
+                    'event_id', :'event2ID',
+                    'group_name', 'Group One',
+                    'name', 'Event Two',
+                    'starts_at', (select extract(epoch from starts_at)::bigint from event where event_id = :'event2ID')
+                ),
+                jsonb_build_object(
+                    'attendees', 2,
+                    'check_ins', 1,
+                    'event_id', :'event1ID',
+                    'group_name', 'Group One',
+                    'name', 'Event One',
+                    'starts_at', (select extract(epoch from starts_at)::bigint from event where event_id = :'event1ID')
+                )
+            )
+        )
+        from months
+    ),
+    'Should return engagement stats for the group'
+);
+
+-- Should include active subgroups engagement when requested
+select is(
+    get_group_engagement_stats(:'communityID'::uuid, :'group1ID'::uuid, true)::jsonb,
+    (
+        with
+        -- Define the months used in test data relative to current_timestamp at UTC
+        months as (
+            select
+                date_trunc('month', current_timestamp at time zone 'UTC') - interval '1 month' as m1,
+                date_trunc('month', current_timestamp at time zone 'UTC') - interval '2 months' as m2
+        )
+        select jsonb_build_object(
+            'check_ins', jsonb_build_object(
+                'total', 4,
+                'running_total', jsonb_build_array(
+                    jsonb_build_array(
+                        (extract(epoch from m2 at time zone 'UTC') * 1000)::bigint,
+                        1
+                    ),
+                    jsonb_build_array(
+                        (extract(epoch from m1 at time zone 'UTC') * 1000)::bigint,
+                        4
+                    )
+                ),
+                'per_month', jsonb_build_array(
+                    jsonb_build_array(to_char(m2, 'YYYY-MM'), 1),
+                    jsonb_build_array(to_char(m1, 'YYYY-MM'), 3)
+                )
+            ),
+            'retention', jsonb_build_object(
+                'per_month', jsonb_build_array(
+                    jsonb_build_array(to_char(m1, 'YYYY-MM'), 2)
+                ),
+                'repeat_attendees', 2,
+                'unique_attendees', 3
+            ),
+            'top_events', jsonb_build_array(
+                jsonb_build_object(
+                    'attendees', 2,
+                    'check_ins', 2,
+                    'event_id', :'event2ID',
+                    'group_name', 'Group One',
+                    'name', 'Event Two',
+                    'starts_at', (select extract(epoch from starts_at)::bigint from event where event_id = :'event2ID')
+                ),
+                jsonb_build_object(
+                    'attendees', 2,
+                    'check_ins', 1,
+                    'event_id', :'event1ID',
+                    'group_name', 'Group One',
+                    'name', 'Event One',
+                    'starts_at', (select extract(epoch from starts_at)::bigint from event where event_id = :'event1ID')
+                ),
+                jsonb_build_object(
+                    'attendees', 1,
+                    'check_ins', 1,
+                    'event_id', :'subgroupEventID',
+                    'group_name', 'Group Two',
+                    'name', 'Subgroup Event',
+                    'starts_at', (select extract(epoch from starts_at)::bigint from event where event_id = :'subgroupEventID')
+                )
+            )
+        )
+        from months
+    ),
+    'Should include active subgroups engagement when requested'
+);
+
+-- Should return empty stats for unknown group
+select is(
+    get_group_engagement_stats(:'communityID'::uuid, :'nonExistentGroupID'::uuid, false)::jsonb,
+    $$
+    {
+        "check_ins": {
+            "total": 0,
+            "running_total": [],
+            "per_month": []
+        },
+        "retention": {
+            "per_month": [],
+            "repeat_attendees": 0,
+            "unique_attendees": 0
+        },
+        "top_events": []
+    }
+    $$,
+    'Should return empty stats for unknown group'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
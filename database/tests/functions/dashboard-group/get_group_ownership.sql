@@ -0,0 +1,96 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c10c0000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c10c0000-0000-0000-0000-000000000002'
+\set groupID 'c10c0000-0000-0000-0000-000000000003'
+\set newOwnerID 'c10c0000-0000-0000-0000-000000000004'
+\set ownerID 'c10c0000-0000-0000-0000-000000000005'
+\set pendingMemberID 'c10c0000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'group-ownership-community',
+    'Group Ownership Community',
+    'Community for group ownership tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, name) values
+    (:'newOwnerID', gen_random_bytes(32), 'bob@example.com', true, 'bob', 'Bob'),
+    (:'ownerID', gen_random_bytes(32), 'alice@example.com', true, 'alice', 'Alice'),
+    (:'pendingMemberID', gen_random_bytes(32), 'carol@example.com', true, 'carol', 'Carol');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, owner_user_id)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Ownership Group', 'ownership-group', :'ownerID');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted) values
+    (:'groupID', :'newOwnerID', 'viewer', true),
+    (:'groupID', :'ownerID', 'admin', true),
+    (:'groupID', :'pendingMemberID', 'admin', false);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the owner and the accepted team members as candidates
+select is(
+    get_group_ownership(:'groupID'::uuid)::jsonb,
+    format('{
+        "candidates": [
+            {"name": "Bob", "user_id": "%s", "username": "bob"}
+        ],
+        "owner": {"name": "Alice", "user_id": "%s", "username": "alice"}
+    }', :'newOwnerID', :'ownerID')::jsonb,
+    'Should return the owner and the accepted team members as candidates'
+);
+
+-- Should include the pending ownership transfer
+insert into group_ownership_transfer (group_id, created_at, created_by, user_id)
+values (:'groupID', '2024-01-01 00:00:00+00', :'ownerID', :'newOwnerID');
+
+select is(
+    get_group_ownership(:'groupID'::uuid)::jsonb->'pending_transfer',
+    format('{
+        "created_at": 1704067200,
+        "user": {"name": "Bob", "user_id": "%s", "username": "bob"}
+    }', :'newOwnerID')::jsonb,
+    'Should include the pending ownership transfer'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,94 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0a20000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0a20000-0000-0000-0000-000000000002'
+\set groupID 'c0a20000-0000-0000-0000-000000000003'
+\set otherGroupID 'c0a20000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cloud-native-porto',
+    'Cloud Native Porto',
+    'Community for cloud native technologies in Porto',
+    'https://example.com/banner_mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group Porto', 'group-porto'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Other Group', 'other-group');
+
+-- Zoom credentials
+insert into group_zoom_credentials (
+    group_id,
+    account_id,
+    client_id,
+    client_secret,
+    host_user,
+    webhook_secret_token
+) values (
+    :'groupID',
+    'account-1',
+    'client-1',
+    'secret-1',
+    'host@example.com',
+    'webhook-secret-1'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the account details without the secrets
+select is(
+    get_group_zoom_account(:'groupID'::uuid)::jsonb,
+    '{
+        "account_id": "account-1",
+        "client_id": "client-1",
+        "host_user": "host@example.com",
+        "webhook_secret_token_set": true
+    }'::jsonb,
+    'Should return the account details without the secrets'
+);
+
+-- Should return null when the group has no credentials
+select ok(
+    get_group_zoom_account(:'otherGroupID'::uuid) is null,
+    'Should return null when the group has no credentials'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
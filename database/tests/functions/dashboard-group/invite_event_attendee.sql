@@ -3,13 +3,14 @@
 -- ============================================================================
 
 begin;
-select plan(23);
+select plan(24);
 
 -- ============================================================================
 -- VARIABLES
 -- ============================================================================
 
 \set actorID '3a130000-0000-0000-0000-000000000001'
+\set bannedUserID '3a130000-0000-0000-0000-00000000001a'
 \set canceledAttendeeUserID '3a130000-0000-0000-0000-000000000019'
 \set canceledEventID '3a130000-0000-0000-0000-000000000002'
 \set communityID '3a130000-0000-0000-0000-000000000003'
@@ -68,6 +69,7 @@ values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-grou
 insert into "user" (auth_hash, email, email_verified, name, user_id, username)
 values
     ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor'),
+    ('hash-banned', 'banned@example.com', true, 'Banned', :'bannedUserID', 'banned'),
     ('hash-canceled-attendee', 'canceled-attendee@example.com', true, 'Canceled', :'canceledAttendeeUserID', 'canceled-attendee'),
     ('hash-confirmed', 'confirmed@example.com', true, 'Confirmed', :'confirmedAttendeeUserID', 'confirmed'),
     ('hash-registered', 'registered@example.com', true, 'Registered', :'registeredUserID', 'registered'),
@@ -210,6 +212,10 @@ insert into event_attendee (
 insert into event_waitlist (event_id, user_id)
 values (:'eventID', :'waitlistedUserID');
 
+-- Group ban
+insert into group_ban (group_id, user_id, reason)
+values (:'groupID', :'bannedUserID', 'Spam');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -450,6 +456,17 @@ select throws_ok(
     'Should reject re-inviting a user that rejected an event invitation'
 );
 
+-- Should reject inviting users banned from the group.
+select throws_ok(
+    format(
+        $$ select invite_event_attendee(%L, %L, %L, %L, null) $$,
+        :'actorID', :'groupID', :'eventID', :'bannedUserID'
+    ),
+    'P0001',
+    'user is banned from this group',
+    'Should reject inviting users banned from the group'
+);
+
 -- Should reject ticketed events.
 select throws_ok(
     format(
@@ -0,0 +1,233 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(9);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0c40000-0000-0000-0000-000000000001'
+\set communityID 'c0c40000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0c40000-0000-0000-0000-000000000003'
+\set groupID 'c0c40000-0000-0000-0000-000000000004'
+\set registeredUserID 'c0c40000-0000-0000-0000-000000000005'
+\set unverifiedUserID 'c0c40000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor'),
+    ('hash-registered', 'registered@example.com', true, 'Registered', :'registeredUserID', 'registered'),
+    ('hash-unverified', 'unverified@example.com', false, 'Unverified', :'unverifiedUserID', 'unverified');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted)
+values (:'groupID', :'actorID', 'admin', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should invite a registered user by email
+select is(
+    invite_group_team_member(
+        :'actorID'::uuid,
+        :'groupID'::uuid,
+        ' Registered@Example.com ',
+        'events-manager',
+        'token-hash-registered'
+    ),
+    :'registeredUserID'::uuid,
+    'Should return the registered user invited'
+);
+
+-- Should create a pending membership with the role provided
+select results_eq(
+    format(
+        $$
+            select accepted, role
+            from group_team
+            where group_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'groupID',
+        :'registeredUserID'
+    ),
+    $$ values (false, 'events-manager') $$,
+    'Should create a pending membership with the role provided'
+);
+
+-- Should store the link to accept the invitation
+select results_eq(
+    $$
+        select
+            group_id,
+            user_id,
+            expires_at > current_timestamp + interval '6 days'
+        from group_team_invitation_link
+        where token_hash = 'token-hash-registered'
+    $$,
+    format(
+        $$ values (%L::uuid, %L::uuid, true) $$,
+        :'groupID',
+        :'registeredUserID'
+    ),
+    'Should store the link to accept the invitation'
+);
+
+-- Should track the created membership
+select results_eq(
+    format(
+        $$
+            select action, actor_user_id, community_id, group_id, resource_id, details
+            from audit_log
+            where resource_id = %L::uuid
+        $$,
+        :'registeredUserID'
+    ),
+    format(
+        $$ values (
+            'group_team_member_added',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            '{"role": "events-manager"}'::jsonb
+        ) $$,
+        :'actorID',
+        :'communityID',
+        :'groupID',
+        :'registeredUserID'
+    ),
+    'Should track the created membership'
+);
+
+-- Should pre-register emails without an account
+select lives_ok(
+    format(
+        $$
+            select invite_group_team_member(
+                %L::uuid,
+                %L::uuid,
+                'new-member@example.com',
+                'viewer',
+                'token-hash-new'
+            )
+        $$,
+        :'actorID',
+        :'groupID'
+    ),
+    'Should invite emails without an account'
+);
+select results_eq(
+    format(
+        $$
+            select u.email_verified, u.registration_status, gt.accepted
+            from "user" u
+            join group_team gt using (user_id)
+            where u.email = 'new-member@example.com'
+            and gt.group_id = %L::uuid
+        $$,
+        :'groupID'
+    ),
+    $$ values (false, 'pre-registered', false) $$,
+    'Should pre-register the invitee with a pending membership'
+);
+
+-- Should reject users already in the team
+select throws_ok(
+    format(
+        $$
+            select invite_group_team_member(
+                %L::uuid,
+                %L::uuid,
+                'registered@example.com',
+                'viewer',
+                'token-hash-duplicate'
+            )
+        $$,
+        :'actorID',
+        :'groupID'
+    ),
+    'user is already a group team member',
+    'Should reject users already in the team'
+);
+
+-- Should reject registered users with unverified emails
+select throws_ok(
+    format(
+        $$
+            select invite_group_team_member(
+                %L::uuid,
+                %L::uuid,
+                'unverified@example.com',
+                'viewer',
+                'token-hash-unverified'
+            )
+        $$,
+        :'actorID',
+        :'groupID'
+    ),
+    'registered user email is not verified',
+    'Should reject registered users with unverified emails'
+);
+
+-- Should reject blank emails
+select throws_ok(
+    format(
+        $$
+            select invite_group_team_member(
+                %L::uuid,
+                %L::uuid,
+                '  ',
+                'viewer',
+                'token-hash-blank'
+            )
+        $$,
+        :'actorID',
+        :'groupID'
+    ),
+    'email is required',
+    'Should reject blank emails'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(18);
+select plan(19);
 
 -- ============================================================================
 -- VARIABLES
@@ -283,6 +283,32 @@ select is(
     'Event recording preference change desyncs meeting'
 );
 
+-- Webinar mode change desyncs meeting
+select is(
+    is_event_meeting_in_sync(
+        '{
+            "name": "Sync Event",
+            "timezone": "America/New_York",
+            "kind": "virtual",
+            "starts_at": 1748786400,
+            "ends_at": 1748790000,
+            "meeting_requested": true,
+            "meeting_webinar": false
+        }'::jsonb,
+        '{
+            "name": "Sync Event",
+            "timezone": "America/New_York",
+            "kind_id": "virtual",
+            "starts_at": "2025-06-01T10:00:00",
+            "ends_at": "2025-06-01T11:00:00",
+            "meeting_requested": true,
+            "meeting_webinar": true
+        }'::jsonb
+    ),
+    false,
+    'Event webinar mode change desyncs meeting'
+);
+
 -- meeting_hosts unchanged keeps sync
 select is(
     is_event_meeting_in_sync(
@@ -0,0 +1,166 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendee1ID 'c0e70000-0000-0000-0000-000000000001'
+\set attendee2ID 'c0e70000-0000-0000-0000-000000000002'
+\set communityID 'c0e70000-0000-0000-0000-000000000003'
+\set emptyEventID 'c0e70000-0000-0000-0000-000000000004'
+\set eventCategoryID 'c0e70000-0000-0000-0000-000000000005'
+\set eventID 'c0e70000-0000-0000-0000-000000000006'
+\set eventTicketTypeID 'c0e70000-0000-0000-0000-000000000007'
+\set groupCategoryID 'c0e70000-0000-0000-0000-000000000008'
+\set groupID 'c0e70000-0000-0000-0000-000000000009'
+\set pendingUserID 'c0e70000-0000-0000-0000-00000000000a'
+\set ticketCode1 'c0e70000-0000-0000-0000-00000000000b'
+\set ticketCode2 'c0e70000-0000-0000-0000-00000000000c'
+\set unknownGroupID 'c0e70000-0000-0000-0000-00000000000d'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, company, email, email_verified, name, user_id, username)
+values
+    ('hash-1', 'Acme', 'zoe@example.com', true, 'Zoe', :'attendee1ID', 'zoe'),
+    ('hash-2', null, 'alice@example.com', true, null, :'attendee2ID', 'alice'),
+    ('hash-3', null, 'pending@example.com', true, 'Pending', :'pendingUserID', 'pending');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values
+    (
+        :'eventID',
+        'Event',
+        'event',
+        'Event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    ),
+    (
+        :'emptyEventID',
+        'Empty Event',
+        'empty-event',
+        'Event without attendees',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    );
+
+-- Ticket types
+insert into event_ticket_type (event_ticket_type_id, event_id, "order", seats_total, title)
+values (:'eventTicketTypeID', :'eventID', 1, 100, 'General admission');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, status, ticket_code) values
+    (:'eventID', :'attendee1ID', 'confirmed', :'ticketCode1'),
+    (:'eventID', :'attendee2ID', 'confirmed', :'ticketCode2'),
+    (:'eventID', :'pendingUserID', 'invitation-pending', gen_random_uuid());
+
+-- Purchases
+insert into event_purchase (
+    amount_minor,
+    currency_code,
+    event_id,
+    event_ticket_type_id,
+    status,
+    ticket_title,
+    user_id
+) values
+    (2500, 'USD', :'eventID', :'eventTicketTypeID', 'completed', 'General admission', :'attendee1ID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the badges of the confirmed attendees sorted by name
+select is(
+    list_event_attendee_badges(:'groupID'::uuid, :'eventID'::uuid)::jsonb,
+    jsonb_build_array(
+        jsonb_build_object(
+            'name', 'alice',
+            'ticket_code', :'ticketCode2'::uuid
+        ),
+        jsonb_build_object(
+            'company', 'Acme',
+            'name', 'Zoe',
+            'ticket_code', :'ticketCode1'::uuid,
+            'ticket_title', 'General admission'
+        )
+    ),
+    'Should return the badges of the confirmed attendees sorted by name'
+);
+
+-- Should return an empty list for events without attendees
+select is(
+    list_event_attendee_badges(:'groupID'::uuid, :'emptyEventID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for events without attendees'
+);
+
+-- Should return an empty list for events in other groups
+select is(
+    list_event_attendee_badges(:'unknownGroupID'::uuid, :'eventID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for events in other groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
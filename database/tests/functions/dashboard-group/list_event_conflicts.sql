@@ -0,0 +1,212 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set canceledEventID 'c0ee0000-0000-0000-0000-000000000001'
+\set communityID 'c0ee0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0ee0000-0000-0000-0000-000000000003'
+\set group1ID 'c0ee0000-0000-0000-0000-000000000004'
+\set group2ID 'c0ee0000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0ee0000-0000-0000-0000-000000000006'
+\set otherVenueEventID 'c0ee0000-0000-0000-0000-000000000007'
+\set sameGroupEventID 'c0ee0000-0000-0000-0000-000000000008'
+\set sameVenueEventID 'c0ee0000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'event-conflicts-community',
+    'Event Conflicts Community',
+    'Community for event conflicts tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'group1ID', :'communityID', :'groupCategoryID', 'Group One', 'group-one'),
+    (:'group2ID', :'communityID', :'groupCategoryID', 'Group Two', 'group-two');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone,
+    canceled,
+    published,
+    starts_at,
+    ends_at,
+    venue_city,
+    venue_name
+) values
+    (
+        :'sameGroupEventID',
+        :'group1ID',
+        'Group Meetup',
+        'group-meetup',
+        'Draft event from the same group',
+        :'eventCategoryID',
+        'in-person',
+        'UTC',
+        false,
+        false,
+        '2030-01-01 10:00:00+00',
+        '2030-01-01 12:00:00+00',
+        null,
+        null
+    ),
+    (
+        :'canceledEventID',
+        :'group1ID',
+        'Canceled Meetup',
+        'canceled-meetup',
+        'Canceled event from the same group',
+        :'eventCategoryID',
+        'in-person',
+        'UTC',
+        true,
+        false,
+        '2030-01-01 10:00:00+00',
+        '2030-01-01 12:00:00+00',
+        null,
+        null
+    ),
+    (
+        :'sameVenueEventID',
+        :'group2ID',
+        'Venue Workshop',
+        'venue-workshop',
+        'Event from another group at the same venue',
+        :'eventCategoryID',
+        'in-person',
+        'UTC',
+        false,
+        true,
+        '2030-01-01 11:30:00+00',
+        null,
+        'Madrid',
+        'Main Hall'
+    ),
+    (
+        :'otherVenueEventID',
+        :'group2ID',
+        'Other Venue Talk',
+        'other-venue-talk',
+        'Event from another group at a different venue',
+        :'eventCategoryID',
+        'in-person',
+        'UTC',
+        false,
+        true,
+        '2030-01-01 11:00:00+00',
+        '2030-01-01 12:00:00+00',
+        'Madrid',
+        'Side Room'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return overlapping events from the same group and the same venue
+select is(
+    list_event_conflicts(
+        :'group1ID'::uuid,
+        null,
+        '{
+            "starts_at": "2030-01-01T11:00:00",
+            "ends_at": "2030-01-01T13:00:00",
+            "timezone": "UTC",
+            "venue_city": "Madrid",
+            "venue_name": " main hall "
+        }'::jsonb
+    )::jsonb,
+    format('[
+        {
+            "event_id": "%s",
+            "group_name": "Group One",
+            "name": "Group Meetup",
+            "same_group": true,
+            "starts_at": 1893492000
+        },
+        {
+            "event_id": "%s",
+            "group_name": "Group Two",
+            "name": "Venue Workshop",
+            "same_group": false,
+            "starts_at": 1893497400
+        }
+    ]', :'sameGroupEventID', :'sameVenueEventID')::jsonb,
+    'Should return overlapping events from the same group and the same venue'
+);
+
+-- Should exclude the event being updated
+select is(
+    list_event_conflicts(
+        :'group1ID'::uuid,
+        :'sameGroupEventID'::uuid,
+        '{
+            "starts_at": "2030-01-01T11:00:00",
+            "ends_at": "2030-01-01T13:00:00",
+            "timezone": "UTC"
+        }'::jsonb
+    )::jsonb,
+    '[]'::jsonb,
+    'Should exclude the event being updated'
+);
+
+-- Should return an empty list when no events overlap
+select is(
+    list_event_conflicts(
+        :'group1ID'::uuid,
+        null,
+        '{
+            "starts_at": "2030-01-02T11:00:00",
+            "timezone": "UTC",
+            "venue_city": "Madrid",
+            "venue_name": "Main Hall"
+        }'::jsonb
+    )::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when no events overlap'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
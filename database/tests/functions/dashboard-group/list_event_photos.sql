@@ -0,0 +1,164 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0d40000-0000-0000-0000-000000000001'
+\set approvedPhotoID 'c0d40000-0000-0000-0000-000000000010'
+\set attendeeID 'c0d40000-0000-0000-0000-000000000002'
+\set communityID 'c0d40000-0000-0000-0000-000000000003'
+\set eventCategoryID 'c0d40000-0000-0000-0000-000000000004'
+\set eventID 'c0d40000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0d40000-0000-0000-0000-000000000006'
+\set groupID 'c0d40000-0000-0000-0000-000000000007'
+\set photoID 'c0d40000-0000-0000-0000-000000000008'
+\set unknownGroupID 'c0d40000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor'),
+    ('hash-attendee', 'attendee@example.com', true, 'Attendee', :'attendeeID', 'attendee');
+
+-- Event
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    attendee_photos_enabled,
+    starts_at,
+    ends_at
+) values (
+    :'eventID',
+    'Past Event',
+    'past-event',
+    'Past event accepting photos',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    true,
+    true,
+    current_timestamp - interval '2 days',
+    current_timestamp - interval '2 days' + interval '2 hours'
+);
+
+-- Event photos
+insert into event_photo (
+    event_photo_id,
+    event_id,
+    url,
+    user_id,
+    created_at,
+    status,
+    reviewed_at,
+    reviewed_by
+) values
+    (
+        :'photoID',
+        :'eventID',
+        '/images/photo.png',
+        :'attendeeID',
+        '2024-01-01 10:00:00+00',
+        'pending',
+        null,
+        null
+    ),
+    (
+        :'approvedPhotoID',
+        :'eventID',
+        '/images/approved.png',
+        :'attendeeID',
+        '2024-01-02 10:00:00+00',
+        'approved',
+        '2024-01-03 10:00:00+00',
+        :'actorID'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list pending photos first
+select is(
+    list_event_photos(:'groupID'::uuid, :'eventID'::uuid)::jsonb,
+    format('[
+        {
+            "created_at": 1704103200,
+            "event_photo_id": "%s",
+            "status": "pending",
+            "url": "/images/photo.png",
+            "user": {"name": "Attendee", "user_id": "%s", "username": "attendee"}
+        },
+        {
+            "created_at": 1704189600,
+            "event_photo_id": "%s",
+            "reviewed_at": 1704276000,
+            "status": "approved",
+            "url": "/images/approved.png",
+            "user": {"name": "Attendee", "user_id": "%s", "username": "attendee"}
+        }
+    ]', :'photoID', :'attendeeID', :'approvedPhotoID', :'attendeeID')::jsonb,
+    'Should list pending photos first'
+);
+
+-- Should return an empty list for events from other groups
+select is(
+    list_event_photos(:'unknownGroupID'::uuid, :'eventID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for events from other groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
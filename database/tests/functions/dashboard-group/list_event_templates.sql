@@ -0,0 +1,117 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1330000-0000-0000-0000-000000000031'
+\set communityID 'c1330000-0000-0000-0000-000000000032'
+\set eventTemplate1ID 'c1330000-0000-0000-0000-000000000033'
+\set eventTemplate2ID 'c1330000-0000-0000-0000-000000000034'
+\set groupCategoryID 'c1330000-0000-0000-0000-000000000035'
+\set groupID 'c1330000-0000-0000-0000-000000000036'
+\set otherGroupID 'c1330000-0000-0000-0000-000000000037'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Event templates
+insert into event_template (event_template_id, group_id, name, details, created_by, created_at) values
+    (
+        :'eventTemplate1ID',
+        :'groupID',
+        'workshop',
+        '{"kind": "virtual"}',
+        null,
+        '2030-01-02 00:00:00+00'
+    ),
+    (
+        :'eventTemplate2ID',
+        :'groupID',
+        'Monthly Meetup',
+        '{"kind": "in-person", "sessions": [{"name": "Welcome"}, {"name": "Talks"}]}',
+        :'actorID',
+        '2030-01-01 00:00:00+00'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list the group templates sorted by name
+select is(
+    list_event_templates(:'groupID'::uuid)::jsonb,
+    format(
+        '[
+            {
+                "created_at": 1893456000,
+                "created_by_username": "actor",
+                "event_template_id": "%s",
+                "kind": "in-person",
+                "name": "Monthly Meetup",
+                "sessions_count": 2
+            },
+            {
+                "created_at": 1893542400,
+                "event_template_id": "%s",
+                "kind": "virtual",
+                "name": "workshop",
+                "sessions_count": 0
+            }
+        ]',
+        :'eventTemplate2ID', :'eventTemplate1ID'
+    )::jsonb,
+    'Should list the group templates sorted by name'
+);
+
+-- Should return an empty list for groups without templates
+select is(
+    list_event_templates(:'otherGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for groups without templates'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,99 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1010000-0000-0000-0000-000000000001'
+\set communityID 'c1010000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c1010000-0000-0000-0000-000000000003'
+\set groupID 'c1010000-0000-0000-0000-000000000004'
+\set newAnnouncementID 'c1010000-0000-0000-0000-000000000005'
+\set oldAnnouncementID 'c1010000-0000-0000-0000-000000000006'
+\set otherGroupID 'c1010000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Announcements
+insert into group_announcement (group_announcement_id, group_id, title, content, created_at, pinned) values
+    (:'oldAnnouncementID', :'groupID', 'Old', 'Old news', '2024-01-01 10:00:00+00', false),
+    (:'newAnnouncementID', :'groupID', 'New', 'Fresh news', '2024-02-01 10:00:00+00', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list all the group announcements, newest first
+select is(
+    list_group_announcements(:'groupID'::uuid)::jsonb,
+    format('[
+        {
+            "content": "Fresh news",
+            "created_at": 1706781600,
+            "group_announcement_id": "%s",
+            "pinned": true,
+            "title": "New"
+        },
+        {
+            "content": "Old news",
+            "created_at": 1704103200,
+            "group_announcement_id": "%s",
+            "pinned": false,
+            "title": "Old"
+        }
+    ]', :'newAnnouncementID', :'oldAnnouncementID')::jsonb,
+    'Should list all the group announcements, newest first'
+);
+
+-- Should return an empty list for groups without announcements
+select is(
+    list_group_announcements(:'otherGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for groups without announcements'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
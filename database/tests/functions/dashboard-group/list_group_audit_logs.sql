@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(7);
+select plan(8);
 
 -- ============================================================================
 -- VARIABLES
@@ -23,6 +23,7 @@ select plan(7);
 \set audit10ID '3a1f0000-0000-0000-0000-000000000111'
 \set audit11ID '3a1f0000-0000-0000-0000-000000000112'
 \set audit12ID '3a1f0000-0000-0000-0000-000000000113'
+\set audit13ID '3a1f0000-0000-0000-0000-000000000114'
 \set communityID '3a1f0000-0000-0000-0000-000000000001'
 \set eventCategoryID '3a1f0000-0000-0000-0000-000000000022'
 \set eventID '3a1f0000-0000-0000-0000-000000000051'
@@ -256,6 +257,18 @@ insert into audit_log (
         :'eventID',
         'event'
     ),
+    (
+        :'audit13ID',
+        'event_waitlist_user_removed',
+        :'actor1ID',
+        'alice',
+        :'communityID',
+        '2024-02-28 10:00:00+00',
+        '{"event_id": "3a1f0000-0000-0000-0000-000000000051", "user_id": "3a1f0000-0000-0000-0000-000000000041"}',
+        :'groupID',
+        :'targetUserID',
+        'user'
+    ),
     (
         :'wildcardAuditID',
         'group_updated',
@@ -391,10 +404,20 @@ select is(
                 "resource_id": "3a1f0000-0000-0000-0000-000000000031",
                 "resource_name": "Platform",
                 "resource_type": "group"
+            },
+            {
+                "action": "event_waitlist_user_removed",
+                "actor_username": "alice",
+                "audit_log_id": "3a1f0000-0000-0000-0000-000000000114",
+                "created_at": 1709114400,
+                "details": {"event_id": "3a1f0000-0000-0000-0000-000000000051", "user_id": "3a1f0000-0000-0000-0000-000000000041"},
+                "resource_id": "3a1f0000-0000-0000-0000-000000000041",
+                "resource_name": "Sara",
+                "resource_type": "user"
             }
         ]'::jsonb,
         'total',
-        11
+        12
     ),
     'Should return only group dashboard actions for the selected group'
 );
@@ -477,6 +500,32 @@ select is(
     'Should filter group audit logs by attendee attendance cancellation action'
 );
 
+-- Should filter group audit logs by waitlist user removal action
+select is(
+    list_group_audit_logs(
+        :'groupID'::uuid,
+        '{"action": "event_waitlist_user_removed", "limit": 50, "offset": 0, "sort": "created-desc"}'::jsonb
+    )::jsonb,
+    jsonb_build_object(
+        'logs',
+        '[
+            {
+                "action": "event_waitlist_user_removed",
+                "actor_username": "alice",
+                "audit_log_id": "3a1f0000-0000-0000-0000-000000000114",
+                "created_at": 1709114400,
+                "details": {"event_id": "3a1f0000-0000-0000-0000-000000000051", "user_id": "3a1f0000-0000-0000-0000-000000000041"},
+                "resource_id": "3a1f0000-0000-0000-0000-000000000041",
+                "resource_name": "Sara",
+                "resource_type": "user"
+            }
+        ]'::jsonb,
+        'total',
+        1
+    ),
+    'Should filter group audit logs by waitlist user removal action'
+);
+
 -- Should return group audit logs in ascending order with pagination
 select is(
     list_group_audit_logs(
@@ -0,0 +1,113 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1300000-0000-0000-0000-000000000001'
+\set communityID 'c1300000-0000-0000-0000-000000000002'
+\set emptyGroupID 'c1300000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c1300000-0000-0000-0000-000000000004'
+\set groupID 'c1300000-0000-0000-0000-000000000005'
+\set newerBannedUserID 'c1300000-0000-0000-0000-000000000006'
+\set olderBannedUserID 'c1300000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, name, photo_url)
+values
+    (:'actorID', 'hash1', 'actor@test.com', true, 'actor', null, null),
+    (:'newerBannedUserID', 'hash2', 'newer@test.com', true, 'newer', null, null),
+    (
+        :'olderBannedUserID',
+        'hash3',
+        'older@test.com',
+        true,
+        'older',
+        'Older Banned',
+        'https://example.com/older.png'
+    );
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'emptyGroupID', :'communityID', :'groupCategoryID', 'Empty Group', 'empty-group'),
+    (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group');
+
+-- Group bans
+insert into group_ban (group_id, user_id, created_at, created_by, reason)
+values
+    (:'groupID', :'newerBannedUserID', '2024-01-02 00:00:00+00', null, 'Spam'),
+    (:'groupID', :'olderBannedUserID', '2024-01-01 00:00:00+00', :'actorID', 'Harassment');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the users banned from the group, most recent first
+select is(
+    list_group_bans(:'groupID'::uuid)::jsonb,
+    format('[
+        {
+            "created_at": 1704153600,
+            "reason": "Spam",
+            "user_id": "%s",
+            "username": "newer"
+        },
+        {
+            "banned_by_username": "actor",
+            "created_at": 1704067200,
+            "name": "Older Banned",
+            "photo_url": "https://example.com/older.png",
+            "reason": "Harassment",
+            "user_id": "%s",
+            "username": "older"
+        }
+    ]', :'newerBannedUserID', :'olderBannedUserID')::jsonb,
+    'Should return the users banned from the group, most recent first'
+);
+
+-- Should return an empty list when the group has no banned users
+select is(
+    list_group_bans(:'emptyGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the group has no banned users'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
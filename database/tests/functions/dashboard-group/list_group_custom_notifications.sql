@@ -0,0 +1,217 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0b50000-0000-0000-0000-000000000001'
+\set customNotification1ID 'c0b50000-0000-0000-0000-000000000061'
+\set customNotification2ID 'c0b50000-0000-0000-0000-000000000062'
+\set customNotification3ID 'c0b50000-0000-0000-0000-000000000063'
+\set eventCategoryID 'c0b50000-0000-0000-0000-000000000022'
+\set eventID 'c0b50000-0000-0000-0000-000000000051'
+\set groupCategoryID 'c0b50000-0000-0000-0000-000000000021'
+\set groupID 'c0b50000-0000-0000-0000-000000000031'
+\set missingGroupID 'c0b50000-0000-0000-0000-000000000099'
+\set otherGroupID 'c0b50000-0000-0000-0000-000000000032'
+\set recipientID 'c0b50000-0000-0000-0000-000000000042'
+\set senderID 'c0b50000-0000-0000-0000-000000000041'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'senderID', gen_random_bytes(32), 'sender@example.com', true, 'sender'),
+    (:'recipientID', gen_random_bytes(32), 'recipient@example.com', true, 'recipient');
+
+-- Community
+insert into community (
+    community_id,
+    banner_mobile_url,
+    banner_url,
+    description,
+    display_name,
+    logo_url,
+    name
+) values (
+    :'communityID',
+    'https://e/community-mobile.png',
+    'https://e/community.png',
+    'Community 1',
+    'Community One',
+    'https://e/community-logo.png',
+    'community-one'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Platform', 'platform'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Infra', 'infra');
+
+-- Event
+insert into event (
+    description,
+    event_category_id,
+    event_id,
+    event_kind_id,
+    group_id,
+    name,
+    slug,
+    timezone
+) values (
+    'Test event',
+    :'eventCategoryID',
+    :'eventID',
+    'virtual',
+    :'groupID',
+    'Platform Meetup',
+    'platform-meetup',
+    'UTC'
+);
+
+-- Custom notifications
+insert into custom_notification (
+    custom_notification_id,
+    body,
+    created_at,
+    created_by,
+    event_id,
+    group_id,
+    subject
+) values
+    (
+        :'customNotification1ID',
+        'Group body',
+        '2024-03-01 10:00:00+00',
+        :'senderID',
+        null,
+        :'groupID',
+        'Group update'
+    ),
+    (
+        :'customNotification2ID',
+        'Event body',
+        '2024-03-02 10:00:00+00',
+        :'senderID',
+        :'eventID',
+        null,
+        'Event update'
+    ),
+    (
+        :'customNotification3ID',
+        'Other group body',
+        '2024-03-03 10:00:00+00',
+        :'senderID',
+        null,
+        :'otherGroupID',
+        'Other group update'
+    );
+
+-- Notifications
+insert into notification (
+    channel,
+    custom_notification_id,
+    delivery_status,
+    kind,
+    user_id
+) values
+    ('email', :'customNotification2ID', 'bounced', 'event-custom', :'recipientID'),
+    ('email', :'customNotification2ID', 'delivery-unknown', 'event-custom', :'recipientID'),
+    ('email', :'customNotification2ID', 'failed', 'event-custom', :'recipientID'),
+    ('email', :'customNotification2ID', 'pending', 'event-custom', :'recipientID'),
+    ('email', :'customNotification2ID', 'processed', 'event-custom', :'recipientID'),
+    ('email', :'customNotification2ID', 'processed', 'event-custom', :'recipientID'),
+    ('email', :'customNotification2ID', 'processing', 'event-custom', :'recipientID'),
+    ('webhook', :'customNotification2ID', 'processed', 'event-custom', :'recipientID'),
+    ('email', :'customNotification3ID', 'processed', 'group-custom', :'recipientID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the group custom notifications with their email delivery status
+select is(
+    list_group_custom_notifications(
+        :'groupID'::uuid,
+        '{"limit": 50, "offset": 0}'::jsonb
+    )::jsonb,
+    jsonb_build_object(
+        'notifications', jsonb_build_array(
+            jsonb_build_object(
+                'bounced', 1,
+                'created_at', 1709373600,
+                'created_by_username', 'sender',
+                'custom_notification_id', :'customNotification2ID'::uuid,
+                'event_name', 'Platform Meetup',
+                'failed', 1,
+                'group_name', 'Platform',
+                'queued', 2,
+                'sent', 2,
+                'subject', 'Event update',
+                'unknown', 1
+            ),
+            jsonb_build_object(
+                'bounced', 0,
+                'created_at', 1709287200,
+                'created_by_username', 'sender',
+                'custom_notification_id', :'customNotification1ID'::uuid,
+                'event_name', null,
+                'failed', 0,
+                'group_name', 'Platform',
+                'queued', 0,
+                'sent', 0,
+                'subject', 'Group update',
+                'unknown', 0
+            )
+        ),
+        'total', 2
+    ),
+    'Should return the group custom notifications with their email delivery status'
+);
+
+-- Should return paginated custom notifications when limit and offset are provided
+select is(
+    list_group_custom_notifications(
+        :'groupID'::uuid,
+        '{"limit": 1, "offset": 1}'::jsonb
+    )::jsonb->'notifications'->0->>'subject',
+    'Group update',
+    'Should return paginated custom notifications when limit and offset are provided'
+);
+
+-- Should return empty list for non-existing group
+select is(
+    list_group_custom_notifications(
+        :'missingGroupID'::uuid,
+        '{"limit": 50, "offset": 0}'::jsonb
+    )::jsonb,
+    jsonb_build_object(
+        'notifications', '[]'::jsonb,
+        'total', 0
+    ),
+    'Should return empty list for non-existing group'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,134 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0f30000-0000-0000-0000-000000000001'
+\set emptyGroupID 'c0f30000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0f30000-0000-0000-0000-000000000003'
+\set groupID 'c0f30000-0000-0000-0000-000000000004'
+\set newerRequesterID 'c0f30000-0000-0000-0000-000000000005'
+\set olderRequesterID 'c0f30000-0000-0000-0000-000000000006'
+\set reviewedRequesterID 'c0f30000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, name, company, title, photo_url)
+values
+    (:'newerRequesterID', 'hash1', 'newer@test.com', true, 'newer', null, null, null, null),
+    (
+        :'olderRequesterID',
+        'hash2',
+        'older@test.com',
+        true,
+        'older',
+        'Older Requester',
+        'Example Inc',
+        'Engineer',
+        'https://example.com/older.png'
+    ),
+    (:'reviewedRequesterID', 'hash3', 'reviewed@test.com', true, 'reviewed', null, null, null, null);
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, private)
+values
+    (:'emptyGroupID', :'communityID', :'groupCategoryID', 'Empty Group', 'empty-group', true),
+    (:'groupID', :'communityID', :'groupCategoryID', 'Private Group', 'private-group', true);
+
+-- Group join requests
+insert into group_join_request (group_id, user_id, created_at, status, reviewed_at, reviewed_by, join_answers)
+values
+    (:'groupID', :'newerRequesterID', '2024-01-02 00:00:00+00', 'pending', null, null, null),
+    (
+        :'groupID',
+        :'olderRequesterID',
+        '2024-01-01 00:00:00+00',
+        'pending',
+        null,
+        null,
+        '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]}'
+    ),
+    (
+        :'groupID',
+        :'reviewedRequesterID',
+        '2023-12-31 00:00:00+00',
+        'rejected',
+        '2024-01-03 00:00:00+00',
+        :'olderRequesterID',
+        null
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the pending join requests with their answers, oldest first
+select is(
+    list_group_join_requests(:'groupID'::uuid)::jsonb,
+    format('[
+        {
+            "company": "Example Inc",
+            "created_at": 1704067200,
+            "join_answers": {
+                "answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]
+            },
+            "name": "Older Requester",
+            "photo_url": "https://example.com/older.png",
+            "title": "Engineer",
+            "user_id": "%s",
+            "username": "older"
+        },
+        {
+            "created_at": 1704153600,
+            "user_id": "%s",
+            "username": "newer"
+        }
+    ]', :'olderRequesterID', :'newerRequesterID')::jsonb,
+    'Should return the pending join requests with their answers, oldest first'
+);
+
+-- Should return an empty list when the group has no pending join requests
+select is(
+    list_group_join_requests(:'emptyGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the group has no pending join requests'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,217 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set canceledEventID 'c1230000-0000-0000-0000-000000000001'
+\set communityID 'c1230000-0000-0000-0000-000000000002'
+\set emptyGroupID 'c1230000-0000-0000-0000-000000000003'
+\set eventCategoryID 'c1230000-0000-0000-0000-000000000004'
+\set futureEventID 'c1230000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c1230000-0000-0000-0000-000000000006'
+\set groupID 'c1230000-0000-0000-0000-000000000007'
+\set oldEventID 'c1230000-0000-0000-0000-000000000008'
+\set recentEvent1ID 'c1230000-0000-0000-0000-000000000009'
+\set recentEvent2ID 'c1230000-0000-0000-0000-000000000010'
+\set user1ID 'c1230000-0000-0000-0000-000000000011'
+\set user2ID 'c1230000-0000-0000-0000-000000000012'
+\set user3ID 'c1230000-0000-0000-0000-000000000013'
+\set user4ID 'c1230000-0000-0000-0000-000000000014'
+\set user5ID 'c1230000-0000-0000-0000-000000000015'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'leaderboard-community',
+    'Leaderboard Community',
+    'Community for member leaderboard tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Leaderboard Group', 'leaderboard-group'),
+    (:'emptyGroupID', :'communityID', :'groupCategoryID', 'Empty Group', 'empty-group');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, name) values
+    (:'user1ID', gen_random_bytes(32), 'user1@example.com', true, 'user1', 'Alice'),
+    (:'user2ID', gen_random_bytes(32), 'user2@example.com', true, 'user2', 'Bob'),
+    (:'user3ID', gen_random_bytes(32), 'user3@example.com', true, 'user3', null),
+    (:'user4ID', gen_random_bytes(32), 'user4@example.com', true, 'user4', 'Dana'),
+    (:'user5ID', gen_random_bytes(32), 'user5@example.com', true, 'user5', 'Eve');
+
+-- Group members (user5 is not a member)
+insert into group_member (group_id, user_id) values
+    (:'groupID', :'user1ID'),
+    (:'groupID', :'user2ID'),
+    (:'groupID', :'user3ID'),
+    (:'groupID', :'user4ID');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    event_category_id,
+    event_kind_id,
+    name,
+    slug,
+    description,
+    timezone,
+    published,
+    canceled,
+    starts_at
+) values
+    (
+        :'recentEvent1ID',
+        :'groupID',
+        :'eventCategoryID',
+        'in-person',
+        'Recent Event One',
+        'recent-event-one',
+        'Recent event',
+        'UTC',
+        true,
+        false,
+        current_timestamp - interval '2 months'
+    ), (
+        :'recentEvent2ID',
+        :'groupID',
+        :'eventCategoryID',
+        'in-person',
+        'Recent Event Two',
+        'recent-event-two',
+        'Recent event',
+        'UTC',
+        true,
+        false,
+        current_timestamp - interval '1 month'
+    ), (
+        :'oldEventID',
+        :'groupID',
+        :'eventCategoryID',
+        'in-person',
+        'Old Event',
+        'old-event',
+        'Event older than 12 months',
+        'UTC',
+        true,
+        false,
+        current_timestamp - interval '14 months'
+    ), (
+        :'canceledEventID',
+        :'groupID',
+        :'eventCategoryID',
+        'in-person',
+        'Canceled Event',
+        'canceled-event',
+        'Canceled event',
+        'UTC',
+        true,
+        true,
+        current_timestamp - interval '3 months'
+    ), (
+        :'futureEventID',
+        :'groupID',
+        :'eventCategoryID',
+        'in-person',
+        'Future Event',
+        'future-event',
+        'Upcoming event',
+        'UTC',
+        true,
+        false,
+        current_timestamp + interval '10 days'
+    );
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in) values
+    (:'recentEvent1ID', :'user1ID', true),
+    (:'recentEvent2ID', :'user1ID', true),
+    (:'recentEvent1ID', :'user2ID', false),
+    (:'recentEvent2ID', :'user2ID', true),
+    (:'recentEvent1ID', :'user3ID', true),
+    (:'oldEventID', :'user3ID', true),
+    (:'canceledEventID', :'user3ID', true),
+    (:'futureEventID', :'user3ID', false),
+    (:'recentEvent1ID', :'user5ID', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should rank members by recent check-ins and registrations
+select is(
+    list_group_member_leaderboard(:'groupID'::uuid)::jsonb,
+    jsonb_build_array(
+        jsonb_build_object(
+            'check_ins', 2,
+            'last_attended_at', (select extract(epoch from starts_at)::bigint from event where event_id = :'recentEvent2ID'),
+            'name', 'Alice',
+            'rank', 1,
+            'rsvps', 2,
+            'user_id', :'user1ID',
+            'username', 'user1'
+        ),
+        jsonb_build_object(
+            'check_ins', 1,
+            'last_attended_at', (select extract(epoch from starts_at)::bigint from event where event_id = :'recentEvent2ID'),
+            'name', 'Bob',
+            'rank', 2,
+            'rsvps', 2,
+            'user_id', :'user2ID',
+            'username', 'user2'
+        ),
+        jsonb_build_object(
+            'check_ins', 1,
+            'last_attended_at', (select extract(epoch from starts_at)::bigint from event where event_id = :'recentEvent1ID'),
+            'rank', 3,
+            'rsvps', 1,
+            'user_id', :'user3ID',
+            'username', 'user3'
+        )
+    ),
+    'Should rank members by recent check-ins and registrations'
+);
+
+-- Should return an empty list when members have not attended any events
+select is(
+    list_group_member_leaderboard(:'emptyGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when members have not attended any events'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
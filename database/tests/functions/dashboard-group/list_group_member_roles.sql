@@ -0,0 +1,91 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0f70000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0f70000-0000-0000-0000-000000000002'
+\set groupID 'c0f70000-0000-0000-0000-000000000003'
+\set otherGroupID 'c0f70000-0000-0000-0000-000000000004'
+\set user1ID 'c0f70000-0000-0000-0000-000000000005'
+\set user2ID 'c0f70000-0000-0000-0000-000000000006'
+\set user3ID 'c0f70000-0000-0000-0000-000000000007'
+\set user4ID 'c0f70000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'member-roles-community',
+    'Member Roles Community',
+    'Community for member roles tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Member Roles Group', 'member-roles-group'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Other Group', 'other-group');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'user1ID', gen_random_bytes(32), 'user1@example.com', true, 'user1'),
+    (:'user2ID', gen_random_bytes(32), 'user2@example.com', true, 'user2'),
+    (:'user3ID', gen_random_bytes(32), 'user3@example.com', true, 'user3'),
+    (:'user4ID', gen_random_bytes(32), 'user4@example.com', true, 'user4');
+
+-- Group members
+insert into group_member (group_id, user_id, member_role) values
+    (:'groupID', :'user1ID', 'volunteer'),
+    (:'groupID', :'user2ID', 'Mentor'),
+    (:'groupID', :'user3ID', 'Mentor'),
+    (:'groupID', :'user4ID', null),
+    (:'otherGroupID', :'user1ID', 'Founding member');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the distinct roles of the group sorted case-insensitively
+select is(
+    list_group_member_roles(:'groupID'::uuid)::jsonb,
+    '["Mentor", "volunteer"]'::jsonb,
+    'Should return the distinct roles of the group sorted case-insensitively'
+);
+
+-- Should only return the roles of the group requested
+select is(
+    list_group_member_roles(:'otherGroupID'::uuid)::jsonb,
+    '["Founding member"]'::jsonb,
+    'Should only return the roles of the group requested'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
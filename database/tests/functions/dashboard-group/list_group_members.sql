@@ -10,6 +10,10 @@ select plan(3);
 -- ============================================================================
 
 \set communityID '3a210000-0000-0000-0000-000000000001'
+\set event1ID '3a210000-0000-0000-0000-00000000000a'
+\set event2ID '3a210000-0000-0000-0000-00000000000b'
+\set event3ID '3a210000-0000-0000-0000-00000000000c'
+\set eventCategoryID '3a210000-0000-0000-0000-00000000000d'
 \set groupCategoryID '3a210000-0000-0000-0000-000000000002'
 \set groupID '3a210000-0000-0000-0000-000000000003'
 \set missingGroupID '3a210000-0000-0000-0000-000000000004'
@@ -101,6 +105,11 @@ insert into "user" (
     'https://example.com/u5.png'
 );
 
+-- Member whose email address bounced
+update "user"
+set email_undeliverable_at = current_timestamp, email_undeliverable_reason = 'bounce'
+where user_id = :'user2ID';
+
 -- Group members
 insert into group_member (group_id, user_id, created_at)
 values
@@ -110,29 +119,80 @@ values
     (:'groupID', :'user4ID', '2024-01-04 00:00:00+00'),
     (:'groupID', :'user5ID', '2024-01-05 00:00:00+00');
 
+-- Member with a role assigned by the organizers and join answers
+update group_member
+set
+    join_answers = '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]}',
+    member_role = 'Mentor'
+where group_id = :'groupID'
+and user_id = :'user1ID';
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Past events, the last one without check-ins
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone,
+    published,
+    starts_at
+) values
+    (:'event1ID', :'groupID', 'Event 1', 'event-1', 'Event 1', :'eventCategoryID', 'in-person', 'UTC', true, '2024-02-01 10:00:00+00'),
+    (:'event2ID', :'groupID', 'Event 2', 'event-2', 'Event 2', :'eventCategoryID', 'in-person', 'UTC', true, '2024-03-01 10:00:00+00'),
+    (:'event3ID', :'groupID', 'Event 3', 'event-3', 'Event 3', :'eventCategoryID', 'in-person', 'UTC', true, '2024-04-01 10:00:00+00');
+
+-- Event attendees
+insert into event_attendee (event_id, user_id, checked_in) values
+    (:'event1ID', :'user1ID', true),
+    (:'event1ID', :'user5ID', false),
+    (:'event2ID', :'user1ID', false),
+    (:'event2ID', :'user3ID', true),
+    (:'event3ID', :'user1ID', false);
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
 
--- Should order named users by name then username, then unnamed by username
+-- Should order named users by name then username, then unnamed by username,
+-- flag members with undeliverable email addresses and include their attendance record, role
+-- and join answers
 select is(
     list_group_members(
         :'groupID'::uuid,
         '{"limit": 50, "offset": 0}'::jsonb
     )::jsonb,
     jsonb_build_object(
-        'members', '[
-            {"created_at": 1704067200, "username": "alice", "company": null, "name": "Alice",
+        'members', format('[
+            {"created_at": 1704067200, "email_undeliverable": false, "events_attended": 1, "events_missed": 1,
+                "user_id": "%s",
+                "username": "alice", "company": null,
+                "join_answers": {"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]},
+                "member_role": "Mentor", "name": "Alice",
                 "photo_url": "https://example.com/u1.png", "title": null},
-            {"created_at": 1704326400, "username": "alice2", "company": null, "name": "Alice",
+            {"created_at": 1704326400, "email_undeliverable": false, "events_attended": 0, "events_missed": 0,
+                "user_id": "%s",
+                "username": "alice2", "company": null, "join_answers": null, "member_role": null, "name": "Alice",
                 "photo_url": "https://example.com/u4.png", "title": null},
-            {"created_at": 1704412800, "username": "bobby", "company": null, "name": "Bob",
+            {"created_at": 1704412800, "email_undeliverable": false, "events_attended": 0, "events_missed": 1,
+                "user_id": "%s",
+                "username": "bobby", "company": null, "join_answers": null, "member_role": null, "name": "Bob",
                 "photo_url": "https://example.com/u5.png", "title": null},
-            {"created_at": 1704240000, "username": "aaron", "company": null, "name": null,
+            {"created_at": 1704240000, "email_undeliverable": false, "events_attended": 1, "events_missed": 0,
+                "user_id": "%s",
+                "username": "aaron", "company": null, "join_answers": null, "member_role": null, "name": null,
                 "photo_url": "https://example.com/u3.png", "title": null},
-            {"created_at": 1704153600, "username": "bob", "company": null, "name": null,
+            {"created_at": 1704153600, "email_undeliverable": true, "events_attended": 0, "events_missed": 0,
+                "user_id": "%s",
+                "username": "bob", "company": null, "join_answers": null, "member_role": null, "name": null,
                 "photo_url": "https://example.com/u2.png", "title": null}
-        ]'::jsonb,
+        ]', :'user1ID', :'user4ID', :'user5ID', :'user3ID', :'user2ID')::jsonb,
         'total', 5
     ),
     'Should order named users by name then username, then unnamed by username'
@@ -145,12 +205,16 @@ select is(
         '{"limit": 2, "offset": 2}'::jsonb
     )::jsonb,
     jsonb_build_object(
-        'members', '[
-            {"created_at": 1704412800, "username": "bobby", "company": null, "name": "Bob",
+        'members', format('[
+            {"created_at": 1704412800, "email_undeliverable": false, "events_attended": 0, "events_missed": 1,
+                "user_id": "%s",
+                "username": "bobby", "company": null, "join_answers": null, "member_role": null, "name": "Bob",
                 "photo_url": "https://example.com/u5.png", "title": null},
-            {"created_at": 1704240000, "username": "aaron", "company": null, "name": null,
+            {"created_at": 1704240000, "email_undeliverable": false, "events_attended": 1, "events_missed": 0,
+                "user_id": "%s",
+                "username": "aaron", "company": null, "join_answers": null, "member_role": null, "name": null,
                 "photo_url": "https://example.com/u3.png", "title": null}
-        ]'::jsonb,
+        ]', :'user5ID', :'user3ID')::jsonb,
         'total', 5
     ),
     'Should return paginated group members when limit and offset are provided'
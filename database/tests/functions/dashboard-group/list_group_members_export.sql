@@ -0,0 +1,139 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c11f0000-0000-0000-0000-000000000001'
+\set event1ID 'c11f0000-0000-0000-0000-000000000002'
+\set event2ID 'c11f0000-0000-0000-0000-000000000003'
+\set event3ID 'c11f0000-0000-0000-0000-000000000004'
+\set eventCategoryID 'c11f0000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c11f0000-0000-0000-0000-000000000006'
+\set groupID 'c11f0000-0000-0000-0000-000000000007'
+\set missingGroupID 'c11f0000-0000-0000-0000-000000000008'
+\set user1ID 'c11f0000-0000-0000-0000-000000000009'
+\set user2ID 'c11f0000-0000-0000-0000-00000000000a'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, name) values
+    (:'user1ID', gen_random_bytes(32), 'alice@example.com', true, 'alice', 'Alice'),
+    (:'user2ID', gen_random_bytes(32), 'bob@example.com', true, 'bob', null);
+
+-- Group members
+insert into group_member (group_id, user_id, created_at, join_answers, member_role) values
+    (
+        :'groupID',
+        :'user1ID',
+        '2024-01-01 00:00:00+00',
+        '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]}',
+        'Mentor'
+    ),
+    (:'groupID', :'user2ID', '2024-01-02 00:00:00+00', null, null);
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Events, the last one canceled
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone,
+    published,
+    canceled,
+    starts_at
+) values
+    (:'event1ID', :'groupID', 'Event 1', 'event-1', 'Event 1', :'eventCategoryID', 'in-person', 'UTC', true, false, '2024-02-01 10:00:00+00'),
+    (:'event2ID', :'groupID', 'Event 2', 'event-2', 'Event 2', :'eventCategoryID', 'in-person', 'UTC', true, false, '2024-03-01 10:00:00+00'),
+    (:'event3ID', :'groupID', 'Event 3', 'event-3', 'Event 3', :'eventCategoryID', 'in-person', 'UTC', true, true, '2024-04-01 10:00:00+00');
+
+-- Event attendees
+insert into event_attendee (event_id, user_id, checked_in) values
+    (:'event1ID', :'user1ID', true),
+    (:'event1ID', :'user2ID', false),
+    (:'event2ID', :'user1ID', true),
+    (:'event3ID', :'user1ID', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return all members with their email, join date, answers and last attendance
+select is(
+    list_group_members_export(:'groupID'::uuid)::jsonb,
+    '[
+        {
+            "created_at": 1704067200,
+            "email": "alice@example.com",
+            "join_answers": {
+                "answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]
+            },
+            "last_attended_at": 1709287200,
+            "member_role": "Mentor",
+            "name": "Alice",
+            "username": "alice"
+        },
+        {
+            "created_at": 1704153600,
+            "email": "bob@example.com",
+            "username": "bob"
+        }
+    ]'::jsonb,
+    'Should return all members with their email, join date, answers and last attendance'
+);
+
+-- Should return an empty list for non-existing group
+select is(
+    list_group_members_export(:'missingGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for non-existing group'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
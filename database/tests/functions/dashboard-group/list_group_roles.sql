@@ -17,6 +17,10 @@ select is(
             "group_role_id": "admin",
             "display_name": "Admin"
         },
+        {
+            "group_role_id": "check-in-volunteer",
+            "display_name": "Check-in Volunteer"
+        },
         {
             "group_role_id": "events-manager",
             "display_name": "Events Manager"
@@ -0,0 +1,90 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1060000-0000-0000-0000-000000000001'
+\set communityID 'c1060000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c1060000-0000-0000-0000-000000000003'
+\set groupID 'c1060000-0000-0000-0000-000000000004'
+\set otherGroupID 'c1060000-0000-0000-0000-000000000005'
+\set platinumTierID 'c1060000-0000-0000-0000-000000000006'
+\set communityTierID 'c1060000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Other group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- Sponsor tiers
+insert into group_sponsor_tier (group_sponsor_tier_id, group_id, logo_size, name, "order") values
+    (:'communityTierID', :'groupID', 'small', 'Community', 3),
+    (:'platinumTierID', :'groupID', 'large', 'Platinum', 1);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the tiers of the group in display order
+select is(
+    list_group_sponsor_tiers(:'groupID'::uuid)::jsonb,
+    format('[
+        {"group_sponsor_tier_id": "%s", "logo_size": "large", "name": "Platinum", "order": 1},
+        {"group_sponsor_tier_id": "%s", "logo_size": "small", "name": "Community", "order": 3}
+    ]', :'platinumTierID', :'communityTierID')::jsonb,
+    'Should return the tiers of the group in display order'
+);
+
+-- Should return an empty list when the group has no tiers
+select is(
+    list_group_sponsor_tiers(:'otherGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the group has no tiers'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
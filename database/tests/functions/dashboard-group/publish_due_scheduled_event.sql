@@ -0,0 +1,248 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(7);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0e90000-0000-0000-0000-000000000001'
+\set eventApprovalID 'c0e90000-0000-0000-0000-000000000009'
+\set eventCategoryID 'c0e90000-0000-0000-0000-000000000002'
+\set eventDueID 'c0e90000-0000-0000-0000-000000000003'
+\set eventFutureID 'c0e90000-0000-0000-0000-000000000004'
+\set eventInvalidID 'c0e90000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0e90000-0000-0000-0000-000000000006'
+\set groupID 'c0e90000-0000-0000-0000-000000000007'
+\set userID 'c0e90000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner_mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, description)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group', 'A test group');
+
+-- User
+insert into "user" (user_id, auth_hash, email, username)
+values (:'userID', 'organizer-hash', 'organizer@test.local', 'organizer');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    publish_scheduled_at,
+    publish_scheduled_by,
+    published
+) values
+    -- Draft event due to be published
+    (
+        :'eventDueID',
+        :'groupID',
+        'Due Event',
+        'due-event',
+        'Draft event due to be published',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        current_timestamp - interval '1 hour',
+        :'userID',
+        false
+    ),
+    -- Draft event scheduled to be published in the future
+    (
+        :'eventFutureID',
+        :'groupID',
+        'Future Event',
+        'future-event',
+        'Draft event scheduled to be published later',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        current_timestamp + interval '7 days',
+        current_timestamp + interval '1 day',
+        :'userID',
+        false
+    ),
+    -- Draft event due to be published that cannot be published
+    (
+        :'eventInvalidID',
+        :'groupID',
+        'Invalid Event',
+        'invalid-event',
+        'Draft event without a start date',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        null,
+        current_timestamp - interval '2 hours',
+        :'userID',
+        false
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should publish the due event and return its identifiers
+select is(
+    publish_due_scheduled_event(null::text),
+    jsonb_build_object(
+        'community_id', :'communityID'::uuid,
+        'event_id', :'eventDueID'::uuid,
+        'group_id', :'groupID'::uuid
+    ),
+    'Should publish the due event and return its identifiers'
+);
+
+-- Should publish the event on behalf of the organizer who scheduled it
+select is(
+    (
+        select jsonb_build_object(
+            'publish_scheduled_at', publish_scheduled_at,
+            'published', published,
+            'published_by', published_by
+        )
+        from event
+        where event_id = :'eventDueID'
+    ),
+    jsonb_build_object(
+        'publish_scheduled_at', null,
+        'published', true,
+        'published_by', :'userID'::uuid
+    ),
+    'Should publish the event on behalf of the organizer who scheduled it'
+);
+
+-- Should drop the schedule of due events that cannot be published
+select is(
+    (
+        select jsonb_build_object(
+            'publish_scheduled_at', publish_scheduled_at,
+            'published', published
+        )
+        from event
+        where event_id = :'eventInvalidID'
+    ),
+    jsonb_build_object(
+        'publish_scheduled_at', null,
+        'published', false
+    ),
+    'Should drop the schedule of due events that cannot be published'
+);
+
+-- Should keep events scheduled in the future unpublished
+select is(
+    (select published from event where event_id = :'eventFutureID'),
+    false,
+    'Should keep events scheduled in the future unpublished'
+);
+
+-- Should return null when no scheduled publication is due
+select is(
+    publish_due_scheduled_event(null::text),
+    null::jsonb,
+    'Should return null when no scheduled publication is due'
+);
+
+-- Require community approval and schedule another draft event
+update community set event_approval_required = true where community_id = :'communityID';
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    publish_scheduled_at,
+    publish_scheduled_by,
+    published
+) values (
+    :'eventApprovalID',
+    :'groupID',
+    'Approval Event',
+    'approval-event',
+    'Draft event due to be published that requires approval',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    current_timestamp + interval '7 days',
+    current_timestamp - interval '1 hour',
+    :'userID',
+    false
+);
+
+-- Should not return events sent for approval instead of being published
+select is(
+    publish_due_scheduled_event(null::text),
+    null::jsonb,
+    'Should not return events sent for approval instead of being published'
+);
+
+-- Should send the due event for approval and drop its schedule
+select is(
+    (
+        select jsonb_build_object(
+            'approval_status', approval_status,
+            'publish_scheduled_at', publish_scheduled_at,
+            'published', published
+        )
+        from event
+        where event_id = :'eventApprovalID'
+    ),
+    jsonb_build_object(
+        'approval_status', 'pending',
+        'publish_scheduled_at', null,
+        'published', false
+    ),
+    'Should send the due event for approval and drop its schedule'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
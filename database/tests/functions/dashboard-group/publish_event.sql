@@ -3,28 +3,32 @@
 -- ============================================================================
 
 begin;
-select plan(18);
+select plan(27);
 
 -- ============================================================================
 -- VARIABLES
 -- ============================================================================
 
 \set communityID '3a2b0000-0000-0000-0000-000000000001'
+\set eventApprovalID '3a2b0000-0000-0000-0000-00000000001d'
 \set eventCategoryID '3a2b0000-0000-0000-0000-000000000002'
 \set eventID '3a2b0000-0000-0000-0000-000000000003'
 \set eventNoMeetingID '3a2b0000-0000-0000-0000-000000000004'
 \set eventNoStartDateID '3a2b0000-0000-0000-0000-000000000005'
 \set eventPublishedID '3a2b0000-0000-0000-0000-000000000006'
+\set eventTicketedFreeID '3a2b0000-0000-0000-0000-00000000001a'
 \set eventTicketedInvalidCurrencyID '3a2b0000-0000-0000-0000-000000000007'
 \set eventTicketedNoRecipientID '3a2b0000-0000-0000-0000-000000000008'
 \set groupCategoryID '3a2b0000-0000-0000-0000-000000000009'
 \set groupID '3a2b0000-0000-0000-0000-000000000010'
 \set groupNoRecipientID '3a2b0000-0000-0000-0000-000000000011'
 \set missingGroupID '3a2b0000-0000-0000-0000-000000000012'
+\set priceWindowFreeID '3a2b0000-0000-0000-0000-00000000001c'
 \set previousPublisherID '3a2b0000-0000-0000-0000-000000000013'
 \set sessionMeetingID '3a2b0000-0000-0000-0000-000000000014'
 \set sessionNoMeetingID '3a2b0000-0000-0000-0000-000000000015'
 \set sessionPublishedMeetingID '3a2b0000-0000-0000-0000-000000000016'
+\set ticketTypeFreeID '3a2b0000-0000-0000-0000-00000000001b'
 \set ticketTypeInvalidCurrencyID '3a2b0000-0000-0000-0000-000000000017'
 \set ticketTypeNoRecipientID '3a2b0000-0000-0000-0000-000000000018'
 \set userID '3a2b0000-0000-0000-0000-000000000019'
@@ -119,6 +123,8 @@ insert into event (
     meeting_in_sync,
     meeting_provider_id,
     meeting_requested,
+    publish_scheduled_at,
+    publish_scheduled_by,
     published
 ) values (
     :'eventID',
@@ -136,6 +142,8 @@ insert into event (
     true,
     'zoom',
     true,
+    current_timestamp + interval '1 day',
+    :'previousPublisherID',
     false
 );
 
@@ -170,6 +178,31 @@ insert into event (
     false
 );
 
+-- Event to publish once the community requires approval
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    published
+) values (
+    :'eventApprovalID',
+    :'groupID',
+    'Test Event Approval',
+    'test-event-approval',
+    'A test event requiring approval',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    current_timestamp + interval '2 days',
+    false
+);
+
 -- Event already published (to verify publishing is idempotent)
 insert into event (
     event_id,
@@ -261,6 +294,31 @@ insert into event (
     false
 );
 
+-- Ticketed event offering only free ticket types on the group without a payment recipient
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    published
+) values (
+    :'eventTicketedFreeID',
+    :'groupNoRecipientID',
+    'Ticketed Event Free',
+    'ticketed-event-free',
+    'A ticketed event offering only free ticket types',
+    'UTC',
+    :'eventCategoryID',
+    'virtual',
+    current_timestamp + interval '2 days',
+    false
+);
+
 -- Ticketed event with an invalid currency code
 insert into event (
     event_id,
@@ -303,6 +361,31 @@ insert into event_ticket_type (
     'Paid ticket'
 );
 
+-- Free ticket type for the event without a payment currency
+insert into event_ticket_type (
+    event_ticket_type_id,
+    event_id,
+    "order",
+    seats_total,
+    title
+) values (
+    :'ticketTypeFreeID',
+    :'eventTicketedFreeID',
+    1,
+    50,
+    'Student'
+);
+
+insert into event_ticket_price_window (
+    event_ticket_price_window_id,
+    event_ticket_type_id,
+    amount_minor
+) values (
+    :'priceWindowFreeID',
+    :'ticketTypeFreeID',
+    0
+);
+
 -- Ticket type for the event with an invalid currency code
 insert into event_ticket_type (
     event_ticket_type_id,
@@ -421,6 +504,16 @@ select is(
     'Should set published_by to the user'
 );
 
+-- Should clear the scheduled publication
+select ok(
+    (
+        select publish_scheduled_at is null and publish_scheduled_by is null
+        from event
+        where event_id = :'eventID'
+    ),
+    'Should clear the scheduled publication'
+);
+
 -- Should create the expected audit row
 select results_eq(
     $$
@@ -543,11 +636,11 @@ select is(
     'Should keep event meeting_in_sync unchanged when meeting_requested=false'
 );
 
--- Should mark reminder as evaluated when publishing event within 24 hours
+-- Should skip reminders that were due before the event was published
 select is(
-    (select event_reminder_evaluated_for_starts_at from event where event_id = :'eventNoMeetingID'),
-    (select starts_at from event where event_id = :'eventNoMeetingID'),
-    'Should mark reminder as evaluated when publishing event within 24 hours'
+    (select event_reminder_not_before from event where event_id = :'eventNoMeetingID'),
+    current_timestamp,
+    'Should skip reminders that were due before the event was published'
 );
 
 -- Should throw error when group_id does not match
@@ -598,6 +691,95 @@ select throws_ok(
     'Should reject ticketed events whose currency code is unsupported'
 );
 
+-- Should publish events offering only free ticket types without payments
+select lives_ok(
+    format(
+        'select publish_event(%L::uuid, %L::uuid, %L::uuid, null)',
+        :'userID',
+        :'groupNoRecipientID',
+        :'eventTicketedFreeID'
+    ),
+    'Should publish events offering only free ticket types without payments'
+);
+
+-- Should mark events offering only free ticket types as published
+select ok(
+    (select published from event where event_id = :'eventTicketedFreeID'),
+    'Should mark events offering only free ticket types as published'
+);
+
+-- Require community approval for the remaining events
+update community set event_approval_required = true where community_id = :'communityID';
+
+-- Should send the event for approval when the community requires it
+select lives_ok(
+    format(
+        'select publish_event(%L::uuid, %L::uuid, %L::uuid, null)',
+        :'userID',
+        :'groupID',
+        :'eventApprovalID'
+    ),
+    'Should send the event for approval when the community requires it'
+);
+
+-- Should keep the event unpublished and waiting for approval
+select results_eq(
+    format(
+        $$
+        select published, approval_status, approval_requested_by
+        from event
+        where event_id = %L::uuid
+        $$,
+        :'eventApprovalID'
+    ),
+    format(
+        $$ values (false, 'pending'::text, %L::uuid) $$,
+        :'userID'
+    ),
+    'Should keep the event unpublished and waiting for approval'
+);
+
+-- Should leave an event already waiting for approval unchanged
+select lives_ok(
+    format(
+        'select publish_event(%L::uuid, %L::uuid, %L::uuid, null)',
+        :'userID',
+        :'groupID',
+        :'eventApprovalID'
+    ),
+    'Should leave an event already waiting for approval unchanged'
+);
+
+-- Should track the approval request only once
+select is(
+    (
+        select count(*)::int
+        from audit_log
+        where action = 'event_approval_requested'
+        and resource_id = :'eventApprovalID'::uuid
+    ),
+    1,
+    'Should track the approval request only once'
+);
+
+-- Should publish approved events when the community requires approval
+update event set approval_status = 'approved' where event_id = :'eventApprovalID';
+select lives_ok(
+    format(
+        'select publish_event(%L::uuid, %L::uuid, %L::uuid, null)',
+        :'userID',
+        :'groupID',
+        :'eventApprovalID'
+    ),
+    'Should publish approved events when the community requires approval'
+);
+
+-- Should mark approved events as published
+select ok(
+    (select published from event where event_id = :'eventApprovalID'),
+    'Should mark approved events as published'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
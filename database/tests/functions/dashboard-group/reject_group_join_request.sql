@@ -0,0 +1,137 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0f50000-0000-0000-0000-000000000001'
+\set communityID 'c0f50000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0f50000-0000-0000-0000-000000000003'
+\set groupID 'c0f50000-0000-0000-0000-000000000004'
+\set requesterID 'c0f50000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'actorID', 'hash1', 'actor@test.com', true, 'actor'),
+    (:'requesterID', 'hash2', 'requester@test.com', true, 'requester');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, private)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Private Group', 'private-group', true);
+
+-- Group join request
+insert into group_join_request (group_id, user_id)
+values (:'groupID', :'requesterID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject a pending join request
+select lives_ok(
+    format(
+        'select reject_group_join_request(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'requesterID'
+    ),
+    'Should reject a pending join request'
+);
+
+-- Should mark the request rejected
+select results_eq(
+    format(
+        $$
+            select status, reviewed_by, reviewed_at is not null
+            from group_join_request
+            where group_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'groupID', :'requesterID'
+    ),
+    format($$ values ('rejected'::text, %L::uuid, true) $$, :'actorID'),
+    'Should mark the request rejected'
+);
+
+-- Should not add the user as a group member
+select ok(
+    not exists(select 1 from group_member where group_id = :'groupID'::uuid and user_id = :'requesterID'::uuid),
+    'Should not add the user as a group member'
+);
+
+-- Should track the decision in the audit log
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            group_id,
+            resource_id,
+            resource_type
+        from audit_log
+        where action = 'group_join_request_rejected'
+    $$,
+    format(
+        $$
+        values (
+            'group_join_request_rejected',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            'user'
+        )
+        $$,
+        :'actorID', :'communityID', :'groupID', :'requesterID'
+    ),
+    'Should track the decision in the audit log'
+);
+
+-- Should not reject a request that was already reviewed
+select throws_ok(
+    format(
+        'select reject_group_join_request(%L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'requesterID'
+    ),
+    'pending join request not found',
+    'Should not reject a request that was already reviewed'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,165 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0c60000-0000-0000-0000-000000000001'
+\set communityID 'c0c60000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0c60000-0000-0000-0000-000000000003'
+\set eventID 'c0c60000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0c60000-0000-0000-0000-000000000005'
+\set groupID 'c0c60000-0000-0000-0000-000000000006'
+\set otherWaitlistedUserID 'c0c60000-0000-0000-0000-000000000007'
+\set unknownGroupID 'c0c60000-0000-0000-0000-000000000008'
+\set waitlistedUserID 'c0c60000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor'),
+    (
+        'hash-other-waitlisted',
+        'other-waitlisted@example.com',
+        true,
+        'Other Waitlisted',
+        :'otherWaitlistedUserID',
+        'other-waitlisted'
+    ),
+    (
+        'hash-waitlisted',
+        'waitlisted@example.com',
+        true,
+        'Waitlisted',
+        :'waitlistedUserID',
+        'waitlisted'
+    );
+
+-- Event
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    capacity,
+    waitlist_enabled
+)
+values (
+    :'eventID',
+    'Full Event',
+    'full-event',
+    'Test full event',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    true,
+    1,
+    true
+);
+
+-- Waitlist
+insert into event_waitlist (event_id, user_id)
+values
+    (:'eventID', :'otherWaitlistedUserID'),
+    (:'eventID', :'waitlistedUserID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should remove the user from the waiting list
+select lives_ok(
+    format(
+        'select remove_event_waitlist_user(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventID', :'waitlistedUserID'
+    ),
+    'Should remove the user from the waiting list'
+);
+select results_eq(
+    format('select user_id from event_waitlist where event_id = %L::uuid', :'eventID'),
+    format('values (%L::uuid)', :'otherWaitlistedUserID'),
+    'Should keep other users on the waiting list'
+);
+
+-- Should track the removal in the audit log
+select results_eq(
+    $$ select action, resource_id from audit_log $$,
+    format(
+        $$ values ('event_waitlist_user_removed'::text, %L::uuid) $$,
+        :'waitlistedUserID'
+    ),
+    'Should track the removal in the audit log'
+);
+
+-- Should reject users not on the waiting list
+select throws_ok(
+    format(
+        'select remove_event_waitlist_user(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'groupID', :'eventID', :'waitlistedUserID'
+    ),
+    'waitlisted user not found',
+    'Should reject users not on the waiting list'
+);
+
+-- Should reject events from other groups
+select throws_ok(
+    format(
+        'select remove_event_waitlist_user(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'unknownGroupID', :'eventID', :'otherWaitlistedUserID'
+    ),
+    'event not found',
+    'Should reject events from other groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,148 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c10d0000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c10d0000-0000-0000-0000-000000000002'
+\set groupID 'c10d0000-0000-0000-0000-000000000003'
+\set newOwnerID 'c10d0000-0000-0000-0000-000000000004'
+\set ownerID 'c10d0000-0000-0000-0000-000000000005'
+\set pendingMemberID 'c10d0000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'ownership-transfer-community',
+    'Ownership Transfer Community',
+    'Community for ownership transfer tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'newOwnerID', gen_random_bytes(32), 'bob@example.com', true, 'bob'),
+    (:'ownerID', gen_random_bytes(32), 'alice@example.com', true, 'alice'),
+    (:'pendingMemberID', gen_random_bytes(32), 'carol@example.com', true, 'carol');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, owner_user_id)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Transfer Group', 'transfer-group', :'ownerID');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted) values
+    (:'groupID', :'newOwnerID', 'viewer', true),
+    (:'groupID', :'ownerID', 'admin', true),
+    (:'groupID', :'pendingMemberID', 'admin', false);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should register the transfer requested by the owner
+select lives_ok(
+    format(
+        $$ select request_group_ownership_transfer(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'ownerID', :'groupID', :'newOwnerID'
+    ),
+    'Should register the transfer requested by the owner'
+);
+select results_eq(
+    format(
+        $$ select created_by, user_id from group_ownership_transfer where group_id = %L::uuid $$,
+        :'groupID'
+    ),
+    format($$ values (%L::uuid, %L::uuid) $$, :'ownerID', :'newOwnerID'),
+    'Transfer should be pending the acceptance of the new owner'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'group_ownership_transfer_requested',
+            %L::uuid,
+            'alice',
+            %L::uuid,
+            %L::uuid,
+            'user',
+            %L::uuid
+        )
+        $$,
+        :'ownerID', :'communityID', :'groupID', :'newOwnerID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should reject transfers requested by other team members
+select throws_ok(
+    format(
+        $$ select request_group_ownership_transfer(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'newOwnerID', :'groupID', :'ownerID'
+    ),
+    'only the group owner can transfer its ownership',
+    'Should reject transfers requested by other team members'
+);
+
+-- Should reject transfers to members who have not accepted their invitation
+select throws_ok(
+    format(
+        $$ select request_group_ownership_transfer(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'ownerID', :'groupID', :'pendingMemberID'
+    ),
+    'the new owner must be another accepted group team member',
+    'Should reject transfers to members who have not accepted their invitation'
+);
+
+-- Should reject transfers to the owner
+select throws_ok(
+    format(
+        $$ select request_group_ownership_transfer(%L::uuid, %L::uuid, %L::uuid) $$,
+        :'ownerID', :'groupID', :'ownerID'
+    ),
+    'the new owner must be another accepted group team member',
+    'Should reject transfers to the owner'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,239 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(9);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendeeUserID 'c0b70000-0000-0000-0000-000000000001'
+\set checkedInUserID 'c0b70000-0000-0000-0000-000000000002'
+\set communityID 'c0b70000-0000-0000-0000-000000000003'
+\set eventCategoryID 'c0b70000-0000-0000-0000-000000000004'
+\set eventID 'c0b70000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0b70000-0000-0000-0000-000000000006'
+\set groupID 'c0b70000-0000-0000-0000-000000000007'
+\set inactiveUserID 'c0b70000-0000-0000-0000-000000000008'
+\set newMemberUserID 'c0b70000-0000-0000-0000-000000000009'
+\set otherEventID 'c0b70000-0000-0000-0000-000000000010'
+\set otherGroupID 'c0b70000-0000-0000-0000-000000000011'
+\set teamUserID 'c0b70000-0000-0000-0000-000000000012'
+\set unverifiedUserID 'c0b70000-0000-0000-0000-000000000013'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'group-recipient-community',
+    'Group Recipient Community',
+    'Community used for group recipient tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Categories
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group Recipient Group', 'group-recipient-group'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Other Group', 'other-group');
+
+-- Events
+insert into event (
+    event_id,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    published,
+    starts_at
+) values (
+    :'eventID',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    'Group Recipient Event',
+    'group-recipient-event',
+    'Group recipient test event',
+    'UTC',
+    true,
+    current_timestamp - interval '2 months'
+), (
+    :'otherEventID',
+    :'eventCategoryID',
+    'in-person',
+    :'otherGroupID',
+    'Other Event',
+    'other-event',
+    'Other event',
+    'UTC',
+    true,
+    current_timestamp - interval '2 months'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'attendeeUserID', gen_random_bytes(32), 'attendee@example.com', true, 'attendee'),
+    (:'checkedInUserID', gen_random_bytes(32), 'checked-in@example.com', true, 'checked-in'),
+    (:'inactiveUserID', gen_random_bytes(32), 'inactive@example.com', true, 'inactive'),
+    (:'newMemberUserID', gen_random_bytes(32), 'new-member@example.com', true, 'new-member'),
+    (:'teamUserID', gen_random_bytes(32), 'team@example.com', true, 'team'),
+    (:'unverifiedUserID', gen_random_bytes(32), 'unverified@example.com', false, 'unverified');
+
+-- Group members
+insert into group_member (group_id, user_id, created_at)
+values
+    (:'groupID', :'attendeeUserID', current_timestamp - interval '1 year'),
+    (:'groupID', :'checkedInUserID', current_timestamp - interval '1 year'),
+    (:'groupID', :'inactiveUserID', current_timestamp - interval '1 year'),
+    (:'groupID', :'newMemberUserID', current_timestamp - interval '1 week'),
+    (:'groupID', :'unverifiedUserID', current_timestamp - interval '1 year'),
+    (:'otherGroupID', :'teamUserID', current_timestamp - interval '1 year');
+
+-- Member roles
+update group_member
+set member_role = 'Mentor'
+where (group_id, user_id) in (
+    (:'groupID'::uuid, :'newMemberUserID'::uuid),
+    (:'groupID'::uuid, :'unverifiedUserID'::uuid),
+    (:'otherGroupID'::uuid, :'teamUserID'::uuid)
+);
+
+-- Group team
+insert into group_team (group_id, user_id, accepted, role)
+values (:'groupID', :'teamUserID', true, 'admin');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in, status)
+values
+    (:'eventID', :'attendeeUserID', false, 'confirmed'),
+    (:'eventID', :'checkedInUserID', true, 'confirmed'),
+    (:'eventID', :'unverifiedUserID', true, 'confirmed'),
+    (:'otherEventID', :'inactiveUserID', true, 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should resolve all group members and team members with verified emails
+select is(
+    resolve_group_custom_notification_recipient_ids(:'groupID'::uuid, 'all-members', null, null, null),
+    array[
+        :'attendeeUserID'::uuid,
+        :'checkedInUserID'::uuid,
+        :'inactiveUserID'::uuid,
+        :'newMemberUserID'::uuid,
+        :'teamUserID'::uuid
+    ],
+    'Should resolve all group members and team members with verified emails'
+);
+
+-- Should resolve the attendees of the event provided
+select is(
+    resolve_group_custom_notification_recipient_ids(
+        :'groupID'::uuid,
+        'event-attendees',
+        :'eventID'::uuid,
+        null,
+        null
+    ),
+    array[:'attendeeUserID'::uuid, :'checkedInUserID'::uuid],
+    'Should resolve the attendees of the event provided'
+);
+
+-- Should resolve only the checked-in attendees of the event provided
+select is(
+    resolve_group_custom_notification_recipient_ids(
+        :'groupID'::uuid,
+        'checked-in-attendees',
+        :'eventID'::uuid,
+        null,
+        null
+    ),
+    array[:'checkedInUserID'::uuid],
+    'Should resolve only the checked-in attendees of the event provided'
+);
+
+-- Should return empty list when the event belongs to another group
+select is(
+    resolve_group_custom_notification_recipient_ids(
+        :'groupID'::uuid,
+        'event-attendees',
+        :'otherEventID'::uuid,
+        null,
+        null
+    ),
+    array[]::uuid[],
+    'Should return empty list when the event belongs to another group'
+);
+
+-- Should resolve members without recent attendance at group events
+select is(
+    resolve_group_custom_notification_recipient_ids(:'groupID'::uuid, 'inactive-members', null, 3, null),
+    array[:'inactiveUserID'::uuid],
+    'Should resolve members without recent attendance at group events'
+);
+
+-- Should consider attendance older than the inactivity period
+select is(
+    resolve_group_custom_notification_recipient_ids(:'groupID'::uuid, 'inactive-members', null, 1, null),
+    array[
+        :'attendeeUserID'::uuid,
+        :'checkedInUserID'::uuid,
+        :'inactiveUserID'::uuid
+    ],
+    'Should consider attendance older than the inactivity period'
+);
+
+-- Should return empty list when the inactivity period is missing
+select is(
+    resolve_group_custom_notification_recipient_ids(:'groupID'::uuid, 'inactive-members', null, null, null),
+    array[]::uuid[],
+    'Should return empty list when the inactivity period is missing'
+);
+
+-- Should resolve verified members holding the role provided
+select is(
+    resolve_group_custom_notification_recipient_ids(:'groupID'::uuid, 'members-with-role', null, null, 'Mentor'),
+    array[:'newMemberUserID'::uuid],
+    'Should resolve verified members holding the role provided'
+);
+
+-- Should return empty list for an unknown audience
+select is(
+    resolve_group_custom_notification_recipient_ids(:'groupID'::uuid, 'unknown-audience', null, null, null),
+    array[]::uuid[],
+    'Should return empty list for an unknown audience'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,151 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c11d0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c11d0000-0000-0000-0000-000000000002'
+\set eventID 'c11d0000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c11d0000-0000-0000-0000-000000000004'
+\set groupID 'c11d0000-0000-0000-0000-000000000005'
+\set otherCommunityID 'c11d0000-0000-0000-0000-000000000006'
+\set otherTopicID 'c11d0000-0000-0000-0000-000000000007'
+\set topic1ID 'c11d0000-0000-0000-0000-000000000008'
+\set topic2ID 'c11d0000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cncf-seattle',
+    'CNCF Seattle',
+    'Community for topic tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Other community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'otherCommunityID',
+    'cncf-portland',
+    'CNCF Portland',
+    'Other community for topic tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Topic Group', 'topic-group');
+
+-- Event
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone
+) values (
+    :'eventID',
+    :'groupID',
+    'Topic Event',
+    'topic-event',
+    'Event for topic tests',
+    :'eventCategoryID',
+    'in-person',
+    'UTC'
+);
+
+-- Topic
+insert into topic (topic_id, community_id, name)
+values
+    (:'otherTopicID', :'otherCommunityID', 'Networking'),
+    (:'topic1ID', :'communityID', 'Kubernetes'),
+    (:'topic2ID', :'communityID', 'Security');
+
+-- Event topic
+insert into event_topic (event_id, topic_id)
+values (:'eventID', :'topic1ID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should replace the event topics, ignoring repeated IDs
+select lives_ok(
+    format(
+        $$ select sync_event_topics(%L::uuid, jsonb_build_array(%L, %L)) $$,
+        :'eventID',
+        :'topic2ID',
+        :'topic2ID'
+    ),
+    'Should replace the event topics'
+);
+select results_eq(
+    format($$ select topic_id from event_topic where event_id = %L::uuid $$, :'eventID'),
+    format($$ values (%L::uuid) $$, :'topic2ID'),
+    'Event should only have the topics provided'
+);
+
+-- Should reject topics from another community
+select throws_ok(
+    format(
+        $$ select sync_event_topics(%L::uuid, jsonb_build_array(%L)) $$,
+        :'eventID',
+        :'otherTopicID'
+    ),
+    'topic not found in community',
+    'Should reject topics from another community'
+);
+
+-- Should clear the event topics when none are provided
+select sync_event_topics(:'eventID'::uuid, null);
+select is_empty(
+    format($$ select 1 from event_topic where event_id = %L::uuid $$, :'eventID'),
+    'Event topics should be cleared when none are provided'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,141 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1290000-0000-0000-0000-000000000001'
+\set bannedUserID 'c1290000-0000-0000-0000-000000000002'
+\set communityID 'c1290000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c1290000-0000-0000-0000-000000000004'
+\set groupID 'c1290000-0000-0000-0000-000000000005'
+\set otherCommunityID 'c1290000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+), (
+    :'otherCommunityID',
+    'other-community',
+    'Other Community',
+    'Another test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'actorID', 'hash1', 'actor@test.com', true, 'actor'),
+    (:'bannedUserID', 'hash2', 'banned@test.com', true, 'banned');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group');
+
+-- Group ban
+insert into group_ban (group_id, user_id, created_by, reason)
+values (:'groupID', :'bannedUserID', :'actorID', 'Spam');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not lift bans from groups in other communities
+select throws_ok(
+    format(
+        'select unban_group_member(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'otherCommunityID', :'groupID', :'bannedUserID'
+    ),
+    'group not found or inactive',
+    'Should not lift bans from groups in other communities'
+);
+
+-- Should lift the ban
+select lives_ok(
+    format(
+        'select unban_group_member(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'communityID', :'groupID', :'bannedUserID'
+    ),
+    'Should lift the ban'
+);
+
+-- Should remove the ban
+select ok(
+    not exists(select 1 from group_ban where group_id = :'groupID'::uuid and user_id = :'bannedUserID'::uuid),
+    'Should remove the ban'
+);
+
+-- Should track the ban removal in the audit log
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            group_id,
+            resource_id,
+            resource_type
+        from audit_log
+        where action = 'group_member_unbanned'
+    $$,
+    format(
+        $$
+        values (
+            'group_member_unbanned',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            'user'
+        )
+        $$,
+        :'actorID', :'communityID', :'groupID', :'bannedUserID'
+    ),
+    'Should track the ban removal in the audit log'
+);
+
+-- Should not lift a ban that does not exist
+select throws_ok(
+    format(
+        'select unban_group_member(%L::uuid, %L::uuid, %L::uuid, %L::uuid)',
+        :'actorID', :'communityID', :'groupID', :'bannedUserID'
+    ),
+    'user is not banned from this group',
+    'Should not lift a ban that does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
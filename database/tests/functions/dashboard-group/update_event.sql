@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(23);
+select plan(27);
 
 -- ============================================================================
 -- VARIABLES
@@ -379,7 +379,9 @@ select is(
         "test_event": false,
         "timezone": "America/Los_Angeles",
 
+        "attendance_certificate_emails_enabled": false,
         "attendee_approval_required": false,
+        "attendee_photos_enabled": false,
         "capacity": 100,
         "remaining_capacity": 100,
         "ends_at": 1896220800,
@@ -391,6 +393,7 @@ select is(
         "meeting_provider": "zoom",
         "meeting_recording_published": false,
         "meeting_recording_requested": true,
+        "meeting_webinar": false,
         "meeting_requested": true,
         "registration_questions": [],
         "registration_questions_locked": false,
@@ -461,11 +464,14 @@ select lives_ok(
             "category_id": "3a390000-0000-0000-0000-000000000001",
             "kind_id": "hybrid",
             "meeting_requested": false,
+            "attendance_certificate_emails_enabled": true,
+            "attendee_photos_enabled": true,
             "banner_url": "https://example.com/new-banner.jpg",
             "capacity": 200,
             "description_short": "Updated short description",
             "starts_at": "2030-02-01T14:00:00",
             "ends_at": "2030-02-01T16:00:00",
+            "feedback_url": "https://example.com/new-feedback",
             "logo_url": "https://example.com/new-logo.png",
             "luma_url": "https://luma.com/new-event",
             "meeting_hosts": ["althost1@example.com", "althost2@example.com"],
@@ -475,6 +481,7 @@ select lives_ok(
             "meetup_url": "https://meetup.com/new-event",
             "photos_urls": ["https://example.com/new-photo1.jpg", "https://example.com/new-photo2.jpg"],
             "registration_required": false,
+            "streaming_url": "https://www.twitch.tv/newchannel",
             "tags": ["updated", "event", "tags"],
             "test_event": true,
             "venue_address": "456 New St",
@@ -539,19 +546,23 @@ select is(
         "slug": "def5678",
         "timezone": "Asia/Tokyo",
         "test_event": true,
+        "attendance_certificate_emails_enabled": true,
         "attendee_approval_required": false,
+        "attendee_photos_enabled": true,
         "banner_url": "https://example.com/new-banner.jpg",
         "capacity": 200,
         "remaining_capacity": 200,
         "description_short": "Updated short description",
         "starts_at": 1896152400,
         "ends_at": 1896159600,
+        "feedback_url": "https://example.com/new-feedback",
         "logo_url": "https://example.com/new-logo.png",
         "luma_url": "https://luma.com/new-event",
         "meeting_join_instructions": "Use the event ticket name when joining.",
         "meeting_join_url": "https://youtube.com/new-live",
         "meeting_recording_published": false,
         "meeting_recording_requested": true,
+        "meeting_webinar": false,
         "meeting_recording_url": "https://youtube.com/new-recording",
         "meetup_url": "https://meetup.com/new-event",
         "photos_urls": ["https://example.com/new-photo1.jpg", "https://example.com/new-photo2.jpg"],
@@ -562,6 +573,7 @@ select is(
         "has_registration_questions": false,
         "has_related_events": false,
         "has_ticket_purchases": false,
+        "streaming_url": "https://www.twitch.tv/newchannel",
         "tags": ["updated", "event", "tags"],
         "venue_address": "456 New St",
         "venue_city": "Tokyo",
@@ -799,7 +811,7 @@ select throws_ok(
     'Should throw error when speaker user_id does not exist'
 );
 
--- Should evaluate reminder immediately when published event starts within 24 hours
+-- Should update event when moving its start time within 24 hours
 select lives_ok(
     format(
         $$select update_event(
@@ -819,17 +831,17 @@ select lives_ok(
         )$$,
         :'group1ID', :'event10ID', :'category1ID'
     ),
-    'Should evaluate reminder immediately when published event starts within 24 hours'
+    'Should update event when moving its start time within 24 hours'
 );
 
--- Should mark reminder as evaluated for the updated start date
+-- Should skip reminders that were due before the start time was changed
 select is(
-    (select event_reminder_evaluated_for_starts_at from event where event_id = :'event10ID'),
-    (select starts_at from event where event_id = :'event10ID'),
-    'Should mark reminder as evaluated for the updated start date'
+    (select event_reminder_not_before from event where event_id = :'event10ID'),
+    current_timestamp,
+    'Should skip reminders that were due before the start time was changed'
 );
 
--- Should not evaluate reminder when starts_at remains unchanged inside 24 hours
+-- Should update event when starts_at remains unchanged inside 24 hours
 select lives_ok(
     format(
         $$select update_event(
@@ -849,14 +861,84 @@ select lives_ok(
         )$$,
         :'group1ID', :'event11ID', :'category1ID', :'event11ID', :'event11ID'
     ),
-    'Should not evaluate reminder when starts_at remains unchanged inside 24 hours'
+    'Should update event when starts_at remains unchanged inside 24 hours'
 );
 
--- Should keep reminder unevaluated when starts_at remains unchanged inside 24 hours
+-- Should keep reminders reference time when starts_at remains unchanged
 select is(
-    (select event_reminder_evaluated_for_starts_at from event where event_id = :'event11ID'),
+    (select event_reminder_not_before from event where event_id = :'event11ID'),
     null::timestamptz,
-    'Should keep reminder unevaluated when starts_at remains unchanged inside 24 hours'
+    'Should keep reminders reference time when starts_at remains unchanged'
+);
+
+-- Should schedule the publication of a draft event
+select lives_ok(
+    format(
+        $$select update_event(
+            '%s'::uuid,
+            '%s'::uuid,
+            '%s'::uuid,
+            jsonb_build_object(
+                'name', 'Event With Labels',
+                'description', 'Scheduled publication check',
+                'timezone', 'UTC',
+                'category_id', '%s',
+                'kind_id', 'virtual',
+                'starts_at', '2030-01-15T10:00:00',
+                'ends_at', '2030-01-15T12:00:00',
+                'publish_scheduled_at', '2030-01-10T09:00:00'
+            )
+        )$$,
+        :'user1ID', :'group1ID', :'event12ID', :'category1ID'
+    ),
+    'Should schedule the publication of a draft event'
+);
+
+-- Should store the scheduled publication and who scheduled it
+select is(
+    (
+        select jsonb_build_object(
+            'publish_scheduled_at', publish_scheduled_at,
+            'publish_scheduled_by', publish_scheduled_by
+        )
+        from event
+        where event_id = :'event12ID'
+    ),
+    jsonb_build_object(
+        'publish_scheduled_at', '2030-01-10 09:00:00+00'::timestamptz,
+        'publish_scheduled_by', :'user1ID'::uuid
+    ),
+    'Should store the scheduled publication and who scheduled it'
+);
+
+-- Should update a published event that includes a scheduled publication
+select lives_ok(
+    format(
+        $$select update_event(
+            '%s'::uuid,
+            '%s'::uuid,
+            '%s'::uuid,
+            jsonb_build_object(
+                'name', 'Reminder Event Updated',
+                'description', 'Scheduled publication check',
+                'timezone', 'UTC',
+                'category_id', '%s',
+                'kind_id', 'virtual',
+                'starts_at', to_char((select starts_at from event where event_id = '%s'::uuid) at time zone 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS'),
+                'ends_at', to_char((select ends_at from event where event_id = '%s'::uuid) at time zone 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS'),
+                'publish_scheduled_at', to_char(current_timestamp at time zone 'UTC' + interval '1 hour', 'YYYY-MM-DD"T"HH24:MI:SS')
+            )
+        )$$,
+        :'user1ID', :'group1ID', :'event10ID', :'category1ID', :'event10ID', :'event10ID'
+    ),
+    'Should update a published event that includes a scheduled publication'
+);
+
+-- Should not schedule the publication of a published event
+select is(
+    (select publish_scheduled_at from event where event_id = :'event10ID'),
+    null::timestamptz,
+    'Should not schedule the publication of a published event'
 );
 
 -- Should not promote waitlist entries after an open-only registration window reaches the event start
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(26);
+select plan(27);
 
 -- ============================================================================
 -- VARIABLES
@@ -771,6 +771,30 @@ select is(
     'Meeting becomes orphan (session_id set to null) after session deletion'
 );
 
+-- Should reject webinar mode changes once the event meeting exists
+select throws_ok(
+    $$select update_event(
+        null::uuid,
+        '3a3b0000-0000-0000-0000-000000000008'::uuid,
+        '3a3b0000-0000-0000-0000-000000000003'::uuid,
+        '{
+            "name": "Event With Pending Sync",
+            "description": "Updated description with webinar mode",
+            "timezone": "America/New_York",
+            "category_id": "3a3b0000-0000-0000-0000-000000000001",
+            "capacity": 100,
+            "kind_id": "virtual",
+            "meeting_provider_id": "zoom",
+            "meeting_requested": true,
+            "meeting_webinar": true,
+            "starts_at": "2030-03-01T10:00:00",
+            "ends_at": "2030-03-01T12:00:00"
+        }'::jsonb
+    )$$,
+    'webinar mode cannot be changed after the meeting has been created',
+    'Should reject webinar mode changes once the event meeting exists'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
@@ -1159,8 +1159,8 @@ select throws_ok(
             ]
         }'::jsonb
     )$$,
-    'ticketed events require payment_currency_code',
-    'Should reject ticketed events when payment_currency_code is omitted'
+    'paid ticket types require payment_currency_code',
+    'Should reject paid ticket types when payment_currency_code is omitted'
 );
 
 -- Should throw error when waitlist remains enabled for ticketed events
@@ -0,0 +1,121 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1030000-0000-0000-0000-000000000001'
+\set announcementID 'c1030000-0000-0000-0000-000000000002'
+\set communityID 'c1030000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c1030000-0000-0000-0000-000000000004'
+\set groupID 'c1030000-0000-0000-0000-000000000005'
+\set otherGroupID 'c1030000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Announcement
+insert into group_announcement (group_announcement_id, group_id, title, content, pinned)
+values (:'announcementID', :'groupID', 'Venue change', 'New room', false);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject announcements from other groups
+select throws_ok(
+    format(
+        'select update_group_announcement_pinned(%L::uuid, %L::uuid, %L::uuid, true)',
+        :'actorID', :'otherGroupID', :'announcementID'
+    ),
+    'announcement not found or already updated',
+    'Should reject announcements from other groups'
+);
+
+-- Should pin the announcement
+select lives_ok(
+    format(
+        'select update_group_announcement_pinned(%L::uuid, %L::uuid, %L::uuid, true)',
+        :'actorID', :'groupID', :'announcementID'
+    ),
+    'Should pin the announcement'
+);
+select is(
+    (select pinned from group_announcement where group_announcement_id = :'announcementID'::uuid),
+    true,
+    'Should set the pinned flag'
+);
+
+-- Should reject announcements already pinned
+select throws_ok(
+    format(
+        'select update_group_announcement_pinned(%L::uuid, %L::uuid, %L::uuid, true)',
+        :'actorID', :'groupID', :'announcementID'
+    ),
+    'announcement not found or already updated',
+    'Should reject announcements already pinned'
+);
+
+-- Should unpin the announcement
+select lives_ok(
+    format(
+        'select update_group_announcement_pinned(%L::uuid, %L::uuid, %L::uuid, false)',
+        :'actorID', :'groupID', :'announcementID'
+    ),
+    'Should unpin the announcement'
+);
+
+-- Should track the decisions in the audit log
+select results_eq(
+    $$ select action, resource_id from audit_log order by created_at, action $$,
+    format(
+        $$ values ('group_announcement_pinned'::text, %L::uuid), ('group_announcement_unpinned'::text, %L::uuid) $$,
+        :'announcementID', :'announcementID'
+    ),
+    'Should track the decisions in the audit log'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
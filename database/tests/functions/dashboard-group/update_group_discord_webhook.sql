@@ -0,0 +1,121 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0af0000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0af0000-0000-0000-0000-000000000002'
+\set groupDeletedID 'c0af0000-0000-0000-0000-000000000003'
+\set groupID 'c0af0000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cloud-native-malaga',
+    'Cloud Native Malaga',
+    'Community for cloud native technologies in Malaga',
+    'https://example.com/banner_mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, active, deleted)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group Malaga', 'group-malaga', true, false),
+    (:'groupDeletedID', :'communityID', :'groupCategoryID', 'Deleted Group', 'deleted-group', false, true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should store the provided webhook
+select lives_ok(
+    format(
+        $$select update_group_discord_webhook(null::uuid, %L::uuid, ' https://discord.com/api/webhooks/1/token ')$$,
+        :'groupID'
+    ),
+    'Should store the provided webhook'
+);
+select is(
+    (select discord_webhook_url from "group" where group_id = :'groupID'::uuid),
+    'https://discord.com/api/webhooks/1/token',
+    'Should trim the stored webhook'
+);
+
+-- Should clear the webhook when an empty one is provided
+select lives_ok(
+    format(
+        $$select update_group_discord_webhook(null::uuid, %L::uuid, '')$$,
+        :'groupID'
+    ),
+    'Should clear the webhook when an empty one is provided'
+);
+select is(
+    (select discord_webhook_url from "group" where group_id = :'groupID'::uuid),
+    null,
+    'Should store no webhook after clearing it'
+);
+
+-- Should reject deleted groups
+select throws_ok(
+    format(
+        $$select update_group_discord_webhook(null::uuid, %L::uuid, 'https://discord.com/api/webhooks/1/token')$$,
+        :'groupDeletedID'
+    ),
+    'group not found or inactive',
+    'Should reject deleted groups'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('group_discord_webhook_updated', null::uuid, null::text, %1$L::uuid, %2$L::uuid, 'group', %2$L::uuid),
+            ('group_discord_webhook_updated', null::uuid, null::text, %1$L::uuid, %2$L::uuid, 'group', %2$L::uuid)
+        $$,
+        :'communityID', :'groupID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,124 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c0fe0000-0000-0000-0000-000000000001'
+\set communityID 'c0fe0000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0fe0000-0000-0000-0000-000000000003'
+\set groupID 'c0fe0000-0000-0000-0000-000000000004'
+\set memberID 'c0fe0000-0000-0000-0000-000000000005'
+\set otherGroupID 'c0fe0000-0000-0000-0000-000000000006'
+\set topicID 'c0fe0000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor'),
+    ('hash-member', 'member@example.com', true, 'Member', :'memberID', 'member');
+
+-- Discussion topic
+insert into group_discussion_topic (group_discussion_topic_id, group_id, user_id, title, content)
+values (:'topicID', :'groupID', :'memberID', 'Meetup venues', 'Any ideas?');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject topics from other groups
+select throws_ok(
+    format(
+        'select update_group_discussion_topic_pinned(%L::uuid, %L::uuid, %L::uuid, true)',
+        :'actorID', :'otherGroupID', :'topicID'
+    ),
+    'topic not found or already updated',
+    'Should reject topics from other groups'
+);
+
+-- Should pin the topic
+select lives_ok(
+    format(
+        'select update_group_discussion_topic_pinned(%L::uuid, %L::uuid, %L::uuid, true)',
+        :'actorID', :'groupID', :'topicID'
+    ),
+    'Should pin the topic'
+);
+select is(
+    (select pinned from group_discussion_topic where group_discussion_topic_id = :'topicID'::uuid),
+    true,
+    'Should set the pinned flag'
+);
+
+-- Should reject topics already pinned
+select throws_ok(
+    format(
+        'select update_group_discussion_topic_pinned(%L::uuid, %L::uuid, %L::uuid, true)',
+        :'actorID', :'groupID', :'topicID'
+    ),
+    'topic not found or already updated',
+    'Should reject topics already pinned'
+);
+
+-- Should unpin the topic
+select lives_ok(
+    format(
+        'select update_group_discussion_topic_pinned(%L::uuid, %L::uuid, %L::uuid, false)',
+        :'actorID', :'groupID', :'topicID'
+    ),
+    'Should unpin the topic'
+);
+
+-- Should track the decisions in the audit log
+select results_eq(
+    $$ select action, resource_id from audit_log order by created_at, action $$,
+    format(
+        $$ values ('group_discussion_topic_pinned'::text, %L::uuid), ('group_discussion_topic_unpinned'::text, %L::uuid) $$,
+        :'memberID', :'memberID'
+    ),
+    'Should track the decisions in the audit log'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
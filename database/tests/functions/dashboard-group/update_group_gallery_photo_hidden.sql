@@ -0,0 +1,157 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c10a0000-0000-0000-0000-000000000001'
+\set attendeeID 'c10a0000-0000-0000-0000-000000000002'
+\set communityID 'c10a0000-0000-0000-0000-000000000003'
+\set draftEventID 'c10a0000-0000-0000-0000-000000000004'
+\set eventCategoryID 'c10a0000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c10a0000-0000-0000-0000-000000000006'
+\set groupID 'c10a0000-0000-0000-0000-000000000007'
+\set oldEventID 'c10a0000-0000-0000-0000-000000000008'
+\set recentEventID 'c10a0000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'gallery-community',
+    'Gallery Community',
+    'Community for gallery tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, photos_urls)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Gallery Group',
+    'gallery-group',
+    array['https://example.com/group-1.png', 'https://example.com/group-2.png']
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'actorID', gen_random_bytes(32), 'actor@example.com', true, 'actor'),
+    (:'attendeeID', gen_random_bytes(32), 'attendee@example.com', true, 'attendee');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone,
+    published,
+    canceled,
+    starts_at,
+    photos_urls
+) values
+    (:'oldEventID', :'groupID', 'Old Meetup', 'old-meetup', 'Old Meetup', :'eventCategoryID', 'in-person', 'UTC', true, false, '2024-01-01 10:00:00+00', array['https://example.com/old-event.png']),
+    (:'recentEventID', :'groupID', 'Recent Meetup', 'recent-meetup', 'Recent Meetup', :'eventCategoryID', 'in-person', 'UTC', true, false, '2024-06-01 10:00:00+00', array['https://example.com/recent-event.png', 'https://example.com/group-2.png']),
+    (:'draftEventID', :'groupID', 'Draft Meetup', 'draft-meetup', 'Draft Meetup', :'eventCategoryID', 'in-person', 'UTC', false, false, '2024-07-01 10:00:00+00', array['https://example.com/draft-event.png']);
+
+-- Attendee photos
+insert into event_photo (event_id, user_id, status, url, reviewed_at) values
+    (:'recentEventID', :'attendeeID', 'approved', 'https://example.com/attendee-approved.png', '2024-06-02 10:00:00+00'),
+    (:'recentEventID', :'attendeeID', 'rejected', 'https://example.com/attendee-rejected.png', '2024-06-02 10:00:00+00');
+insert into event_photo (event_id, user_id, url)
+values (:'recentEventID', :'attendeeID', 'https://example.com/attendee-pending.png');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should hide a photo and clear the cover when it was the hidden photo
+update "group" set gallery_cover_url = 'https://example.com/attendee-approved.png' where group_id = :'groupID';
+select lives_ok(
+    format(
+        $$ select update_group_gallery_photo_hidden(%L::uuid, %L::uuid, 'https://example.com/attendee-approved.png', true) $$,
+        :'actorID', :'groupID'
+    ),
+    'Should hide a photo'
+);
+select results_eq(
+    format(
+        $$
+            select
+                (select count(*) from group_gallery_hidden_photo where group_id = %1$L::uuid),
+                (select gallery_cover_url from "group" where group_id = %1$L::uuid)
+        $$,
+        :'groupID'
+    ),
+    $$ values (1::bigint, null::text) $$,
+    'Should store the hidden photo and clear the cover'
+);
+
+-- Should show a hidden photo again
+select lives_ok(
+    format(
+        $$ select update_group_gallery_photo_hidden(%L::uuid, %L::uuid, 'https://example.com/attendee-approved.png', false) $$,
+        :'actorID', :'groupID'
+    ),
+    'Should show a hidden photo again'
+);
+select is(
+    (select count(*) from group_gallery_hidden_photo where group_id = :'groupID'),
+    0::bigint,
+    'Should remove the hidden photo'
+);
+
+-- Should track the visibility updates in the audit log
+select results_eq(
+    $$ select action from audit_log order by created_at, action $$,
+    $$ values ('group_gallery_photo_hidden'::text), ('group_gallery_photo_shown'::text) $$,
+    'Should track the visibility updates in the audit log'
+);
+
+-- Should reject photos that are not in the gallery
+select throws_ok(
+    format(
+        $$ select update_group_gallery_photo_hidden(%L::uuid, %L::uuid, 'https://example.com/attendee-pending.png', true) $$,
+        :'actorID', :'groupID'
+    ),
+    'photo not found in the group gallery',
+    'Should reject photos that are not in the gallery'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,121 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1210000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c1210000-0000-0000-0000-000000000002'
+\set groupDeletedID 'c1210000-0000-0000-0000-000000000003'
+\set groupID 'c1210000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cloud-native-malaga',
+    'Cloud Native Malaga',
+    'Community for cloud native technologies in Malaga',
+    'https://example.com/banner_mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, active, deleted)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group Malaga', 'group-malaga', true, false),
+    (:'groupDeletedID', :'communityID', :'groupCategoryID', 'Deleted Group', 'deleted-group', false, true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should store the provided questions
+select lives_ok(
+    format(
+        $$select update_group_join_questions(null::uuid, %L::uuid, '[{"id": "c1210000-0000-0000-0000-000000000101", "kind": "free-text", "prompt": "What topics interest you?", "required": true}]'::jsonb)$$,
+        :'groupID'
+    ),
+    'Should store the provided questions'
+);
+select is(
+    (select join_questions from "group" where group_id = :'groupID'::uuid),
+    '[{"id": "c1210000-0000-0000-0000-000000000101", "kind": "free-text", "prompt": "What topics interest you?", "required": true}]'::jsonb,
+    'Should store the questions as provided'
+);
+
+-- Should clear the questions when none are provided
+select lives_ok(
+    format(
+        $$select update_group_join_questions(null::uuid, %L::uuid, null)$$,
+        :'groupID'
+    ),
+    'Should clear the questions when none are provided'
+);
+select is(
+    (select join_questions from "group" where group_id = :'groupID'::uuid),
+    '[]'::jsonb,
+    'Should store an empty list after clearing the questions'
+);
+
+-- Should reject deleted groups
+select throws_ok(
+    format(
+        $$select update_group_join_questions(null::uuid, %L::uuid, '[]'::jsonb)$$,
+        :'groupDeletedID'
+    ),
+    'group not found or inactive',
+    'Should reject deleted groups'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('group_join_questions_updated', null::uuid, null::text, %1$L::uuid, %2$L::uuid, 'group', %2$L::uuid),
+            ('group_join_questions_updated', null::uuid, null::text, %1$L::uuid, %2$L::uuid, 'group', %2$L::uuid)
+        $$,
+        :'communityID', :'groupID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
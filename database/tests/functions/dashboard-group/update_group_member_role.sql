@@ -0,0 +1,154 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(7);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0f60000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0f60000-0000-0000-0000-000000000002'
+\set groupID 'c0f60000-0000-0000-0000-000000000003'
+\set memberID 'c0f60000-0000-0000-0000-000000000004'
+\set nonMemberID 'c0f60000-0000-0000-0000-000000000005'
+\set organizerID 'c0f60000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'member-role-community',
+    'Member Role Community',
+    'Community for member role tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Member Role Group', 'member-role-group');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'memberID', gen_random_bytes(32), 'member@example.com', true, 'member'),
+    (:'nonMemberID', gen_random_bytes(32), 'non-member@example.com', true, 'non-member'),
+    (:'organizerID', gen_random_bytes(32), 'organizer@example.com', true, 'organizer');
+
+-- Group member
+insert into group_member (group_id, user_id)
+values (:'groupID', :'memberID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should assign a trimmed role to an existing member
+select lives_ok(
+    format(
+        $$select update_group_member_role(%L::uuid, %L::uuid, %L::uuid, '  Mentor  ')$$,
+        :'organizerID', :'groupID', :'memberID'
+    ),
+    'Should assign a trimmed role to an existing member'
+);
+select results_eq(
+    format(
+        $$select member_role from group_member where group_id = %L::uuid and user_id = %L::uuid$$,
+        :'groupID', :'memberID'
+    ),
+    $$ values ('Mentor'::text) $$,
+    'Role should be stored without surrounding whitespace'
+);
+
+-- Should create the expected audit row
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id,
+            details
+        from audit_log
+    $$,
+    format(
+        $$
+        values (
+            'group_member_role_updated',
+            %L::uuid,
+            %L::uuid,
+            %L::uuid,
+            'user',
+            %L::uuid,
+            jsonb_build_object('member_role', 'Mentor')
+        )
+        $$,
+        :'organizerID', :'communityID', :'groupID', :'memberID'
+    ),
+    'Should create the expected audit row'
+);
+
+-- Should clear the role when an empty value is provided
+select lives_ok(
+    format(
+        $$select update_group_member_role(%L::uuid, %L::uuid, %L::uuid, '')$$,
+        :'organizerID', :'groupID', :'memberID'
+    ),
+    'Should clear the role when an empty value is provided'
+);
+select results_eq(
+    format(
+        $$select member_role from group_member where group_id = %L::uuid and user_id = %L::uuid$$,
+        :'groupID', :'memberID'
+    ),
+    $$ values (null::text) $$,
+    'Role should be cleared'
+);
+
+-- Should reject roles longer than the allowed length
+select throws_ok(
+    format(
+        $$select update_group_member_role(%L::uuid, %L::uuid, %L::uuid, %L)$$,
+        :'organizerID', :'groupID', :'memberID', repeat('a', 51)
+    ),
+    '23514',
+    null,
+    'Should reject roles longer than the allowed length'
+);
+
+-- Should error when the user is not a member of the group
+select throws_ok(
+    format(
+        $$select update_group_member_role(%L::uuid, %L::uuid, %L::uuid, 'Mentor')$$,
+        :'organizerID', :'groupID', :'nonMemberID'
+    ),
+    'user is not a member of this group',
+    'Should error when the user is not a member of the group'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
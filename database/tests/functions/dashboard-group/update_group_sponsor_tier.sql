@@ -0,0 +1,99 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1070000-0000-0000-0000-000000000001'
+\set communityID 'c1070000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c1070000-0000-0000-0000-000000000003'
+\set groupID 'c1070000-0000-0000-0000-000000000004'
+\set goldTierID 'c1070000-0000-0000-0000-000000000005'
+\set silverTierID 'c1070000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Sponsor tiers
+insert into group_sponsor_tier (group_sponsor_tier_id, group_id, name) values
+    (:'goldTierID', :'groupID', 'Gold'),
+    (:'silverTierID', :'groupID', 'Silver');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should update the tier
+select lives_ok(
+    format(
+        $$ select update_group_sponsor_tier(%L::uuid, %L::uuid, %L::uuid, '{"name": " Platinum ", "logo_size": "large", "order": 2}'::jsonb) $$,
+        :'actorID', :'groupID', :'goldTierID'
+    ),
+    'Should update the tier'
+);
+select results_eq(
+    format($$ select name, logo_size, "order" from group_sponsor_tier where group_sponsor_tier_id = %L::uuid $$, :'goldTierID'),
+    $$ values ('Platinum'::text, 'large'::text, 2) $$,
+    'Should store the updated tier values'
+);
+
+-- Should track the tier update in the audit log
+select results_eq(
+    $$ select action, resource_id from audit_log $$,
+    format($$ values ('group_sponsor_tier_updated'::text, %L::uuid) $$, :'goldTierID'),
+    'Should track the tier update in the audit log'
+);
+
+-- Should reject renaming a tier to the name of another tier of the group
+select throws_ok(
+    format(
+        $$ select update_group_sponsor_tier(%L::uuid, %L::uuid, %L::uuid, '{"name": "Silver"}'::jsonb) $$,
+        :'actorID', :'groupID', :'goldTierID'
+    ),
+    'sponsor tier already exists',
+    'Should reject renaming a tier to the name of another tier of the group'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(8);
+select plan(9);
 
 -- ============================================================================
 -- VARIABLES
@@ -152,6 +152,18 @@ select throws_ok(
     'Should error when updating role to an invalid value'
 );
 
+-- Should block demoting the group owner
+update "group" set owner_user_id = :'user1ID' where group_id = :'groupID';
+select throws_ok(
+    format(
+        $$select update_group_team_member_role(null::uuid, %L::uuid, %L::uuid, 'viewer')$$,
+        :'groupID', :'user1ID'
+    ),
+    'cannot change role for the group owner, transfer the ownership first',
+    'Should block demoting the group owner'
+);
+update "group" set owner_user_id = null where group_id = :'groupID';
+
 -- Should allow demoting an admin when another accepted admin remains
 select lives_ok(
     format(
@@ -0,0 +1,204 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(9);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0a30000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0a30000-0000-0000-0000-000000000002'
+\set groupID 'c0a30000-0000-0000-0000-000000000003'
+\set otherGroupID 'c0a30000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cloud-native-seville',
+    'Cloud Native Seville',
+    'Community for cloud native technologies in Seville',
+    'https://example.com/banner_mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group Seville', 'group-seville'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Other Group', 'other-group');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should require a client secret when adding new credentials
+select throws_ok(
+    format(
+        $$select update_group_zoom_credentials(null::uuid, %L::uuid, '{
+            "account_id": "account-1",
+            "client_id": "client-1",
+            "client_secret": " ",
+            "host_user": "host@example.com",
+            "webhook_secret_token": "webhook-secret-1"
+        }'::jsonb)$$,
+        :'groupID'
+    ),
+    'zoom client secret is required',
+    'Should require a client secret when adding new credentials'
+);
+
+-- Should require a webhook secret token when adding new credentials
+select throws_ok(
+    format(
+        $$select update_group_zoom_credentials(null::uuid, %L::uuid, '{
+            "account_id": "account-1",
+            "client_id": "client-1",
+            "client_secret": "secret-1",
+            "host_user": "host@example.com",
+            "webhook_secret_token": " "
+        }'::jsonb)$$,
+        :'groupID'
+    ),
+    'zoom webhook secret token is required',
+    'Should require a webhook secret token when adding new credentials'
+);
+
+-- Should add new credentials
+select lives_ok(
+    format(
+        $$select update_group_zoom_credentials(null::uuid, %L::uuid, '{
+            "account_id": "account-1",
+            "client_id": "client-1",
+            "client_secret": "secret-1",
+            "host_user": "host@example.com",
+            "webhook_secret_token": "webhook-secret-1"
+        }'::jsonb)$$,
+        :'groupID'
+    ),
+    'Should add new credentials'
+);
+
+select results_eq(
+    format(
+        $$
+        select account_id, client_id, client_secret, host_user, webhook_secret_token
+        from group_zoom_credentials
+        where group_id = %L::uuid
+        $$,
+        :'groupID'
+    ),
+    $$ values ('account-1', 'client-1', 'secret-1', 'host@example.com', 'webhook-secret-1') $$,
+    'Should store the new credentials'
+);
+
+-- Should keep the stored secrets when new ones are not provided
+select lives_ok(
+    format(
+        $$select update_group_zoom_credentials(null::uuid, %L::uuid, '{
+            "account_id": "account-2",
+            "client_id": "client-2",
+            "host_user": "host2@example.com"
+        }'::jsonb)$$,
+        :'groupID'
+    ),
+    'Should update credentials without new secrets'
+);
+
+select results_eq(
+    format(
+        $$
+        select
+            account_id,
+            client_id,
+            client_secret,
+            host_user,
+            webhook_secret_token,
+            updated_at is not null
+        from group_zoom_credentials
+        where group_id = %L::uuid
+        $$,
+        :'groupID'
+    ),
+    $$ values ('account-2', 'client-2', 'secret-1', 'host2@example.com', 'webhook-secret-1', true) $$,
+    'Should keep the stored secrets when new ones are not provided'
+);
+
+-- Should replace the stored secrets when new ones are provided
+select lives_ok(
+    format(
+        $$select update_group_zoom_credentials(null::uuid, %L::uuid, '{
+            "account_id": "account-2",
+            "client_id": "client-2",
+            "client_secret": "secret-2",
+            "host_user": "host2@example.com",
+            "webhook_secret_token": "webhook-secret-2"
+        }'::jsonb)$$,
+        :'groupID'
+    ),
+    'Should update credentials with new secrets'
+);
+
+select results_eq(
+    format(
+        $$
+        select client_secret, webhook_secret_token
+        from group_zoom_credentials
+        where group_id = %L::uuid
+        $$,
+        :'groupID'
+    ),
+    $$ values ('secret-2', 'webhook-secret-2') $$,
+    'Should replace the stored secrets when new ones are provided'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            actor_username,
+            community_id,
+            group_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('group_zoom_credentials_updated', null::uuid, null::text, %1$L::uuid, %2$L::uuid, 'group', %2$L::uuid),
+            ('group_zoom_credentials_updated', null::uuid, null::text, %1$L::uuid, %2$L::uuid, 'group', %2$L::uuid),
+            ('group_zoom_credentials_updated', null::uuid, null::text, %1$L::uuid, %2$L::uuid, 'group', %2$L::uuid)
+        $$,
+        :'communityID', :'groupID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
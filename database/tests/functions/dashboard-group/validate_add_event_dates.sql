@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(10);
+select plan(12);
 
 -- ============================================================================
 -- TESTS
@@ -105,6 +105,32 @@ select throws_ok(
     'Should reject open-only registration windows that open after the event start'
 );
 
+-- Should reject scheduled publications in the past
+select throws_ok(
+    $$select validate_add_event_dates(
+        jsonb_build_object(
+            'starts_at', to_char(current_timestamp at time zone 'UTC' + interval '1 day', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'publish_scheduled_at', to_char(current_timestamp at time zone 'UTC' - interval '1 hour', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'timezone', 'UTC'
+        )
+    )$$,
+    'event publish_scheduled_at cannot be in the past',
+    'Should reject scheduled publications in the past'
+);
+
+-- Should reject scheduled publications after the event start
+select throws_ok(
+    $$select validate_add_event_dates(
+        jsonb_build_object(
+            'starts_at', to_char(current_timestamp at time zone 'UTC' + interval '1 day', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'publish_scheduled_at', to_char(current_timestamp at time zone 'UTC' + interval '2 days', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'timezone', 'UTC'
+        )
+    )$$,
+    'event publish_scheduled_at must be before event starts_at',
+    'Should reject scheduled publications after the event start'
+);
+
 -- Should reject session starts_at in the past
 select throws_ok(
     $$select validate_add_event_dates(
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(9);
+select plan(10);
 
 -- ============================================================================
 -- VARIABLES
@@ -209,6 +209,15 @@ select lives_ok(
     'Should ignore provider limits when no meeting is requested'
 );
 
+-- Should ignore meeting provider limits for webinars
+select lives_ok(
+    $$select validate_event_capacity(
+        '{"capacity": 200, "meeting_requested": true, "meeting_provider_id": "zoom", "meeting_webinar": true}'::jsonb,
+        '{"zoom": 100}'::jsonb
+    )$$,
+    'Should ignore meeting provider limits for webinars'
+);
+
 -- Should validate provider limits against the effective capacity override
 select throws_ok(
     $$select validate_event_capacity(
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(10);
+select plan(11);
 
 -- ============================================================================
 -- TESTS
@@ -40,7 +40,31 @@ select throws_ok(
     'Should reject waitlists for ticketed events'
 );
 
--- Should require a payment currency for ticketed events
+-- Should accept free ticket types without a payment currency
+select lives_ok(
+    $$select validate_event_ticketing_payload(
+        null,
+        null,
+        '[
+            {
+                "event_ticket_type_id": "3a470000-0000-0000-0000-000000000003",
+                "order": 1,
+                "price_windows": [
+                    {
+                        "amount_minor": 0,
+                        "event_ticket_price_window_id": "3a470000-0000-0000-0000-000000000005"
+                    }
+                ],
+                "seats_total": 50,
+                "title": "Student"
+            }
+        ]'::jsonb,
+        false
+    )$$,
+    'Should accept free ticket types without a payment currency'
+);
+
+-- Should require a payment currency for paid ticket types
 select throws_ok(
     $$select validate_event_ticketing_payload(
         null,
@@ -61,8 +85,8 @@ select throws_ok(
         ]'::jsonb,
         false
     )$$,
-    'ticketed events require payment_currency_code',
-    'Should require a payment currency for ticketed events'
+    'paid ticket types require payment_currency_code',
+    'Should require a payment currency for paid ticket types'
 );
 
 -- Should reject unsupported payment currencies for ticketed events
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(13);
+select plan(16);
 
 -- ============================================================================
 -- TESTS
@@ -111,6 +111,56 @@ select throws_ok(
     'Should reject open-only registration windows that open after the event start'
 );
 
+-- Should reject scheduled publications in the past
+select throws_ok(
+    $$select validate_update_event_dates(
+        jsonb_build_object(
+            'starts_at', to_char(current_timestamp at time zone 'UTC' + interval '3 days', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'publish_scheduled_at', to_char(current_timestamp at time zone 'UTC' - interval '1 hour', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'timezone', 'UTC'
+        ),
+        jsonb_build_object(
+            'published', false,
+            'starts_at', floor(extract(epoch from current_timestamp + interval '3 days'))::bigint
+        )
+    )$$,
+    'event publish_scheduled_at cannot be in the past',
+    'Should reject scheduled publications in the past'
+);
+
+-- Should reject scheduled publications after the event start
+select throws_ok(
+    $$select validate_update_event_dates(
+        jsonb_build_object(
+            'starts_at', to_char(current_timestamp at time zone 'UTC' + interval '3 days', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'publish_scheduled_at', to_char(current_timestamp at time zone 'UTC' + interval '4 days', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'timezone', 'UTC'
+        ),
+        jsonb_build_object(
+            'published', false,
+            'starts_at', floor(extract(epoch from current_timestamp + interval '3 days'))::bigint
+        )
+    )$$,
+    'event publish_scheduled_at must be before event starts_at',
+    'Should reject scheduled publications after the event start'
+);
+
+-- Should ignore scheduled publications for published events
+select lives_ok(
+    $$select validate_update_event_dates(
+        jsonb_build_object(
+            'starts_at', to_char(current_timestamp at time zone 'UTC' + interval '3 days', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'publish_scheduled_at', to_char(current_timestamp at time zone 'UTC' - interval '1 hour', 'YYYY-MM-DD"T"HH24:MI:SS'),
+            'timezone', 'UTC'
+        ),
+        jsonb_build_object(
+            'published', true,
+            'starts_at', floor(extract(epoch from current_timestamp + interval '3 days'))::bigint
+        )
+    )$$,
+    'Should ignore scheduled publications for published events'
+);
+
 -- Should reject a past event that moves into the future
 select throws_ok(
     $$select validate_update_event_dates(
@@ -3,12 +3,13 @@
 -- ============================================================================
 
 begin;
-select plan(5);
+select plan(6);
 
 -- ============================================================================
 -- VARIABLES
 -- ============================================================================
 
+\set bannedUserID '4a020000-0000-0000-0000-000000000007'
 \set communityID '4a020000-0000-0000-0000-000000000001'
 \set eventCategoryID '4a020000-0000-0000-0000-000000000002'
 \set eventID '4a020000-0000-0000-0000-000000000003'
@@ -62,6 +63,13 @@ insert into "user" (
     true,
     'invited',
     'Invited User'
+), (
+    :'bannedUserID',
+    'hash-banned',
+    'banned@example.com',
+    true,
+    'banned',
+    'Banned User'
 );
 
 -- Group
@@ -99,7 +107,13 @@ insert into event (
 
 -- Event invitations
 insert into event_attendee (event_id, user_id, manually_invited, status)
-values (:'eventID', :'invitedUserID', true, 'invitation-pending');
+values
+    (:'eventID', :'bannedUserID', true, 'invitation-pending'),
+    (:'eventID', :'invitedUserID', true, 'invitation-pending');
+
+-- Group ban
+insert into group_ban (group_id, user_id, reason)
+values (:'groupID', :'bannedUserID', 'Spam');
 
 -- ============================================================================
 -- TESTS
@@ -136,6 +150,19 @@ select throws_ok(
     'Should reject accepting non-pending invitations'
 );
 
+-- Should reject accepting invitations from users banned from the group.
+select throws_ok(
+    format(
+        $$
+            select accept_event_attendee_invitation(%L::uuid, %L::uuid)
+        $$,
+        :'bannedUserID',
+        :'eventID'
+    ),
+    'user is banned from this group',
+    'Should reject accepting invitations from users banned from the group'
+);
+
 -- Should create the expected audit row.
 select results_eq(
     $$
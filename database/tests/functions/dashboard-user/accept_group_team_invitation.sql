@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(4);
+select plan(5);
 
 -- ============================================================================
 -- VARIABLES
@@ -83,6 +83,16 @@ select results_eq(
     'Invite should be marked as accepted'
 );
 
+-- Should make the first accepted admin the group owner
+select results_eq(
+    format(
+        $$ select owner_user_id from "group" where group_id = %L::uuid $$,
+        :'groupID'
+    ),
+    format($$ values (%L::uuid) $$, :'user1ID'),
+    'Should make the first accepted admin the group owner'
+);
+
 -- Should create the expected audit row
 select results_eq(
     $$
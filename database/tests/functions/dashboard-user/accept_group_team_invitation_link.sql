@@ -0,0 +1,127 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0c50000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0c50000-0000-0000-0000-000000000002'
+\set groupID 'c0c50000-0000-0000-0000-000000000003'
+\set otherUserID 'c0c50000-0000-0000-0000-000000000004'
+\set user1ID 'c0c50000-0000-0000-0000-000000000005'
+\set user2ID 'c0c50000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'group-invitation-community',
+    'Group Invitation Community',
+    'Community for testing group invitation links',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'otherUserID', gen_random_bytes(32), 'other@example.com', true, 'other'),
+    (:'user1ID', gen_random_bytes(32), 'alice@example.com', true, 'alice'),
+    (:'user2ID', gen_random_bytes(32), 'bob@example.com', true, 'bob');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Invitation Group', 'invitation-group');
+
+-- Pending invitations
+insert into group_team (group_id, user_id, role, accepted)
+values
+    (:'groupID', :'user1ID', 'events-manager', false),
+    (:'groupID', :'user2ID', 'viewer', false);
+
+-- Invitation links
+insert into group_team_invitation_link (expires_at, group_id, token_hash, user_id)
+values
+    (current_timestamp + interval '7 days', :'groupID', 'token-hash-alice', :'user1ID'),
+    (current_timestamp - interval '1 minute', :'groupID', 'token-hash-bob', :'user2ID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not accept links issued to other users
+select is(
+    accept_group_team_invitation_link(:'otherUserID'::uuid, 'token-hash-alice'),
+    false,
+    'Should not accept links issued to other users'
+);
+
+-- Should not accept expired links
+select is(
+    accept_group_team_invitation_link(:'user2ID'::uuid, 'token-hash-bob'),
+    false,
+    'Should not accept expired links'
+);
+
+-- Should accept the invitation the link was issued for
+select is(
+    accept_group_team_invitation_link(:'user1ID'::uuid, 'token-hash-alice'),
+    true,
+    'Should accept the invitation the link was issued for'
+);
+select results_eq(
+    format(
+        $$
+            select accepted
+            from group_team
+            where group_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'groupID',
+        :'user1ID'
+    ),
+    $$ values (true) $$,
+    'Invitation should be marked as accepted'
+);
+
+-- Should discard the links issued for the accepted invitation
+select is(
+    (select count(*) from group_team_invitation_link where user_id = :'user1ID'::uuid),
+    0::bigint,
+    'Should discard the links issued for the accepted invitation'
+);
+
+-- Should not accept links that were already used
+select is(
+    accept_group_team_invitation_link(:'user1ID'::uuid, 'token-hash-alice'),
+    false,
+    'Should not accept links that were already used'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
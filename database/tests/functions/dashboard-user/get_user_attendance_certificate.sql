@@ -0,0 +1,175 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set canceledEventID 'c0e40000-0000-0000-0000-000000000001'
+\set communityID 'c0e40000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0e40000-0000-0000-0000-000000000003'
+\set eventID 'c0e40000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0e40000-0000-0000-0000-000000000005'
+\set groupID 'c0e40000-0000-0000-0000-000000000006'
+\set siteID 'c0e40000-0000-0000-0000-000000000007'
+\set upcomingEventID 'c0e40000-0000-0000-0000-000000000008'
+\set userCheckedInID 'c0e40000-0000-0000-0000-000000000009'
+\set userNoNameID 'c0e40000-0000-0000-0000-000000000010'
+\set userNotCheckedInID 'c0e40000-0000-0000-0000-000000000011'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Site settings
+insert into site (site_id, title, description, theme) values (
+    :'siteID',
+    'Test Site',
+    'Test Site Description',
+    '{"primary_color": "#2563eb"}'::jsonb
+);
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'certificates-community',
+    'Certificates Community',
+    'Community for testing attendance certificates',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Categories
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Certificates Group', 'certificates');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, name)
+values
+    (:'userCheckedInID', 'hash-1', 'checked-in@example.com', true, 'checked-in', 'Checked In User'),
+    (:'userNoNameID', 'hash-2', 'no-name@example.com', true, 'no-name', null),
+    (:'userNotCheckedInID', 'hash-3', 'not-checked-in@example.com', true, 'not-checked-in', 'Not Checked In');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    ends_at,
+    published,
+    canceled
+) values
+    (:'eventID', :'groupID', 'Past Event', 'past-event', 'Past', 'Europe/Madrid',
+        :'eventCategoryID', 'in-person', '2024-01-02 10:00:00+00', '2024-01-02 12:00:00+00',
+        true, false),
+    (:'canceledEventID', :'groupID', 'Canceled Event', 'canceled-event', 'Canceled', 'UTC',
+        :'eventCategoryID', 'in-person', '2024-01-03 10:00:00+00', '2024-01-03 12:00:00+00',
+        true, true),
+    (:'upcomingEventID', :'groupID', 'Upcoming Event', 'upcoming-event', 'Upcoming', 'UTC',
+        :'eventCategoryID', 'in-person', current_timestamp + interval '1 day',
+        current_timestamp + interval '1 day 2 hours', true, false);
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in, status) values
+    (:'eventID', :'userCheckedInID', true, 'confirmed'),
+    (:'eventID', :'userNoNameID', true, 'confirmed'),
+    (:'eventID', :'userNotCheckedInID', false, 'confirmed'),
+    (:'canceledEventID', :'userCheckedInID', true, 'confirmed'),
+    (:'upcomingEventID', :'userCheckedInID', true, 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the certificate of a checked-in attendee of a past event
+select is(
+    get_user_attendance_certificate(:'userCheckedInID'::uuid, :'eventID'::uuid)::jsonb,
+    format(
+        $json$
+            {
+                "attendee_name": "Checked In User",
+                "community_display_name": "Certificates Community",
+                "event_id": "%s",
+                "event_name": "Past Event",
+                "event_slug": "past-event",
+                "group_name": "Certificates Group",
+                "primary_color": "#2563eb",
+                "starts_at": 1704189600,
+                "timezone": "Europe/Madrid",
+                "ends_at": 1704196800,
+                "logo_url": "https://example.com/logo.png"
+            }
+        $json$,
+        :'eventID'
+    )::jsonb,
+    'Should return the certificate of a checked-in attendee of a past event'
+);
+
+-- Should use the username when the attendee has no name
+select is(
+    get_user_attendance_certificate(:'userNoNameID'::uuid, :'eventID'::uuid)::jsonb->>'attendee_name',
+    'no-name',
+    'Should use the username when the attendee has no name'
+);
+
+-- Should use the community email primary color when configured
+update community set email_primary_color = '#123456' where community_id = :'communityID';
+select is(
+    get_user_attendance_certificate(:'userCheckedInID'::uuid, :'eventID'::uuid)::jsonb->>'primary_color',
+    '#123456',
+    'Should use the community email primary color when configured'
+);
+
+-- Should not return a certificate for attendees who did not check in
+select is(
+    get_user_attendance_certificate(:'userNotCheckedInID'::uuid, :'eventID'::uuid)::jsonb,
+    null::jsonb,
+    'Should not return a certificate for attendees who did not check in'
+);
+
+-- Should not return a certificate for canceled events
+select is(
+    get_user_attendance_certificate(:'userCheckedInID'::uuid, :'canceledEventID'::uuid)::jsonb,
+    null::jsonb,
+    'Should not return a certificate for canceled events'
+);
+
+-- Should not return a certificate for events that have not ended yet
+select is(
+    get_user_attendance_certificate(:'userCheckedInID'::uuid, :'upcomingEventID'::uuid)::jsonb,
+    null::jsonb,
+    'Should not return a certificate for events that have not ended yet'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,121 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0e50000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0e50000-0000-0000-0000-000000000002'
+\set eventNotCheckedInID 'c0e50000-0000-0000-0000-000000000003'
+\set eventOlderID 'c0e50000-0000-0000-0000-000000000004'
+\set eventRecentID 'c0e50000-0000-0000-0000-000000000005'
+\set eventUpcomingID 'c0e50000-0000-0000-0000-000000000006'
+\set groupCategoryID 'c0e50000-0000-0000-0000-000000000007'
+\set groupID 'c0e50000-0000-0000-0000-000000000008'
+\set userID 'c0e50000-0000-0000-0000-000000000009'
+\set userWithoutCertificatesID 'c0e50000-0000-0000-0000-000000000010'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'certificates-list-community',
+    'Certificates List Community',
+    'Community for testing attendance certificates listings',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Categories
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Certificates Group', 'certificates');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, name)
+values
+    (:'userID', 'hash-1', 'user@example.com', true, 'user', 'Certificates User'),
+    (:'userWithoutCertificatesID', 'hash-2', 'other@example.com', true, 'other', 'Other User');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    published
+) values
+    (:'eventNotCheckedInID', :'groupID', 'Not Checked In Event', 'not-checked-in-event',
+        'Not checked in', 'UTC', :'eventCategoryID', 'virtual', '2024-03-01 10:00:00+00', true),
+    (:'eventOlderID', :'groupID', 'Older Event', 'older-event', 'Older', 'UTC',
+        :'eventCategoryID', 'virtual', '2024-01-01 10:00:00+00', true),
+    (:'eventRecentID', :'groupID', 'Recent Event', 'recent-event', 'Recent', 'UTC',
+        :'eventCategoryID', 'virtual', '2024-02-01 10:00:00+00', true),
+    (:'eventUpcomingID', :'groupID', 'Upcoming Event', 'upcoming-event', 'Upcoming', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '1 day', true);
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in, status) values
+    (:'eventNotCheckedInID', :'userID', false, 'confirmed'),
+    (:'eventOlderID', :'userID', true, 'confirmed'),
+    (:'eventRecentID', :'userID', true, 'confirmed'),
+    (:'eventUpcomingID', :'userID', true, 'confirmed'),
+    (:'eventRecentID', :'userWithoutCertificatesID', false, 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list the certificates of the past events the user checked in to
+select is(
+    (
+        select jsonb_agg(c.certificate->>'event_id' order by c.position)
+        from jsonb_array_elements(
+            list_user_attendance_certificates(:'userID'::uuid)::jsonb
+        ) with ordinality as c(certificate, position)
+    ),
+    jsonb_build_array(:'eventRecentID', :'eventOlderID'),
+    'Should list the certificates of the past events the user checked in to, most recent first'
+);
+
+-- Should return an empty list when the user has no certificates
+select is(
+    list_user_attendance_certificates(:'userWithoutCertificatesID'::uuid)::text,
+    '[]',
+    'Should return an empty list when the user has no certificates'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,94 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1110000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c1110000-0000-0000-0000-000000000002'
+\set groupID 'c1110000-0000-0000-0000-000000000003'
+\set newOwnerID 'c1110000-0000-0000-0000-000000000004'
+\set ownerID 'c1110000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'list-ownership-community',
+    'List Ownership Community',
+    'Community for listing ownership transfers tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'newOwnerID', gen_random_bytes(32), 'bob@example.com', true, 'bob'),
+    (:'ownerID', gen_random_bytes(32), 'alice@example.com', true, 'alice');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, owner_user_id)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Owned Group', 'owned-group', :'ownerID');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted) values
+    (:'groupID', :'newOwnerID', 'viewer', true),
+    (:'groupID', :'ownerID', 'admin', true);
+
+-- Pending transfer
+insert into group_ownership_transfer (group_id, created_at, created_by, user_id)
+values (:'groupID', '2024-01-01 00:00:00+00', :'ownerID', :'newOwnerID');
+
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list the transfers pending the acceptance of the user
+select is(
+    list_user_group_ownership_transfers(:'newOwnerID'::uuid)::jsonb,
+    format('[
+        {
+            "community_name": "list-ownership-community",
+            "created_at": 1704067200,
+            "group_id": "%s",
+            "group_name": "Owned Group"
+        }
+    ]', :'groupID')::jsonb,
+    'Should list the transfers pending the acceptance of the user'
+);
+
+-- Should return an empty list for users without pending transfers
+select is(
+    list_user_group_ownership_transfers(:'ownerID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for users without pending transfers'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,131 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendedEventID 'c11e0000-0000-0000-0000-000000000001'
+\set communityID 'c11e0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c11e0000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c11e0000-0000-0000-0000-000000000004'
+\set groupID 'c11e0000-0000-0000-0000-000000000005'
+\set otherTopicEventID 'c11e0000-0000-0000-0000-000000000006'
+\set pastEventID 'c11e0000-0000-0000-0000-000000000007'
+\set suggestedEventID 'c11e0000-0000-0000-0000-000000000008'
+\set topic1ID 'c11e0000-0000-0000-0000-000000000009'
+\set topic2ID 'c11e0000-0000-0000-0000-00000000000a'
+\set userID 'c11e0000-0000-0000-0000-00000000000b'
+\set userWithoutInterestsID 'c11e0000-0000-0000-0000-00000000000c'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cncf-seattle',
+    'CNCF Seattle',
+    'Community for suggested events tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username, interests)
+values
+    (:'userID', 'auth-hash', 'alice@example.com', true, 'alice', array['kubernetes', 'Cloud Security']),
+    (:'userWithoutInterestsID', 'auth-hash', 'bob@example.com', true, 'bob', null);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetup');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Topic Group', 'topic-group');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    event_category_id,
+    event_kind_id,
+    timezone,
+    published,
+    starts_at
+) values
+    (:'attendedEventID', :'groupID', 'Attended Event', 'attended-event', 'Attended event',
+        :'eventCategoryID', 'in-person', 'UTC', true, now() + interval '2 days'),
+    (:'otherTopicEventID', :'groupID', 'Other Topic Event', 'other-topic-event', 'Other topic event',
+        :'eventCategoryID', 'in-person', 'UTC', true, now() + interval '3 days'),
+    (:'pastEventID', :'groupID', 'Past Event', 'past-event', 'Past event',
+        :'eventCategoryID', 'in-person', 'UTC', true, now() - interval '3 days'),
+    (:'suggestedEventID', :'groupID', 'Suggested Event', 'suggested-event', 'Suggested event',
+        :'eventCategoryID', 'in-person', 'UTC', true, now() + interval '4 days');
+
+-- Topics
+insert into topic (topic_id, community_id, name)
+values
+    (:'topic1ID', :'communityID', 'Kubernetes'),
+    (:'topic2ID', :'communityID', 'Observability');
+
+-- Event topics
+insert into event_topic (event_id, topic_id)
+values
+    (:'attendedEventID', :'topic1ID'),
+    (:'otherTopicEventID', :'topic2ID'),
+    (:'pastEventID', :'topic1ID'),
+    (:'suggestedEventID', :'topic1ID');
+
+-- Event attendee
+insert into event_attendee (event_id, user_id)
+values (:'attendedEventID', :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return upcoming events matching the user interests not attended yet
+select is(
+    list_user_suggested_events(:'userID'::uuid)::jsonb,
+    jsonb_build_array(
+        get_event_summary(:'communityID'::uuid, :'groupID'::uuid, :'suggestedEventID'::uuid)::jsonb
+    ),
+    'Should return upcoming events matching the user interests not attended yet'
+);
+
+-- Should return an empty list when the user has no interests
+select is(
+    list_user_suggested_events(:'userWithoutInterestsID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the user has no interests'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,220 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendeeID 'c0da0000-0000-0000-0000-000000000001'
+\set commentID 'c0da0000-0000-0000-0000-000000000002'
+\set communityID 'c0da0000-0000-0000-0000-000000000003'
+\set eventCategoryID 'c0da0000-0000-0000-0000-000000000004'
+\set eventID 'c0da0000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0da0000-0000-0000-0000-000000000006'
+\set groupID 'c0da0000-0000-0000-0000-000000000007'
+\set lockedEventID 'c0da0000-0000-0000-0000-000000000008'
+\set nonAttendeeID 'c0da0000-0000-0000-0000-000000000009'
+\set organizerID 'c0da0000-0000-0000-0000-000000000010'
+\set otherEventCommentID 'c0da0000-0000-0000-0000-000000000011'
+\set otherEventID 'c0da0000-0000-0000-0000-000000000012'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-attendee', 'attendee@example.com', true, 'Attendee', :'attendeeID', 'attendee'),
+    ('hash-non-attendee', 'non-attendee@example.com', true, 'Non Attendee', :'nonAttendeeID', 'non-attendee'),
+    ('hash-organizer', 'organizer@example.com', true, 'Organizer', :'organizerID', 'organizer');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted)
+values (:'groupID', :'organizerID', 'admin', true);
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    comments_locked,
+    starts_at
+) values
+    (
+        :'eventID',
+        'Upcoming Event',
+        'upcoming-event',
+        'Upcoming event with a discussion',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        false,
+        current_timestamp + interval '2 days'
+    ),
+    (
+        :'lockedEventID',
+        'Locked Event',
+        'locked-event',
+        'Upcoming event with a locked discussion',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        true,
+        current_timestamp + interval '2 days'
+    ),
+    (
+        :'otherEventID',
+        'Other Event',
+        'other-event',
+        'Another upcoming event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        false,
+        current_timestamp + interval '3 days'
+    );
+
+-- Event attendees
+insert into event_attendee (event_id, user_id)
+values
+    (:'eventID', :'attendeeID'),
+    (:'lockedEventID', :'attendeeID');
+
+-- Event comments
+insert into event_comment (event_comment_id, event_id, user_id, content)
+values
+    (:'commentID', :'eventID', :'attendeeID', 'Will the talks be recorded?'),
+    (:'otherEventCommentID', :'otherEventID', :'organizerID', 'Welcome!');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add a comment posted by an attendee
+select lives_ok(
+    format(
+        $$ select add_event_comment(%L::uuid, %L::uuid, %L::uuid, '  Is there parking nearby?  ', null) $$,
+        :'communityID', :'eventID', :'attendeeID'
+    ),
+    'Should add a comment posted by an attendee'
+);
+select results_eq(
+    format(
+        $$ select content from event_comment where event_id = %L::uuid and user_id = %L::uuid and event_comment_id <> %L::uuid $$,
+        :'eventID', :'attendeeID', :'commentID'
+    ),
+    $$ values ('Is there parking nearby?'::text) $$,
+    'Should store the trimmed comment content'
+);
+
+-- Should add a reply posted by an organizer
+select lives_ok(
+    format(
+        $$ select add_event_comment(%L::uuid, %L::uuid, %L::uuid, 'Yes, they will.', %L::uuid) $$,
+        :'communityID', :'eventID', :'organizerID', :'commentID'
+    ),
+    'Should add a reply posted by an organizer'
+);
+select results_eq(
+    format(
+        $$ select parent_event_comment_id from event_comment where event_id = %L::uuid and user_id = %L::uuid $$,
+        :'eventID', :'organizerID'
+    ),
+    format($$ values (%L::uuid) $$, :'commentID'),
+    'Should link the reply to the parent comment'
+);
+
+-- Should reject users not attending the event
+select throws_ok(
+    format(
+        $$ select add_event_comment(%L::uuid, %L::uuid, %L::uuid, 'Hello', null) $$,
+        :'communityID', :'eventID', :'nonAttendeeID'
+    ),
+    'only event attendees can post comments',
+    'Should reject users not attending the event'
+);
+
+-- Should reject locked discussions
+select throws_ok(
+    format(
+        $$ select add_event_comment(%L::uuid, %L::uuid, %L::uuid, 'Hello', null) $$,
+        :'communityID', :'lockedEventID', :'attendeeID'
+    ),
+    'the discussion for this event is locked',
+    'Should reject locked discussions'
+);
+
+-- Should reject replies to comments from other events
+select throws_ok(
+    format(
+        $$ select add_event_comment(%L::uuid, %L::uuid, %L::uuid, 'Hello', %L::uuid) $$,
+        :'communityID', :'eventID', :'attendeeID', :'otherEventCommentID'
+    ),
+    'comment not found',
+    'Should reject replies to comments from other events'
+);
+
+-- Should reject events from other communities
+select throws_ok(
+    format(
+        $$ select add_event_comment(%L::uuid, %L::uuid, %L::uuid, 'Hello', null) $$,
+        :'groupID', :'eventID', :'attendeeID'
+    ),
+    'event not found or inactive',
+    'Should reject events from other communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,225 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendeeID 'c0d30000-0000-0000-0000-000000000001'
+\set communityID 'c0d30000-0000-0000-0000-000000000002'
+\set disabledEventID 'c0d30000-0000-0000-0000-000000000003'
+\set eventCategoryID 'c0d30000-0000-0000-0000-000000000004'
+\set eventID 'c0d30000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0d30000-0000-0000-0000-000000000006'
+\set groupID 'c0d30000-0000-0000-0000-000000000007'
+\set nonAttendeeID 'c0d30000-0000-0000-0000-000000000008'
+\set otherCommunityID 'c0d30000-0000-0000-0000-000000000009'
+\set upcomingEventID 'c0d30000-0000-0000-0000-000000000010'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values
+    (
+        :'communityID',
+        'test-community',
+        'Test Community',
+        'A test community',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    ),
+    (
+        :'otherCommunityID',
+        'other-community',
+        'Other Community',
+        'Another test community',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    );
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-attendee', 'attendee@example.com', true, 'Attendee', :'attendeeID', 'attendee'),
+    ('hash-non-attendee', 'non-attendee@example.com', true, 'Non Attendee', :'nonAttendeeID', 'non-attendee');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    attendee_photos_enabled,
+    starts_at,
+    ends_at
+) values
+    (
+        :'eventID',
+        'Past Event',
+        'past-event',
+        'Past event accepting photos',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        true,
+        current_timestamp - interval '2 days',
+        current_timestamp - interval '2 days' + interval '2 hours'
+    ),
+    (
+        :'disabledEventID',
+        'Past Event Without Photos',
+        'past-event-without-photos',
+        'Past event not accepting photos',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        false,
+        current_timestamp - interval '2 days',
+        current_timestamp - interval '2 days' + interval '2 hours'
+    ),
+    (
+        :'upcomingEventID',
+        'Upcoming Event',
+        'upcoming-event',
+        'Upcoming event accepting photos',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true,
+        true,
+        current_timestamp + interval '2 days',
+        current_timestamp + interval '2 days' + interval '2 hours'
+    );
+
+-- Attendees
+insert into event_attendee (event_id, user_id)
+values
+    (:'disabledEventID', :'attendeeID'),
+    (:'eventID', :'attendeeID'),
+    (:'upcomingEventID', :'attendeeID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add the photo pending review
+select lives_ok(
+    format(
+        'select add_event_photo(%L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'eventID', :'attendeeID', '/images/photo-1.png'
+    ),
+    'Should add the photo pending review'
+);
+select results_eq(
+    format('select status, url, user_id from event_photo where event_id = %L::uuid', :'eventID'),
+    format($$ values ('pending'::text, '/images/photo-1.png'::text, %L::uuid) $$, :'attendeeID'),
+    'Should store the photo as pending with its uploader'
+);
+
+-- Should reject photos already submitted
+select throws_ok(
+    format(
+        'select add_event_photo(%L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'eventID', :'attendeeID', '/images/photo-1.png'
+    ),
+    'photo already submitted',
+    'Should reject photos already submitted'
+);
+
+-- Should reject users who did not attend the event
+select throws_ok(
+    format(
+        'select add_event_photo(%L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'eventID', :'nonAttendeeID', '/images/photo-2.png'
+    ),
+    'only event attendees can submit photos',
+    'Should reject users who did not attend the event'
+);
+
+-- Should reject events not accepting photos
+select throws_ok(
+    format(
+        'select add_event_photo(%L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'disabledEventID', :'attendeeID', '/images/photo-2.png'
+    ),
+    'photo submissions are not enabled for this event',
+    'Should reject events not accepting photos'
+);
+
+-- Should reject events that have not ended yet
+select throws_ok(
+    format(
+        'select add_event_photo(%L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'upcomingEventID', :'attendeeID', '/images/photo-2.png'
+    ),
+    'photos can only be submitted once the event has ended',
+    'Should reject events that have not ended yet'
+);
+
+-- Should reject events from other communities
+select throws_ok(
+    format(
+        'select add_event_photo(%L::uuid, %L::uuid, %L::uuid, %L)',
+        :'otherCommunityID', :'eventID', :'attendeeID', '/images/photo-2.png'
+    ),
+    'event not found or inactive',
+    'Should reject events from other communities'
+);
+
+-- Should reject photos over the per attendee limit
+insert into event_photo (event_id, url, user_id)
+select :'eventID', '/images/extra-' || i || '.png', :'attendeeID'
+from generate_series(1, 9) as i;
+select throws_ok(
+    format(
+        'select add_event_photo(%L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'eventID', :'attendeeID', '/images/photo-2.png'
+    ),
+    'you can submit up to 10 photos per event',
+    'Should reject photos over the per attendee limit'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,12 +3,13 @@
 -- ============================================================================
 
 begin;
-select plan(58);
+select plan(61);
 
 -- ============================================================================
 -- VARIABLES
 -- ============================================================================
 
+\set bannedUserID '5e020000-0000-0000-0000-00000000002f'
 \set communityID '5e020000-0000-0000-0000-000000000001'
 \set eventCanceledID '5e020000-0000-0000-0000-000000000002'
 \set eventCategoryID '5e020000-0000-0000-0000-000000000003'
@@ -17,6 +18,8 @@ select plan(58);
 \set eventFullWaitlistID '5e020000-0000-0000-0000-000000000006'
 \set eventInactiveGroupID '5e020000-0000-0000-0000-000000000007'
 \set eventInviteOnlyID '5e020000-0000-0000-0000-000000000008'
+\set eventNoShowPastID '5e020000-0000-0000-0000-00000000002d'
+\set eventNoShowWaitlistID '5e020000-0000-0000-0000-00000000002e'
 \set eventOKID '5e020000-0000-0000-0000-000000000009'
 \set eventPastID '5e020000-0000-0000-0000-00000000000a'
 \set eventQuestionsApprovalID '5e020000-0000-0000-0000-00000000000b'
@@ -634,6 +637,57 @@ insert into event (
     true
 );
 
+-- Events sending frequent no-shows to the waitlist
+insert into event (
+    event_id,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    slug,
+    capacity,
+    description,
+    no_show_waitlist_threshold,
+    published,
+    starts_at,
+    timezone,
+    waitlist_enabled
+) values (
+    :'eventNoShowPastID',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    'No-Show Past',
+    'no-show-past',
+    null,
+    'Past event using check-in',
+    null,
+    true,
+    '2024-01-01 10:00:00+00',
+    'UTC',
+    false
+), (
+    :'eventNoShowWaitlistID',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    'No-Show Waitlist',
+    'no-show-waitlist',
+    10,
+    'Event waitlisting frequent no-shows',
+    1,
+    true,
+    '2030-01-06 10:00:00+00',
+    'UTC',
+    true
+);
+
+-- Past event attendees, one of them missing the event
+insert into event_attendee (event_id, user_id, checked_in)
+values
+    (:'eventNoShowPastID', :'user1ID', true),
+    (:'eventNoShowPastID', :'user2ID', false);
+
 -- Event attendees
 insert into event_attendee (event_id, user_id, status)
 values
@@ -733,6 +787,14 @@ values
         :'user1ID'
     );
 
+-- Banned user
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'bannedUserID', 'banned-hash', 'banned@example.com', true, 'banned');
+
+-- Group ban
+insert into group_ban (group_id, user_id, reason)
+values (:'groupID', :'bannedUserID', 'Spam');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -1197,6 +1259,20 @@ select throws_ok(
     'Rejects users whose invitation request was rejected'
 );
 
+-- Should give a seat to users who attended the group's past events
+select is(
+    attend_event(:'communityID'::uuid, :'eventNoShowWaitlistID'::uuid, :'user1ID'::uuid),
+    'attendee',
+    'Returns attendee when the user has no past no-shows'
+);
+
+-- Should place frequent no-shows on the waitlist even when seats are available
+select is(
+    attend_event(:'communityID'::uuid, :'eventNoShowWaitlistID'::uuid, :'user2ID'::uuid),
+    'waitlisted',
+    'Returns waitlisted when the user reached the no-show threshold'
+);
+
 -- Should reject duplicate waitlist joins
 select throws_ok(
     format(
@@ -1257,6 +1333,16 @@ select throws_ok(
     'Rejects events from inactive groups'
 );
 
+-- Should reject users banned from the event group
+select throws_ok(
+    format(
+        'select attend_event(%L::uuid,%L::uuid,%L::uuid)',
+        :'communityID', :'eventOKID', :'bannedUserID'
+    ),
+    'user is banned from this group',
+    'Rejects users banned from the event group'
+);
+
 -- Should start registration-question completion from a pending attendee
 select is(
     (
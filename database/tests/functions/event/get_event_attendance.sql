@@ -272,17 +272,19 @@ insert into event_ticket_type (
     (:'ticketTypeVIPID', true, :'eventID', 2, 100, 'VIP'),
     (:'questionsTicketTypeID', true, :'eventQuestionsID', 1, 100, 'Questions general admission');
 
--- Event Attendee - user1 is checked in
+-- Event Attendee - user1 is checked in and wants reminders 1 hour before
 insert into event_attendee (
     event_id,
     user_id,
     checked_in,
-    checked_in_at
+    checked_in_at,
+    reminder_preference
 ) values (
     :'eventID',
     :'user1ID',
     true,
-    current_timestamp
+    current_timestamp,
+    '1-hour'
 );
 
 -- Event Attendee - user2 is not checked in
@@ -446,10 +448,11 @@ select is(
         "is_checked_in": true,
         "purchase_amount_minor": null,
         "refund_request_status": null,
+        "reminder_preference": "1-hour",
         "resume_checkout_url": null,
         "status": "attendee"
     }'::jsonb,
-    'Should return attendee status for a checked-in attendee'
+    'Should return attendee status and reminder preference for a checked-in attendee'
 );
 
 -- Should return attendee status for attendee not checked in
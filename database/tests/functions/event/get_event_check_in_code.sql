@@ -0,0 +1,126 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0e00000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0e00000-0000-0000-0000-000000000002'
+\set eventID 'c0e00000-0000-0000-0000-000000000003'
+\set eventOtherID 'c0e00000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0e00000-0000-0000-0000-000000000005'
+\set groupID 'c0e00000-0000-0000-0000-000000000006'
+\set unknownEventID 'c0e00000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values
+    (
+        :'eventID',
+        'Event',
+        'event',
+        'Event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    ),
+    (
+        :'eventOtherID',
+        'Other Event',
+        'other-event',
+        'Other Event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the same code within the same window
+select is(
+    get_event_check_in_code(:'eventID'::uuid, '2030-01-01 10:00:05+00'::timestamptz),
+    get_event_check_in_code(:'eventID'::uuid, '2030-01-01 10:00:55+00'::timestamptz),
+    'Should return the same code within the same window'
+);
+
+-- Should rotate the code in the next window
+select isnt(
+    get_event_check_in_code(:'eventID'::uuid, '2030-01-01 10:00:05+00'::timestamptz),
+    get_event_check_in_code(:'eventID'::uuid, '2030-01-01 10:01:05+00'::timestamptz),
+    'Should rotate the code in the next window'
+);
+
+-- Should return different codes for different events
+select isnt(
+    get_event_check_in_code(:'eventID'::uuid, '2030-01-01 10:00:05+00'::timestamptz),
+    get_event_check_in_code(:'eventOtherID'::uuid, '2030-01-01 10:00:05+00'::timestamptz),
+    'Should return different codes for different events'
+);
+
+-- Should return null for unknown events
+select ok(
+    get_event_check_in_code(:'unknownEventID'::uuid, current_timestamp) is null,
+    'Should return null for unknown events'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,176 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendeeID 'c0db0000-0000-0000-0000-000000000001'
+\set commentID 'c0db0000-0000-0000-0000-000000000002'
+\set communityID 'c0db0000-0000-0000-0000-000000000003'
+\set eventCategoryID 'c0db0000-0000-0000-0000-000000000004'
+\set eventID 'c0db0000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0db0000-0000-0000-0000-000000000006'
+\set groupID 'c0db0000-0000-0000-0000-000000000007'
+\set organizerID 'c0db0000-0000-0000-0000-000000000008'
+\set pinnedCommentID 'c0db0000-0000-0000-0000-000000000009'
+\set replyID 'c0db0000-0000-0000-0000-000000000010'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-attendee', 'attendee@example.com', true, 'Attendee', :'attendeeID', 'attendee'),
+    ('hash-organizer', 'organizer@example.com', true, 'Organizer', :'organizerID', 'organizer');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted)
+values (:'groupID', :'organizerID', 'admin', true);
+
+-- Event
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published,
+    comments_locked,
+    starts_at
+) values (
+    :'eventID',
+    'Upcoming Event',
+    'upcoming-event',
+    'Upcoming event with a discussion',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    true,
+    true,
+    current_timestamp + interval '2 days'
+);
+
+-- Event comments
+insert into event_comment (
+    event_comment_id,
+    event_id,
+    user_id,
+    content,
+    created_at,
+    pinned,
+    parent_event_comment_id
+) values
+    (:'commentID', :'eventID', :'attendeeID', 'Will the talks be recorded?', '2030-01-01 10:00:00+00', false, null),
+    (:'pinnedCommentID', :'eventID', :'organizerID', 'Doors open at 9am', '2030-01-01 11:00:00+00', true, null),
+    (:'replyID', :'eventID', :'organizerID', 'Yes, they will.', '2030-01-01 12:00:00+00', true, :'commentID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the discussion with pinned comments first and replies nested
+select is(
+    get_event_discussion(:'communityID'::uuid, :'eventID'::uuid)::jsonb,
+    jsonb_build_object(
+        'comments', jsonb_build_array(
+            jsonb_build_object(
+                'content', 'Doors open at 9am',
+                'created_at', 1893495600,
+                'event_comment_id', :'pinnedCommentID'::uuid,
+                'organizer', true,
+                'pinned', true,
+                'replies', '[]'::jsonb,
+                'user', jsonb_build_object(
+                    'name', 'Organizer',
+                    'user_id', :'organizerID'::uuid,
+                    'username', 'organizer'
+                )
+            ),
+            jsonb_build_object(
+                'content', 'Will the talks be recorded?',
+                'created_at', 1893492000,
+                'event_comment_id', :'commentID'::uuid,
+                'organizer', false,
+                'pinned', false,
+                'replies', jsonb_build_array(
+                    jsonb_build_object(
+                        'content', 'Yes, they will.',
+                        'created_at', 1893499200,
+                        'event_comment_id', :'replyID'::uuid,
+                        'organizer', true,
+                        'pinned', true,
+                        'replies', '[]'::jsonb,
+                        'user', jsonb_build_object(
+                            'name', 'Organizer',
+                            'user_id', :'organizerID'::uuid,
+                            'username', 'organizer'
+                        )
+                    )
+                ),
+                'user', jsonb_build_object(
+                    'name', 'Attendee',
+                    'user_id', :'attendeeID'::uuid,
+                    'username', 'attendee'
+                )
+            )
+        ),
+        'locked', true
+    ),
+    'Should return the discussion with pinned comments first and replies nested'
+);
+
+-- Should return null for events from other communities
+select is(
+    get_event_discussion(:'groupID'::uuid, :'eventID'::uuid)::jsonb,
+    null,
+    'Should return null for events from other communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
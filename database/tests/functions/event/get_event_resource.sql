@@ -0,0 +1,132 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0de0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0de0000-0000-0000-0000-000000000002'
+\set eventID 'c0de0000-0000-0000-0000-000000000003'
+\set eventUnpublishedID 'c0de0000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0de0000-0000-0000-0000-000000000005'
+\set groupID 'c0de0000-0000-0000-0000-000000000006'
+\set resourceID 'c0de0000-0000-0000-0000-000000000007'
+\set resourceUnpublishedID 'c0de0000-0000-0000-0000-000000000008'
+\set unknownCommunityID 'c0de0000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values
+    (
+        :'eventID',
+        'Event',
+        'event',
+        'Published event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    ),
+    (
+        :'eventUnpublishedID',
+        'Unpublished Event',
+        'unpublished-event',
+        'Unpublished event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        false
+    );
+
+-- Event resources
+insert into event_resource (event_resource_id, event_id, content_type, file_name, name, size_bytes)
+values
+    (:'resourceID', :'eventID', 'application/pdf', 'abc.pdf', 'slides.pdf', 2048),
+    (:'resourceUnpublishedID', :'eventUnpublishedID', 'application/pdf', 'def.pdf', 'draft.pdf', 1024);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the resource download details
+select is(
+    get_event_resource(:'communityID'::uuid, :'eventID'::uuid, :'resourceID'::uuid)::jsonb,
+    '{"content_type": "application/pdf", "file_name": "abc.pdf", "name": "slides.pdf"}'::jsonb,
+    'Should return the resource download details'
+);
+
+-- Should return null for resources of other events
+select ok(
+    get_event_resource(:'communityID'::uuid, :'eventID'::uuid, :'resourceUnpublishedID'::uuid) is null,
+    'Should return null for resources of other events'
+);
+
+-- Should return null for unpublished events
+select ok(
+    get_event_resource(:'communityID'::uuid, :'eventUnpublishedID'::uuid, :'resourceUnpublishedID'::uuid) is null,
+    'Should return null for unpublished events'
+);
+
+-- Should return null for events in other communities
+select ok(
+    get_event_resource(:'unknownCommunityID'::uuid, :'eventID'::uuid, :'resourceID'::uuid) is null,
+    'Should return null for events in other communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,249 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0c70000-0000-0000-0000-000000000001'
+\set community2ID 'c0c70000-0000-0000-0000-000000000002'
+\set emptySeriesID 'c0c70000-0000-0000-0000-000000000003'
+\set event1ID 'c0c70000-0000-0000-0000-000000000004'
+\set event2ID 'c0c70000-0000-0000-0000-000000000005'
+\set event3ID 'c0c70000-0000-0000-0000-000000000006'
+\set event4ID 'c0c70000-0000-0000-0000-000000000007'
+\set eventCategoryID 'c0c70000-0000-0000-0000-000000000008'
+\set eventSeriesID 'c0c70000-0000-0000-0000-000000000009'
+\set groupCategoryID 'c0c70000-0000-0000-0000-00000000000a'
+\set groupID 'c0c70000-0000-0000-0000-00000000000b'
+\set user1ID 'c0c70000-0000-0000-0000-00000000000c'
+\set user2ID 'c0c70000-0000-0000-0000-00000000000d'
+\set user3ID 'c0c70000-0000-0000-0000-00000000000e'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'series-community',
+    'Series Community',
+    'Community for event series tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+), (
+    :'community2ID',
+    'other-community',
+    'Other Community',
+    'Another community',
+    'https://example.test/other-banner-mobile.png',
+    'https://example.test/other-banner.png',
+    'https://example.test/other-logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Workshops');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'user1ID', 'test_hash', 'series-user1@example.test', true, 'series-user1'),
+    (:'user2ID', 'test_hash', 'series-user2@example.test', true, 'series-user2'),
+    (:'user3ID', 'test_hash', 'series-user3@example.test', true, 'series-user3');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Series Group',
+    'series-group',
+    'default'
+);
+
+-- Event series
+insert into event_series (
+    event_series_id,
+    group_id,
+    recurrence_additional_occurrences,
+    recurrence_anchor_starts_at,
+    recurrence_pattern,
+    timezone,
+
+    name
+) values (
+    :'eventSeriesID',
+    :'groupID',
+    2,
+    '2030-01-07 10:00:00+00',
+    'weekly',
+    'UTC',
+
+    'Kubernetes 101 Workshop Series'
+), (
+    :'emptySeriesID',
+    :'groupID',
+    1,
+    '2030-03-04 10:00:00+00',
+    'weekly',
+    'UTC',
+
+    null
+);
+
+-- Events
+insert into event (
+    event_id,
+    description,
+    event_category_id,
+    event_kind_id,
+    event_series_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone,
+
+    deleted
+) values (
+    :'event2ID',
+    'Second workshop',
+    :'eventCategoryID',
+    'virtual',
+    :'eventSeriesID',
+    :'groupID',
+    'Workshop 2',
+    true,
+    'workshop-2',
+    '2030-01-14 10:00:00+00',
+    'UTC',
+
+    false
+), (
+    :'event1ID',
+    'First workshop',
+    :'eventCategoryID',
+    'virtual',
+    :'eventSeriesID',
+    :'groupID',
+    'Workshop 1',
+    true,
+    'workshop-1',
+    '2030-01-07 10:00:00+00',
+    'UTC',
+
+    false
+), (
+    :'event3ID',
+    'Third workshop',
+    :'eventCategoryID',
+    'virtual',
+    :'eventSeriesID',
+    :'groupID',
+    'Workshop 3',
+    false,
+    'workshop-3',
+    '2030-01-21 10:00:00+00',
+    'UTC',
+
+    false
+), (
+    :'event4ID',
+    'Draft workshop',
+    :'eventCategoryID',
+    'virtual',
+    :'emptySeriesID',
+    :'groupID',
+    'Workshop 4',
+    false,
+    'workshop-4',
+    '2030-03-04 10:00:00+00',
+    'UTC',
+
+    false
+);
+
+-- Event attendees
+insert into event_attendee (event_id, user_id, checked_in, status)
+values
+    (:'event1ID', :'user1ID', true, 'confirmed'),
+    (:'event1ID', :'user2ID', false, 'confirmed'),
+    (:'event1ID', :'user3ID', false, 'invitation-pending'),
+    (:'event2ID', :'user1ID', false, 'confirmed'),
+    (:'event3ID', :'user2ID', false, 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the series details with the combined attendance of published events
+select is(
+    get_event_series(:'communityID'::uuid, :'eventSeriesID'::uuid)::jsonb - 'community' - 'events' - 'group',
+    jsonb_build_object(
+        'attendee_count', 3,
+        'checked_in_count', 1,
+        'event_series_id', :'eventSeriesID',
+        'name', 'Kubernetes 101 Workshop Series'
+    ),
+    'Should return the series details with the combined attendance of published events'
+);
+
+-- Should list the published events of the series in chronological order
+select results_eq(
+    format(
+        $$
+            select event->>'name'
+            from jsonb_array_elements(get_event_series(%L::uuid, %L::uuid)::jsonb->'events') as event
+        $$,
+        :'communityID', :'eventSeriesID'
+    ),
+    $$ values ('Workshop 1'), ('Workshop 2') $$,
+    'Should list the published events of the series in chronological order'
+);
+
+-- Should include the group hosting the series
+select is(
+    get_event_series(:'communityID'::uuid, :'eventSeriesID'::uuid)::jsonb->'group'->>'name',
+    'Series Group',
+    'Should include the group hosting the series'
+);
+
+-- Should return null when the series belongs to another community
+select ok(
+    get_event_series(:'community2ID'::uuid, :'eventSeriesID'::uuid) is null,
+    'Should return null when the series belongs to another community'
+);
+
+-- Should return null when the series has no published events
+select ok(
+    get_event_series(:'communityID'::uuid, :'emptySeriesID'::uuid) is null,
+    'Should return null when the series has no published events'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
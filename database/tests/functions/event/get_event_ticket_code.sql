@@ -0,0 +1,121 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set attendeeID 'c0e20000-0000-0000-0000-000000000001'
+\set communityID 'c0e20000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0e20000-0000-0000-0000-000000000003'
+\set eventID 'c0e20000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0e20000-0000-0000-0000-000000000005'
+\set groupID 'c0e20000-0000-0000-0000-000000000006'
+\set pendingTicketCode 'c0e20000-0000-0000-0000-000000000007'
+\set pendingUserID 'c0e20000-0000-0000-0000-000000000008'
+\set ticketCode 'c0e20000-0000-0000-0000-000000000009'
+\set unknownCommunityID 'c0e20000-0000-0000-0000-00000000000a'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-1', 'attendee@example.com', true, 'Attendee', :'attendeeID', 'attendee'),
+    ('hash-2', 'pending@example.com', true, 'Pending', :'pendingUserID', 'pending');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values
+    (
+        :'eventID',
+        'Event',
+        'event',
+        'Event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    );
+
+-- Attendees
+insert into event_attendee (event_id, user_id, status, checked_in, ticket_code) values
+    (:'eventID', :'attendeeID', 'confirmed', true, :'ticketCode'),
+    (:'eventID', :'pendingUserID', 'invitation-pending', false, :'pendingTicketCode');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the ticket code of confirmed attendees
+select is(
+    get_event_ticket_code(:'communityID'::uuid, :'eventID'::uuid, :'attendeeID'::uuid),
+    :'ticketCode'::uuid,
+    'Should return the ticket code of confirmed attendees'
+);
+
+-- Should return null for users that are not confirmed attendees
+select ok(
+    get_event_ticket_code(:'communityID'::uuid, :'eventID'::uuid, :'pendingUserID'::uuid) is null,
+    'Should return null for users that are not confirmed attendees'
+);
+
+-- Should return null for events in other communities
+select ok(
+    get_event_ticket_code(:'unknownCommunityID'::uuid, :'eventID'::uuid, :'attendeeID'::uuid) is null,
+    'Should return null for events in other communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,196 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0c80000-0000-0000-0000-000000000001'
+\set community2ID 'c0c80000-0000-0000-0000-000000000002'
+\set event1ID 'c0c80000-0000-0000-0000-000000000003'
+\set event2ID 'c0c80000-0000-0000-0000-000000000004'
+\set event3ID 'c0c80000-0000-0000-0000-000000000005'
+\set eventCategoryID 'c0c80000-0000-0000-0000-000000000006'
+\set groupCategoryID 'c0c80000-0000-0000-0000-000000000007'
+\set groupID 'c0c80000-0000-0000-0000-000000000008'
+\set otherUserID 'c0c80000-0000-0000-0000-000000000009'
+\set sessionID 'c0c80000-0000-0000-0000-00000000000a'
+\set speakerID 'c0c80000-0000-0000-0000-00000000000b'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'speaker-community',
+    'Speaker Community',
+    'Community for speaker profile tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+), (
+    :'community2ID',
+    'other-community',
+    'Other Community',
+    'Another community',
+    'https://example.test/other-banner-mobile.png',
+    'https://example.test/other-banner.png',
+    'https://example.test/other-logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Conferences');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, name, username)
+values
+    (:'otherUserID', 'test_hash', 'other@example.test', true, 'Other User', 'other-user'),
+    (:'speakerID', 'test_hash', 'speaker@example.test', true, 'Speaker One', 'speaker-one');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Speaker Group',
+    'speaker-group',
+    'default'
+);
+
+-- Events
+insert into event (
+    event_id,
+    description,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    ends_at,
+    timezone
+) values (
+    :'event1ID',
+    'First conference',
+    :'eventCategoryID',
+    'virtual',
+    :'groupID',
+    'Conference 1',
+    true,
+    'conference-1',
+    '2030-01-10 09:00:00+00',
+    '2030-01-10 17:00:00+00',
+    'UTC'
+), (
+    :'event2ID',
+    'Second conference',
+    :'eventCategoryID',
+    'virtual',
+    :'groupID',
+    'Conference 2',
+    true,
+    'conference-2',
+    '2030-02-10 09:00:00+00',
+    '2030-02-10 17:00:00+00',
+    'UTC'
+), (
+    :'event3ID',
+    'Draft conference',
+    :'eventCategoryID',
+    'virtual',
+    :'groupID',
+    'Conference 3',
+    false,
+    'conference-3',
+    '2030-03-10 09:00:00+00',
+    '2030-03-10 17:00:00+00',
+    'UTC'
+);
+
+-- Session
+insert into session (session_id, event_id, name, session_kind_id, starts_at)
+values (:'sessionID', :'event1ID', 'Intro Talk', 'virtual', '2030-01-10 10:00:00+00');
+
+-- Session speaker
+insert into session_speaker (session_id, user_id, featured)
+values (:'sessionID', :'speakerID', false);
+
+-- Event speakers
+insert into event_speaker (event_id, user_id)
+values
+    (:'event2ID', :'speakerID'),
+    (:'event3ID', :'speakerID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the speaker profile
+select is(
+    get_speaker_profile(:'communityID'::uuid, 'speaker-one')::jsonb->'user',
+    jsonb_build_object(
+        'name', 'Speaker One',
+        'user_id', :'speakerID',
+        'username', 'speaker-one'
+    ),
+    'Should return the speaker profile'
+);
+
+-- Should list the talks in published events, most recent first
+select results_eq(
+    format(
+        $$
+            select talk->'event'->>'name', talk->>'session_name'
+            from jsonb_array_elements(get_speaker_profile(%L::uuid, 'speaker-one')::jsonb->'talks') as talk
+        $$,
+        :'communityID'
+    ),
+    $$ values ('Conference 2', null), ('Conference 1', 'Intro Talk') $$,
+    'Should list the talks in published events, most recent first'
+);
+
+-- Should return null when the user has no talks in the community
+select ok(
+    get_speaker_profile(:'communityID'::uuid, 'other-user') is null,
+    'Should return null when the user has no talks in the community'
+);
+
+-- Should return null when the speaker has no talks in another community
+select ok(
+    get_speaker_profile(:'community2ID'::uuid, 'speaker-one') is null,
+    'Should return null when the speaker has no talks in another community'
+);
+
+-- Should return null when the username does not exist
+select ok(
+    get_speaker_profile(:'communityID'::uuid, 'missing-user') is null,
+    'Should return null when the username does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
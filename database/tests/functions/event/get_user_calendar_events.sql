@@ -0,0 +1,129 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set canceledEventID 'c0ca0000-0000-0000-0000-000000000001'
+\set communityID 'c0ca0000-0000-0000-0000-000000000002'
+\set draftEventID 'c0ca0000-0000-0000-0000-000000000003'
+\set eventCategoryID 'c0ca0000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0ca0000-0000-0000-0000-000000000005'
+\set groupID 'c0ca0000-0000-0000-0000-000000000006'
+\set otherUserID 'c0ca0000-0000-0000-0000-000000000007'
+\set pastEventID 'c0ca0000-0000-0000-0000-000000000008'
+\set upcomingEventID 'c0ca0000-0000-0000-0000-000000000009'
+\set userID 'c0ca0000-0000-0000-0000-00000000000a'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'calendar-community',
+    'Calendar Community',
+    'Community for calendar feed tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetups');
+
+-- Users
+insert into "user" (user_id, auth_hash, calendar_token, email, email_verified, username)
+values
+    (:'otherUserID', 'hash', 'other-token', 'other@example.test', true, 'other-user'),
+    (:'userID', 'hash', 'user-token', 'user@example.test', true, 'user');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Calendar Group',
+    'calendar-group',
+    'default'
+);
+
+-- Events
+insert into event (
+    event_id,
+    canceled,
+    description,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values
+    (:'canceledEventID', true, 'Canceled', :'eventCategoryID', 'virtual', :'groupID', 'Canceled Event', true, 'canceled-event', now() + interval '1 day', 'UTC'),
+    (:'draftEventID', false, 'Draft', :'eventCategoryID', 'virtual', :'groupID', 'Draft Event', false, 'draft-event', now() + interval '3 days', 'UTC'),
+    (:'pastEventID', false, 'Past', :'eventCategoryID', 'virtual', :'groupID', 'Past Event', true, 'past-event', now() - interval '90 days', 'UTC'),
+    (:'upcomingEventID', false, 'Upcoming', :'eventCategoryID', 'virtual', :'groupID', 'Upcoming Event', true, 'upcoming-event', now() + interval '2 days', 'UTC');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, status) values
+    (:'canceledEventID', :'userID', 'confirmed'),
+    (:'draftEventID', :'userID', 'confirmed'),
+    (:'pastEventID', :'userID', 'confirmed'),
+    (:'upcomingEventID', :'userID', 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the published recent and upcoming events the user attends
+select results_eq(
+    $$
+        select event->>'name'
+        from json_array_elements(get_user_calendar_events('user-token')) as event
+    $$,
+    $$ values ('Canceled Event'), ('Upcoming Event') $$,
+    'Should return the published recent and upcoming events the user attends'
+);
+
+-- Should return an empty list when the user attends no events
+select is(
+    get_user_calendar_events('other-token')::text,
+    '[]',
+    'Should return an empty list when the user attends no events'
+);
+
+-- Should return null when the token is unknown
+select ok(
+    get_user_calendar_events('unknown-token') is null,
+    'Should return null when the token is unknown'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
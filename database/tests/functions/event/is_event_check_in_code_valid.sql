@@ -0,0 +1,133 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0e10000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0e10000-0000-0000-0000-000000000002'
+\set eventID 'c0e10000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0e10000-0000-0000-0000-000000000004'
+\set groupID 'c0e10000-0000-0000-0000-000000000005'
+\set unknownCommunityID 'c0e10000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Event category
+insert into event_category (event_category_id, name, community_id)
+values (:'eventCategoryID', 'General', :'communityID');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1');
+
+-- Events
+insert into event (
+    event_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    published
+) values
+    (
+        :'eventID',
+        'Event',
+        'event',
+        'Event',
+        'UTC',
+        :'eventCategoryID',
+        'in-person',
+        :'groupID',
+        true
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should accept the current code
+select ok(
+    is_event_check_in_code_valid(
+        :'communityID'::uuid,
+        :'eventID'::uuid,
+        get_event_check_in_code(:'eventID'::uuid, current_timestamp)
+    ),
+    'Should accept the current code'
+);
+
+-- Should accept the previous code
+select ok(
+    is_event_check_in_code_valid(
+        :'communityID'::uuid,
+        :'eventID'::uuid,
+        get_event_check_in_code(:'eventID'::uuid, current_timestamp - interval '1 minute')
+    ),
+    'Should accept the previous code'
+);
+
+-- Should reject expired codes
+select ok(
+    not is_event_check_in_code_valid(
+        :'communityID'::uuid,
+        :'eventID'::uuid,
+        get_event_check_in_code(:'eventID'::uuid, current_timestamp - interval '5 minutes')
+    ),
+    'Should reject expired codes'
+);
+
+-- Should reject unknown codes
+select ok(
+    not is_event_check_in_code_valid(:'communityID'::uuid, :'eventID'::uuid, 'not-a-code'),
+    'Should reject unknown codes'
+);
+
+-- Should reject codes for events in other communities
+select ok(
+    not is_event_check_in_code_valid(
+        :'unknownCommunityID'::uuid,
+        :'eventID'::uuid,
+        get_event_check_in_code(:'eventID'::uuid, current_timestamp)
+    ),
+    'Should reject codes for events in other communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
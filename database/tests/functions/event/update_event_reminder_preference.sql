@@ -0,0 +1,232 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(9);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0e80000-0000-0000-0000-000000000001'
+\set eventCanceledID 'c0e80000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0e80000-0000-0000-0000-000000000003'
+\set eventID 'c0e80000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0e80000-0000-0000-0000-000000000005'
+\set groupID 'c0e80000-0000-0000-0000-000000000006'
+\set userAttendeeID 'c0e80000-0000-0000-0000-000000000007'
+\set userOtherID 'c0e80000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    description
+) values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Test Group',
+    'test-group',
+    'A test group'
+);
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    published,
+    canceled
+) values (
+    :'eventID',
+    :'groupID',
+    'Test Event',
+    'test-event',
+    'A test event',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    current_timestamp + interval '3 days',
+    true,
+    false
+), (
+    :'eventCanceledID',
+    :'groupID',
+    'Canceled Event',
+    'canceled-event',
+    'A canceled event',
+    'UTC',
+    :'eventCategoryID',
+    'in-person',
+    current_timestamp + interval '3 days',
+    false,
+    true
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userAttendeeID', 'hash-1', 'attendee@example.com', true, 'attendee'),
+    (:'userOtherID', 'hash-2', 'other@example.com', true, 'other');
+
+-- Attendees
+insert into event_attendee (
+    event_id,
+    user_id,
+    status,
+    reminder_preference,
+    reminder_sent_for_starts_at
+) values (
+    :'eventID',
+    :'userAttendeeID',
+    'confirmed',
+    '1-day',
+    (select starts_at from event where event_id = :'eventID')
+), (
+    :'eventCanceledID',
+    :'userAttendeeID',
+    'confirmed',
+    null,
+    null
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should keep the sent reminder when the preference does not change
+select lives_ok(
+    format(
+        $$select update_event_reminder_preference(%L::uuid, %L::uuid, %L::uuid, '1-day')$$,
+        :'communityID', :'eventID', :'userAttendeeID'
+    ),
+    'Should accept the same reminder preference again'
+);
+select isnt(
+    (
+        select reminder_sent_for_starts_at
+        from event_attendee
+        where event_id = :'eventID'
+        and user_id = :'userAttendeeID'
+    ),
+    null::timestamptz,
+    'Should keep the sent reminder when the preference does not change'
+);
+
+-- Should update the attendee reminder preference
+select lives_ok(
+    format(
+        $$select update_event_reminder_preference(%L::uuid, %L::uuid, %L::uuid, '1-hour')$$,
+        :'communityID', :'eventID', :'userAttendeeID'
+    ),
+    'Should accept a new reminder preference'
+);
+select is(
+    (
+        select reminder_preference
+        from event_attendee
+        where event_id = :'eventID'
+        and user_id = :'userAttendeeID'
+    ),
+    '1-hour',
+    'Should update the attendee reminder preference'
+);
+
+-- Should reset the sent reminder when the preference changes
+select is(
+    (
+        select reminder_sent_for_starts_at
+        from event_attendee
+        where event_id = :'eventID'
+        and user_id = :'userAttendeeID'
+    ),
+    null::timestamptz,
+    'Should reset the sent reminder when the preference changes'
+);
+
+-- Should clear the attendee reminder preference
+select lives_ok(
+    format(
+        $$select update_event_reminder_preference(%L::uuid, %L::uuid, %L::uuid, null)$$,
+        :'communityID', :'eventID', :'userAttendeeID'
+    ),
+    'Should accept clearing the reminder preference'
+);
+select is(
+    (
+        select reminder_preference
+        from event_attendee
+        where event_id = :'eventID'
+        and user_id = :'userAttendeeID'
+    ),
+    null::text,
+    'Should clear the attendee reminder preference'
+);
+
+-- Should reject users not attending the event
+select throws_ok(
+    format(
+        $$select update_event_reminder_preference(%L::uuid, %L::uuid, %L::uuid, '1-day')$$,
+        :'communityID', :'eventID', :'userOtherID'
+    ),
+    'user is not attending this event',
+    'Should reject users not attending the event'
+);
+
+-- Should reject inactive events
+select throws_ok(
+    format(
+        $$select update_event_reminder_preference(%L::uuid, %L::uuid, %L::uuid, '1-day')$$,
+        :'communityID', :'eventCanceledID', :'userAttendeeID'
+    ),
+    'event not found or inactive',
+    'Should reject inactive events'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,151 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set canceledEventID 'c0cb0000-0000-0000-0000-000000000001'
+\set communityID 'c0cb0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0cb0000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0cb0000-0000-0000-0000-000000000004'
+\set groupID 'c0cb0000-0000-0000-0000-000000000005'
+\set pastEventID 'c0cb0000-0000-0000-0000-000000000006'
+\set upcomingEventID 'c0cb0000-0000-0000-0000-000000000007'
+\set userID 'c0cb0000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'calendar-community',
+    'Calendar Community',
+    'Community for Google Calendar tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetups');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'userID', 'hash', 'user@example.test', true, 'user');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Calendar Group',
+    'calendar-group',
+    'default'
+);
+
+-- Events
+insert into event (
+    event_id,
+    canceled,
+    description,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values
+    (:'canceledEventID', true, 'Canceled Event', :'eventCategoryID', 'virtual', :'groupID', 'Canceled Event', true, 'canceled-event', now() + interval '2 days', 'UTC'),
+    (:'pastEventID', false, 'Past Event', :'eventCategoryID', 'virtual', :'groupID', 'Past Event', true, 'past-event', now() - interval '2 days', 'UTC'),
+    (:'upcomingEventID', false, 'Upcoming Event', :'eventCategoryID', 'virtual', :'groupID', 'Upcoming Event', true, 'upcoming-event', now() + interval '2 days', 'UTC');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, status) values
+    (:'canceledEventID', :'userID', 'confirmed'),
+    (:'pastEventID', :'userID', 'confirmed'),
+    (:'upcomingEventID', :'userID', 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should connect the user's Google Calendar
+select lives_ok(
+    format($$select add_user_google_calendar(%L::uuid, 'refresh-token')$$, :'userID'),
+    'Should connect the user''s Google Calendar'
+);
+
+-- Should queue only the upcoming events the user attends
+select results_eq(
+    $$ select event_id, in_sync from google_calendar_entry $$,
+    format($$ values (%L::uuid, false) $$, :'upcomingEventID'),
+    'Should queue only the upcoming events the user attends'
+);
+
+-- Should replace the credentials when the calendar is connected again
+update google_calendar_entry set in_sync = true;
+select add_user_google_calendar(:'userID'::uuid, 'new-refresh-token');
+select is(
+    (select refresh_token from user_google_calendar where user_id = :'userID'::uuid),
+    'new-refresh-token',
+    'Should replace the credentials when the calendar is connected again'
+);
+
+-- Should queue the upcoming events again when the calendar is connected again
+select is(
+    (select in_sync from google_calendar_entry where event_id = :'upcomingEventID'::uuid),
+    false,
+    'Should queue the upcoming events again when the calendar is connected again'
+);
+
+-- Should create the expected audit rows
+select results_eq(
+    $$
+        select
+            action,
+            actor_user_id,
+            resource_type,
+            resource_id
+        from audit_log
+    $$,
+    format(
+        $$
+        values
+            ('user_google_calendar_connected', %1$L::uuid, 'user', %1$L::uuid),
+            ('user_google_calendar_connected', %1$L::uuid, 'user', %1$L::uuid)
+        $$,
+        :'userID'
+    ),
+    'Should create the expected audit rows'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,174 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set canceledEventID 'c0cc0000-0000-0000-0000-000000000001'
+\set communityID 'c0cc0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0cc0000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0cc0000-0000-0000-0000-000000000004'
+\set groupID 'c0cc0000-0000-0000-0000-000000000005'
+\set upcomingEventID 'c0cc0000-0000-0000-0000-000000000006'
+\set userID 'c0cc0000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'calendar-community',
+    'Calendar Community',
+    'Community for Google Calendar tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetups');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'userID', 'hash', 'user@example.test', true, 'user');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Calendar Group',
+    'calendar-group',
+    'default'
+);
+
+-- Events
+insert into event (
+    event_id,
+    canceled,
+    description,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values
+    (:'canceledEventID', true, 'Canceled Event', :'eventCategoryID', 'virtual', :'groupID', 'Canceled Event', true, 'canceled-event', now() + interval '2 days', 'UTC'),
+    (:'upcomingEventID', false, 'Upcoming Event', :'eventCategoryID', 'virtual', :'groupID', 'Upcoming Event', true, 'upcoming-event', now() + interval '2 days', 'UTC');
+
+-- Google Calendar connection
+insert into user_google_calendar (user_id, refresh_token)
+values (:'userID', 'refresh-token');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, status) values
+    (:'canceledEventID', :'userID', 'confirmed'),
+    (:'upcomingEventID', :'userID', 'confirmed');
+
+-- Entries
+update google_calendar_entry
+set google_event_id = 'google-event-canceled', in_sync = true
+where event_id = :'canceledEventID';
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should claim the entry out of sync with the event to add
+select is(
+    (
+        select jsonb_build_object(
+            'event_id', claimed->'event_id',
+            'event_name', claimed->'event'->'name',
+            'google_event_id', claimed->'google_event_id',
+            'refresh_token', claimed->'refresh_token',
+            'sync_version', claimed->'sync_version',
+            'user_id', claimed->'user_id'
+        )
+        from claim_google_calendar_entry_out_of_sync() as claimed
+    ),
+    jsonb_build_object(
+        'event_id', :'upcomingEventID'::uuid,
+        'event_name', 'Upcoming Event',
+        'google_event_id', null,
+        'refresh_token', 'refresh-token',
+        'sync_version', 1,
+        'user_id', :'userID'::uuid
+    ),
+    'Should claim the entry out of sync with the event to add'
+);
+
+-- Should set the sync claim timestamp
+select isnt(
+    (select sync_claimed_at from google_calendar_entry where event_id = :'upcomingEventID'::uuid),
+    null,
+    'Should set the sync claim timestamp'
+);
+
+-- Should return null when every entry out of sync is already claimed
+select is(
+    claim_google_calendar_entry_out_of_sync(),
+    null,
+    'Should return null when every entry out of sync is already claimed'
+);
+
+-- Should claim the entry without the event when it must be removed
+update google_calendar_entry set in_sync = false where event_id = :'canceledEventID';
+select is(
+    (
+        select jsonb_build_object(
+            'event', claimed->'event',
+            'event_id', claimed->'event_id',
+            'google_event_id', claimed->'google_event_id'
+        )
+        from claim_google_calendar_entry_out_of_sync() as claimed
+    ),
+    jsonb_build_object(
+        'event', null,
+        'event_id', :'canceledEventID'::uuid,
+        'google_event_id', 'google-event-canceled'
+    ),
+    'Should claim the entry without the event when it must be removed'
+);
+
+-- Should claim entries again once their claim expires
+update google_calendar_entry
+set sync_claimed_at = current_timestamp - interval '16 minutes'
+where event_id = :'upcomingEventID';
+select is(
+    (claim_google_calendar_entry_out_of_sync()->>'event_id')::uuid,
+    :'upcomingEventID'::uuid,
+    'Should claim entries again once their claim expires'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
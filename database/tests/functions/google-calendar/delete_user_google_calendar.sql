@@ -0,0 +1,114 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0cd0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0cd0000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0cd0000-0000-0000-0000-000000000003'
+\set groupID 'c0cd0000-0000-0000-0000-000000000004'
+\set upcomingEventID 'c0cd0000-0000-0000-0000-000000000005'
+\set userID 'c0cd0000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'calendar-community',
+    'Calendar Community',
+    'Community for Google Calendar tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetups');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'userID', 'hash', 'user@example.test', true, 'user');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Calendar Group',
+    'calendar-group',
+    'default'
+);
+
+-- Events
+insert into event (
+    event_id,
+    canceled,
+    description,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values
+    (:'upcomingEventID', false, 'Upcoming Event', :'eventCategoryID', 'virtual', :'groupID', 'Upcoming Event', true, 'upcoming-event', now() + interval '2 days', 'UTC');
+
+-- Google Calendar connection
+insert into user_google_calendar (user_id, refresh_token)
+values (:'userID', 'refresh-token');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, status)
+values (:'upcomingEventID', :'userID', 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should remove the connection and its entries
+select delete_user_google_calendar(:'userID'::uuid);
+select is(
+    (select count(*) from user_google_calendar)::int + (select count(*) from google_calendar_entry)::int,
+    0,
+    'Should remove the connection and its entries'
+);
+
+-- Should not track the removal in the audit log
+select is(
+    (select count(*) from audit_log)::int,
+    0,
+    'Should not track the removal in the audit log'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
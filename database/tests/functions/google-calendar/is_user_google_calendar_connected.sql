@@ -0,0 +1,50 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set otherUserID 'c0cf0000-0000-0000-0000-000000000001'
+\set userID 'c0cf0000-0000-0000-0000-000000000002'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'otherUserID', 'hash', 'other@example.test', true, 'other-user'),
+    (:'userID', 'hash', 'user@example.test', true, 'user');
+
+-- Google Calendar connection
+insert into user_google_calendar (user_id, refresh_token)
+values (:'userID', 'refresh-token');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return true when the user connected their Google Calendar
+select ok(
+    is_user_google_calendar_connected(:'userID'::uuid),
+    'Should return true when the user connected their Google Calendar'
+);
+
+-- Should return false when the user did not connect their Google Calendar
+select ok(
+    not is_user_google_calendar_connected(:'otherUserID'::uuid),
+    'Should return false when the user did not connect their Google Calendar'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,135 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set changedEventID 'c0d10000-0000-0000-0000-000000000001'
+\set communityID 'c0d10000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0d10000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0d10000-0000-0000-0000-000000000004'
+\set groupID 'c0d10000-0000-0000-0000-000000000005'
+\set removedEventID 'c0d10000-0000-0000-0000-000000000006'
+\set upcomingEventID 'c0d10000-0000-0000-0000-000000000007'
+\set userID 'c0d10000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'calendar-community',
+    'Calendar Community',
+    'Community for Google Calendar tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetups');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'userID', 'hash', 'user@example.test', true, 'user');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Calendar Group',
+    'calendar-group',
+    'default'
+);
+
+-- Events
+insert into event (
+    event_id,
+    canceled,
+    description,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values
+    (:'changedEventID', false, 'Changed Event', :'eventCategoryID', 'virtual', :'groupID', 'Changed Event', true, 'changed-event', now() + interval '2 days', 'UTC'),
+    (:'removedEventID', false, 'Removed Event', :'eventCategoryID', 'virtual', :'groupID', 'Removed Event', true, 'removed-event', now() + interval '2 days', 'UTC'),
+    (:'upcomingEventID', false, 'Upcoming Event', :'eventCategoryID', 'virtual', :'groupID', 'Upcoming Event', true, 'upcoming-event', now() + interval '2 days', 'UTC');
+
+-- Google Calendar connection
+insert into user_google_calendar (user_id, refresh_token)
+values (:'userID', 'refresh-token');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, status) values
+    (:'changedEventID', :'userID', 'confirmed'),
+    (:'removedEventID', :'userID', 'confirmed'),
+    (:'upcomingEventID', :'userID', 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should store the Google event and mark the entry as in sync
+select set_google_calendar_entry_synced(:'upcomingEventID'::uuid, :'userID'::uuid, 'google-event', 1);
+select results_eq(
+    format(
+        $$ select google_event_id, in_sync from google_calendar_entry where event_id = %L::uuid $$,
+        :'upcomingEventID'
+    ),
+    $$ values ('google-event'::text, true) $$,
+    'Should store the Google event and mark the entry as in sync'
+);
+
+-- Should keep the entry out of sync when it changed while being synchronized
+update event set name = 'Changed Event Renamed' where event_id = :'changedEventID';
+select set_google_calendar_entry_synced(:'changedEventID'::uuid, :'userID'::uuid, 'google-event-changed', 1);
+select results_eq(
+    format(
+        $$ select google_event_id, in_sync from google_calendar_entry where event_id = %L::uuid $$,
+        :'changedEventID'
+    ),
+    $$ values ('google-event-changed'::text, false) $$,
+    'Should keep the entry out of sync when it changed while being synchronized'
+);
+
+-- Should remove the entry once it is no longer in the user's calendar
+select set_google_calendar_entry_synced(:'removedEventID'::uuid, :'userID'::uuid, null, 1);
+select ok(
+    not exists (select 1 from google_calendar_entry where event_id = :'removedEventID'::uuid),
+    'Should remove the entry once it is no longer in the user''s calendar'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,143 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(7);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1240000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c1240000-0000-0000-0000-000000000002'
+\set groupID 'c1240000-0000-0000-0000-000000000003'
+\set inactiveGroupID 'c1240000-0000-0000-0000-000000000004'
+\set userID 'c1240000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, active) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1', true),
+    (:'inactiveGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2', false);
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-user', 'user@example.com', true, 'User', :'userID', 'user');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add a message sent to the group organizers
+select isnt(
+    add_group_contact_message(
+        :'communityID'::uuid,
+        :'groupID'::uuid,
+        :'userID'::uuid,
+        ' Venue ',
+        ' Can we host a meetup? ',
+        current_timestamp - interval '1 day',
+        2
+    ),
+    null,
+    'Should add a message sent to the group organizers'
+);
+select results_eq(
+    format('select group_id, subject, body from group_contact_message where user_id = %L::uuid', :'userID'),
+    format($$ values (%L::uuid, 'Venue'::text, 'Can we host a meetup?'::text) $$, :'groupID'),
+    'Should store the trimmed message subject and body'
+);
+
+-- Should add messages while the user is under the limit
+select isnt(
+    add_group_contact_message(
+        :'communityID'::uuid,
+        :'groupID'::uuid,
+        :'userID'::uuid,
+        'Subject',
+        'Body',
+        current_timestamp - interval '1 day',
+        2
+    ),
+    null,
+    'Should add messages while the user is under the limit'
+);
+
+-- Should not add messages once the user reached the limit
+select is(
+    add_group_contact_message(
+        :'communityID'::uuid,
+        :'groupID'::uuid,
+        :'userID'::uuid,
+        'Subject',
+        'Body',
+        current_timestamp - interval '1 day',
+        2
+    ),
+    null,
+    'Should not add messages once the user reached the limit'
+);
+select is(
+    (select count(*)::int from group_contact_message where user_id = :'userID'::uuid),
+    2,
+    'Should not store the messages not accepted'
+);
+
+-- Should only count the messages sent since the date provided
+select isnt(
+    add_group_contact_message(
+        :'communityID'::uuid,
+        :'groupID'::uuid,
+        :'userID'::uuid,
+        'Subject',
+        'Body',
+        current_timestamp + interval '1 hour',
+        2
+    ),
+    null,
+    'Should only count the messages sent since the date provided'
+);
+
+-- Should reject inactive groups
+select throws_ok(
+    format(
+        'select add_group_contact_message(%L::uuid, %L::uuid, %L::uuid, %L, %L, %L::timestamptz, 2)',
+        :'communityID', :'inactiveGroupID', :'userID', 'Subject', 'Body', '2000-01-01'
+    ),
+    'group not found or inactive',
+    'Should reject inactive groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,120 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0f90000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0f90000-0000-0000-0000-000000000002'
+\set groupID 'c0f90000-0000-0000-0000-000000000003'
+\set lockedTopicID 'c0f90000-0000-0000-0000-000000000004'
+\set memberID 'c0f90000-0000-0000-0000-000000000005'
+\set otherGroupID 'c0f90000-0000-0000-0000-000000000006'
+\set outsiderID 'c0f90000-0000-0000-0000-000000000007'
+\set topicID 'c0f90000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-member', 'member@example.com', true, 'Member', :'memberID', 'member'),
+    ('hash-outsider', 'outsider@example.com', true, 'Outsider', :'outsiderID', 'outsider');
+
+-- Group member
+insert into group_member (group_id, user_id)
+values (:'groupID', :'memberID');
+
+-- Discussion topics
+insert into group_discussion_topic (group_discussion_topic_id, group_id, user_id, title, content, locked)
+values
+    (:'topicID', :'groupID', :'memberID', 'Meetup venues', 'Any ideas?', false),
+    (:'lockedTopicID', :'groupID', :'memberID', 'Old topic', 'Closed', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add a reply posted by a group member
+select isnt(
+    add_group_discussion_reply(:'communityID'::uuid, :'groupID'::uuid, :'memberID'::uuid, :'topicID'::uuid, ' The library '),
+    null,
+    'Should add a reply posted by a group member'
+);
+select results_eq(
+    format('select content from group_discussion_reply where group_discussion_topic_id = %L::uuid', :'topicID'),
+    $$ values ('The library'::text) $$,
+    'Should store the trimmed reply content'
+);
+
+-- Should reject users who are not group members
+select throws_ok(
+    format(
+        'select add_group_discussion_reply(%L::uuid, %L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'groupID', :'outsiderID', :'topicID', 'Hi'
+    ),
+    'only group members can take part in the discussion',
+    'Should reject users who are not group members'
+);
+
+-- Should reject locked topics
+select throws_ok(
+    format(
+        'select add_group_discussion_reply(%L::uuid, %L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'groupID', :'memberID', :'lockedTopicID', 'Hi'
+    ),
+    'this topic is locked',
+    'Should reject locked topics'
+);
+
+-- Should reject topics from other groups
+select throws_ok(
+    format(
+        'select add_group_discussion_reply(%L::uuid, %L::uuid, %L::uuid, %L::uuid, %L)',
+        :'communityID', :'otherGroupID', :'memberID', :'topicID', 'Hi'
+    ),
+    'topic not found',
+    'Should reject topics from other groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
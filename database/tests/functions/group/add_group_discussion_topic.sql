@@ -0,0 +1,115 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0f80000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0f80000-0000-0000-0000-000000000002'
+\set groupID 'c0f80000-0000-0000-0000-000000000003'
+\set inactiveGroupID 'c0f80000-0000-0000-0000-000000000004'
+\set memberID 'c0f80000-0000-0000-0000-000000000005'
+\set organizerID 'c0f80000-0000-0000-0000-000000000006'
+\set outsiderID 'c0f80000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, active) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1', true),
+    (:'inactiveGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2', false);
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-member', 'member@example.com', true, 'Member', :'memberID', 'member'),
+    ('hash-organizer', 'organizer@example.com', true, 'Organizer', :'organizerID', 'organizer'),
+    ('hash-outsider', 'outsider@example.com', true, 'Outsider', :'outsiderID', 'outsider');
+
+-- Group member
+insert into group_member (group_id, user_id)
+values (:'groupID', :'memberID');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted)
+values (:'groupID', :'organizerID', 'admin', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add a topic started by a group member
+select isnt(
+    add_group_discussion_topic(:'communityID'::uuid, :'groupID'::uuid, :'memberID'::uuid, ' Meetup venues ', ' Any ideas? '),
+    null,
+    'Should add a topic started by a group member'
+);
+select results_eq(
+    format('select title, content, locked, pinned from group_discussion_topic where user_id = %L::uuid', :'memberID'),
+    $$ values ('Meetup venues'::text, 'Any ideas?'::text, false, false) $$,
+    'Should store the trimmed topic title and content'
+);
+
+-- Should add a topic started by a group team member
+select isnt(
+    add_group_discussion_topic(:'communityID'::uuid, :'groupID'::uuid, :'organizerID'::uuid, 'Welcome', 'Say hello!'),
+    null,
+    'Should add a topic started by a group team member'
+);
+
+-- Should reject users who are not group members
+select throws_ok(
+    format(
+        'select add_group_discussion_topic(%L::uuid, %L::uuid, %L::uuid, %L, %L)',
+        :'communityID', :'groupID', :'outsiderID', 'Title', 'Content'
+    ),
+    'only group members can take part in the discussion',
+    'Should reject users who are not group members'
+);
+
+-- Should reject inactive groups
+select throws_ok(
+    format(
+        'select add_group_discussion_topic(%L::uuid, %L::uuid, %L::uuid, %L, %L)',
+        :'communityID', :'inactiveGroupID', :'memberID', 'Title', 'Content'
+    ),
+    'group not found or inactive',
+    'Should reject inactive groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
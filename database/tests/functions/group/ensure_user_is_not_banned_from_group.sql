@@ -0,0 +1,99 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set bannedUserID 'c1320000-0000-0000-0000-000000000001'
+\set communityID 'c1320000-0000-0000-0000-000000000002'
+\set group1ID 'c1320000-0000-0000-0000-000000000003'
+\set group2ID 'c1320000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c1320000-0000-0000-0000-000000000005'
+\set userID 'c1320000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'bannedUserID', 'hash1', 'banned@test.com', true, 'banned'),
+    (:'userID', 'hash2', 'user@test.com', true, 'user');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'group1ID', :'communityID', :'groupCategoryID', 'Group One', 'group-one'),
+    (:'group2ID', :'communityID', :'groupCategoryID', 'Group Two', 'group-two');
+
+-- Group ban
+insert into group_ban (group_id, user_id, reason)
+values (:'group1ID', :'bannedUserID', 'Spam');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should raise for users banned from the group
+select throws_ok(
+    format(
+        'select ensure_user_is_not_banned_from_group(%L::uuid, %L::uuid)',
+        :'group1ID', :'bannedUserID'
+    ),
+    'user is banned from this group',
+    'Should raise for users banned from the group'
+);
+
+-- Should pass for users banned from other groups
+select lives_ok(
+    format(
+        'select ensure_user_is_not_banned_from_group(%L::uuid, %L::uuid)',
+        :'group2ID', :'bannedUserID'
+    ),
+    'Should pass for users banned from other groups'
+);
+
+-- Should pass for users that are not banned
+select lives_ok(
+    format(
+        'select ensure_user_is_not_banned_from_group(%L::uuid, %L::uuid)',
+        :'group1ID', :'userID'
+    ),
+    'Should pass for users that are not banned'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,130 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0fa0000-0000-0000-0000-000000000001'
+\set emptyGroupID 'c0fa0000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0fa0000-0000-0000-0000-000000000003'
+\set groupID 'c0fa0000-0000-0000-0000-000000000004'
+\set memberID 'c0fa0000-0000-0000-0000-000000000005'
+\set organizerID 'c0fa0000-0000-0000-0000-000000000006'
+\set pinnedTopicID 'c0fa0000-0000-0000-0000-000000000007'
+\set replyID 'c0fa0000-0000-0000-0000-000000000008'
+\set topicID 'c0fa0000-0000-0000-0000-000000000009'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'emptyGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- Users
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values
+    ('hash-member', 'member@example.com', true, 'Member', :'memberID', 'member'),
+    ('hash-organizer', 'organizer@example.com', true, 'Organizer', :'organizerID', 'organizer');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted)
+values (:'groupID', :'organizerID', 'admin', true);
+
+-- Discussion topics
+insert into group_discussion_topic (group_discussion_topic_id, group_id, user_id, title, content, created_at, pinned)
+values
+    (:'topicID', :'groupID', :'memberID', 'Meetup venues', 'Any ideas?', '2024-01-02 10:00:00+00', false),
+    (:'pinnedTopicID', :'groupID', :'organizerID', 'Welcome', 'Say hello!', '2024-01-01 10:00:00+00', true);
+
+-- Discussion replies
+insert into group_discussion_reply (group_discussion_reply_id, group_discussion_topic_id, user_id, content, created_at)
+values (:'replyID', :'topicID', :'organizerID', 'The library', '2024-01-02 11:00:00+00');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the topics, pinned ones first, with their replies
+select is(
+    get_group_discussion(:'communityID'::uuid, :'groupID'::uuid)::jsonb,
+    format('{
+        "topics": [
+            {
+                "content": "Say hello!",
+                "created_at": 1704103200,
+                "group_discussion_topic_id": "%s",
+                "locked": false,
+                "organizer": true,
+                "pinned": true,
+                "replies": [],
+                "title": "Welcome",
+                "user": {"name": "Organizer", "user_id": "%s", "username": "organizer"}
+            },
+            {
+                "content": "Any ideas?",
+                "created_at": 1704189600,
+                "group_discussion_topic_id": "%s",
+                "locked": false,
+                "organizer": false,
+                "pinned": false,
+                "replies": [
+                    {
+                        "content": "The library",
+                        "created_at": 1704193200,
+                        "group_discussion_reply_id": "%s",
+                        "organizer": true,
+                        "user": {"name": "Organizer", "user_id": "%s", "username": "organizer"}
+                    }
+                ],
+                "title": "Meetup venues",
+                "user": {"name": "Member", "user_id": "%s", "username": "member"}
+            }
+        ]
+    }', :'pinnedTopicID', :'organizerID', :'topicID', :'replyID', :'organizerID', :'memberID')::jsonb,
+    'Should return the topics, pinned ones first, with their replies'
+);
+
+-- Should return an empty list of topics for groups without discussions
+select is(
+    get_group_discussion(:'communityID'::uuid, :'emptyGroupID'::uuid)::jsonb,
+    '{"topics": []}'::jsonb,
+    'Should return an empty list of topics for groups without discussions'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
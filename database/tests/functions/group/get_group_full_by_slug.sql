@@ -245,6 +245,7 @@ select is(
         "active": true,
         "city": "New York",
         "name": "Kubernetes NYC",
+        "private": false,
         "slug": "abc1234",
         "tags": ["kubernetes", "cloud-native", "devops"],
         "state": "NY",
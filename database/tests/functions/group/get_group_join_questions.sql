@@ -0,0 +1,105 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1210000-0000-0000-0000-000000000011'
+\set groupCategoryID 'c1210000-0000-0000-0000-000000000012'
+\set groupID 'c1210000-0000-0000-0000-000000000013'
+\set groupWithoutQuestionsID 'c1210000-0000-0000-0000-000000000014'
+\set otherCommunityID 'c1210000-0000-0000-0000-000000000015'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values
+    (
+        :'communityID',
+        'cloud-native-malaga',
+        'Cloud Native Malaga',
+        'Community for cloud native technologies in Malaga',
+        'https://example.com/banner_mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    ),
+    (
+        :'otherCommunityID',
+        'cloud-native-sevilla',
+        'Cloud Native Sevilla',
+        'Community for cloud native technologies in Sevilla',
+        'https://example.com/banner_mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    );
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, join_questions)
+values
+    (
+        :'groupID',
+        :'communityID',
+        :'groupCategoryID',
+        'Group Malaga',
+        'group-malaga',
+        '[{"id": "c1210000-0000-0000-0000-000000000101", "kind": "free-text", "prompt": "What topics interest you?", "required": true}]'
+    ),
+    (
+        :'groupWithoutQuestionsID',
+        :'communityID',
+        :'groupCategoryID',
+        'Group Without Questions',
+        'group-without-questions',
+        default
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the questions configured for the group
+select is(
+    get_group_join_questions(:'communityID'::uuid, :'groupID'::uuid)::jsonb,
+    '[{"id": "c1210000-0000-0000-0000-000000000101", "kind": "free-text", "prompt": "What topics interest you?", "required": true}]'::jsonb,
+    'Should return the questions configured for the group'
+);
+
+-- Should return an empty list when the group has no questions
+select is(
+    get_group_join_questions(:'communityID'::uuid, :'groupWithoutQuestionsID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list when the group has no questions'
+);
+
+-- Should return null when the group belongs to another community
+select is(
+    get_group_join_questions(:'otherCommunityID'::uuid, :'groupID'::uuid)::jsonb,
+    null,
+    'Should return null when the group belongs to another community'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,106 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0f10000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0f10000-0000-0000-0000-000000000002'
+\set groupID 'c0f10000-0000-0000-0000-000000000003'
+\set memberID 'c0f10000-0000-0000-0000-000000000004'
+\set outsiderID 'c0f10000-0000-0000-0000-000000000005'
+\set rejectedID 'c0f10000-0000-0000-0000-000000000006'
+\set requesterID 'c0f10000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'memberID', 'hash1', 'member@test.com', true, 'member'),
+    (:'outsiderID', 'hash2', 'outsider@test.com', true, 'outsider'),
+    (:'rejectedID', 'hash3', 'rejected@test.com', true, 'rejected'),
+    (:'requesterID', 'hash4', 'requester@test.com', true, 'requester');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, private)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Private Group', 'private-group', true);
+
+-- Group member
+insert into group_member (group_id, user_id)
+values (:'groupID', :'memberID');
+
+-- Group join requests
+insert into group_join_request (group_id, user_id, status, reviewed_at, reviewed_by)
+values
+    (:'groupID', :'rejectedID', 'rejected', current_timestamp, :'memberID'),
+    (:'groupID', :'requesterID', 'pending', null, null);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return member for group members
+select is(
+    get_group_membership_status(:'communityID'::uuid, :'groupID'::uuid, :'memberID'::uuid),
+    'member',
+    'Should return member for group members'
+);
+
+-- Should return pending-approval for users with a pending join request
+select is(
+    get_group_membership_status(:'communityID'::uuid, :'groupID'::uuid, :'requesterID'::uuid),
+    'pending-approval',
+    'Should return pending-approval for users with a pending join request'
+);
+
+-- Should return none for users whose join request was rejected
+select is(
+    get_group_membership_status(:'communityID'::uuid, :'groupID'::uuid, :'rejectedID'::uuid),
+    'none',
+    'Should return none for users whose join request was rejected'
+);
+
+-- Should return none for users without any relationship with the group
+select is(
+    get_group_membership_status(:'communityID'::uuid, :'groupID'::uuid, :'outsiderID'::uuid),
+    'none',
+    'Should return none for users without any relationship with the group'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
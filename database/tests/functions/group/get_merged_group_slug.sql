@@ -0,0 +1,123 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1150000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c1150000-0000-0000-0000-000000000002'
+\set inactiveTargetGroupID 'c1150000-0000-0000-0000-000000000003'
+\set orphanGroupID 'c1150000-0000-0000-0000-000000000004'
+\set sourceGroupID 'c1150000-0000-0000-0000-000000000005'
+\set targetGroupID 'c1150000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'merged-slug-community',
+    'Merged Slug Community',
+    'Community for merged group slug tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Target groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, slug_pretty, active) values
+    (:'inactiveTargetGroupID', :'communityID', :'groupCategoryID', 'Inactive', 'def5678', null, false),
+    (:'targetGroupID', :'communityID', :'groupCategoryID', 'Madrid', 'abc1234', 'madrid', true);
+
+-- Merged groups
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    slug_pretty,
+    active,
+    merged_at,
+    merged_into_group_id
+) values
+    (
+        :'orphanGroupID',
+        :'communityID',
+        :'groupCategoryID',
+        'Orphan',
+        'ghi9012',
+        null,
+        false,
+        current_timestamp,
+        :'inactiveTargetGroupID'
+    ),
+    (
+        :'sourceGroupID',
+        :'communityID',
+        :'groupCategoryID',
+        'Madrid Chapter',
+        'jkl3456',
+        'madrid-chapter',
+        false,
+        current_timestamp,
+        :'targetGroupID'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the target slug when looking up the merged group slug
+select is(
+    get_merged_group_slug(:'communityID'::uuid, 'jkl3456'),
+    'madrid',
+    'Should return the target slug when looking up the merged group slug'
+);
+
+-- Should return the target slug when looking up the merged group pretty slug
+select is(
+    get_merged_group_slug(:'communityID'::uuid, 'madrid-chapter'),
+    'madrid',
+    'Should return the target slug when looking up the merged group pretty slug'
+);
+
+-- Should return null when the target group is inactive
+select is(
+    get_merged_group_slug(:'communityID'::uuid, 'ghi9012'),
+    null,
+    'Should return null when the target group is inactive'
+);
+
+-- Should return null when the group was not merged
+select is(
+    get_merged_group_slug(:'communityID'::uuid, 'abc1234'),
+    null,
+    'Should return null when the group was not merged'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,19 +3,22 @@
 -- ============================================================================
 
 begin;
-select plan(5);
+select plan(13);
 
 -- ============================================================================
 -- VARIABLES
 -- ============================================================================
 
+\set archivedGroupID '6a050000-0000-0000-0000-000000000009'
 \set communityID '6a050000-0000-0000-0000-000000000001'
 \set deletedGroupID '6a050000-0000-0000-0000-000000000002'
 \set groupCategoryID '6a050000-0000-0000-0000-000000000003'
 \set groupID '6a050000-0000-0000-0000-000000000004'
 \set inactiveGroupID '6a050000-0000-0000-0000-000000000005'
+\set privateGroupID '6a050000-0000-0000-0000-000000000008'
 \set user1ID '6a050000-0000-0000-0000-000000000006'
 \set user2ID '6a050000-0000-0000-0000-000000000007'
+\set user3ID '6a050000-0000-0000-0000-000000000010'
 
 -- ============================================================================
 -- SEED DATA
@@ -48,7 +51,8 @@ values (:'groupCategoryID', :'communityID', 'Technology');
 insert into "user" (user_id, auth_hash, email, email_verified, username)
 values
     (:'user1ID', 'hash1', 'user1@test.com', true, 'testuser1'),
-    (:'user2ID', 'hash2', 'user2@test.com', true, 'testuser2');
+    (:'user2ID', 'hash2', 'user2@test.com', true, 'testuser2'),
+    (:'user3ID', 'hash3', 'user3@test.com', true, 'testuser3');
 
 -- Groups
 insert into "group" (
@@ -58,9 +62,10 @@ insert into "group" (
     name,
     slug,
     active,
-    deleted
+    deleted,
+    private
 ) values
-    (:'groupID', :'communityID', :'groupCategoryID', 'Active Group', 'active-group', true, false),
+    (:'groupID', :'communityID', :'groupCategoryID', 'Active Group', 'active-group', true, false, false),
     (
         :'inactiveGroupID',
         :'communityID',
@@ -68,6 +73,7 @@ insert into "group" (
         'Inactive Group',
         'inactive-group',
         false,
+        false,
         false
     ),
     (
@@ -77,32 +83,73 @@ insert into "group" (
         'Deleted Group',
         'deleted-group',
         false,
+        true,
+        false
+    ),
+    (
+        :'privateGroupID',
+        :'communityID',
+        :'groupCategoryID',
+        'Private Group',
+        'private-group',
+        true,
+        false,
         true
     );
 
+-- Group (archived)
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    archived_at
+) values (
+    :'archivedGroupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Archived Group',
+    'archived-group',
+    '2024-01-01 00:00:00+00'
+);
+
+-- Group ban
+insert into group_ban (group_id, user_id, reason)
+values (:'groupID', :'user2ID', 'Spam');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
 
--- Should succeed for active group
-select lives_ok(
-    format(
-        $$select join_group(%L::uuid, %L::uuid, %L::uuid)$$,
-        :'communityID', :'groupID', :'user1ID'
+-- Should add the user as a member of an active group
+select is(
+    join_group(
+        :'communityID'::uuid,
+        :'groupID'::uuid,
+        :'user1ID'::uuid,
+        '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]}'::jsonb
     ),
+    'member',
     'User should be able to join an active group'
 );
 
--- Should add user to group_member table
-select ok(
-    exists(select 1 from group_member where group_id = :'groupID'::uuid and user_id = :'user1ID'::uuid),
-    'User should be added to group_member table after joining'
+-- Should add user to group_member table with the join answers
+select is(
+    (
+        select join_answers
+        from group_member
+        where group_id = :'groupID'::uuid
+        and user_id = :'user1ID'::uuid
+    ),
+    '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Rust"}]}'::jsonb,
+    'User should be added to group_member table with the join answers after joining'
 );
 
 -- Should error on duplicate join
 select throws_ok(
     format(
-        $$select join_group(%L::uuid, %L::uuid, %L::uuid)$$,
+        $$select join_group(%L::uuid, %L::uuid, %L::uuid, null)$$,
         :'communityID', :'groupID', :'user1ID'
     ),
     'user is already a member of this group',
@@ -112,7 +159,7 @@ select throws_ok(
 -- Should error for inactive group
 select throws_ok(
     format(
-        $$select join_group(%L::uuid, %L::uuid, %L::uuid)$$,
+        $$select join_group(%L::uuid, %L::uuid, %L::uuid, null)$$,
         :'communityID', :'inactiveGroupID', :'user1ID'
     ),
     'group not found or inactive',
@@ -122,13 +169,118 @@ select throws_ok(
 -- Should error for deleted group
 select throws_ok(
     format(
-        $$select join_group(%L::uuid, %L::uuid, %L::uuid)$$,
+        $$select join_group(%L::uuid, %L::uuid, %L::uuid, null)$$,
         :'communityID', :'deletedGroupID', :'user1ID'
     ),
     'group not found or inactive',
     'Should not allow user to join a deleted group'
 );
 
+-- Should error for archived group
+select throws_ok(
+    format(
+        $$select join_group(%L::uuid, %L::uuid, %L::uuid, null)$$,
+        :'communityID', :'archivedGroupID', :'user1ID'
+    ),
+    'group is archived',
+    'Should not allow user to join an archived group'
+);
+
+-- Should error for banned users
+select throws_ok(
+    format(
+        $$select join_group(%L::uuid, %L::uuid, %L::uuid, null)$$,
+        :'communityID', :'groupID', :'user2ID'
+    ),
+    'user is banned from this group',
+    'Should not allow banned users to rejoin the group'
+);
+
+-- Should queue a join request for a private group
+select is(
+    join_group(
+        :'communityID'::uuid,
+        :'privateGroupID'::uuid,
+        :'user1ID'::uuid,
+        '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Go"}]}'::jsonb
+    ),
+    'pending-approval',
+    'Should queue a join request when the group is private'
+);
+
+-- Should not add the user to the private group until the request is accepted
+select results_eq(
+    format(
+        $$
+            select
+                exists(select 1 from group_member where group_id = %L::uuid and user_id = %L::uuid),
+                status,
+                join_answers
+            from group_join_request
+            where group_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'privateGroupID', :'user1ID', :'privateGroupID', :'user1ID'
+    ),
+    $$ values (
+        false,
+        'pending'::text,
+        '{"answers": [{"question_id": "00000000-0000-0000-0000-000000000101", "value": "Go"}]}'::jsonb
+    ) $$,
+    'Should keep the user out of the private group while the request is pending'
+);
+
+-- Should error on duplicate join requests
+select throws_ok(
+    format(
+        $$select join_group(%L::uuid, %L::uuid, %L::uuid, null)$$,
+        :'communityID', :'privateGroupID', :'user1ID'
+    ),
+    'user has already requested to join this group',
+    'Should not allow user to request to join a group twice'
+);
+
+-- Should let users request to join again after a rejection
+update group_join_request
+set reviewed_at = current_timestamp, reviewed_by = :'user2ID'::uuid, status = 'rejected'
+where group_id = :'privateGroupID'::uuid
+and user_id = :'user1ID'::uuid;
+
+select is(
+    join_group(:'communityID'::uuid, :'privateGroupID'::uuid, :'user1ID'::uuid, null),
+    'pending-approval',
+    'Should reopen a rejected join request'
+);
+
+-- Should reset the review and answers of the reopened request
+select results_eq(
+    format(
+        $$
+            select status, reviewed_at is null, reviewed_by is null, join_answers is null
+            from group_join_request
+            where group_id = %L::uuid
+            and user_id = %L::uuid
+        $$,
+        :'privateGroupID', :'user1ID'
+    ),
+    $$ values ('pending'::text, true, true, true) $$,
+    'Should reset the review and answers of the reopened join request'
+);
+
+-- Should error for users with an email domain not allowed by the community
+update community
+set allowed_email_domains = array['example.org']
+where community_id = :'communityID'::uuid;
+
+select throws_ok(
+    format(
+        $$select join_group(%L::uuid, %L::uuid, %L::uuid, null)$$,
+        :'communityID', :'groupID', :'user3ID'
+    ),
+    'your email domain is not allowed in this community',
+    'Should not allow users with an email domain not allowed by the community to join'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
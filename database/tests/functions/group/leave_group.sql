@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(5);
+select plan(7);
 
 -- ============================================================================
 -- VARIABLES
@@ -16,6 +16,7 @@ select plan(5);
 \set inactiveGroupID '6a060000-0000-0000-0000-000000000005'
 \set user1ID '6a060000-0000-0000-0000-000000000006'
 \set user2ID '6a060000-0000-0000-0000-000000000007'
+\set user3ID '6a060000-0000-0000-0000-000000000008'
 
 -- ============================================================================
 -- SEED DATA
@@ -48,7 +49,8 @@ values (:'groupCategoryID', :'communityID', 'Technology');
 insert into "user" (user_id, auth_hash, email, email_verified, username)
 values
     (:'user1ID', 'hash1', 'user1@test.com', true, 'testuser1'),
-    (:'user2ID', 'hash2', 'user2@test.com', true, 'testuser2');
+    (:'user2ID', 'hash2', 'user2@test.com', true, 'testuser2'),
+    (:'user3ID', 'hash3', 'user3@test.com', true, 'testuser3');
 
 -- Group
 insert into "group" (
@@ -84,6 +86,10 @@ insert into "group" (
 insert into group_member (group_id, user_id)
 values (:'groupID', :'user1ID');
 
+-- Group join request
+insert into group_join_request (group_id, user_id)
+values (:'groupID', :'user3ID');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -103,6 +109,21 @@ select ok(
     'User should be removed from group_member table after leaving'
 );
 
+-- Should withdraw a pending join request
+select lives_ok(
+    format(
+        $$select leave_group(%L::uuid, %L::uuid, %L::uuid)$$,
+        :'communityID', :'groupID', :'user3ID'
+    ),
+    'User should be able to withdraw a pending join request'
+);
+
+-- Should remove the withdrawn join request
+select ok(
+    not exists(select 1 from group_join_request where group_id = :'groupID'::uuid and user_id = :'user3ID'::uuid),
+    'Join request should be removed after withdrawing it'
+);
+
 -- Should error when user is not a member
 select throws_ok(
     format(
@@ -0,0 +1,105 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set actorID 'c1020000-0000-0000-0000-000000000001'
+\set communityID 'c1020000-0000-0000-0000-000000000002'
+\set deletedGroupID 'c1020000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c1020000-0000-0000-0000-000000000004'
+\set groupID 'c1020000-0000-0000-0000-000000000005'
+\set otherGroupID 'c1020000-0000-0000-0000-000000000006'
+\set pinnedAnnouncementID 'c1020000-0000-0000-0000-000000000007'
+\set unpinnedAnnouncementID 'c1020000-0000-0000-0000-000000000008'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, name, community_id)
+values (:'groupCategoryID', 'Tech', :'communityID');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug) values
+    (:'groupID', :'communityID', :'groupCategoryID', 'G1', 'g1'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'G2', 'g2');
+
+-- Deleted group
+insert into "group" (group_id, community_id, group_category_id, name, slug, active, deleted)
+values (:'deletedGroupID', :'communityID', :'groupCategoryID', 'G3', 'g3', false, true);
+
+-- User
+insert into "user" (auth_hash, email, email_verified, name, user_id, username)
+values ('hash-actor', 'actor@example.com', true, 'Actor', :'actorID', 'actor');
+
+-- Announcements
+insert into group_announcement (group_announcement_id, group_id, title, content, created_at, pinned) values
+    (:'pinnedAnnouncementID', :'groupID', 'Pinned', 'Pinned news', '2024-02-01 10:00:00+00', true),
+    (:'unpinnedAnnouncementID', :'groupID', 'Unpinned', 'Old news', '2024-01-01 10:00:00+00', false),
+    (gen_random_uuid(), :'deletedGroupID', 'Deleted', 'Deleted group news', '2024-01-01 10:00:00+00', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should only list the pinned announcements of the group
+select is(
+    list_group_pinned_announcements(:'communityID'::uuid, :'groupID'::uuid)::jsonb,
+    format('[
+        {
+            "content": "Pinned news",
+            "created_at": 1706781600,
+            "group_announcement_id": "%s",
+            "pinned": true,
+            "title": "Pinned"
+        }
+    ]', :'pinnedAnnouncementID')::jsonb,
+    'Should only list the pinned announcements of the group'
+);
+
+-- Should not list announcements of deleted groups
+select is(
+    list_group_pinned_announcements(:'communityID'::uuid, :'deletedGroupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should not list announcements of deleted groups'
+);
+
+-- Should not list announcements of groups in other communities
+select is(
+    list_group_pinned_announcements(gen_random_uuid(), :'groupID'::uuid)::jsonb,
+    '[]'::jsonb,
+    'Should not list announcements of groups in other communities'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,113 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0f20000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0f20000-0000-0000-0000-000000000002'
+\set memberID 'c0f20000-0000-0000-0000-000000000003'
+\set organizerID 'c0f20000-0000-0000-0000-000000000004'
+\set outsiderID 'c0f20000-0000-0000-0000-000000000005'
+\set privateGroupID 'c0f20000-0000-0000-0000-000000000006'
+\set publicGroupID 'c0f20000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'memberID', 'hash1', 'member@test.com', true, 'member'),
+    (:'organizerID', 'hash2', 'organizer@test.com', true, 'organizer'),
+    (:'outsiderID', 'hash3', 'outsider@test.com', true, 'outsider');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug, private)
+values
+    (:'privateGroupID', :'communityID', :'groupCategoryID', 'Private Group', 'private-group', true),
+    (:'publicGroupID', :'communityID', :'groupCategoryID', 'Public Group', 'public-group', false);
+
+-- Group member
+insert into group_member (group_id, user_id)
+values (:'privateGroupID', :'memberID');
+
+-- Group team
+insert into group_team (group_id, user_id, role, accepted)
+values (:'privateGroupID', :'organizerID', 'admin', true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should allow anyone to view the members of public groups
+select ok(
+    user_can_view_group_members(:'communityID'::uuid, :'publicGroupID'::uuid, :'outsiderID'::uuid),
+    'Should allow anyone to view the members of public groups'
+);
+
+-- Should allow anonymous users to view the members of public groups
+select ok(
+    user_can_view_group_members(:'communityID'::uuid, :'publicGroupID'::uuid, null),
+    'Should allow anonymous users to view the members of public groups'
+);
+
+-- Should allow members to view the members of private groups
+select ok(
+    user_can_view_group_members(:'communityID'::uuid, :'privateGroupID'::uuid, :'memberID'::uuid),
+    'Should allow members to view the members of private groups'
+);
+
+-- Should allow the group team to view the members of private groups
+select ok(
+    user_can_view_group_members(:'communityID'::uuid, :'privateGroupID'::uuid, :'organizerID'::uuid),
+    'Should allow the group team to view the members of private groups'
+);
+
+-- Should not allow non-members to view the members of private groups
+select ok(
+    not user_can_view_group_members(:'communityID'::uuid, :'privateGroupID'::uuid, :'outsiderID'::uuid),
+    'Should not allow non-members to view the members of private groups'
+);
+
+-- Should not allow anonymous users to view the members of private groups
+select ok(
+    not user_can_view_group_members(:'communityID'::uuid, :'privateGroupID'::uuid, null),
+    'Should not allow anonymous users to view the members of private groups'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -303,6 +303,7 @@ select results_eq(
         $query$
         select
             event_id,
+            group_id,
             join_url,
             meeting_provider_id,
             provider_host_user_id,
@@ -323,6 +324,7 @@ select results_eq(
     format(
         $expected$
         values (
+            %L::uuid,
             %L::uuid,
             'https://zoom.us/j/123456789',
             'zoom',
@@ -338,7 +340,8 @@ select results_eq(
             true
         )
         $expected$,
-        :'eventID'
+        :'eventID',
+        :'groupID'
     ),
     'Meeting record created for event with expected fields'
 );
@@ -465,6 +468,7 @@ select results_eq(
             session_id,
 
             event_id,
+            group_id,
             password,
             recording_urls,
             updated_at,
@@ -486,6 +490,7 @@ select results_eq(
             %L::uuid,
 
             null::uuid,
+            %L::uuid,
             'sesspass',
             array[]::text[],
             null::timestamptz,
@@ -494,7 +499,8 @@ select results_eq(
             true
         )
         $expected$,
-        :'sessionID'
+        :'sessionID',
+        :'groupID'
     ),
     'Meeting record created for session with expected fields'
 );
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(11);
+select plan(13);
 
 -- ============================================================================
 -- VARIABLES
@@ -90,8 +90,8 @@ insert into event (
 );
 
 -- Meeting linked to event
-insert into meeting (meeting_id, event_id, meeting_provider_id, provider_meeting_id, join_url)
-values (:'meetingID', :'eventID', 'zoom', '123456789', 'https://zoom.us/j/123456789');
+insert into meeting (meeting_id, event_id, group_id, meeting_provider_id, provider_meeting_id, join_url)
+values (:'meetingID', :'eventID', :'groupID', 'zoom', '123456789', 'https://zoom.us/j/123456789');
 
 -- ============================================================================
 -- TESTS
@@ -99,7 +99,7 @@ values (:'meetingID', :'eventID', 'zoom', '123456789', 'https://zoom.us/j/123456
 
 -- Should append recording URL
 select lives_ok(
-    $$select append_meeting_recording_url('zoom', '123456789', 'https://zoom.us/rec/share/abc123')$$,
+    $$select append_meeting_recording_url('zoom', '123456789', null, 'https://zoom.us/rec/share/abc123')$$,
     'Should append recording URL'
 );
 select results_eq(
@@ -125,7 +125,7 @@ where event_id = :'eventID';
 
 -- Should append distinct recording URL
 select lives_ok(
-    $$select append_meeting_recording_url('zoom', '123456789', 'https://zoom.us/rec/share/xyz789')$$,
+    $$select append_meeting_recording_url('zoom', '123456789', null, 'https://zoom.us/rec/share/xyz789')$$,
     'Should append distinct recording URL'
 );
 select results_eq(
@@ -144,7 +144,7 @@ select is(
 
 -- Should not append duplicate recording URL
 select lives_ok(
-    $$select append_meeting_recording_url('zoom', '123456789', 'https://zoom.us/rec/share/abc123')$$,
+    $$select append_meeting_recording_url('zoom', '123456789', null, 'https://zoom.us/rec/share/abc123')$$,
     'Should accept duplicate recording URL update'
 );
 select results_eq(
@@ -158,7 +158,7 @@ select results_eq(
 
 -- Should not append blank recording URL
 select lives_ok(
-    $$select append_meeting_recording_url('zoom', '123456789', '   ')$$,
+    $$select append_meeting_recording_url('zoom', '123456789', null, '   ')$$,
     'Should accept blank recording URL update'
 );
 select results_eq(
@@ -170,9 +170,23 @@ select results_eq(
     'Blank recording URL is not appended'
 );
 
+-- Should not append recording URL reported for another group
+select lives_ok(
+    $$select append_meeting_recording_url('zoom', '123456789', '7a020000-0000-0000-0000-000000000099', 'https://zoom.us/rec/share/other')$$,
+    'Should accept recording URL update for another group'
+);
+select results_eq(
+    format(
+        $$select recording_urls from meeting where meeting_id = %L::uuid$$,
+        :'meetingID'
+    ),
+    $$ values (array['https://zoom.us/rec/share/abc123', 'https://zoom.us/rec/share/xyz789']::text[]) $$,
+    'Recording URL reported for another group is not appended'
+);
+
 -- Should not raise error when updating non-existent meeting
 select lives_ok(
-    $$ select append_meeting_recording_url('zoom', 'nonexistent', 'https://example.com/rec') $$,
+    $$ select append_meeting_recording_url('zoom', 'nonexistent', null, 'https://example.com/rec') $$,
     'Updating non-existent meeting does not raise error'
 );
 
@@ -313,10 +313,14 @@ insert into meeting (
 -- Returns and claims most recently overdue eligible event meeting first
 select is(
     claim_meeting_for_auto_end() - 'auto_end_check_claimed_at' - 'meeting_id',
-    '{
-        "meeting_provider_id": "zoom",
-        "provider_meeting_id": "event-recent-overdue"
-    }'::jsonb,
+    format(
+        '{
+            "group_id": "%s",
+            "meeting_provider_id": "zoom",
+            "provider_meeting_id": "event-recent-overdue"
+        }',
+        :'groupID'
+    )::jsonb,
     'Returns provider and meeting IDs for the most recently overdue event meeting'
 );
 select isnt(
@@ -0,0 +1,203 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0aa0000-0000-0000-0000-000000000001'
+\set eventCanceledID 'c0aa0000-0000-0000-0000-000000000002'
+\set eventCategoryID 'c0aa0000-0000-0000-0000-000000000003'
+\set eventEndedID 'c0aa0000-0000-0000-0000-000000000004'
+\set eventOldID 'c0aa0000-0000-0000-0000-000000000005'
+\set eventRecentID 'c0aa0000-0000-0000-0000-000000000006'
+\set eventWithSessionsID 'c0aa0000-0000-0000-0000-000000000007'
+\set groupCategoryID 'c0aa0000-0000-0000-0000-000000000008'
+\set groupID 'c0aa0000-0000-0000-0000-000000000009'
+\set meetingEventCanceledID 'c0aa0000-0000-0000-0000-000000000010'
+\set meetingEventEndedID 'c0aa0000-0000-0000-0000-000000000011'
+\set meetingEventOldID 'c0aa0000-0000-0000-0000-000000000012'
+\set meetingEventRecentID 'c0aa0000-0000-0000-0000-000000000013'
+\set meetingEventSyncedID 'c0aa0000-0000-0000-0000-000000000014'
+\set meetingSessionEndedID 'c0aa0000-0000-0000-0000-000000000015'
+\set sessionEndedID 'c0aa0000-0000-0000-0000-000000000016'
+\set eventSyncedID 'c0aa0000-0000-0000-0000-000000000017'
+\set eventNotRecordedID 'c0aa0000-0000-0000-0000-000000000018'
+\set meetingEventNotRecordedID 'c0aa0000-0000-0000-0000-000000000019'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Conference');
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, description)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group', 'A test group');
+
+-- Event candidates and exclusions
+insert into event (
+    canceled,
+    description,
+    ends_at,
+    event_category_id,
+    event_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values
+(false, 'Ended event', current_timestamp - interval '2 hours', :'eventCategoryID', :'eventEndedID', 'virtual', :'groupID', 'Event Ended', true, 'event-ended', current_timestamp - interval '3 hours', 'UTC'),
+(false, 'Event ended too recently', current_timestamp - interval '5 minutes', :'eventCategoryID', :'eventRecentID', 'virtual', :'groupID', 'Event Recent', true, 'event-recent', current_timestamp - interval '65 minutes', 'UTC'),
+(false, 'Event ended too long ago', current_timestamp - interval '4 days', :'eventCategoryID', :'eventOldID', 'virtual', :'groupID', 'Event Old', true, 'event-old', current_timestamp - interval '4 days 1 hour', 'UTC'),
+(true, 'Canceled event', current_timestamp - interval '2 hours', :'eventCategoryID', :'eventCanceledID', 'virtual', :'groupID', 'Event Canceled', false, 'event-canceled', current_timestamp - interval '3 hours', 'UTC'),
+(false, 'Event already synced', current_timestamp - interval '2 hours', :'eventCategoryID', :'eventSyncedID', 'virtual', :'groupID', 'Event Synced', true, 'event-synced', current_timestamp - interval '3 hours', 'UTC'),
+(false, 'Event without recording', current_timestamp - interval '2 hours', :'eventCategoryID', :'eventNotRecordedID', 'virtual', :'groupID', 'Event Not Recorded', true, 'event-not-recorded', current_timestamp - interval '3 hours', 'UTC'),
+(false, 'Parent event for session meetings', current_timestamp - interval '2 hours', :'eventCategoryID', :'eventWithSessionsID', 'virtual', :'groupID', 'Event With Sessions', true, 'event-with-sessions', current_timestamp - interval '5 hours', 'UTC');
+
+-- Events that did not request recordings are skipped
+update event set meeting_recording_requested = false where event_id = :'eventNotRecordedID';
+
+-- Session candidates
+insert into session (ends_at, event_id, name, session_id, session_kind_id, starts_at)
+values (
+    current_timestamp - interval '3 hours',
+    :'eventWithSessionsID',
+    'Session Ended',
+    :'sessionEndedID',
+    'virtual',
+    current_timestamp - interval '4 hours'
+);
+
+-- Event meeting rows
+insert into meeting (recording_synced_at, event_id, join_url, meeting_id, meeting_provider_id, provider_meeting_id)
+values
+    (null, :'eventEndedID', 'https://zoom.us/j/event-ended', :'meetingEventEndedID', 'zoom', 'event-ended'),
+    (null, :'eventRecentID', 'https://zoom.us/j/event-recent', :'meetingEventRecentID', 'zoom', 'event-recent'),
+    (null, :'eventOldID', 'https://zoom.us/j/event-old', :'meetingEventOldID', 'zoom', 'event-old'),
+    (null, :'eventCanceledID', 'https://zoom.us/j/event-canceled', :'meetingEventCanceledID', 'zoom', 'event-canceled'),
+    (null, :'eventNotRecordedID', 'https://zoom.us/j/event-not-recorded', :'meetingEventNotRecordedID', 'zoom', 'event-not-recorded'),
+    (current_timestamp, :'eventSyncedID', 'https://zoom.us/j/event-synced', :'meetingEventSyncedID', 'zoom', 'event-synced');
+
+-- Session meeting rows
+insert into meeting (join_url, meeting_id, meeting_provider_id, provider_meeting_id, session_id)
+values ('https://zoom.us/j/session-ended', :'meetingSessionEndedID', 'zoom', 'session-ended', :'sessionEndedID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Returns and claims the ended event meeting first
+select is(
+    claim_meeting_for_recording_sync() - 'recording_sync_claimed_at',
+    format(
+        '{
+            "event_id": "%s",
+            "group_id": "%s",
+            "meeting_id": "%s",
+            "meeting_provider_id": "zoom",
+            "provider_meeting_id": "event-ended"
+        }',
+        :'eventEndedID',
+        :'groupID',
+        :'meetingEventEndedID'
+    )::jsonb,
+    'Returns the ended event meeting details'
+);
+select isnt(
+    (select recording_sync_claimed_at from meeting where meeting_id = :'meetingEventEndedID'),
+    null,
+    'Claims the selected event meeting'
+);
+
+-- Falls back to session meetings, returning the parent event
+select claim_meeting_for_recording_sync() as session_claim \gset
+select is(
+    (:'session_claim'::jsonb)->>'provider_meeting_id',
+    'session-ended',
+    'Falls back to ended session meetings once event meetings are claimed'
+);
+select is(
+    ((:'session_claim'::jsonb)->>'event_id')::uuid,
+    :'eventWithSessionsID'::uuid,
+    'Returns the parent event of session meetings'
+);
+select is(
+    ((:'session_claim'::jsonb)->>'session_id')::uuid,
+    :'sessionEndedID'::uuid,
+    'Returns the session of session meetings'
+);
+
+-- Returns null once all eligible meetings are claimed
+select is(
+    claim_meeting_for_recording_sync(),
+    null::jsonb,
+    'Returns null once all eligible meetings are claimed'
+);
+
+-- Stale claims can be claimed again
+update meeting
+set recording_sync_claimed_at = current_timestamp - interval '40 minutes'
+where meeting_id = :'meetingEventEndedID';
+select is(
+    claim_meeting_for_recording_sync()->>'provider_meeting_id',
+    'event-ended',
+    'Reclaims meetings with stale recording sync claims'
+);
+
+-- Excluded meetings stay unclaimed
+select is(
+    (
+        select count(*)::integer
+        from meeting
+        where meeting_id in (
+            :'meetingEventCanceledID',
+            :'meetingEventNotRecordedID',
+            :'meetingEventOldID',
+            :'meetingEventRecentID',
+            :'meetingEventSyncedID'
+        )
+        and recording_sync_claimed_at is not null
+    ),
+    0,
+    'Keeps canceled, not recorded, old, recent and already synced meetings unclaimed'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
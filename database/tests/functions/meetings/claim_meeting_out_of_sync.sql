@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(26);
+select plan(27);
 
 -- ============================================================================
 -- VARIABLES
@@ -675,6 +675,13 @@ insert into meeting (
 -- TESTS
 -- ============================================================================
 
+-- Paused providers - meetings handled by them are not claimed
+select is(
+    claim_meeting_out_of_sync(array['zoom']),
+    null::jsonb,
+    'Meetings of paused providers are skipped'
+);
+
 -- Event create - returns event with delete=false and no meeting row
 -- Priority: event create/update work is claimed before delete work
 -- Hosts include explicit meeting_hosts, event_host, and event_speaker emails
@@ -685,13 +692,16 @@ select is(
         "delete": false,
         "duration_secs": 3600,
         "event_id": "%s",
+        "group_id": "%s",
         "hosts": ["eventhost@example.com", "eventspeaker@example.com", "explicit@example.com"],
         "meeting_provider_id": "zoom",
         "meeting_recording_requested": true,
         "timezone": "UTC",
-        "topic": "Event Create Test"
+        "topic": "Event Create Test",
+        "webinar": false
     }$json$,
-        :'eventCreateID'
+        :'eventCreateID',
+        :'groupID'
     )::jsonb,
     'Event needing create returns correctly'
 );
@@ -712,6 +722,7 @@ select is(
         "delete": false,
         "duration_secs": 7200,
         "event_id": "%s",
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/event-update",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
@@ -719,9 +730,11 @@ select is(
         "password": "eventpass",
         "provider_meeting_id": "event-update",
         "timezone": "UTC",
-        "topic": "Event Update Test"
+        "topic": "Event Update Test",
+        "webinar": false
     }$json$,
         :'eventUpdateID',
+        :'groupID',
         :'meetingEventUpdateID'
     )::jsonb,
     'Event needing update returns correctly'
@@ -739,6 +752,7 @@ select is(
         $json${
         "delete": false,
         "duration_secs": 1800,
+        "group_id": "%s",
         "hosts": ["eventhost@example.com", "sessionhost@example.com", "sessionspeaker@example.com"],
         "meeting_provider_id": "zoom",
         "meeting_recording_requested": true,
@@ -746,6 +760,7 @@ select is(
         "timezone": "UTC",
         "topic": "Session Create Test"
     }$json$,
+        :'groupID',
         :'sessionCreateID'
     )::jsonb,
     'Session needing create returns correctly'
@@ -762,6 +777,7 @@ select is(
         $json${
         "delete": false,
         "duration_secs": 1800,
+        "group_id": "%s",
         "hosts": ["eventhost@example.com"],
         "join_url": "https://zoom.us/j/session-update",
         "meeting_id": "%s",
@@ -773,6 +789,7 @@ select is(
         "timezone": "UTC",
         "topic": "Session Update Test"
     }$json$,
+        :'groupID',
         :'meetingSessionUpdateID',
         :'sessionUpdateID'
     )::jsonb,
@@ -790,12 +807,14 @@ select is(
         $json${
         "delete": true,
         "event_id": "%s",
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/event-canceled-delete",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
         "provider_meeting_id": "event-canceled-delete"
     }$json$,
         :'eventCanceledDeleteID',
+        :'groupID',
         :'meetingEventCanceledDeleteID'
     )::jsonb,
     'Event needing delete returns correctly'
@@ -817,12 +836,14 @@ select is(
         $json${
         "delete": true,
         "event_id": "%s",
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/event-deleted",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
         "provider_meeting_id": "event-deleted"
     }$json$,
         :'eventDeletedID',
+        :'groupID',
         :'meetingEventDeletedID'
     )::jsonb,
     'Soft-deleted event with meeting returns for delete'
@@ -843,9 +864,11 @@ select is(
     format(
         $json${
         "delete": true,
-        "event_id": "%s"
+        "event_id": "%s",
+        "group_id": "%s"
     }$json$,
-        :'eventUnpublishedID'
+        :'eventUnpublishedID',
+        :'groupID'
     )::jsonb,
     'Unpublished event without meeting returns for delete'
 );
@@ -866,12 +889,14 @@ select is(
         $json${
         "delete": true,
         "event_id": "%s",
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/event-unpublished",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
         "provider_meeting_id": "event-unpublished"
     }$json$,
         :'eventUnpublishedWithMeetingID',
+        :'groupID',
         :'meetingEventUnpublishedID'
     )::jsonb,
     'Unpublished event with meeting returns for delete'
@@ -893,12 +918,14 @@ select is(
         $json${
         "delete": true,
         "event_id": "%s",
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/event-disabled",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
         "provider_meeting_id": "event-disabled"
     }$json$,
         :'eventDisabledID',
+        :'groupID',
         :'meetingDisabledID'
     )::jsonb,
     'Event with meeting disabled triggers delete'
@@ -919,9 +946,11 @@ select is(
     format(
         $json${
         "delete": true,
-        "event_id": "%s"
+        "event_id": "%s",
+        "group_id": "%s"
     }$json$,
-        :'eventCanceledNoMeetingID'
+        :'eventCanceledNoMeetingID',
+        :'groupID'
     )::jsonb,
     'Event canceled before meeting created returns with delete=true and null meeting fields'
 );
@@ -941,12 +970,14 @@ select is(
     format(
         $json${
         "delete": true,
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/session-delete",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
         "provider_meeting_id": "session-delete",
         "session_id": "%s"
     }$json$,
+        :'groupID',
         :'meetingSessionDeleteID',
         :'sessionDeleteID'
     )::jsonb,
@@ -968,12 +999,14 @@ select is(
     format(
         $json${
         "delete": true,
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/session-deleted-parent",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
         "provider_meeting_id": "session-deleted-parent",
         "session_id": "%s"
     }$json$,
+        :'groupID',
         :'meetingSessionDeletedParentID',
         :'sessionDeletedParentID'
     )::jsonb,
@@ -995,8 +1028,10 @@ select is(
     format(
         $json${
         "delete": true,
+        "group_id": "%s",
         "session_id": "%s"
     }$json$,
+        :'groupID',
         :'sessionCanceledNoMeetingID'
     )::jsonb,
     'Session on canceled event before meeting created returns with delete=true and null meeting fields'
@@ -1017,8 +1052,10 @@ select is(
     format(
         $json${
         "delete": true,
+        "group_id": "%s",
         "session_id": "%s"
     }$json$,
+        :'groupID',
         :'sessionUnpublishedNoMeetingID'
     )::jsonb,
     'Session on unpublished event without meeting returns for delete'
@@ -1039,12 +1076,14 @@ select is(
     format(
         $json${
         "delete": true,
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/session-unpublished",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
         "provider_meeting_id": "session-unpublished",
         "session_id": "%s"
     }$json$,
+        :'groupID',
         :'meetingSessionUnpublishedID',
         :'sessionUnpublishedWithMeetingID'
     )::jsonb,
@@ -1066,12 +1105,14 @@ select is(
     format(
         $json${
         "delete": true,
+        "group_id": "%s",
         "join_url": "https://zoom.us/j/session-disabled",
         "meeting_id": "%s",
         "meeting_provider_id": "zoom",
         "provider_meeting_id": "session-disabled",
         "session_id": "%s"
     }$json$,
+        :'groupID',
         :'meetingSessionDisabledID',
         :'sessionDisabledID'
     )::jsonb,
@@ -1175,12 +1216,15 @@ select is(
         "delete": false,
         "duration_secs": 3600,
         "event_id": "%s",
+        "group_id": "%s",
         "meeting_provider_id": "zoom",
         "meeting_recording_requested": true,
         "timezone": "UTC",
-        "topic": "Event Unpublished Test"
+        "topic": "Event Unpublished Test",
+        "webinar": false
     }$json$,
-        :'eventUnpublishedID'
+        :'eventUnpublishedID',
+        :'groupID'
     )::jsonb,
     'Republished event triggers create'
 );
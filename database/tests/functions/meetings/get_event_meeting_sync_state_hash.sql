@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(6);
+select plan(7);
 
 -- ============================================================================
 -- VARIABLES
@@ -180,6 +180,20 @@ select isnt(
     'Should change hash when event meeting recording preference changes'
 );
 
+-- Should change the hash when the webinar mode changes
+select get_event_meeting_sync_state_hash(:'eventID') as "hashBefore" \gset
+
+-- Change the webinar mode included in the provider payload
+update event
+set meeting_webinar = true
+where event_id = :'eventID';
+
+select isnt(
+    get_event_meeting_sync_state_hash(:'eventID'),
+    :'hashBefore',
+    'Should change hash when event meeting webinar mode changes'
+);
+
 -- Should change the hash when event hosts change
 select get_event_meeting_sync_state_hash(:'eventID') as "hashBefore" \gset
 
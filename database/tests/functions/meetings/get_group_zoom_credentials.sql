@@ -0,0 +1,81 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0a10000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0a10000-0000-0000-0000-000000000002'
+\set groupID 'c0a10000-0000-0000-0000-000000000003'
+\set otherGroupID 'c0a10000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cloud-native-lisbon',
+    'Cloud Native Lisbon',
+    'Community for cloud native technologies in Lisbon',
+    'https://example.com/banner_mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'groupID', :'communityID', :'groupCategoryID', 'Group Lisbon', 'group-lisbon'),
+    (:'otherGroupID', :'communityID', :'groupCategoryID', 'Other Group', 'other-group');
+
+-- Zoom credentials
+insert into group_zoom_credentials (group_id, account_id, client_id, client_secret, host_user)
+values (:'groupID', 'account-1', 'client-1', 'secret-1', 'host@example.com');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the group credentials
+select is(
+    get_group_zoom_credentials(:'groupID'::uuid)::jsonb,
+    '{
+        "account_id": "account-1",
+        "client_id": "client-1",
+        "client_secret": "secret-1",
+        "host_user": "host@example.com"
+    }'::jsonb,
+    'Should return the group credentials'
+);
+
+-- Should return null when the group has no credentials
+select ok(
+    get_group_zoom_credentials(:'otherGroupID'::uuid) is null,
+    'Should return null when the group has no credentials'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
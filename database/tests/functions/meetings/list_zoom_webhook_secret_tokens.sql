@@ -0,0 +1,105 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0a20000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0a20000-0000-0000-0000-000000000002'
+\set group1ID 'c0a20000-0000-0000-0000-000000000003'
+\set group2ID 'c0a20000-0000-0000-0000-000000000004'
+\set group3ID 'c0a20000-0000-0000-0000-000000000005'
+\set group4ID 'c0a20000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'cloud-native-lisbon',
+    'Cloud Native Lisbon',
+    'Community for cloud native technologies in Lisbon',
+    'https://example.com/banner_mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Tech');
+
+-- Groups
+insert into "group" (group_id, community_id, group_category_id, name, slug)
+values
+    (:'group1ID', :'communityID', :'groupCategoryID', 'Group One', 'group-one'),
+    (:'group2ID', :'communityID', :'groupCategoryID', 'Group Two', 'group-two'),
+    (:'group3ID', :'communityID', :'groupCategoryID', 'Group Three', 'group-three'),
+    (:'group4ID', :'communityID', :'groupCategoryID', 'Group Four', 'group-four');
+
+-- Zoom credentials
+insert into group_zoom_credentials (
+    group_id,
+    account_id,
+    client_id,
+    client_secret,
+    host_user,
+    webhook_secret_token
+) values
+    (:'group1ID', 'account-1', 'client-1', 'secret-1', 'host1@example.com', 'webhook-secret-1'),
+    (:'group2ID', 'account-1', 'client-2', 'secret-2', 'host2@example.com', 'webhook-secret-2'),
+    (:'group3ID', 'account-1', 'client-3', 'secret-3', 'host3@example.com', null),
+    (:'group4ID', 'account-2', 'client-4', 'secret-4', 'host4@example.com', 'webhook-secret-4');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the webhook secret tokens of the account
+select is(
+    list_zoom_webhook_secret_tokens('account-1')::jsonb,
+    jsonb_build_array(
+        jsonb_build_object('group_id', :'group1ID'::uuid, 'secret_token', 'webhook-secret-1'),
+        jsonb_build_object('group_id', :'group2ID'::uuid, 'secret_token', 'webhook-secret-2')
+    ),
+    'Should return the webhook secret tokens of the account'
+);
+
+-- Should return all webhook secret tokens when no account is provided
+select is(
+    list_zoom_webhook_secret_tokens(null)::jsonb,
+    jsonb_build_array(
+        jsonb_build_object('group_id', :'group1ID'::uuid, 'secret_token', 'webhook-secret-1'),
+        jsonb_build_object('group_id', :'group2ID'::uuid, 'secret_token', 'webhook-secret-2'),
+        jsonb_build_object('group_id', :'group4ID'::uuid, 'secret_token', 'webhook-secret-4')
+    ),
+    'Should return all webhook secret tokens when no account is provided'
+);
+
+-- Should return an empty list for unknown accounts
+select is(
+    list_zoom_webhook_secret_tokens('account-unknown')::jsonb,
+    '[]'::jsonb,
+    'Should return an empty list for unknown accounts'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
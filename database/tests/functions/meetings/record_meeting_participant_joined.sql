@@ -0,0 +1,144 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(7);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0a60000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0a60000-0000-0000-0000-000000000002'
+\set eventID 'c0a60000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0a60000-0000-0000-0000-000000000004'
+\set groupID 'c0a60000-0000-0000-0000-000000000005'
+\set meetingID 'c0a60000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Event Category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Conference');
+
+-- Group Category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    description
+) values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Test Group',
+    'test-group',
+    'A test group'
+);
+
+-- Event
+insert into event (
+    event_id,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    slug,
+    description,
+    starts_at,
+    ends_at,
+    timezone
+) values (
+    :'eventID',
+    :'eventCategoryID',
+    'virtual',
+    :'groupID',
+    'Event Test',
+    'event-test',
+    'Test event for participant joins',
+    '2025-06-01 10:00:00-04',
+    '2025-06-01 11:00:00-04',
+    'America/New_York'
+);
+
+-- Meeting linked to event
+insert into meeting (meeting_id, event_id, group_id, meeting_provider_id, provider_meeting_id, join_url)
+values (:'meetingID', :'eventID', :'groupID', 'zoom', '123456789', 'https://zoom.us/j/123456789');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should count participant joins
+select lives_ok(
+    $$select record_meeting_participant_joined('zoom', '123456789', null)$$,
+    'Should record first participant join'
+);
+select lives_ok(
+    $$select record_meeting_participant_joined('zoom', '123456789', 'c0a60000-0000-0000-0000-000000000005')$$,
+    'Should record second participant join'
+);
+select is(
+    (select participants_joined_count from meeting where meeting_id = :'meetingID'),
+    2,
+    'Participant joins are counted'
+);
+
+-- Should set updated_at after recording a participant join
+select isnt(
+    (select updated_at from meeting where meeting_id = :'meetingID'),
+    null,
+    'updated_at is set after recording a participant join'
+);
+
+-- Should not count participant joins reported for another group
+select lives_ok(
+    $$select record_meeting_participant_joined('zoom', '123456789', 'c0a60000-0000-0000-0000-000000000099')$$,
+    'Should accept participant join for another group'
+);
+select is(
+    (select participants_joined_count from meeting where meeting_id = :'meetingID'),
+    2,
+    'Participant joins reported for another group are not counted'
+);
+
+-- Should not raise error when updating non-existent meeting
+select lives_ok(
+    $$select record_meeting_participant_joined('zoom', 'nonexistent', null)$$,
+    'Updating non-existent meeting does not raise error'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
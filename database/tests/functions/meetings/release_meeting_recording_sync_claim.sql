@@ -0,0 +1,138 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0ab0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0ab0000-0000-0000-0000-000000000002'
+\set eventID 'c0ab0000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0ab0000-0000-0000-0000-000000000004'
+\set groupID 'c0ab0000-0000-0000-0000-000000000005'
+\set meetingID 'c0ab0000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Conference');
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, description)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group', 'A test group');
+
+-- Event
+insert into event (
+    description,
+    ends_at,
+    event_category_id,
+    event_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values (
+    'Ended event',
+    current_timestamp - interval '2 hours',
+    :'eventCategoryID',
+    :'eventID',
+    'virtual',
+    :'groupID',
+    'Event Ended',
+    true,
+    'event-ended',
+    current_timestamp - interval '3 hours',
+    'UTC'
+);
+
+-- Meeting with an recording sync claim
+insert into meeting (
+    recording_sync_claimed_at,
+    event_id,
+    join_url,
+    meeting_id,
+    meeting_provider_id,
+    provider_meeting_id
+) values (
+    '2025-06-01 10:00:00+00',
+    :'eventID',
+    'https://zoom.us/j/123456789',
+    :'meetingID',
+    'zoom',
+    '123456789'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should not release the claim when the claim token does not match
+select lives_ok(
+    format(
+        $$select release_meeting_recording_sync_claim('2025-06-01 11:00:00+00', %L::uuid)$$,
+        :'meetingID'
+    ),
+    'Should accept a release with a mismatched claim token'
+);
+select isnt(
+    (select recording_sync_claimed_at from meeting where meeting_id = :'meetingID'),
+    null,
+    'Keeps the claim when the claim token does not match'
+);
+
+-- Should release the claim when the claim token matches
+select lives_ok(
+    format(
+        $$select release_meeting_recording_sync_claim('2025-06-01 10:00:00+00', %L::uuid)$$,
+        :'meetingID'
+    ),
+    'Should release a matching claim'
+);
+select results_eq(
+    format(
+        $$select recording_sync_claimed_at, recording_synced_at from meeting where meeting_id = %L::uuid$$,
+        :'meetingID'
+    ),
+    $$ values (null::timestamptz, null::timestamptz) $$,
+    'Releases the claim without marking the meeting as synced'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
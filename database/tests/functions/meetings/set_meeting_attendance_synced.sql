@@ -0,0 +1,188 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0a90000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0a90000-0000-0000-0000-000000000002'
+\set eventID 'c0a90000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0a90000-0000-0000-0000-000000000004'
+\set groupID 'c0a90000-0000-0000-0000-000000000005'
+\set meetingID 'c0a90000-0000-0000-0000-000000000006'
+\set user1ID 'c0a90000-0000-0000-0000-000000000007'
+\set user2ID 'c0a90000-0000-0000-0000-000000000008'
+\set user3ID 'c0a90000-0000-0000-0000-000000000009'
+\set user4ID 'c0a90000-0000-0000-0000-000000000010'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Conference');
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, description)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group', 'A test group');
+
+-- Event
+insert into event (
+    description,
+    ends_at,
+    event_category_id,
+    event_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values (
+    'Ended event',
+    current_timestamp - interval '2 hours',
+    :'eventCategoryID',
+    :'eventID',
+    'virtual',
+    :'groupID',
+    'Event Ended',
+    true,
+    'event-ended',
+    current_timestamp - interval '3 hours',
+    'UTC'
+);
+
+-- Meeting with an attendance sync claim
+insert into meeting (
+    attendance_sync_claimed_at,
+    event_id,
+    join_url,
+    meeting_id,
+    meeting_provider_id,
+    provider_meeting_id
+) values (
+    '2025-06-01 10:00:00+00',
+    :'eventID',
+    'https://zoom.us/j/123456789',
+    :'meetingID',
+    'zoom',
+    '123456789'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'user1ID', 'hash-1', 'attendee1@test.local', true, 'attendee1'),
+    (:'user2ID', 'hash-2', 'attendee2@test.local', true, 'attendee2'),
+    (:'user3ID', 'hash-3', 'attendee3@test.local', true, 'attendee3'),
+    (:'user4ID', 'hash-4', 'attendee4@test.local', true, 'attendee4');
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in, checked_in_at, status)
+values
+    (:'eventID', :'user1ID', false, null, 'confirmed'),
+    (:'eventID', :'user2ID', false, null, 'confirmed'),
+    (:'eventID', :'user3ID', true, '2025-06-01 09:00:00+00', 'confirmed'),
+    (:'eventID', :'user4ID', false, null, 'invitation-pending');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should do nothing when the claim token does not match
+select is(
+    set_meeting_attendance_synced(
+        '2025-06-01 11:00:00+00',
+        :'meetingID'::uuid,
+        :'eventID'::uuid,
+        array['attendee1@test.local']
+    ),
+    0,
+    'Returns zero when the claim token does not match'
+);
+select is(
+    (select checked_in from event_attendee where event_id = :'eventID' and user_id = :'user1ID'),
+    false,
+    'Does not check in attendees when the claim token does not match'
+);
+
+-- Should check in matching confirmed attendees
+select is(
+    set_meeting_attendance_synced(
+        '2025-06-01 10:00:00+00',
+        :'meetingID'::uuid,
+        :'eventID'::uuid,
+        array[' ATTENDEE1@test.local', 'attendee3@test.local', 'attendee4@test.local', 'guest@test.local']
+    ),
+    1,
+    'Returns the number of attendees newly checked in'
+);
+select results_eq(
+    format(
+        $$
+            select user_id, checked_in
+            from event_attendee
+            where event_id = %L::uuid
+            order by user_id
+        $$,
+        :'eventID'
+    ),
+    format(
+        $$ values (%L::uuid, true), (%L::uuid, false), (%L::uuid, true), (%L::uuid, false) $$,
+        :'user1ID',
+        :'user2ID',
+        :'user3ID',
+        :'user4ID'
+    ),
+    'Checks in only confirmed attendees that joined the meeting'
+);
+select is(
+    (select checked_in_at from event_attendee where event_id = :'eventID' and user_id = :'user3ID'),
+    '2025-06-01 09:00:00+00'::timestamptz,
+    'Keeps the check-in time of attendees already checked in'
+);
+select results_eq(
+    format(
+        $$select attendance_sync_claimed_at is null, attendance_synced_at is not null from meeting where meeting_id = %L::uuid$$,
+        :'meetingID'
+    ),
+    $$ values (true, true) $$,
+    'Completes the attendance sync claim'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
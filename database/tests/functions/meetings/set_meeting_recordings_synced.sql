@@ -0,0 +1,228 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0ac0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0ac0000-0000-0000-0000-000000000002'
+\set eventID 'c0ac0000-0000-0000-0000-000000000003'
+\set eventManualID 'c0ac0000-0000-0000-0000-000000000004'
+\set groupCategoryID 'c0ac0000-0000-0000-0000-000000000005'
+\set groupID 'c0ac0000-0000-0000-0000-000000000006'
+\set meetingEventID 'c0ac0000-0000-0000-0000-000000000007'
+\set meetingEventManualID 'c0ac0000-0000-0000-0000-000000000008'
+\set meetingSessionID 'c0ac0000-0000-0000-0000-000000000009'
+\set sessionID 'c0ac0000-0000-0000-0000-000000000010'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Conference');
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, description)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Test Group', 'test-group', 'A test group');
+
+-- Events
+insert into event (
+    description,
+    ends_at,
+    event_category_id,
+    event_id,
+    event_kind_id,
+    group_id,
+    meeting_recording_url,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values
+(
+    'Ended event',
+    current_timestamp - interval '2 hours',
+    :'eventCategoryID',
+    :'eventID',
+    'virtual',
+    :'groupID',
+    null,
+    'Event Ended',
+    true,
+    'event-ended',
+    current_timestamp - interval '3 hours',
+    'UTC'
+),
+(
+    'Ended event with an organizer recording',
+    current_timestamp - interval '2 hours',
+    :'eventCategoryID',
+    :'eventManualID',
+    'virtual',
+    :'groupID',
+    'https://youtube.com/watch?v=processed',
+    'Event Manual Recording',
+    true,
+    'event-manual-recording',
+    current_timestamp - interval '3 hours',
+    'UTC'
+);
+
+-- Session
+insert into session (ends_at, event_id, name, session_id, session_kind_id, starts_at)
+values (
+    current_timestamp - interval '150 minutes',
+    :'eventID',
+    'Session Ended',
+    :'sessionID',
+    'virtual',
+    current_timestamp - interval '3 hours'
+);
+
+-- Meetings with recording sync claims
+insert into meeting (
+    event_id,
+    join_url,
+    meeting_id,
+    meeting_provider_id,
+    provider_meeting_id,
+    recording_sync_claimed_at,
+    recording_urls,
+    session_id
+) values
+(
+    :'eventID',
+    'https://zoom.us/j/111',
+    :'meetingEventID',
+    'zoom',
+    '111',
+    '2025-06-01 10:00:00+00',
+    array['https://zoom.us/rec/share/old'],
+    null
+),
+(
+    :'eventManualID',
+    'https://zoom.us/j/222',
+    :'meetingEventManualID',
+    'zoom',
+    '222',
+    '2025-06-01 10:00:00+00',
+    array[]::text[],
+    null
+),
+(
+    null,
+    'https://zoom.us/j/333',
+    :'meetingSessionID',
+    'zoom',
+    '333',
+    '2025-06-01 10:00:00+00',
+    array[]::text[],
+    :'sessionID'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should do nothing when the claim token does not match
+select set_meeting_recordings_synced(
+    '2025-06-01 11:00:00+00',
+    :'meetingEventID'::uuid,
+    array['https://zoom.us/rec/share/new']
+);
+select results_eq(
+    format(
+        $$select recording_sync_claimed_at is not null, recording_urls from meeting where meeting_id = %L::uuid$$,
+        :'meetingEventID'
+    ),
+    $$ values (true, array['https://zoom.us/rec/share/old']) $$,
+    'Keeps the meeting untouched when the claim token does not match'
+);
+
+-- Should store new recording URLs and complete the claim
+select set_meeting_recordings_synced(
+    '2025-06-01 10:00:00+00',
+    :'meetingEventID'::uuid,
+    array[' https://zoom.us/rec/share/new ', 'https://zoom.us/rec/share/old', '']
+);
+select is(
+    (select recording_urls from meeting where meeting_id = :'meetingEventID'),
+    array['https://zoom.us/rec/share/old', 'https://zoom.us/rec/share/new'],
+    'Appends only new non-blank recording URLs'
+);
+select results_eq(
+    format(
+        $$select recording_sync_claimed_at is null, recording_synced_at is not null from meeting where meeting_id = %L::uuid$$,
+        :'meetingEventID'
+    ),
+    $$ values (true, true) $$,
+    'Completes the recording sync claim'
+);
+select is(
+    (select meeting_recording_url from event where event_id = :'eventID'),
+    'https://zoom.us/rec/share/new',
+    'Sets the first recording URL on events without one'
+);
+
+-- Should keep recording URLs set by organizers
+select set_meeting_recordings_synced(
+    '2025-06-01 10:00:00+00',
+    :'meetingEventManualID'::uuid,
+    array['https://zoom.us/rec/share/manual']
+);
+select is(
+    (select meeting_recording_url from event where event_id = :'eventManualID'),
+    'https://youtube.com/watch?v=processed',
+    'Keeps the recording URL set by organizers'
+);
+
+-- Should set the recording URL on sessions
+select set_meeting_recordings_synced(
+    '2025-06-01 10:00:00+00',
+    :'meetingSessionID'::uuid,
+    array['https://zoom.us/rec/share/session']
+);
+select is(
+    (select meeting_recording_url from session where session_id = :'sessionID'),
+    'https://zoom.us/rec/share/session',
+    'Sets the first recording URL on sessions without one'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
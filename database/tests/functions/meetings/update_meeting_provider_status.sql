@@ -0,0 +1,190 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(14);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0a50000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0a50000-0000-0000-0000-000000000002'
+\set eventID 'c0a50000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0a50000-0000-0000-0000-000000000004'
+\set groupID 'c0a50000-0000-0000-0000-000000000005'
+\set meetingID 'c0a50000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Event Category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Conference');
+
+-- Group Category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    description
+) values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Test Group',
+    'test-group',
+    'A test group'
+);
+
+-- Event
+insert into event (
+    event_id,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    slug,
+    description,
+    starts_at,
+    ends_at,
+    timezone
+) values (
+    :'eventID',
+    :'eventCategoryID',
+    'virtual',
+    :'groupID',
+    'Event Test',
+    'event-test',
+    'Test event for provider status update',
+    '2025-06-01 10:00:00-04',
+    '2025-06-01 11:00:00-04',
+    'America/New_York'
+);
+
+-- Meeting linked to event
+insert into meeting (meeting_id, event_id, group_id, meeting_provider_id, provider_meeting_id, join_url)
+values (:'meetingID', :'eventID', :'groupID', 'zoom', '123456789', 'https://zoom.us/j/123456789');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should record started status
+select lives_ok(
+    $$select update_meeting_provider_status('zoom', '123456789', null, 'started', '2025-06-01 10:01:00-04')$$,
+    'Should record started status'
+);
+select results_eq(
+    format(
+        $$select provider_status, provider_status_updated_at from meeting where meeting_id = %L::uuid$$,
+        :'meetingID'
+    ),
+    $$ values ('started', '2025-06-01 10:01:00-04'::timestamptz) $$,
+    'Started status is recorded'
+);
+
+-- Should ignore notifications older than the current status
+select lives_ok(
+    $$select update_meeting_provider_status('zoom', '123456789', null, 'ended', '2025-06-01 10:00:00-04')$$,
+    'Should accept out of order status update'
+);
+select is(
+    (select provider_status from meeting where meeting_id = :'meetingID'),
+    'started',
+    'Out of order status update is ignored'
+);
+
+-- Should record ended status
+select lives_ok(
+    $$select update_meeting_provider_status('zoom', '123456789', null, 'ended', '2025-06-01 11:02:00-04')$$,
+    'Should record ended status'
+);
+select results_eq(
+    format(
+        $$select provider_status, provider_status_updated_at from meeting where meeting_id = %L::uuid$$,
+        :'meetingID'
+    ),
+    $$ values ('ended', '2025-06-01 11:02:00-04'::timestamptz) $$,
+    'Ended status is recorded'
+);
+select is(
+    (select meeting_error from event where event_id = :'eventID'),
+    null,
+    'Ended status does not set an event meeting error'
+);
+
+-- Should ignore statuses reported for another group
+select lives_ok(
+    $$select update_meeting_provider_status('zoom', '123456789', 'c0a50000-0000-0000-0000-000000000099', 'deleted', '2025-06-01 11:30:00-04')$$,
+    'Should accept status update for another group'
+);
+select is(
+    (select provider_status from meeting where meeting_id = :'meetingID'),
+    'ended',
+    'Status reported for another group is ignored'
+);
+
+-- Should record deleted status and surface it on the event
+select lives_ok(
+    $$select update_meeting_provider_status('zoom', '123456789', 'c0a50000-0000-0000-0000-000000000005', 'deleted', '2025-06-01 12:00:00-04')$$,
+    'Should record deleted status'
+);
+select is(
+    (select provider_status from meeting where meeting_id = :'meetingID'),
+    'deleted',
+    'Deleted status is recorded'
+);
+select is(
+    (select meeting_error from event where event_id = :'eventID'),
+    'Meeting was deleted in the meetings provider',
+    'Deleted status sets the event meeting error'
+);
+
+-- Should reject unknown statuses
+select throws_ok(
+    $$select update_meeting_provider_status('zoom', '123456789', null, 'unknown', '2025-06-01 13:00:00-04')$$,
+    '23503',
+    null,
+    'Unknown statuses are rejected'
+);
+
+-- Should not raise error when updating non-existent meeting
+select lives_ok(
+    $$select update_meeting_provider_status('zoom', 'nonexistent', null, 'ended', null)$$,
+    'Updating non-existent meeting does not raise error'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(18);
+select plan(25);
 
 -- ============================================================================
 -- VARIABLES
@@ -11,27 +11,41 @@ select plan(18);
 
 \set attachmentID1 '8a010000-0000-0000-0000-000000000001'
 \set attachmentID2 '8a010000-0000-0000-0000-000000000002'
+\set communityID '8a010000-0000-0000-0000-000000000024'
+\set groupCategoryID '8a010000-0000-0000-0000-000000000026'
+\set groupID '8a010000-0000-0000-0000-000000000027'
 \set notificationAlreadyClaimedID '8a010000-0000-0000-0000-000000000003'
 \set notificationAlreadyProcessedID '8a010000-0000-0000-0000-000000000004'
 \set notificationAttachmentID '8a010000-0000-0000-0000-000000000005'
+\set notificationBrandedEmailID '8a010000-0000-0000-0000-000000000035'
+\set notificationDiscordID '8a010000-0000-0000-0000-000000000028'
 \set notificationEmailVerificationID '8a010000-0000-0000-0000-000000000006'
 \set notificationEventPublishedID '8a010000-0000-0000-0000-000000000007'
 \set notificationFutureRetryID '8a010000-0000-0000-0000-000000000023'
 \set notificationGroupWelcomeID '8a010000-0000-0000-0000-000000000008'
 \set notificationPreRegisteredEventInvitationID '8a010000-0000-0000-0000-000000000009'
+\set notificationPreRegisteredGroupTeamInvitationID '8a010000-0000-0000-0000-000000000037'
 \set notificationPreRegisteredGroupWelcomeID '8a010000-0000-0000-0000-000000000010'
 \set notificationPreRegisteredVerifiedGroupWelcomeID '8a010000-0000-0000-0000-000000000011'
 \set notificationRetryID '8a010000-0000-0000-0000-000000000012'
+\set notificationSlackID '8a010000-0000-0000-0000-000000000025'
+\set notificationSmsEventReminderID '8a010000-0000-0000-0000-000000000033'
+\set notificationSmsPhoneVerificationID '8a010000-0000-0000-0000-000000000032'
 \set notificationUnverifiedEmailVerificationID '8a010000-0000-0000-0000-000000000013'
 \set notificationUnverifiedEventPublishedID '8a010000-0000-0000-0000-000000000014'
 \set notificationUnverifiedGroupWelcomeID '8a010000-0000-0000-0000-000000000015'
+\set notificationWebhookID '8a010000-0000-0000-0000-000000000029'
+\set templateBrandedEventPublishedID '8a010000-0000-0000-0000-000000000036'
 \set templateEmailVerificationID '8a010000-0000-0000-0000-000000000016'
 \set templateEventPublishedID '8a010000-0000-0000-0000-000000000017'
 \set templateGroupWelcomeID '8a010000-0000-0000-0000-000000000018'
+\set templatePhoneVerificationID '8a010000-0000-0000-0000-000000000034'
 \set userPreRegisteredID '8a010000-0000-0000-0000-000000000019'
 \set userPreRegisteredVerifiedID '8a010000-0000-0000-0000-000000000020'
 \set userUnverifiedID '8a010000-0000-0000-0000-000000000021'
+\set userPhoneID '8a010000-0000-0000-0000-000000000031'
 \set userVerifiedID '8a010000-0000-0000-0000-000000000022'
+\set userWebhookID '8a010000-0000-0000-0000-000000000030'
 
 -- ============================================================================
 -- SEED DATA
@@ -52,6 +66,90 @@ insert into "user" (
     (:'userPreRegisteredVerifiedID', 'hash4', 'verified-invited@example.com',
         true, 'verified-invited', 'pre-registered');
 
+-- User with a webhook configured
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    username,
+    webhook_secret,
+    webhook_url
+) values (
+    :'userWebhookID',
+    'hash5',
+    'webhook@example.com',
+    true,
+    'webhook',
+    'webhook-secret',
+    'https://example.com/webhooks/ocg'
+);
+
+-- User with an unverified phone number
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    phone_number,
+    username
+) values (
+    :'userPhoneID',
+    'hash6',
+    'phone@example.com',
+    true,
+    '+15551234567',
+    'phone'
+);
+
+-- Community with a Slack webhook and email branding configured
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    email_from_name,
+    email_logo_url,
+    email_primary_color,
+    logo_url,
+    slack_webhook_url
+) values (
+    :'communityID',
+    'claim-community',
+    'Claim Community',
+    'Notification claim tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'Claim Community Team',
+    'https://example.com/email-logo.png',
+    '#0A3D62',
+    'https://example.com/logo.png',
+    'https://hooks.slack.com/services/T0/B0/secret'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Group with a Discord webhook configured
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    discord_webhook_url
+) values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Claim Group',
+    'claim-group',
+    'https://discord.com/api/webhooks/1/token'
+);
+
 -- Notification templates
 insert into notification_template_data (data, hash, notification_template_data_id) values
     (
@@ -60,7 +158,13 @@ insert into notification_template_data (data, hash, notification_template_data_i
         :'templateEmailVerificationID'
     ),
     ('{"event": "test"}'::jsonb, 'hash_event_published', :'templateEventPublishedID'),
-    ('{"group": "test"}'::jsonb, 'hash_group_welcome', :'templateGroupWelcomeID');
+    ('{"group": "test"}'::jsonb, 'hash_group_welcome', :'templateGroupWelcomeID'),
+    ('{"code": "123456"}'::jsonb, 'hash_phone_verification', :'templatePhoneVerificationID'),
+    (
+        '{"event": {"community_name": "claim-community"}}'::jsonb,
+        'hash_branded_event_published',
+        :'templateBrandedEventPublishedID'
+    );
 
 -- Notifications that should be skipped before the first eligible row
 insert into notification (
@@ -215,6 +319,16 @@ insert into notification (
         :'templateEventPublishedID',
         :'userPreRegisteredID'
     ),
+    (
+        '2025-01-01 00:00:11.5',
+        0,
+        'pending',
+        'group-team-invitation',
+        null,
+        :'notificationPreRegisteredGroupTeamInvitationID',
+        :'templateGroupWelcomeID',
+        :'userPreRegisteredID'
+    ),
     (
         '2025-01-01 00:00:12',
         0,
@@ -226,6 +340,109 @@ insert into notification (
         :'userPreRegisteredID'
     );
 
+-- Community channel notification claimed after user notifications
+insert into notification (
+    channel,
+    community_id,
+    created_at,
+    delivery_status,
+    kind,
+    notification_id,
+    notification_template_data_id
+) values (
+    'slack',
+    :'communityID',
+    '2025-01-01 00:00:13',
+    'pending',
+    'event-published',
+    :'notificationSlackID',
+    :'templateEventPublishedID'
+);
+
+-- Group channel notification claimed after community notifications
+insert into notification (
+    channel,
+    created_at,
+    delivery_status,
+    group_id,
+    kind,
+    notification_id,
+    notification_template_data_id
+) values (
+    'discord',
+    '2025-01-01 00:00:14',
+    'pending',
+    :'groupID',
+    'event-published',
+    :'notificationDiscordID',
+    :'templateEventPublishedID'
+);
+
+-- User webhook notification claimed after channel notifications
+insert into notification (
+    channel,
+    created_at,
+    delivery_status,
+    kind,
+    notification_id,
+    notification_template_data_id,
+    user_id
+) values (
+    'webhook',
+    '2025-01-01 00:00:15',
+    'pending',
+    'group-welcome',
+    :'notificationWebhookID',
+    :'templateGroupWelcomeID',
+    :'userWebhookID'
+);
+
+-- SMS notifications claimed after user webhook notifications
+insert into notification (
+    channel,
+    created_at,
+    delivery_status,
+    kind,
+    notification_id,
+    notification_template_data_id,
+    user_id
+) values
+    (
+        'sms',
+        '2025-01-01 00:00:16',
+        'pending',
+        'phone-verification',
+        :'notificationSmsPhoneVerificationID',
+        :'templatePhoneVerificationID',
+        :'userPhoneID'
+    ),
+    (
+        'sms',
+        '2025-01-01 00:00:17',
+        'pending',
+        'event-reminder',
+        :'notificationSmsEventReminderID',
+        null,
+        :'userPhoneID'
+    );
+
+-- Email notification of a community with email branding configured
+insert into notification (
+    created_at,
+    delivery_status,
+    kind,
+    notification_id,
+    notification_template_data_id,
+    user_id
+) values (
+    '2025-01-01 00:00:18',
+    'pending',
+    'event-published',
+    :'notificationBrandedEmailID',
+    :'templateBrandedEventPublishedID',
+    :'userVerifiedID'
+);
+
 -- Notification attachments
 insert into attachment (attachment_id, content_type, data, file_name, hash) values
     (:'attachmentID1', 'text/calendar', 'BEGIN:VCALENDAR'::bytea, 'event.ics', 'hash1'),
@@ -283,11 +500,19 @@ select is(
     (select row_to_json(r)::jsonb from claim_pending_notification(1, 60) r),
     jsonb_build_object(
         'attachment_ids', null,
+        'channel', 'email',
         'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
         'email', 'verified@example.com',
+        'email_branding', null,
         'kind', 'email-verification',
+        'language', null,
         'notification_id', :'notificationEmailVerificationID',
-        'template_data', '{"link": "https://example.com/verify"}'::jsonb
+        'phone_number', null,
+        'slack_webhook_url', null,
+        'template_data', '{"link": "https://example.com/verify"}'::jsonb,
+        'webhook_secret', null,
+        'webhook_url', null
     ),
     'Skips non-deliverable rows and returns all expected fields'
 );
@@ -315,11 +540,19 @@ select is(
     (select row_to_json(r)::jsonb from claim_pending_notification() r),
     jsonb_build_object(
         'attachment_ids', null,
+        'channel', 'email',
         'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
         'email', 'verified@example.com',
+        'email_branding', null,
         'kind', 'group-welcome',
+        'language', null,
         'notification_id', :'notificationGroupWelcomeID',
-        'template_data', '{"group": "test"}'::jsonb
+        'phone_number', null,
+        'slack_webhook_url', null,
+        'template_data', '{"group": "test"}'::jsonb,
+        'webhook_secret', null,
+        'webhook_url', null
     ),
     'Claims group-welcome notification for verified user'
 );
@@ -330,11 +563,19 @@ select is(
     (select row_to_json(r)::jsonb from claim_pending_notification() r),
     jsonb_build_object(
         'attachment_ids', null,
+        'channel', 'email',
         'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
         'email', 'verified@example.com',
+        'email_branding', null,
         'kind', 'event-published',
+        'language', null,
         'notification_id', :'notificationEventPublishedID',
-        'template_data', '{"event": "test"}'::jsonb
+        'phone_number', null,
+        'slack_webhook_url', null,
+        'template_data', '{"event": "test"}'::jsonb,
+        'webhook_secret', null,
+        'webhook_url', null
     ),
     'Claims event-published notification for verified user'
 );
@@ -345,11 +586,19 @@ select is(
     (select row_to_json(r)::jsonb from claim_pending_notification() r),
     jsonb_build_object(
         'attachment_ids', array[:'attachmentID1', :'attachmentID2']::uuid[],
+        'channel', 'email',
         'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
         'email', 'verified@example.com',
+        'email_branding', null,
         'kind', 'event-welcome',
+        'language', null,
         'notification_id', :'notificationAttachmentID',
-        'template_data', null
+        'phone_number', null,
+        'slack_webhook_url', null,
+        'template_data', null,
+        'webhook_secret', null,
+        'webhook_url', null
     ),
     'Claims attachment notification and returns sorted attachment ids'
 );
@@ -392,15 +641,30 @@ select is(
     (select row_to_json(r)::jsonb from claim_pending_notification() r),
     jsonb_build_object(
         'attachment_ids', null,
+        'channel', 'email',
         'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
         'email', 'invited@example.com',
+        'email_branding', null,
         'kind', 'event-invitation',
+        'language', null,
         'notification_id', :'notificationPreRegisteredEventInvitationID',
-        'template_data', '{"event": "test"}'::jsonb
+        'phone_number', null,
+        'slack_webhook_url', null,
+        'template_data', '{"event": "test"}'::jsonb,
+        'webhook_secret', null,
+        'webhook_url', null
     ),
     'Claims event invitation notification for pre-registered user'
 );
 
+-- Should return group team invitation notifications for pre-registered users
+select is(
+    (select notification_id from claim_pending_notification()),
+    :'notificationPreRegisteredGroupTeamInvitationID'::uuid,
+    'Claims group team invitation notification for pre-registered user'
+);
+
 -- Should leave other notification kinds for unverified users pending
 select results_eq(
     format(
@@ -455,6 +719,128 @@ select is(
     'Leaves regular notifications pending for pre-registered users even when email is verified'
 );
 
+-- Should claim community channel notifications with their webhook
+select is(
+    (select row_to_json(r)::jsonb from claim_pending_notification() r),
+    jsonb_build_object(
+        'attachment_ids', null,
+        'channel', 'slack',
+        'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
+        'email', null,
+        'email_branding', null,
+        'kind', 'event-published',
+        'language', null,
+        'notification_id', :'notificationSlackID',
+        'phone_number', null,
+        'slack_webhook_url', 'https://hooks.slack.com/services/T0/B0/secret',
+        'template_data', '{"event": "test"}'::jsonb,
+        'webhook_secret', null,
+        'webhook_url', null
+    ),
+    'Claims community channel notification and returns its webhook'
+);
+
+-- Should claim group channel notifications with their webhook
+select is(
+    (select row_to_json(r)::jsonb from claim_pending_notification() r),
+    jsonb_build_object(
+        'attachment_ids', null,
+        'channel', 'discord',
+        'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', 'https://discord.com/api/webhooks/1/token',
+        'email', null,
+        'email_branding', null,
+        'kind', 'event-published',
+        'language', null,
+        'notification_id', :'notificationDiscordID',
+        'phone_number', null,
+        'slack_webhook_url', null,
+        'template_data', '{"event": "test"}'::jsonb,
+        'webhook_secret', null,
+        'webhook_url', null
+    ),
+    'Claims group channel notification and returns its webhook'
+);
+
+-- Should claim user webhook notifications with their webhook and secret
+select is(
+    (select row_to_json(r)::jsonb from claim_pending_notification() r),
+    jsonb_build_object(
+        'attachment_ids', null,
+        'channel', 'webhook',
+        'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
+        'email', 'webhook@example.com',
+        'email_branding', null,
+        'kind', 'group-welcome',
+        'language', null,
+        'notification_id', :'notificationWebhookID',
+        'phone_number', null,
+        'slack_webhook_url', null,
+        'template_data', '{"group": "test"}'::jsonb,
+        'webhook_secret', 'webhook-secret',
+        'webhook_url', 'https://example.com/webhooks/ocg'
+    ),
+    'Claims user webhook notification and returns its webhook and secret'
+);
+
+-- Should claim phone verification notifications for unverified phone numbers
+select is(
+    (select row_to_json(r)::jsonb from claim_pending_notification() r),
+    jsonb_build_object(
+        'attachment_ids', null,
+        'channel', 'sms',
+        'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
+        'email', 'phone@example.com',
+        'email_branding', null,
+        'kind', 'phone-verification',
+        'language', null,
+        'notification_id', :'notificationSmsPhoneVerificationID',
+        'phone_number', '+15551234567',
+        'slack_webhook_url', null,
+        'template_data', '{"code": "123456"}'::jsonb,
+        'webhook_secret', null,
+        'webhook_url', null
+    ),
+    'Claims phone verification notification and returns the phone number'
+);
+
+-- Should not return the phone number when the user has not opted in
+select is(
+    (select phone_number from claim_pending_notification()),
+    null::text,
+    'Returns no phone number for SMS notifications the user has not opted in'
+);
+
+-- Should return the email branding of the community the notification belongs to
+update "user" set language = 'es' where user_id = :'userVerifiedID';
+select is(
+    (select row_to_json(r)::jsonb from claim_pending_notification() r),
+    jsonb_build_object(
+        'attachment_ids', null,
+        'channel', 'email',
+        'delivery_claimed_at', current_timestamp,
+        'discord_webhook_url', null,
+        'email', 'verified@example.com',
+        'email_branding', jsonb_build_object(
+            'from_name', 'Claim Community Team',
+            'logo_url', 'https://example.com/email-logo.png',
+            'primary_color', '#0A3D62'
+        ),
+        'kind', 'event-published',
+        'language', 'es',
+        'notification_id', :'notificationBrandedEmailID',
+        'phone_number', null,
+        'slack_webhook_url', null,
+        'template_data', '{"event": {"community_name": "claim-community"}}'::jsonb,
+        'webhook_secret', null,
+        'webhook_url', null
+    ),
+    'Claims email notification and returns its community email branding and user language'
+);
+
 -- Should return NULL when no deliverable pending notifications exist
 select is(
     (select notification_id from claim_pending_notification()),
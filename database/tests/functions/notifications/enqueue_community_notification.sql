@@ -0,0 +1,112 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0ad0000-0000-0000-0000-000000000001'
+\set communityWithoutWebhookID 'c0ad0000-0000-0000-0000-000000000002'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    slack_webhook_url
+) values
+    (
+        :'communityID',
+        'slack-community',
+        'Slack Community',
+        'Community with a Slack webhook',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png',
+        'https://hooks.slack.com/services/T0/B0/secret'
+    ),
+    (
+        :'communityWithoutWebhookID',
+        'quiet-community',
+        'Quiet Community',
+        'Community without a Slack webhook',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png',
+        null
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should skip communities without a Slack webhook
+select lives_ok(
+    format(
+        $$select enqueue_community_notification(%L::uuid, 'event-published', '{"event": "test"}'::jsonb)$$,
+        :'communityWithoutWebhookID'
+    ),
+    'Should skip communities without a Slack webhook'
+);
+select is(
+    (select count(*) from notification where community_id = :'communityWithoutWebhookID'),
+    0::bigint,
+    'Should not create notifications for communities without a Slack webhook'
+);
+
+-- Should enqueue a Slack notification for communities with a webhook
+select lives_ok(
+    format(
+        $$select enqueue_community_notification(%L::uuid, 'event-published', '{"event": "test"}'::jsonb)$$,
+        :'communityID'
+    ),
+    'Should enqueue a Slack notification for communities with a webhook'
+);
+select results_eq(
+    format(
+        $$
+        select
+            n.channel,
+            n.delivery_status,
+            n.kind,
+            n.user_id,
+            ntd.data
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.community_id = %L::uuid
+        $$,
+        :'communityID'
+    ),
+    $$ values ('slack', 'pending', 'event-published', null::uuid, '{"event": "test"}'::jsonb) $$,
+    'Should store the Slack notification with its template data'
+);
+
+-- Should reject unknown notification kinds
+select throws_ok(
+    format(
+        $$select enqueue_community_notification(%L::uuid, 'unknown-kind', null)$$,
+        :'communityID'
+    ),
+    '23503',
+    null,
+    'Should reject unknown notification kinds'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,171 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c1360000-0000-0000-0000-000000000061'
+\set draftIssueID 'c1360000-0000-0000-0000-000000000062'
+\set issueID 'c1360000-0000-0000-0000-000000000063'
+\set siteID 'c1360000-0000-0000-0000-000000000064'
+\set user1ID 'c1360000-0000-0000-0000-000000000065'
+\set user2ID 'c1360000-0000-0000-0000-000000000066'
+\set user3ID 'c1360000-0000-0000-0000-000000000067'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Site settings
+insert into site (site_id, title, description, theme) values (
+    :'siteID',
+    'Test Site',
+    'Test Site Description',
+    '{"primary_color": "#2563eb"}'::jsonb
+);
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'newsletter-community',
+    'Newsletter Community',
+    'Community used for newsletter tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'user1ID', 'hash-1', 'user1@example.com', true, 'user1'),
+    (:'user2ID', 'hash-2', 'user2@example.com', true, 'user2'),
+    (:'user3ID', 'hash-3', 'user3@example.com', true, 'user3');
+
+-- Newsletter issues
+insert into community_newsletter_issue (
+    community_newsletter_issue_id,
+    body,
+    community_id,
+    subject,
+    recipient_count,
+    sent_at
+) values
+    (:'draftIssueID', 'Draft body', :'communityID', 'Draft issue', null, null),
+    (:'issueID', 'Hello **everyone**', :'communityID', 'Monthly update', 3, current_timestamp);
+
+-- Newsletter issue recipients
+insert into community_newsletter_issue_recipient (community_newsletter_issue_id, user_id) values
+    (:'issueID', :'user1ID'),
+    (:'issueID', :'user2ID'),
+    (:'issueID', :'user3ID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should reject invalid batch sizes
+select throws_ok(
+    $$ select enqueue_due_community_newsletter_batches('https://example.test', 0) $$,
+    'batch size must be positive',
+    'Should reject invalid batch sizes'
+);
+
+-- Should enqueue the first batch of recipients
+select is(
+    enqueue_due_community_newsletter_batches('https://example.test/', 2),
+    2,
+    'Should enqueue the first batch of recipients'
+);
+select results_eq(
+    $$
+        select user_id, community_id, community_newsletter_issue_id
+        from notification
+        where kind = 'community-newsletter'
+        order by user_id
+    $$,
+    format(
+        $$ values (%L::uuid, %L::uuid, %L::uuid), (%L::uuid, %L::uuid, %L::uuid) $$,
+        :'user1ID',
+        :'communityID',
+        :'issueID',
+        :'user2ID',
+        :'communityID',
+        :'issueID'
+    ),
+    'Should link the notifications to the community and the issue'
+);
+select results_eq(
+    format(
+        $$
+        select user_id
+        from community_newsletter_issue_recipient
+        where community_newsletter_issue_id = %L::uuid
+        and enqueued_at is null
+        $$,
+        :'issueID'
+    ),
+    format($$ values (%L::uuid) $$, :'user3ID'),
+    'Should leave the remaining recipients pending'
+);
+
+-- Should include the issue and community links in the template data
+select is(
+    (
+        select ntd.data
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'community-newsletter'
+        and n.user_id = :'user1ID'::uuid
+    ),
+    jsonb_build_object(
+        'body', 'Hello **everyone**',
+        'community_display_name', 'Newsletter Community',
+        'link', 'https://example.test/newsletter-community',
+        'newsletter_link', format(
+            'https://example.test/newsletter-community/newsletter/%s',
+            :'issueID'
+        ),
+        'subject', 'Monthly update',
+        'theme', '{"primary_color": "#2563eb"}'::jsonb
+    ),
+    'Should include the issue and community links in the template data'
+);
+
+-- Should enqueue the remaining recipients in the next batch
+select is(
+    enqueue_due_community_newsletter_batches('https://example.test/', 2),
+    1,
+    'Should enqueue the remaining recipients in the next batch'
+);
+select is(
+    (select count(*)::int from notification where kind = 'community-newsletter'),
+    3,
+    'Should have enqueued all the recipients'
+);
+
+-- Should not enqueue anything when all the recipients have been enqueued
+select is(
+    enqueue_due_community_newsletter_batches('https://example.test/', 2),
+    0,
+    'Should not enqueue anything when all the recipients have been enqueued'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,213 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(7);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityDisabledID 'c1260000-0000-0000-0000-000000000001'
+\set communityEmptyID 'c1260000-0000-0000-0000-000000000002'
+\set communityID 'c1260000-0000-0000-0000-000000000003'
+\set groupActiveID 'c1260000-0000-0000-0000-000000000004'
+\set groupArchivedID 'c1260000-0000-0000-0000-000000000005'
+\set groupCategoryDisabledID 'c1260000-0000-0000-0000-000000000006'
+\set groupCategoryID 'c1260000-0000-0000-0000-000000000007'
+\set groupDisabledID 'c1260000-0000-0000-0000-000000000008'
+\set groupDormant1ID 'c1260000-0000-0000-0000-000000000009'
+\set groupDormant2ID 'c1260000-0000-0000-0000-000000000010'
+\set siteID 'c1260000-0000-0000-0000-000000000011'
+\set userAdminID 'c1260000-0000-0000-0000-000000000012'
+\set userPendingAdminID 'c1260000-0000-0000-0000-000000000013'
+\set userViewerID 'c1260000-0000-0000-0000-000000000014'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Site settings
+insert into site (site_id, title, description, theme) values (
+    :'siteID',
+    'Test Site',
+    'Test Site Description',
+    '{"primary_color": "#2563eb"}'::jsonb
+);
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url,
+    dormant_group_months,
+    dormant_groups_report_enabled
+) values
+    (
+        :'communityID',
+        'report-community',
+        'Report Community',
+        'Dormant groups report tests',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png',
+        3,
+        true
+    ),
+    (
+        :'communityEmptyID',
+        'empty-community',
+        'Empty Community',
+        'Community without dormant groups',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png',
+        6,
+        true
+    ),
+    (
+        :'communityDisabledID',
+        'disabled-community',
+        'Disabled Community',
+        'Community with the report disabled',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png',
+        6,
+        false
+    );
+
+-- Group categories
+insert into group_category (group_category_id, community_id, name) values
+    (:'groupCategoryID', :'communityID', 'Technology'),
+    (:'groupCategoryDisabledID', :'communityDisabledID', 'Technology');
+
+-- Groups
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    slug_pretty,
+    archived_at,
+    dormant_since
+) values
+    (:'groupActiveID', :'communityID', :'groupCategoryID', 'Active Group', 'active-group', null,
+        null, null),
+    (:'groupArchivedID', :'communityID', :'groupCategoryID', 'Archived Group', 'archived-group', null,
+        current_timestamp, '2024-01-01 00:00:00+00'),
+    (:'groupDormant1ID', :'communityID', :'groupCategoryID', 'Dormant Group 1', 'dormant-group-1', 'dormant-one',
+        null, '2024-02-01 00:00:00+00'),
+    (:'groupDormant2ID', :'communityID', :'groupCategoryID', 'Dormant Group 2', 'dormant-group-2', null,
+        null, '2024-01-01 00:00:00+00'),
+    (:'groupDisabledID', :'communityDisabledID', :'groupCategoryDisabledID', 'Disabled Group', 'disabled-group', null,
+        null, '2024-01-01 00:00:00+00');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userAdminID', 'hash-1', 'admin@example.com', true, 'admin'),
+    (:'userPendingAdminID', 'hash-2', 'pending@example.com', true, 'pending'),
+    (:'userViewerID', 'hash-3', 'viewer@example.com', true, 'viewer');
+
+-- Community team
+insert into community_team (community_id, user_id, accepted, role) values
+    (:'communityID', :'userAdminID', true, 'admin'),
+    (:'communityID', :'userPendingAdminID', false, 'admin'),
+    (:'communityID', :'userViewerID', true, 'viewer'),
+    (:'communityDisabledID', :'userAdminID', true, 'admin');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should enqueue one report per community with dormant groups
+select is(
+    enqueue_due_dormant_groups_reports('https://example.test/'),
+    1,
+    'Should enqueue one report per community with dormant groups'
+);
+
+-- Should only send the report to the accepted community admins
+select results_eq(
+    $$
+        select user_id
+        from notification
+        where kind = 'dormant-groups-report'
+        order by user_id
+    $$,
+    format($$ values (%L::uuid) $$, :'userAdminID'),
+    'Should only send the report to the accepted community admins'
+);
+
+-- Should include the active dormant groups ordered by dormant date
+select is(
+    (
+        select ntd.data
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'dormant-groups-report'
+    ),
+    '{
+        "community_display_name": "Report Community",
+        "dormant_group_months": 3,
+        "groups": [
+            {
+                "dormant_since": 1704067200,
+                "link": "https://example.test/report-community/group/dormant-group-2",
+                "name": "Dormant Group 2"
+            },
+            {
+                "dormant_since": 1706745600,
+                "link": "https://example.test/report-community/group/dormant-one",
+                "name": "Dormant Group 1"
+            }
+        ],
+        "link": "https://example.test/dashboard/community?tab=groups&dormant=true",
+        "theme": {"primary_color": "#2563eb"}
+    }'::jsonb,
+    'Should include the active dormant groups ordered by dormant date'
+);
+
+-- Should mark the report as sent for communities without dormant groups
+select isnt(
+    (select dormant_groups_report_sent_at from community where community_id = :'communityEmptyID'),
+    null::timestamptz,
+    'Should mark the report as sent for communities without dormant groups'
+);
+
+-- Should not process communities with the report disabled
+select is(
+    (select dormant_groups_report_sent_at from community where community_id = :'communityDisabledID'),
+    null::timestamptz,
+    'Should not process communities with the report disabled'
+);
+
+-- Should not enqueue reports again within the same month
+select is(
+    enqueue_due_dormant_groups_reports('https://example.test'),
+    0,
+    'Should not enqueue reports again within the same month'
+);
+
+-- Should enqueue reports again once a month has passed
+update community
+set dormant_groups_report_sent_at = current_timestamp - interval '32 days'
+where community_id = :'communityID';
+select is(
+    enqueue_due_dormant_groups_reports('https://example.test'),
+    1,
+    'Should enqueue reports again once a month has passed'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,213 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(7);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0e60000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0e60000-0000-0000-0000-000000000002'
+\set eventDisabledID 'c0e60000-0000-0000-0000-000000000003'
+\set eventEndedID 'c0e60000-0000-0000-0000-000000000004'
+\set eventOldID 'c0e60000-0000-0000-0000-000000000005'
+\set eventUpcomingID 'c0e60000-0000-0000-0000-000000000006'
+\set groupCategoryID 'c0e60000-0000-0000-0000-000000000007'
+\set groupID 'c0e60000-0000-0000-0000-000000000008'
+\set siteID 'c0e60000-0000-0000-0000-000000000009'
+\set userCheckedIn1ID 'c0e60000-0000-0000-0000-000000000010'
+\set userCheckedIn2ID 'c0e60000-0000-0000-0000-000000000011'
+\set userNotCheckedInID 'c0e60000-0000-0000-0000-000000000012'
+\set userUnverifiedID 'c0e60000-0000-0000-0000-000000000013'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Site settings
+insert into site (site_id, title, description, theme) values (
+    :'siteID',
+    'Test Site',
+    'Test Site Description',
+    '{"primary_color": "#2563eb"}'::jsonb
+);
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'certificates-community',
+    'Certificates Community',
+    'Attendance certificates tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Categories
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, description)
+values (:'groupID', :'communityID', :'groupCategoryID', 'Group 1', 'group-1', 'Group 1');
+
+-- Users
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    optional_notifications_enabled,
+    username,
+    name
+) values
+    (:'userCheckedIn1ID', 'hash-1', 'checked-in-1@example.com', true, true, 'checked-in-1', 'First Attendee'),
+    (:'userCheckedIn2ID', 'hash-2', 'checked-in-2@example.com', true, true, 'checked-in-2', 'Second Attendee'),
+    (:'userNotCheckedInID', 'hash-3', 'not-checked-in@example.com', true, true, 'not-checked-in', null),
+    (:'userUnverifiedID', 'hash-4', 'unverified@example.com', false, true, 'unverified', null);
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    ends_at,
+    published,
+    attendance_certificate_emails_enabled
+) values
+    (:'eventEndedID', :'groupID', 'Ended Event', 'ended-event', 'Ended', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp - interval '3 hours',
+        current_timestamp - interval '1 hour', true, true),
+    (:'eventDisabledID', :'groupID', 'Disabled Event', 'disabled-event', 'Disabled', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp - interval '3 hours',
+        current_timestamp - interval '1 hour', true, false),
+    (:'eventOldID', :'groupID', 'Old Event', 'old-event', 'Old', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp - interval '30 days',
+        current_timestamp - interval '30 days', true, true),
+    (:'eventUpcomingID', :'groupID', 'Upcoming Event', 'upcoming-event', 'Upcoming', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '1 day',
+        current_timestamp + interval '1 day 2 hours', true, true);
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in, status) values
+    (:'eventEndedID', :'userCheckedIn1ID', true, 'confirmed'),
+    (:'eventEndedID', :'userCheckedIn2ID', true, 'confirmed'),
+    (:'eventEndedID', :'userNotCheckedInID', false, 'confirmed'),
+    (:'eventEndedID', :'userUnverifiedID', true, 'confirmed'),
+    (:'eventDisabledID', :'userCheckedIn1ID', true, 'confirmed'),
+    (:'eventOldID', :'userCheckedIn1ID', true, 'confirmed'),
+    (:'eventUpcomingID', :'userCheckedIn1ID', true, 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should enqueue certificates for the checked-in attendees of recently ended events
+select is(
+    enqueue_due_event_attendance_certificates('https://example.test/'),
+    2,
+    'Should enqueue certificates for the checked-in attendees of recently ended events'
+);
+
+-- Should only enqueue certificates for verified checked-in attendees
+select results_eq(
+    $$
+        select user_id
+        from notification
+        where kind = 'event-attendance-certificate'
+        order by user_id
+    $$,
+    format(
+        $$ values (%L::uuid), (%L::uuid) $$,
+        :'userCheckedIn1ID',
+        :'userCheckedIn2ID'
+    ),
+    'Should only enqueue certificates for verified checked-in attendees'
+);
+
+-- Should include each attendee certificate in the template data
+select results_eq(
+    $$
+        select ntd.data->'certificate'->>'attendee_name'
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-attendance-certificate'
+        order by n.user_id
+    $$,
+    $$ values ('First Attendee'), ('Second Attendee') $$,
+    'Should include each attendee certificate in the template data'
+);
+
+-- Should include the event and the dashboard link in the template data
+select is(
+    (
+        select distinct jsonb_build_object(
+            'event_id', ntd.data->'event'->>'event_id',
+            'link', ntd.data->>'link'
+        )
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-attendance-certificate'
+    ),
+    jsonb_build_object(
+        'event_id', :'eventEndedID',
+        'link', 'https://example.test/dashboard/user?tab=certificates'
+    ),
+    'Should include the event and the dashboard link in the template data'
+);
+
+-- Should only mark the evaluated events as processed
+select results_eq(
+    $$
+        select event_id
+        from event
+        where attendance_certificates_sent_at is not null
+        order by event_id
+    $$,
+    format($$ values (%L::uuid) $$, :'eventEndedID'),
+    'Should only mark the evaluated events as processed'
+);
+
+-- Should not enqueue the certificates of an event twice
+select is(
+    enqueue_due_event_attendance_certificates('https://example.test'),
+    0,
+    'Should not enqueue the certificates of an event twice'
+);
+
+-- Should mark events as processed even when there are no recipients
+update event_attendee set checked_in = false where event_id = :'eventEndedID';
+update event set attendance_certificates_sent_at = null where event_id = :'eventEndedID';
+select enqueue_due_event_attendance_certificates('https://example.test');
+select isnt(
+    (select attendance_certificates_sent_at from event where event_id = :'eventEndedID'),
+    null,
+    'Should mark events as processed even when there are no recipients'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
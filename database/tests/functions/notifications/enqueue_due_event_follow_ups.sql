@@ -0,0 +1,237 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0b90000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0b90000-0000-0000-0000-000000000002'
+\set eventEndedID 'c0b90000-0000-0000-0000-000000000003'
+\set eventNoFeedbackID 'c0b90000-0000-0000-0000-000000000004'
+\set eventOldID 'c0b90000-0000-0000-0000-000000000005'
+\set eventUpcomingID 'c0b90000-0000-0000-0000-000000000006'
+\set groupCategoryID 'c0b90000-0000-0000-0000-000000000007'
+\set groupID 'c0b90000-0000-0000-0000-000000000008'
+\set siteID 'c0b90000-0000-0000-0000-000000000009'
+\set userCheckedInID 'c0b90000-0000-0000-0000-000000000010'
+\set userNotCheckedInID 'c0b90000-0000-0000-0000-000000000011'
+\set userOptedOutID 'c0b90000-0000-0000-0000-000000000012'
+\set userUnverifiedID 'c0b90000-0000-0000-0000-000000000013'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Site settings
+insert into site (site_id, title, description, theme) values (
+    :'siteID',
+    'Test Site',
+    'Test Site Description',
+    '{"primary_color": "#2563eb"}'::jsonb
+);
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'follow-up-community',
+    'Follow Up Community',
+    'Event follow-up tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Categories
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    slug_pretty,
+    description
+) values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Group 1',
+    'group-1',
+    'group-one',
+    'Group 1'
+);
+
+-- Users
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    optional_notifications_enabled,
+    username
+) values
+    (:'userCheckedInID', 'hash-1', 'checked-in@example.com', true, true, 'checked-in'),
+    (:'userNotCheckedInID', 'hash-2', 'not-checked-in@example.com', true, true, 'not-checked-in'),
+    (:'userOptedOutID', 'hash-3', 'opted-out@example.com', true, false, 'opted-out'),
+    (:'userUnverifiedID', 'hash-4', 'unverified@example.com', false, true, 'unverified');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    ends_at,
+    published,
+    feedback_url,
+    meeting_recording_published,
+    meeting_recording_url
+) values
+    (:'eventEndedID', :'groupID', 'Ended Event', 'ended-event', 'Ended', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp - interval '3 hours',
+        current_timestamp - interval '1 hour', true, 'https://example.com/feedback', true,
+        'https://example.com/recording'),
+    (:'eventNoFeedbackID', :'groupID', 'No Feedback Event', 'no-feedback-event', 'No feedback', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp - interval '3 hours',
+        current_timestamp - interval '1 hour', true, null, false, null),
+    (:'eventOldID', :'groupID', 'Old Event', 'old-event', 'Old', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp - interval '30 days',
+        current_timestamp - interval '30 days', true, 'https://example.com/feedback', false, null),
+    (:'eventUpcomingID', :'groupID', 'Upcoming Event', 'upcoming-event', 'Upcoming', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '1 day',
+        current_timestamp + interval '1 day 2 hours', true, 'https://example.com/feedback', false, null);
+
+-- Attendees
+insert into event_attendee (event_id, user_id, checked_in, status) values
+    (:'eventEndedID', :'userCheckedInID', true, 'confirmed'),
+    (:'eventEndedID', :'userNotCheckedInID', false, 'confirmed'),
+    (:'eventEndedID', :'userOptedOutID', true, 'confirmed'),
+    (:'eventEndedID', :'userUnverifiedID', true, 'confirmed'),
+    (:'eventNoFeedbackID', :'userCheckedInID', true, 'confirmed'),
+    (:'eventOldID', :'userCheckedInID', true, 'confirmed'),
+    (:'eventUpcomingID', :'userCheckedInID', true, 'confirmed');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should enqueue follow-ups for the checked-in attendees of recently ended events
+select is(
+    enqueue_due_event_follow_ups('https://example.test/'),
+    1,
+    'Should enqueue follow-ups for the checked-in attendees of recently ended events'
+);
+
+-- Should only enqueue follow-ups for verified checked-in attendees who did not opt out
+select results_eq(
+    $$
+        select user_id
+        from notification
+        where kind = 'event-follow-up'
+        order by user_id
+    $$,
+    format($$ values (%L::uuid) $$, :'userCheckedInID'),
+    'Should only enqueue follow-ups for verified checked-in attendees who did not opt out'
+);
+
+-- Should include the event in the follow-up template data
+select is(
+    (
+        select ntd.data->'event'->>'event_id'
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-follow-up'
+    ),
+    :'eventEndedID',
+    'Should include the event in the follow-up template data'
+);
+
+-- Should include the feedback and published recording links
+select is(
+    (
+        select jsonb_build_object(
+            'feedback_link', ntd.data->>'feedback_link',
+            'recording_link', ntd.data->>'recording_link'
+        )
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-follow-up'
+    ),
+    jsonb_build_object(
+        'feedback_link', 'https://example.com/feedback',
+        'recording_link', 'https://example.com/recording'
+    ),
+    'Should include the feedback and published recording links'
+);
+
+-- Should build the event link using the provided base URL
+select is(
+    (
+        select ntd.data->>'link'
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-follow-up'
+    ),
+    'https://example.test/follow-up-community/group/group-one/event/ended-event',
+    'Should build the event link using the provided base URL'
+);
+
+-- Should only mark the evaluated events as followed up
+select results_eq(
+    $$
+        select event_id
+        from event
+        where follow_up_sent_at is not null
+        order by event_id
+    $$,
+    format($$ values (%L::uuid) $$, :'eventEndedID'),
+    'Should only mark the evaluated events as followed up'
+);
+
+-- Should not enqueue the follow-up of an event twice
+select is(
+    enqueue_due_event_follow_ups('https://example.test'),
+    0,
+    'Should not enqueue the follow-up of an event twice'
+);
+
+-- Should mark events as followed up even when there are no recipients
+update event_attendee set checked_in = false where event_id = :'eventEndedID';
+update event set follow_up_sent_at = null where event_id = :'eventEndedID';
+select enqueue_due_event_follow_ups('https://example.test');
+select isnt(
+    (select follow_up_sent_at from event where event_id = :'eventEndedID'),
+    null,
+    'Should mark events as followed up even when there are no recipients'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(17);
+select plan(29);
 
 -- ============================================================================
 -- VARIABLES
@@ -18,9 +18,12 @@ select plan(17);
 \set eventDueID '8a020000-0000-0000-0000-000000000007'
 \set eventInactiveCommunityID '8a020000-0000-0000-0000-000000000008'
 \set eventInactiveGroupID '8a020000-0000-0000-0000-000000000009'
+\set eventLatePublishedID '8a020000-0000-0000-0000-000000000025'
 \set eventNoRecipientsID '8a020000-0000-0000-0000-000000000010'
 \set eventNotDueID '8a020000-0000-0000-0000-000000000011'
+\set eventPersonalID '8a020000-0000-0000-0000-000000000027'
 \set eventSentID '8a020000-0000-0000-0000-000000000012'
+\set eventSoonID '8a020000-0000-0000-0000-000000000026'
 \set groupCategoryID '8a020000-0000-0000-0000-000000000013'
 \set groupCategoryInactiveCommunityID '8a020000-0000-0000-0000-000000000014'
 \set groupDeletedID '8a020000-0000-0000-0000-000000000015'
@@ -28,6 +31,8 @@ select plan(17);
 \set groupInactiveCommunityID '8a020000-0000-0000-0000-000000000017'
 \set groupInactiveID '8a020000-0000-0000-0000-000000000018'
 \set siteID '8a020000-0000-0000-0000-000000000019'
+\set userOptedOutID '8a020000-0000-0000-0000-000000000028'
+\set userPersonalID '8a020000-0000-0000-0000-000000000029'
 \set userPreRegisteredInvitedID '8a020000-0000-0000-0000-000000000020'
 \set userUnverifiedID '8a020000-0000-0000-0000-000000000021'
 \set userVerifiedAttendeeID '8a020000-0000-0000-0000-000000000022'
@@ -365,7 +370,6 @@ insert into event (
     starts_at,
     ends_at,
     published,
-    event_reminder_sent_at,
     venue_city,
     venue_country_code,
     venue_country_name,
@@ -382,12 +386,15 @@ insert into event (
     current_timestamp + interval '20 hours',
     current_timestamp + interval '21 hours',
     true,
-    current_timestamp,
     'Chicago',
     'US',
     'United States',
     'Center'
 );
+insert into event_reminder (event_id, offset_minutes, starts_at, sent_at)
+select event_id, 1440, starts_at, current_timestamp
+from event
+where event_id = :'eventSentID';
 
 -- Attendees and speakers for due event
 insert into event_attendee (event_id, user_id, status) values
@@ -410,7 +417,7 @@ insert into event_speaker (event_id, user_id, featured) values
 
 -- Should enqueue reminders for verified attendees and speakers on due events
 select is(
-    enqueue_due_event_reminders('https://example.test/'),
+    enqueue_due_event_reminders('https://example.test/', array[1440, 60]),
     2,
     'Should enqueue reminders for verified attendees and speakers on due events'
 );
@@ -532,30 +539,75 @@ select is(
     'Should include the latest site theme in reminder template data'
 );
 
+-- Should include the reminder offset in reminder template data
+select is(
+    (
+        select ntd.data->'reminder_offset_minutes'
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-reminder'
+        limit 1
+    ),
+    '1440'::jsonb,
+    'Should include the reminder offset in reminder template data'
+);
+
 -- Should mark due event as evaluated for its current start date
 select is(
-    (select event_reminder_evaluated_for_starts_at from event where event_id = :'eventDueID'),
+    (
+        select starts_at
+        from event_reminder
+        where event_id = :'eventDueID'
+        and offset_minutes = 1440
+    ),
     (select starts_at from event where event_id = :'eventDueID'),
     'Should mark due event as evaluated for its current start date'
 );
 
 -- Should set reminder sent timestamp on due event when notifications are queued
 select isnt(
-    (select event_reminder_sent_at from event where event_id = :'eventDueID'),
+    (
+        select sent_at
+        from event_reminder
+        where event_id = :'eventDueID'
+        and offset_minutes = 1440
+    ),
     null::timestamptz,
     'Should set reminder sent timestamp on due event when notifications are queued'
 );
 
+-- Should not evaluate offsets that are not due yet
+select is(
+    (
+        select count(*)
+        from event_reminder
+        where event_id = :'eventDueID'
+        and offset_minutes = 60
+    ),
+    0::bigint,
+    'Should not evaluate offsets that are not due yet'
+);
+
 -- Should mark no-recipients event as evaluated even when nothing is enqueued
 select is(
-    (select event_reminder_evaluated_for_starts_at from event where event_id = :'eventNoRecipientsID'),
+    (
+        select starts_at
+        from event_reminder
+        where event_id = :'eventNoRecipientsID'
+        and offset_minutes = 1440
+    ),
     (select starts_at from event where event_id = :'eventNoRecipientsID'),
     'Should mark no-recipients event as evaluated even when nothing is enqueued'
 );
 
 -- Should not set reminder sent timestamp when no recipients are found
 select is(
-    (select event_reminder_sent_at from event where event_id = :'eventNoRecipientsID'),
+    (
+        select sent_at
+        from event_reminder
+        where event_id = :'eventNoRecipientsID'
+        and offset_minutes = 1440
+    ),
     null::timestamptz,
     'Should not set reminder sent timestamp when no recipients are found'
 );
@@ -582,7 +634,7 @@ select is(
 
 -- Should enqueue no additional notifications when run again
 select is(
-    enqueue_due_event_reminders('https://example.test'),
+    enqueue_due_event_reminders('https://example.test', array[1440, 60]),
     0,
     'Should enqueue no additional notifications when run again'
 );
@@ -591,7 +643,7 @@ select is(
 insert into event_attendee (event_id, user_id)
 values (:'eventNoRecipientsID', :'userVerifiedLateSignupID');
 select is(
-    enqueue_due_event_reminders('https://example.test'),
+    enqueue_due_event_reminders('https://example.test', array[1440, 60]),
     0,
     'Should keep no-recipients event ignored after late signup'
 );
@@ -609,6 +661,221 @@ select is(
     'Should not create reminders for late signups after the event was evaluated'
 );
 
+-- Should post reminders to the group Discord channel even without recipients
+update "group" set discord_webhook_url = 'https://discord.com/api/webhooks/1/token'
+where group_id = :'groupID';
+update event set
+    starts_at = current_timestamp + interval '22 hours',
+    ends_at = current_timestamp + interval '23 hours'
+where event_id = :'eventNotDueID';
+select is(
+    enqueue_due_event_reminders('https://example.test', array[1440, 60]),
+    0,
+    'Should post reminders to the group Discord channel even without recipients'
+);
+
+-- Should create a Discord reminder notification for the group
+select results_eq(
+    format(
+        $$
+    select n.channel, n.group_id, n.user_id
+    from notification n
+    join notification_template_data ntd using (notification_template_data_id)
+    where n.kind = 'event-reminder'
+    and ntd.data->'event'->>'event_id' = %L
+        $$,
+        :'eventNotDueID'
+    ),
+    format(
+        $$ values ('discord'::text, %L::uuid, null::uuid) $$,
+        :'groupID'
+    ),
+    'Should create a Discord reminder notification for the group'
+);
+
+-- Should enqueue a single reminder when several offsets are due
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    ends_at,
+    published
+) values (
+    :'eventSoonID',
+    :'groupID',
+    'Soon Event',
+    'soon-event',
+    'Event starting within all reminder offsets',
+    'UTC',
+    :'eventCategoryID',
+    'virtual',
+    current_timestamp + interval '30 minutes',
+    current_timestamp + interval '90 minutes',
+    true
+);
+insert into event_attendee (event_id, user_id, status)
+values (:'eventSoonID', :'userVerifiedAttendeeID', 'confirmed');
+select is(
+    enqueue_due_event_reminders('https://example.test', array[1440, 60]),
+    1,
+    'Should enqueue a single reminder when several offsets are due'
+);
+
+-- Should use the closest offset due in the reminder template data
+select is(
+    (
+        select ntd.data->'reminder_offset_minutes'
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-reminder'
+        and n.user_id = :'userVerifiedAttendeeID'::uuid
+        and ntd.data->'event'->>'event_id' = :'eventSoonID'
+    ),
+    '60'::jsonb,
+    'Should use the closest offset due in the reminder template data'
+);
+
+-- Should mark all the offsets due as sent
+select results_eq(
+    format(
+        $$
+    select offset_minutes, sent_at is not null
+    from event_reminder
+    where event_id = %L
+    order by offset_minutes
+        $$,
+        :'eventSoonID'
+    ),
+    $$ values (60, true), (1440, true) $$,
+    'Should mark all the offsets due as sent'
+);
+
+-- Should skip reminders that were due before the event was published
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    ends_at,
+    published,
+    event_reminder_not_before
+) values (
+    :'eventLatePublishedID',
+    :'groupID',
+    'Late Published Event',
+    'late-published-event',
+    'Event published after its first reminder was due',
+    'UTC',
+    :'eventCategoryID',
+    'virtual',
+    current_timestamp + interval '20 hours',
+    current_timestamp + interval '21 hours',
+    true,
+    current_timestamp
+);
+insert into event_attendee (event_id, user_id, status)
+values (:'eventLatePublishedID', :'userVerifiedAttendeeID', 'confirmed');
+select is(
+    enqueue_due_event_reminders('https://example.test', array[1440, 60]),
+    0,
+    'Should skip reminders that were due before the event was published'
+);
+
+-- Should enqueue personal reminders at the offset chosen by the attendee
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    username,
+    registration_status,
+    event_reminder_preference
+) values
+    (:'userOptedOutID', 'hash-6', 'opted-out@example.com', true, 'opted-out', 'registered', null),
+    (:'userPersonalID', 'hash-7', 'personal@example.com', true, 'personal', 'registered', '1-week');
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    ends_at,
+    published
+) values (
+    :'eventPersonalID',
+    :'groupID',
+    'Personal Reminder Event',
+    'personal-reminder-event',
+    'Event with attendees using their own reminder preferences',
+    'UTC',
+    :'eventCategoryID',
+    'virtual',
+    current_timestamp + interval '5 days',
+    current_timestamp + interval '5 days 2 hours',
+    true
+);
+insert into event_attendee (event_id, user_id, status, created_at, reminder_preference) values
+    (:'eventPersonalID', :'userOptedOutID', 'confirmed', current_timestamp - interval '3 days', 'none'),
+    (:'eventPersonalID', :'userPersonalID', 'confirmed', current_timestamp - interval '3 days', null);
+select is(
+    enqueue_due_event_reminders('https://example.test', array[1440, 60]),
+    1,
+    'Should enqueue personal reminders at the offset chosen by the attendee'
+);
+
+-- Should include the personal reminder offset in reminder template data
+select is(
+    (
+        select ntd.data->'reminder_offset_minutes'
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-reminder'
+        and n.user_id = :'userPersonalID'::uuid
+        and ntd.data->'event'->>'event_id' = :'eventPersonalID'
+    ),
+    '10080'::jsonb,
+    'Should include the personal reminder offset in reminder template data'
+);
+
+-- Should not enqueue reminders for attendees who opted out
+select is(
+    (
+        select count(*)::int
+        from notification
+        where kind = 'event-reminder'
+        and user_id = :'userOptedOutID'::uuid
+    ),
+    0,
+    'Should not enqueue reminders for attendees who opted out'
+);
+
+-- Should mark personal reminders as sent for the current start date
+select is(
+    (
+        select reminder_sent_for_starts_at
+        from event_attendee
+        where event_id = :'eventPersonalID'
+        and user_id = :'userPersonalID'
+    ),
+    (select starts_at from event where event_id = :'eventPersonalID'),
+    'Should mark personal reminders as sent for the current start date'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
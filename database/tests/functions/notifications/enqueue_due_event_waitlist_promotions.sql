@@ -0,0 +1,231 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0ba0000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0ba0000-0000-0000-0000-000000000002'
+\set eventID 'c0ba0000-0000-0000-0000-000000000003'
+\set groupCategoryID 'c0ba0000-0000-0000-0000-000000000004'
+\set groupID 'c0ba0000-0000-0000-0000-000000000005'
+\set siteID 'c0ba0000-0000-0000-0000-000000000006'
+\set userActivePendingID 'c0ba0000-0000-0000-0000-000000000007'
+\set userExpiredID 'c0ba0000-0000-0000-0000-000000000008'
+\set userWaitlistFirstID 'c0ba0000-0000-0000-0000-000000000009'
+\set userWaitlistSecondID 'c0ba0000-0000-0000-0000-000000000010'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Site settings
+insert into site (site_id, title, description, theme) values (
+    :'siteID',
+    'Test Site',
+    'Test Site Description',
+    '{"primary_color": "#2563eb"}'::jsonb
+);
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'waitlist-community',
+    'Waitlist Community',
+    'Waitlist promotion tests',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Categories
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    slug_pretty,
+    description
+) values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Group 1',
+    'group-1',
+    'group-one',
+    'Group 1'
+);
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username) values
+    (:'userActivePendingID', 'hash-1', 'active-pending@example.com', true, 'active-pending'),
+    (:'userExpiredID', 'hash-2', 'expired@example.com', true, 'expired'),
+    (:'userWaitlistFirstID', 'hash-3', 'waitlist-first@example.com', true, 'waitlist-first'),
+    (:'userWaitlistSecondID', 'hash-4', 'waitlist-second@example.com', true, 'waitlist-second');
+
+-- Event
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    published,
+    capacity,
+    waitlist_enabled,
+    waitlist_confirmation_hours
+) values (
+    :'eventID',
+    :'groupID',
+    'Waitlist Event',
+    'waitlist-event',
+    'Waitlist',
+    'UTC',
+    :'eventCategoryID',
+    'virtual',
+    current_timestamp + interval '3 days',
+    true,
+    2,
+    true,
+    24
+);
+
+-- Attendees holding seats offered from the waitlist
+insert into event_attendee (event_id, user_id, status, waitlist_confirmation_expires_at) values
+    (:'eventID', :'userActivePendingID', 'waitlist-confirmation-pending', current_timestamp + interval '1 hour'),
+    (:'eventID', :'userExpiredID', 'waitlist-confirmation-pending', current_timestamp - interval '1 hour');
+
+-- Waitlist entries
+insert into event_waitlist (event_id, user_id, created_at) values
+    (:'eventID', :'userWaitlistFirstID', '2024-01-01 00:00:00+00'),
+    (:'eventID', :'userWaitlistSecondID', '2024-01-02 00:00:00+00');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should promote one waitlisted user for the expired confirmation
+select is(
+    enqueue_due_event_waitlist_promotions('https://example.test/'),
+    1,
+    'Should promote one waitlisted user for the expired confirmation'
+);
+
+-- Should release the expired seat and keep the unexpired one
+select is(
+    (
+        select jsonb_object_agg(user_id, status order by user_id)
+        from event_attendee
+        where event_id = :'eventID'
+    ),
+    jsonb_build_object(
+        :'userActivePendingID', 'waitlist-confirmation-pending',
+        :'userExpiredID', 'attendance-canceled',
+        :'userWaitlistFirstID', 'waitlist-confirmation-pending'
+    ),
+    'Should release the expired seat and keep the unexpired one'
+);
+
+-- Should give the promoted user the configured confirmation window
+select ok(
+    (
+        select waitlist_confirmation_expires_at
+            between current_timestamp + interval '23 hours' and current_timestamp + interval '24 hours'
+        from event_attendee
+        where event_id = :'eventID'
+        and user_id = :'userWaitlistFirstID'
+    ),
+    'Should give the promoted user the configured confirmation window'
+);
+
+-- Should keep the next user on the waiting list
+select results_eq(
+    format(
+        $$ select user_id from event_waitlist where event_id = %L::uuid $$,
+        :'eventID'
+    ),
+    format($$ values (%L::uuid) $$, :'userWaitlistSecondID'),
+    'Should keep the next user on the waiting list'
+);
+
+-- Should enqueue the promotion email for the promoted user only
+select results_eq(
+    $$
+        select user_id
+        from notification
+        where kind = 'event-waitlist-promoted'
+    $$,
+    format($$ values (%L::uuid) $$, :'userWaitlistFirstID'),
+    'Should enqueue the promotion email for the promoted user only'
+);
+
+-- Should include the confirmation deadline and links in the template data
+select is(
+    (
+        select jsonb_build_object(
+            'has_confirmation_expires_at', ntd.data ? 'confirmation_expires_at',
+            'has_registration_questions', ntd.data->'has_registration_questions',
+            'link', ntd.data->>'link'
+        )
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'event-waitlist-promoted'
+    ),
+    jsonb_build_object(
+        'has_confirmation_expires_at', true,
+        'has_registration_questions', false,
+        'link', 'https://example.test/waitlist-community/group/group-one/event/waitlist-event'
+    ),
+    'Should include the confirmation deadline and links in the template data'
+);
+
+-- Should not promote anyone when no confirmations have expired
+select is(
+    enqueue_due_event_waitlist_promotions('https://example.test'),
+    0,
+    'Should not promote anyone when no confirmations have expired'
+);
+
+-- Should skip canceled events
+update event_attendee
+set waitlist_confirmation_expires_at = current_timestamp - interval '1 minute'
+where event_id = :'eventID'
+and user_id = :'userWaitlistFirstID';
+update event set canceled = true where event_id = :'eventID';
+select is(
+    enqueue_due_event_waitlist_promotions('https://example.test'),
+    0,
+    'Should skip canceled events'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
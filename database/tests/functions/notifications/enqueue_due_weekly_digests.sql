@@ -0,0 +1,264 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(10);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityEmptyID 'c0b30000-0000-0000-0000-000000000001'
+\set communityID 'c0b30000-0000-0000-0000-000000000002'
+\set eventCanceledID 'c0b30000-0000-0000-0000-000000000003'
+\set eventCategoryEmptyID 'c0b30000-0000-0000-0000-000000000004'
+\set eventCategoryID 'c0b30000-0000-0000-0000-000000000005'
+\set eventLaterID 'c0b30000-0000-0000-0000-000000000006'
+\set eventNextMonthID 'c0b30000-0000-0000-0000-000000000007'
+\set eventSoonID 'c0b30000-0000-0000-0000-000000000008'
+\set group1ID 'c0b30000-0000-0000-0000-000000000009'
+\set group2ID 'c0b30000-0000-0000-0000-000000000010'
+\set groupCategoryEmptyID 'c0b30000-0000-0000-0000-000000000011'
+\set groupCategoryID 'c0b30000-0000-0000-0000-000000000012'
+\set siteID 'c0b30000-0000-0000-0000-000000000013'
+\set userMember1ID 'c0b30000-0000-0000-0000-000000000014'
+\set userMember2ID 'c0b30000-0000-0000-0000-000000000015'
+\set userOptedOutID 'c0b30000-0000-0000-0000-000000000016'
+\set userUnverifiedID 'c0b30000-0000-0000-0000-000000000017'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Site settings
+insert into site (site_id, title, description, theme) values (
+    :'siteID',
+    'Test Site',
+    'Test Site Description',
+    '{"primary_color": "#2563eb"}'::jsonb
+);
+
+-- Communities
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values
+    (
+        :'communityID',
+        'digest-community',
+        'Digest Community',
+        'Weekly digest tests',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    ),
+    (
+        :'communityEmptyID',
+        'empty-community',
+        'Empty Community',
+        'Community without upcoming events',
+        'https://example.com/banner-mobile.png',
+        'https://example.com/banner.png',
+        'https://example.com/logo.png'
+    );
+
+-- Group categories
+insert into group_category (group_category_id, community_id, name) values
+    (:'groupCategoryID', :'communityID', 'Technology'),
+    (:'groupCategoryEmptyID', :'communityEmptyID', 'Technology');
+
+-- Event categories
+insert into event_category (event_category_id, community_id, name) values
+    (:'eventCategoryID', :'communityID', 'General'),
+    (:'eventCategoryEmptyID', :'communityEmptyID', 'General');
+
+-- Groups
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    slug_pretty,
+    description
+) values
+    (:'group1ID', :'communityID', :'groupCategoryID', 'Group 1', 'group-1', 'group-one', 'Group 1'),
+    (:'group2ID', :'communityID', :'groupCategoryID', 'Group 2', 'group-2', null, 'Group 2');
+
+-- Users
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    username,
+    weekly_digest_enabled
+) values
+    (:'userMember1ID', 'hash-1', 'member1@example.com', true, 'member1', true),
+    (:'userMember2ID', 'hash-2', 'member2@example.com', true, 'member2', true),
+    (:'userOptedOutID', 'hash-3', 'opted-out@example.com', true, 'opted-out', false),
+    (:'userUnverifiedID', 'hash-4', 'unverified@example.com', false, 'unverified', true);
+
+-- Group members
+insert into group_member (group_id, user_id) values
+    (:'group1ID', :'userMember1ID'),
+    (:'group2ID', :'userMember1ID'),
+    (:'group2ID', :'userMember2ID'),
+    (:'group1ID', :'userOptedOutID'),
+    (:'group1ID', :'userUnverifiedID');
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    published,
+    canceled
+) values
+    (:'eventSoonID', :'group1ID', 'Soon Event', 'soon-event', 'Soon', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '1 day', true, false),
+    (:'eventLaterID', :'group2ID', 'Later Event', 'later-event', 'Later', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '5 days', true, false),
+    (:'eventNextMonthID', :'group1ID', 'Next Month Event', 'next-month-event', 'Next month', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '30 days', true, false),
+    (:'eventCanceledID', :'group2ID', 'Canceled Event', 'canceled-event', 'Canceled', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '2 days', false, true);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should enqueue one digest per member with upcoming events
+select is(
+    enqueue_due_weekly_digests('https://example.test/'),
+    2,
+    'Should enqueue one digest per member with upcoming events'
+);
+
+-- Should only enqueue digests for verified members who did not opt out
+select results_eq(
+    $$
+        select user_id
+        from notification
+        where kind = 'weekly-digest'
+        order by user_id
+    $$,
+    format(
+        $$ values (%L::uuid), (%L::uuid) $$,
+        :'userMember1ID',
+        :'userMember2ID'
+    ),
+    'Should only enqueue digests for verified members who did not opt out'
+);
+
+-- Should include the upcoming events of all the member groups in start order
+select is(
+    (
+        select jsonb_path_query_array(ntd.data, '$.events[*].event.event_id')
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'weekly-digest'
+        and n.user_id = :'userMember1ID'::uuid
+    ),
+    jsonb_build_array(:'eventSoonID', :'eventLaterID'),
+    'Should include the upcoming events of all the member groups in start order'
+);
+
+-- Should only include the upcoming events of the member groups
+select is(
+    (
+        select jsonb_path_query_array(ntd.data, '$.events[*].event.event_id')
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'weekly-digest'
+        and n.user_id = :'userMember2ID'::uuid
+    ),
+    jsonb_build_array(:'eventLaterID'),
+    'Should only include the upcoming events of the member groups'
+);
+
+-- Should build event links using the provided base URL
+select is(
+    (
+        select ntd.data->'events'->0->>'link'
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'weekly-digest'
+        and n.user_id = :'userMember1ID'::uuid
+    ),
+    'https://example.test/digest-community/group/group-one/event/soon-event',
+    'Should build event links using the provided base URL'
+);
+
+-- Should include the community and preferences links in the template data
+select is(
+    (
+        select jsonb_build_object(
+            'community_display_name', ntd.data->'community_display_name',
+            'link', ntd.data->'link',
+            'preferences_link', ntd.data->'preferences_link',
+            'theme', ntd.data->'theme'
+        )
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.kind = 'weekly-digest'
+        and n.user_id = :'userMember2ID'::uuid
+    ),
+    '{
+        "community_display_name": "Digest Community",
+        "link": "https://example.test/digest-community",
+        "preferences_link": "https://example.test/dashboard/user?tab=account",
+        "theme": {"primary_color": "#2563eb"}
+    }'::jsonb,
+    'Should include the community and preferences links in the template data'
+);
+
+-- Should mark the community digest as sent
+select isnt(
+    (select weekly_digest_sent_at from community where community_id = :'communityID'),
+    null::timestamptz,
+    'Should mark the community digest as sent'
+);
+
+-- Should mark the digest as sent for communities without upcoming events
+select isnt(
+    (select weekly_digest_sent_at from community where community_id = :'communityEmptyID'),
+    null::timestamptz,
+    'Should mark the digest as sent for communities without upcoming events'
+);
+
+-- Should not enqueue digests again within the same week
+select is(
+    enqueue_due_weekly_digests('https://example.test'),
+    0,
+    'Should not enqueue digests again within the same week'
+);
+
+-- Should enqueue digests again once a week has passed
+update community
+set weekly_digest_sent_at = current_timestamp - interval '8 days'
+where community_id = :'communityID';
+select is(
+    enqueue_due_weekly_digests('https://example.test'),
+    2,
+    'Should enqueue digests again once a week has passed'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,131 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0ae0000-0000-0000-0000-000000000001'
+\set groupCategoryID 'c0ae0000-0000-0000-0000-000000000002'
+\set groupID 'c0ae0000-0000-0000-0000-000000000003'
+\set groupWithoutWebhookID 'c0ae0000-0000-0000-0000-000000000004'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'discord-community',
+    'Discord Community',
+    'Community with Discord groups',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Groups
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    discord_webhook_url
+) values
+    (
+        :'groupID',
+        :'communityID',
+        :'groupCategoryID',
+        'Discord Group',
+        'discord-group',
+        'https://discord.com/api/webhooks/1/token'
+    ),
+    (
+        :'groupWithoutWebhookID',
+        :'communityID',
+        :'groupCategoryID',
+        'Quiet Group',
+        'quiet-group',
+        null
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should skip groups without a Discord webhook
+select lives_ok(
+    format(
+        $$select enqueue_group_notification(%L::uuid, 'event-published', '{"event": "test"}'::jsonb)$$,
+        :'groupWithoutWebhookID'
+    ),
+    'Should skip groups without a Discord webhook'
+);
+select is(
+    (select count(*) from notification where group_id = :'groupWithoutWebhookID'),
+    0::bigint,
+    'Should not create notifications for groups without a Discord webhook'
+);
+
+-- Should enqueue a Discord notification for groups with a webhook
+select lives_ok(
+    format(
+        $$select enqueue_group_notification(%L::uuid, 'event-published', '{"event": "test"}'::jsonb)$$,
+        :'groupID'
+    ),
+    'Should enqueue a Discord notification for groups with a webhook'
+);
+select results_eq(
+    format(
+        $$
+        select
+            n.channel,
+            n.delivery_status,
+            n.kind,
+            n.user_id,
+            ntd.data
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where n.group_id = %L::uuid
+        $$,
+        :'groupID'
+    ),
+    $$ values ('discord', 'pending', 'event-published', null::uuid, '{"event": "test"}'::jsonb) $$,
+    'Should store the Discord notification with its template data'
+);
+
+-- Should reject unknown notification kinds
+select throws_ok(
+    format(
+        $$select enqueue_group_notification(%L::uuid, 'unknown-kind', null)$$,
+        :'groupID'
+    ),
+    '23503',
+    null,
+    'Should reject unknown notification kinds'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(13);
+select plan(24);
 
 -- ============================================================================
 -- VARIABLES
@@ -12,6 +12,10 @@ select plan(13);
 \set userID1 '8a030000-0000-0000-0000-000000000001'
 \set userID2 '8a030000-0000-0000-0000-000000000002'
 \set userID3 '8a030000-0000-0000-0000-000000000003'
+\set userID4 '8a030000-0000-0000-0000-000000000004'
+\set userID5 '8a030000-0000-0000-0000-000000000005'
+\set userID6 '8a030000-0000-0000-0000-000000000006'
+\set userID7 '8a030000-0000-0000-0000-000000000007'
 
 -- ============================================================================
 -- SEED DATA
@@ -30,6 +34,62 @@ insert into "user" (
     (:'userID2', 'hash-2', 'user2@example.com', true, 'user-two', false),
     (:'userID3', 'hash-3', 'user3@example.com', false, 'user-three', true);
 
+-- User with webhook
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    username,
+    webhook_secret,
+    webhook_url
+) values (
+    :'userID4',
+    'hash-4',
+    'user4@example.com',
+    true,
+    'user-four',
+    'secret-4',
+    'https://example.com/webhooks/ocg'
+);
+
+-- Users with verified phone numbers
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    phone_number,
+    phone_number_verified,
+    sms_notifications_enabled,
+    username
+) values
+    (:'userID5', 'hash-5', 'user5@example.com', true, '+15551234567', true, true, 'user-five'),
+    (:'userID6', 'hash-6', 'user6@example.com', true, '+15557654321', true, false, 'user-six');
+
+-- User with an undeliverable email address and webhook
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_undeliverable_at,
+    email_undeliverable_reason,
+    email_verified,
+    username,
+    webhook_secret,
+    webhook_url
+) values (
+    :'userID7',
+    'hash-7',
+    'user7@example.com',
+    current_timestamp,
+    'bounce',
+    true,
+    'user-seven',
+    'secret-7',
+    'https://example.com/webhooks/ocg-7'
+);
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -313,6 +373,171 @@ select results_eq(
     'Should keep template reference null for event-welcome notifications'
 );
 
+-- Should enqueue webhook notifications for webhook kinds
+select lives_ok(
+    format(
+        $$select enqueue_notification(
+            'group-welcome',
+            '{"group":"go"}'::jsonb,
+            '[]'::jsonb,
+            array[%L]::uuid[]
+        )$$,
+        :'userID4'
+    ),
+    'Should enqueue webhook notifications for webhook kinds'
+);
+
+-- Should create email and webhook notifications sharing template data
+select results_eq(
+    $$
+    select
+        n.channel,
+        td.data
+    from notification n
+    join notification_template_data td using (notification_template_data_id)
+    where n.user_id = '8a030000-0000-0000-0000-000000000004'::uuid
+    and n.kind = 'group-welcome'
+    order by n.channel
+    $$,
+    $$ values
+        ('email', '{"group":"go"}'::jsonb),
+        ('webhook', '{"group":"go"}'::jsonb)
+    $$,
+    'Should create email and webhook notifications sharing template data'
+);
+
+-- Should enqueue notifications for kinds not forwarded to webhooks
+select lives_ok(
+    format(
+        $$select enqueue_notification(
+            'event-reminder',
+            null,
+            '[]'::jsonb,
+            array[%L]::uuid[]
+        )$$,
+        :'userID4'
+    ),
+    'Should enqueue notifications for kinds not forwarded to webhooks'
+);
+
+-- Should only create email notifications for kinds not forwarded to webhooks
+select results_eq(
+    $$
+    select n.channel
+    from notification n
+    where n.user_id = '8a030000-0000-0000-0000-000000000004'::uuid
+    and n.kind = 'event-reminder'
+    $$,
+    $$ values ('email') $$,
+    'Should only create email notifications for kinds not forwarded to webhooks'
+);
+
+-- Should enqueue SMS notifications for SMS kinds
+select lives_ok(
+    format(
+        $$select enqueue_notification(
+            'event-reminder',
+            null,
+            '[]'::jsonb,
+            array[%L, %L]::uuid[]
+        )$$,
+        :'userID5',
+        :'userID6'
+    ),
+    'Should enqueue SMS notifications for SMS kinds'
+);
+
+-- Should only create SMS notifications for recipients who opted in
+select results_eq(
+    $$
+    select n.user_id, n.channel
+    from notification n
+    where n.kind = 'event-reminder'
+    and n.user_id in (
+        '8a030000-0000-0000-0000-000000000005'::uuid,
+        '8a030000-0000-0000-0000-000000000006'::uuid
+    )
+    order by n.user_id, n.channel
+    $$,
+    $$ values
+        ('8a030000-0000-0000-0000-000000000005'::uuid, 'email'),
+        ('8a030000-0000-0000-0000-000000000005'::uuid, 'sms'),
+        ('8a030000-0000-0000-0000-000000000006'::uuid, 'email')
+    $$,
+    'Should only create SMS notifications for recipients who opted in'
+);
+
+-- Should enqueue notifications for recipients with undeliverable emails
+select lives_ok(
+    format(
+        $$select enqueue_notification(
+            'event-canceled',
+            null,
+            '[]'::jsonb,
+            array[%L]::uuid[]
+        )$$,
+        :'userID7'
+    ),
+    'Should enqueue notifications for recipients with undeliverable emails'
+);
+
+-- Should skip email notifications for recipients with undeliverable emails
+select results_eq(
+    $$
+    select n.channel
+    from notification n
+    where n.user_id = '8a030000-0000-0000-0000-000000000007'::uuid
+    $$,
+    $$ values ('webhook') $$,
+    'Should skip email notifications for recipients with undeliverable emails'
+);
+
+-- Should enqueue verification emails for undeliverable email addresses
+select lives_ok(
+    format(
+        $$select enqueue_notification(
+            'email-verification',
+            null,
+            '[]'::jsonb,
+            array[%L]::uuid[]
+        )$$,
+        :'userID7'
+    ),
+    'Should enqueue verification emails for undeliverable email addresses'
+);
+
+-- Should still send verification emails to undeliverable email addresses
+select results_eq(
+    $$
+    select n.channel
+    from notification n
+    where n.user_id = '8a030000-0000-0000-0000-000000000007'::uuid
+    and n.kind = 'email-verification'
+    $$,
+    $$ values ('email') $$,
+    'Should still send verification emails to undeliverable email addresses'
+);
+
+-- Enqueue email and webhook notifications and keep the returned identifiers
+select enqueue_notification(
+    'group-custom',
+    '{"subject":"Returned ids"}'::jsonb,
+    '[]'::jsonb,
+    array[:'userID1', :'userID4']::uuid[]
+) as "returnedIDs" \gset
+
+-- Should return the identifiers of all the notifications created
+select is(
+    (select array_agg(id order by id) from unnest(:'returnedIDs'::uuid[]) as id),
+    (
+        select array_agg(n.notification_id order by n.notification_id)
+        from notification n
+        join notification_template_data ntd using (notification_template_data_id)
+        where ntd.data = '{"subject":"Returned ids"}'::jsonb
+    ),
+    'Should return the identifiers of all the notifications created'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
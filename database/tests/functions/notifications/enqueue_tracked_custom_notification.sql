@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(6);
+select plan(7);
 
 -- ============================================================================
 -- VARIABLES
@@ -99,6 +99,21 @@ select is(
     'Should create one audit row'
 );
 
+-- Should link the notification to the custom notification.
+select is(
+    (
+        select n.custom_notification_id
+        from notification n
+        where n.kind = 'group-custom'
+    ),
+    (
+        select cn.custom_notification_id
+        from custom_notification cn
+        where cn.subject = 'Group update'
+    ),
+    'Should link the notification to the custom notification'
+);
+
 -- Should roll back tracking when enqueue fails.
 select throws_ok(
     format(
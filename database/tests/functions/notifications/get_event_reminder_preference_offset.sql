@@ -0,0 +1,52 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return one hour for the 1-hour preference
+select is(
+    get_event_reminder_preference_offset('1-hour'),
+    60,
+    'Should return one hour for the 1-hour preference'
+);
+
+-- Should return one day for the 1-day preference
+select is(
+    get_event_reminder_preference_offset('1-day'),
+    1440,
+    'Should return one day for the 1-day preference'
+);
+
+-- Should return one week for the 1-week preference
+select is(
+    get_event_reminder_preference_offset('1-week'),
+    10080,
+    'Should return one week for the 1-week preference'
+);
+
+-- Should return null when reminders are disabled
+select is(
+    get_event_reminder_preference_offset('none'),
+    null::int,
+    'Should return null when reminders are disabled'
+);
+
+-- Should return null when no preference is set
+select is(
+    get_event_reminder_preference_offset(null),
+    null::int,
+    'Should return null when no preference is set'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
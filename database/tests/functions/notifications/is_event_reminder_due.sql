@@ -0,0 +1,158 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0b20000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0b20000-0000-0000-0000-000000000002'
+\set eventEvaluatedID 'c0b20000-0000-0000-0000-000000000003'
+\set eventID 'c0b20000-0000-0000-0000-000000000004'
+\set eventSentID 'c0b20000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0b20000-0000-0000-0000-000000000006'
+\set groupID 'c0b20000-0000-0000-0000-000000000007'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'test-community',
+    'Test Community',
+    'A test community',
+    'https://example.com/banner-mobile.png',
+    'https://example.com/banner.png',
+    'https://example.com/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'General');
+
+-- Group
+insert into "group" (
+    group_id,
+    community_id,
+    group_category_id,
+    name,
+    slug,
+    description
+) values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Test Group',
+    'test-group',
+    'A test group'
+);
+
+-- Events
+insert into event (
+    event_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    event_category_id,
+    event_kind_id,
+    starts_at,
+    published
+) values
+    (:'eventID', :'groupID', 'Event', 'event', 'Event', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '20 hours', true),
+    (:'eventEvaluatedID', :'groupID', 'Evaluated Event', 'evaluated-event', 'Event', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '20 hours', true),
+    (:'eventSentID', :'groupID', 'Sent Event', 'sent-event', 'Event', 'UTC',
+        :'eventCategoryID', 'virtual', current_timestamp + interval '20 hours', true);
+
+-- Reminders already evaluated or sent
+insert into event_reminder (event_id, offset_minutes, starts_at, sent_at) values
+    (:'eventEvaluatedID', 1440, current_timestamp + interval '20 hours', null),
+    (:'eventSentID', 1440, current_timestamp + interval '10 hours', current_timestamp);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should be due when the event starts within the offset
+select ok(
+    is_event_reminder_due(:'eventID', current_timestamp + interval '20 hours', null, 1440),
+    'Should be due when the event starts within the offset'
+);
+
+-- Should not be due when the event starts after the offset
+select ok(
+    not is_event_reminder_due(:'eventID', current_timestamp + interval '20 hours', null, 60),
+    'Should not be due when the event starts after the offset'
+);
+
+-- Should not be due when it was scheduled before the reference time
+select ok(
+    not is_event_reminder_due(
+        :'eventID',
+        current_timestamp + interval '20 hours',
+        current_timestamp,
+        1440
+    ),
+    'Should not be due when it was scheduled before the reference time'
+);
+
+-- Should not be due when already evaluated for the same start time
+select ok(
+    not is_event_reminder_due(
+        :'eventEvaluatedID',
+        current_timestamp + interval '20 hours',
+        null,
+        1440
+    ),
+    'Should not be due when already evaluated for the same start time'
+);
+
+-- Should be due again when evaluated for a different start time
+select ok(
+    is_event_reminder_due(
+        :'eventEvaluatedID',
+        current_timestamp + interval '21 hours',
+        null,
+        1440
+    ),
+    'Should be due again when evaluated for a different start time'
+);
+
+-- Should not be due when already sent
+select ok(
+    not is_event_reminder_due(
+        :'eventSentID',
+        current_timestamp + interval '20 hours',
+        null,
+        1440
+    ),
+    'Should not be due when already sent'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,115 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set notificationOldProcessedID 'c0b40000-0000-0000-0000-000000000006'
+\set notificationPendingID 'c0b40000-0000-0000-0000-000000000001'
+\set notificationProcessedID 'c0b40000-0000-0000-0000-000000000002'
+\set notificationWebhookID 'c0b40000-0000-0000-0000-000000000003'
+\set userBouncedID 'c0b40000-0000-0000-0000-000000000004'
+\set userOtherID 'c0b40000-0000-0000-0000-000000000005'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users
+insert into "user" (
+    user_id,
+    auth_hash,
+    email,
+    email_verified,
+    username,
+    webhook_secret,
+    webhook_url
+) values
+    (:'userBouncedID', 'hash-1', 'Bounced@Example.com', true, 'bounced', 'secret-1', 'https://example.com/hook'),
+    (:'userOtherID', 'hash-2', 'other@example.com', true, 'other', null, null);
+
+-- Notifications
+insert into notification (notification_id, channel, kind, user_id, delivery_status, processed_at) values
+    (:'notificationPendingID', 'email', 'group-welcome', :'userBouncedID', 'pending', null),
+    (:'notificationProcessedID', 'email', 'group-welcome', :'userBouncedID', 'processed', current_timestamp),
+    (:'notificationWebhookID', 'webhook', 'group-welcome', :'userBouncedID', 'pending', null),
+    (:'notificationOldProcessedID', 'email', 'group-welcome', :'userBouncedID', 'processed', current_timestamp - interval '7 days');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should flag the users using the address ignoring case
+select is(
+    mark_email_undeliverable('bounced@example.com', 'bounce'),
+    1,
+    'Should flag the users using the address ignoring case'
+);
+
+-- Should record the undeliverable reason
+select results_eq(
+    $$
+        select user_id, email_undeliverable_at is not null, email_undeliverable_reason
+        from "user"
+        order by user_id
+    $$,
+    format(
+        $$ values (%L::uuid, true, 'bounce'), (%L::uuid, false, null) $$,
+        :'userBouncedID',
+        :'userOtherID'
+    ),
+    'Should record the undeliverable reason'
+);
+
+-- Should fail pending emails and flag recently sent ones as bounced
+select results_eq(
+    $$
+        select notification_id, delivery_status, error
+        from notification
+        order by notification_id
+    $$,
+    format(
+        $$ values
+            (%L::uuid, 'failed', 'email address is undeliverable'),
+            (%L::uuid, 'bounced', null),
+            (%L::uuid, 'pending', null),
+            (%L::uuid, 'processed', null)
+        $$,
+        :'notificationPendingID',
+        :'notificationProcessedID',
+        :'notificationWebhookID',
+        :'notificationOldProcessedID'
+    ),
+    'Should fail pending emails and flag recently sent ones as bounced'
+);
+
+-- Should keep the first reason reported for an address
+select is(
+    mark_email_undeliverable('bounced@example.com', 'complaint'),
+    1,
+    'Should accept further reports for an address already flagged'
+);
+select is(
+    (select email_undeliverable_reason from "user" where user_id = :'userBouncedID'),
+    'bounce',
+    'Should keep the first reason reported for an address'
+);
+
+-- Should not flag any user for unknown addresses
+select is(
+    mark_email_undeliverable('unknown@example.com', 'bounce'),
+    0,
+    'Should not flag any user for unknown addresses'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
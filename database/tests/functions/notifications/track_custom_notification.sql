@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(6);
+select plan(7);
 
 -- ============================================================================
 -- VARIABLES
@@ -162,21 +162,25 @@ select results_eq(
     'Should create the expected audit row for the event notification'
 );
 
--- Should store the group custom notification
-select lives_ok(
-    format(
-        $$select track_custom_notification(
-        %L::uuid,
+-- Should store the group custom notification and return its identifier
+select is(
+    track_custom_notification(
+        :'userID'::uuid,
         null::uuid,
-        %L::uuid,
+        :'groupID'::uuid,
         8,
         'Group update',
         'Body for group notification'
-        )$$,
-        :'userID',
-        :'groupID'
     ),
-    'Should store the group custom notification'
+    (select custom_notification_id from custom_notification where subject = 'Group update'),
+    'Should store the group custom notification and return its identifier'
+);
+
+-- Should return a single custom notification for the group
+select is(
+    (select count(*) from custom_notification where subject = 'Group update'),
+    1::bigint,
+    'Should return a single custom notification for the group'
 );
 
 -- Should persist the group custom notification row
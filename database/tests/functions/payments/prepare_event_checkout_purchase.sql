@@ -3,13 +3,14 @@
 -- ============================================================================
 
 begin;
-select plan(19);
+select plan(22);
 
 -- ============================================================================
 -- VARIABLES
 -- ============================================================================
 
 \set attendeeUserID '79100000-0000-0000-0000-000000000022'
+\set bannedUserID '79100000-0000-0000-0000-000000000051'
 \set checkoutUserID '79100000-0000-0000-0000-000000000023'
 \set closedWindowEventID '79100000-0000-0000-0000-000000000041'
 \set closedWindowMatchingPurchaseID '79100000-0000-0000-0000-000000000042'
@@ -28,6 +29,10 @@ select plan(19);
 \set eventCategoryID '79100000-0000-0000-0000-000000000002'
 \set exhaustedDiscountUserID '79100000-0000-0000-0000-000000000027'
 \set freeDiscountID '79100000-0000-0000-0000-000000000016'
+\set freeEventID '79100000-0000-0000-0000-00000000004d'
+\set freePriceWindowID '79100000-0000-0000-0000-00000000004e'
+\set freeTicketTypeID '79100000-0000-0000-0000-00000000004f'
+\set freeUserID '79100000-0000-0000-0000-000000000050'
 \set groupCategoryID '79100000-0000-0000-0000-000000000010'
 \set groupID '79100000-0000-0000-0000-000000000011'
 \set inactiveDiscountID '79100000-0000-0000-0000-000000000017'
@@ -97,6 +102,7 @@ values (:'eventCategoryID', :'communityID', 'General');
 -- Users
 insert into "user" (user_id, auth_hash, email, email_verified, username) values
     (:'attendeeUserID', 'hash-1', 'attendee@example.com', true, 'attendee'),
+    (:'bannedUserID', 'hash-20', 'banned@example.com', true, 'banned-user'),
     (:'checkoutUserID', 'hash-2', 'checkout@example.com', true, 'checkout-user'),
     (:'closedWindowMatchingUserID', 'hash-15', 'closed-matching@example.com', true, 'closed-matching-user'),
     (:'closedWindowMismatchedUserID', 'hash-16', 'closed-mismatched@example.com', true, 'closed-mismatched-user'),
@@ -113,7 +119,8 @@ insert into "user" (user_id, auth_hash, email, email_verified, username) values
     (:'soldOutPendingUserID', 'hash-18', 'soldout-pending@example.com', true, 'soldout-pending-user'),
     (:'underMinimumUserID', 'hash-12', 'under-minimum@example.com', true, 'under-minimum-user'),
     (:'questionsUserID', 'hash-13', 'questions@example.com', true, 'questions-user'),
-    (:'invitedUserID', 'hash-14', 'invited@example.com', true, 'invited-user');
+    (:'invitedUserID', 'hash-14', 'invited@example.com', true, 'invited-user'),
+    (:'freeUserID', 'hash-19', 'free@example.com', true, 'free-user');
 
 -- Group
 insert into "group" (
@@ -250,6 +257,35 @@ insert into event (
     '[]'::jsonb
 );
 
+-- Event offering only free ticket types, so it has no payment currency
+insert into event (
+    event_id,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    slug,
+    description,
+    timezone,
+    starts_at,
+    published,
+    published_at,
+    registration_questions
+) values (
+    :'freeEventID',
+    :'eventCategoryID',
+    'in-person',
+    :'groupID',
+    'Free Event',
+    'free-event',
+    'Test event',
+    'UTC',
+    now() + interval '2 days',
+    true,
+    now(),
+    '[]'::jsonb
+);
+
 -- Ticket types
 insert into event_ticket_type (event_ticket_type_id, active, event_id, "order", seats_total, title)
 values
@@ -259,7 +295,8 @@ values
     (:'closedWindowTicketTypeBID', true, :'closedWindowEventID', 2, 10, 'VIP'),
     (:'soldOutTicketTypeID', true, :'soldOutEventID', 1, 1, 'General admission'),
     (:'inactiveTicketTypeID', false, :'inactiveEventID', 1, 10, 'General admission'),
-    (:'questionsTicketTypeID', true, :'questionsEventID', 1, 10, 'General admission');
+    (:'questionsTicketTypeID', true, :'questionsEventID', 1, 10, 'General admission'),
+    (:'freeTicketTypeID', true, :'freeEventID', 1, 10, 'Student');
 
 -- Price windows
 insert into event_ticket_price_window (
@@ -273,7 +310,8 @@ insert into event_ticket_price_window (
     (:'closedWindowPriceWindowBID', 4000, :'closedWindowTicketTypeBID'),
     (:'soldOutPriceWindowID', 2500, :'soldOutTicketTypeID'),
     (:'inactivePriceWindowID', 2500, :'inactiveTicketTypeID'),
-    (:'questionsPriceWindowID', 2500, :'questionsTicketTypeID');
+    (:'questionsPriceWindowID', 2500, :'questionsTicketTypeID'),
+    (:'freePriceWindowID', 0, :'freeTicketTypeID');
 
 -- Discount codes
 insert into event_discount_code (
@@ -464,6 +502,10 @@ insert into event_purchase (
     :'soldOutHolderUserID'
 );
 
+-- Group ban
+insert into group_ban (group_id, user_id, reason)
+values (:'groupID', :'bannedUserID', 'Spam');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -684,6 +726,25 @@ select throws_ok(
     'Should reject creating a checkout purchase after registration closes'
 );
 
+-- Should reject checkout purchases from users banned from the group
+select throws_ok(
+    format($$select prepare_event_checkout_purchase(
+        %L::uuid,
+        %L::uuid,
+        %L::uuid,
+        %L::uuid,
+        null,
+        'stripe'
+    )$$,
+        :'communityID',
+        :'mainEventID',
+        :'ticketTypeAID',
+        :'bannedUserID'
+    ),
+    'user is banned from this group',
+    'Should reject checkout purchases from users banned from the group'
+);
+
 -- Should reuse an equivalent pending purchase when the ticket type is sold out
 select is(
     (
@@ -812,6 +873,35 @@ select throws_ok(
     'Should reject reusing a pending purchase when an invitation is pending'
 );
 
+-- Should create a free pending purchase when payments are not configured
+select lives_ok(
+    format($$select prepare_event_checkout_purchase(
+        %L::uuid,
+        %L::uuid,
+        %L::uuid,
+        %L::uuid,
+        null,
+        null
+    )$$, :'communityID', :'freeEventID', :'freeTicketTypeID', :'freeUserID'),
+    'Should create a free pending purchase when payments are not configured'
+);
+
+-- Should persist the free purchase without a currency
+select results_eq(
+    format($$
+        select
+            amount_minor,
+            currency_code,
+            ticket_title
+        from event_purchase
+        where event_id = %L::uuid
+        and user_id = %L::uuid
+        and status = 'pending'
+    $$, :'freeEventID', :'freeUserID'),
+    $$ values (0::bigint, null::text, 'Student'::text) $$,
+    'Should persist the free purchase without a currency'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
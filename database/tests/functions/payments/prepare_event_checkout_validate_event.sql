@@ -264,15 +264,11 @@ select throws_ok(
     'Should reject groups whose payments recipient does not match the server provider'
 );
 
--- Should reject ticketed events without a payment currency
-select throws_ok(
-    format($$select prepare_event_checkout_validate_event(
-        %L::uuid,
-        %L::uuid,
-        'stripe'
-    )$$, :'communityID', :'missingCurrencyEventID'),
-    'ticketed event is missing payment_currency_code',
-    'Should reject ticketed events without a payment currency'
+-- Should not require payments for events offering only free ticket types
+select is(
+    prepare_event_checkout_validate_event(:'communityID'::uuid, :'missingCurrencyEventID'::uuid, null),
+    null,
+    'Should not require payments for events offering only free ticket types'
 );
 
 -- Should reject inactive events
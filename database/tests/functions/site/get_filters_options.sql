@@ -20,6 +20,8 @@ select plan(5);
 \set groupCategory2ID '9a010000-0000-0000-0000-000000000009'
 \set region1ID '9a010000-0000-0000-0000-000000000010'
 \set region2ID '9a010000-0000-0000-0000-000000000011'
+\set topic1ID '9a010000-0000-0000-0000-000000000012'
+\set topic2ID '9a010000-0000-0000-0000-000000000013'
 
 -- ============================================================================
 -- SEED DATA
@@ -66,6 +68,12 @@ values
     (:'region1ID', 'North America', :'community2ID', 1),
     (:'region2ID', 'Europe', :'community2ID', 2);
 
+-- Topic
+insert into topic (topic_id, name, community_id)
+values
+    (:'topic1ID', 'Observability', :'community2ID'),
+    (:'topic2ID', 'Kubernetes', :'community2ID');
+
 -- Event category
 insert into event_category (event_category_id, community_id, name, "order")
 values
@@ -140,6 +148,10 @@ select is(
         "region": [
             {"name": "North America", "value": "north-america"},
             {"name": "Europe", "value": "europe"}
+        ],
+        "topic": [
+            {"name": "Kubernetes", "value": "kubernetes"},
+            {"name": "Observability", "value": "observability"}
         ]
     }'::jsonb,
     'Should return community filters but not groups when entity_kind is groups'
@@ -176,6 +188,10 @@ select is(
         "region": [
             {"name": "North America", "value": "north-america"},
             {"name": "Europe", "value": "europe"}
+        ],
+        "topic": [
+            {"name": "Kubernetes", "value": "kubernetes"},
+            {"name": "Observability", "value": "observability"}
         ]
     }'::jsonb,
     'Should return all filter options including groups when entity_kind is events'
@@ -199,7 +215,8 @@ select is(
         "event_category": [],
         "group_category": [],
         "groups": [],
-        "region": []
+        "region": [],
+        "topic": []
     }'::jsonb,
     'Should return communities, distance and empty arrays for non-existing community'
 );
@@ -0,0 +1,133 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0d20000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0d20000-0000-0000-0000-000000000002'
+\set groupCategoryID 'c0d20000-0000-0000-0000-000000000003'
+\set groupID 'c0d20000-0000-0000-0000-000000000004'
+\set otherUserID 'c0d20000-0000-0000-0000-000000000007'
+\set upcomingEventID 'c0d20000-0000-0000-0000-000000000005'
+\set userID 'c0d20000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'calendar-community',
+    'Calendar Community',
+    'Community for Google Calendar tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetups');
+
+-- Users
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values
+    (:'otherUserID', 'hash', 'other@example.test', true, 'other-user'),
+    (:'userID', 'hash', 'user@example.test', true, 'user');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Calendar Group',
+    'calendar-group',
+    'default'
+);
+
+-- Events
+insert into event (
+    event_id,
+    canceled,
+    description,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values
+    (:'upcomingEventID', false, 'Upcoming Event', :'eventCategoryID', 'virtual', :'groupID', 'Upcoming Event', true, 'upcoming-event', now() + interval '2 days', 'UTC');
+
+-- Google Calendar connection
+insert into user_google_calendar (user_id, refresh_token)
+values (:'userID', 'refresh-token');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should queue an entry when a user with a connected calendar attends an event
+insert into event_attendee (event_id, user_id, status)
+values (:'upcomingEventID', :'userID', 'confirmed');
+select results_eq(
+    $$ select event_id, in_sync, sync_version from google_calendar_entry $$,
+    format($$ values (%L::uuid, false, 1::bigint) $$, :'upcomingEventID'),
+    'Should queue an entry when a user with a connected calendar attends an event'
+);
+
+-- Should not queue entries for users without a connected calendar
+insert into event_attendee (event_id, user_id, status)
+values (:'upcomingEventID', :'otherUserID', 'confirmed');
+select is(
+    (select count(*) from google_calendar_entry where user_id = :'otherUserID'::uuid)::int,
+    0,
+    'Should not queue entries for users without a connected calendar'
+);
+
+-- Should mark the entry out of sync when the attendance is removed
+update google_calendar_entry set in_sync = true;
+delete from event_attendee where user_id = :'userID'::uuid;
+select results_eq(
+    $$ select in_sync, sync_version from google_calendar_entry $$,
+    $$ values (false, 2::bigint) $$,
+    'Should mark the entry out of sync when the attendance is removed'
+);
+
+-- Should mark the entries out of sync when the event details change
+update google_calendar_entry set in_sync = true;
+update event set starts_at = starts_at + interval '1 hour' where event_id = :'upcomingEventID';
+select results_eq(
+    $$ select in_sync, sync_version from google_calendar_entry $$,
+    $$ values (false, 3::bigint) $$,
+    'Should mark the entries out of sync when the event details change'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,148 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- Capture notifications sent by the trigger, as pg_notify only delivers them
+-- once the transaction commits
+create schema notify_spy;
+create table notify_spy.notification (channel text, payload text);
+create function notify_spy.pg_notify(p_channel text, p_payload text)
+returns void as $$
+    insert into notify_spy.notification (channel, payload) values (p_channel, p_payload);
+$$ language sql;
+set local search_path = notify_spy, pg_catalog, public;
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set communityID 'c0d30000-0000-0000-0000-000000000001'
+\set eventCategoryID 'c0d30000-0000-0000-0000-000000000002'
+\set eventID 'c0d30000-0000-0000-0000-000000000005'
+\set groupCategoryID 'c0d30000-0000-0000-0000-000000000003'
+\set groupID 'c0d30000-0000-0000-0000-000000000004'
+\set userID 'c0d30000-0000-0000-0000-000000000006'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Community
+insert into community (
+    community_id,
+    name,
+    display_name,
+    description,
+    banner_mobile_url,
+    banner_url,
+    logo_url
+) values (
+    :'communityID',
+    'calendar-community',
+    'Calendar Community',
+    'Community for Google Calendar tests',
+    'https://example.test/banner-mobile.png',
+    'https://example.test/banner.png',
+    'https://example.test/logo.png'
+);
+
+-- Group category
+insert into group_category (group_category_id, community_id, name)
+values (:'groupCategoryID', :'communityID', 'Technology');
+
+-- Event category
+insert into event_category (event_category_id, community_id, name)
+values (:'eventCategoryID', :'communityID', 'Meetups');
+
+-- User
+insert into "user" (user_id, auth_hash, email, email_verified, username)
+values (:'userID', 'hash', 'user@example.test', true, 'user');
+
+-- Group
+insert into "group" (group_id, community_id, group_category_id, name, slug, group_site_layout_id)
+values (
+    :'groupID',
+    :'communityID',
+    :'groupCategoryID',
+    'Calendar Group',
+    'calendar-group',
+    'default'
+);
+
+-- Event
+insert into event (
+    event_id,
+    canceled,
+    description,
+    event_category_id,
+    event_kind_id,
+    group_id,
+    name,
+    published,
+    slug,
+    starts_at,
+    timezone
+) values (
+    :'eventID',
+    false,
+    'Upcoming Event',
+    :'eventCategoryID',
+    'virtual',
+    :'groupID',
+    'Upcoming Event',
+    true,
+    'upcoming-event',
+    now() + interval '2 days',
+    'UTC'
+);
+
+-- Google Calendar connection
+insert into user_google_calendar (user_id, refresh_token)
+values (:'userID', 'refresh-token');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should notify the workers when an out of sync entry is inserted
+insert into google_calendar_entry (event_id, user_id, in_sync)
+values (:'eventID', :'userID', false);
+select results_eq(
+    $$ select channel, payload from notify_spy.notification $$,
+    $$ values ('google_calendar_entry_out_of_sync'::text, ''::text) $$,
+    'Should notify the workers when an out of sync entry is inserted'
+);
+
+-- Should not notify the workers when an entry is marked in sync
+delete from notify_spy.notification;
+update google_calendar_entry set in_sync = true;
+select is_empty(
+    $$ select channel, payload from notify_spy.notification $$,
+    'Should not notify the workers when an entry is marked in sync'
+);
+
+-- Should notify the workers when an entry is marked out of sync
+update google_calendar_entry set in_sync = false, sync_version = sync_version + 1;
+select results_eq(
+    $$ select channel, payload from notify_spy.notification $$,
+    $$ values ('google_calendar_entry_out_of_sync'::text, ''::text) $$,
+    'Should notify the workers when an entry is marked out of sync'
+);
+
+-- Should not notify the workers when other entry columns change
+delete from notify_spy.notification;
+update google_calendar_entry set sync_claimed_at = now();
+select is_empty(
+    $$ select channel, payload from notify_spy.notification $$,
+    'Should not notify the workers when other entry columns change'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(69);
+select plan(100);
 
 -- ============================================================================
 -- TESTS
@@ -22,6 +22,10 @@ select has_table('cfs_submission_label');
 select has_table('cfs_submission_rating');
 select has_table('cfs_submission_status');
 select has_table('community');
+select has_table('community_domain');
+select has_table('community_newsletter_issue');
+select has_table('community_newsletter_issue_recipient');
+select has_table('community_newsletter_subscriber');
 select has_table('community_permission');
 select has_table('community_redirect_settings');
 select has_table('community_role');
@@ -35,43 +39,65 @@ select has_table('email_verification_code');
 select has_table('event');
 select has_table('event_attendee');
 select has_table('event_category');
+select has_table('event_comment');
 select has_table('event_discount_code');
 select has_table('event_host');
 select has_table('event_invitation_request');
 select has_table('event_kind');
 select has_table('event_organizer');
+select has_table('event_photo');
 select has_table('event_purchase');
 select has_table('event_purchase_refund');
 select has_table('event_refund_request');
+select has_table('event_reminder');
+select has_table('event_resource');
 select has_table('event_ticket_price_window');
 select has_table('event_ticket_type');
+select has_table('event_topic');
 select has_table('event_cfs_label');
 select has_table('event_series');
 select has_table('event_speaker');
 select has_table('event_sponsor');
+select has_table('event_template');
 select has_table('event_views');
 select has_table('event_waitlist');
+select has_table('google_calendar_entry');
 select has_table('group');
+select has_table('group_announcement');
+select has_table('group_ban');
 select has_table('group_category');
+select has_table('group_contact_message');
+select has_table('group_discussion_reply');
+select has_table('group_discussion_topic');
+select has_table('group_gallery_hidden_photo');
+select has_table('group_join_request');
 select has_table('group_member');
+select has_table('group_ownership_transfer');
 select has_table('group_permission');
 select has_table('group_role');
 select has_table('group_role_group_permission');
 select has_table('group_site_layout');
 select has_table('group_sponsor');
+select has_table('group_sponsor_tier');
 select has_table('group_team');
+select has_table('group_topic');
+select has_table('group_team_invitation_link');
 select has_table('group_views');
+select has_table('group_zoom_credentials');
 select has_table('images');
 select has_table('legacy_event_host');
 select has_table('legacy_event_speaker');
+select has_table('log_in_failure');
 select has_table('meeting');
 select has_table('meeting_auto_end_check_outcome');
 select has_table('meeting_provider');
+select has_table('meeting_provider_status');
 select has_table('notification');
 select has_table('notification_attachment');
 select has_table('notification_kind');
 select has_table('notification_template_data');
 select has_table('payment_provider');
+select has_table('phone_verification_code');
 select has_table('region');
 select has_table('session');
 select has_table('session_kind');
@@ -80,7 +106,12 @@ select has_table('session_proposal_level');
 select has_table('session_proposal_status');
 select has_table('session_speaker');
 select has_table('site');
+select has_table('topic');
 select has_table('user');
+select has_table('user_google_calendar');
+select has_table('user_magic_link');
+select has_table('user_passkey');
+select has_table('user_password_reset');
 
 -- ============================================================================
 -- CLEANUP
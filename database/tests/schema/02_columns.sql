@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(71);
+select plan(102);
 
 -- ============================================================================
 -- TESTS
@@ -38,8 +38,12 @@ select columns_are('audit_log', array[
 -- Test: auth_session columns should match expected
 select columns_are('auth_session', array[
     'auth_session_id',
+    'auth_session_ref',
+    'created_at',
     'data',
-    'expires_at'
+    'expires_at',
+    'last_seen_at',
+    'user_id'
 ]);
 
 -- Test: cfs_submission columns should match expected
@@ -89,13 +93,23 @@ select columns_are('community', array[
     'created_at',
     'description',
     'display_name',
+    'dormant_group_months',
+    'dormant_groups_report_enabled',
+    'event_approval_required',
     'group_team_management_restricted',
     'logo_url',
     'name',
+    'passkey_required',
 
     'ad_banner_link_url',
     'ad_banner_url',
+    'allowed_email_domains',
     'bluesky_url',
+    'dormant_groups_report_sent_at',
+    'email_footer_text',
+    'email_from_name',
+    'email_logo_url',
+    'email_primary_color',
     'extra_links',
     'facebook_url',
     'flickr_url',
@@ -105,13 +119,61 @@ select columns_are('community', array[
     'new_group_details',
     'og_image_url',
     'photos_urls',
+    'required_oidc_provider',
     'slack_url',
+    'slack_webhook_url',
+    'theme_favicon_url',
+    'theme_font_family',
+    'theme_header_logo_url',
+    'theme_primary_color',
     'twitter_url',
     'website_url',
     'wechat_url',
+    'weekly_digest_sent_at',
     'youtube_url'
 ]);
 
+-- Test: community_domain columns should match expected
+select columns_are('community_domain', array[
+    'community_domain_id',
+    'community_id',
+    'created_at',
+    'domain',
+    'verification_token',
+
+    'created_by',
+    'verified_at'
+]);
+
+-- Test: community_newsletter_issue columns should match expected
+select columns_are('community_newsletter_issue', array[
+    'community_newsletter_issue_id',
+    'body',
+    'community_id',
+    'created_at',
+    'subject',
+
+    'created_by',
+    'recipient_count',
+    'sent_at',
+    'sent_by'
+]);
+
+-- Test: community_newsletter_issue_recipient columns should match expected
+select columns_are('community_newsletter_issue_recipient', array[
+    'community_newsletter_issue_id',
+    'user_id',
+
+    'enqueued_at'
+]);
+
+-- Test: community_newsletter_subscriber columns should match expected
+select columns_are('community_newsletter_subscriber', array[
+    'community_id',
+    'user_id',
+    'created_at'
+]);
+
 -- Test: community_redirect_settings columns should match expected
 select columns_are('community_redirect_settings', array[
     'community_id',
@@ -186,6 +248,7 @@ select columns_are('email_verification_code', array[
 select columns_are('event', array[
     'event_id',
     'canceled',
+    'check_in_secret',
     'created_at',
     'deleted',
     'description',
@@ -200,7 +263,14 @@ select columns_are('event', array[
     'timezone',
     'tsdoc',
 
+    'approval_rejection_reason',
+    'approval_requested_at',
+    'approval_requested_by',
+    'approval_status',
+    'attendance_certificate_emails_enabled',
+    'attendance_certificates_sent_at',
     'attendee_approval_required',
+    'attendee_photos_enabled',
     'banner_mobile_url',
     'banner_url',
     'capacity',
@@ -208,13 +278,15 @@ select columns_are('event', array[
     'cfs_enabled',
     'cfs_ends_at',
     'cfs_starts_at',
+    'comments_locked',
     'created_by',
     'deleted_at',
     'description_short',
     'ends_at',
-    'event_reminder_evaluated_for_starts_at',
-    'event_reminder_sent_at',
+    'event_reminder_not_before',
     'event_series_id',
+    'feedback_url',
+    'follow_up_sent_at',
     'legacy_id',
     'legacy_url',
     'location',
@@ -232,9 +304,13 @@ select columns_are('event', array[
     'meeting_recording_url',
     'meeting_requested',
     'meeting_sync_claimed_at',
+    'meeting_webinar',
     'meetup_url',
+    'no_show_waitlist_threshold',
     'payment_currency_code',
     'photos_urls',
+    'publish_scheduled_at',
+    'publish_scheduled_by',
     'published_at',
     'published_by',
     'registration_ends_at',
@@ -242,6 +318,7 @@ select columns_are('event', array[
     'registration_required',
     'registration_starts_at',
     'starts_at',
+    'streaming_url',
     'tags',
     'venue_address',
     'venue_city',
@@ -250,6 +327,7 @@ select columns_are('event', array[
     'venue_name',
     'venue_state',
     'venue_zip_code',
+    'waitlist_confirmation_hours',
     'waitlist_enabled'
 ]);
 
@@ -297,11 +375,15 @@ select columns_are('event_attendee', array[
     'created_at',
     'manually_invited',
     'status',
+    'ticket_code',
 
     'attendance_canceled_at',
     'attendance_canceled_by_user_id',
     'checked_in_at',
-    'registration_answers'
+    'registration_answers',
+    'reminder_preference',
+    'reminder_sent_for_starts_at',
+    'waitlist_confirmation_expires_at'
 ]);
 
 -- Test: event_category columns should match expected
@@ -314,6 +396,18 @@ select columns_are('event_category', array[
     'slug'
 ]);
 
+-- Test: event_comment columns should match expected
+select columns_are('event_comment', array[
+    'event_comment_id',
+    'event_id',
+    'user_id',
+    'content',
+    'created_at',
+    'pinned',
+
+    'parent_event_comment_id'
+]);
+
 -- Test: event_series columns should match expected
 select columns_are('event_series', array[
     'event_series_id',
@@ -324,7 +418,8 @@ select columns_are('event_series', array[
     'recurrence_pattern',
     'timezone',
 
-    'created_by'
+    'created_by',
+    'name'
 ]);
 
 -- Test: event_discount_code columns should match expected
@@ -368,6 +463,19 @@ select columns_are('event_organizer', array[
     'order'
 ]);
 
+-- Test: event_photo columns should match expected
+select columns_are('event_photo', array[
+    'event_photo_id',
+    'event_id',
+    'user_id',
+    'created_at',
+    'status',
+    'url',
+
+    'reviewed_at',
+    'reviewed_by'
+]);
+
 -- Test: event_purchase columns should match expected
 select columns_are('event_purchase', array[
     'event_purchase_id',
@@ -439,6 +547,28 @@ select columns_are('event_refund_request', array[
     'reviewed_by_user_id'
 ]);
 
+-- Test: event_reminder columns should match expected
+select columns_are('event_reminder', array[
+    'event_id',
+    'offset_minutes',
+    'starts_at',
+
+    'sent_at'
+]);
+
+-- Test: event_resource columns should match expected
+select columns_are('event_resource', array[
+    'event_resource_id',
+    'event_id',
+    'content_type',
+    'created_at',
+    'file_name',
+    'name',
+    'size_bytes',
+
+    'created_by'
+]);
+
 -- Test: event_ticket_price_window columns should match expected
 select columns_are('event_ticket_price_window', array[
     'event_ticket_price_window_id',
@@ -465,6 +595,12 @@ select columns_are('event_ticket_type', array[
     'description'
 ]);
 
+-- Test: event_topic columns should match expected
+select columns_are('event_topic', array[
+    'event_id',
+    'topic_id'
+]);
+
 -- Test: event_cfs_label columns should match expected
 select columns_are('event_cfs_label', array[
     'color',
@@ -490,6 +626,16 @@ select columns_are('event_sponsor', array[
     'level'
 ]);
 
+-- Test: event_template columns should match expected
+select columns_are('event_template', array[
+    'event_template_id',
+    'group_id',
+    'name',
+    'details',
+    'created_at',
+    'created_by'
+]);
+
 -- Test: event_views columns should match expected
 select columns_are('event_views', array[
     'event_id',
@@ -512,12 +658,20 @@ select columns_are('meeting', array[
     'meeting_provider_id',
     'provider_meeting_id',
 
+    'attendance_sync_claimed_at',
+    'attendance_synced_at',
     'auto_end_check_at',
     'auto_end_check_claimed_at',
     'auto_end_check_outcome',
     'event_id',
+    'group_id',
+    'participants_joined_count',
     'password',
     'provider_host_user_id',
+    'provider_status',
+    'provider_status_updated_at',
+    'recording_sync_claimed_at',
+    'recording_synced_at',
     'recording_urls',
     'session_id',
     'sync_claimed_at',
@@ -536,6 +690,23 @@ select columns_are('meeting_provider', array[
     'display_name'
 ]);
 
+-- Test: meeting_provider_status columns should match expected
+select columns_are('meeting_provider_status', array[
+    'meeting_provider_status_id',
+    'display_name'
+]);
+
+-- Test: google_calendar_entry columns should match expected
+select columns_are('google_calendar_entry', array[
+    'event_id',
+    'user_id',
+    'in_sync',
+    'sync_version',
+
+    'google_event_id',
+    'sync_claimed_at'
+]);
+
 -- Test: group columns should match expected
 select columns_are('group', array[
     'group_id',
@@ -549,6 +720,7 @@ select columns_are('group', array[
     'slug',
     'tsdoc',
 
+    'archived_at',
     'banner_mobile_url',
     'banner_url',
     'bluesky_url',
@@ -558,20 +730,28 @@ select columns_are('group', array[
     'deleted_at',
     'description',
     'description_short',
+    'discord_webhook_url',
+    'dormant_since',
     'extra_links',
     'facebook_url',
     'flickr_url',
+    'gallery_cover_url',
     'github_url',
     'instagram_url',
+    'join_questions',
     'legacy_id',
     'legacy_url',
     'linkedin_url',
     'location',
     'logo_url',
+    'merged_at',
+    'merged_into_group_id',
     'og_image_url',
+    'owner_user_id',
     'parent_group_id',
     'payment_recipient',
     'photos_urls',
+    'private',
     'region_id',
     'slack_url',
     'slug_pretty',
@@ -580,9 +760,31 @@ select columns_are('group', array[
     'twitter_url',
     'website_url',
     'wechat_url',
+    'welcome_message',
     'youtube_url'
 ]);
 
+-- Test: group_announcement columns should match expected
+select columns_are('group_announcement', array[
+    'group_announcement_id',
+    'group_id',
+    'title',
+    'content',
+    'created_at',
+    'created_by',
+    'pinned'
+]);
+
+-- Test: group_ban columns should match expected
+select columns_are('group_ban', array[
+    'group_id',
+    'user_id',
+    'created_at',
+    'reason',
+
+    'created_by'
+]);
+
 -- Test: group_category columns should match expected
 select columns_are('group_category', array[
     'group_category_id',
@@ -594,11 +796,71 @@ select columns_are('group_category', array[
     'order'
 ]);
 
+-- Test: group_contact_message columns should match expected
+select columns_are('group_contact_message', array[
+    'group_contact_message_id',
+    'group_id',
+    'user_id',
+    'subject',
+    'body',
+    'created_at'
+]);
+
+-- Test: group_discussion_reply columns should match expected
+select columns_are('group_discussion_reply', array[
+    'group_discussion_reply_id',
+    'group_discussion_topic_id',
+    'user_id',
+    'content',
+    'created_at'
+]);
+
+-- Test: group_discussion_topic columns should match expected
+select columns_are('group_discussion_topic', array[
+    'group_discussion_topic_id',
+    'group_id',
+    'user_id',
+    'title',
+    'content',
+    'created_at',
+    'locked',
+    'pinned'
+]);
+
+-- Test: group_gallery_hidden_photo columns should match expected
+select columns_are('group_gallery_hidden_photo', array[
+    'group_id',
+    'url',
+    'created_at'
+]);
+
+-- Test: group_join_request columns should match expected
+select columns_are('group_join_request', array[
+    'group_id',
+    'user_id',
+    'created_at',
+    'status',
+
+    'join_answers',
+    'reviewed_at',
+    'reviewed_by'
+]);
+
+-- Test: group_ownership_transfer columns should match expected
+select columns_are('group_ownership_transfer', array[
+    'group_id',
+    'created_at',
+    'created_by',
+    'user_id'
+]);
+
 -- Test: group_member columns should match expected
 select columns_are('group_member', array[
     'group_id',
     'user_id',
-    'created_at'
+    'created_at',
+    'join_answers',
+    'member_role'
 ]);
 
 -- Test: group_role columns should match expected
@@ -633,9 +895,20 @@ select columns_are('group_sponsor', array[
     'logo_url',
     'name',
 
+    'group_sponsor_tier_id',
     'website_url'
 ]);
 
+-- Test: group_sponsor_tier columns should match expected
+select columns_are('group_sponsor_tier', array[
+    'group_sponsor_tier_id',
+    'group_id',
+    'created_at',
+    'logo_size',
+    'name',
+    'order'
+]);
+
 -- Test: group_team columns should match expected
 select columns_are('group_team', array[
     'group_id',
@@ -647,6 +920,22 @@ select columns_are('group_team', array[
     'order'
 ]);
 
+-- Test: group_topic columns should match expected
+select columns_are('group_topic', array[
+    'group_id',
+    'topic_id'
+]);
+
+-- Test: group_team_invitation_link columns should match expected
+select columns_are('group_team_invitation_link', array[
+    'group_team_invitation_link_id',
+    'created_at',
+    'expires_at',
+    'group_id',
+    'token_hash',
+    'user_id'
+]);
+
 -- Test: group_views columns should match expected
 select columns_are('group_views', array[
     'group_id',
@@ -654,6 +943,19 @@ select columns_are('group_views', array[
     'total'
 ]);
 
+-- Test: group_zoom_credentials columns should match expected
+select columns_are('group_zoom_credentials', array[
+    'group_id',
+    'account_id',
+    'client_id',
+    'client_secret',
+    'created_at',
+    'host_user',
+
+    'updated_at',
+    'webhook_secret_token'
+]);
+
 -- Test: images columns should match expected
 select columns_are('images', array[
     'file_name',
@@ -686,7 +988,8 @@ select columns_are('session', array[
     'meeting_recording_published',
     'meeting_recording_url',
     'meeting_requested',
-    'meeting_sync_claimed_at'
+    'meeting_sync_claimed_at',
+    'track'
 ]);
 
 select is(
@@ -776,20 +1079,34 @@ select columns_are('legacy_event_speaker', array[
     'title'
 ]);
 
+-- Test: log_in_failure columns should match expected
+select columns_are('log_in_failure', array[
+    'log_in_failure_id',
+    'created_at',
+    'username',
+
+    'ip_address'
+]);
+
 -- Test: notification columns should match expected
 select columns_are('notification', array[
     'notification_id',
+    'channel',
     'created_at',
     'delivery_attempts',
     'delivery_status',
     'kind',
-    'user_id',
 
+    'community_id',
+    'community_newsletter_issue_id',
+    'custom_notification_id',
     'delivery_claimed_at',
     'error',
+    'group_id',
     'next_delivery_attempt_at',
     'notification_template_data_id',
-    'processed_at'
+    'processed_at',
+    'user_id'
 ]);
 
 -- Test: notification_attachment columns should match expected
@@ -803,7 +1120,9 @@ select columns_are('notification_kind', array[
     'notification_kind_id',
 
     'name',
-    'optional_notification'
+    'optional_notification',
+    'sms_notification',
+    'webhook_notification'
 ]);
 
 -- Test: notification_template_data columns should match expected
@@ -820,6 +1139,14 @@ select columns_are('payment_provider', array[
     'display_name'
 ]);
 
+-- Test: phone_verification_code columns should match expected
+select columns_are('phone_verification_code', array[
+    'user_id',
+    'attempts',
+    'code_hash',
+    'created_at'
+]);
+
 -- Test: region columns should match expected
 select columns_are('region', array[
     'region_id',
@@ -846,6 +1173,15 @@ select columns_are('site', array[
     'og_image_url'
 ]);
 
+-- Test: topic columns should match expected
+select columns_are('topic', array[
+    'topic_id',
+    'community_id',
+    'created_at',
+    'name',
+    'normalized_name'
+]);
+
 -- Test: user columns should match expected
 select columns_are('user', array[
     'user_id',
@@ -858,24 +1194,72 @@ select columns_are('user', array[
 
     'bio',
     'bluesky_url',
+    'calendar_token',
     'city',
     'company',
     'country',
+    'email_undeliverable_at',
+    'email_undeliverable_reason',
+    'event_reminder_preference',
     'facebook_url',
     'github_url',
     'interests',
+    'language',
     'legacy_id',
     'linkedin_url',
     'name',
     'optional_notifications_enabled',
     'password',
+    'phone_number',
+    'phone_number_verified',
     'photo_url',
     'provider',
     'registration_status',
+    'sms_notifications_enabled',
     'timezone',
     'title',
     'twitter_url',
-    'website_url'
+    'webhook_secret',
+    'webhook_url',
+    'website_url',
+    'weekly_digest_enabled'
+]);
+
+-- Test: user_google_calendar columns should match expected
+select columns_are('user_google_calendar', array[
+    'user_id',
+    'created_at',
+    'refresh_token'
+]);
+
+-- Test: user_magic_link columns should match expected
+select columns_are('user_magic_link', array[
+    'user_magic_link_id',
+    'created_at',
+    'expires_at',
+    'token_hash',
+    'user_id'
+]);
+
+-- Test: user_passkey columns should match expected
+select columns_are('user_passkey', array[
+    'user_passkey_id',
+    'created_at',
+    'credential_id',
+    'name',
+    'passkey',
+    'user_id',
+
+    'last_used_at'
+]);
+
+-- Test: user_password_reset columns should match expected
+select columns_are('user_password_reset', array[
+    'user_password_reset_id',
+    'code_hash',
+    'created_at',
+    'expires_at',
+    'user_id'
 ]);
 
 -- ============================================================================
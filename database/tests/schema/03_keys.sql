@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(161);
+select plan(258);
 
 -- ============================================================================
 -- TESTS
@@ -17,6 +17,10 @@ select has_pk('cfs_submission');
 select has_pk('cfs_submission_rating');
 select has_pk('cfs_submission_status');
 select has_pk('community');
+select has_pk('community_domain');
+select has_pk('community_newsletter_issue');
+select has_pk('community_newsletter_issue_recipient');
+select has_pk('community_newsletter_subscriber');
 select has_pk('community_permission');
 select has_pk('community_redirect_settings');
 select has_pk('community_role');
@@ -30,41 +34,63 @@ select has_pk('email_verification_code');
 select has_pk('event');
 select has_pk('event_attendee');
 select has_pk('event_category');
+select has_pk('event_comment');
 select has_pk('event_discount_code');
 select has_pk('event_host');
 select has_pk('event_kind');
 select has_pk('event_organizer');
+select has_pk('event_photo');
 select has_pk('event_purchase');
 select has_pk('event_purchase_refund');
 select has_pk('event_refund_request');
+select has_pk('event_reminder');
+select has_pk('event_resource');
 select has_pk('event_ticket_price_window');
 select has_pk('event_ticket_type');
+select has_pk('event_topic');
 select has_pk('event_series');
 select has_pk('event_speaker');
 select has_pk('event_sponsor');
+select has_pk('event_template');
 select hasnt_pk('event_views');
 select has_pk('event_waitlist');
+select has_pk('google_calendar_entry');
 select has_pk('group');
+select has_pk('group_announcement');
+select has_pk('group_ban');
 select has_pk('group_category');
+select has_pk('group_contact_message');
+select has_pk('group_discussion_reply');
+select has_pk('group_discussion_topic');
+select has_pk('group_gallery_hidden_photo');
+select has_pk('group_join_request');
 select has_pk('group_member');
+select has_pk('group_ownership_transfer');
 select has_pk('group_permission');
 select has_pk('group_role');
 select has_pk('group_role_group_permission');
 select has_pk('group_site_layout');
 select has_pk('group_sponsor');
+select has_pk('group_sponsor_tier');
 select has_pk('group_team');
+select has_pk('group_topic');
+select has_pk('group_team_invitation_link');
 select hasnt_pk('group_views');
+select has_pk('group_zoom_credentials');
 select has_pk('images');
 select has_pk('legacy_event_host');
 select has_pk('legacy_event_speaker');
+select has_pk('log_in_failure');
 select has_pk('meeting');
 select has_pk('meeting_auto_end_check_outcome');
 select has_pk('meeting_provider');
+select has_pk('meeting_provider_status');
 select has_pk('notification');
 select has_pk('notification_attachment');
 select has_pk('notification_kind');
 select has_pk('notification_template_data');
 select has_pk('payment_provider');
+select has_pk('phone_verification_code');
 select has_pk('region');
 select has_pk('session');
 select has_pk('session_kind');
@@ -73,10 +99,24 @@ select has_pk('session_proposal_level');
 select has_pk('session_proposal_status');
 select has_pk('session_speaker');
 select has_pk('site');
+select has_pk('topic');
 select has_pk('user');
+select has_pk('user_google_calendar');
+select has_pk('user_magic_link');
+select has_pk('user_passkey');
+select has_pk('user_password_reset');
 
 -- Test: check tables have expected foreign keys
 select col_is_fk('community', 'community_site_layout_id', 'community_site_layout');
+select col_is_fk('community_domain', 'community_id', 'community');
+select col_is_fk('community_domain', 'created_by', 'user');
+select col_is_fk('community_newsletter_issue', 'community_id', 'community');
+select col_is_fk('community_newsletter_issue', 'created_by', 'user');
+select col_is_fk('community_newsletter_issue', 'sent_by', 'user');
+select col_is_fk('community_newsletter_issue_recipient', 'community_newsletter_issue_id', 'community_newsletter_issue');
+select col_is_fk('community_newsletter_issue_recipient', 'user_id', 'user');
+select col_is_fk('community_newsletter_subscriber', 'community_id', 'community');
+select col_is_fk('community_newsletter_subscriber', 'user_id', 'user');
 select col_is_fk('community_redirect_settings', 'community_id', 'community');
 select col_is_fk('community_role_community_permission', 'community_permission_id', 'community_permission');
 select col_is_fk('community_role_community_permission', 'community_role_id', 'community_role');
@@ -95,22 +135,30 @@ select col_is_fk('cfs_submission', 'status_id', 'cfs_submission_status');
 select col_is_fk('cfs_submission_rating', 'cfs_submission_id', 'cfs_submission');
 select col_is_fk('cfs_submission_rating', 'reviewer_id', 'user');
 select col_is_fk('email_verification_code', 'user_id', 'user');
+select col_is_fk('event', 'approval_requested_by', 'user');
 select col_is_fk('event', 'event_category_id', 'event_category');
 select col_is_fk('event', 'created_by', 'user');
 select col_is_fk('event', 'event_kind_id', 'event_kind');
 select col_is_fk('event', 'event_series_id', 'event_series');
 select col_is_fk('event', 'group_id', 'group');
 select col_is_fk('event', 'meeting_provider_id', 'meeting_provider');
+select col_is_fk('event', 'publish_scheduled_by', 'user');
 select col_is_fk('event', 'published_by', 'user');
 select col_is_fk('event_attendee', 'attendance_canceled_by_user_id', 'user');
 select col_is_fk('event_attendee', 'event_id', 'event');
 select col_is_fk('event_attendee', 'user_id', 'user');
 select col_is_fk('event_category', 'community_id', 'community');
+select col_is_fk('event_comment', 'event_id', 'event');
+select col_is_fk('event_comment', 'parent_event_comment_id', 'event_comment');
+select col_is_fk('event_comment', 'user_id', 'user');
 select col_is_fk('event_discount_code', 'event_id', 'event');
 select col_is_fk('event_host', 'event_id', 'event');
 select col_is_fk('event_host', 'user_id', 'user');
 select col_is_fk('event_organizer', 'event_id', 'event');
 select col_is_fk('event_organizer', 'user_id', 'user');
+select col_is_fk('event_photo', 'event_id', 'event');
+select col_is_fk('event_photo', 'reviewed_by', 'user');
+select col_is_fk('event_photo', 'user_id', 'user');
 select col_is_fk('event_purchase', 'event_discount_code_id', 'event_discount_code');
 select col_is_fk('event_purchase', 'event_id', 'event');
 select col_is_fk('event_purchase', 'payment_provider_id', 'payment_provider');
@@ -124,44 +172,86 @@ select col_is_fk('event_purchase_refund', 'recovery_completed_by_user_id', 'user
 select col_is_fk('event_refund_request', 'event_purchase_id', 'event_purchase');
 select col_is_fk('event_refund_request', 'requested_by_user_id', 'user');
 select col_is_fk('event_refund_request', 'reviewed_by_user_id', 'user');
+select col_is_fk('event_reminder', 'event_id', 'event');
+select col_is_fk('event_resource', 'created_by', 'user');
+select col_is_fk('event_resource', 'event_id', 'event');
 select col_is_fk('event_ticket_price_window', 'event_ticket_type_id', 'event_ticket_type');
 select col_is_fk('event_ticket_type', 'event_id', 'event');
+select col_is_fk('event_topic', 'event_id', 'event');
+select col_is_fk('event_topic', 'topic_id', 'topic');
 select col_is_fk('event_series', 'created_by', 'user');
 select col_is_fk('event_series', 'group_id', 'group');
 select col_is_fk('event_speaker', 'event_id', 'event');
 select col_is_fk('event_speaker', 'user_id', 'user');
 select col_is_fk('event_sponsor', 'event_id', 'event');
 select col_is_fk('event_sponsor', 'group_sponsor_id', 'group_sponsor');
+select col_is_fk('event_template', 'created_by', 'user');
+select col_is_fk('event_template', 'group_id', 'group');
 select col_is_fk('event_views', 'event_id', 'event');
 select col_is_fk('event_waitlist', 'event_id', 'event');
 select col_is_fk('event_waitlist', 'user_id', 'user');
+select col_is_fk('google_calendar_entry', 'event_id', 'event');
+select col_is_fk('google_calendar_entry', 'user_id', 'user_google_calendar');
 select col_is_fk('group', 'community_id', 'community');
 select col_is_fk('group', 'group_category_id', 'group_category');
 select col_is_fk('group', 'group_site_layout_id', 'group_site_layout');
+select col_is_fk('group', 'merged_into_group_id', 'group');
+select col_is_fk('group', 'owner_user_id', 'user');
 select col_is_fk('group', 'parent_group_id', 'group');
 select col_is_fk('group', 'region_id', 'region');
+select col_is_fk('group_announcement', 'created_by', 'user');
+select col_is_fk('group_announcement', 'group_id', 'group');
+select col_is_fk('group_ban', 'created_by', 'user');
+select col_is_fk('group_ban', 'group_id', 'group');
+select col_is_fk('group_ban', 'user_id', 'user');
 select col_is_fk('group_category', 'community_id', 'community');
+select col_is_fk('group_contact_message', 'group_id', 'group');
+select col_is_fk('group_contact_message', 'user_id', 'user');
+select col_is_fk('group_discussion_reply', 'group_discussion_topic_id', 'group_discussion_topic');
+select col_is_fk('group_discussion_reply', 'user_id', 'user');
+select col_is_fk('group_discussion_topic', 'group_id', 'group');
+select col_is_fk('group_discussion_topic', 'user_id', 'user');
+select col_is_fk('group_gallery_hidden_photo', 'group_id', 'group');
+select col_is_fk('group_join_request', 'group_id', 'group');
+select col_is_fk('group_join_request', 'reviewed_by', 'user');
+select col_is_fk('group_join_request', 'user_id', 'user');
 select col_is_fk('group_member', 'group_id', 'group');
 select col_is_fk('group_member', 'user_id', 'user');
+select col_is_fk('group_ownership_transfer', 'created_by', 'user');
+select col_is_fk('group_ownership_transfer', 'group_id', 'group');
+select col_is_fk('group_ownership_transfer', array['group_id', 'user_id'], 'group_team');
 select col_is_fk('group_role_group_permission', 'group_permission_id', 'group_permission');
 select col_is_fk('group_role_group_permission', 'group_role_id', 'group_role');
 select col_is_fk('group_sponsor', 'group_id', 'group');
+select col_is_fk('group_sponsor', 'group_sponsor_tier_id', 'group_sponsor_tier');
+select col_is_fk('group_sponsor_tier', 'group_id', 'group');
 select col_is_fk('group_team', 'group_id', 'group');
 select col_is_fk('group_team', 'role', 'group_role');
 select col_is_fk('group_team', 'user_id', 'user');
+select col_is_fk('group_topic', 'group_id', 'group');
+select col_is_fk('group_topic', 'topic_id', 'topic');
+select col_is_fk('group_team_invitation_link', array['group_id', 'user_id'], 'group_team');
 select col_is_fk('group_views', 'group_id', 'group');
+select col_is_fk('group_zoom_credentials', 'group_id', 'group');
 select col_is_fk('images', 'created_by', 'user');
 select col_is_fk('legacy_event_host', 'event_id', 'event');
 select col_is_fk('legacy_event_speaker', 'event_id', 'event');
 select col_is_fk('meeting', 'auto_end_check_outcome', 'meeting_auto_end_check_outcome');
 select col_is_fk('meeting', 'event_id', 'event');
+select col_is_fk('meeting', 'group_id', 'group');
 select col_is_fk('meeting', 'meeting_provider_id', 'meeting_provider');
+select col_is_fk('meeting', 'provider_status', 'meeting_provider_status');
 select col_is_fk('meeting', 'session_id', 'session');
+select col_is_fk('notification', 'community_id', 'community');
+select col_is_fk('notification', 'community_newsletter_issue_id', 'community_newsletter_issue');
+select col_is_fk('notification', 'custom_notification_id', 'custom_notification');
+select col_is_fk('notification', 'group_id', 'group');
 select col_is_fk('notification', 'kind', 'notification_kind');
 select col_is_fk('notification', 'notification_template_data_id', 'notification_template_data');
 select col_is_fk('notification', 'user_id', 'user');
 select col_is_fk('notification_attachment', 'attachment_id', 'attachment');
 select col_is_fk('notification_attachment', 'notification_id', 'notification');
+select col_is_fk('phone_verification_code', 'user_id', 'user');
 select col_is_fk('region', 'community_id', 'community');
 select col_is_fk('session', 'event_id', 'event');
 select col_is_fk('session', 'cfs_submission_id', 'cfs_submission');
@@ -173,6 +263,11 @@ select col_is_fk('session_proposal', 'session_proposal_status_id', 'session_prop
 select col_is_fk('session_proposal', 'user_id', 'user');
 select col_is_fk('session_speaker', 'session_id', 'session');
 select col_is_fk('session_speaker', 'user_id', 'user');
+select col_is_fk('topic', 'community_id', 'community');
+select col_is_fk('user_google_calendar', 'user_id', 'user');
+select col_is_fk('user_magic_link', 'user_id', 'user');
+select col_is_fk('user_passkey', 'user_id', 'user');
+select col_is_fk('user_password_reset', 'user_id', 'user');
 
 -- ============================================================================
 -- CLEANUP
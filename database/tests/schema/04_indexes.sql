@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(70);
+select plan(111);
 
 -- ============================================================================
 -- TESTS
@@ -27,7 +27,9 @@ select indexes_are('audit_log', array[
 
 -- Test: auth_session indexes should match expected
 select indexes_are('auth_session', array[
-    'auth_session_pkey'
+    'auth_session_auth_session_ref_key',
+    'auth_session_pkey',
+    'auth_session_user_id_idx'
 ]);
 
 -- Test: cfs_submission indexes should match expected
@@ -67,6 +69,35 @@ select indexes_are('community', array[
     'community_og_image_url_idx'
 ]);
 
+-- Test: community_domain indexes should match expected
+select indexes_are('community_domain', array[
+    'community_domain_pkey',
+    'community_domain_community_id_idx',
+    'community_domain_created_by_idx',
+    'community_domain_domain_key'
+]);
+
+-- Test: community_newsletter_issue indexes should match expected
+select indexes_are('community_newsletter_issue', array[
+    'community_newsletter_issue_pkey',
+    'community_newsletter_issue_community_id_idx',
+    'community_newsletter_issue_created_by_idx',
+    'community_newsletter_issue_sent_by_idx'
+]);
+
+-- Test: community_newsletter_issue_recipient indexes should match expected
+select indexes_are('community_newsletter_issue_recipient', array[
+    'community_newsletter_issue_recipient_pkey',
+    'community_newsletter_issue_recipient_pending_idx',
+    'community_newsletter_issue_recipient_user_id_idx'
+]);
+
+-- Test: community_newsletter_subscriber indexes should match expected
+select indexes_are('community_newsletter_subscriber', array[
+    'community_newsletter_subscriber_pkey',
+    'community_newsletter_subscriber_user_id_idx'
+]);
+
 -- Test: community_redirect_settings indexes should match expected
 select indexes_are('community_redirect_settings', array[
     'community_redirect_settings_pkey'
@@ -132,6 +163,7 @@ select indexes_are('email_verification_code', array[
 select indexes_are('event', array[
     'event_pkey',
     'event_slug_group_id_key',
+    'event_approval_requested_at_idx',
     'event_event_category_id_idx',
     'event_event_kind_id_idx',
     'event_event_series_id_idx',
@@ -139,6 +171,7 @@ select indexes_are('event', array[
     'event_location_idx',
     'event_meeting_sync_claim_idx',
     'event_meeting_sync_idx',
+    'event_publish_scheduled_at_idx',
     'event_published_by_idx',
     'event_search_idx',
     'event_starts_at_idx',
@@ -159,8 +192,11 @@ select indexes_are('event_attendee', array[
     'event_attendee_user_id_idx',
     'event_attendee_event_id_created_at_idx',
     'event_attendee_event_id_status_created_at_idx',
-    'event_attendee_event_id_registration_answers_idx'
+    'event_attendee_event_id_registration_answers_idx',
+    'event_attendee_ticket_code_key',
+    'event_attendee_waitlist_confirmation_expires_at_idx'
 ]);
+select index_is_unique('event_attendee', 'event_attendee_ticket_code_key');
 
 -- Test: event_category indexes should match expected
 select indexes_are('event_category', array[
@@ -170,6 +206,14 @@ select indexes_are('event_category', array[
     'event_category_community_id_idx'
 ]);
 
+-- Test: event_comment indexes should match expected
+select indexes_are('event_comment', array[
+    'event_comment_pkey',
+    'event_comment_event_id_created_at_idx',
+    'event_comment_parent_event_comment_id_idx',
+    'event_comment_user_id_idx'
+]);
+
 -- Test: event_discount_code indexes should match expected
 select indexes_are('event_discount_code', array[
     'event_discount_code_pkey',
@@ -206,6 +250,15 @@ select indexes_are('event_organizer', array[
     'event_organizer_user_id_idx'
 ]);
 
+-- Test: event_photo indexes should match expected
+select indexes_are('event_photo', array[
+    'event_photo_pkey',
+    'event_photo_event_id_status_created_at_idx',
+    'event_photo_event_id_url_key',
+    'event_photo_reviewed_by_idx',
+    'event_photo_user_id_idx'
+]);
+
 -- Test: event_purchase indexes should match expected
 select indexes_are('event_purchase', array[
     'event_purchase_pkey',
@@ -233,6 +286,18 @@ select indexes_are('event_refund_request', array[
     'event_refund_request_status_idx'
 ]);
 
+-- Test: event_reminder indexes should match expected
+select indexes_are('event_reminder', array[
+    'event_reminder_pkey'
+]);
+
+-- Test: event_resource indexes should match expected
+select indexes_are('event_resource', array[
+    'event_resource_pkey',
+    'event_resource_created_by_idx',
+    'event_resource_event_id_idx'
+]);
+
 -- Test: event_ticket_price_window indexes should match expected
 select indexes_are('event_ticket_price_window', array[
     'event_ticket_price_window_pkey',
@@ -246,6 +311,12 @@ select indexes_are('event_ticket_type', array[
     'event_ticket_type_event_id_event_ticket_type_id_key'
 ]);
 
+-- Test: event_topic indexes should match expected
+select indexes_are('event_topic', array[
+    'event_topic_pkey',
+    'event_topic_topic_id_idx'
+]);
+
 -- Test: event_cfs_label indexes should match expected
 select indexes_are('event_cfs_label', array[
     'event_cfs_label_pkey',
@@ -253,6 +324,13 @@ select indexes_are('event_cfs_label', array[
     'event_cfs_label_event_id_idx'
 ]);
 
+-- Test: google_calendar_entry indexes should match expected
+select indexes_are('google_calendar_entry', array[
+    'google_calendar_entry_pkey',
+    'google_calendar_entry_sync_claim_idx',
+    'google_calendar_entry_user_id_idx'
+]);
+
 -- Test: group indexes should match expected
 select indexes_are('group', array[
     'group_pkey',
@@ -268,7 +346,24 @@ select indexes_are('group', array[
     'group_og_image_url_idx',
     'group_active_created_at_idx',
     'group_community_active_created_at_idx',
-    'group_parent_group_id_idx'
+    'group_parent_group_id_idx',
+    'group_owner_user_id_idx',
+    'group_merged_into_group_id_idx',
+    'group_community_id_dormant_since_idx'
+]);
+
+-- Test: group_announcement indexes should match expected
+select indexes_are('group_announcement', array[
+    'group_announcement_pkey',
+    'group_announcement_group_id_created_at_idx',
+    'group_announcement_created_by_idx'
+]);
+
+-- Test: group_ban indexes should match expected
+select indexes_are('group_ban', array[
+    'group_ban_pkey',
+    'group_ban_created_by_idx',
+    'group_ban_user_id_idx'
 ]);
 
 -- Test: group_category indexes should match expected
@@ -279,6 +374,39 @@ select indexes_are('group_category', array[
     'group_category_community_id_idx'
 ]);
 
+-- Test: group_contact_message indexes should match expected
+select indexes_are('group_contact_message', array[
+    'group_contact_message_pkey',
+    'group_contact_message_group_id_idx',
+    'group_contact_message_user_id_created_at_idx'
+]);
+
+-- Test: group_discussion_reply indexes should match expected
+select indexes_are('group_discussion_reply', array[
+    'group_discussion_reply_pkey',
+    'group_discussion_reply_group_discussion_topic_id_created_at_idx',
+    'group_discussion_reply_user_id_idx'
+]);
+
+-- Test: group_discussion_topic indexes should match expected
+select indexes_are('group_discussion_topic', array[
+    'group_discussion_topic_pkey',
+    'group_discussion_topic_group_id_created_at_idx',
+    'group_discussion_topic_user_id_idx'
+]);
+
+-- Test: group_gallery_hidden_photo indexes should match expected
+select indexes_are('group_gallery_hidden_photo', array[
+    'group_gallery_hidden_photo_pkey'
+]);
+
+-- Test: group_join_request indexes should match expected
+select indexes_are('group_join_request', array[
+    'group_join_request_pkey',
+    'group_join_request_group_id_status_created_at_idx',
+    'group_join_request_user_id_idx'
+]);
+
 -- Test: group_member indexes should match expected
 select indexes_are('group_member', array[
     'group_member_pkey',
@@ -287,6 +415,13 @@ select indexes_are('group_member', array[
     'group_member_group_id_created_at_idx'
 ]);
 
+-- Test: group_ownership_transfer indexes should match expected
+select indexes_are('group_ownership_transfer', array[
+    'group_ownership_transfer_pkey',
+    'group_ownership_transfer_created_by_idx',
+    'group_ownership_transfer_user_id_idx'
+]);
+
 -- Test: group_role indexes should match expected
 select indexes_are('group_role', array[
     'group_role_pkey',
@@ -312,7 +447,14 @@ select indexes_are('group_site_layout', array[
 -- Test: group_sponsor indexes should match expected
 select indexes_are('group_sponsor', array[
     'group_sponsor_pkey',
-    'group_sponsor_group_id_idx'
+    'group_sponsor_group_id_idx',
+    'group_sponsor_group_sponsor_tier_id_idx'
+]);
+
+-- Test: group_sponsor_tier indexes should match expected
+select indexes_are('group_sponsor_tier', array[
+    'group_sponsor_tier_pkey',
+    'group_sponsor_tier_group_id_name_idx'
 ]);
 
 -- Test: event_speaker indexes should match expected
@@ -329,6 +471,13 @@ select indexes_are('event_sponsor', array[
     'event_sponsor_group_sponsor_id_idx'
 ]);
 
+-- Test: event_template indexes should match expected
+select indexes_are('event_template', array[
+    'event_template_pkey',
+    'event_template_created_by_idx',
+    'event_template_group_id_lower_name_idx'
+]);
+
 -- Test: event_views indexes should match expected
 select indexes_are('event_views', array[
     'event_views_event_id_day_key'
@@ -350,11 +499,31 @@ select indexes_are('group_team', array[
     'group_team_pending_user_created_at_idx'
 ]);
 
+-- Test: group_topic indexes should match expected
+select indexes_are('group_topic', array[
+    'group_topic_pkey',
+    'group_topic_topic_id_idx'
+]);
+
+-- Test: group_team_invitation_link indexes should match expected
+select indexes_are('group_team_invitation_link', array[
+    'group_team_invitation_link_pkey',
+    'group_team_invitation_link_token_hash_key',
+    'group_team_invitation_link_group_id_user_id_idx'
+]);
+select index_is_unique('group_team_invitation_link', 'group_team_invitation_link_token_hash_key');
+
 -- Test: group_views indexes should match expected
 select indexes_are('group_views', array[
     'group_views_group_id_day_key'
 ]);
 
+-- Test: group_zoom_credentials indexes should match expected
+select indexes_are('group_zoom_credentials', array[
+    'group_zoom_credentials_account_id_idx',
+    'group_zoom_credentials_pkey'
+]);
+
 -- Test: images indexes should match expected
 select indexes_are('images', array[
     'images_pkey'
@@ -372,17 +541,28 @@ select indexes_are('legacy_event_speaker', array[
     'legacy_event_speaker_event_id_idx'
 ]);
 
+-- Test: log_in_failure indexes should match expected
+select indexes_are('log_in_failure', array[
+    'log_in_failure_pkey',
+    'log_in_failure_created_at_idx',
+    'log_in_failure_ip_address_idx',
+    'log_in_failure_username_idx'
+]);
+
 -- Test: meeting indexes should match expected
 select indexes_are('meeting', array[
     'meeting_auto_end_check_claim_idx',
     'meeting_event_id_idx',
+    'meeting_group_id_idx',
     'meeting_meeting_provider_id_idx',
     'meeting_meeting_provider_id_provider_meeting_id_idx',
     'meeting_meeting_provider_id_provider_host_user_id_idx',
     'meeting_pkey',
     'meeting_session_id_idx',
     'meeting_sync_claim_idx',
-    'meeting_zoom_auto_end_pending_idx'
+    'meeting_zoom_attendance_sync_pending_idx',
+    'meeting_zoom_auto_end_pending_idx',
+    'meeting_zoom_recording_sync_pending_idx'
 ]);
 
 -- Test: meeting_auto_end_check_outcome indexes should match expected
@@ -397,10 +577,21 @@ select indexes_are('meeting_provider', array[
     'meeting_provider_pkey'
 ]);
 
+-- Test: meeting_provider_status indexes should match expected
+select indexes_are('meeting_provider_status', array[
+    'meeting_provider_status_display_name_key',
+    'meeting_provider_status_pkey'
+]);
+
 -- Test: notification indexes should match expected
 select indexes_are('notification', array[
     'notification_pkey',
+    'notification_community_id_idx',
+    'notification_community_newsletter_issue_id_idx',
+    'notification_custom_notification_id_idx',
+    'notification_dead_letter_idx',
     'notification_delivery_claimed_at_idx',
+    'notification_group_id_idx',
     'notification_kind_idx',
     'notification_not_processed_idx',
     'notification_user_id_idx'
@@ -430,6 +621,11 @@ select indexes_are('payment_provider', array[
     'payment_provider_pkey'
 ]);
 
+-- Test: phone_verification_code indexes should match expected
+select indexes_are('phone_verification_code', array[
+    'phone_verification_code_pkey'
+]);
+
 -- Test: region indexes should match expected
 select indexes_are('region', array[
     'region_pkey',
@@ -487,19 +683,64 @@ select indexes_are('site', array[
     'site_pkey'
 ]);
 
+-- Test: topic indexes should match expected
+select indexes_are('topic', array[
+    'topic_pkey',
+    'topic_name_community_id_key',
+    'topic_normalized_name_community_id_key',
+    'topic_community_id_idx'
+]);
+
 -- Test: user indexes should match expected
 select indexes_are('user', array[
     'user_pkey',
+    'user_calendar_token_key',
     'user_email_lower_idx',
+    'user_github_identity_idx',
+    'user_gitlab_identity_idx',
+    'user_google_identity_idx',
     'user_linuxfoundation_identity_idx',
     'user_name_lower_idx',
     'user_tsdoc_idx',
     'user_username_lower_idx'
 ]);
+select index_is_unique('user', 'user_calendar_token_key');
 select index_is_unique('user', 'user_email_lower_idx');
+select index_is_unique('user', 'user_github_identity_idx');
+select index_is_unique('user', 'user_gitlab_identity_idx');
+select index_is_unique('user', 'user_google_identity_idx');
 select index_is_unique('user', 'user_linuxfoundation_identity_idx');
 select index_is_unique('user', 'user_username_lower_idx');
 
+-- Test: user_google_calendar indexes should match expected
+select indexes_are('user_google_calendar', array[
+    'user_google_calendar_pkey'
+]);
+
+-- Test: user_magic_link indexes should match expected
+select indexes_are('user_magic_link', array[
+    'user_magic_link_pkey',
+    'user_magic_link_token_hash_key',
+    'user_magic_link_user_id_idx'
+]);
+select index_is_unique('user_magic_link', 'user_magic_link_token_hash_key');
+
+-- Test: user_passkey indexes should match expected
+select indexes_are('user_passkey', array[
+    'user_passkey_pkey',
+    'user_passkey_credential_id_key',
+    'user_passkey_user_id_idx'
+]);
+select index_is_unique('user_passkey', 'user_passkey_credential_id_key');
+
+-- Test: user_password_reset indexes should match expected
+select indexes_are('user_password_reset', array[
+    'user_password_reset_pkey',
+    'user_password_reset_code_hash_key',
+    'user_password_reset_user_id_idx'
+]);
+select index_is_unique('user_password_reset', 'user_password_reset_code_hash_key');
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
@@ -5,7 +5,7 @@
 -- ============================================================================
 
 begin;
-select plan(301);
+select plan(497);
 
 -- ============================================================================
 -- VARIABLES
@@ -17,6 +17,7 @@ select plan(301);
 \set eventID 'f0050000-0000-0000-0000-000000000004'
 \set groupCategoryID 'f0050000-0000-0000-0000-000000000005'
 \set groupID 'f0050000-0000-0000-0000-000000000006'
+\set priceWindowID 'f0050000-0000-0000-0000-000000000008'
 \set ticketTypeID 'f0050000-0000-0000-0000-000000000007'
 
 -- ============================================================================
@@ -83,74 +84,139 @@ insert into event (
 select has_function('accept_community_team_invitation', array['uuid', 'uuid']::name[]);
 select has_function('accept_event_attendee_invitation', array['uuid', 'uuid']::name[]);
 select has_function('accept_event_invitation_request', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
+select has_function('accept_group_join_request', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('accept_group_ownership_transfer', array['uuid', 'uuid']::name[]);
 select has_function('accept_group_team_invitation', array['uuid', 'uuid']::name[]);
+select has_function('accept_group_team_invitation_link', array['uuid', 'text']::name[]);
 select has_function('accept_session_proposal_co_speaker_invitation', array['uuid', 'uuid']::name[]);
 select has_function('activate_group', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('activate_pre_registered_user_email_password', array['jsonb', 'uuid', 'jsonb']::name[]);
 select has_function('activate_pre_registered_user_external_provider', array['uuid', 'jsonb']::name[]);
 select has_function('add_cfs_submission', array['uuid', 'uuid', 'uuid', 'uuid', 'uuid[]']::name[]);
+select has_function('add_community_domain', array['uuid', 'uuid', 'text']::name[]);
+select has_function('add_community_newsletter_issue', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('add_community_team_member', array['uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('add_event', array['uuid', 'uuid', 'jsonb', 'jsonb']::name[]);
 select has_function('add_event_category', array['uuid', 'uuid', 'jsonb']::name[]);
+select has_function('add_event_comment', array['uuid', 'uuid', 'uuid', 'text', 'uuid']::name[]);
+select has_function('add_event_photo', array['uuid', 'uuid', 'uuid', 'text']::name[]);
+select has_function('add_event_resource', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
 select has_function('add_event_series', array['uuid', 'uuid', 'jsonb', 'jsonb', 'jsonb']::name[]);
+select has_function('add_event_template', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
 select has_function('add_group', array['uuid', 'uuid', 'jsonb']::name[]);
+select has_function('add_group_announcement', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('add_group_category', array['uuid', 'uuid', 'jsonb']::name[]);
+select has_function('add_group_contact_message', array['uuid', 'uuid', 'uuid', 'text', 'text', 'timestamp with time zone', 'integer']::name[]);
+select has_function('add_group_discussion_reply', array['uuid', 'uuid', 'uuid', 'uuid', 'text']::name[]);
+select has_function('add_group_discussion_topic', array['uuid', 'uuid', 'uuid', 'text', 'text']::name[]);
 select has_function('add_group_sponsor', array['uuid', 'uuid', 'jsonb']::name[]);
+select has_function('add_group_sponsor_tier', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('add_group_team_member', array['uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('add_meeting', array['text', 'text', 'text', 'text', 'text', 'uuid', 'uuid', 'timestamp with time zone', 'text']::name[]);
 select has_function('add_region', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('add_session_proposal', array['uuid', 'jsonb']::name[]);
-select has_function('append_meeting_recording_url', array['text', 'text', 'text']::name[]);
+select has_function('add_topic', array['uuid', 'uuid', 'jsonb']::name[]);
+select has_function('add_user_google_calendar', array['uuid', 'text']::name[]);
+select has_function('add_user_ldap_community_roles', array['uuid', 'jsonb']::name[]);
+select has_function('add_user_magic_link', array['text', 'text']::name[]);
+select has_function('add_user_passkey', array['uuid', 'text', 'text', 'jsonb']::name[]);
+select has_function('add_user_password_reset', array['text', 'text']::name[]);
+select has_function('append_meeting_recording_url', array['text', 'text', 'uuid', 'text']::name[]);
+select has_function('approve_event', array['uuid', 'uuid', 'uuid', 'text']::name[]);
+select has_function('approve_event_photo', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
+select has_function('archive_group', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('assign_zoom_host_user', array['uuid', 'uuid', 'timestamp with time zone', 'text[]', 'integer', 'timestamp with time zone', 'timestamp with time zone']::name[]);
 select has_function('attach_checkout_session_to_event_purchase', array['uuid', 'text', 'text', 'text']::name[]);
 select has_function('attend_event', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
+select has_function('ban_group_member', array['uuid', 'uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('cancel_event', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('cancel_event_attendee_attendance', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
 select has_function('cancel_event_attendee_invitation', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
 select has_function('cancel_event_checkout', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('cancel_event_series_events', array['uuid', 'uuid', 'uuid[]']::name[]);
+select has_function('cancel_group_ownership_transfer', array['uuid', 'uuid']::name[]);
 select has_function('check_in_event', array['uuid', 'uuid', 'uuid', 'boolean']::name[]);
 select has_function('claim_event_purchase_refund', array['text']::name[]);
+select has_function('claim_google_calendar_entry_out_of_sync', '{}'::name[]);
+select has_function('claim_meeting_for_attendance_sync', '{}'::name[]);
 select has_function('claim_meeting_for_auto_end', '{}'::name[]);
-select has_function('claim_meeting_out_of_sync', '{}'::name[]);
+select has_function('claim_meeting_for_recording_sync', '{}'::name[]);
+select has_function('claim_meeting_out_of_sync', array['text[]']::name[]);
 select has_function('claim_pending_notification', array['integer', 'integer']::name[]);
 select has_function(
     'complete_event_purchase_refund_recovery',
     array['uuid', 'uuid', 'uuid', 'text', 'text', 'jsonb']::name[]
 );
+select has_function('community_email_domain_allowed', array['text', 'text']::name[]);
+select has_function('community_passkey_required', array['uuid']::name[]);
 select has_function('complete_free_event_purchase', array['uuid']::name[]);
+select has_function('count_community_newsletter_subscribers', array['uuid']::name[]);
+select has_function('count_group_custom_notifications_since', array['uuid', 'timestamp with time zone']::name[]);
 select has_function('deactivate_group', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_community_domain', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_community_newsletter_issue', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_community_team_member', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_event', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_event_category', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_event_comment', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_event_resource', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_event_series_events', array['uuid', 'uuid', 'uuid[]']::name[]);
+select has_function('delete_event_template', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_group', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_group_announcement', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_group_category', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_group_discussion_reply', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_group_discussion_topic', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_group_sponsor', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_group_sponsor_tier', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_group_team_member', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_group_zoom_credentials', array['uuid', 'uuid']::name[]);
 select has_function('delete_meeting', array['uuid', 'uuid', 'uuid', 'timestamp with time zone', 'text']::name[]);
 select has_function('delete_region', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('delete_session_proposal', array['uuid', 'uuid']::name[]);
-select has_function('enqueue_due_event_reminders', array['text']::name[]);
+select has_function('delete_topic', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('delete_user_google_calendar', array['uuid']::name[]);
+select has_function('delete_user_passkey', array['uuid', 'uuid']::name[]);
+select has_function('disconnect_user_google_calendar', array['uuid']::name[]);
+select has_function('enqueue_community_notification', array['uuid', 'text', 'jsonb']::name[]);
+select has_function('enqueue_due_community_newsletter_batches', array['text', 'integer']::name[]);
+select has_function('enqueue_due_dormant_groups_reports', array['text']::name[]);
+select has_function('enqueue_due_event_attendance_certificates', array['text']::name[]);
+select has_function('enqueue_due_event_follow_ups', array['text']::name[]);
+select has_function('enqueue_due_event_reminders', array['text', 'integer[]']::name[]);
+select has_function('enqueue_due_event_waitlist_promotions', array['text']::name[]);
+select has_function('enqueue_due_weekly_digests', array['text']::name[]);
+select has_function('enqueue_group_notification', array['uuid', 'text', 'jsonb']::name[]);
 select has_function('enqueue_notification', array['text', 'jsonb', 'jsonb', 'uuid[]']::name[]);
 select has_function('enqueue_tracked_custom_notification', array['text', 'jsonb', 'jsonb', 'uuid[]', 'uuid', 'uuid', 'uuid', 'integer', 'text', 'text']::name[]);
 select has_function('ensure_event_is_active', array['uuid', 'uuid']::name[]);
+select has_function('ensure_user_email_domain_allowed', array['uuid', 'uuid']::name[]);
+select has_function('ensure_user_is_not_banned_from_group', array['uuid', 'uuid']::name[]);
 select has_function('escape_ilike_pattern', array['text']::name[]);
 select has_function('expire_event_purchase_for_checkout_session', array['text', 'text']::name[]);
 select has_function('finalize_event_purchase_refund', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('generate_slug', array['integer']::name[]);
 select has_function('generate_slug_from_source', array['text', 'integer']::name[]);
 select has_function('get_cfs_submission_notification_data', array['uuid', 'uuid']::name[]);
+select has_function('get_community_domain', array['uuid', 'uuid']::name[]);
 select has_function('get_community_full', array['uuid']::name[]);
 select has_function('get_community_id_by_name', array['text']::name[]);
+select has_function('get_community_name_by_domain', array['text']::name[]);
 select has_function('get_community_name_by_id', array['uuid']::name[]);
+select has_function('get_community_newsletter_archive_issue', array['uuid', 'uuid']::name[]);
+select has_function('get_community_newsletter_issue', array['uuid', 'uuid']::name[]);
 select has_function('get_community_recently_added_groups', array['uuid']::name[]);
+select has_function('get_community_required_oidc_provider', array['text']::name[]);
+select has_function('get_community_required_oidc_provider_by_id', array['uuid']::name[]);
 select has_function('get_community_site_stats', array['uuid']::name[]);
 select has_function('get_community_stats', array['uuid']::name[]);
 select has_function('get_community_summary', array['uuid']::name[]);
 select has_function('get_community_upcoming_events', array['uuid', 'text[]']::name[]);
 select has_function('get_event_attendance', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('get_event_attendee_by_ticket_code', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('get_event_check_in_code', array['uuid', 'timestamp with time zone']::name[]);
 select has_function('get_event_delete_eligibility', array['uuid', 'uuid']::name[]);
+select has_function('get_event_discussion', array['uuid', 'uuid']::name[]);
 select has_function('get_event_full', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('get_event_full_by_slug', array['uuid', 'text', 'text']::name[]);
 select has_function('get_event_meeting_sync_state_hash', array['uuid']::name[]);
@@ -158,18 +224,33 @@ select has_function('get_event_occupied_seat_count', array['uuid']::name[]);
 select has_function('get_event_purchase_refund', array['uuid']::name[]);
 select has_function('get_event_purchase_refund_recovery_context', array['uuid', 'uuid']::name[]);
 select has_function('get_event_registration_questions', array['uuid', 'uuid']::name[]);
+select has_function('get_event_reminder_preference_offset', array['text']::name[]);
+select has_function('get_event_resource', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('get_event_series', array['uuid', 'uuid']::name[]);
 select has_function('get_event_summary', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('get_event_summary_by_id', array['uuid', 'uuid']::name[]);
 select has_function('get_event_summary_dashboard', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('get_event_template_details', array['uuid', 'uuid']::name[]);
 select has_function('get_event_ticket_capacity', array['jsonb']::name[]);
+select has_function('get_event_ticket_code', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('get_filters_options', array['text', 'text']::name[]);
+select has_function('get_group_discussion', array['uuid', 'uuid']::name[]);
+select has_function('get_group_engagement_stats', array['uuid', 'uuid', 'boolean']::name[]);
 select has_function('get_group_full', array['uuid', 'uuid']::name[]);
 select has_function('get_group_full_by_slug', array['uuid', 'text']::name[]);
+select has_function('get_group_join_questions', array['uuid', 'uuid']::name[]);
+select has_function('get_group_membership_status', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('get_group_ownership', array['uuid']::name[]);
 select has_function('get_group_past_events', array['uuid', 'text', 'text[]', 'integer']::name[]);
 select has_function('get_group_sponsor', array['uuid', 'uuid']::name[]);
 select has_function('get_group_stats', array['uuid', 'uuid', 'boolean']::name[]);
 select has_function('get_group_summary', array['uuid', 'uuid']::name[]);
 select has_function('get_group_upcoming_events', array['uuid', 'text', 'text[]', 'integer']::name[]);
+select has_function('get_group_zoom_account', array['uuid']::name[]);
+select has_function('get_group_zoom_credentials', array['uuid']::name[]);
+select has_function('get_hosted_event_group', array['uuid', 'uuid']::name[]);
+select has_function('get_merged_group_slug', array['uuid', 'text']::name[]);
+select has_function('get_notification_community_id', array['uuid']::name[]);
 select has_function('get_public_user_provider', array['jsonb']::name[]);
 select has_function('group_has_active_subgroups', array['uuid', 'uuid']::name[]);
 select has_function('group_has_child_links', array['uuid', 'uuid']::name[]);
@@ -179,6 +260,8 @@ select has_function('get_site_recently_added_groups', '{}'::name[]);
 select has_function('get_site_settings', '{}'::name[]);
 select has_function('get_site_stats', '{}'::name[]);
 select has_function('get_site_upcoming_events', array['text[]']::name[]);
+select has_function('get_speaker_profile', array['uuid', 'text']::name[]);
+select has_function('get_user_attendance_certificate', array['uuid', 'uuid']::name[]);
 select has_function('get_user_by_email', array['text']::name[]);
 select has_function('get_user_by_email_for_external_auth', array['text']::name[]);
 select has_function('get_user_by_id', array['uuid', 'boolean']::name[]);
@@ -187,20 +270,33 @@ select has_function(
     'get_user_by_linuxfoundation_identity_for_external_auth',
     array['text', 'text']::name[]
 );
+select has_function(
+    'get_user_by_oauth2_identity_for_external_auth',
+    array['text', 'text']::name[]
+);
 select has_function('get_user_by_username', array['text']::name[]);
+select has_function('get_user_calendar_events', array['text']::name[]);
+select has_function('get_user_group_attendance_record', array['uuid', 'uuid']::name[]);
+select has_function('get_user_passkeys', array['uuid']::name[]);
 select has_function('i_array_to_string', array['text[]', 'text']::name[]);
 select has_function('insert_audit_log', array['text', 'uuid', 'text', 'uuid', 'uuid', 'uuid', 'uuid', 'jsonb']::name[]);
 select has_function('invite_event_attendee', array['uuid', 'uuid', 'uuid', 'uuid', 'text']::name[]);
+select has_function('invite_group_team_member', array['uuid', 'uuid', 'text', 'text', 'text']::name[]);
+select has_function('is_community_newsletter_subscriber', array['uuid', 'uuid']::name[]);
+select has_function('is_event_check_in_code_valid', array['uuid', 'uuid', 'text']::name[]);
 select has_function('is_event_check_in_window_open', array['uuid', 'uuid']::name[]);
 select has_function('is_event_meeting_in_sync', array['jsonb', 'jsonb']::name[]);
+select has_function('is_event_reminder_due', array['uuid', 'timestamp with time zone', 'timestamp with time zone', 'integer']::name[]);
 select has_function('is_group_member', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('is_log_in_locked', array['text', 'inet']::name[]);
 select has_function('is_open_graph_image', array['text']::name[]);
 select has_function(
     'is_registration_window_open',
     array['timestamp with time zone', 'timestamp with time zone', 'timestamp with time zone']::name[]
 );
 select has_function('is_session_meeting_in_sync', array['jsonb', 'jsonb', 'jsonb', 'jsonb']::name[]);
-select has_function('join_group', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('is_user_google_calendar_connected', array['uuid']::name[]);
+select has_function('join_group', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
 select has_function('jsonb_geography_point', array['jsonb']::name[]);
 select has_function('jsonb_text_array', array['jsonb']::name[]);
 select has_function('leave_event', array['uuid', 'uuid', 'uuid']::name[]);
@@ -208,29 +304,53 @@ select has_function('leave_group', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('list_cfs_submission_statuses_for_review', '{}'::name[]);
 select has_function('list_communities', '{}'::name[]);
 select has_function('list_community_audit_logs', array['uuid', 'jsonb']::name[]);
+select has_function('list_community_custom_notifications', array['uuid', 'jsonb']::name[]);
+select has_function('list_community_domains', array['uuid']::name[]);
+select has_function('list_community_failed_notifications', array['uuid', 'jsonb']::name[]);
+select has_function('list_community_group_bans', array['uuid']::name[]);
+select has_function('list_community_newsletter_archive', array['uuid']::name[]);
+select has_function('list_community_newsletter_issues', array['uuid']::name[]);
+select has_function('list_community_pending_events', array['uuid']::name[]);
 select has_function('list_community_roles', '{}'::name[]);
 select has_function('list_community_team_members', array['uuid', 'jsonb']::name[]);
 select has_function('list_event_approved_cfs_submissions', array['uuid']::name[]);
+select has_function('list_event_attendee_badges', array['uuid', 'uuid']::name[]);
 select has_function('list_event_attendees_ids', array['uuid', 'uuid']::name[]);
 select has_function('list_event_categories', array['uuid']::name[]);
 select has_function('list_event_cfs_labels', array['uuid']::name[]);
 select has_function('list_event_cfs_submissions', array['uuid', 'jsonb']::name[]);
+select has_function('list_event_conflicts', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('list_event_discount_codes', array['uuid']::name[]);
 select has_function('list_event_kinds', '{}'::name[]);
+select has_function('list_event_photos', array['uuid', 'uuid']::name[]);
+select has_function('list_event_resources', array['uuid']::name[]);
 select has_function('list_event_series_cancelable_event_ids', array['uuid', 'uuid']::name[]);
 select has_function('list_event_series_event_ids', array['uuid', 'uuid']::name[]);
 select has_function('list_event_series_publishable_event_ids', array['uuid', 'uuid']::name[]);
+select has_function('list_event_templates', array['uuid']::name[]);
 select has_function('list_event_ticket_types', array['uuid']::name[]);
 select has_function('list_event_waitlist_ids', array['uuid', 'uuid']::name[]);
+select has_function('list_group_announcements', array['uuid']::name[]);
 select has_function('list_group_audit_logs', array['uuid', 'jsonb']::name[]);
+select has_function('list_group_bans', array['uuid']::name[]);
+select has_function('list_group_custom_notifications', array['uuid', 'jsonb']::name[]);
 select has_function('list_group_categories', array['uuid']::name[]);
 select has_function('list_group_events', array['uuid', 'jsonb']::name[]);
+select has_function('list_group_gallery_photos', array['uuid', 'boolean']::name[]);
+select has_function('list_group_join_requests', array['uuid']::name[]);
+select has_function('list_group_member_leaderboard', array['uuid']::name[]);
+select has_function('list_group_member_roles', array['uuid']::name[]);
 select has_function('list_group_members', array['uuid', 'jsonb']::name[]);
+select has_function('list_group_members_export', array['uuid']::name[]);
 select has_function('list_group_members_ids', array['uuid']::name[]);
+select has_function('list_group_merge_targets', array['uuid', 'uuid']::name[]);
 select has_function('list_group_parent_options', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('list_group_pinned_announcements', array['uuid', 'uuid']::name[]);
 select has_function('list_group_refunds', array['uuid', 'jsonb']::name[]);
 select has_function('list_group_roles', '{}'::name[]);
+select has_function('list_group_sponsor_tiers', array['uuid']::name[]);
 select has_function('list_group_sponsors', array['uuid', 'jsonb', 'boolean']::name[]);
+select has_function('link_user_provider', array['uuid', 'jsonb']::name[]);
 select has_function('list_group_team_members', array['uuid', 'jsonb']::name[]);
 select has_function('list_group_team_members_ids', array['uuid']::name[]);
 select has_function('list_payment_currency_codes', '{}'::name[]);
@@ -239,20 +359,28 @@ select has_function('list_redirects', '{}'::name[]);
 select has_function('list_regions', array['uuid']::name[]);
 select has_function('list_session_kinds', '{}'::name[]);
 select has_function('list_session_proposal_levels', '{}'::name[]);
+select has_function('list_topics', array['uuid']::name[]);
+select has_function('list_user_attendance_certificates', array['uuid']::name[]);
 select has_function('list_user_audit_logs', array['uuid', 'jsonb']::name[]);
 select has_function('list_user_cfs_submissions', array['uuid', 'jsonb']::name[]);
 select has_function('list_user_communities', array['uuid']::name[]);
 select has_function('list_user_community_team_invitations', array['uuid']::name[]);
 select has_function('list_user_event_invitations', array['uuid']::name[]);
 select has_function('list_user_events', array['uuid', 'jsonb']::name[]);
+select has_function('list_user_group_ownership_transfers', array['uuid']::name[]);
 select has_function('list_user_group_team_invitations', array['uuid']::name[]);
 select has_function('list_user_groups', array['uuid']::name[]);
+select has_function('list_user_passkeys', array['uuid']::name[]);
 select has_function('list_user_pending_session_proposal_co_speaker_invitations', array['uuid']::name[]);
 select has_function('list_user_session_proposals', array['uuid', 'jsonb']::name[]);
 select has_function('list_user_session_proposals_for_cfs_event', array['uuid', 'uuid']::name[]);
+select has_function('list_user_sessions', array['uuid', 'text']::name[]);
+select has_function('list_user_suggested_events', array['uuid']::name[]);
+select has_function('list_zoom_webhook_secret_tokens', array['text']::name[]);
 select has_function('lock_events_for_cancellation', array['uuid', 'uuid[]']::name[]);
 select has_function('manual_check_in_event', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
 select has_function('manual_requeue_notifications', array['uuid[]', 'text']::name[]);
+select has_function('mark_email_undeliverable', array['text', 'text']::name[]);
 select has_function(
     'mark_notification_delivery_unknown',
     array['uuid', 'text', 'timestamp with time zone']::name[]
@@ -260,6 +388,7 @@ select has_function(
 select has_function('mark_stale_meeting_auto_end_checks_unknown', array['bigint']::name[]);
 select has_function('mark_stale_meeting_syncs_unknown', array['bigint']::name[]);
 select has_function('mark_stale_processing_notifications_unknown', array['bigint']::name[]);
+select has_function('merge_groups', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
 select has_function('prepare_event_checkout_expire_previous_hold', array['uuid']::name[]);
 select has_function('prepare_event_checkout_expire_stale_holds', array['uuid']::name[]);
 select has_function('prepare_event_checkout_find_existing_purchase', array['uuid', 'uuid', 'uuid', 'text']::name[]);
@@ -270,6 +399,7 @@ select has_function('prepare_event_checkout_validate_and_resolve_pricing', array
 select has_function('prepare_event_checkout_validate_attendee_state', array['uuid', 'uuid']::name[]);
 select has_function('prepare_event_checkout_validate_event', array['uuid', 'uuid', 'text']::name[]);
 select has_function('promote_event_waitlist', array['uuid', 'integer']::name[]);
+select has_function('publish_due_scheduled_event', array['text']::name[]);
 select has_function('publish_event', array['uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('publish_event_series_events', array['uuid', 'uuid', 'uuid[]', 'text']::name[]);
 select has_function('questionnaire_answers_exist_for_event', array['uuid']::name[]);
@@ -294,77 +424,133 @@ select has_function(
     'record_event_purchase_refund_terminal_failed',
     array['uuid', 'text', 'text', 'text', 'uuid']::name[]
 );
+select has_function('redeem_user_magic_link', array['text']::name[]);
+select has_function('register_failed_log_in', array['text', 'inet']::name[]);
 select has_function('refund_free_event_purchase', array['uuid']::name[]);
 select has_function('reject_community_team_invitation', array['uuid', 'uuid']::name[]);
+select has_function('reject_event', array['uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('reject_event_attendee_invitation', array['uuid', 'uuid']::name[]);
 select has_function('reject_event_invitation_request', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
+select has_function('reject_event_photo', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
 select has_function('reject_event_refund_request', array['uuid', 'uuid', 'uuid', 'text']::name[]);
+select has_function('reject_group_join_request', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('reject_group_ownership_transfer', array['uuid', 'uuid']::name[]);
 select has_function('reject_group_team_invitation', array['uuid', 'uuid']::name[]);
 select has_function('reject_session_proposal_co_speaker_invitation', array['uuid', 'uuid']::name[]);
 select has_function('release_event_checkout_attendee_hold', array['uuid', 'uuid']::name[]);
 select has_function('release_event_discount_code_availability', array['uuid', 'integer']::name[]);
+select has_function('record_meeting_participant_joined', array['text', 'text', 'uuid']::name[]);
+select has_function('release_google_calendar_entry_sync_claim', array['uuid', 'uuid']::name[]);
+select has_function('release_meeting_attendance_sync_claim', array['timestamp with time zone', 'uuid']::name[]);
 select has_function('release_meeting_auto_end_check_claim', array['timestamp with time zone', 'uuid']::name[]);
+select has_function('release_meeting_recording_sync_claim', array['timestamp with time zone', 'uuid']::name[]);
 select has_function('release_meeting_sync_claim', array['uuid', 'uuid', 'uuid', 'timestamp with time zone']::name[]);
+select has_function('remove_event_waitlist_user', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
+select has_function('request_group_ownership_transfer', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('request_event_refund', array['uuid', 'uuid', 'uuid', 'text', 'jsonb']::name[]);
+select has_function('requeue_community_notification', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('requeue_event_purchase_refund', array['uuid', 'uuid']::name[]);
 select has_function(
     'requeue_notification',
     array['uuid', 'text', 'bigint', 'bigint', 'integer', 'timestamp with time zone']::name[]
 );
 select has_function('requeue_stale_event_purchase_refund_claims', '{}'::name[]);
+select has_function('resend_email_verification', array['text', 'uuid', 'jsonb']::name[]);
+select has_function('reset_failed_log_ins', array['text']::name[]);
+select has_function('reset_user_password', array['text', 'text']::name[]);
 select has_function('resolve_event_custom_notification_recipient_ids', array['uuid', 'uuid', 'text', 'uuid[]']::name[]);
+select has_function('resolve_group_custom_notification_recipient_ids', array['uuid', 'text', 'uuid', 'integer', 'text']::name[]);
 select has_function('resolve_unique_username', array['text', 'uuid']::name[]);
+select has_function('restore_group', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('resubmit_cfs_submission', array['uuid', 'uuid']::name[]);
+select has_function('revoke_user_session', array['uuid', 'uuid']::name[]);
+select has_function('revoke_user_sessions', array['uuid']::name[]);
 select has_function('search_event_attendees', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('search_event_invitation_requests', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('search_event_waitlist', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('search_events', array['jsonb']::name[]);
 select has_function('search_groups', array['jsonb']::name[]);
 select has_function('search_user', array['text']::name[]);
+select has_function('send_community_newsletter_issue', array['uuid', 'uuid', 'uuid']::name[]);
+select has_function('set_google_calendar_entry_synced', array['uuid', 'uuid', 'text', 'bigint']::name[]);
+select has_function('set_meeting_attendance_synced', array['timestamp with time zone', 'uuid', 'uuid', 'text[]']::name[]);
 select has_function('set_meeting_auto_end_check_outcome', array['timestamp with time zone', 'uuid', 'text']::name[]);
 select has_function('set_meeting_error', array['text', 'uuid', 'uuid', 'uuid', 'timestamp with time zone', 'text']::name[]);
+select has_function('set_meeting_recordings_synced', array['timestamp with time zone', 'uuid', 'text[]']::name[]);
 select has_function('sign_up_user', array['jsonb', 'boolean', 'uuid', 'jsonb']::name[]);
 select has_function('stats_label_count_series', array['jsonb']::name[]);
 select has_function('stats_label_count_series_by_name', array['jsonb']::name[]);
 select has_function('stats_running_total_series', array['jsonb']::name[]);
 select has_function('stats_running_total_series_by_name', array['jsonb']::name[]);
 select has_function('submit_event_registration_answers', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
+select has_function('subscribe_community_newsletter', array['uuid', 'uuid']::name[]);
 select has_function('sync_cfs_submission_labels', array['uuid', 'uuid', 'uuid[]']::name[]);
 select has_function('sync_event_cfs_labels', array['uuid', 'jsonb']::name[]);
 select has_function('sync_event_discount_codes', array['uuid', 'jsonb']::name[]);
 select has_function('sync_event_hosts_speakers_sponsors', array['uuid', 'jsonb']::name[]);
 select has_function('sync_event_sessions', array['uuid', 'jsonb', 'jsonb']::name[]);
 select has_function('sync_event_ticket_types', array['uuid', 'jsonb']::name[]);
+select has_function('sync_event_topics', array['uuid', 'jsonb']::name[]);
+select has_function('sync_group_topics', array['uuid', 'jsonb']::name[]);
 select has_function('track_custom_notification', array['uuid', 'uuid', 'uuid', 'integer', 'text', 'text']::name[]);
+select has_function('unban_group_member', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
+select has_function('unlink_user_provider', array['uuid', 'text']::name[]);
 select has_function('unpublish_event', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('unpublish_event_series_events', array['uuid', 'uuid', 'uuid[]']::name[]);
+select has_function('unsubscribe_community_newsletter', array['uuid', 'uuid']::name[]);
 select has_function('update_cfs_submission', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
 select has_function('update_community', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('update_community_team_member_role', array['uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('update_community_views', array['jsonb']::name[]);
+select has_function('update_dormant_groups', '{}'::name[]);
 select has_function('update_event', array['uuid', 'uuid', 'uuid', 'jsonb', 'jsonb']::name[]);
 select has_function('update_event_category', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
+select has_function('update_event_comment_pinned', array['uuid', 'uuid', 'uuid', 'uuid', 'boolean']::name[]);
+select has_function('update_event_comments_locked', array['uuid', 'uuid', 'uuid', 'boolean']::name[]);
+select has_function('update_event_reminder_preference', array['uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('update_event_views', array['jsonb']::name[]);
 select has_function('update_group', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
+select has_function('update_group_announcement_pinned', array['uuid', 'uuid', 'uuid', 'boolean']::name[]);
 select has_function('update_group_category', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
+select has_function('update_group_discord_webhook', array['uuid', 'uuid', 'text']::name[]);
+select has_function('update_group_discussion_topic_locked', array['uuid', 'uuid', 'uuid', 'boolean']::name[]);
+select has_function('update_group_discussion_topic_pinned', array['uuid', 'uuid', 'uuid', 'boolean']::name[]);
+select has_function('update_group_gallery_cover', array['uuid', 'uuid', 'text']::name[]);
+select has_function('update_group_gallery_photo_hidden', array['uuid', 'uuid', 'text', 'boolean']::name[]);
+select has_function('update_group_gallery_photos', array['uuid', 'uuid', 'jsonb']::name[]);
+select has_function('update_group_member_role', array['uuid', 'uuid', 'uuid', 'text']::name[]);
+select has_function('update_group_owner', array['uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('update_group_sponsor', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
 select has_function('update_group_sponsor_featured', array['uuid', 'uuid', 'uuid', 'boolean']::name[]);
+select has_function('update_group_sponsor_tier', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
 select has_function('update_group_team_member_role', array['uuid', 'uuid', 'uuid', 'text']::name[]);
 select has_function('update_group_views', array['jsonb']::name[]);
+select has_function('update_group_join_questions', array['uuid', 'uuid', 'jsonb']::name[]);
+select has_function('update_group_welcome_message', array['uuid', 'uuid', 'text']::name[]);
+select has_function('update_group_zoom_credentials', array['uuid', 'uuid', 'jsonb']::name[]);
 select has_function('update_meeting', array['uuid', 'text', 'text', 'text', 'uuid', 'uuid', 'timestamp with time zone', 'text']::name[]);
+select has_function('update_meeting_provider_status', array['text', 'text', 'uuid', 'text', 'timestamp with time zone']::name[]);
 select has_function(
     'update_notification',
     array['uuid', 'text', 'timestamp with time zone']::name[]
 );
 select has_function('update_region', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
 select has_function('update_session_proposal', array['uuid', 'uuid', 'jsonb']::name[]);
+select has_function('update_topic', array['uuid', 'uuid', 'uuid', 'jsonb']::name[]);
+select has_function('update_user_calendar_feed', array['uuid', 'boolean']::name[]);
 select has_function('update_user_details', array['uuid', 'jsonb']::name[]);
 select has_function('update_user_external_auth', array['uuid', 'jsonb']::name[]);
+select has_function('update_user_passkey', array['uuid', 'text', 'jsonb']::name[]);
 select has_function('update_user_password', array['uuid', 'text']::name[]);
+select has_function('update_user_phone_number', array['uuid', 'text']::name[]);
 select has_function('update_user_provider', array['uuid', 'jsonb']::name[]);
+select has_function('update_user_sms_notifications', array['uuid', 'boolean']::name[]);
+select has_function('update_user_webhook', array['uuid', 'text']::name[]);
 select has_function('upsert_pending_registration_answers', array['uuid', 'uuid', 'jsonb', 'jsonb']::name[]);
+select has_function('user_can_view_group_members', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('user_has_community_permission', array['uuid', 'uuid', 'text']::name[]);
 select has_function('user_has_group_permission', array['uuid', 'uuid', 'uuid', 'text']::name[]);
+select has_function('user_is_event_host', array['uuid', 'uuid', 'uuid', 'uuid']::name[]);
 select has_function('validate_add_event_dates', array['jsonb']::name[]);
 select has_function('validate_cfs_submission_label_ids', array['uuid', 'uuid[]']::name[]);
 select has_function('validate_event_capacity', array['jsonb', 'jsonb', 'uuid', 'integer']::name[]);
@@ -379,7 +565,9 @@ select has_function('validate_payment_currency_code', array['text']::name[]);
 select has_function('validate_questionnaire_answers_payload', array['jsonb', 'jsonb']::name[]);
 select has_function('validate_questionnaire_questions_payload', array['jsonb']::name[]);
 select has_function('validate_update_event_dates', array['jsonb', 'jsonb']::name[]);
+select has_function('verify_community_domain', array['uuid', 'uuid', 'uuid']::name[]);
 select has_function('verify_email', array['uuid']::name[]);
+select has_function('verify_user_phone_number', array['uuid', 'text']::name[]);
 select has_function('withdraw_cfs_submission', array['uuid', 'uuid']::name[]);
 
 -- Test: check expected trigger functions exist
@@ -393,23 +581,33 @@ select has_function('check_group_parent_relationship', '{}'::name[]);
 select has_function('check_group_region_community', '{}'::name[]);
 select has_function('check_session_cfs_submission_approved', '{}'::name[]);
 select has_function('check_session_within_event_bounds', '{}'::name[]);
+select has_function('mark_google_calendar_attendee_out_of_sync', '{}'::name[]);
+select has_function('mark_google_calendar_event_out_of_sync', '{}'::name[]);
+select has_function('notify_google_calendar_entry_out_of_sync', '{}'::name[]);
+select has_function('notify_meeting_out_of_sync', '{}'::name[]);
 select has_function('prevent_audit_log_mutation', '{}'::name[]);
 select has_function('validate_group_slug_pretty', '{}'::name[]);
 
 -- Test: check expected triggers exist
 select has_trigger('audit_log', 'audit_log_mutation_guard');
+select has_trigger('event_attendee', 'event_attendee_google_calendar_out_of_sync');
 select has_trigger('event_attendee', 'event_attendee_waitlist_check');
 select has_trigger('event', 'event_category_community_check');
+select has_trigger('event', 'event_google_calendar_out_of_sync');
+select has_trigger('event', 'event_meeting_out_of_sync_notify');
 select has_trigger('event', 'event_ticketing_consistency_on_event');
 select has_trigger('event_discount_code', 'event_ticketing_consistency_on_event_discount_code');
 select has_trigger('event_sponsor', 'event_sponsor_group_check');
+select has_trigger('event_ticket_price_window', 'event_ticketing_consistency_on_event_ticket_price_window');
 select has_trigger('event_ticket_type', 'event_ticketing_consistency_on_event_ticket_type');
 select has_trigger('event_waitlist', 'event_waitlist_attendee_check');
+select has_trigger('google_calendar_entry', 'google_calendar_entry_out_of_sync_notify');
 select has_trigger('group', 'group_category_community_check');
 select has_trigger('group', 'group_parent_relationship_check');
 select has_trigger('group', 'group_region_community_check');
 select has_trigger('group', 'group_slug_pretty_validate');
 select has_trigger('session', 'session_cfs_submission_approved_check');
+select has_trigger('session', 'session_meeting_out_of_sync_notify');
 select has_trigger('session', 'session_within_event_bounds_check');
 
 -- Test: event ticketing consistency triggers should enforce the ticketing shape
@@ -417,19 +615,10 @@ select has_trigger('session', 'session_within_event_bounds_check');
 set constraints
     event_ticketing_consistency_on_event,
     event_ticketing_consistency_on_event_discount_code,
+    event_ticketing_consistency_on_event_ticket_price_window,
     event_ticketing_consistency_on_event_ticket_type
     immediate;
 
--- Should reject ticket types when the event has no payment currency
-select throws_ok(
-    format($$
-        insert into event_ticket_type (event_ticket_type_id, event_id, "order", seats_total, title)
-        values (%L, %L, 1, 10, 'General')
-    $$, :'ticketTypeID', :'eventID'),
-    'ticketed events require payment_currency_code',
-    'Ticket types should require a payment currency on the event'
-);
-
 -- Should reject discount codes when the event has no ticket types
 select throws_ok(
     format($$
@@ -451,7 +640,26 @@ select throws_ok(
     'Payment currency should require ticket types on the event'
 );
 
--- Should accept a payment currency and a ticket type written together
+-- Should accept free ticket types when the event has no payment currency
+select lives_ok(
+    format($$
+        insert into event_ticket_type (event_ticket_type_id, event_id, "order", seats_total, title)
+        values (%L, %L, 1, 10, 'General')
+    $$, :'ticketTypeID', :'eventID'),
+    'Free ticket types should not require a payment currency on the event'
+);
+
+-- Should reject paid price windows when the event has no payment currency
+select throws_ok(
+    format($$
+        insert into event_ticket_price_window (event_ticket_price_window_id, event_ticket_type_id, amount_minor)
+        values (%L, %L, 500)
+    $$, :'priceWindowID', :'ticketTypeID'),
+    'paid ticket types require payment_currency_code',
+    'Paid price windows should require a payment currency on the event'
+);
+
+-- Should accept a payment currency and a paid price window written together
 select lives_ok(
     format($$
         with currency as (
@@ -459,10 +667,10 @@ select lives_ok(
             set payment_currency_code = 'USD'
             where event_id = %L
         )
-        insert into event_ticket_type (event_ticket_type_id, event_id, "order", seats_total, title)
-        values (%L, %L, 1, 10, 'General')
-    $$, :'eventID', :'ticketTypeID', :'eventID'),
-    'Payment currency and ticket types written together should be accepted'
+        insert into event_ticket_price_window (event_ticket_price_window_id, event_ticket_type_id, amount_minor)
+        values (%L, %L, 500)
+    $$, :'eventID', :'priceWindowID', :'ticketTypeID'),
+    'Payment currency and paid price windows written together should be accepted'
 );
 
 -- Should reject removing the last ticket type while the payment currency remains
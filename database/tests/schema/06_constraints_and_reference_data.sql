@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(69);
+select plan(94);
 
 -- ============================================================================
 -- VARIABLES
@@ -21,8 +21,15 @@ select plan(69);
 select has_check('custom_notification');
 
 -- Test: community table expected constraints exist
+select has_check('community', 'community_dormant_group_months_chk');
 select has_check('community', 'community_og_image_url_check');
 
+-- Test: community domain table expected constraints exist
+select has_check('community_domain');
+
+-- Test: community newsletter issue table expected constraints exist
+select has_check('community_newsletter_issue');
+
 -- Test: community redirect settings table expected constraints exist
 select has_check(
     'community_redirect_settings',
@@ -156,6 +163,7 @@ select has_check('event', 'event_meeting_conflict_chk');
 select has_check('event', 'event_meeting_kind_chk');
 select has_check('event', 'event_meeting_provider_required_chk');
 select has_check('event', 'event_meeting_requested_times_chk');
+select has_check('event', 'event_no_show_waitlist_threshold_check');
 select has_check('event', 'event_registration_end_before_event_start_chk');
 select has_check('event', 'event_registration_start_before_event_start_chk');
 select has_check('event', 'event_registration_window_order_chk');
@@ -164,6 +172,40 @@ select has_check('event', 'event_waitlist_capacity_required_chk');
 -- Test: event invitation request table expected constraints exist
 select has_check('event_invitation_request');
 
+-- Test: group member table expected constraints exist
+select has_check('group_member', 'group_member_member_role_chk');
+
+-- Test: group announcement table expected constraints exist
+select has_check('group_announcement');
+
+-- Test: group ban table expected constraints exist
+select has_check('group_ban');
+
+-- Test: group contact message table expected constraints exist
+select has_check('group_contact_message');
+
+-- Test: group discussion tables expected constraints exist
+select has_check('group_discussion_reply');
+select has_check('group_discussion_topic');
+
+-- Test: group gallery hidden photo table expected constraints exist
+select has_check('group_gallery_hidden_photo');
+
+-- Test: group sponsor tier table expected constraints exist
+select has_check('group_sponsor_tier');
+
+-- Test: group join request table expected constraints exist
+select has_check('group_join_request');
+
+-- Test: event comment table expected constraints exist
+select has_check('event_comment');
+
+-- Test: event photo table expected constraints exist
+select has_check('event_photo');
+
+-- Test: event template table expected constraints exist
+select has_check('event_template');
+
 -- Test: event discount code table expected constraints exist
 select has_check('event_discount_code', 'event_discount_code_kind_value_chk');
 select has_check('event_discount_code', 'event_discount_code_window_chk');
@@ -185,8 +227,15 @@ select has_check('session', 'session_meeting_conflict_chk');
 select has_check('session', 'session_meeting_provider_required_chk');
 select has_check('session', 'session_meeting_requested_times_chk');
 
+-- Test: user table expected constraints exist
+select has_check('user', 'user_email_undeliverable_reason_chk');
+select has_check('user', 'user_phone_number_verified_chk');
+select has_check('user', 'user_sms_notifications_enabled_chk');
+select has_check('user', 'user_webhook_secret_chk');
+
 -- Test: event attendee statuses should match expected values
 select has_check('event_attendee', 'event_attendee_attendance_canceled_chk');
+select has_check('event_attendee', 'event_attendee_waitlist_confirmation_chk');
 select results_eq(
     $$
         select (regexp_matches(pg_get_constraintdef(oid), $re$'([^']+)'$re$, 'g'))[1]
@@ -199,7 +248,8 @@ select results_eq(
         ('invitation-canceled'),
         ('invitation-pending'),
         ('invitation-rejected'),
-        ('registration-questions-pending')
+        ('registration-questions-pending'),
+        ('waitlist-confirmation-pending')
     $$,
     'Event attendee statuses should match expected values'
 );
@@ -338,21 +388,40 @@ select results_eq(
 );
 
 -- Test: meeting table expected constraints exist
+select has_check('meeting', 'meeting_provider_status_pair_chk');
 select has_check('meeting', 'meeting_recording_urls_not_empty_chk');
 
 -- Test: meeting providers should match expected values
 select results_eq(
     'select * from meeting_provider order by meeting_provider_id',
     $$ values
+        ('bigbluebutton', 'BigBlueButton'),
+        ('google_meet', 'Google Meet'),
+        ('jitsi', 'Jitsi'),
+        ('teams', 'Microsoft Teams'),
+        ('webex', 'Cisco Webex'),
         ('zoom', 'Zoom')
     $$,
     'Meeting providers should exist'
 );
 
+-- Test: meeting provider statuses should match expected values
+select results_eq(
+    'select * from meeting_provider_status order by meeting_provider_status_id',
+    $$ values
+        ('deleted', 'Deleted'),
+        ('ended', 'Ended'),
+        ('started', 'Started')
+    $$,
+    'Meeting provider statuses should exist'
+);
+
 -- Test: notification table expected constraints exist
+select has_check('notification', 'notification_channel_chk');
 select has_check('notification', 'notification_delivery_attempts_chk');
 select has_check('notification', 'notification_delivery_status_chk');
 select has_check('notification', 'notification_next_delivery_attempt_at_chk');
+select has_check('notification', 'notification_recipient_chk');
 
 -- Test: payment providers should match expected values
 select results_eq(
@@ -378,33 +447,49 @@ select results_eq(
 
 -- Test: notification kinds should match expected values
 select results_eq(
-    'select name, optional_notification from notification_kind order by name',
+    '
+    select name, optional_notification, sms_notification, webhook_notification
+    from notification_kind
+    order by name
+    ',
     $$ values
-        ('cfs-submission-updated', false),
-        ('community-team-invitation', false),
-        ('email-verification', false),
-        ('event-attendance-canceled', false),
-        ('event-canceled', false),
-        ('event-custom', true),
-        ('event-invitation', false),
-        ('event-published', true),
-        ('event-refund-approved', false),
-        ('event-refund-rejected', false),
-        ('event-refund-requested', false),
-        ('event-reminder', true),
-        ('event-rescheduled', false),
-        ('event-series-canceled', false),
-        ('event-series-published', true),
-        ('event-waitlist-joined', false),
-        ('event-waitlist-left', false),
-        ('event-waitlist-promoted', false),
-        ('event-welcome', false),
-        ('group-custom', true),
-        ('group-team-invitation', false),
-        ('group-welcome', false),
-        ('session-proposal-co-speaker-invitation', false),
-        ('speaker-series-welcome', false),
-        ('speaker-welcome', false)
+        ('cfs-submission-updated', false, false, false),
+        ('community-newsletter', true, false, false),
+        ('community-team-invitation', false, false, false),
+        ('dormant-groups-report', false, false, false),
+        ('email-verification', false, false, false),
+        ('event-approved', false, false, false),
+        ('event-attendance-canceled', false, false, false),
+        ('event-attendance-certificate', true, false, false),
+        ('event-canceled', false, false, true),
+        ('event-custom', true, false, false),
+        ('event-follow-up', true, false, false),
+        ('event-invitation', false, false, false),
+        ('event-invitation-request-rejected', false, false, false),
+        ('event-published', true, false, true),
+        ('event-refund-approved', false, false, false),
+        ('event-refund-rejected', false, false, false),
+        ('event-refund-requested', false, false, false),
+        ('event-rejected', false, false, false),
+        ('event-reminder', true, true, false),
+        ('event-rescheduled', false, false, false),
+        ('event-series-canceled', false, false, false),
+        ('event-series-published', true, false, false),
+        ('event-waitlist-joined', false, false, false),
+        ('event-waitlist-left', false, false, false),
+        ('event-waitlist-promoted', false, false, false),
+        ('event-welcome', false, false, true),
+        ('group-contact-message', false, false, false),
+        ('group-custom', true, false, false),
+        ('group-team-invitation', false, false, false),
+        ('group-welcome', false, false, true),
+        ('magic-link', false, false, false),
+        ('password-reset', false, false, false),
+        ('phone-verification', false, false, false),
+        ('session-proposal-co-speaker-invitation', false, false, false),
+        ('speaker-series-welcome', false, false, false),
+        ('speaker-welcome', false, false, false),
+        ('weekly-digest', true, false, false)
     $$,
     'Notification kinds should exist'
 );
@@ -493,12 +578,14 @@ select results_eq(
 select results_eq(
     'select community_role_id, group_permission_id from community_role_group_permission order by community_role_id, group_permission_id',
     $$ values
+        ('admin', 'group.check-in.write'),
         ('admin', 'group.events.write'),
         ('admin', 'group.members.write'),
         ('admin', 'group.read'),
         ('admin', 'group.settings.write'),
         ('admin', 'group.sponsors.write'),
         ('admin', 'group.team.write'),
+        ('groups-manager', 'group.check-in.write'),
         ('groups-manager', 'group.events.write'),
         ('groups-manager', 'group.members.write'),
         ('groups-manager', 'group.read'),
@@ -514,6 +601,7 @@ select results_eq(
 select results_eq(
     'select group_permission_id, display_name from group_permission order by group_permission_id',
     $$ values
+        ('group.check-in.write', 'Check-in Write'),
         ('group.events.write', 'Events Write'),
         ('group.members.write', 'Members Write'),
         ('group.read', 'Read'),
@@ -529,6 +617,7 @@ select results_eq(
     'select * from group_role order by group_role_id',
     $$ values
         ('admin', 'Admin'),
+        ('check-in-volunteer', 'Check-in Volunteer'),
         ('events-manager', 'Events Manager'),
         ('viewer', 'Viewer')
     $$,
@@ -539,10 +628,14 @@ select results_eq(
 select results_eq(
     'select group_permission_id, group_role_id from group_role_group_permission order by group_permission_id, group_role_id',
     $$ values
+        ('group.check-in.write', 'admin'),
+        ('group.check-in.write', 'check-in-volunteer'),
+        ('group.check-in.write', 'events-manager'),
         ('group.events.write', 'admin'),
         ('group.events.write', 'events-manager'),
         ('group.members.write', 'admin'),
         ('group.read', 'admin'),
+        ('group.read', 'check-in-volunteer'),
         ('group.read', 'events-manager'),
         ('group.read', 'viewer'),
         ('group.settings.write', 'admin'),
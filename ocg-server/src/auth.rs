@@ -10,20 +10,29 @@ use axum_login::{
     tower_sessions::{self, session, session_store},
 };
 use garde::Validate;
+use ldap3::{LdapConnAsync, Scope, SearchEntry, ldap_escape};
 use oauth2::{TokenResponse, reqwest as oauth2_reqwest};
 use openidconnect::{self as oidc, LocalizedClaim};
 use password_auth::verify_password;
 use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use time::Duration;
+use time::{Duration, OffsetDateTime};
 use tower_sessions::{Expiry, SessionManagerLayer, cookie::SameSite};
 use uuid::Uuid;
+use webauthn_rs::prelude::{
+    PasskeyAuthentication, PublicKeyCredential, Url, Webauthn, WebauthnBuilder,
+};
 
 use crate::{
-    config::{HttpServerConfig, OAuth2Config, OAuth2Provider, OidcConfig, OidcProvider},
+    config::{
+        HttpServerConfig, LdapConfig, LdapGroupRoleMapping, OAuth2Config, OAuth2Provider,
+        OidcConfig, OidcProvider, SessionConfig,
+    },
     db::DynDB,
-    types::user::UserProvider,
+    i18n::Language,
+    types::{event::EventReminderPreference, user::UserProvider},
+    util::compute_hash,
     validation::{
         MAX_LEN_DISPLAY_NAME, MAX_LEN_S, MIN_PASSWORD_LEN, trimmed_non_empty, trimmed_non_empty_opt,
     },
@@ -36,35 +45,66 @@ mod tests;
 pub(crate) const EXTERNAL_AUTH_EMAIL_CONFLICT_ERROR: &str =
     "external auth email belongs to another user";
 
+/// Error raised when an external-auth email domain is not allowed by the
+/// community the user is signing up from.
+pub(crate) const EXTERNAL_AUTH_EMAIL_DOMAIN_NOT_ALLOWED_ERROR: &str =
+    "external auth email domain is not allowed by the community";
+
 /// Error raised when an external-auth identity is already owned by another user.
 pub(crate) const EXTERNAL_AUTH_IDENTITY_CONFLICT_ERROR: &str =
     "external auth identity belongs to another user";
 
+/// Key used to store the lifetime selected when logging in in the session.
+pub(crate) const SESSION_LIFETIME_KEY: &str = "session_lifetime";
+
 /// Type alias for the authentication layer used in the router.
 pub(crate) type AuthLayer = AuthManagerLayer<AuthnBackend, SessionStore>;
 
 /// Setup router authentication/authorization layer.
 pub(crate) async fn setup_layer(cfg: &HttpServerConfig, db: DynDB) -> Result<AuthLayer> {
     // Setup session layer
-    let session_store = SessionStore::new(db.clone());
+    let session_store = SessionStore::new(db.clone(), cfg.session.clone());
     let secure = if let Some(cookie) = &cfg.cookie {
         cookie.secure.unwrap_or(true)
     } else {
         true
     };
     let session_layer = SessionManagerLayer::new(session_store)
-        .with_expiry(Expiry::OnInactivity(Duration::days(7)))
+        .with_expiry(Expiry::OnInactivity(Duration::hours(
+            cfg.session.idle_timeout_hours.into(),
+        )))
         .with_http_only(true)
         .with_same_site(SameSite::Lax)
         .with_secure(secure);
 
+    // Setup passkeys support when enabled
+    let webauthn = if cfg.login.passkey {
+        Some(Arc::new(setup_webauthn(&cfg.base_url)?))
+    } else {
+        None
+    };
+
     // Setup auth layer
-    let authn_backend = AuthnBackend::new(db, &cfg.oauth2, &cfg.oidc).await?;
+    let authn_backend =
+        AuthnBackend::new(db, &cfg.oauth2, &cfg.oidc, webauthn, cfg.ldap.clone()).await?;
     let auth_layer = AuthManagerLayerBuilder::new(authn_backend, session_layer).build();
 
     Ok(auth_layer)
 }
 
+/// Setup the `WebAuthn` relying party used to register and verify passkeys.
+fn setup_webauthn(base_url: &str) -> Result<Webauthn> {
+    let origin = Url::parse(base_url)?;
+    let Some(rp_id) = origin.host_str() else {
+        bail!("base url host missing")
+    };
+    let webauthn = WebauthnBuilder::new(rp_id, &origin)?
+        .rp_name("Open Community Groups")
+        .build()?;
+
+    Ok(webauthn)
+}
+
 // Session store.
 
 /// Store for managing user sessions in the database.
@@ -72,12 +112,26 @@ pub(crate) async fn setup_layer(cfg: &HttpServerConfig, db: DynDB) -> Result<Aut
 pub(crate) struct SessionStore {
     /// Database handle used for session persistence.
     db: DynDB,
+    /// Session lifetime configuration.
+    session_cfg: SessionConfig,
 }
 
 impl SessionStore {
     /// Create a new `SessionStore` with the given database handle.
-    pub fn new(db: DynDB) -> Self {
-        Self { db }
+    pub fn new(db: DynDB, session_cfg: SessionConfig) -> Self {
+        Self { db, session_cfg }
+    }
+
+    /// Apply the lifetime selected when logging in, if any, to the record, so
+    /// that sessions not kept across browser restarts expire on time too.
+    fn apply_lifetime(&self, record: &mut session::Record) {
+        let lifetime = record
+            .data
+            .get(SESSION_LIFETIME_KEY)
+            .and_then(|value| serde_json::from_value::<SessionLifetime>(value.clone()).ok());
+        if let Some(lifetime) = lifetime {
+            record.expiry_date = lifetime.expires_at(&self.session_cfg);
+        }
     }
 
     /// Convert an `anyhow::Error` to a session store error.
@@ -91,6 +145,7 @@ impl SessionStore {
 impl tower_sessions::SessionStore for SessionStore {
     /// Create a new session record in the database.
     async fn create(&self, record: &mut session::Record) -> session_store::Result<()> {
+        self.apply_lifetime(record);
         self.db
             .create_session(record)
             .await
@@ -118,8 +173,10 @@ impl tower_sessions::SessionStore for SessionStore {
 
     /// Save (update) a session record in the database.
     async fn save(&self, record: &session::Record) -> session_store::Result<()> {
+        let mut record = record.clone();
+        self.apply_lifetime(&mut record);
         self.db
-            .update_session(record)
+            .update_session(&record)
             .await
             .map_err(Self::to_session_store_error)
     }
@@ -131,25 +188,76 @@ impl std::fmt::Debug for SessionStore {
     }
 }
 
+/// Lifetime of a session, selected by the user when logging in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SessionLifetime {
+    /// When the user logged in, as a Unix timestamp.
+    pub logged_in_at: i64,
+    /// Whether the session is kept across browser restarts.
+    pub remember: bool,
+}
+
+impl SessionLifetime {
+    /// Create a new `SessionLifetime` for a user logging in now.
+    pub(crate) fn new(remember: bool) -> Self {
+        Self {
+            logged_in_at: OffsetDateTime::now_utc().unix_timestamp(),
+            remember,
+        }
+    }
+
+    /// Returns when the session expires if it is not used again, which is never
+    /// later than the maximum lifetime allowed since logging in.
+    pub(crate) fn expires_at(&self, cfg: &SessionConfig) -> OffsetDateTime {
+        let now = OffsetDateTime::now_utc();
+        let idle_expires_at = now.saturating_add(Duration::hours(cfg.idle_timeout_hours.into()));
+        let max_expires_at = OffsetDateTime::from_unix_timestamp(self.logged_in_at)
+            .unwrap_or(now)
+            .saturating_add(Duration::days(cfg.max_lifetime_days.into()));
+
+        idle_expires_at.min(max_expires_at)
+    }
+
+    /// Returns the expiry of the session cookie. Sessions not remembered use a
+    /// cookie that is discarded when the browser is closed.
+    pub(crate) fn expiry(&self, cfg: &SessionConfig) -> Expiry {
+        if self.remember {
+            Expiry::AtDateTime(self.expires_at(cfg))
+        } else {
+            Expiry::OnSessionEnd
+        }
+    }
+}
+
 // Authentication backend.
 
-/// Backend for authenticating users via `OAuth2`, `Oidc`, or password.
+/// Backend for authenticating users via `OAuth2`, `Oidc`, LDAP, passkey, or password.
 #[derive(Clone)]
 pub(crate) struct AuthnBackend {
     /// Database handle.
     db: DynDB,
     /// HTTP client for making requests to `OAuth2` and `Oidc` providers.
     http_client: oauth2_reqwest::Client,
+    /// LDAP directory configuration, available when LDAP login is enabled.
+    ldap_cfg: Option<LdapConfig>,
     /// Registered `OAuth2` providers.
     pub oauth2_providers: OAuth2Providers,
     /// Registered `Oidc` providers.
     pub oidc_providers: OidcProviders,
+    /// `WebAuthn` relying party, available when passkey login is enabled.
+    pub webauthn: Option<Arc<Webauthn>>,
 }
 
 impl AuthnBackend {
     /// Create a new `AuthnBackend` instance.
     #[allow(unused_mut)]
-    pub async fn new(db: DynDB, oauth2_cfg: &OAuth2Config, oidc_cfg: &OidcConfig) -> Result<Self> {
+    pub async fn new(
+        db: DynDB,
+        oauth2_cfg: &OAuth2Config,
+        oidc_cfg: &OidcConfig,
+        webauthn: Option<Arc<Webauthn>>,
+        ldap_cfg: Option<LdapConfig>,
+    ) -> Result<Self> {
         let mut builder =
             oauth2_reqwest::ClientBuilder::new().redirect(oauth2_reqwest::redirect::Policy::none());
         #[cfg(test)]
@@ -164,61 +272,100 @@ impl AuthnBackend {
         Ok(Self {
             db,
             http_client,
+            ldap_cfg,
             oauth2_providers,
             oidc_providers,
+            webauthn,
         })
     }
 
-    /// Authenticate a user using `OAuth2` credentials.
-    async fn authenticate_oauth2(&self, creds: OAuth2Credentials) -> Result<Option<User>> {
-        // Exchange the authorization code for an access token
-        let Some(oauth2_provider) = self.oauth2_providers.get(&creds.provider) else {
-            bail!("oauth2 provider not found")
+    /// Authenticate a user against the LDAP directory.
+    async fn authenticate_ldap(&self, creds: LdapCredentials) -> Result<Option<User>> {
+        let Some(ldap_cfg) = &self.ldap_cfg else {
+            bail!("ldap login not enabled")
+        };
+
+        // Find the user in the directory, checking their password
+        let Some(entry) = find_ldap_user(ldap_cfg, &creds).await? else {
+            return Ok(None);
         };
-        let access_token = oauth2_provider
-            .client
-            .exchange_code(oauth2::AuthorizationCode::new(creds.code))
-            .request_async(&self.http_client)
-            .await?
-            .access_token()
-            .secret()
-            .clone();
 
         // Get the user if they exist, otherwise sign them up
-        let user_summary = match creds.provider {
-            OAuth2Provider::GitHub => UserSummary::from_github_profile(&access_token).await?,
+        let user_summary = UserSummary::from_ldap_entry(ldap_cfg, &creds.username, &entry)?;
+        let user = self.get_or_sign_up_external_user(&user_summary, None).await?;
+
+        // Grant the community team roles mapped to the user's directory groups
+        let mappings = ldap_group_role_mappings(ldap_cfg, &entry);
+        if !mappings.is_empty() {
+            self.db
+                .add_user_ldap_community_roles(&user.user_id, &mappings)
+                .await?;
+        }
+
+        Ok(Some(user))
+    }
+
+    /// Authenticate a user using a magic link token.
+    async fn authenticate_magic_link(&self, creds: MagicLinkCredentials) -> Result<Option<User>> {
+        // Consume the link, which can only be used once before it expires
+        let token_hash = compute_hash(creds.token.as_bytes());
+        let Some(user_id) = self.db.redeem_user_magic_link(&token_hash).await? else {
+            return Ok(None);
         };
-        let user = self.get_or_sign_up_external_user(&user_summary).await?;
+
+        // Get the user the link was issued to
+        self.db.get_user_by_id(&user_id).await
+    }
+
+    /// Authenticate a user using `OAuth2` credentials.
+    async fn authenticate_oauth2(&self, creds: OAuth2Credentials) -> Result<Option<User>> {
+        // Get the user if they exist, otherwise sign them up
+        let sign_up_community = creds.sign_up_community.clone();
+        let user_summary = self.oauth2_user_summary(creds).await?;
+        let user = self
+            .get_or_sign_up_external_user(&user_summary, sign_up_community.as_deref())
+            .await?;
 
         Ok(Some(user))
     }
 
     /// Authenticate a user using `Oidc` credentials.
     async fn authenticate_oidc(&self, creds: OidcCredentials) -> Result<Option<User>> {
-        // Exchange the authorization code for an access and id token
-        let Some(oidc_provider) = self.oidc_providers.get(&creds.provider) else {
-            bail!("oidc provider not found")
-        };
-        let token_response = oidc_provider
-            .client
-            .exchange_code(oidc::AuthorizationCode::new(creds.code))?
-            .request_async(&self.http_client)
+        // Get the user if they exist, otherwise sign them up
+        let sign_up_community = creds.sign_up_community.clone();
+        let user_summary = self.oidc_user_summary(creds).await?;
+        let user = self
+            .get_or_sign_up_external_user(&user_summary, sign_up_community.as_deref())
             .await?;
 
-        // Extract and verify ID token claims
-        let id_token_verifier = oidc_provider.client.id_token_verifier();
-        let Some(id_token) = token_response.extra_fields().id_token() else {
-            bail!("id token missing")
+        Ok(Some(user))
+    }
+
+    /// Authenticate a user using passkey credentials.
+    async fn authenticate_passkey(&self, creds: PasskeyCredentials) -> Result<Option<User>> {
+        let Some(webauthn) = &self.webauthn else {
+            bail!("passkey login not enabled")
         };
-        let claims = id_token.claims(&id_token_verifier, &creds.nonce)?;
 
-        // Get the user if they exist, otherwise sign them up
-        let user_summary = match creds.provider {
-            OidcProvider::LinuxFoundation => UserSummary::from_oidc_id_token_claims(claims)?,
+        // Verify the assertion against the challenge issued for the user
+        let Ok(result) = webauthn.finish_passkey_authentication(&creds.credential, &creds.state)
+        else {
+            return Ok(None);
         };
-        let user = self.get_or_sign_up_external_user(&user_summary).await?;
 
-        Ok(Some(user))
+        // Persist the updated passkey state (i.e. its signature counter)
+        let mut passkeys = self.db.get_user_passkeys(&creds.user_id).await?;
+        let Some(passkey) = passkeys
+            .iter_mut()
+            .find(|passkey| passkey.cred_id() == result.cred_id())
+        else {
+            return Ok(None);
+        };
+        passkey.update_credential(&result);
+        self.db.update_user_passkey(&creds.user_id, passkey).await?;
+
+        // Get the user the passkey belongs to
+        self.db.get_user_by_id(&creds.user_id).await
     }
 
     /// Authenticate user using password credentials.
@@ -246,8 +393,30 @@ impl AuthnBackend {
         Ok(None)
     }
 
+    /// Ensure the email of a user signing up is allowed by the community they
+    /// are signing up from, if any.
+    async fn ensure_sign_up_email_domain_allowed(
+        &self,
+        user_summary: &UserSummary,
+        sign_up_community: Option<&str>,
+    ) -> Result<()> {
+        if let Some(community_name) = sign_up_community
+            && !self
+                .db
+                .community_email_domain_allowed(community_name, &user_summary.email)
+                .await?
+        {
+            bail!(EXTERNAL_AUTH_EMAIL_DOMAIN_NOT_ALLOWED_ERROR);
+        }
+        Ok(())
+    }
+
     /// Get an existing external-auth user or sign them up.
-    async fn get_or_sign_up_external_user(&self, user_summary: &UserSummary) -> Result<User> {
+    async fn get_or_sign_up_external_user(
+        &self,
+        user_summary: &UserSummary,
+        sign_up_community: Option<&str>,
+    ) -> Result<User> {
         // Extract immutable LF identity before email-based fallbacks
         let incoming_linuxfoundation_identity = linuxfoundation_identity(user_summary);
 
@@ -261,6 +430,17 @@ impl AuthnBackend {
             return self.db.update_user_external_auth(&user.user_id, user_summary).await;
         }
 
+        // Try to reconcile returning OAuth2 users by account ID, even if their email changed
+        if let Some((provider, id)) = oauth2_identity(user_summary)
+            && let Some(mut user) = self
+                .db
+                .get_user_by_oauth2_identity_for_external_auth(&provider, &id)
+                .await?
+        {
+            self.sync_user_provider(&mut user, user_summary).await?;
+            return Ok(user);
+        }
+
         // Fall back to email for existing verified users and invitation placeholders
         if let Some(mut user) = self
             .db
@@ -269,6 +449,8 @@ impl AuthnBackend {
         {
             // Promote a pre-registered placeholder with the verified external identity
             if user.registration_status == "pre-registered" {
+                self.ensure_sign_up_email_domain_allowed(user_summary, sign_up_community)
+                    .await?;
                 return self
                     .db
                     .activate_pre_registered_user_external_provider(&user.user_id, user_summary)
@@ -285,24 +467,117 @@ impl AuthnBackend {
             }
 
             // Persist new provider metadata for the email-matched user when needed
-            if let Some(provider) = user_summary.provider.clone() {
-                let mut merged_provider = user.provider.clone().unwrap_or_default();
-                merged_provider.merge(provider.clone());
-
-                // Update the user's provider metadata if it has changed
-                if user.provider.as_ref() != Some(&merged_provider) {
-                    self.db.update_user_provider(&user.user_id, &provider).await?;
-                    user.provider = Some(merged_provider);
-                }
-            }
+            self.sync_user_provider(&mut user, user_summary).await?;
             Ok(user)
         } else {
             // Create a verified account when no user or placeholder matches
+            self.ensure_sign_up_email_domain_allowed(user_summary, sign_up_community)
+                .await?;
             let (user, _) = self.db.sign_up_user(user_summary, true, None).await?;
             Ok(user)
         }
     }
 
+    /// Link the external identity of a user summary to a user's account.
+    async fn link_external_identity(
+        &self,
+        user_id: &Uuid,
+        user_summary: &UserSummary,
+    ) -> Result<()> {
+        let Some(provider) = &user_summary.provider else {
+            bail!("external auth identity is required")
+        };
+        self.db.link_user_provider(user_id, provider).await
+    }
+
+    /// Link the `OAuth2` identity the credentials belong to to a user's account.
+    pub(crate) async fn link_oauth2_identity(
+        &self,
+        user_id: &Uuid,
+        creds: OAuth2Credentials,
+    ) -> Result<()> {
+        let user_summary = self.oauth2_user_summary(creds).await?;
+        self.link_external_identity(user_id, &user_summary).await
+    }
+
+    /// Link the `Oidc` identity the credentials belong to to a user's account.
+    pub(crate) async fn link_oidc_identity(
+        &self,
+        user_id: &Uuid,
+        creds: OidcCredentials,
+    ) -> Result<()> {
+        let user_summary = self.oidc_user_summary(creds).await?;
+        self.link_external_identity(user_id, &user_summary).await
+    }
+
+    /// Get the user summary for the `OAuth2` identity the credentials belong to.
+    async fn oauth2_user_summary(&self, creds: OAuth2Credentials) -> Result<UserSummary> {
+        // Exchange the authorization code for an access token
+        let Some(oauth2_provider) = self.oauth2_providers.get(&creds.provider) else {
+            bail!("oauth2 provider not found")
+        };
+        let access_token = oauth2_provider
+            .client
+            .exchange_code(oauth2::AuthorizationCode::new(creds.code))
+            .request_async(&self.http_client)
+            .await?
+            .access_token()
+            .secret()
+            .clone();
+
+        // Get the user's profile from the provider
+        let user_summary = match creds.provider {
+            OAuth2Provider::GitHub => UserSummary::from_github_profile(&access_token).await?,
+            OAuth2Provider::GitLab => UserSummary::from_gitlab_profile(&access_token).await?,
+            OAuth2Provider::Google => UserSummary::from_google_profile(&access_token).await?,
+        };
+
+        Ok(user_summary)
+    }
+
+    /// Get the user summary for the `Oidc` identity the credentials belong to.
+    async fn oidc_user_summary(&self, creds: OidcCredentials) -> Result<UserSummary> {
+        // Exchange the authorization code for an access and id token
+        let Some(oidc_provider) = self.oidc_providers.get(&creds.provider) else {
+            bail!("oidc provider not found")
+        };
+        let token_response = oidc_provider
+            .client
+            .exchange_code(oidc::AuthorizationCode::new(creds.code))?
+            .request_async(&self.http_client)
+            .await?;
+
+        // Extract and verify ID token claims
+        let id_token_verifier = oidc_provider.client.id_token_verifier();
+        let Some(id_token) = token_response.extra_fields().id_token() else {
+            bail!("id token missing")
+        };
+        let claims = id_token.claims(&id_token_verifier, &creds.nonce)?;
+
+        // Get the user's profile from the id token claims
+        let user_summary = match creds.provider {
+            OidcProvider::LinuxFoundation => UserSummary::from_oidc_id_token_claims(claims)?,
+        };
+
+        Ok(user_summary)
+    }
+
+    /// Persist the provider metadata of a user summary for an existing user
+    /// when it has changed.
+    async fn sync_user_provider(&self, user: &mut User, user_summary: &UserSummary) -> Result<()> {
+        if let Some(provider) = user_summary.provider.clone() {
+            let mut merged_provider = user.provider.clone().unwrap_or_default();
+            merged_provider.merge(provider.clone());
+
+            // Update the user's provider metadata if it has changed
+            if user.provider.as_ref() != Some(&merged_provider) {
+                self.db.update_user_provider(&user.user_id, &provider).await?;
+                user.provider = Some(merged_provider);
+            }
+        }
+        Ok(())
+    }
+
     /// Set up `OAuth2` providers from configuration.
     fn setup_oauth2_providers(oauth2_cfg: &OAuth2Config) -> Result<OAuth2Providers> {
         let mut providers: OAuth2Providers = HashMap::new();
@@ -367,8 +642,15 @@ impl axum_login::AuthnBackend for AuthnBackend {
         creds: Self::Credentials,
     ) -> Result<Option<Self::User>, Self::Error> {
         match creds {
+            Credentials::Ldap(creds) => self.authenticate_ldap(creds).await.map_err(AuthError),
+            Credentials::MagicLink(creds) => {
+                self.authenticate_magic_link(creds).await.map_err(AuthError)
+            }
             Credentials::OAuth2(creds) => self.authenticate_oauth2(creds).await.map_err(AuthError),
             Credentials::Oidc(creds) => self.authenticate_oidc(creds).await.map_err(AuthError),
+            Credentials::Passkey(creds) => {
+                self.authenticate_passkey(creds).await.map_err(AuthError)
+            }
             Credentials::Password(creds) => {
                 self.authenticate_password(creds).await.map_err(AuthError)
             }
@@ -434,10 +716,16 @@ pub(crate) struct AuthError(#[from] anyhow::Error);
 /// Credentials for authenticating a user.
 #[derive(Clone, Serialize, Deserialize)]
 pub enum Credentials {
+    /// LDAP directory credentials.
+    Ldap(LdapCredentials),
+    /// Magic link credentials.
+    MagicLink(MagicLinkCredentials),
     /// `OAuth2` credentials.
     OAuth2(OAuth2Credentials),
     /// `Oidc` credentials.
     Oidc(OidcCredentials),
+    /// Passkey credentials.
+    Passkey(PasskeyCredentials),
     /// Username and password credentials.
     Password(PasswordCredentials),
 }
@@ -445,6 +733,8 @@ pub enum Credentials {
 /// GitHub user profile information.
 #[derive(Debug, Deserialize)]
 struct GitHubProfile {
+    /// GitHub account identifier.
+    id: i64,
     /// GitHub username.
     login: String,
     /// GitHub display name.
@@ -462,6 +752,54 @@ struct GitHubUserEmail {
     verified: bool,
 }
 
+/// GitLab user profile information.
+#[derive(Debug, Deserialize)]
+struct GitLabProfile {
+    /// GitLab account identifier.
+    id: i64,
+    /// GitLab display name.
+    name: String,
+    /// GitLab username.
+    username: String,
+
+    /// When the primary email was confirmed, if it was.
+    confirmed_at: Option<String>,
+    /// Primary email address.
+    email: Option<String>,
+}
+
+/// Google user profile information.
+#[derive(Debug, Deserialize)]
+struct GoogleProfile {
+    /// Google account identifier.
+    sub: String,
+
+    /// Email address.
+    email: Option<String>,
+    /// Whether the email address is verified.
+    email_verified: Option<bool>,
+    /// Given name.
+    given_name: Option<String>,
+    /// Display name.
+    name: Option<String>,
+}
+
+/// Credentials for LDAP directory authentication.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct LdapCredentials {
+    /// Password of the directory account.
+    pub password: String,
+    /// Username of the directory account.
+    pub username: String,
+}
+
+/// Credentials for magic link authentication.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct MagicLinkCredentials {
+    /// Token included in the log in link sent to the user.
+    pub token: String,
+}
+
 /// Credentials for `OAuth2` authentication.
 #[derive(Clone, Serialize, Deserialize)]
 pub(crate) struct OAuth2Credentials {
@@ -469,6 +807,9 @@ pub(crate) struct OAuth2Credentials {
     pub code: String,
     /// The `OAuth2` provider to use.
     pub provider: OAuth2Provider,
+
+    /// Community the user is signing up from, if any.
+    pub sign_up_community: Option<String>,
 }
 
 /// Credentials for `Oidc` authentication.
@@ -480,6 +821,20 @@ pub(crate) struct OidcCredentials {
     pub nonce: oidc::Nonce,
     /// The `Oidc` provider to use.
     pub provider: OidcProvider,
+
+    /// Community the user is signing up from, if any.
+    pub sign_up_community: Option<String>,
+}
+
+/// Credentials for passkey authentication.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct PasskeyCredentials {
+    /// Assertion produced by the user's authenticator.
+    pub credential: PublicKeyCredential,
+    /// Authentication state created when the challenge was issued.
+    pub state: PasskeyAuthentication,
+    /// User the challenge was issued for.
+    pub user_id: Uuid,
 }
 
 /// Credentials for password authentication.
@@ -513,6 +868,9 @@ pub(crate) struct User {
     pub user_id: Uuid,
     /// User's username.
     pub username: String,
+    /// Whether the user receives the weekly digest of upcoming events.
+    #[serde(default = "default_weekly_digest_enabled")]
+    pub weekly_digest_enabled: bool,
 
     /// Whether the user belongs to any group team.
     pub belongs_to_any_group_team: Option<bool>,
@@ -528,6 +886,8 @@ pub(crate) struct User {
     pub company: Option<String>,
     /// User's country.
     pub country: Option<String>,
+    /// When the user wants to be reminded about the events they attend.
+    pub event_reminder_preference: Option<EventReminderPreference>,
     /// User's Facebook URL.
     pub facebook_url: Option<String>,
     /// User's GitHub URL.
@@ -536,6 +896,8 @@ pub(crate) struct User {
     pub has_password: Option<bool>,
     /// User's interests.
     pub interests: Option<Vec<String>>,
+    /// Language the user's notifications are delivered in.
+    pub language: Option<Language>,
     /// User's `LinkedIn` URL.
     pub linkedin_url: Option<String>,
     /// User's password hash (if present).
@@ -629,12 +991,7 @@ impl UserSummary {
     /// Create a `UserSummary` instance from a GitHub profile.
     async fn from_github_profile(access_token: &str) -> Result<Self> {
         // Setup headers for GitHub API requests
-        let mut headers = HeaderMap::new();
-        headers.insert(USER_AGENT, "open-community-groups".parse()?);
-        headers.insert(
-            AUTHORIZATION,
-            format!("Bearer {access_token}").as_str().parse()?,
-        );
+        let headers = oauth2_api_headers(access_token)?;
 
         // Get user profile from GitHub
         let profile = reqwest::Client::new()
@@ -663,13 +1020,102 @@ impl UserSummary {
         Ok(Self {
             email: email.email,
             name: profile.name,
-            provider: Some(UserProvider::from_github_username(profile.login.clone())),
+            provider: Some(UserProvider::from_github_account(
+                profile.id,
+                profile.login.clone(),
+            )),
             username: profile.login,
             has_password: Some(false),
             password: None,
         })
     }
 
+    /// Create a `UserSummary` instance from a GitLab profile.
+    async fn from_gitlab_profile(access_token: &str) -> Result<Self> {
+        // Get user profile from GitLab
+        let profile = reqwest::Client::new()
+            .get("https://gitlab.com/api/v4/user")
+            .headers(oauth2_api_headers(access_token)?)
+            .send()
+            .await?
+            .json::<GitLabProfile>()
+            .await?;
+
+        // Ensure the primary email is confirmed
+        let email = match (profile.email, profile.confirmed_at) {
+            (Some(email), Some(_)) if !email.is_empty() => email,
+            _ => bail!("no valid email found (primary email must be confirmed)"),
+        };
+
+        Ok(Self {
+            email,
+            name: profile.name,
+            provider: Some(UserProvider::from_gitlab_account(
+                profile.id,
+                profile.username.clone(),
+            )),
+            username: profile.username,
+            has_password: Some(false),
+            password: None,
+        })
+    }
+
+    /// Create a `UserSummary` instance from a Google profile.
+    async fn from_google_profile(access_token: &str) -> Result<Self> {
+        // Get user profile from Google
+        let profile = reqwest::Client::new()
+            .get("https://openidconnect.googleapis.com/v1/userinfo")
+            .headers(oauth2_api_headers(access_token)?)
+            .send()
+            .await?
+            .json::<GoogleProfile>()
+            .await?;
+
+        // Ensure email is verified
+        if !profile.email_verified.unwrap_or(false) {
+            bail!("email not verified");
+        }
+        let email = profile.email.ok_or_else(|| anyhow!("email missing"))?;
+
+        // Google accounts have no username, so derive one from the email
+        let username = email.split('@').next().unwrap_or_default().to_string();
+        let name = profile
+            .name
+            .or(profile.given_name)
+            .unwrap_or_else(|| username.clone());
+
+        Ok(Self {
+            email,
+            name,
+            provider: Some(UserProvider::from_google_account(
+                profile.sub,
+                username.clone(),
+            )),
+            username,
+            has_password: Some(false),
+            password: None,
+        })
+    }
+
+    /// Create a `UserSummary` from an LDAP directory entry.
+    fn from_ldap_entry(ldap_cfg: &LdapConfig, username: &str, entry: &SearchEntry) -> Result<Self> {
+        let Some(email) = ldap_attribute_values(entry, &ldap_cfg.email_attribute).first() else {
+            bail!("ldap entry email missing")
+        };
+        let name = ldap_attribute_values(entry, &ldap_cfg.name_attribute)
+            .first()
+            .map_or(username, String::as_str);
+
+        Ok(Self {
+            email: email.clone(),
+            name: name.to_string(),
+            username: username.to_string(),
+            has_password: Some(false),
+            password: None,
+            provider: None,
+        })
+    }
+
     /// Create a `UserSummary` from `Oidc` Id token claims.
     fn from_oidc_id_token_claims(
         claims: &oidc::IdTokenClaims<oidc::EmptyAdditionalClaims, oidc::core::CoreGenderClaim>,
@@ -732,6 +1178,57 @@ fn default_registration_status() -> String {
     "registered".to_string()
 }
 
+/// Default weekly digest preference for users loaded before it existed.
+fn default_weekly_digest_enabled() -> bool {
+    true
+}
+
+/// Find a user in the LDAP directory, returning their entry only when the
+/// password provided is valid.
+async fn find_ldap_user(
+    ldap_cfg: &LdapConfig,
+    creds: &LdapCredentials,
+) -> Result<Option<SearchEntry>> {
+    // Directories treat binds without a password as anonymous binds
+    if creds.password.is_empty() {
+        return Ok(None);
+    }
+
+    // Connect to the directory using the search account
+    let (conn, mut ldap) = LdapConnAsync::new(&ldap_cfg.url).await?;
+    ldap3::drive!(conn);
+    ldap.simple_bind(&ldap_cfg.bind_dn, &ldap_cfg.bind_password)
+        .await?
+        .success()?;
+
+    // Search for the user, which must match exactly one entry
+    let filter = ldap_user_search_filter(ldap_cfg, &creds.username);
+    let attrs = vec![
+        ldap_cfg.email_attribute.as_str(),
+        ldap_cfg.group_attribute.as_str(),
+        ldap_cfg.name_attribute.as_str(),
+    ];
+    let (entries, _) = ldap
+        .search(&ldap_cfg.user_search_base, Scope::Subtree, &filter, attrs)
+        .await?
+        .success()?;
+    let mut entries: Vec<SearchEntry> = entries
+        .into_iter()
+        .filter(|entry| !entry.is_ref())
+        .map(SearchEntry::construct)
+        .collect();
+    let (Some(entry), None) = (entries.pop(), entries.pop()) else {
+        ldap.unbind().await?;
+        return Ok(None);
+    };
+
+    // Check the password binding as the user found
+    let password_is_valid = ldap.simple_bind(&entry.dn, &creds.password).await?.success().is_ok();
+    ldap.unbind().await?;
+
+    Ok(password_is_valid.then_some(entry))
+}
+
 /// Get the first value from a localized claim, if present.
 fn get_localized_claim<T>(claim: Option<&LocalizedClaim<T>>) -> Option<T>
 where
@@ -746,6 +1243,40 @@ where
     })
 }
 
+/// Get the values of an LDAP entry attribute, matching its name case-insensitively.
+fn ldap_attribute_values<'a>(entry: &'a SearchEntry, attribute: &str) -> &'a [String] {
+    entry
+        .attrs
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(attribute))
+        .map_or(&[], |(_, values)| values.as_slice())
+}
+
+/// Get the community role mappings of the directory groups an LDAP entry belongs to.
+fn ldap_group_role_mappings(
+    ldap_cfg: &LdapConfig,
+    entry: &SearchEntry,
+) -> Vec<LdapGroupRoleMapping> {
+    let groups = ldap_attribute_values(entry, &ldap_cfg.group_attribute);
+    ldap_cfg
+        .group_role_mappings
+        .iter()
+        .filter(|mapping| {
+            groups
+                .iter()
+                .any(|group| group.eq_ignore_ascii_case(&mapping.group_dn))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Build the filter used to find a user in the LDAP directory.
+fn ldap_user_search_filter(ldap_cfg: &LdapConfig, username: &str) -> String {
+    ldap_cfg
+        .user_search_filter
+        .replace("{username}", &ldap_escape(username))
+}
+
 /// Gets a Linux Foundation OIDC identity from a user summary.
 fn linuxfoundation_identity(user_summary: &UserSummary) -> Option<(&str, &str)> {
     user_summary
@@ -762,6 +1293,30 @@ fn linuxfoundation_provider_identity(provider: &UserProvider) -> Option<(&str, &
     Some((issuer, subject))
 }
 
+/// Builds the headers used to call an `OAuth2` provider API on behalf of a user.
+fn oauth2_api_headers(access_token: &str) -> Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, "open-community-groups".parse()?);
+    headers.insert(
+        AUTHORIZATION,
+        format!("Bearer {access_token}").as_str().parse()?,
+    );
+    Ok(headers)
+}
+
+/// Gets the `OAuth2` provider and account ID from a user summary.
+fn oauth2_identity(user_summary: &UserSummary) -> Option<(OAuth2Provider, String)> {
+    let provider = user_summary.provider.as_ref()?;
+    if let Some(id) = provider.github.as_ref().and_then(|github| github.id) {
+        return Some((OAuth2Provider::GitHub, id.to_string()));
+    }
+    if let Some(id) = provider.gitlab.as_ref().and_then(|gitlab| gitlab.id) {
+        return Some((OAuth2Provider::GitLab, id.to_string()));
+    }
+    let id = provider.google.as_ref()?.id.clone()?;
+    Some((OAuth2Provider::Google, id))
+}
+
 /// Returns whether an optional profile field has non-whitespace content.
 fn profile_field_is_filled(value: Option<&str>) -> bool {
     value.is_some_and(|value| !value.trim().is_empty())
@@ -10,10 +10,11 @@ use uuid::Uuid;
 
 use crate::{
     config::{
-        OAuth2Config, OAuth2Provider, OAuth2ProviderConfig, OidcConfig, OidcProvider,
-        OidcProviderConfig,
+        LdapConfig, LdapGroupRoleMapping, OAuth2Config, OAuth2Provider, OAuth2ProviderConfig,
+        OidcConfig, OidcProvider, OidcProviderConfig,
     },
     db::{DynDB, mock::MockDB},
+    types::community::CommunityRole,
     types::user::{GitHubUserProvider, LinuxFoundationUserProvider, UserProvider},
 };
 
@@ -92,6 +93,7 @@ async fn authenticate_maps_oauth2_backend_error_to_auth_error() {
         Credentials::OAuth2(OAuth2Credentials {
             code: "code".to_string(),
             provider: OAuth2Provider::GitHub,
+            sign_up_community: None,
         }),
     )
     .await;
@@ -116,6 +118,7 @@ async fn authenticate_maps_oidc_backend_error_to_auth_error() {
             code: "code".to_string(),
             nonce: oidc::Nonce::new("nonce".to_string()),
             provider: OidcProvider::LinuxFoundation,
+            sign_up_community: None,
         }),
     )
     .await;
@@ -127,6 +130,48 @@ async fn authenticate_maps_oidc_backend_error_to_auth_error() {
     }
 }
 
+#[tokio::test]
+async fn authenticate_ldap_returns_error_when_not_enabled() {
+    // Setup backend without LDAP configuration
+    let db: DynDB = Arc::new(MockDB::new());
+    let backend = authn_backend(db).await;
+
+    // Execute authentication
+    let result = backend
+        .authenticate_ldap(LdapCredentials {
+            password: "password".to_string(),
+            username: "test-user".to_string(),
+        })
+        .await;
+
+    // Check result
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("ldap login not enabled"));
+}
+
+#[tokio::test]
+async fn authenticate_ldap_returns_none_when_password_is_empty() {
+    // Setup backend with LDAP configuration
+    let db: DynDB = Arc::new(MockDB::new());
+    let oidc_cfg: OidcConfig = HashMap::new();
+    let oauth2_cfg: OAuth2Config = HashMap::new();
+    let backend = AuthnBackend::new(db, &oauth2_cfg, &oidc_cfg, None, Some(sample_ldap_config()))
+        .await
+        .unwrap();
+
+    // Execute authentication
+    let result = backend
+        .authenticate_ldap(LdapCredentials {
+            password: String::new(),
+            username: "test-user".to_string(),
+        })
+        .await
+        .unwrap();
+
+    // Check result
+    assert!(result.is_none());
+}
+
 #[tokio::test]
 async fn authenticate_oauth2_returns_error_when_provider_missing() {
     // Setup backend without configured providers
@@ -138,6 +183,7 @@ async fn authenticate_oauth2_returns_error_when_provider_missing() {
         .authenticate_oauth2(OAuth2Credentials {
             code: "code".to_string(),
             provider: OAuth2Provider::GitHub,
+            sign_up_community: None,
         })
         .await;
 
@@ -158,6 +204,7 @@ async fn authenticate_oidc_returns_error_when_provider_missing() {
             code: "code".to_string(),
             nonce: oidc::Nonce::new("nonce".to_string()),
             provider: OidcProvider::LinuxFoundation,
+            sign_up_community: None,
         })
         .await;
 
@@ -324,7 +371,10 @@ async fn get_or_sign_up_external_user_merges_provider_into_existing_user() {
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let user = backend.get_or_sign_up_external_user(&user_summary).await.unwrap();
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
 
     // Check result
     assert_eq!(
@@ -332,7 +382,11 @@ async fn get_or_sign_up_external_user_merges_provider_into_existing_user() {
         Some(UserProvider {
             github: Some(GitHubUserProvider {
                 username: "test-user-gh".to_string(),
+
+                id: None,
             }),
+            gitlab: None,
+            google: None,
             linuxfoundation: Some(LinuxFoundationUserProvider {
                 username: "test-user-lf".to_string(),
 
@@ -343,6 +397,37 @@ async fn get_or_sign_up_external_user_merges_provider_into_existing_user() {
     );
 }
 
+#[tokio::test]
+async fn get_or_sign_up_external_user_rejects_email_domain_not_allowed() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    let user_summary = sample_external_user_summary(Some(sample_user_provider()));
+
+    db.expect_get_user_by_email_for_external_auth()
+        .times(1)
+        .withf(|email| email == "user@example.com")
+        .returning(|_| Ok(None));
+    db.expect_community_email_domain_allowed()
+        .times(1)
+        .withf(|community_name, email| community_name == "corporate" && email == "user@example.com")
+        .returning(|_, _| Ok(false));
+    db.expect_sign_up_user().times(0);
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let backend = authn_backend(db).await;
+    let error = backend
+        .get_or_sign_up_external_user(&user_summary, Some("corporate"))
+        .await
+        .unwrap_err();
+
+    // Check result
+    assert_eq!(
+        error.to_string(),
+        EXTERNAL_AUTH_EMAIL_DOMAIN_NOT_ALLOWED_ERROR
+    );
+}
+
 #[tokio::test]
 async fn get_or_sign_up_external_user_rejects_linuxfoundation_identity_relink() {
     // Setup database mock
@@ -376,7 +461,10 @@ async fn get_or_sign_up_external_user_rejects_linuxfoundation_identity_relink()
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let error = backend.get_or_sign_up_external_user(&user_summary).await.unwrap_err();
+    let error = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap_err();
 
     // Check result
     assert!(error.to_string().contains(EXTERNAL_AUTH_IDENTITY_CONFLICT_ERROR));
@@ -417,12 +505,113 @@ async fn get_or_sign_up_external_user_ignores_linuxfoundation_username_mismatch_
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let user = backend.get_or_sign_up_external_user(&user_summary).await.unwrap();
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
 
     // Check result
     assert_eq!(user.provider, Some(sample_linuxfoundation_user_provider()));
 }
 
+#[tokio::test]
+async fn get_or_sign_up_external_user_reconciles_github_identity_before_email() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    let incoming_provider = UserProvider::from_github_account(42, "renamed-gh".to_string());
+    let existing_user = User {
+        email: "linked@example.com".to_string(),
+        provider: Some(UserProvider::from_github_account(
+            42,
+            "test-user-gh".to_string(),
+        )),
+        ..sample_user()
+    };
+    let existing_user_id = existing_user.user_id;
+    let user_summary = sample_external_user_summary(Some(incoming_provider.clone()));
+
+    db.expect_get_user_by_oauth2_identity_for_external_auth()
+        .times(1)
+        .withf(|provider, id| *provider == OAuth2Provider::GitHub && id == "42")
+        .returning(move |_, _| Ok(Some(existing_user.clone())));
+    db.expect_update_user_provider()
+        .times(1)
+        .withf(move |user_id, provider| {
+            *user_id == existing_user_id && provider == &incoming_provider
+        })
+        .returning(|_, _| Ok(()));
+    db.expect_get_user_by_email_for_external_auth().times(0);
+    db.expect_update_user_external_auth().times(0);
+    db.expect_sign_up_user().times(0);
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let backend = authn_backend(db).await;
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
+
+    // Check result
+    assert_eq!(user.email, "linked@example.com");
+    assert_eq!(user.user_id, existing_user_id);
+    assert_eq!(
+        user.provider
+            .and_then(|provider| provider.github)
+            .map(|github| github.username),
+        Some("renamed-gh".to_string())
+    );
+}
+
+#[tokio::test]
+async fn get_or_sign_up_external_user_reconciles_google_identity_before_email() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    let incoming_provider =
+        UserProvider::from_google_account("108001".to_string(), "renamed".to_string());
+    let existing_user = User {
+        email: "linked@example.com".to_string(),
+        provider: Some(UserProvider::from_google_account(
+            "108001".to_string(),
+            "test-user".to_string(),
+        )),
+        ..sample_user()
+    };
+    let existing_user_id = existing_user.user_id;
+    let user_summary = sample_external_user_summary(Some(incoming_provider.clone()));
+
+    db.expect_get_user_by_oauth2_identity_for_external_auth()
+        .times(1)
+        .withf(|provider, id| *provider == OAuth2Provider::Google && id == "108001")
+        .returning(move |_, _| Ok(Some(existing_user.clone())));
+    db.expect_update_user_provider()
+        .times(1)
+        .withf(move |user_id, provider| {
+            *user_id == existing_user_id && provider == &incoming_provider
+        })
+        .returning(|_, _| Ok(()));
+    db.expect_get_user_by_email_for_external_auth().times(0);
+    db.expect_update_user_external_auth().times(0);
+    db.expect_sign_up_user().times(0);
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let backend = authn_backend(db).await;
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
+
+    // Check result
+    assert_eq!(user.user_id, existing_user_id);
+    assert_eq!(
+        user.provider
+            .and_then(|provider| provider.google)
+            .map(|google| google.username),
+        Some("renamed".to_string())
+    );
+}
+
 #[tokio::test]
 async fn get_or_sign_up_external_user_reconciles_linuxfoundation_identity_before_email() {
     // Setup database mock
@@ -462,7 +651,10 @@ async fn get_or_sign_up_external_user_reconciles_linuxfoundation_identity_before
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let user = backend.get_or_sign_up_external_user(&user_summary).await.unwrap();
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
 
     // Check result
     assert_eq!(user.email, "new@example.com");
@@ -494,7 +686,10 @@ async fn get_or_sign_up_external_user_sets_provider_for_existing_user_without_pr
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let user = backend.get_or_sign_up_external_user(&user_summary).await.unwrap();
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
 
     // Check result
     assert_eq!(user.provider, Some(sample_user_provider()));
@@ -517,7 +712,10 @@ async fn get_or_sign_up_external_user_skips_provider_update_when_unchanged() {
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let user = backend.get_or_sign_up_external_user(&user_summary).await.unwrap();
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
 
     // Check result
     assert_eq!(user.provider, Some(sample_user_provider()));
@@ -551,7 +749,10 @@ async fn get_or_sign_up_external_user_signs_up_new_user() {
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let user = backend.get_or_sign_up_external_user(&user_summary).await.unwrap();
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
 
     // Check result
     assert_eq!(user.provider, Some(sample_user_provider()));
@@ -591,7 +792,10 @@ async fn get_or_sign_up_external_user_activates_pre_registered_user() {
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let user = backend.get_or_sign_up_external_user(&user_summary).await.unwrap();
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
 
     // Check result
     assert_eq!(user.registration_status, "registered");
@@ -614,7 +818,10 @@ async fn get_or_sign_up_external_user_skips_provider_update_when_missing() {
 
     // Execute helper
     let backend = authn_backend(db).await;
-    let user = backend.get_or_sign_up_external_user(&user_summary).await.unwrap();
+    let user = backend
+        .get_or_sign_up_external_user(&user_summary, None)
+        .await
+        .unwrap();
 
     // Check result
     assert_eq!(user.provider, Some(sample_user_provider()));
@@ -661,6 +868,120 @@ async fn get_user_returns_none_when_user_not_found() {
     assert!(result.is_none());
 }
 
+#[test]
+fn ldap_group_role_mappings_returns_mappings_of_entry_groups() {
+    // Setup entry belonging to one of the mapped groups
+    let ldap_cfg = sample_ldap_config();
+    let entry = sample_ldap_entry(&[
+        ("memberof", &["CN=Organizers,OU=Groups,DC=example,DC=com"]),
+        ("mail", &["user@example.com"]),
+    ]);
+
+    // Execute mappings resolution
+    let mappings = ldap_group_role_mappings(&ldap_cfg, &entry);
+
+    // Check result
+    assert_eq!(mappings, vec![ldap_cfg.group_role_mappings[0].clone()]);
+}
+
+#[test]
+fn ldap_user_search_filter_escapes_username() {
+    // Execute filter building with special characters
+    let filter = ldap_user_search_filter(&sample_ldap_config(), "user*)(uid=*");
+
+    // Check result
+    assert_eq!(filter, r"(uid=user\2a\29\28uid=\2a)");
+}
+
+#[tokio::test]
+async fn link_external_identity_links_provider_to_user() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    let user_id = Uuid::new_v4();
+    let provider = UserProvider::from_github_account(42, "test-user-gh".to_string());
+    let expected_provider = provider.clone();
+    db.expect_link_user_provider()
+        .times(1)
+        .withf(move |id, provider| *id == user_id && provider == &expected_provider)
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let backend = authn_backend(db).await;
+    let user_summary = sample_external_user_summary(Some(provider));
+    let result = backend.link_external_identity(&user_id, &user_summary).await;
+
+    // Check result
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn link_external_identity_requires_provider() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_link_user_provider().times(0);
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let backend = authn_backend(db).await;
+    let user_summary = sample_external_user_summary(None);
+    let result = backend.link_external_identity(&Uuid::new_v4(), &user_summary).await;
+
+    // Check result
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "external auth identity is required"
+    );
+}
+
+#[test]
+fn session_lifetime_expires_at_is_capped_by_max_lifetime() {
+    // Setup a lifetime that started close to the maximum allowed
+    let cfg = SessionConfig {
+        idle_timeout_hours: 168,
+        max_lifetime_days: 30,
+    };
+    let logged_in_at = OffsetDateTime::now_utc() - Duration::days(29);
+    let lifetime = SessionLifetime {
+        logged_in_at: logged_in_at.unix_timestamp(),
+        remember: true,
+    };
+
+    // Check the expiry does not go past the maximum lifetime
+    assert_eq!(
+        lifetime.expires_at(&cfg).unix_timestamp(),
+        (logged_in_at + Duration::days(30)).unix_timestamp()
+    );
+}
+
+#[test]
+fn session_lifetime_expires_at_uses_idle_timeout() {
+    // Setup a lifetime that just started
+    let cfg = SessionConfig {
+        idle_timeout_hours: 12,
+        max_lifetime_days: 30,
+    };
+    let lifetime = SessionLifetime::new(true);
+
+    // Check the expiry is set by the idle timeout
+    let expected = OffsetDateTime::now_utc() + Duration::hours(12);
+    let expires_at = lifetime.expires_at(&cfg);
+    assert!((expected - expires_at).abs() < Duration::minutes(1));
+}
+
+#[test]
+fn session_lifetime_expiry_depends_on_remember() {
+    let cfg = SessionConfig::default();
+
+    // Check remembered sessions expire at a fixed date
+    let remembered = SessionLifetime::new(true);
+    assert!(matches!(remembered.expiry(&cfg), Expiry::AtDateTime(_)));
+
+    // Check other sessions end with the browser session
+    let not_remembered = SessionLifetime::new(false);
+    assert!(matches!(not_remembered.expiry(&cfg), Expiry::OnSessionEnd));
+}
+
 #[tokio::test]
 async fn setup_oidc_providers_rejects_invalid_issuer_url() {
     // Setup invalid OIDC configuration
@@ -834,6 +1155,51 @@ fn user_summary_debug_does_not_expose_password() {
     assert!(!output.contains("private-password-hash"));
 }
 
+#[test]
+fn user_summary_from_ldap_entry_extracts_user() {
+    // Setup directory entry
+    let entry = sample_ldap_entry(&[
+        ("mail", &["user@example.com"]),
+        ("displayName", &["Test User"]),
+    ]);
+
+    // Execute conversion
+    let result = UserSummary::from_ldap_entry(&sample_ldap_config(), "test-user", &entry).unwrap();
+
+    // Check result
+    assert_eq!(result.email, "user@example.com");
+    assert_eq!(result.name, "Test User");
+    assert_eq!(result.username, "test-user");
+    assert_eq!(result.has_password, Some(false));
+    assert!(result.password.is_none());
+    assert!(result.provider.is_none());
+}
+
+#[test]
+fn user_summary_from_ldap_entry_uses_username_when_name_is_missing() {
+    // Setup directory entry without display name
+    let entry = sample_ldap_entry(&[("mail", &["user@example.com"])]);
+
+    // Execute conversion
+    let result = UserSummary::from_ldap_entry(&sample_ldap_config(), "test-user", &entry).unwrap();
+
+    // Check result
+    assert_eq!(result.name, "test-user");
+}
+
+#[test]
+fn user_summary_from_ldap_entry_rejects_missing_email() {
+    // Setup directory entry without email
+    let entry = sample_ldap_entry(&[("displayName", &["Test User"])]);
+
+    // Execute conversion
+    let result = UserSummary::from_ldap_entry(&sample_ldap_config(), "test-user", &entry);
+
+    // Check result
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("ldap entry email missing"));
+}
+
 #[test]
 fn user_summary_from_oidc_id_token_claims_extracts_verified_user() {
     // Setup valid claims
@@ -854,8 +1220,11 @@ fn user_summary_from_oidc_id_token_claims_extracts_verified_user() {
         result.provider,
         Some(UserProvider {
             github: None,
+            gitlab: None,
+            google: None,
             linuxfoundation: Some(LinuxFoundationUserProvider {
                 username: "test-user".to_string(),
+                weekly_digest_enabled: true,
 
                 issuer: Some("https://issuer.example.com".to_string()),
                 subject: Some("subject".to_string()),
@@ -970,7 +1339,48 @@ fn user_session_auth_hash_matches_auth_hash_bytes() {
 async fn authn_backend(db: DynDB) -> AuthnBackend {
     let oidc_cfg: OidcConfig = HashMap::new();
     let oauth2_cfg: OAuth2Config = HashMap::new();
-    AuthnBackend::new(db, &oauth2_cfg, &oidc_cfg).await.unwrap()
+    AuthnBackend::new(db, &oauth2_cfg, &oidc_cfg, None, None)
+        .await
+        .unwrap()
+}
+
+fn sample_ldap_config() -> LdapConfig {
+    LdapConfig {
+        bind_dn: "cn=ocg,ou=services,dc=example,dc=com".to_string(),
+        bind_password: "bind-password".to_string(),
+        email_attribute: "mail".to_string(),
+        group_attribute: "memberOf".to_string(),
+        name_attribute: "displayName".to_string(),
+        url: "ldap://127.0.0.1:1".to_string(),
+        user_search_base: "ou=people,dc=example,dc=com".to_string(),
+        user_search_filter: "(uid={username})".to_string(),
+        group_role_mappings: vec![
+            LdapGroupRoleMapping {
+                community_name: "test-community".to_string(),
+                group_dn: "cn=organizers,ou=groups,dc=example,dc=com".to_string(),
+                role: CommunityRole::GroupsManager,
+            },
+            LdapGroupRoleMapping {
+                community_name: "test-community".to_string(),
+                group_dn: "cn=admins,ou=groups,dc=example,dc=com".to_string(),
+                role: CommunityRole::Admin,
+            },
+        ],
+    }
+}
+
+fn sample_ldap_entry(attrs: &[(&str, &[&str])]) -> SearchEntry {
+    SearchEntry {
+        dn: "uid=test-user,ou=people,dc=example,dc=com".to_string(),
+        attrs: attrs
+            .iter()
+            .map(|(name, values)| {
+                let values = values.iter().map(ToString::to_string).collect();
+                ((*name).to_string(), values)
+            })
+            .collect(),
+        bin_attrs: HashMap::new(),
+    }
 }
 
 fn sample_oauth2_provider_config() -> OAuth2ProviderConfig {
@@ -1065,7 +1475,11 @@ fn sample_user_provider() -> UserProvider {
     UserProvider {
         github: Some(GitHubUserProvider {
             username: "test-user-gh".to_string(),
+
+            id: None,
         }),
+        gitlab: None,
+        google: None,
         linuxfoundation: None,
     }
 }
@@ -1073,6 +1487,8 @@ fn sample_user_provider() -> UserProvider {
 fn sample_linuxfoundation_user_provider() -> UserProvider {
     UserProvider {
         github: None,
+        gitlab: None,
+        google: None,
         linuxfoundation: Some(LinuxFoundationUserProvider {
             username: "test-user-lf".to_string(),
 
@@ -1085,6 +1501,8 @@ fn sample_linuxfoundation_user_provider() -> UserProvider {
 fn sample_linuxfoundation_username_only_user_provider(username: &str) -> UserProvider {
     UserProvider {
         github: None,
+        gitlab: None,
+        google: None,
         linuxfoundation: Some(LinuxFoundationUserProvider {
             username: username.to_string(),
 
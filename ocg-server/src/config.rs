@@ -9,6 +9,7 @@
 use std::{
     collections::{HashMap, HashSet},
     fmt,
+    net::IpAddr,
     path::PathBuf,
 };
 
@@ -19,11 +20,21 @@ use figment::{
     providers::{Env, Format, Serialized, Yaml},
 };
 use garde::rules::email::parse_email;
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
-use strum::AsRefStr;
+use strum::{AsRefStr, EnumString};
 use tracing::instrument;
 
-use crate::types::payments::{PaymentMode, PaymentProvider};
+use crate::{
+    services::meetings::MeetingProvider,
+    types::{
+        community::CommunityRole,
+        payments::{PaymentMode, PaymentProvider},
+    },
+};
+
+/// Default offsets, in minutes before the event starts, of event reminders.
+const DEFAULT_EVENT_REMINDER_OFFSETS_MINUTES: [u32; 2] = [1440, 60];
 
 /// Placeholder used when formatting sensitive configuration values.
 const REDACTED_CONFIG_VALUE: &str = "[redacted]";
@@ -39,13 +50,19 @@ pub(crate) struct Config {
     pub images: ImageStorageConfig,
     /// Logging configuration.
     pub log: LogConfig,
+    /// Notifications configuration.
+    pub notifications: NotificationsConfig,
     /// HTTP server configuration.
     pub server: HttpServerConfig,
 
+    /// Google Calendar sync configuration.
+    pub google_calendar: Option<GoogleCalendarConfig>,
     /// Meetings configuration.
     pub meetings: Option<MeetingsConfig>,
     /// Payments configuration.
     pub payments: Option<PaymentsConfig>,
+    /// SMS notifications configuration.
+    pub sms: Option<SmsConfig>,
 }
 
 impl Config {
@@ -60,8 +77,16 @@ impl Config {
     pub(crate) fn new(config_file: Option<&PathBuf>) -> Result<Self> {
         let mut figment = Figment::new()
             .merge(Serialized::default("log.format", "json"))
+            .merge(Serialized::default("email.provider", "smtp"))
             .merge(Serialized::default("images.provider", "db"))
-            .merge(Serialized::default("server.addr", "127.0.0.1:9000"));
+            .merge(Serialized::default(
+                "notifications.event_reminder_offsets_minutes",
+                DEFAULT_EVENT_REMINDER_OFFSETS_MINUTES,
+            ))
+            .merge(Serialized::default("server.addr", "127.0.0.1:9000"))
+            .merge(Serialized::default("server.login.ldap", false))
+            .merge(Serialized::default("server.login.magic_link", false))
+            .merge(Serialized::default("server.login.passkey", false));
 
         if let Some(config_file) = config_file {
             figment = figment.merge(Yaml::file(config_file));
@@ -79,16 +104,26 @@ impl Config {
 
     /// Validate configuration consistency after loading from all sources.
     fn validate(&self) -> Result<()> {
-        if let Some(meetings_cfg) = &self.meetings
-            && let Some(zoom_cfg) = &meetings_cfg.zoom
-        {
-            zoom_cfg.validate()?;
+        self.email.validate()?;
+        self.notifications.validate()?;
+        self.server.validate()?;
+
+        if let Some(google_calendar_cfg) = &self.google_calendar {
+            google_calendar_cfg.validate()?;
+        }
+
+        if let Some(meetings_cfg) = &self.meetings {
+            meetings_cfg.validate()?;
         }
 
         if let Some(payments_cfg) = &self.payments {
             payments_cfg.validate()?;
         }
 
+        if let Some(sms_cfg) = &self.sms {
+            sms_cfg.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -100,9 +135,12 @@ impl fmt::Debug for Config {
             .field("email", &self.email)
             .field("images", &self.images)
             .field("log", &self.log)
+            .field("notifications", &self.notifications)
             .field("server", &self.server)
+            .field("google_calendar", &self.google_calendar)
             .field("meetings", &self.meetings)
             .field("payments", &self.payments)
+            .field("sms", &self.sms)
             .finish()
     }
 }
@@ -114,13 +152,193 @@ pub(crate) struct EmailConfig {
     pub from_address: String,
     /// Sender display name.
     pub from_name: String,
-    /// SMTP server configuration.
-    pub smtp: SmtpConfig,
+    /// Provider used to send emails.
+    pub provider: EmailProvider,
 
+    /// Optional bounce and complaint feedback endpoint configuration.
+    pub feedback: Option<EmailFeedbackConfig>,
     /// Optional whitelist of allowed recipient email addresses for
     /// development environments. If not present, all recipients are
     /// allowed. If present and empty, none are allowed.
     pub rcpts_whitelist: Option<Vec<String>>,
+    /// SendGrid API configuration (required by the `sendgrid` provider).
+    pub sendgrid: Option<EmailSendgridConfig>,
+    /// Amazon SES API configuration (required by the `ses` provider).
+    pub ses: Option<EmailSesConfig>,
+    /// SMTP server configuration (required by the `smtp` provider).
+    pub smtp: Option<SmtpConfig>,
+}
+
+impl EmailConfig {
+    /// Validate the configuration of the selected email provider.
+    fn validate(&self) -> Result<()> {
+        match self.provider {
+            EmailProvider::Sendgrid => {
+                let Some(sendgrid_cfg) = &self.sendgrid else {
+                    bail!("email.sendgrid is required when using the sendgrid provider");
+                };
+                sendgrid_cfg.validate()
+            }
+            EmailProvider::Ses => {
+                let Some(ses_cfg) = &self.ses else {
+                    bail!("email.ses is required when using the ses provider");
+                };
+                ses_cfg.validate()
+            }
+            EmailProvider::Smtp => {
+                if self.smtp.is_none() {
+                    bail!("email.smtp is required when using the smtp provider");
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Bounce and complaint feedback endpoint configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct EmailFeedbackConfig {
+    /// Secret feedback requests must authenticate with.
+    pub webhook_secret: String,
+}
+
+impl fmt::Debug for EmailFeedbackConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmailFeedbackConfig")
+            .field("webhook_secret", &REDACTED_CONFIG_VALUE)
+            .finish()
+    }
+}
+
+/// Supported email providers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EmailProvider {
+    /// Send emails using the SendGrid API.
+    Sendgrid,
+    /// Send emails using the Amazon SES API.
+    Ses,
+    /// Send emails using an SMTP server.
+    Smtp,
+}
+
+/// SendGrid API configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct EmailSendgridConfig {
+    /// API key used to authenticate requests.
+    pub api_key: String,
+
+    /// Validate requests without delivering emails, useful for staging
+    /// environments. Defaults to `false`.
+    pub sandbox_mode: Option<bool>,
+}
+
+impl fmt::Debug for EmailSendgridConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmailSendgridConfig")
+            .field("api_key", &REDACTED_CONFIG_VALUE)
+            .field("sandbox_mode", &self.sandbox_mode)
+            .finish()
+    }
+}
+
+impl EmailSendgridConfig {
+    /// Validate SendGrid API configuration.
+    fn validate(&self) -> Result<()> {
+        if self.api_key.trim().is_empty() {
+            bail!("email.sendgrid.api_key cannot be empty");
+        }
+
+        Ok(())
+    }
+}
+
+/// Amazon SES API configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct EmailSesConfig {
+    /// Access key identifier used for authentication.
+    pub access_key_id: String,
+    /// Region of the SES endpoint used to send emails.
+    pub region: String,
+    /// Secret access key used for authentication.
+    pub secret_access_key: String,
+
+    /// Optional configuration set applied to sent emails, used to publish
+    /// delivery, bounce and complaint events.
+    pub configuration_set: Option<String>,
+    /// Optional custom endpoint, mainly useful for local testing.
+    pub endpoint: Option<String>,
+}
+
+impl fmt::Debug for EmailSesConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmailSesConfig")
+            .field("access_key_id", &self.access_key_id)
+            .field("region", &self.region)
+            .field("secret_access_key", &REDACTED_CONFIG_VALUE)
+            .field("configuration_set", &self.configuration_set)
+            .field("endpoint", &self.endpoint)
+            .finish()
+    }
+}
+
+impl EmailSesConfig {
+    /// Validate Amazon SES API configuration.
+    fn validate(&self) -> Result<()> {
+        if self.access_key_id.trim().is_empty() {
+            bail!("email.ses.access_key_id cannot be empty");
+        }
+
+        if self.region.trim().is_empty() {
+            bail!("email.ses.region cannot be empty");
+        }
+
+        if self.secret_access_key.trim().is_empty() {
+            bail!("email.ses.secret_access_key cannot be empty");
+        }
+
+        Ok(())
+    }
+}
+
+/// Google Calendar sync configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct GoogleCalendarConfig {
+    /// `OAuth2` client ID.
+    pub client_id: String,
+    /// `OAuth2` client secret.
+    pub client_secret: String,
+    /// Redirect URI after authorizing access to the calendar.
+    pub redirect_uri: String,
+}
+
+impl fmt::Debug for GoogleCalendarConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GoogleCalendarConfig")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &REDACTED_CONFIG_VALUE)
+            .field("redirect_uri", &self.redirect_uri)
+            .finish()
+    }
+}
+
+impl GoogleCalendarConfig {
+    /// Validate Google Calendar sync configuration.
+    fn validate(&self) -> Result<()> {
+        if self.client_id.trim().is_empty() {
+            bail!("google_calendar.client_id cannot be empty");
+        }
+
+        if self.client_secret.trim().is_empty() {
+            bail!("google_calendar.client_secret cannot be empty");
+        }
+
+        if self.redirect_uri.trim().is_empty() {
+            bail!("google_calendar.redirect_uri cannot be empty");
+        }
+
+        Ok(())
+    }
 }
 
 /// Image storage configuration.
@@ -167,15 +385,354 @@ impl fmt::Debug for ImageStorageConfigS3 {
 /// Meetings configuration (multiple providers supported).
 #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub(crate) struct MeetingsConfig {
+    /// BigBlueButton provider configuration.
+    pub bigbluebutton: Option<MeetingsBigBlueButtonConfig>,
+    /// Google Meet provider configuration.
+    pub google_meet: Option<MeetingsGoogleMeetConfig>,
+    /// Jitsi provider configuration.
+    pub jitsi: Option<MeetingsJitsiConfig>,
+    /// Microsoft Teams provider configuration.
+    pub teams: Option<MeetingsTeamsConfig>,
+    /// Cisco Webex provider configuration.
+    pub webex: Option<MeetingsWebexConfig>,
     /// Zoom provider configuration.
     pub zoom: Option<MeetingsZoomConfig>,
 }
 
 impl MeetingsConfig {
+    /// Return the meetings providers that are enabled.
+    pub(crate) fn enabled_providers(&self) -> Vec<MeetingProvider> {
+        let mut providers = Vec::new();
+        if self.bigbluebutton.as_ref().is_some_and(|b| b.enabled) {
+            providers.push(MeetingProvider::BigBlueButton);
+        }
+        if self.google_meet.as_ref().is_some_and(|g| g.enabled) {
+            providers.push(MeetingProvider::GoogleMeet);
+        }
+        if self.jitsi.as_ref().is_some_and(|j| j.enabled) {
+            providers.push(MeetingProvider::Jitsi);
+        }
+        if self.teams.as_ref().is_some_and(|t| t.enabled) {
+            providers.push(MeetingProvider::Teams);
+        }
+        if self.webex.as_ref().is_some_and(|w| w.enabled) {
+            providers.push(MeetingProvider::Webex);
+        }
+        if self.zoom_enabled() {
+            providers.push(MeetingProvider::Zoom);
+        }
+        providers
+    }
+
     /// Check if at least one meetings provider is enabled.
     pub(crate) fn meetings_enabled(&self) -> bool {
+        self.bigbluebutton.as_ref().is_some_and(|b| b.enabled)
+            || self.google_meet.as_ref().is_some_and(|g| g.enabled)
+            || self.jitsi.as_ref().is_some_and(|j| j.enabled)
+            || self.teams.as_ref().is_some_and(|t| t.enabled)
+            || self.webex.as_ref().is_some_and(|w| w.enabled)
+            || self.zoom.as_ref().is_some_and(|z| z.enabled)
+    }
+
+    /// Check if the Zoom meetings provider is enabled.
+    pub(crate) fn zoom_enabled(&self) -> bool {
         self.zoom.as_ref().is_some_and(|z| z.enabled)
     }
+
+    /// Validate the configured meetings providers.
+    fn validate(&self) -> Result<()> {
+        if let Some(bigbluebutton_cfg) = &self.bigbluebutton {
+            bigbluebutton_cfg.validate()?;
+        }
+
+        if let Some(google_meet_cfg) = &self.google_meet {
+            google_meet_cfg.validate()?;
+        }
+
+        if let Some(jitsi_cfg) = &self.jitsi {
+            jitsi_cfg.validate()?;
+        }
+
+        if let Some(teams_cfg) = &self.teams {
+            teams_cfg.validate()?;
+        }
+
+        if let Some(webex_cfg) = &self.webex {
+            webex_cfg.validate()?;
+        }
+
+        if let Some(zoom_cfg) = &self.zoom {
+            zoom_cfg.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// BigBlueButton meetings configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct MeetingsBigBlueButtonConfig {
+    /// API endpoint of the server (e.g. `https://bbb.example.org/bigbluebutton/api`).
+    pub api_url: String,
+    /// Whether this provider is enabled.
+    pub enabled: bool,
+    /// Maximum number of participants supported by the server.
+    pub max_participants: i32,
+    /// Shared secret used to compute API call checksums.
+    pub shared_secret: String,
+}
+
+impl fmt::Debug for MeetingsBigBlueButtonConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeetingsBigBlueButtonConfig")
+            .field("api_url", &self.api_url)
+            .field("enabled", &self.enabled)
+            .field("max_participants", &self.max_participants)
+            .field("shared_secret", &REDACTED_CONFIG_VALUE)
+            .finish()
+    }
+}
+
+impl MeetingsBigBlueButtonConfig {
+    /// Validate BigBlueButton meetings configuration.
+    fn validate(&self) -> Result<()> {
+        // Skip validation when BigBlueButton meetings are disabled
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // Validate the API endpoint
+        if !self.api_url.starts_with("https://") && !self.api_url.starts_with("http://") {
+            bail!("meetings.bigbluebutton.api_url must be an http(s) URL");
+        }
+
+        // Validate the shared secret
+        if self.shared_secret.trim().is_empty() {
+            bail!("meetings.bigbluebutton.shared_secret cannot be empty");
+        }
+
+        Ok(())
+    }
+}
+
+/// Google Meet meetings configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct MeetingsGoogleMeetConfig {
+    /// Service account email used to sign access token requests.
+    pub client_email: String,
+    /// Google Workspace user impersonated to host meetings on their calendar.
+    pub delegated_user: String,
+    /// Whether this provider is enabled.
+    pub enabled: bool,
+    /// Maximum number of participants allowed in a meeting (Workspace plan limit).
+    pub max_participants: i32,
+    /// Service account private key in PEM format.
+    pub private_key: String,
+}
+
+impl fmt::Debug for MeetingsGoogleMeetConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeetingsGoogleMeetConfig")
+            .field("client_email", &self.client_email)
+            .field("delegated_user", &self.delegated_user)
+            .field("enabled", &self.enabled)
+            .field("max_participants", &self.max_participants)
+            .field("private_key", &REDACTED_CONFIG_VALUE)
+            .finish()
+    }
+}
+
+impl MeetingsGoogleMeetConfig {
+    /// Validate Google Meet meetings configuration.
+    fn validate(&self) -> Result<()> {
+        // Skip validation when Google Meet meetings are disabled
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // Validate service account credentials
+        if self.client_email.trim().is_empty() {
+            bail!("meetings.google_meet.client_email cannot be empty");
+        }
+        if self.private_key.trim().is_empty() {
+            bail!("meetings.google_meet.private_key cannot be empty");
+        }
+
+        // Validate the impersonated calendar user
+        parse_email(&self.delegated_user).map_err(|err| {
+            anyhow::anyhow!(
+                "meetings.google_meet.delegated_user has invalid email '{}': {err}",
+                self.delegated_user
+            )
+        })?;
+
+        Ok(())
+    }
+}
+
+/// Jitsi meetings configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct MeetingsJitsiConfig {
+    /// Base URL of the Jitsi deployment (e.g. `https://meet.example.org`).
+    pub base_url: String,
+    /// Whether this provider is enabled.
+    pub enabled: bool,
+    /// Maximum number of participants supported by the deployment.
+    pub max_participants: i32,
+
+    /// Application identifier used to issue room tokens (secured deployments).
+    pub jwt_app_id: Option<String>,
+    /// Application secret used to sign room tokens (secured deployments).
+    pub jwt_app_secret: Option<String>,
+}
+
+impl fmt::Debug for MeetingsJitsiConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeetingsJitsiConfig")
+            .field("base_url", &self.base_url)
+            .field("enabled", &self.enabled)
+            .field("max_participants", &self.max_participants)
+            .field("jwt_app_id", &self.jwt_app_id)
+            .field(
+                "jwt_app_secret",
+                &self.jwt_app_secret.as_ref().map(|_| REDACTED_CONFIG_VALUE),
+            )
+            .finish()
+    }
+}
+
+impl MeetingsJitsiConfig {
+    /// Validate Jitsi meetings configuration.
+    fn validate(&self) -> Result<()> {
+        // Skip validation when Jitsi meetings are disabled
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // Validate the deployment URL
+        if !self.base_url.starts_with("https://") && !self.base_url.starts_with("http://") {
+            bail!("meetings.jitsi.base_url must be an http(s) URL");
+        }
+
+        // Validate token authentication settings are provided together
+        let has_app_id = self.jwt_app_id.as_ref().is_some_and(|v| !v.trim().is_empty());
+        let has_app_secret = self.jwt_app_secret.as_ref().is_some_and(|v| !v.trim().is_empty());
+        if has_app_id != has_app_secret {
+            bail!(
+                "meetings.jitsi.jwt_app_id and meetings.jitsi.jwt_app_secret must be set together"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Microsoft Teams meetings configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct MeetingsTeamsConfig {
+    /// Entra ID application (client) identifier.
+    pub client_id: String,
+    /// Entra ID application client secret.
+    pub client_secret: String,
+    /// Whether this provider is enabled.
+    pub enabled: bool,
+    /// Maximum number of participants allowed in a meeting (Teams plan limit).
+    pub max_participants: i32,
+    /// User (ID or principal name) that organizes all online meetings.
+    pub organizer_user: String,
+    /// Entra ID tenant identifier.
+    pub tenant_id: String,
+}
+
+impl fmt::Debug for MeetingsTeamsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeetingsTeamsConfig")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &REDACTED_CONFIG_VALUE)
+            .field("enabled", &self.enabled)
+            .field("max_participants", &self.max_participants)
+            .field("organizer_user", &self.organizer_user)
+            .field("tenant_id", &self.tenant_id)
+            .finish()
+    }
+}
+
+impl MeetingsTeamsConfig {
+    /// Validate Microsoft Teams meetings configuration.
+    fn validate(&self) -> Result<()> {
+        // Skip validation when Teams meetings are disabled
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // Validate application credentials
+        if self.tenant_id.trim().is_empty() {
+            bail!("meetings.teams.tenant_id cannot be empty");
+        }
+        if self.client_id.trim().is_empty() {
+            bail!("meetings.teams.client_id cannot be empty");
+        }
+        if self.client_secret.trim().is_empty() {
+            bail!("meetings.teams.client_secret cannot be empty");
+        }
+
+        // Validate the meetings organizer
+        if self.organizer_user.trim().is_empty() {
+            bail!("meetings.teams.organizer_user cannot be empty");
+        }
+
+        Ok(())
+    }
+}
+
+/// Cisco Webex meetings configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct MeetingsWebexConfig {
+    /// Webex integration client identifier.
+    pub client_id: String,
+    /// Webex integration client secret.
+    pub client_secret: String,
+    /// Whether this provider is enabled.
+    pub enabled: bool,
+    /// Maximum number of participants allowed in a meeting (Webex plan limit).
+    pub max_participants: i32,
+    /// Refresh token of the user that hosts all meetings.
+    pub refresh_token: String,
+}
+
+impl fmt::Debug for MeetingsWebexConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeetingsWebexConfig")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &REDACTED_CONFIG_VALUE)
+            .field("enabled", &self.enabled)
+            .field("max_participants", &self.max_participants)
+            .field("refresh_token", &REDACTED_CONFIG_VALUE)
+            .finish()
+    }
+}
+
+impl MeetingsWebexConfig {
+    /// Validate Cisco Webex meetings configuration.
+    fn validate(&self) -> Result<()> {
+        // Skip validation when Webex meetings are disabled
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // Validate integration credentials
+        if self.client_id.trim().is_empty() {
+            bail!("meetings.webex.client_id cannot be empty");
+        }
+        if self.client_secret.trim().is_empty() {
+            bail!("meetings.webex.client_secret cannot be empty");
+        }
+        if self.refresh_token.trim().is_empty() {
+            bail!("meetings.webex.refresh_token cannot be empty");
+        }
+
+        Ok(())
+    }
 }
 
 /// Zoom meetings configuration.
@@ -324,6 +881,63 @@ impl PaymentsStripeConfig {
     }
 }
 
+/// SMS notifications configuration for the single active provider.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub(crate) enum SmsConfig {
+    /// Twilio SMS configuration.
+    Twilio(SmsTwilioConfig),
+}
+
+impl SmsConfig {
+    /// Validate the configured SMS provider.
+    fn validate(&self) -> Result<()> {
+        match self {
+            Self::Twilio(cfg) => cfg.validate(),
+        }
+    }
+}
+
+/// Twilio SMS configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct SmsTwilioConfig {
+    /// Twilio account SID.
+    pub account_sid: String,
+    /// Twilio auth token used to authenticate API requests.
+    pub auth_token: String,
+    /// Twilio phone number messages are sent from, in E.164 format.
+    pub from_number: String,
+}
+
+impl fmt::Debug for SmsTwilioConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmsTwilioConfig")
+            .field("account_sid", &self.account_sid)
+            .field("auth_token", &REDACTED_CONFIG_VALUE)
+            .field("from_number", &self.from_number)
+            .finish()
+    }
+}
+
+impl SmsTwilioConfig {
+    /// Validate Twilio SMS configuration.
+    fn validate(&self) -> Result<()> {
+        if self.account_sid.trim().is_empty() {
+            bail!("sms.account_sid cannot be empty");
+        }
+
+        if self.auth_token.trim().is_empty() {
+            bail!("sms.auth_token cannot be empty");
+        }
+
+        if self.from_number.trim().is_empty() {
+            bail!("sms.from_number cannot be empty");
+        }
+
+        Ok(())
+    }
+}
+
 /// SMTP server configuration.
 #[derive(Clone, PartialEq, Deserialize, Serialize)]
 pub(crate) struct SmtpConfig {
@@ -365,6 +979,30 @@ pub(crate) enum LogFormat {
     Pretty,
 }
 
+/// Notifications configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct NotificationsConfig {
+    /// Offsets, in minutes before the event starts, at which attendees are
+    /// reminded about upcoming events.
+    pub event_reminder_offsets_minutes: Vec<u32>,
+}
+
+impl NotificationsConfig {
+    /// Validate the notifications configuration.
+    fn validate(&self) -> Result<()> {
+        let mut offsets = HashSet::new();
+        for offset in &self.event_reminder_offsets_minutes {
+            if *offset == 0 || i32::try_from(*offset).is_err() {
+                bail!("notifications.event_reminder_offsets_minutes contains an invalid offset");
+            }
+            if !offsets.insert(offset) {
+                bail!("notifications.event_reminder_offsets_minutes contains duplicate offsets");
+            }
+        }
+        Ok(())
+    }
+}
+
 /// HTTP server configuration settings.
 #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub(crate) struct HttpServerConfig {
@@ -372,6 +1010,12 @@ pub(crate) struct HttpServerConfig {
     pub addr: String,
     /// Base URL for the server.
     pub base_url: String,
+    /// Custom domains configuration.
+    #[serde(default)]
+    pub custom_domains: CustomDomainsConfig,
+    /// Disable CAPTCHA verification on sign up (e.g. when running tests).
+    #[serde(default)]
+    pub disable_captcha_checks: bool,
     /// Disable referer header validation for image endpoints.
     pub disable_referer_checks: bool,
     /// Login options configuration.
@@ -380,11 +1024,83 @@ pub(crate) struct HttpServerConfig {
     pub oauth2: OAuth2Config,
     /// OIDC providers configuration.
     pub oidc: OidcConfig,
+    /// Session lifetime configuration.
+    #[serde(default)]
+    pub session: SessionConfig,
 
+    /// Optional CAPTCHA configuration used to protect sign ups.
+    pub captcha: Option<CaptchaConfig>,
     /// Optional cookie configuration.
     pub cookie: Option<CookieConfig>,
+    /// Optional LDAP directory configuration.
+    pub ldap: Option<LdapConfig>,
     /// Optional list of hostnames that should redirect to `base_url`.
     pub redirect_hosts: Option<Vec<String>>,
+    /// Optional list of proxy addresses allowed to set the `X-Forwarded-For` header.
+    pub trusted_proxies: Option<Vec<IpAddr>>,
+}
+
+impl HttpServerConfig {
+    /// Validate the HTTP server configuration.
+    fn validate(&self) -> Result<()> {
+        if let Some(captcha_cfg) = &self.captcha {
+            captcha_cfg.validate()?;
+        }
+        self.custom_domains.validate()?;
+        self.session.validate()?;
+        match &self.ldap {
+            Some(ldap_cfg) => ldap_cfg.validate()?,
+            None if self.login.ldap => bail!("server.ldap is required when ldap login is enabled"),
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+/// CAPTCHA configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct CaptchaConfig {
+    /// Provider used to challenge users.
+    pub provider: CaptchaProvider,
+    /// Secret key used to verify the challenge responses.
+    pub secret_key: String,
+    /// Site key used to render the challenge widget.
+    pub site_key: String,
+}
+
+impl fmt::Debug for CaptchaConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CaptchaConfig")
+            .field("provider", &self.provider)
+            .field("secret_key", &REDACTED_CONFIG_VALUE)
+            .field("site_key", &self.site_key)
+            .finish()
+    }
+}
+
+impl CaptchaConfig {
+    /// Validate CAPTCHA configuration.
+    fn validate(&self) -> Result<()> {
+        if self.secret_key.trim().is_empty() {
+            bail!("server.captcha.secret_key cannot be empty");
+        }
+
+        if self.site_key.trim().is_empty() {
+            bail!("server.captcha.site_key cannot be empty");
+        }
+
+        Ok(())
+    }
+}
+
+/// Supported CAPTCHA providers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum CaptchaProvider {
+    /// hCaptcha.
+    HCaptcha,
+    /// Cloudflare Turnstile.
+    Turnstile,
 }
 
 /// Cookie settings configuration.
@@ -402,8 +1118,100 @@ pub(crate) struct LoginOptions {
     pub email: bool,
     /// Enable GitHub login.
     pub github: bool,
+    /// Enable GitLab login.
+    pub gitlab: bool,
+    /// Enable Google login.
+    pub google: bool,
+    /// Enable LDAP (directory account) login.
+    pub ldap: bool,
     /// Enable Linux Foundation login.
     pub linuxfoundation: bool,
+    /// Enable magic link (passwordless email) login.
+    pub magic_link: bool,
+    /// Enable passkey login.
+    pub passkey: bool,
+}
+
+/// LDAP directory configuration.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct LdapConfig {
+    /// DN of the account used to search the directory.
+    pub bind_dn: String,
+    /// Password of the account used to search the directory.
+    pub bind_password: String,
+    /// Attribute holding the user's email address (e.g. `mail`).
+    pub email_attribute: String,
+    /// Attribute listing the DNs of the groups the user belongs to (e.g. `memberOf`).
+    pub group_attribute: String,
+    /// Attribute holding the user's display name (e.g. `displayName`).
+    pub name_attribute: String,
+    /// LDAP server URL (e.g. `ldaps://ldap.example.org`).
+    pub url: String,
+    /// Base DN users are searched under.
+    pub user_search_base: String,
+    /// Filter used to find users, where `{username}` is replaced with the
+    /// escaped username (e.g. `(sAMAccountName={username})`).
+    pub user_search_filter: String,
+
+    /// Community team roles granted to members of directory groups.
+    #[serde(default)]
+    pub group_role_mappings: Vec<LdapGroupRoleMapping>,
+}
+
+impl fmt::Debug for LdapConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LdapConfig")
+            .field("bind_dn", &self.bind_dn)
+            .field("bind_password", &REDACTED_CONFIG_VALUE)
+            .field("email_attribute", &self.email_attribute)
+            .field("group_attribute", &self.group_attribute)
+            .field("name_attribute", &self.name_attribute)
+            .field("url", &self.url)
+            .field("user_search_base", &self.user_search_base)
+            .field("user_search_filter", &self.user_search_filter)
+            .field("group_role_mappings", &self.group_role_mappings)
+            .finish()
+    }
+}
+
+impl LdapConfig {
+    /// Validate LDAP directory configuration.
+    fn validate(&self) -> Result<()> {
+        if self.url.trim().is_empty() {
+            bail!("server.ldap.url cannot be empty");
+        }
+
+        if self.bind_dn.trim().is_empty() {
+            bail!("server.ldap.bind_dn cannot be empty");
+        }
+
+        if self.bind_password.trim().is_empty() {
+            bail!("server.ldap.bind_password cannot be empty");
+        }
+
+        if !self.user_search_filter.contains("{username}") {
+            bail!("server.ldap.user_search_filter must contain the {{username}} placeholder");
+        }
+
+        for mapping in &self.group_role_mappings {
+            if mapping.group_dn.trim().is_empty() || mapping.community_name.trim().is_empty() {
+                bail!("server.ldap.group_role_mappings contains an incomplete mapping");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Mapping between a directory group and a community team role.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct LdapGroupRoleMapping {
+    /// Name of the community the role is granted in.
+    pub community_name: String,
+    /// DN of the directory group.
+    pub group_dn: String,
+    /// Role granted to the group members.
+    pub role: CommunityRole,
 }
 
 /// Type alias for the `OAuth2` configuration section.
@@ -416,6 +1224,12 @@ pub(crate) enum OAuth2Provider {
     /// GitHub as an `OAuth2` provider.
     #[strum(serialize = "github")]
     GitHub,
+    /// GitLab as an `OAuth2` provider.
+    #[strum(serialize = "gitlab")]
+    GitLab,
+    /// Google as an `OAuth2` provider.
+    #[strum(serialize = "google")]
+    Google,
 }
 
 /// `OAuth2` provider configuration.
@@ -452,7 +1266,7 @@ impl fmt::Debug for OAuth2ProviderConfig {
 pub(crate) type OidcConfig = HashMap<OidcProvider, OidcProviderConfig>;
 
 /// Supported OIDC providers.
-#[derive(AsRefStr, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(AsRefStr, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString)]
 #[serde(rename_all = "lowercase")]
 pub(crate) enum OidcProvider {
     /// Linux Foundation as an OIDC provider.
@@ -487,6 +1301,67 @@ impl fmt::Debug for OidcProviderConfig {
     }
 }
 
+/// Custom domains configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct CustomDomainsConfig {
+    /// DNS over HTTPS endpoint used to look up the domains verification records.
+    pub dns_resolver_url: String,
+}
+
+impl Default for CustomDomainsConfig {
+    fn default() -> Self {
+        Self {
+            dns_resolver_url: "https://cloudflare-dns.com/dns-query".to_string(),
+        }
+    }
+}
+
+impl CustomDomainsConfig {
+    /// Validate custom domains configuration.
+    fn validate(&self) -> Result<()> {
+        if Url::parse(&self.dns_resolver_url).is_err() {
+            bail!("server.custom_domains.dns_resolver_url must be a valid url");
+        }
+
+        Ok(())
+    }
+}
+
+/// Session lifetime configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct SessionConfig {
+    /// Hours without activity after which sessions expire.
+    pub idle_timeout_hours: u32,
+    /// Days after logging in after which sessions expire, even if still in use.
+    pub max_lifetime_days: u32,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            idle_timeout_hours: 168,
+            max_lifetime_days: 30,
+        }
+    }
+}
+
+impl SessionConfig {
+    /// Validate session lifetime configuration.
+    fn validate(&self) -> Result<()> {
+        if self.idle_timeout_hours == 0 {
+            bail!("server.session.idle_timeout_hours must be greater than zero");
+        }
+
+        if self.max_lifetime_days == 0 {
+            bail!("server.session.max_lifetime_days must be greater than zero");
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -497,11 +1372,18 @@ mod tests {
         let cfg = sample_config();
         let outputs = [
             format!("{cfg:?}"),
+            format!("{:?}", cfg.email.feedback),
+            format!("{:?}", cfg.email.sendgrid),
+            format!("{:?}", cfg.email.ses),
             format!("{:?}", cfg.email.smtp),
+            format!("{:?}", cfg.google_calendar),
             format!("{:?}", cfg.images),
             format!("{:?}", cfg.meetings),
             format!("{:?}", cfg.payments),
+            format!("{:?}", cfg.server.captcha),
+            format!("{:?}", cfg.server.ldap),
             format!("{:?}", cfg.server.oauth2),
+            format!("{:?}", cfg.sms),
             format!("{:?}", cfg.server.oidc),
         ];
 
@@ -517,6 +1399,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn captcha_config_validate_rejects_empty_keys() {
+        let mut cfg = sample_config().server;
+        assert!(cfg.validate().is_ok());
+
+        cfg.captcha.as_mut().unwrap().secret_key = " ".to_string();
+        assert!(cfg.validate().is_err());
+
+        let mut cfg = sample_config().server;
+        cfg.captcha.as_mut().unwrap().site_key = String::new();
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn custom_domains_config_validate_rejects_invalid_resolver_url() {
+        let mut cfg = CustomDomainsConfig::default();
+        assert!(cfg.validate().is_ok());
+
+        cfg.dns_resolver_url = "cloudflare-dns.com/dns-query".to_string();
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn email_config_validate_requires_selected_provider_config() {
+        let mut cfg = sample_config().email;
+        assert!(cfg.validate().is_ok());
+
+        cfg.provider = EmailProvider::Sendgrid;
+        assert!(cfg.validate().is_ok());
+
+        cfg.sendgrid = None;
+        assert!(cfg.validate().is_err());
+
+        cfg.provider = EmailProvider::Ses;
+        assert!(cfg.validate().is_ok());
+
+        cfg.ses = None;
+        assert!(cfg.validate().is_err());
+
+        cfg.provider = EmailProvider::Smtp;
+        cfg.smtp = None;
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn email_ses_config_validate_rejects_empty_credentials() {
+        let mut cfg = sample_config().email.ses.unwrap();
+        cfg.secret_access_key = " ".to_string();
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn google_calendar_config_validate_rejects_empty_credentials() {
+        let mut cfg = sample_config().google_calendar.unwrap();
+        assert!(cfg.validate().is_ok());
+
+        cfg.client_secret = " ".to_string();
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn http_server_config_validate_requires_ldap_config_when_enabled() {
+        let mut cfg = sample_config().server;
+        assert!(cfg.validate().is_ok());
+
+        cfg.ldap = None;
+        assert!(cfg.validate().is_err());
+
+        cfg.login.ldap = false;
+        assert!(cfg.validate().is_ok());
+    }
+
+    #[test]
+    fn ldap_config_validate_rejects_invalid_settings() {
+        let mut cfg = sample_ldap_config();
+        cfg.bind_password = " ".to_string();
+        assert!(cfg.validate().is_err());
+
+        let mut cfg = sample_ldap_config();
+        cfg.user_search_filter = "(uid=*)".to_string();
+        assert!(cfg.validate().is_err());
+
+        let mut cfg = sample_ldap_config();
+        cfg.group_role_mappings[0].group_dn = String::new();
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn notifications_config_validate_rejects_invalid_offsets() {
+        for offsets in [vec![0], vec![60, 60], vec![u32::MAX]] {
+            let cfg = NotificationsConfig {
+                event_reminder_offsets_minutes: offsets,
+            };
+            assert!(cfg.validate().is_err());
+        }
+
+        let cfg = NotificationsConfig {
+            event_reminder_offsets_minutes: DEFAULT_EVENT_REMINDER_OFFSETS_MINUTES.to_vec(),
+        };
+        assert!(cfg.validate().is_ok());
+    }
+
+    #[test]
+    fn session_config_validate_rejects_zero_lifetimes() {
+        let mut cfg = SessionConfig::default();
+        assert!(cfg.validate().is_ok());
+
+        cfg.idle_timeout_hours = 0;
+        assert!(cfg.validate().is_err());
+
+        let mut cfg = SessionConfig::default();
+        cfg.max_lifetime_days = 0;
+        assert!(cfg.validate().is_err());
+    }
+
     // Helpers.
 
     fn sample_config() -> Config {
@@ -550,13 +1547,28 @@ mod tests {
             email: EmailConfig {
                 from_address: "noreply@example.test".to_string(),
                 from_name: "OCG".to_string(),
-                smtp: SmtpConfig {
+                provider: EmailProvider::Smtp,
+                feedback: Some(EmailFeedbackConfig {
+                    webhook_secret: "email-feedback-sensitive-value".to_string(),
+                }),
+                rcpts_whitelist: None,
+                sendgrid: Some(EmailSendgridConfig {
+                    api_key: "sendgrid-sensitive-value".to_string(),
+                    sandbox_mode: Some(true),
+                }),
+                ses: Some(EmailSesConfig {
+                    access_key_id: "ses-access-key-id".to_string(),
+                    region: "us-east-1".to_string(),
+                    secret_access_key: "ses-sensitive-value".to_string(),
+                    configuration_set: Some("ocg-delivery-events".to_string()),
+                    endpoint: None,
+                }),
+                smtp: Some(SmtpConfig {
                     host: "smtp.example.test".to_string(),
                     password: "smtp-sensitive-value".to_string(),
                     port: 587,
                     username: "smtp-user".to_string(),
-                },
-                rcpts_whitelist: None,
+                }),
             },
             images: ImageStorageConfig::S3(ImageStorageConfigS3 {
                 access_key_id: "s3-access-key-id".to_string(),
@@ -569,21 +1581,80 @@ mod tests {
             log: LogConfig {
                 format: LogFormat::Json,
             },
+            notifications: NotificationsConfig {
+                event_reminder_offsets_minutes: DEFAULT_EVENT_REMINDER_OFFSETS_MINUTES.to_vec(),
+            },
             server: HttpServerConfig {
                 addr: "127.0.0.1:9000".to_string(),
                 base_url: "https://app.example.test".to_string(),
+                custom_domains: CustomDomainsConfig::default(),
+                disable_captcha_checks: false,
                 disable_referer_checks: false,
                 login: LoginOptions {
                     email: true,
                     github: true,
+                    gitlab: false,
+                    google: false,
+                    ldap: true,
                     linuxfoundation: true,
+                    magic_link: false,
+                    passkey: false,
                 },
                 oauth2,
                 oidc,
+                session: SessionConfig::default(),
+                captcha: Some(CaptchaConfig {
+                    provider: CaptchaProvider::Turnstile,
+                    secret_key: "captcha-secret-sensitive-value".to_string(),
+                    site_key: "captcha-site-key".to_string(),
+                }),
                 cookie: None,
+                ldap: Some(sample_ldap_config()),
                 redirect_hosts: None,
+                trusted_proxies: None,
             },
+            google_calendar: Some(GoogleCalendarConfig {
+                client_id: "google-calendar-client-id".to_string(),
+                client_secret: "google-calendar-sensitive-value".to_string(),
+                redirect_uri: "https://app.example.test/dashboard/account/google-calendar/callback"
+                    .to_string(),
+            }),
             meetings: Some(MeetingsConfig {
+                bigbluebutton: Some(MeetingsBigBlueButtonConfig {
+                    api_url: "https://bbb.example.test/bigbluebutton/api".to_string(),
+                    enabled: true,
+                    max_participants: 100,
+                    shared_secret: "bigbluebutton-secret-sensitive-value".to_string(),
+                }),
+                google_meet: Some(MeetingsGoogleMeetConfig {
+                    client_email: "meet@example.iam.gserviceaccount.test".to_string(),
+                    delegated_user: "meetings@example.test".to_string(),
+                    enabled: true,
+                    max_participants: 100,
+                    private_key: "google-meet-key-sensitive-value".to_string(),
+                }),
+                jitsi: Some(MeetingsJitsiConfig {
+                    base_url: "https://meet.example.test".to_string(),
+                    enabled: true,
+                    max_participants: 100,
+                    jwt_app_id: Some("ocg".to_string()),
+                    jwt_app_secret: Some("jitsi-secret-sensitive-value".to_string()),
+                }),
+                teams: Some(MeetingsTeamsConfig {
+                    client_id: "teams-client-id".to_string(),
+                    client_secret: "teams-client-sensitive-value".to_string(),
+                    enabled: true,
+                    max_participants: 300,
+                    organizer_user: "organizer@example.test".to_string(),
+                    tenant_id: "teams-tenant-id".to_string(),
+                }),
+                webex: Some(MeetingsWebexConfig {
+                    client_id: "webex-client-id".to_string(),
+                    client_secret: "webex-client-sensitive-value".to_string(),
+                    enabled: true,
+                    max_participants: 200,
+                    refresh_token: "webex-refresh-sensitive-value".to_string(),
+                }),
                 zoom: Some(MeetingsZoomConfig {
                     account_id: "zoom-account-id".to_string(),
                     client_id: "zoom-client-id".to_string(),
@@ -601,6 +1672,11 @@ mod tests {
                 secret_key: "stripe-key-sensitive-value".to_string(),
                 webhook_secret: "stripe-webhook-sensitive-value".to_string(),
             })),
+            sms: Some(SmsConfig::Twilio(SmsTwilioConfig {
+                account_sid: "twilio-account-sid".to_string(),
+                auth_token: "twilio-token-sensitive-value".to_string(),
+                from_number: "+15550000000".to_string(),
+            })),
         }
     }
 
@@ -611,16 +1687,47 @@ mod tests {
         cfg
     }
 
-    fn sensitive_values() -> [&'static str; 10] {
+    fn sample_ldap_config() -> LdapConfig {
+        LdapConfig {
+            bind_dn: "cn=ocg,ou=services,dc=example,dc=test".to_string(),
+            bind_password: "ldap-bind-sensitive-value".to_string(),
+            email_attribute: "mail".to_string(),
+            group_attribute: "memberOf".to_string(),
+            name_attribute: "displayName".to_string(),
+            url: "ldaps://ldap.example.test".to_string(),
+            user_search_base: "ou=people,dc=example,dc=test".to_string(),
+            user_search_filter: "(uid={username})".to_string(),
+            group_role_mappings: vec![LdapGroupRoleMapping {
+                community_name: "test-community".to_string(),
+                group_dn: "cn=organizers,ou=groups,dc=example,dc=test".to_string(),
+                role: CommunityRole::GroupsManager,
+            }],
+        }
+    }
+
+    fn sensitive_values() -> [&'static str; 23] {
         [
             "db-password-sensitive-value",
             "db-url-sensitive-value",
+            "bigbluebutton-secret-sensitive-value",
+            "captcha-secret-sensitive-value",
+            "email-feedback-sensitive-value",
+            "google-calendar-sensitive-value",
+            "google-meet-key-sensitive-value",
+            "jitsi-secret-sensitive-value",
+            "ldap-bind-sensitive-value",
             "oauth2-sensitive-value",
             "oidc-sensitive-value",
             "s3-sensitive-value",
+            "sendgrid-sensitive-value",
+            "ses-sensitive-value",
             "smtp-sensitive-value",
             "stripe-key-sensitive-value",
             "stripe-webhook-sensitive-value",
+            "teams-client-sensitive-value",
+            "twilio-token-sensitive-value",
+            "webex-client-sensitive-value",
+            "webex-refresh-sensitive-value",
             "zoom-client-sensitive-value",
             "zoom-webhook-sensitive-value",
         ]
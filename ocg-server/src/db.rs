@@ -10,8 +10,9 @@ use tokio_postgres::types::{FromSql, Json, ToSql};
 
 use crate::db::{
     activity_tracker::DBActivityTracker, auth::DBAuth, common::DBCommon, community::DBCommunity,
-    dashboard::DBDashboard, event::DBEvent, group::DBGroup, images::DBImages, meetings::DBMeetings,
-    notifications::DBNotifications, payments::DBPayments, site::DBSite,
+    dashboard::DBDashboard, event::DBEvent, google_calendar::DBGoogleCalendar, group::DBGroup,
+    images::DBImages, meetings::DBMeetings, notifications::DBNotifications, payments::DBPayments,
+    site::DBSite,
 };
 
 /// Module containing database functionality for the activity tracker.
@@ -36,12 +37,18 @@ pub(crate) mod dashboard;
 /// Module containing database functionality for the event page.
 pub(crate) mod event;
 
+/// Module containing database functionality for syncing Google Calendars.
+pub(crate) mod google_calendar;
+
 /// Module containing database functionality for the group site.
 pub(crate) mod group;
 
 /// Module containing database functionality for storing images.
 pub(crate) mod images;
 
+/// Module containing Postgres LISTEN/NOTIFY support.
+pub(crate) mod listener;
+
 /// Module containing database functionality for managing meetings.
 pub(crate) mod meetings;
 
@@ -69,6 +76,7 @@ pub(crate) trait DBOperations:
     + DBCommunity
     + DBDashboard
     + DBEvent
+    + DBGoogleCalendar
     + DBGroup
     + DBImages
     + DBMeetings
@@ -87,6 +95,7 @@ impl<T> DBOperations for T where
         + DBCommunity
         + DBDashboard
         + DBEvent
+        + DBGoogleCalendar
         + DBGroup
         + DBImages
         + DBMeetings
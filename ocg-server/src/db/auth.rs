@@ -1,18 +1,26 @@
 //! Database operations for authentication and authorization.
 
+use std::net::IpAddr;
+
 use anyhow::Result;
 use async_trait::async_trait;
 use axum_login::tower_sessions::session;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL};
 use tokio_postgres::types::Json;
 use tracing::instrument;
 use uuid::Uuid;
+use webauthn_rs::prelude::Passkey;
 
 use crate::{
     auth::{User, UserSummary},
+    config::{LdapGroupRoleMapping, OAuth2Provider},
     db::PgExecutor,
-    templates::{auth::UserDetails, notifications::EmailVerification},
+    templates::{
+        auth::{UserDetails, UserPhoneNumberUpdate, UserPhoneVerification, UserWebhookUpdate},
+        notifications::EmailVerification,
+    },
     types::permissions::{CommunityPermission, GroupPermission},
-    types::user::UserProvider,
+    types::user::{UserPasskey, UserPhone, UserProvider, UserSession, UserWebhook},
 };
 
 /// Trait for database operations related to authentication and authorization.
@@ -32,12 +40,69 @@ pub(crate) trait DBAuth {
         user_summary: &UserSummary,
     ) -> Result<User>;
 
+    /// Adds a user to the community teams their directory groups are mapped to.
+    async fn add_user_ldap_community_roles(
+        &self,
+        user_id: &Uuid,
+        mappings: &[LdapGroupRoleMapping],
+    ) -> Result<()>;
+
+    /// Stores a log in link for the verified user with the email provided,
+    /// returning the user's ID when the link was stored.
+    async fn add_user_magic_link(&self, email: &str, token_hash: &str) -> Result<Option<Uuid>>;
+
+    /// Stores a passkey registered by a user.
+    async fn add_user_passkey(
+        &self,
+        actor_user_id: &Uuid,
+        name: &str,
+        passkey: &Passkey,
+    ) -> Result<Uuid>;
+
+    /// Stores a password reset code for the verified user with the email
+    /// provided, returning the user's ID when the code was stored.
+    async fn add_user_password_reset(&self, email: &str, code_hash: &str) -> Result<Option<Uuid>>;
+
+    /// Checks whether an email address can be used to sign up from a community.
+    async fn community_email_domain_allowed(
+        &self,
+        community_name: &str,
+        email: &str,
+    ) -> Result<bool>;
+
+    /// Checks whether a community requires passkey sign-in for its dashboard.
+    async fn community_passkey_required(&self, community_id: &Uuid) -> Result<bool>;
+
     /// Creates a new session in the database.
     async fn create_session(&self, record: &session::Record) -> Result<()>;
 
     /// Deletes a session from the database.
     async fn delete_session(&self, session_id: &session::Id) -> Result<()>;
 
+    /// Deletes a passkey registered by a user.
+    async fn delete_user_passkey(&self, actor_user_id: &Uuid, user_passkey_id: &Uuid)
+    -> Result<()>;
+
+    /// Retrieves the OIDC provider a community requires users to log in with.
+    async fn get_community_required_oidc_provider(
+        &self,
+        community_name: &str,
+    ) -> Result<Option<String>>;
+
+    /// Retrieves the OIDC provider a community, looked up by id, requires
+    /// users to log in with.
+    async fn get_community_required_oidc_provider_by_id(
+        &self,
+        community_id: &Uuid,
+    ) -> Result<Option<String>>;
+
+    /// Retrieves the community and group of an event hosted by the user.
+    async fn get_hosted_event_group(
+        &self,
+        event_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Option<(Uuid, Uuid)>>;
+
     /// Retrieves a session by its ID.
     async fn get_session(&self, session_id: &session::Id) -> Result<Option<session::Record>>;
 
@@ -54,12 +119,31 @@ pub(crate) trait DBAuth {
         subject: &str,
     ) -> Result<Option<User>>;
 
+    /// Retrieves a verified registered user by `OAuth2` account ID for external auth.
+    async fn get_user_by_oauth2_identity_for_external_auth(
+        &self,
+        provider: &OAuth2Provider,
+        id: &str,
+    ) -> Result<Option<User>>;
+
     /// Retrieves a user by their username.
     async fn get_user_by_username(&self, username: &str) -> Result<Option<User>>;
 
+    /// Retrieves the token of a user's personal calendar feed, if enabled.
+    async fn get_user_calendar_token(&self, user_id: &Uuid) -> Result<Option<String>>;
+
+    /// Retrieves the passkeys a user can sign in with.
+    async fn get_user_passkeys(&self, user_id: &Uuid) -> Result<Vec<Passkey>>;
+
     /// Retrieves the password hash for a user.
     async fn get_user_password(&self, user_id: &Uuid) -> Result<Option<String>>;
 
+    /// Retrieves the phone number a user's SMS notifications are sent to, if any.
+    async fn get_user_phone(&self, user_id: &Uuid) -> Result<Option<UserPhone>>;
+
+    /// Retrieves the webhook a user's notifications are posted to, if any.
+    async fn get_user_webhook(&self, user_id: &Uuid) -> Result<Option<UserWebhook>>;
+
     /// Checks whether a group belongs to a community.
     async fn group_belongs_to_community(
         &self,
@@ -67,6 +151,57 @@ pub(crate) trait DBAuth {
         group_id: &Uuid,
     ) -> Result<bool>;
 
+    /// Checks whether password log ins are temporarily locked for the username
+    /// or address provided.
+    async fn is_log_in_locked(&self, username: &str, ip_address: Option<IpAddr>) -> Result<bool>;
+
+    /// Links an external provider identity to a user's account.
+    async fn link_user_provider(&self, user_id: &Uuid, provider: &UserProvider) -> Result<()>;
+
+    /// Lists the passkeys registered by a user.
+    async fn list_user_passkeys(&self, user_id: &Uuid) -> Result<Vec<UserPasskey>>;
+
+    /// Lists the active sessions of a user, flagging the current one.
+    async fn list_user_sessions(
+        &self,
+        user_id: &Uuid,
+        current_session_id: Option<session::Id>,
+    ) -> Result<Vec<UserSession>>;
+
+    /// Consumes a log in link that has not expired, returning the user it was
+    /// issued to.
+    async fn redeem_user_magic_link(&self, token_hash: &str) -> Result<Option<Uuid>>;
+
+    /// Records a failed password log in attempt, returning whether it locked
+    /// the username or address provided.
+    async fn register_failed_log_in(
+        &self,
+        username: &str,
+        ip_address: Option<IpAddr>,
+    ) -> Result<bool>;
+
+    /// Replaces the verification code of the unverified user with the email
+    /// provided and enqueues a new verification email, returning whether it was
+    /// sent.
+    async fn resend_email_verification(
+        &self,
+        email: &str,
+        verification: &EmailVerificationNotification,
+    ) -> Result<bool>;
+
+    /// Discards the failed log in attempts of the username provided.
+    async fn reset_failed_log_ins(&self, username: &str) -> Result<()>;
+
+    /// Consumes a password reset code that has not expired and sets the new
+    /// password, returning the user it was issued to.
+    async fn reset_user_password(&self, code_hash: &str, password: &str) -> Result<Option<Uuid>>;
+
+    /// Revokes one of the sessions of a user.
+    async fn revoke_user_session(&self, user_id: &Uuid, session_id: &Uuid) -> Result<()>;
+
+    /// Revokes all the sessions of a user, logging them out everywhere.
+    async fn revoke_user_sessions(&self, user_id: &Uuid) -> Result<()>;
+
     /// Registers a new user in the database.
     async fn sign_up_user(
         &self,
@@ -75,9 +210,15 @@ pub(crate) trait DBAuth {
         verification: Option<EmailVerificationNotification>,
     ) -> Result<(User, Option<Uuid>)>;
 
+    /// Removes an external provider identity from a user's account.
+    async fn unlink_user_provider(&self, user_id: &Uuid, provider: &str) -> Result<()>;
+
     /// Updates an existing session in the database.
     async fn update_session(&self, record: &session::Record) -> Result<()>;
 
+    /// Enables, regenerating its token, or disables a user's personal calendar feed.
+    async fn update_user_calendar_feed(&self, actor_user_id: &Uuid, enabled: bool) -> Result<()>;
+
     /// Updates user details in the database.
     async fn update_user_details(&self, actor_user_id: &Uuid, user: &UserDetails) -> Result<()>;
 
@@ -88,12 +229,36 @@ pub(crate) trait DBAuth {
         user_summary: &UserSummary,
     ) -> Result<User>;
 
+    /// Stores the updated state of a passkey after a sign-in.
+    async fn update_user_passkey(&self, user_id: &Uuid, passkey: &Passkey) -> Result<()>;
+
     /// Updates a user's password in the database.
     async fn update_user_password(&self, actor_user_id: &Uuid, new_password: &str) -> Result<()>;
 
+    /// Sets or clears a user's phone number, sending a verification code by SMS.
+    async fn update_user_phone_number(
+        &self,
+        actor_user_id: &Uuid,
+        phone: &UserPhoneNumberUpdate,
+    ) -> Result<()>;
+
     /// Updates externally sourced provider metadata for a user.
     async fn update_user_provider(&self, user_id: &Uuid, provider: &UserProvider) -> Result<()>;
 
+    /// Enables or disables the SMS notifications of a user.
+    async fn update_user_sms_notifications(
+        &self,
+        actor_user_id: &Uuid,
+        enabled: bool,
+    ) -> Result<()>;
+
+    /// Sets or clears the webhook a user's notifications are posted to.
+    async fn update_user_webhook(
+        &self,
+        actor_user_id: &Uuid,
+        webhook: &UserWebhookUpdate,
+    ) -> Result<()>;
+
     /// Checks whether a user has a permission in a specific community.
     async fn user_has_community_permission(
         &self,
@@ -111,8 +276,24 @@ pub(crate) trait DBAuth {
         permission: GroupPermission,
     ) -> Result<bool>;
 
+    /// Checks whether a user hosts a specific event of a group.
+    async fn user_is_event_host(
+        &self,
+        community_id: &Uuid,
+        group_id: &Uuid,
+        event_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<bool>;
+
     /// Verifies a user's email address using a verification code.
     async fn verify_email(&self, code: &Uuid) -> Result<()>;
+
+    /// Verifies a user's phone number using the code sent by SMS.
+    async fn verify_user_phone_number(
+        &self,
+        actor_user_id: &Uuid,
+        verification: &UserPhoneVerification,
+    ) -> Result<bool>;
 }
 
 #[async_trait]
@@ -165,6 +346,78 @@ where
         .await
     }
 
+    #[instrument(skip(self, mappings), err)]
+    async fn add_user_ldap_community_roles(
+        &self,
+        user_id: &Uuid,
+        mappings: &[LdapGroupRoleMapping],
+    ) -> Result<()> {
+        self.execute(
+            "select add_user_ldap_community_roles($1::uuid, $2::jsonb);",
+            &[user_id, &Json(mappings)],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, email, token_hash), err)]
+    async fn add_user_magic_link(&self, email: &str, token_hash: &str) -> Result<Option<Uuid>> {
+        self.fetch_scalar_opt(
+            "select add_user_magic_link($1::text, $2::text);",
+            &[&email, &token_hash],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, passkey), err)]
+    async fn add_user_passkey(
+        &self,
+        actor_user_id: &Uuid,
+        name: &str,
+        passkey: &Passkey,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_user_passkey($1::uuid, $2::text, $3::text, $4::jsonb);",
+            &[
+                actor_user_id,
+                &credential_id(passkey),
+                &name,
+                &Json(passkey),
+            ],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, email, code_hash), err)]
+    async fn add_user_password_reset(&self, email: &str, code_hash: &str) -> Result<Option<Uuid>> {
+        self.fetch_scalar_opt(
+            "select add_user_password_reset($1::text, $2::text);",
+            &[&email, &code_hash],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, email), err)]
+    async fn community_email_domain_allowed(
+        &self,
+        community_name: &str,
+        email: &str,
+    ) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select community_email_domain_allowed($1::text, $2::text);",
+            &[&community_name, &email],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn community_passkey_required(&self, community_id: &Uuid) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select community_passkey_required($1::uuid);",
+            &[community_id],
+        )
+        .await
+    }
+
     #[instrument(skip(self, record), err)]
     async fn create_session(&self, record: &session::Record) -> Result<()> {
         self.execute(
@@ -197,12 +450,78 @@ where
         .await
     }
 
+    #[instrument(skip(self), err)]
+    async fn delete_user_passkey(
+        &self,
+        actor_user_id: &Uuid,
+        user_passkey_id: &Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_user_passkey($1::uuid, $2::uuid);",
+            &[actor_user_id, user_passkey_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_community_required_oidc_provider(
+        &self,
+        community_name: &str,
+    ) -> Result<Option<String>> {
+        self.fetch_scalar_opt(
+            "select get_community_required_oidc_provider($1::text);",
+            &[&community_name],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_community_required_oidc_provider_by_id(
+        &self,
+        community_id: &Uuid,
+    ) -> Result<Option<String>> {
+        self.fetch_scalar_opt(
+            "select get_community_required_oidc_provider_by_id($1::uuid);",
+            &[community_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_hosted_event_group(
+        &self,
+        event_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Option<(Uuid, Uuid)>> {
+        let db = self.client().await?;
+        let row = db
+            .query_opt(
+                "select community_id, group_id from get_hosted_event_group($1::uuid, $2::uuid);",
+                &[&event_id, &user_id],
+            )
+            .await?;
+
+        Ok(row.map(|row| (row.get("community_id"), row.get("group_id"))))
+    }
+
     #[instrument(skip(self, session_id), err)]
     async fn get_session(&self, session_id: &session::Id) -> Result<Option<session::Record>> {
         let db = self.client().await?;
         let row = db
             .query_opt(
-                "select data, expires_at from auth_session where auth_session_id = $1::text;",
+                "
+                with seen as (
+                    update auth_session
+                    set last_seen_at = current_timestamp
+                    where auth_session_id = $1::text
+                    and expires_at > current_timestamp
+                    and last_seen_at < current_timestamp - interval '1 minute'
+                )
+                select data, expires_at
+                from auth_session
+                where auth_session_id = $1::text
+                and expires_at > current_timestamp;
+                ",
                 &[&session_id.to_string()],
             )
             .await?;
@@ -247,12 +566,40 @@ where
         .await
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_user_by_oauth2_identity_for_external_auth(
+        &self,
+        provider: &OAuth2Provider,
+        id: &str,
+    ) -> Result<Option<User>> {
+        self.fetch_json_opt(
+            "select get_user_by_oauth2_identity_for_external_auth($1::text, $2::text);",
+            &[&provider.as_ref(), &id],
+        )
+        .await
+    }
+
     #[instrument(skip(self, username), err)]
     async fn get_user_by_username(&self, username: &str) -> Result<Option<User>> {
         self.fetch_json_opt("select get_user_by_username($1::text);", &[&username])
             .await
     }
 
+    #[instrument(skip(self, user_id), err)]
+    async fn get_user_calendar_token(&self, user_id: &Uuid) -> Result<Option<String>> {
+        self.fetch_scalar_opt(
+            r#"select calendar_token from "user" where user_id = $1::uuid;"#,
+            &[&user_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_user_passkeys(&self, user_id: &Uuid) -> Result<Vec<Passkey>> {
+        self.fetch_json_one("select get_user_passkeys($1::uuid);", &[user_id])
+            .await
+    }
+
     #[instrument(skip(self, user_id), err)]
     async fn get_user_password(&self, user_id: &Uuid) -> Result<Option<String>> {
         self.fetch_scalar_opt(
@@ -262,6 +609,45 @@ where
         .await
     }
 
+    #[instrument(skip(self, user_id), err)]
+    async fn get_user_phone(&self, user_id: &Uuid) -> Result<Option<UserPhone>> {
+        self.fetch_json_opt(
+            r#"
+            select (
+                select json_build_object(
+                    'number', phone_number,
+                    'sms_notifications_enabled', sms_notifications_enabled,
+                    'verified', phone_number_verified
+                )
+                from "user"
+                where user_id = $1::uuid
+                and phone_number is not null
+            );
+            "#,
+            &[&user_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, user_id), err)]
+    async fn get_user_webhook(&self, user_id: &Uuid) -> Result<Option<UserWebhook>> {
+        self.fetch_json_opt(
+            r#"
+            select (
+                select json_build_object(
+                    'secret', webhook_secret,
+                    'url', webhook_url
+                )
+                from "user"
+                where user_id = $1::uuid
+                and webhook_url is not null
+            );
+            "#,
+            &[&user_id],
+        )
+        .await
+    }
+
     #[instrument(skip(self), err)]
     async fn group_belongs_to_community(
         &self,
@@ -283,6 +669,106 @@ where
         .await
     }
 
+    #[instrument(skip(self, username), err)]
+    async fn is_log_in_locked(&self, username: &str, ip_address: Option<IpAddr>) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select is_log_in_locked($1::text, $2::inet);",
+            &[&username, &ip_address],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, provider), err)]
+    async fn link_user_provider(&self, user_id: &Uuid, provider: &UserProvider) -> Result<()> {
+        self.execute(
+            "select link_user_provider($1::uuid, $2::jsonb);",
+            &[user_id, &Json(provider)],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list_user_passkeys(&self, user_id: &Uuid) -> Result<Vec<UserPasskey>> {
+        self.fetch_json_one("select list_user_passkeys($1::uuid);", &[user_id])
+            .await
+    }
+
+    #[instrument(skip(self, current_session_id), err)]
+    async fn list_user_sessions(
+        &self,
+        user_id: &Uuid,
+        current_session_id: Option<session::Id>,
+    ) -> Result<Vec<UserSession>> {
+        self.fetch_json_one(
+            "select list_user_sessions($1::uuid, $2::text);",
+            &[user_id, &current_session_id.map(|id| id.to_string())],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, token_hash), err)]
+    async fn redeem_user_magic_link(&self, token_hash: &str) -> Result<Option<Uuid>> {
+        self.fetch_scalar_opt("select redeem_user_magic_link($1::text);", &[&token_hash])
+            .await
+    }
+
+    #[instrument(skip(self, username), err)]
+    async fn register_failed_log_in(
+        &self,
+        username: &str,
+        ip_address: Option<IpAddr>,
+    ) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select register_failed_log_in($1::text, $2::inet);",
+            &[&username, &ip_address],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, email, verification), err)]
+    async fn resend_email_verification(
+        &self,
+        email: &str,
+        verification: &EmailVerificationNotification,
+    ) -> Result<bool> {
+        let template_data = serde_json::to_value(&verification.template_data)?;
+        self.fetch_scalar_one(
+            "select resend_email_verification($1::text, $2::uuid, $3::jsonb);",
+            &[&email, &verification.code, &template_data],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, username), err)]
+    async fn reset_failed_log_ins(&self, username: &str) -> Result<()> {
+        self.execute("select reset_failed_log_ins($1::text);", &[&username])
+            .await
+    }
+
+    #[instrument(skip(self, code_hash, password), err)]
+    async fn reset_user_password(&self, code_hash: &str, password: &str) -> Result<Option<Uuid>> {
+        self.fetch_scalar_opt(
+            "select reset_user_password($1::text, $2::text);",
+            &[&code_hash, &password],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn revoke_user_session(&self, user_id: &Uuid, session_id: &Uuid) -> Result<()> {
+        self.execute(
+            "select revoke_user_session($1::uuid, $2::uuid);",
+            &[user_id, session_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn revoke_user_sessions(&self, user_id: &Uuid) -> Result<()> {
+        self.execute("select revoke_user_sessions($1::uuid);", &[user_id])
+            .await
+    }
+
     #[instrument(skip(self, user_summary, verification), err)]
     async fn sign_up_user(
         &self,
@@ -323,6 +809,15 @@ where
         Ok((user, verification_code))
     }
 
+    #[instrument(skip(self), err)]
+    async fn unlink_user_provider(&self, user_id: &Uuid, provider: &str) -> Result<()> {
+        self.execute(
+            "select unlink_user_provider($1::uuid, $2::text);",
+            &[user_id, &provider],
+        )
+        .await
+    }
+
     #[instrument(skip(self, record), err)]
     async fn update_session(&self, record: &session::Record) -> Result<()> {
         self.execute(
@@ -342,6 +837,15 @@ where
         .await
     }
 
+    #[instrument(skip(self), err)]
+    async fn update_user_calendar_feed(&self, actor_user_id: &Uuid, enabled: bool) -> Result<()> {
+        self.execute(
+            "select update_user_calendar_feed($1::uuid, $2::boolean);",
+            &[actor_user_id, &enabled],
+        )
+        .await
+    }
+
     #[instrument(skip(self, user), err)]
     async fn update_user_details(&self, actor_user_id: &Uuid, user: &UserDetails) -> Result<()> {
         self.execute(
@@ -364,6 +868,15 @@ where
         .await
     }
 
+    #[instrument(skip(self, passkey), err)]
+    async fn update_user_passkey(&self, user_id: &Uuid, passkey: &Passkey) -> Result<()> {
+        self.execute(
+            "select update_user_passkey($1::uuid, $2::text, $3::jsonb);",
+            &[user_id, &credential_id(passkey), &Json(passkey)],
+        )
+        .await
+    }
+
     #[instrument(skip(self, new_password), err)]
     async fn update_user_password(&self, actor_user_id: &Uuid, new_password: &str) -> Result<()> {
         self.execute(
@@ -373,6 +886,19 @@ where
         .await
     }
 
+    #[instrument(skip(self, phone), err)]
+    async fn update_user_phone_number(
+        &self,
+        actor_user_id: &Uuid,
+        phone: &UserPhoneNumberUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_user_phone_number($1::uuid, $2::text);",
+            &[actor_user_id, &phone.phone_number],
+        )
+        .await
+    }
+
     #[instrument(skip(self, provider), err)]
     async fn update_user_provider(&self, user_id: &Uuid, provider: &UserProvider) -> Result<()> {
         self.execute(
@@ -382,6 +908,32 @@ where
         .await
     }
 
+    #[instrument(skip(self), err)]
+    async fn update_user_sms_notifications(
+        &self,
+        actor_user_id: &Uuid,
+        enabled: bool,
+    ) -> Result<()> {
+        self.execute(
+            "select update_user_sms_notifications($1::uuid, $2::boolean);",
+            &[actor_user_id, &enabled],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, webhook), err)]
+    async fn update_user_webhook(
+        &self,
+        actor_user_id: &Uuid,
+        webhook: &UserWebhookUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_user_webhook($1::uuid, $2::text);",
+            &[actor_user_id, &webhook.webhook_url],
+        )
+        .await
+    }
+
     #[instrument(skip(self, permission), err)]
     async fn user_has_community_permission(
         &self,
@@ -411,10 +963,38 @@ where
         .await
     }
 
+    #[instrument(skip(self), err)]
+    async fn user_is_event_host(
+        &self,
+        community_id: &Uuid,
+        group_id: &Uuid,
+        event_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select user_is_event_host($1::uuid, $2::uuid, $3::uuid, $4::uuid);",
+            &[&community_id, &group_id, &event_id, &user_id],
+        )
+        .await
+    }
+
     #[instrument(skip(self), err)]
     async fn verify_email(&self, code: &Uuid) -> Result<()> {
         self.execute("select verify_email($1::uuid);", &[&code]).await
     }
+
+    #[instrument(skip(self, verification), err)]
+    async fn verify_user_phone_number(
+        &self,
+        actor_user_id: &Uuid,
+        verification: &UserPhoneVerification,
+    ) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select verify_user_phone_number($1::uuid, $2::text);",
+            &[actor_user_id, &verification.code],
+        )
+        .await
+    }
 }
 
 /// Verification notification data required for password signups.
@@ -425,3 +1005,8 @@ pub(crate) struct EmailVerificationNotification {
     /// Typed notification template data serialized for enqueueing.
     pub(crate) template_data: EmailVerification,
 }
+
+/// Returns the identifier of a passkey credential as stored in the database.
+fn credential_id(passkey: &Passkey) -> String {
+    BASE64_URL.encode(passkey.cred_id())
+}
@@ -11,9 +11,9 @@ use uuid::Uuid;
 use crate::{
     db::{BBox, PgClient, PgExecutor, Total},
     types::{
-        community::{CommunityFull, CommunitySummary},
+        community::{CommunityFull, CommunitySummary, CommunityTheme},
         event::{EventCfsLabel, EventFull, EventSummary},
-        group::{GroupFull, GroupSummary},
+        group::{GroupFull, GroupGalleryPhoto, GroupSummary},
         search::{SearchEventsFilters, SearchGroupsFilters},
     },
 };
@@ -27,6 +27,9 @@ pub(crate) trait DBCommon {
     /// Retrieves community summary by its unique identifier.
     async fn get_community_summary(&self, community_id: Uuid) -> Result<CommunitySummary>;
 
+    /// Retrieves the theme overrides applied to the community pages.
+    async fn get_community_theme(&self, community_id: Uuid) -> Result<CommunityTheme>;
+
     /// Gets full event details.
     async fn get_event_full(
         &self,
@@ -52,6 +55,13 @@ pub(crate) trait DBCommon {
     /// Lists labels configured for an event.
     async fn list_event_cfs_labels(&self, event_id: Uuid) -> Result<Vec<EventCfsLabel>>;
 
+    /// Lists the photos of the group gallery, optionally including the hidden ones.
+    async fn list_group_gallery_photos(
+        &self,
+        group_id: Uuid,
+        include_hidden: bool,
+    ) -> Result<Vec<GroupGalleryPhoto>>;
+
     /// Lists all available timezones.
     async fn list_timezones(&self) -> Result<Vec<String>>;
 
@@ -81,6 +91,25 @@ where
             .await
     }
 
+    /// [`DBCommon::get_community_theme`]
+    #[instrument(skip(self), err)]
+    async fn get_community_theme(&self, community_id: Uuid) -> Result<CommunityTheme> {
+        self.fetch_json_one(
+            "
+            select json_strip_nulls(json_build_object(
+                'favicon_url', theme_favicon_url,
+                'font_family', theme_font_family,
+                'header_logo_url', theme_header_logo_url,
+                'primary_color', theme_primary_color
+            ))
+            from community
+            where community_id = $1::uuid;
+            ",
+            &[&community_id],
+        )
+        .await
+    }
+
     /// [`DBCommon::get_event_full`]
     #[instrument(skip(self), err)]
     async fn get_event_full(
@@ -138,6 +167,20 @@ where
             .await
     }
 
+    /// [`DBCommon::list_group_gallery_photos`]
+    #[instrument(skip(self), err)]
+    async fn list_group_gallery_photos(
+        &self,
+        group_id: Uuid,
+        include_hidden: bool,
+    ) -> Result<Vec<GroupGalleryPhoto>> {
+        self.fetch_json_one(
+            "select list_group_gallery_photos($1::uuid, $2::bool)",
+            &[&group_id, &include_hidden],
+        )
+        .await
+    }
+
     /// [`DBCommon::list_timezones`]
     #[instrument(skip(self), err)]
     async fn list_timezones(&self) -> Result<Vec<String>> {
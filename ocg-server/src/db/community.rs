@@ -10,6 +10,7 @@ use crate::{
     db::{PgClient, PgExecutor},
     templates::community,
     types::{
+        community::CommunityNewsletterArchiveIssue,
         event::{EventKind, EventSummary},
         group::GroupSummary,
     },
@@ -21,9 +22,19 @@ pub(crate) trait DBCommunity {
     /// Resolves a community ID from the provided community name.
     async fn get_community_id_by_name(&self, name: &str) -> Result<Option<Uuid>>;
 
+    /// Resolves a community name from the provided verified custom domain.
+    async fn get_community_name_by_domain(&self, domain: &str) -> Result<Option<String>>;
+
     /// Resolves a community name from the provided community ID.
     async fn get_community_name_by_id(&self, community_id: Uuid) -> Result<Option<String>>;
 
+    /// Retrieves a newsletter issue sent by the community.
+    async fn get_community_newsletter_archive_issue(
+        &self,
+        community_id: Uuid,
+        community_newsletter_issue_id: Uuid,
+    ) -> Result<Option<CommunityNewsletterArchiveIssue>>;
+
     /// Retrieves the most recently added groups in the community.
     async fn get_community_recently_added_groups(
         &self,
@@ -39,6 +50,30 @@ pub(crate) trait DBCommunity {
         community_id: Uuid,
         event_kinds: Vec<EventKind>,
     ) -> Result<Vec<EventSummary>>;
+
+    /// Checks whether the user is subscribed to the community newsletter.
+    async fn is_community_newsletter_subscriber(
+        &self,
+        community_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool>;
+
+    /// Lists the newsletter issues sent by the community.
+    async fn list_community_newsletter_archive(
+        &self,
+        community_id: Uuid,
+    ) -> Result<Vec<CommunityNewsletterArchiveIssue>>;
+
+    /// Subscribes the user to the community newsletter.
+    async fn subscribe_community_newsletter(&self, community_id: Uuid, user_id: Uuid)
+    -> Result<()>;
+
+    /// Unsubscribes the user from the community newsletter.
+    async fn unsubscribe_community_newsletter(
+        &self,
+        community_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()>;
 }
 
 #[async_trait]
@@ -71,6 +106,16 @@ where
         inner(db, name).await
     }
 
+    /// [`DB::get_community_name_by_domain`]
+    #[instrument(skip(self), err)]
+    async fn get_community_name_by_domain(&self, domain: &str) -> Result<Option<String>> {
+        if domain.is_empty() {
+            return Ok(None);
+        }
+        self.fetch_scalar_opt("select get_community_name_by_domain($1::text)", &[&domain])
+            .await
+    }
+
     /// [`DB::get_community_name_by_id`]
     #[instrument(skip(self), err)]
     async fn get_community_name_by_id(&self, community_id: Uuid) -> Result<Option<String>> {
@@ -96,6 +141,20 @@ where
         inner(db, community_id).await
     }
 
+    /// [`DB::get_community_newsletter_archive_issue`]
+    #[instrument(skip(self), err)]
+    async fn get_community_newsletter_archive_issue(
+        &self,
+        community_id: Uuid,
+        community_newsletter_issue_id: Uuid,
+    ) -> Result<Option<CommunityNewsletterArchiveIssue>> {
+        self.fetch_json_opt(
+            "select get_community_newsletter_archive_issue($1::uuid, $2::uuid)",
+            &[&community_id, &community_newsletter_issue_id],
+        )
+        .await
+    }
+
     /// [`DB::get_community_recently_added_groups`]
     #[instrument(skip(self), err)]
     async fn get_community_recently_added_groups(
@@ -133,4 +192,59 @@ where
         )
         .await
     }
+
+    /// [`DB::is_community_newsletter_subscriber`]
+    #[instrument(skip(self), err)]
+    async fn is_community_newsletter_subscriber(
+        &self,
+        community_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select is_community_newsletter_subscriber($1::uuid, $2::uuid)",
+            &[&community_id, &user_id],
+        )
+        .await
+    }
+
+    /// [`DB::list_community_newsletter_archive`]
+    #[instrument(skip(self), err)]
+    async fn list_community_newsletter_archive(
+        &self,
+        community_id: Uuid,
+    ) -> Result<Vec<CommunityNewsletterArchiveIssue>> {
+        self.fetch_json_one(
+            "select list_community_newsletter_archive($1::uuid)",
+            &[&community_id],
+        )
+        .await
+    }
+
+    /// [`DB::subscribe_community_newsletter`]
+    #[instrument(skip(self), err)]
+    async fn subscribe_community_newsletter(
+        &self,
+        community_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select subscribe_community_newsletter($1::uuid, $2::uuid)",
+            &[&community_id, &user_id],
+        )
+        .await
+    }
+
+    /// [`DB::unsubscribe_community_newsletter`]
+    #[instrument(skip(self), err)]
+    async fn unsubscribe_community_newsletter(
+        &self,
+        community_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select unsubscribe_community_newsletter($1::uuid, $2::uuid)",
+            &[&community_id, &user_id],
+        )
+        .await
+    }
 }
@@ -30,7 +30,7 @@ use crate::{
         },
         site::DBSite,
     },
-    services::meetings::MeetingProvider,
+    services::{meetings::MeetingProvider, notifications::NotificationRecipient},
     templates::{
         dashboard::{
             audit::AuditLogFilters,
@@ -60,6 +60,7 @@ use crate::{
         payments::{EventPurchaseStatus, PaymentProvider},
         questionnaire::QuestionnaireAnswerValue,
         search::{SearchEventsFilters, SearchGroupsFilters},
+        site::ThemeFontFamily,
         user::UserProvider,
     },
 };
@@ -75,7 +76,8 @@ async fn db_contracts_activate_pre_registered_user_external_provider_deserialize
 
         has_password: None,
         password: None,
-        provider: Some(UserProvider::from_github_username(
+        provider: Some(UserProvider::from_github_account(
+            4242,
             "contract-activation".to_string(),
         )),
     };
@@ -160,7 +162,7 @@ async fn db_contracts_claim_meeting_for_auto_end_deserializes() -> Result<()> {
 async fn db_contracts_claim_meeting_out_of_sync_deserializes() -> Result<()> {
     let db = contract_tests_db()?;
     let meeting = db
-        .claim_meeting_out_of_sync()
+        .claim_meeting_out_of_sync(&[])
         .await?
         .expect("contract meeting sync candidate should exist");
 
@@ -194,9 +196,13 @@ async fn db_contracts_claim_pending_notification_deserializes() -> Result<()> {
     assert!(notification.attachments.is_empty());
     assert!(notification.delivery_claimed_at >= claim_started_at);
     assert!(notification.delivery_claimed_at <= claim_finished_at);
-    assert_eq!(notification.email, "organizer.contract@example.com");
+    assert!(notification.email_branding.is_none());
     assert_eq!(notification.kind.to_string(), "event-welcome");
     assert_eq!(notification.notification_id, notification_id());
+    assert_eq!(
+        notification.recipient,
+        NotificationRecipient::Email("organizer.contract@example.com".to_string())
+    );
     assert!(notification.template_data.is_none());
 
     // Finalize through the production wrapper to verify the claim identity round trip
@@ -397,6 +403,20 @@ async fn db_contracts_get_community_summary_deserializes() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore = "requires the contract test database"]
+async fn db_contracts_get_community_theme_deserializes() -> Result<()> {
+    let db = contract_tests_db()?;
+    let theme = db.get_community_theme(community_id()).await?;
+
+    assert_eq!(theme.favicon_url, None);
+    assert_eq!(theme.font_family, Some(ThemeFontFamily::System));
+    assert_eq!(theme.header_logo_url, None);
+    assert_eq!(theme.primary_color.as_deref(), Some("#1B4F72"));
+
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore = "requires the contract test database"]
 async fn db_contracts_get_community_upcoming_events_deserializes() -> Result<()> {
@@ -557,7 +577,7 @@ async fn db_contracts_get_event_purchase_summary_deserializes() -> Result<()> {
     let summary = db.get_event_purchase_summary(summary_purchase_id()).await?;
 
     assert_eq!(summary.amount_minor, 2500);
-    assert_eq!(summary.currency_code, "USD");
+    assert_eq!(summary.currency_code.as_deref(), Some("USD"));
     assert_eq!(summary.discount_amount_minor, 0);
     assert_eq!(summary.event_purchase_id, summary_purchase_id());
     assert_eq!(summary.event_ticket_type_id, paid_ticket_type_id());
@@ -636,6 +656,24 @@ async fn db_contracts_get_filters_options_deserializes() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore = "requires the contract test database"]
+async fn db_contracts_get_group_engagement_stats_deserializes() -> Result<()> {
+    let db = contract_tests_db()?;
+    let stats = db
+        .get_group_engagement_stats(community_id(), group_id(), false)
+        .await?;
+
+    assert_eq!(stats.check_ins.total, 1);
+    assert_eq!(stats.retention.repeat_attendees, 0);
+    assert_eq!(stats.retention.unique_attendees, 1);
+    assert_eq!(stats.top_events.len(), 1);
+    assert_eq!(stats.top_events[0].event_id, event_id());
+    assert_eq!(stats.top_events[0].check_ins, 1);
+
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore = "requires the contract test database"]
 async fn db_contracts_get_group_full_deserializes() -> Result<()> {
@@ -1951,7 +1989,11 @@ async fn db_contracts_update_user_external_auth_deserializes() -> Result<()> {
         Some(UserProvider {
             github: Some(crate::types::user::GitHubUserProvider {
                 username: "contract-external-update".to_string(),
+
+                id: None,
             }),
+            gitlab: None,
+            google: None,
             linuxfoundation: Some(crate::types::user::LinuxFoundationUserProvider {
                 username: "contract-external-update".to_string(),
 
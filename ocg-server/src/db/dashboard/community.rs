@@ -1,6 +1,6 @@
 //! Database interface for community dashboard operations.
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use cached::cached;
 use tokio_postgres::types::Json;
@@ -13,17 +13,28 @@ use crate::{
         audit::{AuditLogFilters, AuditLogsOutput},
         community::{
             analytics::CommunityDashboardStats,
+            domains::CommunityDomain,
+            event_approvals::{EventRejectionInput, PendingEvent},
             event_categories::EventCategoryInput,
+            failed_notifications::{FailedNotificationsFilters, FailedNotificationsOutput},
+            group_bans::CommunityGroupBan,
             group_categories::GroupCategoryInput,
             groups::Group,
+            newsletter::{CommunityNewsletterIssue, CommunityNewsletterIssueInput},
             regions::RegionInput,
             settings::CommunityUpdate,
             team::{CommunityTeamFilters, CommunityTeamOutput},
+            topics::TopicInput,
         },
+        notifications::{CustomNotificationsFilters, CustomNotificationsOutput},
     },
     types::{
-        community::{CommunityRole, CommunityRoleSummary, CommunitySummary},
-        group::{GroupCategory, GroupRegion},
+        community::{
+            CommunityEmailBranding, CommunityRole, CommunityRoleSummary, CommunitySummary,
+            CommunityTopic,
+        },
+        group::{GroupCategory, GroupMinimal, GroupRegion},
+        payments::PaymentProvider,
     },
 };
 
@@ -38,6 +49,22 @@ pub(crate) trait DBDashboardCommunity {
         group_id: Uuid,
     ) -> Result<()>;
 
+    /// Adds a custom domain to the community, pending its verification.
+    async fn add_community_domain(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        domain: &str,
+    ) -> Result<Uuid>;
+
+    /// Adds a newsletter issue draft to the community.
+    async fn add_community_newsletter_issue(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        issue: &CommunityNewsletterIssueInput,
+    ) -> Result<Uuid>;
+
     /// Adds a user to the community team.
     async fn add_community_team_member(
         &self,
@@ -79,6 +106,35 @@ pub(crate) trait DBDashboardCommunity {
         region: &RegionInput,
     ) -> Result<Uuid>;
 
+    /// Adds a new topic to the database.
+    async fn add_topic(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        topic: &TopicInput,
+    ) -> Result<Uuid>;
+
+    /// Approves an event waiting for the community approval and publishes it,
+    /// returning the event group id.
+    async fn approve_event(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        event_id: Uuid,
+        configured_provider: Option<PaymentProvider>,
+    ) -> Result<Uuid>;
+
+    /// Archives a group, keeping its page readable as a historical record.
+    async fn archive_group(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()>;
+
+    /// Counts the users subscribed to the community newsletter.
+    async fn count_community_newsletter_subscribers(&self, community_id: Uuid) -> Result<usize>;
+
     /// Deactivates a group (sets active=false without deleting).
     async fn deactivate_group(
         &self,
@@ -87,6 +143,22 @@ pub(crate) trait DBDashboardCommunity {
         group_id: Uuid,
     ) -> Result<()>;
 
+    /// Deletes a custom domain from the community.
+    async fn delete_community_domain(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        community_domain_id: Uuid,
+    ) -> Result<()>;
+
+    /// Deletes a newsletter issue draft from the community.
+    async fn delete_community_newsletter_issue(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        community_newsletter_issue_id: Uuid,
+    ) -> Result<()>;
+
     /// Deletes a user from the community team.
     async fn delete_community_team_member(
         &self,
@@ -127,6 +199,37 @@ pub(crate) trait DBDashboardCommunity {
         region_id: Uuid,
     ) -> Result<()>;
 
+    /// Deletes a topic from the database, unassigning it from groups and events.
+    async fn delete_topic(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        topic_id: Uuid,
+    ) -> Result<()>;
+
+    /// Retrieves a custom domain of the community.
+    async fn get_community_domain(
+        &self,
+        community_id: Uuid,
+        community_domain_id: Uuid,
+    ) -> Result<Option<CommunityDomain>>;
+
+    /// Retrieves the branding applied to the emails sent on behalf of a community.
+    async fn get_community_email_branding(
+        &self,
+        community_id: Uuid,
+    ) -> Result<CommunityEmailBranding>;
+
+    /// Retrieves a newsletter issue of the community.
+    async fn get_community_newsletter_issue(
+        &self,
+        community_id: Uuid,
+        community_newsletter_issue_id: Uuid,
+    ) -> Result<Option<CommunityNewsletterIssue>>;
+
+    /// Retrieves the Slack webhook URL used to post community notifications.
+    async fn get_community_slack_webhook_url(&self, community_id: Uuid) -> Result<Option<String>>;
+
     /// Retrieves analytics statistics for a community.
     async fn get_community_stats(&self, community_id: Uuid) -> Result<CommunityDashboardStats>;
 
@@ -137,6 +240,37 @@ pub(crate) trait DBDashboardCommunity {
         filters: &AuditLogFilters,
     ) -> Result<AuditLogsOutput>;
 
+    /// Lists the custom notifications sent by the community groups with their
+    /// delivery status.
+    async fn list_community_custom_notifications(
+        &self,
+        community_id: Uuid,
+        filters: &CustomNotificationsFilters,
+    ) -> Result<CustomNotificationsOutput>;
+
+    /// Lists the custom domains added to a community.
+    async fn list_community_domains(&self, community_id: Uuid) -> Result<Vec<CommunityDomain>>;
+
+    /// Lists the notifications sent on behalf of the community whose delivery failed.
+    async fn list_community_failed_notifications(
+        &self,
+        community_id: Uuid,
+        filters: &FailedNotificationsFilters,
+    ) -> Result<FailedNotificationsOutput>;
+
+    /// Lists the users banned from the groups of a community.
+    async fn list_community_group_bans(&self, community_id: Uuid)
+    -> Result<Vec<CommunityGroupBan>>;
+
+    /// Lists the newsletter issues of a community, drafts first.
+    async fn list_community_newsletter_issues(
+        &self,
+        community_id: Uuid,
+    ) -> Result<Vec<CommunityNewsletterIssue>>;
+
+    /// Lists the events waiting for the community approval.
+    async fn list_community_pending_events(&self, community_id: Uuid) -> Result<Vec<PendingEvent>>;
+
     /// Lists all available community roles.
     async fn list_community_roles(&self) -> Result<Vec<CommunityRoleSummary>>;
 
@@ -150,12 +284,67 @@ pub(crate) trait DBDashboardCommunity {
     /// Lists all group categories for a community.
     async fn list_group_categories(&self, community_id: Uuid) -> Result<Vec<GroupCategory>>;
 
+    /// Lists the active groups of a community a group can be merged into.
+    async fn list_group_merge_targets(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Vec<GroupMinimal>>;
+
     /// Lists all regions for a community.
     async fn list_regions(&self, community_id: Uuid) -> Result<Vec<GroupRegion>>;
 
+    /// Lists all topics for a community.
+    async fn list_topics(&self, community_id: Uuid) -> Result<Vec<CommunityTopic>>;
+
     /// Lists all communities where the user is a team member.
     async fn list_user_communities(&self, user_id: &Uuid) -> Result<Vec<CommunitySummary>>;
 
+    /// Merges a group into another group of the same community, moving its
+    /// members, events, team and sponsors and archiving it.
+    async fn merge_groups(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        source_group_id: Uuid,
+        target_group_id: Uuid,
+    ) -> Result<()>;
+
+    /// Rejects an event waiting for the community approval, returning the
+    /// event group id.
+    async fn reject_event(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        event_id: Uuid,
+        rejection: &EventRejectionInput,
+    ) -> Result<Uuid>;
+
+    /// Requeues a failed notification sent on behalf of the community.
+    async fn requeue_community_notification(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        notification_id: Uuid,
+    ) -> Result<()>;
+
+    /// Restores an archived group.
+    async fn restore_group(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()>;
+
+    /// Sends a newsletter issue to the community subscribers and returns the number of
+    /// recipients.
+    async fn send_community_newsletter_issue(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        community_newsletter_issue_id: Uuid,
+    ) -> Result<usize>;
+
     /// Updates a community's settings.
     async fn update_community(
         &self,
@@ -173,6 +362,10 @@ pub(crate) trait DBDashboardCommunity {
         role: &CommunityRole,
     ) -> Result<()>;
 
+    /// Flags the groups with no recent events as dormant and returns the number of groups
+    /// updated.
+    async fn update_dormant_groups(&self) -> Result<usize>;
+
     /// Updates an event category in the database.
     async fn update_event_category(
         &self,
@@ -191,6 +384,15 @@ pub(crate) trait DBDashboardCommunity {
         group_category: &GroupCategoryInput,
     ) -> Result<()>;
 
+    /// Makes the provided user the owner of a group, overriding the current one.
+    async fn update_group_owner(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+        username: &str,
+    ) -> Result<()>;
+
     /// Updates a region in the database.
     async fn update_region(
         &self,
@@ -199,6 +401,23 @@ pub(crate) trait DBDashboardCommunity {
         region_id: Uuid,
         region: &RegionInput,
     ) -> Result<()>;
+
+    /// Updates a topic in the database.
+    async fn update_topic(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        topic_id: Uuid,
+        topic: &TopicInput,
+    ) -> Result<()>;
+
+    /// Marks a custom domain of the community as verified.
+    async fn verify_community_domain(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        community_domain_id: Uuid,
+    ) -> Result<()>;
 }
 
 #[async_trait]
@@ -221,6 +440,36 @@ where
         .await
     }
 
+    /// [`DBDashboardCommunity::add_community_domain`]
+    #[instrument(skip(self), err)]
+    async fn add_community_domain(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        domain: &str,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_community_domain($1::uuid, $2::uuid, $3::text)::uuid",
+            &[&actor_user_id, &community_id, &domain],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::add_community_newsletter_issue`]
+    #[instrument(skip(self, issue), err)]
+    async fn add_community_newsletter_issue(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        issue: &CommunityNewsletterIssueInput,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_community_newsletter_issue($1::uuid, $2::uuid, $3::jsonb)::uuid",
+            &[&actor_user_id, &community_id, &Json(issue)],
+        )
+        .await
+    }
+
     /// [`DBDashboardCommunity::add_community_team_member`]
     #[instrument(skip(self), err)]
     async fn add_community_team_member(
@@ -297,6 +546,72 @@ where
         .await
     }
 
+    /// [`DBDashboardCommunity::add_topic`]
+    #[instrument(skip(self, topic), err)]
+    async fn add_topic(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        topic: &TopicInput,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_topic($1::uuid, $2::uuid, $3::jsonb)::uuid",
+            &[&actor_user_id, &community_id, &Json(topic)],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::approve_event`]
+    #[instrument(skip(self), err)]
+    async fn approve_event(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        event_id: Uuid,
+        configured_provider: Option<PaymentProvider>,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select approve_event($1::uuid, $2::uuid, $3::uuid, $4::text)",
+            &[
+                &actor_user_id,
+                &community_id,
+                &event_id,
+                &configured_provider.map(|provider| provider.to_string()),
+            ],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::archive_group`]
+    #[instrument(skip(self), err)]
+    async fn archive_group(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select archive_group($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &community_id, &group_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::count_community_newsletter_subscribers`]
+    #[instrument(skip(self), err)]
+    async fn count_community_newsletter_subscribers(&self, community_id: Uuid) -> Result<usize> {
+        let count: i32 = self
+            .fetch_scalar_one(
+                "select count_community_newsletter_subscribers($1::uuid)",
+                &[&community_id],
+            )
+            .await?;
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("newsletter subscribers count cannot be negative"))?;
+
+        Ok(count)
+    }
+
     /// [`DBDashboardCommunity::deactivate_group`]
     #[instrument(skip(self), err)]
     async fn deactivate_group(
@@ -312,6 +627,40 @@ where
         .await
     }
 
+    /// [`DBDashboardCommunity::delete_community_domain`]
+    #[instrument(skip(self), err)]
+    async fn delete_community_domain(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        community_domain_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_community_domain($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &community_id, &community_domain_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::delete_community_newsletter_issue`]
+    #[instrument(skip(self), err)]
+    async fn delete_community_newsletter_issue(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        community_newsletter_issue_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_community_newsletter_issue($1::uuid, $2::uuid, $3::uuid)",
+            &[
+                &actor_user_id,
+                &community_id,
+                &community_newsletter_issue_id,
+            ],
+        )
+        .await
+    }
+
     /// [`DBDashboardCommunity::delete_community_team_member`]
     #[instrument(skip(self), err)]
     async fn delete_community_team_member(
@@ -387,6 +736,81 @@ where
         .await
     }
 
+    /// [`DBDashboardCommunity::delete_topic`]
+    #[instrument(skip(self), err)]
+    async fn delete_topic(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        topic_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_topic($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &community_id, &topic_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::get_community_domain`]
+    #[instrument(skip(self), err)]
+    async fn get_community_domain(
+        &self,
+        community_id: Uuid,
+        community_domain_id: Uuid,
+    ) -> Result<Option<CommunityDomain>> {
+        self.fetch_json_opt(
+            "select get_community_domain($1::uuid, $2::uuid)",
+            &[&community_id, &community_domain_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::get_community_email_branding`]
+    #[instrument(skip(self), err)]
+    async fn get_community_email_branding(
+        &self,
+        community_id: Uuid,
+    ) -> Result<CommunityEmailBranding> {
+        self.fetch_json_one(
+            "
+            select json_strip_nulls(json_build_object(
+                'footer_text', email_footer_text,
+                'from_name', email_from_name,
+                'logo_url', email_logo_url,
+                'primary_color', email_primary_color
+            ))
+            from community
+            where community_id = $1::uuid;
+            ",
+            &[&community_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::get_community_newsletter_issue`]
+    #[instrument(skip(self), err)]
+    async fn get_community_newsletter_issue(
+        &self,
+        community_id: Uuid,
+        community_newsletter_issue_id: Uuid,
+    ) -> Result<Option<CommunityNewsletterIssue>> {
+        self.fetch_json_opt(
+            "select get_community_newsletter_issue($1::uuid, $2::uuid)",
+            &[&community_id, &community_newsletter_issue_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::get_community_slack_webhook_url`]
+    #[instrument(skip(self), err)]
+    async fn get_community_slack_webhook_url(&self, community_id: Uuid) -> Result<Option<String>> {
+        self.fetch_scalar_opt(
+            "select slack_webhook_url from community where community_id = $1::uuid;",
+            &[&community_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardCommunity::get_community_stats`]
     #[instrument(skip(self), err)]
     async fn get_community_stats(&self, community_id: Uuid) -> Result<CommunityDashboardStats> {
@@ -423,6 +847,77 @@ where
         .await
     }
 
+    /// [`DBDashboardCommunity::list_community_custom_notifications`]
+    #[instrument(skip(self, filters), err)]
+    async fn list_community_custom_notifications(
+        &self,
+        community_id: Uuid,
+        filters: &CustomNotificationsFilters,
+    ) -> Result<CustomNotificationsOutput> {
+        self.fetch_json_one(
+            "select list_community_custom_notifications($1::uuid, $2::jsonb)",
+            &[&community_id, &Json(filters)],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::list_community_domains`]
+    #[instrument(skip(self), err)]
+    async fn list_community_domains(&self, community_id: Uuid) -> Result<Vec<CommunityDomain>> {
+        self.fetch_json_one("select list_community_domains($1::uuid)", &[&community_id])
+            .await
+    }
+
+    /// [`DBDashboardCommunity::list_community_failed_notifications`]
+    #[instrument(skip(self, filters), err)]
+    async fn list_community_failed_notifications(
+        &self,
+        community_id: Uuid,
+        filters: &FailedNotificationsFilters,
+    ) -> Result<FailedNotificationsOutput> {
+        self.fetch_json_one(
+            "select list_community_failed_notifications($1::uuid, $2::jsonb)",
+            &[&community_id, &Json(filters)],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::list_community_group_bans`]
+    #[instrument(skip(self), err)]
+    async fn list_community_group_bans(
+        &self,
+        community_id: Uuid,
+    ) -> Result<Vec<CommunityGroupBan>> {
+        self.fetch_json_one(
+            "select list_community_group_bans($1::uuid)",
+            &[&community_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::list_community_newsletter_issues`]
+    #[instrument(skip(self), err)]
+    async fn list_community_newsletter_issues(
+        &self,
+        community_id: Uuid,
+    ) -> Result<Vec<CommunityNewsletterIssue>> {
+        self.fetch_json_one(
+            "select list_community_newsletter_issues($1::uuid)",
+            &[&community_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::list_community_pending_events`]
+    #[instrument(skip(self), err)]
+    async fn list_community_pending_events(&self, community_id: Uuid) -> Result<Vec<PendingEvent>> {
+        self.fetch_json_one(
+            "select list_community_pending_events($1::uuid)",
+            &[&community_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardCommunity::list_community_roles`]
     #[instrument(skip(self), err)]
     async fn list_community_roles(&self) -> Result<Vec<CommunityRoleSummary>> {
@@ -464,6 +959,20 @@ where
             .await
     }
 
+    /// [`DBDashboardCommunity::list_group_merge_targets`]
+    #[instrument(skip(self), err)]
+    async fn list_group_merge_targets(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Vec<GroupMinimal>> {
+        self.fetch_json_one(
+            "select list_group_merge_targets($1::uuid, $2::uuid)",
+            &[&community_id, &group_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardCommunity::list_regions`]
     #[instrument(skip(self), err)]
     async fn list_regions(&self, community_id: Uuid) -> Result<Vec<GroupRegion>> {
@@ -471,6 +980,13 @@ where
             .await
     }
 
+    /// [`DBDashboardCommunity::list_topics`]
+    #[instrument(skip(self), err)]
+    async fn list_topics(&self, community_id: Uuid) -> Result<Vec<CommunityTopic>> {
+        self.fetch_json_one("select list_topics($1::uuid)", &[&community_id])
+            .await
+    }
+
     /// [`DBDashboardCommunity::list_user_communities`]
     #[instrument(skip(self), err)]
     async fn list_user_communities(&self, user_id: &Uuid) -> Result<Vec<CommunitySummary>> {
@@ -478,6 +994,97 @@ where
             .await
     }
 
+    /// [`DBDashboardCommunity::merge_groups`]
+    #[instrument(skip(self), err)]
+    async fn merge_groups(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        source_group_id: Uuid,
+        target_group_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select merge_groups($1::uuid, $2::uuid, $3::uuid, $4::uuid)",
+            &[
+                &actor_user_id,
+                &community_id,
+                &source_group_id,
+                &target_group_id,
+            ],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::reject_event`]
+    #[instrument(skip(self, rejection), err)]
+    async fn reject_event(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        event_id: Uuid,
+        rejection: &EventRejectionInput,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select reject_event($1::uuid, $2::uuid, $3::uuid, $4::text)",
+            &[&actor_user_id, &community_id, &event_id, &rejection.reason],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::requeue_community_notification`]
+    #[instrument(skip(self), err)]
+    async fn requeue_community_notification(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        notification_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select requeue_community_notification($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &community_id, &notification_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::restore_group`]
+    #[instrument(skip(self), err)]
+    async fn restore_group(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select restore_group($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &community_id, &group_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::send_community_newsletter_issue`]
+    #[instrument(skip(self), err)]
+    async fn send_community_newsletter_issue(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        community_newsletter_issue_id: Uuid,
+    ) -> Result<usize> {
+        let count: i32 = self
+            .fetch_scalar_one(
+                "select send_community_newsletter_issue($1::uuid, $2::uuid, $3::uuid)",
+                &[
+                    &actor_user_id,
+                    &community_id,
+                    &community_newsletter_issue_id,
+                ],
+            )
+            .await?;
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("newsletter recipients count cannot be negative"))?;
+
+        Ok(count)
+    }
+
     /// [`DBDashboardCommunity::update_community`]
     #[instrument(skip(self, community), err)]
     async fn update_community(
@@ -509,6 +1116,16 @@ where
         .await
     }
 
+    /// [`DBDashboardCommunity::update_dormant_groups`]
+    #[instrument(skip(self), err)]
+    async fn update_dormant_groups(&self) -> Result<usize> {
+        let count: i32 = self.fetch_scalar_one("select update_dormant_groups()", &[]).await?;
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("updated dormant groups count cannot be negative"))?;
+
+        Ok(count)
+    }
+
     /// [`DBDashboardCommunity::update_event_category`]
     #[instrument(skip(self, event_category), err)]
     async fn update_event_category(
@@ -551,6 +1168,22 @@ where
         .await
     }
 
+    /// [`DBDashboardCommunity::update_group_owner`]
+    #[instrument(skip(self), err)]
+    async fn update_group_owner(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+        username: &str,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_owner($1::uuid, $2::uuid, $3::uuid, $4::text)",
+            &[&actor_user_id, &community_id, &group_id, &username],
+        )
+        .await
+    }
+
     /// [`DBDashboardCommunity::update_region`]
     #[instrument(skip(self, region), err)]
     async fn update_region(
@@ -566,4 +1199,35 @@ where
         )
         .await
     }
+
+    /// [`DBDashboardCommunity::update_topic`]
+    #[instrument(skip(self, topic), err)]
+    async fn update_topic(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        topic_id: Uuid,
+        topic: &TopicInput,
+    ) -> Result<()> {
+        self.execute(
+            "select update_topic($1::uuid, $2::uuid, $3::uuid, $4::jsonb)",
+            &[&actor_user_id, &community_id, &topic_id, &Json(topic)],
+        )
+        .await
+    }
+
+    /// [`DBDashboardCommunity::verify_community_domain`]
+    #[instrument(skip(self), err)]
+    async fn verify_community_domain(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        community_domain_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select verify_community_domain($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &community_id, &community_domain_id],
+        )
+        .await
+    }
 }
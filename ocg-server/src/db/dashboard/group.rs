@@ -5,26 +5,42 @@ use std::collections::HashMap;
 use anyhow::Result;
 use async_trait::async_trait;
 use cached::cached;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
 use tokio_postgres::types::Json;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
     db::{PgClient, PgExecutor},
-    services::meetings::MeetingProvider,
+    services::{badges::AttendeeBadge, meetings::MeetingProvider},
     templates::dashboard::{
         audit::{AuditLogFilters, AuditLogsOutput},
         group::{
-            analytics::GroupDashboardStats,
+            analytics::{GroupDashboardStats, GroupEngagementStats},
+            announcements::GroupAnnouncementInput,
             attendees::{AttendeesFilters, AttendeesOutput},
             events::{
-                ApprovedSubmissionSummary, CfsSubmissionStatus, EventsListFilters, GroupEvents,
+                ApprovedSubmissionSummary, CfsSubmissionStatus, EventConflict, EventTemplate,
+                EventsListFilters, GroupEvents,
             },
+            gallery::{GroupGalleryCoverUpdate, GroupGalleryPhotosUpdate},
             home::UserGroupsByCommunity,
             invitation_requests::{InvitationRequestsFilters, InvitationRequestsOutput},
-            members::{GroupMembersFilters, GroupMembersOutput},
+            kiosk::KioskAttendee,
+            members::{
+                GroupBan, GroupBanInput, GroupJoinRequest, GroupMemberExport,
+                GroupMemberLeaderboardEntry, GroupMemberRoleUpdate, GroupMembersFilters,
+                GroupMembersOutput,
+            },
+            photos::EventPhoto,
             refunds::{RefundsFilters, RefundsOutput},
-            sponsors::{GroupSponsorsFilters, GroupSponsorsOutput, Sponsor},
+            resources::NewEventResource,
+            settings::{
+                GroupDiscordWebhookUpdate, GroupJoinQuestionsUpdate, GroupOwnership,
+                GroupWelcomeMessageUpdate, GroupZoomAccount, GroupZoomCredentialsUpdate,
+            },
+            sponsors::{GroupSponsorTierInput, GroupSponsorsFilters, GroupSponsorsOutput, Sponsor},
             submissions::{
                 CfsSubmissionNotificationData, CfsSubmissionUpdate, CfsSubmissionsFilters,
                 CfsSubmissionsOutput,
@@ -32,13 +48,14 @@ use crate::{
             team::{GroupTeamFilters, GroupTeamOutput},
             waitlist::{WaitlistFilters, WaitlistOutput},
         },
+        notifications::{CustomNotificationsFilters, CustomNotificationsOutput},
     },
     types::{
         event::{
-            EventCategory, EventKindSummary as EventKind, EventLeaveOutcome,
+            EventCategory, EventKindSummary as EventKind, EventLeaveOutcome, EventResource,
             SessionKindSummary as SessionKind,
         },
-        group::{GroupRole, GroupRoleSummary, GroupSponsor},
+        group::{GroupAnnouncement, GroupRole, GroupRoleSummary, GroupSponsor, GroupSponsorTier},
         payments::{GroupPaymentRecipient, PaymentProvider},
     },
 };
@@ -55,6 +72,14 @@ pub(crate) trait DBDashboardGroup {
         user_id: Uuid,
     ) -> Result<()>;
 
+    /// Accepts a pending group join request and adds the user as a member.
+    async fn accept_group_join_request(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()>;
+
     /// Adds a new event to the database.
     async fn add_event(
         &self,
@@ -74,6 +99,32 @@ pub(crate) trait DBDashboardGroup {
         cfg_max_participants: &HashMap<MeetingProvider, i32>,
     ) -> Result<Vec<Uuid>>;
 
+    /// Attaches a file uploaded by an organizer to an event.
+    async fn add_event_resource(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        resource: &NewEventResource,
+    ) -> Result<Uuid>;
+
+    /// Saves an event as a reusable template for the group.
+    async fn add_event_template(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<Uuid>;
+
+    /// Posts a new announcement in a group.
+    async fn add_group_announcement(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        announcement: &GroupAnnouncementInput,
+    ) -> Result<Uuid>;
+
     /// Adds a new sponsor to the database.
     async fn add_group_sponsor(
         &self,
@@ -82,6 +133,14 @@ pub(crate) trait DBDashboardGroup {
         sponsor: &Sponsor,
     ) -> Result<Uuid>;
 
+    /// Adds a new sponsor tier to the group.
+    async fn add_group_sponsor_tier(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        tier: &GroupSponsorTierInput,
+    ) -> Result<Uuid>;
+
     /// Adds a user to the group team (pending by default).
     async fn add_group_team_member(
         &self,
@@ -91,6 +150,25 @@ pub(crate) trait DBDashboardGroup {
         role: &GroupRole,
     ) -> Result<()>;
 
+    /// Approves a pending photo submitted by an event attendee.
+    async fn approve_event_photo(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_photo_id: Uuid,
+    ) -> Result<()>;
+
+    /// Bans a user from a group, removing their membership.
+    async fn ban_group_member(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        ban: &GroupBanInput,
+    ) -> Result<()>;
+
     /// Cancels an event (sets canceled=true).
     async fn cancel_event(&self, actor_user_id: Uuid, group_id: Uuid, event_id: Uuid)
     -> Result<()>;
@@ -121,10 +199,42 @@ pub(crate) trait DBDashboardGroup {
         event_ids: &[Uuid],
     ) -> Result<()>;
 
+    /// Cancels the pending ownership transfer of a group.
+    async fn cancel_group_ownership_transfer(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()>;
+
+    /// Counts the custom notifications sent to a group's members since the date provided.
+    async fn count_group_custom_notifications_since(
+        &self,
+        group_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<i64>;
+
     /// Deletes an event (soft delete by setting deleted=true and `deleted_at`).
     async fn delete_event(&self, actor_user_id: Uuid, group_id: Uuid, event_id: Uuid)
     -> Result<()>;
 
+    /// Deletes a comment, and its replies, from an event's discussion.
+    async fn delete_event_comment(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_comment_id: Uuid,
+    ) -> Result<()>;
+
+    /// Removes a file attached to an event.
+    async fn delete_event_resource(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_resource_id: Uuid,
+    ) -> Result<()>;
+
     /// Deletes event series events atomically.
     async fn delete_event_series_events(
         &self,
@@ -133,6 +243,38 @@ pub(crate) trait DBDashboardGroup {
         event_ids: &[Uuid],
     ) -> Result<()>;
 
+    /// Deletes an event template from a group.
+    async fn delete_event_template(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_template_id: Uuid,
+    ) -> Result<()>;
+
+    /// Deletes an announcement from a group.
+    async fn delete_group_announcement(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_announcement_id: Uuid,
+    ) -> Result<()>;
+
+    /// Deletes a reply from a topic in a group's discussion board.
+    async fn delete_group_discussion_reply(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_discussion_reply_id: Uuid,
+    ) -> Result<()>;
+
+    /// Deletes a topic, and its replies, from a group's discussion board.
+    async fn delete_group_discussion_topic(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_discussion_topic_id: Uuid,
+    ) -> Result<()>;
+
     /// Deletes a sponsor from the database.
     async fn delete_group_sponsor(
         &self,
@@ -141,6 +283,14 @@ pub(crate) trait DBDashboardGroup {
         group_sponsor_id: Uuid,
     ) -> Result<()>;
 
+    /// Deletes a sponsor tier from the group.
+    async fn delete_group_sponsor_tier(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_sponsor_tier_id: Uuid,
+    ) -> Result<()>;
+
     /// Deletes a user from the group team.
     async fn delete_group_team_member(
         &self,
@@ -149,6 +299,13 @@ pub(crate) trait DBDashboardGroup {
         user_id: Uuid,
     ) -> Result<()>;
 
+    /// Deletes the Zoom credentials configured for a group.
+    async fn delete_group_zoom_credentials(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()>;
+
     /// Gets submission notification data.
     async fn get_cfs_submission_notification_data(
         &self,
@@ -156,6 +313,38 @@ pub(crate) trait DBDashboardGroup {
         cfs_submission_id: Uuid,
     ) -> Result<CfsSubmissionNotificationData>;
 
+    /// Gets the confirmed attendee of an event that owns a ticket code.
+    async fn get_event_attendee_by_ticket_code(
+        &self,
+        group_id: Uuid,
+        event_id: Uuid,
+        ticket_code: Uuid,
+    ) -> Result<Option<KioskAttendee>>;
+
+    /// Gets the current kiosk check-in code of an event.
+    async fn get_event_check_in_code(&self, event_id: Uuid) -> Result<String>;
+
+    /// Gets the event details saved in a group event template.
+    async fn get_event_template_details(
+        &self,
+        group_id: Uuid,
+        event_template_id: Uuid,
+    ) -> Result<Option<serde_json::Value>>;
+
+    /// Gets the Discord webhook used to post group notifications.
+    async fn get_group_discord_webhook_url(&self, group_id: Uuid) -> Result<Option<String>>;
+
+    /// Retrieves engagement statistics for a group.
+    async fn get_group_engagement_stats(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        include_subgroups: bool,
+    ) -> Result<GroupEngagementStats>;
+
+    /// Gets the group owner, ownership candidates and pending transfer.
+    async fn get_group_ownership(&self, group_id: Uuid) -> Result<GroupOwnership>;
+
     /// Gets the configured payment recipient for a group.
     async fn get_group_payment_recipient(
         &self,
@@ -178,6 +367,12 @@ pub(crate) trait DBDashboardGroup {
         include_subgroups: bool,
     ) -> Result<GroupDashboardStats>;
 
+    /// Gets the message included in the welcome email sent to new group members.
+    async fn get_group_welcome_message(&self, group_id: Uuid) -> Result<Option<String>>;
+
+    /// Gets the Zoom account configured for a group, without its secret.
+    async fn get_group_zoom_account(&self, group_id: Uuid) -> Result<Option<GroupZoomAccount>>;
+
     /// Creates an organizer-created event invitation.
     async fn invite_event_attendee(
         &self,
@@ -188,6 +383,16 @@ pub(crate) trait DBDashboardGroup {
         email: Option<String>,
     ) -> Result<Uuid>;
 
+    /// Invites a user to the group team by email, storing the link to accept it.
+    async fn invite_group_team_member(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        email: &str,
+        role: &GroupRole,
+        token_hash: &str,
+    ) -> Result<Uuid>;
+
     /// Lists reviewer-available CFS submission statuses.
     async fn list_cfs_submission_statuses_for_review(&self) -> Result<Vec<CfsSubmissionStatus>>;
 
@@ -197,6 +402,13 @@ pub(crate) trait DBDashboardGroup {
         event_id: Uuid,
     ) -> Result<Vec<ApprovedSubmissionSummary>>;
 
+    /// Lists the badges of the confirmed attendees of an event.
+    async fn list_event_attendee_badges(
+        &self,
+        group_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<Vec<AttendeeBadge>>;
+
     /// Lists all verified attendees user ids for an event.
     async fn list_event_attendees_ids(&self, group_id: Uuid, event_id: Uuid) -> Result<Vec<Uuid>>;
 
@@ -210,9 +422,23 @@ pub(crate) trait DBDashboardGroup {
         filters: &CfsSubmissionsFilters,
     ) -> Result<CfsSubmissionsOutput>;
 
+    /// Lists the events overlapping in time with the provided event payload.
+    async fn list_event_conflicts(
+        &self,
+        group_id: Uuid,
+        event_id: Option<Uuid>,
+        event: &serde_json::Value,
+    ) -> Result<Vec<EventConflict>>;
+
     /// Lists all available event kinds.
     async fn list_event_kinds(&self) -> Result<Vec<EventKind>>;
 
+    /// Lists the photos submitted by attendees to an event.
+    async fn list_event_photos(&self, group_id: Uuid, event_id: Uuid) -> Result<Vec<EventPhoto>>;
+
+    /// Lists the files attached to an event.
+    async fn list_event_resources(&self, event_id: Uuid) -> Result<Vec<EventResource>>;
+
     /// Lists non-completed event identifiers from the same event series.
     async fn list_event_series_cancelable_event_ids(
         &self,
@@ -234,9 +460,15 @@ pub(crate) trait DBDashboardGroup {
         event_id: Uuid,
     ) -> Result<Vec<Uuid>>;
 
+    /// Lists the event templates saved in a group.
+    async fn list_event_templates(&self, group_id: Uuid) -> Result<Vec<EventTemplate>>;
+
     /// Lists all verified waitlisted user ids for an event.
     async fn list_event_waitlist_ids(&self, group_id: Uuid, event_id: Uuid) -> Result<Vec<Uuid>>;
 
+    /// Lists all the announcements posted in a group.
+    async fn list_group_announcements(&self, group_id: Uuid) -> Result<Vec<GroupAnnouncement>>;
+
     /// Lists group dashboard audit log rows.
     async fn list_group_audit_logs(
         &self,
@@ -244,6 +476,16 @@ pub(crate) trait DBDashboardGroup {
         filters: &AuditLogFilters,
     ) -> Result<AuditLogsOutput>;
 
+    /// Lists the users banned from a group.
+    async fn list_group_bans(&self, group_id: Uuid) -> Result<Vec<GroupBan>>;
+
+    /// Lists the custom notifications sent by a group with their delivery status.
+    async fn list_group_custom_notifications(
+        &self,
+        group_id: Uuid,
+        filters: &CustomNotificationsFilters,
+    ) -> Result<CustomNotificationsOutput>;
+
     /// Lists all events for a group for management.
     async fn list_group_events(
         &self,
@@ -251,6 +493,18 @@ pub(crate) trait DBDashboardGroup {
         filters: &EventsListFilters,
     ) -> Result<GroupEvents>;
 
+    /// Lists the pending join requests of a group.
+    async fn list_group_join_requests(&self, group_id: Uuid) -> Result<Vec<GroupJoinRequest>>;
+
+    /// Lists the most active members of a group during the last 12 months.
+    async fn list_group_member_leaderboard(
+        &self,
+        group_id: Uuid,
+    ) -> Result<Vec<GroupMemberLeaderboardEntry>>;
+
+    /// Lists the distinct roles assigned to the members of a group.
+    async fn list_group_member_roles(&self, group_id: Uuid) -> Result<Vec<String>>;
+
     /// Lists all group members.
     async fn list_group_members(
         &self,
@@ -258,6 +512,9 @@ pub(crate) trait DBDashboardGroup {
         filters: &GroupMembersFilters,
     ) -> Result<GroupMembersOutput>;
 
+    /// Lists all group members with the information included in the CSV export.
+    async fn list_group_members_export(&self, group_id: Uuid) -> Result<Vec<GroupMemberExport>>;
+
     /// Lists all group member user ids.
     async fn list_group_members_ids(&self, group_id: Uuid) -> Result<Vec<Uuid>>;
 
@@ -271,6 +528,9 @@ pub(crate) trait DBDashboardGroup {
     /// Lists all available group roles.
     async fn list_group_roles(&self) -> Result<Vec<GroupRoleSummary>>;
 
+    /// Lists the sponsor tiers defined by a group.
+    async fn list_group_sponsor_tiers(&self, group_id: Uuid) -> Result<Vec<GroupSponsorTier>>;
+
     /// Lists sponsors for a group.
     /// When `full_list` is true, ignores pagination filters.
     async fn list_group_sponsors(
@@ -311,6 +571,12 @@ pub(crate) trait DBDashboardGroup {
         user_id: Uuid,
     ) -> Result<()>;
 
+    /// Publishes the next draft event whose scheduled publication is due.
+    async fn publish_due_scheduled_event(
+        &self,
+        configured_provider: Option<PaymentProvider>,
+    ) -> Result<Option<ScheduledEventPublication>>;
+
     /// Publishes an event (sets published=true and records publication metadata).
     async fn publish_event(
         &self,
@@ -338,6 +604,40 @@ pub(crate) trait DBDashboardGroup {
         user_id: Uuid,
     ) -> Result<()>;
 
+    /// Rejects a pending photo submitted by an event attendee.
+    async fn reject_event_photo(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_photo_id: Uuid,
+    ) -> Result<()>;
+
+    /// Rejects a pending group join request.
+    async fn reject_group_join_request(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()>;
+
+    /// Removes a user from an event waiting list.
+    async fn remove_event_waitlist_user(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()>;
+
+    /// Requests the transfer of a group ownership to another team member.
+    async fn request_group_ownership_transfer(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()>;
+
     /// Resolves custom email recipient user ids for an event and recipient scope.
     /// Selected scopes are constrained to `requested_user_ids`.
     async fn resolve_event_custom_notification_recipient_ids(
@@ -348,6 +648,18 @@ pub(crate) trait DBDashboardGroup {
         requested_user_ids: Option<Vec<Uuid>>,
     ) -> Result<Vec<Uuid>>;
 
+    /// Resolves custom email recipient user ids for a group and audience.
+    /// Event audiences are constrained to `event_id`, and the inactive members
+    /// audience to members without attendance in the last `inactive_months`.
+    async fn resolve_group_custom_notification_recipient_ids(
+        &self,
+        group_id: Uuid,
+        audience: &str,
+        event_id: Option<Uuid>,
+        inactive_months: Option<i32>,
+        member_role: Option<String>,
+    ) -> Result<Vec<Uuid>>;
+
     /// Searches attendees for a group's event using filters.
     async fn search_event_attendees(
         &self,
@@ -372,6 +684,15 @@ pub(crate) trait DBDashboardGroup {
         filters: &WaitlistFilters,
     ) -> Result<WaitlistOutput>;
 
+    /// Lifts the ban of a user from a group.
+    async fn unban_group_member(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()>;
+
     /// Unpublishes an event (sets published=false and clears publication metadata).
     async fn unpublish_event(
         &self,
@@ -407,6 +728,102 @@ pub(crate) trait DBDashboardGroup {
         cfg_max_participants: &HashMap<MeetingProvider, i32>,
     ) -> Result<Vec<Uuid>>;
 
+    /// Pins or unpins a comment in an event's discussion.
+    async fn update_event_comment_pinned(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_comment_id: Uuid,
+        pinned: bool,
+    ) -> Result<()>;
+
+    /// Locks or unlocks an event's discussion.
+    async fn update_event_comments_locked(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        locked: bool,
+    ) -> Result<()>;
+
+    /// Pins or unpins an announcement on the group page.
+    async fn update_group_announcement_pinned(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_announcement_id: Uuid,
+        pinned: bool,
+    ) -> Result<()>;
+
+    /// Sets or clears the Discord webhook used to post group notifications.
+    async fn update_group_discord_webhook(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        webhook: &GroupDiscordWebhookUpdate,
+    ) -> Result<()>;
+
+    /// Locks or unlocks a topic in a group's discussion board.
+    async fn update_group_discussion_topic_locked(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_discussion_topic_id: Uuid,
+        locked: bool,
+    ) -> Result<()>;
+
+    /// Pins or unpins a topic in a group's discussion board.
+    async fn update_group_discussion_topic_pinned(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_discussion_topic_id: Uuid,
+        pinned: bool,
+    ) -> Result<()>;
+
+    /// Sets or clears the cover of the group gallery.
+    async fn update_group_gallery_cover(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        cover: &GroupGalleryCoverUpdate,
+    ) -> Result<()>;
+
+    /// Hides a photo from the group gallery or shows it again.
+    async fn update_group_gallery_photo_hidden(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        url: &str,
+        hidden: bool,
+    ) -> Result<()>;
+
+    /// Updates the photos uploaded by the organizers to the group gallery.
+    async fn update_group_gallery_photos(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        gallery: &GroupGalleryPhotosUpdate,
+    ) -> Result<()>;
+
+    /// Replaces the questions asked to users joining a group.
+    async fn update_group_join_questions(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        join_questions: &GroupJoinQuestionsUpdate,
+    ) -> Result<()>;
+
+    /// Sets or clears the role displayed for a group member.
+    async fn update_group_member_role(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        update: &GroupMemberRoleUpdate,
+    ) -> Result<()>;
+
     /// Updates an existing sponsor.
     async fn update_group_sponsor(
         &self,
@@ -425,6 +842,15 @@ pub(crate) trait DBDashboardGroup {
         featured: bool,
     ) -> Result<()>;
 
+    /// Updates an existing sponsor tier.
+    async fn update_group_sponsor_tier(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_sponsor_tier_id: Uuid,
+        tier: &GroupSponsorTierInput,
+    ) -> Result<()>;
+
     /// Updates a group team member role.
     async fn update_group_team_member_role(
         &self,
@@ -433,6 +859,22 @@ pub(crate) trait DBDashboardGroup {
         user_id: Uuid,
         role: &GroupRole,
     ) -> Result<()>;
+
+    /// Sets or clears the message included in the welcome email sent to new members.
+    async fn update_group_welcome_message(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        welcome_message: &GroupWelcomeMessageUpdate,
+    ) -> Result<()>;
+
+    /// Creates or updates the Zoom credentials configured for a group.
+    async fn update_group_zoom_credentials(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        credentials: &GroupZoomCredentialsUpdate,
+    ) -> Result<()>;
 }
 
 #[async_trait]
@@ -456,9 +898,24 @@ where
         .await
     }
 
-    /// [`DBDashboardGroup::add_event`]
-    #[instrument(skip(self, event, cfg_max_participants), err)]
-    async fn add_event(
+    /// [`DBDashboardGroup::accept_group_join_request`]
+    #[instrument(skip(self), err)]
+    async fn accept_group_join_request(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select accept_group_join_request($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &group_id, &user_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::add_event`]
+    #[instrument(skip(self, event, cfg_max_participants), err)]
+    async fn add_event(
         &self,
         actor_user_id: Uuid,
         group_id: Uuid,
@@ -500,6 +957,53 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::add_event_resource`]
+    #[instrument(skip(self, resource), err)]
+    async fn add_event_resource(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        resource: &NewEventResource,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_event_resource($1::uuid, $2::uuid, $3::uuid, $4::jsonb)::uuid",
+            &[&actor_user_id, &group_id, &event_id, &Json(resource)],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::add_event_template`]
+    #[instrument(skip(self), err)]
+    async fn add_event_template(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_event_template($1::uuid, $2::uuid, $3::uuid, $4::uuid)::uuid",
+            &[&actor_user_id, &community_id, &group_id, &event_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::add_group_announcement`]
+    #[instrument(skip(self, announcement), err)]
+    async fn add_group_announcement(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        announcement: &GroupAnnouncementInput,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_group_announcement($1::uuid, $2::uuid, $3::jsonb)::uuid",
+            &[&actor_user_id, &group_id, &Json(announcement)],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::add_group_sponsor`]
     #[instrument(skip(self, sponsor), err)]
     async fn add_group_sponsor(
@@ -515,6 +1019,21 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::add_group_sponsor_tier`]
+    #[instrument(skip(self, tier), err)]
+    async fn add_group_sponsor_tier(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        tier: &GroupSponsorTierInput,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_group_sponsor_tier($1::uuid, $2::uuid, $3::jsonb)::uuid",
+            &[&actor_user_id, &group_id, &Json(tier)],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::add_group_team_member`]
     #[instrument(skip(self), err)]
     async fn add_group_team_member(
@@ -531,6 +1050,45 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::approve_event_photo`]
+    #[instrument(skip(self), err)]
+    async fn approve_event_photo(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_photo_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select approve_event_photo($1::uuid, $2::uuid, $3::uuid, $4::uuid)",
+            &[&actor_user_id, &group_id, &event_id, &event_photo_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::ban_group_member`]
+    #[instrument(skip(self, ban), err)]
+    async fn ban_group_member(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        ban: &GroupBanInput,
+    ) -> Result<()> {
+        self.execute(
+            "select ban_group_member($1::uuid, $2::uuid, $3::uuid, $4::uuid, $5::text)",
+            &[
+                &actor_user_id,
+                &community_id,
+                &group_id,
+                &user_id,
+                &ban.reason,
+            ],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::cancel_event`]
     #[instrument(skip(self), err)]
     async fn cancel_event(
@@ -593,6 +1151,34 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::cancel_group_ownership_transfer`]
+    #[instrument(skip(self), err)]
+    async fn cancel_group_ownership_transfer(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select cancel_group_ownership_transfer($1::uuid, $2::uuid)",
+            &[&actor_user_id, &group_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::count_group_custom_notifications_since`]
+    #[instrument(skip(self), err)]
+    async fn count_group_custom_notifications_since(
+        &self,
+        group_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<i64> {
+        self.fetch_scalar_one(
+            "select count_group_custom_notifications_since($1::uuid, $2::timestamptz)",
+            &[&group_id, &since],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::delete_event`]
     #[instrument(skip(self), err)]
     async fn delete_event(
@@ -608,6 +1194,38 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::delete_event_comment`]
+    #[instrument(skip(self), err)]
+    async fn delete_event_comment(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_comment_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_event_comment($1::uuid, $2::uuid, $3::uuid, $4::uuid)",
+            &[&actor_user_id, &group_id, &event_id, &event_comment_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::delete_event_resource`]
+    #[instrument(skip(self), err)]
+    async fn delete_event_resource(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_resource_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_event_resource($1::uuid, $2::uuid, $3::uuid, $4::uuid)",
+            &[&actor_user_id, &group_id, &event_id, &event_resource_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::delete_event_series_events`]
     #[instrument(skip(self), err)]
     async fn delete_event_series_events(
@@ -623,6 +1241,66 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::delete_event_template`]
+    #[instrument(skip(self), err)]
+    async fn delete_event_template(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_template_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_event_template($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &group_id, &event_template_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::delete_group_announcement`]
+    #[instrument(skip(self), err)]
+    async fn delete_group_announcement(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_announcement_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_group_announcement($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &group_id, &group_announcement_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::delete_group_discussion_reply`]
+    #[instrument(skip(self), err)]
+    async fn delete_group_discussion_reply(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_discussion_reply_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_group_discussion_reply($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &group_id, &group_discussion_reply_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::delete_group_discussion_topic`]
+    #[instrument(skip(self), err)]
+    async fn delete_group_discussion_topic(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_discussion_topic_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_group_discussion_topic($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &group_id, &group_discussion_topic_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::delete_group_sponsor`]
     #[instrument(skip(self), err)]
     async fn delete_group_sponsor(
@@ -638,6 +1316,21 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::delete_group_sponsor_tier`]
+    #[instrument(skip(self), err)]
+    async fn delete_group_sponsor_tier(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_sponsor_tier_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_group_sponsor_tier($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &group_id, &group_sponsor_tier_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::delete_group_team_member`]
     #[instrument(skip(self), err)]
     async fn delete_group_team_member(
@@ -653,6 +1346,20 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::delete_group_zoom_credentials`]
+    #[instrument(skip(self), err)]
+    async fn delete_group_zoom_credentials(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select delete_group_zoom_credentials($1::uuid, $2::uuid)",
+            &[&actor_user_id, &group_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::get_cfs_submission_notification_data`]
     #[instrument(skip(self), err)]
     async fn get_cfs_submission_notification_data(
@@ -667,6 +1374,97 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::get_event_attendee_by_ticket_code`]
+    #[instrument(skip(self), err)]
+    async fn get_event_attendee_by_ticket_code(
+        &self,
+        group_id: Uuid,
+        event_id: Uuid,
+        ticket_code: Uuid,
+    ) -> Result<Option<KioskAttendee>> {
+        self.fetch_json_opt(
+            "select get_event_attendee_by_ticket_code($1::uuid, $2::uuid, $3::uuid)",
+            &[&group_id, &event_id, &ticket_code],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::get_event_check_in_code`]
+    #[instrument(skip(self), err)]
+    async fn get_event_check_in_code(&self, event_id: Uuid) -> Result<String> {
+        self.fetch_scalar_one(
+            "select get_event_check_in_code($1::uuid, current_timestamp)",
+            &[&event_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::get_event_template_details`]
+    #[instrument(skip(self), err)]
+    async fn get_event_template_details(
+        &self,
+        group_id: Uuid,
+        event_template_id: Uuid,
+    ) -> Result<Option<serde_json::Value>> {
+        self.fetch_json_opt(
+            "select get_event_template_details($1::uuid, $2::uuid)",
+            &[&group_id, &event_template_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::get_group_discord_webhook_url`]
+    #[instrument(skip(self), err)]
+    async fn get_group_discord_webhook_url(&self, group_id: Uuid) -> Result<Option<String>> {
+        self.fetch_scalar_opt(
+            "select discord_webhook_url from \"group\" where group_id = $1::uuid;",
+            &[&group_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::get_group_engagement_stats`]
+    #[instrument(skip(self), err)]
+    async fn get_group_engagement_stats(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        include_subgroups: bool,
+    ) -> Result<GroupEngagementStats> {
+        #[cached(
+            ttl = 3600,
+            key = "(Uuid, Uuid, bool)",
+            convert = "{ (community_id, group_id, include_subgroups) }",
+            sync_writes = "by_key"
+        )]
+        async fn inner(
+            db: PgClient<'_>,
+            community_id: Uuid,
+            group_id: Uuid,
+            include_subgroups: bool,
+        ) -> Result<GroupEngagementStats> {
+            let row = db
+                .query_one(
+                    "select get_group_engagement_stats($1::uuid, $2::uuid, $3::bool)",
+                    &[&community_id, &group_id, &include_subgroups],
+                )
+                .await?;
+            let stats = row.try_get::<_, Json<GroupEngagementStats>>(0)?.0;
+
+            Ok(stats)
+        }
+
+        let db = self.client().await?;
+        inner(db, community_id, group_id, include_subgroups).await
+    }
+
+    /// [`DBDashboardGroup::get_group_ownership`]
+    #[instrument(skip(self), err)]
+    async fn get_group_ownership(&self, group_id: Uuid) -> Result<GroupOwnership> {
+        self.fetch_json_one("select get_group_ownership($1::uuid)", &[&group_id])
+            .await
+    }
+
     /// [`DBDashboardGroup::get_group_payment_recipient`]
     #[instrument(skip(self), err)]
     async fn get_group_payment_recipient(
@@ -737,6 +1535,23 @@ where
         inner(db, community_id, group_id, include_subgroups).await
     }
 
+    /// [`DBDashboardGroup::get_group_welcome_message`]
+    #[instrument(skip(self), err)]
+    async fn get_group_welcome_message(&self, group_id: Uuid) -> Result<Option<String>> {
+        self.fetch_scalar_opt(
+            "select welcome_message from \"group\" where group_id = $1::uuid;",
+            &[&group_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::get_group_zoom_account`]
+    #[instrument(skip(self), err)]
+    async fn get_group_zoom_account(&self, group_id: Uuid) -> Result<Option<GroupZoomAccount>> {
+        self.fetch_json_opt("select get_group_zoom_account($1::uuid)", &[&group_id])
+            .await
+    }
+
     /// [`DBDashboardGroup::invite_event_attendee`]
     #[instrument(skip(self, email), err)]
     async fn invite_event_attendee(
@@ -754,6 +1569,23 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::invite_group_team_member`]
+    #[instrument(skip(self, email, token_hash), err)]
+    async fn invite_group_team_member(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        email: &str,
+        role: &GroupRole,
+        token_hash: &str,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select invite_group_team_member($1::uuid, $2::uuid, $3::text, $4::text, $5::text)::uuid",
+            &[&actor_user_id, &group_id, &email, &role.to_string(), &token_hash],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::list_cfs_submission_statuses_for_review`]
     #[instrument(skip(self), err)]
     async fn list_cfs_submission_statuses_for_review(&self) -> Result<Vec<CfsSubmissionStatus>> {
@@ -774,6 +1606,20 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::list_event_attendee_badges`]
+    #[instrument(skip(self), err)]
+    async fn list_event_attendee_badges(
+        &self,
+        group_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<Vec<AttendeeBadge>> {
+        self.fetch_json_one(
+            "select list_event_attendee_badges($1::uuid, $2::uuid)",
+            &[&group_id, &event_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::list_event_attendees_ids`]
     #[instrument(skip(self), err)]
     async fn list_event_attendees_ids(&self, group_id: Uuid, event_id: Uuid) -> Result<Vec<Uuid>> {
@@ -805,6 +1651,21 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::list_event_conflicts`]
+    #[instrument(skip(self, event), err)]
+    async fn list_event_conflicts(
+        &self,
+        group_id: Uuid,
+        event_id: Option<Uuid>,
+        event: &serde_json::Value,
+    ) -> Result<Vec<EventConflict>> {
+        self.fetch_json_one(
+            "select list_event_conflicts($1::uuid, $2::uuid, $3::jsonb)",
+            &[&group_id, &event_id, &Json(event)],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::list_event_kinds`]
     #[instrument(skip(self), err)]
     async fn list_event_kinds(&self) -> Result<Vec<EventKind>> {
@@ -825,6 +1686,26 @@ where
         inner(db).await
     }
 
+    /// [`DBDashboardGroup::list_event_photos`]
+    #[instrument(skip(self), err)]
+    async fn list_event_photos(&self, group_id: Uuid, event_id: Uuid) -> Result<Vec<EventPhoto>> {
+        self.fetch_json_one(
+            "select list_event_photos($1::uuid, $2::uuid)",
+            &[&group_id, &event_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::list_event_resources`]
+    #[instrument(skip(self), err)]
+    async fn list_event_resources(&self, event_id: Uuid) -> Result<Vec<EventResource>> {
+        self.fetch_json_one(
+            "select coalesce(list_event_resources($1::uuid), '[]'::jsonb)",
+            &[&event_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::list_event_series_cancelable_event_ids`].
     #[instrument(skip(self), err)]
     async fn list_event_series_cancelable_event_ids(
@@ -867,6 +1748,13 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::list_event_templates`]
+    #[instrument(skip(self), err)]
+    async fn list_event_templates(&self, group_id: Uuid) -> Result<Vec<EventTemplate>> {
+        self.fetch_json_one("select list_event_templates($1::uuid)", &[&group_id])
+            .await
+    }
+
     /// [`DBDashboardGroup::list_event_waitlist_ids`]
     #[instrument(skip(self), err)]
     async fn list_event_waitlist_ids(&self, group_id: Uuid, event_id: Uuid) -> Result<Vec<Uuid>> {
@@ -877,6 +1765,13 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::list_group_announcements`]
+    #[instrument(skip(self), err)]
+    async fn list_group_announcements(&self, group_id: Uuid) -> Result<Vec<GroupAnnouncement>> {
+        self.fetch_json_one("select list_group_announcements($1::uuid)", &[&group_id])
+            .await
+    }
+
     /// [`DBDashboardGroup::list_group_audit_logs`]
     #[instrument(skip(self, filters), err)]
     async fn list_group_audit_logs(
@@ -891,20 +1786,68 @@ where
         .await
     }
 
-    /// [`DBDashboardGroup::list_group_events`]
+    /// [`DBDashboardGroup::list_group_bans`]
+    #[instrument(skip(self), err)]
+    async fn list_group_bans(&self, group_id: Uuid) -> Result<Vec<GroupBan>> {
+        self.fetch_json_one("select list_group_bans($1::uuid)", &[&group_id])
+            .await
+    }
+
+    /// [`DBDashboardGroup::list_group_custom_notifications`]
+    #[instrument(skip(self, filters), err)]
+    async fn list_group_custom_notifications(
+        &self,
+        group_id: Uuid,
+        filters: &CustomNotificationsFilters,
+    ) -> Result<CustomNotificationsOutput> {
+        self.fetch_json_one(
+            "select list_group_custom_notifications($1::uuid, $2::jsonb)",
+            &[&group_id, &Json(filters)],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::list_group_events`]
+    #[instrument(skip(self), err)]
+    async fn list_group_events(
+        &self,
+        group_id: Uuid,
+        filters: &EventsListFilters,
+    ) -> Result<GroupEvents> {
+        self.fetch_json_one(
+            "select list_group_events($1::uuid, $2::jsonb)",
+            &[&group_id, &Json(filters)],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::list_group_join_requests`]
+    #[instrument(skip(self), err)]
+    async fn list_group_join_requests(&self, group_id: Uuid) -> Result<Vec<GroupJoinRequest>> {
+        self.fetch_json_one("select list_group_join_requests($1::uuid)", &[&group_id])
+            .await
+    }
+
+    /// [`DBDashboardGroup::list_group_member_leaderboard`]
     #[instrument(skip(self), err)]
-    async fn list_group_events(
+    async fn list_group_member_leaderboard(
         &self,
         group_id: Uuid,
-        filters: &EventsListFilters,
-    ) -> Result<GroupEvents> {
+    ) -> Result<Vec<GroupMemberLeaderboardEntry>> {
         self.fetch_json_one(
-            "select list_group_events($1::uuid, $2::jsonb)",
-            &[&group_id, &Json(filters)],
+            "select list_group_member_leaderboard($1::uuid)",
+            &[&group_id],
         )
         .await
     }
 
+    /// [`DBDashboardGroup::list_group_member_roles`]
+    #[instrument(skip(self), err)]
+    async fn list_group_member_roles(&self, group_id: Uuid) -> Result<Vec<String>> {
+        self.fetch_json_one("select list_group_member_roles($1::uuid)", &[&group_id])
+            .await
+    }
+
     /// [`DBDashboardGroup::list_group_members`]
     #[instrument(skip(self), err)]
     async fn list_group_members(
@@ -919,6 +1862,13 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::list_group_members_export`]
+    #[instrument(skip(self), err)]
+    async fn list_group_members_export(&self, group_id: Uuid) -> Result<Vec<GroupMemberExport>> {
+        self.fetch_json_one("select list_group_members_export($1::uuid)", &[&group_id])
+            .await
+    }
+
     /// [`DBDashboardGroup::list_group_members_ids`]
     #[instrument(skip(self), err)]
     async fn list_group_members_ids(&self, group_id: Uuid) -> Result<Vec<Uuid>> {
@@ -960,6 +1910,13 @@ where
         inner(db).await
     }
 
+    /// [`DBDashboardGroup::list_group_sponsor_tiers`]
+    #[instrument(skip(self), err)]
+    async fn list_group_sponsor_tiers(&self, group_id: Uuid) -> Result<Vec<GroupSponsorTier>> {
+        self.fetch_json_one("select list_group_sponsor_tiers($1::uuid)", &[&group_id])
+            .await
+    }
+
     /// [`DBDashboardGroup::list_group_sponsors`]
     #[instrument(skip(self), err)]
     async fn list_group_sponsors(
@@ -1069,6 +2026,19 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::publish_due_scheduled_event`]
+    #[instrument(skip(self), err)]
+    async fn publish_due_scheduled_event(
+        &self,
+        configured_provider: Option<PaymentProvider>,
+    ) -> Result<Option<ScheduledEventPublication>> {
+        self.fetch_json_opt(
+            "select publish_due_scheduled_event($1::text)",
+            &[&configured_provider.map(|provider| provider.to_string())],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::publish_event`]
     #[instrument(skip(self), err)]
     async fn publish_event(
@@ -1127,6 +2097,68 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::reject_event_photo`]
+    #[instrument(skip(self), err)]
+    async fn reject_event_photo(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_photo_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select reject_event_photo($1::uuid, $2::uuid, $3::uuid, $4::uuid)",
+            &[&actor_user_id, &group_id, &event_id, &event_photo_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::reject_group_join_request`]
+    #[instrument(skip(self), err)]
+    async fn reject_group_join_request(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select reject_group_join_request($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &group_id, &user_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::remove_event_waitlist_user`]
+    #[instrument(skip(self), err)]
+    async fn remove_event_waitlist_user(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select remove_event_waitlist_user($1::uuid, $2::uuid, $3::uuid, $4::uuid)",
+            &[&actor_user_id, &group_id, &event_id, &user_id],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::request_group_ownership_transfer`]
+    #[instrument(skip(self), err)]
+    async fn request_group_ownership_transfer(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select request_group_ownership_transfer($1::uuid, $2::uuid, $3::uuid)",
+            &[&actor_user_id, &group_id, &user_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::resolve_event_custom_notification_recipient_ids`]
     #[instrument(skip(self, requested_user_ids), err)]
     async fn resolve_event_custom_notification_recipient_ids(
@@ -1143,6 +2175,23 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::resolve_group_custom_notification_recipient_ids`]
+    #[instrument(skip(self), err)]
+    async fn resolve_group_custom_notification_recipient_ids(
+        &self,
+        group_id: Uuid,
+        audience: &str,
+        event_id: Option<Uuid>,
+        inactive_months: Option<i32>,
+        member_role: Option<String>,
+    ) -> Result<Vec<Uuid>> {
+        self.fetch_scalar_one(
+            "select resolve_group_custom_notification_recipient_ids($1::uuid, $2::text, $3::uuid, $4::int, $5::text)",
+            &[&group_id, &audience, &event_id, &inactive_months, &member_role],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::search_event_attendees`]
     #[instrument(skip(self, filters), err)]
     async fn search_event_attendees(
@@ -1188,6 +2237,22 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::unban_group_member`]
+    #[instrument(skip(self), err)]
+    async fn unban_group_member(
+        &self,
+        actor_user_id: Uuid,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select unban_group_member($1::uuid, $2::uuid, $3::uuid, $4::uuid)",
+            &[&actor_user_id, &community_id, &group_id, &user_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::unpublish_event`]
     #[instrument(skip(self), err)]
     async fn unpublish_event(
@@ -1262,6 +2327,199 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::update_event_comment_pinned`]
+    #[instrument(skip(self), err)]
+    async fn update_event_comment_pinned(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        event_comment_id: Uuid,
+        pinned: bool,
+    ) -> Result<()> {
+        self.execute(
+            "select update_event_comment_pinned($1::uuid, $2::uuid, $3::uuid, $4::uuid, $5::bool)",
+            &[
+                &actor_user_id,
+                &group_id,
+                &event_id,
+                &event_comment_id,
+                &pinned,
+            ],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_event_comments_locked`]
+    #[instrument(skip(self), err)]
+    async fn update_event_comments_locked(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        event_id: Uuid,
+        locked: bool,
+    ) -> Result<()> {
+        self.execute(
+            "select update_event_comments_locked($1::uuid, $2::uuid, $3::uuid, $4::bool)",
+            &[&actor_user_id, &group_id, &event_id, &locked],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_announcement_pinned`]
+    #[instrument(skip(self), err)]
+    async fn update_group_announcement_pinned(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_announcement_id: Uuid,
+        pinned: bool,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_announcement_pinned($1::uuid, $2::uuid, $3::uuid, $4::boolean)",
+            &[&actor_user_id, &group_id, &group_announcement_id, &pinned],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_discord_webhook`]
+    #[instrument(skip(self, webhook), err)]
+    async fn update_group_discord_webhook(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        webhook: &GroupDiscordWebhookUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_discord_webhook($1::uuid, $2::uuid, $3::text)",
+            &[&actor_user_id, &group_id, &webhook.webhook_url],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_discussion_topic_locked`]
+    #[instrument(skip(self), err)]
+    async fn update_group_discussion_topic_locked(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_discussion_topic_id: Uuid,
+        locked: bool,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_discussion_topic_locked($1::uuid, $2::uuid, $3::uuid, $4::bool)",
+            &[
+                &actor_user_id,
+                &group_id,
+                &group_discussion_topic_id,
+                &locked,
+            ],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_discussion_topic_pinned`]
+    #[instrument(skip(self), err)]
+    async fn update_group_discussion_topic_pinned(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_discussion_topic_id: Uuid,
+        pinned: bool,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_discussion_topic_pinned($1::uuid, $2::uuid, $3::uuid, $4::bool)",
+            &[
+                &actor_user_id,
+                &group_id,
+                &group_discussion_topic_id,
+                &pinned,
+            ],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_gallery_cover`]
+    #[instrument(skip(self), err)]
+    async fn update_group_gallery_cover(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        cover: &GroupGalleryCoverUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_gallery_cover($1::uuid, $2::uuid, $3::text)",
+            &[&actor_user_id, &group_id, &cover.url],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_gallery_photo_hidden`]
+    #[instrument(skip(self), err)]
+    async fn update_group_gallery_photo_hidden(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        url: &str,
+        hidden: bool,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_gallery_photo_hidden($1::uuid, $2::uuid, $3::text, $4::bool)",
+            &[&actor_user_id, &group_id, &url, &hidden],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_gallery_photos`]
+    #[instrument(skip(self, gallery), err)]
+    async fn update_group_gallery_photos(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        gallery: &GroupGalleryPhotosUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_gallery_photos($1::uuid, $2::uuid, $3::jsonb)",
+            &[&actor_user_id, &group_id, &Json(gallery)],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_join_questions`]
+    #[instrument(skip(self, join_questions), err)]
+    async fn update_group_join_questions(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        join_questions: &GroupJoinQuestionsUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_join_questions($1::uuid, $2::uuid, $3::jsonb)",
+            &[
+                &actor_user_id,
+                &group_id,
+                &Json(&join_questions.join_questions),
+            ],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_member_role`]
+    #[instrument(skip(self, update), err)]
+    async fn update_group_member_role(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        update: &GroupMemberRoleUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_member_role($1::uuid, $2::uuid, $3::uuid, $4::text)",
+            &[&actor_user_id, &group_id, &user_id, &update.member_role],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::update_group_sponsor`]
     #[instrument(skip(self, sponsor), err)]
     async fn update_group_sponsor(
@@ -1294,6 +2552,27 @@ where
         .await
     }
 
+    /// [`DBDashboardGroup::update_group_sponsor_tier`]
+    #[instrument(skip(self, tier), err)]
+    async fn update_group_sponsor_tier(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        group_sponsor_tier_id: Uuid,
+        tier: &GroupSponsorTierInput,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_sponsor_tier($1::uuid, $2::uuid, $3::uuid, $4::jsonb)",
+            &[
+                &actor_user_id,
+                &group_id,
+                &group_sponsor_tier_id,
+                &Json(tier),
+            ],
+        )
+        .await
+    }
+
     /// [`DBDashboardGroup::update_group_team_member_role`]
     #[instrument(skip(self), err)]
     async fn update_group_team_member_role(
@@ -1309,4 +2588,45 @@ where
         )
         .await
     }
+
+    /// [`DBDashboardGroup::update_group_welcome_message`]
+    #[instrument(skip(self, welcome_message), err)]
+    async fn update_group_welcome_message(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        welcome_message: &GroupWelcomeMessageUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_welcome_message($1::uuid, $2::uuid, $3::text)",
+            &[&actor_user_id, &group_id, &welcome_message.welcome_message],
+        )
+        .await
+    }
+
+    /// [`DBDashboardGroup::update_group_zoom_credentials`]
+    #[instrument(skip(self, credentials), err)]
+    async fn update_group_zoom_credentials(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+        credentials: &GroupZoomCredentialsUpdate,
+    ) -> Result<()> {
+        self.execute(
+            "select update_group_zoom_credentials($1::uuid, $2::uuid, $3::jsonb)",
+            &[&actor_user_id, &group_id, &Json(credentials)],
+        )
+        .await
+    }
+}
+
+/// Draft event published because its scheduled publication was due.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ScheduledEventPublication {
+    /// Community identifier.
+    pub community_id: Uuid,
+    /// Event identifier.
+    pub event_id: Uuid,
+    /// Group identifier.
+    pub group_id: Uuid,
 }
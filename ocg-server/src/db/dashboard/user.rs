@@ -8,11 +8,15 @@ use uuid::Uuid;
 
 use crate::{
     db::PgExecutor,
+    services::certificates::AttendanceCertificate,
     templates::dashboard::{
         audit::{AuditLogFilters, AuditLogsOutput},
         user::{
             events::{UserEventsFilters, UserEventsOutput},
-            invitations::{CommunityTeamInvitation, EventInvitation, GroupTeamInvitation},
+            invitations::{
+                CommunityTeamInvitation, EventInvitation, GroupOwnershipTransferInvitation,
+                GroupTeamInvitation,
+            },
             session_proposals::{
                 PendingCoSpeakerInvitation, SessionProposalInput, SessionProposalLevel,
                 SessionProposalsFilters, SessionProposalsOutput,
@@ -20,7 +24,7 @@ use crate::{
             submissions::{CfsSubmissionsFilters, CfsSubmissionsOutput},
         },
     },
-    types::questionnaire::QuestionnaireAnswers,
+    types::{event::EventSummary, questionnaire::QuestionnaireAnswers},
 };
 
 /// Database trait for user dashboard operations.
@@ -40,10 +44,24 @@ pub(crate) trait DBDashboardUser {
         event_id: Uuid,
     ) -> Result<Uuid>;
 
+    /// Accepts a pending group ownership transfer, making the user the group owner.
+    async fn accept_group_ownership_transfer(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()>;
+
     /// Accepts a pending group team invitation.
     async fn accept_group_team_invitation(&self, actor_user_id: Uuid, group_id: Uuid)
     -> Result<()>;
 
+    /// Accepts the pending group team invitation a link was issued for.
+    async fn accept_group_team_invitation_link(
+        &self,
+        actor_user_id: Uuid,
+        token_hash: &str,
+    ) -> Result<bool>;
+
     /// Accepts a pending co-speaker invitation for a session proposal.
     async fn accept_session_proposal_co_speaker_invitation(
         &self,
@@ -72,9 +90,22 @@ pub(crate) trait DBDashboardUser {
         session_proposal_id: Uuid,
     ) -> Result<Option<SessionProposalCoSpeakerUser>>;
 
+    /// Gets the certificate of attendance of the user for an event, if any.
+    async fn get_user_attendance_certificate(
+        &self,
+        user_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<Option<AttendanceCertificate>>;
+
     /// Lists all available session proposal levels.
     async fn list_session_proposal_levels(&self) -> Result<Vec<SessionProposalLevel>>;
 
+    /// Lists the certificates of attendance available to the user.
+    async fn list_user_attendance_certificates(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<AttendanceCertificate>>;
+
     /// Lists user dashboard audit log rows.
     async fn list_user_audit_logs(
         &self,
@@ -105,6 +136,12 @@ pub(crate) trait DBDashboardUser {
         filters: &UserEventsFilters,
     ) -> Result<UserEventsOutput>;
 
+    /// Lists all pending group ownership transfers for the user.
+    async fn list_user_group_ownership_transfers(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<GroupOwnershipTransferInvitation>>;
+
     /// Lists all pending group team invitations for the user.
     async fn list_user_group_team_invitations(
         &self,
@@ -124,6 +161,9 @@ pub(crate) trait DBDashboardUser {
         filters: &SessionProposalsFilters,
     ) -> Result<SessionProposalsOutput>;
 
+    /// Lists upcoming events matching the user interests they are not attending.
+    async fn list_user_suggested_events(&self, user_id: Uuid) -> Result<Vec<EventSummary>>;
+
     /// Rejects a pending community team invitation.
     async fn reject_community_team_invitation(
         &self,
@@ -138,6 +178,13 @@ pub(crate) trait DBDashboardUser {
         event_id: Uuid,
     ) -> Result<()>;
 
+    /// Rejects a pending group ownership transfer.
+    async fn reject_group_ownership_transfer(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()>;
+
     /// Rejects a pending group team invitation.
     async fn reject_group_team_invitation(&self, actor_user_id: Uuid, group_id: Uuid)
     -> Result<()>;
@@ -214,6 +261,20 @@ where
         .await
     }
 
+    /// [`DBDashboardUser::accept_group_ownership_transfer`]
+    #[instrument(skip(self), err)]
+    async fn accept_group_ownership_transfer(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select accept_group_ownership_transfer($1::uuid, $2::uuid)",
+            &[&actor_user_id, &group_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardUser::accept_group_team_invitation`]
     #[instrument(skip(self), err)]
     async fn accept_group_team_invitation(
@@ -228,6 +289,20 @@ where
         .await
     }
 
+    /// [`DBDashboardUser::accept_group_team_invitation_link`]
+    #[instrument(skip(self, token_hash), err)]
+    async fn accept_group_team_invitation_link(
+        &self,
+        actor_user_id: Uuid,
+        token_hash: &str,
+    ) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select accept_group_team_invitation_link($1::uuid, $2::text)",
+            &[&actor_user_id, &token_hash],
+        )
+        .await
+    }
+
     /// [`DBDashboardUser::accept_session_proposal_co_speaker_invitation`]
     #[instrument(skip(self), err)]
     async fn accept_session_proposal_co_speaker_invitation(
@@ -295,6 +370,20 @@ where
         }))
     }
 
+    /// [`DBDashboardUser::get_user_attendance_certificate`]
+    #[instrument(skip(self), err)]
+    async fn get_user_attendance_certificate(
+        &self,
+        user_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<Option<AttendanceCertificate>> {
+        self.fetch_json_opt(
+            "select get_user_attendance_certificate($1::uuid, $2::uuid)",
+            &[&user_id, &event_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardUser::list_session_proposal_levels`]
     #[instrument(skip(self), err)]
     async fn list_session_proposal_levels(&self) -> Result<Vec<SessionProposalLevel>> {
@@ -302,6 +391,19 @@ where
             .await
     }
 
+    /// [`DBDashboardUser::list_user_attendance_certificates`]
+    #[instrument(skip(self), err)]
+    async fn list_user_attendance_certificates(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<AttendanceCertificate>> {
+        self.fetch_json_one(
+            "select list_user_attendance_certificates($1::uuid)",
+            &[&user_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardUser::list_user_audit_logs`]
     #[instrument(skip(self, filters), err)]
     async fn list_user_audit_logs(
@@ -364,6 +466,19 @@ where
         .await
     }
 
+    /// [`DBDashboardUser::list_user_group_ownership_transfers`]
+    #[instrument(skip(self), err)]
+    async fn list_user_group_ownership_transfers(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<GroupOwnershipTransferInvitation>> {
+        self.fetch_json_one(
+            "select list_user_group_ownership_transfers($1::uuid)",
+            &[&user_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardUser::list_user_group_team_invitations`]
     #[instrument(skip(self), err)]
     async fn list_user_group_team_invitations(
@@ -404,6 +519,13 @@ where
         .await
     }
 
+    /// [`DBDashboardUser::list_user_suggested_events`]
+    #[instrument(skip(self), err)]
+    async fn list_user_suggested_events(&self, user_id: Uuid) -> Result<Vec<EventSummary>> {
+        self.fetch_json_one("select list_user_suggested_events($1::uuid)", &[&user_id])
+            .await
+    }
+
     /// [`DBDashboardUser::reject_community_team_invitation`]
     #[instrument(skip(self), err)]
     async fn reject_community_team_invitation(
@@ -432,6 +554,20 @@ where
         .await
     }
 
+    /// [`DBDashboardUser::reject_group_ownership_transfer`]
+    #[instrument(skip(self), err)]
+    async fn reject_group_ownership_transfer(
+        &self,
+        actor_user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<()> {
+        self.execute(
+            "select reject_group_ownership_transfer($1::uuid, $2::uuid)",
+            &[&actor_user_id, &group_id],
+        )
+        .await
+    }
+
     /// [`DBDashboardUser::reject_group_team_invitation`]
     #[instrument(skip(self), err)]
     async fn reject_group_team_invitation(
@@ -11,7 +11,9 @@ use crate::{
     templates::event::SessionProposal,
     types::{
         event::{
-            EventAttendanceInfo, EventAttendanceStatus, EventFull, EventLeaveOutcome, EventSummary,
+            EventAttendanceInfo, EventAttendanceStatus, EventDiscussion, EventFull,
+            EventLeaveOutcome, EventReminderPreference, EventResourceFile, EventSeries,
+            EventSummary, SpeakerProfile,
         },
         questionnaire::{QuestionnaireAnswers, QuestionnaireQuestion},
     },
@@ -30,6 +32,25 @@ pub(crate) trait DBEvent {
         label_ids: &[Uuid],
     ) -> Result<Uuid>;
 
+    /// Adds a comment or reply to an event's discussion.
+    async fn add_event_comment(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+        content: &str,
+        parent_event_comment_id: Option<Uuid>,
+    ) -> Result<Uuid>;
+
+    /// Adds a photo submitted by an attendee to an event.
+    async fn add_event_photo(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+        url: &str,
+    ) -> Result<Uuid>;
+
     /// Registers attendance and returns the resulting attendance status.
     async fn attend_event(
         &self,
@@ -59,6 +80,13 @@ pub(crate) trait DBEvent {
         user_id: Uuid,
     ) -> Result<EventAttendanceInfo>;
 
+    /// Retrieves the discussion of an event.
+    async fn get_event_discussion(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<EventDiscussion>;
+
     /// Retrieves detailed event information.
     async fn get_event_full_by_slug(
         &self,
@@ -74,6 +102,21 @@ pub(crate) trait DBEvent {
         event_id: Uuid,
     ) -> Result<Vec<QuestionnaireQuestion>>;
 
+    /// Retrieves the details needed to download a file attached to an event.
+    async fn get_event_resource(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        event_resource_id: Uuid,
+    ) -> Result<Option<EventResourceFile>>;
+
+    /// Retrieves a linked event series with its published events.
+    async fn get_event_series(
+        &self,
+        community_id: Uuid,
+        event_series_id: Uuid,
+    ) -> Result<Option<EventSeries>>;
+
     /// Retrieves summary event information by its identifier.
     async fn get_event_summary_by_id(
         &self,
@@ -81,6 +124,36 @@ pub(crate) trait DBEvent {
         event_id: Uuid,
     ) -> Result<EventSummary>;
 
+    /// Retrieves the ticket code of a confirmed attendee of an event.
+    async fn get_event_ticket_code(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Uuid>>;
+
+    /// Retrieves a speaker's public profile with their talks in a community.
+    async fn get_speaker_profile(
+        &self,
+        community_id: Uuid,
+        username: &str,
+    ) -> Result<Option<SpeakerProfile>>;
+
+    /// Retrieves the events in a user's personal calendar feed, identified by
+    /// its token.
+    async fn get_user_calendar_events(
+        &self,
+        calendar_token: &str,
+    ) -> Result<Option<Vec<EventSummary>>>;
+
+    /// Checks if a kiosk check-in code is still valid for an event.
+    async fn is_event_check_in_code_valid(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        code: &str,
+    ) -> Result<bool>;
+
     /// Checks if the check-in window is open for an event.
     async fn is_event_check_in_window_open(
         &self,
@@ -102,6 +175,15 @@ pub(crate) trait DBEvent {
         user_id: Uuid,
         event_id: Uuid,
     ) -> Result<Vec<SessionProposal>>;
+
+    /// Updates when an attendee wants to be reminded about an event.
+    async fn update_event_reminder_preference(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+        reminder_preference: Option<EventReminderPreference>,
+    ) -> Result<()>;
 }
 
 #[async_trait]
@@ -132,6 +214,45 @@ where
         .await
     }
 
+    /// [`DBEvent::add_event_comment`]
+    #[instrument(skip(self), err)]
+    async fn add_event_comment(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+        content: &str,
+        parent_event_comment_id: Option<Uuid>,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_event_comment($1::uuid, $2::uuid, $3::uuid, $4::text, $5::uuid)::uuid",
+            &[
+                &community_id,
+                &event_id,
+                &user_id,
+                &content,
+                &parent_event_comment_id,
+            ],
+        )
+        .await
+    }
+
+    /// [`DBEvent::add_event_photo`]
+    #[instrument(skip(self), err)]
+    async fn add_event_photo(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+        url: &str,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_event_photo($1::uuid, $2::uuid, $3::uuid, $4::text)::uuid",
+            &[&community_id, &event_id, &user_id, &url],
+        )
+        .await
+    }
+
     /// [`DBEvent::attend_event`]
     #[instrument(skip(self, registration_answers), err)]
     async fn attend_event(
@@ -199,6 +320,20 @@ where
         .await
     }
 
+    /// [`DBEvent::get_event_discussion`]
+    #[instrument(skip(self), err)]
+    async fn get_event_discussion(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<EventDiscussion> {
+        self.fetch_json_one(
+            "select get_event_discussion($1::uuid, $2::uuid)",
+            &[&community_id, &event_id],
+        )
+        .await
+    }
+
     /// [`DBEvent::get_event_full_by_slug`]
     #[instrument(skip(self), err)]
     async fn get_event_full_by_slug(
@@ -228,6 +363,35 @@ where
         .await
     }
 
+    /// [`DBEvent::get_event_resource`]
+    #[instrument(skip(self), err)]
+    async fn get_event_resource(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        event_resource_id: Uuid,
+    ) -> Result<Option<EventResourceFile>> {
+        self.fetch_json_opt(
+            "select get_event_resource($1::uuid, $2::uuid, $3::uuid)",
+            &[&community_id, &event_id, &event_resource_id],
+        )
+        .await
+    }
+
+    /// [`DBEvent::get_event_series`]
+    #[instrument(skip(self), err)]
+    async fn get_event_series(
+        &self,
+        community_id: Uuid,
+        event_series_id: Uuid,
+    ) -> Result<Option<EventSeries>> {
+        self.fetch_json_opt(
+            "select get_event_series($1::uuid, $2::uuid)",
+            &[&community_id, &event_series_id],
+        )
+        .await
+    }
+
     /// [`DBEvent::get_event_summary_by_id`]
     #[instrument(skip(self), err)]
     async fn get_event_summary_by_id(
@@ -242,6 +406,63 @@ where
         .await
     }
 
+    /// [`DBEvent::get_event_ticket_code`]
+    #[instrument(skip(self), err)]
+    async fn get_event_ticket_code(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Uuid>> {
+        self.fetch_scalar_opt(
+            "select get_event_ticket_code($1::uuid, $2::uuid, $3::uuid)",
+            &[&community_id, &event_id, &user_id],
+        )
+        .await
+    }
+
+    /// [`DBEvent::get_speaker_profile`]
+    #[instrument(skip(self), err)]
+    async fn get_speaker_profile(
+        &self,
+        community_id: Uuid,
+        username: &str,
+    ) -> Result<Option<SpeakerProfile>> {
+        self.fetch_json_opt(
+            "select get_speaker_profile($1::uuid, $2::text)",
+            &[&community_id, &username],
+        )
+        .await
+    }
+
+    /// [`DBEvent::get_user_calendar_events`]
+    #[instrument(skip(self, calendar_token), err)]
+    async fn get_user_calendar_events(
+        &self,
+        calendar_token: &str,
+    ) -> Result<Option<Vec<EventSummary>>> {
+        self.fetch_json_opt(
+            "select get_user_calendar_events($1::text)",
+            &[&calendar_token],
+        )
+        .await
+    }
+
+    /// [`DBEvent::is_event_check_in_code_valid`]
+    #[instrument(skip(self, code), err)]
+    async fn is_event_check_in_code_valid(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        code: &str,
+    ) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select is_event_check_in_code_valid($1::uuid, $2::uuid, $3::text)",
+            &[&community_id, &event_id, &code],
+        )
+        .await
+    }
+
     /// [`DBEvent::is_event_check_in_window_open`]
     #[instrument(skip(self), err)]
     async fn is_event_check_in_window_open(
@@ -284,4 +505,25 @@ where
         )
         .await
     }
+
+    /// [`DBEvent::update_event_reminder_preference`]
+    #[instrument(skip(self), err)]
+    async fn update_event_reminder_preference(
+        &self,
+        community_id: Uuid,
+        event_id: Uuid,
+        user_id: Uuid,
+        reminder_preference: Option<EventReminderPreference>,
+    ) -> Result<()> {
+        self.execute(
+            "select update_event_reminder_preference($1::uuid, $2::uuid, $3::uuid, $4::text)",
+            &[
+                &community_id,
+                &event_id,
+                &user_id,
+                &reminder_preference.map(EventReminderPreference::value),
+            ],
+        )
+        .await
+    }
 }
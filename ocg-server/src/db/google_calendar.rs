@@ -0,0 +1,115 @@
+//! This module defines database functionality used to sync Google Calendars.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{db::PgExecutor, services::google_calendar::GoogleCalendarEntry};
+
+/// Trait that defines database operations used to sync Google Calendars.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub(crate) trait DBGoogleCalendar {
+    /// Connects a user's Google Calendar.
+    async fn add_user_google_calendar(&self, user_id: &Uuid, refresh_token: &str) -> Result<()>;
+
+    /// Claims a Google Calendar entry that is out of sync.
+    async fn claim_google_calendar_entry_out_of_sync(&self) -> Result<Option<GoogleCalendarEntry>>;
+
+    /// Deletes a Google Calendar connection whose access has been revoked.
+    async fn delete_user_google_calendar(&self, user_id: &Uuid) -> Result<()>;
+
+    /// Disconnects a user's Google Calendar.
+    async fn disconnect_user_google_calendar(&self, user_id: &Uuid) -> Result<()>;
+
+    /// Checks if a user connected their Google Calendar.
+    async fn is_user_google_calendar_connected(&self, user_id: &Uuid) -> Result<bool>;
+
+    /// Releases a retryable Google Calendar entry sync claim.
+    async fn release_google_calendar_entry_sync_claim(
+        &self,
+        entry: &GoogleCalendarEntry,
+    ) -> Result<()>;
+
+    /// Stores the Google Calendar event of an entry and completes the sync claim.
+    async fn set_google_calendar_entry_synced(&self, entry: &GoogleCalendarEntry) -> Result<()>;
+}
+
+/// Shared database handle for Google Calendar operations.
+pub(crate) type DynDBGoogleCalendar = Arc<dyn DBGoogleCalendar + Send + Sync>;
+
+#[async_trait]
+impl<T> DBGoogleCalendar for T
+where
+    T: PgExecutor + Send + Sync,
+{
+    #[instrument(skip(self, refresh_token), err)]
+    async fn add_user_google_calendar(&self, user_id: &Uuid, refresh_token: &str) -> Result<()> {
+        self.execute(
+            "select add_user_google_calendar($1::uuid, $2::text)",
+            &[&user_id, &refresh_token],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn claim_google_calendar_entry_out_of_sync(&self) -> Result<Option<GoogleCalendarEntry>> {
+        self.fetch_json_opt("select claim_google_calendar_entry_out_of_sync()", &[])
+            .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete_user_google_calendar(&self, user_id: &Uuid) -> Result<()> {
+        self.execute("select delete_user_google_calendar($1::uuid)", &[&user_id])
+            .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn disconnect_user_google_calendar(&self, user_id: &Uuid) -> Result<()> {
+        self.execute(
+            "select disconnect_user_google_calendar($1::uuid)",
+            &[&user_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn is_user_google_calendar_connected(&self, user_id: &Uuid) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select is_user_google_calendar_connected($1::uuid)",
+            &[&user_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, entry), err)]
+    async fn release_google_calendar_entry_sync_claim(
+        &self,
+        entry: &GoogleCalendarEntry,
+    ) -> Result<()> {
+        self.execute(
+            "select release_google_calendar_entry_sync_claim($1::uuid, $2::uuid)",
+            &[&entry.event_id, &entry.user_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, entry), err)]
+    async fn set_google_calendar_entry_synced(&self, entry: &GoogleCalendarEntry) -> Result<()> {
+        self.execute(
+            "select set_google_calendar_entry_synced($1::uuid, $2::uuid, $3::text, $4::bigint)",
+            &[
+                &entry.event_id,
+                &entry.user_id,
+                &entry.google_event_id,
+                &entry.sync_version,
+            ],
+        )
+        .await
+    }
+}
@@ -2,6 +2,8 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio_postgres::types::Json;
 use tracing::instrument;
 use uuid::Uuid;
 
@@ -9,13 +11,56 @@ use crate::{
     db::PgExecutor,
     types::{
         event::{EventKind, EventSummary},
-        group::GroupFull,
+        group::{GroupAnnouncement, GroupDiscussion, GroupFull, GroupMembershipStatus},
+        questionnaire::{QuestionnaireAnswers, QuestionnaireQuestion},
     },
 };
 
 /// Database trait defining all data access operations for the group site.
 #[async_trait]
 pub(crate) trait DBGroup {
+    /// Records a message sent by a user to the organizers of a group, unless
+    /// the user already sent `max_messages` messages since the given
+    /// timestamp. Returns `None` when the message was not accepted.
+    #[allow(clippy::too_many_arguments)]
+    async fn add_group_contact_message(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        subject: &str,
+        body: &str,
+        since: DateTime<Utc>,
+        max_messages: i32,
+    ) -> Result<Option<Uuid>>;
+
+    /// Adds a reply to a topic in a group's discussion board.
+    async fn add_group_discussion_reply(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        group_discussion_topic_id: Uuid,
+        content: &str,
+    ) -> Result<Uuid>;
+
+    /// Starts a new topic in a group's discussion board.
+    async fn add_group_discussion_topic(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        title: &str,
+        content: &str,
+    ) -> Result<Uuid>;
+
+    /// Retrieves the discussion board of a group.
+    async fn get_group_discussion(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<GroupDiscussion>;
+
     /// Retrieves group information.
     async fn get_group_full_by_slug(
         &self,
@@ -23,6 +68,29 @@ pub(crate) trait DBGroup {
         group_slug: &str,
     ) -> Result<Option<GroupFull>>;
 
+    /// Retrieves the questions asked to users joining a group.
+    async fn get_group_join_questions(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Vec<QuestionnaireQuestion>>;
+
+    /// Retrieves the membership status of a user in a group.
+    async fn get_group_membership_status(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<GroupMembershipStatus>;
+
+    /// Retrieves the public slug of the group the group with the provided
+    /// slug was merged into, if any.
+    async fn get_merged_group_slug(
+        &self,
+        community_id: Uuid,
+        group_slug: &str,
+    ) -> Result<Option<String>>;
+
     /// Retrieves past events for a specific group.
     async fn get_group_past_events(
         &self,
@@ -41,19 +109,33 @@ pub(crate) trait DBGroup {
         limit: i32,
     ) -> Result<Vec<EventSummary>>;
 
-    /// Checks if a user is a member of a group.
-    async fn is_group_member(
+    /// Adds a user as a member of a group, or queues a join request when the
+    /// group is private.
+    async fn join_group(
         &self,
         community_id: Uuid,
         group_id: Uuid,
         user_id: Uuid,
-    ) -> Result<bool>;
-
-    /// Adds a user as a member of a group.
-    async fn join_group(&self, community_id: Uuid, group_id: Uuid, user_id: Uuid) -> Result<()>;
+        join_answers: Option<QuestionnaireAnswers>,
+    ) -> Result<GroupMembershipStatus>;
 
-    /// Removes a user from a group.
+    /// Removes a user from a group, or withdraws their pending join request.
     async fn leave_group(&self, community_id: Uuid, group_id: Uuid, user_id: Uuid) -> Result<()>;
+
+    /// Lists the announcements pinned on the group page.
+    async fn list_group_pinned_announcements(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Vec<GroupAnnouncement>>;
+
+    /// Checks if a user can see who takes part in a group.
+    async fn user_can_view_group_members(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<bool>;
 }
 
 #[async_trait]
@@ -61,6 +143,88 @@ impl<T> DBGroup for T
 where
     T: PgExecutor + Send + Sync,
 {
+    /// [`DBGroup::add_group_contact_message`]
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(skip(self, subject, body), err)]
+    async fn add_group_contact_message(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        subject: &str,
+        body: &str,
+        since: DateTime<Utc>,
+        max_messages: i32,
+    ) -> Result<Option<Uuid>> {
+        self.fetch_scalar_opt(
+            "select add_group_contact_message($1::uuid, $2::uuid, $3::uuid, $4::text, $5::text, $6::timestamptz, $7::int)::uuid",
+            &[
+                &community_id,
+                &group_id,
+                &user_id,
+                &subject,
+                &body,
+                &since,
+                &max_messages,
+            ],
+        )
+        .await
+    }
+
+    /// [`DBGroup::add_group_discussion_reply`]
+    #[instrument(skip(self), err)]
+    async fn add_group_discussion_reply(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        group_discussion_topic_id: Uuid,
+        content: &str,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_group_discussion_reply($1::uuid, $2::uuid, $3::uuid, $4::uuid, $5::text)::uuid",
+            &[
+                &community_id,
+                &group_id,
+                &user_id,
+                &group_discussion_topic_id,
+                &content,
+            ],
+        )
+        .await
+    }
+
+    /// [`DBGroup::add_group_discussion_topic`]
+    #[instrument(skip(self), err)]
+    async fn add_group_discussion_topic(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+        title: &str,
+        content: &str,
+    ) -> Result<Uuid> {
+        self.fetch_scalar_one(
+            "select add_group_discussion_topic($1::uuid, $2::uuid, $3::uuid, $4::text, $5::text)::uuid",
+            &[&community_id, &group_id, &user_id, &title, &content],
+        )
+        .await
+    }
+
+    /// [`DBGroup::get_group_discussion`]
+    #[instrument(skip(self), err)]
+    async fn get_group_discussion(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<GroupDiscussion> {
+        self.fetch_json_one(
+            "select get_group_discussion($1::uuid, $2::uuid)",
+            &[&community_id, &group_id],
+        )
+        .await
+    }
+
     /// [`DBGroup::get_group_full_by_slug`]
     #[instrument(skip(self), err)]
     async fn get_group_full_by_slug(
@@ -75,6 +239,54 @@ where
         .await
     }
 
+    /// [`DBGroup::get_group_join_questions`]
+    #[instrument(skip(self), err)]
+    async fn get_group_join_questions(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Vec<QuestionnaireQuestion>> {
+        self.fetch_json_one(
+            "select get_group_join_questions($1::uuid, $2::uuid)",
+            &[&community_id, &group_id],
+        )
+        .await
+    }
+
+    /// [`DBGroup::get_group_membership_status`]
+    #[instrument(skip(self), err)]
+    async fn get_group_membership_status(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<GroupMembershipStatus> {
+        let status: String = self
+            .fetch_scalar_one(
+                "select get_group_membership_status($1::uuid, $2::uuid, $3::uuid)",
+                &[&community_id, &group_id, &user_id],
+            )
+            .await?;
+
+        status.parse().map_err(|_| {
+            anyhow::anyhow!("unknown group membership status returned by database: {status}")
+        })
+    }
+
+    /// [`DBGroup::get_merged_group_slug`]
+    #[instrument(skip(self), err)]
+    async fn get_merged_group_slug(
+        &self,
+        community_id: Uuid,
+        group_slug: &str,
+    ) -> Result<Option<String>> {
+        self.fetch_scalar_opt(
+            "select get_merged_group_slug($1::uuid, $2::text)",
+            &[&community_id, &group_slug],
+        )
+        .await
+    }
+
     /// [`DB::get_group_past_events`]
     #[instrument(skip(self), err)]
     async fn get_group_past_events(
@@ -109,36 +321,66 @@ where
         .await
     }
 
-    /// [`DB::is_group_member`]
-    #[instrument(skip(self), err)]
-    async fn is_group_member(
+    /// [`DB::join_group`]
+    #[instrument(skip(self, join_answers), err)]
+    async fn join_group(
         &self,
         community_id: Uuid,
         group_id: Uuid,
         user_id: Uuid,
-    ) -> Result<bool> {
-        self.fetch_scalar_one(
-            "select is_group_member($1::uuid, $2::uuid, $3::uuid)",
+        join_answers: Option<QuestionnaireAnswers>,
+    ) -> Result<GroupMembershipStatus> {
+        let status: String = self
+            .fetch_scalar_one(
+                "select join_group($1::uuid, $2::uuid, $3::uuid, $4::jsonb)",
+                &[
+                    &community_id,
+                    &group_id,
+                    &user_id,
+                    &join_answers.as_ref().map(Json),
+                ],
+            )
+            .await?;
+
+        status.parse().map_err(|_| {
+            anyhow::anyhow!("unknown group membership status returned by database: {status}")
+        })
+    }
+
+    /// [`DB::leave_group`]
+    #[instrument(skip(self), err)]
+    async fn leave_group(&self, community_id: Uuid, group_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.execute(
+            "select leave_group($1::uuid, $2::uuid, $3::uuid)",
             &[&community_id, &group_id, &user_id],
         )
         .await
     }
 
-    /// [`DB::join_group`]
+    /// [`DBGroup::list_group_pinned_announcements`]
     #[instrument(skip(self), err)]
-    async fn join_group(&self, community_id: Uuid, group_id: Uuid, user_id: Uuid) -> Result<()> {
-        self.execute(
-            "select join_group($1::uuid, $2::uuid, $3::uuid)",
-            &[&community_id, &group_id, &user_id],
+    async fn list_group_pinned_announcements(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Vec<GroupAnnouncement>> {
+        self.fetch_json_one(
+            "select list_group_pinned_announcements($1::uuid, $2::uuid)",
+            &[&community_id, &group_id],
         )
         .await
     }
 
-    /// [`DB::leave_group`]
+    /// [`DBGroup::user_can_view_group_members`]
     #[instrument(skip(self), err)]
-    async fn leave_group(&self, community_id: Uuid, group_id: Uuid, user_id: Uuid) -> Result<()> {
-        self.execute(
-            "select leave_group($1::uuid, $2::uuid, $3::uuid)",
+    async fn user_can_view_group_members(
+        &self,
+        community_id: Uuid,
+        group_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<bool> {
+        self.fetch_scalar_one(
+            "select user_can_view_group_members($1::uuid, $2::uuid, $3::uuid)",
             &[&community_id, &group_id, &user_id],
         )
         .await
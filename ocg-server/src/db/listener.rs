@@ -0,0 +1,87 @@
+//! Postgres LISTEN/NOTIFY support.
+
+use std::{future::poll_fn, sync::Arc, time::Duration};
+
+use anyhow::{Result, bail};
+use deadpool_postgres::Config;
+use postgres_openssl::MakeTlsConnector;
+use tokio::{sync::Notify, time::sleep};
+use tokio_postgres::AsyncMessage;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error};
+
+/// Time to wait before reconnecting after a listener error.
+const PAUSE_ON_LISTENER_ERROR: Duration = Duration::from_secs(10);
+
+/// Listener that wakes up waiters when a notification is received on a channel.
+#[derive(Clone)]
+pub(crate) struct PgListener {
+    /// Database configuration used to open the dedicated connection.
+    cfg: Config,
+    /// TLS connector used to open the dedicated connection.
+    tls: MakeTlsConnector,
+}
+
+impl PgListener {
+    /// Create a new `PgListener`.
+    pub(crate) fn new(cfg: Config, tls: MakeTlsConnector) -> Self {
+        Self { cfg, tls }
+    }
+
+    /// Listen on the channel until cancelled, reconnecting on errors.
+    ///
+    /// Waiters are also woken up after (re)connecting, so that notifications
+    /// sent while the listener was not connected are not missed.
+    pub(crate) async fn run(
+        &self,
+        channel: &str,
+        notify: &Arc<Notify>,
+        cancellation_token: &CancellationToken,
+    ) {
+        loop {
+            tokio::select! {
+                result = self.listen(channel, notify) => {
+                    if let Err(err) = result {
+                        error!(%err, channel, "error listening for notifications");
+                    }
+                },
+                () = cancellation_token.cancelled() => break,
+            }
+
+            // Pause before reconnecting unless we've been asked to stop
+            tokio::select! {
+                () = sleep(PAUSE_ON_LISTENER_ERROR) => {},
+                () = cancellation_token.cancelled() => break,
+            }
+        }
+    }
+
+    /// Open a dedicated connection and listen on the channel until it fails.
+    async fn listen(&self, channel: &str, notify: &Arc<Notify>) -> Result<()> {
+        // Open a dedicated connection, pooled ones discard notifications
+        let pg_cfg = self.cfg.get_pg_config()?;
+        let (client, mut connection) = pg_cfg.connect(self.tls.clone()).await?;
+
+        // Drive the connection, forwarding notifications to the waiters
+        let connection_notify = notify.clone();
+        let connection_task = tokio::spawn(async move {
+            while let Some(message) = poll_fn(|cx| connection.poll_message(cx)).await {
+                if let AsyncMessage::Notification(_) = message? {
+                    connection_notify.notify_one();
+                }
+            }
+            Ok::<(), tokio_postgres::Error>(())
+        });
+
+        // Subscribe to the channel
+        client.batch_execute(&format!("listen {channel}")).await?;
+        debug!(channel, "listening for notifications");
+
+        // Wake up waiters in case something changed while we weren't listening
+        notify.notify_one();
+
+        // Keep the client alive until the connection is closed
+        connection_task.await??;
+        bail!("listener connection closed")
+    }
+}
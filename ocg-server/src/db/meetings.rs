@@ -13,7 +13,9 @@ use uuid::Uuid;
 
 use crate::{
     db::PgExecutor,
-    services::meetings::{Meeting, MeetingAutoEndCheckOutcome, MeetingProvider},
+    services::meetings::{
+        Meeting, MeetingAutoEndCheckOutcome, MeetingProvider, MeetingProviderStatus,
+    },
 };
 
 /// Trait that defines database operations used to manage meetings.
@@ -24,10 +26,12 @@ pub(crate) trait DBMeetings {
     async fn add_meeting(&self, meeting: &Meeting) -> Result<()>;
 
     /// Appends a recording URL for a meeting by its provider and provider meeting ID.
+    /// When a group is provided, only meetings of that group are updated.
     async fn append_meeting_recording_url(
         &self,
         provider: MeetingProvider,
         provider_meeting_id: &str,
+        group_id: Option<Uuid>,
         recording_url: &str,
     ) -> Result<()>;
 
@@ -41,30 +45,82 @@ pub(crate) trait DBMeetings {
         ends_at: DateTime<Utc>,
     ) -> Result<Option<String>>;
 
+    /// Claims one ended meeting for participants attendance syncs.
+    async fn claim_meeting_for_attendance_sync(&self)
+    -> Result<Option<MeetingAttendanceCandidate>>;
+
     /// Claims one overdue meeting for auto-end checks.
     async fn claim_meeting_for_auto_end(&self) -> Result<Option<MeetingAutoEndCandidate>>;
 
-    /// Claims a meeting that is out of sync.
-    async fn claim_meeting_out_of_sync(&self) -> Result<Option<Meeting>>;
+    /// Claims one ended meeting for cloud recording syncs.
+    async fn claim_meeting_for_recording_sync(&self) -> Result<Option<MeetingRecordingCandidate>>;
+
+    /// Claims a meeting that is out of sync, skipping the paused providers.
+    async fn claim_meeting_out_of_sync(
+        &self,
+        paused_providers: &[MeetingProvider],
+    ) -> Result<Option<Meeting>>;
 
     /// Deletes a meeting and completes the sync claim.
     async fn delete_meeting(&self, meeting: &Meeting) -> Result<()>;
 
+    /// Gets the Zoom credentials configured for a group, if any.
+    async fn get_group_zoom_credentials(
+        &self,
+        group_id: Uuid,
+    ) -> Result<Option<GroupZoomCredentials>>;
+
+    /// Lists the webhook secret tokens of the group Zoom apps in an account, or
+    /// of all of them when no account is provided.
+    async fn list_zoom_webhook_secret_tokens(
+        &self,
+        account_id: Option<String>,
+    ) -> Result<Vec<ZoomWebhookSecretToken>>;
+
     /// Marks stale auto-end check claims with an unknown outcome.
     async fn mark_stale_meeting_auto_end_checks_unknown(&self, timeout: Duration) -> Result<usize>;
 
     /// Marks stale meeting sync claims with an unknown outcome.
     async fn mark_stale_meeting_syncs_unknown(&self, timeout: Duration) -> Result<usize>;
 
+    /// Counts a participant joining a meeting by its provider and provider meeting ID.
+    /// When a group is provided, only meetings of that group are updated.
+    async fn record_meeting_participant_joined(
+        &self,
+        provider: MeetingProvider,
+        provider_meeting_id: &str,
+        group_id: Option<Uuid>,
+    ) -> Result<()>;
+
+    /// Releases a retryable attendance sync claim.
+    async fn release_meeting_attendance_sync_claim(
+        &self,
+        candidate: &MeetingAttendanceCandidate,
+    ) -> Result<()>;
+
     /// Releases a retryable auto-end check claim.
     async fn release_meeting_auto_end_check_claim(
         &self,
         candidate: &MeetingAutoEndCandidate,
     ) -> Result<()>;
 
+    /// Releases a retryable recording sync claim.
+    async fn release_meeting_recording_sync_claim(
+        &self,
+        candidate: &MeetingRecordingCandidate,
+    ) -> Result<()>;
+
     /// Releases a retryable sync claim.
     async fn release_meeting_sync_claim(&self, meeting: &Meeting) -> Result<()>;
 
+    /// Checks in the event attendees that joined a meeting and completes the
+    /// attendance sync claim. Returns the number of attendees checked in.
+    async fn set_meeting_attendance_synced(
+        &self,
+        candidate: &MeetingAttendanceCandidate,
+        participants_emails: &[String],
+    ) -> Result<usize>;
+
     /// Records the outcome of an auto-end check for a meeting.
     async fn set_meeting_auto_end_check_outcome(
         &self,
@@ -75,8 +131,27 @@ pub(crate) trait DBMeetings {
     /// Records an error for a meeting and completes the sync claim.
     async fn set_meeting_error(&self, meeting: &Meeting, error: &str) -> Result<()>;
 
+    /// Stores the cloud recording URLs of a meeting and completes the
+    /// recording sync claim.
+    async fn set_meeting_recordings_synced(
+        &self,
+        candidate: &MeetingRecordingCandidate,
+        recording_urls: &[String],
+    ) -> Result<()>;
+
     /// Updates meeting details and completes the sync claim.
     async fn update_meeting(&self, meeting: &Meeting) -> Result<()>;
+
+    /// Records the lifecycle status reported by the provider for a meeting.
+    /// When a group is provided, only meetings of that group are updated.
+    async fn update_meeting_provider_status(
+        &self,
+        provider: MeetingProvider,
+        provider_meeting_id: &str,
+        group_id: Option<Uuid>,
+        status: MeetingProviderStatus,
+        occurred_at: Option<DateTime<Utc>>,
+    ) -> Result<()>;
 }
 
 /// Shared database handle for meeting operations.
@@ -113,11 +188,17 @@ where
         &self,
         provider: MeetingProvider,
         provider_meeting_id: &str,
+        group_id: Option<Uuid>,
         recording_url: &str,
     ) -> Result<()> {
         self.execute(
-            "select append_meeting_recording_url($1, $2, $3)",
-            &[&provider.as_ref(), &provider_meeting_id, &recording_url],
+            "select append_meeting_recording_url($1::text, $2::text, $3::uuid, $4::text)",
+            &[
+                &provider.as_ref(),
+                &provider_meeting_id,
+                &group_id,
+                &recording_url,
+            ],
         )
         .await
     }
@@ -156,14 +237,37 @@ where
         .await
     }
 
+    #[instrument(skip(self), err)]
+    async fn claim_meeting_for_attendance_sync(
+        &self,
+    ) -> Result<Option<MeetingAttendanceCandidate>> {
+        self.fetch_json_opt("select claim_meeting_for_attendance_sync()", &[])
+            .await
+    }
+
     #[instrument(skip(self), err)]
     async fn claim_meeting_for_auto_end(&self) -> Result<Option<MeetingAutoEndCandidate>> {
         self.fetch_json_opt("select claim_meeting_for_auto_end()", &[]).await
     }
 
     #[instrument(skip(self), err)]
-    async fn claim_meeting_out_of_sync(&self) -> Result<Option<Meeting>> {
-        self.fetch_json_opt("select claim_meeting_out_of_sync()", &[]).await
+    async fn claim_meeting_for_recording_sync(&self) -> Result<Option<MeetingRecordingCandidate>> {
+        self.fetch_json_opt("select claim_meeting_for_recording_sync()", &[])
+            .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn claim_meeting_out_of_sync(
+        &self,
+        paused_providers: &[MeetingProvider],
+    ) -> Result<Option<Meeting>> {
+        let paused_provider_ids: Vec<String> =
+            paused_providers.iter().map(ToString::to_string).collect();
+        self.fetch_json_opt(
+            "select claim_meeting_out_of_sync($1::text[])",
+            &[&paused_provider_ids],
+        )
+        .await
     }
 
     #[instrument(skip(self, meeting), err)]
@@ -181,6 +285,27 @@ where
         .await
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_group_zoom_credentials(
+        &self,
+        group_id: Uuid,
+    ) -> Result<Option<GroupZoomCredentials>> {
+        self.fetch_json_opt("select get_group_zoom_credentials($1::uuid)", &[&group_id])
+            .await
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list_zoom_webhook_secret_tokens(
+        &self,
+        account_id: Option<String>,
+    ) -> Result<Vec<ZoomWebhookSecretToken>> {
+        self.fetch_json_one(
+            "select list_zoom_webhook_secret_tokens($1::text)",
+            &[&account_id],
+        )
+        .await
+    }
+
     #[instrument(skip(self), err)]
     async fn mark_stale_meeting_auto_end_checks_unknown(&self, timeout: Duration) -> Result<usize> {
         let timeout_seconds = i64::try_from(timeout.as_secs())
@@ -213,6 +338,32 @@ where
             .map_err(|_| anyhow::anyhow!("stale sync claim count cannot be negative"))
     }
 
+    #[instrument(skip(self), err)]
+    async fn record_meeting_participant_joined(
+        &self,
+        provider: MeetingProvider,
+        provider_meeting_id: &str,
+        group_id: Option<Uuid>,
+    ) -> Result<()> {
+        self.execute(
+            "select record_meeting_participant_joined($1::text, $2::text, $3::uuid)",
+            &[&provider.as_ref(), &provider_meeting_id, &group_id],
+        )
+        .await
+    }
+
+    #[instrument(skip(self, candidate), err)]
+    async fn release_meeting_attendance_sync_claim(
+        &self,
+        candidate: &MeetingAttendanceCandidate,
+    ) -> Result<()> {
+        self.execute(
+            "select release_meeting_attendance_sync_claim($1::timestamptz, $2::uuid)",
+            &[&candidate.attendance_sync_claimed_at, &candidate.meeting_id],
+        )
+        .await
+    }
+
     #[instrument(skip(self, candidate), err)]
     async fn release_meeting_auto_end_check_claim(
         &self,
@@ -225,6 +376,18 @@ where
         .await
     }
 
+    #[instrument(skip(self, candidate), err)]
+    async fn release_meeting_recording_sync_claim(
+        &self,
+        candidate: &MeetingRecordingCandidate,
+    ) -> Result<()> {
+        self.execute(
+            "select release_meeting_recording_sync_claim($1::timestamptz, $2::uuid)",
+            &[&candidate.recording_sync_claimed_at, &candidate.meeting_id],
+        )
+        .await
+    }
+
     #[instrument(skip(self, meeting), err)]
     async fn release_meeting_sync_claim(&self, meeting: &Meeting) -> Result<()> {
         self.execute(
@@ -239,6 +402,28 @@ where
         .await
     }
 
+    #[instrument(skip(self, candidate, participants_emails), err)]
+    async fn set_meeting_attendance_synced(
+        &self,
+        candidate: &MeetingAttendanceCandidate,
+        participants_emails: &[String],
+    ) -> Result<usize> {
+        let count = self
+            .fetch_scalar_one::<i64>(
+                "select set_meeting_attendance_synced($1::timestamptz, $2::uuid, $3::uuid, $4::text[])::bigint;",
+                &[
+                    &candidate.attendance_sync_claimed_at,
+                    &candidate.meeting_id,
+                    &candidate.event_id,
+                    &participants_emails,
+                ],
+            )
+            .await?;
+
+        usize::try_from(count)
+            .map_err(|_| anyhow::anyhow!("checked in attendees count cannot be negative"))
+    }
+
     #[instrument(skip(self, candidate), err)]
     async fn set_meeting_auto_end_check_outcome(
         &self,
@@ -272,6 +457,23 @@ where
         .await
     }
 
+    #[instrument(skip(self, candidate, recording_urls), err)]
+    async fn set_meeting_recordings_synced(
+        &self,
+        candidate: &MeetingRecordingCandidate,
+        recording_urls: &[String],
+    ) -> Result<()> {
+        self.execute(
+            "select set_meeting_recordings_synced($1::timestamptz, $2::uuid, $3::text[])",
+            &[
+                &candidate.recording_sync_claimed_at,
+                &candidate.meeting_id,
+                &recording_urls,
+            ],
+        )
+        .await
+    }
+
     #[instrument(skip(self, meeting), err)]
     async fn update_meeting(&self, meeting: &Meeting) -> Result<()> {
         self.execute(
@@ -289,6 +491,59 @@ where
         )
         .await
     }
+
+    #[instrument(skip(self), err)]
+    async fn update_meeting_provider_status(
+        &self,
+        provider: MeetingProvider,
+        provider_meeting_id: &str,
+        group_id: Option<Uuid>,
+        status: MeetingProviderStatus,
+        occurred_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        self.execute(
+            "select update_meeting_provider_status($1::text, $2::text, $3::uuid, $4::text, $5::timestamptz)",
+            &[
+                &provider.as_ref(),
+                &provider_meeting_id,
+                &group_id,
+                &status.as_ref(),
+                &occurred_at,
+            ],
+        )
+        .await
+    }
+}
+
+/// Zoom server-to-server OAuth credentials configured by a group.
+#[derive(Clone, Deserialize, PartialEq)]
+pub(crate) struct GroupZoomCredentials {
+    /// Zoom account identifier.
+    pub account_id: String,
+    /// Zoom OAuth client identifier.
+    pub client_id: String,
+    /// Zoom OAuth client secret.
+    pub client_secret: String,
+    /// Zoom user that hosts the group's meetings.
+    pub host_user: String,
+}
+
+/// Candidate meeting to process for attendance syncs.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub(crate) struct MeetingAttendanceCandidate {
+    /// Claim token that must match when releasing or completing the claim.
+    pub attendance_sync_claimed_at: DateTime<Utc>,
+    /// Event whose attendees are checked in.
+    pub event_id: Uuid,
+    /// Owning group identifier.
+    pub group_id: Option<Uuid>,
+    /// Local meeting identifier.
+    pub meeting_id: Uuid,
+    /// Provider that hosts the meeting.
+    #[serde(alias = "meeting_provider_id")]
+    pub provider: MeetingProvider,
+    /// Provider-assigned meeting identifier.
+    pub provider_meeting_id: String,
 }
 
 /// Candidate meeting to process for auto-end checks.
@@ -296,6 +551,24 @@ where
 pub(crate) struct MeetingAutoEndCandidate {
     /// Claim token that must match when releasing the claim or recording the outcome.
     pub auto_end_check_claimed_at: DateTime<Utc>,
+    /// Owning group identifier.
+    pub group_id: Option<Uuid>,
+    /// Local meeting identifier.
+    pub meeting_id: Uuid,
+    /// Provider that hosts the meeting.
+    #[serde(alias = "meeting_provider_id")]
+    pub provider: MeetingProvider,
+    /// Provider-assigned meeting identifier.
+    pub provider_meeting_id: String,
+}
+
+/// Candidate meeting to process for cloud recording syncs.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub(crate) struct MeetingRecordingCandidate {
+    /// Event the meeting belongs to, directly or through a session.
+    pub event_id: Uuid,
+    /// Owning group identifier.
+    pub group_id: Option<Uuid>,
     /// Local meeting identifier.
     pub meeting_id: Uuid,
     /// Provider that hosts the meeting.
@@ -303,4 +576,17 @@ pub(crate) struct MeetingAutoEndCandidate {
     pub provider: MeetingProvider,
     /// Provider-assigned meeting identifier.
     pub provider_meeting_id: String,
+    /// Claim token that must match when releasing or completing the claim.
+    pub recording_sync_claimed_at: DateTime<Utc>,
+    /// Session the meeting belongs to, if any.
+    pub session_id: Option<Uuid>,
+}
+
+/// Webhook secret token of a group Zoom app.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub(crate) struct ZoomWebhookSecretToken {
+    /// Group owning the Zoom app.
+    pub group_id: Uuid,
+    /// Webhook secret token of the Zoom app.
+    pub secret_token: String,
 }
@@ -34,6 +34,33 @@ mock! {
             user_id: &Uuid,
             user_summary: &crate::auth::UserSummary,
         ) -> Result<crate::auth::User>;
+        async fn add_user_ldap_community_roles(
+            &self,
+            user_id: &Uuid,
+            mappings: &[crate::config::LdapGroupRoleMapping],
+        ) -> Result<()>;
+        async fn add_user_magic_link(
+            &self,
+            email: &str,
+            token_hash: &str,
+        ) -> Result<Option<Uuid>>;
+        async fn add_user_passkey(
+            &self,
+            actor_user_id: &Uuid,
+            name: &str,
+            passkey: &webauthn_rs::prelude::Passkey,
+        ) -> Result<Uuid>;
+        async fn add_user_password_reset(
+            &self,
+            email: &str,
+            code_hash: &str,
+        ) -> Result<Option<Uuid>>;
+        async fn community_email_domain_allowed(
+            &self,
+            community_name: &str,
+            email: &str,
+        ) -> Result<bool>;
+        async fn community_passkey_required(&self, community_id: &Uuid) -> Result<bool>;
         async fn create_session(
             &self,
             record: &axum_login::tower_sessions::session::Record,
@@ -42,6 +69,24 @@ mock! {
             &self,
             session_id: &axum_login::tower_sessions::session::Id,
         ) -> Result<()>;
+        async fn delete_user_passkey(
+            &self,
+            actor_user_id: &Uuid,
+            user_passkey_id: &Uuid,
+        ) -> Result<()>;
+        async fn get_community_required_oidc_provider(
+            &self,
+            community_name: &str,
+        ) -> Result<Option<String>>;
+        async fn get_community_required_oidc_provider_by_id(
+            &self,
+            community_id: &Uuid,
+        ) -> Result<Option<String>>;
+        async fn get_hosted_event_group(
+            &self,
+            event_id: &Uuid,
+            user_id: &Uuid,
+        ) -> Result<Option<(Uuid, Uuid)>>;
         async fn get_session(
             &self,
             session_id: &axum_login::tower_sessions::session::Id,
@@ -56,26 +101,88 @@ mock! {
             issuer: &str,
             subject: &str,
         ) -> Result<Option<crate::auth::User>>;
+        async fn get_user_by_oauth2_identity_for_external_auth(
+            &self,
+            provider: &crate::config::OAuth2Provider,
+            id: &str,
+        ) -> Result<Option<crate::auth::User>>;
         async fn get_user_by_username(
             &self,
             username: &str,
         ) -> Result<Option<crate::auth::User>>;
+        async fn get_user_calendar_token(&self, user_id: &Uuid) -> Result<Option<String>>;
+        async fn get_user_passkeys(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<Vec<webauthn_rs::prelude::Passkey>>;
         async fn get_user_password(&self, user_id: &Uuid) -> Result<Option<String>>;
+        async fn get_user_phone(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<Option<crate::types::user::UserPhone>>;
+        async fn get_user_webhook(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<Option<crate::types::user::UserWebhook>>;
         async fn group_belongs_to_community(
             &self,
             community_id: &Uuid,
             group_id: &Uuid,
         ) -> Result<bool>;
+        async fn is_log_in_locked(
+            &self,
+            username: &str,
+            ip_address: Option<std::net::IpAddr>,
+        ) -> Result<bool>;
+        async fn link_user_provider(
+            &self,
+            user_id: &Uuid,
+            provider: &crate::types::user::UserProvider,
+        ) -> Result<()>;
+        async fn list_user_passkeys(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<Vec<crate::types::user::UserPasskey>>;
+        async fn list_user_sessions(
+            &self,
+            user_id: &Uuid,
+            current_session_id: Option<axum_login::tower_sessions::session::Id>,
+        ) -> Result<Vec<crate::types::user::UserSession>>;
+        async fn redeem_user_magic_link(&self, token_hash: &str) -> Result<Option<Uuid>>;
+        async fn register_failed_log_in(
+            &self,
+            username: &str,
+            ip_address: Option<std::net::IpAddr>,
+        ) -> Result<bool>;
+        async fn resend_email_verification(
+            &self,
+            email: &str,
+            verification: &crate::db::auth::EmailVerificationNotification,
+        ) -> Result<bool>;
+        async fn reset_failed_log_ins(&self, username: &str) -> Result<()>;
+        async fn reset_user_password(
+            &self,
+            code_hash: &str,
+            password: &str,
+        ) -> Result<Option<Uuid>>;
+        async fn revoke_user_session(&self, user_id: &Uuid, session_id: &Uuid) -> Result<()>;
+        async fn revoke_user_sessions(&self, user_id: &Uuid) -> Result<()>;
         async fn sign_up_user(
             &self,
             user_summary: &crate::auth::UserSummary,
             email_verified: bool,
             verification: Option<crate::db::auth::EmailVerificationNotification>,
         ) -> Result<(crate::auth::User, Option<Uuid>)>;
+        async fn unlink_user_provider(&self, user_id: &Uuid, provider: &str) -> Result<()>;
         async fn update_session(
             &self,
             record: &axum_login::tower_sessions::session::Record,
         ) -> Result<()>;
+        async fn update_user_calendar_feed(
+            &self,
+            actor_user_id: &Uuid,
+            enabled: bool,
+        ) -> Result<()>;
         async fn update_user_details(
             &self,
             actor_user_id: &Uuid,
@@ -86,16 +193,36 @@ mock! {
             user_id: &Uuid,
             user_summary: &crate::auth::UserSummary,
         ) -> Result<crate::auth::User>;
+        async fn update_user_passkey(
+            &self,
+            user_id: &Uuid,
+            passkey: &webauthn_rs::prelude::Passkey,
+        ) -> Result<()>;
         async fn update_user_password(
             &self,
             actor_user_id: &Uuid,
             new_password: &str,
         ) -> Result<()>;
+        async fn update_user_phone_number(
+            &self,
+            actor_user_id: &Uuid,
+            phone: &crate::templates::auth::UserPhoneNumberUpdate,
+        ) -> Result<()>;
         async fn update_user_provider(
             &self,
             user_id: &Uuid,
             provider: &crate::types::user::UserProvider,
         ) -> Result<()>;
+        async fn update_user_sms_notifications(
+            &self,
+            actor_user_id: &Uuid,
+            enabled: bool,
+        ) -> Result<()>;
+        async fn update_user_webhook(
+            &self,
+            actor_user_id: &Uuid,
+            webhook: &crate::templates::auth::UserWebhookUpdate,
+        ) -> Result<()>;
         async fn user_has_community_permission(
             &self,
             community_id: &Uuid,
@@ -109,7 +236,19 @@ mock! {
             user_id: &Uuid,
             permission: crate::types::permissions::GroupPermission,
         ) -> Result<bool>;
+        async fn user_is_event_host(
+            &self,
+            community_id: &Uuid,
+            group_id: &Uuid,
+            event_id: &Uuid,
+            user_id: &Uuid,
+        ) -> Result<bool>;
         async fn verify_email(&self, code: &Uuid) -> Result<()>;
+        async fn verify_user_phone_number(
+            &self,
+            actor_user_id: &Uuid,
+            verification: &crate::templates::auth::UserPhoneVerification,
+        ) -> Result<bool>;
     }
 
     #[async_trait]
@@ -122,6 +261,10 @@ mock! {
             &self,
             community_id: Uuid,
         ) -> Result<crate::types::community::CommunitySummary>;
+        async fn get_community_theme(
+            &self,
+            community_id: Uuid,
+        ) -> Result<crate::types::community::CommunityTheme>;
         async fn get_event_full(
             &self,
             community_id: Uuid,
@@ -149,6 +292,11 @@ mock! {
             group_id: Uuid,
         )
             -> Result<crate::types::group::GroupSummary>;
+        async fn list_group_gallery_photos(
+            &self,
+            group_id: Uuid,
+            include_hidden: bool,
+        ) -> Result<Vec<crate::types::group::GroupGalleryPhoto>>;
         async fn list_timezones(&self) -> Result<Vec<String>>;
         async fn search_events(
             &self,
@@ -163,7 +311,13 @@ mock! {
     #[async_trait]
     impl crate::db::community::DBCommunity for DB {
         async fn get_community_id_by_name(&self, name: &str) -> Result<Option<Uuid>>;
+        async fn get_community_name_by_domain(&self, domain: &str) -> Result<Option<String>>;
         async fn get_community_name_by_id(&self, community_id: Uuid) -> Result<Option<String>>;
+        async fn get_community_newsletter_archive_issue(
+            &self,
+            community_id: Uuid,
+            community_newsletter_issue_id: Uuid,
+        ) -> Result<Option<crate::types::community::CommunityNewsletterArchiveIssue>>;
         async fn get_community_recently_added_groups(
             &self,
             community_id: Uuid,
@@ -177,6 +331,25 @@ mock! {
             community_id: Uuid,
             event_kinds: Vec<crate::types::event::EventKind>,
         ) -> Result<Vec<crate::types::event::EventSummary>>;
+        async fn is_community_newsletter_subscriber(
+            &self,
+            community_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<bool>;
+        async fn list_community_newsletter_archive(
+            &self,
+            community_id: Uuid,
+        ) -> Result<Vec<crate::types::community::CommunityNewsletterArchiveIssue>>;
+        async fn subscribe_community_newsletter(
+            &self,
+            community_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<()>;
+        async fn unsubscribe_community_newsletter(
+            &self,
+            community_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<()>;
     }
 
     impl crate::db::dashboard::DBDashboard for DB {}
@@ -215,6 +388,18 @@ mock! {
     #[async_trait]
     impl crate::db::dashboard::community::DBDashboardCommunity for DB {
         async fn activate_group(&self, actor_user_id: Uuid, community_id: Uuid, group_id: Uuid) -> Result<()>;
+        async fn add_community_domain(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            domain: &str,
+        ) -> Result<Uuid>;
+        async fn add_community_newsletter_issue(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            issue: &crate::templates::dashboard::community::newsletter::CommunityNewsletterIssueInput,
+        ) -> Result<Uuid>;
         async fn add_community_team_member(
             &self,
             actor_user_id: Uuid,
@@ -246,8 +431,36 @@ mock! {
             community_id: Uuid,
             region: &crate::templates::dashboard::community::regions::RegionInput,
         ) -> Result<Uuid>;
+        async fn add_topic(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            topic: &crate::templates::dashboard::community::topics::TopicInput,
+        ) -> Result<Uuid>;
+        async fn approve_event(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            event_id: Uuid,
+            configured_provider: Option<crate::types::payments::PaymentProvider>,
+        ) -> Result<Uuid>;
+        async fn archive_group(&self, actor_user_id: Uuid, community_id: Uuid, group_id: Uuid)
+            -> Result<()>;
+        async fn count_community_newsletter_subscribers(&self, community_id: Uuid) -> Result<usize>;
         async fn deactivate_group(&self, actor_user_id: Uuid, community_id: Uuid, group_id: Uuid)
             -> Result<()>;
+        async fn delete_community_domain(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            community_domain_id: Uuid,
+        ) -> Result<()>;
+        async fn delete_community_newsletter_issue(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            community_newsletter_issue_id: Uuid,
+        ) -> Result<()>;
         async fn delete_community_team_member(
             &self,
             actor_user_id: Uuid,
@@ -268,6 +481,25 @@ mock! {
             group_category_id: Uuid,
         ) -> Result<()>;
         async fn delete_region(&self, actor_user_id: Uuid, community_id: Uuid, region_id: Uuid) -> Result<()>;
+        async fn delete_topic(&self, actor_user_id: Uuid, community_id: Uuid, topic_id: Uuid) -> Result<()>;
+        async fn get_community_domain(
+            &self,
+            community_id: Uuid,
+            community_domain_id: Uuid,
+        ) -> Result<Option<crate::templates::dashboard::community::domains::CommunityDomain>>;
+        async fn get_community_email_branding(
+            &self,
+            community_id: Uuid,
+        ) -> Result<crate::types::community::CommunityEmailBranding>;
+        async fn get_community_newsletter_issue(
+            &self,
+            community_id: Uuid,
+            community_newsletter_issue_id: Uuid,
+        ) -> Result<Option<crate::templates::dashboard::community::newsletter::CommunityNewsletterIssue>>;
+        async fn get_community_slack_webhook_url(
+            &self,
+            community_id: Uuid,
+        ) -> Result<Option<String>>;
         async fn get_community_stats(
             &self,
             community_id: Uuid,
@@ -277,6 +509,32 @@ mock! {
             community_id: Uuid,
             filters: &crate::templates::dashboard::audit::AuditLogFilters,
         ) -> Result<crate::templates::dashboard::audit::AuditLogsOutput>;
+        async fn list_community_custom_notifications(
+            &self,
+            community_id: Uuid,
+            filters: &crate::templates::dashboard::notifications::CustomNotificationsFilters,
+        ) -> Result<crate::templates::dashboard::notifications::CustomNotificationsOutput>;
+        async fn list_community_domains(
+            &self,
+            community_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::community::domains::CommunityDomain>>;
+        async fn list_community_failed_notifications(
+            &self,
+            community_id: Uuid,
+            filters: &crate::templates::dashboard::community::failed_notifications::FailedNotificationsFilters,
+        ) -> Result<crate::templates::dashboard::community::failed_notifications::FailedNotificationsOutput>;
+        async fn list_community_group_bans(
+            &self,
+            community_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::community::group_bans::CommunityGroupBan>>;
+        async fn list_community_newsletter_issues(
+            &self,
+            community_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::community::newsletter::CommunityNewsletterIssue>>;
+        async fn list_community_pending_events(
+            &self,
+            community_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::community::event_approvals::PendingEvent>>;
         async fn list_community_team_members(
             &self,
             community_id: Uuid,
@@ -289,14 +547,51 @@ mock! {
             &self,
             community_id: Uuid,
         ) -> Result<Vec<crate::types::group::GroupCategory>>;
+        async fn list_group_merge_targets(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::types::group::GroupMinimal>>;
         async fn list_regions(
             &self,
             community_id: Uuid,
         ) -> Result<Vec<crate::types::group::GroupRegion>>;
+        async fn list_topics(
+            &self,
+            community_id: Uuid,
+        ) -> Result<Vec<crate::types::community::CommunityTopic>>;
         async fn list_user_communities(
             &self,
             user_id: &Uuid,
         ) -> Result<Vec<crate::types::community::CommunitySummary>>;
+        async fn merge_groups(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            source_group_id: Uuid,
+            target_group_id: Uuid,
+        ) -> Result<()>;
+        async fn reject_event(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            event_id: Uuid,
+            rejection: &crate::templates::dashboard::community::event_approvals::EventRejectionInput,
+        ) -> Result<Uuid>;
+        async fn requeue_community_notification(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            notification_id: Uuid,
+        ) -> Result<()>;
+        async fn restore_group(&self, actor_user_id: Uuid, community_id: Uuid, group_id: Uuid)
+            -> Result<()>;
+        async fn send_community_newsletter_issue(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            community_newsletter_issue_id: Uuid,
+        ) -> Result<usize>;
         async fn update_community(
             &self,
             actor_user_id: Uuid,
@@ -310,6 +605,7 @@ mock! {
             user_id: Uuid,
             role: &crate::types::community::CommunityRole,
         ) -> Result<()>;
+        async fn update_dormant_groups(&self) -> Result<usize>;
         async fn update_event_category(
             &self,
             actor_user_id: Uuid,
@@ -324,6 +620,13 @@ mock! {
             group_category_id: Uuid,
             group_category: &crate::templates::dashboard::community::group_categories::GroupCategoryInput,
         ) -> Result<()>;
+        async fn update_group_owner(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            group_id: Uuid,
+            username: &str,
+        ) -> Result<()>;
         async fn update_region(
             &self,
             actor_user_id: Uuid,
@@ -331,6 +634,19 @@ mock! {
             region_id: Uuid,
             region: &crate::templates::dashboard::community::regions::RegionInput,
         ) -> Result<()>;
+        async fn update_topic(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            topic_id: Uuid,
+            topic: &crate::templates::dashboard::community::topics::TopicInput,
+        ) -> Result<()>;
+        async fn verify_community_domain(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            community_domain_id: Uuid,
+        ) -> Result<()>;
     }
 
     #[async_trait]
@@ -342,6 +658,12 @@ mock! {
             event_id: Uuid,
             user_id: Uuid,
         ) -> Result<()>;
+        async fn accept_group_join_request(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<()>;
         async fn add_event(
             &self,
             actor_user_id: Uuid,
@@ -357,12 +679,38 @@ mock! {
             recurrence: &serde_json::Value,
             cfg_max_participants: &HashMap<crate::services::meetings::MeetingProvider, i32>,
         ) -> Result<Vec<Uuid>>;
+        async fn add_event_resource(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            event_id: Uuid,
+            resource: &crate::templates::dashboard::group::resources::NewEventResource,
+        ) -> Result<Uuid>;
+        async fn add_event_template(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            group_id: Uuid,
+            event_id: Uuid,
+        ) -> Result<Uuid>;
+        async fn add_group_announcement(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            announcement: &crate::templates::dashboard::group::announcements::GroupAnnouncementInput,
+        ) -> Result<Uuid>;
         async fn add_group_sponsor(
             &self,
             actor_user_id: Uuid,
             group_id: Uuid,
             sponsor: &crate::templates::dashboard::group::sponsors::Sponsor,
         ) -> Result<Uuid>;
+        async fn add_group_sponsor_tier(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            tier: &crate::templates::dashboard::group::sponsors::GroupSponsorTierInput,
+        ) -> Result<Uuid>;
         async fn add_group_team_member(
             &self,
             actor_user_id: Uuid,
@@ -370,6 +718,21 @@ mock! {
             user_id: Uuid,
             role: &crate::types::group::GroupRole,
         ) -> Result<()>;
+        async fn approve_event_photo(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            event_id: Uuid,
+            event_photo_id: Uuid,
+        ) -> Result<()>;
+        async fn ban_group_member(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+            ban: &crate::templates::dashboard::group::members::GroupBanInput,
+        ) -> Result<()>;
         async fn cancel_event(&self, actor_user_id: Uuid, group_id: Uuid, event_id: Uuid) -> Result<()>;
         async fn cancel_event_attendee_attendance(
             &self,
@@ -391,30 +754,115 @@ mock! {
             group_id: Uuid,
             event_ids: &[Uuid],
         ) -> Result<()>;
+        async fn cancel_group_ownership_transfer(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+        ) -> Result<()>;
+        async fn count_group_custom_notifications_since(
+            &self,
+            group_id: Uuid,
+            since: chrono::DateTime<chrono::Utc>,
+        ) -> Result<i64>;
         async fn delete_event(&self, actor_user_id: Uuid, group_id: Uuid, event_id: Uuid) -> Result<()>;
+        async fn delete_event_comment(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            event_id: Uuid,
+            event_comment_id: Uuid,
+        ) -> Result<()>;
+        async fn delete_event_resource(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            event_id: Uuid,
+            event_resource_id: Uuid,
+        ) -> Result<()>;
         async fn delete_event_series_events(
             &self,
             actor_user_id: Uuid,
             group_id: Uuid,
             event_ids: &[Uuid],
         ) -> Result<()>;
+        async fn delete_event_template(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            event_template_id: Uuid,
+        ) -> Result<()>;
+        async fn delete_group_announcement(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            group_announcement_id: Uuid,
+        ) -> Result<()>;
+        async fn delete_group_discussion_reply(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            group_discussion_reply_id: Uuid,
+        ) -> Result<()>;
+        async fn delete_group_discussion_topic(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            group_discussion_topic_id: Uuid,
+        ) -> Result<()>;
         async fn delete_group_sponsor(
             &self,
             actor_user_id: Uuid,
             group_id: Uuid,
             group_sponsor_id: Uuid,
         ) -> Result<()>;
+        async fn delete_group_sponsor_tier(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            group_sponsor_tier_id: Uuid,
+        ) -> Result<()>;
         async fn delete_group_team_member(
             &self,
             actor_user_id: Uuid,
             group_id: Uuid,
             user_id: Uuid,
         ) -> Result<()>;
+        async fn delete_group_zoom_credentials(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+        ) -> Result<()>;
         async fn get_cfs_submission_notification_data(
             &self,
             event_id: Uuid,
             cfs_submission_id: Uuid,
         ) -> Result<crate::templates::dashboard::group::submissions::CfsSubmissionNotificationData>;
+        async fn get_event_attendee_by_ticket_code(
+            &self,
+            group_id: Uuid,
+            event_id: Uuid,
+            ticket_code: Uuid,
+        ) -> Result<Option<crate::templates::dashboard::group::kiosk::KioskAttendee>>;
+        async fn get_event_check_in_code(&self, event_id: Uuid) -> Result<String>;
+        async fn get_event_template_details(
+            &self,
+            group_id: Uuid,
+            event_template_id: Uuid,
+        ) -> Result<Option<serde_json::Value>>;
+        async fn get_group_discord_webhook_url(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Option<String>>;
+        async fn get_group_engagement_stats(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+            include_subgroups: bool,
+        ) -> Result<crate::templates::dashboard::group::analytics::GroupEngagementStats>;
+        async fn get_group_ownership(
+            &self,
+            group_id: Uuid,
+        ) -> Result<crate::templates::dashboard::group::settings::GroupOwnership>;
         async fn get_group_payment_recipient(
             &self,
             community_id: Uuid,
@@ -431,6 +879,14 @@ mock! {
             group_id: Uuid,
             include_subgroups: bool,
         ) -> Result<crate::templates::dashboard::group::analytics::GroupDashboardStats>;
+        async fn get_group_welcome_message(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Option<String>>;
+        async fn get_group_zoom_account(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Option<crate::templates::dashboard::group::settings::GroupZoomAccount>>;
         async fn invite_event_attendee(
             &self,
             actor_user_id: Uuid,
@@ -439,14 +895,40 @@ mock! {
             user_id: Option<Uuid>,
             email: Option<String>,
         ) -> Result<Uuid>;
+        async fn invite_group_team_member(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            email: &str,
+            role: &crate::types::group::GroupRole,
+            token_hash: &str,
+        ) -> Result<Uuid>;
         async fn list_cfs_submission_statuses_for_review(
             &self,
         ) -> Result<Vec<crate::templates::dashboard::group::events::CfsSubmissionStatus>>;
+        async fn list_group_announcements(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::types::group::GroupAnnouncement>>;
         async fn list_group_audit_logs(
             &self,
             group_id: Uuid,
             filters: &crate::templates::dashboard::audit::AuditLogFilters,
         ) -> Result<crate::templates::dashboard::audit::AuditLogsOutput>;
+        async fn list_group_bans(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::group::members::GroupBan>>;
+        async fn list_group_custom_notifications(
+            &self,
+            group_id: Uuid,
+            filters: &crate::templates::dashboard::notifications::CustomNotificationsFilters,
+        ) -> Result<crate::templates::dashboard::notifications::CustomNotificationsOutput>;
+        async fn list_event_attendee_badges(
+            &self,
+            group_id: Uuid,
+            event_id: Uuid,
+        ) -> Result<Vec<crate::services::badges::AttendeeBadge>>;
         async fn list_event_attendees_ids(
             &self,
             group_id: Uuid,
@@ -465,8 +947,23 @@ mock! {
             event_id: Uuid,
             filters: &crate::templates::dashboard::group::submissions::CfsSubmissionsFilters,
         ) -> Result<crate::templates::dashboard::group::submissions::CfsSubmissionsOutput>;
+        async fn list_event_conflicts(
+            &self,
+            group_id: Uuid,
+            event_id: Option<Uuid>,
+            event: &serde_json::Value,
+        ) -> Result<Vec<crate::templates::dashboard::group::events::EventConflict>>;
         async fn list_event_kinds(&self)
             -> Result<Vec<crate::types::event::EventKindSummary>>;
+        async fn list_event_photos(
+            &self,
+            group_id: Uuid,
+            event_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::group::photos::EventPhoto>>;
+        async fn list_event_resources(
+            &self,
+            event_id: Uuid,
+        ) -> Result<Vec<crate::types::event::EventResource>>;
         async fn list_event_series_cancelable_event_ids(
             &self,
             group_id: Uuid,
@@ -482,6 +979,10 @@ mock! {
             group_id: Uuid,
             event_id: Uuid,
         ) -> Result<Vec<Uuid>>;
+        async fn list_event_templates(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::group::events::EventTemplate>>;
         async fn list_event_waitlist_ids(
             &self,
             group_id: Uuid,
@@ -492,11 +993,27 @@ mock! {
             group_id: Uuid,
             filters: &crate::templates::dashboard::group::events::EventsListFilters,
         ) -> Result<crate::templates::dashboard::group::events::GroupEvents>;
+        async fn list_group_join_requests(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::group::members::GroupJoinRequest>>;
+        async fn list_group_member_leaderboard(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::group::members::GroupMemberLeaderboardEntry>>;
+        async fn list_group_member_roles(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Vec<String>>;
         async fn list_group_members(
             &self,
             group_id: Uuid,
             filters: &crate::templates::dashboard::group::members::GroupMembersFilters,
         ) -> Result<crate::templates::dashboard::group::members::GroupMembersOutput>;
+        async fn list_group_members_export(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::templates::dashboard::group::members::GroupMemberExport>>;
         async fn list_group_members_ids(
             &self,
             group_id: Uuid,
@@ -508,6 +1025,10 @@ mock! {
         ) -> Result<crate::templates::dashboard::group::refunds::RefundsOutput>;
         async fn list_group_roles(&self)
             -> Result<Vec<crate::types::group::GroupRoleSummary>>;
+        async fn list_group_sponsor_tiers(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::types::group::GroupSponsorTier>>;
         async fn list_group_sponsors(
             &self,
             group_id: Uuid,
@@ -538,31 +1059,61 @@ mock! {
         async fn manual_check_in_event(
             &self,
             actor_user_id: Uuid,
-            community_id: Uuid,
+            community_id: Uuid,
+            event_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<()>;
+        async fn publish_due_scheduled_event(
+            &self,
+            configured_provider: Option<PaymentProvider>,
+        ) -> Result<Option<crate::db::dashboard::group::ScheduledEventPublication>>;
+        async fn publish_event(
+            &self,
+            actor_user_id: Uuid,
+            configured_provider: Option<crate::types::payments::PaymentProvider>,
+            group_id: Uuid,
+            event_id: Uuid,
+        ) -> Result<()>;
+        async fn publish_event_series_events(
+            &self,
+            actor_user_id: Uuid,
+            configured_provider: Option<crate::types::payments::PaymentProvider>,
+            group_id: Uuid,
+            event_ids: &[Uuid],
+        ) -> Result<()>;
+        async fn reject_event_invitation_request(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
             event_id: Uuid,
             user_id: Uuid,
         ) -> Result<()>;
-        async fn publish_event(
+        async fn reject_event_photo(
             &self,
             actor_user_id: Uuid,
-            configured_provider: Option<crate::types::payments::PaymentProvider>,
             group_id: Uuid,
             event_id: Uuid,
+            event_photo_id: Uuid,
         ) -> Result<()>;
-        async fn publish_event_series_events(
+        async fn reject_group_join_request(
             &self,
             actor_user_id: Uuid,
-            configured_provider: Option<crate::types::payments::PaymentProvider>,
             group_id: Uuid,
-            event_ids: &[Uuid],
+            user_id: Uuid,
         ) -> Result<()>;
-        async fn reject_event_invitation_request(
+        async fn remove_event_waitlist_user(
             &self,
             actor_user_id: Uuid,
             group_id: Uuid,
             event_id: Uuid,
             user_id: Uuid,
         ) -> Result<()>;
+        async fn request_group_ownership_transfer(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<()>;
         async fn resolve_event_custom_notification_recipient_ids(
             &self,
             group_id: Uuid,
@@ -570,6 +1121,14 @@ mock! {
             recipient_scope: &str,
             requested_user_ids: Option<Vec<Uuid>>,
         ) -> Result<Vec<Uuid>>;
+        async fn resolve_group_custom_notification_recipient_ids(
+            &self,
+            group_id: Uuid,
+            audience: &str,
+            event_id: Option<Uuid>,
+            inactive_months: Option<i32>,
+            member_role: Option<String>,
+        ) -> Result<Vec<Uuid>>;
         async fn search_event_attendees(
             &self,
             group_id: Uuid,
@@ -588,6 +1147,13 @@ mock! {
             event_id: Uuid,
             filters: &crate::templates::dashboard::group::waitlist::WaitlistFilters,
         ) -> Result<crate::templates::dashboard::group::waitlist::WaitlistOutput>;
+        async fn unban_group_member(
+            &self,
+            actor_user_id: Uuid,
+            community_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<()>;
         async fn unpublish_event(&self, actor_user_id: Uuid, group_id: Uuid, event_id: Uuid)
             -> Result<()>;
         async fn unpublish_event_series_events(&self, actor_user_id: Uuid, group_id: Uuid, event_ids: &[Uuid])
@@ -600,6 +1166,21 @@ mock! {
             event: &serde_json::Value,
             cfg_max_participants: &HashMap<crate::services::meetings::MeetingProvider, i32>,
         ) -> Result<Vec<Uuid>>;
+        async fn update_event_comment_pinned(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            event_id: Uuid,
+            event_comment_id: Uuid,
+            pinned: bool,
+        ) -> Result<()>;
+        async fn update_event_comments_locked(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            event_id: Uuid,
+            locked: bool,
+        ) -> Result<()>;
         async fn update_cfs_submission(
             &self,
             reviewer_id: Uuid,
@@ -607,6 +1188,65 @@ mock! {
             cfs_submission_id: Uuid,
             submission: &crate::templates::dashboard::group::submissions::CfsSubmissionUpdate,
         ) -> Result<bool>;
+        async fn update_group_announcement_pinned(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            group_announcement_id: Uuid,
+            pinned: bool,
+        ) -> Result<()>;
+        async fn update_group_discord_webhook(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            webhook: &crate::templates::dashboard::group::settings::GroupDiscordWebhookUpdate,
+        ) -> Result<()>;
+        async fn update_group_discussion_topic_locked(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            group_discussion_topic_id: Uuid,
+            locked: bool,
+        ) -> Result<()>;
+        async fn update_group_discussion_topic_pinned(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            group_discussion_topic_id: Uuid,
+            pinned: bool,
+        ) -> Result<()>;
+        async fn update_group_gallery_cover(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            cover: &crate::templates::dashboard::group::gallery::GroupGalleryCoverUpdate,
+        ) -> Result<()>;
+        async fn update_group_gallery_photo_hidden(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            url: &str,
+            hidden: bool,
+        ) -> Result<()>;
+        async fn update_group_gallery_photos(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            gallery: &crate::templates::dashboard::group::gallery::GroupGalleryPhotosUpdate,
+        ) -> Result<()>;
+        async fn update_group_join_questions(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            join_questions: &crate::templates::dashboard::group::settings::GroupJoinQuestionsUpdate,
+        ) -> Result<()>;
+        async fn update_group_member_role(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+            update: &crate::templates::dashboard::group::members::GroupMemberRoleUpdate,
+        ) -> Result<()>;
         async fn update_group_sponsor(
             &self,
             actor_user_id: Uuid,
@@ -621,6 +1261,13 @@ mock! {
             group_sponsor_id: Uuid,
             featured: bool,
         ) -> Result<()>;
+        async fn update_group_sponsor_tier(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            group_sponsor_tier_id: Uuid,
+            tier: &crate::templates::dashboard::group::sponsors::GroupSponsorTierInput,
+        ) -> Result<()>;
         async fn update_group_team_member_role(
             &self,
             actor_user_id: Uuid,
@@ -628,6 +1275,18 @@ mock! {
             user_id: Uuid,
             role: &crate::types::group::GroupRole,
         ) -> Result<()>;
+        async fn update_group_welcome_message(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            welcome_message: &crate::templates::dashboard::group::settings::GroupWelcomeMessageUpdate,
+        ) -> Result<()>;
+        async fn update_group_zoom_credentials(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+            credentials: &crate::templates::dashboard::group::settings::GroupZoomCredentialsUpdate,
+        ) -> Result<()>;
     }
 
     #[async_trait]
@@ -642,11 +1301,21 @@ mock! {
             actor_user_id: Uuid,
             event_id: Uuid,
         ) -> Result<Uuid>;
+        async fn accept_group_ownership_transfer(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+        ) -> Result<()>;
         async fn accept_group_team_invitation(
             &self,
             actor_user_id: Uuid,
             group_id: Uuid,
         ) -> Result<()>;
+        async fn accept_group_team_invitation_link(
+            &self,
+            actor_user_id: Uuid,
+            token_hash: &str,
+        ) -> Result<bool>;
         async fn accept_session_proposal_co_speaker_invitation(
             &self,
             actor_user_id: Uuid,
@@ -672,6 +1341,11 @@ mock! {
             actor_user_id: Uuid,
             event_id: Uuid,
         ) -> Result<()>;
+        async fn reject_group_ownership_transfer(
+            &self,
+            actor_user_id: Uuid,
+            group_id: Uuid,
+        ) -> Result<()>;
         async fn reject_group_team_invitation(
             &self,
             actor_user_id: Uuid,
@@ -682,9 +1356,18 @@ mock! {
             user_id: Uuid,
             session_proposal_id: Uuid,
         ) -> Result<Option<crate::db::dashboard::user::SessionProposalCoSpeakerUser>>;
+        async fn get_user_attendance_certificate(
+            &self,
+            user_id: Uuid,
+            event_id: Uuid,
+        ) -> Result<Option<crate::services::certificates::AttendanceCertificate>>;
         async fn list_session_proposal_levels(
             &self,
         ) -> Result<Vec<crate::templates::dashboard::user::session_proposals::SessionProposalLevel>>;
+        async fn list_user_attendance_certificates(
+            &self,
+            user_id: Uuid,
+        ) -> Result<Vec<crate::services::certificates::AttendanceCertificate>>;
         async fn list_user_audit_logs(
             &self,
             actor_user_id: Uuid,
@@ -712,6 +1395,12 @@ mock! {
             user_id: Uuid,
             filters: &crate::templates::dashboard::user::events::UserEventsFilters,
         ) -> Result<crate::templates::dashboard::user::events::UserEventsOutput>;
+        async fn list_user_group_ownership_transfers(
+            &self,
+            user_id: Uuid,
+        ) -> Result<Vec<
+            crate::templates::dashboard::user::invitations::GroupOwnershipTransferInvitation,
+        >>;
         async fn list_user_group_team_invitations(
             &self,
             user_id: Uuid,
@@ -729,6 +1418,10 @@ mock! {
             user_id: Uuid,
             filters: &crate::templates::dashboard::user::session_proposals::SessionProposalsFilters,
         ) -> Result<crate::templates::dashboard::user::session_proposals::SessionProposalsOutput>;
+        async fn list_user_suggested_events(
+            &self,
+            user_id: Uuid,
+        ) -> Result<Vec<crate::types::event::EventSummary>>;
         async fn reject_session_proposal_co_speaker_invitation(
             &self,
             actor_user_id: Uuid,
@@ -769,6 +1462,21 @@ mock! {
             session_proposal_id: Uuid,
             label_ids: &[Uuid],
         ) -> Result<Uuid>;
+        async fn add_event_comment(
+            &self,
+            community_id: Uuid,
+            event_id: Uuid,
+            user_id: Uuid,
+            content: &str,
+            parent_event_comment_id: Option<Uuid>,
+        ) -> Result<Uuid>;
+        async fn add_event_photo(
+            &self,
+            community_id: Uuid,
+            event_id: Uuid,
+            user_id: Uuid,
+            url: &str,
+        ) -> Result<Uuid>;
         async fn attend_event(
             &self,
             community_id: Uuid,
@@ -799,17 +1507,54 @@ mock! {
             community_id: Uuid,
             event_id: Uuid,
         ) -> Result<Vec<crate::types::questionnaire::QuestionnaireQuestion>>;
+        async fn get_event_resource(
+            &self,
+            community_id: Uuid,
+            event_id: Uuid,
+            event_resource_id: Uuid,
+        ) -> Result<Option<crate::types::event::EventResourceFile>>;
+        async fn get_event_series(
+            &self,
+            community_id: Uuid,
+            event_series_id: Uuid,
+        ) -> Result<Option<crate::types::event::EventSeries>>;
         async fn get_event_summary_by_id(
             &self,
             community_id: Uuid,
             event_id: Uuid,
         ) -> Result<crate::types::event::EventSummary>;
+        async fn get_event_ticket_code(
+            &self,
+            community_id: Uuid,
+            event_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<Option<Uuid>>;
         async fn get_event_attendance(
             &self,
             community_id: Uuid,
             event_id: Uuid,
             user_id: Uuid,
         ) -> Result<crate::types::event::EventAttendanceInfo>;
+        async fn get_event_discussion(
+            &self,
+            community_id: Uuid,
+            event_id: Uuid,
+        ) -> Result<crate::types::event::EventDiscussion>;
+        async fn get_speaker_profile(
+            &self,
+            community_id: Uuid,
+            username: &str,
+        ) -> Result<Option<crate::types::event::SpeakerProfile>>;
+        async fn get_user_calendar_events(
+            &self,
+            calendar_token: &str,
+        ) -> Result<Option<Vec<crate::types::event::EventSummary>>>;
+        async fn is_event_check_in_code_valid(
+            &self,
+            community_id: Uuid,
+            event_id: Uuid,
+            code: &str,
+        ) -> Result<bool>;
         async fn is_event_check_in_window_open(
             &self,
             community_id: Uuid,
@@ -826,6 +1571,13 @@ mock! {
             user_id: Uuid,
             event_id: Uuid,
         ) -> Result<Vec<crate::templates::event::SessionProposal>>;
+        async fn update_event_reminder_preference(
+            &self,
+            community_id: Uuid,
+            event_id: Uuid,
+            user_id: Uuid,
+            reminder_preference: Option<crate::types::event::EventReminderPreference>,
+        ) -> Result<()>;
     }
 
     #[async_trait]
@@ -835,13 +1587,93 @@ mock! {
         async fn update_group_views(&self, data: Vec<(Uuid, String, u32)>) -> Result<()>;
     }
 
+    #[async_trait]
+    impl crate::db::google_calendar::DBGoogleCalendar for DB {
+        async fn add_user_google_calendar(
+            &self,
+            user_id: &Uuid,
+            refresh_token: &str,
+        ) -> Result<()>;
+        async fn claim_google_calendar_entry_out_of_sync(
+            &self,
+        ) -> Result<Option<crate::services::google_calendar::GoogleCalendarEntry>>;
+        async fn delete_user_google_calendar(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<()>;
+        async fn disconnect_user_google_calendar(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<()>;
+        async fn is_user_google_calendar_connected(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<bool>;
+        async fn release_google_calendar_entry_sync_claim(
+            &self,
+            entry: &crate::services::google_calendar::GoogleCalendarEntry,
+        ) -> Result<()>;
+        async fn set_google_calendar_entry_synced(
+            &self,
+            entry: &crate::services::google_calendar::GoogleCalendarEntry,
+        ) -> Result<()>;
+    }
+
     #[async_trait]
     impl crate::db::group::DBGroup for DB {
+        #[allow(clippy::too_many_arguments)]
+        async fn add_group_contact_message(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+            subject: &str,
+            body: &str,
+            since: chrono::DateTime<chrono::Utc>,
+            max_messages: i32,
+        ) -> Result<Option<Uuid>>;
+        async fn add_group_discussion_reply(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+            group_discussion_topic_id: Uuid,
+            content: &str,
+        ) -> Result<Uuid>;
+        async fn add_group_discussion_topic(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+            title: &str,
+            content: &str,
+        ) -> Result<Uuid>;
+        async fn get_group_discussion(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+        ) -> Result<crate::types::group::GroupDiscussion>;
         async fn get_group_full_by_slug(
             &self,
             community_id: Uuid,
             group_slug: &str,
         ) -> Result<Option<crate::types::group::GroupFull>>;
+        async fn get_group_join_questions(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+        ) -> Result<Vec<crate::types::questionnaire::QuestionnaireQuestion>>;
+        async fn get_group_membership_status(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<crate::types::group::GroupMembershipStatus>;
+        async fn get_merged_group_slug(
+            &self,
+            community_id: Uuid,
+            group_slug: &str,
+        ) -> Result<Option<String>>;
         async fn get_group_past_events(
             &self,
             community_id: Uuid,
@@ -856,24 +1688,30 @@ mock! {
             event_kinds: Vec<crate::types::event::EventKind>,
             limit: i32,
         ) -> Result<Vec<crate::types::event::EventSummary>>;
-        async fn is_group_member(
+        async fn join_group(
             &self,
             community_id: Uuid,
             group_id: Uuid,
             user_id: Uuid,
-        ) -> Result<bool>;
-        async fn join_group(
+            join_answers: Option<crate::types::questionnaire::QuestionnaireAnswers>,
+        ) -> Result<crate::types::group::GroupMembershipStatus>;
+        async fn leave_group(
             &self,
             community_id: Uuid,
             group_id: Uuid,
             user_id: Uuid,
         ) -> Result<()>;
-        async fn leave_group(
+        async fn list_group_pinned_announcements(
             &self,
             community_id: Uuid,
             group_id: Uuid,
-            user_id: Uuid,
-        ) -> Result<()>;
+        ) -> Result<Vec<crate::types::group::GroupAnnouncement>>;
+        async fn user_can_view_group_members(
+            &self,
+            community_id: Uuid,
+            group_id: Uuid,
+            user_id: Option<Uuid>,
+        ) -> Result<bool>;
     }
 
     #[async_trait]
@@ -905,6 +1743,7 @@ mock! {
             &self,
             provider: crate::services::meetings::MeetingProvider,
             provider_meeting_id: &str,
+            group_id: Option<Uuid>,
             recording_url: &str,
         ) -> Result<()>;
         async fn assign_zoom_host_user(
@@ -915,16 +1754,31 @@ mock! {
             starts_at: chrono::DateTime<chrono::Utc>,
             ends_at: chrono::DateTime<chrono::Utc>,
         ) -> Result<Option<String>>;
+        async fn claim_meeting_for_attendance_sync(
+            &self,
+        ) -> Result<Option<crate::db::meetings::MeetingAttendanceCandidate>>;
         async fn claim_meeting_for_auto_end(
             &self,
         ) -> Result<Option<crate::db::meetings::MeetingAutoEndCandidate>>;
+        async fn claim_meeting_for_recording_sync(
+            &self,
+        ) -> Result<Option<crate::db::meetings::MeetingRecordingCandidate>>;
         async fn claim_meeting_out_of_sync(
             &self,
+            paused_providers: &[crate::services::meetings::MeetingProvider],
         ) -> Result<Option<crate::services::meetings::Meeting>>;
         async fn delete_meeting(
             &self,
             meeting: &crate::services::meetings::Meeting,
         ) -> Result<()>;
+        async fn get_group_zoom_credentials(
+            &self,
+            group_id: Uuid,
+        ) -> Result<Option<crate::db::meetings::GroupZoomCredentials>>;
+        async fn list_zoom_webhook_secret_tokens(
+            &self,
+            account_id: Option<String>,
+        ) -> Result<Vec<crate::db::meetings::ZoomWebhookSecretToken>>;
         async fn mark_stale_meeting_auto_end_checks_unknown(
             &self,
             timeout: std::time::Duration,
@@ -933,14 +1787,33 @@ mock! {
             &self,
             timeout: std::time::Duration,
         ) -> Result<usize>;
+        async fn record_meeting_participant_joined(
+            &self,
+            provider: crate::services::meetings::MeetingProvider,
+            provider_meeting_id: &str,
+            group_id: Option<Uuid>,
+        ) -> Result<()>;
+        async fn release_meeting_attendance_sync_claim(
+            &self,
+            candidate: &crate::db::meetings::MeetingAttendanceCandidate,
+        ) -> Result<()>;
         async fn release_meeting_auto_end_check_claim(
             &self,
             candidate: &crate::db::meetings::MeetingAutoEndCandidate,
         ) -> Result<()>;
+        async fn release_meeting_recording_sync_claim(
+            &self,
+            candidate: &crate::db::meetings::MeetingRecordingCandidate,
+        ) -> Result<()>;
         async fn release_meeting_sync_claim(
             &self,
             meeting: &crate::services::meetings::Meeting,
         ) -> Result<()>;
+        async fn set_meeting_attendance_synced(
+            &self,
+            candidate: &crate::db::meetings::MeetingAttendanceCandidate,
+            participants_emails: &[String],
+        ) -> Result<usize>;
         async fn set_meeting_auto_end_check_outcome(
             &self,
             candidate: &crate::db::meetings::MeetingAutoEndCandidate,
@@ -951,10 +1824,23 @@ mock! {
             meeting: &crate::services::meetings::Meeting,
             error: &str,
         ) -> Result<()>;
+        async fn set_meeting_recordings_synced(
+            &self,
+            candidate: &crate::db::meetings::MeetingRecordingCandidate,
+            recording_urls: &[String],
+        ) -> Result<()>;
         async fn update_meeting(
             &self,
             meeting: &crate::services::meetings::Meeting,
         ) -> Result<()>;
+        async fn update_meeting_provider_status(
+            &self,
+            provider: crate::services::meetings::MeetingProvider,
+            provider_meeting_id: &str,
+            group_id: Option<Uuid>,
+            status: crate::services::meetings::MeetingProviderStatus,
+            occurred_at: Option<chrono::DateTime<chrono::Utc>>,
+        ) -> Result<()>;
     }
 
     #[async_trait]
@@ -962,10 +1848,46 @@ mock! {
         async fn claim_pending_notification(
             &self,
         ) -> Result<Option<crate::services::notifications::Notification>>;
+        async fn enqueue_community_notification(
+            &self,
+            community_id: Uuid,
+            notification: &crate::services::notifications::NewNotification,
+        ) -> Result<()>;
+        async fn enqueue_due_community_newsletter_batches(
+            &self,
+            base_url: &str,
+            batch_size: i32,
+        ) -> Result<usize>;
+        async fn enqueue_due_dormant_groups_reports(
+            &self,
+            base_url: &str,
+        ) -> Result<usize>;
+        async fn enqueue_due_event_attendance_certificates(
+            &self,
+            base_url: &str,
+        ) -> Result<usize>;
+        async fn enqueue_due_event_follow_ups(
+            &self,
+            base_url: &str,
+        ) -> Result<usize>;
         async fn enqueue_due_event_reminders(
             &self,
             base_url: &str,
+            offsets_minutes: &[i32],
+        ) -> Result<usize>;
+        async fn enqueue_due_event_waitlist_promotions(
+            &self,
+            base_url: &str,
+        ) -> Result<usize>;
+        async fn enqueue_due_weekly_digests(
+            &self,
+            base_url: &str,
         ) -> Result<usize>;
+        async fn enqueue_group_notification(
+            &self,
+            group_id: Uuid,
+            notification: &crate::services::notifications::NewNotification,
+        ) -> Result<()>;
         async fn enqueue_notification(
             &self,
             notification: &crate::services::notifications::NewNotification,
@@ -979,6 +1901,11 @@ mock! {
             &self,
             attachment_id: Uuid
         ) -> Result<crate::services::notifications::Attachment>;
+        async fn mark_email_undeliverable(
+            &self,
+            email: &str,
+            reason: crate::types::user::EmailUndeliverableReason,
+        ) -> Result<usize>;
         async fn mark_notification_delivery_unknown(
             &self,
             notification: &crate::services::notifications::Notification,
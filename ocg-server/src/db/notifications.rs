@@ -8,12 +8,18 @@ use async_trait::async_trait;
 use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use cached::cached;
 use serde::Serialize;
+use tokio_postgres::types::Json;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
     db::{PgClient, PgExecutor},
-    services::notifications::{Attachment, NewNotification, Notification},
+    i18n::Language,
+    services::notifications::{Attachment, NewNotification, Notification, NotificationRecipient},
+    types::{
+        community::CommunityEmailBranding,
+        user::{EmailUndeliverableReason, UserWebhook},
+    },
 };
 
 /// Trait that defines database operations used to manage notifications.
@@ -22,8 +28,51 @@ pub(crate) trait DBNotifications {
     /// Claims a pending notification for delivery.
     async fn claim_pending_notification(&self) -> Result<Option<Notification>>;
 
+    /// Enqueues a notification to be delivered to the community channels, if any.
+    async fn enqueue_community_notification(
+        &self,
+        community_id: Uuid,
+        notification: &NewNotification,
+    ) -> Result<()>;
+
+    /// Enqueues the next batch of pending community newsletter recipients and returns the
+    /// number of notifications created.
+    async fn enqueue_due_community_newsletter_batches(
+        &self,
+        base_url: &str,
+        batch_size: i32,
+    ) -> Result<usize>;
+
+    /// Enqueues due dormant groups reports and returns the number of notifications created.
+    async fn enqueue_due_dormant_groups_reports(&self, base_url: &str) -> Result<usize>;
+
+    /// Enqueues due event attendance certificates and returns the number of notifications
+    /// created.
+    async fn enqueue_due_event_attendance_certificates(&self, base_url: &str) -> Result<usize>;
+
+    /// Enqueues due event follow-ups and returns the number of notifications created.
+    async fn enqueue_due_event_follow_ups(&self, base_url: &str) -> Result<usize>;
+
     /// Enqueues due event reminders and returns the number of notifications created.
-    async fn enqueue_due_event_reminders(&self, base_url: &str) -> Result<usize>;
+    async fn enqueue_due_event_reminders(
+        &self,
+        base_url: &str,
+        offsets_minutes: &[i32],
+    ) -> Result<usize>;
+
+    /// Releases expired waitlist confirmations, promotes the next waitlisted users and
+    /// returns the number of users notified.
+    async fn enqueue_due_event_waitlist_promotions(&self, base_url: &str) -> Result<usize>;
+
+    /// Enqueues due weekly digests and returns the number of notifications created.
+    async fn enqueue_due_weekly_digests(&self, base_url: &str) -> Result<usize>;
+
+    /// Enqueues a notification to be delivered to the group channels, if any.
+    async fn enqueue_group_notification(
+        &self,
+        group_id: Uuid,
+        notification: &NewNotification,
+    ) -> Result<()>;
 
     /// Enqueues a notification to be delivered.
     async fn enqueue_notification(&self, notification: &NewNotification) -> Result<()>;
@@ -38,6 +87,13 @@ pub(crate) trait DBNotifications {
     /// Retrieves a notification attachment by its ID.
     async fn get_notification_attachment(&self, attachment_id: Uuid) -> Result<Attachment>;
 
+    /// Marks an email address as undeliverable and returns the number of users affected.
+    async fn mark_email_undeliverable(
+        &self,
+        email: &str,
+        reason: EmailUndeliverableReason,
+    ) -> Result<usize>;
+
     /// Marks a claimed notification with an unknown delivery outcome.
     async fn mark_notification_delivery_unknown(
         &self,
@@ -110,41 +166,216 @@ where
         }
 
         // Prepare notification and return it
+        let recipient = match row.get::<_, String>("channel").as_str() {
+            "discord" => NotificationRecipient::Discord(row.get("discord_webhook_url")),
+            "slack" => NotificationRecipient::Slack(row.get("slack_webhook_url")),
+            "sms" => NotificationRecipient::Sms(row.get("phone_number")),
+            "webhook" => {
+                let url: Option<String> = row.get("webhook_url");
+                let secret: Option<String> = row.get("webhook_secret");
+                NotificationRecipient::Webhook(
+                    url.zip(secret).map(|(url, secret)| UserWebhook { secret, url }),
+                )
+            }
+            _ => NotificationRecipient::Email(row.get("email")),
+        };
         let notification = Notification {
             attachments,
             delivery_claimed_at,
-            email: row.get("email"),
+            email_branding: row
+                .try_get::<_, Option<Json<CommunityEmailBranding>>>("email_branding")?
+                .map(|branding| branding.0),
             kind: row
                 .get::<_, String>("kind")
                 .as_str()
                 .try_into()
                 .expect("kind to be valid"),
+            language: row
+                .get::<_, Option<String>>("language")
+                .and_then(|language| language.parse::<Language>().ok()),
             notification_id,
+            recipient,
             template_data: row.get("template_data"),
         };
 
         Ok(Some(notification))
     }
 
-    /// [`DBNotifications::enqueue_due_event_reminders`].
+    /// [`DBNotifications::enqueue_community_notification`].
+    #[instrument(skip(self, notification), err)]
+    async fn enqueue_community_notification(
+        &self,
+        community_id: Uuid,
+        notification: &NewNotification,
+    ) -> Result<()> {
+        let kind = notification.kind.to_string();
+        self.execute(
+            "
+            select enqueue_community_notification($1::uuid, $2::text, $3::jsonb);
+            ",
+            &[&community_id, &kind, &notification.template_data],
+        )
+        .await
+    }
+
+    /// [`DBNotifications::enqueue_group_notification`].
+    #[instrument(skip(self, notification), err)]
+    async fn enqueue_group_notification(
+        &self,
+        group_id: Uuid,
+        notification: &NewNotification,
+    ) -> Result<()> {
+        let kind = notification.kind.to_string();
+        self.execute(
+            "
+            select enqueue_group_notification($1::uuid, $2::text, $3::jsonb);
+            ",
+            &[&group_id, &kind, &notification.template_data],
+        )
+        .await
+    }
+
+    /// [`DBNotifications::enqueue_due_community_newsletter_batches`].
+    #[instrument(skip(self), err)]
+    async fn enqueue_due_community_newsletter_batches(
+        &self,
+        base_url: &str,
+        batch_size: i32,
+    ) -> Result<usize> {
+        let db = self.client().await?;
+        let count = db
+            .query_one(
+                "
+                select enqueue_due_community_newsletter_batches($1::text, $2::int)::int;
+                ",
+                &[&base_url, &batch_size],
+            )
+            .await?
+            .get::<_, i32>(0);
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("enqueued newsletter notifications count cannot be negative"))?;
+
+        Ok(count)
+    }
+
+    /// [`DBNotifications::enqueue_due_dormant_groups_reports`].
+    #[instrument(skip(self), err)]
+    async fn enqueue_due_dormant_groups_reports(&self, base_url: &str) -> Result<usize> {
+        let db = self.client().await?;
+        let count = db
+            .query_one(
+                "
+                select enqueue_due_dormant_groups_reports($1::text)::int;
+                ",
+                &[&base_url],
+            )
+            .await?
+            .get::<_, i32>(0);
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("enqueued dormant groups reports count cannot be negative"))?;
+
+        Ok(count)
+    }
+
+    /// [`DBNotifications::enqueue_due_event_attendance_certificates`].
     #[instrument(skip(self), err)]
-    async fn enqueue_due_event_reminders(&self, base_url: &str) -> Result<usize> {
+    async fn enqueue_due_event_attendance_certificates(&self, base_url: &str) -> Result<usize> {
         let db = self.client().await?;
         let count = db
             .query_one(
                 "
-                select enqueue_due_event_reminders($1::text)::int;
+                select enqueue_due_event_attendance_certificates($1::text)::int;
                 ",
                 &[&base_url],
             )
             .await?
             .get::<_, i32>(0);
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("enqueued attendance certificates count cannot be negative"))?;
+
+        Ok(count)
+    }
+
+    /// [`DBNotifications::enqueue_due_event_follow_ups`].
+    #[instrument(skip(self), err)]
+    async fn enqueue_due_event_follow_ups(&self, base_url: &str) -> Result<usize> {
+        let db = self.client().await?;
+        let count = db
+            .query_one(
+                "
+                select enqueue_due_event_follow_ups($1::text)::int;
+                ",
+                &[&base_url],
+            )
+            .await?
+            .get::<_, i32>(0);
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("enqueued follow-ups count cannot be negative"))?;
+
+        Ok(count)
+    }
+
+    /// [`DBNotifications::enqueue_due_event_reminders`].
+    #[instrument(skip(self), err)]
+    async fn enqueue_due_event_reminders(
+        &self,
+        base_url: &str,
+        offsets_minutes: &[i32],
+    ) -> Result<usize> {
+        let db = self.client().await?;
+        let count = db
+            .query_one(
+                "
+                select enqueue_due_event_reminders($1::text, $2::int[])::int;
+                ",
+                &[&base_url, &offsets_minutes],
+            )
+            .await?
+            .get::<_, i32>(0);
         let count = usize::try_from(count)
             .map_err(|_| anyhow!("enqueued reminders count cannot be negative"))?;
 
         Ok(count)
     }
 
+    /// [`DBNotifications::enqueue_due_event_waitlist_promotions`].
+    #[instrument(skip(self), err)]
+    async fn enqueue_due_event_waitlist_promotions(&self, base_url: &str) -> Result<usize> {
+        let db = self.client().await?;
+        let count = db
+            .query_one(
+                "
+                select enqueue_due_event_waitlist_promotions($1::text)::int;
+                ",
+                &[&base_url],
+            )
+            .await?
+            .get::<_, i32>(0);
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("enqueued waitlist promotions count cannot be negative"))?;
+
+        Ok(count)
+    }
+
+    /// [`DBNotifications::enqueue_due_weekly_digests`].
+    #[instrument(skip(self), err)]
+    async fn enqueue_due_weekly_digests(&self, base_url: &str) -> Result<usize> {
+        let db = self.client().await?;
+        let count = db
+            .query_one(
+                "
+                select enqueue_due_weekly_digests($1::text)::int;
+                ",
+                &[&base_url],
+            )
+            .await?
+            .get::<_, i32>(0);
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("enqueued digests count cannot be negative"))?;
+
+        Ok(count)
+    }
+
     /// [`DBNotifications::enqueue_notification`].
     #[instrument(skip(self, notification), err)]
     async fn enqueue_notification(&self, notification: &NewNotification) -> Result<()> {
@@ -282,6 +513,26 @@ where
         inner(db, attachment_id).await
     }
 
+    /// [`DBNotifications::mark_email_undeliverable`].
+    #[instrument(skip(self, email), err)]
+    async fn mark_email_undeliverable(
+        &self,
+        email: &str,
+        reason: EmailUndeliverableReason,
+    ) -> Result<usize> {
+        // Flag the address and stop its pending email deliveries
+        let count = self
+            .fetch_scalar_one::<i32>(
+                "select mark_email_undeliverable($1::text, $2::text)::int;",
+                &[&email, &reason.to_string()],
+            )
+            .await?;
+        let count = usize::try_from(count)
+            .map_err(|_| anyhow!("flagged users count cannot be negative"))?;
+
+        Ok(count)
+    }
+
     /// [`DBNotifications::mark_notification_delivery_unknown`].
     #[instrument(skip(self, notification), err)]
     async fn mark_notification_delivery_unknown(
@@ -16,6 +16,8 @@ pub(crate) mod auth;
 pub(crate) mod community;
 /// Dashboards handlers.
 pub(crate) mod dashboard;
+/// Email delivery feedback handlers.
+pub(crate) mod email_feedback;
 /// Error handling utilities for HTTP handlers.
 pub(crate) mod error;
 /// Event page handlers.
@@ -5,9 +5,9 @@ use std::collections::HashMap;
 use askama::Template;
 use async_trait::async_trait;
 use axum::{
-    Form,
+    Form, Json,
     extract::{Path, Query, Request, State},
-    http::{HeaderMap, StatusCode},
+    http::{HeaderMap, StatusCode, header::ACCEPT_LANGUAGE},
     middleware::Next,
     response::{Html, IntoResponse, Redirect, Response},
 };
@@ -16,32 +16,50 @@ use garde::Validate;
 use openidconnect as oidc;
 use password_auth::verify_password;
 use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tower_sessions::Session;
-use tracing::instrument;
+use tracing::{error, instrument, warn};
 use uuid::Uuid;
+use webauthn_rs::prelude::{PasskeyAuthentication, PasskeyRegistration, PublicKeyCredential};
 
 use crate::{
     auth::{
-        self, AuthSession, Credentials, OAuth2Credentials, OidcCredentials, PasswordCredentials,
+        self, AuthSession, Credentials, LdapCredentials, MagicLinkCredentials, OAuth2Credentials,
+        OidcCredentials, PasskeyCredentials, PasswordCredentials,
+    },
+    config::{
+        GoogleCalendarConfig, HttpServerConfig, LoginOptions, OAuth2Provider, OidcProvider,
+        SmsConfig,
     },
-    config::{HttpServerConfig, OAuth2Provider, OidcProvider},
     db::{DynDB, auth::EmailVerificationNotification},
     handlers::{
         error::HandlerError,
         extractors::{
-            CurrentUser, OAuth2, Oidc, SelectedCommunityId, SelectedGroupId, ValidatedForm,
-            ValidatedFormQs,
+            ClientIp, CurrentUser, OAuth2, Oidc, Passkeys, SelectedCommunityId, SelectedGroupId,
+            SessionClient, ValidatedForm, ValidatedFormQs,
+        },
+    },
+    i18n::Language,
+    services::{
+        captcha,
+        google_calendar::client::GoogleCalendarClient,
+        notifications::{
+            DynNotificationsManager, NewNotification, NotificationKind,
+            webhook::check_webhook_url_host,
         },
     },
     templates::{
         self, PageId,
-        auth::{User, UserDetails},
-        notifications::EmailVerification,
+        auth::{
+            CaptchaWidget, User, UserCalendarFeedUpdate, UserDetails, UserPasskeyRegistration,
+            UserPhoneNumberUpdate, UserPhoneVerification, UserSmsNotificationsUpdate,
+            UserWebhookUpdate,
+        },
+        notifications::{EmailVerification, MagicLink, PasswordReset},
     },
     types::permissions::{CommunityPermission, GroupPermission},
-    util::base_url_without_trailing_slash,
-    validation::{MAX_LEN_S, trimmed_non_empty},
+    util::{base_url_without_trailing_slash, compute_hash},
+    validation::{MAX_LEN_S, MIN_PASSWORD_LEN, trimmed_non_empty},
 };
 
 #[cfg(test)]
@@ -53,6 +71,33 @@ pub(crate) const AUTH_PROVIDER_KEY: &str = "auth_provider";
 /// Session value for password authentication.
 pub(crate) const AUTH_PROVIDER_EMAIL: &str = "email";
 
+/// Session value for LDAP directory authentication.
+pub(crate) const AUTH_PROVIDER_LDAP: &str = "ldap";
+
+/// Session value for magic link authentication.
+pub(crate) const AUTH_PROVIDER_MAGIC_LINK: &str = "magic_link";
+
+/// Session value for passkey authentication.
+pub(crate) const AUTH_PROVIDER_PASSKEY: &str = "passkey";
+
+/// Message shown when the CAPTCHA challenge is not passed.
+pub(crate) const CAPTCHA_FAILED_MESSAGE: &str =
+    "Please complete the CAPTCHA challenge and try again.";
+
+/// Message shown when the community does not allow the email domain to sign up.
+const EMAIL_DOMAIN_NOT_ALLOWED_MESSAGE: &str = concat!(
+    "Sign ups from this community are restricted to some email domains. ",
+    "Please use your organization email address."
+);
+
+/// Message shown when an external account cannot be linked.
+const IDENTITY_LINK_FAILED_MESSAGE: &str =
+    "Something went wrong linking the account. Please try again later.";
+
+/// Message shown when an external account is linked to another user.
+const IDENTITY_LINK_CONFLICT_MESSAGE: &str =
+    "This account is already linked to another OCG account.";
+
 /// Friendly message for LF SSO email ownership conflicts.
 const LF_SSO_EMAIL_CONFLICT_MESSAGE: &str = concat!(
     "Your LF SSO account matches an existing OCG account, but its email address is already used ",
@@ -63,6 +108,19 @@ const LF_SSO_EMAIL_CONFLICT_MESSAGE: &str = concat!(
 const LF_SSO_IDENTITY_CONFLICT_MESSAGE: &str =
     "This LF SSO account is already linked to another OCG account.";
 
+/// URL for the forgot password page.
+pub(crate) const FORGOT_PASSWORD_URL: &str = "/forgot-password";
+
+/// Key used to store the Google Calendar authorization CSRF state in the session.
+pub(crate) const GOOGLE_CALENDAR_CSRF_STATE_KEY: &str = "google_calendar.csrf_state";
+
+/// Key used to store the user linking an external identity in the session.
+pub(crate) const LINK_IDENTITY_USER_ID_KEY: &str = "link_identity.user_id";
+
+/// Message shown when password log ins are temporarily locked.
+const LOG_IN_LOCKED_MESSAGE: &str =
+    "Too many failed log in attempts. Please try again in a few minutes.";
+
 /// URL for the log in page.
 pub(crate) const LOG_IN_URL: &str = "/log-in";
 
@@ -78,12 +136,26 @@ pub(crate) const OAUTH2_CSRF_STATE_KEY: &str = "oauth2.csrf_state";
 /// Key used to store the `Oidc` nonce in the session.
 pub(crate) const OIDC_NONCE_KEY: &str = "oidc.nonce";
 
+/// Key used to store the pending passkey authentication in the session.
+pub(crate) const PASSKEY_AUTHENTICATION_KEY: &str = "passkey.authentication";
+
+/// Key used to store the pending passkey registration in the session.
+pub(crate) const PASSKEY_REGISTRATION_KEY: &str = "passkey.registration";
+
+/// Message shown when a community requires signing in with a passkey.
+const PASSKEY_REQUIRED_MESSAGE: &str =
+    "This community requires signing in with a passkey to access its dashboard.";
+
 /// Key used to store the selected community ID in the session.
 pub(crate) const SELECTED_COMMUNITY_ID_KEY: &str = "selected_community_id";
 
 /// Key used to store the selected group ID in the session.
 pub(crate) const SELECTED_GROUP_ID_KEY: &str = "selected_group_id";
 
+/// Key used to store the details of the client the user logged in from in the
+/// session.
+pub(crate) const SESSION_CLIENT_KEY: &str = "session_client";
+
 /// Defines whether syncing a community selection requires a group selection.
 pub(crate) enum SelectedGroupPolicy {
     /// Group selection may be absent.
@@ -95,11 +167,47 @@ pub(crate) enum SelectedGroupPolicy {
 /// URL for the sign up page.
 pub(crate) const SIGN_UP_URL: &str = "/sign-up";
 
+/// Message shown when a community requires logging in with its SSO provider.
+const SSO_REQUIRED_MESSAGE: &str =
+    "This community requires logging in with its single sign-on provider.";
+
+/// URL for user dashboard account tab.
+pub(crate) const USER_DASHBOARD_ACCOUNT_URL: &str = "/dashboard/user?tab=account";
+
 /// URL for user dashboard invitations tab.
 pub(crate) const USER_DASHBOARD_INVITATIONS_URL: &str = "/dashboard/user?tab=invitations";
 
 // Pages and sections handlers.
 
+/// Handler that returns the forgot password page.
+#[instrument(skip_all, err)]
+pub(crate) async fn forgot_password_page(
+    auth_session: AuthSession,
+    messages: Messages,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Check if the user is already logged in
+    if auth_session.user.is_some() {
+        return Ok(Redirect::to("/").into_response());
+    }
+
+    // Get site settings
+    let site_settings = db.get_site_settings().await?;
+
+    // Prepare template
+    let template = templates::auth::ForgotPasswordPage {
+        login: server_cfg.login.clone(),
+        messages: messages.into_iter().collect(),
+        page_id: PageId::ForgotPassword,
+        path: FORGOT_PASSWORD_URL.to_string(),
+        site_settings,
+        user: User::default(),
+    };
+
+    Ok(Html(template.render()?).into_response())
+}
+
 /// Handler that returns the log in page.
 #[instrument(skip_all, err)]
 pub(crate) async fn log_in_page(
@@ -117,13 +225,17 @@ pub(crate) async fn log_in_page(
     // Get site settings
     let site_settings = db.get_site_settings().await?;
 
-    // Sanitize and encode the next url (if any)
-    let next_url = sanitize_next_url(query.get("next_url").map(String::as_str))
-        .map(|value| encode_next_url(&value));
+    // Sanitize the next url (if any)
+    let next_url = sanitize_next_url(query.get("next_url").map(String::as_str));
+
+    // Only offer the provider required by the community (if any)
+    let required_oidc_provider = get_required_oidc_provider(&db, next_url.as_deref()).await?;
+    let login = available_login_options(&server_cfg.login, required_oidc_provider.as_ref());
 
     // Prepare template
+    let next_url = next_url.map(|value| encode_next_url(&value));
     let template = templates::auth::LogInPage {
-        login: server_cfg.login.clone(),
+        login,
         messages: messages.into_iter().collect(),
         page_id: PageId::LogIn,
         path: LOG_IN_URL.to_string(),
@@ -136,6 +248,38 @@ pub(crate) async fn log_in_page(
     Ok(Html(template.render()?).into_response())
 }
 
+/// Handler that returns the reset password page.
+#[instrument(skip_all, err)]
+pub(crate) async fn reset_password_page(
+    auth_session: AuthSession,
+    messages: Messages,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(code): Path<String>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Check if the user is already logged in
+    if auth_session.user.is_some() {
+        return Ok(Redirect::to("/").into_response());
+    }
+
+    // Get site settings
+    let site_settings = db.get_site_settings().await?;
+
+    // Prepare template
+    let path = format!("/reset-password/{code}");
+    let template = templates::auth::ResetPasswordPage {
+        code,
+        login: server_cfg.login.clone(),
+        messages: messages.into_iter().collect(),
+        page_id: PageId::ResetPassword,
+        path,
+        site_settings,
+        user: User::default(),
+    };
+
+    Ok(Html(template.render()?).into_response())
+}
+
 /// Handler that returns the sign up page.
 #[instrument(skip_all, err)]
 pub(crate) async fn sign_up_page(
@@ -153,19 +297,24 @@ pub(crate) async fn sign_up_page(
     // Get site settings
     let site_settings = db.get_site_settings().await?;
 
-    // Sanitize and encode the next url (if any)
-    let next_url = sanitize_next_url(query.get("next_url").map(String::as_str))
-        .map(|value| encode_next_url(&value));
+    // Sanitize the next url (if any)
+    let next_url = sanitize_next_url(query.get("next_url").map(String::as_str));
+
+    // Only offer the provider required by the community (if any)
+    let required_oidc_provider = get_required_oidc_provider(&db, next_url.as_deref()).await?;
+    let login = available_login_options(&server_cfg.login, required_oidc_provider.as_ref());
 
     // Prepare template
+    let next_url = next_url.map(|value| encode_next_url(&value));
     let template = templates::auth::SignUpPage {
-        login: server_cfg.login.clone(),
+        login,
         messages: messages.into_iter().collect(),
         page_id: PageId::SignUp,
         path: SIGN_UP_URL.to_string(),
         site_settings,
         user: User::default(),
 
+        captcha: CaptchaWidget::from_server_cfg(&server_cfg),
         next_url,
     };
 
@@ -187,12 +336,168 @@ pub(crate) async fn user_menu_section(
 
 // Actions handlers.
 
+/// Handler that redirects the user to Google to connect their calendar.
+#[instrument(skip_all, err)]
+pub(crate) async fn connect_google_calendar(
+    session: Session,
+    State(google_calendar_cfg): State<Option<GoogleCalendarConfig>>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Calendars cannot be connected when the sync is not configured
+    let Some(google_calendar_cfg) = google_calendar_cfg else {
+        return Err(HandlerError::NotFound);
+    };
+
+    // Generate the authorization url
+    let csrf_state = oauth2::CsrfToken::new_random();
+    let authorize_url =
+        GoogleCalendarClient::new(google_calendar_cfg).authorize_url(csrf_state.secret())?;
+
+    // Save the csrf state in the session
+    session
+        .insert(GOOGLE_CALENDAR_CSRF_STATE_KEY, csrf_state.secret())
+        .await?;
+
+    // Redirect to the authorization url
+    Ok(Redirect::to(&authorize_url))
+}
+
+/// Handler that deletes one of the user's passkeys.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete_user_passkey(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    Path(user_passkey_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Delete passkey from database
+    db.delete_user_passkey(&user.user_id, &user_passkey_id).await?;
+    messages.success("Passkey removed successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Handler that disconnects the user's Google Calendar.
+#[instrument(skip_all, err)]
+pub(crate) async fn disconnect_google_calendar(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Disconnect Google Calendar in database
+    db.disconnect_user_google_calendar(&user.user_id).await?;
+    messages.success("Google Calendar disconnected successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Handler that emails the user a link to reset their password.
+#[instrument(skip_all, err)]
+pub(crate) async fn forgot_password(
+    messages: Messages,
+    State(db): State<DynDB>,
+    State(notifications_manager): State<DynNotificationsManager>,
+    State(server_cfg): State<HttpServerConfig>,
+    Form(input): Form<ForgotPasswordInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Validate form
+    if let Err(e) = input.validate() {
+        messages.error(e.to_string());
+        return Ok(Redirect::to(FORGOT_PASSWORD_URL));
+    }
+
+    // Store the reset code, which is only issued to verified users
+    let code = Uuid::new_v4().simple().to_string();
+    let code_hash = compute_hash(code.as_bytes());
+    if let Some(user_id) = db.add_user_password_reset(&input.email, &code_hash).await? {
+        let notification =
+            build_password_reset_notification(&db, &server_cfg, user_id, &code).await?;
+        notifications_manager.enqueue(&notification).await?;
+    }
+
+    // Do not disclose whether the email belongs to an account
+    messages.success(
+        "If the email belongs to an account, you will receive a link to reset your password shortly.",
+    );
+    Ok(Redirect::to(LOG_IN_URL))
+}
+
+/// Handler that completes the Google Calendar authorization process.
+#[instrument(skip_all, err)]
+pub(crate) async fn google_calendar_callback(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    session: Session,
+    State(db): State<DynDB>,
+    State(google_calendar_cfg): State<Option<GoogleCalendarConfig>>,
+    Query(GoogleCalendarAuthorizationResponse { code, state }): Query<
+        GoogleCalendarAuthorizationResponse,
+    >,
+) -> Result<impl IntoResponse, HandlerError> {
+    const GOOGLE_CALENDAR_CONNECTION_FAILED: &str = "Google Calendar connection failed";
+
+    // Calendars cannot be connected when the sync is not configured
+    let Some(google_calendar_cfg) = google_calendar_cfg else {
+        return Err(HandlerError::NotFound);
+    };
+
+    // Verify csrf state, the code is missing when the user denied access
+    let state_in_session = session.remove::<String>(GOOGLE_CALENDAR_CSRF_STATE_KEY).await?;
+    let Some(code) = code.filter(|_| state_in_session.as_deref() == Some(state.as_str())) else {
+        messages.error(GOOGLE_CALENDAR_CONNECTION_FAILED);
+        return Ok(Redirect::to(USER_DASHBOARD_ACCOUNT_URL));
+    };
+
+    // Exchange the authorization code for a refresh token
+    let client = GoogleCalendarClient::new(google_calendar_cfg);
+    let refresh_token = match client.exchange_code(&code).await {
+        Ok(refresh_token) => refresh_token,
+        Err(err) => {
+            warn!(%err, "error connecting google calendar");
+            messages.error(GOOGLE_CALENDAR_CONNECTION_FAILED);
+            return Ok(Redirect::to(USER_DASHBOARD_ACCOUNT_URL));
+        }
+    };
+
+    // Connect Google Calendar in database
+    db.add_user_google_calendar(&user.user_id, &refresh_token).await?;
+    messages.success("Google Calendar connected successfully.");
+
+    Ok(Redirect::to(USER_DASHBOARD_ACCOUNT_URL))
+}
+
+/// Handler that redirects the user to the oauth2 provider to link their account.
+#[instrument(skip_all)]
+pub(crate) async fn link_oauth2_redirect(
+    CurrentUser(user): CurrentUser,
+    session: Session,
+    oauth2_provider: OAuth2,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Flag the authorization as a link request for the current user
+    session.insert(LINK_IDENTITY_USER_ID_KEY, user.user_id).await?;
+
+    oauth2_redirect(session, oauth2_provider, Query(NextUrl { next_url: None })).await
+}
+
+/// Handler that redirects the user to the oidc provider to link their account.
+#[instrument(skip_all)]
+pub(crate) async fn link_oidc_redirect(
+    CurrentUser(user): CurrentUser,
+    session: Session,
+    oidc_provider: Oidc,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Flag the authorization as a link request for the current user
+    session.insert(LINK_IDENTITY_USER_ID_KEY, user.user_id).await?;
+
+    oidc_redirect(session, oidc_provider, Query(NextUrl { next_url: None })).await
+}
+
 /// Handler that logs the user in.
 #[instrument(skip_all)]
 pub(crate) async fn log_in(
     mut auth_session: AuthSession,
     messages: Messages,
     session: Session,
+    client: SessionClient,
     State(db): State<DynDB>,
     Query(query): Query<HashMap<String, String>>,
     Form(login_form): Form<LoginForm>,
@@ -200,6 +505,13 @@ pub(crate) async fn log_in(
     // Sanitize next url
     let next_url = sanitize_next_url(query.get("next_url").map(String::as_str));
 
+    // Reject password log ins when the community requires its SSO provider
+    if get_required_oidc_provider(&db, next_url.as_deref()).await?.is_some() {
+        messages.error(SSO_REQUIRED_MESSAGE);
+        let log_in_url = get_log_in_url(next_url.as_deref());
+        return Ok(Redirect::to(&log_in_url));
+    }
+
     // Validate form
     if let Err(e) = login_form.validate() {
         messages.error(e.to_string());
@@ -207,22 +519,42 @@ pub(crate) async fn log_in(
         return Ok(Redirect::to(&log_in_url));
     }
 
+    // Reject the attempt while the username or address is locked out
+    let client_ip = client.ip_address;
+    let username = login_form.username;
+    if db.is_log_in_locked(&username, client_ip).await? {
+        messages.error(LOG_IN_LOCKED_MESSAGE);
+        let log_in_url = get_log_in_url(next_url.as_deref());
+        return Ok(Redirect::to(&log_in_url));
+    }
+
     // Authenticate user
     let creds = PasswordCredentials {
         password: login_form.password,
-        username: login_form.username,
+        username: username.clone(),
     };
     let Some(user) = auth_session
         .authenticate(Credentials::Password(creds))
         .await
         .map_err(|e| HandlerError::Auth(e.to_string()))?
     else {
+        // Track the failure, reporting bursts that trigger a lockout
+        if db.register_failed_log_in(&username, client_ip).await? {
+            warn!(
+                username,
+                ?client_ip,
+                "log in locked after repeated failed attempts"
+            );
+        }
         messages
             .error("Invalid credentials. Please make sure you have verified your email address.");
         let log_in_url = get_log_in_url(next_url.as_deref());
         return Ok(Redirect::to(&log_in_url));
     };
 
+    // Reset the failures tracked for the user
+    db.reset_failed_log_ins(&username).await?;
+
     // Log user in
     auth_session
         .login(&user)
@@ -235,10 +567,237 @@ pub(crate) async fn log_in(
     // Track auth provider in the session
     track_auth_provider(&session, AUTH_PROVIDER_EMAIL).await?;
 
+    // Track the client used to log in
+    track_session_client(&session, client).await?;
+
+    // Track the lifetime selected for the session
+    track_session_lifetime(&session, login_form.remember_me).await?;
+
     let next_url = next_url.as_deref().unwrap_or("/");
     Ok(Redirect::to(next_url))
 }
 
+/// Handler that logs the user in using their directory account.
+#[instrument(skip_all)]
+pub(crate) async fn log_in_ldap(
+    mut auth_session: AuthSession,
+    messages: Messages,
+    session: Session,
+    client: SessionClient,
+    State(db): State<DynDB>,
+    Query(NextUrl { next_url }): Query<NextUrl>,
+    Form(login_form): Form<LoginForm>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Sanitize next url
+    let next_url = sanitize_next_url(next_url.as_deref());
+
+    // Reject directory log ins when the community requires its SSO provider
+    if get_required_oidc_provider(&db, next_url.as_deref()).await?.is_some() {
+        messages.error(SSO_REQUIRED_MESSAGE);
+        let log_in_url = get_log_in_url(next_url.as_deref());
+        return Ok(Redirect::to(&log_in_url));
+    }
+
+    // Validate form
+    if let Err(e) = login_form.validate() {
+        messages.error(e.to_string());
+        let log_in_url = get_log_in_url(next_url.as_deref());
+        return Ok(Redirect::to(&log_in_url));
+    }
+
+    // Reject the attempt while the username or address is locked out
+    let client_ip = client.ip_address;
+    let username = login_form.username;
+    if db.is_log_in_locked(&username, client_ip).await? {
+        messages.error(LOG_IN_LOCKED_MESSAGE);
+        let log_in_url = get_log_in_url(next_url.as_deref());
+        return Ok(Redirect::to(&log_in_url));
+    }
+
+    // Authenticate user
+    let creds = LdapCredentials {
+        password: login_form.password,
+        username: username.clone(),
+    };
+    let Some(user) = auth_session
+        .authenticate(Credentials::Ldap(creds))
+        .await
+        .map_err(|e| HandlerError::Auth(e.to_string()))?
+    else {
+        // Track the failure, reporting bursts that trigger a lockout
+        if db.register_failed_log_in(&username, client_ip).await? {
+            warn!(
+                username,
+                ?client_ip,
+                "directory log in locked after repeated failed attempts"
+            );
+        }
+        messages.error("Invalid directory credentials.");
+        let log_in_url = get_log_in_url(next_url.as_deref());
+        return Ok(Redirect::to(&log_in_url));
+    };
+
+    // Reset the failures tracked for the user
+    db.reset_failed_log_ins(&username).await?;
+
+    // Log user in
+    auth_session
+        .login(&user)
+        .await
+        .map_err(|e| HandlerError::Auth(e.to_string()))?;
+
+    // Select the first community and group as selected in the session
+    select_first_community_and_group(&db, &session, &user.user_id).await?;
+
+    // Track auth provider in the session
+    track_auth_provider(&session, AUTH_PROVIDER_LDAP).await?;
+
+    // Track the client used to log in
+    track_session_client(&session, client).await?;
+
+    // Track the lifetime selected for the session
+    track_session_lifetime(&session, login_form.remember_me).await?;
+
+    let next_url = next_url.as_deref().unwrap_or("/");
+    Ok(Redirect::to(next_url))
+}
+
+/// Handler that logs the user in using a link sent to their email address.
+#[instrument(skip_all)]
+pub(crate) async fn log_in_magic_link(
+    mut auth_session: AuthSession,
+    messages: Messages,
+    session: Session,
+    client: SessionClient,
+    State(db): State<DynDB>,
+    Path(token): Path<String>,
+    Query(NextUrl { next_url }): Query<NextUrl>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Sanitize next url
+    let next_url = sanitize_next_url(next_url.as_deref());
+
+    // Authenticate user
+    let creds = MagicLinkCredentials { token };
+    let Some(user) = auth_session
+        .authenticate(Credentials::MagicLink(creds))
+        .await
+        .map_err(|e| HandlerError::Auth(e.to_string()))?
+    else {
+        messages.error(
+            "Invalid log in link (please note that links are only valid for 15 minutes and can only be used once).",
+        );
+        let log_in_url = get_log_in_url(next_url.as_deref());
+        return Ok(Redirect::to(&log_in_url));
+    };
+
+    // Log user in
+    auth_session
+        .login(&user)
+        .await
+        .map_err(|e| HandlerError::Auth(e.to_string()))?;
+
+    // Select the first community and group as selected in the session
+    select_first_community_and_group(&db, &session, &user.user_id).await?;
+
+    // Track auth provider in the session
+    track_auth_provider(&session, AUTH_PROVIDER_MAGIC_LINK).await?;
+
+    // Track the client used to log in
+    track_session_client(&session, client).await?;
+
+    // Track the lifetime selected for the session
+    track_session_lifetime(&session, true).await?;
+
+    let next_url = next_url.as_deref().unwrap_or("/");
+    Ok(Redirect::to(next_url))
+}
+
+/// Handler that completes a passkey log in using the authenticator assertion.
+#[instrument(skip_all, err)]
+pub(crate) async fn log_in_passkey_finish(
+    mut auth_session: AuthSession,
+    session: Session,
+    client: SessionClient,
+    State(db): State<DynDB>,
+    Query(NextUrl { next_url }): Query<NextUrl>,
+    Json(credential): Json<PublicKeyCredential>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get the challenge issued when the log in was started
+    let Some(pending) = session
+        .remove::<PendingPasskeyAuthentication>(PASSKEY_AUTHENTICATION_KEY)
+        .await?
+    else {
+        return Ok(StatusCode::UNAUTHORIZED.into_response());
+    };
+
+    // Authenticate user
+    let creds = PasskeyCredentials {
+        credential,
+        state: pending.state,
+        user_id: pending.user_id,
+    };
+    let Some(user) = auth_session
+        .authenticate(Credentials::Passkey(creds))
+        .await
+        .map_err(|e| HandlerError::Auth(e.to_string()))?
+    else {
+        return Ok(StatusCode::UNAUTHORIZED.into_response());
+    };
+
+    // Log user in
+    auth_session
+        .login(&user)
+        .await
+        .map_err(|e| HandlerError::Auth(e.to_string()))?;
+
+    // Select the first community and group as selected in the session
+    select_first_community_and_group(&db, &session, &user.user_id).await?;
+
+    // Track auth provider in the session
+    track_auth_provider(&session, AUTH_PROVIDER_PASSKEY).await?;
+
+    // Track the client used to log in
+    track_session_client(&session, client).await?;
+
+    // Track the lifetime selected for the session
+    track_session_lifetime(&session, true).await?;
+
+    let next_url = sanitize_next_url(next_url.as_deref()).unwrap_or_else(|| "/".to_string());
+    Ok((StatusCode::NO_CONTENT, [("X-OCG-Redirect", next_url)]).into_response())
+}
+
+/// Handler that starts a passkey log in, issuing a challenge for the user's
+/// registered passkeys.
+#[instrument(skip_all, err)]
+pub(crate) async fn log_in_passkey_start(
+    session: Session,
+    State(db): State<DynDB>,
+    Passkeys(webauthn): Passkeys,
+    Json(input): Json<PasskeyLogInInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get the passkeys the user can sign in with
+    input.validate()?;
+    let Some(user) = db.get_user_by_username(&input.username).await? else {
+        return Ok(StatusCode::UNAUTHORIZED.into_response());
+    };
+    let passkeys = db.get_user_passkeys(&user.user_id).await?;
+    if passkeys.is_empty() {
+        return Ok(StatusCode::UNAUTHORIZED.into_response());
+    }
+
+    // Issue the challenge, keeping the authentication state in the session
+    let (challenge, state) = webauthn
+        .start_passkey_authentication(&passkeys)
+        .map_err(anyhow::Error::from)?;
+    let pending = PendingPasskeyAuthentication {
+        state,
+        user_id: user.user_id,
+    };
+    session.insert(PASSKEY_AUTHENTICATION_KEY, pending).await?;
+
+    Ok(Json(challenge).into_response())
+}
+
 /// Handler that logs the user out.
 #[instrument(skip_all)]
 pub(crate) async fn log_out(
@@ -258,6 +817,7 @@ pub(crate) async fn oauth2_callback(
     mut auth_session: AuthSession,
     messages: Messages,
     session: Session,
+    client: SessionClient,
     State(db): State<DynDB>,
     Path(provider): Path<OAuth2Provider>,
     Query(OAuth2AuthorizationResponse { code, state }): Query<OAuth2AuthorizationResponse>,
@@ -265,6 +825,7 @@ pub(crate) async fn oauth2_callback(
     oauth2_callback_with_auth(
         &mut auth_session,
         session,
+        client,
         &db,
         provider,
         code,
@@ -305,6 +866,7 @@ pub(crate) async fn oidc_callback(
     mut auth_session: AuthSession,
     messages: Messages,
     session: Session,
+    client: SessionClient,
     State(db): State<DynDB>,
     Path(provider): Path<OidcProvider>,
     Query(OAuth2AuthorizationResponse { code, state }): Query<OAuth2AuthorizationResponse>,
@@ -312,6 +874,7 @@ pub(crate) async fn oidc_callback(
     oidc_callback_with_auth(
         &mut auth_session,
         session,
+        client,
         &db,
         provider,
         code,
@@ -351,24 +914,256 @@ pub(crate) async fn oidc_redirect(
     Ok(Redirect::to(authorize_url.as_str()))
 }
 
+/// Handler that completes the registration of a passkey for the user.
+#[instrument(skip_all, err)]
+pub(crate) async fn register_passkey_finish(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    session: Session,
+    State(db): State<DynDB>,
+    Passkeys(webauthn): Passkeys,
+    Json(input): Json<UserPasskeyRegistration>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get the challenge issued when the registration was started
+    input.validate()?;
+    let Some(state) = session
+        .remove::<PasskeyRegistration>(PASSKEY_REGISTRATION_KEY)
+        .await?
+    else {
+        return Ok(StatusCode::BAD_REQUEST.into_response());
+    };
+
+    // Verify the credential created by the authenticator
+    let Ok(passkey) = webauthn.finish_passkey_registration(&input.credential, &state) else {
+        return Ok(StatusCode::BAD_REQUEST.into_response());
+    };
+
+    // Store passkey in database
+    db.add_user_passkey(&user.user_id, &input.name, &passkey).await?;
+    messages.success("Passkey added successfully.");
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("X-OCG-Redirect", USER_DASHBOARD_ACCOUNT_URL)],
+    )
+        .into_response())
+}
+
+/// Handler that starts the registration of a passkey for the user.
+#[instrument(skip_all, err)]
+pub(crate) async fn register_passkey_start(
+    CurrentUser(user): CurrentUser,
+    session: Session,
+    State(db): State<DynDB>,
+    Passkeys(webauthn): Passkeys,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Exclude the authenticators the user has already registered
+    let exclude_credentials = db
+        .get_user_passkeys(&user.user_id)
+        .await?
+        .iter()
+        .map(|passkey| passkey.cred_id().clone())
+        .collect();
+
+    // Issue the challenge, keeping the registration state in the session
+    let (challenge, state) = webauthn
+        .start_passkey_registration(
+            user.user_id,
+            &user.username,
+            &user.name,
+            Some(exclude_credentials),
+        )
+        .map_err(anyhow::Error::from)?;
+    session.insert(PASSKEY_REGISTRATION_KEY, state).await?;
+
+    Ok(Json(challenge))
+}
+
+/// Handler that emails the user a link they can log in with.
+#[instrument(skip_all, err)]
+pub(crate) async fn request_magic_link(
+    messages: Messages,
+    State(db): State<DynDB>,
+    State(notifications_manager): State<DynNotificationsManager>,
+    State(server_cfg): State<HttpServerConfig>,
+    Query(NextUrl { next_url }): Query<NextUrl>,
+    Form(input): Form<MagicLinkRequest>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Sanitize next url
+    let next_url = sanitize_next_url(next_url.as_deref());
+    let log_in_url = get_log_in_url(next_url.as_deref());
+
+    // Validate form
+    if let Err(e) = input.validate() {
+        messages.error(e.to_string());
+        return Ok(Redirect::to(&log_in_url));
+    }
+
+    // Store the link, which is only issued to verified users
+    let token = Uuid::new_v4().simple().to_string();
+    let token_hash = compute_hash(token.as_bytes());
+    if let Some(user_id) = db.add_user_magic_link(&input.email, &token_hash).await? {
+        let notification =
+            build_magic_link_notification(&db, &server_cfg, user_id, &token, next_url.as_deref())
+                .await?;
+        notifications_manager.enqueue(&notification).await?;
+    }
+
+    // Do not disclose whether the email belongs to an account
+    messages
+        .success("If the email belongs to an account, you will receive a link to log in shortly.");
+    Ok(Redirect::to(&log_in_url))
+}
+
+/// Handler that emails the user a new link to verify their email address.
+#[instrument(skip_all, err)]
+pub(crate) async fn resend_email_verification(
+    messages: Messages,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Query(NextUrl { next_url }): Query<NextUrl>,
+    headers: HeaderMap,
+    Form(input): Form<ResendEmailVerificationInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Sanitize next url
+    let next_url = sanitize_next_url(next_url.as_deref());
+    let log_in_url = get_log_in_url(next_url.as_deref());
+
+    // Validate form
+    if let Err(e) = input.validate() {
+        messages.error(e.to_string());
+        return Ok(Redirect::to(&log_in_url));
+    }
+
+    // Replace the verification code, which is only issued to unverified users
+    let language = get_request_language(&headers);
+    let verification = build_email_verification_notification(&db, &server_cfg, language).await?;
+    db.resend_email_verification(&input.email, &verification).await?;
+
+    // Do not disclose whether the email belongs to an account
+    messages.success(
+        "If the email belongs to an account pending verification, you will receive a new verification link shortly.",
+    );
+    Ok(Redirect::to(&log_in_url))
+}
+
+/// Handler that sets a new password using a password reset link.
+#[instrument(skip_all, err)]
+pub(crate) async fn reset_password(
+    messages: Messages,
+    State(db): State<DynDB>,
+    Path(code): Path<String>,
+    Form(input): Form<ResetPasswordInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Validate form
+    if let Err(e) = input.validate() {
+        messages.error(e.to_string());
+        return Ok(Redirect::to(&format!("/reset-password/{code}")));
+    }
+
+    // Consume the reset code and update the user's password
+    let password = password_auth::generate_hash(&input.password);
+    let code_hash = compute_hash(code.as_bytes());
+    if db.reset_user_password(&code_hash, &password).await?.is_none() {
+        messages.error(concat!(
+            "Invalid password reset link (please note that links are only valid for 1 hour ",
+            "and can only be used once)."
+        ));
+        return Ok(Redirect::to(FORGOT_PASSWORD_URL));
+    }
+
+    messages.success("Your password has been reset. You can now log in using your new password.");
+    Ok(Redirect::to(LOG_IN_URL))
+}
+
+/// Handler that revokes one of the user's sessions.
+#[instrument(skip_all, err)]
+pub(crate) async fn revoke_user_session(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    Path(session_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Revoke session in database
+    db.revoke_user_session(&user.user_id, &session_id).await?;
+    messages.success("Session revoked successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Handler that revokes all the user's sessions, logging them out everywhere.
+#[instrument(skip_all, err)]
+pub(crate) async fn revoke_user_sessions(
+    CurrentUser(user): CurrentUser,
+    mut auth_session: AuthSession,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Revoke sessions in database, including the current one
+    db.revoke_user_sessions(&user.user_id).await?;
+    auth_session
+        .logout()
+        .await
+        .map_err(|e| HandlerError::Auth(e.to_string()))?;
+
+    Ok((StatusCode::OK, [("HX-Redirect", LOG_IN_URL)]).into_response())
+}
+
 /// Handler that signs up a new user.
 #[instrument(skip_all)]
 pub(crate) async fn sign_up(
     messages: Messages,
     State(db): State<DynDB>,
     State(server_cfg): State<HttpServerConfig>,
+    ClientIp(client_ip): ClientIp,
     Query(query): Query<HashMap<String, String>>,
-    Form(mut user_summary): Form<auth::UserSummary>,
+    headers: HeaderMap,
+    Form(input): Form<SignUpInput>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Sanitize next url
     let next_url = sanitize_next_url(query.get("next_url").map(String::as_str));
 
+    // Reject password sign ups when the community requires its SSO provider
+    if get_required_oidc_provider(&db, next_url.as_deref()).await?.is_some() {
+        messages.error(SSO_REQUIRED_MESSAGE);
+        return Ok(get_sign_up_url(next_url.as_deref()).into_response());
+    }
+
     // Validate form
+    let mut user_summary = input.user;
     if let Err(e) = user_summary.validate() {
         messages.error(e.to_string());
         return Ok(get_sign_up_url(next_url.as_deref()).into_response());
     }
 
+    // Verify the CAPTCHA challenge, unless checks are disabled
+    if let Some(captcha_cfg) = &server_cfg.captcha
+        && !server_cfg.disable_captcha_checks
+    {
+        let response = input.captcha_response.unwrap_or_default();
+        match captcha::verify(captcha_cfg, &response, client_ip).await {
+            Ok(true) => {}
+            Ok(false) => {
+                messages.error(CAPTCHA_FAILED_MESSAGE);
+                return Ok(get_sign_up_url(next_url.as_deref()).into_response());
+            }
+            Err(err) => {
+                error!(?err, "error verifying captcha response");
+                messages.error("Something went wrong while signing up. Please try again later.");
+                return Ok(get_sign_up_url(next_url.as_deref()).into_response());
+            }
+        }
+    }
+
+    // Check the email domain is allowed by the community signing up from, if any
+    if let Some(community_name) = community_name_from_next_url(next_url.as_deref())
+        && !db
+            .community_email_domain_allowed(&community_name, &user_summary.email)
+            .await?
+    {
+        messages.error(EMAIL_DOMAIN_NOT_ALLOWED_MESSAGE);
+        return Ok(get_sign_up_url(next_url.as_deref()).into_response());
+    }
+
     // Check if the password has been provided
     let Some(password) = user_summary.password.take() else {
         return Ok((StatusCode::BAD_REQUEST, "password not provided").into_response());
@@ -377,8 +1172,11 @@ pub(crate) async fn sign_up(
     // Generate password hash
     user_summary.password = Some(password_auth::generate_hash(&password));
 
-    // Prepare the required email verification notification before mutating users
-    let Ok(verification) = build_email_verification_notification(&db, &server_cfg).await else {
+    // Prepare the required email verification notification before mutating users,
+    // in the language requested by the browser as the user has no preference yet
+    let language = get_request_language(&headers);
+    let Ok(verification) = build_email_verification_notification(&db, &server_cfg, language).await
+    else {
         messages.error("Something went wrong while signing up. Please try again later.");
         return Ok(Redirect::to(SIGN_UP_URL).into_response());
     };
@@ -408,6 +1206,49 @@ pub(crate) async fn sign_up(
     Ok(Redirect::to(&log_in_url).into_response())
 }
 
+/// Handler that unlinks an external provider from the user's account.
+#[instrument(skip_all, err)]
+pub(crate) async fn unlink_user_provider(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    Path(provider): Path<String>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Check the provider is one users can link
+    if ![
+        OAuth2Provider::GitHub.as_ref(),
+        OAuth2Provider::GitLab.as_ref(),
+        OAuth2Provider::Google.as_ref(),
+        OidcProvider::LinuxFoundation.as_ref(),
+    ]
+    .contains(&provider.as_str())
+    {
+        return Ok((StatusCode::BAD_REQUEST, "invalid provider").into_response());
+    }
+
+    // Unlink provider in database
+    db.unlink_user_provider(&user.user_id, &provider).await?;
+    messages.success("Account unlinked successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Handler that enables or disables the user's personal calendar feed.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_user_calendar_feed(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    ValidatedForm(input): ValidatedForm<UserCalendarFeedUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Update calendar feed in database
+    db.update_user_calendar_feed(&user.user_id, input.calendar_feed_enabled)
+        .await?;
+    messages.success("Calendar feed updated successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
 /// Handler that updates the user's details.
 #[instrument(skip_all, err)]
 pub(crate) async fn update_user_details(
@@ -450,6 +1291,69 @@ pub(crate) async fn update_user_password(
     Ok(Redirect::to(LOG_OUT_URL).into_response())
 }
 
+/// Handler that updates the phone number the user's SMS notifications are sent to.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_user_phone_number(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    State(sms_cfg): State<Option<SmsConfig>>,
+    ValidatedForm(phone): ValidatedForm<UserPhoneNumberUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Verification codes cannot be sent when SMS notifications are not configured
+    if sms_cfg.is_none() {
+        return Err(HandlerError::NotFound);
+    }
+
+    // Update phone number in database, which sends a verification code when set
+    db.update_user_phone_number(&user.user_id, &phone).await?;
+    if phone.phone_number.is_some() {
+        messages.success("We sent you a code by SMS to verify your phone number.");
+    } else {
+        messages.success("Phone number removed successfully.");
+    }
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Handler that enables or disables the user's SMS notifications.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_user_sms_notifications(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    ValidatedForm(input): ValidatedForm<UserSmsNotificationsUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Update SMS notifications preference in database
+    db.update_user_sms_notifications(&user.user_id, input.sms_notifications_enabled)
+        .await?;
+    messages.success("SMS notifications updated successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Handler that updates the webhook the user's notifications are posted to.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_user_webhook(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    ValidatedForm(webhook): ValidatedForm<UserWebhookUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Make sure the webhook host does not resolve to internal addresses
+    if let Some(webhook_url) = &webhook.webhook_url
+        && let Err(err) = check_webhook_url_host(webhook_url).await
+    {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response());
+    }
+
+    // Update webhook in database
+    db.update_user_webhook(&user.user_id, &webhook).await?;
+    messages.success("Webhook updated successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
 /// Handler that verifies the user's email.
 #[instrument(skip_all, err)]
 pub(crate) async fn verify_email(
@@ -467,6 +1371,27 @@ pub(crate) async fn verify_email(
     Ok(Redirect::to(LOG_IN_URL))
 }
 
+/// Handler that verifies the user's phone number using the code sent by SMS.
+#[instrument(skip_all, err)]
+pub(crate) async fn verify_user_phone_number(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    ValidatedForm(verification): ValidatedForm<UserPhoneVerification>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Verify the phone number in database
+    if !db.verify_user_phone_number(&user.user_id, &verification).await? {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "invalid or expired verification code",
+        )
+            .into_response());
+    }
+    messages.success("Phone number verified successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
 // Auth callback helpers.
 
 #[async_trait]
@@ -475,6 +1400,7 @@ trait CallbackAuth {
         &mut self,
         code: String,
         provider: OAuth2Provider,
+        sign_up_community: Option<String>,
     ) -> Result<Option<auth::User>, String>;
 
     async fn authenticate_oidc(
@@ -482,8 +1408,24 @@ trait CallbackAuth {
         code: String,
         nonce: oidc::Nonce,
         provider: OidcProvider,
+        sign_up_community: Option<String>,
     ) -> Result<Option<auth::User>, String>;
 
+    async fn link_oauth2(
+        &mut self,
+        user_id: &Uuid,
+        code: String,
+        provider: OAuth2Provider,
+    ) -> Result<(), String>;
+
+    async fn link_oidc(
+        &mut self,
+        user_id: &Uuid,
+        code: String,
+        nonce: oidc::Nonce,
+        provider: OidcProvider,
+    ) -> Result<(), String>;
+
     async fn log_in(&mut self, user: &auth::User) -> Result<(), HandlerError>;
 }
 
@@ -493,10 +1435,15 @@ impl CallbackAuth for AuthSession {
         &mut self,
         code: String,
         provider: OAuth2Provider,
+        sign_up_community: Option<String>,
     ) -> Result<Option<auth::User>, String> {
-        self.authenticate(Credentials::OAuth2(OAuth2Credentials { code, provider }))
-            .await
-            .map_err(|e| e.to_string())
+        self.authenticate(Credentials::OAuth2(OAuth2Credentials {
+            code,
+            provider,
+            sign_up_community,
+        }))
+        .await
+        .map_err(|e| e.to_string())
     }
 
     async fn authenticate_oidc(
@@ -504,24 +1451,70 @@ impl CallbackAuth for AuthSession {
         code: String,
         nonce: oidc::Nonce,
         provider: OidcProvider,
+        sign_up_community: Option<String>,
     ) -> Result<Option<auth::User>, String> {
         self.authenticate(Credentials::Oidc(OidcCredentials {
             code,
             nonce,
             provider,
+            sign_up_community,
         }))
         .await
         .map_err(|e| e.to_string())
     }
 
+    async fn link_oauth2(
+        &mut self,
+        user_id: &Uuid,
+        code: String,
+        provider: OAuth2Provider,
+    ) -> Result<(), String> {
+        ensure_logged_in_as(self, user_id)?;
+        self.backend
+            .link_oauth2_identity(
+                user_id,
+                OAuth2Credentials {
+                    code,
+                    provider,
+                    sign_up_community: None,
+                },
+            )
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn link_oidc(
+        &mut self,
+        user_id: &Uuid,
+        code: String,
+        nonce: oidc::Nonce,
+        provider: OidcProvider,
+    ) -> Result<(), String> {
+        ensure_logged_in_as(self, user_id)?;
+        self.backend
+            .link_oidc_identity(
+                user_id,
+                OidcCredentials {
+                    code,
+                    nonce,
+                    provider,
+                    sign_up_community: None,
+                },
+            )
+            .await
+            .map_err(|e| e.to_string())
+    }
+
     async fn log_in(&mut self, user: &auth::User) -> Result<(), HandlerError> {
         self.login(user).await.map_err(|e| HandlerError::Auth(e.to_string()))
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn oauth2_callback_with_auth<A, F>(
     auth: &mut A,
     session: Session,
+    client: SessionClient,
     db: &DynDB,
     provider: OAuth2Provider,
     code: String,
@@ -553,13 +1546,29 @@ where
         .and_then(|value| sanitize_next_url(Some(value.as_str())));
     let log_in_url = get_log_in_url(next_url.as_deref());
 
+    // Link the identity to the account that requested it instead of logging in
+    if let Some(user_id) = session.remove::<Uuid>(LINK_IDENTITY_USER_ID_KEY).await? {
+        if let Err(err) = auth.link_oauth2(&user_id, code, provider).await {
+            on_error(identity_link_error_message(&err));
+        }
+        return Ok(Redirect::to(USER_DASHBOARD_ACCOUNT_URL));
+    }
+
     // Authenticate user
-    let user = match auth.authenticate_oauth2(code, provider.clone()).await {
+    let sign_up_community = community_name_from_next_url(next_url.as_deref());
+    let user = match auth
+        .authenticate_oauth2(code, provider.clone(), sign_up_community)
+        .await
+    {
         Ok(Some(user)) => user,
         Ok(None) => {
             on_error(OAUTH2_AUTHORIZATION_FAILED.to_string());
             return Ok(Redirect::to(&log_in_url));
         }
+        Err(err) if err.contains(auth::EXTERNAL_AUTH_EMAIL_DOMAIN_NOT_ALLOWED_ERROR) => {
+            on_error(EMAIL_DOMAIN_NOT_ALLOWED_MESSAGE.to_string());
+            return Ok(Redirect::to(&log_in_url));
+        }
         Err(err) => {
             on_error(format!("{OAUTH2_AUTHORIZATION_FAILED}: {err}"));
             return Ok(Redirect::to(&log_in_url));
@@ -575,13 +1584,21 @@ where
     // Track auth provider in the session
     track_auth_provider(&session, provider.as_ref()).await?;
 
+    // Track the client used to log in
+    track_session_client(&session, client).await?;
+
+    // Track the lifetime selected for the session
+    track_session_lifetime(&session, true).await?;
+
     let next_url = next_url.as_deref().unwrap_or("/");
     Ok(Redirect::to(next_url))
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn oidc_callback_with_auth<A, F>(
     auth: &mut A,
     session: Session,
+    client: SessionClient,
     db: &DynDB,
     provider: OidcProvider,
     code: String,
@@ -619,8 +1636,20 @@ where
         .and_then(|value| sanitize_next_url(Some(value.as_str())));
     let log_in_url = get_log_in_url(next_url.as_deref());
 
+    // Link the identity to the account that requested it instead of logging in
+    if let Some(user_id) = session.remove::<Uuid>(LINK_IDENTITY_USER_ID_KEY).await? {
+        if let Err(err) = auth.link_oidc(&user_id, code, nonce, provider).await {
+            on_error(identity_link_error_message(&err));
+        }
+        return Ok(Redirect::to(USER_DASHBOARD_ACCOUNT_URL));
+    }
+
     // Authenticate user
-    let user = match auth.authenticate_oidc(code, nonce, provider.clone()).await {
+    let sign_up_community = community_name_from_next_url(next_url.as_deref());
+    let user = match auth
+        .authenticate_oidc(code, nonce, provider.clone(), sign_up_community)
+        .await
+    {
         Ok(Some(user)) => user,
         Ok(None) => {
             on_error(OIDC_AUTHORIZATION_FAILED.to_string());
@@ -641,12 +1670,26 @@ where
     // Track auth provider in the session
     track_auth_provider(&session, provider.as_ref()).await?;
 
+    // Track the client used to log in
+    track_session_client(&session, client).await?;
+
+    // Track the lifetime selected for the session
+    track_session_lifetime(&session, true).await?;
+
     let next_url = next_url.as_deref().unwrap_or("/");
     Ok(Redirect::to(next_url))
 }
 
 // Types.
 
+/// Input for requesting a password reset link.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct ForgotPasswordInput {
+    /// Email address of the user's account.
+    #[garde(email)]
+    pub email: String,
+}
+
 /// Login form data from the user.
 #[derive(Debug, Deserialize, Validate)]
 pub(crate) struct LoginForm {
@@ -656,10 +1699,78 @@ pub(crate) struct LoginForm {
     /// Username for authentication.
     #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_S))]
     pub username: String,
+
+    /// Whether to keep the user signed in across browser restarts.
+    #[garde(skip)]
+    #[serde(default)]
+    pub remember_me: bool,
+}
+
+/// Input for requesting a magic link.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct MagicLinkRequest {
+    /// Email address of the user logging in.
+    #[garde(email)]
+    pub email: String,
+}
+
+/// Input for starting a passkey log in.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct PasskeyLogInInput {
+    /// Username of the user signing in.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_S))]
+    pub username: String,
+}
+
+/// Passkey authentication pending completion, stored in the session.
+#[derive(Deserialize, Serialize)]
+struct PendingPasskeyAuthentication {
+    /// Authentication state created when the challenge was issued.
+    state: PasskeyAuthentication,
+    /// User the challenge was issued for.
+    user_id: Uuid,
+}
+
+/// Input for requesting a new email verification link.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct ResendEmailVerificationInput {
+    /// Email address of the user's account.
+    #[garde(email)]
+    pub email: String,
+}
+
+/// Input for setting a new password using a password reset link.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct ResetPasswordInput {
+    /// New password to set.
+    #[garde(length(min = MIN_PASSWORD_LEN, max = MAX_LEN_S))]
+    pub password: String,
+}
+
+/// Sign up form data from the user.
+#[derive(Deserialize)]
+pub(crate) struct SignUpInput {
+    /// Details of the user signing up.
+    #[serde(flatten)]
+    pub user: auth::UserSummary,
+
+    /// Response to the CAPTCHA challenge, when enabled.
+    #[serde(alias = "cf-turnstile-response", alias = "h-captcha-response")]
+    pub captcha_response: Option<String>,
 }
 
 // Deserialization helpers.
 
+/// Google Calendar authorization response containing code and CSRF state.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GoogleCalendarAuthorizationResponse {
+    /// CSRF state returned by Google.
+    state: String,
+
+    /// Authorization code returned by Google (missing when access is denied).
+    code: Option<String>,
+}
+
 /// `OAuth2` authorization response containing code and CSRF state.
 #[derive(Debug, Clone, Deserialize)]
 pub struct OAuth2AuthorizationResponse {
@@ -684,6 +1795,7 @@ pub(crate) struct NextUrl {
 pub(crate) async fn user_has_community_dashboard_permission(
     State(db): State<DynDB>,
     mut auth_session: AuthSession,
+    messages: Messages,
     session: Session,
     request: Request,
     next: Next,
@@ -721,6 +1833,22 @@ pub(crate) async fn user_has_community_dashboard_permission(
         };
     }
 
+    // Require the sign-in method the community enforces (if any)
+    match enforce_community_sign_in_requirements(
+        &db,
+        &mut auth_session,
+        messages,
+        &session,
+        request.headers(),
+        &community_id,
+    )
+    .await
+    {
+        Ok(Some(response)) => return response,
+        Ok(None) => {}
+        Err(error) => return error.into_response(),
+    }
+
     // Store selected community context for downstream extractors
     let mut request = request;
     request.extensions_mut().insert(SelectedCommunityId(community_id));
@@ -733,18 +1861,20 @@ pub(crate) async fn user_has_community_dashboard_permission(
 pub(crate) async fn user_has_path_community_permission(
     State((db, permission)): State<(DynDB, CommunityPermission)>,
     Path(community_id): Path<Uuid>,
-    auth_session: AuthSession,
+    mut auth_session: AuthSession,
+    messages: Messages,
+    session: Session,
     request: Request,
     next: Next,
 ) -> impl IntoResponse {
     // Require an authenticated user
-    let Some(user) = auth_session.user else {
+    let Some(user_id) = auth_session.user.as_ref().map(|user| user.user_id) else {
         return StatusCode::FORBIDDEN.into_response();
     };
 
     // Check required permission against the community id from the path
     let Ok(has_permission) = db
-        .user_has_community_permission(&community_id, &user.user_id, permission)
+        .user_has_community_permission(&community_id, &user_id, permission)
         .await
     else {
         return StatusCode::INTERNAL_SERVER_ERROR.into_response();
@@ -753,6 +1883,22 @@ pub(crate) async fn user_has_path_community_permission(
         return StatusCode::FORBIDDEN.into_response();
     }
 
+    // Require the sign-in method the community enforces (if any)
+    match enforce_community_sign_in_requirements(
+        &db,
+        &mut auth_session,
+        messages,
+        &session,
+        request.headers(),
+        &community_id,
+    )
+    .await
+    {
+        Ok(Some(response)) => return response,
+        Ok(None) => {}
+        Err(error) => return error.into_response(),
+    }
+
     next.run(request).await.into_response()
 }
 
@@ -807,6 +1953,7 @@ pub(crate) async fn user_has_path_group_permission(
 pub(crate) async fn user_has_selected_community_permission(
     State((db, permission)): State<(DynDB, CommunityPermission)>,
     mut auth_session: AuthSession,
+    messages: Messages,
     session: Session,
     request: Request,
     next: Next,
@@ -858,6 +2005,22 @@ pub(crate) async fn user_has_selected_community_permission(
         };
     }
 
+    // Require the sign-in method the community enforces (if any)
+    match enforce_community_sign_in_requirements(
+        &db,
+        &mut auth_session,
+        messages,
+        &session,
+        request.headers(),
+        &community_id,
+    )
+    .await
+    {
+        Ok(Some(response)) => return response,
+        Ok(None) => {}
+        Err(error) => return error.into_response(),
+    }
+
     // Store selected community context for downstream extractors
     let mut request = request;
     request.extensions_mut().insert(SelectedCommunityId(community_id));
@@ -865,15 +2028,98 @@ pub(crate) async fn user_has_selected_community_permission(
     next.run(request).await.into_response()
 }
 
+/// Check if the user has a specific group permission in the selected group,
+/// or is granted it as a host of the event in the request path.
+#[instrument(skip_all)]
+pub(crate) async fn user_has_selected_group_event_permission(
+    State((db, permission)): State<(DynDB, GroupPermission)>,
+    Path(params): Path<HashMap<String, String>>,
+    auth_session: AuthSession,
+    session: Session,
+    request: Request,
+    next: Next,
+) -> impl IntoResponse {
+    // Resolve the event from the request path
+    let Some(Ok(event_id)) = params.get("event_id").map(|id| id.parse::<Uuid>()) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    authorize_selected_group_request(
+        db,
+        permission,
+        Some(event_id),
+        auth_session,
+        session,
+        request,
+        next,
+    )
+    .await
+}
+
 /// Check if the user has a specific group permission in the selected group.
 #[instrument(skip_all)]
 pub(crate) async fn user_has_selected_group_permission(
     State((db, permission)): State<(DynDB, GroupPermission)>,
-    mut auth_session: AuthSession,
+    auth_session: AuthSession,
     session: Session,
     request: Request,
     next: Next,
 ) -> impl IntoResponse {
+    authorize_selected_group_request(db, permission, None, auth_session, session, request, next)
+        .await
+}
+
+// Session middleware.
+
+/// Keeps the session cookie expiry in line with the lifetime selected when
+/// logging in every time the session is saved.
+pub(crate) async fn apply_session_lifetime(
+    State(server_cfg): State<HttpServerConfig>,
+    session: Session,
+    request: Request,
+    next: Next,
+) -> Response {
+    let response = next.run(request).await;
+
+    if session.is_modified()
+        && let Ok(Some(lifetime)) =
+            session.get::<auth::SessionLifetime>(auth::SESSION_LIFETIME_KEY).await
+    {
+        session.set_expiry(Some(lifetime.expiry(&server_cfg.session)));
+    }
+
+    response
+}
+
+// Helpers.
+
+/// Returns the log in options available, limited to the OIDC provider required
+/// by the community when there is one.
+fn available_login_options(
+    login: &LoginOptions,
+    required_oidc_provider: Option<&OidcProvider>,
+) -> LoginOptions {
+    match required_oidc_provider {
+        Some(OidcProvider::LinuxFoundation) => LoginOptions {
+            linuxfoundation: login.linuxfoundation,
+            ..Default::default()
+        },
+        None => login.clone(),
+    }
+}
+
+/// Authorizes a request against the selected group, repairing the selected
+/// dashboard context when needed. When an event is provided, its hosts are
+/// granted the permissions available to them.
+async fn authorize_selected_group_request(
+    db: DynDB,
+    permission: GroupPermission,
+    event_id: Option<Uuid>,
+    mut auth_session: AuthSession,
+    session: Session,
+    request: Request,
+    next: Next,
+) -> Response {
     // Require an authenticated user
     let Some(user_id) = auth_session.user.as_ref().map(|user| user.user_id) else {
         return StatusCode::FORBIDDEN.into_response();
@@ -906,23 +2152,30 @@ pub(crate) async fn user_has_selected_group_permission(
     };
 
     // Check required permission in the selected group
-    let Ok(has_permission) = db
-        .user_has_group_permission(&community_id, &group_id, &user_id, permission)
-        .await
+    let Ok(has_permission) = has_group_permission(
+        &db,
+        &community_id,
+        &group_id,
+        event_id.as_ref(),
+        &user_id,
+        permission,
+    )
+    .await
     else {
         return StatusCode::INTERNAL_SERVER_ERROR.into_response();
     };
     if !has_permission {
         // Missing write permission is a normal 403 when base Read still works
         if permission != GroupPermission::Read {
-            let Ok(has_read_permission) = db
-                .user_has_group_permission(
-                    &community_id,
-                    &group_id,
-                    &user_id,
-                    GroupPermission::Read,
-                )
-                .await
+            let Ok(has_read_permission) = has_group_permission(
+                &db,
+                &community_id,
+                &group_id,
+                event_id.as_ref(),
+                &user_id,
+                GroupPermission::Read,
+            )
+            .await
             else {
                 return StatusCode::INTERNAL_SERVER_ERROR.into_response();
             };
@@ -948,12 +2201,11 @@ pub(crate) async fn user_has_selected_group_permission(
     next.run(request).await.into_response()
 }
 
-// Helpers.
-
 /// Builds the email verification notification payload required by password signup.
 async fn build_email_verification_notification(
     db: &DynDB,
     server_cfg: &HttpServerConfig,
+    language: Language,
 ) -> Result<EmailVerificationNotification, HandlerError> {
     // Prepare verification link inputs before loading template context
     let code = Uuid::new_v4();
@@ -969,6 +2221,8 @@ async fn build_email_verification_notification(
     let template_data = EmailVerification {
         link: format!("{base_url}/verify-email/{code}"),
         theme: site_settings.theme,
+
+        language,
     };
 
     // Return the database-ready verification notification payload
@@ -978,11 +2232,135 @@ async fn build_email_verification_notification(
     })
 }
 
+/// Builds the notification with the link a user can log in with.
+async fn build_magic_link_notification(
+    db: &DynDB,
+    server_cfg: &HttpServerConfig,
+    user_id: Uuid,
+    token: &str,
+    next_url: Option<&str>,
+) -> Result<NewNotification, HandlerError> {
+    // Prepare the link, keeping the page to return to after logging in
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let mut link = format!("{base_url}/log-in/magic-link/{token}");
+    if let Some(next_url) = next_url {
+        link = format!("{link}?next_url={}", encode_next_url(next_url));
+    }
+
+    // Build template data from the current site theme
+    let site_settings = db.get_site_settings().await?;
+    let template_data = MagicLink {
+        link,
+        theme: site_settings.theme,
+
+        language: Language::default(),
+    };
+
+    Ok(NewNotification {
+        attachments: vec![],
+        kind: NotificationKind::MagicLink,
+        recipients: vec![user_id],
+        template_data: Some(serde_json::to_value(&template_data)?),
+    })
+}
+
+/// Builds the notification with the link a user can reset their password with.
+async fn build_password_reset_notification(
+    db: &DynDB,
+    server_cfg: &HttpServerConfig,
+    user_id: Uuid,
+    code: &str,
+) -> Result<NewNotification, HandlerError> {
+    // Build template data from the current site theme
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let site_settings = db.get_site_settings().await?;
+    let template_data = PasswordReset {
+        link: format!("{base_url}/reset-password/{code}"),
+        theme: site_settings.theme,
+
+        language: Language::default(),
+    };
+
+    Ok(NewNotification {
+        attachments: vec![],
+        kind: NotificationKind::PasswordReset,
+        recipients: vec![user_id],
+        template_data: Some(serde_json::to_value(&template_data)?),
+    })
+}
+
+/// Returns the name of the community a `next_url` points to, if any. Community
+/// pages are served under `/{community}`, so this is the first path segment.
+fn community_name_from_next_url(next_url: Option<&str>) -> Option<String> {
+    let next_url = sanitize_next_url(next_url)?;
+    next_url
+        .strip_prefix('/')?
+        .split(['/', '?', '#'])
+        .next()
+        .filter(|segment| !segment.is_empty())
+        .map(ToString::to_string)
+}
+
 /// Percent-encode a `next_url` so it can be safely embedded in a query string.
 fn encode_next_url(next_url: &str) -> String {
     utf8_percent_encode(next_url, NON_ALPHANUMERIC).to_string()
 }
 
+/// Checks that the session belongs to the user linking an external identity.
+fn ensure_logged_in_as(auth_session: &AuthSession, user_id: &Uuid) -> Result<(), String> {
+    if auth_session.user.as_ref().map(|user| user.user_id) == Some(*user_id) {
+        return Ok(());
+    }
+    Err("user linking the identity is not logged in".to_string())
+}
+
+/// Signs the user out when the community requires passkey sign-in or its
+/// single sign-on provider and the current session was authenticated by other
+/// means, returning the response that sends them to the log in page.
+async fn enforce_community_sign_in_requirements(
+    db: &DynDB,
+    auth_session: &mut AuthSession,
+    messages: Messages,
+    session: &Session,
+    headers: &HeaderMap,
+    community_id: &Uuid,
+) -> Result<Option<Response>, HandlerError> {
+    let auth_provider: Option<String> = session.get(AUTH_PROVIDER_KEY).await?;
+
+    // Ask the user to sign in again using a passkey when required
+    if auth_provider.as_deref() != Some(AUTH_PROVIDER_PASSKEY)
+        && db.community_passkey_required(community_id).await?
+    {
+        auth_session
+            .logout()
+            .await
+            .map_err(|e| HandlerError::Auth(e.to_string()))?;
+        messages.error(PASSKEY_REQUIRED_MESSAGE);
+        return Ok(Some(redirect_to_log_in_for_request(headers)));
+    }
+
+    // Ask the user to sign in again using the community's provider when required
+    let Some(required_oidc_provider) =
+        db.get_community_required_oidc_provider_by_id(community_id).await?
+    else {
+        return Ok(None);
+    };
+    if auth_provider.as_deref() == Some(required_oidc_provider.as_str()) {
+        return Ok(None);
+    }
+    auth_session
+        .logout()
+        .await
+        .map_err(|e| HandlerError::Auth(e.to_string()))?;
+    messages.error(SSO_REQUIRED_MESSAGE);
+
+    // Point the log in page at the community so only its provider is offered
+    let community_name = db.get_community_name_by_id(*community_id).await?;
+    let log_in_url = get_log_in_url(community_name.map(|name| format!("/{name}")).as_deref());
+
+    Ok(Some(redirect_for_request(headers, &log_in_url)))
+}
+
 /// Get the log in url including the next url if provided.
 fn get_log_in_url(next_url: Option<&str>) -> String {
     let mut log_in_url = LOG_IN_URL.to_string();
@@ -992,6 +2370,31 @@ fn get_log_in_url(next_url: Option<&str>) -> String {
     log_in_url
 }
 
+/// Returns the language requested by the browser, falling back to the default.
+fn get_request_language(headers: &HeaderMap) -> Language {
+    headers
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Language::from_accept_language)
+        .unwrap_or_default()
+}
+
+/// Returns the OIDC provider required by the community the `next_url` points
+/// to, if any.
+async fn get_required_oidc_provider(
+    db: &DynDB,
+    next_url: Option<&str>,
+) -> Result<Option<OidcProvider>, HandlerError> {
+    let Some(community_name) = community_name_from_next_url(next_url) else {
+        return Ok(None);
+    };
+    let required_oidc_provider = db
+        .get_community_required_oidc_provider(&community_name)
+        .await?
+        .and_then(|provider| provider.parse().ok());
+    Ok(required_oidc_provider)
+}
+
 /// Resolves selected community and group IDs from the current session if present.
 async fn get_selected_community_and_group_ids_optional(
     session: &Session,
@@ -1038,6 +2441,35 @@ fn get_sign_up_url(next_url: Option<&str>) -> Redirect {
     Redirect::to(&sign_up_url)
 }
 
+/// Checks whether the user has a group permission, taking into account the
+/// permissions granted to the hosts of the event provided.
+async fn has_group_permission(
+    db: &DynDB,
+    community_id: &Uuid,
+    group_id: &Uuid,
+    event_id: Option<&Uuid>,
+    user_id: &Uuid,
+    permission: GroupPermission,
+) -> anyhow::Result<bool> {
+    match event_id {
+        Some(event_id) => {
+            user_has_group_event_permission(
+                db,
+                community_id,
+                group_id,
+                event_id,
+                user_id,
+                permission,
+            )
+            .await
+        }
+        None => {
+            db.user_has_group_permission(community_id, group_id, user_id, permission)
+                .await
+        }
+    }
+}
+
 /// Returns whether the request came from HTMX.
 fn is_htmx_request(headers: &HeaderMap) -> bool {
     headers
@@ -1067,6 +2499,10 @@ pub(crate) async fn log_out_for_stale_dashboard_context(
 
 /// Formats OIDC authorization errors for user-facing flash messages.
 fn oidc_authorization_error_message(err: &str) -> String {
+    if err.contains(auth::EXTERNAL_AUTH_EMAIL_DOMAIN_NOT_ALLOWED_ERROR) {
+        return EMAIL_DOMAIN_NOT_ALLOWED_MESSAGE.to_string();
+    }
+
     if err.contains(auth::EXTERNAL_AUTH_EMAIL_CONFLICT_ERROR) {
         return LF_SSO_EMAIL_CONFLICT_MESSAGE.to_string();
     }
@@ -1078,20 +2514,34 @@ fn oidc_authorization_error_message(err: &str) -> String {
     format!("OpenID Connect authorization failed: {err}")
 }
 
-/// Builds the log-in redirect response expected by the request type.
-fn redirect_to_log_in_for_request(headers: &HeaderMap) -> Response {
+/// Formats identity linking errors for user-facing flash messages.
+fn identity_link_error_message(err: &str) -> String {
+    if err.contains(auth::EXTERNAL_AUTH_IDENTITY_CONFLICT_ERROR) {
+        return IDENTITY_LINK_CONFLICT_MESSAGE.to_string();
+    }
+
+    IDENTITY_LINK_FAILED_MESSAGE.to_string()
+}
+
+/// Builds the redirect response to the given url expected by the request type.
+fn redirect_for_request(headers: &HeaderMap, url: &str) -> Response {
     // HTMX follows redirects from response headers when swapping fragments
     if is_htmx_request(headers) {
-        return (StatusCode::OK, [("HX-Redirect", LOG_IN_URL)]).into_response();
+        return (StatusCode::OK, [("HX-Redirect", url)]).into_response();
     }
 
     // OCG fetch helpers use redirect metadata for browser navigation
     if is_ocg_fetch_request(headers) {
-        return (StatusCode::UNAUTHORIZED, [("X-OCG-Redirect", LOG_IN_URL)]).into_response();
+        return (StatusCode::UNAUTHORIZED, [("X-OCG-Redirect", url)]).into_response();
     }
 
     // Normal page requests can use a standard redirect response
-    Redirect::to(LOG_IN_URL).into_response()
+    Redirect::to(url).into_response()
+}
+
+/// Builds the log-in redirect response expected by the request type.
+fn redirect_to_log_in_for_request(headers: &HeaderMap) -> Response {
+    redirect_for_request(headers, LOG_IN_URL)
 }
 
 /// Sanitize a `next_url` value ensuring it points to an in-site path.
@@ -1238,3 +2688,48 @@ async fn track_auth_provider(session: &Session, provider: &str) -> Result<(), Ha
     session.insert(AUTH_PROVIDER_KEY, provider).await?;
     Ok(())
 }
+
+/// Stores the lifetime selected for the current login.
+async fn track_session_lifetime(session: &Session, remember: bool) -> Result<(), HandlerError> {
+    session
+        .insert(
+            auth::SESSION_LIFETIME_KEY,
+            auth::SessionLifetime::new(remember),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Stores the details of the client used for the current login.
+async fn track_session_client(
+    session: &Session,
+    client: SessionClient,
+) -> Result<(), HandlerError> {
+    session.insert(SESSION_CLIENT_KEY, client).await?;
+    Ok(())
+}
+
+/// Checks whether the user has a group permission, or is granted it as a host
+/// of the event provided.
+pub(crate) async fn user_has_group_event_permission(
+    db: &DynDB,
+    community_id: &Uuid,
+    group_id: &Uuid,
+    event_id: &Uuid,
+    user_id: &Uuid,
+    permission: GroupPermission,
+) -> anyhow::Result<bool> {
+    // Team members are checked first, as most requests come from them
+    if db
+        .user_has_group_permission(community_id, group_id, user_id, permission)
+        .await?
+    {
+        return Ok(true);
+    }
+
+    // Event hosts are only granted a subset of the group permissions
+    if !permission.granted_to_event_hosts() {
+        return Ok(false);
+    }
+    db.user_is_event_host(community_id, group_id, event_id, user_id).await
+}
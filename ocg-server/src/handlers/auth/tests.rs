@@ -1,19 +1,20 @@
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
 use anyhow::anyhow;
-use axum::extract::Query;
+use axum::extract::{ConnectInfo, Query};
 use axum::{
     Router,
     body::{Body, to_bytes},
     http::{
         HeaderValue, Request, StatusCode,
-        header::{CONTENT_TYPE, COOKIE, HOST, LOCATION},
+        header::{ACCEPT_LANGUAGE, CONTENT_TYPE, COOKIE, HOST, LOCATION, USER_AGENT},
     },
     middleware,
     response::IntoResponse,
     routing::get,
 };
 use axum_login::tower_sessions::session;
+use axum_messages::MessagesManagerLayer;
 use oauth2::{AuthUrl, ClientId, ClientSecret, RedirectUrl, TokenUrl, basic::BasicClient};
 use openidconnect as oidc;
 use serde_json::json;
@@ -23,7 +24,9 @@ use uuid::Uuid;
 
 use crate::{
     auth::{OAuth2ProviderDetails, OidcProviderDetails},
-    config::{HttpServerConfig, LoginOptions, OAuth2Provider, OAuth2ProviderConfig},
+    config::{
+        CaptchaProvider, HttpServerConfig, LoginOptions, OAuth2Provider, OAuth2ProviderConfig,
+    },
     db::{DynDB, mock::MockDB},
     handlers::{
         extractors::{OAuth2, Oidc},
@@ -65,6 +68,45 @@ async fn test_log_in_page_success() {
     assert!(!bytes.is_empty());
 }
 
+#[tokio::test]
+async fn test_log_in_page_limits_options_to_required_oidc_provider() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "sso-community")
+        .returning(|_| Ok(Some("linuxfoundation".to_string())));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    server_cfg.login.linuxfoundation = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/log-in?next_url=%2Fsso-community")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(html.contains("/log-in/oidc/linuxfoundation?next_url=%2Fsso%2Dcommunity"));
+    assert!(!html.contains(r#"name="username""#));
+}
+
 #[tokio::test]
 async fn test_log_in_page_redirects_when_authenticated() {
     // Setup identifiers and data structures
@@ -139,6 +181,41 @@ async fn test_sign_up_page_success() {
     assert!(!bytes.is_empty());
 }
 
+#[tokio::test]
+async fn test_sign_up_page_renders_captcha() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    server_cfg.captcha = Some(sample_captcha_config());
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(SIGN_UP_URL)
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(body.contains(r#"data-sitekey="captcha-site-key""#));
+    assert!(body.contains(CaptchaProvider::Turnstile.script_url()));
+}
+
 #[tokio::test]
 async fn test_sign_up_page_redirects_when_authenticated() {
     // Setup identifiers and data structures
@@ -328,15 +405,11 @@ async fn test_dashboard_group_redirects_to_user_invitations_when_context_is_miss
 }
 
 #[tokio::test]
-async fn test_log_in_success() {
+async fn test_forgot_password_success() {
     // Setup identifiers and data structures
-    let community_id = Uuid::new_v4();
-    let group_id = Uuid::new_v4();
     let session_id = session::Id::default();
-    let user_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let password_hash = password_auth::generate_hash("secret-password");
     let session_record = sample_empty_session_record(session_id);
+    let user_id = Uuid::new_v4();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -344,36 +417,49 @@ async fn test_log_in_success() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_user_by_username()
+    db.expect_add_user_password_reset()
         .times(1)
-        .withf(move |username| username == "test-user")
-        .returning(move |_| {
-            let mut user = sample_auth_user(user_id, &auth_hash);
-            user.password = Some(password_hash.clone());
-            Ok(Some(user))
-        });
-    db.expect_delete_session()
+        .withf(|email, code_hash| email == "test@example.test" && code_hash.len() == 64)
+        .returning(move |_, _| Ok(Some(user_id)));
+    db.expect_get_site_settings()
         .times(1)
-        .withf(move |id| *id == session_id)
-        .returning(|_| Ok(()));
-    db.expect_create_session()
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_update_session()
         .times(1)
         .withf(move |record| {
-            session_record_contains_auth_provider(record, AUTH_PROVIDER_EMAIL)
-                && session_record_contains_selected_group(record, group_id)
+            message_matches(
+                record,
+                "If the email belongs to an account, you will receive a link to reset your password shortly.",
+            )
         })
         .returning(|_| Ok(()));
-    db.expect_list_user_groups()
-        .times(1)
-        .withf(move |uid| uid == &user_id)
-        .returning(move |_| Ok(sample_user_groups_by_community(community_id, group_id)));
 
     // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue()
+        .times(1)
+        .withf(move |notification| {
+            matches!(notification.kind, NotificationKind::PasswordReset)
+                && notification.recipients == vec![user_id]
+                && notification.template_data.as_ref().is_some_and(|data| {
+                    serde_json::from_value::<PasswordReset>(data.clone()).is_ok_and(
+                        |password_reset| {
+                            password_reset.link.starts_with("https://app.example/reset-password/")
+                        },
+                    )
+                })
+        })
+        .returning(|_| Box::pin(async { Ok(()) }));
 
     // Setup router
-    let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.email = true;
+    let server_cfg = HttpServerConfig {
+        base_url: "https://app.example".to_string(),
+        login: LoginOptions {
+            email: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
@@ -382,10 +468,10 @@ async fn test_log_in_success() {
     // Setup request
     let request = Request::builder()
         .method("POST")
-        .uri("/log-in?next_url=%2Fdashboard")
+        .uri("/forgot-password")
         .header(COOKIE, format!("id={session_id}"))
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from("username=test-user&password=secret-password"))
+        .body(Body::from("email=test%40example.test"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -393,16 +479,15 @@ async fn test_log_in_success() {
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
-    assert!(parts.headers.contains_key("set-cookie"));
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/dashboard"),
+        &HeaderValue::from_static(LOG_IN_URL),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_log_in_invalid_credentials() {
+async fn test_forgot_password_unknown_email() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let session_record = sample_empty_session_record(session_id);
@@ -413,22 +498,22 @@ async fn test_log_in_invalid_credentials() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_user_by_username()
+    db.expect_add_user_password_reset()
         .times(1)
-        .withf(move |username| username == "test-user")
-        .returning(|_| Ok(None));
+        .returning(|_, _| Ok(None));
     db.expect_update_session()
         .times(1)
         .withf(move |record| {
             message_matches(
                 record,
-                "Invalid credentials. Please make sure you have verified your email address.",
+                "If the email belongs to an account, you will receive a link to reset your password shortly.",
             )
         })
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
 
     // Setup router
     let mut server_cfg = HttpServerConfig::default();
@@ -441,10 +526,10 @@ async fn test_log_in_invalid_credentials() {
     // Setup request
     let request = Request::builder()
         .method("POST")
-        .uri("/log-in")
+        .uri("/forgot-password")
         .header(COOKIE, format!("id={session_id}"))
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from("username=test-user&password=wrong"))
+        .body(Body::from("email=unknown%40example.test"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -460,9 +545,14 @@ async fn test_log_in_invalid_credentials() {
 }
 
 #[tokio::test]
-async fn test_log_in_validation_error() {
+async fn test_log_in_success() {
     // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
     let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let password_hash = password_auth::generate_hash("secret-password");
     let session_record = sample_empty_session_record(session_id);
 
     // Setup database mock
@@ -471,16 +561,43 @@ async fn test_log_in_validation_error() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_user_by_username().times(0);
-    db.expect_update_session()
+    db.expect_get_community_required_oidc_provider()
         .times(1)
-        .withf(|record| {
-            message_matches(
-                record,
-                "username: value cannot be empty or whitespace-only\n",
-            )
+        .withf(|community_name| community_name == "dashboard")
+        .returning(|_| Ok(None));
+    db.expect_is_log_in_locked()
+        .times(1)
+        .withf(|username, ip_address| username == "test-user" && ip_address.is_none())
+        .returning(|_, _| Ok(false));
+    db.expect_get_user_by_username()
+        .times(1)
+        .withf(move |username| username == "test-user")
+        .returning(move |_| {
+            let mut user = sample_auth_user(user_id, &auth_hash);
+            user.password = Some(password_hash.clone());
+            Ok(Some(user))
+        });
+    db.expect_reset_failed_log_ins()
+        .times(1)
+        .withf(|username| username == "test-user")
+        .returning(|_| Ok(()));
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
+    db.expect_create_session()
+        .times(1)
+        .withf(move |record| {
+            session_record_contains_auth_provider(record, AUTH_PROVIDER_EMAIL)
+                && session_record_contains_selected_group(record, group_id)
+                && session_record_contains_session_lifetime(record, false)
+                && session_record_contains_user_agent(record, "test-browser")
         })
         .returning(|_| Ok(()));
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_groups_by_community(community_id, group_id)));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -493,13 +610,14 @@ async fn test_log_in_validation_error() {
         .build()
         .await;
 
-    // Setup request (username is whitespace only - validation should fail)
+    // Setup request
     let request = Request::builder()
         .method("POST")
-        .uri("/log-in")
+        .uri("/log-in?next_url=%2Fdashboard")
         .header(COOKIE, format!("id={session_id}"))
+        .header(USER_AGENT, "test-browser")
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from("username=+++&password=secret"))
+        .body(Body::from("username=test-user&password=secret-password"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -507,20 +625,25 @@ async fn test_log_in_validation_error() {
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    let cookie = parts.headers.get("set-cookie").unwrap().to_str().unwrap();
+    assert!(!cookie.contains("Max-Age"));
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
+        &HeaderValue::from_static("/dashboard"),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_log_out_success() {
+async fn test_log_in_success_remember_me() {
     // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let password_hash = password_auth::generate_hash("secret-password");
+    let session_record = sample_empty_session_record(session_id);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -528,26 +651,65 @@ async fn test_log_out_success() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_user_by_id()
+    db.expect_get_community_required_oidc_provider()
         .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+        .withf(|community_name| community_name == "dashboard")
+        .returning(|_| Ok(None));
+    db.expect_is_log_in_locked()
+        .times(1)
+        .withf(|username, ip_address| username == "test-user" && ip_address.is_none())
+        .returning(|_, _| Ok(false));
+    db.expect_get_user_by_username()
+        .times(1)
+        .withf(move |username| username == "test-user")
+        .returning(move |_| {
+            let mut user = sample_auth_user(user_id, &auth_hash);
+            user.password = Some(password_hash.clone());
+            Ok(Some(user))
+        });
+    db.expect_reset_failed_log_ins()
+        .times(1)
+        .withf(|username| username == "test-user")
+        .returning(|_| Ok(()));
     db.expect_delete_session()
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(|_| Ok(()));
+    db.expect_create_session()
+        .times(1)
+        .withf(move |record| {
+            session_record_contains_auth_provider(record, AUTH_PROVIDER_EMAIL)
+                && session_record_contains_selected_group(record, group_id)
+                && session_record_contains_session_lifetime(record, true)
+                && session_record_contains_user_agent(record, "test-browser")
+        })
+        .returning(|_| Ok(()));
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_groups_by_community(community_id, group_id)));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
-    // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
     let request = Request::builder()
-        .method("GET")
-        .uri(LOG_OUT_URL)
-        .header(HOST, "example.test")
+        .method("POST")
+        .uri("/log-in?next_url=%2Fdashboard")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(USER_AGENT, "test-browser")
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(
+            "username=test-user&password=secret-password&remember_me=true",
+        ))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -555,38 +717,75 @@ async fn test_log_out_success() {
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    let cookie = parts.headers.get("set-cookie").unwrap().to_str().unwrap();
+    assert!(cookie.contains("Max-Age"));
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
+        &HeaderValue::from_static("/dashboard"),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_log_out_invalid_session() {
+async fn test_log_in_invalid_credentials() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
 
     // Setup database mock
     let mut db = MockDB::new();
     db.expect_get_session()
         .times(1)
         .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_is_log_in_locked()
+        .times(1)
+        .withf(|username, ip_address| {
+            username == "test-user" && *ip_address == Some([203, 0, 113, 7].into())
+        })
+        .returning(|_, _| Ok(false));
+    db.expect_get_user_by_username()
+        .times(1)
+        .withf(move |username| username == "test-user")
         .returning(|_| Ok(None));
-    db.expect_get_user_by_id().times(0);
-    db.expect_delete_session().times(0);
+    db.expect_register_failed_log_in()
+        .times(1)
+        .withf(|username, ip_address| {
+            username == "test-user" && *ip_address == Some([203, 0, 113, 7].into())
+        })
+        .returning(|_, _| Ok(false));
+    db.expect_reset_failed_log_ins().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Invalid credentials. Please make sure you have verified your email address.",
+            )
+        })
+        .returning(|_| Ok(()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
-    // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
-    let request = Request::builder()
-        .method("GET")
-        .uri(LOG_OUT_URL)
-        .header(HOST, "example.test")
-        .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    server_cfg.trusted_proxies = Some(vec![[10, 0, 0, 1].into()]);
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("POST")
+        .uri("/log-in")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .header("x-forwarded-for", "198.51.100.1, 203.0.113.7")
+        .extension(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 8080))))
+        .body(Body::from("username=test-user&password=wrong"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -602,7 +801,7 @@ async fn test_log_out_invalid_session() {
 }
 
 #[tokio::test]
-async fn test_oauth2_callback_missing_state() {
+async fn test_log_in_locked() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let session_record = sample_empty_session_record(session_id);
@@ -613,29 +812,32 @@ async fn test_oauth2_callback_missing_state() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "dashboard")
+        .returning(|_| Ok(None));
+    db.expect_is_log_in_locked()
+        .times(1)
+        .withf(|username, _| username == "test-user")
+        .returning(|_, _| Ok(true));
+    db.expect_get_user_by_username().times(0);
+    db.expect_register_failed_log_in().times(0);
     db.expect_update_session()
         .times(1)
-        .withf(move |record| message_matches(record, "OAuth2 authorization failed"))
+        .withf(|record| {
+            message_matches(
+                record,
+                "Too many failed log in attempts. Please try again in a few minutes.",
+            )
+        })
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
+    let nm = MockNotificationsManager::new();
 
     // Setup router
     let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.github = true;
-    server_cfg.oauth2.insert(
-        OAuth2Provider::GitHub,
-        OAuth2ProviderConfig {
-            auth_url: "https://oauth.example/authorize".to_string(),
-            client_id: "client-id".to_string(),
-            client_secret: "client-secret".to_string(),
-            redirect_uri: "https://app.example/log-in/oauth2/github/callback".to_string(),
-            scopes: vec!["read:user".to_string()],
-            token_url: "https://oauth.example/token".to_string(),
-        },
-    );
+    server_cfg.login.email = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
@@ -643,10 +845,11 @@ async fn test_oauth2_callback_missing_state() {
 
     // Setup request
     let request = Request::builder()
-        .method("GET")
-        .uri("/log-in/oauth2/github/callback?code=test-code&state=test-state")
+        .method("POST")
+        .uri("/log-in?next_url=%2Fdashboard")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("username=test-user&password=secret-password"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -656,19 +859,16 @@ async fn test_oauth2_callback_missing_state() {
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_oauth2_callback_state_mismatch() {
+async fn test_log_in_sso_required() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let mut session_record = sample_empty_session_record(session_id);
-    session_record
-        .data
-        .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
+    let session_record = sample_empty_session_record(session_id);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -676,29 +876,23 @@ async fn test_oauth2_callback_state_mismatch() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "sso-community")
+        .returning(|_| Ok(Some("linuxfoundation".to_string())));
+    db.expect_is_log_in_locked().times(0);
+    db.expect_get_user_by_username().times(0);
     db.expect_update_session()
         .times(1)
-        .withf(move |record| message_matches(record, "OAuth2 authorization failed"))
+        .withf(|record| message_matches(record, SSO_REQUIRED_MESSAGE))
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
+    let nm = MockNotificationsManager::new();
 
     // Setup router
     let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.github = true;
-    server_cfg.oauth2.insert(
-        OAuth2Provider::GitHub,
-        OAuth2ProviderConfig {
-            auth_url: "https://oauth.example/authorize".to_string(),
-            client_id: "client-id".to_string(),
-            client_secret: "client-secret".to_string(),
-            redirect_uri: "https://app.example/log-in/oauth2/github/callback".to_string(),
-            scopes: vec!["read:user".to_string()],
-            token_url: "https://oauth.example/token".to_string(),
-        },
-    );
+    server_cfg.login.email = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
@@ -706,169 +900,27 @@ async fn test_oauth2_callback_state_mismatch() {
 
     // Setup request
     let request = Request::builder()
-        .method("GET")
-        .uri("/log-in/oauth2/github/callback?code=test-code&state=state-in-request")
+        .method("POST")
+        .uri("/log-in?next_url=%2Fsso-community")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("username=test-user&password=secret-password"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
-    let (parts, body) = response.into_parts();
-    let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::SEE_OTHER);
-    assert_eq!(
-        parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
-    );
-    assert!(bytes.is_empty());
-}
-
-#[tokio::test]
-async fn test_oauth2_callback_authorization_error() {
-    // Setup in-memory session
-    let store = Arc::new(MemoryStore::default());
-    let session = Session::new(None, store, None);
-    session
-        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
-        .await
-        .unwrap();
-    session
-        .insert(NEXT_URL_KEY, Some("/dashboard".to_string()))
-        .await
-        .unwrap();
-
-    // Setup database mock
-    let mut db = MockDB::new();
-    db.expect_list_user_groups().times(0);
-
-    // Setup callback auth mock
-    let mut callback_auth = MockCallbackAuth {
-        login_called: false,
-        login_result: Some(Ok(())),
-        oidc_result: None,
-        oauth2_result: Some(Err("oauth2 auth error".to_string())),
-    };
-    let db: DynDB = Arc::new(db);
-
-    // Execute helper
-    let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
-    let captured_error_message = error_message.clone();
-    let redirect = oauth2_callback_with_auth(
-        &mut callback_auth,
-        session.clone(),
-        &db,
-        OAuth2Provider::GitHub,
-        "test-code".to_string(),
-        oauth2::CsrfToken::new("state-in-session".to_string()),
-        move |message| {
-            let mut guard = captured_error_message.lock().unwrap();
-            *guard = Some(message);
-        },
-    )
-    .await
-    .unwrap();
-
-    // Check callback result and side effects
-    let response = redirect.into_response();
-    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
-    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
-    assert_eq!(response.status(), StatusCode::SEE_OTHER);
-    assert_eq!(
-        response.headers().get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
-    );
-    assert_eq!(
-        *error_message.lock().unwrap(),
-        Some("OAuth2 authorization failed: oauth2 auth error".to_string()),
-    );
-    assert!(!callback_auth.login_called);
-    assert_eq!(selected_community_id, None);
-    assert_eq!(selected_group_id, None);
-}
-
-#[tokio::test]
-async fn test_oauth2_callback_success() {
-    // Setup identifiers and data structures
-    let community_id = Uuid::new_v4();
-    let group_id = Uuid::new_v4();
-    let user_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let groups = sample_user_groups_by_community(community_id, group_id);
-
-    // Setup in-memory session
-    let store = Arc::new(MemoryStore::default());
-    let session = Session::new(None, store, None);
-    session
-        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
-        .await
-        .unwrap();
-    session
-        .insert(NEXT_URL_KEY, Some("/dashboard".to_string()))
-        .await
-        .unwrap();
-
-    // Setup database mock
-    let mut db = MockDB::new();
-    db.expect_list_user_groups()
-        .times(1)
-        .withf(move |uid| uid == &user_id)
-        .returning(move |_| Ok(groups.clone()));
-
-    // Setup callback auth mock
-    let mut callback_auth = MockCallbackAuth {
-        login_called: false,
-        login_result: Some(Ok(())),
-        oidc_result: None,
-        oauth2_result: Some(Ok(Some(sample_auth_user(user_id, &auth_hash)))),
-    };
-    let db: DynDB = Arc::new(db);
-
-    // Execute helper
-    let redirect = oauth2_callback_with_auth(
-        &mut callback_auth,
-        session.clone(),
-        &db,
-        OAuth2Provider::GitHub,
-        "test-code".to_string(),
-        oauth2::CsrfToken::new("state-in-session".to_string()),
-        |_| {
-            panic!("oauth2 callback success should not emit an error message");
-        },
-    )
-    .await
-    .unwrap();
-
-    // Check callback result and side effects
-    let response = redirect.into_response();
-    let auth_provider: Option<String> = session.get(AUTH_PROVIDER_KEY).await.unwrap();
-    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
-    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
     assert_eq!(response.status(), StatusCode::SEE_OTHER);
     assert_eq!(
         response.headers().get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/dashboard"),
-    );
-    assert!(callback_auth.login_called);
-    assert_eq!(
-        auth_provider.as_deref(),
-        Some(OAuth2Provider::GitHub.as_ref())
+        &HeaderValue::from_static("/log-in?next_url=%2Fsso%2Dcommunity"),
     );
-    assert_eq!(selected_community_id, Some(community_id));
-    assert_eq!(selected_group_id, Some(group_id));
 }
 
 #[tokio::test]
-async fn test_oauth2_callback_returns_error_when_provider_is_not_configured() {
+async fn test_log_in_validation_error() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let mut session_record = sample_empty_session_record(session_id);
-    session_record
-        .data
-        .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
-    session_record
-        .data
-        .insert(NEXT_URL_KEY.to_string(), json!("/dashboard"));
+    let session_record = sample_empty_session_record(session_id);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -876,34 +928,36 @@ async fn test_oauth2_callback_returns_error_when_provider_is_not_configured() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_username().times(0);
+    db.expect_is_log_in_locked().times(0);
     db.expect_update_session()
         .times(1)
-        .withf(move |record| {
+        .withf(|record| {
             message_matches(
                 record,
-                "OAuth2 authorization failed: oauth2 provider not found",
+                "username: value cannot be empty or whitespace-only\n",
             )
         })
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
+    let nm = MockNotificationsManager::new();
 
     // Setup router
     let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.github = true;
+    server_cfg.login.email = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
         .await;
 
-    // Setup request
+    // Setup request (username is whitespace only - validation should fail)
     let request = Request::builder()
-        .method("GET")
-        .uri("/log-in/oauth2/github/callback?code=test-code&state=state-in-session")
+        .method("POST")
+        .uri("/log-in")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("username=+++&password=secret"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -913,70 +967,80 @@ async fn test_oauth2_callback_returns_error_when_provider_is_not_configured() {
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
+        &HeaderValue::from_static(LOG_IN_URL),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_oauth2_redirect_success() {
-    // Setup session and form input
-    let store = Arc::new(MemoryStore::default());
-    let session = Session::new(None, store, None);
-    let query = Query(NextUrl {
-        next_url: Some("/dashboard".to_string()),
-    });
+async fn test_log_in_ldap_locked() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
 
-    // Setup oauth2 provider details
-    let client = BasicClient::new(ClientId::new("client-id".to_string()))
-        .set_client_secret(ClientSecret::new("client-secret".to_string()))
-        .set_auth_uri(AuthUrl::new("https://oauth.example/authorize".to_string()).unwrap())
-        .set_token_uri(TokenUrl::new("https://oauth.example/token".to_string()).unwrap())
-        .set_redirect_uri(
-            RedirectUrl::new("https://app.example/log-in/oauth2/github/callback".to_string())
-                .unwrap(),
-        );
-    let provider = OAuth2ProviderDetails {
-        client,
-        scopes: vec!["read:user".to_string()],
-    };
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "dashboard")
+        .returning(|_| Ok(None));
+    db.expect_is_log_in_locked()
+        .times(1)
+        .withf(|username, _| username == "test-user")
+        .returning(|_, _| Ok(true));
+    db.expect_add_user_ldap_community_roles().times(0);
+    db.expect_register_failed_log_in().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| {
+            message_matches(
+                record,
+                "Too many failed log in attempts. Please try again in a few minutes.",
+            )
+        })
+        .returning(|_| Ok(()));
 
-    // Execute handler
-    let response = oauth2_redirect(session.clone(), OAuth2(Arc::new(provider)), query)
-        .await
-        .expect("oauth2 redirect should succeed")
-        .into_response();
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.ldap = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("POST")
+        .uri("/log-in/ldap?next_url=%2Fdashboard")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("username=test-user&password=secret-password"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
-    let location = parts.headers.get(LOCATION).unwrap().to_str().unwrap();
-    let (base_url, query) = location
-        .split_once('?')
-        .expect("redirect url to contain query string");
 
     // Check response matches expectations
-    let csrf_state: Option<String> = session.get(OAUTH2_CSRF_STATE_KEY).await.unwrap();
-    let next_url: Option<Option<String>> = session.get(NEXT_URL_KEY).await.unwrap();
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
-    assert_eq!(base_url, "https://oauth.example/authorize");
     assert_eq!(
-        csrf_state.as_deref(),
-        query
-            .split('&')
-            .find_map(|pair| pair.strip_prefix("state=").map(String::from))
-            .as_deref(),
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
     );
-    assert_eq!(next_url, Some(Some("/dashboard".to_string())));
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_oidc_callback_missing_nonce() {
+async fn test_log_in_ldap_validation_error() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let mut session_record = sample_empty_session_record(session_id);
-    session_record
-        .data
-        .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
+    let session_record = sample_empty_session_record(session_id);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -984,29 +1048,40 @@ async fn test_oidc_callback_missing_nonce() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "dashboard")
+        .returning(|_| Ok(None));
+    db.expect_add_user_ldap_community_roles().times(0);
+    db.expect_is_log_in_locked().times(0);
     db.expect_update_session()
         .times(1)
-        .withf(move |record| message_matches(record, "OpenID Connect authorization failed"))
+        .withf(|record| {
+            message_matches(
+                record,
+                "password: value cannot be empty or whitespace-only\n",
+            )
+        })
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
+    let nm = MockNotificationsManager::new();
 
     // Setup router
     let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.linuxfoundation = true;
+    server_cfg.login.ldap = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
         .await;
 
-    // Setup request
+    // Setup request (password is whitespace only - validation should fail)
     let request = Request::builder()
-        .method("GET")
-        .uri("/log-in/oidc/linuxfoundation/callback?code=test-code&state=state-in-session")
+        .method("POST")
+        .uri("/log-in/ldap?next_url=%2Fdashboard")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("username=test-user&password=+++"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -1016,16 +1091,20 @@ async fn test_oidc_callback_missing_nonce() {
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_oidc_callback_missing_state() {
+async fn test_log_in_magic_link_success() {
     // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
     let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
     let session_record = sample_empty_session_record(session_id);
+    let token_hash = compute_hash("magic-token".as_bytes());
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1033,18 +1112,36 @@ async fn test_oidc_callback_missing_state() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_update_session()
+    db.expect_redeem_user_magic_link()
         .times(1)
-        .withf(move |record| message_matches(record, "OpenID Connect authorization failed"))
+        .withf(move |hash| hash == token_hash)
+        .returning(move |_| Ok(Some(user_id)));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, "hash"))));
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
+    db.expect_create_session()
+        .times(1)
+        .withf(move |record| {
+            session_record_contains_auth_provider(record, AUTH_PROVIDER_MAGIC_LINK)
+                && session_record_contains_selected_group(record, group_id)
+        })
         .returning(|_| Ok(()));
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_groups_by_community(community_id, group_id)));
 
     // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
+    let nm = MockNotificationsManager::new();
 
     // Setup router
     let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.linuxfoundation = true;
+    server_cfg.login.magic_link = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
@@ -1053,7 +1150,7 @@ async fn test_oidc_callback_missing_state() {
     // Setup request
     let request = Request::builder()
         .method("GET")
-        .uri("/log-in/oidc/linuxfoundation/callback?code=test-code&state=test-state")
+        .uri("/log-in/magic-link/magic-token?next_url=%2Fdashboard")
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -1063,24 +1160,19 @@ async fn test_oidc_callback_missing_state() {
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert!(parts.headers.contains_key("set-cookie"));
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
+        &HeaderValue::from_static("/dashboard"),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_oidc_callback_state_mismatch() {
+async fn test_log_in_magic_link_invalid_token() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let mut session_record = sample_empty_session_record(session_id);
-    session_record
-        .data
-        .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
-    session_record
-        .data
-        .insert(OIDC_NONCE_KEY.to_string(), json!("nonce-in-session"));
+    let session_record = sample_empty_session_record(session_id);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1088,18 +1180,23 @@ async fn test_oidc_callback_state_mismatch() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_redeem_user_magic_link().times(1).returning(|_| Ok(None));
     db.expect_update_session()
         .times(1)
-        .withf(move |record| message_matches(record, "OpenID Connect authorization failed"))
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Invalid log in link (please note that links are only valid for 15 minutes and can only be used once).",
+            )
+        })
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
+    let nm = MockNotificationsManager::new();
 
     // Setup router
     let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.linuxfoundation = true;
+    server_cfg.login.magic_link = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
@@ -1108,7 +1205,7 @@ async fn test_oidc_callback_state_mismatch() {
     // Setup request
     let request = Request::builder()
         .method("GET")
-        .uri("/log-in/oidc/linuxfoundation/callback?code=test-code&state=state-in-request")
+        .uri("/log-in/magic-link/used-token")
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -1126,81 +1223,228 @@ async fn test_oidc_callback_state_mismatch() {
 }
 
 #[tokio::test]
-async fn test_oidc_callback_authorization_error() {
-    // Setup in-memory session
-    let store = Arc::new(MemoryStore::default());
-    let session = Session::new(None, store, None);
-    session
-        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
-        .await
-        .unwrap();
-    session.insert(OIDC_NONCE_KEY, "nonce-in-session").await.unwrap();
-    session
-        .insert(NEXT_URL_KEY, Some("/dashboard".to_string()))
-        .await
-        .unwrap();
+async fn test_log_out_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
 
     // Setup database mock
     let mut db = MockDB::new();
-    db.expect_list_user_groups().times(0);
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
 
-    // Setup callback auth mock
-    let mut callback_auth = MockCallbackAuth {
-        login_called: false,
-        login_result: Some(Ok(())),
-        oidc_result: Some(Err("oidc auth error".to_string())),
-        oauth2_result: None,
-    };
-    let db: DynDB = Arc::new(db);
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
 
-    // Execute helper
-    let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
-    let captured_error_message = error_message.clone();
-    let redirect = oidc_callback_with_auth(
-        &mut callback_auth,
-        session.clone(),
-        &db,
-        OidcProvider::LinuxFoundation,
-        "test-code".to_string(),
-        oauth2::CsrfToken::new("state-in-session".to_string()),
-        move |message| {
-            let mut guard = captured_error_message.lock().unwrap();
-            *guard = Some(message);
-        },
-    )
-    .await
-    .unwrap();
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(LOG_OUT_URL)
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
-    // Check callback result and side effects
-    let response = redirect.into_response();
-    let auth_provider: Option<String> = session.get(AUTH_PROVIDER_KEY).await.unwrap();
-    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
-    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
-    assert_eq!(response.status(), StatusCode::SEE_OTHER);
-    assert_eq!(
-        response.headers().get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
-    );
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
     assert_eq!(
-        *error_message.lock().unwrap(),
-        Some("OpenID Connect authorization failed: oidc auth error".to_string()),
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
     );
-    assert!(!callback_auth.login_called);
-    assert_eq!(auth_provider, None);
-    assert_eq!(selected_community_id, None);
-    assert_eq!(selected_group_id, None);
+    assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_oidc_callback_uses_friendly_external_auth_email_conflict_message() {
-    // Setup in-memory session
-    let store = Arc::new(MemoryStore::default());
-    let session = Session::new(None, store, None);
-    session
-        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
-        .await
-        .unwrap();
-    session.insert(OIDC_NONCE_KEY, "nonce-in-session").await.unwrap();
+async fn test_log_out_invalid_session() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(None));
+    db.expect_get_user_by_id().times(0);
+    db.expect_delete_session().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(LOG_OUT_URL)
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_oauth2_callback_missing_state() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| message_matches(record, "OAuth2 authorization failed"))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.github = true;
+    server_cfg.oauth2.insert(
+        OAuth2Provider::GitHub,
+        OAuth2ProviderConfig {
+            auth_url: "https://oauth.example/authorize".to_string(),
+            client_id: "client-id".to_string(),
+            client_secret: "client-secret".to_string(),
+            redirect_uri: "https://app.example/log-in/oauth2/github/callback".to_string(),
+            scopes: vec!["read:user".to_string()],
+            token_url: "https://oauth.example/token".to_string(),
+        },
+    );
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("GET")
+        .uri("/log-in/oauth2/github/callback?code=test-code&state=test-state")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_oauth2_callback_state_mismatch() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let mut session_record = sample_empty_session_record(session_id);
+    session_record
+        .data
+        .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| message_matches(record, "OAuth2 authorization failed"))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.github = true;
+    server_cfg.oauth2.insert(
+        OAuth2Provider::GitHub,
+        OAuth2ProviderConfig {
+            auth_url: "https://oauth.example/authorize".to_string(),
+            client_id: "client-id".to_string(),
+            client_secret: "client-secret".to_string(),
+            redirect_uri: "https://app.example/log-in/oauth2/github/callback".to_string(),
+            scopes: vec!["read:user".to_string()],
+            token_url: "https://oauth.example/token".to_string(),
+        },
+    );
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("GET")
+        .uri("/log-in/oauth2/github/callback?code=test-code&state=state-in-request")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_oauth2_callback_authorization_error() {
+    // Setup in-memory session
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+    session
+        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
+        .await
+        .unwrap();
+    session
+        .insert(NEXT_URL_KEY, Some("/dashboard".to_string()))
+        .await
+        .unwrap();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1208,24 +1452,23 @@ async fn test_oidc_callback_uses_friendly_external_auth_email_conflict_message()
 
     // Setup callback auth mock
     let mut callback_auth = MockCallbackAuth {
+        link_result: None,
         login_called: false,
         login_result: Some(Ok(())),
-        oidc_result: Some(Err(format!(
-            "db error: {}",
-            crate::auth::EXTERNAL_AUTH_EMAIL_CONFLICT_ERROR
-        ))),
-        oauth2_result: None,
+        oidc_result: None,
+        oauth2_result: Some(Err("oauth2 auth error".to_string())),
     };
     let db: DynDB = Arc::new(db);
 
     // Execute helper
     let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
     let captured_error_message = error_message.clone();
-    let redirect = oidc_callback_with_auth(
+    let redirect = oauth2_callback_with_auth(
         &mut callback_auth,
-        session,
+        session.clone(),
+        SessionClient::default(),
         &db,
-        OidcProvider::LinuxFoundation,
+        OAuth2Provider::GitHub,
         "test-code".to_string(),
         oauth2::CsrfToken::new("state-in-session".to_string()),
         move |message| {
@@ -1238,20 +1481,27 @@ async fn test_oidc_callback_uses_friendly_external_auth_email_conflict_message()
 
     // Check callback result and side effects
     let response = redirect.into_response();
+    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
+    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
     assert_eq!(response.status(), StatusCode::SEE_OTHER);
     assert_eq!(
         response.headers().get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
     );
     assert_eq!(
         *error_message.lock().unwrap(),
-        Some(LF_SSO_EMAIL_CONFLICT_MESSAGE.to_string()),
+        Some("OAuth2 authorization failed: oauth2 auth error".to_string()),
     );
     assert!(!callback_auth.login_called);
+    assert_eq!(selected_community_id, None);
+    assert_eq!(selected_group_id, None);
 }
 
 #[tokio::test]
-async fn test_oidc_callback_uses_friendly_external_auth_identity_conflict_message() {
+async fn test_oauth2_callback_links_identity_when_requested() {
+    // Setup identifiers and data structures
+    let user_id = Uuid::new_v4();
+
     // Setup in-memory session
     let store = Arc::new(MemoryStore::default());
     let session = Session::new(None, store, None);
@@ -1259,7 +1509,7 @@ async fn test_oidc_callback_uses_friendly_external_auth_identity_conflict_messag
         .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
         .await
         .unwrap();
-    session.insert(OIDC_NONCE_KEY, "nonce-in-session").await.unwrap();
+    session.insert(LINK_IDENTITY_USER_ID_KEY, user_id).await.unwrap();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1267,29 +1517,25 @@ async fn test_oidc_callback_uses_friendly_external_auth_identity_conflict_messag
 
     // Setup callback auth mock
     let mut callback_auth = MockCallbackAuth {
+        link_result: Some(Ok(())),
         login_called: false,
-        login_result: Some(Ok(())),
-        oidc_result: Some(Err(format!(
-            "db error: {}",
-            crate::auth::EXTERNAL_AUTH_IDENTITY_CONFLICT_ERROR
-        ))),
+        login_result: None,
+        oidc_result: None,
         oauth2_result: None,
     };
     let db: DynDB = Arc::new(db);
 
     // Execute helper
-    let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
-    let captured_error_message = error_message.clone();
-    let redirect = oidc_callback_with_auth(
+    let redirect = oauth2_callback_with_auth(
         &mut callback_auth,
-        session,
+        session.clone(),
+        SessionClient::default(),
         &db,
-        OidcProvider::LinuxFoundation,
+        OAuth2Provider::GitHub,
         "test-code".to_string(),
         oauth2::CsrfToken::new("state-in-session".to_string()),
-        move |message| {
-            let mut guard = captured_error_message.lock().unwrap();
-            *guard = Some(message);
+        |_| {
+            panic!("identity link success should not emit an error message");
         },
     )
     .await
@@ -1297,27 +1543,18 @@ async fn test_oidc_callback_uses_friendly_external_auth_identity_conflict_messag
 
     // Check callback result and side effects
     let response = redirect.into_response();
+    let link_user_id: Option<Uuid> = session.get(LINK_IDENTITY_USER_ID_KEY).await.unwrap();
     assert_eq!(response.status(), StatusCode::SEE_OTHER);
     assert_eq!(
         response.headers().get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
-    );
-    assert_eq!(
-        *error_message.lock().unwrap(),
-        Some(LF_SSO_IDENTITY_CONFLICT_MESSAGE.to_string()),
+        &HeaderValue::from_static(USER_DASHBOARD_ACCOUNT_URL),
     );
     assert!(!callback_auth.login_called);
+    assert_eq!(link_user_id, None);
 }
 
 #[tokio::test]
-async fn test_oidc_callback_success() {
-    // Setup identifiers and data structures
-    let community_id = Uuid::new_v4();
-    let group_id = Uuid::new_v4();
-    let user_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let groups = sample_user_groups_by_community(community_id, group_id);
-
+async fn test_oauth2_callback_reports_identity_link_conflict() {
     // Setup in-memory session
     let store = Arc::new(MemoryStore::default());
     let session = Session::new(None, store, None);
@@ -1325,38 +1562,37 @@ async fn test_oidc_callback_success() {
         .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
         .await
         .unwrap();
-    session.insert(OIDC_NONCE_KEY, "nonce-in-session").await.unwrap();
     session
-        .insert(NEXT_URL_KEY, Some("/dashboard".to_string()))
+        .insert(LINK_IDENTITY_USER_ID_KEY, Uuid::new_v4())
         .await
         .unwrap();
 
-    // Setup database mock
-    let mut db = MockDB::new();
-    db.expect_list_user_groups()
-        .times(1)
-        .withf(move |uid| uid == &user_id)
-        .returning(move |_| Ok(groups.clone()));
-
     // Setup callback auth mock
     let mut callback_auth = MockCallbackAuth {
+        link_result: Some(Err(
+            crate::auth::EXTERNAL_AUTH_IDENTITY_CONFLICT_ERROR.to_string()
+        )),
         login_called: false,
-        login_result: Some(Ok(())),
-        oidc_result: Some(Ok(Some(sample_auth_user(user_id, &auth_hash)))),
+        login_result: None,
+        oidc_result: None,
         oauth2_result: None,
     };
-    let db: DynDB = Arc::new(db);
+    let db: DynDB = Arc::new(MockDB::new());
 
     // Execute helper
-    let redirect = oidc_callback_with_auth(
+    let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let captured_error_message = error_message.clone();
+    let redirect = oauth2_callback_with_auth(
         &mut callback_auth,
         session.clone(),
+        SessionClient::default(),
         &db,
-        OidcProvider::LinuxFoundation,
+        OAuth2Provider::GitHub,
         "test-code".to_string(),
         oauth2::CsrfToken::new("state-in-session".to_string()),
-        |_| {
-            panic!("oidc callback success should not emit an error message");
+        move |message| {
+            let mut guard = captured_error_message.lock().unwrap();
+            *guard = Some(message);
         },
     )
     .await
@@ -1364,34 +1600,162 @@ async fn test_oidc_callback_success() {
 
     // Check callback result and side effects
     let response = redirect.into_response();
-    let auth_provider: Option<String> = session.get(AUTH_PROVIDER_KEY).await.unwrap();
-    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
-    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
     assert_eq!(response.status(), StatusCode::SEE_OTHER);
     assert_eq!(
         response.headers().get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/dashboard"),
+        &HeaderValue::from_static(USER_DASHBOARD_ACCOUNT_URL),
+    );
+    assert_eq!(
+        *error_message.lock().unwrap(),
+        Some("This account is already linked to another OCG account.".to_string()),
+    );
+    assert!(!callback_auth.login_called);
+}
+
+#[tokio::test]
+async fn test_oauth2_callback_uses_friendly_email_domain_not_allowed_message() {
+    // Setup in-memory session
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+    session
+        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
+        .await
+        .unwrap();
+    session
+        .insert(NEXT_URL_KEY, Some("/corporate".to_string()))
+        .await
+        .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_user_groups().times(0);
+
+    // Setup callback auth mock
+    let mut callback_auth = MockCallbackAuth {
+        link_result: None,
+        login_called: false,
+        login_result: Some(Ok(())),
+        oidc_result: None,
+        oauth2_result: Some(Err(
+            crate::auth::EXTERNAL_AUTH_EMAIL_DOMAIN_NOT_ALLOWED_ERROR.to_string(),
+        )),
+    };
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let captured_error_message = error_message.clone();
+    let redirect = oauth2_callback_with_auth(
+        &mut callback_auth,
+        session,
+        SessionClient::default(),
+        &db,
+        OAuth2Provider::GitHub,
+        "test-code".to_string(),
+        oauth2::CsrfToken::new("state-in-session".to_string()),
+        move |message| {
+            let mut guard = captured_error_message.lock().unwrap();
+            *guard = Some(message);
+        },
+    )
+    .await
+    .unwrap();
+
+    // Check callback result and side effects
+    let response = redirect.into_response();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fcorporate"),
+    );
+    assert_eq!(
+        *error_message.lock().unwrap(),
+        Some(EMAIL_DOMAIN_NOT_ALLOWED_MESSAGE.to_string()),
+    );
+    assert!(!callback_auth.login_called);
+}
+
+#[tokio::test]
+async fn test_oauth2_callback_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let groups = sample_user_groups_by_community(community_id, group_id);
+
+    // Setup in-memory session
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+    session
+        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
+        .await
+        .unwrap();
+    session
+        .insert(NEXT_URL_KEY, Some("/dashboard".to_string()))
+        .await
+        .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(groups.clone()));
+
+    // Setup callback auth mock
+    let mut callback_auth = MockCallbackAuth {
+        link_result: None,
+        login_called: false,
+        login_result: Some(Ok(())),
+        oidc_result: None,
+        oauth2_result: Some(Ok(Some(sample_auth_user(user_id, &auth_hash)))),
+    };
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let redirect = oauth2_callback_with_auth(
+        &mut callback_auth,
+        session.clone(),
+        SessionClient::default(),
+        &db,
+        OAuth2Provider::GitHub,
+        "test-code".to_string(),
+        oauth2::CsrfToken::new("state-in-session".to_string()),
+        |_| {
+            panic!("oauth2 callback success should not emit an error message");
+        },
+    )
+    .await
+    .unwrap();
+
+    // Check callback result and side effects
+    let response = redirect.into_response();
+    let auth_provider: Option<String> = session.get(AUTH_PROVIDER_KEY).await.unwrap();
+    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
+    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/dashboard"),
     );
     assert!(callback_auth.login_called);
     assert_eq!(
         auth_provider.as_deref(),
-        Some(OidcProvider::LinuxFoundation.as_ref())
+        Some(OAuth2Provider::GitHub.as_ref())
     );
     assert_eq!(selected_community_id, Some(community_id));
     assert_eq!(selected_group_id, Some(group_id));
 }
 
 #[tokio::test]
-async fn test_oidc_callback_returns_error_when_provider_is_not_configured() {
+async fn test_oauth2_callback_returns_error_when_provider_is_not_configured() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let mut session_record = sample_empty_session_record(session_id);
     session_record
         .data
         .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
-    session_record
-        .data
-        .insert(OIDC_NONCE_KEY.to_string(), json!("nonce-in-session"));
     session_record
         .data
         .insert(NEXT_URL_KEY.to_string(), json!("/dashboard"));
@@ -1407,7 +1771,7 @@ async fn test_oidc_callback_returns_error_when_provider_is_not_configured() {
         .withf(move |record| {
             message_matches(
                 record,
-                "OpenID Connect authorization failed: oidc provider not found",
+                "OAuth2 authorization failed: oauth2 provider not found",
             )
         })
         .returning(|_| Ok(()));
@@ -1418,7 +1782,7 @@ async fn test_oidc_callback_returns_error_when_provider_is_not_configured() {
 
     // Setup router
     let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.linuxfoundation = true;
+    server_cfg.login.github = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
@@ -1427,7 +1791,7 @@ async fn test_oidc_callback_returns_error_when_provider_is_not_configured() {
     // Setup request
     let request = Request::builder()
         .method("GET")
-        .uri("/log-in/oidc/linuxfoundation/callback?code=test-code&state=state-in-session")
+        .uri("/log-in/oauth2/github/callback?code=test-code&state=state-in-session")
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -1445,7 +1809,7 @@ async fn test_oidc_callback_returns_error_when_provider_is_not_configured() {
 }
 
 #[tokio::test]
-async fn test_oidc_redirect_success() {
+async fn test_oauth2_redirect_success() {
     // Setup session and form input
     let store = Arc::new(MemoryStore::default());
     let session = Session::new(None, store, None);
@@ -1453,37 +1817,24 @@ async fn test_oidc_redirect_success() {
         next_url: Some("/dashboard".to_string()),
     });
 
-    // Setup oidc provider details
-    let metadata = oidc::core::CoreProviderMetadata::new(
-        oidc::IssuerUrl::new("https://issuer.example".to_string()).unwrap(),
-        oidc::AuthUrl::new("https://issuer.example/authorize".to_string()).unwrap(),
-        oidc::JsonWebKeySetUrl::new("https://issuer.example/jwks".to_string()).unwrap(),
-        vec![oidc::ResponseTypes::new(vec![
-            oidc::core::CoreResponseType::Code,
-        ])],
-        vec![oidc::core::CoreSubjectIdentifierType::Public],
-        vec![oidc::core::CoreJwsSigningAlgorithm::RsaSsaPkcs1V15Sha256],
-        oidc::EmptyAdditionalProviderMetadata::default(),
-    )
-    .set_jwks(oidc::JsonWebKeySet::new(vec![]));
-    let client = oidc::core::CoreClient::from_provider_metadata(
-        metadata,
-        oidc::ClientId::new("client-id".to_string()),
-        Some(oidc::ClientSecret::new("client-secret".to_string())),
-    )
-    .set_redirect_uri(
-        oidc::RedirectUrl::new("https://app.example/log-in/oidc/provider/callback".to_string())
-            .unwrap(),
-    );
-    let provider = OidcProviderDetails {
+    // Setup oauth2 provider details
+    let client = BasicClient::new(ClientId::new("client-id".to_string()))
+        .set_client_secret(ClientSecret::new("client-secret".to_string()))
+        .set_auth_uri(AuthUrl::new("https://oauth.example/authorize".to_string()).unwrap())
+        .set_token_uri(TokenUrl::new("https://oauth.example/token".to_string()).unwrap())
+        .set_redirect_uri(
+            RedirectUrl::new("https://app.example/log-in/oauth2/github/callback".to_string())
+                .unwrap(),
+        );
+    let provider = OAuth2ProviderDetails {
         client,
-        scopes: vec!["openid".to_string()],
+        scopes: vec!["read:user".to_string()],
     };
 
     // Execute handler
-    let response = oidc_redirect(session.clone(), Oidc(Arc::new(provider)), query)
+    let response = oauth2_redirect(session.clone(), OAuth2(Arc::new(provider)), query)
         .await
-        .expect("oidc redirect should succeed")
+        .expect("oauth2 redirect should succeed")
         .into_response();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
@@ -1494,10 +1845,9 @@ async fn test_oidc_redirect_success() {
 
     // Check response matches expectations
     let csrf_state: Option<String> = session.get(OAUTH2_CSRF_STATE_KEY).await.unwrap();
-    let nonce: Option<String> = session.get(OIDC_NONCE_KEY).await.unwrap();
     let next_url: Option<Option<String>> = session.get(NEXT_URL_KEY).await.unwrap();
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
-    assert_eq!(base_url, "https://issuer.example/authorize");
+    assert_eq!(base_url, "https://oauth.example/authorize");
     assert_eq!(
         csrf_state.as_deref(),
         query
@@ -1505,26 +1855,18 @@ async fn test_oidc_redirect_success() {
             .find_map(|pair| pair.strip_prefix("state=").map(String::from))
             .as_deref(),
     );
-    assert_eq!(
-        nonce.as_deref(),
-        query
-            .split('&')
-            .find_map(|pair| pair.strip_prefix("nonce=").map(String::from))
-            .as_deref(),
-    );
     assert_eq!(next_url, Some(Some("/dashboard".to_string())));
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_sign_up_success() {
+async fn test_oidc_callback_missing_nonce() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let session_record = sample_empty_session_record(session_id);
-    let user_for_db = sample_auth_user(Uuid::new_v4(), "hash");
-    let site_settings = sample_site_settings();
-    let activation_primary_color = site_settings.theme.primary_color.clone();
-    let sign_up_primary_color = site_settings.theme.primary_color.clone();
+    let mut session_record = sample_empty_session_record(session_id);
+    session_record
+        .data
+        .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1532,42 +1874,9 @@ async fn test_sign_up_success() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_site_settings()
-        .times(1)
-        .returning(move || Ok(site_settings.clone()));
-    db.expect_activate_pre_registered_user_email_password()
-        .times(1)
-        .withf(move |summary, verification| {
-            summary.email == "test@example.test"
-                && !matches!(summary.password.as_deref(), Some("secret-password"))
-                && verification.template_data.link
-                    == format!("https://app.example/verify-email/{}", verification.code)
-                && verification.template_data.theme.primary_color == activation_primary_color
-        })
-        .returning(|_, _| Ok(None));
-    db.expect_sign_up_user()
-        .times(1)
-        .withf(move |summary, verify, verification| {
-            !matches!(summary.password.as_deref(), Some("secret-password"))
-                && !*verify
-                && verification.as_ref().is_some_and(|verification| {
-                    verification.template_data.link
-                        == format!("https://app.example/verify-email/{}", verification.code)
-                        && verification.template_data.theme.primary_color == sign_up_primary_color
-                })
-        })
-        .returning({
-            let user = user_for_db;
-            move |_, _, verification| Ok((user.clone(), verification.map(|value| value.code)))
-        });
     db.expect_update_session()
         .times(1)
-        .withf(move |record| {
-            message_matches(
-                record,
-                "Please verify your email to complete the sign up process.",
-            )
-        })
+        .withf(move |record| message_matches(record, "OpenID Connect authorization failed"))
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
@@ -1575,28 +1884,19 @@ async fn test_sign_up_success() {
     nm.expect_enqueue().times(0);
 
     // Setup router
-    let server_cfg = HttpServerConfig {
-        base_url: "https://app.example".to_string(),
-        login: LoginOptions {
-            email: true,
-            ..Default::default()
-        },
-        ..Default::default()
-    };
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.linuxfoundation = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
         .await;
 
     // Setup request
-    let form =
-        "email=test%40example.test&name=Test+User&username=test-user&password=secret-password";
     let request = Request::builder()
-        .method("POST")
-        .uri("/sign-up?next_url=%2Fwelcome")
+        .method("GET")
+        .uri("/log-in/oidc/linuxfoundation/callback?code=test-code&state=state-in-session")
         .header(COOKIE, format!("id={session_id}"))
-        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
+        .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -1606,19 +1906,16 @@ async fn test_sign_up_success() {
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/log-in?next_url=%2Fwelcome"),
+        &HeaderValue::from_static(LOG_IN_URL),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_sign_up_activates_pre_registered_user() {
+async fn test_oidc_callback_missing_state() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let session_record = sample_empty_session_record(session_id);
-    let user_for_db = sample_auth_user(Uuid::new_v4(), "hash");
-    let site_settings = sample_site_settings();
-    let activation_primary_color = site_settings.theme.primary_color.clone();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1626,33 +1923,9 @@ async fn test_sign_up_activates_pre_registered_user() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_site_settings()
-        .times(1)
-        .returning(move || Ok(site_settings.clone()));
-    db.expect_activate_pre_registered_user_email_password()
-        .times(1)
-        .withf(move |summary, verification| {
-            summary.email == "invited@example.test"
-                && summary.name == "Invited User"
-                && summary.username == "invited-user"
-                && !matches!(summary.password.as_deref(), Some("secret-password"))
-                && verification.template_data.link
-                    == format!("https://app.example/verify-email/{}", verification.code)
-                && verification.template_data.theme.primary_color == activation_primary_color
-        })
-        .returning({
-            let user = user_for_db;
-            move |_, verification| Ok(Some((user.clone(), verification.code)))
-        });
-    db.expect_sign_up_user().times(0);
     db.expect_update_session()
         .times(1)
-        .withf(move |record| {
-            message_matches(
-                record,
-                "Please verify your email to complete the sign up process.",
-            )
-        })
+        .withf(move |record| message_matches(record, "OpenID Connect authorization failed"))
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
@@ -1660,27 +1933,19 @@ async fn test_sign_up_activates_pre_registered_user() {
     nm.expect_enqueue().times(0);
 
     // Setup router
-    let server_cfg = HttpServerConfig {
-        base_url: "https://app.example".to_string(),
-        login: LoginOptions {
-            email: true,
-            ..Default::default()
-        },
-        ..Default::default()
-    };
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.linuxfoundation = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
         .await;
 
     // Setup request
-    let form = "email=invited%40example.test&name=Invited+User&username=invited-user&password=secret-password";
     let request = Request::builder()
-        .method("POST")
-        .uri("/sign-up?next_url=%2Fdashboard%2Fuser%3Ftab%3Dinvitations")
+        .method("GET")
+        .uri("/log-in/oidc/linuxfoundation/callback?code=test-code&state=test-state")
         .header(COOKIE, format!("id={session_id}"))
-        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
+        .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -1690,61 +1955,22 @@ async fn test_sign_up_activates_pre_registered_user() {
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard%2Fuser%3Ftab%3Dinvitations"),
+        &HeaderValue::from_static(LOG_IN_URL),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_sign_up_missing_password() {
-    // Setup identifiers and data structures
-    let session_id = session::Id::default();
-    let session_record = sample_empty_session_record(session_id);
-
-    // Setup database mock
-    let mut db = MockDB::new();
-    db.expect_get_session()
-        .times(1)
-        .withf(move |id| *id == session_id)
-        .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_activate_pre_registered_user_email_password().times(0);
-    db.expect_sign_up_user().times(0);
-
-    // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
-
-    // Setup router
-    let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.email = true;
-    let router = TestRouterBuilder::new(db, nm)
-        .with_server_cfg(server_cfg)
-        .build()
-        .await;
-
-    // Setup request (password not provided)
-    let form = "email=test%40example.test&name=Test+User&username=test-user";
-    let request = Request::builder()
-        .method("POST")
-        .uri("/sign-up")
-        .header(COOKIE, format!("id={session_id}"))
-        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
-        .unwrap();
-    let response = router.oneshot(request).await.unwrap();
-    let (parts, body) = response.into_parts();
-    let bytes = to_bytes(body, usize::MAX).await.unwrap();
-
-    // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
-    assert_eq!(bytes, "password not provided");
-}
-
-#[tokio::test]
-async fn test_sign_up_rejects_empty_normalized_base_url() {
+async fn test_oidc_callback_state_mismatch() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let session_record = sample_empty_session_record(session_id);
+    let mut session_record = sample_empty_session_record(session_id);
+    session_record
+        .data
+        .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
+    session_record
+        .data
+        .insert(OIDC_NONCE_KEY.to_string(), json!("nonce-in-session"));
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1752,17 +1978,9 @@ async fn test_sign_up_rejects_empty_normalized_base_url() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_site_settings().times(0);
-    db.expect_activate_pre_registered_user_email_password().times(0);
-    db.expect_sign_up_user().times(0);
     db.expect_update_session()
         .times(1)
-        .withf(move |record| {
-            message_matches(
-                record,
-                "Something went wrong while signing up. Please try again later.",
-            )
-        })
+        .withf(move |record| message_matches(record, "OpenID Connect authorization failed"))
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
@@ -1770,27 +1988,19 @@ async fn test_sign_up_rejects_empty_normalized_base_url() {
     nm.expect_enqueue().times(0);
 
     // Setup router
-    let server_cfg = HttpServerConfig {
-        base_url: "/".to_string(),
-        login: LoginOptions {
-            email: true,
-            ..Default::default()
-        },
-        ..Default::default()
-    };
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.linuxfoundation = true;
     let router = TestRouterBuilder::new(db, nm)
         .with_server_cfg(server_cfg)
         .build()
         .await;
 
     // Setup request
-    let form = "email=test%40example.test&name=Test+User&username=test-user&password=secretpw";
     let request = Request::builder()
-        .method("POST")
-        .uri("/sign-up")
+        .method("GET")
+        .uri("/log-in/oidc/linuxfoundation/callback?code=test-code&state=state-in-request")
         .header(COOKIE, format!("id={session_id}"))
-        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
+        .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -1800,142 +2010,2574 @@ async fn test_sign_up_rejects_empty_normalized_base_url() {
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(SIGN_UP_URL),
+        &HeaderValue::from_static(LOG_IN_URL),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_sign_up_db_error() {
-    // Setup identifiers and data structures
-    let session_id = session::Id::default();
-    let session_record = sample_empty_session_record(session_id);
+async fn test_oidc_callback_authorization_error() {
+    // Setup in-memory session
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+    session
+        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
+        .await
+        .unwrap();
+    session.insert(OIDC_NONCE_KEY, "nonce-in-session").await.unwrap();
+    session
+        .insert(NEXT_URL_KEY, Some("/dashboard".to_string()))
+        .await
+        .unwrap();
 
     // Setup database mock
     let mut db = MockDB::new();
-    db.expect_get_session()
-        .times(1)
-        .withf(move |id| *id == session_id)
-        .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_site_settings()
-        .times(1)
-        .returning(|| Ok(sample_site_settings()));
-    db.expect_activate_pre_registered_user_email_password()
-        .times(1)
-        .returning(|_, _| Ok(None));
-    db.expect_sign_up_user()
-        .times(1)
-        .returning(|_, _, _| Err(anyhow!("db error")));
-    db.expect_update_session()
-        .times(1)
-        .withf(move |record| {
-            message_matches(
-                record,
-                "Something went wrong while signing up. Please try again later.",
-            )
-        })
-        .returning(|_| Ok(()));
-
-    // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
+    db.expect_list_user_groups().times(0);
 
-    // Setup router
-    let server_cfg = HttpServerConfig {
-        base_url: "https://app.example".to_string(),
-        login: LoginOptions {
-            email: true,
-            ..Default::default()
-        },
-        ..Default::default()
+    // Setup callback auth mock
+    let mut callback_auth = MockCallbackAuth {
+        link_result: None,
+        login_called: false,
+        login_result: Some(Ok(())),
+        oidc_result: Some(Err("oidc auth error".to_string())),
+        oauth2_result: None,
     };
-    let router = TestRouterBuilder::new(db, nm)
-        .with_server_cfg(server_cfg)
-        .build()
-        .await;
+    let db: DynDB = Arc::new(db);
 
-    // Setup request
-    let form = "email=test%40example.test&name=Test+User&username=test-user&password=secretpw";
-    let request = Request::builder()
-        .method("POST")
-        .uri("/sign-up")
-        .header(COOKIE, format!("id={session_id}"))
-        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
-        .unwrap();
-    let response = router.oneshot(request).await.unwrap();
-    let (parts, body) = response.into_parts();
-    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    // Execute helper
+    let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let captured_error_message = error_message.clone();
+    let redirect = oidc_callback_with_auth(
+        &mut callback_auth,
+        session.clone(),
+        SessionClient::default(),
+        &db,
+        OidcProvider::LinuxFoundation,
+        "test-code".to_string(),
+        oauth2::CsrfToken::new("state-in-session".to_string()),
+        move |message| {
+            let mut guard = captured_error_message.lock().unwrap();
+            *guard = Some(message);
+        },
+    )
+    .await
+    .unwrap();
 
-    // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    // Check callback result and side effects
+    let response = redirect.into_response();
+    let auth_provider: Option<String> = session.get(AUTH_PROVIDER_KEY).await.unwrap();
+    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
+    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
     assert_eq!(
-        parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(SIGN_UP_URL),
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
     );
-    assert!(bytes.is_empty());
+    assert_eq!(
+        *error_message.lock().unwrap(),
+        Some("OpenID Connect authorization failed: oidc auth error".to_string()),
+    );
+    assert!(!callback_auth.login_called);
+    assert_eq!(auth_provider, None);
+    assert_eq!(selected_community_id, None);
+    assert_eq!(selected_group_id, None);
 }
 
 #[tokio::test]
-async fn test_sign_up_validation_error() {
-    // Setup identifiers and data structures
-    let session_id = session::Id::default();
-    let session_record = sample_empty_session_record(session_id);
-
-    // Setup database mock
-    let mut db = MockDB::new();
-    db.expect_get_session()
-        .times(1)
-        .withf(move |id| *id == session_id)
+async fn test_oidc_callback_uses_friendly_external_auth_email_conflict_message() {
+    // Setup in-memory session
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+    session
+        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
+        .await
+        .unwrap();
+    session.insert(OIDC_NONCE_KEY, "nonce-in-session").await.unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_user_groups().times(0);
+
+    // Setup callback auth mock
+    let mut callback_auth = MockCallbackAuth {
+        link_result: None,
+        login_called: false,
+        login_result: Some(Ok(())),
+        oidc_result: Some(Err(format!(
+            "db error: {}",
+            crate::auth::EXTERNAL_AUTH_EMAIL_CONFLICT_ERROR
+        ))),
+        oauth2_result: None,
+    };
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let captured_error_message = error_message.clone();
+    let redirect = oidc_callback_with_auth(
+        &mut callback_auth,
+        session,
+        SessionClient::default(),
+        &db,
+        OidcProvider::LinuxFoundation,
+        "test-code".to_string(),
+        oauth2::CsrfToken::new("state-in-session".to_string()),
+        move |message| {
+            let mut guard = captured_error_message.lock().unwrap();
+            *guard = Some(message);
+        },
+    )
+    .await
+    .unwrap();
+
+    // Check callback result and side effects
+    let response = redirect.into_response();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+    assert_eq!(
+        *error_message.lock().unwrap(),
+        Some(LF_SSO_EMAIL_CONFLICT_MESSAGE.to_string()),
+    );
+    assert!(!callback_auth.login_called);
+}
+
+#[tokio::test]
+async fn test_oidc_callback_uses_friendly_external_auth_identity_conflict_message() {
+    // Setup in-memory session
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+    session
+        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
+        .await
+        .unwrap();
+    session.insert(OIDC_NONCE_KEY, "nonce-in-session").await.unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_user_groups().times(0);
+
+    // Setup callback auth mock
+    let mut callback_auth = MockCallbackAuth {
+        link_result: None,
+        login_called: false,
+        login_result: Some(Ok(())),
+        oidc_result: Some(Err(format!(
+            "db error: {}",
+            crate::auth::EXTERNAL_AUTH_IDENTITY_CONFLICT_ERROR
+        ))),
+        oauth2_result: None,
+    };
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let error_message = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let captured_error_message = error_message.clone();
+    let redirect = oidc_callback_with_auth(
+        &mut callback_auth,
+        session,
+        SessionClient::default(),
+        &db,
+        OidcProvider::LinuxFoundation,
+        "test-code".to_string(),
+        oauth2::CsrfToken::new("state-in-session".to_string()),
+        move |message| {
+            let mut guard = captured_error_message.lock().unwrap();
+            *guard = Some(message);
+        },
+    )
+    .await
+    .unwrap();
+
+    // Check callback result and side effects
+    let response = redirect.into_response();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+    assert_eq!(
+        *error_message.lock().unwrap(),
+        Some(LF_SSO_IDENTITY_CONFLICT_MESSAGE.to_string()),
+    );
+    assert!(!callback_auth.login_called);
+}
+
+#[tokio::test]
+async fn test_oidc_callback_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let groups = sample_user_groups_by_community(community_id, group_id);
+
+    // Setup in-memory session
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+    session
+        .insert(OAUTH2_CSRF_STATE_KEY, "state-in-session")
+        .await
+        .unwrap();
+    session.insert(OIDC_NONCE_KEY, "nonce-in-session").await.unwrap();
+    session
+        .insert(NEXT_URL_KEY, Some("/dashboard".to_string()))
+        .await
+        .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(groups.clone()));
+
+    // Setup callback auth mock
+    let mut callback_auth = MockCallbackAuth {
+        link_result: None,
+        login_called: false,
+        login_result: Some(Ok(())),
+        oidc_result: Some(Ok(Some(sample_auth_user(user_id, &auth_hash)))),
+        oauth2_result: None,
+    };
+    let db: DynDB = Arc::new(db);
+
+    // Execute helper
+    let redirect = oidc_callback_with_auth(
+        &mut callback_auth,
+        session.clone(),
+        SessionClient::default(),
+        &db,
+        OidcProvider::LinuxFoundation,
+        "test-code".to_string(),
+        oauth2::CsrfToken::new("state-in-session".to_string()),
+        |_| {
+            panic!("oidc callback success should not emit an error message");
+        },
+    )
+    .await
+    .unwrap();
+
+    // Check callback result and side effects
+    let response = redirect.into_response();
+    let auth_provider: Option<String> = session.get(AUTH_PROVIDER_KEY).await.unwrap();
+    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
+    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/dashboard"),
+    );
+    assert!(callback_auth.login_called);
+    assert_eq!(
+        auth_provider.as_deref(),
+        Some(OidcProvider::LinuxFoundation.as_ref())
+    );
+    assert_eq!(selected_community_id, Some(community_id));
+    assert_eq!(selected_group_id, Some(group_id));
+}
+
+#[tokio::test]
+async fn test_oidc_callback_returns_error_when_provider_is_not_configured() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let mut session_record = sample_empty_session_record(session_id);
+    session_record
+        .data
+        .insert(OAUTH2_CSRF_STATE_KEY.to_string(), json!("state-in-session"));
+    session_record
+        .data
+        .insert(OIDC_NONCE_KEY.to_string(), json!("nonce-in-session"));
+    session_record
+        .data
+        .insert(NEXT_URL_KEY.to_string(), json!("/dashboard"));
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "OpenID Connect authorization failed: oidc provider not found",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.linuxfoundation = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("GET")
+        .uri("/log-in/oidc/linuxfoundation/callback?code=test-code&state=state-in-session")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_oidc_redirect_success() {
+    // Setup session and form input
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+    let query = Query(NextUrl {
+        next_url: Some("/dashboard".to_string()),
+    });
+
+    // Setup oidc provider details
+    let metadata = oidc::core::CoreProviderMetadata::new(
+        oidc::IssuerUrl::new("https://issuer.example".to_string()).unwrap(),
+        oidc::AuthUrl::new("https://issuer.example/authorize".to_string()).unwrap(),
+        oidc::JsonWebKeySetUrl::new("https://issuer.example/jwks".to_string()).unwrap(),
+        vec![oidc::ResponseTypes::new(vec![
+            oidc::core::CoreResponseType::Code,
+        ])],
+        vec![oidc::core::CoreSubjectIdentifierType::Public],
+        vec![oidc::core::CoreJwsSigningAlgorithm::RsaSsaPkcs1V15Sha256],
+        oidc::EmptyAdditionalProviderMetadata::default(),
+    )
+    .set_jwks(oidc::JsonWebKeySet::new(vec![]));
+    let client = oidc::core::CoreClient::from_provider_metadata(
+        metadata,
+        oidc::ClientId::new("client-id".to_string()),
+        Some(oidc::ClientSecret::new("client-secret".to_string())),
+    )
+    .set_redirect_uri(
+        oidc::RedirectUrl::new("https://app.example/log-in/oidc/provider/callback".to_string())
+            .unwrap(),
+    );
+    let provider = OidcProviderDetails {
+        client,
+        scopes: vec!["openid".to_string()],
+    };
+
+    // Execute handler
+    let response = oidc_redirect(session.clone(), Oidc(Arc::new(provider)), query)
+        .await
+        .expect("oidc redirect should succeed")
+        .into_response();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let location = parts.headers.get(LOCATION).unwrap().to_str().unwrap();
+    let (base_url, query) = location
+        .split_once('?')
+        .expect("redirect url to contain query string");
+
+    // Check response matches expectations
+    let csrf_state: Option<String> = session.get(OAUTH2_CSRF_STATE_KEY).await.unwrap();
+    let nonce: Option<String> = session.get(OIDC_NONCE_KEY).await.unwrap();
+    let next_url: Option<Option<String>> = session.get(NEXT_URL_KEY).await.unwrap();
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(base_url, "https://issuer.example/authorize");
+    assert_eq!(
+        csrf_state.as_deref(),
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("state=").map(String::from))
+            .as_deref(),
+    );
+    assert_eq!(
+        nonce.as_deref(),
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("nonce=").map(String::from))
+            .as_deref(),
+    );
+    assert_eq!(next_url, Some(Some("/dashboard".to_string())));
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_request_magic_link_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_add_user_magic_link()
+        .times(1)
+        .withf(|email, token_hash| email == "test@example.test" && token_hash.len() == 64)
+        .returning(move |_, _| Ok(Some(user_id)));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "If the email belongs to an account, you will receive a link to log in shortly.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue()
+        .times(1)
+        .withf(move |notification| {
+            matches!(notification.kind, NotificationKind::MagicLink)
+                && notification.recipients == vec![user_id]
+                && notification.template_data.as_ref().is_some_and(|data| {
+                    serde_json::from_value::<MagicLink>(data.clone()).is_ok_and(|magic_link| {
+                        magic_link.link.starts_with("https://app.example/log-in/magic-link/")
+                            && magic_link.link.ends_with("?next_url=%2Fdashboard")
+                    })
+                })
+        })
+        .returning(|_| Box::pin(async { Ok(()) }));
+
+    // Setup router
+    let server_cfg = HttpServerConfig {
+        base_url: "https://app.example".to_string(),
+        login: LoginOptions {
+            magic_link: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("POST")
+        .uri("/log-in/magic-link?next_url=%2Fdashboard")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("email=test%40example.test"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_request_magic_link_unknown_email() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_add_user_magic_link().times(1).returning(|_, _| Ok(None));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "If the email belongs to an account, you will receive a link to log in shortly.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.magic_link = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("POST")
+        .uri("/log-in/magic-link")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("email=unknown%40example.test"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_resend_email_verification_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_resend_email_verification()
+        .times(1)
+        .withf(|email, verification| {
+            email == "test@example.test"
+                && verification.template_data.link
+                    == format!("https://app.example/verify-email/{}", verification.code)
+                && verification.template_data.language == Language::Es
+        })
+        .returning(|_, _| Ok(true));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "If the email belongs to an account pending verification, you will receive a new verification link shortly.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let server_cfg = HttpServerConfig {
+        base_url: "https://app.example".to_string(),
+        login: LoginOptions {
+            email: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("POST")
+        .uri("/verify-email/resend?next_url=%2Fdashboard")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .header(ACCEPT_LANGUAGE, "es-ES,es;q=0.9,en;q=0.8")
+        .body(Body::from("email=test%40example.test"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_resend_email_verification_validation_error() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_resend_email_verification().times(0);
+    db.expect_update_session().times(1).returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("POST")
+        .uri("/verify-email/resend")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("email=not-an-email"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+}
+
+#[tokio::test]
+async fn test_reset_password_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_reset_user_password()
+        .times(1)
+        .withf(|code_hash, password| {
+            code_hash == compute_hash("reset-code".as_bytes())
+                && verify_password("new-password", password).is_ok()
+        })
+        .returning(move |_, _| Ok(Some(user_id)));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Your password has been reset. You can now log in using your new password.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("POST")
+        .uri("/reset-password/reset-code")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("password=new-password"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_reset_password_invalid_code() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_reset_user_password().times(1).returning(|_, _| Ok(None));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Invalid password reset link (please note that links are only valid for 1 hour and can only be used once).",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let request = Request::builder()
+        .method("POST")
+        .uri("/reset-password/used-code")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("password=new-password"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(FORGOT_PASSWORD_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_reset_password_validation_error() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_reset_user_password().times(0);
+    db.expect_update_session().times(1).returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request (password is too short - validation should fail)
+    let request = Request::builder()
+        .method("POST")
+        .uri("/reset-password/reset-code")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("password=short"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/reset-password/reset-code"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_sign_up_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+    let user_for_db = sample_auth_user(Uuid::new_v4(), "hash");
+    let site_settings = sample_site_settings();
+    let activation_primary_color = site_settings.theme.primary_color.clone();
+    let sign_up_primary_color = site_settings.theme.primary_color.clone();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "welcome")
+        .returning(|_| Ok(None));
+    db.expect_community_email_domain_allowed()
+        .times(1)
+        .withf(|community_name, email| community_name == "welcome" && email == "test@example.test")
+        .returning(|_, _| Ok(true));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(move || Ok(site_settings.clone()));
+    db.expect_activate_pre_registered_user_email_password()
+        .times(1)
+        .withf(move |summary, verification| {
+            summary.email == "test@example.test"
+                && !matches!(summary.password.as_deref(), Some("secret-password"))
+                && verification.template_data.link
+                    == format!("https://app.example/verify-email/{}", verification.code)
+                && verification.template_data.theme.primary_color == activation_primary_color
+                && verification.template_data.language == Language::Es
+        })
+        .returning(|_, _| Ok(None));
+    db.expect_sign_up_user()
+        .times(1)
+        .withf(move |summary, verify, verification| {
+            !matches!(summary.password.as_deref(), Some("secret-password"))
+                && !*verify
+                && verification.as_ref().is_some_and(|verification| {
+                    verification.template_data.link
+                        == format!("https://app.example/verify-email/{}", verification.code)
+                        && verification.template_data.theme.primary_color == sign_up_primary_color
+                        && verification.template_data.language == Language::Es
+                })
+        })
+        .returning({
+            let user = user_for_db;
+            move |_, _, verification| Ok((user.clone(), verification.map(|value| value.code)))
+        });
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Please verify your email to complete the sign up process.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let server_cfg = HttpServerConfig {
+        base_url: "https://app.example".to_string(),
+        login: LoginOptions {
+            email: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let form =
+        "email=test%40example.test&name=Test+User&username=test-user&password=secret-password";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up?next_url=%2Fwelcome")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .header(ACCEPT_LANGUAGE, "es-ES,es;q=0.9,en;q=0.8")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fwelcome"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_sign_up_activates_pre_registered_user() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+    let user_for_db = sample_auth_user(Uuid::new_v4(), "hash");
+    let site_settings = sample_site_settings();
+    let activation_primary_color = site_settings.theme.primary_color.clone();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "dashboard")
+        .returning(|_| Ok(None));
+    db.expect_community_email_domain_allowed()
+        .times(1)
+        .withf(|community_name, email| {
+            community_name == "dashboard" && email == "invited@example.test"
+        })
+        .returning(|_, _| Ok(true));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(move || Ok(site_settings.clone()));
+    db.expect_activate_pre_registered_user_email_password()
+        .times(1)
+        .withf(move |summary, verification| {
+            summary.email == "invited@example.test"
+                && summary.name == "Invited User"
+                && summary.username == "invited-user"
+                && !matches!(summary.password.as_deref(), Some("secret-password"))
+                && verification.template_data.link
+                    == format!("https://app.example/verify-email/{}", verification.code)
+                && verification.template_data.theme.primary_color == activation_primary_color
+        })
+        .returning({
+            let user = user_for_db;
+            move |_, verification| Ok(Some((user.clone(), verification.code)))
+        });
+    db.expect_sign_up_user().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Please verify your email to complete the sign up process.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let server_cfg = HttpServerConfig {
+        base_url: "https://app.example".to_string(),
+        login: LoginOptions {
+            email: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let form = "email=invited%40example.test&name=Invited+User&username=invited-user&password=secret-password";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up?next_url=%2Fdashboard%2Fuser%3Ftab%3Dinvitations")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fdashboard%2Fuser%3Ftab%3Dinvitations"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_sign_up_missing_password() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_activate_pre_registered_user_email_password().times(0);
+    db.expect_sign_up_user().times(0);
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request (password not provided)
+    let form = "email=test%40example.test&name=Test+User&username=test-user";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert_eq!(bytes, "password not provided");
+}
+
+#[tokio::test]
+async fn test_sign_up_rejects_empty_normalized_base_url() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_site_settings().times(0);
+    db.expect_activate_pre_registered_user_email_password().times(0);
+    db.expect_sign_up_user().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Something went wrong while signing up. Please try again later.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let server_cfg = HttpServerConfig {
+        base_url: "/".to_string(),
+        login: LoginOptions {
+            email: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let form = "email=test%40example.test&name=Test+User&username=test-user&password=secretpw";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(SIGN_UP_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_sign_up_db_error() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_activate_pre_registered_user_email_password()
+        .times(1)
+        .returning(|_, _| Ok(None));
+    db.expect_sign_up_user()
+        .times(1)
+        .returning(|_, _, _| Err(anyhow!("db error")));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Something went wrong while signing up. Please try again later.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let server_cfg = HttpServerConfig {
+        base_url: "https://app.example".to_string(),
+        login: LoginOptions {
+            email: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let form = "email=test%40example.test&name=Test+User&username=test-user&password=secretpw";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(SIGN_UP_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_sign_up_captcha_checks_disabled() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+    let user_for_db = sample_auth_user(Uuid::new_v4(), "hash");
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_activate_pre_registered_user_email_password()
+        .times(1)
+        .returning(|_, _| Ok(None));
+    db.expect_sign_up_user()
+        .times(1)
+        .returning(move |_, _, verification| {
+            Ok((user_for_db.clone(), verification.map(|value| value.code)))
+        });
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| {
+            message_matches(
+                record,
+                "Please verify your email to complete the sign up process.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let server_cfg = HttpServerConfig {
+        base_url: "https://app.example".to_string(),
+        captcha: Some(sample_captcha_config()),
+        disable_captcha_checks: true,
+        login: LoginOptions {
+            email: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request (no captcha response)
+    let form =
+        "email=test%40example.test&name=Test+User&username=test-user&password=secret-password";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+}
+
+#[tokio::test]
+async fn test_sign_up_captcha_missing_response() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "welcome")
+        .returning(|_| Ok(None));
+    db.expect_activate_pre_registered_user_email_password().times(0);
+    db.expect_sign_up_user().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| {
+            message_matches(
+                record,
+                "Please complete the CAPTCHA challenge and try again.",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    server_cfg.captcha = Some(sample_captcha_config());
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request (empty captcha response)
+    let form = "email=test%40example.test&name=Test+User&username=test-user\
+        &password=secret-password&cf-turnstile-response=";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up?next_url=%2Fwelcome")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/sign-up?next_url=%2Fwelcome"),
+    );
+}
+
+#[tokio::test]
+async fn test_sign_up_email_domain_not_allowed() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "corporate")
+        .returning(|_| Ok(None));
+    db.expect_community_email_domain_allowed()
+        .times(1)
+        .withf(|community_name, email| {
+            community_name == "corporate" && email == "test@example.test"
+        })
+        .returning(|_, _| Ok(false));
+    db.expect_activate_pre_registered_user_email_password().times(0);
+    db.expect_sign_up_user().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| message_matches(record, EMAIL_DOMAIN_NOT_ALLOWED_MESSAGE))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let form = "email=test%40example.test&name=Test+User&username=test-user\
+        &password=secret-password";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up?next_url=%2Fcorporate")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/sign-up?next_url=%2Fcorporate"),
+    );
+}
+
+#[tokio::test]
+async fn test_sign_up_sso_required() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_community_required_oidc_provider()
+        .times(1)
+        .withf(|community_name| community_name == "sso-community")
+        .returning(|_| Ok(Some("linuxfoundation".to_string())));
+    db.expect_activate_pre_registered_user_email_password().times(0);
+    db.expect_sign_up_user().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| message_matches(record, SSO_REQUIRED_MESSAGE))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request
+    let form = "email=test%40example.test&name=Test+User&username=test-user\
+        &password=secret-password";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up?next_url=%2Fsso-community")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/sign-up?next_url=%2Fsso%2Dcommunity"),
+    );
+}
+
+#[tokio::test]
+async fn test_sign_up_validation_error() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let session_record = sample_empty_session_record(session_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_activate_pre_registered_user_email_password().times(0);
+    db.expect_sign_up_user().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| {
+            message_matches(
+                record,
+                "email: not a valid email: value is missing `@`\npassword: length is lower than 8\n",
+            )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+
+    // Setup request (invalid email - validation should fail)
+    let form = "email=invalid-email&name=Test+User&username=test-user&password=secret";
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sign-up")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(SIGN_UP_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_calendar_feed_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_calendar_feed()
+        .times(1)
+        .withf(move |uid, enabled| *uid == user_id && !*enabled)
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| message_matches(record, "Calendar feed updated successfully."))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/calendar-feed")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_details_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_details()
+        .times(1)
+        .withf(move |uid, details| {
+            *uid == user_id
+                && details.optional_notifications_enabled
+                && details.weekly_digest_enabled
+                && details.name == "Updated User"
+                && details.github_url.as_deref() == Some("https://github.com/updated-user")
+                && details.language == Some(Language::Fr)
+        })
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| message_matches(record, "User details updated successfully."))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/details")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(
+            "name=Updated+User&company=Example&github_url=https%3A%2F%2Fgithub.com%2Fupdated-user&optional_notifications_enabled=true&weekly_digest_enabled=true&language=fr",
+        ))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_details_invalid_body() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_details().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/details")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::from("invalid"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_details_returns_error_on_db_failure() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_details()
+        .times(1)
+        .withf(move |uid, details| {
+            *uid == user_id
+                && !details.optional_notifications_enabled
+                && !details.weekly_digest_enabled
+                && details.name == "Updated User"
+        })
+        .returning(|_, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/details")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(
+            "name=Updated+User&company=Example&optional_notifications_enabled=false&weekly_digest_enabled=false",
+        ))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_password_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let existing_password_hash = password_auth::generate_hash("current-password");
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_user_password()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(existing_password_hash.clone())));
+    db.expect_update_user_password()
+        .times(1)
+        .withf(move |uid, new_password| *uid == user_id && new_password != "new-password")
+        .returning(|_, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let form = "old_password=current-password&new_password=new-password";
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/password")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert!(bytes.is_empty());
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_OUT_URL),
+    );
+}
+
+#[tokio::test]
+async fn test_update_user_password_wrong_old_password() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let existing_password_hash = password_auth::generate_hash("current-password");
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_user_password()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(existing_password_hash.clone())));
+    db.expect_update_user_password().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let form = "old_password=wrong-password&new_password=new-password";
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/password")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::FORBIDDEN);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_password_returns_bad_request_when_hash_is_missing() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_user_password()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(None));
+    db.expect_update_user_password().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let form = "old_password=current-password&new_password=new-password";
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/password")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_password_returns_error_on_db_failure() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let existing_password_hash = password_auth::generate_hash("current-password");
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_user_password()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(existing_password_hash.clone())));
+    db.expect_update_user_password()
+        .times(1)
+        .withf(move |uid, new_password| *uid == user_id && new_password != "new-password")
+        .returning(|_, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let form = "old_password=current-password&new_password=new-password";
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/password")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_connect_google_calendar_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| record.data.contains_key(GOOGLE_CALENDAR_CSRF_STATE_KEY))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_google_calendar_cfg(sample_google_calendar_cfg())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/account/google-calendar/connect")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    let location = parts.headers.get(LOCATION).unwrap().to_str().unwrap();
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert!(location.starts_with("https://accounts.google.com/o/oauth2/v2/auth?"));
+    assert!(location.contains("client_id=client-id"));
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_connect_google_calendar_not_configured() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/account/google-calendar/connect")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_user_passkey_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let user_passkey_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_delete_user_passkey()
+        .times(1)
+        .withf(move |uid, pid| *uid == user_id && *pid == user_passkey_id)
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| message_matches(record, "Passkey removed successfully."))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!("/dashboard/account/passkeys/{user_passkey_id}"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_disconnect_google_calendar_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_disconnect_user_google_calendar()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| message_matches(record, "Google Calendar disconnected successfully."))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri("/dashboard/account/google-calendar")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_google_calendar_callback_state_mismatch() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let mut session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    session_record.data.insert(
+        GOOGLE_CALENDAR_CSRF_STATE_KEY.to_string(),
+        json!("state-in-session"),
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_add_user_google_calendar().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| {
+            message_matches(record, "Google Calendar connection failed")
+                && !record.data.contains_key(GOOGLE_CALENDAR_CSRF_STATE_KEY)
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_google_calendar_cfg(sample_google_calendar_cfg())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/account/google-calendar/callback?code=test-code&state=state-in-request")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(USER_DASHBOARD_ACCOUNT_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_revoke_user_session_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let user_session_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_revoke_user_session()
+        .times(1)
+        .withf(move |uid, sid| *uid == user_id && *sid == user_session_id)
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| message_matches(record, "Session revoked successfully."))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!("/dashboard/account/sessions/{user_session_id}"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_revoke_user_sessions_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_revoke_user_sessions()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(|_| Ok(()));
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri("/dashboard/account/sessions")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get("HX-Redirect").unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_unlink_user_provider_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_unlink_user_provider()
+        .times(1)
+        .withf(move |uid, provider| *uid == user_id && provider == "github")
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(|record| message_matches(record, "Account unlinked successfully."))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri("/dashboard/account/providers/github")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_unlink_user_provider_invalid_provider() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_unlink_user_provider().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri("/dashboard/account/providers/unknown")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert_eq!(bytes.as_ref(), b"invalid provider");
+}
+
+#[tokio::test]
+async fn test_update_user_phone_number_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_activate_pre_registered_user_email_password().times(0);
-    db.expect_sign_up_user().times(0);
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_phone_number()
+        .times(1)
+        .withf(move |uid, phone| {
+            *uid == user_id && phone.phone_number.as_deref() == Some("+15551234567")
+        })
+        .returning(|_, _| Ok(()));
     db.expect_update_session()
         .times(1)
-        .withf(|record| {
+        .withf(move |record| {
             message_matches(
                 record,
-                "email: not a valid email: value is missing `@`\npassword: length is lower than 8\n",
+                "We sent you a code by SMS to verify your phone number.",
             )
         })
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
-    let mut nm = MockNotificationsManager::new();
-    nm.expect_enqueue().times(0);
+    let nm = MockNotificationsManager::new();
 
-    // Setup router
-    let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.email = true;
+    // Setup router and send request
     let router = TestRouterBuilder::new(db, nm)
-        .with_server_cfg(server_cfg)
+        .with_sms_cfg(sample_sms_cfg())
         .build()
         .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/phone")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("phone_number=%2B15551234567"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
-    // Setup request (invalid email - validation should fail)
-    let form = "email=invalid-email&name=Test+User&username=test-user&password=secret";
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_phone_number_invalid_body() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_phone_number().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_sms_cfg(sample_sms_cfg())
+        .build()
+        .await;
     let request = Request::builder()
-        .method("POST")
-        .uri("/sign-up")
+        .method("PUT")
+        .uri("/dashboard/account/update/phone")
+        .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
+        .body(Body::from("phone_number=555-1234"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_phone_number_sms_not_configured() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_phone_number().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/phone")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("phone_number=%2B15551234567"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_update_user_sms_notifications_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_sms_notifications()
+        .times(1)
+        .withf(move |uid, enabled| *uid == user_id && *enabled)
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| message_matches(record, "SMS notifications updated successfully."))
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/sms")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("sms_notifications_enabled=true"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
     assert_eq!(
-        parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(SIGN_UP_URL),
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_update_user_details_success() {
+async fn test_update_user_webhook_success() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
@@ -1952,18 +4594,15 @@ async fn test_update_user_details_success() {
         .times(1)
         .withf(move |id| *id == user_id)
         .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_update_user_details()
+    db.expect_update_user_webhook()
         .times(1)
-        .withf(move |uid, details| {
-            *uid == user_id
-                && details.optional_notifications_enabled
-                && details.name == "Updated User"
-                && details.github_url.as_deref() == Some("https://github.com/updated-user")
+        .withf(move |uid, webhook| {
+            *uid == user_id && webhook.webhook_url.as_deref() == Some("https://203.0.113.10/hook")
         })
         .returning(|_, _| Ok(()));
     db.expect_update_session()
         .times(1)
-        .withf(move |record| message_matches(record, "User details updated successfully."))
+        .withf(move |record| message_matches(record, "Webhook updated successfully."))
         .returning(|_| Ok(()));
 
     // Setup notifications manager mock
@@ -1973,13 +4612,11 @@ async fn test_update_user_details_success() {
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
         .method("PUT")
-        .uri("/dashboard/account/update/details")
+        .uri("/dashboard/account/update/webhook")
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(
-            "name=Updated+User&company=Example&github_url=https%3A%2F%2Fgithub.com%2Fupdated-user&optional_notifications_enabled=true",
-        ))
+        .body(Body::from("webhook_url=https%3A%2F%2F203.0.113.10%2Fhook"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -1995,7 +4632,7 @@ async fn test_update_user_details_success() {
 }
 
 #[tokio::test]
-async fn test_update_user_details_invalid_body() {
+async fn test_update_user_webhook_clears_blank_webhook() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
@@ -2012,7 +4649,14 @@ async fn test_update_user_details_invalid_body() {
         .times(1)
         .withf(move |id| *id == user_id)
         .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_update_user_details().times(0);
+    db.expect_update_user_webhook()
+        .times(1)
+        .withf(move |uid, webhook| *uid == user_id && webhook.webhook_url.is_none())
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| message_matches(record, "Webhook updated successfully."))
+        .returning(|_| Ok(()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -2021,10 +4665,99 @@ async fn test_update_user_details_invalid_body() {
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
         .method("PUT")
-        .uri("/dashboard/account/update/details")
+        .uri("/dashboard/account/update/webhook")
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::from("invalid"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("webhook_url="))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_webhook_invalid_body() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_webhook().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/webhook")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("webhook_url=not-a-url"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_user_webhook_internal_host() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_update_user_webhook().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/dashboard/account/update/webhook")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("webhook_url=https%3A%2F%2Flocalhost%2Fhook"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -2036,12 +4769,11 @@ async fn test_update_user_details_invalid_body() {
 }
 
 #[tokio::test]
-async fn test_update_user_details_returns_error_on_db_failure() {
+async fn test_verify_email_success() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let user_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let session_record = sample_empty_session_record(session_id);
+    let verification_code = Uuid::new_v4();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2049,51 +4781,56 @@ async fn test_update_user_details_returns_error_on_db_failure() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_user_by_id()
+    db.expect_verify_email()
         .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_update_user_details()
+        .withf(move |code| *code == verification_code)
+        .returning(|_| Ok(()));
+    db.expect_update_session()
         .times(1)
-        .withf(move |uid, details| {
-            *uid == user_id
-                && !details.optional_notifications_enabled
-                && details.name == "Updated User"
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Email verified successfully. You can now log in using your credentials.",
+            )
         })
-        .returning(|_, _| Err(anyhow!("db error")));
+        .returning(|_| Ok(()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
     // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
     let request = Request::builder()
-        .method("PUT")
-        .uri("/dashboard/account/update/details")
+        .method("GET")
+        .uri(format!("/verify-email/{verification_code}"))
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
-        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(
-            "name=Updated+User&company=Example&optional_notifications_enabled=false",
-        ))
+        .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
     assert!(bytes.is_empty());
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
 }
 
 #[tokio::test]
-async fn test_update_user_password_success() {
+async fn test_verify_email_failure() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let user_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
-    let existing_password_hash = password_auth::generate_hash("current-password");
+    let session_record = sample_empty_session_record(session_id);
+    let verification_code = Uuid::new_v4();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2101,32 +4838,36 @@ async fn test_update_user_password_success() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_user_by_id()
-        .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_get_user_password()
+    db.expect_verify_email()
         .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(Some(existing_password_hash.clone())));
-    db.expect_update_user_password()
+        .withf(move |code| *code == verification_code)
+        .returning(|_| Err(anyhow!("db error")));
+    db.expect_update_session()
         .times(1)
-        .withf(move |uid, new_password| *uid == user_id && new_password != "new-password")
-        .returning(|_, _| Ok(()));
+        .withf(move |record| {
+            message_matches(
+                record,
+                "Error verifying email (please note that links are only valid for 24 hours).",
+            )
+        })
+        .returning(|_| Ok(()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
     // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
-    let form = "old_password=current-password&new_password=new-password";
+    let mut server_cfg = HttpServerConfig::default();
+    server_cfg.login.email = true;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
     let request = Request::builder()
-        .method("PUT")
-        .uri("/dashboard/account/update/password")
+        .method("GET")
+        .uri(format!("/verify-email/{verification_code}"))
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
-        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
+        .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -2137,18 +4878,17 @@ async fn test_update_user_password_success() {
     assert!(bytes.is_empty());
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_OUT_URL),
+        &HeaderValue::from_static(LOG_IN_URL),
     );
 }
 
 #[tokio::test]
-async fn test_update_user_password_wrong_old_password() {
+async fn test_verify_user_phone_number_success() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
     let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
-    let existing_password_hash = password_auth::generate_hash("current-password");
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2160,37 +4900,43 @@ async fn test_update_user_password_wrong_old_password() {
         .times(1)
         .withf(move |id| *id == user_id)
         .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_get_user_password()
+    db.expect_verify_user_phone_number()
         .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(Some(existing_password_hash.clone())));
-    db.expect_update_user_password().times(0);
+        .withf(move |uid, verification| *uid == user_id && verification.code == "123456")
+        .returning(|_, _| Ok(true));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| message_matches(record, "Phone number verified successfully."))
+        .returning(|_| Ok(()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
     // Setup router and send request
     let router = TestRouterBuilder::new(db, nm).build().await;
-    let form = "old_password=wrong-password&new_password=new-password";
     let request = Request::builder()
         .method("PUT")
-        .uri("/dashboard/account/update/password")
+        .uri("/dashboard/account/verify/phone")
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
+        .body(Body::from("code=123456"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::FORBIDDEN);
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        parts.headers.get("HX-Trigger").unwrap(),
+        &HeaderValue::from_static("refresh-body"),
+    );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_update_user_password_returns_bad_request_when_hash_is_missing() {
+async fn test_verify_user_phone_number_invalid_code() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
@@ -2207,43 +4953,148 @@ async fn test_update_user_password_returns_bad_request_when_hash_is_missing() {
         .times(1)
         .withf(move |id| *id == user_id)
         .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_get_user_password()
+    db.expect_verify_user_phone_number()
         .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(|_| Ok(None));
-    db.expect_update_user_password().times(0);
+        .withf(move |uid, verification| *uid == user_id && verification.code == "000000")
+        .returning(|_, _| Ok(false));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
     // Setup router and send request
     let router = TestRouterBuilder::new(db, nm).build().await;
-    let form = "old_password=current-password&new_password=new-password";
     let request = Request::builder()
         .method("PUT")
-        .uri("/dashboard/account/update/password")
+        .uri("/dashboard/account/verify/phone")
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
+        .body(Body::from("code=000000"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
-    assert!(bytes.is_empty());
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(bytes, "invalid or expired verification code");
 }
 
 #[tokio::test]
-async fn test_update_user_password_returns_error_on_db_failure() {
+async fn test_select_first_community_and_group_selects_community_when_user_has_no_groups() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_user_communities()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(move |_| Ok(vec![sample_community_summary(community_id)]));
+
+    // Setup in-memory session
+    let db: DynDB = Arc::new(db);
+    let store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, store, None);
+
+    // Execute helper
+    select_first_community_and_group(&db, &session, &user_id)
+        .await
+        .expect("helper should select first available community");
+
+    // Check session data matches expectations
+    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
+    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
+    assert_eq!(selected_community_id, Some(community_id));
+    assert_eq!(selected_group_id, None);
+}
+
+#[test]
+fn test_available_login_options() {
+    let login = LoginOptions {
+        email: true,
+        github: true,
+        linuxfoundation: true,
+        passkey: true,
+        ..Default::default()
+    };
+
+    // All options are available when no provider is required
+    let options = available_login_options(&login, None);
+    assert!(options.email && options.github && options.linuxfoundation && options.passkey);
+
+    // Only the required provider is available otherwise
+    let options = available_login_options(&login, Some(&OidcProvider::LinuxFoundation));
+    assert!(options.linuxfoundation);
+    assert!(!options.email && !options.github && !options.passkey);
+}
+
+#[test]
+fn test_community_name_from_next_url() {
+    assert_eq!(
+        community_name_from_next_url(Some("/my-community/group/abc")),
+        Some("my-community".to_string())
+    );
+    assert_eq!(
+        community_name_from_next_url(Some("/my-community?tab=events")),
+        Some("my-community".to_string())
+    );
+    assert_eq!(community_name_from_next_url(Some("/")), None);
+    assert_eq!(community_name_from_next_url(Some("//evil.example")), None);
+    assert_eq!(community_name_from_next_url(None), None);
+}
+
+#[test]
+fn test_get_log_in_url_without_next() {
+    let url = get_log_in_url(None);
+    assert_eq!(url, LOG_IN_URL);
+}
+
+#[test]
+fn test_get_log_in_url_with_next() {
+    let url = get_log_in_url(Some("/dashboard"));
+    assert_eq!(url, "/log-in?next_url=%2Fdashboard");
+}
+
+#[test]
+fn test_sanitize_next_url_accepts_internal_paths() {
+    assert_eq!(
+        sanitize_next_url(Some("/dashboard")),
+        Some("/dashboard".to_string())
+    );
+    assert_eq!(
+        sanitize_next_url(Some("/groups?page=2#section")),
+        Some("/groups?page=2#section".to_string())
+    );
+    assert_eq!(
+        sanitize_next_url(Some("   /profile  ")),
+        Some("/profile".to_string())
+    );
+}
+
+#[test]
+fn test_sanitize_next_url_rejects_external_paths() {
+    assert_eq!(sanitize_next_url(Some("")), None);
+    assert_eq!(sanitize_next_url(Some("https://evil.example")), None);
+    assert_eq!(sanitize_next_url(Some("//evil.example")), None);
+    assert_eq!(sanitize_next_url(Some("javascript:alert(1)")), None);
+    assert_eq!(sanitize_next_url(Some("relative/path")), None);
+}
+
+#[tokio::test]
+async fn test_user_has_community_dashboard_permission_allows_request() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
-    let existing_password_hash = password_auth::generate_hash("current-password");
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2255,44 +5106,65 @@ async fn test_update_user_password_returns_error_on_db_failure() {
         .times(1)
         .withf(move |id| *id == user_id)
         .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_get_user_password()
-        .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(Some(existing_password_hash.clone())));
-    db.expect_update_user_password()
+    db.expect_user_has_community_permission()
         .times(1)
-        .withf(move |uid, new_password| *uid == user_id && new_password != "new-password")
-        .returning(|_, _| Err(anyhow!("db error")));
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_list_user_communities().times(0);
+    db.expect_list_user_groups().times(0);
+    db.expect_update_session().times(0);
 
-    // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    // Setup router
+    let server_cfg = HttpServerConfig::default();
+    let db: DynDB = Arc::new(db);
+    let nm = Arc::new(MockNotificationsManager::new());
+    let state = test_state_with_server_cfg(
+        db.clone(),
+        Arc::new(MockImageStorage::new()),
+        nm.clone(),
+        &server_cfg,
+    );
+    let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
+    let router = Router::new()
+        .route("/protected", get(|| async { StatusCode::OK }))
+        .layer(middleware::from_fn_with_state(
+            db.clone(),
+            user_has_community_dashboard_permission,
+        ))
+        .layer(MessagesManagerLayer)
+        .layer(auth_layer)
+        .with_state(state);
 
-    // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
-    let form = "old_password=current-password&new_password=new-password";
+    // Execute request
     let request = Request::builder()
-        .method("PUT")
-        .uri("/dashboard/account/update/password")
-        .header(HOST, "example.test")
+        .method("GET")
+        .uri("/protected")
         .header(COOKIE, format!("id={session_id}"))
-        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from(form))
+        .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(parts.status, StatusCode::OK);
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_verify_email_success() {
+async fn test_user_has_community_dashboard_permission_returns_error_on_db_failure() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let session_record = sample_empty_session_record(session_id);
-    let verification_code = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2300,34 +5172,45 @@ async fn test_verify_email_success() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_verify_email()
+    db.expect_get_user_by_id()
         .times(1)
-        .withf(move |code| *code == verification_code)
-        .returning(|_| Ok(()));
-    db.expect_update_session()
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
         .times(1)
-        .withf(move |record| {
-            message_matches(
-                record,
-                "Email verified successfully. You can now log in using your credentials.",
-            )
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
-        .returning(|_| Ok(()));
+        .returning(|_, _, _| Err(anyhow!("db error")));
+    db.expect_list_user_communities().times(0);
+    db.expect_list_user_groups().times(0);
+    db.expect_update_session().times(0);
 
-    // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    // Setup router
+    let server_cfg = HttpServerConfig::default();
+    let db: DynDB = Arc::new(db);
+    let nm = Arc::new(MockNotificationsManager::new());
+    let state = test_state_with_server_cfg(
+        db.clone(),
+        Arc::new(MockImageStorage::new()),
+        nm.clone(),
+        &server_cfg,
+    );
+    let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
+    let router = Router::new()
+        .route("/protected", get(|| async { StatusCode::OK }))
+        .layer(middleware::from_fn_with_state(
+            db.clone(),
+            user_has_community_dashboard_permission,
+        ))
+        .layer(MessagesManagerLayer)
+        .layer(auth_layer)
+        .with_state(state);
 
-    // Setup router and send request
-    let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.email = true;
-    let router = TestRouterBuilder::new(db, nm)
-        .with_server_cfg(server_cfg)
-        .build()
-        .await;
+    // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/verify-email/{verification_code}"))
-        .header(HOST, "example.test")
+        .uri("/protected")
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -2336,20 +5219,18 @@ async fn test_verify_email_success() {
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
     assert!(bytes.is_empty());
-    assert_eq!(
-        parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
-    );
 }
 
 #[tokio::test]
-async fn test_verify_email_failure() {
+async fn test_user_has_community_dashboard_permission_redirects_when_context_is_missing_and_unavailable()
+ {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
-    let session_record = sample_empty_session_record(session_id);
-    let verification_code = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2357,34 +5238,43 @@ async fn test_verify_email_failure() {
         .times(1)
         .withf(move |id| *id == session_id)
         .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_verify_email()
+    db.expect_get_user_by_id()
         .times(1)
-        .withf(move |code| *code == verification_code)
-        .returning(|_| Err(anyhow!("db error")));
-    db.expect_update_session()
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission().times(0);
+    db.expect_list_user_communities()
         .times(1)
-        .withf(move |record| {
-            message_matches(
-                record,
-                "Error verifying email (please note that links are only valid for 24 hours).",
-            )
-        })
-        .returning(|_| Ok(()));
+        .withf(move |uid| *uid == user_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_user_groups().times(0);
+    db.expect_update_session().times(0);
 
-    // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    // Setup router
+    let server_cfg = HttpServerConfig::default();
+    let db: DynDB = Arc::new(db);
+    let nm = Arc::new(MockNotificationsManager::new());
+    let state = test_state_with_server_cfg(
+        db.clone(),
+        Arc::new(MockImageStorage::new()),
+        nm.clone(),
+        &server_cfg,
+    );
+    let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
+    let router = Router::new()
+        .route("/protected", get(|| async { StatusCode::OK }))
+        .layer(middleware::from_fn_with_state(
+            db.clone(),
+            user_has_community_dashboard_permission,
+        ))
+        .layer(MessagesManagerLayer)
+        .layer(auth_layer)
+        .with_state(state);
 
-    // Setup router and send request
-    let mut server_cfg = HttpServerConfig::default();
-    server_cfg.login.email = true;
-    let router = TestRouterBuilder::new(db, nm)
-        .with_server_cfg(server_cfg)
-        .build()
-        .await;
+    // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/verify-email/{verification_code}"))
-        .header(HOST, "example.test")
+        .uri("/protected")
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -2394,94 +5284,120 @@ async fn test_verify_email_failure() {
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::SEE_OTHER);
-    assert!(bytes.is_empty());
     assert_eq!(
         parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
+        &HeaderValue::from_static(USER_DASHBOARD_INVITATIONS_URL),
     );
+    assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_select_first_community_and_group_selects_community_when_user_has_no_groups() {
+async fn test_user_has_community_dashboard_permission_repairs_missing_context() {
     // Setup identifiers and data structures
-    let community_id = Uuid::new_v4();
+    let accessible_community_id = Uuid::new_v4();
+    let accessible_group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let groups = sample_user_groups_by_community(accessible_community_id, accessible_group_id);
 
     // Setup database mock
     let mut db = MockDB::new();
-    db.expect_list_user_groups()
+    db.expect_get_session()
         .times(1)
-        .withf(move |uid| *uid == user_id)
-        .returning(|_| Ok(vec![]));
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
     db.expect_list_user_communities()
         .times(1)
         .withf(move |uid| *uid == user_id)
-        .returning(move |_| Ok(vec![sample_community_summary(community_id)]));
+        .returning(move |_| Ok(vec![sample_community_summary(accessible_community_id)]));
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(move |_| Ok(groups.clone()));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == accessible_community_id
+                && *uid == user_id
+                && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            record.id == session_id
+                && record
+                    .data
+                    .get(SELECTED_COMMUNITY_ID_KEY)
+                    .is_some_and(|value| value == &json!(accessible_community_id))
+                && record
+                    .data
+                    .get(SELECTED_GROUP_ID_KEY)
+                    .is_some_and(|value| value == &json!(accessible_group_id))
+        })
+        .returning(|_| Ok(()));
 
-    // Setup in-memory session
+    // Setup router
+    let server_cfg = HttpServerConfig::default();
     let db: DynDB = Arc::new(db);
-    let store = Arc::new(MemoryStore::default());
-    let session = Session::new(None, store, None);
-
-    // Execute helper
-    select_first_community_and_group(&db, &session, &user_id)
-        .await
-        .expect("helper should select first available community");
-
-    // Check session data matches expectations
-    let selected_community_id: Option<Uuid> = session.get(SELECTED_COMMUNITY_ID_KEY).await.unwrap();
-    let selected_group_id: Option<Uuid> = session.get(SELECTED_GROUP_ID_KEY).await.unwrap();
-    assert_eq!(selected_community_id, Some(community_id));
-    assert_eq!(selected_group_id, None);
-}
-
-#[test]
-fn test_get_log_in_url_without_next() {
-    let url = get_log_in_url(None);
-    assert_eq!(url, LOG_IN_URL);
-}
-
-#[test]
-fn test_get_log_in_url_with_next() {
-    let url = get_log_in_url(Some("/dashboard"));
-    assert_eq!(url, "/log-in?next_url=%2Fdashboard");
-}
-
-#[test]
-fn test_sanitize_next_url_accepts_internal_paths() {
-    assert_eq!(
-        sanitize_next_url(Some("/dashboard")),
-        Some("/dashboard".to_string())
-    );
-    assert_eq!(
-        sanitize_next_url(Some("/groups?page=2#section")),
-        Some("/groups?page=2#section".to_string())
-    );
-    assert_eq!(
-        sanitize_next_url(Some("   /profile  ")),
-        Some("/profile".to_string())
+    let nm = Arc::new(MockNotificationsManager::new());
+    let state = test_state_with_server_cfg(
+        db.clone(),
+        Arc::new(MockImageStorage::new()),
+        nm.clone(),
+        &server_cfg,
     );
-}
+    let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
+    let router = Router::new()
+        .route("/protected", get(|| async { StatusCode::OK }))
+        .layer(middleware::from_fn_with_state(
+            db.clone(),
+            user_has_community_dashboard_permission,
+        ))
+        .layer(MessagesManagerLayer)
+        .layer(auth_layer)
+        .with_state(state);
+
+    // Execute request
+    let request = Request::builder()
+        .method("GET")
+        .uri("/protected")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
-#[test]
-fn test_sanitize_next_url_rejects_external_paths() {
-    assert_eq!(sanitize_next_url(Some("")), None);
-    assert_eq!(sanitize_next_url(Some("https://evil.example")), None);
-    assert_eq!(sanitize_next_url(Some("//evil.example")), None);
-    assert_eq!(sanitize_next_url(Some("javascript:alert(1)")), None);
-    assert_eq!(sanitize_next_url(Some("relative/path")), None);
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_community_dashboard_permission_allows_request() {
+async fn test_user_has_community_dashboard_permission_logs_out_when_selected_community_is_stale() {
     // Setup identifiers and data structures
+    let inaccessible_community_id = Uuid::new_v4();
+    let stale_group_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
-    let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record =
-        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
-
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(inaccessible_community_id),
+        Some(stale_group_id),
+    );
     // Setup database mock
     let mut db = MockDB::new();
     db.expect_get_session()
@@ -2495,12 +5411,18 @@ async fn test_user_has_community_dashboard_permission_allows_request() {
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
-            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+            *cid == inaccessible_community_id
+                && *uid == user_id
+                && permission == CommunityPermission::Read
         })
-        .returning(|_, _, _| Ok(true));
+        .returning(|_, _, _| Ok(false));
     db.expect_list_user_communities().times(0);
     db.expect_list_user_groups().times(0);
     db.expect_update_session().times(0);
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -2519,6 +5441,7 @@ async fn test_user_has_community_dashboard_permission_allows_request() {
             db.clone(),
             user_has_community_dashboard_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -2534,19 +5457,30 @@ async fn test_user_has_community_dashboard_permission_allows_request() {
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL)
+    );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_community_dashboard_permission_returns_error_on_db_failure() {
+async fn test_user_has_community_dashboard_permission_hx_redirects_when_selected_context_is_stale()
+{
     // Setup identifiers and data structures
+    let inaccessible_community_id = Uuid::new_v4();
+    let stale_group_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
-    let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record =
-        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(inaccessible_community_id),
+        Some(stale_group_id),
+    );
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2561,12 +5495,18 @@ async fn test_user_has_community_dashboard_permission_returns_error_on_db_failur
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
-            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+            *cid == inaccessible_community_id
+                && *uid == user_id
+                && permission == CommunityPermission::Read
         })
-        .returning(|_, _, _| Err(anyhow!("db error")));
+        .returning(|_, _, _| Ok(false));
     db.expect_list_user_communities().times(0);
     db.expect_list_user_groups().times(0);
     db.expect_update_session().times(0);
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -2585,6 +5525,7 @@ async fn test_user_has_community_dashboard_permission_returns_error_on_db_failur
             db.clone(),
             user_has_community_dashboard_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -2592,6 +5533,7 @@ async fn test_user_has_community_dashboard_permission_returns_error_on_db_failur
     let request = Request::builder()
         .method("GET")
         .uri("/protected")
+        .header("HX-Request", "true")
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -2600,18 +5542,30 @@ async fn test_user_has_community_dashboard_permission_returns_error_on_db_failur
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get("HX-Redirect").unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
+    );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_community_dashboard_permission_redirects_when_context_is_missing_and_unavailable()
+async fn test_user_has_community_dashboard_permission_fetch_redirects_when_selected_context_is_stale()
  {
     // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let stale_group_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(stale_group_id),
+    );
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2623,13 +5577,19 @@ async fn test_user_has_community_dashboard_permission_redirects_when_context_is_
         .times(1)
         .withf(move |id| *id == user_id)
         .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_user_has_community_permission().times(0);
-    db.expect_list_user_communities()
+    db.expect_user_has_community_permission()
         .times(1)
-        .withf(move |uid| *uid == user_id)
-        .returning(|_| Ok(vec![]));
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(false));
+    db.expect_list_user_communities().times(0);
     db.expect_list_user_groups().times(0);
     db.expect_update_session().times(0);
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -2648,6 +5608,7 @@ async fn test_user_has_community_dashboard_permission_redirects_when_context_is_
             db.clone(),
             user_has_community_dashboard_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -2655,6 +5616,7 @@ async fn test_user_has_community_dashboard_permission_redirects_when_context_is_
     let request = Request::builder()
         .method("GET")
         .uri("/protected")
+        .header("X-OCG-Fetch", "true")
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -2663,24 +5625,22 @@ async fn test_user_has_community_dashboard_permission_redirects_when_context_is_
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(parts.status, StatusCode::UNAUTHORIZED);
     assert_eq!(
-        parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(USER_DASHBOARD_INVITATIONS_URL),
+        parts.headers.get("X-OCG-Redirect").unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL),
     );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_community_dashboard_permission_repairs_missing_context() {
+async fn test_user_has_path_community_permission_select_route_allows_request() {
     // Setup identifiers and data structures
-    let accessible_community_id = Uuid::new_v4();
-    let accessible_group_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
     let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
-    let groups = sample_user_groups_by_community(accessible_community_id, accessible_group_id);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2692,36 +5652,15 @@ async fn test_user_has_community_dashboard_permission_repairs_missing_context()
         .times(1)
         .withf(move |id| *id == user_id)
         .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
-    db.expect_list_user_communities()
-        .times(1)
-        .withf(move |uid| *uid == user_id)
-        .returning(move |_| Ok(vec![sample_community_summary(accessible_community_id)]));
-    db.expect_list_user_groups()
-        .times(1)
-        .withf(move |uid| *uid == user_id)
-        .returning(move |_| Ok(groups.clone()));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
-            *cid == accessible_community_id
-                && *uid == user_id
-                && permission == CommunityPermission::Read
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
-    db.expect_update_session()
-        .times(1)
-        .withf(move |record| {
-            record.id == session_id
-                && record
-                    .data
-                    .get(SELECTED_COMMUNITY_ID_KEY)
-                    .is_some_and(|value| value == &json!(accessible_community_id))
-                && record
-                    .data
-                    .get(SELECTED_GROUP_ID_KEY)
-                    .is_some_and(|value| value == &json!(accessible_group_id))
-        })
-        .returning(|_| Ok(()));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -2735,18 +5674,22 @@ async fn test_user_has_community_dashboard_permission_repairs_missing_context()
     );
     let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
     let router = Router::new()
-        .route("/protected", get(|| async { StatusCode::OK }))
+        .route(
+            "/{community_id}/protected",
+            get(|| async { StatusCode::OK }),
+        )
         .layer(middleware::from_fn_with_state(
-            db.clone(),
-            user_has_community_dashboard_permission,
+            (db.clone(), CommunityPermission::Read),
+            user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
     // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri("/protected")
+        .uri(format!("/{community_id}/protected"))
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -2760,20 +5703,14 @@ async fn test_user_has_community_dashboard_permission_repairs_missing_context()
 }
 
 #[tokio::test]
-async fn test_user_has_community_dashboard_permission_logs_out_when_selected_community_is_stale() {
+async fn test_user_has_path_community_permission_select_route_forbidden_without_permission() {
     // Setup identifiers and data structures
-    let inaccessible_community_id = Uuid::new_v4();
-    let stale_group_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(
-        session_id,
-        user_id,
-        &auth_hash,
-        Some(inaccessible_community_id),
-        Some(stale_group_id),
-    );
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
     // Setup database mock
     let mut db = MockDB::new();
     db.expect_get_session()
@@ -2787,18 +5724,9 @@ async fn test_user_has_community_dashboard_permission_logs_out_when_selected_com
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
-            *cid == inaccessible_community_id
-                && *uid == user_id
-                && permission == CommunityPermission::Read
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(false));
-    db.expect_list_user_communities().times(0);
-    db.expect_list_user_groups().times(0);
-    db.expect_update_session().times(0);
-    db.expect_delete_session()
-        .times(1)
-        .withf(move |id| *id == session_id)
-        .returning(|_| Ok(()));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -2812,18 +5740,22 @@ async fn test_user_has_community_dashboard_permission_logs_out_when_selected_com
     );
     let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
     let router = Router::new()
-        .route("/protected", get(|| async { StatusCode::OK }))
+        .route(
+            "/{community_id}/protected",
+            get(|| async { StatusCode::OK }),
+        )
         .layer(middleware::from_fn_with_state(
-            db.clone(),
-            user_has_community_dashboard_permission,
+            (db.clone(), CommunityPermission::Read),
+            user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
     // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri("/protected")
+        .uri(format!("/{community_id}/protected"))
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -2832,30 +5764,18 @@ async fn test_user_has_community_dashboard_permission_logs_out_when_selected_com
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::SEE_OTHER);
-    assert_eq!(
-        parts.headers.get(LOCATION).unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL)
-    );
+    assert_eq!(parts.status, StatusCode::FORBIDDEN);
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_community_dashboard_permission_hx_redirects_when_selected_context_is_stale()
-{
+async fn test_user_has_path_community_permission_select_route_returns_error_on_db_failure() {
     // Setup identifiers and data structures
-    let inaccessible_community_id = Uuid::new_v4();
-    let stale_group_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(
-        session_id,
-        user_id,
-        &auth_hash,
-        Some(inaccessible_community_id),
-        Some(stale_group_id),
-    );
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2870,18 +5790,9 @@ async fn test_user_has_community_dashboard_permission_hx_redirects_when_selected
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
-            *cid == inaccessible_community_id
-                && *uid == user_id
-                && permission == CommunityPermission::Read
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
-        .returning(|_, _, _| Ok(false));
-    db.expect_list_user_communities().times(0);
-    db.expect_list_user_groups().times(0);
-    db.expect_update_session().times(0);
-    db.expect_delete_session()
-        .times(1)
-        .withf(move |id| *id == session_id)
-        .returning(|_| Ok(()));
+        .returning(|_, _, _| Err(anyhow!("db error")));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -2895,19 +5806,22 @@ async fn test_user_has_community_dashboard_permission_hx_redirects_when_selected
     );
     let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
     let router = Router::new()
-        .route("/protected", get(|| async { StatusCode::OK }))
+        .route(
+            "/{community_id}/protected",
+            get(|| async { StatusCode::OK }),
+        )
         .layer(middleware::from_fn_with_state(
-            db.clone(),
-            user_has_community_dashboard_permission,
+            (db.clone(), CommunityPermission::Read),
+            user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
     // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri("/protected")
-        .header("HX-Request", "true")
+        .uri(format!("/{community_id}/protected"))
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -2916,30 +5830,18 @@ async fn test_user_has_community_dashboard_permission_hx_redirects_when_selected
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::OK);
-    assert_eq!(
-        parts.headers.get("HX-Redirect").unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
-    );
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_community_dashboard_permission_fetch_redirects_when_selected_context_is_stale()
- {
-    // Setup identifiers and data structures
-    let community_id = Uuid::new_v4();
-    let stale_group_id = Uuid::new_v4();
-    let session_id = session::Id::default();
-    let user_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(
-        session_id,
-        user_id,
-        &auth_hash,
-        Some(community_id),
-        Some(stale_group_id),
-    );
+async fn test_user_has_path_community_permission_allows_request() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2956,14 +5858,10 @@ async fn test_user_has_community_dashboard_permission_fetch_redirects_when_selec
         .withf(move |cid, uid, permission| {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
-        .returning(|_, _, _| Ok(false));
-    db.expect_list_user_communities().times(0);
-    db.expect_list_user_groups().times(0);
-    db.expect_update_session().times(0);
-    db.expect_delete_session()
-        .times(1)
-        .withf(move |id| *id == session_id)
-        .returning(|_| Ok(()));
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -2977,19 +5875,22 @@ async fn test_user_has_community_dashboard_permission_fetch_redirects_when_selec
     );
     let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
     let router = Router::new()
-        .route("/protected", get(|| async { StatusCode::OK }))
+        .route(
+            "/community/{community_id}/select",
+            get(|| async { StatusCode::OK }),
+        )
         .layer(middleware::from_fn_with_state(
-            db.clone(),
-            user_has_community_dashboard_permission,
+            (db.clone(), CommunityPermission::Read),
+            user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
     // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri("/protected")
-        .header("X-OCG-Fetch", "true")
+        .uri(format!("/community/{community_id}/select"))
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -2998,22 +5899,21 @@ async fn test_user_has_community_dashboard_permission_fetch_redirects_when_selec
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::UNAUTHORIZED);
-    assert_eq!(
-        parts.headers.get("X-OCG-Redirect").unwrap(),
-        &HeaderValue::from_static(LOG_IN_URL),
-    );
+    assert_eq!(parts.status, StatusCode::OK);
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_path_community_permission_select_route_allows_request() {
+async fn test_user_has_path_community_permission_allows_passkey_session_when_required() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
     let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let mut session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    session_record
+        .data
+        .insert(AUTH_PROVIDER_KEY.to_string(), json!(AUTH_PROVIDER_PASSKEY));
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -3031,6 +5931,11 @@ async fn test_user_has_path_community_permission_select_route_allows_request() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().times(0);
+    db.expect_get_community_required_oidc_provider_by_id()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(None));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -3045,20 +5950,21 @@ async fn test_user_has_path_community_permission_select_route_allows_request() {
     let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
     let router = Router::new()
         .route(
-            "/{community_id}/protected",
+            "/community/{community_id}/select",
             get(|| async { StatusCode::OK }),
         )
         .layer(middleware::from_fn_with_state(
             (db.clone(), CommunityPermission::Read),
             user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
     // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/{community_id}/protected"))
+        .uri(format!("/community/{community_id}/select"))
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -3072,7 +5978,7 @@ async fn test_user_has_path_community_permission_select_route_allows_request() {
 }
 
 #[tokio::test]
-async fn test_user_has_path_community_permission_select_route_forbidden_without_permission() {
+async fn test_user_has_path_community_permission_logs_out_when_passkey_is_required() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
@@ -3095,7 +6001,16 @@ async fn test_user_has_path_community_permission_select_route_forbidden_without_
         .withf(move |cid, uid, permission| {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
-        .returning(|_, _, _| Ok(false));
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(true));
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
+    db.expect_create_session().returning(|_| Ok(()));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -3110,20 +6025,21 @@ async fn test_user_has_path_community_permission_select_route_forbidden_without_
     let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
     let router = Router::new()
         .route(
-            "/{community_id}/protected",
+            "/community/{community_id}/select",
             get(|| async { StatusCode::OK }),
         )
         .layer(middleware::from_fn_with_state(
             (db.clone(), CommunityPermission::Read),
             user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
     // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/{community_id}/protected"))
+        .uri(format!("/community/{community_id}/select"))
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -3132,18 +6048,25 @@ async fn test_user_has_path_community_permission_select_route_forbidden_without_
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::FORBIDDEN);
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static(LOG_IN_URL)
+    );
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_path_community_permission_select_route_returns_error_on_db_failure() {
+async fn test_user_has_path_community_permission_allows_sso_session_when_required() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
     let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let mut session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    session_record
+        .data
+        .insert(AUTH_PROVIDER_KEY.to_string(), json!("linuxfoundation"));
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -3160,7 +6083,15 @@ async fn test_user_has_path_community_permission_select_route_returns_error_on_d
         .withf(move |cid, uid, permission| {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
-        .returning(|_, _, _| Err(anyhow!("db error")));
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(Some("linuxfoundation".to_string())));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -3175,20 +6106,21 @@ async fn test_user_has_path_community_permission_select_route_returns_error_on_d
     let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
     let router = Router::new()
         .route(
-            "/{community_id}/protected",
+            "/community/{community_id}/select",
             get(|| async { StatusCode::OK }),
         )
         .layer(middleware::from_fn_with_state(
             (db.clone(), CommunityPermission::Read),
             user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
     // Execute request
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/{community_id}/protected"))
+        .uri(format!("/community/{community_id}/select"))
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
         .unwrap();
@@ -3197,18 +6129,22 @@ async fn test_user_has_path_community_permission_select_route_returns_error_on_d
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(parts.status, StatusCode::OK);
     assert!(bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_user_has_path_community_permission_allows_request() {
+async fn test_user_has_path_community_permission_logs_out_when_sso_is_required() {
     // Setup identifiers and data structures
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
     let community_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let mut session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    session_record.data.insert(
+        AUTH_PROVIDER_KEY.to_string(),
+        json!(AUTH_PROVIDER_MAGIC_LINK),
+    );
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -3226,6 +6162,23 @@ async fn test_user_has_path_community_permission_allows_request() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(Some("linuxfoundation".to_string())));
+    db.expect_get_community_name_by_id()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(Some("sso-community".to_string())));
+    db.expect_delete_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(|_| Ok(()));
+    db.expect_create_session().returning(|_| Ok(()));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -3247,6 +6200,7 @@ async fn test_user_has_path_community_permission_allows_request() {
             (db.clone(), CommunityPermission::Read),
             user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -3262,7 +6216,11 @@ async fn test_user_has_path_community_permission_allows_request() {
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/log-in?next_url=%2Fsso%2Dcommunity")
+    );
     assert!(bytes.is_empty());
 }
 
@@ -3312,6 +6270,7 @@ async fn test_user_has_path_community_permission_forbidden_without_permission()
             (db.clone(), CommunityPermission::Read),
             user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -3377,6 +6336,7 @@ async fn test_user_has_path_community_permission_returns_error_on_db_failure() {
             (db.clone(), CommunityPermission::Read),
             user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -3432,6 +6392,7 @@ async fn test_user_has_path_community_permission_select_route_forbidden_when_not
             (db.clone(), CommunityPermission::Read),
             user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -3487,6 +6448,7 @@ async fn test_user_has_path_community_permission_protected_route_forbidden_when_
             (db.clone(), CommunityPermission::Read),
             user_has_path_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -3918,6 +6880,9 @@ async fn test_user_has_selected_community_permission_allows_request() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup router
     let server_cfg = HttpServerConfig::default();
@@ -3936,6 +6901,7 @@ async fn test_user_has_selected_community_permission_allows_request() {
             (db.clone(), CommunityPermission::Read),
             user_has_selected_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -4005,6 +6971,7 @@ async fn test_user_has_selected_community_permission_forbidden_without_permissio
             (db.clone(), CommunityPermission::TeamWrite),
             user_has_selected_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -4068,6 +7035,7 @@ async fn test_user_has_selected_community_permission_returns_error_on_db_failure
             (db.clone(), CommunityPermission::Read),
             user_has_selected_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -4131,6 +7099,7 @@ async fn test_user_has_selected_community_permission_redirects_when_context_is_m
             (db.clone(), CommunityPermission::Read),
             user_has_selected_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -4191,6 +7160,9 @@ async fn test_user_has_selected_community_permission_repairs_missing_context() {
                 && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_update_session()
         .times(1)
         .withf(move |record| {
@@ -4223,6 +7195,7 @@ async fn test_user_has_selected_community_permission_repairs_missing_context() {
             (db.clone(), CommunityPermission::Read),
             user_has_selected_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -4278,6 +7251,7 @@ async fn test_user_has_selected_community_permission_forbidden_when_not_logged_i
             (db.clone(), CommunityPermission::Read),
             user_has_selected_community_permission,
         ))
+        .layer(MessagesManagerLayer)
         .layer(auth_layer)
         .with_state(state);
 
@@ -4297,6 +7271,169 @@ async fn test_user_has_selected_community_permission_forbidden_when_not_logged_i
     assert!(bytes.is_empty());
 }
 
+#[tokio::test]
+async fn test_user_has_selected_group_event_permission_allows_event_host() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::EventsWrite
+        })
+        .returning(|_, _, _, _| Ok(false));
+    db.expect_user_is_event_host()
+        .times(1)
+        .withf(move |cid, gid, eid, uid| {
+            *cid == community_id && *gid == group_id && *eid == event_id && *uid == user_id
+        })
+        .returning(|_, _, _, _| Ok(true));
+
+    // Setup router
+    let server_cfg = HttpServerConfig::default();
+    let db: DynDB = Arc::new(db);
+    let nm = Arc::new(MockNotificationsManager::new());
+    let state = test_state_with_server_cfg(
+        db.clone(),
+        Arc::new(MockImageStorage::new()),
+        nm.clone(),
+        &server_cfg,
+    );
+    let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
+    let router = Router::new()
+        .route("/events/{event_id}", get(|| async { StatusCode::OK }))
+        .route_layer(middleware::from_fn_with_state(
+            (db.clone(), GroupPermission::EventsWrite),
+            user_has_selected_group_event_permission,
+        ))
+        .layer(auth_layer)
+        .with_state(state);
+
+    // Execute request
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/events/{event_id}"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_user_has_selected_group_event_permission_forbidden_for_event_host_without_permission()
+{
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(2)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && matches!(
+                    permission,
+                    GroupPermission::Read | GroupPermission::TeamWrite
+                )
+        })
+        .returning(|_, _, _, _| Ok(false));
+    db.expect_user_is_event_host()
+        .times(1)
+        .withf(move |cid, gid, eid, uid| {
+            *cid == community_id && *gid == group_id && *eid == event_id && *uid == user_id
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_delete_session().times(0);
+
+    // Setup router
+    let server_cfg = HttpServerConfig::default();
+    let db: DynDB = Arc::new(db);
+    let nm = Arc::new(MockNotificationsManager::new());
+    let state = test_state_with_server_cfg(
+        db.clone(),
+        Arc::new(MockImageStorage::new()),
+        nm.clone(),
+        &server_cfg,
+    );
+    let auth_layer = crate::auth::setup_layer(&server_cfg, db.clone()).await.unwrap();
+    let router = Router::new()
+        .route("/events/{event_id}", get(|| async { StatusCode::OK }))
+        .route_layer(middleware::from_fn_with_state(
+            (db.clone(), GroupPermission::TeamWrite),
+            user_has_selected_group_event_permission,
+        ))
+        .layer(auth_layer)
+        .with_state(state);
+
+    // Execute request
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/events/{event_id}"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::FORBIDDEN);
+    assert!(bytes.is_empty());
+}
+
 #[tokio::test]
 async fn test_user_has_selected_group_permission_allows_request() {
     // Setup identifiers and data structures
@@ -4974,6 +8111,7 @@ async fn test_user_has_selected_group_permission_forbidden_when_not_logged_in()
 // Helpers.
 
 struct MockCallbackAuth {
+    link_result: Option<Result<(), String>>,
     login_called: bool,
     login_result: Option<Result<(), HandlerError>>,
     oidc_result: Option<Result<Option<auth::User>, String>>,
@@ -4986,6 +8124,7 @@ impl CallbackAuth for MockCallbackAuth {
         &mut self,
         _code: String,
         _provider: OAuth2Provider,
+        _sign_up_community: Option<String>,
     ) -> Result<Option<auth::User>, String> {
         self.oauth2_result
             .take()
@@ -4997,12 +8136,36 @@ impl CallbackAuth for MockCallbackAuth {
         _code: String,
         _nonce: oidc::Nonce,
         _provider: OidcProvider,
+        _sign_up_community: Option<String>,
     ) -> Result<Option<auth::User>, String> {
         self.oidc_result
             .take()
             .expect("oidc callback auth result should be configured in tests")
     }
 
+    async fn link_oauth2(
+        &mut self,
+        _user_id: &Uuid,
+        _code: String,
+        _provider: OAuth2Provider,
+    ) -> Result<(), String> {
+        self.link_result
+            .take()
+            .expect("identity link result should be configured in tests")
+    }
+
+    async fn link_oidc(
+        &mut self,
+        _user_id: &Uuid,
+        _code: String,
+        _nonce: oidc::Nonce,
+        _provider: OidcProvider,
+    ) -> Result<(), String> {
+        self.link_result
+            .take()
+            .expect("identity link result should be configured in tests")
+    }
+
     async fn log_in(&mut self, _user: &auth::User) -> Result<(), HandlerError> {
         self.login_called = true;
         self.login_result
@@ -5014,3 +8177,21 @@ impl CallbackAuth for MockCallbackAuth {
 fn session_record_contains_auth_provider(record: &session::Record, provider: &str) -> bool {
     record.data.get(AUTH_PROVIDER_KEY).and_then(|value| value.as_str()) == Some(provider)
 }
+
+fn session_record_contains_session_lifetime(record: &session::Record, remember: bool) -> bool {
+    record
+        .data
+        .get(auth::SESSION_LIFETIME_KEY)
+        .and_then(|lifetime| lifetime.get("remember"))
+        .and_then(|value| value.as_bool())
+        == Some(remember)
+}
+
+fn session_record_contains_user_agent(record: &session::Record, user_agent: &str) -> bool {
+    record
+        .data
+        .get(SESSION_CLIENT_KEY)
+        .and_then(|client| client.get("user_agent"))
+        .and_then(|value| value.as_str())
+        == Some(user_agent)
+}
@@ -1,7 +1,8 @@
 //! HTTP handlers for the community site.
 //!
 //! The home page displays an overview of the community including recent groups,
-//! upcoming events (both in-person and virtual), and community statistics.
+//! upcoming events (both in-person and virtual), and community statistics. The
+//! newsletter handlers render the archive of sent issues and manage subscriptions.
 
 use anyhow::Result;
 use askama::Template;
@@ -15,10 +16,15 @@ use uuid::Uuid;
 
 use crate::{
     activity_tracker::{Activity, DynActivityTracker},
+    auth::AuthSession,
     config::HttpServerConfig,
     db::DynDB,
     handlers::{
-        error::HandlerError, request_matches_site, site::not_found, trim_public_gallery_images,
+        error::HandlerError,
+        extractors::{CommunityId, CurrentUser},
+        request_matches_site,
+        site::not_found,
+        trim_public_gallery_images,
     },
     router::PUBLIC_SHARED_CACHE_HEADERS,
     templates::{PageId, auth::User, community},
@@ -39,7 +45,7 @@ pub(crate) async fn page(
     uri: Uri,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get community and site settings
-    let (community_id, site_settings) = tokio::try_join!(
+    let (community_id, mut site_settings) = tokio::try_join!(
         db.get_community_id_by_name(&community_name),
         db.get_site_settings()
     )?;
@@ -47,6 +53,9 @@ pub(crate) async fn page(
         return not_found::render(site_settings);
     };
 
+    // Apply the community theme to the site settings
+    site_settings.apply_community_theme(&db.get_community_theme(community_id).await?);
+
     // Prepare template
     let (
         mut community,
@@ -90,8 +99,132 @@ pub(crate) async fn page(
     Ok((PUBLIC_SHARED_CACHE_HEADERS, Html(template.render()?)).into_response())
 }
 
+/// Handler that renders the community newsletter archive page.
+#[instrument(skip_all, err)]
+pub(crate) async fn newsletter_archive_page(
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(community_name): Path<String>,
+    uri: Uri,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get community and site settings
+    let (community_id, mut site_settings) = tokio::try_join!(
+        db.get_community_id_by_name(&community_name),
+        db.get_site_settings()
+    )?;
+    let Some(community_id) = community_id else {
+        return not_found::render(site_settings);
+    };
+
+    // Apply the community theme to the site settings
+    site_settings.apply_community_theme(&db.get_community_theme(community_id).await?);
+
+    // Prepare template
+    let (community, issues) = tokio::try_join!(
+        db.get_community_full(community_id),
+        db.list_community_newsletter_archive(community_id),
+    )?;
+    let template = community::NewsletterArchivePage {
+        base_url: server_cfg.base_url,
+        community,
+        issues,
+        page_id: PageId::Community,
+        path: uri.path().to_string(),
+        site_settings,
+        user: User::default(),
+    };
+
+    Ok((PUBLIC_SHARED_CACHE_HEADERS, Html(template.render()?)).into_response())
+}
+
+/// Handler that renders a community newsletter issue page.
+#[instrument(skip_all, err)]
+pub(crate) async fn newsletter_issue_page(
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path((community_name, community_newsletter_issue_id)): Path<(String, Uuid)>,
+    uri: Uri,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get community and site settings
+    let (community_id, mut site_settings) = tokio::try_join!(
+        db.get_community_id_by_name(&community_name),
+        db.get_site_settings()
+    )?;
+    let Some(community_id) = community_id else {
+        return not_found::render(site_settings);
+    };
+
+    // Apply the community theme to the site settings
+    site_settings.apply_community_theme(&db.get_community_theme(community_id).await?);
+
+    // Prepare template
+    let (community, issue) = tokio::try_join!(
+        db.get_community_full(community_id),
+        db.get_community_newsletter_archive_issue(community_id, community_newsletter_issue_id),
+    )?;
+    let Some(issue) = issue else {
+        return not_found::render(site_settings);
+    };
+    let template = community::NewsletterIssuePage {
+        base_url: server_cfg.base_url,
+        community,
+        issue,
+        page_id: PageId::Community,
+        path: uri.path().to_string(),
+        site_settings,
+        user: User::default(),
+    };
+
+    Ok((PUBLIC_SHARED_CACHE_HEADERS, Html(template.render()?)).into_response())
+}
+
+// Sections handlers.
+
+/// Handler that renders the newsletter subscription section for the current user.
+#[instrument(skip_all, err)]
+pub(crate) async fn newsletter_subscription_section(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path(community_name): Path<String>,
+    CommunityId(community_id): CommunityId,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Check the subscription of the user, if any
+    let subscribed = match auth_session.user.as_ref().map(|user| user.user_id) {
+        Some(user_id) => Some(db.is_community_newsletter_subscriber(community_id, user_id).await?),
+        None => None,
+    };
+
+    // Prepare template
+    let template = community::NewsletterSubscriptionSection {
+        community_name,
+        subscribed,
+    };
+
+    Ok(Html(template.render()?))
+}
+
 // Actions handlers.
 
+/// Subscribes the current user to the community newsletter.
+#[instrument(skip_all, err)]
+pub(crate) async fn subscribe_newsletter(
+    CurrentUser(user): CurrentUser,
+    State(db): State<DynDB>,
+    Path(community_name): Path<String>,
+    CommunityId(community_id): CommunityId,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Subscribe the user to the newsletter
+    db.subscribe_community_newsletter(community_id, user.user_id).await?;
+
+    // Prepare template
+    let template = community::NewsletterSubscriptionSection {
+        community_name,
+        subscribed: Some(true),
+    };
+
+    Ok(Html(template.render()?))
+}
+
 /// Tracks a community page view.
 #[instrument(skip_all)]
 pub(crate) async fn track_view(
@@ -108,3 +241,24 @@ pub(crate) async fn track_view(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Unsubscribes the current user from the community newsletter.
+#[instrument(skip_all, err)]
+pub(crate) async fn unsubscribe_newsletter(
+    CurrentUser(user): CurrentUser,
+    State(db): State<DynDB>,
+    Path(community_name): Path<String>,
+    CommunityId(community_id): CommunityId,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Unsubscribe the user from the newsletter
+    db.unsubscribe_community_newsletter(community_id, user.user_id)
+        .await?;
+
+    // Prepare template
+    let template = community::NewsletterSubscriptionSection {
+        community_name,
+        subscribed: Some(false),
+    };
+
+    Ok(Html(template.render()?))
+}
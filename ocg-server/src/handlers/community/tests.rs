@@ -2,8 +2,10 @@ use anyhow::anyhow;
 use axum::body::{Body, to_bytes};
 use axum::http::{
     HeaderValue, Request, StatusCode,
-    header::{CACHE_CONTROL, CONTENT_TYPE},
+    header::{CACHE_CONTROL, CONTENT_TYPE, COOKIE},
 };
+use axum_login::tower_sessions::session;
+use chrono::Utc;
 use tower::ServiceExt;
 use uuid::Uuid;
 
@@ -14,7 +16,7 @@ use crate::{
     router::CACHE_CONTROL_PUBLIC_SHARED,
     services::notifications::MockNotificationsManager,
     templates::community::Stats,
-    types::event::EventKind,
+    types::{community::CommunityNewsletterArchiveIssue, event::EventKind},
 };
 
 #[tokio::test]
@@ -33,6 +35,10 @@ async fn test_page_success() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -112,6 +118,11 @@ async fn test_page_success() {
     assert!(body.contains(
         r#"<meta name="twitter:image" content="https://example.test/images/og/community-og.png">"#
     ));
+    assert!(body.contains(r#"<link rel="icon" href="https://example.test/favicon.ico""#));
+    assert!(body.contains("--ocg-primary: #1B4F72;"));
+    assert!(body.contains("--color-primary-500: #1B4F72;"));
+    assert!(body.contains("--font-sans: Georgia,"));
+    assert!(body.contains(r#"src="https://example.test/header-logo.png""#));
 }
 
 #[tokio::test]
@@ -166,6 +177,10 @@ async fn test_page_db_error() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -213,6 +228,314 @@ async fn test_page_db_error() {
     assert!(bytes.is_empty());
 }
 
+#[tokio::test]
+async fn test_newsletter_archive_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let mut community = sample_community_full(community_id);
+    community.display_name = "Test Community".to_string();
+    community.name = "test-community".to_string();
+    let mut issue = sample_newsletter_archive_issue();
+    issue.body = None;
+    let issue_id = issue.community_newsletter_issue_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(community.clone()));
+    db.expect_list_community_newsletter_archive()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(vec![issue.clone()]));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/newsletter")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("<title>Test Community newsletter</title>"));
+    assert!(body.contains(
+        r#"<link rel="canonical" href="https://example.test/test-community/newsletter">"#
+    ));
+    assert!(body.contains(&format!(r#"href="/test-community/newsletter/{issue_id}""#)));
+    assert!(body.contains("Monthly update"));
+    assert!(body.contains(r#"hx-get="/test-community/newsletter/subscription""#));
+}
+
+#[tokio::test]
+async fn test_newsletter_issue_page_not_found() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let issue_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_get_community_newsletter_archive_issue()
+        .times(1)
+        .withf(move |cid, iid| *cid == community_id && *iid == issue_id)
+        .returning(|_, _| Ok(None));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/newsletter/{issue_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("We could not find that page"));
+}
+
+#[tokio::test]
+async fn test_newsletter_issue_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let mut community = sample_community_full(community_id);
+    community.display_name = "Test Community".to_string();
+    community.name = "test-community".to_string();
+    let issue = sample_newsletter_archive_issue();
+    let issue_id = issue.community_newsletter_issue_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(community.clone()));
+    db.expect_get_community_newsletter_archive_issue()
+        .times(1)
+        .withf(move |cid, iid| *cid == community_id && *iid == issue_id)
+        .returning(move |_, _| Ok(Some(issue.clone())));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/newsletter/{issue_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("<title>Monthly update - Test Community</title>"));
+    assert!(body.contains(&format!(
+        r#"<link rel="canonical" href="https://example.test/test-community/newsletter/{issue_id}">"#
+    )));
+    assert!(body.contains("<strong>Hello</strong> everyone"));
+}
+
+#[tokio::test]
+async fn test_newsletter_subscription_section_anonymous() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/newsletter/subscription")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("/log-in?next_url="));
+    assert!(body.contains("Sign in"));
+    assert!(!body.contains("hx-put"));
+}
+
+#[tokio::test]
+async fn test_newsletter_subscription_section_subscribed() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_is_community_newsletter_subscriber()
+        .times(1)
+        .withf(move |cid, uid| *cid == community_id && *uid == user_id)
+        .returning(|_, _| Ok(true));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/newsletter/subscription")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains(r#"hx-delete="/test-community/newsletter/subscription""#));
+    assert!(body.contains("Unsubscribe"));
+}
+
+#[tokio::test]
+async fn test_subscribe_newsletter_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_subscribe_community_newsletter()
+        .times(1)
+        .withf(move |cid, uid| *cid == community_id && *uid == user_id)
+        .returning(|_, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/test-community/newsletter/subscription")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains(r#"hx-delete="/test-community/newsletter/subscription""#));
+}
+
 #[tokio::test]
 async fn test_track_view_success() {
     // Setup identifiers and data structures
@@ -285,3 +608,65 @@ async fn test_track_view_ignores_cross_origin_request() {
     assert_eq!(parts.status, StatusCode::NO_CONTENT);
     assert!(bytes.is_empty());
 }
+
+#[tokio::test]
+async fn test_unsubscribe_newsletter_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_unsubscribe_community_newsletter()
+        .times(1)
+        .withf(move |cid, uid| *cid == community_id && *uid == user_id)
+        .returning(|_, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri("/test-community/newsletter/subscription")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains(r#"hx-put="/test-community/newsletter/subscription""#));
+}
+
+// Helpers.
+
+/// Create a sample newsletter issue published in the community archive.
+fn sample_newsletter_archive_issue() -> CommunityNewsletterArchiveIssue {
+    CommunityNewsletterArchiveIssue {
+        community_newsletter_issue_id: Uuid::new_v4(),
+        sent_at: Utc::now(),
+        subject: "Monthly update".to_string(),
+
+        body: Some("**Hello** everyone".to_string()),
+    }
+}
@@ -51,6 +51,9 @@ async fn test_search_user_success() {
                 && permission == CommunityPermission::TeamWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_search_user()
         .times(1)
         .withf(move |query| query == "john")
@@ -115,6 +118,9 @@ async fn test_search_user_missing_query() {
                 && permission == CommunityPermission::TeamWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -22,14 +22,21 @@ use crate::{
 mod tests;
 
 pub(crate) mod analytics;
+pub(crate) mod domains;
+pub(crate) mod event_approvals;
 pub(crate) mod event_categories;
+pub(crate) mod failed_notifications;
+pub(crate) mod group_bans;
 pub(crate) mod group_categories;
 pub(crate) mod groups;
 pub(crate) mod home;
 pub(crate) mod logs;
+pub(crate) mod newsletter;
+pub(crate) mod notifications;
 pub(crate) mod regions;
 pub(crate) mod settings;
 pub(crate) mod team;
+pub(crate) mod topics;
 
 /// Sets the selected community and auto-selects the first group in session.
 #[instrument(skip_all, err)]
@@ -41,6 +41,9 @@ async fn test_page_db_error() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_stats()
         .times(1)
         .withf(move |cid| *cid == community_id)
@@ -94,6 +97,9 @@ async fn test_page_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_stats()
         .times(1)
         .withf(move |cid| *cid == community_id)
@@ -0,0 +1,144 @@
+//! HTTP handlers for managing custom domains in the community dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use reqwest::Url;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::HttpServerConfig,
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, ValidatedForm},
+    },
+    services::domains::verify as verify_domain_ownership,
+    templates::dashboard::community::domains::{self, CommunityDomainInput},
+    types::permissions::CommunityPermission,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the list of custom domains added to the community.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_list_page(&db, community_id, user.user_id, &server_cfg.base_url).await?;
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Adds a custom domain to the community, pending its verification.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    ValidatedForm(input): ValidatedForm<CommunityDomainInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.add_community_domain(user.user_id, community_id, &input.domain)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+/// Deletes a custom domain from the community.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(community_domain_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_community_domain(user.user_id, community_id, community_domain_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+/// Verifies the ownership of a custom domain by checking its TXT record.
+#[instrument(skip_all, err)]
+pub(crate) async fn verify(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(community_domain_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get the domain to verify
+    let Some(domain) = db.get_community_domain(community_id, community_domain_id).await? else {
+        return Err(HandlerError::NotFound);
+    };
+
+    // Check the verification record
+    let dns_resolver_url = &server_cfg.custom_domains.dns_resolver_url;
+    if !verify_domain_ownership(dns_resolver_url, &domain.domain, &domain.verification_token)
+        .await?
+    {
+        let message = format!(
+            "The TXT record {} was not found or does not contain the verification token yet. DNS changes may take some time to propagate, please try again later.",
+            domain.verification_record_name()
+        );
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, message).into_response());
+    }
+
+    // Mark the domain as verified
+    db.verify_community_domain(user.user_id, community_id, community_domain_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    )
+        .into_response())
+}
+
+// Helpers.
+
+/// Prepares the custom domains list page for the community dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    community_id: Uuid,
+    user_id: Uuid,
+    base_url: &str,
+) -> Result<domains::ListPage> {
+    let (can_manage_settings, domains) = tokio::try_join!(
+        db.user_has_community_permission(
+            &community_id,
+            &user_id,
+            CommunityPermission::SettingsWrite
+        ),
+        db.list_community_domains(community_id)
+    )?;
+    let target_host = Url::parse(base_url)
+        .ok()
+        .and_then(|url| url.host_str().map(ToString::to_string))
+        .unwrap_or_default();
+
+    Ok(domains::ListPage {
+        can_manage_settings,
+        domains,
+        target_host,
+    })
+}
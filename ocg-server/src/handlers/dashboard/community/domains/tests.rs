@@ -0,0 +1,339 @@
+use anyhow::anyhow;
+use axum::{
+    body::{Body, to_bytes},
+    http::{
+        HeaderValue, Request, StatusCode,
+        header::{CONTENT_TYPE, COOKIE, HOST},
+    },
+};
+use axum_login::tower_sessions::session;
+use chrono::Utc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    db::mock::MockDB, handlers::tests::*, services::notifications::MockNotificationsManager,
+    templates::dashboard::community::domains::CommunityDomain,
+    types::permissions::CommunityPermission,
+};
+
+#[tokio::test]
+async fn test_add_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_add_community_domain()
+        .times(1)
+        .withf(move |uid, cid, domain| {
+            *uid == user_id && *cid == community_id && domain == "events.example.org"
+        })
+        .returning(|_, _, _| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/community/domains/add")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("domain=events.example.org"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_invalid_payload() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/community/domains/add")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("domain=https%3A%2F%2Fevents.example.org"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_add_community_domain()
+        .times(1)
+        .withf(move |uid, cid, domain| {
+            *uid == user_id && *cid == community_id && domain == "events.example.org"
+        })
+        .returning(|_, _, _| Ok(Uuid::new_v4()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/community/domains/add")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("domain=events.example.org"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::CREATED,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_delete_success() {
+    // Setup identifiers and data structures
+    let community_domain_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_delete_community_domain()
+        .times(1)
+        .withf(move |uid, cid, did| {
+            *uid == user_id && *cid == community_id && *did == community_domain_id
+        })
+        .returning(|_, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!(
+            "/dashboard/community/domains/{community_domain_id}/delete"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_list_page_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_list_community_domains()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/domains")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_page_success() {
+    // Setup identifiers and data structures
+    let community_domain_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let domains = vec![CommunityDomain {
+        community_domain_id,
+        created_at: Utc::now(),
+        domain: "events.example.org".to_string(),
+        verification_token: "0123456789abcdef".to_string(),
+        verified_at: None,
+    }];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_list_community_domains()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(domains.clone()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/domains")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    let html = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(html.contains("_ocg-challenge.events.example.org"));
+    assert!(html.contains("0123456789abcdef"));
+    assert!(html.contains(&format!("/domains/{community_domain_id}/verify")));
+    assert!(html.contains("example.test"));
+}
+
+#[tokio::test]
+async fn test_verify_not_found() {
+    // Setup identifiers and data structures
+    let community_domain_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_get_community_domain()
+        .times(1)
+        .withf(move |cid, did| *cid == community_id && *did == community_domain_id)
+        .returning(|_, _| Ok(None));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/domains/{community_domain_id}/verify"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
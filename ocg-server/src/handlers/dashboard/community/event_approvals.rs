@@ -0,0 +1,137 @@
+//! HTTP handlers for reviewing group events in the community dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::{HttpServerConfig, PaymentsConfig},
+    db::{DBExt, DynDB},
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, ValidatedForm},
+    },
+    services::notifications::enqueue::{
+        enqueue_event_approved_notifications, enqueue_event_rejected_notification,
+    },
+    templates::dashboard::community::event_approvals::{self, EventRejectionInput},
+    types::permissions::CommunityPermission,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the list of events waiting for the community approval.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare template
+    let (can_manage_groups, events) = tokio::try_join!(
+        db.user_has_community_permission(
+            &community_id,
+            &user.user_id,
+            CommunityPermission::GroupsWrite
+        ),
+        db.list_community_pending_events(community_id)
+    )?;
+    let template = event_approvals::ListPage {
+        can_manage_groups,
+        events,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Approves an event waiting for the community approval and publishes it.
+#[instrument(skip_all, err)]
+pub(crate) async fn approve(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    State(payments_cfg): State<Option<PaymentsConfig>>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let configured_provider = payments_cfg.as_ref().map(PaymentsConfig::provider);
+
+    db.as_ref()
+        .transaction(|tx| {
+            Box::pin(async move {
+                // Approve and publish the event
+                let group_id = tx
+                    .approve_event(user.user_id, community_id, event_id, configured_provider)
+                    .await?;
+
+                // Enqueue approval and publication notifications before committing
+                enqueue_event_approved_notifications(
+                    tx,
+                    &server_cfg,
+                    community_id,
+                    group_id,
+                    event_id,
+                )
+                .await?;
+
+                Ok(())
+            })
+        })
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+/// Rejects an event waiting for the community approval.
+#[instrument(skip_all, err)]
+pub(crate) async fn reject(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(event_id): Path<Uuid>,
+    ValidatedForm(rejection): ValidatedForm<EventRejectionInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.as_ref()
+        .transaction(|tx| {
+            Box::pin(async move {
+                // Reject the event
+                let group_id = tx
+                    .reject_event(user.user_id, community_id, event_id, &rejection)
+                    .await?;
+
+                // Enqueue the rejection notification before committing
+                enqueue_event_rejected_notification(
+                    tx,
+                    &server_cfg,
+                    community_id,
+                    group_id,
+                    event_id,
+                    rejection.reason,
+                )
+                .await?;
+
+                Ok(())
+            })
+        })
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
@@ -0,0 +1,375 @@
+use anyhow::anyhow;
+use axum::{
+    body::{Body, to_bytes},
+    http::{
+        HeaderValue, Request, StatusCode,
+        header::{CONTENT_TYPE, COOKIE, HOST},
+    },
+};
+use axum_login::tower_sessions::session;
+use chrono::{Duration, Utc};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    db::mock::MockDB,
+    handlers::tests::*,
+    services::notifications::{MockNotificationsManager, NotificationKind},
+    templates::{
+        dashboard::community::event_approvals::PendingEvent, notifications::EventRejected,
+    },
+    types::{event::EventSummary, permissions::CommunityPermission},
+};
+
+#[tokio::test]
+async fn test_approve_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    let mut tx = MockDB::new();
+    tx.expect_approve_event()
+        .times(1)
+        .withf(move |uid, cid, eid, provider| {
+            *uid == user_id && *cid == community_id && *eid == event_id && provider.is_none()
+        })
+        .returning(|_, _, _, _| Err(anyhow!("db error")));
+    expect_rolled_back_transaction(&mut db, tx);
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/event-approvals/{event_id}/approve"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_approve_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let team_member_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let starts_at = Utc::now() - Duration::days(1);
+    let past_event = EventSummary {
+        ends_at: Some(starts_at + Duration::hours(2)),
+        starts_at: Some(starts_at),
+        ..sample_event_summary(event_id, group_id)
+    };
+    let site_settings = sample_site_settings();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    let mut tx = MockDB::new();
+    tx.expect_approve_event()
+        .times(1)
+        .withf(move |uid, cid, eid, provider| {
+            *uid == user_id && *cid == community_id && *eid == event_id && provider.is_none()
+        })
+        .returning(move |_, _, _, _| Ok(group_id));
+    tx.expect_get_site_settings()
+        .times(1)
+        .returning(move || Ok(site_settings.clone()));
+    tx.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(past_event.clone()));
+    tx.expect_list_group_team_members_ids()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(move |_| Ok(vec![team_member_id]));
+    tx.expect_enqueue_notification()
+        .times(1)
+        .withf(move |notification| {
+            matches!(notification.kind, NotificationKind::EventApproved)
+                && notification.recipients == vec![team_member_id]
+        })
+        .returning(|_| Ok(()));
+    expect_successful_transaction(&mut db, tx);
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/event-approvals/{event_id}/approve"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_list_page_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    db.expect_list_community_pending_events()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/event-approvals")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let events = vec![PendingEvent {
+        event: sample_event_summary(event_id, Uuid::new_v4()),
+        requested_at: Some(Utc::now()),
+        requested_by_display_name: Some("Organizer".to_string()),
+        requested_by_username: Some("organizer".to_string()),
+    }];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    db.expect_list_community_pending_events()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(events.clone()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/event-approvals")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    let html = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(html.contains("Sample Event"));
+    assert!(html.contains(&format!("/event-approvals/{event_id}/approve")));
+}
+
+#[tokio::test]
+async fn test_reject_invalid_payload() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/event-approvals/{event_id}/reject"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(format!("reason={}", "a".repeat(1001))))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_reject_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let team_member_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let event = EventSummary {
+        published: false,
+        ..sample_event_summary(event_id, group_id)
+    };
+    let site_settings = sample_site_settings();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    let mut tx = MockDB::new();
+    tx.expect_reject_event()
+        .times(1)
+        .withf(move |uid, cid, eid, rejection| {
+            *uid == user_id
+                && *cid == community_id
+                && *eid == event_id
+                && rejection.reason.as_deref() == Some("Missing agenda")
+        })
+        .returning(move |_, _, _, _| Ok(group_id));
+    tx.expect_get_site_settings()
+        .times(1)
+        .returning(move || Ok(site_settings.clone()));
+    tx.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event.clone()));
+    tx.expect_list_group_team_members_ids()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(move |_| Ok(vec![team_member_id]));
+    tx.expect_enqueue_notification()
+        .times(1)
+        .withf(move |notification| {
+            let Some(template_data) = notification.template_data.clone() else {
+                return false;
+            };
+            let Ok(template) = serde_json::from_value::<EventRejected>(template_data) else {
+                return false;
+            };
+            matches!(notification.kind, NotificationKind::EventRejected)
+                && notification.recipients == vec![team_member_id]
+                && template.reason.as_deref() == Some("Missing agenda")
+        })
+        .returning(|_| Ok(()));
+    expect_successful_transaction(&mut db, tx);
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/event-approvals/{event_id}/reject"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("reason=Missing+agenda"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
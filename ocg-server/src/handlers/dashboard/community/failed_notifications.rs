@@ -0,0 +1,115 @@
+//! HTTP handlers for the community dashboard failed notifications.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, RawQuery, State},
+    http::{HeaderName, StatusCode},
+    response::{Html, IntoResponse},
+};
+use garde::Validate;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId},
+    },
+    router::serde_qs_config,
+    templates::dashboard::community::failed_notifications::{FailedNotificationsFilters, ListPage},
+    types::{
+        pagination::{self, NavigationLinks},
+        permissions::CommunityPermission,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+// URLs used by the dashboard page and tab partial
+const DASHBOARD_URL: &str = "/dashboard/community?tab=failed-notifications";
+const PARTIAL_URL: &str = "/dashboard/community/failed-notifications";
+
+// Pages handlers.
+
+/// Displays the notifications sent on behalf of the community whose delivery failed.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    RawQuery(raw_query): RawQuery,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare list page content
+    let (filters, template) = prepare_list_page(
+        &db,
+        community_id,
+        user.user_id,
+        raw_query.as_deref().unwrap_or_default(),
+    )
+    .await?;
+
+    // Prepare response headers
+    let url = pagination::build_url(DASHBOARD_URL, &filters)?;
+    let headers = [(HeaderName::from_static("hx-push-url"), url)];
+
+    Ok((headers, Html(template.render()?)))
+}
+
+// Actions handlers.
+
+/// Requeues a failed notification for immediate delivery.
+#[instrument(skip_all, err)]
+pub(crate) async fn requeue(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(notification_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.requeue_community_notification(user.user_id, community_id, notification_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+// Helpers.
+
+/// Prepares the failed notifications list page for the community dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    community_id: Uuid,
+    user_id: Uuid,
+    raw_query: &str,
+) -> Result<(FailedNotificationsFilters, ListPage), HandlerError> {
+    // Fetch failed notifications
+    let filters: FailedNotificationsFilters = serde_qs_config().deserialize_str(raw_query)?;
+    filters.validate()?;
+    let (can_requeue, results) = tokio::try_join!(
+        db.user_has_community_permission(
+            &community_id,
+            &user_id,
+            CommunityPermission::SettingsWrite
+        ),
+        db.list_community_failed_notifications(community_id, &filters)
+    )?;
+
+    // Prepare template
+    let navigation_links =
+        NavigationLinks::from_filters(&filters, results.total, DASHBOARD_URL, PARTIAL_URL)?;
+    let template = ListPage {
+        can_requeue,
+        navigation_links,
+        notifications: results.notifications,
+        total: results.total,
+
+        limit: filters.limit,
+        offset: filters.offset,
+    };
+
+    Ok((filters, template))
+}
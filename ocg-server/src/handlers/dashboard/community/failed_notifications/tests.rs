@@ -0,0 +1,230 @@
+use anyhow::anyhow;
+use axum::{
+    body::{Body, to_bytes},
+    http::{
+        HeaderValue, Request, StatusCode,
+        header::{CONTENT_TYPE, COOKIE},
+    },
+};
+use axum_login::tower_sessions::session;
+use chrono::Utc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    db::mock::MockDB,
+    handlers::tests::*,
+    services::notifications::MockNotificationsManager,
+    templates::dashboard::community::failed_notifications::{
+        FailedNotification, FailedNotificationsOutput,
+    },
+    types::permissions::CommunityPermission,
+};
+
+#[tokio::test]
+async fn test_list_page_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_list_community_failed_notifications()
+        .times(1)
+        .withf(move |id, filters| {
+            *id == community_id && filters.limit == Some(50) && filters.offset == Some(0)
+        })
+        .returning(|_, _| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/failed-notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let notification_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let output = FailedNotificationsOutput {
+        notifications: vec![FailedNotification {
+            channel: "email".to_string(),
+            created_at: Utc::now(),
+            delivery_attempts: 5,
+            delivery_status: "failed".to_string(),
+            kind: "community-newsletter".to_string(),
+            notification_id,
+
+            error: Some("mailbox unavailable".to_string()),
+            processed_at: Some(Utc::now()),
+            username: Some("jdoe".to_string()),
+        }],
+        total: 1,
+    };
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_list_community_failed_notifications()
+        .times(1)
+        .withf(move |id, filters| {
+            *id == community_id && filters.limit == Some(5) && filters.offset == Some(10)
+        })
+        .returning(move |_, _| Ok(output.clone()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/failed-notifications?limit=5&offset=10")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    assert_eq!(
+        parts.headers.get("hx-push-url").unwrap(),
+        &HeaderValue::from_static(
+            "/dashboard/community?tab=failed-notifications&limit=5&offset=10"
+        ),
+    );
+    assert!(body.contains("mailbox unavailable"));
+    assert!(body.contains(&format!("/failed-notifications/{notification_id}/requeue")));
+}
+
+#[tokio::test]
+async fn test_requeue_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let notification_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_requeue_community_notification()
+        .times(1)
+        .withf(move |uid, cid, nid| {
+            *uid == user_id && *cid == community_id && *nid == notification_id
+        })
+        .returning(|_, _, _| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/failed-notifications/{notification_id}/requeue"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_requeue_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let notification_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_requeue_community_notification()
+        .times(1)
+        .withf(move |uid, cid, nid| {
+            *uid == user_id && *cid == community_id && *nid == notification_id
+        })
+        .returning(|_, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/failed-notifications/{notification_id}/requeue"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
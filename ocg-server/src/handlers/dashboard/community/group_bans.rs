@@ -0,0 +1,70 @@
+//! HTTP handlers for reviewing group bans in the community dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId},
+    },
+    templates::dashboard::community::group_bans,
+    types::permissions::CommunityPermission,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the list of users banned from the community groups.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare template
+    let (bans, can_manage_groups) = tokio::try_join!(
+        db.list_community_group_bans(community_id),
+        db.user_has_community_permission(
+            &community_id,
+            &user.user_id,
+            CommunityPermission::GroupsWrite
+        )
+    )?;
+    let template = group_bans::ListPage {
+        bans,
+        can_manage_groups,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Lifts the ban of a user from a group of the community.
+#[instrument(skip_all, err)]
+pub(crate) async fn unban(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path((group_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Lift the ban
+    db.unban_group_member(user.user_id, community_id, group_id, user_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
@@ -0,0 +1,219 @@
+use anyhow::anyhow;
+use axum::{
+    body::{Body, to_bytes},
+    http::{
+        HeaderValue, Request, StatusCode,
+        header::{CONTENT_TYPE, COOKIE, HOST},
+    },
+};
+use axum_login::tower_sessions::session;
+use chrono::Utc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    db::mock::MockDB, handlers::tests::*, services::notifications::MockNotificationsManager,
+    templates::dashboard::community::group_bans::CommunityGroupBan,
+    types::permissions::CommunityPermission,
+};
+
+#[tokio::test]
+async fn test_list_page_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    db.expect_list_community_group_bans()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/group-bans")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let banned_user_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let bans = vec![CommunityGroupBan {
+        created_at: Utc::now(),
+        group_id,
+        group_name: "Sample Group".to_string(),
+        reason: "Harassing other members".to_string(),
+        user_id: banned_user_id,
+        username: "banned-user".to_string(),
+        banned_by_username: Some("organizer".to_string()),
+        name: Some("Banned User".to_string()),
+        photo_url: None,
+    }];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    db.expect_list_community_group_bans()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(bans.clone()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/group-bans")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    let html = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(html.contains("Harassing other members"));
+    assert!(html.contains(&format!("/group-bans/{group_id}/{banned_user_id}/unban")));
+}
+
+#[tokio::test]
+async fn test_unban_db_error() {
+    // Setup identifiers and data structures
+    let banned_user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    db.expect_unban_group_member()
+        .times(1)
+        .withf(move |uid, cid, gid, buid| {
+            *uid == user_id && *cid == community_id && *gid == group_id && *buid == banned_user_id
+        })
+        .returning(|_, _, _, _| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/group-bans/{group_id}/{banned_user_id}/unban"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_unban_success() {
+    // Setup identifiers and data structures
+    let banned_user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::GroupsWrite,
+    );
+    db.expect_unban_group_member()
+        .times(1)
+        .withf(move |uid, cid, gid, buid| {
+            *uid == user_id && *cid == community_id && *gid == group_id && *buid == banned_user_id
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/group-bans/{group_id}/{banned_user_id}/unban"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
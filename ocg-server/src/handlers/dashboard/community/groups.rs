@@ -20,7 +20,9 @@ use crate::{
         extractors::{CurrentUser, SelectedCommunityId, ValidatedFormQs},
     },
     router::serde_qs_config,
-    templates::dashboard::community::groups::{self, CommunityGroupsFilters, Group},
+    templates::dashboard::community::groups::{
+        self, CommunityGroupsFilters, Group, GroupMergeInput, GroupOwnerUpdate,
+    },
     types::{
         pagination::{self, NavigationLinks},
         permissions::CommunityPermission,
@@ -70,7 +72,7 @@ pub(crate) async fn add_page(
     State(db): State<DynDB>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Prepare template
-    let (can_manage_groups, categories, parent_options, regions) = tokio::try_join!(
+    let (can_manage_groups, categories, parent_options, regions, topics) = tokio::try_join!(
         db.user_has_community_permission(
             &community_id,
             &user.user_id,
@@ -78,13 +80,15 @@ pub(crate) async fn add_page(
         ),
         db.list_group_categories(community_id),
         db.list_group_parent_options(community_id, user.user_id, None),
-        db.list_regions(community_id)
+        db.list_regions(community_id),
+        db.list_topics(community_id)
     )?;
     let template = groups::AddPage {
         can_manage_groups,
         categories,
         parent_options,
         regions,
+        topics,
     };
 
     Ok(Html(template.render()?))
@@ -99,7 +103,17 @@ pub(crate) async fn update_page(
     Path(group_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Prepare template
-    let (can_manage_groups, group, has_child_links, categories, parent_options, regions) = tokio::try_join!(
+    let (
+        can_manage_groups,
+        group,
+        has_child_links,
+        categories,
+        merge_targets,
+        ownership,
+        parent_options,
+        regions,
+        topics,
+    ) = tokio::try_join!(
         db.user_has_community_permission(
             &community_id,
             &user.user_id,
@@ -108,16 +122,22 @@ pub(crate) async fn update_page(
         db.get_group_full(community_id, group_id),
         db.group_has_child_links(community_id, group_id),
         db.list_group_categories(community_id),
+        db.list_group_merge_targets(community_id, group_id),
+        db.get_group_ownership(group_id),
         db.list_group_parent_options(community_id, user.user_id, Some(group_id)),
-        db.list_regions(community_id)
+        db.list_regions(community_id),
+        db.list_topics(community_id)
     )?;
     let template = groups::UpdatePage {
         can_manage_groups,
         categories,
         group,
         has_child_links,
+        merge_targets,
+        ownership,
         parent_options,
         regions,
+        topics,
     };
 
     Ok(Html(template.render()?))
@@ -166,6 +186,23 @@ pub(crate) async fn add(
         .into_response())
 }
 
+/// Archives a group, disabling joining and event creation.
+#[instrument(skip_all, err)]
+pub(crate) async fn archive(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(group_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Mark group as archived in database
+    db.archive_group(user.user_id, community_id, group_id).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
 /// Deactivates a group (sets active=false without deleting).
 #[instrument(skip_all, err)]
 pub(crate) async fn deactivate(
@@ -218,6 +255,69 @@ pub(crate) async fn delete(
     ))
 }
 
+/// Merges a group into another group of the community, archiving it.
+#[instrument(skip_all, err)]
+pub(crate) async fn merge(
+    CurrentUser(user): CurrentUser,
+    session: Session,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(group_id): Path<Uuid>,
+    ValidatedFormQs(merge): ValidatedFormQs<GroupMergeInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Merge groups in database
+    db.merge_groups(user.user_id, community_id, group_id, merge.target_group_id)
+        .await?;
+
+    // Select the target group if the merged group was selected
+    if session.get::<Uuid>(SELECTED_GROUP_ID_KEY).await? == Some(group_id) {
+        session.insert(SELECTED_GROUP_ID_KEY, merge.target_group_id).await?;
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Makes another user the owner of a group, for example when it was abandoned.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_owner(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(group_id): Path<Uuid>,
+    ValidatedFormQs(owner): ValidatedFormQs<GroupOwnerUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Update group owner in database
+    db.update_group_owner(user.user_id, community_id, group_id, owner.username.trim())
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Restores an archived group.
+#[instrument(skip_all, err)]
+pub(crate) async fn restore(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(group_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Clear group archived state in database
+    db.restore_group(user.user_id, community_id, group_id).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
 /// Updates an existing group's information in the database.
 #[instrument(skip_all, err)]
 pub(crate) async fn update(
@@ -262,6 +362,7 @@ pub(crate) async fn prepare_list_page(
     filters.validate()?;
     let search_filters = SearchGroupsFilters {
         community: vec![community_name],
+        dormant: filters.dormant,
         include_inactive: Some(true),
         limit: filters.limit,
         offset: filters.offset,
@@ -282,6 +383,7 @@ pub(crate) async fn prepare_list_page(
         groups: results.groups,
         navigation_links,
         total: results.total,
+        dormant: filters.dormant,
         limit: filters.limit,
         offset: filters.offset,
         ts_query: filters.ts_query.clone(),
@@ -7,6 +7,7 @@ use axum::{
     },
 };
 use axum_login::tower_sessions::session;
+use chrono::{TimeZone, Utc};
 use serde_json::json;
 use tower::ServiceExt;
 use uuid::Uuid;
@@ -16,7 +17,10 @@ use crate::{
     handlers::{auth::SELECTED_GROUP_ID_KEY, tests::*},
     services::notifications::MockNotificationsManager,
     templates::dashboard::DASHBOARD_PAGINATION_LIMIT,
-    types::{group::GroupParentOption, permissions::CommunityPermission},
+    types::{
+        group::{GroupParentOption, GroupSummary},
+        permissions::CommunityPermission,
+    },
 };
 
 #[tokio::test]
@@ -51,6 +55,9 @@ async fn test_list_page_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -59,6 +66,9 @@ async fn test_list_page_success() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_name_by_id()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -128,6 +138,9 @@ async fn test_list_page_allows_empty_search_query() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -136,6 +149,9 @@ async fn test_list_page_allows_empty_search_query() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_name_by_id()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -171,6 +187,94 @@ async fn test_list_page_allows_empty_search_query() {
     assert_html_response(&parts, &bytes, StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_list_page_dormant_filter() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+    let groups_output = SearchGroupsOutput {
+        groups: vec![GroupSummary {
+            dormant_since: Some(Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap()),
+            ..sample_group_summary(group_id)
+        }],
+        total: 1,
+        ..Default::default()
+    };
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_get_community_name_by_id()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(Some("test".to_string())));
+    db.expect_search_groups()
+        .times(1)
+        .withf(move |filters| {
+            filters.community == vec!["test".to_string()]
+                && filters.dormant == Some(true)
+                && filters.include_inactive == Some(true)
+        })
+        .returning(move |_| Ok(groups_output.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/groups?dormant=true")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get("hx-push-url").unwrap(),
+        format!(
+            "/dashboard/community?tab=groups&dormant=true&limit={DASHBOARD_PAGINATION_LIMIT}&offset=0"
+        )
+        .as_str()
+    );
+    assert!(body.contains("Dormant since July  1, 2024"));
+}
+
 #[tokio::test]
 async fn test_list_page_db_error() {
     // Setup identifiers and data structures
@@ -197,6 +301,9 @@ async fn test_list_page_db_error() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -205,6 +312,9 @@ async fn test_list_page_db_error() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_name_by_id()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -266,6 +376,9 @@ async fn test_add_page_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -274,6 +387,9 @@ async fn test_add_page_success() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_list_group_categories()
         .times(1)
         .withf(move |cid| *cid == community_id)
@@ -286,6 +402,10 @@ async fn test_add_page_success() {
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Ok(regions.clone()));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(vec![]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -333,6 +453,9 @@ async fn test_add_page_db_error() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -341,6 +464,9 @@ async fn test_add_page_db_error() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_list_group_categories()
         .times(1)
         .withf(move |cid| *cid == community_id)
@@ -396,6 +522,9 @@ async fn test_update_page_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -404,6 +533,9 @@ async fn test_update_page_success() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_group_full()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
@@ -416,6 +548,14 @@ async fn test_update_page_success() {
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Ok(categories.clone()));
+    db.expect_list_group_merge_targets()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(vec![sample_group_minimal(Uuid::new_v4())]));
+    db.expect_get_group_ownership()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(move |_| Ok(sample_group_ownership(user_id)));
     db.expect_list_group_parent_options()
         .times(1)
         .withf(move |cid, uid, gid| {
@@ -426,6 +566,10 @@ async fn test_update_page_success() {
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Ok(regions.clone()));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(vec![]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -485,6 +629,9 @@ async fn test_update_page_selects_current_inactive_parent_option() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -493,6 +640,9 @@ async fn test_update_page_selects_current_inactive_parent_option() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_group_full()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
@@ -505,6 +655,14 @@ async fn test_update_page_selects_current_inactive_parent_option() {
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Ok(categories.clone()));
+    db.expect_list_group_merge_targets()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(vec![sample_group_minimal(Uuid::new_v4())]));
+    db.expect_get_group_ownership()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(move |_| Ok(sample_group_ownership(user_id)));
     db.expect_list_group_parent_options()
         .times(1)
         .withf(move |cid, uid, gid| {
@@ -515,6 +673,10 @@ async fn test_update_page_selects_current_inactive_parent_option() {
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Ok(regions.clone()));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(vec![]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -573,6 +735,9 @@ async fn test_update_page_db_error() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -581,6 +746,9 @@ async fn test_update_page_db_error() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_group_full()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
@@ -637,6 +805,9 @@ async fn test_add_success_auto_selects_group() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_add_group()
         .times(1)
         .withf(move |uid, cid, group| {
@@ -720,6 +891,9 @@ async fn test_add_success_keeps_existing_group_selection() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_add_group()
         .times(1)
         .withf(move |uid, cid, group| {
@@ -781,6 +955,9 @@ async fn test_add_invalid_payload() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -833,6 +1010,9 @@ async fn test_add_db_error() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_add_group()
         .times(1)
         .withf(move |uid, cid, group| {
@@ -892,6 +1072,9 @@ async fn test_update_success() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_update_group()
         .times(1)
         .withf(move |uid, cid, gid, group| {
@@ -957,6 +1140,9 @@ async fn test_update_invalid_payload() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -1010,6 +1196,9 @@ async fn test_update_db_error() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_update_group()
         .times(1)
         .withf(move |uid, cid, gid, group| {
@@ -1042,7 +1231,7 @@ async fn test_update_db_error() {
 }
 
 #[tokio::test]
-async fn test_activate_success() {
+async fn test_update_owner_success() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
@@ -1070,10 +1259,15 @@ async fn test_activate_success() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
-    db.expect_activate_group()
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_update_group_owner()
         .times(1)
-        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
-        .returning(move |_, _, _| Ok(()));
+        .withf(move |uid, cid, gid, username| {
+            *uid == user_id && *cid == community_id && *gid == group_id && username == "new-owner"
+        })
+        .returning(|_, _, _, _| Ok(()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -1082,10 +1276,11 @@ async fn test_activate_success() {
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
         .method("PUT")
-        .uri(format!("/dashboard/community/groups/{group_id}/activate"))
+        .uri(format!("/dashboard/community/groups/{group_id}/owner"))
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("username=+new-owner+"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -1101,7 +1296,7 @@ async fn test_activate_success() {
 }
 
 #[tokio::test]
-async fn test_deactivate_success() {
+async fn test_update_owner_invalid_payload() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
@@ -1129,10 +1324,69 @@ async fn test_deactivate_success() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
-    db.expect_deactivate_group()
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/owner"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("username=+"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_non_empty_response(&parts, &bytes, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_update_owner_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
         .times(1)
-        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
-        .returning(move |_, _, _| Ok(()));
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_update_group_owner()
+        .times(1)
+        .withf(move |uid, cid, gid, username| {
+            *uid == user_id && *cid == community_id && *gid == group_id && username == "unknown"
+        })
+        .returning(|_, _, _, _| Err(anyhow!("user not found")));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -1141,40 +1395,31 @@ async fn test_deactivate_success() {
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
         .method("PUT")
-        .uri(format!("/dashboard/community/groups/{group_id}/deactivate"))
+        .uri(format!("/dashboard/community/groups/{group_id}/owner"))
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("username=unknown"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_empty_hx_trigger_response(
-        &parts,
-        &bytes,
-        StatusCode::NO_CONTENT,
-        "refresh-community-dashboard-table",
-    );
+    assert_empty_response(&parts, &bytes, StatusCode::INTERNAL_SERVER_ERROR);
 }
 
 #[tokio::test]
-async fn test_delete_non_selected_group() {
+async fn test_merge_success() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
-    let other_group_id = Uuid::new_v4();
     let session_id = session::Id::default();
+    let target_group_id = Uuid::new_v4();
     let user_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(
-        session_id,
-        user_id,
-        &auth_hash,
-        Some(community_id),
-        Some(other_group_id),
-    );
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1194,10 +1439,15 @@ async fn test_delete_non_selected_group() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
-    db.expect_delete_group()
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_merge_groups()
         .times(1)
-        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
-        .returning(move |_, _, _| Ok(()));
+        .withf(move |uid, cid, sid, tid| {
+            *uid == user_id && *cid == community_id && *sid == group_id && *tid == target_group_id
+        })
+        .returning(|_, _, _, _| Ok(()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -1205,11 +1455,12 @@ async fn test_delete_non_selected_group() {
     // Setup router and send request
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
-        .method("DELETE")
-        .uri(format!("/dashboard/community/groups/{group_id}/delete"))
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/merge"))
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(format!("target_group_id={target_group_id}")))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -1225,12 +1476,12 @@ async fn test_delete_non_selected_group() {
 }
 
 #[tokio::test]
-async fn test_delete_selected_group_updates_selection() {
+async fn test_merge_selected_group_selects_target_group() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
-    let next_group_id = Uuid::new_v4();
     let session_id = session::Id::default();
+    let target_group_id = Uuid::new_v4();
     let user_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
     let session_record = sample_session_record(
@@ -1240,6 +1491,7 @@ async fn test_delete_selected_group_updates_selection() {
         Some(community_id),
         Some(group_id),
     );
+
     // Setup database mock
     let mut db = MockDB::new();
     db.expect_get_session()
@@ -1258,14 +1510,15 @@ async fn test_delete_selected_group_updates_selection() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
-    db.expect_delete_group()
-        .times(1)
-        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
-        .returning(move |_, _, _| Ok(()));
-    db.expect_list_user_groups()
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_merge_groups()
         .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(sample_user_groups_by_community(community_id, next_group_id)));
+        .withf(move |uid, cid, sid, tid| {
+            *uid == user_id && *cid == community_id && *sid == group_id && *tid == target_group_id
+        })
+        .returning(|_, _, _, _| Ok(()));
     db.expect_update_session()
         .times(1)
         .withf(move |record| {
@@ -1273,7 +1526,7 @@ async fn test_delete_selected_group_updates_selection() {
                 && record
                     .data
                     .get(SELECTED_GROUP_ID_KEY)
-                    .is_some_and(|value| value == &json!(next_group_id))
+                    .is_some_and(|value| value == &json!(target_group_id))
         })
         .returning(|_| Ok(()));
 
@@ -1283,11 +1536,12 @@ async fn test_delete_selected_group_updates_selection() {
     // Setup router and send request
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
-        .method("DELETE")
-        .uri(format!("/dashboard/community/groups/{group_id}/delete"))
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/merge"))
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
-        .body(Body::empty())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(format!("target_group_id={target_group_id}")))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -1303,20 +1557,15 @@ async fn test_delete_selected_group_updates_selection() {
 }
 
 #[tokio::test]
-async fn test_delete_selected_group_without_fallback_clears_selection() {
+async fn test_merge_invalid_payload() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
     let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(
-        session_id,
-        user_id,
-        &auth_hash,
-        Some(community_id),
-        Some(group_id),
-    );
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -1336,20 +1585,9 @@ async fn test_delete_selected_group_without_fallback_clears_selection() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
-    db.expect_delete_group()
-        .times(1)
-        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
-        .returning(move |_, _, _| Ok(()));
-    db.expect_list_user_groups()
-        .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(|_| Ok(vec![]));
-    db.expect_update_session()
-        .times(1)
-        .withf(move |record| {
-            record.id == session_id && !record.data.contains_key(SELECTED_GROUP_ID_KEY)
-        })
-        .returning(|_| Ok(()));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -1357,8 +1595,596 @@ async fn test_delete_selected_group_without_fallback_clears_selection() {
     // Setup router and send request
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
-        .method("DELETE")
-        .uri(format!("/dashboard/community/groups/{group_id}/delete"))
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/merge"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("target_group_id=invalid"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_non_empty_response(&parts, &bytes, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_merge_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let target_group_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_merge_groups()
+        .times(1)
+        .withf(move |uid, cid, sid, tid| {
+            *uid == user_id && *cid == community_id && *sid == group_id && *tid == target_group_id
+        })
+        .returning(|_, _, _, _| Err(anyhow!("target group not found or inactive")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/merge"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(format!("target_group_id={target_group_id}")))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_response(&parts, &bytes, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_activate_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_activate_group()
+        .times(1)
+        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
+        .returning(move |_, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/activate"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_archive_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_archive_group()
+        .times(1)
+        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
+        .returning(move |_, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/archive"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_archive_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_archive_group()
+        .times(1)
+        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
+        .returning(move |_, _, _| Err(anyhow!("group not found, inactive or already archived")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/archive"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_response(&parts, &bytes, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_deactivate_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_deactivate_group()
+        .times(1)
+        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
+        .returning(move |_, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/deactivate"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_delete_non_selected_group() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let other_group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(other_group_id),
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_delete_group()
+        .times(1)
+        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
+        .returning(move |_, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!("/dashboard/community/groups/{group_id}/delete"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_delete_selected_group_updates_selection() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let next_group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_delete_group()
+        .times(1)
+        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
+        .returning(move |_, _, _| Ok(()));
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(sample_user_groups_by_community(community_id, next_group_id)));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            record.id == session_id
+                && record
+                    .data
+                    .get(SELECTED_GROUP_ID_KEY)
+                    .is_some_and(|value| value == &json!(next_group_id))
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!("/dashboard/community/groups/{group_id}/delete"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_delete_selected_group_without_fallback_clears_selection() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_delete_group()
+        .times(1)
+        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
+        .returning(move |_, _, _| Ok(()));
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            record.id == session_id && !record.data.contains_key(SELECTED_GROUP_ID_KEY)
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!("/dashboard/community/groups/{group_id}/delete"))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_restore_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_restore_group()
+        .times(1)
+        .withf(move |uid, cid, gid| *uid == user_id && *cid == community_id && *gid == group_id)
+        .returning(move |_, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/community/groups/{group_id}/restore"))
         .header(HOST, "example.test")
         .header(COOKIE, format!("id={session_id}"))
         .body(Body::empty())
@@ -11,10 +11,11 @@ use axum::{
 use axum_messages::Messages;
 use tracing::instrument;
 
-use super::{groups, logs, team};
+use super::{domains, failed_notifications, groups, logs, newsletter, notifications, team};
 
 use crate::{
     auth::AuthSession,
+    config::HttpServerConfig,
     db::DynDB,
     handlers::{
         error::HandlerError,
@@ -24,9 +25,9 @@ use crate::{
         PageId,
         auth::User,
         dashboard::community::{
-            analytics, event_categories, group_categories,
+            analytics, event_approvals, event_categories, group_bans, group_categories,
             home::{Content, Page, Tab},
-            regions, settings,
+            regions, settings, topics,
         },
     },
     types::permissions::CommunityPermission,
@@ -47,6 +48,7 @@ pub(crate) async fn page(
     messages: Messages,
     SelectedCommunityId(community_id): SelectedCommunityId,
     State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
     Query(query): Query<HashMap<String, String>>,
     RawQuery(raw_query): RawQuery,
 ) -> Result<impl IntoResponse, HandlerError> {
@@ -69,6 +71,26 @@ pub(crate) async fn page(
             let stats = db.get_community_stats(community_id).await?;
             Content::Analytics(Box::new(analytics::Page { stats }))
         }
+        Tab::Domains => {
+            let template =
+                domains::prepare_list_page(&db, community_id, user_id, &server_cfg.base_url)
+                    .await?;
+            Content::Domains(template)
+        }
+        Tab::EventApprovals => {
+            let (can_manage_groups, events) = tokio::try_join!(
+                db.user_has_community_permission(
+                    &community_id,
+                    &user_id,
+                    CommunityPermission::GroupsWrite
+                ),
+                db.list_community_pending_events(community_id)
+            )?;
+            Content::EventApprovals(event_approvals::ListPage {
+                can_manage_groups,
+                events,
+            })
+        }
         Tab::EventCategories => {
             let (can_manage_taxonomy, categories) = tokio::try_join!(
                 db.user_has_community_permission(
@@ -83,6 +105,30 @@ pub(crate) async fn page(
                 categories,
             })
         }
+        Tab::FailedNotifications => {
+            let (_, template) = failed_notifications::prepare_list_page(
+                &db,
+                community_id,
+                user_id,
+                raw_query.as_deref().unwrap_or_default(),
+            )
+            .await?;
+            Content::FailedNotifications(template)
+        }
+        Tab::GroupBans => {
+            let (bans, can_manage_groups) = tokio::try_join!(
+                db.list_community_group_bans(community_id),
+                db.user_has_community_permission(
+                    &community_id,
+                    &user_id,
+                    CommunityPermission::GroupsWrite
+                )
+            )?;
+            Content::GroupBans(group_bans::ListPage {
+                bans,
+                can_manage_groups,
+            })
+        }
         Tab::GroupCategories => {
             let (can_manage_taxonomy, categories) = tokio::try_join!(
                 db.user_has_community_permission(
@@ -117,6 +163,19 @@ pub(crate) async fn page(
             .await?;
             Content::Logs(template)
         }
+        Tab::Newsletter => {
+            let template = newsletter::prepare_list_page(&db, community_id, user_id).await?;
+            Content::Newsletter(template)
+        }
+        Tab::Notifications => {
+            let (_, template) = notifications::prepare_list_page(
+                &db,
+                community_id,
+                raw_query.as_deref().unwrap_or_default(),
+            )
+            .await?;
+            Content::Notifications(template)
+        }
         Tab::Regions => {
             let (can_manage_taxonomy, regions) = tokio::try_join!(
                 db.user_has_community_permission(
@@ -132,16 +191,22 @@ pub(crate) async fn page(
             })
         }
         Tab::Settings => {
-            let can_manage_settings = db
-                .user_has_community_permission(
+            let (can_manage_settings, email_branding, theme, slack_webhook_url) = tokio::try_join!(
+                db.user_has_community_permission(
                     &community_id,
                     &user_id,
                     CommunityPermission::SettingsWrite,
-                )
-                .await?;
+                ),
+                db.get_community_email_branding(community_id),
+                db.get_community_theme(community_id),
+                db.get_community_slack_webhook_url(community_id)
+            )?;
             Content::Settings(Box::new(settings::UpdatePage {
                 can_manage_settings,
                 community: community.clone(),
+                email_branding,
+                theme,
+                slack_webhook_url,
             }))
         }
         Tab::Team => {
@@ -154,6 +219,20 @@ pub(crate) async fn page(
             .await?;
             Content::Team(template)
         }
+        Tab::Topics => {
+            let (can_manage_taxonomy, topics) = tokio::try_join!(
+                db.user_has_community_permission(
+                    &community_id,
+                    &user_id,
+                    CommunityPermission::TaxonomyWrite
+                ),
+                db.list_topics(community_id)
+            )?;
+            Content::Topics(topics::ListPage {
+                can_manage_taxonomy,
+                topics,
+            })
+        }
     };
 
     // Render the page
@@ -14,7 +14,10 @@ use crate::{
     db::{common::SearchGroupsOutput, mock::MockDB},
     handlers::tests::*,
     services::notifications::MockNotificationsManager,
-    templates::dashboard::{DASHBOARD_PAGINATION_LIMIT, audit::AuditLogSort},
+    templates::dashboard::{
+        DASHBOARD_PAGINATION_LIMIT, audit::AuditLogSort,
+        community::failed_notifications::FailedNotificationsOutput,
+    },
     types::permissions::CommunityPermission,
 };
 
@@ -45,6 +48,9 @@ async fn test_page_analytics_tab_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -81,6 +87,160 @@ async fn test_page_analytics_tab_success() {
     assert_html_response(&parts, &bytes, StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_page_domains_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::SettingsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_list_user_communities()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_communities(community_id)));
+    db.expect_list_community_domains()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community?tab=domains")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_page_failed_notifications_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::SettingsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_list_user_communities()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_communities(community_id)));
+    db.expect_list_community_failed_notifications()
+        .times(1)
+        .withf(move |id, filters| {
+            *id == community_id
+                && filters.limit == Some(DASHBOARD_PAGINATION_LIMIT)
+                && filters.offset == Some(0)
+        })
+        .returning(|_, _| {
+            Ok(FailedNotificationsOutput {
+                notifications: vec![],
+                total: 0,
+            })
+        });
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community?tab=failed-notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
 #[tokio::test]
 async fn test_page_groups_tab_success() {
     // Setup identifiers and data structures
@@ -115,6 +275,9 @@ async fn test_page_groups_tab_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -123,6 +286,9 @@ async fn test_page_groups_tab_success() {
                 && permission == CommunityPermission::GroupsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -195,6 +361,9 @@ async fn test_page_logs_tab_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -236,6 +405,153 @@ async fn test_page_logs_tab_success() {
     assert_html_response(&parts, &bytes, StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_page_newsletter_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::SettingsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_list_user_communities()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_communities(community_id)));
+    db.expect_list_community_newsletter_issues()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_count_community_newsletter_subscribers()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(0));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community?tab=newsletter")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_page_notifications_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let output = sample_custom_notifications_output();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_list_user_communities()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_communities(community_id)));
+    db.expect_list_community_custom_notifications()
+        .times(1)
+        .withf(move |cid, filters| {
+            *cid == community_id
+                && filters.limit == Some(DASHBOARD_PAGINATION_LIMIT)
+                && filters.offset == Some(0)
+        })
+        .returning(move |_, _| Ok(output.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community?tab=notifications")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
 #[tokio::test]
 async fn test_page_settings_tab_success() {
     // Setup identifiers and data structures
@@ -262,6 +578,9 @@ async fn test_page_settings_tab_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -270,6 +589,21 @@ async fn test_page_settings_tab_success() {
                 && permission == CommunityPermission::SettingsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_get_community_email_branding()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(sample_community_email_branding()));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_community_slack_webhook_url()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -338,12 +672,18 @@ async fn test_page_team_tab_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::TeamWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -414,6 +754,9 @@ async fn test_page_regions_tab_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -422,6 +765,9 @@ async fn test_page_regions_tab_success() {
                 && permission == CommunityPermission::TaxonomyWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -485,6 +831,9 @@ async fn test_page_group_categories_tab_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -493,6 +842,9 @@ async fn test_page_group_categories_tab_success() {
                 && permission == CommunityPermission::TaxonomyWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -556,6 +908,9 @@ async fn test_page_event_categories_tab_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -564,6 +919,9 @@ async fn test_page_event_categories_tab_success() {
                 && permission == CommunityPermission::TaxonomyWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -600,6 +958,237 @@ async fn test_page_event_categories_tab_success() {
     assert_html_response(&parts, &bytes, StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_page_event_approvals_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+    let events = vec![];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_list_user_communities()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_communities(community_id)));
+    db.expect_list_community_pending_events()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(events.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community?tab=event-approvals")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_page_group_bans_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+    let bans = vec![];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::GroupsWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_list_user_communities()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_communities(community_id)));
+    db.expect_list_community_group_bans()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(bans.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community?tab=group-bans")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_page_topics_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record =
+        sample_session_record(session_id, user_id, &auth_hash, Some(community_id), None);
+    let topics = vec![sample_community_topic()];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_user_has_community_permission()
+        .times(1)
+        .withf(move |cid, uid, permission| {
+            *cid == community_id
+                && *uid == user_id
+                && permission == CommunityPermission::TaxonomyWrite
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_list_user_communities()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(sample_user_communities(community_id)));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(topics.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community?tab=topics")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
 #[tokio::test]
 async fn test_page_db_error() {
     // Setup identifiers and data structures
@@ -626,6 +1215,9 @@ async fn test_page_db_error() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |id| *id == community_id)
@@ -0,0 +1,158 @@
+//! HTTP handlers for managing the newsletter in the community dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::HttpServerConfig,
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, ValidatedForm},
+    },
+    services::notifications::preview::{self, EmailPreviewContext},
+    templates::dashboard::community::newsletter::{self, CommunityNewsletterIssueInput},
+    types::permissions::CommunityPermission,
+    util::base_url_without_trailing_slash,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the newsletter issues of the community.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_list_page(&db, community_id, user.user_id).await?;
+
+    Ok(Html(template.render()?))
+}
+
+/// Renders the email of a newsletter issue with the community branding.
+#[instrument(skip_all, err)]
+pub(crate) async fn preview(
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(community_newsletter_issue_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get the issue, community data and site settings
+    let (issue, community, email_branding, site_settings) = tokio::try_join!(
+        db.get_community_newsletter_issue(community_id, community_newsletter_issue_id),
+        db.get_community_full(community_id),
+        db.get_community_email_branding(community_id),
+        db.get_site_settings(),
+    )?;
+    let Some(issue) = issue else {
+        return Err(HandlerError::NotFound);
+    };
+
+    // Render the issue email
+    let ctx = EmailPreviewContext {
+        base_url: base_url_without_trailing_slash(&server_cfg.base_url),
+        community: &community,
+        email_branding,
+        theme: &site_settings.theme,
+    };
+    let (_, body) = preview::render_community_newsletter_preview(
+        &ctx,
+        issue.community_newsletter_issue_id,
+        &issue.subject,
+        &issue.body,
+    )?;
+
+    Ok(Html(body))
+}
+
+// Actions handlers.
+
+/// Adds a newsletter issue draft to the community.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    ValidatedForm(input): ValidatedForm<CommunityNewsletterIssueInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.add_community_newsletter_issue(user.user_id, community_id, &input)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+/// Deletes a newsletter issue draft from the community.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(community_newsletter_issue_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_community_newsletter_issue(user.user_id, community_id, community_newsletter_issue_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+/// Sends a newsletter issue to the community subscribers.
+///
+/// The recipients are snapshotted when the issue is sent, and the notifications
+/// worker enqueues their emails in batches.
+#[instrument(skip_all, err)]
+pub(crate) async fn send(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(community_newsletter_issue_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.send_community_newsletter_issue(user.user_id, community_id, community_newsletter_issue_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+// Helpers.
+
+/// Prepares the newsletter issues list page for the community dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    community_id: Uuid,
+    user_id: Uuid,
+) -> Result<newsletter::ListPage> {
+    let (can_manage_settings, issues, subscriber_count) = tokio::try_join!(
+        db.user_has_community_permission(
+            &community_id,
+            &user_id,
+            CommunityPermission::SettingsWrite
+        ),
+        db.list_community_newsletter_issues(community_id),
+        db.count_community_newsletter_subscribers(community_id)
+    )?;
+
+    Ok(newsletter::ListPage {
+        can_manage_settings,
+        issues,
+        subscriber_count,
+    })
+}
@@ -0,0 +1,440 @@
+use anyhow::anyhow;
+use axum::{
+    body::{Body, to_bytes},
+    http::{
+        HeaderValue, Request, StatusCode,
+        header::{CONTENT_TYPE, COOKIE, HOST},
+    },
+};
+use axum_login::tower_sessions::session;
+use chrono::Utc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    db::mock::MockDB, handlers::tests::*, services::notifications::MockNotificationsManager,
+    templates::dashboard::community::newsletter::CommunityNewsletterIssue,
+    types::permissions::CommunityPermission,
+};
+
+#[tokio::test]
+async fn test_add_invalid_payload() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/community/newsletter/add")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("subject=News&body=+++"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_add_community_newsletter_issue()
+        .times(1)
+        .withf(move |uid, cid, issue| {
+            *uid == user_id
+                && *cid == community_id
+                && issue.subject == "Monthly news"
+                && issue.body == "**Hello** everyone"
+        })
+        .returning(|_, _, _| Ok(Uuid::new_v4()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/community/newsletter/add")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(
+            "subject=Monthly+news&body=%2A%2AHello%2A%2A+everyone",
+        ))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::CREATED,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_delete_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let community_newsletter_issue_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_delete_community_newsletter_issue()
+        .times(1)
+        .withf(move |uid, cid, iid| {
+            *uid == user_id && *cid == community_id && *iid == community_newsletter_issue_id
+        })
+        .returning(|_, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!(
+            "/dashboard/community/newsletter/{community_newsletter_issue_id}/delete"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_list_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let draft = sample_issue("Draft issue", None);
+    let sent = sample_issue("Sent issue", Some(3));
+    let draft_id = draft.community_newsletter_issue_id;
+    let issues = vec![draft, sent];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_list_community_newsletter_issues()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(issues.clone()));
+    db.expect_count_community_newsletter_subscribers()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(3));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community/newsletter")
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    let html = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(html.contains("3 subscribers"));
+    assert!(html.contains("Draft issue"));
+    assert!(html.contains("Sent issue"));
+    assert!(html.contains(&format!("/newsletter/{draft_id}/send")));
+    assert!(html.contains("3 recipients"));
+}
+
+#[tokio::test]
+async fn test_preview_not_found() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let community_newsletter_issue_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    db.expect_get_community_newsletter_issue()
+        .times(1)
+        .withf(move |cid, iid| *cid == community_id && *iid == community_newsletter_issue_id)
+        .returning(|_, _| Ok(None));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_get_community_email_branding()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_email_branding()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/dashboard/community/newsletter/{community_newsletter_issue_id}/preview"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_preview_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let issue = sample_issue("Monthly news", None);
+    let community_newsletter_issue_id = issue.community_newsletter_issue_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(&mut db, community_id, user_id, CommunityPermission::Read);
+    db.expect_get_community_newsletter_issue()
+        .times(1)
+        .withf(move |cid, iid| *cid == community_id && *iid == community_newsletter_issue_id)
+        .returning(move |_, _| Ok(Some(issue.clone())));
+    db.expect_get_community_full()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    db.expect_get_community_email_branding()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_email_branding()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/dashboard/community/newsletter/{community_newsletter_issue_id}/preview"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    let html = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(html.contains("Monthly news"));
+    assert!(html.contains("<strong>Hello</strong> everyone"));
+    assert!(html.contains("#0A3D62"));
+}
+
+#[tokio::test]
+async fn test_send_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let community_newsletter_issue_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_send_community_newsletter_issue()
+        .times(1)
+        .withf(move |uid, cid, iid| {
+            *uid == user_id && *cid == community_id && *iid == community_newsletter_issue_id
+        })
+        .returning(|_, _, _| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/newsletter/{community_newsletter_issue_id}/send"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_send_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let community_newsletter_issue_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_community_session(&mut db, session_id, user_id, community_id);
+    expect_community_permission(
+        &mut db,
+        community_id,
+        user_id,
+        CommunityPermission::SettingsWrite,
+    );
+    db.expect_send_community_newsletter_issue()
+        .times(1)
+        .withf(move |uid, cid, iid| {
+            *uid == user_id && *cid == community_id && *iid == community_newsletter_issue_id
+        })
+        .returning(|_, _, _| Ok(3));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/community/newsletter/{community_newsletter_issue_id}/send"
+        ))
+        .header(HOST, "example.test")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-community-dashboard-table",
+    );
+}
+
+// Helpers.
+
+/// Create a sample newsletter issue, sent to the recipients provided if any.
+fn sample_issue(subject: &str, recipient_count: Option<usize>) -> CommunityNewsletterIssue {
+    CommunityNewsletterIssue {
+        body: "**Hello** everyone".to_string(),
+        bounced: 0,
+        community_newsletter_issue_id: Uuid::new_v4(),
+        created_at: Utc::now(),
+        failed: 0,
+        queued: recipient_count.unwrap_or_default(),
+        sent: 0,
+        subject: subject.to_string(),
+        unknown: 0,
+
+        created_by_username: Some("organizer".to_string()),
+        recipient_count,
+        sent_at: recipient_count.map(|_| Utc::now()),
+    }
+}
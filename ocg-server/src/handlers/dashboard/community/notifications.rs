@@ -0,0 +1,110 @@
+//! HTTP handlers for community dashboard custom notifications.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, RawQuery, State},
+    http::{HeaderName, StatusCode},
+    response::{Html, IntoResponse},
+};
+use garde::Validate;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::HttpServerConfig,
+    db::DynDB,
+    handlers::{error::HandlerError, extractors::SelectedCommunityId},
+    router::serde_qs_config,
+    services::notifications::{
+        NotificationKind,
+        preview::{self, EmailPreviewContext},
+    },
+    templates::dashboard::notifications::{
+        CustomNotificationsFilters, ListPage, NotificationsScope,
+    },
+    types::pagination::{self, NavigationLinks},
+    util::base_url_without_trailing_slash,
+};
+
+#[cfg(test)]
+mod tests;
+
+// URLs used by the dashboard page and tab partial
+const DASHBOARD_URL: &str = "/dashboard/community?tab=notifications";
+const PARTIAL_URL: &str = "/dashboard/community/notifications";
+
+// Pages handlers.
+
+/// Displays the community custom notifications list.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    RawQuery(raw_query): RawQuery,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare list page content
+    let (filters, template) =
+        prepare_list_page(&db, community_id, raw_query.as_deref().unwrap_or_default()).await?;
+
+    // Prepare response headers
+    let url = pagination::build_url(DASHBOARD_URL, &filters)?;
+    let headers = [(HeaderName::from_static("hx-push-url"), url)];
+
+    Ok((headers, Html(template.render()?)))
+}
+
+/// Renders a notification email with sample data and the community branding.
+#[instrument(skip_all, err)]
+pub(crate) async fn preview(
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(kind): Path<NotificationKind>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get community data and site settings
+    let (community, email_branding, site_settings) = tokio::try_join!(
+        db.get_community_full(community_id),
+        db.get_community_email_branding(community_id),
+        db.get_site_settings(),
+    )?;
+
+    // Render the email preview, if the notification is delivered by email
+    let ctx = EmailPreviewContext {
+        base_url: base_url_without_trailing_slash(&server_cfg.base_url),
+        community: &community,
+        email_branding,
+        theme: &site_settings.theme,
+    };
+    let Some((_, body)) = preview::render_email_preview(&kind, &ctx)? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    Ok(Html(body).into_response())
+}
+
+// Helpers.
+
+/// Prepares the custom notifications list page for the community dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    community_id: Uuid,
+    raw_query: &str,
+) -> Result<(CustomNotificationsFilters, ListPage), HandlerError> {
+    // Fetch custom notifications
+    let filters: CustomNotificationsFilters = serde_qs_config().deserialize_str(raw_query)?;
+    filters.validate()?;
+    let results = db.list_community_custom_notifications(community_id, &filters).await?;
+
+    // Prepare template
+    let navigation_links =
+        NavigationLinks::from_filters(&filters, results.total, DASHBOARD_URL, PARTIAL_URL)?;
+    let template = ListPage::new(
+        NotificationsScope::Community,
+        &filters,
+        results,
+        navigation_links,
+    );
+
+    Ok((filters, template))
+}
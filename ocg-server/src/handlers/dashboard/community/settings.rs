@@ -32,17 +32,23 @@ pub(crate) async fn update_page(
     State(db): State<DynDB>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Prepare template
-    let (can_manage_settings, community) = tokio::try_join!(
+    let (can_manage_settings, community, email_branding, theme, slack_webhook_url) = tokio::try_join!(
         db.user_has_community_permission(
             &community_id,
             &user.user_id,
             CommunityPermission::SettingsWrite
         ),
-        db.get_community_full(community_id)
+        db.get_community_full(community_id),
+        db.get_community_email_branding(community_id),
+        db.get_community_theme(community_id),
+        db.get_community_slack_webhook_url(community_id)
     )?;
     let template = settings::UpdatePage {
         can_manage_settings,
         community,
+        email_branding,
+        theme,
+        slack_webhook_url,
     };
 
     Ok(Html(template.render()?))
@@ -42,6 +42,9 @@ async fn test_update_page_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -50,10 +53,29 @@ async fn test_update_page_success() {
                 && permission == CommunityPermission::SettingsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Ok(community.clone()));
+    db.expect_get_community_email_branding()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(sample_community_email_branding()));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_community_slack_webhook_url()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| {
+            Ok(Some(
+                "https://hooks.slack.com/services/T0/B0/secret".to_string(),
+            ))
+        });
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -106,6 +128,9 @@ async fn test_update_page_db_error() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_user_has_community_permission()
         .times(1)
         .withf(move |cid, uid, permission| {
@@ -114,10 +139,25 @@ async fn test_update_page_db_error() {
                 && permission == CommunityPermission::SettingsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_get_community_full()
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Err(anyhow!("db error")));
+    db.expect_get_community_email_branding()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(sample_community_email_branding()));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_community_slack_webhook_url()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -171,6 +211,9 @@ async fn test_update_success() {
                 && permission == CommunityPermission::SettingsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_update_community()
         .times(1)
         .withf(move |uid, cid, update| {
@@ -232,6 +275,9 @@ async fn test_update_invalid_payload() {
                 && permission == CommunityPermission::SettingsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -285,6 +331,9 @@ async fn test_update_db_error() {
                 && permission == CommunityPermission::SettingsWrite
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_update_community()
         .times(1)
         .withf(move |uid, cid, _| *uid == user_id && *cid == community_id)
@@ -62,6 +62,9 @@ async fn test_list_page_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_list_community_team_members()
         .times(1)
         .withf(move |cid, filters| {
@@ -79,6 +82,9 @@ async fn test_list_page_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::TeamWrite
         })
         .returning(move |_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -142,6 +148,9 @@ async fn test_list_page_with_pagination_params() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_list_community_team_members()
         .times(1)
         .withf(move |cid, filters| {
@@ -157,6 +166,9 @@ async fn test_list_page_with_pagination_params() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::TeamWrite
         })
         .returning(move |_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -206,6 +218,9 @@ async fn test_list_page_db_error() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::Read
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_list_community_team_members()
         .times(1)
         .withf(move |cid, filters| {
@@ -272,6 +287,9 @@ async fn test_add_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::TeamWrite
         })
         .returning(move |_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_add_community_team_member()
         .times(1)
         .withf(move |actor_user_id, cid, uid, role| {
@@ -363,6 +381,9 @@ async fn test_add_db_error() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::TeamWrite
         })
         .returning(move |_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_add_community_team_member()
         .times(1)
         .withf(move |actor_user_id, cid, uid, role| {
@@ -422,6 +443,9 @@ async fn test_delete_success() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::TeamWrite
         })
         .returning(move |_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_delete_community_team_member()
         .times(1)
         .withf(move |actor_user_id, cid, uid| {
@@ -480,6 +504,9 @@ async fn test_delete_current_user_logs_out() {
             *cid == community_id && *uid == user_id && permission == CommunityPermission::TeamWrite
         })
         .returning(move |_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
     db.expect_delete_community_team_member()
         .times(1)
         .withf(move |actor_user_id, cid, uid| {
@@ -0,0 +1,151 @@
+//! HTTP handlers for managing topics in the community dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, ValidatedForm},
+    },
+    templates::dashboard::community::topics::{self, TopicInput},
+    types::permissions::CommunityPermission,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the list of topics for the selected community.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare template
+    let (can_manage_taxonomy, topics) = tokio::try_join!(
+        db.user_has_community_permission(
+            &community_id,
+            &user.user_id,
+            CommunityPermission::TaxonomyWrite
+        ),
+        db.list_topics(community_id)
+    )?;
+    let template = topics::ListPage {
+        can_manage_taxonomy,
+        topics,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+/// Displays the form to create a new topic.
+#[instrument(skip_all, err)]
+pub(crate) async fn add_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare template
+    let can_manage_taxonomy = db
+        .user_has_community_permission(
+            &community_id,
+            &user.user_id,
+            CommunityPermission::TaxonomyWrite,
+        )
+        .await?;
+    let template = topics::AddPage {
+        can_manage_taxonomy,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+/// Displays the form to update an existing topic.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(topic_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare template
+    let (can_manage_taxonomy, topics) = tokio::try_join!(
+        db.user_has_community_permission(
+            &community_id,
+            &user.user_id,
+            CommunityPermission::TaxonomyWrite
+        ),
+        db.list_topics(community_id)
+    )?;
+    let Some(topic) = topics.into_iter().find(|topic| topic.topic_id == topic_id) else {
+        return Err(HandlerError::Database("topic not found".to_string()));
+    };
+    let template = topics::UpdatePage {
+        can_manage_taxonomy,
+        topic,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Adds a new topic to the selected community.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    ValidatedForm(topic): ValidatedForm<TopicInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.add_topic(user.user_id, community_id, &topic).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+/// Deletes a topic from the selected community.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(topic_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_topic(user.user_id, community_id, topic_id).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
+
+/// Updates a topic in the selected community.
+#[instrument(skip_all, err)]
+pub(crate) async fn update(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    State(db): State<DynDB>,
+    Path(topic_id): Path<Uuid>,
+    ValidatedForm(topic): ValidatedForm<TopicInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_topic(user.user_id, community_id, topic_id, &topic).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-community-dashboard-table")],
+    ))
+}
@@ -22,13 +22,22 @@ use crate::{
 mod tests;
 
 pub(crate) mod analytics;
+pub(crate) mod announcements;
 pub(crate) mod attendees;
+pub(crate) mod comments;
+pub(crate) mod discussions;
+pub(crate) mod event_templates;
 pub(crate) mod events;
+pub(crate) mod gallery;
 pub(crate) mod home;
 pub(crate) mod invitation_requests;
+pub(crate) mod kiosk;
 pub(crate) mod logs;
 pub(crate) mod members;
+pub(crate) mod notifications;
+pub(crate) mod photos;
 pub(crate) mod refunds;
+pub(crate) mod resources;
 pub(crate) mod settings;
 pub(crate) mod sponsors;
 pub(crate) mod submissions;
@@ -30,11 +30,13 @@ pub(crate) async fn page(
     Query(query): Query<analytics::AnalyticsQuery>,
 ) -> Result<impl IntoResponse, HandlerError> {
     let include_subgroups = query.include_subgroups.unwrap_or(false);
-    let (stats, has_subgroups) = tokio::try_join!(
+    let (stats, engagement, has_subgroups) = tokio::try_join!(
         db.get_group_stats(community_id, group_id, include_subgroups),
+        db.get_group_engagement_stats(community_id, group_id, include_subgroups),
         db.group_has_active_subgroups(community_id, group_id)
     )?;
     let page = analytics::Page {
+        engagement,
         include_subgroups,
         has_subgroups,
         stats,
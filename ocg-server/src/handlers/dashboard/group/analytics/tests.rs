@@ -88,6 +88,7 @@ async fn test_page_success() {
         Some(community_id),
         Some(group_id),
     );
+    let engagement = sample_group_engagement_stats();
     let stats = sample_group_stats();
 
     // Setup database mock
@@ -112,6 +113,12 @@ async fn test_page_success() {
             *cid == community_id && *gid == group_id && *include_subgroups
         })
         .returning(move |_, _, _| Ok(stats.clone()));
+    db.expect_get_group_engagement_stats()
+        .times(1)
+        .withf(move |cid, gid, include_subgroups| {
+            *cid == community_id && *gid == group_id && *include_subgroups
+        })
+        .returning(move |_, _, _| Ok(engagement.clone()));
     db.expect_group_has_active_subgroups()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
@@ -0,0 +1,223 @@
+//! HTTP handlers for managing the group announcements in the dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use chrono::{Duration, Utc};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::HttpServerConfig,
+    db::{DynDB, notifications::CustomNotificationTracking},
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId, ValidatedForm},
+    },
+    services::notifications::{NewNotification, NotificationKind},
+    templates::{
+        dashboard::group::announcements::{self, GroupAnnouncementInput},
+        notifications::GroupCustom,
+    },
+    types::permissions::GroupPermission,
+    util::base_url_without_trailing_slash,
+};
+
+use super::members::{
+    CUSTOM_NOTIFICATIONS_LIMIT, CUSTOM_NOTIFICATIONS_WINDOW_HOURS, GroupCustomNotificationAudience,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the announcements posted in the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_list_page(&db, community_id, group_id, user.user_id).await?;
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Posts a new announcement in the group, emailing it to the members if requested.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    ValidatedForm(announcement): ValidatedForm<GroupAnnouncementInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Post the announcement without notifying the members
+    if !announcement.notify_members {
+        db.add_group_announcement(user.user_id, group_id, &announcement)
+            .await?;
+
+        return Ok((
+            StatusCode::NO_CONTENT,
+            [("HX-Trigger", "refresh-group-dashboard-table")],
+        )
+            .into_response());
+    }
+
+    // Get group data, site settings and the members to notify
+    let since = Utc::now() - Duration::hours(CUSTOM_NOTIFICATIONS_WINDOW_HOURS);
+    let (site_settings, group, recipients, recent_notifications) = tokio::try_join!(
+        db.get_site_settings(),
+        db.get_group_summary(community_id, group_id),
+        db.resolve_group_custom_notification_recipient_ids(
+            group_id,
+            GroupCustomNotificationAudience::All.as_ref(),
+            None,
+            None,
+            None
+        ),
+        db.count_group_custom_notifications_since(group_id, since),
+    )?;
+
+    // Throttle the number of custom notifications sent by the group
+    if recent_notifications >= CUSTOM_NOTIFICATIONS_LIMIT {
+        let message = format!(
+            "Groups can send up to {CUSTOM_NOTIFICATIONS_LIMIT} emails every {CUSTOM_NOTIFICATIONS_WINDOW_HOURS} hours. Please post the announcement without emailing it or try again later."
+        );
+        return Ok((StatusCode::TOO_MANY_REQUESTS, message).into_response());
+    }
+
+    // Post the announcement
+    db.add_group_announcement(user.user_id, group_id, &announcement)
+        .await?;
+
+    // Build and enqueue the announcement notification with its audit entry
+    if !recipients.is_empty() {
+        let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+        let link = format!(
+            "{}/{}/group/{}",
+            base_url,
+            group.community_name,
+            group.public_slug()
+        );
+        let template_data = GroupCustom {
+            body: announcement.content.clone(),
+            group,
+            link,
+            subject: announcement.title.clone(),
+            theme: site_settings.theme,
+        };
+        let new_notification = NewNotification {
+            attachments: vec![],
+            kind: NotificationKind::GroupCustom,
+            recipients,
+            template_data: Some(serde_json::to_value(&template_data)?),
+        };
+        db.enqueue_tracked_custom_notification(
+            &new_notification,
+            CustomNotificationTracking {
+                body: announcement.content,
+                created_by: user.user_id,
+                event_id: None,
+                group_id: Some(group_id),
+                recipient_count: new_notification.recipients.len(),
+                subject: announcement.title,
+            },
+        )
+        .await?;
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Deletes an announcement from the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_announcement_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_group_announcement(user.user_id, group_id, group_announcement_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Pins an announcement, showing it on the group page.
+#[instrument(skip_all, err)]
+pub(crate) async fn pin(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_announcement_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_announcement_pinned(user.user_id, group_id, group_announcement_id, true)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Unpins an announcement, hiding it from the group page.
+#[instrument(skip_all, err)]
+pub(crate) async fn unpin(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_announcement_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_announcement_pinned(user.user_id, group_id, group_announcement_id, false)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+// Helpers.
+
+/// Prepares the announcements list page for the group dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    community_id: Uuid,
+    group_id: Uuid,
+    user_id: Uuid,
+) -> Result<announcements::ListPage> {
+    let (can_manage_members, announcements) = tokio::try_join!(
+        db.user_has_group_permission(
+            &community_id,
+            &group_id,
+            &user_id,
+            GroupPermission::MembersWrite
+        ),
+        db.list_group_announcements(group_id)
+    )?;
+
+    Ok(announcements::ListPage {
+        announcements,
+        can_manage_members,
+    })
+}
@@ -0,0 +1,504 @@
+use anyhow::anyhow;
+use axum::{
+    body::{Body, to_bytes},
+    http::{
+        HeaderValue, Request, StatusCode,
+        header::{CONTENT_TYPE, COOKIE},
+    },
+};
+use axum_login::tower_sessions::session;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    db::mock::MockDB,
+    handlers::tests::*,
+    services::notifications::{MockNotificationsManager, NotificationKind},
+    templates::{
+        dashboard::group::announcements::GroupAnnouncementInput, notifications::GroupCustom,
+    },
+    types::permissions::GroupPermission,
+};
+
+#[tokio::test]
+async fn test_add_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_announcement_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let form_data = serde_qs::to_string(&GroupAnnouncementInput {
+        content: "The venue for the next meetup has changed.".to_string(),
+        title: "Venue change".to_string(),
+        notify_members: false,
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_add_group_announcement()
+        .times(1)
+        .withf(move |actor_id, gid, announcement| {
+            *actor_id == user_id
+                && *gid == group_id
+                && announcement.title == "Venue change"
+                && !announcement.notify_members
+        })
+        .returning(move |_, _, _| Ok(group_announcement_id));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/announcements")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_add_notify_members_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_announcement_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let member_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let group_summary = sample_group_summary(group_id);
+    let expected_link = format!(
+        "/{}/group/{}",
+        group_summary.community_name,
+        group_summary.public_slug()
+    );
+    let form_data = serde_qs::to_string(&GroupAnnouncementInput {
+        content: "The venue for the next meetup has changed.".to_string(),
+        title: "Venue change".to_string(),
+        notify_members: true,
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_resolve_group_custom_notification_recipient_ids()
+        .times(1)
+        .withf(
+            move |gid, audience, event_id, inactive_months, member_role| {
+                *gid == group_id
+                    && audience == "all-members"
+                    && event_id.is_none()
+                    && inactive_months.is_none()
+                    && member_role.is_none()
+            },
+        )
+        .returning(move |_, _, _, _, _| Ok(vec![member_id]));
+    db.expect_count_group_custom_notifications_since()
+        .times(1)
+        .withf(move |gid, _| *gid == group_id)
+        .returning(|_, _| Ok(0));
+    db.expect_add_group_announcement()
+        .times(1)
+        .withf(move |actor_id, gid, announcement| {
+            *actor_id == user_id && *gid == group_id && announcement.notify_members
+        })
+        .returning(move |_, _, _| Ok(group_announcement_id));
+    db.expect_enqueue_tracked_custom_notification()
+        .times(1)
+        .withf(move |notification, tracking| {
+            matches!(notification.kind, NotificationKind::GroupCustom)
+                && notification.recipients == vec![member_id]
+                && notification.template_data.as_ref().is_some_and(|value| {
+                    serde_json::from_value::<GroupCustom>(value.clone()).is_ok_and(|template| {
+                        template.subject == "Venue change"
+                            && template.body == "The venue for the next meetup has changed."
+                            && template.link == expected_link
+                    })
+                })
+                && tracking.created_by == user_id
+                && tracking.group_id == Some(group_id)
+                && tracking.recipient_count == 1
+                && tracking.subject == "Venue change"
+        })
+        .returning(|_, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/announcements")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_add_notify_members_throttled() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let group_summary = sample_group_summary(group_id);
+    let form_data = serde_qs::to_string(&GroupAnnouncementInput {
+        content: "Content".to_string(),
+        title: "Title".to_string(),
+        notify_members: true,
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_summary()
+        .times(1)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_resolve_group_custom_notification_recipient_ids()
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(vec![Uuid::new_v4()]));
+    db.expect_count_group_custom_notifications_since()
+        .times(1)
+        .withf(move |gid, _| *gid == group_id)
+        .returning(|_, _| Ok(5));
+    db.expect_add_group_announcement().times(0);
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/announcements")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::TOO_MANY_REQUESTS);
+    assert!(
+        String::from_utf8(bytes.to_vec())
+            .unwrap()
+            .contains("Groups can send up to 5 emails every 24 hours")
+    );
+}
+
+#[tokio::test]
+async fn test_delete_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_announcement_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_delete_group_announcement()
+        .times(1)
+        .withf(move |actor_id, gid, aid| {
+            *actor_id == user_id && *gid == group_id && *aid == group_announcement_id
+        })
+        .returning(|_, _, _| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!(
+            "/dashboard/group/announcements/{group_announcement_id}"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_announcement_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_delete_group_announcement()
+        .times(1)
+        .withf(move |actor_id, gid, aid| {
+            *actor_id == user_id && *gid == group_id && *aid == group_announcement_id
+        })
+        .returning(|_, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!(
+            "/dashboard/group/announcements/{group_announcement_id}"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_list_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::Read,
+    );
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_list_group_announcements()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(vec![sample_group_announcement()]));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/group/announcements")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(body.contains("Venue change"));
+    assert!(body.contains("The venue for the next meetup has changed."));
+    assert!(body.contains("/unpin"));
+}
+
+#[tokio::test]
+async fn test_pin_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_announcement_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_update_group_announcement_pinned()
+        .times(1)
+        .withf(move |actor_id, gid, aid, pinned| {
+            *actor_id == user_id && *gid == group_id && *aid == group_announcement_id && *pinned
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/group/announcements/{group_announcement_id}/pin"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_unpin_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_announcement_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_update_group_announcement_pinned()
+        .times(1)
+        .withf(move |actor_id, gid, aid, pinned| {
+            *actor_id == user_id && *gid == group_id && *aid == group_announcement_id && !*pinned
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/group/announcements/{group_announcement_id}/unpin"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
@@ -10,8 +10,9 @@ use axum::{
     },
     response::{Html, IntoResponse},
 };
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use garde::Validate;
-use qrcode::render::svg;
 use serde::{Deserialize, Serialize};
 use tracing::{instrument, warn};
 use uuid::Uuid;
@@ -20,6 +21,7 @@ use crate::{
     config::HttpServerConfig,
     db::{DBExt, DynDB, notifications::CustomNotificationTracking},
     handlers::{
+        auth::user_has_group_event_permission,
         error::HandlerError,
         extractors::{
             CurrentUser, SelectedCommunityId, SelectedGroupId, ValidatedForm, ValidatedFormQs,
@@ -27,10 +29,12 @@ use crate::{
     },
     router::serde_qs_config,
     services::{
+        badges::render_attendee_badges_pdf,
         notifications::{
             DynNotificationsManager, NewNotification, NotificationKind,
             enqueue::{
                 enqueue_event_attendance_cancellation_notifications,
+                enqueue_event_invitation_request_rejected_notification,
                 enqueue_event_welcome_notification,
             },
             load_event_notification_context,
@@ -47,7 +51,7 @@ use crate::{
         permissions::GroupPermission,
         questionnaire::QuestionnaireQuestion,
     },
-    util::base_url_without_trailing_slash,
+    util::{base_url_without_trailing_slash, build_qr_code_svg},
     validation::{
         MAX_LEN_DESCRIPTION_SHORT, MAX_LEN_M, MAX_LEN_NOTIFICATION_BODY, trimmed_non_empty,
         trimmed_non_empty_opt,
@@ -76,17 +80,28 @@ pub(crate) async fn list_page(
     let filters: AttendeesFilters =
         serde_qs_config().deserialize_str(raw_query.as_deref().unwrap_or_default())?;
     filters.validate()?;
-    let (can_manage_events, event, registration_questions, search_attendees_results) = tokio::try_join!(
-        db.user_has_group_permission(
-            &community_id,
-            &group_id,
-            &user.user_id,
-            GroupPermission::EventsWrite
-        ),
-        db.get_event_summary(community_id, group_id, event_id),
-        db.get_event_registration_questions(community_id, event_id),
-        db.search_event_attendees(group_id, event_id, &filters)
-    )?;
+    let (can_manage_events, can_check_in, event, registration_questions, search_attendees_results) =
+        tokio::try_join!(
+            user_has_group_event_permission(
+                &db,
+                &community_id,
+                &group_id,
+                &event_id,
+                &user.user_id,
+                GroupPermission::EventsWrite
+            ),
+            user_has_group_event_permission(
+                &db,
+                &community_id,
+                &group_id,
+                &event_id,
+                &user.user_id,
+                GroupPermission::CheckInWrite
+            ),
+            db.get_event_summary(community_id, group_id, event_id),
+            db.get_event_registration_questions(community_id, event_id),
+            db.search_event_attendees(group_id, event_id, &filters)
+        )?;
 
     // Prepare template
     let navigation_links = NavigationLinks::from_filters(
@@ -109,6 +124,7 @@ pub(crate) async fn list_page(
             .all_attendees_email_recipient_total,
         attendance,
         attendees: search_attendees_results.attendees,
+        can_check_in,
         can_manage_events,
         event,
         navigation_links,
@@ -290,14 +306,7 @@ pub(crate) async fn generate_check_in_qr_code(
     let check_in_url = format!("{base_url}/{community_name}/check-in/{event_id}");
 
     // Generate QR code
-    let code = qrcode::QrCode::new(check_in_url.as_bytes())
-        .map_err(|e| anyhow::anyhow!("Failed to generate QR code: {e}"))?;
-    let svg = code
-        .render()
-        .min_dimensions(500, 500)
-        .dark_color(svg::Color("#000000"))
-        .light_color(svg::Color("#ffffff"))
-        .build();
+    let svg = build_qr_code_svg(&check_in_url, 500)?;
 
     // Prepare response headers
     let headers = [
@@ -399,13 +408,35 @@ pub(crate) async fn manual_check_in(
 
 /// Rejects an event invitation request.
 #[instrument(skip_all, err)]
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn reject_invitation_request(
     CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
     SelectedGroupId(group_id): SelectedGroupId,
     State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
     Path((event_id, user_id)): Path<(Uuid, Uuid)>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    db.reject_event_invitation_request(user.user_id, group_id, event_id, user_id)
+    db.as_ref()
+        .transaction(|tx| {
+            Box::pin(async move {
+                // Reject the invitation request
+                tx.reject_event_invitation_request(user.user_id, group_id, event_id, user_id)
+                    .await?;
+
+                // Enqueue the rejection notification
+                enqueue_event_invitation_request_rejected_notification(
+                    tx,
+                    &server_cfg,
+                    community_id,
+                    event_id,
+                    user_id,
+                )
+                .await?;
+
+                Ok(())
+            })
+        })
         .await?;
 
     Ok((
@@ -553,6 +584,36 @@ pub(crate) async fn send_event_custom_notification(
 
 // Download handlers.
 
+/// Downloads a PDF file with printable badges for the event attendees.
+#[instrument(skip_all, err)]
+pub(crate) async fn download_badges(
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Fetch event summary and the attendees badges
+    let (event, badges) = tokio::try_join!(
+        db.get_event_summary(community_id, group_id, event_id),
+        db.list_event_attendee_badges(group_id, event_id)
+    )?;
+
+    // Render badges laid out for printing on label sheets
+    let pdf = render_attendee_badges_pdf(&event.name, &badges)?;
+    let file_name = format!("event-{}-badges.pdf", event.slug);
+
+    Ok((
+        [
+            (CONTENT_TYPE, "application/pdf".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            ),
+        ],
+        pdf,
+    ))
+}
+
 /// Downloads a CSV file with all attendees for a specific event.
 #[instrument(skip_all, err)]
 pub(crate) async fn download_csv(
@@ -569,7 +630,7 @@ pub(crate) async fn download_csv(
     )?;
 
     // Build CSV payload without registration question answers
-    let csv = build_attendees_csv(&search_attendees_results.attendees, None)?;
+    let csv = build_attendees_csv(&search_attendees_results.attendees, event.timezone, None)?;
     let file_name = format!("event-{}-attendees.csv", event.slug);
 
     Ok((
@@ -603,6 +664,7 @@ pub(crate) async fn download_csv_with_answers(
     // Build CSV payload that also includes registration question answers
     let csv = build_attendees_csv(
         &search_attendees_results.attendees,
+        event.timezone,
         Some(&registration_questions),
     )?;
     let file_name = format!("event-{}-attendees-with-answers.csv", event.slug);
@@ -679,6 +741,7 @@ pub(crate) struct RefundReviewInput {
 /// column per registration question with the attendee's answer.
 fn build_attendees_csv(
     attendees: &[Attendee],
+    timezone: Tz,
     registration_questions: Option<&[QuestionnaireQuestion]>,
 ) -> Result<Vec<u8>, HandlerError> {
     let mut writer = csv::WriterBuilder::new()
@@ -688,9 +751,14 @@ fn build_attendees_csv(
     // Write header row
     let mut headers = vec![
         "Name".to_string(),
+        "Email".to_string(),
         "Company".to_string(),
         "Title".to_string(),
+        "Ticket".to_string(),
         "Invited".to_string(),
+        "Registered at".to_string(),
+        "Checked in".to_string(),
+        "Checked in at".to_string(),
     ];
     if let Some(questions) = registration_questions {
         headers.extend(questions.iter().map(|question| question.prompt.clone()));
@@ -709,14 +777,22 @@ fn build_attendees_csv(
                 .as_deref()
                 .unwrap_or(&attendee.user.username)
                 .to_string(),
+            attendee.email.clone(),
             attendee.user.company.clone().unwrap_or_default(),
             attendee.user.title.clone().unwrap_or_default(),
+            attendee.ticket_title.clone().unwrap_or_default(),
             if attendee.manually_invited {
                 "Yes"
             } else {
                 "No"
             }
             .to_string(),
+            format_csv_datetime(attendee.created_at, timezone),
+            if attendee.checked_in { "Yes" } else { "No" }.to_string(),
+            attendee
+                .checked_in_at
+                .map(|checked_in_at| format_csv_datetime(checked_in_at, timezone))
+                .unwrap_or_default(),
         ];
         if let Some(questions) = registration_questions {
             row.extend(
@@ -730,3 +806,8 @@ fn build_attendees_csv(
 
     writer.into_inner().map_err(|err| anyhow::Error::from(err).into())
 }
+
+/// Formats a timestamp in the event timezone for CSV exports.
+fn format_csv_datetime(datetime: DateTime<Utc>, timezone: Tz) -> String {
+    datetime.with_timezone(&timezone).format("%Y-%m-%d %H:%M").to_string()
+}
@@ -23,6 +23,7 @@ use crate::{
         tests::*,
     },
     services::{
+        badges::AttendeeBadge,
         notifications::{MockNotificationsManager, NotificationKind},
         payments::MockPaymentsManager,
     },
@@ -99,12 +100,18 @@ async fn test_accept_invitation_request_returns_no_content_and_sends_welcome() {
         .times(1)
         .withf(move |cid, eid| *cid == community_id && *eid == event_id)
         .returning(move |_, _| Ok(event.clone()));
+    tx.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| {
+            *cid == community_id && *eid == event_id && *uid == target_user_id
+        })
+        .returning(|_, _, _| Ok(Some(Uuid::new_v4())));
     tx.expect_enqueue_notification()
         .times(1)
         .withf(move |notification| {
             matches!(notification.kind, NotificationKind::EventWelcome)
                 && notification.recipients == vec![target_user_id]
-                && notification.attachments.len() == 1
+                && notification.attachments.len() == 2
         })
         .returning(|_| Ok(()));
     expect_successful_transaction(&mut db, tx);
@@ -589,6 +596,152 @@ async fn test_cancel_event_attendee_invitation_returns_no_content() {
     );
 }
 
+#[tokio::test]
+async fn test_download_badges_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let event = sample_event_summary(event_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::Read
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_get_event_summary()
+        .times(1)
+        .withf(move |cid, gid, eid| *cid == community_id && *gid == group_id && *eid == event_id)
+        .returning(move |_, _, _| Ok(event.clone()));
+    db.expect_list_event_attendee_badges()
+        .times(1)
+        .withf(move |gid, eid| *gid == group_id && *eid == event_id)
+        .returning(|_, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/dashboard/group/events/{event_id}/badges.pdf"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_response(&parts, &bytes, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_download_badges_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let badge = AttendeeBadge {
+        name: "Jane Doe".to_string(),
+        ticket_code: Uuid::new_v4(),
+
+        company: Some("Example Cloud".to_string()),
+        ticket_title: Some("General admission".to_string()),
+    };
+    let event = sample_event_summary(event_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::Read
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_get_event_summary()
+        .times(1)
+        .withf(move |cid, gid, eid| *cid == community_id && *gid == group_id && *eid == event_id)
+        .returning(move |_, _, _| Ok(event.clone()));
+    db.expect_list_event_attendee_badges()
+        .times(1)
+        .withf(move |gid, eid| *gid == group_id && *eid == event_id)
+        .returning(move |_, _| Ok(vec![badge.clone()]));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/dashboard/group/events/{event_id}/badges.pdf"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("application/pdf"),
+    );
+    assert_eq!(
+        parts.headers.get(CONTENT_DISPOSITION).unwrap(),
+        &HeaderValue::from_static("attachment; filename=\"event-ghi9abc-badges.pdf\""),
+    );
+    assert!(bytes.starts_with(b"%PDF-1.4"));
+}
+
 #[tokio::test]
 async fn test_download_csv_success() {
     // Setup identifiers and data structures
@@ -610,18 +763,22 @@ async fn test_download_csv_success() {
     attendee.user.company = Some("Example \"Cloud\"".to_string());
     attendee.manually_invited = true;
     attendee.user.title = Some("Principal\nEngineer".to_string());
+    attendee.ticket_title = Some("Student".to_string());
     let mut attendee_without_name = sample_attendee();
     attendee_without_name.user.name = None;
     attendee_without_name.user.username = "anonymous-attendee".to_string();
     attendee_without_name.user.company = None;
     attendee_without_name.user.title = None;
+    attendee_without_name.checked_in = false;
+    attendee_without_name.checked_in_at = None;
     let mut pending_invitation = sample_attendee();
     pending_invitation.user.name = Some("Pending Invite".to_string());
     pending_invitation.status = "invitation-pending".to_string();
     let mut rejected_invitation = sample_attendee();
     rejected_invitation.user.name = Some("Rejected Invite".to_string());
     rejected_invitation.status = "invitation-rejected".to_string();
-    let event = sample_event_summary(event_id, group_id);
+    let mut event = sample_event_summary(event_id, group_id);
+    event.timezone = chrono_tz::Europe::Madrid;
     let output = crate::templates::dashboard::group::attendees::AttendeesOutput {
         all_attendees_email_recipient_total: 2,
         attendees: vec![
@@ -693,7 +850,11 @@ async fn test_download_csv_success() {
     );
     assert_eq!(
         String::from_utf8(bytes.to_vec()).unwrap(),
-        "Name,Company,Title,Invited\n\"Doe, Jane\",\"Example \"\"Cloud\"\"\",\"Principal\nEngineer\",Yes\nanonymous-attendee,,,No\n",
+        concat!(
+            "Name,Email,Company,Title,Ticket,Invited,Registered at,Checked in,Checked in at\n",
+            "\"Doe, Jane\",attendee@example.test,\"Example \"\"Cloud\"\"\",\"Principal\nEngineer\",Student,Yes,2024-01-01 13:00,Yes,2024-01-01 14:00\n",
+            "anonymous-attendee,attendee@example.test,,,,No,2024-01-01 13:00,No,\n",
+        ),
     );
 }
 
@@ -856,7 +1017,11 @@ async fn test_download_csv_with_answers_success() {
     );
     assert_eq!(
         String::from_utf8(bytes.to_vec()).unwrap(),
-        "Name,Company,Title,Invited,Dietary restrictions?,Meal preference,Topics\nEvent Attendee,Example,Engineer,No,No peanuts,Vegetarian,\"Rust, Databases\"\nNo Answers,Example,Engineer,No,,,\n",
+        concat!(
+            "Name,Email,Company,Title,Ticket,Invited,Registered at,Checked in,Checked in at,Dietary restrictions?,Meal preference,Topics\n",
+            "Event Attendee,attendee@example.test,Example,Engineer,,No,2024-01-01 12:00,Yes,2024-01-01 13:00,No peanuts,Vegetarian,\"Rust, Databases\"\n",
+            "No Answers,attendee@example.test,Example,Engineer,,No,2024-01-01 12:00,Yes,2024-01-01 13:00,,,\n",
+        ),
     );
 }
 
@@ -1492,6 +1657,15 @@ async fn test_list_page_success() {
                 && permission == GroupPermission::EventsWrite
         })
         .returning(|_, _, _, _| Ok(true));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::CheckInWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
     db.expect_search_event_attendees()
         .times(1)
         .withf(move |gid, eid, filters| {
@@ -1588,6 +1762,15 @@ async fn test_list_page_db_error() {
                 && permission == GroupPermission::EventsWrite
         })
         .returning(|_, _, _, _| Ok(true));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::CheckInWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
     db.expect_get_event_summary()
         .times(1)
         .withf(move |cid, gid, eid| *cid == community_id && *gid == group_id && *eid == event_id)
@@ -1793,6 +1976,15 @@ async fn test_list_page_with_pagination_params() {
                 && permission == GroupPermission::EventsWrite
         })
         .returning(|_, _, _, _| Ok(true));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::CheckInWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
     db.expect_search_event_attendees()
         .times(1)
         .withf(move |gid, eid, filters| {
@@ -1894,6 +2086,15 @@ async fn test_list_page_with_search_query() {
                 && permission == GroupPermission::EventsWrite
         })
         .returning(|_, _, _, _| Ok(true));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::CheckInWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
     db.expect_search_event_attendees()
         .times(1)
         .withf(move |gid, eid, filters| {
@@ -1986,7 +2187,7 @@ async fn test_manual_check_in_success() {
             *cid == community_id
                 && *gid == group_id
                 && *uid == user_id
-                && permission == GroupPermission::EventsWrite
+                && permission == GroupPermission::CheckInWrite
         })
         .returning(|_, _, _, _| Ok(true));
     db.expect_get_event_summary()
@@ -2025,7 +2226,7 @@ async fn test_manual_check_in_success() {
 }
 
 #[tokio::test]
-async fn test_reject_invitation_request_returns_no_content() {
+async fn test_reject_invitation_request_returns_no_content_and_notifies_user() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let event_id = Uuid::new_v4();
@@ -2041,6 +2242,8 @@ async fn test_reject_invitation_request_returns_no_content() {
         Some(community_id),
         Some(group_id),
     );
+    let event = sample_event_summary(event_id, group_id);
+    let site_settings = sample_site_settings();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -2061,18 +2264,43 @@ async fn test_reject_invitation_request_returns_no_content() {
                 && permission == GroupPermission::EventsWrite
         })
         .returning(|_, _, _, _| Ok(true));
-    db.expect_reject_event_invitation_request()
+    let mut tx = MockDB::new();
+    tx.expect_reject_event_invitation_request()
         .times(1)
         .withf(move |actor_id, gid, eid, uid| {
             *actor_id == user_id && *gid == group_id && *eid == event_id && *uid == target_user_id
         })
         .returning(|_, _, _, _| Ok(()));
+    tx.expect_get_site_settings()
+        .times(1)
+        .returning(move || Ok(site_settings.clone()));
+    tx.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event.clone()));
+    tx.expect_enqueue_notification()
+        .times(1)
+        .withf(move |notification| {
+            matches!(
+                notification.kind,
+                NotificationKind::EventInvitationRequestRejected
+            ) && notification.recipients == vec![target_user_id]
+                && notification.attachments.is_empty()
+        })
+        .returning(|_| Ok(()));
+    expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
     // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(HttpServerConfig {
+            base_url: "https://ocg.test".to_string(),
+            ..sample_tracking_server_cfg()
+        })
+        .build()
+        .await;
     let request = Request::builder()
         .method("PUT")
         .uri(format!(
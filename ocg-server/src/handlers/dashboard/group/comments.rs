@@ -0,0 +1,148 @@
+//! HTTP handlers for the event discussion section in the group dashboard.
+
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId},
+    },
+    templates::dashboard::group::comments,
+    types::permissions::GroupPermission,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the discussion of a specific event.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Fetch event summary and discussion
+    let (can_manage_events, event, discussion) = tokio::try_join!(
+        db.user_has_group_permission(
+            &community_id,
+            &group_id,
+            &user.user_id,
+            GroupPermission::EventsWrite
+        ),
+        db.get_event_summary(community_id, group_id, event_id),
+        db.get_event_discussion(community_id, event_id)
+    )?;
+
+    // Prepare template
+    let template = comments::ListPage {
+        can_manage_events,
+        discussion,
+        event,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Deletes a comment, and its replies, from the event discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path((event_id, event_comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_event_comment(user.user_id, group_id, event_id, event_comment_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-comments")],
+    )
+        .into_response())
+}
+
+/// Locks the event discussion, so no new comments can be posted.
+#[instrument(skip_all, err)]
+pub(crate) async fn lock(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_event_comments_locked(user.user_id, group_id, event_id, true)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-comments")],
+    )
+        .into_response())
+}
+
+/// Pins a comment, showing it first in the event discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn pin(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path((event_id, event_comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_event_comment_pinned(user.user_id, group_id, event_id, event_comment_id, true)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-comments")],
+    )
+        .into_response())
+}
+
+/// Unlocks the event discussion, so attendees can post comments again.
+#[instrument(skip_all, err)]
+pub(crate) async fn unlock(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_event_comments_locked(user.user_id, group_id, event_id, false)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-comments")],
+    )
+        .into_response())
+}
+
+/// Unpins a comment from the event discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn unpin(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path((event_id, event_comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_event_comment_pinned(user.user_id, group_id, event_id, event_comment_id, false)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-comments")],
+    )
+        .into_response())
+}
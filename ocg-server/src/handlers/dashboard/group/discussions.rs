@@ -0,0 +1,194 @@
+//! HTTP handlers for moderating the group discussion in the dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId},
+    },
+    templates::dashboard::group::discussions,
+    types::permissions::GroupPermission,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the discussion topics of the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_list_page(&db, community_id, group_id, user.user_id).await?;
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Deletes a reply from the group discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete_reply(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_discussion_reply_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_group_discussion_reply(user.user_id, group_id, group_discussion_reply_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Deletes a topic, and its replies, from the group discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete_topic(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_discussion_topic_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_group_discussion_topic(user.user_id, group_id, group_discussion_topic_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Locks a topic, so no new replies can be posted.
+#[instrument(skip_all, err)]
+pub(crate) async fn lock(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_discussion_topic_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_discussion_topic_locked(
+        user.user_id,
+        group_id,
+        group_discussion_topic_id,
+        true,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Pins a topic, showing it first in the group discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn pin(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_discussion_topic_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_discussion_topic_pinned(
+        user.user_id,
+        group_id,
+        group_discussion_topic_id,
+        true,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Unlocks a topic, so members can reply to it again.
+#[instrument(skip_all, err)]
+pub(crate) async fn unlock(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_discussion_topic_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_discussion_topic_locked(
+        user.user_id,
+        group_id,
+        group_discussion_topic_id,
+        false,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Unpins a topic from the group discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn unpin(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_discussion_topic_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_discussion_topic_pinned(
+        user.user_id,
+        group_id,
+        group_discussion_topic_id,
+        false,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+// Helpers.
+
+/// Prepares the discussions list page for the group dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    community_id: Uuid,
+    group_id: Uuid,
+    user_id: Uuid,
+) -> Result<discussions::ListPage> {
+    let (can_manage_members, discussion) = tokio::try_join!(
+        db.user_has_group_permission(
+            &community_id,
+            &group_id,
+            &user_id,
+            GroupPermission::MembersWrite
+        ),
+        db.get_group_discussion(community_id, group_id)
+    )?;
+
+    Ok(discussions::ListPage {
+        can_manage_members,
+        discussion,
+    })
+}
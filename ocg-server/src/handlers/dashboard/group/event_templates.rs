@@ -0,0 +1,76 @@
+//! HTTP handlers for managing the event templates in the group dashboard.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId},
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+// JSON handlers.
+
+/// Returns the event details saved in a template in JSON format.
+#[instrument(skip_all, err)]
+pub(crate) async fn details(
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_template_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let Some(details) = db.get_event_template_details(group_id, event_template_id).await? else {
+        return Err(HandlerError::NotFound);
+    };
+
+    Ok(Json(details).into_response())
+}
+
+// Actions handlers.
+
+/// Saves an event as a template to create new events from.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.add_event_template(user.user_id, community_id, group_id, event_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Deletes an event template from the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_template_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_event_template(user.user_id, group_id, event_template_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
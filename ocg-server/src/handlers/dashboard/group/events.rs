@@ -7,19 +7,23 @@ use askama::Template;
 use axum::{
     Json,
     extract::{Path, RawQuery, State},
-    http::{HeaderName, StatusCode},
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode, Uri},
     response::{Html, IntoResponse},
 };
 use chrono::Utc;
 use garde::Validate;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
 use serde::Deserialize;
-use tracing::instrument;
+use tower_sessions::Session;
+use tracing::{instrument, warn};
 use uuid::Uuid;
 
 use crate::{
+    auth::AuthSession,
     config::{HttpServerConfig, MeetingsConfig, PaymentsConfig},
     db::{DBExt, DBOperations, DynDB},
     handlers::{
+        auth::{SELECTED_COMMUNITY_ID_KEY, SELECTED_GROUP_ID_KEY, user_has_group_event_permission},
         error::HandlerError,
         extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId, ValidatedFormQs},
     },
@@ -33,12 +37,16 @@ use crate::{
             enqueue_event_waitlist_promoted_notification,
         },
     },
-    templates::dashboard::group::{
-        events::{self, Event, EventsListFilters, EventsTab},
-        sponsors::GroupSponsorsFilters,
+    templates::{
+        PageId,
+        auth::User,
+        dashboard::group::{
+            events::{self, Event, EventConflict, EventsListFilters, EventsTab},
+            sponsors::GroupSponsorsFilters,
+        },
     },
     types::{
-        event::EventSummary,
+        event::{EventApprovalStatus, EventSummary},
         pagination::{self, NavigationLinks},
         payments::GroupPaymentRecipient,
         permissions::GroupPermission,
@@ -56,6 +64,9 @@ use recurrence::RecurringEventPayloads;
 const DASHBOARD_URL: &str = "/dashboard/group?tab=events";
 const PARTIAL_URL: &str = "/dashboard/group/events";
 
+/// Response header carrying the warning about events overlapping the saved one.
+const EVENT_CONFLICTS_HEADER: &str = "X-OCG-Event-Conflicts";
+
 // Pages handlers.
 
 /// Displays the page to add a new event.
@@ -67,10 +78,19 @@ pub(crate) async fn add_page(
     State(db): State<DynDB>,
     State(meetings_cfg): State<Option<MeetingsConfig>>,
     State(payments_cfg): State<Option<PaymentsConfig>>,
+    RawQuery(raw_query): RawQuery,
 ) -> Result<impl IntoResponse, HandlerError> {
+    // Get the event or event template to copy from, if any
+    let query: AddPageQuery =
+        serde_qs_config().deserialize_str(raw_query.as_deref().unwrap_or_default())?;
+
     // Fetch template data concurrently
     let meetings_enabled = meetings_cfg.as_ref().is_some_and(MeetingsConfig::meetings_enabled);
     let meetings_max_participants = build_meetings_max_participants(meetings_cfg.as_ref());
+    let meetings_providers = meetings_cfg
+        .as_ref()
+        .map(MeetingsConfig::enabled_providers)
+        .unwrap_or_default();
     let sponsor_filters: GroupSponsorsFilters = serde_qs_config().deserialize_str("")?;
     let (
         can_manage_events,
@@ -81,6 +101,7 @@ pub(crate) async fn add_page(
         session_kinds,
         sponsors,
         timezones,
+        topics,
     ) = tokio::try_join!(
         db.user_has_group_permission(
             &community_id,
@@ -94,23 +115,68 @@ pub(crate) async fn add_page(
         db.get_group_payment_recipient(community_id, group_id),
         db.list_session_kinds(),
         db.list_group_sponsors(group_id, &sponsor_filters, true),
-        db.list_timezones()
+        db.list_timezones(),
+        db.list_topics(community_id)
     )?;
 
     // Prepare template
     let template = events::AddPage {
         can_manage_events,
         categories,
+        copy_from_event_id: query.copy_from,
         event_kinds,
+        event_template_id: query.template,
         group_id,
         meetings_enabled,
         payments_enabled: payments_cfg.is_some(),
         payment_currency_codes,
         payments_ready: payments_ready(payment_recipient.as_ref(), payments_cfg.as_ref()),
         meetings_max_participants,
+        meetings_providers,
         session_kinds,
         sponsors: sponsors.sponsors,
         timezones,
+        topics,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+/// Displays the page event hosts use to manage the events they host.
+///
+/// Hosts are not part of the group team, so the event group is selected in
+/// the session to allow the event endpoints to resolve the dashboard context.
+#[instrument(skip_all, err)]
+pub(crate) async fn host_page(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    session: Session,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+    uri: Uri,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get the event group, checking the user hosts the event
+    let Some((community_id, group_id)) =
+        db.get_hosted_event_group(&event_id, &user.user_id).await?
+    else {
+        return Err(HandlerError::Forbidden);
+    };
+
+    // Select the event group in the session
+    session.insert(SELECTED_COMMUNITY_ID_KEY, community_id).await?;
+    session.insert(SELECTED_GROUP_ID_KEY, group_id).await?;
+
+    // Prepare template
+    let (event, site_settings) = tokio::try_join!(
+        db.get_event_summary(community_id, group_id, event_id),
+        db.get_site_settings()
+    )?;
+    let template = events::HostPage {
+        event,
+        page_id: PageId::GroupDashboard,
+        path: uri.path().to_string(),
+        site_settings,
+        user: User::from_session(auth_session).await?,
     };
 
     Ok(Html(template.render()?))
@@ -173,6 +239,10 @@ pub(crate) async fn update_page(
     // Prepare template
     let meetings_enabled = meetings_cfg.as_ref().is_some_and(MeetingsConfig::meetings_enabled);
     let meetings_max_participants = build_meetings_max_participants(meetings_cfg.as_ref());
+    let meetings_providers = meetings_cfg
+        .as_ref()
+        .map(MeetingsConfig::enabled_providers)
+        .unwrap_or_default();
     let sponsor_filters: GroupSponsorsFilters = serde_qs_config().deserialize_str("")?;
     let (
         can_manage_events,
@@ -186,10 +256,13 @@ pub(crate) async fn update_page(
         session_kinds,
         sponsors,
         timezones,
+        topics,
     ) = tokio::try_join!(
-        db.user_has_group_permission(
+        user_has_group_event_permission(
+            &db,
             &community_id,
             &group_id,
+            &event_id,
             &user.user_id,
             GroupPermission::EventsWrite
         ),
@@ -203,6 +276,7 @@ pub(crate) async fn update_page(
         db.list_session_kinds(),
         db.list_group_sponsors(group_id, &sponsor_filters, true),
         db.list_timezones(),
+        db.list_topics(community_id),
     )?;
     let template = events::UpdatePage {
         approved_submissions,
@@ -218,9 +292,11 @@ pub(crate) async fn update_page(
         payment_currency_codes,
         payments_ready: payments_ready(payment_recipient.as_ref(), payments_cfg.as_ref()),
         meetings_max_participants,
+        meetings_providers,
         session_kinds,
         sponsors: sponsors.sponsors,
         timezones,
+        topics,
     };
 
     Ok(Html(template.render()?))
@@ -255,14 +331,15 @@ pub(crate) async fn add(
     ValidatedFormQs(event): ValidatedFormQs<Event>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Prepare and validate the event payload
+    ensure_meeting_providers_enabled(&event, meetings_cfg.as_ref())?;
     let cfg_max_participants = build_meetings_max_participants(meetings_cfg.as_ref());
     let event_payload = build_event_payload(&event)?;
-    if event_payload_uses_ticketing(&event_payload) {
+    if event_payload_uses_paid_ticketing(&event_payload) {
         ensure_ticketing_ready(&db, community_id, group_id, payments_cfg.as_ref()).await?;
     }
 
     // Create either a single event or a linked recurring event series
-    if let Some(recurring_event_payloads) =
+    let event_id = if let Some(recurring_event_payloads) =
         RecurringEventPayloads::from_event(&event, &event_payload)
             .map_err(|err| HandlerError::Deserialization(err.to_string()))?
     {
@@ -273,22 +350,25 @@ pub(crate) async fn add(
             &recurring_event_payloads.recurrence,
             &cfg_max_participants,
         )
-        .await?;
+        .await?
+        .first()
+        .copied()
     } else {
-        db.add_event(
-            user.user_id,
-            group_id,
-            &event_payload,
-            &cfg_max_participants,
+        Some(
+            db.add_event(
+                user.user_id,
+                group_id,
+                &event_payload,
+                &cfg_max_participants,
+            )
+            .await?,
         )
-        .await?;
-    }
+    };
 
-    Ok((
-        StatusCode::CREATED,
-        [("HX-Trigger", "refresh-group-dashboard-table")],
-    )
-        .into_response())
+    // Warn about other events overlapping with the new one
+    let headers = prepare_saved_event_headers(&db, group_id, event_id, &event_payload).await;
+
+    Ok((StatusCode::CREATED, headers).into_response())
 }
 
 /// Cancels an event (sets canceled=true).
@@ -458,9 +538,15 @@ pub(crate) async fn publish(
                     }
                 }
 
-                // Enqueue required publish notifications before committing
+                // Enqueue required publish notifications before committing, skipping
+                // events that were sent to the community team for approval instead
+                let community = tx.get_community_full(community_id).await?;
                 let events_to_notify: Vec<EventSummary> = events
                     .into_iter()
+                    .filter(|event| {
+                        !community.event_approval_required
+                            || event.approval_status == Some(EventApprovalStatus::Approved)
+                    })
                     .filter(|event| {
                         matches!(
                             (event.published, event.starts_at),
@@ -557,11 +643,13 @@ pub(crate) async fn update(
     event.validate()?;
 
     // Prepare update payload and ticketing prerequisites
+    ensure_meeting_providers_enabled(&event, meetings_cfg.as_ref())?;
     let cfg_max_participants = build_meetings_max_participants(meetings_cfg.as_ref());
     let event_json = build_event_payload(&event)?;
-    if event_payload_uses_ticketing(&event_json) {
+    if event_payload_uses_paid_ticketing(&event_json) {
         ensure_ticketing_ready(&db, community_id, group_id, payments_cfg.as_ref()).await?;
     }
+    let conflicts_event_json = event_json.clone();
 
     db.as_ref()
         .transaction(|tx| {
@@ -608,15 +696,24 @@ pub(crate) async fn update(
         })
         .await?;
 
-    Ok((
-        StatusCode::NO_CONTENT,
-        [("HX-Trigger", "refresh-group-dashboard-table")],
-    )
-        .into_response())
+    // Warn about other events overlapping with the updated one
+    let headers =
+        prepare_saved_event_headers(&db, group_id, Some(event_id), &conflicts_event_json).await;
+
+    Ok((StatusCode::NO_CONTENT, headers).into_response())
 }
 
 // Types.
 
+/// Query parameters accepted by the add event page.
+#[derive(Debug, Default, Deserialize)]
+struct AddPageQuery {
+    /// Event to copy the details from.
+    copy_from: Option<Uuid>,
+    /// Event template to apply.
+    template: Option<Uuid>,
+}
+
 /// Query parameters accepted by cancel/delete actions.
 #[derive(Debug, Default, Deserialize)]
 struct EventActionQuery {
@@ -646,17 +743,19 @@ pub(crate) async fn prepare_list_page(
     user_id: Uuid,
     raw_query: &str,
 ) -> Result<(EventsListFilters, events::ListPage), HandlerError> {
-    // Fetch group's past and upcoming events
+    // Fetch group's past and upcoming events and its event templates
     let filters: EventsListFilters = serde_qs_config().deserialize_str(raw_query)?;
     filters.validate()?;
-    let (can_manage_events, events) = tokio::try_join!(
+    let (can_manage_events, events, group, event_templates) = tokio::try_join!(
         db.user_has_group_permission(
             &community_id,
             &group_id,
             &user_id,
             GroupPermission::EventsWrite
         ),
-        db.list_group_events(group_id, &filters)
+        db.list_group_events(group_id, &filters),
+        db.get_group_summary(community_id, group_id),
+        db.list_event_templates(group_id)
     )?;
 
     // Prepare pagination links for each events tab
@@ -680,8 +779,10 @@ pub(crate) async fn prepare_list_page(
     // Prepare template
     let template = events::ListPage {
         can_manage_events,
+        event_templates,
         events,
         events_tab: filters.current_tab(),
+        group_archived: group.archived_at.is_some(),
         past_navigation_links,
         upcoming_navigation_links,
         limit: filters.limit,
@@ -692,6 +793,29 @@ pub(crate) async fn prepare_list_page(
     Ok((filters, template))
 }
 
+/// Builds the warning about the events overlapping with a saved event.
+fn build_event_conflicts_warning(conflicts: &[EventConflict]) -> Option<String> {
+    if conflicts.is_empty() {
+        return None;
+    }
+
+    let conflicts = conflicts
+        .iter()
+        .map(|conflict| {
+            if conflict.same_group {
+                conflict.name.clone()
+            } else {
+                format!("{} ({}, same venue)", conflict.name, conflict.group_name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Some(format!(
+        "This event overlaps with other events: {conflicts}."
+    ))
+}
+
 /// Builds the database payload for an event form.
 fn build_event_payload(event: &Event) -> Result<serde_json::Value, HandlerError> {
     event
@@ -704,6 +828,34 @@ fn build_meetings_max_participants(
     meetings_cfg: Option<&MeetingsConfig>,
 ) -> HashMap<MeetingProvider, i32> {
     let mut map = HashMap::new();
+    if let Some(cfg) = meetings_cfg
+        && let Some(bigbluebutton) = &cfg.bigbluebutton
+    {
+        map.insert(
+            MeetingProvider::BigBlueButton,
+            bigbluebutton.max_participants,
+        );
+    }
+    if let Some(cfg) = meetings_cfg
+        && let Some(google_meet) = &cfg.google_meet
+    {
+        map.insert(MeetingProvider::GoogleMeet, google_meet.max_participants);
+    }
+    if let Some(cfg) = meetings_cfg
+        && let Some(jitsi) = &cfg.jitsi
+    {
+        map.insert(MeetingProvider::Jitsi, jitsi.max_participants);
+    }
+    if let Some(cfg) = meetings_cfg
+        && let Some(teams) = &cfg.teams
+    {
+        map.insert(MeetingProvider::Teams, teams.max_participants);
+    }
+    if let Some(cfg) = meetings_cfg
+        && let Some(webex) = &cfg.webex
+    {
+        map.insert(MeetingProvider::Webex, webex.max_participants);
+    }
     if let Some(cfg) = meetings_cfg
         && let Some(zoom) = &cfg.zoom
     {
@@ -731,7 +883,7 @@ async fn cancel_event_action_ids(
     }
 }
 
-/// Ensures that ticketing can be used for the event by checking payments configuration and group setup.
+/// Ensures that paid ticketing can be used for the event by checking payments configuration and group setup.
 async fn ensure_ticketing_ready(
     db: &DynDB,
     community_id: Uuid,
@@ -762,6 +914,38 @@ async fn ensure_ticketing_ready(
     Ok(())
 }
 
+/// Ensures requested meetings use providers enabled on this server.
+fn ensure_meeting_providers_enabled(
+    event: &Event,
+    meetings_cfg: Option<&MeetingsConfig>,
+) -> Result<(), HandlerError> {
+    // Collect the providers requested by the event and its sessions
+    let event_provider = event
+        .meeting_requested
+        .unwrap_or(false)
+        .then(|| event.meeting_provider.unwrap_or_default());
+    let sessions_providers = event.sessions.iter().flatten().filter_map(|session| {
+        session
+            .meeting_requested
+            .unwrap_or(false)
+            .then(|| session.meeting_provider.unwrap_or_default())
+    });
+
+    // Reject providers that are not enabled
+    let enabled_providers = meetings_cfg
+        .map(MeetingsConfig::enabled_providers)
+        .unwrap_or_default();
+    for provider in event_provider.into_iter().chain(sessions_providers) {
+        if !enabled_providers.contains(&provider) {
+            return Err(HandlerError::Database(format!(
+                "meeting provider {provider} is not enabled on this server"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 /// Resolves the event identifiers affected by a dashboard event action.
 async fn event_action_ids(
     db: &dyn DBOperations,
@@ -781,12 +965,32 @@ async fn event_action_ids(
     }
 }
 
-/// Checks if the event payload includes ticket types, indicating that ticketing is used.
-fn event_payload_uses_ticketing(event_payload: &serde_json::Value) -> bool {
-    event_payload
+/// Checks if the event payload can charge attendees, which requires payments.
+///
+/// Events offering only free ticket types can use ticketing without payments.
+fn event_payload_uses_paid_ticketing(event_payload: &serde_json::Value) -> bool {
+    let has_payment_currency_code = event_payload
+        .get("payment_currency_code")
+        .is_some_and(|currency_code| !currency_code.is_null());
+    let has_discount_codes = event_payload
+        .get("discount_codes")
+        .and_then(serde_json::Value::as_array)
+        .is_some_and(|discount_codes| !discount_codes.is_empty());
+    let has_paid_price_windows = event_payload
         .get("ticket_types")
         .and_then(serde_json::Value::as_array)
-        .is_some_and(|ticket_types| !ticket_types.is_empty())
+        .into_iter()
+        .flatten()
+        .filter_map(|ticket_type| ticket_type.get("price_windows")?.as_array())
+        .flatten()
+        .any(|price_window| {
+            price_window
+                .get("amount_minor")
+                .and_then(serde_json::Value::as_i64)
+                .is_some_and(|amount_minor| amount_minor > 0)
+        });
+
+    has_payment_currency_code || has_discount_codes || has_paid_price_windows
 }
 
 /// Parses dashboard event action query parameters.
@@ -805,3 +1009,32 @@ fn payments_ready(
             if payment_recipient.provider == payments_cfg.provider()
     )
 }
+
+/// Prepares the response headers for a saved event, warning about the events
+/// overlapping with it. Conflicts never prevent saving the event.
+async fn prepare_saved_event_headers(
+    db: &DynDB,
+    group_id: Uuid,
+    event_id: Option<Uuid>,
+    event_payload: &serde_json::Value,
+) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "HX-Trigger",
+        HeaderValue::from_static("refresh-group-dashboard-table"),
+    );
+
+    match db.list_event_conflicts(group_id, event_id, event_payload).await {
+        Ok(conflicts) => {
+            if let Some(warning) = build_event_conflicts_warning(&conflicts) {
+                let encoded_warning = utf8_percent_encode(&warning, NON_ALPHANUMERIC).to_string();
+                if let Ok(value) = HeaderValue::from_str(&encoded_warning) {
+                    headers.insert(EVENT_CONFLICTS_HEADER, value);
+                }
+            }
+        }
+        Err(err) => warn!(error = %err, "failed to list event conflicts"),
+    }
+
+    headers
+}
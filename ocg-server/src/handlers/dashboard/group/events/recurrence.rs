@@ -15,10 +15,11 @@ use crate::{
 };
 
 /// Event-level local datetime fields shifted for each generated occurrence.
-const EVENT_LOCAL_DATETIME_FIELD_NAMES: [&str; 6] = [
+const EVENT_LOCAL_DATETIME_FIELD_NAMES: [&str; 7] = [
     "cfs_ends_at",
     "cfs_starts_at",
     "ends_at",
+    "publish_scheduled_at",
     "registration_ends_at",
     "registration_starts_at",
     "starts_at",
@@ -245,6 +246,9 @@ struct RecurrenceRequest {
     pattern: EventRecurrencePattern,
     /// Timezone used to expand local occurrence dates.
     timezone: Tz,
+
+    /// Name given to the linked event series.
+    series_name: Option<String>,
 }
 
 impl RecurrenceRequest {
@@ -276,6 +280,7 @@ impl RecurrenceRequest {
             base_starts_at,
             pattern,
             timezone,
+            series_name: event.recurrence_series_name.clone(),
         }))
     }
 
@@ -286,10 +291,15 @@ impl RecurrenceRequest {
             .recurrence_db_value()
             .context("recurring event pattern must be supported by the database")?;
 
-        Ok(json!({
+        let mut metadata = json!({
             "additional_occurrences": self.additional_occurrences,
             "pattern": pattern,
-        }))
+        });
+        if let Some(series_name) = &self.series_name {
+            metadata["name"] = json!(series_name);
+        }
+
+        Ok(metadata)
     }
 }
 
@@ -510,6 +520,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_event_includes_series_name_in_metadata() {
+        // Setup recurring event form with a series name
+        let mut event = sample_event(
+            EventRecurrencePattern::Monthly,
+            Some(1),
+            Some(at(2030, 1, 7)),
+            "UTC",
+        );
+        event.recurrence_series_name = Some("Study Group Series".to_string());
+        let base_payload = json!({ "starts_at": "2030-01-07T10:00:00" });
+
+        // Build recurring payloads
+        let recurring_payloads = RecurringEventPayloads::from_event(&event, &base_payload)
+            .unwrap()
+            .unwrap();
+
+        // Check recurrence metadata includes the series name
+        assert_eq!(
+            recurring_payloads.recurrence,
+            json!({
+                "additional_occurrences": 1,
+                "name": "Study Group Series",
+                "pattern": "monthly"
+            })
+        );
+    }
+
     #[test]
     fn from_event_rejects_invalid_recurrence_settings() {
         // Setup shared recurring event inputs
@@ -934,6 +972,7 @@ mod tests {
             base_starts_at: starts_at,
             pattern,
             timezone: "UTC".parse().unwrap(),
+            series_name: None,
         }
     }
 
@@ -9,27 +9,34 @@ use axum::{
 use axum_login::tower_sessions::session;
 use chrono::Utc;
 use mockall::Sequence;
-use serde_json::{from_slice, from_value, to_value};
+use serde_json::{from_slice, from_value, json, to_value};
 use tower::ServiceExt;
 use uuid::Uuid;
 
 use crate::{
     config::{PaymentsConfig, PaymentsStripeConfig},
     db::mock::MockDB,
-    handlers::tests::*,
+    handlers::{
+        auth::{SELECTED_COMMUNITY_ID_KEY, SELECTED_GROUP_ID_KEY},
+        tests::*,
+    },
     services::{
         meetings::MeetingProvider,
         notifications::{MockNotificationsManager, NotificationKind},
     },
     templates::{
-        dashboard::{DASHBOARD_PAGINATION_LIMIT, group::events::EventRecurrencePattern},
+        dashboard::{
+            DASHBOARD_PAGINATION_LIMIT,
+            group::events::{EventConflict, EventRecurrencePattern, Session as GroupEventSession},
+        },
         notifications::{
             EventCanceled, EventPublished, EventRescheduled, EventSeriesCanceled,
             EventSeriesPublished, EventWaitlistPromoted, SpeakerWelcome,
         },
     },
     types::{
-        event::{EventFull, EventSummary, Speaker},
+        community::CommunityFull,
+        event::{EventFull, EventSummary, SessionKind, Speaker},
         payments::{EventTicketType, PaymentMode},
         permissions::GroupPermission,
     },
@@ -117,6 +124,10 @@ async fn test_add_page_success() {
     db.expect_list_timezones()
         .times(1)
         .returning(move || Ok(timezones.clone()));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_group_payment_recipient()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
@@ -149,6 +160,245 @@ async fn test_add_page_success() {
     assert_html_response(&parts, &bytes, StatusCode::OK);
 }
 
+#[tokio::test]
+#[allow(clippy::too_many_lines)]
+async fn test_add_page_with_copy_from_event() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let source_event_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let category = sample_event_category();
+    let kind = sample_event_kind_summary();
+    let payment_currency_codes = vec!["EUR".to_string(), "USD".to_string()];
+    let session_kind = sample_session_kind_summary();
+    let sponsor = sample_group_sponsor();
+    let timezones = vec!["UTC".to_string()];
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::Read
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::EventsWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_list_event_categories()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(vec![category.clone()]));
+    db.expect_list_event_kinds()
+        .times(1)
+        .returning(move || Ok(vec![kind.clone()]));
+    db.expect_list_payment_currency_codes()
+        .times(1)
+        .returning(move || Ok(payment_currency_codes.clone()));
+    db.expect_list_session_kinds()
+        .times(1)
+        .returning(move || Ok(vec![session_kind.clone()]));
+    db.expect_list_group_sponsors()
+        .times(1)
+        .withf(move |id, filters, full_list| {
+            *id == group_id
+                && filters.limit == Some(DASHBOARD_PAGINATION_LIMIT)
+                && filters.offset == Some(0)
+                && *full_list
+        })
+        .returning(move |_, _, _| {
+            Ok(
+                crate::templates::dashboard::group::sponsors::GroupSponsorsOutput {
+                    sponsors: vec![sponsor.clone()],
+                    total: 1,
+                },
+            )
+        });
+    db.expect_list_timezones()
+        .times(1)
+        .returning(move || Ok(timezones.clone()));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_get_group_payment_recipient()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(None));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_payments_cfg(PaymentsConfig::Stripe(PaymentsStripeConfig {
+            mode: PaymentMode::Test,
+            publishable_key: "pk_test_123".to_string(),
+            secret_key: "sk_test_123".to_string(),
+            webhook_secret: "whsec_test_123".to_string(),
+        }))
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/dashboard/group/events/add?copy_from={source_event_id}"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains(&format!("copy-event-id=\"{source_event_id}\"")));
+}
+
+#[tokio::test]
+async fn test_host_page_forbidden_when_user_does_not_host_event() {
+    // Setup identifiers and data structures
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_hosted_event_group()
+        .times(1)
+        .withf(move |eid, uid| *eid == event_id && *uid == user_id)
+        .returning(|_, _| Ok(None));
+    db.expect_get_event_summary().never();
+    db.expect_update_session().never();
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/dashboard/group/events/{event_id}/host"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, _) = response.into_parts();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_host_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let event = sample_event_summary(event_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_hosted_event_group()
+        .times(1)
+        .withf(move |eid, uid| *eid == event_id && *uid == user_id)
+        .returning(move |_, _| Ok(Some((community_id, group_id))));
+    db.expect_get_event_summary()
+        .times(1)
+        .withf(move |cid, gid, eid| *cid == community_id && *gid == group_id && *eid == event_id)
+        .returning(move |_, _, _| Ok(event.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            record.id == session_id
+                && record
+                    .data
+                    .get(SELECTED_COMMUNITY_ID_KEY)
+                    .is_some_and(|value| value == &json!(community_id))
+                && record
+                    .data
+                    .get(SELECTED_GROUP_ID_KEY)
+                    .is_some_and(|value| value == &json!(group_id))
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/dashboard/group/events/{event_id}/host"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(body.contains(&format!(
+        "hx-get=\"/dashboard/group/events/{event_id}/update\""
+    )));
+    assert!(body.contains(&format!("/dashboard/group/check-in/{event_id}/kiosk")));
+}
+
 #[tokio::test]
 async fn test_list_page_success() {
     // Setup identifiers and data structures
@@ -208,6 +458,14 @@ async fn test_list_page_success() {
             let group_events = group_events.clone();
             move |_, _| Ok(group_events.clone())
         });
+    db.expect_list_event_templates()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(sample_group_summary(group_id)));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -340,6 +598,10 @@ async fn test_update_page_hides_clear_ticketing_when_event_has_ticket_purchases(
     db.expect_list_timezones()
         .times(1)
         .returning(move || Ok(timezones.clone()));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_group_payment_recipient()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
@@ -469,6 +731,10 @@ async fn test_update_page_success() {
     db.expect_list_timezones()
         .times(1)
         .returning(move || Ok(timezones.clone()));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_group_payment_recipient()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
@@ -696,6 +962,10 @@ async fn test_add_success() {
                 .get("name")
                 .and_then(serde_json::Value::as_str)
                 .unwrap_or_default();
+            db.expect_list_event_conflicts()
+                .times(1)
+                .withf(move |gid, eid, _| *gid == group_id && eid.is_some())
+                .returning(|_, _, _| Ok(vec![]));
             *uid == user_id
                 && *id == group_id
                 && event_name == event_form.name
@@ -786,6 +1056,10 @@ async fn test_add_recurring_success() {
                     .and_then(serde_json::Value::as_str)
                     .is_some_and(|name| name == event_name)
             });
+            db.expect_list_event_conflicts()
+                .times(1)
+                .withf(move |gid, eid, _| *gid == group_id && eid.is_some())
+                .returning(|_, _, _| Ok(vec![]));
 
             *uid == user_id
                 && *id == group_id
@@ -798,16 +1072,247 @@ async fn test_add_recurring_success() {
                 && recurrence.get("pattern").and_then(serde_json::Value::as_str) == Some("weekly")
                 && cfg_max_participants.get(&MeetingProvider::Zoom) == Some(&100)
         })
-        .returning(move |_, _, _, _, _| Ok(vec![Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()]));
+        .returning(move |_, _, _, _, _| Ok(vec![Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()]));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router with meetings config and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("test-token"))
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/events/add")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(body))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::CREATED,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_add_with_conflicts_returns_warning_header() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let conflicts = vec![
+        EventConflict {
+            event_id: Uuid::new_v4(),
+            group_name: "Test Group".to_string(),
+            name: "Group Meetup".to_string(),
+            same_group: true,
+            starts_at: Utc::now(),
+        },
+        EventConflict {
+            event_id: Uuid::new_v4(),
+            group_name: "Other Group".to_string(),
+            name: "Venue Workshop".to_string(),
+            same_group: false,
+            starts_at: Utc::now(),
+        },
+    ];
+    let event_form = sample_event_form();
+    let body = serde_qs::to_string(&event_form).unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::EventsWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_add_event()
+        .times(1)
+        .withf(move |uid, id, _, _| *uid == user_id && *id == group_id)
+        .returning(move |_, _, _, _| Ok(event_id));
+    db.expect_list_event_conflicts()
+        .times(1)
+        .withf(move |gid, eid, event| {
+            *gid == group_id
+                && *eid == Some(event_id)
+                && event.get("name").and_then(serde_json::Value::as_str)
+                    == Some(event_form.name.as_str())
+        })
+        .returning(move |_, _, _| Ok(conflicts.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router with meetings config and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("test-token"))
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/events/add")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(body))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::CREATED,
+        "refresh-group-dashboard-table",
+    );
+    let warning = parts.headers["X-OCG-Event-Conflicts"].to_str().unwrap();
+    assert_eq!(
+        percent_encoding::percent_decode_str(warning).decode_utf8().unwrap(),
+        "This event overlaps with other events: Group Meetup, Venue Workshop (Other Group, same venue)."
+    );
+}
+
+#[tokio::test]
+async fn test_add_invalid_body() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::EventsWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/events/add")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("invalid"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_invalid_ticketing_fields_returns_unprocessable_entity() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let event_form = sample_event_form();
+    let body = format!(
+        concat!(
+            "{}",
+            "&ticket_types_present=true",
+            "&ticket_types[0][active]=true",
+            "&ticket_types[0][order]=1",
+            "&ticket_types[0][title]=General%20admission",
+            "&ticket_types[0][price_windows][0][amount_minor]=invalid",
+        ),
+        serde_qs::to_string(&event_form).unwrap(),
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::EventsWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
-    // Setup router with meetings config and send request
-    let router = TestRouterBuilder::new(db, nm)
-        .with_meetings_cfg(sample_zoom_meetings_cfg("test-token"))
-        .build()
-        .await;
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
         .method("POST")
         .uri("/dashboard/group/events/add")
@@ -820,16 +1325,12 @@ async fn test_add_recurring_success() {
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_empty_hx_trigger_response(
-        &parts,
-        &bytes,
-        StatusCode::CREATED,
-        "refresh-group-dashboard-table",
-    );
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
 }
 
 #[tokio::test]
-async fn test_add_invalid_body() {
+async fn test_add_free_ticketed_event_without_payments_success() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
@@ -843,6 +1344,18 @@ async fn test_add_invalid_body() {
         Some(community_id),
         Some(group_id),
     );
+    let body = format!(
+        concat!(
+            "{}",
+            "&ticket_types_present=true",
+            "&ticket_types[0][active]=true",
+            "&ticket_types[0][order]=1",
+            "&ticket_types[0][price_windows][0][amount_minor]=0",
+            "&ticket_types[0][seats_total]=25",
+            "&ticket_types[0][title]=Student"
+        ),
+        serde_qs::to_string(&sample_event_form()).unwrap(),
+    );
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -863,6 +1376,18 @@ async fn test_add_invalid_body() {
                 && permission == GroupPermission::EventsWrite
         })
         .returning(|_, _, _, _| Ok(true));
+    db.expect_get_group_payment_recipient().times(0);
+    db.expect_add_event()
+        .times(1)
+        .withf(move |uid, id, event, _| {
+            let ticket_title = event["ticket_types"][0]["title"].as_str();
+            db.expect_list_event_conflicts()
+                .times(1)
+                .withf(move |gid, eid, _| *gid == group_id && eid.is_some())
+                .returning(|_, _, _| Ok(vec![]));
+            *uid == user_id && *id == group_id && ticket_title == Some("Student")
+        })
+        .returning(move |_, _, _, _| Ok(Uuid::new_v4()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -874,19 +1399,23 @@ async fn test_add_invalid_body() {
         .uri("/dashboard/group/events/add")
         .header(COOKIE, format!("id={session_id}"))
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(Body::from("invalid"))
+        .body(Body::from(body))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
-    assert!(!bytes.is_empty());
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::CREATED,
+        "refresh-group-dashboard-table",
+    );
 }
 
 #[tokio::test]
-async fn test_add_invalid_ticketing_fields_returns_unprocessable_entity() {
+async fn test_add_ticketed_event_without_payments_returns_unprocessable_entity() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
@@ -900,18 +1429,7 @@ async fn test_add_invalid_ticketing_fields_returns_unprocessable_entity() {
         Some(community_id),
         Some(group_id),
     );
-    let event_form = sample_event_form();
-    let body = format!(
-        concat!(
-            "{}",
-            "&ticket_types_present=true",
-            "&ticket_types[0][active]=true",
-            "&ticket_types[0][order]=1",
-            "&ticket_types[0][title]=General%20admission",
-            "&ticket_types[0][price_windows][0][amount_minor]=invalid",
-        ),
-        serde_qs::to_string(&event_form).unwrap(),
-    );
+    let body = sample_ticketed_event_body();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -932,6 +1450,8 @@ async fn test_add_invalid_ticketing_fields_returns_unprocessable_entity() {
                 && permission == GroupPermission::EventsWrite
         })
         .returning(|_, _, _, _| Ok(true));
+    db.expect_get_group_payment_recipient().times(0);
+    db.expect_add_event().times(0);
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -951,11 +1471,14 @@ async fn test_add_invalid_ticketing_fields_returns_unprocessable_entity() {
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
-    assert!(!bytes.is_empty());
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        "payments are not configured on this server",
+    );
 }
 
 #[tokio::test]
-async fn test_add_ticketed_event_without_payments_returns_unprocessable_entity() {
+async fn test_add_meeting_provider_not_enabled_returns_unprocessable_entity() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
@@ -969,7 +1492,10 @@ async fn test_add_ticketed_event_without_payments_returns_unprocessable_entity()
         Some(community_id),
         Some(group_id),
     );
-    let body = sample_ticketed_event_body();
+    let mut event_form = sample_event_form();
+    event_form.meeting_provider = Some(MeetingProvider::Jitsi);
+    event_form.meeting_requested = Some(true);
+    let body = serde_qs::to_string(&event_form).unwrap();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -990,14 +1516,16 @@ async fn test_add_ticketed_event_without_payments_returns_unprocessable_entity()
                 && permission == GroupPermission::EventsWrite
         })
         .returning(|_, _, _, _| Ok(true));
-    db.expect_get_group_payment_recipient().times(0);
     db.expect_add_event().times(0);
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
-    // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
+    // Setup router with only Zoom enabled and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("test-token"))
+        .build()
+        .await;
     let request = Request::builder()
         .method("POST")
         .uri("/dashboard/group/events/add")
@@ -1013,7 +1541,7 @@ async fn test_add_ticketed_event_without_payments_returns_unprocessable_entity()
     assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
     assert_eq!(
         String::from_utf8(bytes.to_vec()).unwrap(),
-        "payments are not configured on this server",
+        "meeting provider jitsi is not enabled on this server",
     );
 }
 
@@ -1557,6 +2085,10 @@ async fn test_publish_success() {
             *uid == user_id && provider.is_none() && *gid == group_id && *eid == event_id
         })
         .returning(move |_, _, _, _| Ok(()));
+    tx.expect_get_community_full()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
     tx.expect_get_event_full()
         .times(1)
         .withf(move |cid, gid, eid| *cid == community_id && *gid == group_id && *eid == event_id)
@@ -1572,6 +2104,18 @@ async fn test_publish_success() {
     tx.expect_get_site_settings()
         .times(1)
         .returning(move || Ok(site_settings.clone()));
+    tx.expect_enqueue_community_notification()
+        .times(1)
+        .withf(move |cid, notification| {
+            *cid == community_id && matches!(notification.kind, NotificationKind::EventPublished)
+        })
+        .returning(|_, _| Ok(()));
+    tx.expect_enqueue_group_notification()
+        .times(1)
+        .withf(move |gid, notification| {
+            *gid == group_id && matches!(notification.kind, NotificationKind::EventPublished)
+        })
+        .returning(|_, _| Ok(()));
     tx.expect_enqueue_notification()
         .times(1)
         .withf(move |notification| {
@@ -1676,6 +2220,10 @@ async fn test_publish_test_event_no_notification() {
             *uid == user_id && provider.is_none() && *gid == group_id && *eid == event_id
         })
         .returning(move |_, _, _, _| Ok(()));
+    tx.expect_get_community_full()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
     expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock
@@ -1775,6 +2323,10 @@ async fn test_publish_series_success() {
                 && event_ids == expected_series_event_ids.as_slice()
         })
         .returning(move |_, _, _, _| Ok(()));
+    tx.expect_get_community_full()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
     expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock
@@ -1891,6 +2443,10 @@ async fn test_publish_series_sends_aggregate_notification() {
                 && event_ids == expected_series_event_ids.as_slice()
         })
         .returning(move |_, _, _, _| Ok(()));
+    tx.expect_get_community_full()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
     tx.expect_list_group_members_ids()
         .times(1)
         .withf(move |gid| *gid == group_id)
@@ -2004,6 +2560,96 @@ async fn test_publish_already_published_no_notification() {
             *uid == user_id && provider.is_none() && *gid == group_id && *eid == event_id
         })
         .returning(move |_, _, _, _| Ok(()));
+    tx.expect_get_community_full()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
+    expect_successful_transaction(&mut db, tx);
+
+    // Setup notifications manager mock (no enqueue expected)
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/group/events/{event_id}/publish"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_publish_pending_approval_no_notification() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    // Community requires approval, so the event is sent for review instead
+    let community = CommunityFull {
+        event_approval_required: true,
+        ..sample_community_full(community_id)
+    };
+    let unpublished_event = EventSummary {
+        published: false,
+        ..sample_event_summary(event_id, group_id)
+    };
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::EventsWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+    let mut tx = MockDB::new();
+    tx.expect_get_event_summary()
+        .times(1)
+        .withf(move |cid, gid, eid| *cid == community_id && *gid == group_id && *eid == event_id)
+        .returning(move |_, _, _| Ok(unpublished_event.clone()));
+    tx.expect_publish_event()
+        .times(1)
+        .withf(move |uid, provider, gid, eid| {
+            *uid == user_id && provider.is_none() && *gid == group_id && *eid == event_id
+        })
+        .returning(move |_, _, _, _| Ok(()));
+    tx.expect_get_community_full()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(community.clone()));
     expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock (no enqueue expected)
@@ -2092,6 +2738,10 @@ async fn test_publish_speakers_only() {
             *uid == user_id && provider.is_none() && *gid == group_id && *eid == event_id
         })
         .returning(move |_, _, _, _| Ok(()));
+    tx.expect_get_community_full()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(move |_| Ok(sample_community_full(community_id)));
     tx.expect_get_event_full()
         .times(1)
         .withf(move |cid, gid, eid| *cid == community_id && *gid == group_id && *eid == event_id)
@@ -2108,6 +2758,18 @@ async fn test_publish_speakers_only() {
     tx.expect_get_site_settings()
         .times(1)
         .returning(move || Ok(site_settings.clone()));
+    tx.expect_enqueue_community_notification()
+        .times(1)
+        .withf(move |cid, notification| {
+            *cid == community_id && matches!(notification.kind, NotificationKind::EventPublished)
+        })
+        .returning(|_, _| Ok(()));
+    tx.expect_enqueue_group_notification()
+        .times(1)
+        .withf(move |gid, notification| {
+            *gid == group_id && matches!(notification.kind, NotificationKind::EventPublished)
+        })
+        .returning(|_, _| Ok(()));
     tx.expect_enqueue_notification()
         .times(1)
         .withf(move |notification| {
@@ -2499,6 +3161,10 @@ async fn test_update_success() {
                 && cfg_max_participants.is_empty()
         })
         .returning(move |_, _, _, _, _| Ok(vec![]));
+    db.expect_list_event_conflicts()
+        .times(1)
+        .withf(move |gid, eid, _| *gid == group_id && *eid == Some(event_id))
+        .returning(|_, _, _| Ok(vec![]));
     expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock
@@ -2671,6 +3337,93 @@ async fn test_update_ticketed_event_without_payment_recipient_returns_unprocessa
     );
 }
 
+#[tokio::test]
+async fn test_update_session_meeting_provider_not_enabled_returns_unprocessable_entity() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let mut event_form = sample_event_form();
+    event_form.sessions = Some(vec![GroupEventSession {
+        kind: SessionKind::Virtual,
+        name: "Keynote".to_string(),
+        session_id: None,
+        starts_at: Utc::now().naive_utc(),
+
+        cfs_submission_id: None,
+        description: None,
+        ends_at: None,
+        location: None,
+        meeting_hosts: None,
+        meeting_join_instructions: None,
+        meeting_join_url: None,
+        meeting_provider: Some(MeetingProvider::Teams),
+        meeting_recording_published: None,
+        meeting_recording_url: None,
+        meeting_requested: Some(true),
+        speakers: None,
+        track: None,
+    }]);
+    let body = serde_qs::to_string(&event_form).unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::EventsWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_update_event().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router with only Zoom enabled and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("test-token"))
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/group/events/{event_id}/update"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(body))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        "meeting provider teams is not enabled on this server",
+    );
+}
+
 #[tokio::test]
 #[allow(clippy::too_many_lines)]
 async fn test_update_promotes_waitlist_and_sends_reschedule_notification() {
@@ -2796,6 +3549,10 @@ async fn test_update_promotes_waitlist_and_sends_reschedule_notification() {
                 })
         })
         .returning(|_| Ok(()));
+    db.expect_list_event_conflicts()
+        .times(1)
+        .withf(move |gid, eid, _| *gid == group_id && *eid == Some(event_id))
+        .returning(|_, _, _| Ok(vec![]));
     expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock
@@ -3100,6 +3857,10 @@ async fn test_update_no_notification_when_shift_too_small() {
                 && cfg_max_participants.is_empty()
         })
         .returning(move |_, _, _, _, _| Ok(vec![]));
+    db.expect_list_event_conflicts()
+        .times(1)
+        .withf(move |gid, eid, _| *gid == group_id && *eid == Some(event_id))
+        .returning(|_, _, _| Ok(vec![]));
     expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock (no enqueue expected - shift too small)
@@ -3202,6 +3963,10 @@ async fn test_update_no_notification_when_unpublished() {
                 && cfg_max_participants.is_empty()
         })
         .returning(move |_, _, _, _, _| Ok(vec![]));
+    db.expect_list_event_conflicts()
+        .times(1)
+        .withf(move |gid, eid, _| *gid == group_id && *eid == Some(event_id))
+        .returning(|_, _, _| Ok(vec![]));
     expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock (no enqueue expected - event unpublished)
@@ -3295,6 +4060,10 @@ async fn test_update_past_event_success() {
                 && cfg_max_participants.is_empty()
         })
         .returning(move |_, _, _, _, _| Ok(vec![]));
+    db.expect_list_event_conflicts()
+        .times(1)
+        .withf(move |gid, eid, _| *gid == group_id && *eid == Some(event_id))
+        .returning(|_, _, _| Ok(vec![]));
     expect_successful_transaction(&mut db, tx);
 
     // Setup notifications manager mock (no expectations - past events don't notify)
@@ -0,0 +1,141 @@
+//! HTTP handlers for curating the group gallery in the dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId, ValidatedForm},
+    },
+    templates::dashboard::group::gallery::{
+        self, GroupGalleryCoverUpdate, GroupGalleryPhotoInput, GroupGalleryPhotosUpdate,
+    },
+    types::permissions::GroupPermission,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the photos of the group gallery.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_list_page(&db, community_id, group_id, user.user_id).await?;
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Hides a photo from the group gallery.
+#[instrument(skip_all, err)]
+pub(crate) async fn hide_photo(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedForm(photo): ValidatedForm<GroupGalleryPhotoInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_gallery_photo_hidden(user.user_id, group_id, &photo.url, true)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Shows a hidden photo in the group gallery again.
+#[instrument(skip_all, err)]
+pub(crate) async fn show_photo(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedForm(photo): ValidatedForm<GroupGalleryPhotoInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_gallery_photo_hidden(user.user_id, group_id, &photo.url, false)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Sets or clears the cover of the group gallery.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_cover(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedForm(cover): ValidatedForm<GroupGalleryCoverUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_gallery_cover(user.user_id, group_id, &cover).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+/// Updates the photos uploaded by the organizers to the group gallery.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_photos(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedForm(gallery): ValidatedForm<GroupGalleryPhotosUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_group_gallery_photos(user.user_id, group_id, &gallery)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
+// Helpers.
+
+/// Prepares the gallery list page for the group dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    community_id: Uuid,
+    group_id: Uuid,
+    user_id: Uuid,
+) -> Result<gallery::ListPage> {
+    let (can_manage_settings, group, photos) = tokio::try_join!(
+        db.user_has_group_permission(
+            &community_id,
+            &group_id,
+            &user_id,
+            GroupPermission::SettingsWrite
+        ),
+        db.get_group_full(community_id, group_id),
+        db.list_group_gallery_photos(group_id, true)
+    )?;
+
+    Ok(gallery::ListPage {
+        can_manage_settings,
+        photos,
+        photos_urls: group.photos_urls.unwrap_or_default(),
+    })
+}
@@ -13,7 +13,7 @@ use tracing::instrument;
 
 use crate::{
     auth::AuthSession,
-    config::PaymentsConfig,
+    config::{MeetingsConfig, PaymentsConfig},
     db::DynDB,
     handlers::{
         error::HandlerError,
@@ -31,7 +31,10 @@ use crate::{
     types::permissions::GroupPermission,
 };
 
-use super::{events, logs, members, refunds, sponsors, team};
+use super::{
+    announcements, discussions, events, gallery, logs, members, notifications, refunds, sponsors,
+    team,
+};
 
 #[cfg(test)]
 mod tests;
@@ -50,6 +53,7 @@ pub(crate) async fn page(
     SelectedCommunityId(community_id): SelectedCommunityId,
     SelectedGroupId(group_id): SelectedGroupId,
     State(db): State<DynDB>,
+    State(meetings_cfg): State<Option<MeetingsConfig>>,
     State(payments_cfg): State<Option<PaymentsConfig>>,
     Query(query): Query<HashMap<String, String>>,
     RawQuery(raw_query): RawQuery,
@@ -66,16 +70,28 @@ pub(crate) async fn page(
     // Prepare content for the selected tab
     let content = match tab {
         Tab::Analytics => {
-            let (stats, has_subgroups) = tokio::try_join!(
+            let (stats, engagement, has_subgroups) = tokio::try_join!(
                 db.get_group_stats(community_id, group_id, false),
+                db.get_group_engagement_stats(community_id, group_id, false),
                 db.group_has_active_subgroups(community_id, group_id)
             )?;
             Content::Analytics(Box::new(analytics::Page {
+                engagement,
                 include_subgroups: false,
                 has_subgroups,
                 stats,
             }))
         }
+        Tab::Announcements => {
+            let template =
+                announcements::prepare_list_page(&db, community_id, group_id, user.user_id).await?;
+            Content::Announcements(template)
+        }
+        Tab::Discussions => {
+            let template =
+                discussions::prepare_list_page(&db, community_id, group_id, user.user_id).await?;
+            Content::Discussions(template)
+        }
         Tab::Events => {
             let (_, template) = events::prepare_list_page(
                 &db,
@@ -87,6 +103,11 @@ pub(crate) async fn page(
             .await?;
             Content::Events(Box::new(template))
         }
+        Tab::Gallery => {
+            let template =
+                gallery::prepare_list_page(&db, community_id, group_id, user.user_id).await?;
+            Content::Gallery(template)
+        }
         Tab::Logs => {
             let (_, template) =
                 logs::prepare_list_page(&db, group_id, raw_query.as_deref().unwrap_or_default())
@@ -104,6 +125,15 @@ pub(crate) async fn page(
             .await?;
             Content::Members(template)
         }
+        Tab::Notifications => {
+            let (_, template) = notifications::prepare_list_page(
+                &db,
+                group_id,
+                raw_query.as_deref().unwrap_or_default(),
+            )
+            .await?;
+            Content::Notifications(template)
+        }
         Tab::Refunds => {
             let (_, template) = refunds::prepare_list_page(
                 &db,
@@ -116,28 +146,56 @@ pub(crate) async fn page(
             Content::Refunds(template)
         }
         Tab::Settings => {
-            let (can_manage_settings, group, has_child_links, categories, parent_options, regions) =
-                tokio::try_join!(
-                    db.user_has_group_permission(
-                        &community_id,
-                        &group_id,
-                        &user.user_id,
-                        GroupPermission::SettingsWrite
-                    ),
-                    db.get_group_full(community_id, group_id),
-                    db.group_has_child_links(community_id, group_id),
-                    db.list_group_categories(community_id),
-                    db.list_group_parent_options(community_id, user.user_id, Some(group_id)),
-                    db.list_regions(community_id)
-                )?;
+            let zoom_enabled = meetings_cfg.as_ref().is_some_and(MeetingsConfig::zoom_enabled);
+            let (
+                can_manage_settings,
+                group,
+                has_child_links,
+                categories,
+                discord_webhook_url,
+                ownership,
+                parent_options,
+                regions,
+                topics,
+                welcome_message,
+                zoom_account,
+            ) = tokio::try_join!(
+                db.user_has_group_permission(
+                    &community_id,
+                    &group_id,
+                    &user.user_id,
+                    GroupPermission::SettingsWrite
+                ),
+                db.get_group_full(community_id, group_id),
+                db.group_has_child_links(community_id, group_id),
+                db.list_group_categories(community_id),
+                db.get_group_discord_webhook_url(group_id),
+                db.get_group_ownership(group_id),
+                db.list_group_parent_options(community_id, user.user_id, Some(group_id)),
+                db.list_regions(community_id),
+                db.list_topics(community_id),
+                db.get_group_welcome_message(group_id),
+                super::settings::get_zoom_account(&db, group_id, zoom_enabled)
+            )?;
+            let is_owner = ownership
+                .owner
+                .as_ref()
+                .is_some_and(|owner| owner.user_id == user.user_id);
             Content::Settings(Box::new(settings::UpdatePage {
                 can_manage_settings,
                 categories,
+                discord_webhook_url,
                 group,
                 has_child_links,
+                is_owner,
+                ownership,
                 parent_options,
                 payments_enabled: payments_cfg.is_some(),
                 regions,
+                topics,
+                welcome_message,
+                zoom_account,
+                zoom_enabled,
             }))
         }
         Tab::Sponsors => {
@@ -30,6 +30,7 @@ async fn test_page_analytics_tab_success() {
         Some(group_id),
     );
     let groups = sample_user_groups_by_community(community_id, group_id);
+    let engagement = sample_group_engagement_stats();
     let stats = sample_group_stats();
 
     // Setup database mock
@@ -61,6 +62,12 @@ async fn test_page_analytics_tab_success() {
             *cid == community_id && *gid == group_id && !*include_subgroups
         })
         .returning(move |_, _, _| Ok(stats.clone()));
+    db.expect_get_group_engagement_stats()
+        .times(1)
+        .withf(move |cid, gid, include_subgroups| {
+            *cid == community_id && *gid == group_id && !*include_subgroups
+        })
+        .returning(move |_, _, _| Ok(engagement.clone()));
     db.expect_group_has_active_subgroups()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
@@ -88,6 +95,121 @@ async fn test_page_analytics_tab_success() {
     assert_html_response(&parts, &bytes, StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_page_announcements_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let groups = sample_user_groups_by_community(community_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::Read,
+    );
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(groups.clone()));
+    db.expect_list_group_announcements()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(vec![sample_group_announcement()]));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/group?tab=announcements")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_page_discussions_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let discussion = sample_group_discussion();
+    let groups = sample_user_groups_by_community(community_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::Read,
+    );
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(groups.clone()));
+    db.expect_get_group_discussion()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(discussion.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/group?tab=discussions")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
 #[tokio::test]
 async fn test_page_events_tab_success() {
     // Setup identifiers and data structures
@@ -148,6 +270,14 @@ async fn test_page_events_tab_success() {
                 && filters.upcoming_offset == Some(0)
         })
         .returning(move |_, _| Ok(group_events.clone()));
+    db.expect_list_event_templates()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(sample_group_summary(group_id)));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -171,6 +301,68 @@ async fn test_page_events_tab_success() {
     assert_html_response(&parts, &bytes, StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_page_gallery_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let group = sample_group_full(community_id, group_id);
+    let groups = sample_user_groups_by_community(community_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::Read,
+    );
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::SettingsWrite,
+    );
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(groups.clone()));
+    db.expect_get_group_full()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group.clone()));
+    db.expect_list_group_gallery_photos()
+        .times(1)
+        .withf(move |gid, include_hidden| *gid == group_id && *include_hidden)
+        .returning(|_, _| Ok(vec![sample_group_gallery_photo()]));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/group?tab=gallery")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
 #[tokio::test]
 async fn test_page_logs_tab_success() {
     // Setup identifiers and data structures
@@ -307,10 +499,40 @@ async fn test_page_members_tab_success() {
                 && filters.offset == Some(0)
         })
         .returning(move |_, _| Ok(output.clone()));
+    db.expect_list_group_events()
+        .times(1)
+        .withf(move |id, filters| {
+            *id == group_id && filters.limit == Some(DASHBOARD_PAGINATION_LIMIT)
+        })
+        .returning(move |_, _| Ok(sample_group_events(Uuid::new_v4(), group_id)));
+    db.expect_list_event_templates()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_group_summary()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
         .returning(move |_, _| Ok(group.clone()));
+    db.expect_get_group_join_questions()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(|_, _| Ok(vec![]));
+    db.expect_list_group_join_requests()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_group_member_leaderboard()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_group_member_roles()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec!["Mentor".to_string()]));
+    db.expect_list_group_bans()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -337,6 +559,78 @@ async fn test_page_members_tab_success() {
     assert!(body.contains("value=\"Test Group\""));
 }
 
+#[tokio::test]
+async fn test_page_notifications_tab_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let groups = sample_user_groups_by_community(community_id, group_id);
+    let output = sample_custom_notifications_output();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::Read
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| uid == &user_id)
+        .returning(move |_| Ok(groups.clone()));
+    db.expect_list_group_custom_notifications()
+        .times(1)
+        .withf(move |gid, filters| {
+            *gid == group_id
+                && filters.limit == Some(DASHBOARD_PAGINATION_LIMIT)
+                && filters.offset == Some(0)
+        })
+        .returning(move |_, _| Ok(output.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/group?tab=notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+}
+
 #[tokio::test]
 async fn test_page_settings_tab_success() {
     // Setup identifiers and data structures
@@ -401,6 +695,18 @@ async fn test_page_settings_tab_success() {
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Ok(vec![category.clone()]));
+    db.expect_get_group_discord_webhook_url()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(None));
+    db.expect_get_group_welcome_message()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(None));
+    db.expect_get_group_ownership()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(move |_| Ok(sample_group_ownership(user_id)));
     db.expect_list_group_parent_options()
         .times(1)
         .withf(move |cid, uid, gid| {
@@ -411,6 +717,10 @@ async fn test_page_settings_tab_success() {
         .times(1)
         .withf(move |cid| *cid == community_id)
         .returning(move |_| Ok(vec![region.clone()]));
+    db.expect_list_topics()
+        .times(1)
+        .withf(move |cid| *cid == community_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -497,6 +807,10 @@ async fn test_page_sponsors_tab_success() {
                 && !*full_list
         })
         .returning(move |_, _, _| Ok(output.clone()));
+    db.expect_list_group_sponsor_tiers()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![sample_group_sponsor_tier()]));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
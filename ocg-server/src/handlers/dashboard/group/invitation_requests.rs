@@ -12,6 +12,7 @@ use uuid::Uuid;
 use crate::{
     db::DynDB,
     handlers::{
+        auth::user_has_group_event_permission,
         error::HandlerError,
         extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId},
     },
@@ -43,9 +44,11 @@ pub(crate) async fn list_page(
         serde_qs_config().deserialize_str(raw_query.as_deref().unwrap_or_default())?;
     filters.validate()?;
     let (can_manage_events, event, search_results) = tokio::try_join!(
-        db.user_has_group_permission(
+        user_has_group_event_permission(
+            &db,
             &community_id,
             &group_id,
+            &event_id,
             &user.user_id,
             GroupPermission::EventsWrite
         ),
@@ -0,0 +1,131 @@
+//! HTTP handlers for the event check-in kiosk in the group dashboard.
+
+use askama::Template;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{
+        StatusCode, Uri,
+        header::{CACHE_CONTROL, CONTENT_TYPE},
+    },
+    response::{Html, IntoResponse},
+};
+use serde_json::json;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthSession,
+    config::HttpServerConfig,
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId},
+    },
+    router::CACHE_CONTROL_NO_STORE,
+    templates::{PageId, auth::User, dashboard::group::kiosk},
+    util::{base_url_without_trailing_slash, build_qr_code_svg},
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the check-in kiosk page for an event.
+#[instrument(skip_all, err)]
+pub(crate) async fn page(
+    auth_session: AuthSession,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+    uri: Uri,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get site settings and event details (checking event belongs to group)
+    let (event, site_settings) = tokio::try_join!(
+        db.get_event_summary(community_id, group_id, event_id),
+        db.get_site_settings()
+    )?;
+
+    // Prepare template
+    let template = kiosk::Page {
+        event,
+        page_id: PageId::GroupDashboard,
+        path: uri.path().to_string(),
+        site_settings,
+        user: User::from_session(auth_session).await?,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+/// Generates the rotating check-in QR code displayed in the kiosk.
+#[instrument(skip_all, err)]
+pub(crate) async fn qr_code(
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get community name (cached) and ensure event belongs to selected group
+    let (community_name, _) = tokio::try_join!(
+        db.get_community_name_by_id(community_id),
+        db.get_event_summary(community_id, group_id, event_id)
+    )?;
+    let Some(community_name) = community_name else {
+        return Err(anyhow::anyhow!("community not found").into());
+    };
+
+    // Get the current check-in code of the event
+    let code = db.get_event_check_in_code(event_id).await?;
+
+    // Construct check-in URL including the code
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let check_in_url = format!("{base_url}/{community_name}/check-in/{event_id}?code={code}");
+
+    // Generate QR code
+    let svg = build_qr_code_svg(&check_in_url, 500)?;
+
+    // Prepare response headers
+    let headers = [
+        (CACHE_CONTROL, CACHE_CONTROL_NO_STORE),
+        (CONTENT_TYPE, "image/svg+xml"),
+    ];
+
+    Ok((StatusCode::OK, headers, svg))
+}
+
+// Actions handlers.
+
+/// Checks in the attendee owning a scanned ticket.
+#[instrument(skip_all, err)]
+pub(crate) async fn check_in_ticket(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path((event_id, ticket_code)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Find the attendee owning the ticket
+    let Some(attendee) = db
+        .get_event_attendee_by_ticket_code(group_id, event_id, ticket_code)
+        .await?
+    else {
+        return Ok((StatusCode::NOT_FOUND, "ticket not found for this event").into_response());
+    };
+
+    // Check in the attendee unless they already are
+    if !attendee.checked_in {
+        db.manual_check_in_event(user.user_id, community_id, event_id, attendee.user_id)
+            .await?;
+    }
+
+    Ok(Json(json!({
+        "already_checked_in": attendee.checked_in,
+        "name": attendee.display_name(),
+        "ticket_title": attendee.ticket_title,
+    }))
+    .into_response())
+}
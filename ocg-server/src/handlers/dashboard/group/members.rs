@@ -3,10 +3,14 @@
 use anyhow::Result;
 use askama::Template;
 use axum::{
-    extract::{RawQuery, State},
-    http::{HeaderName, StatusCode},
+    extract::{Path, RawQuery, State},
+    http::{
+        HeaderName, StatusCode,
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+    },
     response::{Html, IntoResponse},
 };
+use chrono::{DateTime, Duration, Utc};
 use garde::Validate;
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
@@ -14,23 +18,38 @@ use uuid::Uuid;
 
 use crate::{
     config::HttpServerConfig,
-    db::{DynDB, notifications::CustomNotificationTracking},
+    db::{DBExt, DynDB, notifications::CustomNotificationTracking},
     handlers::{
         error::HandlerError,
         extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId, ValidatedForm},
     },
     router::serde_qs_config,
-    services::notifications::{NewNotification, NotificationKind},
+    services::notifications::{
+        NewNotification, NotificationKind, enqueue::enqueue_group_welcome_notification,
+    },
     templates::{
-        dashboard::group::members::{self, GroupMembersFilters},
+        dashboard::{
+            self,
+            group::{
+                events::EventsListFilters,
+                members::{
+                    self, GroupBanInput, GroupMemberExport, GroupMemberRoleUpdate,
+                    GroupMembersFilters,
+                },
+            },
+        },
         notifications::GroupCustom,
     },
     types::{
         pagination::{self, NavigationLinks},
         permissions::GroupPermission,
+        questionnaire::QuestionnaireQuestion,
     },
     util::base_url_without_trailing_slash,
-    validation::{MAX_LEN_M, MAX_LEN_NOTIFICATION_BODY, trimmed_non_empty},
+    validation::{
+        MAX_INACTIVE_MONTHS, MAX_LEN_GROUP_MEMBER_ROLE, MAX_LEN_M, MAX_LEN_NOTIFICATION_BODY,
+        blank_string_as_none, trimmed_non_empty,
+    },
 };
 
 #[cfg(test)]
@@ -40,6 +59,12 @@ mod tests;
 const DASHBOARD_URL: &str = "/dashboard/group?tab=members";
 const PARTIAL_URL: &str = "/dashboard/group/members";
 
+/// Maximum number of custom notifications a group can send within the throttling window.
+pub(super) const CUSTOM_NOTIFICATIONS_LIMIT: i64 = 5;
+
+/// Throttling window for the custom notifications sent by a group (hours).
+pub(super) const CUSTOM_NOTIFICATIONS_WINDOW_HOURS: i64 = 24;
+
 // Pages handlers.
 
 /// Displays the list of group members.
@@ -70,7 +95,81 @@ pub(crate) async fn list_page(
 
 // Actions handlers.
 
-/// Sends a custom notification to all group members.
+/// Bans a user from the group, removing their membership.
+#[instrument(skip_all, err)]
+pub(crate) async fn ban_member(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(user_id): Path<Uuid>,
+    ValidatedForm(ban): ValidatedForm<GroupBanInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Ban the user from the group
+    db.ban_group_member(user.user_id, community_id, group_id, user_id, &ban)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    ))
+}
+
+/// Accepts a pending request to join the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn accept_join_request(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.as_ref()
+        .transaction(|tx| {
+            Box::pin(async move {
+                // Accept the join request
+                tx.accept_group_join_request(user.user_id, group_id, user_id).await?;
+
+                // Enqueue the welcome notification before committing
+                enqueue_group_welcome_notification(
+                    tx,
+                    &server_cfg,
+                    community_id,
+                    group_id,
+                    user_id,
+                )
+                .await?;
+
+                Ok(())
+            })
+        })
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    ))
+}
+
+/// Rejects a pending request to join the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn reject_join_request(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Reject the join request
+    db.reject_group_join_request(user.user_id, group_id, user_id).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    ))
+}
+
+/// Sends a custom notification to the group members in the selected audience.
 #[instrument(skip_all, err)]
 pub(crate) async fn send_group_custom_notification(
     CurrentUser(user): CurrentUser,
@@ -80,23 +179,78 @@ pub(crate) async fn send_group_custom_notification(
     State(server_cfg): State<HttpServerConfig>,
     ValidatedForm(notification): ValidatedForm<GroupCustomNotification>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    // Get group data and site settings
-    let (site_settings, group, group_members_ids, team_member_ids) = tokio::try_join!(
+    // Make sure the audience parameters required were provided
+    let audience = notification.audience;
+    let (event_id, inactive_months, member_role) = match audience {
+        GroupCustomNotificationAudience::All => (None, None, None),
+        GroupCustomNotificationAudience::CheckedInAttendees
+        | GroupCustomNotificationAudience::EventAttendees => {
+            if notification.event_id.is_none() {
+                return Ok((StatusCode::BAD_REQUEST, "Select an event.").into_response());
+            }
+            (notification.event_id, None, None)
+        }
+        GroupCustomNotificationAudience::InactiveMembers => {
+            if notification.inactive_months.is_none() {
+                return Ok((
+                    StatusCode::BAD_REQUEST,
+                    "Enter the number of months of inactivity.",
+                )
+                    .into_response());
+            }
+            (None, notification.inactive_months, None)
+        }
+        GroupCustomNotificationAudience::MembersWithRole => {
+            if notification.member_role.is_none() {
+                return Ok((StatusCode::BAD_REQUEST, "Select a role.").into_response());
+            }
+            (None, None, notification.member_role.clone())
+        }
+    };
+
+    // Get group data, site settings and the audience recipients
+    let since = Utc::now() - Duration::hours(CUSTOM_NOTIFICATIONS_WINDOW_HOURS);
+    let (site_settings, group, recipients, recent_notifications) = tokio::try_join!(
         db.get_site_settings(),
         db.get_group_summary(community_id, group_id),
-        db.list_group_members_ids(group_id),
-        db.list_group_team_members_ids(group_id),
+        db.resolve_group_custom_notification_recipient_ids(
+            group_id,
+            audience.as_ref(),
+            event_id,
+            inactive_months,
+            member_role
+        ),
+        db.count_group_custom_notifications_since(group_id, since),
     )?;
 
-    // Combine group members and team members
-    let mut recipients = group_members_ids;
-    recipients.extend(team_member_ids);
-    recipients.sort();
-    recipients.dedup();
+    // Throttle the number of custom notifications sent by the group
+    if recent_notifications >= CUSTOM_NOTIFICATIONS_LIMIT {
+        let message = format!(
+            "Groups can send up to {CUSTOM_NOTIFICATIONS_LIMIT} emails every {CUSTOM_NOTIFICATIONS_WINDOW_HOURS} hours. Please try again later."
+        );
+        return Ok((StatusCode::TOO_MANY_REQUESTS, message).into_response());
+    }
 
-    // If there are no recipients, nothing to do
+    // Check there is someone in the audience to send the notification to
     if recipients.is_empty() {
-        return Ok(StatusCode::NO_CONTENT.into_response());
+        let message = match audience {
+            GroupCustomNotificationAudience::All => {
+                return Ok(StatusCode::NO_CONTENT.into_response());
+            }
+            GroupCustomNotificationAudience::CheckedInAttendees => {
+                "No checked-in attendees with verified email addresses."
+            }
+            GroupCustomNotificationAudience::EventAttendees => {
+                "No attendees with verified email addresses."
+            }
+            GroupCustomNotificationAudience::InactiveMembers => {
+                "No inactive members with verified email addresses."
+            }
+            GroupCustomNotificationAudience::MembersWithRole => {
+                "No members with this role and verified email addresses."
+            }
+        };
+        return Ok((StatusCode::BAD_REQUEST, message).into_response());
     }
 
     // Build and enqueue the custom notification with its audit entry
@@ -136,11 +290,93 @@ pub(crate) async fn send_group_custom_notification(
     Ok(StatusCode::NO_CONTENT.into_response())
 }
 
+/// Lifts the ban of a user from the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn unban_member(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Lift the ban
+    db.unban_group_member(user.user_id, community_id, group_id, user_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    ))
+}
+
+/// Sets or clears the role displayed for a group member.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_member_role(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(user_id): Path<Uuid>,
+    ValidatedForm(update): ValidatedForm<GroupMemberRoleUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Update the member role
+    db.update_group_member_role(user.user_id, group_id, user_id, &update)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    ))
+}
+
+// Download handlers.
+
+/// Downloads a CSV file with all the group members.
+#[instrument(skip_all, err)]
+pub(crate) async fn download_csv(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Fetch group summary, join questions, all members and whether emails can
+    // be included
+    let (group, join_questions, members, can_manage_members) = tokio::try_join!(
+        db.get_group_summary(community_id, group_id),
+        db.get_group_join_questions(community_id, group_id),
+        db.list_group_members_export(group_id),
+        db.user_has_group_permission(
+            &community_id,
+            &group_id,
+            &user.user_id,
+            GroupPermission::MembersWrite
+        )
+    )?;
+
+    // Build CSV payload, including emails only for roles managing members
+    let csv = build_members_csv(&members, &join_questions, can_manage_members)?;
+    let file_name = format!("group-{}-members.csv", group.slug);
+
+    Ok((
+        [
+            (CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            ),
+        ],
+        csv,
+    ))
+}
+
 // Types.
 
 /// Form data for custom group notifications.
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub(crate) struct GroupCustomNotification {
+    /// Audience the notification is sent to.
+    #[serde(default)]
+    #[garde(skip)]
+    pub audience: GroupCustomNotificationAudience,
     /// Body text for the notification.
     #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_NOTIFICATION_BODY))]
     pub body: String,
@@ -148,10 +384,93 @@ pub(crate) struct GroupCustomNotification {
     #[serde(alias = "title")]
     #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
     pub subject: String,
+
+    /// Event whose attendees receive the notification.
+    #[serde(default)]
+    #[garde(skip)]
+    pub event_id: Option<Uuid>,
+    /// Months without attending group events for members to be inactive.
+    #[serde(default)]
+    #[garde(range(min = 1, max = MAX_INACTIVE_MONTHS))]
+    pub inactive_months: Option<i32>,
+    /// Role held by the members receiving the notification.
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(length(max = MAX_LEN_GROUP_MEMBER_ROLE))]
+    pub member_role: Option<String>,
+}
+
+/// Audience for custom group notifications.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Serialize, strum::AsRefStr)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum GroupCustomNotificationAudience {
+    /// Send to all group members and team members.
+    #[default]
+    #[serde(rename = "all-members")]
+    #[strum(serialize = "all-members")]
+    All,
+    /// Send to the checked-in attendees of an event.
+    CheckedInAttendees,
+    /// Send to the attendees of an event.
+    EventAttendees,
+    /// Send to members that have not attended group events recently.
+    InactiveMembers,
+    /// Send to the members holding a given role.
+    MembersWithRole,
 }
 
 // Helpers.
 
+/// Builds the CSV payload for the group members, optionally including the
+/// members' email addresses. Each join question gets its own column.
+fn build_members_csv(
+    members: &[GroupMemberExport],
+    join_questions: &[QuestionnaireQuestion],
+    include_emails: bool,
+) -> Result<Vec<u8>, HandlerError> {
+    let mut writer = csv::WriterBuilder::new()
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(vec![]);
+
+    // Write header row
+    let mut headers = vec!["Name", "Username"];
+    if include_emails {
+        headers.push("Email");
+    }
+    headers.extend(["Role", "Joined at", "Last attended at"]);
+    headers.extend(join_questions.iter().map(|question| question.prompt.as_str()));
+    writer.write_record(headers).map_err(anyhow::Error::from)?;
+
+    // Write one row per member
+    for member in members {
+        let mut row = vec![
+            member.name.clone().unwrap_or_default(),
+            member.username.clone(),
+        ];
+        if include_emails {
+            row.push(member.email.clone());
+        }
+        row.extend([
+            member.member_role.clone().unwrap_or_default(),
+            format_csv_date(member.created_at),
+            member.last_attended_at.map(format_csv_date).unwrap_or_default(),
+        ]);
+        row.extend(
+            join_questions
+                .iter()
+                .map(|question| question.format_answer(member.join_answers.as_ref())),
+        );
+        writer.write_record(row).map_err(anyhow::Error::from)?;
+    }
+
+    writer.into_inner().map_err(|err| anyhow::Error::from(err).into())
+}
+
+/// Formats a timestamp as a UTC date for CSV exports.
+fn format_csv_date(datetime: DateTime<Utc>) -> String {
+    datetime.format("%Y-%m-%d").to_string()
+}
+
 /// Prepares the members list page and filters for the group dashboard.
 pub(crate) async fn prepare_list_page(
     db: &DynDB,
@@ -160,17 +479,37 @@ pub(crate) async fn prepare_list_page(
     user_id: Uuid,
     raw_query: &str,
 ) -> Result<(GroupMembersFilters, members::ListPage), HandlerError> {
-    // Fetch group members
+    // Fetch group members and the events used to select the email audience
     let filters: GroupMembersFilters = serde_qs_config().deserialize_str(raw_query)?;
     filters.validate()?;
-    let (can_manage_members, group, results) = tokio::try_join!(
+    let events_filters = EventsListFilters {
+        limit: dashboard::default_limit(),
+        ..Default::default()
+    };
+    let (
+        bans,
+        can_manage_members,
+        events,
+        group,
+        join_questions,
+        join_requests,
+        leaderboard,
+        member_roles,
+        results,
+    ) = tokio::try_join!(
+        db.list_group_bans(group_id),
         db.user_has_group_permission(
             &community_id,
             &group_id,
             &user_id,
             GroupPermission::MembersWrite
         ),
+        db.list_group_events(group_id, &events_filters),
         db.get_group_summary(community_id, group_id),
+        db.get_group_join_questions(community_id, group_id),
+        db.list_group_join_requests(group_id),
+        db.list_group_member_leaderboard(group_id),
+        db.list_group_member_roles(group_id),
         db.list_group_members(group_id, &filters)
     )?;
 
@@ -178,8 +517,14 @@ pub(crate) async fn prepare_list_page(
     let navigation_links =
         NavigationLinks::from_filters(&filters, results.total, DASHBOARD_URL, PARTIAL_URL)?;
     let template = members::ListPage {
+        bans,
         can_manage_members,
         default_notification_subject: group.name,
+        events: events.upcoming.events.into_iter().chain(events.past.events).collect(),
+        join_questions,
+        join_requests,
+        leaderboard,
+        member_roles,
         members: results.members,
         navigation_links,
         total: results.total,
@@ -3,22 +3,404 @@ use axum::{
     body::{Body, to_bytes},
     http::{
         HeaderValue, Request, StatusCode,
-        header::{CONTENT_TYPE, COOKIE},
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE, COOKIE},
     },
 };
 use axum_login::tower_sessions::session;
+use chrono::{TimeZone, Utc};
 use tower::ServiceExt;
 use uuid::Uuid;
 
 use crate::{
     db::mock::MockDB,
-    handlers::{dashboard::group::members::GroupCustomNotification, tests::*},
+    handlers::{
+        dashboard::group::members::{GroupCustomNotification, GroupCustomNotificationAudience},
+        tests::*,
+    },
     services::notifications::{MockNotificationsManager, NotificationKind},
-    templates::dashboard::DASHBOARD_PAGINATION_LIMIT,
+    templates::dashboard::{
+        DASHBOARD_PAGINATION_LIMIT,
+        group::members::{
+            GroupBan, GroupJoinRequest, GroupMemberExport, GroupMemberLeaderboardEntry,
+        },
+    },
     templates::notifications::GroupCustom,
-    types::permissions::GroupPermission,
+    types::{
+        permissions::GroupPermission,
+        questionnaire::{QuestionnaireAnswer, QuestionnaireAnswerValue, QuestionnaireAnswers},
+    },
 };
 
+#[tokio::test]
+async fn test_ban_member_invalid_payload() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let member_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/group/members/{member_id}/ban"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("reason=+++"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_ban_member_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let member_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_ban_group_member()
+        .times(1)
+        .withf(move |aid, cid, gid, uid, ban| {
+            *aid == user_id
+                && *cid == community_id
+                && *gid == group_id
+                && *uid == member_id
+                && ban.reason == "Spamming other members"
+        })
+        .returning(|_, _, _, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/group/members/{member_id}/ban"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("reason=Spamming+other+members"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_accept_join_request_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let requester_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let group = sample_group_summary(group_id);
+    let site_settings = sample_site_settings();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    let mut tx = MockDB::new();
+    tx.expect_accept_group_join_request()
+        .times(1)
+        .withf(move |aid, gid, uid| *aid == user_id && *gid == group_id && *uid == requester_id)
+        .returning(|_, _, _| Ok(()));
+    tx.expect_get_site_settings()
+        .times(1)
+        .returning(move || Ok(site_settings.clone()));
+    tx.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group.clone()));
+    tx.expect_get_group_welcome_message()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(None));
+    tx.expect_enqueue_notification()
+        .times(1)
+        .withf(move |notification| {
+            matches!(notification.kind, NotificationKind::GroupWelcome)
+                && notification.recipients == vec![requester_id]
+        })
+        .returning(|_| Ok(()));
+    tx.expect_enqueue_community_notification()
+        .times(1)
+        .withf(move |cid, notification| {
+            *cid == community_id && matches!(notification.kind, NotificationKind::GroupWelcome)
+        })
+        .returning(|_, _| Ok(()));
+    expect_successful_transaction(&mut db, tx);
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/group/members/{requester_id}/join-request/accept"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_download_csv_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let question_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let group = sample_group_summary(group_id);
+    let join_questions = vec![sample_join_question(question_id)];
+    let members = vec![
+        GroupMemberExport {
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            email: "jane@example.test".to_string(),
+            username: "jane".to_string(),
+            join_answers: Some(QuestionnaireAnswers {
+                answers: vec![QuestionnaireAnswer {
+                    question_id,
+                    value: QuestionnaireAnswerValue::One("Rust, Kubernetes".to_string()),
+                }],
+            }),
+            last_attended_at: Some(Utc.with_ymd_and_hms(2024, 3, 1, 18, 0, 0).unwrap()),
+            member_role: Some("Mentor".to_string()),
+            name: Some("Doe, Jane".to_string()),
+        },
+        GroupMemberExport {
+            created_at: Utc.with_ymd_and_hms(2024, 2, 1, 10, 0, 0).unwrap(),
+            email: "anonymous@example.test".to_string(),
+            username: "anonymous".to_string(),
+            join_answers: None,
+            last_attended_at: None,
+            member_role: None,
+            name: None,
+        },
+    ];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::Read,
+    );
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::MembersWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group.clone()));
+    db.expect_get_group_join_questions()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(join_questions.clone()));
+    db.expect_list_group_members_export()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(move |_| Ok(members.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/group/members.csv")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/csv; charset=utf-8"),
+    );
+    assert_eq!(
+        parts.headers.get(CONTENT_DISPOSITION).unwrap(),
+        &HeaderValue::from_static("attachment; filename=\"group-npq6789-members.csv\""),
+    );
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        concat!(
+            "Name,Username,Email,Role,Joined at,Last attended at,What topics interest you?\n",
+            "\"Doe, Jane\",jane,jane@example.test,Mentor,2024-01-01,2024-03-01,\"Rust, Kubernetes\"\n",
+            ",anonymous,anonymous@example.test,,2024-02-01,,\n",
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_download_csv_without_emails() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let group = sample_group_summary(group_id);
+    let members = vec![
+        GroupMemberExport {
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            email: "jane@example.test".to_string(),
+            username: "jane".to_string(),
+            join_answers: None,
+            last_attended_at: Some(Utc.with_ymd_and_hms(2024, 3, 1, 18, 0, 0).unwrap()),
+            member_role: Some("Mentor".to_string()),
+            name: Some("Doe, Jane".to_string()),
+        },
+        GroupMemberExport {
+            created_at: Utc.with_ymd_and_hms(2024, 2, 1, 10, 0, 0).unwrap(),
+            email: "anonymous@example.test".to_string(),
+            username: "anonymous".to_string(),
+            join_answers: None,
+            last_attended_at: None,
+            member_role: None,
+            name: None,
+        },
+    ];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::Read,
+    );
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::MembersWrite
+        })
+        .returning(|_, _, _, _| Ok(false));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group.clone()));
+    db.expect_get_group_join_questions()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(|_, _| Ok(vec![]));
+    db.expect_list_group_members_export()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(move |_| Ok(members.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/group/members.csv")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/csv; charset=utf-8"),
+    );
+    assert_eq!(
+        parts.headers.get(CONTENT_DISPOSITION).unwrap(),
+        &HeaderValue::from_static("attachment; filename=\"group-npq6789-members.csv\""),
+    );
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        concat!(
+            "Name,Username,Role,Joined at,Last attended at\n",
+            "\"Doe, Jane\",jane,Mentor,2024-01-01,2024-03-01\n",
+            ",anonymous,,2024-02-01,\n",
+        ),
+    );
+}
+
 #[tokio::test]
 async fn test_list_page_success() {
     // Setup identifiers and data structures
@@ -34,7 +416,42 @@ async fn test_list_page_success() {
         Some(community_id),
         Some(group_id),
     );
+    let join_request_id = Uuid::new_v4();
+    let join_request = GroupJoinRequest {
+        created_at: Utc::now(),
+        user_id: join_request_id,
+        username: "requester".to_string(),
+
+        company: None,
+        join_answers: None,
+        name: Some("Join Requester".to_string()),
+        photo_url: None,
+        title: None,
+    };
+    let leaderboard_entry = GroupMemberLeaderboardEntry {
+        check_ins: 7,
+        rank: 1,
+        rsvps: 9,
+        user_id: Uuid::new_v4(),
+        username: "regular".to_string(),
+
+        last_attended_at: Some(Utc::now()),
+        name: Some("Regular Attendee".to_string()),
+        photo_url: None,
+    };
+    let banned_user_id = Uuid::new_v4();
+    let ban = GroupBan {
+        created_at: Utc::now(),
+        reason: "Spamming other members".to_string(),
+        user_id: banned_user_id,
+        username: "spammer".to_string(),
+
+        banned_by_username: Some("organizer".to_string()),
+        name: Some("Banned User".to_string()),
+        photo_url: None,
+    };
     let member = sample_group_member();
+    let event_id = Uuid::new_v4();
     let group = sample_group_summary(group_id);
     let output = crate::templates::dashboard::group::members::GroupMembersOutput {
         members: vec![member.clone()],
@@ -77,10 +494,40 @@ async fn test_list_page_success() {
                 && filters.offset == Some(0)
         })
         .returning(move |_, _| Ok(output.clone()));
+    db.expect_list_group_events()
+        .times(1)
+        .withf(move |id, filters| {
+            *id == group_id && filters.limit == Some(DASHBOARD_PAGINATION_LIMIT)
+        })
+        .returning(move |_, _| Ok(sample_group_events(event_id, group_id)));
+    db.expect_list_event_templates()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_group_summary()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
         .returning(move |_, _| Ok(group.clone()));
+    db.expect_get_group_join_questions()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(|_, _| Ok(vec![]));
+    db.expect_list_group_join_requests()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(move |_| Ok(vec![join_request.clone()]));
+    db.expect_list_group_member_leaderboard()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(move |_| Ok(vec![leaderboard_entry.clone()]));
+    db.expect_list_group_member_roles()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec!["Mentor".to_string()]));
+    db.expect_list_group_bans()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(move |_| Ok(vec![ban.clone()]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -106,6 +553,17 @@ async fn test_list_page_success() {
     let body = std::str::from_utf8(&bytes).unwrap();
     assert!(body.contains("name=\"subject\""));
     assert!(body.contains("value=\"Test Group\""));
+    assert!(body.contains("name=\"audience\""));
+    assert!(body.contains(&format!("value=\"{event_id}\"")));
+    assert!(body.contains("3 attended, 1 missed"));
+    assert!(body.contains("25% no-show rate"));
+    assert!(body.contains("Join Requester"));
+    assert!(body.contains("Regular Attendee"));
+    assert!(body.contains(&format!(
+        "/dashboard/group/members/{join_request_id}/join-request/accept"
+    )));
+    assert!(body.contains("Spamming other members"));
+    assert!(body.contains(&format!("/dashboard/group/members/{banned_user_id}/unban")));
 }
 
 #[tokio::test]
@@ -124,6 +582,7 @@ async fn test_list_page_with_pagination_params() {
         Some(group_id),
     );
     let member = sample_group_member();
+    let event_id = Uuid::new_v4();
     let group = sample_group_summary(group_id);
     let output = crate::templates::dashboard::group::members::GroupMembersOutput {
         members: vec![member.clone()],
@@ -164,10 +623,40 @@ async fn test_list_page_with_pagination_params() {
             *id == group_id && filters.limit == Some(5) && filters.offset == Some(10)
         })
         .returning(move |_, _| Ok(output.clone()));
+    db.expect_list_group_events()
+        .times(1)
+        .withf(move |id, filters| {
+            *id == group_id && filters.limit == Some(DASHBOARD_PAGINATION_LIMIT)
+        })
+        .returning(move |_, _| Ok(sample_group_events(event_id, group_id)));
+    db.expect_list_event_templates()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_group_summary()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
         .returning(move |_, _| Ok(group.clone()));
+    db.expect_get_group_join_questions()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(|_, _| Ok(vec![]));
+    db.expect_list_group_join_requests()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_group_member_leaderboard()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_group_member_roles()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec!["Mentor".to_string()]));
+    db.expect_list_group_bans()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -208,6 +697,7 @@ async fn test_list_page_db_error() {
         Some(community_id),
         Some(group_id),
     );
+    let event_id = Uuid::new_v4();
     let group = sample_group_summary(group_id);
     // Setup database mock
     let mut db = MockDB::new();
@@ -245,10 +735,40 @@ async fn test_list_page_db_error() {
                 && filters.offset == Some(0)
         })
         .returning(move |_, _| Err(anyhow!("db error")));
+    db.expect_list_group_events()
+        .times(1)
+        .withf(move |id, filters| {
+            *id == group_id && filters.limit == Some(DASHBOARD_PAGINATION_LIMIT)
+        })
+        .returning(move |_, _| Ok(sample_group_events(event_id, group_id)));
+    db.expect_list_event_templates()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_group_summary()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
         .returning(move |_, _| Ok(group.clone()));
+    db.expect_get_group_join_questions()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(|_, _| Ok(vec![]));
+    db.expect_list_group_join_requests()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_group_member_leaderboard()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_group_member_roles()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec!["Mentor".to_string()]));
+    db.expect_list_group_bans()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -270,26 +790,75 @@ async fn test_list_page_db_error() {
     assert!(bytes.is_empty());
 }
 
-#[allow(clippy::too_many_lines)]
 #[tokio::test]
-async fn test_send_group_custom_notification_success() {
+async fn test_reject_join_request_success() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
-    let member_id1 = Uuid::new_v4();
-    let member_id2 = Uuid::new_v4();
-    let team_member_id = Uuid::new_v4();
+    let requester_id = Uuid::new_v4();
     let session_id = session::Id::default();
     let user_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(
-        session_id,
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
         user_id,
-        &auth_hash,
-        Some(community_id),
-        Some(group_id),
+        GroupPermission::MembersWrite,
     );
-    let site_settings = sample_site_settings();
+    db.expect_reject_group_join_request()
+        .times(1)
+        .withf(move |aid, gid, uid| *aid == user_id && *gid == group_id && *uid == requester_id)
+        .returning(|_, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/group/members/{requester_id}/join-request/reject"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[allow(clippy::too_many_lines)]
+#[tokio::test]
+async fn test_send_group_custom_notification_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let member_id1 = Uuid::new_v4();
+    let member_id2 = Uuid::new_v4();
+    let team_member_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let site_settings = sample_site_settings();
     let site_settings_for_notifications = site_settings.clone();
     let mut group_summary = sample_group_summary(group_id);
     group_summary.slug_pretty = Some("pretty-group".to_string());
@@ -304,9 +873,14 @@ async fn test_send_group_custom_notification_success() {
     let notification_subject = "Important Update";
     let mut expected_recipients = vec![member_id1, member_id2, team_member_id];
     expected_recipients.sort();
+    let resolved_recipients = expected_recipients.clone();
     let form_data = serde_qs::to_string(&GroupCustomNotification {
+        audience: GroupCustomNotificationAudience::All,
         body: notification_body.to_string(),
         subject: notification_subject.to_string(),
+        event_id: None,
+        inactive_months: None,
+        member_role: None,
     })
     .unwrap();
 
@@ -335,14 +909,22 @@ async fn test_send_group_custom_notification_success() {
                 && permission == GroupPermission::MembersWrite
         })
         .returning(|_, _, _, _| Ok(true));
-    db.expect_list_group_members_ids()
+    db.expect_resolve_group_custom_notification_recipient_ids()
         .times(1)
-        .withf(move |gid| *gid == group_id)
-        .returning(move |_| Ok(vec![member_id1, member_id2]));
-    db.expect_list_group_team_members_ids()
+        .withf(
+            move |gid, audience, event_id, inactive_months, member_role| {
+                *gid == group_id
+                    && audience == "all-members"
+                    && event_id.is_none()
+                    && inactive_months.is_none()
+                    && member_role.is_none()
+            },
+        )
+        .returning(move |_, _, _, _, _| Ok(resolved_recipients.clone()));
+    db.expect_count_group_custom_notifications_since()
         .times(1)
-        .withf(move |gid| *gid == group_id)
-        .returning(move |_| Ok(vec![team_member_id]));
+        .withf(move |gid, _| *gid == group_id)
+        .returning(|_, _| Ok(0));
     db.expect_get_site_settings()
         .times(1)
         .returning(move || Ok(site_settings.clone()));
@@ -412,8 +994,12 @@ async fn test_send_group_custom_notification_no_members() {
         Some(group_id),
     );
     let form_data = serde_qs::to_string(&GroupCustomNotification {
+        audience: GroupCustomNotificationAudience::All,
         body: "Body".to_string(),
         subject: "Subject".to_string(),
+        event_id: None,
+        inactive_months: None,
+        member_role: None,
     })
     .unwrap();
 
@@ -440,14 +1026,14 @@ async fn test_send_group_custom_notification_no_members() {
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
         .returning(move |_, _| Ok(group_for_db.clone()));
-    db.expect_list_group_members_ids()
+    db.expect_resolve_group_custom_notification_recipient_ids()
         .times(1)
-        .withf(move |gid| *gid == group_id)
-        .returning(move |_| Ok(vec![]));
-    db.expect_list_group_team_members_ids()
+        .withf(move |gid, audience, _, _, _| *gid == group_id && audience == "all-members")
+        .returning(|_, _, _, _, _| Ok(vec![]));
+    db.expect_count_group_custom_notifications_since()
         .times(1)
-        .withf(move |gid| *gid == group_id)
-        .returning(move |_| Ok(vec![]));
+        .withf(move |gid, _| *gid == group_id)
+        .returning(|_, _| Ok(0));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -472,3 +1058,571 @@ async fn test_send_group_custom_notification_no_members() {
     assert_eq!(parts.status, StatusCode::NO_CONTENT);
     assert!(bytes.is_empty());
 }
+
+#[allow(clippy::too_many_lines)]
+#[tokio::test]
+async fn test_send_group_custom_notification_checked_in_attendees() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let attendee_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let group_for_db = sample_group_summary(group_id);
+    let form_data = serde_qs::to_string(&GroupCustomNotification {
+        audience: GroupCustomNotificationAudience::CheckedInAttendees,
+        body: "Thanks for coming!".to_string(),
+        subject: "Thank you".to_string(),
+        event_id: Some(event_id),
+        inactive_months: None,
+        member_role: None,
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::MembersWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_for_db.clone()));
+    db.expect_resolve_group_custom_notification_recipient_ids()
+        .times(1)
+        .withf(move |gid, audience, eid, inactive_months, member_role| {
+            *gid == group_id
+                && audience == "checked-in-attendees"
+                && *eid == Some(event_id)
+                && inactive_months.is_none()
+                && member_role.is_none()
+        })
+        .returning(move |_, _, _, _, _| Ok(vec![attendee_id]));
+    db.expect_count_group_custom_notifications_since()
+        .times(1)
+        .withf(move |gid, _| *gid == group_id)
+        .returning(|_, _| Ok(4));
+    db.expect_enqueue_tracked_custom_notification()
+        .times(1)
+        .withf(move |notification, tracking| {
+            matches!(notification.kind, NotificationKind::GroupCustom)
+                && notification.recipients == vec![attendee_id]
+                && tracking.group_id == Some(group_id)
+                && tracking.recipient_count == 1
+        })
+        .returning(|_, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_send_group_custom_notification_missing_event() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let form_data = serde_qs::to_string(&GroupCustomNotification {
+        audience: GroupCustomNotificationAudience::EventAttendees,
+        body: "Body".to_string(),
+        subject: "Subject".to_string(),
+        event_id: None,
+        inactive_months: None,
+        member_role: None,
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::MembersWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        "Select an event."
+    );
+}
+
+#[tokio::test]
+async fn test_send_group_custom_notification_missing_member_role() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let form_data = serde_qs::to_string(&GroupCustomNotification {
+        audience: GroupCustomNotificationAudience::MembersWithRole,
+        body: "Body".to_string(),
+        subject: "Subject".to_string(),
+        event_id: None,
+        inactive_months: None,
+        member_role: None,
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert_eq!(String::from_utf8(bytes.to_vec()).unwrap(), "Select a role.");
+}
+
+#[tokio::test]
+async fn test_send_group_custom_notification_no_members_with_role() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let group_for_db = sample_group_summary(group_id);
+    let form_data = serde_qs::to_string(&GroupCustomNotification {
+        audience: GroupCustomNotificationAudience::MembersWithRole,
+        body: "Mentors sync".to_string(),
+        subject: "Mentors".to_string(),
+        event_id: None,
+        inactive_months: None,
+        member_role: Some("Mentor".to_string()),
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_for_db.clone()));
+    db.expect_resolve_group_custom_notification_recipient_ids()
+        .times(1)
+        .withf(
+            move |gid, audience, event_id, inactive_months, member_role| {
+                *gid == group_id
+                    && audience == "members-with-role"
+                    && event_id.is_none()
+                    && inactive_months.is_none()
+                    && member_role.as_deref() == Some("Mentor")
+            },
+        )
+        .returning(|_, _, _, _, _| Ok(vec![]));
+    db.expect_count_group_custom_notifications_since()
+        .times(1)
+        .withf(move |gid, _| *gid == group_id)
+        .returning(|_, _| Ok(0));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        "No members with this role and verified email addresses."
+    );
+}
+
+#[tokio::test]
+async fn test_send_group_custom_notification_no_inactive_members() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let group_for_db = sample_group_summary(group_id);
+    let form_data = serde_qs::to_string(&GroupCustomNotification {
+        audience: GroupCustomNotificationAudience::InactiveMembers,
+        body: "We miss you!".to_string(),
+        subject: "Come back".to_string(),
+        event_id: None,
+        inactive_months: Some(6),
+        member_role: None,
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::MembersWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_for_db.clone()));
+    db.expect_resolve_group_custom_notification_recipient_ids()
+        .times(1)
+        .withf(
+            move |gid, audience, event_id, inactive_months, member_role| {
+                *gid == group_id
+                    && audience == "inactive-members"
+                    && event_id.is_none()
+                    && *inactive_months == Some(6)
+                    && member_role.is_none()
+            },
+        )
+        .returning(|_, _, _, _, _| Ok(vec![]));
+    db.expect_count_group_custom_notifications_since()
+        .times(1)
+        .withf(move |gid, _| *gid == group_id)
+        .returning(|_, _| Ok(0));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        "No inactive members with verified email addresses."
+    );
+}
+
+#[tokio::test]
+async fn test_send_group_custom_notification_throttled() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let group_for_db = sample_group_summary(group_id);
+    let form_data = serde_qs::to_string(&GroupCustomNotification {
+        audience: GroupCustomNotificationAudience::All,
+        body: "Body".to_string(),
+        subject: "Subject".to_string(),
+        event_id: None,
+        inactive_months: None,
+        member_role: None,
+    })
+    .unwrap();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::MembersWrite
+        })
+        .returning(|_, _, _, _| Ok(true));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_for_db.clone()));
+    db.expect_resolve_group_custom_notification_recipient_ids()
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(vec![Uuid::new_v4()]));
+    db.expect_count_group_custom_notifications_since()
+        .times(1)
+        .withf(move |gid, since| {
+            *gid == group_id && *since < chrono::Utc::now() - chrono::Duration::hours(23)
+        })
+        .returning(|_, _| Ok(5));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/notifications")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, _) = response.into_parts();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_unban_member_success() {
+    // Setup identifiers and data structures
+    let banned_user_id = Uuid::new_v4();
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_unban_group_member()
+        .times(1)
+        .withf(move |aid, cid, gid, uid| {
+            *aid == user_id && *cid == community_id && *gid == group_id && *uid == banned_user_id
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/group/members/{banned_user_id}/unban"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_update_member_role_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let member_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::MembersWrite,
+    );
+    db.expect_update_group_member_role()
+        .times(1)
+        .withf(move |aid, gid, uid, update| {
+            *aid == user_id
+                && *gid == group_id
+                && *uid == member_id
+                && update.member_role.as_deref() == Some("Mentor")
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/dashboard/group/members/{member_id}/role"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("member_role=Mentor"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
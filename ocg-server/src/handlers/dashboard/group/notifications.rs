@@ -0,0 +1,75 @@
+//! HTTP handlers for group dashboard custom notifications.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{RawQuery, State},
+    http::HeaderName,
+    response::{Html, IntoResponse},
+};
+use garde::Validate;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{error::HandlerError, extractors::SelectedGroupId},
+    router::serde_qs_config,
+    templates::dashboard::notifications::{
+        CustomNotificationsFilters, ListPage, NotificationsScope,
+    },
+    types::pagination::{self, NavigationLinks},
+};
+
+#[cfg(test)]
+mod tests;
+
+// URLs used by the dashboard page and tab partial
+const DASHBOARD_URL: &str = "/dashboard/group?tab=notifications";
+const PARTIAL_URL: &str = "/dashboard/group/notifications";
+
+// Pages handlers.
+
+/// Displays the group custom notifications list.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    RawQuery(raw_query): RawQuery,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare list page content
+    let (filters, template) =
+        prepare_list_page(&db, group_id, raw_query.as_deref().unwrap_or_default()).await?;
+
+    // Prepare response headers
+    let url = pagination::build_url(DASHBOARD_URL, &filters)?;
+    let headers = [(HeaderName::from_static("hx-push-url"), url)];
+
+    Ok((headers, Html(template.render()?)))
+}
+
+// Helpers.
+
+/// Prepares the custom notifications list page for the group dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    group_id: Uuid,
+    raw_query: &str,
+) -> Result<(CustomNotificationsFilters, ListPage), HandlerError> {
+    // Fetch custom notifications
+    let filters: CustomNotificationsFilters = serde_qs_config().deserialize_str(raw_query)?;
+    filters.validate()?;
+    let results = db.list_group_custom_notifications(group_id, &filters).await?;
+
+    // Prepare template
+    let navigation_links =
+        NavigationLinks::from_filters(&filters, results.total, DASHBOARD_URL, PARTIAL_URL)?;
+    let template = ListPage::new(
+        NotificationsScope::Group,
+        &filters,
+        results,
+        navigation_links,
+    );
+
+    Ok((filters, template))
+}
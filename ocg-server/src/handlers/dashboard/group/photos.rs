@@ -0,0 +1,94 @@
+//! HTTP handlers for the attendee photos section in the group dashboard.
+
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId},
+    },
+    templates::dashboard::group::photos,
+    types::permissions::GroupPermission,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Displays the photos shared by attendees of a specific event.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Fetch event summary and photos
+    let (can_manage_events, event, photos) = tokio::try_join!(
+        db.user_has_group_permission(
+            &community_id,
+            &group_id,
+            &user.user_id,
+            GroupPermission::EventsWrite
+        ),
+        db.get_event_summary(community_id, group_id, event_id),
+        db.list_event_photos(group_id, event_id)
+    )?;
+
+    // Prepare template
+    let template = photos::ListPage {
+        can_manage_events,
+        event,
+        photos,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Approves a photo shared by an attendee, adding it to the event gallery.
+#[instrument(skip_all, err)]
+pub(crate) async fn approve(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path((event_id, event_photo_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.approve_event_photo(user.user_id, group_id, event_id, event_photo_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-photos")],
+    )
+        .into_response())
+}
+
+/// Rejects a photo shared by an attendee.
+#[instrument(skip_all, err)]
+pub(crate) async fn reject(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path((event_id, event_photo_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.reject_event_photo(user.user_id, group_id, event_id, event_photo_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-photos")],
+    )
+        .into_response())
+}
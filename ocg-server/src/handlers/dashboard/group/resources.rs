@@ -0,0 +1,236 @@
+//! HTTP handlers for managing the resources attached to events in the group dashboard.
+
+use anyhow::Context;
+use askama::Template;
+use axum::{
+    extract::{Multipart, Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{
+        error::HandlerError,
+        extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId},
+    },
+    services::images::{DynImageStorage, NewImage},
+    templates::dashboard::group::resources::{self, NewEventResource},
+    types::permissions::GroupPermission,
+    util::compute_hash,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Maximum payload size allowed for event resources uploads (10 MiB).
+pub(crate) const MAX_EVENT_RESOURCE_SIZE_BYTES: usize = 10 * 1024 * 1024;
+
+// Pages handlers.
+
+/// Displays the resources attached to a specific event.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    SelectedCommunityId(community_id): SelectedCommunityId,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Fetch event summary and resources
+    let (can_manage_events, event, resources) = tokio::try_join!(
+        db.user_has_group_permission(
+            &community_id,
+            &group_id,
+            &user.user_id,
+            GroupPermission::EventsWrite
+        ),
+        db.get_event_summary(community_id, group_id, event_id),
+        db.list_event_resources(event_id)
+    )?;
+
+    // Prepare template
+    let template = resources::ListPage {
+        can_manage_events,
+        event,
+        resources,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Removes a resource from an event.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path((event_id, event_resource_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_event_resource(user.user_id, group_id, event_id, event_resource_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-resources")],
+    )
+        .into_response())
+}
+
+/// Uploads a file and attaches it to an event.
+#[instrument(skip_all, err)]
+pub(crate) async fn upload(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    State(image_storage): State<DynImageStorage>,
+    Path(event_id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Extract file name and bytes from multipart payload
+    let mut file_name: Option<String> = None;
+    let mut data: Option<bytes::Bytes> = None;
+
+    while let Ok(Some(field)) = multipart.next_field().await {
+        if field.name() == Some("file") {
+            file_name = field.file_name().map(str::to_string);
+            data = Some(field.bytes().await.context("error reading uploaded file")?);
+        }
+    }
+
+    // Ensure we have a file
+    let (Some(file_name), Some(data)) = (file_name, data) else {
+        return Ok((StatusCode::BAD_REQUEST, "missing file in upload payload").into_response());
+    };
+    let name = display_name(&file_name);
+    if name.is_empty() || data.is_empty() {
+        return Ok((StatusCode::BAD_REQUEST, "missing file in upload payload").into_response());
+    }
+
+    // Enforce maximum file size
+    if data.len() > MAX_EVENT_RESOURCE_SIZE_BYTES {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "file exceeds 10MB limit").into_response());
+    }
+
+    // Detect the file format from its extension and check the contents match
+    let Some(format) = ResourceFormat::from_file_name(name) else {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "only PDF, PowerPoint, OpenDocument and Keynote files are supported",
+        )
+            .into_response());
+    };
+    if !format.matches_contents(data.as_ref()) {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "file contents do not match its extension",
+        )
+            .into_response());
+    }
+
+    // Store file using the configured storage provider
+    let hash = compute_hash(data.as_ref());
+    let new_image = NewImage {
+        bytes: data.as_ref(),
+        content_type: format.mime_type(),
+        file_name: &format!("{hash}.{}", format.extension()),
+        user_id: user.user_id,
+    };
+    image_storage.save(&new_image).await?;
+
+    // Attach the file to the event
+    let resource = NewEventResource {
+        content_type: new_image.content_type.to_string(),
+        file_name: new_image.file_name.to_string(),
+        name: name.to_string(),
+        size_bytes: i64::try_from(data.len()).context("invalid file size")?,
+    };
+    db.add_event_resource(user.user_id, group_id, event_id, &resource)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-resources")],
+    )
+        .into_response())
+}
+
+// Helpers.
+
+/// Returns the file name without any path components sent by the browser.
+fn display_name(file_name: &str) -> &str {
+    file_name.rsplit(['/', '\\']).next().unwrap_or_default().trim()
+}
+
+// Types.
+
+/// File formats accepted as event resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResourceFormat {
+    /// Apple Keynote presentation.
+    Key,
+    /// OpenDocument presentation.
+    Odp,
+    /// Portable Document Format document.
+    Pdf,
+    /// Legacy Microsoft PowerPoint presentation.
+    Ppt,
+    /// Microsoft PowerPoint presentation.
+    Pptx,
+}
+
+impl ResourceFormat {
+    /// Returns the format matching the extension of the provided file name.
+    fn from_file_name(file_name: &str) -> Option<Self> {
+        let (_, extension) = file_name.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "key" => Some(Self::Key),
+            "odp" => Some(Self::Odp),
+            "pdf" => Some(Self::Pdf),
+            "ppt" => Some(Self::Ppt),
+            "pptx" => Some(Self::Pptx),
+            _ => None,
+        }
+    }
+
+    /// Returns the canonical extension for the format.
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Key => "key",
+            Self::Odp => "odp",
+            Self::Pdf => "pdf",
+            Self::Ppt => "ppt",
+            Self::Pptx => "pptx",
+        }
+    }
+
+    /// Checks the file signature matches the format.
+    fn matches_contents(self, bytes: &[u8]) -> bool {
+        const OLE_SIGNATURE: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+        const PDF_SIGNATURE: &[u8] = b"%PDF-";
+        const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+        match self {
+            Self::Key | Self::Odp | Self::Pptx => bytes.starts_with(ZIP_SIGNATURE),
+            Self::Pdf => bytes.starts_with(PDF_SIGNATURE),
+            Self::Ppt => bytes.starts_with(OLE_SIGNATURE),
+        }
+    }
+
+    /// Returns the MIME type associated with the format.
+    fn mime_type(self) -> &'static str {
+        match self {
+            Self::Key => "application/vnd.apple.keynote",
+            Self::Odp => "application/vnd.oasis.opendocument.presentation",
+            Self::Pdf => "application/pdf",
+            Self::Ppt => "application/vnd.ms-powerpoint",
+            Self::Pptx => {
+                "application/vnd.openxmlformats-officedocument.presentationml.presentation"
+            }
+        }
+    }
+}
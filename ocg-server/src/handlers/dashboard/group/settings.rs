@@ -9,14 +9,19 @@ use axum::{
 };
 use tracing::instrument;
 
+use uuid::Uuid;
+
 use crate::{
-    config::PaymentsConfig,
+    config::{MeetingsConfig, PaymentsConfig},
     db::DynDB,
     handlers::{
         error::HandlerError,
         extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId, ValidatedFormQs},
     },
-    templates::dashboard::group::settings::{self, GroupUpdate},
+    templates::dashboard::group::settings::{
+        self, GroupDiscordWebhookUpdate, GroupJoinQuestionsUpdate, GroupOwnershipTransferInput,
+        GroupUpdate, GroupWelcomeMessageUpdate, GroupZoomAccount, GroupZoomCredentialsUpdate,
+    },
     types::permissions::GroupPermission,
 };
 
@@ -32,10 +37,24 @@ pub(crate) async fn update_page(
     SelectedCommunityId(community_id): SelectedCommunityId,
     SelectedGroupId(group_id): SelectedGroupId,
     State(db): State<DynDB>,
+    State(meetings_cfg): State<Option<MeetingsConfig>>,
     State(payments_cfg): State<Option<PaymentsConfig>>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Prepare template
-    let (can_manage_settings, group, has_child_links, categories, parent_options, regions) = tokio::try_join!(
+    let zoom_enabled = meetings_cfg.as_ref().is_some_and(MeetingsConfig::zoom_enabled);
+    let (
+        can_manage_settings,
+        group,
+        has_child_links,
+        categories,
+        discord_webhook_url,
+        ownership,
+        parent_options,
+        regions,
+        topics,
+        welcome_message,
+        zoom_account,
+    ) = tokio::try_join!(
         db.user_has_group_permission(
             &community_id,
             &group_id,
@@ -45,17 +64,33 @@ pub(crate) async fn update_page(
         db.get_group_full(community_id, group_id),
         db.group_has_child_links(community_id, group_id),
         db.list_group_categories(community_id),
+        db.get_group_discord_webhook_url(group_id),
+        db.get_group_ownership(group_id),
         db.list_group_parent_options(community_id, user.user_id, Some(group_id)),
-        db.list_regions(community_id)
+        db.list_regions(community_id),
+        db.list_topics(community_id),
+        db.get_group_welcome_message(group_id),
+        get_zoom_account(&db, group_id, zoom_enabled)
     )?;
+    let is_owner = ownership
+        .owner
+        .as_ref()
+        .is_some_and(|owner| owner.user_id == user.user_id);
     let template = settings::UpdatePage {
         can_manage_settings,
         categories,
+        discord_webhook_url,
         group,
         has_child_links,
+        is_owner,
+        ownership,
         parent_options,
         payments_enabled: payments_cfg.is_some(),
         regions,
+        topics,
+        welcome_message,
+        zoom_account,
+        zoom_enabled,
     };
 
     Ok(Html(template.render()?))
@@ -78,3 +113,124 @@ pub(crate) async fn update(
 
     Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
 }
+
+/// Sets or clears the Discord webhook used to post group notifications.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_discord_webhook(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedFormQs(webhook): ValidatedFormQs<GroupDiscordWebhookUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Update webhook in database
+    db.update_group_discord_webhook(user.user_id, group_id, &webhook)
+        .await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Cancels the pending transfer of the group ownership.
+#[instrument(skip_all, err)]
+pub(crate) async fn cancel_ownership_transfer(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Cancel transfer in database
+    db.cancel_group_ownership_transfer(user.user_id, group_id).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Deletes the group's Zoom credentials, so its meetings use the global account.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete_zoom_credentials(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Delete credentials from database
+    db.delete_group_zoom_credentials(user.user_id, group_id).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Requests the transfer of the group ownership to another team member.
+#[instrument(skip_all, err)]
+pub(crate) async fn request_ownership_transfer(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedFormQs(transfer): ValidatedFormQs<GroupOwnershipTransferInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Request transfer in database
+    db.request_group_ownership_transfer(user.user_id, group_id, transfer.user_id)
+        .await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Replaces the questions asked to users joining the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_join_questions(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedFormQs(join_questions): ValidatedFormQs<GroupJoinQuestionsUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Update join questions in database
+    db.update_group_join_questions(user.user_id, group_id, &join_questions)
+        .await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Sets or clears the message included in the welcome email sent to new members.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_welcome_message(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedFormQs(welcome_message): ValidatedFormQs<GroupWelcomeMessageUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Update welcome message in database
+    db.update_group_welcome_message(user.user_id, group_id, &welcome_message)
+        .await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+/// Creates or updates the Zoom credentials used by the group's meetings.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_zoom_credentials(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    State(meetings_cfg): State<Option<MeetingsConfig>>,
+    ValidatedFormQs(credentials): ValidatedFormQs<GroupZoomCredentialsUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Group credentials are only used when Zoom meetings are enabled
+    if !meetings_cfg.as_ref().is_some_and(MeetingsConfig::zoom_enabled) {
+        return Err(HandlerError::NotFound);
+    }
+
+    // Update credentials in database
+    db.update_group_zoom_credentials(user.user_id, group_id, &credentials)
+        .await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
+// Helpers.
+
+/// Gets the group's Zoom account when Zoom meetings are enabled.
+pub(super) async fn get_zoom_account(
+    db: &DynDB,
+    group_id: Uuid,
+    zoom_enabled: bool,
+) -> Result<Option<GroupZoomAccount>> {
+    if !zoom_enabled {
+        return Ok(None);
+    }
+    db.get_group_zoom_account(group_id).await
+}
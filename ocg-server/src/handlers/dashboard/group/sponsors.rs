@@ -19,7 +19,9 @@ use crate::{
         extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId, ValidatedForm},
     },
     router::serde_qs_config,
-    templates::dashboard::group::sponsors::{self, GroupSponsorsFilters, Sponsor},
+    templates::dashboard::group::sponsors::{
+        self, GroupSponsorTierInput, GroupSponsorsFilters, Sponsor,
+    },
     types::{
         pagination::{self, NavigationLinks},
         permissions::GroupPermission,
@@ -44,17 +46,19 @@ pub(crate) async fn add_page(
     State(db): State<DynDB>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Prepare template
-    let can_manage_sponsors = db
-        .user_has_group_permission(
+    let (can_manage_sponsors, tiers) = tokio::try_join!(
+        db.user_has_group_permission(
             &community_id,
             &group_id,
             &user.user_id,
-            GroupPermission::SponsorsWrite,
-        )
-        .await?;
+            GroupPermission::SponsorsWrite
+        ),
+        db.list_group_sponsor_tiers(group_id)
+    )?;
     let template = sponsors::AddPage {
         can_manage_sponsors,
         group_id,
+        tiers,
     };
 
     Ok(Html(template.render()?))
@@ -96,19 +100,21 @@ pub(crate) async fn update_page(
     Path(group_sponsor_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Prepare template
-    let (can_manage_sponsors, sponsor) = tokio::try_join!(
+    let (can_manage_sponsors, sponsor, tiers) = tokio::try_join!(
         db.user_has_group_permission(
             &community_id,
             &group_id,
             &user.user_id,
             GroupPermission::SponsorsWrite
         ),
-        db.get_group_sponsor(group_id, group_sponsor_id)
+        db.get_group_sponsor(group_id, group_sponsor_id),
+        db.list_group_sponsor_tiers(group_id)
     )?;
     let template = sponsors::UpdatePage {
         can_manage_sponsors,
         group_id,
         sponsor,
+        tiers,
     };
 
     Ok(Html(template.render()?))
@@ -134,6 +140,24 @@ pub(crate) async fn add(
         .into_response())
 }
 
+/// Adds a new sponsor tier to the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn add_tier(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    ValidatedForm(tier): ValidatedForm<GroupSponsorTierInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Add sponsor tier to database
+    db.add_group_sponsor_tier(user.user_id, group_id, &tier).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    )
+        .into_response())
+}
+
 /// Deletes a sponsor from the database.
 #[instrument(skip_all, err)]
 pub(crate) async fn delete(
@@ -152,6 +176,24 @@ pub(crate) async fn delete(
     ))
 }
 
+/// Deletes a sponsor tier from the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete_tier(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_sponsor_tier_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Delete the sponsor tier from database
+    db.delete_group_sponsor_tier(user.user_id, group_id, group_sponsor_tier_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    ))
+}
+
 /// Updates an existing sponsor in the database.
 #[instrument(skip_all, err)]
 pub(crate) async fn update(
@@ -190,6 +232,25 @@ pub(crate) async fn update_featured(
     ))
 }
 
+/// Updates an existing sponsor tier in the group.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_tier(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path(group_sponsor_tier_id): Path<Uuid>,
+    ValidatedForm(tier): ValidatedForm<GroupSponsorTierInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Update sponsor tier in database
+    db.update_group_sponsor_tier(user.user_id, group_id, group_sponsor_tier_id, &tier)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-group-dashboard-table")],
+    ))
+}
+
 // Types.
 
 /// Sponsor featured flag input for list toggle operations.
@@ -214,14 +275,15 @@ pub(crate) async fn prepare_list_page(
     // Fetch sponsors
     let filters: GroupSponsorsFilters = serde_qs_config().deserialize_str(raw_query)?;
     filters.validate()?;
-    let (can_manage_sponsors, results) = tokio::try_join!(
+    let (can_manage_sponsors, results, tiers) = tokio::try_join!(
         db.user_has_group_permission(
             &community_id,
             &group_id,
             &user_id,
             GroupPermission::SponsorsWrite
         ),
-        db.list_group_sponsors(group_id, &filters, false)
+        db.list_group_sponsors(group_id, &filters, false),
+        db.list_group_sponsor_tiers(group_id)
     )?;
 
     // Prepare template
@@ -231,6 +293,7 @@ pub(crate) async fn prepare_list_page(
         can_manage_sponsors,
         navigation_links,
         sponsors: results.sponsors,
+        tiers,
         total: results.total,
         limit: filters.limit,
         offset: filters.offset,
@@ -16,7 +16,7 @@ use crate::{
     handlers::{dashboard::group::sponsors::SponsorFeatured, tests::*},
     services::notifications::MockNotificationsManager,
     templates::dashboard::DASHBOARD_PAGINATION_LIMIT,
-    types::permissions::GroupPermission,
+    types::{group::SponsorLogoSize, permissions::GroupPermission},
 };
 
 #[tokio::test]
@@ -134,6 +134,10 @@ async fn test_add_page_success() {
         user_id,
         GroupPermission::SponsorsWrite,
     );
+    db.expect_list_group_sponsor_tiers()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![sample_group_sponsor_tier()]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -217,6 +221,98 @@ async fn test_add_success() {
     );
 }
 
+#[tokio::test]
+async fn test_add_tier_invalid_body() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::SponsorsWrite,
+    );
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/sponsors/tiers/add")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("name=%20%20&order=-1"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_tier_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::SponsorsWrite,
+    );
+    db.expect_add_group_sponsor_tier()
+        .times(1)
+        .withf(move |actor_user_id, id, tier| {
+            *actor_user_id == user_id
+                && *id == group_id
+                && tier.logo_size == SponsorLogoSize::Large
+                && tier.name == "Platinum"
+                && tier.order == 1
+        })
+        .returning(|_, _, _| Ok(Uuid::new_v4()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/sponsors/tiers/add")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("name=Platinum&order=1&logo_size=large"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::CREATED,
+        "refresh-group-dashboard-table",
+    );
+}
+
 #[tokio::test]
 async fn test_delete_db_error() {
     // Setup identifiers and data structures
@@ -316,6 +412,57 @@ async fn test_delete_success() {
     );
 }
 
+#[tokio::test]
+async fn test_delete_tier_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let group_sponsor_tier_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::SponsorsWrite,
+    );
+    db.expect_delete_group_sponsor_tier()
+        .times(1)
+        .withf(move |actor_user_id, id, tier_id| {
+            *actor_user_id == user_id && *id == group_id && *tier_id == group_sponsor_tier_id
+        })
+        .returning(|_, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!(
+            "/dashboard/group/sponsors/tiers/{group_sponsor_tier_id}/delete"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
+
 #[tokio::test]
 async fn test_list_page_db_error() {
     // Setup identifiers and data structures
@@ -350,6 +497,10 @@ async fn test_list_page_db_error() {
                 && !*full_list
         })
         .returning(|_, _, _| Err(anyhow!("db error")));
+    db.expect_list_group_sponsor_tiers()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![sample_group_sponsor_tier()]));
 
     // Setup router and send request
     let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
@@ -409,6 +560,10 @@ async fn test_list_page_success() {
                 && !*full_list
         })
         .returning(move |_, _, _| Ok(output.clone()));
+    db.expect_list_group_sponsor_tiers()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![sample_group_sponsor_tier()]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -470,6 +625,10 @@ async fn test_list_page_with_pagination_params() {
             *id == group_id && filters.limit == Some(5) && filters.offset == Some(10) && !*full_list
         })
         .returning(move |_, _, _| Ok(output.clone()));
+    db.expect_list_group_sponsor_tiers()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![sample_group_sponsor_tier()]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -734,6 +893,10 @@ async fn test_update_page_success() {
         .times(1)
         .withf(move |id, sponsor_id| *id == group_id && *sponsor_id == group_sponsor_id)
         .returning(move |_, _| Ok(sponsor.clone()));
+    db.expect_list_group_sponsor_tiers()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(vec![sample_group_sponsor_tier()]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -819,3 +982,108 @@ async fn test_update_success() {
         "refresh-group-dashboard-table",
     );
 }
+
+#[tokio::test]
+async fn test_update_tier_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let group_sponsor_tier_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::SponsorsWrite,
+    );
+    db.expect_update_group_sponsor_tier()
+        .times(1)
+        .withf(move |actor_user_id, id, tier_id, _| {
+            *actor_user_id == user_id && *id == group_id && *tier_id == group_sponsor_tier_id
+        })
+        .returning(|_, _, _, _| Err(anyhow!("db error")));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/group/sponsors/tiers/{group_sponsor_tier_id}/update"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("name=Gold&order=2&logo_size=medium"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_tier_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let group_sponsor_tier_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    expect_authenticated_group_session(&mut db, session_id, user_id, community_id, group_id);
+    expect_group_permission(
+        &mut db,
+        community_id,
+        group_id,
+        user_id,
+        GroupPermission::SponsorsWrite,
+    );
+    db.expect_update_group_sponsor_tier()
+        .times(1)
+        .withf(move |actor_user_id, id, tier_id, tier| {
+            *actor_user_id == user_id
+                && *id == group_id
+                && *tier_id == group_sponsor_tier_id
+                && tier.logo_size == SponsorLogoSize::Small
+                && tier.name == "Community"
+                && tier.order == 3
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/group/sponsors/tiers/{group_sponsor_tier_id}/update"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("name=Community&order=3&logo_size=small"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::NO_CONTENT,
+        "refresh-group-dashboard-table",
+    );
+}
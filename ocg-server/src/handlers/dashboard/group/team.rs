@@ -30,7 +30,8 @@ use crate::{
         pagination::{self, NavigationLinks},
         permissions::GroupPermission,
     },
-    util::base_url_without_trailing_slash,
+    util::{base_url_without_trailing_slash, compute_hash},
+    validation::MAX_LEN_M,
 };
 
 #[cfg(test)]
@@ -76,7 +77,7 @@ pub(crate) async fn list_page(
 
 // Actions handlers.
 
-/// Adds a user to the group team.
+/// Adds a user to the group team, or invites them by email.
 #[instrument(skip_all, err)]
 pub(crate) async fn add(
     CurrentUser(user): CurrentUser,
@@ -87,9 +88,35 @@ pub(crate) async fn add(
     State(server_cfg): State<HttpServerConfig>,
     ValidatedForm(member): ValidatedForm<NewTeamMember>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    // Add team member to database using provided role
-    db.add_group_team_member(user.user_id, group_id, member.user_id, &member.role)
-        .await?;
+    // Add team member to database using provided role, storing a link to
+    // accept the invitation when inviting by email
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let (invited_user_id, link) = match (member.user_id, member.email) {
+        (Some(user_id), None) => {
+            db.add_group_team_member(user.user_id, group_id, user_id, &member.role)
+                .await?;
+            (
+                user_id,
+                format!("{base_url}/dashboard/user?tab=invitations"),
+            )
+        }
+        (None, Some(email)) => {
+            let token = Uuid::new_v4().simple().to_string();
+            let token_hash = compute_hash(token.as_bytes());
+            let user_id = db
+                .invite_group_team_member(user.user_id, group_id, &email, &member.role, &token_hash)
+                .await?;
+            (
+                user_id,
+                format!("{base_url}/dashboard/user/invitations/group-team/{token}"),
+            )
+        }
+        _ => {
+            return Ok(
+                (StatusCode::BAD_REQUEST, "provide exactly one invite target").into_response(),
+            );
+        }
+    };
 
     // Enqueue invitation email notification best-effort
     if let Err(err) = async {
@@ -99,16 +126,13 @@ pub(crate) async fn add(
         )?;
         let template_data = GroupTeamInvitation {
             group,
-            link: format!(
-                "{}/dashboard/user?tab=invitations",
-                base_url_without_trailing_slash(&server_cfg.base_url)
-            ),
+            link,
             theme: site_settings.theme,
         };
         let notification = NewNotification {
             attachments: vec![],
             kind: NotificationKind::GroupTeamInvitation,
-            recipients: vec![member.user_id],
+            recipients: vec![invited_user_id],
             template_data: Some(serde_json::to_value(&template_data)?),
         };
         notifications_manager.enqueue(&notification).await
@@ -119,7 +143,7 @@ pub(crate) async fn add(
             error = %err,
             %community_id,
             %group_id,
-            user_id = %member.user_id,
+            user_id = %invited_user_id,
             "failed to enqueue group team invitation notification"
         );
     }
@@ -127,7 +151,8 @@ pub(crate) async fn add(
     Ok((
         StatusCode::CREATED,
         [("HX-Trigger", "refresh-group-dashboard-table")],
-    ))
+    )
+        .into_response())
 }
 
 /// Deletes a user from the group team.
@@ -196,12 +221,15 @@ pub(crate) async fn update_role(
 /// Data needed to add a new team member.
 #[derive(Debug, Deserialize, Validate)]
 pub(crate) struct NewTeamMember {
+    /// Email address to invite, for users not selected from the search.
+    #[garde(email, length(max = MAX_LEN_M))]
+    email: Option<String>,
     /// Team role.
     #[garde(skip)]
     role: GroupRole,
     /// User identifier.
     #[garde(skip)]
-    user_id: Uuid,
+    user_id: Option<Uuid>,
 }
 
 /// Data needed to update a team member role.
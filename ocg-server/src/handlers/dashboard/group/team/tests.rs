@@ -300,10 +300,11 @@ async fn test_add_success() {
         Some(group_id),
     );
     let form = NewTeamMember {
+        email: None,
         role: GroupRole::Admin,
-        user_id: new_member_id,
+        user_id: Some(new_member_id),
     };
-    let body = format!("role={}&user_id={}", form.role, form.user_id);
+    let body = format!("role={}&user_id={new_member_id}", form.role);
     let group_summary = sample_group_summary(group_id);
     let group_summary_for_db = group_summary.clone();
     let site_settings = sample_site_settings();
@@ -385,6 +386,159 @@ async fn test_add_success() {
     );
 }
 
+#[tokio::test]
+async fn test_add_by_email_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let invited_user_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let body = format!("email=new%40example.com&role={}", GroupRole::EventsManager);
+    let group_summary = sample_group_summary(group_id);
+    let site_settings = sample_site_settings();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::TeamWrite
+        })
+        .returning(move |_, _, _, _| Ok(true));
+    db.expect_add_group_team_member().times(0);
+    db.expect_invite_group_team_member()
+        .times(1)
+        .withf(move |actor_user_id, id, email, role, token_hash| {
+            *actor_user_id == user_id
+                && *id == group_id
+                && email == "new@example.com"
+                && role == &GroupRole::EventsManager
+                && !token_hash.is_empty()
+        })
+        .returning(move |_, _, _, _, _| Ok(invited_user_id));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(move || Ok(site_settings.clone()));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue()
+        .times(1)
+        .withf(move |notification| {
+            matches!(notification.kind, NotificationKind::GroupTeamInvitation)
+                && notification.recipients == vec![invited_user_id]
+                && notification.template_data.as_ref().is_some_and(|value| {
+                    from_value::<GroupTeamInvitation>(value.clone()).is_ok_and(|template| {
+                        template.link.starts_with("/dashboard/user/invitations/group-team/")
+                    })
+                })
+        })
+        .returning(|_| Box::pin(async { Ok(()) }));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/team/add")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(body))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(
+        &parts,
+        &bytes,
+        StatusCode::CREATED,
+        "refresh-group-dashboard-table",
+    );
+}
+
+#[tokio::test]
+async fn test_add_without_target_bad_request() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(
+        session_id,
+        user_id,
+        &auth_hash,
+        Some(community_id),
+        Some(group_id),
+    );
+    let body = format!("role={}", GroupRole::Admin);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_user_has_group_permission()
+        .times(1)
+        .withf(move |cid, gid, uid, permission| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && permission == GroupPermission::TeamWrite
+        })
+        .returning(move |_, _, _, _| Ok(true));
+    db.expect_add_group_team_member().times(0);
+    db.expect_invite_group_team_member().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/dashboard/group/team/add")
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(body))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, _) = response.into_parts();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn test_add_forbidden_when_group_team_management_is_restricted() {
     // Setup identifiers and data structures
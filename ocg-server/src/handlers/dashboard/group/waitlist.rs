@@ -3,6 +3,7 @@
 use askama::Template;
 use axum::{
     extract::{Path, RawQuery, State},
+    http::StatusCode,
     response::{Html, IntoResponse},
 };
 use garde::Validate;
@@ -12,6 +13,7 @@ use uuid::Uuid;
 use crate::{
     db::DynDB,
     handlers::{
+        auth::user_has_group_event_permission,
         error::HandlerError,
         extractors::{CurrentUser, SelectedCommunityId, SelectedGroupId},
     },
@@ -43,9 +45,11 @@ pub(crate) async fn list_page(
         serde_qs_config().deserialize_str(raw_query.as_deref().unwrap_or_default())?;
     filters.validate()?;
     let (can_manage_events, event, search_waitlist_results) = tokio::try_join!(
-        db.user_has_group_permission(
+        user_has_group_event_permission(
+            &db,
             &community_id,
             &group_id,
+            &event_id,
             &user.user_id,
             GroupPermission::EventsWrite
         ),
@@ -80,3 +84,23 @@ pub(crate) async fn list_page(
 
     Ok(Html(template.render()?))
 }
+
+// Actions handlers.
+
+/// Removes a user from the waiting list of a specific event.
+#[instrument(skip_all, err)]
+pub(crate) async fn remove(
+    CurrentUser(user): CurrentUser,
+    SelectedGroupId(group_id): SelectedGroupId,
+    State(db): State<DynDB>,
+    Path((event_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.remove_event_waitlist_user(user.user_id, group_id, event_id, user_id)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-event-waitlist")],
+    )
+        .into_response())
+}
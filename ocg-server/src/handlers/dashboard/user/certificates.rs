@@ -0,0 +1,78 @@
+//! HTTP handlers for the certificates section in the user dashboard.
+
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{error::HandlerError, extractors::CurrentUser},
+    services::{certificates::build_attendance_certificate_pdf, images::DynImageStorage},
+    templates::dashboard::user::certificates,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Pages handlers.
+
+/// Returns the certificates list page for the user dashboard.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    CurrentUser(user): CurrentUser,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare list page content
+    let template = prepare_list_page(&db, user.user_id).await?;
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Downloads the certificate of attendance of the user for an event.
+#[instrument(skip_all, err)]
+pub(crate) async fn download(
+    CurrentUser(user): CurrentUser,
+    State(db): State<DynDB>,
+    State(image_storage): State<DynImageStorage>,
+    Path(event_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get certificate, only available to attendees who checked in
+    let Some(certificate) = db.get_user_attendance_certificate(user.user_id, event_id).await?
+    else {
+        return Err(HandlerError::NotFound);
+    };
+
+    // Build certificate PDF
+    let pdf = build_attendance_certificate_pdf(&image_storage, &certificate).await;
+    let file_name = certificate.file_name();
+
+    Ok((
+        [
+            (CONTENT_TYPE, "application/pdf".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            ),
+        ],
+        pdf,
+    ))
+}
+
+// Helpers.
+
+/// Prepares the certificates list page for the user dashboard.
+pub(crate) async fn prepare_list_page(
+    db: &DynDB,
+    user_id: Uuid,
+) -> Result<certificates::ListPage, HandlerError> {
+    let certificates = db.list_user_attendance_certificates(user_id).await?;
+
+    Ok(certificates::ListPage { certificates })
+}
@@ -77,7 +77,7 @@ pub(crate) async fn cancel_attendance(
     // Validate the row still represents cancelable attendance
     let attendance = db.get_event_attendance(community_id, event_id, user.user_id).await?;
     match attendance.status {
-        EventAttendanceStatus::Attendee => {}
+        EventAttendanceStatus::Attendee | EventAttendanceStatus::WaitlistConfirmationPending => {}
         EventAttendanceStatus::RegistrationQuestionsPending => {
             // Pending registrations on ticketed events are owned by the checkout hold flow
             let event = db.get_event_summary_by_id(community_id, event_id).await?;
@@ -189,10 +189,13 @@ pub(crate) async fn prepare_list_page(
     user_id: Uuid,
     raw_query: &str,
 ) -> Result<(events::UserEventsFilters, events::ListPage), HandlerError> {
-    // Fetch upcoming events
+    // Fetch upcoming and suggested events
     let filters: events::UserEventsFilters = serde_qs_config().deserialize_str(raw_query)?;
     filters.validate()?;
-    let results = db.list_user_events(user_id, &filters).await?;
+    let (results, suggested_events) = tokio::try_join!(
+        db.list_user_events(user_id, &filters),
+        db.list_user_suggested_events(user_id)
+    )?;
 
     // Prepare template
     let navigation_links =
@@ -200,6 +203,7 @@ pub(crate) async fn prepare_list_page(
     let template = events::ListPage {
         events: results.events,
         navigation_links,
+        suggested_events,
         total: results.total,
         limit: filters.limit,
         offset: filters.offset,
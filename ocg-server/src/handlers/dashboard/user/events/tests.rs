@@ -61,6 +61,7 @@ async fn test_cancel_attendance_cancels_pending_registration_and_enqueues_notifi
 
                 purchase_amount_minor: None,
                 refund_request_status: None,
+                reminder_preference: None,
                 resume_checkout_url: None,
             })
         });
@@ -166,6 +167,7 @@ async fn test_cancel_attendance_promotes_waitlisted_users_and_enqueues_notificat
 
                 purchase_amount_minor: None,
                 refund_request_status: None,
+                reminder_preference: None,
                 resume_checkout_url: None,
             })
         });
@@ -280,6 +282,7 @@ async fn test_cancel_attendance_rolls_back_when_notification_enqueue_fails() {
 
                 purchase_amount_minor: None,
                 refund_request_status: None,
+                reminder_preference: None,
                 resume_checkout_url: None,
             })
         });
@@ -366,6 +369,7 @@ async fn test_cancel_attendance_rejects_non_attendee_status() {
 
                 purchase_amount_minor: None,
                 refund_request_status: None,
+                reminder_preference: None,
                 resume_checkout_url: None,
             })
         });
@@ -436,6 +440,7 @@ async fn test_cancel_attendance_rejects_ticketed_pending_registration() {
 
                 purchase_amount_minor: None,
                 refund_request_status: None,
+                reminder_preference: None,
                 resume_checkout_url: None,
             })
         });
@@ -541,6 +546,9 @@ async fn test_list_page_db_error() {
                 && filters.offset == Some(0)
         })
         .returning(|_, _| Err(anyhow!("db error")));
+    db.expect_list_user_suggested_events()
+        .withf(move |uid| *uid == user_id)
+        .returning(|_| Ok(vec![]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -581,6 +589,7 @@ async fn test_list_page_success() {
             attendance_status: Some(EventAttendanceStatus::Attendee),
             registration_answers: None,
             resume_checkout_url: None,
+            waitlist_confirmation_expires_at: None,
         }],
         total: 1,
     };
@@ -603,6 +612,10 @@ async fn test_list_page_success() {
                 && filters.offset == Some(0)
         })
         .returning(move |_, _| Ok(output.clone()));
+    db.expect_list_user_suggested_events()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(move |_| Ok(vec![sample_event_summary(Uuid::new_v4(), group_id)]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -625,7 +638,7 @@ async fn test_list_page_success() {
         parts.headers.get(CONTENT_TYPE).unwrap(),
         &HeaderValue::from_static("text/html; charset=utf-8"),
     );
-    assert!(!bytes.is_empty());
+    assert!(String::from_utf8_lossy(&bytes).contains("Suggested for you"));
 }
 
 #[tokio::test]
@@ -656,6 +669,10 @@ async fn test_list_page_with_pagination_params() {
             *uid == user_id && filters.limit == Some(5) && filters.offset == Some(10)
         })
         .returning(move |_, _| Ok(output.clone()));
+    db.expect_list_user_suggested_events()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(|_| Ok(vec![]));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -739,12 +756,16 @@ async fn test_submit_registration_answers_success() {
         .times(1)
         .withf(move |cid, eid| *cid == community_id && *eid == event_id)
         .returning(move |_, _| Ok(event.clone()));
+    tx.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(Some(Uuid::new_v4())));
     tx.expect_enqueue_notification()
         .times(1)
         .withf(move |notification| {
             matches!(notification.kind, NotificationKind::EventWelcome)
                 && notification.recipients == vec![user_id]
-                && notification.attachments.len() == 1
+                && notification.attachments.len() == 2
                 && notification
                     .template_data
                     .as_ref()
@@ -9,12 +9,14 @@ use axum::{
     response::{Html, IntoResponse},
 };
 use axum_messages::Messages;
+use tower_sessions::Session;
 use tracing::instrument;
 
-use super::{events, invitations, logs, session_proposals, submissions};
+use super::{certificates, events, invitations, logs, session_proposals, submissions};
 
 use crate::{
     auth::AuthSession,
+    config::{GoogleCalendarConfig, HttpServerConfig, OAuth2Provider, OidcProvider, SmsConfig},
     db::DynDB,
     handlers::{error::HandlerError, extractors::CurrentUser},
     templates::{
@@ -22,6 +24,7 @@ use crate::{
         auth::{self, User, UserDetails},
         dashboard::user::home::{Content, Page, Tab},
     },
+    util::build_user_calendar_feed_link,
 };
 
 #[cfg(test)]
@@ -36,7 +39,11 @@ pub(crate) async fn page(
     CurrentUser(user): CurrentUser,
     auth_session: AuthSession,
     messages: Messages,
+    session: Session,
     State(db): State<DynDB>,
+    State(google_calendar_cfg): State<Option<GoogleCalendarConfig>>,
+    State(server_cfg): State<HttpServerConfig>,
+    State(sms_cfg): State<Option<SmsConfig>>,
     Query(query): Query<HashMap<String, String>>,
     RawQuery(raw_query): RawQuery,
 ) -> Result<impl IntoResponse, HandlerError> {
@@ -52,13 +59,62 @@ pub(crate) async fn page(
     // Prepare content for the selected tab
     let content = match tab {
         Tab::Account => {
-            let timezones = db.list_timezones().await?;
+            let (calendar_token, phone, sessions, timezones, webhook) = tokio::try_join!(
+                db.get_user_calendar_token(&user.user_id),
+                db.get_user_phone(&user.user_id),
+                db.list_user_sessions(&user.user_id, session.id()),
+                db.list_timezones(),
+                db.get_user_webhook(&user.user_id)
+            )?;
+            let google_calendar_enabled = google_calendar_cfg.is_some();
+            let google_calendar_connected = if google_calendar_enabled {
+                db.is_user_google_calendar_connected(&user.user_id).await?
+            } else {
+                false
+            };
+            let passkeys_enabled = auth_session.backend.webauthn.is_some();
+            let passkeys = if passkeys_enabled {
+                db.list_user_passkeys(&user.user_id).await?
+            } else {
+                vec![]
+            };
             Content::Account(Box::new(auth::UpdateUserPage {
+                github_enabled: auth_session
+                    .backend
+                    .oauth2_providers
+                    .contains_key(&OAuth2Provider::GitHub),
+                gitlab_enabled: auth_session
+                    .backend
+                    .oauth2_providers
+                    .contains_key(&OAuth2Provider::GitLab),
+                google_calendar_connected,
+                google_calendar_enabled,
+                google_enabled: auth_session
+                    .backend
+                    .oauth2_providers
+                    .contains_key(&OAuth2Provider::Google),
                 has_password: user.has_password.unwrap_or(false),
+                linuxfoundation_enabled: auth_session
+                    .backend
+                    .oidc_providers
+                    .contains_key(&OidcProvider::LinuxFoundation),
+                passkeys,
+                passkeys_enabled,
+                provider: user.provider.clone().unwrap_or_default(),
+                sessions,
+                sms_enabled: sms_cfg.is_some(),
                 timezones,
                 user: UserDetails::from(user),
+                calendar_feed_url: calendar_token.map(|calendar_token| {
+                    build_user_calendar_feed_link(&server_cfg.base_url, &calendar_token)
+                }),
+                phone,
+                webhook,
             }))
         }
+        Tab::Certificates => {
+            Content::Certificates(certificates::prepare_list_page(&db, user.user_id).await?)
+        }
         Tab::Events => {
             let (_, template) = events::prepare_list_page(&db, user.user_id, raw_query).await?;
             Content::Events(template)
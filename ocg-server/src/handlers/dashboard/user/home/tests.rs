@@ -39,6 +39,24 @@ async fn test_page_account_tab_success() {
     db.expect_list_timezones()
         .times(1)
         .returning(|| Ok(vec!["UTC".to_string(), "America/New_York".to_string()]));
+    db.expect_get_user_calendar_token()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(Some("token".to_string())));
+    db.expect_get_user_phone()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(None));
+    db.expect_get_user_webhook()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(None));
+    db.expect_list_user_sessions()
+        .times(1)
+        .withf(move |id, current_session_id| {
+            *id == user_id && *current_session_id == Some(session_id)
+        })
+        .returning(|_, _| Ok(vec![sample_user_session()]));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -58,6 +76,129 @@ async fn test_page_account_tab_success() {
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+    let html = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(html.contains("Active sessions"));
+    assert!(html.contains("test-browser"));
+    assert!(html.contains("/calendar/token/events.ics"));
+    assert!(!html.contains("/dashboard/account/google-calendar/connect"));
+}
+
+#[tokio::test]
+async fn test_page_account_tab_google_calendar_connected() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_list_timezones()
+        .times(1)
+        .returning(|| Ok(vec!["UTC".to_string()]));
+    db.expect_get_user_calendar_token()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(None));
+    db.expect_get_user_phone()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(None));
+    db.expect_get_user_webhook()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(None));
+    db.expect_is_user_google_calendar_connected()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(|_| Ok(true));
+    db.expect_list_user_sessions()
+        .times(1)
+        .withf(move |id, current_session_id| {
+            *id == user_id && *current_session_id == Some(session_id)
+        })
+        .returning(|_, _| Ok(vec![sample_user_session()]));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_google_calendar_cfg(sample_google_calendar_cfg())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/user")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_html_response(&parts, &bytes, StatusCode::OK);
+    let html = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(html.contains("Your Google Calendar is connected."));
+    assert!(html.contains("disconnect-google-calendar"));
+}
+
+#[tokio::test]
+async fn test_page_certificates_tab_success() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let certificates = vec![sample_attendance_certificate(Uuid::new_v4())];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_list_user_attendance_certificates()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(move |_| Ok(certificates.clone()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/user?tab=certificates")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
     // Check response matches expectations
     assert_html_response(&parts, &bytes, StatusCode::OK);
 }
@@ -92,6 +233,10 @@ async fn test_page_events_tab_success() {
                 && filters.offset == Some(0)
         })
         .returning(move |_, _| Ok(events_output.clone()));
+    db.expect_list_user_suggested_events()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -150,6 +295,10 @@ async fn test_page_invitations_tab_success() {
         .times(1)
         .withf(move |uid| *uid == user_id)
         .returning(move |_| Ok(group_invitations.clone()));
+    db.expect_list_user_group_ownership_transfers()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(|_| Ok(vec![]));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -4,7 +4,7 @@ use askama::Template;
 use axum::{
     extract::{Path, State},
     http::StatusCode,
-    response::{Html, IntoResponse},
+    response::{Html, IntoResponse, Redirect},
 };
 use axum_messages::Messages;
 use tower_sessions::Session;
@@ -21,11 +21,16 @@ use crate::{
     },
     services::notifications::enqueue::enqueue_event_welcome_notification,
     templates::dashboard::user::invitations,
+    util::compute_hash,
 };
 
 #[cfg(test)]
 mod tests;
 
+// URLs used to redirect after following an invitation link
+const GROUP_DASHBOARD_URL: &str = "/dashboard/group";
+const INVITATIONS_URL: &str = "/dashboard/user?tab=invitations";
+
 // Pages handlers.
 
 /// Returns the invitations list page for the user dashboard.
@@ -101,6 +106,21 @@ pub(crate) async fn accept_event_attendee_invitation(
     Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
 }
 
+/// Accepts a pending group ownership transfer.
+#[instrument(skip_all, err)]
+pub(crate) async fn accept_group_ownership_transfer(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    Path(group_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Become the group owner
+    db.accept_group_ownership_transfer(user.user_id, group_id).await?;
+    messages.success("Ownership transfer accepted.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]))
+}
+
 /// Accepts a pending group team invitation.
 #[instrument(skip_all, err)]
 pub(crate) async fn accept_group_team_invitation(
@@ -122,6 +142,37 @@ pub(crate) async fn accept_group_team_invitation(
     Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]))
 }
 
+/// Accepts the pending group team invitation a link sent by email was issued
+/// for.
+#[instrument(skip_all, err)]
+pub(crate) async fn accept_group_team_invitation_link(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    session: Session,
+    State(db): State<DynDB>,
+    Path(token): Path<String>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Accept the invitation the link was issued for
+    let token_hash = compute_hash(token.as_bytes());
+    if !db
+        .accept_group_team_invitation_link(user.user_id, &token_hash)
+        .await?
+    {
+        messages.error(
+            "Invalid invitation link (please note that links are only valid for 7 days and must be opened with the invited account).",
+        );
+        return Ok(Redirect::to(INVITATIONS_URL));
+    }
+    messages.success("Team invitation accepted.");
+
+    // Select first community and group if none selected
+    if session.get::<Uuid>(SELECTED_COMMUNITY_ID_KEY).await?.is_none() {
+        select_first_community_and_group(&db, &session, &user.user_id).await?;
+    }
+
+    Ok(Redirect::to(GROUP_DASHBOARD_URL))
+}
+
 /// Rejects a pending community team invitation.
 #[instrument(skip_all, err)]
 pub(crate) async fn reject_community_team_invitation(
@@ -153,6 +204,21 @@ pub(crate) async fn reject_event_attendee_invitation(
     Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]))
 }
 
+/// Rejects a pending group ownership transfer.
+#[instrument(skip_all, err)]
+pub(crate) async fn reject_group_ownership_transfer(
+    CurrentUser(user): CurrentUser,
+    messages: Messages,
+    State(db): State<DynDB>,
+    Path(group_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Reject the pending transfer
+    db.reject_group_ownership_transfer(user.user_id, group_id).await?;
+    messages.success("Ownership transfer rejected.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]))
+}
+
 /// Rejects a pending group team invitation.
 #[instrument(skip_all, err)]
 pub(crate) async fn reject_group_team_invitation(
@@ -175,16 +241,18 @@ pub(crate) async fn prepare_list_page(
     db: &DynDB,
     user_id: Uuid,
 ) -> Result<invitations::ListPage, HandlerError> {
-    // Prepare template fetching both lists concurrently
-    let (community_invitations, event_invitations, group_invitations) = tokio::try_join!(
+    // Prepare template fetching all lists concurrently
+    let (community_invitations, event_invitations, group_invitations, ownership_transfers) = tokio::try_join!(
         db.list_user_community_team_invitations(user_id),
         db.list_user_event_invitations(user_id),
-        db.list_user_group_team_invitations(user_id)
+        db.list_user_group_team_invitations(user_id),
+        db.list_user_group_ownership_transfers(user_id)
     )?;
 
     Ok(invitations::ListPage {
         community_invitations,
         event_invitations,
         group_invitations,
+        ownership_transfers,
     })
 }
@@ -1,7 +1,10 @@
 use anyhow::anyhow;
 use axum::{
     body::{Body, to_bytes},
-    http::{Request, StatusCode, header::COOKIE},
+    http::{
+        HeaderValue, Request, StatusCode,
+        header::{COOKIE, LOCATION},
+    },
 };
 use axum_login::tower_sessions::session;
 use tower::ServiceExt;
@@ -18,6 +21,7 @@ use crate::{
     },
     services::notifications::{MockNotificationsManager, NotificationKind},
     templates::notifications::EventWelcome,
+    util::compute_hash,
 };
 
 #[tokio::test]
@@ -31,6 +35,7 @@ async fn test_list_page_success() {
     let community_invitations = vec![sample_community_invitation(community_id)];
     let event_invitations = vec![sample_event_invitation(Uuid::new_v4())];
     let group_invitations = vec![sample_group_invitation(Uuid::new_v4())];
+    let ownership_transfers = vec![sample_group_ownership_transfer(Uuid::new_v4())];
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -54,6 +59,10 @@ async fn test_list_page_success() {
         .times(1)
         .withf(move |uid| *uid == user_id)
         .returning(move |_| Ok(group_invitations.clone()));
+    db.expect_list_user_group_ownership_transfers()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(move |_| Ok(ownership_transfers.clone()));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -84,6 +93,7 @@ async fn test_list_page_db_error() {
     let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
     let community_invitations = vec![sample_community_invitation(community_id)];
     let event_invitations = vec![sample_event_invitation(Uuid::new_v4())];
+    let group_invitations = vec![sample_group_invitation(Uuid::new_v4())];
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -104,6 +114,10 @@ async fn test_list_page_db_error() {
         .withf(move |uid| *uid == user_id)
         .returning(move |_| Ok(event_invitations.clone()));
     db.expect_list_user_group_team_invitations()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(move |_| Ok(group_invitations.clone()));
+    db.expect_list_user_group_ownership_transfers()
         .times(1)
         .withf(move |uid| *uid == user_id)
         .returning(move |_| Err(anyhow!("db error")));
@@ -231,12 +245,16 @@ async fn test_accept_event_attendee_invitation_success() {
         .times(1)
         .withf(move |cid, eid| *cid == community_id && *eid == event_id)
         .returning(move |_, _| Ok(event.clone()));
+    tx.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(Some(Uuid::new_v4())));
     tx.expect_enqueue_notification()
         .times(1)
         .withf(move |notification| {
             matches!(notification.kind, NotificationKind::EventWelcome)
                 && notification.recipients == vec![user_id]
-                && notification.attachments.len() == 1
+                && notification.attachments.len() == 2
                 && notification.template_data.as_ref().is_some_and(|value| {
                     serde_json::from_value::<EventWelcome>(value.clone())
                         .is_ok_and(|template| template.link == expected_link)
@@ -335,6 +353,102 @@ async fn test_accept_event_attendee_invitation_context_failure_rolls_back() {
     assert_empty_response(&parts, &bytes, StatusCode::INTERNAL_SERVER_ERROR);
 }
 
+#[tokio::test]
+async fn test_accept_group_ownership_transfer_db_error() {
+    // Setup identifiers and data structures
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_accept_group_ownership_transfer()
+        .times(1)
+        .withf(move |uid, gid| *uid == user_id && *gid == group_id)
+        .returning(|_, _| Err(anyhow!("no pending group ownership transfer found")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/user/invitations/group/{group_id}/ownership/accept"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_response(&parts, &bytes, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_accept_group_ownership_transfer_success() {
+    // Setup identifiers and data structures
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_accept_group_ownership_transfer()
+        .times(1)
+        .withf(move |uid, gid| *uid == user_id && *gid == group_id)
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            record.id == session_id && message_matches(record, "Ownership transfer accepted.")
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/user/invitations/group/{group_id}/ownership/accept"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(&parts, &bytes, StatusCode::NO_CONTENT, "refresh-body");
+}
+
 #[tokio::test]
 async fn test_accept_group_team_invitation_success() {
     // Setup identifiers and data structures
@@ -401,6 +515,137 @@ async fn test_accept_group_team_invitation_success() {
     assert_empty_hx_trigger_response(&parts, &bytes, StatusCode::NO_CONTENT, "refresh-body");
 }
 
+#[tokio::test]
+async fn test_accept_group_team_invitation_link_invalid() {
+    // Setup identifiers and data structures
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_accept_group_team_invitation_link()
+        .times(1)
+        .withf(move |uid, token_hash| {
+            *uid == user_id && token_hash == compute_hash("invitation-token".as_bytes())
+        })
+        .returning(|_, _| Ok(false));
+    db.expect_list_user_groups().times(0);
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            record.id == session_id
+                && message_matches(
+                    record,
+                    "Invalid invitation link (please note that links are only valid for 7 days and must be opened with the invited account).",
+                )
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/user/invitations/group-team/invitation-token")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/dashboard/user?tab=invitations"),
+    );
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_accept_group_team_invitation_link_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let groups = sample_user_groups_by_community(community_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_accept_group_team_invitation_link()
+        .times(1)
+        .withf(move |uid, token_hash| {
+            *uid == user_id && token_hash == compute_hash("invitation-token".as_bytes())
+        })
+        .returning(|_, _| Ok(true));
+    db.expect_list_user_groups()
+        .times(1)
+        .withf(move |uid| *uid == user_id)
+        .returning(move |_| Ok(groups.clone()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            record.id == session_id
+                && message_matches(record, "Team invitation accepted.")
+                && record
+                    .data
+                    .get(SELECTED_COMMUNITY_ID_KEY)
+                    .is_some_and(|value| value == &json!(community_id))
+                && record
+                    .data
+                    .get(SELECTED_GROUP_ID_KEY)
+                    .is_some_and(|value| value == &json!(group_id))
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/user/invitations/group-team/invitation-token")
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::SEE_OTHER);
+    assert_eq!(
+        parts.headers.get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/dashboard/group"),
+    );
+    assert!(bytes.is_empty());
+}
+
 #[tokio::test]
 async fn test_reject_community_team_invitation_success() {
     // Setup identifiers and data structures
@@ -503,6 +748,57 @@ async fn test_reject_event_attendee_invitation_success() {
     assert_empty_hx_trigger_response(&parts, &bytes, StatusCode::NO_CONTENT, "refresh-body");
 }
 
+#[tokio::test]
+async fn test_reject_group_ownership_transfer_success() {
+    // Setup identifiers and data structures
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_reject_group_ownership_transfer()
+        .times(1)
+        .withf(move |uid, gid| *uid == user_id && *gid == group_id)
+        .returning(|_, _| Ok(()));
+    db.expect_update_session()
+        .times(1)
+        .withf(move |record| {
+            record.id == session_id && message_matches(record, "Ownership transfer rejected.")
+        })
+        .returning(|_| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/dashboard/user/invitations/group/{group_id}/ownership/reject"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_empty_hx_trigger_response(&parts, &bytes, StatusCode::NO_CONTENT, "refresh-body");
+}
+
 #[tokio::test]
 async fn test_reject_group_team_invitation_success() {
     // Setup identifiers and data structures
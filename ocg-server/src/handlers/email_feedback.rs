@@ -0,0 +1,283 @@
+//! Handlers for email delivery feedback webhooks (bounces and complaints).
+//!
+//! Feedback can be delivered by Amazon SES through an SNS HTTPS subscription, or
+//! by any other provider able to post a simple JSON report to the endpoint.
+
+use axum::{
+    extract::State,
+    http::{
+        HeaderMap, StatusCode,
+        header::{AUTHORIZATION, WWW_AUTHENTICATE},
+    },
+    response::IntoResponse,
+};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use serde::Deserialize;
+use subtle::ConstantTimeEq;
+use tracing::{info, instrument, trace, warn};
+
+use crate::{config::EmailFeedbackConfig, db::DynDB, types::user::EmailUndeliverableReason};
+
+#[cfg(test)]
+mod tests;
+
+/// SES bounce type used for addresses that will never accept messages.
+const SES_BOUNCE_TYPE_PERMANENT: &str = "Permanent";
+
+/// SES notification types we handle.
+const SES_NOTIFICATION_BOUNCE: &str = "Bounce";
+const SES_NOTIFICATION_COMPLAINT: &str = "Complaint";
+
+/// SNS message types we handle.
+const SNS_MESSAGE_NOTIFICATION: &str = "Notification";
+const SNS_MESSAGE_SUBSCRIPTION_CONFIRMATION: &str = "SubscriptionConfirmation";
+
+// Handlers.
+
+/// Handles incoming email bounce and complaint reports.
+#[instrument(skip_all)]
+pub(crate) async fn feedback(
+    State(db): State<DynDB>,
+    State(email_feedback_cfg): State<Option<EmailFeedbackConfig>>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    // Extract feedback config (route only registered when feedback is configured)
+    let Some(email_feedback_cfg) = email_feedback_cfg.as_ref() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    // Verify the request credentials
+    if !verify_authorization(&headers, &email_feedback_cfg.webhook_secret) {
+        warn!("email feedback authorization failed");
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(WWW_AUTHENTICATE, r#"Basic realm="email-feedback""#)],
+        )
+            .into_response();
+    }
+
+    // Parse payload and extract the reports it contains
+    let reports = match parse_reports(&body) {
+        Ok(reports) => reports,
+        Err(err) => {
+            warn!(?err, "failed to parse email feedback payload");
+            return StatusCode::BAD_REQUEST.into_response();
+        }
+    };
+
+    // Mark the reported addresses as undeliverable
+    for report in reports {
+        match db.mark_email_undeliverable(&report.email, report.reason).await {
+            Ok(users) => trace!(%report.reason, users, "marked email as undeliverable"),
+            Err(err) => {
+                warn!(?err, "failed to mark email as undeliverable");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        }
+    }
+
+    StatusCode::OK.into_response()
+}
+
+// Helpers.
+
+/// Extracts the undeliverable email reports from a feedback payload.
+fn parse_reports(body: &str) -> Result<Vec<FeedbackReport>, serde_json::Error> {
+    let reports = match serde_json::from_str(body)? {
+        FeedbackPayload::Sns(message) => match message.r#type.as_str() {
+            SNS_MESSAGE_NOTIFICATION => {
+                let notification: SesNotification =
+                    serde_json::from_str(message.message.as_deref().unwrap_or_default())?;
+                notification.reports()
+            }
+            SNS_MESSAGE_SUBSCRIPTION_CONFIRMATION => {
+                // The subscription must be confirmed by visiting the URL provided
+                info!(
+                    subscribe_url = message.subscribe_url.as_deref().unwrap_or_default(),
+                    "email feedback sns subscription pending confirmation"
+                );
+                vec![]
+            }
+            _ => {
+                trace!(message_type = %message.r#type, "ignoring unhandled sns message");
+                vec![]
+            }
+        },
+        FeedbackPayload::Ses(notification) => notification.reports(),
+        FeedbackPayload::Generic(report) => report.reports(),
+    };
+
+    Ok(reports)
+}
+
+/// Verifies the request credentials, sent as a bearer token or basic auth password.
+fn verify_authorization(headers: &HeaderMap, secret: &str) -> bool {
+    // Extract the credentials provided
+    let Some(authorization) = headers.get(AUTHORIZATION).and_then(|v| v.to_str().ok()) else {
+        return false;
+    };
+    let provided = if let Some(token) = authorization.strip_prefix("Bearer ") {
+        token.to_string()
+    } else if let Some(credentials) = authorization.strip_prefix("Basic ") {
+        let Some(password) = BASE64
+            .decode(credentials)
+            .ok()
+            .and_then(|decoded| String::from_utf8(decoded).ok())
+            .and_then(|decoded| decoded.split_once(':').map(|(_, password)| password.to_string()))
+        else {
+            return false;
+        };
+        password
+    } else {
+        return false;
+    };
+
+    // Constant-time comparison
+    provided.as_bytes().ct_eq(secret.as_bytes()).into()
+}
+
+// Types.
+
+/// Undeliverable email address report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FeedbackReport {
+    /// Email address reported.
+    email: String,
+    /// Reason the address is undeliverable.
+    reason: EmailUndeliverableReason,
+}
+
+/// Feedback payloads accepted by the endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum FeedbackPayload {
+    /// SNS message wrapping an SES notification.
+    Sns(SnsMessage),
+    /// SES notification delivered without the SNS envelope.
+    Ses(SesNotification),
+    /// Provider independent report.
+    Generic(GenericFeedback),
+}
+
+/// SNS HTTPS subscription message.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SnsMessage {
+    /// Message type.
+    r#type: String,
+
+    /// Message body, containing the SES notification.
+    #[serde(default)]
+    message: Option<String>,
+    /// URL to visit to confirm the subscription.
+    #[serde(default, rename = "SubscribeURL")]
+    subscribe_url: Option<String>,
+}
+
+/// SES bounce or complaint notification.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SesNotification {
+    /// Notification type (`eventType` when using SES event publishing).
+    #[serde(alias = "eventType")]
+    notification_type: String,
+
+    /// Bounce details.
+    #[serde(default)]
+    bounce: Option<SesBounce>,
+    /// Complaint details.
+    #[serde(default)]
+    complaint: Option<SesComplaint>,
+}
+
+impl SesNotification {
+    /// Returns the reports for permanent bounces and complaints.
+    fn reports(self) -> Vec<FeedbackReport> {
+        match self.notification_type.as_str() {
+            SES_NOTIFICATION_BOUNCE => self
+                .bounce
+                .filter(|bounce| bounce.bounce_type == SES_BOUNCE_TYPE_PERMANENT)
+                .map(|bounce| reports(bounce.bounced_recipients, EmailUndeliverableReason::Bounce))
+                .unwrap_or_default(),
+            SES_NOTIFICATION_COMPLAINT => self
+                .complaint
+                .map(|complaint| {
+                    reports(
+                        complaint.complained_recipients,
+                        EmailUndeliverableReason::Complaint,
+                    )
+                })
+                .unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+}
+
+/// SES bounce details.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SesBounce {
+    /// Bounce type (`Permanent`, `Transient` or `Undetermined`).
+    bounce_type: String,
+    /// Recipients whose messages bounced.
+    bounced_recipients: Vec<SesRecipient>,
+}
+
+/// SES complaint details.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SesComplaint {
+    /// Recipients who reported the messages.
+    complained_recipients: Vec<SesRecipient>,
+}
+
+/// SES recipient.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SesRecipient {
+    /// Recipient email address.
+    email_address: String,
+}
+
+/// Provider independent bounce or complaint report.
+#[derive(Debug, Deserialize)]
+struct GenericFeedback {
+    /// Email address reported.
+    email: String,
+    /// Report type.
+    r#type: EmailUndeliverableReason,
+
+    /// Whether the bounce is permanent (ignored for complaints).
+    #[serde(default = "default_permanent")]
+    permanent: bool,
+}
+
+impl GenericFeedback {
+    /// Returns the report unless it is a temporary bounce.
+    fn reports(self) -> Vec<FeedbackReport> {
+        if self.r#type == EmailUndeliverableReason::Bounce && !self.permanent {
+            return vec![];
+        }
+        vec![FeedbackReport {
+            email: self.email,
+            reason: self.r#type,
+        }]
+    }
+}
+
+/// Generic bounces are permanent unless stated otherwise.
+fn default_permanent() -> bool {
+    true
+}
+
+/// Builds the reports for the SES recipients provided.
+fn reports(recipients: Vec<SesRecipient>, reason: EmailUndeliverableReason) -> Vec<FeedbackReport> {
+    recipients
+        .into_iter()
+        .map(|recipient| FeedbackReport {
+            email: recipient.email_address,
+            reason,
+        })
+        .collect()
+}
@@ -0,0 +1,252 @@
+use anyhow::anyhow;
+use axum::{
+    body::{Body, to_bytes},
+    http::{HeaderValue, Request, StatusCode, header::CONTENT_TYPE},
+};
+use serde_json::json;
+use tower::ServiceExt;
+
+use crate::{
+    db::mock::MockDB,
+    handlers::tests::{TestRouterBuilder, sample_site_settings},
+    services::notifications::MockNotificationsManager,
+};
+
+use super::*;
+
+#[test]
+fn test_parse_reports_generic_complaint() {
+    let body = json!({ "email": "user@example.test", "type": "complaint" }).to_string();
+
+    assert_eq!(
+        parse_reports(&body).unwrap(),
+        vec![FeedbackReport {
+            email: "user@example.test".to_string(),
+            reason: EmailUndeliverableReason::Complaint,
+        }]
+    );
+}
+
+#[test]
+fn test_parse_reports_generic_temporary_bounce() {
+    let body = json!({
+        "email": "user@example.test",
+        "permanent": false,
+        "type": "bounce",
+    })
+    .to_string();
+
+    assert!(parse_reports(&body).unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_reports_ses_event_publishing_complaint() {
+    let body = json!({
+        "eventType": "Complaint",
+        "complaint": {
+            "complainedRecipients": [{ "emailAddress": "user@example.test" }],
+        },
+    })
+    .to_string();
+
+    assert_eq!(
+        parse_reports(&body).unwrap(),
+        vec![FeedbackReport {
+            email: "user@example.test".to_string(),
+            reason: EmailUndeliverableReason::Complaint,
+        }]
+    );
+}
+
+#[test]
+fn test_parse_reports_sns_transient_bounce() {
+    let body = sns_notification_body("Transient");
+
+    assert!(parse_reports(&body).unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_reports_sns_subscription_confirmation() {
+    let body = json!({
+        "Type": "SubscriptionConfirmation",
+        "SubscribeURL": "https://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription",
+    })
+    .to_string();
+
+    assert!(parse_reports(&body).unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_reports_invalid_payload() {
+    assert!(parse_reports(r#"{"unexpected":true}"#).is_err());
+}
+
+#[test]
+fn test_verify_authorization_basic() {
+    let mut headers = HeaderMap::new();
+    let credentials = BASE64.encode("sns:feedback-secret");
+    headers.insert(
+        AUTHORIZATION,
+        format!("Basic {credentials}").parse().unwrap(),
+    );
+
+    assert!(verify_authorization(&headers, "feedback-secret"));
+    assert!(!verify_authorization(&headers, "other-secret"));
+}
+
+#[test]
+fn test_verify_authorization_bearer() {
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, "Bearer feedback-secret".parse().unwrap());
+
+    assert!(verify_authorization(&headers, "feedback-secret"));
+    assert!(!verify_authorization(&headers, "other-secret"));
+}
+
+#[test]
+fn test_verify_authorization_missing_header() {
+    assert!(!verify_authorization(&HeaderMap::new(), "feedback-secret"));
+}
+
+#[tokio::test]
+async fn test_feedback_marks_bounced_recipients_undeliverable() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_mark_email_undeliverable()
+        .times(1)
+        .withf(|email, reason| {
+            email == "bounced@example.test" && *reason == EmailUndeliverableReason::Bounce
+        })
+        .returning(|_, _| Ok(1));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_email_feedback_cfg(sample_email_feedback_cfg())
+        .build()
+        .await;
+    let request = feedback_request(&sns_notification_body("Permanent"), "feedback-secret");
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_feedback_returns_bad_request_for_invalid_payload() {
+    // Setup router and send request
+    let router = TestRouterBuilder::new(MockDB::new(), MockNotificationsManager::new())
+        .with_email_feedback_cfg(sample_email_feedback_cfg())
+        .build()
+        .await;
+    let request = feedback_request("{invalid-json", "feedback-secret");
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_feedback_returns_internal_server_error_when_db_fails() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_mark_email_undeliverable()
+        .times(1)
+        .returning(|_, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_email_feedback_cfg(sample_email_feedback_cfg())
+        .build()
+        .await;
+    let body = json!({ "email": "user@example.test", "type": "bounce" }).to_string();
+    let request = feedback_request(&body, "feedback-secret");
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_feedback_returns_not_found_when_disabled() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = feedback_request("{}", "feedback-secret");
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("We could not find that page"));
+}
+
+#[tokio::test]
+async fn test_feedback_returns_unauthorized_for_invalid_secret() {
+    // Setup router and send request
+    let router = TestRouterBuilder::new(MockDB::new(), MockNotificationsManager::new())
+        .with_email_feedback_cfg(sample_email_feedback_cfg())
+        .build()
+        .await;
+    let request = feedback_request(&sns_notification_body("Permanent"), "wrong-secret");
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        response.headers().get(WWW_AUTHENTICATE).unwrap(),
+        &HeaderValue::from_static(r#"Basic realm="email-feedback""#)
+    );
+}
+
+// Helpers.
+
+/// Builds an authenticated feedback request.
+fn feedback_request(body: &str, secret: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/webhooks/email-feedback")
+        .header(CONTENT_TYPE, "application/json")
+        .header(AUTHORIZATION, format!("Bearer {secret}"))
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+/// Sample email feedback configuration.
+fn sample_email_feedback_cfg() -> EmailFeedbackConfig {
+    EmailFeedbackConfig {
+        webhook_secret: "feedback-secret".to_string(),
+    }
+}
+
+/// Builds an SNS notification wrapping an SES bounce of the given type.
+fn sns_notification_body(bounce_type: &str) -> String {
+    let message = json!({
+        "notificationType": "Bounce",
+        "bounce": {
+            "bounceType": bounce_type,
+            "bouncedRecipients": [{ "emailAddress": "bounced@example.test" }],
+        },
+    });
+
+    json!({
+        "Type": "Notification",
+        "Message": message.to_string(),
+    })
+    .to_string()
+}
@@ -3,13 +3,18 @@
 use askama::Template;
 use axum::{
     Json,
-    extract::{Path, State},
-    http::{HeaderMap, HeaderValue, StatusCode, Uri, header::CACHE_CONTROL},
+    extract::{Path, Query, State},
+    http::{
+        HeaderMap, HeaderValue, StatusCode, Uri,
+        header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+    },
     response::{Html, IntoResponse, Redirect},
 };
 use garde::{Error as ValidationError, Path as ValidationPath, Report, Validate};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use serde_with::{NoneAsEmptyString, serde_as};
 use tracing::{instrument, warn};
 use uuid::Uuid;
 
@@ -26,6 +31,7 @@ use crate::{
     },
     router::{CACHE_CONTROL_NO_STORE, PUBLIC_SHARED_CACHE_HEADERS},
     services::{
+        images::DynImageStorage,
         notifications::{
             DynNotificationsManager,
             enqueue::enqueue_event_attendance_cancellation_notifications,
@@ -40,18 +46,22 @@ use crate::{
     templates::{
         PageId,
         auth::User,
-        event::{CfsModal, CheckInPage, Page},
+        event::{
+            CfsModal, CheckInPage, DiscussionSection, Page, PhotosModal, SeriesPage, SpeakerPage,
+        },
+        group::PastEventCard,
     },
     types::{
-        event::{EventAttendanceStatus, EventFull, EventSummary},
+        event::{EventAttendanceStatus, EventFull, EventReminderPreference, EventSummary},
         payments::{EventPurchaseStatus, EventTicketType, PreparedEventCheckout},
         questionnaire::{
             OptionalQuestionnaireAnswersForm, QuestionnaireAnswers, QuestionnaireQuestion,
         },
     },
+    util::{build_events_calendar_feed, build_qr_code_svg},
     validation::{
-        MAX_EVENT_LABELS_PER_SUBMISSION, MAX_LEN_DESCRIPTION_SHORT, MAX_LEN_S,
-        trimmed_non_empty_opt,
+        MAX_EVENT_LABELS_PER_SUBMISSION, MAX_LEN_DESCRIPTION_SHORT, MAX_LEN_L, MAX_LEN_S,
+        image_url, trimmed_non_empty, trimmed_non_empty_opt,
     },
 };
 
@@ -71,7 +81,7 @@ pub(crate) async fn page(
     uri: Uri,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get community and site settings
-    let (community_id, site_settings) = tokio::try_join!(
+    let (community_id, mut site_settings) = tokio::try_join!(
         db.get_community_id_by_name(&community_name),
         db.get_site_settings()
     )?;
@@ -79,11 +89,19 @@ pub(crate) async fn page(
         return not_found::render(site_settings);
     };
 
+    // Apply the community theme to the site settings
+    site_settings.apply_community_theme(&db.get_community_theme(community_id).await?);
+
     // Fetch event page data
     let event = db
         .get_event_full_by_slug(community_id, &group_slug, &event_slug)
         .await?;
     let Some(mut event) = event else {
+        // Redirect events of groups merged into another group to the target group
+        if let Some(target_slug) = db.get_merged_group_slug(community_id, &group_slug).await? {
+            let url = public_event_url(&community_name, &target_slug, &event_slug, &uri);
+            return Ok(Redirect::permanent(&url).into_response());
+        }
         return not_found::render(site_settings);
     };
 
@@ -98,9 +116,23 @@ pub(crate) async fn page(
         return Ok(Redirect::temporary(&url).into_response());
     }
 
-    // Trim gallery media
+    // Append approved attendee photos to the gallery and trim it
+    if let Some(attendee_photos_urls) = event.attendee_photos_urls.take() {
+        event
+            .photos_urls
+            .get_or_insert_with(Vec::new)
+            .extend(attendee_photos_urls);
+    }
     trim_public_gallery_images(&mut event.photos_urls);
 
+    // Fetch the linked series when the event has related events
+    let series = match event.event_series_id {
+        Some(event_series_id) if event.has_related_events => {
+            db.get_event_series(community_id, event_series_id).await?
+        }
+        _ => None,
+    };
+
     // Prepare template
     let template = Page {
         base_url: server_cfg.base_url,
@@ -109,6 +141,98 @@ pub(crate) async fn page(
         path: uri.path().to_string(),
         site_settings,
         user: User::default(),
+        series,
+    };
+
+    Ok((PUBLIC_SHARED_CACHE_HEADERS, Html(template.render()?)).into_response())
+}
+
+/// Handler that renders the event series page.
+#[instrument(skip_all)]
+pub(crate) async fn series_page(
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path((community_name, group_slug, event_series_id)): Path<(String, String, Uuid)>,
+    uri: Uri,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get community and site settings
+    let (community_id, mut site_settings) = tokio::try_join!(
+        db.get_community_id_by_name(&community_name),
+        db.get_site_settings()
+    )?;
+    let Some(community_id) = community_id else {
+        return not_found::render(site_settings);
+    };
+
+    // Apply the community theme to the site settings
+    site_settings.apply_community_theme(&db.get_community_theme(community_id).await?);
+
+    // Fetch event series page data
+    let series = db.get_event_series(community_id, event_series_id).await?;
+    let Some(series) = series.filter(|series| {
+        group_slug == series.group.slug || group_slug == series.group.public_slug()
+    }) else {
+        return not_found::render(site_settings);
+    };
+
+    // Check if the anonymous (shared cache) page can display attendance figures
+    let can_view_attendees = db
+        .user_can_view_group_members(community_id, series.group.group_id, None)
+        .await?;
+
+    // Prepare template
+    let template = SeriesPage {
+        base_url: server_cfg.base_url,
+        can_view_attendees,
+        events: series
+            .events
+            .iter()
+            .cloned()
+            .map(|event| PastEventCard { event })
+            .collect(),
+        page_id: PageId::Event,
+        path: uri.path().to_string(),
+        series,
+        site_settings,
+        user: User::default(),
+    };
+
+    Ok((PUBLIC_SHARED_CACHE_HEADERS, Html(template.render()?)).into_response())
+}
+
+/// Handler that renders the speaker page.
+#[instrument(skip_all)]
+pub(crate) async fn speaker_page(
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path((community_name, username)): Path<(String, String)>,
+    uri: Uri,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get community and site settings
+    let (community_id, mut site_settings) = tokio::try_join!(
+        db.get_community_id_by_name(&community_name),
+        db.get_site_settings()
+    )?;
+    let Some(community_id) = community_id else {
+        return not_found::render(site_settings);
+    };
+
+    // Apply the community theme to the site settings
+    site_settings.apply_community_theme(&db.get_community_theme(community_id).await?);
+
+    // Fetch speaker profile
+    let Some(profile) = db.get_speaker_profile(community_id, &username).await? else {
+        return not_found::render(site_settings);
+    };
+
+    // Prepare template
+    let template = SpeakerPage {
+        base_url: server_cfg.base_url,
+        page_id: PageId::Event,
+        path: uri.path().to_string(),
+        profile,
+        site_settings,
+        user: User::default(),
     };
 
     Ok((PUBLIC_SHARED_CACHE_HEADERS, Html(template.render()?)).into_response())
@@ -151,6 +275,53 @@ pub(crate) async fn cfs_modal(
     Ok(Html(template.render()?))
 }
 
+/// Handler that renders the event discussion section.
+#[instrument(skip_all, err)]
+pub(crate) async fn discussion(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path((_, event_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session, event details and discussion
+    let user = User::from_session(auth_session).await?;
+    let (event, discussion) = tokio::try_join!(
+        db.get_event_summary_by_id(community_id, event_id),
+        db.get_event_discussion(community_id, event_id),
+    )?;
+
+    // Prepare template
+    let template = DiscussionSection {
+        discussion,
+        event,
+        user,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+/// Handler that renders the attendee photos submission modal.
+#[instrument(skip_all, err)]
+pub(crate) async fn photos_modal(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path((_, event_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session and event details
+    let user = User::from_session(auth_session).await?;
+    let event = db.get_event_summary_by_id(community_id, event_id).await?;
+
+    // Prepare template
+    let template = PhotosModal {
+        event,
+        user,
+        notice: None,
+    };
+
+    Ok(Html(template.render()?))
+}
+
 /// Handler that renders the check-in page.
 #[instrument(skip_all, err)]
 pub(crate) async fn check_in_page(
@@ -159,6 +330,7 @@ pub(crate) async fn check_in_page(
     State(db): State<DynDB>,
     Path((_, event_id)): Path<(String, Uuid)>,
     CommunityId(community_id): CommunityId,
+    Query(query): Query<CheckInQuery>,
     uri: Uri,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get site settings and event details
@@ -168,26 +340,108 @@ pub(crate) async fn check_in_page(
         db.is_event_check_in_window_open(community_id, event_id),
     )?;
 
+    // Validate the kiosk check-in code, when provided
+    let check_in_code_expired = match query.code.as_deref() {
+        Some(code) => !db.is_event_check_in_code_valid(community_id, event_id, code).await?,
+        None => false,
+    };
+
+    // Prepare the ticket QR code attendees can show to be checked in by staff
+    let user_is_attendee = attendance.status == EventAttendanceStatus::Attendee;
+    let ticket_qr_code = if user_is_attendee && !attendance.is_checked_in {
+        db.get_event_ticket_code(community_id, event_id, user.user_id)
+            .await?
+            .map(|ticket_code| build_qr_code_svg(&ticket_code.to_string(), 200))
+            .transpose()?
+    } else {
+        None
+    };
+
     // Prepare template
     let template = CheckInPage {
+        check_in_code_expired,
         check_in_window_open,
         event,
         page_id: PageId::CheckIn,
         path: uri.path().to_string(),
         site_settings,
         user: User::from_session(auth_session).await?,
-        user_is_attendee: attendance.status == EventAttendanceStatus::Attendee,
+        user_is_attendee,
         user_is_checked_in: attendance.is_checked_in,
+
+        check_in_code: query.code,
+        ticket_qr_code,
     };
 
     Ok(Html(template.render()?))
 }
 
+/// Handler that returns a user's personal calendar feed in iCalendar format.
+#[instrument(skip_all, err)]
+pub(crate) async fn user_calendar(
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path(calendar_token): Path<String>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get the events in the user's calendar
+    let Some(events) = db.get_user_calendar_events(&calendar_token).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    // Build the calendar feed, including the meeting details shared with attendees
+    let feed = build_events_calendar_feed(&server_cfg.base_url, "My events", &events, true);
+
+    Ok((
+        [
+            (CACHE_CONTROL, CACHE_CONTROL_NO_STORE),
+            (CONTENT_TYPE, "text/calendar; charset=utf-8"),
+        ],
+        feed,
+    )
+        .into_response())
+}
+
+/// Handler that downloads a file attached to an event.
+#[instrument(skip_all, err)]
+pub(crate) async fn download_resource(
+    State(db): State<DynDB>,
+    State(image_storage): State<DynImageStorage>,
+    Path((_, event_id, event_resource_id)): Path<(String, Uuid, Uuid)>,
+    CommunityId(community_id): CommunityId,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get the resource details and load the file from storage
+    let Some(resource) = db
+        .get_event_resource(community_id, event_id, event_resource_id)
+        .await?
+    else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+    let Some(file) = image_storage.get(&resource.file_name).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    // Use the original file name for the downloaded file
+    let content_disposition = format!(
+        "attachment; filename*=UTF-8''{}",
+        utf8_percent_encode(&resource.name, NON_ALPHANUMERIC)
+    );
+
+    Ok((
+        [
+            (CONTENT_TYPE, resource.content_type),
+            (CONTENT_DISPOSITION, content_disposition),
+        ],
+        file.bytes,
+    )
+        .into_response())
+}
+
 // JSON handlers.
 
 /// Handler that returns fresh public availability for the event page.
 #[instrument(skip_all)]
 pub(crate) async fn availability(
+    auth_session: AuthSession,
     State(db): State<DynDB>,
     CommunityId(community_id): CommunityId,
     Path((_, group_slug, event_slug)): Path<(String, String, String)>,
@@ -198,6 +452,12 @@ pub(crate) async fn availability(
         .await?
         .ok_or(HandlerError::NotFound)?;
 
+    // Private groups only expose attendance figures to their members
+    let user_id = auth_session.user.as_ref().map(|user| user.user_id);
+    let can_view_attendees = db
+        .user_can_view_group_members(community_id, event.group.group_id, user_id)
+        .await?;
+
     // Prevent volatile seat availability from being cached
     let mut headers = HeaderMap::new();
     headers.insert(
@@ -205,11 +465,48 @@ pub(crate) async fn availability(
         HeaderValue::from_static(CACHE_CONTROL_NO_STORE),
     );
 
-    Ok((headers, Json(EventAvailability::from_event(&event))).into_response())
+    let availability = EventAvailability::from_event(&event, can_view_attendees);
+
+    Ok((headers, Json(availability)).into_response())
 }
 
 // Actions handlers.
 
+/// Handler for posting a comment or reply in an event's discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn add_comment(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path((_, event_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+    ValidatedForm(input): ValidatedForm<CommentInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Add comment to the event discussion
+    db.add_event_comment(
+        community_id,
+        event_id,
+        user.user_id,
+        &input.content,
+        input.parent_event_comment_id,
+    )
+    .await?;
+
+    // Prepare template with the refreshed discussion
+    let user = User::from_session(auth_session).await?;
+    let (event, discussion) = tokio::try_join!(
+        db.get_event_summary_by_id(community_id, event_id),
+        db.get_event_discussion(community_id, event_id),
+    )?;
+    let template = DiscussionSection {
+        discussion,
+        event,
+        user,
+    };
+
+    Ok(Html(template.render()?))
+}
+
 /// Handler for attending an event.
 #[instrument(skip_all)]
 pub(crate) async fn attend_event(
@@ -280,10 +577,21 @@ pub(crate) async fn attend_event(
     // Build the notification that matches the new attendance status
     let notification_result = match &attend_result {
         EventAttendanceStatus::Attendee => {
-            // Confirm the RSVP with the event details and calendar attachment
+            // Load the attendee ticket, sending the notification without it on failure
+            let ticket_code =
+                match db.get_event_ticket_code(community_id, event_id, user.user_id).await {
+                    Ok(ticket_code) => ticket_code,
+                    Err(err) => {
+                        warn!(error = %err, "failed to load event welcome ticket code");
+                        None
+                    }
+                };
+
+            // Confirm the RSVP with the event details, calendar and ticket attachments
             match build_event_welcome_notification(
                 &event,
                 user.user_id,
+                ticket_code,
                 &server_cfg,
                 &site_settings,
                 true,
@@ -299,7 +607,8 @@ pub(crate) async fn attend_event(
         | EventAttendanceStatus::PendingApproval
         | EventAttendanceStatus::PendingPayment
         | EventAttendanceStatus::RegistrationQuestionsPending
-        | EventAttendanceStatus::Rejected => Ok(()),
+        | EventAttendanceStatus::Rejected
+        | EventAttendanceStatus::WaitlistConfirmationPending => Ok(()),
         EventAttendanceStatus::Waitlisted => {
             // Let the user know they were added to the waitlist
             match build_event_waitlist_joined_notification(
@@ -355,6 +664,7 @@ pub(crate) async fn attendance_status(
         "manually_invited": attendance.manually_invited,
         "purchase_amount_minor": attendance.purchase_amount_minor,
         "refund_request_status": attendance.refund_request_status,
+        "reminder_preference": attendance.reminder_preference,
         "resume_checkout_url": attendance.resume_checkout_url,
         "status": attendance.status
     })))
@@ -385,11 +695,23 @@ pub(crate) async fn check_in(
     State(db): State<DynDB>,
     Path((_, event_id)): Path<(String, Uuid)>,
     CommunityId(community_id): CommunityId,
+    Query(query): Query<CheckInQuery>,
 ) -> Result<impl IntoResponse, HandlerError> {
+    // Reject expired kiosk check-in codes
+    if let Some(code) = query.code.as_deref()
+        && !db.is_event_check_in_code_valid(community_id, event_id, code).await?
+    {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "check-in code expired, please scan the QR code again",
+        )
+            .into_response());
+    }
+
     // Check in event (bypass_window = false for user self check-in)
     db.check_in_event(community_id, event_id, user.user_id, false).await?;
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(StatusCode::NO_CONTENT.into_response())
 }
 
 /// Handler for leaving an event.
@@ -625,6 +947,32 @@ pub(crate) async fn submit_cfs_submission(
     Ok(Html(template.render()?))
 }
 
+/// Handler for sharing a photo of an event the user attended.
+#[instrument(skip_all, err)]
+pub(crate) async fn submit_photo(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path((_, event_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+    ValidatedForm(input): ValidatedForm<PhotoSubmissionInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Add photo to database, pending review by the organizers
+    db.add_event_photo(community_id, event_id, user.user_id, &input.url)
+        .await?;
+
+    // Prepare template
+    let user = User::from_session(auth_session).await?;
+    let event = db.get_event_summary_by_id(community_id, event_id).await?;
+    let template = PhotosModal {
+        event,
+        user,
+        notice: Some("Photo received. It will appear in the gallery once approved.".to_string()),
+    };
+
+    Ok(Html(template.render()?))
+}
+
 /// Tracks an event page view.
 #[instrument(skip_all)]
 pub(crate) async fn track_view(
@@ -640,6 +988,26 @@ pub(crate) async fn track_view(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Handler for updating when the attendee wants to be reminded about the event.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_reminder_preference(
+    CurrentUser(user): CurrentUser,
+    State(db): State<DynDB>,
+    Path((_, event_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+    ValidatedForm(input): ValidatedForm<ReminderPreferenceInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_event_reminder_preference(
+        community_id,
+        event_id,
+        user.user_id,
+        input.reminder_preference,
+    )
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 // Types.
 
 /// Submitted CFS proposal form data.
@@ -654,6 +1022,13 @@ pub(crate) struct CfsSubmissionInput {
     session_proposal_id: Uuid,
 }
 
+/// Check-in query parameters.
+#[derive(Debug, Deserialize)]
+pub(crate) struct CheckInQuery {
+    /// Rotating code included in the QR code displayed by the check-in kiosk.
+    code: Option<String>,
+}
+
 /// Ticket checkout form data.
 #[derive(Debug, Deserialize, Validate)]
 pub(crate) struct CheckoutInput {
@@ -675,8 +1050,6 @@ pub(crate) struct CheckoutInput {
 struct EventAvailability {
     /// Whether attendance requests require organizer approval.
     attendee_approval_required: bool,
-    /// Current number of event attendees.
-    attendee_count: i32,
     /// Whether the event has been canceled.
     canceled: bool,
     /// Whether the event has at least one ticket type selectable now.
@@ -691,27 +1064,30 @@ struct EventAvailability {
     registration_window_open: bool,
     /// Current public availability for each ticket type.
     ticket_types: Vec<EventTicketAvailability>,
-    /// Current number of users on the waiting list.
-    waitlist_count: i32,
     /// Whether joining the waiting list is enabled.
     waitlist_enabled: bool,
 
-    /// Maximum capacity for the event.
+    /// Current number of event attendees, hidden from non-members of private groups.
+    attendee_count: Option<i32>,
+    /// Maximum capacity for the event, hidden from non-members of private groups.
     capacity: Option<i32>,
     /// Message describing the configured registration window.
     registration_window_message: Option<String>,
     /// Disabled-control title explaining why registration is unavailable.
     registration_window_unavailable_title: Option<String>,
-    /// Remaining capacity after subtracting registered attendees.
+    /// Remaining capacity after subtracting registered attendees, hidden from
+    /// non-members of private groups.
     remaining_capacity: Option<i32>,
+    /// Current number of users on the waiting list, hidden from non-members
+    /// of private groups.
+    waitlist_count: Option<i32>,
 }
 
 impl EventAvailability {
     /// Builds a public availability payload from the current event state.
-    fn from_event(event: &EventFull) -> Self {
+    fn from_event(event: &EventFull, can_view_attendees: bool) -> Self {
         Self {
             attendee_approval_required: event.attendee_approval_required,
-            attendee_count: event.attendee_count,
             canceled: event.canceled,
             has_sellable_ticket_types: event.has_sellable_ticket_types(),
             is_live: event.is_live(),
@@ -727,16 +1103,18 @@ impl EventAvailability {
                     EventTicketAvailability::from_ticket_type(
                         ticket_type,
                         event.payment_currency_code.as_deref(),
+                        can_view_attendees,
                     )
                 })
                 .collect(),
-            waitlist_count: event.waitlist_count,
             waitlist_enabled: event.waitlist_enabled,
 
-            capacity: event.capacity,
+            attendee_count: can_view_attendees.then_some(event.attendee_count),
+            capacity: event.capacity.filter(|_| can_view_attendees),
             registration_window_message: event.registration_window_message(),
             registration_window_unavailable_title: event.registration_window_unavailable_title(),
-            remaining_capacity: event.remaining_capacity,
+            remaining_capacity: event.remaining_capacity.filter(|_| can_view_attendees),
+            waitlist_count: can_view_attendees.then_some(event.waitlist_count),
         }
     }
 }
@@ -755,7 +1133,8 @@ struct EventTicketAvailability {
 
     /// Current attendee-facing price label for this ticket type.
     current_price_label: Option<String>,
-    /// Number of seats still available for this ticket type.
+    /// Number of seats still available for this ticket type, hidden from
+    /// non-members of private groups.
     remaining_seats: Option<i32>,
 }
 
@@ -764,6 +1143,7 @@ impl EventTicketAvailability {
     fn from_ticket_type(
         ticket_type: &EventTicketType,
         payment_currency_code: Option<&str>,
+        can_view_attendees: bool,
     ) -> Self {
         Self {
             active: ticket_type.active,
@@ -771,13 +1151,32 @@ impl EventTicketAvailability {
             is_sellable_now: ticket_type.is_sellable_now(),
             sold_out: ticket_type.sold_out,
 
-            current_price_label: payment_currency_code
-                .and_then(|currency_code| ticket_type.formatted_current_price(currency_code)),
-            remaining_seats: ticket_type.remaining_seats,
+            current_price_label: ticket_type.formatted_current_price(payment_currency_code),
+            remaining_seats: ticket_type.remaining_seats.filter(|_| can_view_attendees),
         }
     }
 }
 
+/// Event discussion comment form data.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct CommentInput {
+    /// Comment content.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_L))]
+    content: String,
+
+    /// Comment being replied to, if any.
+    #[garde(skip)]
+    parent_event_comment_id: Option<Uuid>,
+}
+
+/// Shared event photo form data.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct PhotoSubmissionInput {
+    /// URL of the uploaded photo.
+    #[garde(custom(image_url), length(max = MAX_LEN_L))]
+    url: String,
+}
+
 /// Refund request form data.
 #[derive(Debug, Deserialize, Validate)]
 pub(crate) struct RefundRequestInput {
@@ -786,6 +1185,17 @@ pub(crate) struct RefundRequestInput {
     requested_reason: Option<String>,
 }
 
+/// Event reminder preference form data.
+#[serde_as]
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct ReminderPreferenceInput {
+    /// Reminder preference for the event, or none to use the profile one.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    #[garde(skip)]
+    reminder_preference: Option<EventReminderPreference>,
+}
+
 // Helpers.
 
 /// Creates or reuses a pending checkout hold for the attendee.
@@ -891,8 +1301,8 @@ fn should_redirect_to_pretty_group_slug(event: &EventFull, group_slug: &str) ->
         .is_some_and(|_| group_slug == event.group.slug)
 }
 
-/// Validates submitted registration answers against the event questionnaire.
-fn validate_registration_answers(
+/// Validates submitted registration answers against an event or group questionnaire.
+pub(crate) fn validate_registration_answers(
     registration_answers: Option<&QuestionnaireAnswers>,
     registration_questions: &[QuestionnaireQuestion],
 ) -> Result<(), HandlerError> {
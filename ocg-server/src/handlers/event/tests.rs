@@ -3,7 +3,7 @@ use axum::{
     body::{Body, to_bytes},
     http::{
         HeaderValue, Request, StatusCode,
-        header::{CACHE_CONTROL, CONTENT_TYPE, COOKIE, LOCATION},
+        header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE, COOKIE, LOCATION},
     },
 };
 use axum_login::tower_sessions::session;
@@ -18,6 +18,7 @@ use crate::{
     handlers::tests::*,
     router::{CACHE_CONTROL_NO_STORE, CACHE_CONTROL_PUBLIC_SHARED},
     services::{
+        images::{Image, MockImageStorage},
         notifications::{MockNotificationsManager, NotificationKind},
         payments::MockPaymentsManager,
     },
@@ -26,7 +27,10 @@ use crate::{
         EventWelcome,
     },
     types::{
-        event::{EventAttendanceInfo, EventAttendanceStatus, EventLeaveOutcome},
+        event::{
+            EventAttendanceInfo, EventAttendanceStatus, EventLeaveOutcome, EventReminderPreference,
+            EventResourceFile,
+        },
         payments::{
             EventPurchaseStatus, EventTicketCurrentPrice, EventTicketType, PreparedEventCheckout,
         },
@@ -39,6 +43,69 @@ use crate::{
 
 use super::{HandlerError, get_checkout_status_response};
 
+#[tokio::test]
+async fn test_availability_hides_attendance_figures_from_private_group_non_members() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let mut event = sample_event_full(community_id, event_id, group_id);
+    event.attendee_count = 4;
+    event.remaining_capacity = Some(0);
+    event.ticket_types = Some(vec![EventTicketType {
+        active: true,
+        event_ticket_type_id: Uuid::new_v4(),
+        order: 1,
+        title: "General admission".to_string(),
+
+        remaining_seats: Some(0),
+        seats_total: Some(10),
+        sold_out: true,
+        ..Default::default()
+    }]);
+    event.waitlist_count = 3;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_full_by_slug()
+        .times(1)
+        .withf(move |id, group_slug, event_slug| {
+            *id == community_id && group_slug == "test-group" && event_slug == "test-event"
+        })
+        .returning(move |_, _, _| Ok(Some(event.clone())));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| *cid == community_id && *gid == group_id && uid.is_none())
+        .returning(|_, _, _| Ok(false));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/test-group/event/test-event/availability")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let payload: serde_json::Value = from_slice(&bytes).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(payload["attendee_count"], json!(null));
+    assert_eq!(payload["capacity"], json!(null));
+    assert_eq!(payload["remaining_capacity"], json!(null));
+    assert_eq!(payload["ticket_types"][0]["remaining_seats"], json!(null));
+    assert_eq!(payload["ticket_types"][0]["sold_out"], json!(true));
+    assert_eq!(payload["waitlist_count"], json!(null));
+}
+
 #[tokio::test]
 async fn test_availability_success() {
     // Setup identifiers and data structures
@@ -82,6 +149,10 @@ async fn test_availability_success() {
             *id == community_id && group_slug == "test-group" && event_slug == "test-event"
         })
         .returning(move |_, _, _| Ok(Some(event.clone())));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| *cid == community_id && *gid == group_id && uid.is_none())
+        .returning(|_, _, _| Ok(true));
 
     // Setup router and send request
     let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
@@ -118,6 +189,7 @@ async fn test_availability_success() {
     );
     assert_eq!(payload["ticket_types"][0]["is_sellable_now"], json!(true));
     assert_eq!(payload["ticket_types"][0]["remaining_seats"], json!(7));
+    assert_eq!(payload["waitlist_count"], json!(0));
 }
 
 #[tokio::test]
@@ -172,6 +244,10 @@ async fn test_page_db_error() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -212,6 +288,10 @@ async fn test_page_not_found() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -221,6 +301,10 @@ async fn test_page_not_found() {
             *id == community_id && group_slug == "test-group" && event_slug == "missing-event"
         })
         .returning(move |_, _, _| Ok(None));
+    db.expect_get_merged_group_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "test-group")
+        .returning(|_, _| Ok(None));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -251,6 +335,54 @@ async fn test_page_not_found() {
     assert!(body.contains("Go to home page"));
 }
 
+#[tokio::test]
+async fn test_page_permanently_redirects_merged_group_event_to_target_group() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_event_full_by_slug()
+        .times(1)
+        .withf(move |id, group_slug, event_slug| {
+            *id == community_id && group_slug == "merged-group" && event_slug == "test-event"
+        })
+        .returning(move |_, _, _| Ok(None));
+    db.expect_get_merged_group_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "merged-group")
+        .returning(|_, _| Ok(Some("target-group".to_string())));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/merged-group/event/test-event")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/test-community/group/target-group/event/test-event")
+    );
+}
+
 #[tokio::test]
 async fn test_page_temporarily_redirects_generated_group_slug_to_pretty_slug() {
     // Setup identifiers and data structures
@@ -268,6 +400,10 @@ async fn test_page_temporarily_redirects_generated_group_slug_to_pretty_slug() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -322,6 +458,10 @@ async fn test_page_success() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
@@ -387,56 +527,37 @@ async fn test_page_success() {
 }
 
 #[tokio::test]
-async fn test_check_in_page_success() {
+async fn test_page_success_with_series() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let event_id = Uuid::new_v4();
+    let event_series_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
-    let session_id = session::Id::default();
-    let user_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
-    let event_summary = sample_event_summary(event_id, group_id);
+    let mut event = sample_event_full(community_id, event_id, group_id);
+    event.event_series_id = Some(event_series_id);
+    event.has_related_events = true;
+    let series = sample_event_series(community_id, event_series_id, group_id);
 
     // Setup database mock
     let mut db = MockDB::new();
-    db.expect_get_session()
-        .times(1)
-        .withf(move |id| *id == session_id)
-        .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_user_by_id()
-        .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
     db.expect_get_community_id_by_name()
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
-    db.expect_get_event_summary_by_id()
-        .times(1)
-        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
-        .returning(move |_, _| Ok(event_summary.clone()));
-    db.expect_get_event_attendance()
-        .times(1)
-        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
-        .returning(|_, _, _| {
-            Ok(EventAttendanceInfo {
-                is_checked_in: false,
-                manually_invited: false,
-                status: EventAttendanceStatus::Attendee,
-
-                purchase_amount_minor: None,
-                refund_request_status: None,
-                resume_checkout_url: None,
-            })
-        });
-    db.expect_is_event_check_in_window_open()
+    db.expect_get_community_theme()
         .times(1)
-        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
-        .returning(|_, _| Ok(true));
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
+    db.expect_get_event_full_by_slug()
+        .times(1)
+        .returning(move |_, _, _| Ok(Some(event.clone())));
+    db.expect_get_event_series()
+        .times(1)
+        .withf(move |cid, sid| *cid == community_id && *sid == event_series_id)
+        .returning(move |_, _| Ok(Some(series.clone())));
 
     // Setup router and send request
     let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
@@ -444,8 +565,7 @@ async fn test_check_in_page_success() {
         .await;
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/test-community/check-in/{event_id}"))
-        .header(COOKIE, format!("id={session_id}"))
+        .uri("/test-community/group/test-group/event/test-event")
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
@@ -454,46 +574,69 @@ async fn test_check_in_page_success() {
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::OK);
-    assert_eq!(
-        parts.headers.get(CONTENT_TYPE),
-        Some(&HeaderValue::from_static("text/html; charset=utf-8"))
-    );
-    assert!(!bytes.is_empty());
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("Part of Sample Series"));
+    assert!(body.contains(&format!("/test/group/npq6789/series/{event_series_id}")));
 }
 
 #[tokio::test]
-async fn test_cfs_modal_rejects_invalid_event_id_before_community_lookup() {
-    // Prevent community resolution for an invalid event identifier
+async fn test_series_page_hides_attendance_figures_from_private_group_non_members() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_series_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let series = sample_event_series(community_id, event_series_id, group_id);
+
+    // Setup database mock
     let mut db = MockDB::new();
-    db.expect_get_community_id_by_name().never();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_event_series()
+        .times(1)
+        .withf(move |cid, sid| *cid == community_id && *sid == event_series_id)
+        .returning(move |_, _| Ok(Some(series.clone())));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| *cid == community_id && *gid == group_id && uid.is_none())
+        .returning(|_, _, _| Ok(false));
 
-    // Request the modal with a malformed event identifier
+    // Setup router and send request
     let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .with_server_cfg(sample_tracking_server_cfg())
         .build()
         .await;
     let request = Request::builder()
         .method("GET")
-        .uri("/test-community/event/not-a-uuid/cfs-modal")
+        .uri(format!(
+            "/test-community/group/npq6789/series/{event_series_id}"
+        ))
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
-    let body = String::from_utf8(bytes.to_vec()).unwrap();
 
-    // Check path validation rejects the request before community lookup
-    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
-    assert!(body.contains("Invalid URL:"));
-    assert!(body.contains("not-a-uuid"));
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("Sample Event"));
+    assert!(!body.contains("Checked in"));
 }
 
 #[tokio::test]
-async fn test_cfs_modal_success_anonymous() {
+async fn test_series_page_not_found() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
-    let event_id = Uuid::new_v4();
-    let group_id = Uuid::new_v4();
-    let event_summary = sample_event_summary(event_id, group_id);
+    let event_series_id = Uuid::new_v4();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -501,23 +644,27 @@ async fn test_cfs_modal_success_anonymous() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
-    db.expect_get_event_summary_by_id()
+    db.expect_get_community_theme()
         .times(1)
-        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
-        .returning(move |_, _| Ok(event_summary.clone()));
-    db.expect_list_event_cfs_labels()
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
         .times(1)
-        .withf(move |eid| *eid == event_id)
-        .returning(|_| Ok(vec![]));
-
-    // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_event_series()
+        .times(1)
+        .withf(move |cid, sid| *cid == community_id && *sid == event_series_id)
+        .returning(move |_, _| Ok(None));
 
     // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/test-community/event/{event_id}/cfs-modal"))
+        .uri(format!(
+            "/test-community/group/test-group/series/{event_series_id}"
+        ))
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
@@ -525,84 +672,60 @@ async fn test_cfs_modal_success_anonymous() {
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::OK);
-    assert_eq!(
-        parts.headers.get(CONTENT_TYPE).unwrap(),
-        &HeaderValue::from_static("text/html; charset=utf-8")
-    );
-    assert!(!bytes.is_empty());
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("We could not find that page"));
 }
 
 #[tokio::test]
-async fn test_cfs_modal_success_authenticated() {
+async fn test_series_page_not_found_for_other_group() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
-    let event_id = Uuid::new_v4();
+    let event_series_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
-    let session_id = session::Id::default();
-    let user_id = Uuid::new_v4();
-    let session_proposal_id = Uuid::new_v4();
-    let auth_hash = "hash".to_string();
-    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
-    let event_summary = sample_event_summary(event_id, group_id);
-    let proposals = vec![sample_event_cfs_session_proposal(session_proposal_id)];
+    let series = sample_event_series(community_id, event_series_id, group_id);
 
     // Setup database mock
     let mut db = MockDB::new();
-    db.expect_get_session()
-        .times(1)
-        .withf(move |id| *id == session_id)
-        .returning(move |_| Ok(Some(session_record.clone())));
-    db.expect_get_user_by_id()
-        .times(1)
-        .withf(move |id| *id == user_id)
-        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
     db.expect_get_community_id_by_name()
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
-    db.expect_get_event_summary_by_id()
+    db.expect_get_community_theme()
         .times(1)
-        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
-        .returning(move |_, _| Ok(event_summary.clone()));
-    db.expect_list_event_cfs_labels()
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
         .times(1)
-        .withf(move |eid| *eid == event_id)
-        .returning(|_| Ok(vec![]));
-    db.expect_list_user_session_proposals_for_cfs_event()
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_event_series()
         .times(1)
-        .withf(move |uid, eid| *uid == user_id && *eid == event_id)
-        .returning(move |_, _| Ok(proposals.clone()));
-
-    // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+        .returning(move |_, _| Ok(Some(series.clone())));
 
     // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/test-community/event/{event_id}/cfs-modal"))
-        .header(COOKIE, format!("id={session_id}"))
+        .uri(format!(
+            "/test-community/group/other-group/series/{event_series_id}"
+        ))
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
-    let (parts, body) = response.into_parts();
-    let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::OK);
-    assert_eq!(
-        parts.headers.get(CONTENT_TYPE).unwrap(),
-        &HeaderValue::from_static("text/html; charset=utf-8")
-    );
-    assert!(!bytes.is_empty());
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
 #[tokio::test]
-async fn test_cfs_modal_db_error() {
+async fn test_series_page_success() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
-    let event_id = Uuid::new_v4();
+    let event_series_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let series = sample_event_series(community_id, event_series_id, group_id);
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -610,11 +733,589 @@ async fn test_cfs_modal_db_error() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
-    db.expect_get_event_summary_by_id()
+    db.expect_get_community_theme()
         .times(1)
-        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
-        .returning(move |_, _| Err(anyhow!("db error")));
-
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_event_series()
+        .times(1)
+        .withf(move |cid, sid| *cid == community_id && *sid == event_series_id)
+        .returning(move |_, _| Ok(Some(series.clone())));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| *cid == community_id && *gid == group_id && uid.is_none())
+        .returning(|_, _, _| Ok(true));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .with_server_cfg(sample_tracking_server_cfg())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/test-community/group/npq6789/series/{event_series_id}"
+        ))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("<title>Sample Series - Test Group</title>"));
+    assert!(body.contains(&format!(
+        r#"<link rel="canonical" href="https://example.test/test/group/npq6789/series/{event_series_id}">"#
+    )));
+    assert!(body.contains("Sample Event"));
+    assert!(body.contains("Checked in"));
+}
+
+#[tokio::test]
+async fn test_speaker_page_not_found() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_speaker_profile()
+        .times(1)
+        .withf(move |cid, username| *cid == community_id && username == "speaker")
+        .returning(|_, _| Ok(None));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/speaker/speaker")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("We could not find that page"));
+}
+
+#[tokio::test]
+async fn test_speaker_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let profile = sample_speaker_profile(community_id, user_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_speaker_profile()
+        .times(1)
+        .withf(move |cid, username| *cid == community_id && username == "speaker")
+        .returning(move |_, _| Ok(Some(profile.clone())));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .with_server_cfg(sample_tracking_server_cfg())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/speaker/speaker")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("<title>Sample Speaker - Test</title>"));
+    assert!(
+        body.contains(r#"<link rel="canonical" href="https://example.test/test/speaker/speaker">"#)
+    );
+    assert!(body.contains("Sample Talk"));
+    assert!(body.contains("Sample Event"));
+}
+
+#[tokio::test]
+async fn test_check_in_page_expired_code() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let event_summary = sample_event_summary(event_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event_summary.clone()));
+    db.expect_get_event_attendance()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| {
+            Ok(EventAttendanceInfo {
+                is_checked_in: false,
+                manually_invited: false,
+                status: EventAttendanceStatus::Attendee,
+
+                purchase_amount_minor: None,
+                refund_request_status: None,
+                reminder_preference: None,
+                resume_checkout_url: None,
+            })
+        });
+    db.expect_is_event_check_in_window_open()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(|_, _| Ok(true));
+    db.expect_is_event_check_in_code_valid()
+        .times(1)
+        .withf(move |cid, eid, code| *cid == community_id && *eid == event_id && code == "expired")
+        .returning(|_, _, _| Ok(false));
+    db.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(None));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/check-in/{event_id}?code=expired"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE),
+        Some(&HeaderValue::from_static("text/html; charset=utf-8"))
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("This QR code has expired"));
+}
+
+#[tokio::test]
+async fn test_check_in_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let event_summary = sample_event_summary(event_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event_summary.clone()));
+    db.expect_get_event_attendance()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| {
+            Ok(EventAttendanceInfo {
+                is_checked_in: false,
+                manually_invited: false,
+                status: EventAttendanceStatus::Attendee,
+
+                purchase_amount_minor: None,
+                refund_request_status: None,
+                reminder_preference: None,
+                resume_checkout_url: None,
+            })
+        });
+    db.expect_is_event_check_in_window_open()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(|_, _| Ok(true));
+    db.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(Some(Uuid::new_v4())));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/check-in/{event_id}"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE),
+        Some(&HeaderValue::from_static("text/html; charset=utf-8"))
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("check-in-ticket"));
+    assert!(body.contains("<svg"));
+}
+
+#[tokio::test]
+async fn test_user_calendar_not_found() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_user_calendar_events()
+        .times(1)
+        .withf(|token| token == "unknown-token")
+        .returning(|_| Ok(None));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/calendar/unknown-token/events.ics")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_user_calendar_success() {
+    // Setup identifiers and data structures
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let mut event = sample_event_summary(event_id, group_id);
+    event.meeting_join_url = Some("https://example.test/live".to_string());
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_user_calendar_events()
+        .times(1)
+        .withf(|token| token == "token")
+        .returning(move |_| Ok(Some(vec![event.clone()])));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/calendar/token/events.ics")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/calendar; charset=utf-8")
+    );
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_NO_STORE)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap().replace("\r\n ", "");
+    assert!(body.contains("BEGIN:VCALENDAR"));
+    assert!(body.contains(&format!("UID:{event_id}")));
+    assert!(body.contains("Meeting link: https://example.test/live"));
+}
+
+#[tokio::test]
+async fn test_cfs_modal_rejects_invalid_event_id_before_community_lookup() {
+    // Prevent community resolution for an invalid event identifier
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name().never();
+
+    // Request the modal with a malformed event identifier
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/event/not-a-uuid/cfs-modal")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check path validation rejects the request before community lookup
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert!(body.contains("Invalid URL:"));
+    assert!(body.contains("not-a-uuid"));
+}
+
+#[tokio::test]
+async fn test_cfs_modal_success_anonymous() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let event_summary = sample_event_summary(event_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event_summary.clone()));
+    db.expect_list_event_cfs_labels()
+        .times(1)
+        .withf(move |eid| *eid == event_id)
+        .returning(|_| Ok(vec![]));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/event/{event_id}/cfs-modal"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_cfs_modal_success_authenticated() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let session_proposal_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let event_summary = sample_event_summary(event_id, group_id);
+    let proposals = vec![sample_event_cfs_session_proposal(session_proposal_id)];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event_summary.clone()));
+    db.expect_list_event_cfs_labels()
+        .times(1)
+        .withf(move |eid| *eid == event_id)
+        .returning(|_| Ok(vec![]));
+    db.expect_list_user_session_proposals_for_cfs_event()
+        .times(1)
+        .withf(move |uid, eid| *uid == user_id && *eid == event_id)
+        .returning(move |_, _| Ok(proposals.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/event/{event_id}/cfs-modal"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_cfs_modal_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/event/{event_id}/cfs-modal"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_discussion_success_anonymous() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let discussion = sample_event_discussion();
+    let event_summary = sample_event_summary(event_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event_summary.clone()));
+    db.expect_get_event_discussion()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(discussion.clone()));
+
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
 
@@ -622,12 +1323,305 @@ async fn test_cfs_modal_db_error() {
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
         .method("GET")
-        .uri(format!("/test-community/event/{event_id}/cfs-modal"))
+        .uri(format!("/test-community/event/{event_id}/discussion"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(body.contains("Will the talks be recorded?"));
+    assert!(body.contains("to ask a question or join the conversation"));
+    assert!(!body.contains("discussion-comment-form"));
+}
+
+#[tokio::test]
+async fn test_download_resource_not_found() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let event_resource_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_resource()
+        .times(1)
+        .withf(move |cid, eid, rid| {
+            *cid == community_id && *eid == event_id && *rid == event_resource_id
+        })
+        .returning(|_, _, _| Ok(None));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/test-community/event/{event_id}/resources/{event_resource_id}"
+        ))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_download_resource_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let event_resource_id = Uuid::new_v4();
+    let resource = EventResourceFile {
+        content_type: "application/pdf".to_string(),
+        file_name: "abc123.pdf".to_string(),
+        name: "Talk slides.pdf".to_string(),
+    };
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_resource()
+        .times(1)
+        .withf(move |cid, eid, rid| {
+            *cid == community_id && *eid == event_id && *rid == event_resource_id
+        })
+        .returning(move |_, _, _| Ok(Some(resource.clone())));
+
+    // Setup image storage mock
+    let mut storage = MockImageStorage::new();
+    storage
+        .expect_get()
+        .times(1)
+        .withf(|file_name| file_name == "abc123.pdf")
+        .returning(|_| {
+            Box::pin(async {
+                Ok(Some(Image {
+                    bytes: b"%PDF-1.7".to_vec(),
+                    content_type: "application/pdf".to_string(),
+                }))
+            })
+        });
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_image_storage(storage)
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/test-community/event/{event_id}/resources/{event_resource_id}"
+        ))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("application/pdf")
+    );
+    assert_eq!(
+        parts.headers.get(CONTENT_DISPOSITION).unwrap(),
+        &HeaderValue::from_static("attachment; filename*=UTF-8''Talk%20slides%2Epdf")
+    );
+    assert_eq!(bytes.as_ref(), b"%PDF-1.7");
+}
+
+#[tokio::test]
+async fn test_photos_modal_success_anonymous() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let event_summary = sample_event_summary(event_id, group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event_summary.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/event/{event_id}/photos-modal"))
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(body.contains("You need to sign in to share your photos"));
+}
+
+#[tokio::test]
+async fn test_add_comment_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let parent_event_comment_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let discussion = sample_event_discussion();
+    let event_summary = sample_event_summary(event_id, group_id);
+    let form_data = format!(
+        "content=Thanks+for+the+answer%21&parent_event_comment_id={parent_event_comment_id}"
+    );
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_add_event_comment()
+        .times(1)
+        .withf(move |cid, eid, uid, content, parent_id| {
+            *cid == community_id
+                && *eid == event_id
+                && *uid == user_id
+                && content == "Thanks for the answer!"
+                && *parent_id == Some(parent_event_comment_id)
+        })
+        .returning(|_, _, _, _, _| Ok(Uuid::new_v4()));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event_summary.clone()));
+    db.expect_get_event_discussion()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(discussion.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/test-community/event/{event_id}/comments"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(body.contains("discussion-comment-form"));
+}
+
+#[tokio::test]
+async fn test_add_comment_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let form_data = "content=Is+there+parking+nearby%3F";
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_add_event_comment()
+        .times(1)
+        .withf(move |cid, eid, uid, content, parent_id| {
+            *cid == community_id
+                && *eid == event_id
+                && *uid == user_id
+                && content == "Is there parking nearby?"
+                && parent_id.is_none()
+        })
+        .returning(|_, _, _, _, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/test-community/event/{event_id}/comments"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
@@ -681,6 +1675,10 @@ async fn test_attend_event_success() {
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
+    db.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(Some(Uuid::new_v4())));
 
     // Setup notifications manager mock
     let mut nm = MockNotificationsManager::new();
@@ -688,10 +1686,12 @@ async fn test_attend_event_success() {
         .times(1)
         .withf(move |notification| {
             matches!(notification.kind, NotificationKind::EventWelcome)
+                && notification.attachments.len() == 2
                 && notification.recipients == vec![user_id]
                 && notification.template_data.as_ref().is_some_and(|value| {
                     from_value::<EventWelcome>(value.clone()).is_ok_and(|template| {
                         template.link == "/test-community/group/def5678/event/ghi9abc"
+                            && template.ticket_attached
                     })
                 })
         })
@@ -785,6 +1785,10 @@ async fn test_attend_event_success_with_registration_answers() {
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
+    db.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(None));
 
     // Setup notifications manager mock
     let mut nm = MockNotificationsManager::new();
@@ -1064,6 +2068,7 @@ async fn test_attendance_status_success() {
 
                 purchase_amount_minor: None,
                 refund_request_status: None,
+                reminder_preference: Some(EventReminderPreference::OneHour),
                 resume_checkout_url: None,
             })
         });
@@ -1098,6 +2103,7 @@ async fn test_attendance_status_success() {
             "manually_invited": false,
             "purchase_amount_minor": null,
             "refund_request_status": null,
+            "reminder_preference": "1-hour",
             "resume_checkout_url": null,
             "status": "attendee",
         })
@@ -1139,6 +2145,7 @@ async fn test_attendance_status_stale_event_returns_none_without_summary_lookup(
 
                 purchase_amount_minor: None,
                 refund_request_status: None,
+                reminder_preference: None,
                 resume_checkout_url: None,
             })
         });
@@ -1169,6 +2176,7 @@ async fn test_attendance_status_stale_event_returns_none_without_summary_lookup(
             "manually_invited": false,
             "purchase_amount_minor": null,
             "refund_request_status": null,
+            "reminder_preference": null,
             "resume_checkout_url": null,
             "status": "none",
         })
@@ -1274,6 +2282,58 @@ async fn test_cancel_checkout_returns_internal_server_error_when_db_fails() {
     assert!(bytes.is_empty());
 }
 
+#[tokio::test]
+async fn test_check_in_expired_code() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_is_event_check_in_code_valid()
+        .times(1)
+        .withf(move |cid, eid, code| *cid == community_id && *eid == event_id && code == "expired")
+        .returning(|_, _, _| Ok(false));
+    db.expect_check_in_event().never();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/test-community/check-in/{event_id}?code=expired"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        "check-in code expired, please scan the QR code again"
+    );
+}
+
 #[tokio::test]
 async fn test_check_in_success() {
     // Setup identifiers and data structures
@@ -1879,11 +2939,11 @@ async fn test_start_checkout_rejects_refund_requested_purchase() {
                 event_slug: "event".to_string(),
                 group_slug: "group".to_string(),
                 purchase: sample_purchase_summary(EventPurchaseStatus::RefundRequested),
-                recipient: crate::types::payments::GroupPaymentRecipient {
+                group_slug_pretty: None,
+                recipient: Some(crate::types::payments::GroupPaymentRecipient {
                     provider: crate::types::payments::PaymentProvider::Stripe,
                     recipient_id: "acct_test_123".to_string(),
-                },
-                group_slug_pretty: None,
+                }),
             })
         });
 
@@ -1998,11 +3058,11 @@ async fn test_start_checkout_allows_active_hold_after_registration_window_closes
                 event_slug: "event".to_string(),
                 group_slug: "group".to_string(),
                 purchase: purchase.clone(),
-                recipient: crate::types::payments::GroupPaymentRecipient {
+                group_slug_pretty: None,
+                recipient: Some(crate::types::payments::GroupPaymentRecipient {
                     provider: crate::types::payments::PaymentProvider::Stripe,
                     recipient_id: "acct_test_123".to_string(),
-                },
-                group_slug_pretty: None,
+                }),
             })
         });
 
@@ -2118,11 +3178,11 @@ async fn test_start_checkout_allows_active_hold_when_tickets_are_unavailable() {
                 event_slug: "event".to_string(),
                 group_slug: "group".to_string(),
                 purchase: purchase.clone(),
-                recipient: crate::types::payments::GroupPaymentRecipient {
+                group_slug_pretty: None,
+                recipient: Some(crate::types::payments::GroupPaymentRecipient {
                     provider: crate::types::payments::PaymentProvider::Stripe,
                     recipient_id: "acct_test_123".to_string(),
-                },
-                group_slug_pretty: None,
+                }),
             })
         });
 
@@ -2437,6 +3497,128 @@ async fn test_submit_cfs_submission_db_error() {
     assert!(bytes.is_empty());
 }
 
+#[tokio::test]
+async fn test_submit_photo_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let event_summary = sample_event_summary(event_id, group_id);
+    let form_data = "url=https%3A%2F%2Fexample.test%2Fphoto.png";
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_add_event_photo()
+        .times(1)
+        .withf(move |cid, eid, uid, url| {
+            *cid == community_id
+                && *eid == event_id
+                && *uid == user_id
+                && url == "https://example.test/photo.png"
+        })
+        .returning(|_, _, _, _| Ok(Uuid::new_v4()));
+    db.expect_get_event_summary_by_id()
+        .times(1)
+        .withf(move |cid, eid| *cid == community_id && *eid == event_id)
+        .returning(move |_, _| Ok(event_summary.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/test-community/event/{event_id}/photos"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_submit_photo_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let form_data = "url=https%3A%2F%2Fexample.test%2Fphoto.png";
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_add_event_photo()
+        .times(1)
+        .withf(move |cid, eid, uid, url| {
+            *cid == community_id
+                && *eid == event_id
+                && *uid == user_id
+                && url == "https://example.test/photo.png"
+        })
+        .returning(|_, _, _, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/test-community/event/{event_id}/photos"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_data))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
 #[tokio::test]
 async fn test_track_view_success() {
     // Setup identifiers and data structures
@@ -2509,3 +3691,117 @@ async fn test_track_view_ignores_cross_origin_request() {
     assert_eq!(parts.status, StatusCode::NO_CONTENT);
     assert!(bytes.is_empty());
 }
+
+#[tokio::test]
+async fn test_update_reminder_preference_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_update_event_reminder_preference()
+        .times(1)
+        .withf(move |cid, eid, uid, reminder_preference| {
+            *cid == community_id
+                && *eid == event_id
+                && *uid == user_id
+                && *reminder_preference == Some(EventReminderPreference::OneWeek)
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/test-community/event/{event_id}/reminder-preference"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("reminder_preference=1-week"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_reminder_preference_clears_preference_when_empty() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_update_event_reminder_preference()
+        .times(1)
+        .withf(move |cid, eid, uid, reminder_preference| {
+            *cid == community_id
+                && *eid == event_id
+                && *uid == user_id
+                && reminder_preference.is_none()
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/test-community/event/{event_id}/reminder-preference"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("reminder_preference="))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NO_CONTENT);
+    assert!(bytes.is_empty());
+}
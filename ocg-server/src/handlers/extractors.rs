@@ -1,16 +1,22 @@
 //! Custom extractors for handlers.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
 
 use axum::{
     Form,
-    extract::{FromRequest, FromRequestParts, Path, Request},
-    http::{StatusCode, request::Parts},
+    extract::{ConnectInfo, FromRequest, FromRequestParts, Path, Request},
+    http::{StatusCode, header::USER_AGENT, request::Parts},
 };
 use garde::Validate;
-use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use tracing::{error, instrument};
 use uuid::Uuid;
+use webauthn_rs::prelude::Webauthn;
 
 use crate::{
     auth::{AuthSession, OAuth2ProviderDetails, OidcProviderDetails, User as AuthUser},
@@ -21,6 +27,59 @@ use crate::{
 #[cfg(test)]
 mod tests;
 
+/// Maximum number of characters of the user agent kept in the session.
+const USER_AGENT_MAX_LEN: usize = 256;
+
+/// Extractor for the IP address of the client sending the request.
+///
+/// The address of the peer connected to the server is used, unless it is one
+/// of the configured trusted proxies. In that case, the `X-Forwarded-For`
+/// header is walked from the last address, skipping the trusted proxies, and
+/// the first address not trusted is used.
+pub(crate) struct ClientIp(pub Option<IpAddr>);
+
+impl FromRequestParts<router::State> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &router::State,
+    ) -> Result<Self, Self::Rejection> {
+        // Get the address of the connected peer
+        let Some(peer_ip) = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip())
+        else {
+            return Ok(ClientIp(None));
+        };
+
+        // Only trust the forwarded addresses set by the configured proxies
+        let trusted_proxies = state.server_cfg.trusted_proxies.as_deref().unwrap_or_default();
+        let mut client_ip = peer_ip;
+        if trusted_proxies.contains(&client_ip) {
+            let forwarded_ips = parts
+                .headers
+                .get_all("x-forwarded-for")
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .collect::<Vec<_>>();
+            for value in forwarded_ips.into_iter().rev() {
+                let Ok(forwarded_ip) = value.trim().parse() else {
+                    break;
+                };
+                client_ip = forwarded_ip;
+                if !trusted_proxies.contains(&client_ip) {
+                    break;
+                }
+            }
+        }
+
+        Ok(ClientIp(Some(client_ip)))
+    }
+}
+
 /// Extractor that resolves a community ID from the request path parameter.
 pub(crate) struct CommunityId(pub Uuid);
 
@@ -131,6 +190,27 @@ impl FromRequestParts<router::State> for Oidc {
     }
 }
 
+/// Extractor for the `WebAuthn` relying party used to handle passkeys.
+pub(crate) struct Passkeys(pub Arc<Webauthn>);
+
+impl FromRequestParts<router::State> for Passkeys {
+    type Rejection = (StatusCode, &'static str);
+
+    #[instrument(skip_all, err(Debug))]
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &router::State,
+    ) -> Result<Self, Self::Rejection> {
+        let Ok(auth_session) = AuthSession::from_request_parts(parts, state).await else {
+            return Err((StatusCode::BAD_REQUEST, "missing auth session"));
+        };
+        let Some(webauthn) = auth_session.backend.webauthn else {
+            return Err((StatusCode::NOT_FOUND, "passkeys not enabled"));
+        };
+        Ok(Passkeys(webauthn))
+    }
+}
+
 /// Extractor for the selected community ID from request context.
 /// Returns the Uuid from request extensions populated by middleware.
 #[derive(Clone, Copy)]
@@ -171,6 +251,39 @@ impl FromRequestParts<router::State> for SelectedGroupId {
     }
 }
 
+/// Extractor for the details of the client a session is started from.
+///
+/// They are stored in the session when the user logs in, so the user can tell
+/// their sessions apart when reviewing them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SessionClient {
+    /// IP address of the client.
+    pub ip_address: Option<IpAddr>,
+    /// User agent reported by the client, truncated to a reasonable length.
+    pub user_agent: Option<String>,
+}
+
+impl FromRequestParts<router::State> for SessionClient {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &router::State,
+    ) -> Result<Self, Self::Rejection> {
+        let Ok(ClientIp(ip_address)) = ClientIp::from_request_parts(parts, state).await;
+        let user_agent = parts
+            .headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(USER_AGENT_MAX_LEN).collect());
+
+        Ok(SessionClient {
+            ip_address,
+            user_agent,
+        })
+    }
+}
+
 /// Extractor that deserializes and validates form data using Axum's Form extractor.
 ///
 /// Use this for simple, flat form structures. For complex nested structures
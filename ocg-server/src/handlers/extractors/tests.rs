@@ -20,7 +20,7 @@ use crate::{
     auth::AuthnBackend,
     config::{HttpServerConfig, OAuth2ProviderConfig},
     db::{DynDB, mock::MockDB},
-    handlers::tests::{sample_auth_user, test_state},
+    handlers::tests::{sample_auth_user, test_state, test_state_with_server_cfg},
     services::{
         images::{DynImageStorage, MockImageStorage},
         notifications::{DynNotificationsManager, MockNotificationsManager},
@@ -29,6 +29,125 @@ use crate::{
 
 use super::*;
 
+#[tokio::test]
+async fn test_client_ip_extractor_forwarded_for() {
+    // Setup database mock
+    let db: DynDB = Arc::new(MockDB::new());
+
+    // Setup services mocks
+    let is: DynImageStorage = Arc::new(MockImageStorage::new());
+    let nm: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+
+    // Setup request parts and state
+    let mut request = Request::builder()
+        .uri("/")
+        .header("x-forwarded-for", "198.51.100.1, 203.0.113.7")
+        .body(Body::empty())
+        .unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 8080))));
+    let (mut parts, _) = request.into_parts();
+    let server_cfg = HttpServerConfig {
+        trusted_proxies: Some(vec![IpAddr::from([10, 0, 0, 1])]),
+        ..Default::default()
+    };
+    let state = test_state_with_server_cfg(db, is, nm, &server_cfg);
+
+    // Check extraction matches expectations
+    let Ok(ClientIp(client_ip)) = ClientIp::from_request_parts(&mut parts, &state).await;
+    assert_eq!(client_ip, Some(IpAddr::from([203, 0, 113, 7])));
+}
+
+#[tokio::test]
+async fn test_client_ip_extractor_forwarded_for_skips_trusted_proxies() {
+    // Setup database mock
+    let db: DynDB = Arc::new(MockDB::new());
+
+    // Setup services mocks
+    let is: DynImageStorage = Arc::new(MockImageStorage::new());
+    let nm: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+
+    // Setup request parts and state
+    let mut request = Request::builder()
+        .uri("/")
+        .header("x-forwarded-for", "192.0.2.9, 198.51.100.1, 10.0.0.2")
+        .body(Body::empty())
+        .unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 8080))));
+    let (mut parts, _) = request.into_parts();
+    let server_cfg = HttpServerConfig {
+        trusted_proxies: Some(vec![
+            IpAddr::from([10, 0, 0, 1]),
+            IpAddr::from([10, 0, 0, 2]),
+        ]),
+        ..Default::default()
+    };
+    let state = test_state_with_server_cfg(db, is, nm, &server_cfg);
+
+    // Check extraction matches expectations
+    let Ok(ClientIp(client_ip)) = ClientIp::from_request_parts(&mut parts, &state).await;
+    assert_eq!(client_ip, Some(IpAddr::from([198, 51, 100, 1])));
+}
+
+#[tokio::test]
+async fn test_client_ip_extractor_forwarded_for_untrusted_peer() {
+    // Setup database mock
+    let db: DynDB = Arc::new(MockDB::new());
+
+    // Setup services mocks
+    let is: DynImageStorage = Arc::new(MockImageStorage::new());
+    let nm: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+
+    // Setup request parts and state
+    let mut request = Request::builder()
+        .uri("/")
+        .header("x-forwarded-for", "203.0.113.7")
+        .body(Body::empty())
+        .unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([192, 0, 2, 1], 8080))));
+    let (mut parts, _) = request.into_parts();
+    let server_cfg = HttpServerConfig {
+        trusted_proxies: Some(vec![IpAddr::from([10, 0, 0, 1])]),
+        ..Default::default()
+    };
+    let state = test_state_with_server_cfg(db, is, nm, &server_cfg);
+
+    // Check extraction matches expectations
+    let Ok(ClientIp(client_ip)) = ClientIp::from_request_parts(&mut parts, &state).await;
+    assert_eq!(client_ip, Some(IpAddr::from([192, 0, 2, 1])));
+}
+
+#[tokio::test]
+async fn test_client_ip_extractor_peer_address() {
+    // Setup database mock
+    let db: DynDB = Arc::new(MockDB::new());
+
+    // Setup services mocks
+    let is: DynImageStorage = Arc::new(MockImageStorage::new());
+    let nm: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+
+    // Setup request parts and state
+    let mut request = Request::builder()
+        .uri("/")
+        .header("x-forwarded-for", "invalid")
+        .body(Body::empty())
+        .unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 8080))));
+    let (mut parts, _) = request.into_parts();
+    let state = test_state(db, is, nm);
+
+    // Check extraction matches expectations
+    let Ok(ClientIp(client_ip)) = ClientIp::from_request_parts(&mut parts, &state).await;
+    assert_eq!(client_ip, Some(IpAddr::from([10, 0, 0, 1])));
+}
+
 #[tokio::test]
 async fn test_community_id_extractor_success() {
     // Setup identifiers and data structures
@@ -177,7 +296,7 @@ async fn test_current_user_extractor_session_without_user() {
     // Setup auth layer
     let server_cfg = HttpServerConfig::default();
     let session_layer = SessionManagerLayer::new(MemoryStore::default());
-    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc)
+    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc, None, None)
         .await
         .expect("backend setup should succeed");
     let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
@@ -253,7 +372,7 @@ async fn test_current_user_extractor_success() {
     // Setup auth layer
     let server_cfg = HttpServerConfig::default();
     let session_layer = SessionManagerLayer::new(MemoryStore::default());
-    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc)
+    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc, None, None)
         .await
         .expect("backend setup should succeed");
     let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
@@ -332,7 +451,7 @@ async fn test_oauth2_extractor_success() {
 
     // Setup auth layer with the configured provider
     let session_layer = SessionManagerLayer::new(MemoryStore::default());
-    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc)
+    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc, None, None)
         .await
         .expect("backend setup should succeed");
     let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
@@ -435,7 +554,7 @@ async fn test_oauth2_extractor_unsupported_provider() {
     // Setup auth layer with an empty set of OAuth2 providers
     let server_cfg = HttpServerConfig::default();
     let session_layer = SessionManagerLayer::new(MemoryStore::default());
-    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc)
+    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc, None, None)
         .await
         .expect("backend setup should succeed");
     let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
@@ -531,7 +650,7 @@ async fn test_oidc_extractor_unsupported_provider() {
     // Setup auth layer with an empty set of OIDC providers
     let server_cfg = HttpServerConfig::default();
     let session_layer = SessionManagerLayer::new(MemoryStore::default());
-    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc)
+    let backend = AuthnBackend::new(db.clone(), &server_cfg.oauth2, &server_cfg.oidc, None, None)
         .await
         .expect("backend setup should succeed");
     let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
@@ -660,6 +779,39 @@ async fn test_selected_group_id_extractor_missing_context() {
     ));
 }
 
+#[tokio::test]
+async fn test_session_client_extractor() {
+    // Setup database mock
+    let db: DynDB = Arc::new(MockDB::new());
+
+    // Setup services mocks
+    let is: DynImageStorage = Arc::new(MockImageStorage::new());
+    let nm: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+
+    // Setup request parts and state
+    let user_agent = "a".repeat(USER_AGENT_MAX_LEN + 10);
+    let mut request = Request::builder()
+        .uri("/")
+        .header("user-agent", &user_agent)
+        .body(Body::empty())
+        .unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 8080))));
+    let (mut parts, _) = request.into_parts();
+    let state = test_state(db, is, nm);
+
+    // Check extraction matches expectations
+    let Ok(client) = SessionClient::from_request_parts(&mut parts, &state).await;
+    assert_eq!(
+        client,
+        SessionClient {
+            ip_address: Some(IpAddr::from([10, 0, 0, 1])),
+            user_agent: Some(user_agent[..USER_AGENT_MAX_LEN].to_string()),
+        }
+    );
+}
+
 #[tokio::test]
 async fn test_validated_form_success() {
     // Setup database mock
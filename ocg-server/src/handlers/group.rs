@@ -1,33 +1,52 @@
 //! HTTP handlers for the group site.
 
+use anyhow::Result;
 use askama::Template;
 use axum::{
     Json,
     extract::{Path, State},
-    http::{HeaderMap, StatusCode, Uri},
+    http::{HeaderMap, StatusCode, Uri, header::CONTENT_TYPE},
     response::{Html, IntoResponse, Redirect},
 };
+use chrono::{Duration, Utc};
+use garde::Validate;
+use serde::Deserialize;
 use serde_json::json;
 use tracing::{instrument, warn};
 use uuid::Uuid;
 
 use crate::{
     activity_tracker::{Activity, DynActivityTracker},
+    auth::AuthSession,
     config::HttpServerConfig,
     db::DynDB,
     handlers::{
-        extractors::CurrentUser, request_matches_site, site::not_found, trim_public_gallery_images,
+        auth::CAPTCHA_FAILED_MESSAGE,
+        event::validate_registration_answers,
+        extractors::{ClientIp, CurrentUser, ValidatedForm},
+        request_matches_site,
+        site::not_found,
+        trim_public_gallery_images,
     },
+    i18n::Language,
     router::PUBLIC_SHARED_CACHE_HEADERS,
-    services::notifications::{DynNotificationsManager, NewNotification, NotificationKind},
+    services::{
+        captcha,
+        notifications::{DynNotificationsManager, NewNotification, NotificationKind},
+    },
     templates::{
         PageId,
-        auth::User,
-        group::{self, Page},
-        notifications::GroupWelcome,
+        auth::{CaptchaWidget, User},
+        group::{self, ContactSection, DiscussionSection, Page},
+        notifications::{GroupContactMessage, GroupWelcome},
+    },
+    types::{
+        event::EventKind,
+        group::{GroupDiscussion, GroupFull, GroupMembershipStatus},
+        questionnaire::OptionalQuestionnaireAnswersForm,
     },
-    types::{event::EventKind, group::GroupFull},
-    util::base_url_without_trailing_slash,
+    util::{base_url_without_trailing_slash, build_events_atom_feed, build_events_calendar_feed},
+    validation::{MAX_LEN_L, MAX_LEN_M, trimmed_non_empty},
 };
 
 use super::{error::HandlerError, extractors::CommunityId};
@@ -35,8 +54,143 @@ use super::{error::HandlerError, extractors::CommunityId};
 #[cfg(test)]
 mod tests;
 
+/// Maximum number of upcoming events included in the group calendar and
+/// events feeds.
+const FEEDS_EVENTS_LIMIT: i32 = 100;
+
+/// Maximum number of messages a user can send to group organizers per window.
+const CONTACT_MESSAGES_LIMIT: i32 = 3;
+
+/// Window, in hours, used to limit the messages sent to group organizers.
+const CONTACT_MESSAGES_WINDOW_HOURS: i64 = 24;
+
 // Pages handlers.
 
+/// Handler that returns the group calendar feed in iCalendar format.
+#[instrument(skip_all)]
+pub(crate) async fn calendar(
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path((community_name, group_slug)): Path<(String, String)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get community and site settings
+    let (community_id, site_settings) = tokio::try_join!(
+        db.get_community_id_by_name(&community_name),
+        db.get_site_settings()
+    )?;
+    let Some(community_id) = community_id else {
+        return not_found::render(site_settings);
+    };
+
+    // Fetch the group and its upcoming events
+    let event_kinds = vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid];
+    let (group, upcoming_events) = tokio::try_join!(
+        db.get_group_full_by_slug(community_id, &group_slug),
+        db.get_group_upcoming_events(community_id, &group_slug, event_kinds, FEEDS_EVENTS_LIMIT)
+    )?;
+    let Some(group) = group else {
+        return not_found::render(site_settings);
+    };
+
+    // Build the calendar feed, leaving out the attendee-only meeting details
+    let feed =
+        build_events_calendar_feed(&server_cfg.base_url, &group.name, &upcoming_events, false);
+
+    Ok((
+        PUBLIC_SHARED_CACHE_HEADERS,
+        [(CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        feed,
+    )
+        .into_response())
+}
+
+/// Handler that renders the group contact organizers section.
+#[instrument(skip_all, err)]
+pub(crate) async fn contact(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path((_, group_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get group and user from session
+    let group = db.get_group_summary(community_id, group_id).await?;
+    let user = User::from_session(auth_session).await?;
+
+    // Prepare template
+    let template = ContactSection {
+        captcha: CaptchaWidget::from_server_cfg(&server_cfg),
+        group,
+        message_sent: false,
+        user,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+/// Handler that renders the group discussion section.
+#[instrument(skip_all, err)]
+pub(crate) async fn discussion(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path((_, group_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_discussion_section(&db, auth_session, community_id, group_id).await?;
+
+    Ok(Html(template.render()?))
+}
+
+/// Handler that returns the group upcoming events feed in Atom format.
+#[instrument(skip_all)]
+pub(crate) async fn events_feed(
+    State(db): State<DynDB>,
+    State(server_cfg): State<HttpServerConfig>,
+    Path((community_name, group_slug)): Path<(String, String)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get community and site settings
+    let (community_id, site_settings) = tokio::try_join!(
+        db.get_community_id_by_name(&community_name),
+        db.get_site_settings()
+    )?;
+    let Some(community_id) = community_id else {
+        return not_found::render(site_settings);
+    };
+
+    // Fetch the group and its upcoming events
+    let event_kinds = vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid];
+    let (group, upcoming_events) = tokio::try_join!(
+        db.get_group_full_by_slug(community_id, &group_slug),
+        db.get_group_upcoming_events(community_id, &group_slug, event_kinds, FEEDS_EVENTS_LIMIT)
+    )?;
+    let Some(group) = group else {
+        return not_found::render(site_settings);
+    };
+
+    // Build the events feed
+    let page_url = format!(
+        "{}/{}/group/{}",
+        base_url_without_trailing_slash(&server_cfg.base_url),
+        group.community.name,
+        group.public_slug()
+    );
+    let feed_url = format!("{page_url}/events.atom");
+    let feed = build_events_atom_feed(
+        &server_cfg.base_url,
+        &group.name,
+        &feed_url,
+        &page_url,
+        &upcoming_events,
+    )?;
+
+    Ok((
+        PUBLIC_SHARED_CACHE_HEADERS,
+        [(CONTENT_TYPE, "application/atom+xml; charset=utf-8")],
+        feed,
+    )
+        .into_response())
+}
+
 /// Handler that renders the group home page.
 #[instrument(skip_all)]
 pub(crate) async fn page(
@@ -46,7 +200,7 @@ pub(crate) async fn page(
     uri: Uri,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get community and site settings
-    let (community_id, site_settings) = tokio::try_join!(
+    let (community_id, mut site_settings) = tokio::try_join!(
         db.get_community_id_by_name(&community_name),
         db.get_site_settings()
     )?;
@@ -54,6 +208,9 @@ pub(crate) async fn page(
         return not_found::render(site_settings);
     };
 
+    // Apply the community theme to the site settings
+    site_settings.apply_community_theme(&db.get_community_theme(community_id).await?);
+
     // Fetch the group page data
     let event_kinds = vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid];
     let (group, past_events, upcoming_events) = tokio::try_join!(
@@ -62,6 +219,11 @@ pub(crate) async fn page(
         db.get_group_upcoming_events(community_id, &group_slug, event_kinds, 9)
     )?;
     let Some(mut group) = group else {
+        // Redirect groups merged into another group to the target group
+        if let Some(target_slug) = db.get_merged_group_slug(community_id, &group_slug).await? {
+            let url = public_group_url(&community_name, &target_slug, &uri);
+            return Ok(Redirect::permanent(&url).into_response());
+        }
         return not_found::render(site_settings);
     };
 
@@ -71,7 +233,16 @@ pub(crate) async fn page(
         return Ok(Redirect::temporary(&url).into_response());
     }
 
-    // Trim gallery media
+    // Get the pinned announcements and the curated gallery of the group, and
+    // check if the anonymous (shared cache) page can display its members count
+    let (announcements, gallery_photos, can_view_members) = tokio::try_join!(
+        db.list_group_pinned_announcements(community_id, group.group_id),
+        db.list_group_gallery_photos(group.group_id, false),
+        db.user_can_view_group_members(community_id, group.group_id, None)
+    )?;
+
+    // Display the curated gallery, cover first, and trim its media
+    group.photos_urls = Some(gallery_photos.into_iter().map(|photo| photo.url).collect());
     trim_public_gallery_images(&mut group.photos_urls);
 
     // Only display featured sponsors on the group page
@@ -79,7 +250,9 @@ pub(crate) async fn page(
 
     // Prepare the page template
     let template = Page {
+        announcements,
         base_url: server_cfg.base_url,
+        can_view_members,
         group,
         page_id: PageId::Group,
         past_events: past_events
@@ -100,6 +273,37 @@ pub(crate) async fn page(
 
 // Helpers.
 
+/// Prepares the discussion section, only exposing the topics to the users
+/// allowed to see who takes part in the group.
+async fn prepare_discussion_section(
+    db: &DynDB,
+    auth_session: AuthSession,
+    community_id: Uuid,
+    group_id: Uuid,
+) -> Result<DiscussionSection> {
+    // Check the user can view the group discussion
+    let user_id = auth_session.user.as_ref().map(|user| user.user_id);
+    let (group, can_view) = tokio::try_join!(
+        db.get_group_summary(community_id, group_id),
+        db.user_can_view_group_members(community_id, group_id, user_id)
+    )?;
+
+    // Get user from session and the discussion topics
+    let user = User::from_session(auth_session).await?;
+    let discussion = if can_view {
+        db.get_group_discussion(community_id, group_id).await?
+    } else {
+        GroupDiscussion::default()
+    };
+
+    Ok(DiscussionSection {
+        can_view,
+        discussion,
+        group,
+        user,
+    })
+}
+
 /// Builds a public group URL with the original query string, if present.
 fn public_group_url(community_name: &str, group_slug: &str, uri: &Uri) -> String {
     let mut url = format!("/{community_name}/group/{group_slug}");
@@ -118,6 +322,152 @@ fn should_redirect_to_pretty_group_slug(group: &GroupFull, group_slug: &str) ->
 
 // Actions handlers.
 
+/// Handler for replying to a topic in the group discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn add_discussion_reply(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path((_, group_id, group_discussion_topic_id)): Path<(String, Uuid, Uuid)>,
+    CommunityId(community_id): CommunityId,
+    ValidatedForm(input): ValidatedForm<DiscussionReplyInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Add reply to the discussion topic
+    db.add_group_discussion_reply(
+        community_id,
+        group_id,
+        user.user_id,
+        group_discussion_topic_id,
+        &input.content,
+    )
+    .await?;
+
+    // Prepare template with the refreshed discussion
+    let template = prepare_discussion_section(&db, auth_session, community_id, group_id).await?;
+
+    Ok(Html(template.render()?))
+}
+
+/// Handler for starting a new topic in the group discussion.
+#[instrument(skip_all, err)]
+pub(crate) async fn add_discussion_topic(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path((_, group_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+    ValidatedForm(input): ValidatedForm<DiscussionTopicInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Add topic to the group discussion
+    db.add_group_discussion_topic(
+        community_id,
+        group_id,
+        user.user_id,
+        &input.title,
+        &input.content,
+    )
+    .await?;
+
+    // Prepare template with the refreshed discussion
+    let template = prepare_discussion_section(&db, auth_session, community_id, group_id).await?;
+
+    Ok(Html(template.render()?))
+}
+
+/// Handler for sending a message to the group organizers.
+#[instrument(skip_all, err)]
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn contact_organizers(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    State(notifications_manager): State<DynNotificationsManager>,
+    State(server_cfg): State<HttpServerConfig>,
+    ClientIp(client_ip): ClientIp,
+    Path((_, group_id)): Path<(String, Uuid)>,
+    CommunityId(community_id): CommunityId,
+    ValidatedForm(input): ValidatedForm<ContactOrganizersInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Verify the CAPTCHA challenge, unless checks are disabled
+    if let Some(captcha_cfg) = &server_cfg.captcha
+        && !server_cfg.disable_captcha_checks
+    {
+        let response = input.captcha_response.as_deref().unwrap_or_default();
+        if !captcha::verify(captcha_cfg, response, client_ip).await? {
+            return Ok((StatusCode::UNPROCESSABLE_ENTITY, CAPTCHA_FAILED_MESSAGE).into_response());
+        }
+    }
+
+    // Get group data, site settings and the organizers
+    let (site_settings, group, recipients) = tokio::try_join!(
+        db.get_site_settings(),
+        db.get_group_summary(community_id, group_id),
+        db.list_group_team_members_ids(group_id),
+    )?;
+    if recipients.is_empty() {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "This group has no organizers to contact at the moment.",
+        )
+            .into_response());
+    }
+
+    // Record the message, throttling the number of messages sent by the user
+    let since = Utc::now() - Duration::hours(CONTACT_MESSAGES_WINDOW_HOURS);
+    let message_id = db
+        .add_group_contact_message(
+            community_id,
+            group_id,
+            user.user_id,
+            &input.subject,
+            &input.body,
+            since,
+            CONTACT_MESSAGES_LIMIT,
+        )
+        .await?;
+    if message_id.is_none() {
+        let message = format!(
+            "You can send up to {CONTACT_MESSAGES_LIMIT} messages to group organizers every {CONTACT_MESSAGES_WINDOW_HOURS} hours. Please try again later."
+        );
+        return Ok((StatusCode::TOO_MANY_REQUESTS, message).into_response());
+    }
+
+    // Enqueue the message notification to the organizers
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let template_data = GroupContactMessage {
+        body: input.body.trim().to_string(),
+        group: group.clone(),
+        link: format!(
+            "{}/{}/group/{}",
+            base_url,
+            group.community_name,
+            group.public_slug()
+        ),
+        sender_email: user.email.clone(),
+        sender_name: user.name.clone(),
+        sender_username: user.username.clone(),
+        subject: input.subject.trim().to_string(),
+        theme: site_settings.theme,
+    };
+    let notification = NewNotification {
+        attachments: vec![],
+        kind: NotificationKind::GroupContactMessage,
+        recipients,
+        template_data: Some(serde_json::to_value(&template_data)?),
+    };
+    notifications_manager.enqueue(&notification).await?;
+
+    // Prepare template confirming the message was sent
+    let template = ContactSection {
+        captcha: None,
+        group,
+        message_sent: true,
+        user: User::from_session(auth_session).await?,
+    };
+
+    Ok(Html(template.render()?).into_response())
+}
+
 /// Handler for joining a group.
 #[instrument(skip_all)]
 pub(crate) async fn join_group(
@@ -127,15 +477,34 @@ pub(crate) async fn join_group(
     State(server_cfg): State<HttpServerConfig>,
     Path((_, group_id)): Path<(String, Uuid)>,
     CommunityId(community_id): CommunityId,
+    ValidatedForm(input): ValidatedForm<OptionalQuestionnaireAnswersForm>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    // Join the group
-    db.join_group(community_id, group_id, user.user_id).await?;
+    // Get join questions and validate answers
+    let join_questions = db.get_group_join_questions(community_id, group_id).await?;
+    validate_registration_answers(input.registration_answers.as_ref(), &join_questions)?;
+
+    // Join the group, or request to join it when it is private
+    let status = db
+        .join_group(
+            community_id,
+            group_id,
+            user.user_id,
+            input.registration_answers,
+        )
+        .await?;
+    let response = Json(json!({
+        "status": status,
+    }));
+    if status != GroupMembershipStatus::Member {
+        return Ok(response);
+    }
 
     // Enqueue welcome notification best-effort after the membership mutation
     if let Err(err) = async {
-        let (site_settings, group) = tokio::try_join!(
+        let (site_settings, group, welcome_message) = tokio::try_join!(
             db.get_site_settings(),
-            db.get_group_summary(community_id, group_id)
+            db.get_group_summary(community_id, group_id),
+            db.get_group_welcome_message(group_id)
         )?;
         let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
         let template_data = GroupWelcome {
@@ -147,6 +516,9 @@ pub(crate) async fn join_group(
             ),
             group,
             theme: site_settings.theme,
+
+            language: Language::default(),
+            welcome_message,
         };
         let notification = NewNotification {
             attachments: vec![],
@@ -154,7 +526,8 @@ pub(crate) async fn join_group(
             recipients: vec![user.user_id],
             template_data: Some(serde_json::to_value(&template_data)?),
         };
-        notifications_manager.enqueue(&notification).await
+        notifications_manager.enqueue(&notification).await?;
+        db.enqueue_community_notification(community_id, &notification).await
     }
     .await
     {
@@ -167,10 +540,10 @@ pub(crate) async fn join_group(
         );
     }
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(response)
 }
 
-/// Handler for leaving a group.
+/// Handler for leaving a group or withdrawing a pending join request.
 #[instrument(skip_all)]
 pub(crate) async fn leave_group(
     CurrentUser(user): CurrentUser,
@@ -193,10 +566,13 @@ pub(crate) async fn membership_status(
     CommunityId(community_id): CommunityId,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Check membership
-    let is_member = db.is_group_member(community_id, group_id, user.user_id).await?;
+    let status = db
+        .get_group_membership_status(community_id, group_id, user.user_id)
+        .await?;
 
     Ok(Json(json!({
-        "is_member": is_member
+        "is_member": status == GroupMembershipStatus::Member,
+        "status": status,
     })))
 }
 
@@ -214,3 +590,40 @@ pub(crate) async fn track_view(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+// Types.
+
+/// Group contact organizers form data.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct ContactOrganizersInput {
+    /// Message body.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_L))]
+    body: String,
+    /// Message subject.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
+    subject: String,
+
+    /// Response to the CAPTCHA challenge, when enabled.
+    #[serde(alias = "cf-turnstile-response", alias = "h-captcha-response")]
+    #[garde(skip)]
+    captcha_response: Option<String>,
+}
+
+/// Group discussion reply form data.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct DiscussionReplyInput {
+    /// Reply content.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_L))]
+    content: String,
+}
+
+/// Group discussion topic form data.
+#[derive(Debug, Deserialize, Validate)]
+pub(crate) struct DiscussionTopicInput {
+    /// Topic content.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_L))]
+    content: String,
+    /// Topic title.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
+    title: String,
+}
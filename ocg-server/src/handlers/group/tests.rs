@@ -13,34 +13,96 @@ use uuid::Uuid;
 
 use crate::{
     activity_tracker::{Activity, MockActivityTracker},
+    config::HttpServerConfig,
     db::mock::MockDB,
     handlers::tests::*,
     router::CACHE_CONTROL_PUBLIC_SHARED,
     services::notifications::{MockNotificationsManager, NotificationKind},
-    templates::notifications::GroupWelcome,
-    types::event::EventKind,
+    templates::notifications::{GroupContactMessage, GroupWelcome},
+    types::{event::EventKind, group::GroupMembershipStatus},
 };
 
+use super::CONTACT_MESSAGES_LIMIT;
+
 #[tokio::test]
-async fn test_page_community_not_found() {
+async fn test_calendar_not_found() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+
     // Setup database mock
     let mut db = MockDB::new();
     db.expect_get_community_id_by_name()
         .times(1)
-        .withf(|name| name == "missing-community")
-        .returning(|_| Ok(None));
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "missing-group")
+        .returning(move |_, _| Ok(None));
+    db.expect_get_group_upcoming_events()
+        .times(1)
+        .returning(move |_, _, _, _| Ok(vec![]));
 
-    // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/missing-group/calendar.ics")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, _) = response.into_parts();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_calendar_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let mut group = sample_group_full(community_id, group_id);
+    group.name = "Test Group".to_string();
+    let mut event = sample_event_summary(event_id, group_id);
+    event.meeting_join_url = Some("https://example.test/live".to_string());
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "test-group")
+        .returning(move |_, _| Ok(Some(group.clone())));
+    db.expect_get_group_upcoming_events()
+        .times(1)
+        .withf(move |id, slug, kinds, limit| {
+            *id == community_id
+                && slug == "test-group"
+                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
+                && *limit == 100
+        })
+        .returning(move |_, _, _, _| Ok(vec![event.clone()]));
 
     // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm).build().await;
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
     let request = Request::builder()
         .method("GET")
-        .uri("/missing-community/group/test-group")
+        .uri("/test-community/group/test-group/calendar.ics")
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
@@ -48,25 +110,226 @@ async fn test_page_community_not_found() {
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    assert_eq!(parts.status, StatusCode::OK);
     assert_eq!(
         parts.headers.get(CONTENT_TYPE).unwrap(),
-        &HeaderValue::from_static("text/html; charset=utf-8")
+        &HeaderValue::from_static("text/calendar; charset=utf-8")
     );
     assert_eq!(
         parts.headers.get(CACHE_CONTROL).unwrap(),
         &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
     );
     let body = String::from_utf8(bytes.to_vec()).unwrap();
-    assert!(body.contains("We could not find that page"));
-    assert!(body.contains("Go to home page"));
+    assert!(body.contains("BEGIN:VCALENDAR"));
+    assert!(body.contains("SUMMARY:Sample Event"));
+    assert!(body.contains(&format!("UID:{event_id}")));
+    assert!(!body.contains("https://example.test/live"));
 }
 
 #[tokio::test]
-async fn test_page_db_error() {
+async fn test_contact_success_anonymous() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let group_summary = sample_group_summary(group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/group/{group_id}/contact"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(body.contains("to send a message to the organizers of this group"));
+    assert!(!body.contains("group-contact-form"));
+}
+
+#[tokio::test]
+async fn test_contact_success_authenticated() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let group_summary = sample_group_summary(group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let server_cfg = HttpServerConfig {
+        captcha: Some(sample_captcha_config()),
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/group/{group_id}/contact"))
+        .header(COOKIE, format!("id={session_id}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(body.contains("group-contact-form"));
+    assert!(body.contains(r#"data-sitekey="captcha-site-key""#));
+}
+
+#[tokio::test]
+async fn test_discussion_private_group_hides_topics() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let group_summary = sample_group_summary(group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| *cid == community_id && *gid == group_id && uid.is_none())
+        .returning(|_, _, _| Ok(false));
+    db.expect_get_group_discussion().never();
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/group/{group_id}/discussion"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(body.contains("Only members of this private group can see its discussion."));
+    assert!(!body.contains("discussion-topic-form"));
+}
+
+#[tokio::test]
+async fn test_discussion_success_anonymous() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
+    let discussion = sample_group_discussion();
+    let group_summary = sample_group_summary(group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| *cid == community_id && *gid == group_id && uid.is_none())
+        .returning(|_, _, _| Ok(true));
+    db.expect_get_group_discussion()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(discussion.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/test-community/group/{group_id}/discussion"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(body.contains("Study session"));
+    assert!(body.contains("Yes, we can host it at our office."));
+    assert!(body.contains("to start a topic or join the conversation"));
+    assert!(!body.contains("discussion-topic-form"));
+}
+
+#[tokio::test]
+async fn test_events_feed_not_found() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -77,218 +340,1096 @@ async fn test_page_db_error() {
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(sample_site_settings()));
-    db.expect_get_group_full_by_slug()
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "missing-group")
+        .returning(move |_, _| Ok(None));
+    db.expect_get_group_upcoming_events()
+        .times(1)
+        .returning(move |_, _, _, _| Ok(vec![]));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/missing-group/events.atom")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, _) = response.into_parts();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_events_feed_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let group = sample_group_full(community_id, group_id);
+    let mut event = sample_event_summary(event_id, group_id);
+    event.meeting_join_url = Some("https://example.test/live".to_string());
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "test-group")
+        .returning(move |_, _| Ok(Some(group.clone())));
+    db.expect_get_group_upcoming_events()
+        .times(1)
+        .withf(move |id, slug, kinds, limit| {
+            *id == community_id
+                && slug == "test-group"
+                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
+                && *limit == 100
+        })
+        .returning(move |_, _, _, _| Ok(vec![event.clone()]));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/test-group/events.atom")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("application/atom+xml; charset=utf-8")
+    );
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#));
+    assert!(body.contains("<id>https://example.test/test/group/jkm2345/events.atom</id>"));
+    assert!(body.contains("<title>Sample Event</title>"));
+    assert!(body.contains(&format!("<id>urn:uuid:{event_id}</id>")));
+    assert!(!body.contains("https://example.test/live"));
+}
+
+#[tokio::test]
+async fn test_page_community_not_found() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "missing-community")
+        .returning(|_| Ok(None));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/missing-community/group/test-group")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("We could not find that page"));
+    assert!(body.contains("Go to home page"));
+}
+
+#[tokio::test]
+async fn test_page_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "test-group")
+        .returning(move |_, _| Ok(Some(sample_group_full(community_id, group_id))));
+    db.expect_get_group_past_events()
+        .times(1)
+        .withf(move |id, slug, kinds, limit| {
+            *id == community_id
+                && slug == "test-group"
+                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
+                && *limit == 9
+        })
+        .returning(move |_, _, _, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/test-group")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_page_not_found() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "missing-group")
+        .returning(move |_, _| Ok(None));
+    db.expect_get_group_upcoming_events()
+        .times(1)
+        .withf(move |id, slug, kinds, limit| {
+            *id == community_id
+                && slug == "missing-group"
+                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
+                && *limit == 9
+        })
+        .returning(move |_, _, _, _| Ok(vec![]));
+    db.expect_get_group_past_events()
+        .times(1)
+        .withf(move |id, slug, kinds, limit| {
+            *id == community_id
+                && slug == "missing-group"
+                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
+                && *limit == 9
+        })
+        .returning(move |_, _, _, _| Ok(vec![]));
+    db.expect_get_merged_group_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "missing-group")
+        .returning(|_, _| Ok(None));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/missing-group")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::NOT_FOUND);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("We could not find that page"));
+    assert!(body.contains("Go to home page"));
+}
+
+#[tokio::test]
+async fn test_page_permanently_redirects_merged_group_to_target_group() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "merged-group")
+        .returning(|_, _| Ok(None));
+    db.expect_get_group_upcoming_events()
+        .times(1)
+        .returning(move |_, _, _, _| Ok(vec![]));
+    db.expect_get_group_past_events()
+        .times(1)
+        .returning(move |_, _, _, _| Ok(vec![]));
+    db.expect_get_merged_group_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "merged-group")
+        .returning(|_, _| Ok(Some("target-group".to_string())));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/merged-group?utm_source=test")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/test-community/group/target-group?utm_source=test")
+    );
+}
+
+#[tokio::test]
+async fn test_page_temporarily_redirects_generated_slug_to_pretty_slug() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let mut group = sample_group_full(community_id, group_id);
+    group.slug = "test-group".to_string();
+    group.slug_pretty = Some("pretty-group".to_string());
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "test-group")
+        .returning(move |_, _| Ok(Some(group.clone())));
+    db.expect_get_group_upcoming_events()
+        .times(1)
+        .returning(move |_, _, _, _| Ok(vec![]));
+    db.expect_get_group_past_events()
+        .times(1)
+        .returning(move |_, _, _, _| Ok(vec![]));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/test-group?utm_source=test")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("/test-community/group/pretty-group?utm_source=test")
+    );
+}
+
+#[tokio::test]
+async fn test_page_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let mut group = sample_group_full(community_id, group_id);
+    group.community.display_name = "Test Community".to_string();
+    group.community.name = "test-community".to_string();
+    group.description_short = Some("Group preview description".to_string());
+    group.name = "Test Group".to_string();
+    group.og_image_url = Some("/images/group-og.png".to_string());
+    group.slug_pretty = Some("pretty-group".to_string());
+    let mut hidden_sponsor = sample_group_sponsor();
+    hidden_sponsor.featured = false;
+    hidden_sponsor.name = "Hidden Sponsor".to_string();
+    let mut featured_sponsor = sample_group_sponsor();
+    featured_sponsor.featured = true;
+    featured_sponsor.name = "Featured Sponsor".to_string();
+    group.sponsors = vec![featured_sponsor, hidden_sponsor];
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_community_theme()
+        .times(1)
+        .withf(move |id| *id == community_id)
+        .returning(|_| Ok(sample_community_theme()));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_full_by_slug()
+        .times(1)
+        .withf(move |id, slug| *id == community_id && slug == "pretty-group")
+        .returning(move |_, _| Ok(Some(group.clone())));
+    db.expect_get_group_upcoming_events()
+        .times(1)
+        .withf(move |id, slug, kinds, limit| {
+            *id == community_id
+                && slug == "pretty-group"
+                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
+                && *limit == 9
+        })
+        .returning(move |_, _, _, _| Ok(vec![sample_event_summary(event_id, group_id)]));
+    db.expect_get_group_past_events()
+        .times(1)
+        .withf(move |id, slug, kinds, limit| {
+            *id == community_id
+                && slug == "pretty-group"
+                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
+                && *limit == 9
+        })
+        .returning(move |_, _, _, _| Ok(vec![sample_event_summary(event_id, group_id)]));
+    db.expect_list_group_pinned_announcements()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(|_, _| Ok(vec![sample_group_announcement()]));
+    db.expect_list_group_gallery_photos()
+        .times(1)
+        .withf(move |gid, include_hidden| *gid == group_id && !*include_hidden)
+        .returning(|_, _| Ok(vec![sample_group_gallery_photo()]));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| *cid == community_id && *gid == group_id && uid.is_none())
+        .returning(|_, _, _| Ok(true));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(sample_tracking_server_cfg())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/test-community/group/pretty-group")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert_eq!(
+        parts.headers.get(CACHE_CONTROL).unwrap(),
+        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
+    );
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains("<title>Test Group</title>"));
+    assert!(body.contains(
+        r#"<meta name="description" content="Test Community community in Open Community Groups, where Open Source communities thrive.">"#
+    ));
+    assert!(body.contains(
+        r#"<link rel="canonical" href="https://example.test/test-community/group/pretty-group">"#
+    ));
+    assert!(body.contains(r#"<meta property="og:title" content="Test Group">"#));
+    assert!(body.contains(
+        r#"<meta property="og:url" content="https://example.test/test-community/group/pretty-group">"#
+    ));
+    assert!(body.contains(
+        r#"<meta property="og:description" content="Test Community community in Open Community Groups, where Open Source communities thrive.">"#
+    ));
+    assert!(body.contains(
+        r#"<meta property="og:image" content="https://example.test/images/og/group-og.png">"#
+    ));
+    assert!(body.contains(r#"<meta name="twitter:title" content="Test Group">"#));
+    assert!(body.contains(
+        r#"<meta name="twitter:description" content="Test Community community in Open Community Groups, where Open Source communities thrive.">"#
+    ));
+    assert!(body.contains(
+        r#"<meta name="twitter:image" content="https://example.test/images/og/group-og.png">"#
+    ));
+    assert!(body.contains("Venue change"));
+    assert!(body.contains("https://example.test/gallery/photo.png"));
+    assert!(body.contains("0 members"));
+    assert!(!body.contains("Private group"));
+}
+
+#[tokio::test]
+async fn test_add_discussion_reply_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_discussion_topic_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let discussion = sample_group_discussion();
+    let group_summary = sample_group_summary(group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_add_group_discussion_reply()
+        .times(1)
+        .withf(move |cid, gid, uid, tid, content| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && *tid == group_discussion_topic_id
+                && content == "Count me in!"
+        })
+        .returning(|_, _, _, _, _| Ok(Uuid::new_v4()));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| {
+            *cid == community_id && *gid == group_id && *uid == Some(user_id)
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_get_group_discussion()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(discussion.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!(
+            "/test-community/group/{group_id}/discussion/topics/{group_discussion_topic_id}/replies"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("content=Count+me+in%21"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(
+        parts.headers.get(CONTENT_TYPE).unwrap(),
+        &HeaderValue::from_static("text/html; charset=utf-8")
+    );
+    assert!(body.contains("discussion-topic-form"));
+}
+
+#[tokio::test]
+async fn test_add_discussion_topic_db_error() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_add_group_discussion_topic()
+        .times(1)
+        .withf(move |cid, gid, uid, title, content| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && title == "Study session"
+                && content == "Who is in?"
+        })
+        .returning(|_, _, _, _, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!(
+            "/test-community/group/{group_id}/discussion/topics"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("title=Study+session&content=Who+is+in%3F"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_discussion_topic_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let discussion = sample_group_discussion();
+    let group_summary = sample_group_summary(group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_add_group_discussion_topic()
+        .times(1)
+        .withf(move |cid, gid, uid, title, content| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && title == "Study session"
+                && content == "Who is in?"
+        })
+        .returning(|_, _, _, _, _| Ok(Uuid::new_v4()));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_user_can_view_group_members()
+        .times(1)
+        .withf(move |cid, gid, uid| {
+            *cid == community_id && *gid == group_id && *uid == Some(user_id)
+        })
+        .returning(|_, _, _| Ok(true));
+    db.expect_get_group_discussion()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(discussion.clone()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!(
+            "/test-community/group/{group_id}/discussion/topics"
+        ))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("title=Study+session&content=Who+is+in%3F"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(body.contains("Study session"));
+    assert!(body.contains("discussion-topic-form"));
+}
+
+#[tokio::test]
+async fn test_contact_organizers_captcha_missing_response() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_add_group_contact_message().times(0);
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router and send request
+    let server_cfg = HttpServerConfig {
+        captcha: Some(sample_captcha_config()),
+        ..Default::default()
+    };
+    let router = TestRouterBuilder::new(db, nm)
+        .with_server_cfg(server_cfg)
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/test-community/group/{group_id}/contact"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(
+            "subject=Venue&body=We+can+host+you&cf-turnstile-response=",
+        ))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap(),
+        "Please complete the CAPTCHA challenge and try again."
+    );
+}
+
+#[tokio::test]
+async fn test_contact_organizers_rate_limited() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let group_summary = sample_group_summary(group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_summary()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_list_group_team_members_ids()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(vec![Uuid::new_v4()]));
+    db.expect_add_group_contact_message()
+        .times(1)
+        .withf(move |cid, gid, uid, _, _, _, max_messages| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && *max_messages == CONTACT_MESSAGES_LIMIT
+        })
+        .returning(|_, _, _, _, _, _, _| Ok(None));
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().times(0);
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/test-community/group/{group_id}/contact"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("subject=Venue&body=We+can+host+you"))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_contact_organizers_success() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let organizer_id = Uuid::new_v4();
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let group_summary = sample_group_summary(group_id);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+    db.expect_get_group_summary()
         .times(1)
-        .withf(move |id, slug| *id == community_id && slug == "test-group")
-        .returning(move |_, _| Ok(Some(sample_group_full(community_id, group_id))));
-    db.expect_get_group_past_events()
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(group_summary.clone()));
+    db.expect_list_group_team_members_ids()
         .times(1)
-        .withf(move |id, slug, kinds, limit| {
-            *id == community_id
-                && slug == "test-group"
-                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
-                && *limit == 9
+        .withf(move |gid| *gid == group_id)
+        .returning(move |_| Ok(vec![organizer_id]));
+    db.expect_add_group_contact_message()
+        .times(1)
+        .withf(move |cid, gid, uid, subject, body, _, max_messages| {
+            *cid == community_id
+                && *gid == group_id
+                && *uid == user_id
+                && subject == "Venue"
+                && body == "We can host you"
+                && *max_messages == CONTACT_MESSAGES_LIMIT
         })
-        .returning(move |_, _, _, _| Err(anyhow!("db error")));
+        .returning(|_, _, _, _, _, _, _| Ok(Some(Uuid::new_v4())));
 
     // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue()
+        .times(1)
+        .withf(move |notification| {
+            matches!(notification.kind, NotificationKind::GroupContactMessage)
+                && notification.recipients == vec![organizer_id]
+                && notification.template_data.as_ref().is_some_and(|data| {
+                    serde_json::from_value::<GroupContactMessage>(data.clone()).is_ok_and(
+                        |message| {
+                            message.group.group_id == group_id
+                                && message.sender_email == "user@example.test"
+                                && message.sender_name == "Test User"
+                                && message.subject == "Venue"
+                                && message.body == "We can host you"
+                        },
+                    )
+                })
+        })
+        .returning(|_| Box::pin(async { Ok(()) }));
 
     // Setup router and send request
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
-        .method("GET")
-        .uri("/test-community/group/test-group")
-        .body(Body::empty())
+        .method("POST")
+        .uri(format!("/test-community/group/{group_id}/contact"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from("subject=Venue&body=We+can+host+you"))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
-    assert!(bytes.is_empty());
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(body.contains("Your message was sent to the organizers"));
+    assert!(!body.contains("group-contact-form"));
 }
 
 #[tokio::test]
-async fn test_page_not_found() {
+async fn test_join_group_missing_required_answers() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let join_questions = vec![sample_join_question(Uuid::new_v4())];
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
 
     // Setup database mock
     let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
     db.expect_get_community_id_by_name()
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
-    db.expect_get_group_full_by_slug()
-        .times(1)
-        .withf(move |id, slug| *id == community_id && slug == "missing-group")
-        .returning(move |_, _| Ok(None));
-    db.expect_get_group_upcoming_events()
-        .times(1)
-        .withf(move |id, slug, kinds, limit| {
-            *id == community_id
-                && slug == "missing-group"
-                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
-                && *limit == 9
-        })
-        .returning(move |_, _, _, _| Ok(vec![]));
-    db.expect_get_group_past_events()
-        .times(1)
-        .withf(move |id, slug, kinds, limit| {
-            *id == community_id
-                && slug == "missing-group"
-                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
-                && *limit == 9
-        })
-        .returning(move |_, _, _, _| Ok(vec![]));
-    db.expect_get_site_settings()
+    db.expect_get_group_join_questions()
         .times(1)
-        .returning(|| Ok(sample_site_settings()));
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(join_questions.clone()));
+    db.expect_join_group().never();
 
     // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().never();
 
     // Setup router and send request
     let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
-        .method("GET")
-        .uri("/test-community/group/missing-group")
+        .method("POST")
+        .uri(format!("/test-community/group/{group_id}/join"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
-    let (parts, body) = response.into_parts();
-    let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::NOT_FOUND);
-    assert_eq!(
-        parts.headers.get(CONTENT_TYPE).unwrap(),
-        &HeaderValue::from_static("text/html; charset=utf-8")
-    );
-    assert_eq!(
-        parts.headers.get(CACHE_CONTROL).unwrap(),
-        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
-    );
-    let body = String::from_utf8(bytes.to_vec()).unwrap();
-    assert!(body.contains("We could not find that page"));
-    assert!(body.contains("Go to home page"));
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
 }
 
 #[tokio::test]
-async fn test_page_temporarily_redirects_generated_slug_to_pretty_slug() {
+async fn test_join_group_pending_approval() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
-    let mut group = sample_group_full(community_id, group_id);
-    group.slug = "test-group".to_string();
-    group.slug_pretty = Some("pretty-group".to_string());
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
 
     // Setup database mock
     let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
     db.expect_get_community_id_by_name()
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
-    db.expect_get_site_settings()
-        .times(1)
-        .returning(|| Ok(sample_site_settings()));
-    db.expect_get_group_full_by_slug()
-        .times(1)
-        .withf(move |id, slug| *id == community_id && slug == "test-group")
-        .returning(move |_, _| Ok(Some(group.clone())));
-    db.expect_get_group_upcoming_events()
+    db.expect_get_group_join_questions()
         .times(1)
-        .returning(move |_, _, _, _| Ok(vec![]));
-    db.expect_get_group_past_events()
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(|_, _| Ok(vec![]));
+    db.expect_join_group()
         .times(1)
-        .returning(move |_, _, _, _| Ok(vec![]));
+        .withf(move |id, gid, uid, answers| {
+            *id == community_id && *gid == group_id && *uid == user_id && answers.is_none()
+        })
+        .returning(|_, _, _, _| Ok(GroupMembershipStatus::PendingApproval));
+    db.expect_get_group_summary().never();
+    db.expect_enqueue_community_notification().never();
+
+    // Setup notifications manager mock
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().never();
 
     // Setup router and send request
-    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
-        .build()
-        .await;
+    let router = TestRouterBuilder::new(db, nm).build().await;
     let request = Request::builder()
-        .method("GET")
-        .uri("/test-community/group/test-group?utm_source=test")
+        .method("POST")
+        .uri(format!("/test-community/group/{group_id}/join"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
-    assert_eq!(
-        response.headers().get(LOCATION).unwrap(),
-        &HeaderValue::from_static("/test-community/group/pretty-group?utm_source=test")
-    );
+    assert_eq!(parts.status, StatusCode::OK);
+    let body: serde_json::Value = from_slice(&bytes).unwrap();
+    assert_eq!(body, json!({ "status": "pending-approval" }));
 }
 
 #[tokio::test]
-async fn test_page_success() {
+async fn test_join_group_pending_approval_with_join_answers() {
     // Setup identifiers and data structures
     let community_id = Uuid::new_v4();
-    let event_id = Uuid::new_v4();
     let group_id = Uuid::new_v4();
-    let mut group = sample_group_full(community_id, group_id);
-    group.community.display_name = "Test Community".to_string();
-    group.community.name = "test-community".to_string();
-    group.description_short = Some("Group preview description".to_string());
-    group.name = "Test Group".to_string();
-    group.og_image_url = Some("/images/group-og.png".to_string());
-    group.slug_pretty = Some("pretty-group".to_string());
-    let mut hidden_sponsor = sample_group_sponsor();
-    hidden_sponsor.featured = false;
-    hidden_sponsor.name = "Hidden Sponsor".to_string();
-    let mut featured_sponsor = sample_group_sponsor();
-    featured_sponsor.featured = true;
-    featured_sponsor.name = "Featured Sponsor".to_string();
-    group.sponsors = vec![featured_sponsor, hidden_sponsor];
+    let question_id = Uuid::new_v4();
+    let join_questions = vec![sample_join_question(question_id)];
+    let session_id = session::Id::default();
+    let user_id = Uuid::new_v4();
+    let auth_hash = "hash".to_string();
+    let session_record = sample_session_record(session_id, user_id, &auth_hash, None, None);
+    let answers_json = json!({
+        "answers": [
+            {
+                "question_id": question_id,
+                "value": "Kubernetes"
+            }
+        ]
+    });
 
     // Setup database mock
     let mut db = MockDB::new();
+    db.expect_get_session()
+        .times(1)
+        .withf(move |id| *id == session_id)
+        .returning(move |_| Ok(Some(session_record.clone())));
+    db.expect_get_user_by_id()
+        .times(1)
+        .withf(move |id| *id == user_id)
+        .returning(move |_| Ok(Some(sample_auth_user(user_id, &auth_hash))));
     db.expect_get_community_id_by_name()
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
-    db.expect_get_site_settings()
-        .times(1)
-        .returning(|| Ok(sample_site_settings()));
-    db.expect_get_group_full_by_slug()
-        .times(1)
-        .withf(move |id, slug| *id == community_id && slug == "pretty-group")
-        .returning(move |_, _| Ok(Some(group.clone())));
-    db.expect_get_group_upcoming_events()
+    db.expect_get_group_join_questions()
         .times(1)
-        .withf(move |id, slug, kinds, limit| {
-            *id == community_id
-                && slug == "pretty-group"
-                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
-                && *limit == 9
-        })
-        .returning(move |_, _, _, _| Ok(vec![sample_event_summary(event_id, group_id)]));
-    db.expect_get_group_past_events()
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(move |_, _| Ok(join_questions.clone()));
+    let expected_answers = answers_json.clone();
+    db.expect_join_group()
         .times(1)
-        .withf(move |id, slug, kinds, limit| {
+        .withf(move |id, gid, uid, answers| {
             *id == community_id
-                && slug == "pretty-group"
-                && kinds == &vec![EventKind::InPerson, EventKind::Virtual, EventKind::Hybrid]
-                && *limit == 9
+                && *gid == group_id
+                && *uid == user_id
+                && answers.as_ref().and_then(|value| serde_json::to_value(value).ok())
+                    == Some(expected_answers.clone())
         })
-        .returning(move |_, _, _, _| Ok(vec![sample_event_summary(event_id, group_id)]));
+        .returning(|_, _, _, _| Ok(GroupMembershipStatus::PendingApproval));
+    db.expect_get_group_summary().never();
 
     // Setup notifications manager mock
-    let nm = MockNotificationsManager::new();
+    let mut nm = MockNotificationsManager::new();
+    nm.expect_enqueue().never();
 
     // Setup router and send request
-    let router = TestRouterBuilder::new(db, nm)
-        .with_server_cfg(sample_tracking_server_cfg())
-        .build()
-        .await;
+    let router = TestRouterBuilder::new(db, nm).build().await;
+    let form_body =
+        serde_urlencoded::to_string([("registration_answers", answers_json.to_string())]).unwrap();
     let request = Request::builder()
-        .method("GET")
-        .uri("/test-community/group/pretty-group")
-        .body(Body::empty())
+        .method("POST")
+        .uri(format!("/test-community/group/{group_id}/join"))
+        .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form_body))
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
     let (parts, body) = response.into_parts();
@@ -296,39 +1437,8 @@ async fn test_page_success() {
 
     // Check response matches expectations
     assert_eq!(parts.status, StatusCode::OK);
-    assert_eq!(
-        parts.headers.get(CONTENT_TYPE).unwrap(),
-        &HeaderValue::from_static("text/html; charset=utf-8")
-    );
-    assert_eq!(
-        parts.headers.get(CACHE_CONTROL).unwrap(),
-        &HeaderValue::from_static(CACHE_CONTROL_PUBLIC_SHARED)
-    );
-    let body = String::from_utf8(bytes.to_vec()).unwrap();
-    assert!(body.contains("<title>Test Group</title>"));
-    assert!(body.contains(
-        r#"<meta name="description" content="Test Community community in Open Community Groups, where Open Source communities thrive.">"#
-    ));
-    assert!(body.contains(
-        r#"<link rel="canonical" href="https://example.test/test-community/group/pretty-group">"#
-    ));
-    assert!(body.contains(r#"<meta property="og:title" content="Test Group">"#));
-    assert!(body.contains(
-        r#"<meta property="og:url" content="https://example.test/test-community/group/pretty-group">"#
-    ));
-    assert!(body.contains(
-        r#"<meta property="og:description" content="Test Community community in Open Community Groups, where Open Source communities thrive.">"#
-    ));
-    assert!(body.contains(
-        r#"<meta property="og:image" content="https://example.test/images/og/group-og.png">"#
-    ));
-    assert!(body.contains(r#"<meta name="twitter:title" content="Test Group">"#));
-    assert!(body.contains(
-        r#"<meta name="twitter:description" content="Test Community community in Open Community Groups, where Open Source communities thrive.">"#
-    ));
-    assert!(body.contains(
-        r#"<meta name="twitter:image" content="https://example.test/images/og/group-og.png">"#
-    ));
+    let body: serde_json::Value = from_slice(&bytes).unwrap();
+    assert_eq!(body, json!({ "status": "pending-approval" }));
 }
 
 #[tokio::test]
@@ -357,19 +1467,35 @@ async fn test_join_group_success() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
+    db.expect_get_group_join_questions()
+        .times(1)
+        .withf(move |cid, gid| *cid == community_id && *gid == group_id)
+        .returning(|_, _| Ok(vec![]));
     db.expect_join_group()
         .times(1)
-        .withf(move |id, gid, uid| *id == community_id && *gid == group_id && *uid == user_id)
-        .returning(|_, _, _| Ok(()));
+        .withf(move |id, gid, uid, answers| {
+            *id == community_id && *gid == group_id && *uid == user_id && answers.is_none()
+        })
+        .returning(|_, _, _, _| Ok(GroupMembershipStatus::Member));
     let mut notification_group = sample_group_summary(group_id);
     notification_group.slug_pretty = Some("pretty-group".to_string());
     db.expect_get_group_summary()
         .times(1)
         .withf(move |cid, gid| *cid == community_id && *gid == group_id)
         .returning(move |_, _| Ok(notification_group.clone()));
+    db.expect_get_group_welcome_message()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(Some("Join our **chat**".to_string())));
     db.expect_get_site_settings()
         .times(1)
         .returning(move || Ok(site_settings.clone()));
+    db.expect_enqueue_community_notification()
+        .times(1)
+        .withf(move |cid, notification| {
+            *cid == community_id && matches!(notification.kind, NotificationKind::GroupWelcome)
+        })
+        .returning(|_, _| Ok(()));
 
     // Setup notifications manager mock
     let mut nm = MockNotificationsManager::new();
@@ -384,6 +1510,7 @@ async fn test_join_group_success() {
                             && welcome.link == "/test-community/group/pretty-group"
                             && welcome.theme.primary_color
                                 == site_settings_for_notifications.theme.primary_color
+                            && welcome.welcome_message.as_deref() == Some("Join our **chat**")
                     })
                 })
         })
@@ -395,6 +1522,7 @@ async fn test_join_group_success() {
         .method("POST")
         .uri(format!("/test-community/group/{group_id}/join"))
         .header(COOKIE, format!("id={session_id}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
         .body(Body::empty())
         .unwrap();
     let response = router.oneshot(request).await.unwrap();
@@ -402,8 +1530,9 @@ async fn test_join_group_success() {
     let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
     // Check response matches expectations
-    assert_eq!(parts.status, StatusCode::NO_CONTENT);
-    assert!(bytes.is_empty());
+    assert_eq!(parts.status, StatusCode::OK);
+    let body: serde_json::Value = from_slice(&bytes).unwrap();
+    assert_eq!(body, json!({ "status": "member" }));
 }
 
 #[tokio::test]
@@ -520,10 +1649,10 @@ async fn test_membership_status_success() {
         .times(1)
         .withf(|name| name == "test-community")
         .returning(move |_| Ok(Some(community_id)));
-    db.expect_is_group_member()
+    db.expect_get_group_membership_status()
         .times(1)
         .withf(move |id, gid, uid| *id == community_id && *gid == group_id && *uid == user_id)
-        .returning(|_, _, _| Ok(true));
+        .returning(|_, _, _| Ok(GroupMembershipStatus::Member));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -547,7 +1676,7 @@ async fn test_membership_status_success() {
         &HeaderValue::from_static("application/json")
     );
     let body: serde_json::Value = from_slice(&bytes).unwrap();
-    assert_eq!(body, json!({ "is_member": true }));
+    assert_eq!(body, json!({ "is_member": true, "status": "member" }));
 }
 
 #[tokio::test]
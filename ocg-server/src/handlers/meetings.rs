@@ -6,22 +6,29 @@ use axum::{
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
 };
+use chrono::{DateTime, Utc};
 use hmac::{Hmac, KeyInit, Mac};
 use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, PickFirst, serde_as};
 use sha2::Sha256;
 use subtle::ConstantTimeEq;
 use tracing::{instrument, trace, warn};
+use uuid::Uuid;
 
 use crate::{
     config::{MeetingsConfig, MeetingsZoomConfig},
     db::DynDB,
-    services::meetings::MeetingProvider,
+    services::meetings::{MeetingProvider, MeetingProviderStatus},
 };
 
 #[cfg(test)]
 mod tests;
 
 /// Zoom webhook event types we handle.
+const EVENT_MEETING_DELETED: &str = "meeting.deleted";
+const EVENT_MEETING_ENDED: &str = "meeting.ended";
+const EVENT_MEETING_PARTICIPANT_JOINED: &str = "meeting.participant_joined";
+const EVENT_MEETING_STARTED: &str = "meeting.started";
 const EVENT_RECORDING_COMPLETED: &str = "recording.completed";
 const EVENT_URL_VALIDATION: &str = "endpoint.url_validation";
 
@@ -47,14 +54,23 @@ pub(crate) async fn zoom_event(
         return StatusCode::NOT_FOUND.into_response();
     };
 
-    // Verify signature
-    if !verify_signature(&headers, &body, &zoom_cfg.webhook_secret_token) {
-        warn!("zoom webhook signature verification failed");
-        return StatusCode::UNAUTHORIZED.into_response();
-    }
+    // Parse payload (checked once the signature has been verified)
+    let payload = serde_json::from_str::<ZoomWebhookPayload>(&body);
 
-    // Parse payload
-    let payload: ZoomWebhookPayload = match serde_json::from_str(&body) {
+    // Verify signature
+    let signer =
+        match resolve_webhook_signer(&db, zoom_cfg, &headers, &body, payload.as_ref().ok()).await {
+            Ok(Some(signer)) => signer,
+            Ok(None) => {
+                warn!("zoom webhook signature verification failed");
+                return StatusCode::UNAUTHORIZED.into_response();
+            }
+            Err(err) => {
+                warn!(?err, "failed to list zoom webhook secret tokens");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+    let payload = match payload {
         Ok(p) => p,
         Err(err) => {
             warn!(?err, "failed to parse zoom webhook payload");
@@ -64,8 +80,18 @@ pub(crate) async fn zoom_event(
 
     // Handle based on event type
     match payload.event.as_str() {
-        EVENT_RECORDING_COMPLETED => handle_recording_completed(&db, &payload).await,
-        EVENT_URL_VALIDATION => handle_url_validation(&payload, zoom_cfg),
+        EVENT_MEETING_DELETED => {
+            handle_meeting_status(&db, &signer, &payload, MeetingProviderStatus::Deleted).await
+        }
+        EVENT_MEETING_ENDED => {
+            handle_meeting_status(&db, &signer, &payload, MeetingProviderStatus::Ended).await
+        }
+        EVENT_MEETING_PARTICIPANT_JOINED => handle_participant_joined(&db, &signer, &payload).await,
+        EVENT_MEETING_STARTED => {
+            handle_meeting_status(&db, &signer, &payload, MeetingProviderStatus::Started).await
+        }
+        EVENT_RECORDING_COMPLETED => handle_recording_completed(&db, &signer, &payload).await,
+        EVENT_URL_VALIDATION => handle_url_validation(&payload, &signer.secret),
         _ => {
             trace!(event = %payload.event, "ignoring unhandled zoom event");
             StatusCode::OK.into_response()
@@ -73,9 +99,73 @@ pub(crate) async fn zoom_event(
     }
 }
 
+/// Handles meeting lifecycle events by recording the reported meeting status.
+async fn handle_meeting_status(
+    db: &DynDB,
+    signer: &WebhookSigner,
+    payload: &ZoomWebhookPayload,
+    status: MeetingProviderStatus,
+) -> axum::response::Response {
+    // Extract meeting details from payload
+    let Some(object) = payload.payload.as_ref().and_then(|p| p.object.as_ref()) else {
+        warn!(event = %payload.event, "meeting event missing object");
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    let provider_meeting_id = object.id.to_string();
+
+    // Update meeting status in database
+    if let Err(err) = db
+        .update_meeting_provider_status(
+            MeetingProvider::Zoom,
+            &provider_meeting_id,
+            signer.group_id,
+            status,
+            payload.occurred_at(),
+        )
+        .await
+    {
+        warn!(?err, "failed to update meeting provider status");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    trace!(provider_meeting_id, %status, "updated meeting provider status");
+    StatusCode::OK.into_response()
+}
+
+/// Handles meeting.participant_joined event by counting the participant join.
+async fn handle_participant_joined(
+    db: &DynDB,
+    signer: &WebhookSigner,
+    payload: &ZoomWebhookPayload,
+) -> axum::response::Response {
+    // Extract meeting details from payload
+    let Some(object) = payload.payload.as_ref().and_then(|p| p.object.as_ref()) else {
+        warn!("meeting.participant_joined missing object");
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    let provider_meeting_id = object.id.to_string();
+
+    // Record participant join in database
+    if let Err(err) = db
+        .record_meeting_participant_joined(
+            MeetingProvider::Zoom,
+            &provider_meeting_id,
+            signer.group_id,
+        )
+        .await
+    {
+        warn!(?err, "failed to record meeting participant join");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    trace!(provider_meeting_id, "recorded meeting participant join");
+    StatusCode::OK.into_response()
+}
+
 /// Handles recording.completed event by updating the recording URL.
 async fn handle_recording_completed(
     db: &DynDB,
+    signer: &WebhookSigner,
     payload: &ZoomWebhookPayload,
 ) -> axum::response::Response {
     // Extract recording details from payload
@@ -91,7 +181,12 @@ async fn handle_recording_completed(
 
     // Append recording URL in database
     if let Err(err) = db
-        .append_meeting_recording_url(MeetingProvider::Zoom, &provider_meeting_id, recording_url)
+        .append_meeting_recording_url(
+            MeetingProvider::Zoom,
+            &provider_meeting_id,
+            signer.group_id,
+            recording_url,
+        )
         .await
     {
         warn!(?err, "failed to append meeting recording url");
@@ -103,10 +198,7 @@ async fn handle_recording_completed(
 }
 
 /// Handles Zoom's URL validation challenge-response.
-fn handle_url_validation(
-    payload: &ZoomWebhookPayload,
-    zoom_cfg: &MeetingsZoomConfig,
-) -> axum::response::Response {
+fn handle_url_validation(payload: &ZoomWebhookPayload, secret: &str) -> axum::response::Response {
     // Extract plain token from payload
     let Some(plain_token) = payload.payload.as_ref().and_then(|p| p.plain_token.as_ref()) else {
         warn!("url validation missing plain_token");
@@ -114,7 +206,7 @@ fn handle_url_validation(
     };
 
     // Hash the token with the secret
-    let encrypted_token = compute_hmac(plain_token, &zoom_cfg.webhook_secret_token);
+    let encrypted_token = compute_hmac(plain_token, secret);
 
     // Prepare response
     let response = UrlValidationResponse {
@@ -137,6 +229,46 @@ fn compute_hmac(message: &str, secret: &str) -> String {
     hex::encode(mac.finalize().into_bytes())
 }
 
+/// Returns the Zoom app the webhook was signed by, if any is known.
+///
+/// The platform Zoom app secret is checked first. Webhooks sent by the group
+/// Zoom apps are checked against the secrets of the account in the payload.
+/// Only URL validation requests, which have no account, are checked against
+/// the secrets of all group apps.
+async fn resolve_webhook_signer(
+    db: &DynDB,
+    zoom_cfg: &MeetingsZoomConfig,
+    headers: &HeaderMap,
+    body: &str,
+    payload: Option<&ZoomWebhookPayload>,
+) -> anyhow::Result<Option<WebhookSigner>> {
+    // Check the platform secret
+    if verify_signature(headers, body, &zoom_cfg.webhook_secret_token) {
+        return Ok(Some(WebhookSigner {
+            group_id: None,
+            secret: zoom_cfg.webhook_secret_token.clone(),
+        }));
+    }
+
+    // Check the secrets of the group apps
+    let Some(payload) = payload else {
+        return Ok(None);
+    };
+    let account_id = payload.payload.as_ref().and_then(|p| p.account_id.clone());
+    if account_id.is_none() && payload.event != EVENT_URL_VALIDATION {
+        return Ok(None);
+    }
+    let secret_tokens = db.list_zoom_webhook_secret_tokens(account_id).await?;
+
+    Ok(secret_tokens
+        .into_iter()
+        .find(|secret_token| verify_signature(headers, body, &secret_token.secret_token))
+        .map(|secret_token| WebhookSigner {
+            group_id: Some(secret_token.group_id),
+            secret: secret_token.secret_token,
+        }))
+}
+
 /// Verifies the Zoom webhook signature using HMAC-SHA256.
 fn verify_signature(headers: &HeaderMap, body: &str, secret: &str) -> bool {
     // Extract required headers
@@ -176,25 +308,42 @@ pub(crate) struct ZoomWebhookPayload {
     /// Event payload data.
     #[serde(default)]
     pub payload: Option<WebhookEventPayload>,
+
+    /// Event timestamp (milliseconds since epoch).
+    #[serde(default)]
+    pub event_ts: Option<i64>,
+}
+
+impl ZoomWebhookPayload {
+    /// Returns when the event occurred, if Zoom provided a valid timestamp.
+    fn occurred_at(&self) -> Option<DateTime<Utc>> {
+        self.event_ts.and_then(DateTime::from_timestamp_millis)
+    }
 }
 
 /// Payload for webhook events.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct WebhookEventPayload {
+    /// Zoom account the event belongs to.
+    #[serde(default, rename = "account_id")]
+    pub account_id: Option<String>,
+
     /// Plain token for URL validation.
     #[serde(default)]
     pub plain_token: Option<String>,
 
-    /// Recording object for recording events.
+    /// Meeting or recording object the event refers to.
     #[serde(default)]
-    pub object: Option<RecordingObject>,
+    pub object: Option<WebhookEventObject>,
 }
 
-/// Recording object from recording.completed event.
+/// Meeting or recording object from meeting and recording events.
+#[serde_as]
 #[derive(Debug, Deserialize)]
-pub(crate) struct RecordingObject {
-    /// Zoom meeting ID (numeric).
+pub(crate) struct WebhookEventObject {
+    /// Zoom meeting ID (numeric, sent as a string by some meeting events).
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub id: i64,
 
     /// Share URL for the recording.
@@ -202,6 +351,15 @@ pub(crate) struct RecordingObject {
     pub share_url: Option<String>,
 }
 
+/// Zoom app a webhook was signed by.
+struct WebhookSigner {
+    /// Group owning the Zoom app, or `None` for the platform app.
+    group_id: Option<Uuid>,
+
+    /// Webhook secret token the request was signed with.
+    secret: String,
+}
+
 /// Response for URL validation challenge.
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -7,7 +7,7 @@ use serde_json::{Value, json};
 use tower::ServiceExt;
 
 use crate::{
-    db::mock::MockDB,
+    db::{meetings::ZoomWebhookSecretToken, mock::MockDB},
     handlers::tests::{TestRouterBuilder, sample_site_settings, sample_zoom_meetings_cfg},
     services::notifications::MockNotificationsManager,
 };
@@ -140,12 +140,13 @@ async fn test_zoom_event_returns_internal_server_error_when_recording_append_fai
     let mut db = MockDB::new();
     db.expect_append_meeting_recording_url()
         .times(1)
-        .withf(|provider, provider_meeting_id, recording_url| {
+        .withf(|provider, provider_meeting_id, group_id, recording_url| {
             *provider == MeetingProvider::Zoom
                 && provider_meeting_id == "12345"
+                && group_id.is_none()
                 && recording_url == "https://zoom.example/recording"
         })
-        .returning(|_, _, _| Err(anyhow!("db error")));
+        .returning(|_, _, _, _| Err(anyhow!("db error")));
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -166,6 +167,47 @@ async fn test_zoom_event_returns_internal_server_error_when_recording_append_fai
     assert!(bytes.is_empty());
 }
 
+#[tokio::test]
+async fn test_zoom_event_returns_internal_server_error_when_secrets_lookup_fails() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_ENDED,
+        "payload": {
+            "account_id": "group-account",
+            "object": {
+                "id": 12345,
+            }
+        }
+    })
+    .to_string();
+    let request = signed_zoom_webhook_request(&body, "group-secret");
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_zoom_webhook_secret_tokens()
+        .times(1)
+        .returning(|_| Err(anyhow!("db error")));
+    db.expect_update_meeting_provider_status().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("zoom-secret"))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
 #[tokio::test]
 async fn test_zoom_event_returns_not_found_when_zoom_is_disabled() {
     // Setup database mock
@@ -216,7 +258,16 @@ async fn test_zoom_event_returns_unauthorized_for_invalid_signature() {
     let request = signed_zoom_webhook_request(&body, "wrong-secret");
 
     // Setup database mock
-    let db = MockDB::new();
+    let mut db = MockDB::new();
+    db.expect_list_zoom_webhook_secret_tokens()
+        .times(1)
+        .withf(Option::is_none)
+        .returning(|_| {
+            Ok(vec![sample_zoom_webhook_secret_token(
+                Uuid::new_v4(),
+                "group-secret",
+            )])
+        });
 
     // Setup notifications manager mock
     let nm = MockNotificationsManager::new();
@@ -257,11 +308,305 @@ async fn test_zoom_event_appends_recording_url() {
     let mut db = MockDB::new();
     db.expect_append_meeting_recording_url()
         .times(1)
-        .withf(|provider, provider_meeting_id, recording_url| {
+        .withf(|provider, provider_meeting_id, group_id, recording_url| {
             *provider == MeetingProvider::Zoom
                 && provider_meeting_id == "12345"
+                && group_id.is_none()
                 && recording_url == "https://zoom.example/recording"
         })
+        .returning(|_, _, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg(secret))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_zoom_event_records_meeting_deleted_status() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_DELETED,
+        "payload": {
+            "object": {
+                "id": 12345,
+            }
+        }
+    })
+    .to_string();
+    let secret = "zoom-secret";
+    let request = signed_zoom_webhook_request(&body, secret);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_update_meeting_provider_status()
+        .times(1)
+        .withf(
+            |provider, provider_meeting_id, group_id, status, occurred_at| {
+                *provider == MeetingProvider::Zoom
+                    && provider_meeting_id == "12345"
+                    && group_id.is_none()
+                    && *status == MeetingProviderStatus::Deleted
+                    && occurred_at.is_none()
+            },
+        )
+        .returning(|_, _, _, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg(secret))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_zoom_event_records_meeting_ended_status() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_ENDED,
+        "event_ts": 1_717_250_400_000_i64,
+        "payload": {
+            "object": {
+                "id": "12345",
+            }
+        }
+    })
+    .to_string();
+    let secret = "zoom-secret";
+    let request = signed_zoom_webhook_request(&body, secret);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_update_meeting_provider_status()
+        .times(1)
+        .withf(
+            |provider, provider_meeting_id, group_id, status, occurred_at| {
+                *provider == MeetingProvider::Zoom
+                    && provider_meeting_id == "12345"
+                    && group_id.is_none()
+                    && *status == MeetingProviderStatus::Ended
+                    && *occurred_at == DateTime::from_timestamp_millis(1_717_250_400_000)
+            },
+        )
+        .returning(|_, _, _, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg(secret))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_zoom_event_records_meeting_status_signed_by_group_app() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_ENDED,
+        "payload": {
+            "account_id": "group-account",
+            "object": {
+                "id": 12345,
+            }
+        }
+    })
+    .to_string();
+    let request = signed_zoom_webhook_request(&body, "group-secret");
+
+    // Setup identifiers and data structures
+    let group_id = Uuid::new_v4();
+    let other_group_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_zoom_webhook_secret_tokens()
+        .times(1)
+        .withf(|account_id| account_id.as_deref() == Some("group-account"))
+        .returning(move |_| {
+            Ok(vec![
+                sample_zoom_webhook_secret_token(other_group_id, "other-secret"),
+                sample_zoom_webhook_secret_token(group_id, "group-secret"),
+            ])
+        });
+    db.expect_update_meeting_provider_status()
+        .times(1)
+        .withf(
+            move |provider, provider_meeting_id, meeting_group_id, status, _| {
+                *provider == MeetingProvider::Zoom
+                    && provider_meeting_id == "12345"
+                    && *meeting_group_id == Some(group_id)
+                    && *status == MeetingProviderStatus::Ended
+            },
+        )
+        .returning(|_, _, _, _, _| Ok(()));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("zoom-secret"))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::OK);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_zoom_event_returns_unauthorized_for_unknown_group_app_signature() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_ENDED,
+        "payload": {
+            "account_id": "group-account",
+            "object": {
+                "id": 12345,
+            }
+        }
+    })
+    .to_string();
+    let request = signed_zoom_webhook_request(&body, "group-secret");
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_zoom_webhook_secret_tokens()
+        .times(1)
+        .withf(|account_id| account_id.as_deref() == Some("group-account"))
+        .returning(|_| {
+            Ok(vec![sample_zoom_webhook_secret_token(
+                Uuid::new_v4(),
+                "other-secret",
+            )])
+        });
+    db.expect_update_meeting_provider_status().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("zoom-secret"))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNAUTHORIZED);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_zoom_event_returns_unauthorized_for_group_app_signature_without_account() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_ENDED,
+        "payload": {
+            "object": {
+                "id": 12345,
+            }
+        }
+    })
+    .to_string();
+    let request = signed_zoom_webhook_request(&body, "group-secret");
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_zoom_webhook_secret_tokens().times(0);
+    db.expect_update_meeting_provider_status().times(0);
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("zoom-secret"))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::UNAUTHORIZED);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_zoom_event_records_participant_joined() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_PARTICIPANT_JOINED,
+        "payload": {
+            "object": {
+                "id": "12345",
+                "participant": {
+                    "user_name": "Attendee",
+                }
+            }
+        }
+    })
+    .to_string();
+    let secret = "zoom-secret";
+    let request = signed_zoom_webhook_request(&body, secret);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_record_meeting_participant_joined()
+        .times(1)
+        .withf(|provider, provider_meeting_id, group_id| {
+            *provider == MeetingProvider::Zoom
+                && provider_meeting_id == "12345"
+                && group_id.is_none()
+        })
         .returning(|_, _, _| Ok(()));
 
     // Setup notifications manager mock
@@ -283,6 +628,79 @@ async fn test_zoom_event_appends_recording_url() {
     assert!(bytes.is_empty());
 }
 
+#[tokio::test]
+async fn test_zoom_event_returns_bad_request_when_meeting_object_is_missing() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_STARTED,
+        "payload": {}
+    })
+    .to_string();
+    let secret = "zoom-secret";
+    let request = signed_zoom_webhook_request(&body, secret);
+
+    // Setup database mock
+    let db = MockDB::new();
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg(secret))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    assert!(bytes.is_empty());
+}
+
+#[tokio::test]
+async fn test_zoom_event_returns_internal_server_error_when_status_update_fails() {
+    // Setup request body and signature
+    let body = json!({
+        "event": EVENT_MEETING_STARTED,
+        "payload": {
+            "object": {
+                "id": 12345,
+            }
+        }
+    })
+    .to_string();
+    let secret = "zoom-secret";
+    let request = signed_zoom_webhook_request(&body, secret);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_update_meeting_provider_status()
+        .times(1)
+        .returning(|_, _, _, _, _| Err(anyhow!("db error")));
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg(secret))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(bytes.is_empty());
+}
+
 #[tokio::test]
 async fn test_zoom_event_validates_url() {
     // Setup request body and signature
@@ -324,8 +742,65 @@ async fn test_zoom_event_validates_url() {
     );
 }
 
+#[tokio::test]
+async fn test_zoom_event_validates_url_signed_by_group_app() {
+    // Setup request body and signature
+    let plain_token = "challenge-token";
+    let body = json!({
+        "event": EVENT_URL_VALIDATION,
+        "payload": {
+            "plainToken": plain_token,
+        }
+    })
+    .to_string();
+    let secret = "group-secret";
+    let request = signed_zoom_webhook_request(&body, secret);
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_list_zoom_webhook_secret_tokens()
+        .times(1)
+        .withf(Option::is_none)
+        .returning(|_| {
+            Ok(vec![sample_zoom_webhook_secret_token(
+                Uuid::new_v4(),
+                "group-secret",
+            )])
+        });
+
+    // Setup notifications manager mock
+    let nm = MockNotificationsManager::new();
+
+    // Setup router
+    let router = TestRouterBuilder::new(db, nm)
+        .with_meetings_cfg(sample_zoom_meetings_cfg("zoom-secret"))
+        .build()
+        .await;
+
+    // Execute request
+    let response = router.oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+    // Check response matches expectations
+    let response_json = serde_json::from_slice::<Value>(&bytes).unwrap();
+    assert_eq!(parts.status, StatusCode::OK);
+    assert_eq!(response_json["plainToken"], plain_token);
+    assert_eq!(
+        response_json["encryptedToken"],
+        compute_hmac(plain_token, secret)
+    );
+}
+
 // Helpers
 
+fn sample_zoom_webhook_secret_token(group_id: Uuid, secret_token: &str) -> ZoomWebhookSecretToken {
+    ZoomWebhookSecretToken {
+        group_id,
+        secret_token: secret_token.to_string(),
+    }
+}
+
 fn signed_zoom_webhook_request(body: &str, secret: &str) -> Request<Body> {
     let timestamp = chrono::Utc::now().timestamp().to_string();
     let message = format!("v0:{timestamp}:{body}");
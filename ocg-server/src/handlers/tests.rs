@@ -19,7 +19,11 @@ use uuid::Uuid;
 use crate::{
     activity_tracker::DynActivityTracker,
     auth::User as AuthUser,
-    config::{HttpServerConfig, MeetingsConfig, MeetingsZoomConfig, PaymentsConfig},
+    config::{
+        CaptchaConfig, CaptchaProvider, EmailFeedbackConfig, GoogleCalendarConfig,
+        HttpServerConfig, MeetingsConfig, MeetingsZoomConfig, PaymentsConfig, SmsConfig,
+        SmsTwilioConfig,
+    },
     db::{
         BBox, DynDB,
         common::{SearchEventsOutput, SearchGroupsOutput},
@@ -29,6 +33,8 @@ use crate::{
     handlers::auth::{SELECTED_COMMUNITY_ID_KEY, SELECTED_GROUP_ID_KEY},
     router,
     services::{
+        certificates::AttendanceCertificate,
+        domains::CustomDomains,
         images::{DynImageStorage, MockImageStorage},
         notifications::{DynNotificationsManager, MockNotificationsManager},
         payments::{DynPaymentsManager, MockPaymentsManager},
@@ -47,15 +53,17 @@ use crate::{
             },
             group::{
                 analytics::{
-                    GroupAttendeesStats, GroupDashboardStats, GroupEventsStats, GroupMembersStats,
-                    GroupPageViewsStats, PageViewsStats as GroupPageViewsEntry,
+                    GroupAttendeesStats, GroupCheckInsStats, GroupDashboardStats,
+                    GroupEngagementStats, GroupEventsStats, GroupMembersStats, GroupPageViewsStats,
+                    GroupRetentionStats, GroupTopEvent, PageViewsStats as GroupPageViewsEntry,
                 },
                 attendees::Attendee,
                 events::{CfsSubmissionStatus, Event as GroupEventForm, GroupEvents},
                 home::UserGroupsByCommunity,
                 invitation_requests::InvitationRequest,
                 members::GroupMember,
-                settings::GroupUpdate,
+                photos::{EventPhoto, EventPhotoStatus},
+                settings::{GroupOwnership, GroupUpdate},
                 sponsors::Sponsor,
                 submissions::{
                     CfsSessionProposal as GroupCfsSessionProposal,
@@ -64,8 +72,12 @@ use crate::{
                 team::GroupTeamMember,
                 waitlist::WaitlistEntry,
             },
+            notifications::{CustomNotificationSummary, CustomNotificationsOutput},
             user::{
-                invitations::{CommunityTeamInvitation, EventInvitation, GroupTeamInvitation},
+                invitations::{
+                    CommunityTeamInvitation, EventInvitation, GroupOwnershipTransferInvitation,
+                    GroupTeamInvitation,
+                },
                 session_proposals::{
                     PendingCoSpeakerInvitation, SessionProposal as UserSessionProposal,
                     SessionProposalLevel as UserSessionProposalLevel,
@@ -79,18 +91,25 @@ use crate::{
         event::SessionProposal as EventSessionProposal,
     },
     types::{
-        community::{CommunityFull, CommunityRole, CommunityRoleSummary, CommunitySummary},
+        community::{
+            CommunityEmailBranding, CommunityFull, CommunityRole, CommunityRoleSummary,
+            CommunitySummary, CommunityTheme, CommunityTopic,
+        },
         event::{
-            EventCategory, EventFull, EventKind, EventKindSummary, EventSummary, SessionKindSummary,
+            EventCategory, EventComment, EventDiscussion, EventFull, EventKind, EventKindSummary,
+            EventSeries, EventSummary, SessionKindSummary, SpeakerProfile, SpeakerTalk,
         },
         group::{
-            GroupCategory, GroupFull, GroupMinimal, GroupRegion, GroupRole, GroupRoleSummary,
-            GroupSponsor, GroupSummary,
+            GroupAnnouncement, GroupCategory, GroupDiscussion, GroupDiscussionReply,
+            GroupDiscussionTopic, GroupFull, GroupGalleryPhoto, GroupGalleryPhotoSource,
+            GroupMinimal, GroupRegion, GroupRole, GroupRoleSummary, GroupSponsor, GroupSponsorTier,
+            GroupSummary, SponsorLogoSize,
         },
         payments::{EventPurchaseStatus, EventPurchaseSummary},
         permissions::{CommunityPermission, GroupPermission},
-        site::{SiteSettings, Theme},
-        user::{User as TemplateUser, UserSummary},
+        questionnaire::{QuestionnaireQuestion, QuestionnaireQuestionKind},
+        site::{SiteSettings, Theme, ThemeFontFamily},
+        user::{User as TemplateUser, UserSession, UserSummary},
     },
 };
 
@@ -236,6 +255,9 @@ pub(crate) fn expect_community_permission(
             *cid == community_id && *uid == user_id && permission == expected_permission
         })
         .returning(|_, _, _| Ok(true));
+    db.expect_community_passkey_required().returning(|_| Ok(false));
+    db.expect_get_community_required_oidc_provider_by_id()
+        .returning(|_| Ok(None));
 }
 
 /// Expect a successful group permission check.
@@ -273,6 +295,24 @@ pub(crate) fn expect_successful_transaction(db: &mut MockDB, mut tx: MockDB) {
 
 // Sample data helpers.
 
+/// Sample certificate of attendance used in user dashboard tests.
+pub(crate) fn sample_attendance_certificate(event_id: Uuid) -> AttendanceCertificate {
+    AttendanceCertificate {
+        attendee_name: "Event Attendee".to_string(),
+        community_display_name: "Test Community".to_string(),
+        event_id,
+        event_name: "Sample Event".to_string(),
+        event_slug: "sample-event".to_string(),
+        group_name: "Test Group".to_string(),
+        primary_color: "#D62293".to_string(),
+        starts_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+        timezone: UTC,
+
+        ends_at: Some(Utc.with_ymd_and_hms(2024, 1, 1, 14, 0, 0).unwrap()),
+        logo_url: None,
+    }
+}
+
 /// Sample attendee used in dashboard group home tests.
 pub(crate) fn sample_attendee() -> Attendee {
     let user_id = Uuid::new_v4();
@@ -334,6 +374,7 @@ pub(crate) fn sample_auth_user(user_id: Uuid, auth_hash: &str) -> AuthUser {
         registration_status: "registered".to_string(),
         user_id,
         username: "test-user".to_string(),
+        weekly_digest_enabled: true,
 
         belongs_to_any_group_team: Some(true),
         has_password: Some(true),
@@ -351,6 +392,25 @@ pub(crate) fn sample_bbox() -> BBox {
     }
 }
 
+/// Sample CAPTCHA configuration used by the forms protected by a challenge.
+pub(crate) fn sample_captcha_config() -> CaptchaConfig {
+    CaptchaConfig {
+        provider: CaptchaProvider::Turnstile,
+        secret_key: "captcha-secret".to_string(),
+        site_key: "captcha-site-key".to_string(),
+    }
+}
+
+/// Sample community email branding used across tests.
+pub(crate) fn sample_community_email_branding() -> CommunityEmailBranding {
+    CommunityEmailBranding {
+        footer_text: Some("Test Community, 123 Main St".to_string()),
+        from_name: Some("Test Community".to_string()),
+        logo_url: Some("https://example.test/email-logo.png".to_string()),
+        primary_color: Some("#0A3D62".to_string()),
+    }
+}
+
 /// Sample community used across tests.
 pub(crate) fn sample_community_full(community_id: Uuid) -> CommunityFull {
     CommunityFull {
@@ -361,13 +421,27 @@ pub(crate) fn sample_community_full(community_id: Uuid) -> CommunityFull {
         created_at: DateTime::UNIX_EPOCH,
         description: "Test community".to_string(),
         display_name: "Test".to_string(),
+        dormant_group_months: 6,
+        dormant_groups_report_enabled: false,
+        event_approval_required: false,
         group_team_management_restricted: false,
         logo_url: "/static/images/placeholder_cncf.png".to_string(),
         name: "test".to_string(),
+        passkey_required: false,
         ..Default::default()
     }
 }
 
+/// Sample community theme used across tests.
+pub(crate) fn sample_community_theme() -> CommunityTheme {
+    CommunityTheme {
+        favicon_url: Some("https://example.test/favicon.ico".to_string()),
+        font_family: Some(ThemeFontFamily::Serif),
+        header_logo_url: Some("https://example.test/header-logo.png".to_string()),
+        primary_color: Some("#1B4F72".to_string()),
+    }
+}
+
 /// Sample community summary used across tests.
 pub(crate) fn sample_community_summary(community_id: Uuid) -> CommunitySummary {
     CommunitySummary {
@@ -518,12 +592,49 @@ pub(crate) fn sample_community_update() -> CommunityUpdate {
         banner_url: "https://example.test/banner.png".to_string(),
         description: "Updated description".to_string(),
         display_name: "Test".to_string(),
+        dormant_group_months: 6,
+        dormant_groups_report_enabled: false,
+        event_approval_required: false,
         group_team_management_restricted: false,
         logo_url: "https://example.test/logo.png".to_string(),
+        passkey_required: false,
         ..Default::default()
     }
 }
 
+/// Sample community topic used in dashboard community topics tests.
+pub(crate) fn sample_community_topic() -> CommunityTopic {
+    CommunityTopic {
+        name: "Kubernetes".to_string(),
+        normalized_name: "kubernetes".to_string(),
+        topic_id: Uuid::new_v4(),
+
+        events_count: Some(0),
+        groups_count: Some(0),
+    }
+}
+
+/// Sample custom notifications output used across dashboard notifications tests.
+pub(crate) fn sample_custom_notifications_output() -> CustomNotificationsOutput {
+    CustomNotificationsOutput {
+        notifications: vec![CustomNotificationSummary {
+            bounced: 1,
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            custom_notification_id: Uuid::new_v4(),
+            failed: 2,
+            group_name: "Test Group".to_string(),
+            queued: 0,
+            sent: 10,
+            subject: "Schedule updated".to_string(),
+            unknown: 0,
+
+            created_by_username: Some("test-user".to_string()),
+            event_name: Some("Test Event".to_string()),
+        }],
+        total: 1,
+    }
+}
+
 /// Sample dashboard user entry returned by search endpoints.
 pub(crate) fn sample_dashboard_user(user_id: Uuid) -> DashboardUser {
     DashboardUser {
@@ -603,6 +714,46 @@ pub(crate) fn sample_event_cfs_session_proposal(session_proposal_id: Uuid) -> Ev
     }
 }
 
+/// Sample event discussion with a question answered by an organizer.
+pub(crate) fn sample_event_discussion() -> EventDiscussion {
+    let reply = EventComment {
+        content: "Yes, all talks will be recorded.".to_string(),
+        created_at: Utc.with_ymd_and_hms(2024, 1, 1, 13, 0, 0).unwrap(),
+        event_comment_id: Uuid::new_v4(),
+        organizer: true,
+        pinned: true,
+        replies: vec![],
+        user: sample_dashboard_user_profile(
+            Uuid::new_v4(),
+            "organizer-user",
+            Some("Organizer User"),
+            None,
+            None,
+            None,
+        ),
+    };
+
+    EventDiscussion {
+        comments: vec![EventComment {
+            content: "Will the talks be recorded?".to_string(),
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            event_comment_id: Uuid::new_v4(),
+            organizer: false,
+            pinned: false,
+            replies: vec![reply],
+            user: sample_dashboard_user_profile(
+                Uuid::new_v4(),
+                "attendee-user",
+                Some("Attendee User"),
+                Some("https://example.test/avatar.png"),
+                Some("Engineer"),
+                Some("Example"),
+            ),
+        }],
+        locked: false,
+    }
+}
+
 /// Sample event form payload submitted from the dashboard.
 pub(crate) fn sample_event_form() -> GroupEventForm {
     GroupEventForm {
@@ -688,6 +839,48 @@ pub(crate) fn sample_event_kind_summary() -> EventKindSummary {
     }
 }
 
+/// Sample pending photo shared by an event attendee.
+pub(crate) fn sample_event_photo() -> EventPhoto {
+    let user_id = Uuid::new_v4();
+
+    EventPhoto {
+        created_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+        event_photo_id: Uuid::new_v4(),
+        status: EventPhotoStatus::Pending,
+        url: "https://example.test/photo.png".to_string(),
+        user: sample_dashboard_user_profile(
+            user_id,
+            "attendee-user",
+            Some("Attendee User"),
+            Some("https://example.test/avatar.png"),
+            Some("Engineer"),
+            Some("Example"),
+        ),
+
+        reviewed_at: None,
+    }
+}
+
+/// Sample linked event series with one published event.
+pub(crate) fn sample_event_series(
+    community_id: Uuid,
+    event_series_id: Uuid,
+    group_id: Uuid,
+) -> EventSeries {
+    let mut event = sample_event_summary(Uuid::new_v4(), group_id);
+    event.event_series_id = Some(event_series_id);
+
+    EventSeries {
+        attendee_count: 12,
+        checked_in_count: 7,
+        community: sample_community_summary(community_id),
+        event_series_id,
+        events: vec![event],
+        group: sample_group_summary(group_id),
+        name: Some("Sample Series".to_string()),
+    }
+}
+
 /// Sample event summary used in listings.
 pub(crate) fn sample_event_summary(event_id: Uuid, _group_id: Uuid) -> EventSummary {
     let starts_at = Utc::now() + chrono::Duration::hours(1);
@@ -710,6 +903,7 @@ pub(crate) fn sample_event_summary(event_id: Uuid, _group_id: Uuid) -> EventSumm
         test_event: false,
         timezone: UTC,
 
+        approval_status: None,
         capacity: None,
         created_by_display_name: None,
         created_by_username: None,
@@ -737,6 +931,7 @@ pub(crate) fn sample_event_summary(event_id: Uuid, _group_id: Uuid) -> EventSumm
         venue_country_name: Some("United States".to_string()),
         venue_name: Some("Sample Venue".to_string()),
         venue_state: Some("MA".to_string()),
+        waitlist_confirmation_hours: None,
         waitlist_count: 0,
         waitlist_enabled: false,
         zip_code: Some("02101".to_string()),
@@ -748,6 +943,17 @@ pub(crate) fn sample_filters_options() -> crate::templates::site::explore::Filte
     crate::templates::site::explore::FiltersOptions::default()
 }
 
+/// Sample pinned group announcement.
+pub(crate) fn sample_group_announcement() -> GroupAnnouncement {
+    GroupAnnouncement {
+        content: "The venue for the next meetup has changed.".to_string(),
+        created_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+        group_announcement_id: Uuid::new_v4(),
+        pinned: true,
+        title: "Venue change".to_string(),
+    }
+}
+
 /// Sample group category reused across tests.
 pub(crate) fn sample_group_category() -> GroupCategory {
     GroupCategory {
@@ -811,6 +1017,70 @@ pub(crate) fn sample_group_cfs_submission_status(
     }
 }
 
+/// Sample group discussion with a topic and an organizer reply.
+pub(crate) fn sample_group_discussion() -> GroupDiscussion {
+    let reply = GroupDiscussionReply {
+        content: "Yes, we can host it at our office.".to_string(),
+        created_at: Utc.with_ymd_and_hms(2024, 1, 1, 13, 0, 0).unwrap(),
+        group_discussion_reply_id: Uuid::new_v4(),
+        organizer: true,
+        user: sample_dashboard_user_profile(
+            Uuid::new_v4(),
+            "organizer-user",
+            Some("Organizer User"),
+            None,
+            None,
+            None,
+        ),
+    };
+
+    GroupDiscussion {
+        topics: vec![GroupDiscussionTopic {
+            content: "Could we organize a study session before the next meetup?".to_string(),
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            group_discussion_topic_id: Uuid::new_v4(),
+            locked: false,
+            organizer: false,
+            pinned: true,
+            replies: vec![reply],
+            title: "Study session".to_string(),
+            user: sample_dashboard_user_profile(
+                Uuid::new_v4(),
+                "member-user",
+                Some("Member User"),
+                Some("https://example.test/avatar.png"),
+                Some("Engineer"),
+                Some("Example"),
+            ),
+        }],
+    }
+}
+
+/// Sample group engagement stats used in analytics tests.
+pub(crate) fn sample_group_engagement_stats() -> GroupEngagementStats {
+    GroupEngagementStats {
+        check_ins: GroupCheckInsStats {
+            per_month: vec![("2024-01".to_string(), 4)],
+            running_total: vec![(1, 4)],
+            total: 4,
+        },
+        retention: GroupRetentionStats {
+            per_month: vec![("2024-01".to_string(), 2)],
+            repeat_attendees: 2,
+            unique_attendees: 3,
+        },
+        top_events: vec![GroupTopEvent {
+            attendees: 5,
+            check_ins: 4,
+            event_id: Uuid::new_v4(),
+            group_name: "Test Group".to_string(),
+            name: "Top Event".to_string(),
+
+            starts_at: Some(Utc.with_ymd_and_hms(2024, 1, 15, 18, 0, 0).unwrap()),
+        }],
+    }
+}
+
 /// Sample group events aggregation for dashboard pages.
 pub(crate) fn sample_group_events(event_id: Uuid, group_id: Uuid) -> GroupEvents {
     let summary = sample_event_summary(event_id, group_id);
@@ -860,6 +1130,18 @@ pub(crate) fn sample_group_full(community_id: Uuid, group_id: Uuid) -> GroupFull
     }
 }
 
+/// Sample group gallery photo reused across tests.
+pub(crate) fn sample_group_gallery_photo() -> GroupGalleryPhoto {
+    GroupGalleryPhoto {
+        cover: false,
+        hidden: false,
+        source: GroupGalleryPhotoSource::Event,
+        url: "https://example.test/gallery/photo.png".to_string(),
+
+        event_name: Some("Sample Event".to_string()),
+    }
+}
+
 /// Sample group team invitation used by user dashboard tests.
 pub(crate) fn sample_group_invitation(group_id: Uuid) -> GroupTeamInvitation {
     GroupTeamInvitation {
@@ -875,9 +1157,15 @@ pub(crate) fn sample_group_invitation(group_id: Uuid) -> GroupTeamInvitation {
 pub(crate) fn sample_group_member() -> GroupMember {
     GroupMember {
         created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        email_undeliverable: false,
+        events_attended: 3,
+        events_missed: 1,
+        user_id: Uuid::new_v4(),
         username: "member".to_string(),
 
         company: Some("Example".to_string()),
+        join_answers: None,
+        member_role: Some("Mentor".to_string()),
         name: Some("Group Member".to_string()),
         photo_url: Some("https://example.test/photo.png".to_string()),
         title: Some("Engineer".to_string()),
@@ -896,6 +1184,25 @@ pub(crate) fn sample_group_minimal(group_id: Uuid) -> GroupMinimal {
     }
 }
 
+/// Sample group ownership owned by the provided user with one candidate.
+pub(crate) fn sample_group_ownership(owner_user_id: Uuid) -> GroupOwnership {
+    GroupOwnership {
+        candidates: vec![sample_user_summary(Uuid::new_v4(), "candidate")],
+        owner: Some(sample_user_summary(owner_user_id, "owner")),
+        pending_transfer: None,
+    }
+}
+
+/// Sample group ownership transfer used by user dashboard tests.
+pub(crate) fn sample_group_ownership_transfer(group_id: Uuid) -> GroupOwnershipTransferInvitation {
+    GroupOwnershipTransferInvitation {
+        community_name: "test-community".to_string(),
+        group_id,
+        group_name: "Test Group".to_string(),
+        created_at: Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap(),
+    }
+}
+
 /// Sample group region definition reused across tests.
 pub(crate) fn sample_group_region() -> GroupRegion {
     GroupRegion {
@@ -965,13 +1272,26 @@ pub(crate) fn sample_group_sponsor() -> GroupSponsor {
     GroupSponsor {
         featured: true,
         group_sponsor_id: Uuid::new_v4(),
+        logo_size: SponsorLogoSize::Medium,
         logo_url: "https://example.test/logo.png".to_string(),
         name: "Sponsor".to_string(),
 
+        group_sponsor_tier_id: None,
+        tier_name: None,
         website_url: Some("https://example.test".to_string()),
     }
 }
 
+/// Sample group sponsor tier entry.
+pub(crate) fn sample_group_sponsor_tier() -> GroupSponsorTier {
+    GroupSponsorTier {
+        group_sponsor_tier_id: Uuid::new_v4(),
+        logo_size: SponsorLogoSize::Large,
+        name: "Gold".to_string(),
+        order: 1,
+    }
+}
+
 /// Sample group summary used by multiple fixtures.
 pub(crate) fn sample_group_summary(group_id: Uuid) -> GroupSummary {
     GroupSummary {
@@ -985,12 +1305,14 @@ pub(crate) fn sample_group_summary(group_id: Uuid) -> GroupSummary {
         name: "Test Group".to_string(),
         slug: "npq6789".to_string(),
 
+        archived_at: None,
         banner_mobile_url: Some("https://example.test/banner_mobile.png".to_string()),
         banner_url: Some("https://example.test/banner.png".to_string()),
         city: Some("San Francisco".to_string()),
         country_code: Some("US".to_string()),
         country_name: Some("United States".to_string()),
         description_short: Some("An example summary for the sample group".to_string()),
+        dormant_since: None,
         latitude: Some(37.0),
         longitude: Some(-122.0),
         og_image_url: None,
@@ -1046,6 +1368,18 @@ pub(crate) fn sample_invitation_request() -> InvitationRequest {
     }
 }
 
+/// Sample required free-text question asked to users joining a group.
+pub(crate) fn sample_join_question(question_id: Uuid) -> QuestionnaireQuestion {
+    QuestionnaireQuestion {
+        id: question_id,
+        kind: QuestionnaireQuestionKind::FreeText,
+        prompt: "What topics interest you?".to_string(),
+        required: true,
+
+        options: vec![],
+    }
+}
+
 /// Sample pending co-speaker invitation used in user dashboard tests.
 pub(crate) fn sample_pending_co_speaker_invitation(
     session_proposal_id: Uuid,
@@ -1077,7 +1411,6 @@ pub(crate) fn sample_pending_co_speaker_invitation(
 pub(crate) fn sample_purchase_summary(status: EventPurchaseStatus) -> EventPurchaseSummary {
     EventPurchaseSummary {
         amount_minor: 2_500,
-        currency_code: "USD".to_string(),
         discount_amount_minor: 0,
         event_purchase_id: Uuid::new_v4(),
         event_ticket_type_id: Uuid::new_v4(),
@@ -1085,6 +1418,7 @@ pub(crate) fn sample_purchase_summary(status: EventPurchaseStatus) -> EventPurch
         ticket_title: "General admission".to_string(),
 
         completed_at: None,
+        currency_code: Some("USD".to_string()),
         discount_code: None,
         hold_expires_at: None,
         provider_checkout_url: None,
@@ -1193,6 +1527,7 @@ pub(crate) fn sample_site_settings() -> SiteSettings {
         theme: Theme {
             palette: BTreeMap::new(),
             primary_color: "#000000".to_string(),
+            ..Default::default()
         },
         title: "Test Site".to_string(),
         ..Default::default()
@@ -1225,6 +1560,24 @@ pub(crate) fn sample_site_stats() -> crate::templates::site::stats::SiteStats {
     }
 }
 
+/// Sample speaker profile used by public speaker page tests.
+pub(crate) fn sample_speaker_profile(community_id: Uuid, user_id: Uuid) -> SpeakerProfile {
+    SpeakerProfile {
+        community: sample_community_summary(community_id),
+        talks: vec![SpeakerTalk {
+            event: sample_event_summary(Uuid::new_v4(), Uuid::new_v4()),
+            session_name: Some("Sample Talk".to_string()),
+        }],
+        user: TemplateUser {
+            user_id,
+            username: "speaker".to_string(),
+            name: Some("Sample Speaker".to_string()),
+            title: Some("Engineer".to_string()),
+            ..Default::default()
+        },
+    }
+}
+
 /// Sample sponsor form payload used by dashboard group sponsors tests.
 pub(crate) fn sample_sponsor_form() -> Sponsor {
     Sponsor {
@@ -1232,6 +1585,7 @@ pub(crate) fn sample_sponsor_form() -> Sponsor {
         logo_url: "https://example.test/logo.png".to_string(),
         name: "Example".to_string(),
 
+        group_sponsor_tier_id: None,
         website_url: Some("https://example.test".to_string()),
     }
 }
@@ -1374,6 +1728,19 @@ pub(crate) fn sample_user_groups_by_community(
     }]
 }
 
+/// Sample user session for account page tests.
+pub(crate) fn sample_user_session() -> UserSession {
+    UserSession {
+        created_at: Utc::now(),
+        current: true,
+        last_seen_at: Utc::now(),
+        session_id: Uuid::new_v4(),
+
+        ip_address: Some("192.0.2.1".to_string()),
+        user_agent: Some("test-browser".to_string()),
+    }
+}
+
 /// Sample user summary used across dashboard tests.
 pub(crate) fn sample_user_summary(user_id: Uuid, username: &str) -> UserSummary {
     UserSummary {
@@ -1406,9 +1773,32 @@ pub(crate) fn sample_waitlist_entry() -> WaitlistEntry {
     }
 }
 
+/// Sample Google Calendar sync configuration used in handler tests.
+pub(crate) fn sample_google_calendar_cfg() -> GoogleCalendarConfig {
+    GoogleCalendarConfig {
+        client_id: "client-id".to_string(),
+        client_secret: "client-secret".to_string(),
+        redirect_uri: "https://example.test/dashboard/account/google-calendar/callback".to_string(),
+    }
+}
+
+/// Sample Twilio SMS configuration used in handler tests.
+pub(crate) fn sample_sms_cfg() -> SmsConfig {
+    SmsConfig::Twilio(SmsTwilioConfig {
+        account_sid: "account-sid".to_string(),
+        auth_token: "auth-token".to_string(),
+        from_number: "+15550000000".to_string(),
+    })
+}
+
 /// Sample Zoom meetings configuration used in handler tests.
 pub(crate) fn sample_zoom_meetings_cfg(secret: &str) -> MeetingsConfig {
     MeetingsConfig {
+        bigbluebutton: None,
+        google_meet: None,
+        jitsi: None,
+        teams: None,
+        webex: None,
         zoom: Some(MeetingsZoomConfig {
             account_id: "account-id".to_string(),
             client_id: "client-id".to_string(),
@@ -1458,7 +1848,10 @@ pub(crate) fn test_state_with_server_cfg(
 ) -> router::State {
     router::State {
         activity_tracker: Arc::new(crate::activity_tracker::MockActivityTracker::new()),
+        custom_domains: Arc::new(CustomDomains::default()),
         db,
+        email_feedback_cfg: None,
+        google_calendar_cfg: None,
         image_storage,
         meetings_cfg: None,
         notifications_manager,
@@ -1466,6 +1859,7 @@ pub(crate) fn test_state_with_server_cfg(
         payments_manager: Arc::new(MockPaymentsManager::new()),
         serde_qs_de: router::serde_qs_config(),
         server_cfg: server_cfg.clone(),
+        sms_cfg: None,
     }
 }
 
@@ -1473,12 +1867,15 @@ pub(crate) fn test_state_with_server_cfg(
 pub(crate) struct TestRouterBuilder {
     activity_tracker: Option<crate::activity_tracker::MockActivityTracker>,
     db: MockDB,
+    email_feedback_cfg: Option<EmailFeedbackConfig>,
+    google_calendar_cfg: Option<GoogleCalendarConfig>,
     image_storage: Option<MockImageStorage>,
     meetings_cfg: Option<crate::config::MeetingsConfig>,
     nm: MockNotificationsManager,
     payments_cfg: Option<PaymentsConfig>,
     payments_manager: Option<MockPaymentsManager>,
     server_cfg: Option<HttpServerConfig>,
+    sms_cfg: Option<SmsConfig>,
 }
 
 impl TestRouterBuilder {
@@ -1487,12 +1884,15 @@ impl TestRouterBuilder {
         Self {
             activity_tracker: None,
             db,
+            email_feedback_cfg: None,
+            google_calendar_cfg: None,
             image_storage: None,
             meetings_cfg: None,
             nm,
             payments_cfg: None,
             payments_manager: None,
             server_cfg: None,
+            sms_cfg: None,
         }
     }
 
@@ -1510,10 +1910,13 @@ impl TestRouterBuilder {
         router::setup(
             activity_tracker,
             db,
+            self.email_feedback_cfg,
+            self.google_calendar_cfg,
             is,
             self.meetings_cfg,
             self.payments_cfg,
             payments_manager,
+            self.sms_cfg,
             nm,
             &server_cfg,
         )
@@ -1530,6 +1933,18 @@ impl TestRouterBuilder {
         self
     }
 
+    /// Sets a custom email feedback configuration.
+    pub(crate) fn with_email_feedback_cfg(mut self, cfg: EmailFeedbackConfig) -> Self {
+        self.email_feedback_cfg = Some(cfg);
+        self
+    }
+
+    /// Sets a custom Google Calendar sync configuration.
+    pub(crate) fn with_google_calendar_cfg(mut self, cfg: GoogleCalendarConfig) -> Self {
+        self.google_calendar_cfg = Some(cfg);
+        self
+    }
+
     /// Sets a custom image storage.
     pub(crate) fn with_image_storage(mut self, is: MockImageStorage) -> Self {
         self.image_storage = Some(is);
@@ -1559,4 +1974,10 @@ impl TestRouterBuilder {
         self.server_cfg = Some(cfg);
         self
     }
+
+    /// Sets a custom SMS notifications configuration.
+    pub(crate) fn with_sms_cfg(mut self, cfg: SmsConfig) -> Self {
+        self.sms_cfg = Some(cfg);
+        self
+    }
 }
@@ -0,0 +1,260 @@
+//! Translation layer for user-facing notification content.
+//!
+//! Messages are keyed by their English text, so English needs no catalog and
+//! any message missing from a catalog falls back to English.
+
+use std::{collections::HashMap, str::FromStr, sync::LazyLock};
+
+use serde::{Deserialize, Serialize};
+
+/// Translation catalogs of the supported languages, keyed by English text.
+static CATALOGS: LazyLock<HashMap<Language, HashMap<String, String>>> = LazyLock::new(|| {
+    [
+        (Language::De, include_str!("../i18n/de.json")),
+        (Language::Es, include_str!("../i18n/es.json")),
+        (Language::Fr, include_str!("../i18n/fr.json")),
+        (Language::Pt, include_str!("../i18n/pt.json")),
+    ]
+    .into_iter()
+    .map(|(language, catalog)| {
+        let catalog = serde_json::from_str(catalog).expect("translation catalog to be valid");
+        (language, catalog)
+    })
+    .collect()
+});
+
+/// Languages notifications can be delivered in.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum Language {
+    /// German.
+    De,
+    /// English.
+    #[default]
+    En,
+    /// Spanish.
+    Es,
+    /// French.
+    Fr,
+    /// Portuguese.
+    Pt,
+}
+
+impl Language {
+    /// All supported languages.
+    pub(crate) const ALL: [Language; 5] = [
+        Language::De,
+        Language::En,
+        Language::Es,
+        Language::Fr,
+        Language::Pt,
+    ];
+
+    /// Return the ISO 639-1 code of the language.
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            Language::De => "de",
+            Language::En => "en",
+            Language::Es => "es",
+            Language::Fr => "fr",
+            Language::Pt => "pt",
+        }
+    }
+
+    /// Return the format used to display dates in the language.
+    ///
+    /// Month and weekday names are only available in English, so other
+    /// languages use a numeric format.
+    pub(crate) fn datetime_format(self) -> &'static str {
+        match self {
+            Language::En => "%A, %B %e, %G · %I:%M %p %Z",
+            _ => "%Y-%m-%d · %H:%M %Z",
+        }
+    }
+
+    /// Pick the preferred supported language from an `Accept-Language` header.
+    pub(crate) fn from_accept_language(header: &str) -> Option<Language> {
+        let mut preferred: Option<(Language, f32)> = None;
+        for entry in header.split(',') {
+            let mut parts = entry.split(';');
+            let tag = parts.next().unwrap_or_default().trim();
+            let primary = tag.split('-').next().unwrap_or_default().to_lowercase();
+            let Ok(language) = Language::from_str(&primary) else {
+                continue;
+            };
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok());
+            let Some(quality) = quality.filter(|q| *q > 0.0) else {
+                continue;
+            };
+            if preferred.is_none_or(|(_, best)| quality > best) {
+                preferred = Some((language, quality));
+            }
+        }
+        preferred.map(|(language, _)| language)
+    }
+
+    /// Return the native name of the language.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Language::De => "Deutsch",
+            Language::En => "English",
+            Language::Es => "Español",
+            Language::Fr => "Français",
+            Language::Pt => "Português",
+        }
+    }
+
+    /// Translate a message, falling back to English when not translated.
+    pub(crate) fn tr<'a>(self, key: &'a str) -> &'a str {
+        CATALOGS
+            .get(&self)
+            .and_then(|catalog| catalog.get(key))
+            .map_or(key, String::as_str)
+    }
+
+    /// Translate a message and replace its `{name}` placeholders.
+    pub(crate) fn tr_args(self, key: &str, args: &[(&str, &str)]) -> String {
+        args.iter().fold(self.tr(key).to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{name}}}"), value)
+        })
+    }
+
+    /// Translate a message containing HTML markup, escaping the arguments.
+    pub(crate) fn tr_html(self, key: &str, args: &[(&str, &str)]) -> String {
+        let escaped_args: Vec<(&str, String)> =
+            args.iter().map(|(name, value)| (*name, escape_html(value))).collect();
+        let args: Vec<(&str, &str)> = escaped_args
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.tr_args(key, &args)
+    }
+}
+
+/// Return the date format of the language with the code provided.
+///
+/// Used by template macros, which receive the language as a code.
+pub(crate) fn datetime_format(code: &str) -> &'static str {
+    Language::from_str(code).unwrap_or_default().datetime_format()
+}
+
+/// Translate a message to the language with the code provided.
+///
+/// Used by template macros, which receive the language as a code.
+pub(crate) fn tr<'a>(code: &str, key: &'a str) -> &'a str {
+    Language::from_str(code).unwrap_or_default().tr(key)
+}
+
+/// Escape the characters with a special meaning in HTML.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#x27;")
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn catalogs_cover_the_same_messages() {
+        let keys: Vec<BTreeSet<&String>> =
+            CATALOGS.values().map(|catalog| catalog.keys().collect()).collect();
+
+        assert_eq!(keys.len(), 4);
+        assert!(keys.windows(2).all(|pair| pair[0] == pair[1]));
+    }
+
+    #[test]
+    fn catalogs_keep_message_placeholders() {
+        let placeholders = |text: &str| -> BTreeSet<String> {
+            text.split('{')
+                .skip(1)
+                .filter_map(|part| part.split_once('}').map(|(name, _)| name.to_string()))
+                .collect()
+        };
+
+        for catalog in CATALOGS.values() {
+            for (key, message) in catalog {
+                assert_eq!(placeholders(key), placeholders(message), "{key}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_accept_language_picks_highest_quality_supported_language() {
+        assert_eq!(
+            Language::from_accept_language("ja, fr-CA;q=0.8, es;q=0.9"),
+            Some(Language::Es)
+        );
+        assert_eq!(
+            Language::from_accept_language("pt-BR,pt;q=0.9"),
+            Some(Language::Pt)
+        );
+        assert_eq!(Language::from_accept_language("DE"), Some(Language::De));
+    }
+
+    #[test]
+    fn from_accept_language_ignores_unsupported_and_rejected_languages() {
+        assert_eq!(Language::from_accept_language("ja, zh;q=0.5"), None);
+        assert_eq!(Language::from_accept_language("fr;q=0, *"), None);
+        assert_eq!(Language::from_accept_language(""), None);
+    }
+
+    #[test]
+    fn tr_falls_back_to_english() {
+        assert_eq!(
+            Language::Es.tr("Untranslated message"),
+            "Untranslated message"
+        );
+        assert_eq!(Language::En.tr("View event"), "View event");
+        assert_eq!(Language::Es.tr("View event"), "Ver evento");
+    }
+
+    #[test]
+    fn tr_args_replaces_placeholders() {
+        assert_eq!(
+            Language::Fr.tr_args("Welcome to {group}", &[("group", "Rust Paris")]),
+            "Bienvenue dans Rust Paris"
+        );
+    }
+
+    #[test]
+    fn tr_html_escapes_arguments() {
+        assert_eq!(
+            Language::En.tr_html(
+                "Welcome to <strong>{group}</strong> on <strong>Open Community Groups</strong>!",
+                &[("group", "<Rust & Go>")]
+            ),
+            "Welcome to <strong>&lt;Rust &amp; Go&gt;</strong> on <strong>Open Community Groups</strong>!"
+        );
+    }
+
+    #[test]
+    fn tr_with_code_uses_english_for_unknown_codes() {
+        assert_eq!(tr("xx", "View event"), "View event");
+        assert_eq!(tr("de", "View event"), "Veranstaltung ansehen");
+        assert_eq!(datetime_format("xx"), Language::En.datetime_format());
+    }
+}
@@ -6,7 +6,7 @@
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::struct_field_names)]
 
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{collections::HashMap, net::SocketAddr, path::PathBuf, sync::Arc};
 
 use activity_tracker::ActivityTrackerDB;
 use anyhow::{Context, Result};
@@ -21,19 +21,30 @@ use tracing_subscriber::EnvFilter;
 
 use crate::{
     config::{
-        Config, HttpServerConfig, ImageStorageConfig, LogFormat, MeetingsConfig, PaymentsConfig,
+        Config, EmailFeedbackConfig, EmailProvider, GoogleCalendarConfig, HttpServerConfig,
+        ImageStorageConfig, LogFormat, MeetingsConfig, PaymentsConfig, SmsConfig,
     },
-    db::{DynDB, PgDB, pool as db_pool},
+    db::{DynDB, PgDB, listener::PgListener, pool as db_pool},
     services::{
+        google_calendar::GoogleCalendarManager,
         images::{DbImageStorage, DynImageStorage, S3ImageStorage},
         meetings::{
-            DynMeetingsProvider, MeetingProvider, MeetingsManager, zoom::ZoomMeetingsProvider,
+            DynMeetingsProvider, MeetingProvider, MeetingsManager,
+            bigbluebutton::BigBlueButtonMeetingsProvider, google_meet::GoogleMeetMeetingsProvider,
+            jitsi::JitsiMeetingsProvider, teams::TeamsMeetingsProvider,
+            webex::WebexMeetingsProvider, zoom::ZoomMeetingsProvider,
+        },
+        notifications::{
+            DynEmailSender, DynNotificationChannel, LettreEmailSender, NotificationChannels,
+            PgNotificationsManager, discord::DiscordNotificationChannel,
+            sendgrid::SendgridEmailSender, ses::SesEmailSender, slack::SlackNotificationChannel,
+            sms::TwilioNotificationChannel, webhook::HttpWebhookSender,
         },
-        notifications::{DynEmailSender, LettreEmailSender, PgNotificationsManager},
         payments::{
             DynPaymentsManager, DynPaymentsProvider, PgPaymentsManager, build_payments_provider,
             start_refund_workers,
         },
+        scheduled_publications::start_scheduled_publications_worker,
     },
 };
 
@@ -47,6 +58,8 @@ mod config;
 mod db;
 /// HTTP request handlers.
 mod handlers;
+/// Translation of notification content.
+mod i18n;
 /// HTTP router configuration and setup.
 mod router;
 /// Background services and workers.
@@ -107,9 +120,16 @@ async fn main() -> Result<()> {
     let image_storage = setup_image_storage(&cfg, db.clone());
 
     // Configure background services that depend on the database
-    start_meetings_workers(&cfg, db.clone(), &background_tasks);
+    start_google_calendar_workers(&cfg, db.clone(), &background_tasks)?;
+    start_meetings_workers(
+        &cfg,
+        db.clone(),
+        setup_db_listener(&cfg)?,
+        &background_tasks,
+    );
     let activity_tracker = setup_activity_tracker(db.clone(), &background_tasks);
-    let notifications_manager = setup_notifications_manager(&cfg, db.clone(), &background_tasks)?;
+    let notifications_manager =
+        setup_notifications_manager(&cfg, db.clone(), image_storage.clone(), &background_tasks)?;
     let payments_provider = build_payments_provider(cfg.payments.as_ref());
     let refund_workers_db = db.clone() as DynDB;
     start_refund_workers(
@@ -120,6 +140,13 @@ async fn main() -> Result<()> {
         &background_tasks.task_tracker,
         &background_tasks.cancellation_token,
     );
+    start_scheduled_publications_worker(
+        db.clone() as DynDB,
+        cfg.payments.as_ref().map(PaymentsConfig::provider),
+        cfg.server.clone(),
+        &background_tasks.task_tracker,
+        &background_tasks.cancellation_token,
+    );
     let payments_manager = setup_payments_manager(
         db.clone(),
         notifications_manager.clone(),
@@ -131,10 +158,13 @@ async fn main() -> Result<()> {
     run_server(
         activity_tracker,
         db,
+        cfg.email.feedback.clone(),
+        cfg.google_calendar.clone(),
         image_storage,
         cfg.meetings.clone(),
         cfg.payments.clone(),
         payments_manager,
+        cfg.sms.clone(),
         notifications_manager,
         &cfg.server,
     )
@@ -151,10 +181,13 @@ async fn main() -> Result<()> {
 async fn run_server(
     activity_tracker: Arc<ActivityTrackerDB>,
     db: Arc<PgDB>,
+    email_feedback_cfg: Option<EmailFeedbackConfig>,
+    google_calendar_cfg: Option<GoogleCalendarConfig>,
     image_storage: DynImageStorage,
     meetings_cfg: Option<MeetingsConfig>,
     payments_cfg: Option<PaymentsConfig>,
     payments_manager: DynPaymentsManager,
+    sms_cfg: Option<SmsConfig>,
     notifications_manager: Arc<PgNotificationsManager>,
     server_cfg: &HttpServerConfig,
 ) -> Result<()> {
@@ -162,10 +195,13 @@ async fn run_server(
     let router = router::setup(
         activity_tracker,
         db,
+        email_feedback_cfg,
+        google_calendar_cfg,
         image_storage,
         meetings_cfg,
         payments_cfg,
         payments_manager,
+        sms_cfg,
         notifications_manager,
         server_cfg,
     )
@@ -176,9 +212,12 @@ async fn run_server(
     info!("server started");
     info!(%server_cfg.addr, "listening");
 
-    if let Err(err) = axum::serve(listener, router)
-        .with_graceful_shutdown(shutdown_signal())
-        .await
+    if let Err(err) = axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await
     {
         error!(?err, "server error");
         return Err(err.into());
@@ -209,12 +248,8 @@ fn setup_config() -> Result<Config> {
 
 /// Configure the database pool.
 fn setup_db(cfg: &Config) -> Result<Arc<PgDB>> {
-    // Build the TLS connector used by the Postgres pool
-    let mut builder = SslConnector::builder(SslMethod::tls())?;
-    builder.set_verify(SslVerifyMode::NONE);
-
     // Create the Postgres connection pool and wrap it in our database abstraction
-    let connector = MakeTlsConnector::new(builder.build());
+    let connector = setup_db_tls_connector()?;
     let db_cfg = db_pool::config_with_defaults(&cfg.db);
     let pool = db_cfg.create_pool(Some(Runtime::Tokio1), connector)?;
     let db = Arc::new(PgDB::new(pool));
@@ -222,6 +257,19 @@ fn setup_db(cfg: &Config) -> Result<Arc<PgDB>> {
     Ok(db)
 }
 
+/// Configure the database listener used for LISTEN/NOTIFY.
+fn setup_db_listener(cfg: &Config) -> Result<PgListener> {
+    let connector = setup_db_tls_connector()?;
+    Ok(PgListener::new(cfg.db.clone(), connector))
+}
+
+/// Build the TLS connector used by database connections.
+fn setup_db_tls_connector() -> Result<MakeTlsConnector> {
+    let mut builder = SslConnector::builder(SslMethod::tls())?;
+    builder.set_verify(SslVerifyMode::NONE);
+    Ok(MakeTlsConnector::new(builder.build()))
+}
+
 /// Configure the image storage implementation.
 fn setup_image_storage(cfg: &Config, db: Arc<PgDB>) -> DynImageStorage {
     match &cfg.images {
@@ -252,16 +300,45 @@ fn setup_logging(log_format: &LogFormat) {
 fn setup_notifications_manager(
     cfg: &Config,
     db: Arc<PgDB>,
+    image_storage: DynImageStorage,
     background_tasks: &BackgroundTasks,
 ) -> Result<Arc<PgNotificationsManager>> {
-    // Create the sender first so the manager can share it with workers
-    let email_sender: DynEmailSender = Arc::new(LettreEmailSender::new(&cfg.email)?);
+    // Create the senders first so the manager can share them with workers
+    let email_sender: DynEmailSender = match cfg.email.provider {
+        EmailProvider::Sendgrid => {
+            let sendgrid_cfg =
+                cfg.email.sendgrid.clone().context("email sendgrid config missing")?;
+            Arc::new(SendgridEmailSender::new(sendgrid_cfg))
+        }
+        EmailProvider::Ses => {
+            let ses_cfg = cfg.email.ses.as_ref().context("email ses config missing")?;
+            Arc::new(SesEmailSender::new(ses_cfg))
+        }
+        EmailProvider::Smtp => {
+            let smtp_cfg = cfg.email.smtp.as_ref().context("email smtp config missing")?;
+            Arc::new(LettreEmailSender::new(smtp_cfg)?)
+        }
+    };
+    let channels = NotificationChannels {
+        discord: Arc::new(DiscordNotificationChannel::new()),
+        slack: Arc::new(SlackNotificationChannel::new()),
+        sms: cfg.sms.as_ref().map(|sms_cfg| match sms_cfg {
+            SmsConfig::Twilio(twilio_cfg) => {
+                Arc::new(TwilioNotificationChannel::new(twilio_cfg.clone()))
+                    as DynNotificationChannel
+            }
+        }),
+        webhook: Arc::new(HttpWebhookSender::new()),
+    };
 
     Ok(Arc::new(PgNotificationsManager::new(
         db,
         &cfg.email,
+        &cfg.notifications,
         &cfg.server.base_url,
         &email_sender,
+        &channels,
+        &image_storage,
         &background_tasks.task_tracker,
         &background_tasks.cancellation_token,
     )))
@@ -312,11 +389,86 @@ async fn shutdown_signal() {
     }
 }
 
+/// Start Google Calendar sync workers when the sync is configured.
+fn start_google_calendar_workers(
+    cfg: &Config,
+    db: Arc<PgDB>,
+    background_tasks: &BackgroundTasks,
+) -> Result<()> {
+    if let Some(ref google_calendar_cfg) = cfg.google_calendar {
+        GoogleCalendarManager::new(
+            google_calendar_cfg,
+            &cfg.server.base_url,
+            db,
+            setup_db_listener(cfg)?,
+            &background_tasks.task_tracker,
+            &background_tasks.cancellation_token,
+        );
+    }
+
+    Ok(())
+}
+
 /// Start meetings workers for the enabled providers.
-fn start_meetings_workers(cfg: &Config, db: Arc<PgDB>, background_tasks: &BackgroundTasks) {
+fn start_meetings_workers(
+    cfg: &Config,
+    db: Arc<PgDB>,
+    db_listener: PgListener,
+    background_tasks: &BackgroundTasks,
+) {
     // Collect the meetings providers enabled in the configuration
     let mut meetings_providers = HashMap::new();
 
+    if let Some(ref meetings_cfg) = cfg.meetings
+        && let Some(ref bigbluebutton_cfg) = meetings_cfg.bigbluebutton
+        && bigbluebutton_cfg.enabled
+    {
+        meetings_providers.insert(
+            MeetingProvider::BigBlueButton,
+            Arc::new(BigBlueButtonMeetingsProvider::new(bigbluebutton_cfg)) as DynMeetingsProvider,
+        );
+    }
+
+    if let Some(ref meetings_cfg) = cfg.meetings
+        && let Some(ref google_meet_cfg) = meetings_cfg.google_meet
+        && google_meet_cfg.enabled
+    {
+        meetings_providers.insert(
+            MeetingProvider::GoogleMeet,
+            Arc::new(GoogleMeetMeetingsProvider::new(google_meet_cfg)) as DynMeetingsProvider,
+        );
+    }
+
+    if let Some(ref meetings_cfg) = cfg.meetings
+        && let Some(ref jitsi_cfg) = meetings_cfg.jitsi
+        && jitsi_cfg.enabled
+    {
+        meetings_providers.insert(
+            MeetingProvider::Jitsi,
+            Arc::new(JitsiMeetingsProvider::new(jitsi_cfg)) as DynMeetingsProvider,
+        );
+    }
+
+    if let Some(ref meetings_cfg) = cfg.meetings
+        && let Some(ref teams_cfg) = meetings_cfg.teams
+        && teams_cfg.enabled
+    {
+        meetings_providers.insert(
+            MeetingProvider::Teams,
+            Arc::new(TeamsMeetingsProvider::new(teams_cfg)) as DynMeetingsProvider,
+        );
+    }
+
+    if let Some(ref meetings_cfg) = cfg.meetings
+        && let Some(ref webex_cfg) = meetings_cfg.webex
+        && webex_cfg.enabled
+    {
+        meetings_providers.insert(
+            MeetingProvider::Webex,
+            Arc::new(WebexMeetingsProvider::new(webex_cfg)) as DynMeetingsProvider,
+        );
+    }
+
     if let Some(ref meetings_cfg) = cfg.meetings
         && let Some(ref zoom_cfg) = meetings_cfg.zoom
         && zoom_cfg.enabled
@@ -332,6 +484,7 @@ fn start_meetings_workers(cfg: &Config, db: Arc<PgDB>, background_tasks: &Backgr
         MeetingsManager::new(
             Arc::new(meetings_providers),
             db,
+            db_listener,
             cfg.meetings
                 .as_ref()
                 .and_then(|meetings_cfg| meetings_cfg.zoom.clone()),
@@ -8,10 +8,12 @@ mod dashboard;
 #[cfg(test)]
 mod tests;
 
+use std::{collections::HashMap, sync::Arc};
+
 use anyhow::Result;
 use axum::{
     Router,
-    extract::{FromRef, Request, State as AxumState},
+    extract::{FromRef, Query, Request, State as AxumState},
     http::{
         HeaderName, HeaderValue, StatusCode, Uri,
         header::{CACHE_CONTROL, CONTENT_TYPE, HOST, VARY},
@@ -22,6 +24,7 @@ use axum::{
 };
 use axum_login::login_required;
 use axum_messages::MessagesManagerLayer;
+use reqwest::Url;
 use rust_embed::Embed;
 use tower::ServiceBuilder;
 use tower_http::{set_header::SetResponseHeaderLayer, trace::TraceLayer};
@@ -30,16 +33,20 @@ use tracing::instrument;
 use crate::{
     activity_tracker::DynActivityTracker,
     auth::AuthnBackend,
-    config::{HttpServerConfig, MeetingsConfig, PaymentsConfig},
+    config::{
+        EmailFeedbackConfig, GoogleCalendarConfig, HttpServerConfig, MeetingsConfig,
+        PaymentsConfig, SmsConfig,
+    },
     db::DynDB,
     handlers::{
         auth::{self, LOG_IN_URL},
-        community, event, group, images, meetings, payments, site,
+        community, email_feedback, event, group, images, meetings, payments, site,
     },
     services::{
-        images::DynImageStorage, notifications::DynNotificationsManager,
+        domains::CustomDomains, images::DynImageStorage, notifications::DynNotificationsManager,
         payments::DynPaymentsManager,
     },
+    util::base_url_without_trailing_slash,
 };
 
 /// Cache-Control header value for immutable public assets.
@@ -76,6 +83,37 @@ const CACHE_CONTROL_STATIC_DEVELOPMENT: &str = "max-age=0";
 #[cfg(any(not(debug_assertions), test))]
 const CACHE_CONTROL_STATIC_IMAGES: &str = "max-age=604800";
 
+/// First path segments of the requests served as they are on custom domains.
+const CUSTOM_DOMAIN_PASSTHROUGH_SEGMENTS: [&str; 10] = [
+    ".well-known",
+    "communities",
+    "custom-domains",
+    "events",
+    "favicon.ico",
+    "groups",
+    "health-check",
+    "images",
+    "section",
+    "static",
+];
+
+/// First path segments of the requests redirected from custom domains to the
+/// main site.
+const CUSTOM_DOMAIN_REDIRECT_SEGMENTS: [&str; 12] = [
+    "calendar",
+    "dashboard",
+    "docs",
+    "explore",
+    "forgot-password",
+    "log-in",
+    "log-out",
+    "reset-password",
+    "sign-up",
+    "stats",
+    "verify-email",
+    "webhooks",
+];
+
 /// Current application commit SHA embedded at build time.
 pub(crate) const COMMIT_SHA: &str = env!("OCG_COMMIT_SHA");
 
@@ -106,8 +144,14 @@ struct StaticFile;
 pub(crate) struct State {
     /// Activity tracker handle.
     pub activity_tracker: DynActivityTracker,
+    /// Communities served from custom domains.
+    pub custom_domains: Arc<CustomDomains>,
     /// Database handle.
     pub db: DynDB,
+    /// Email feedback configuration.
+    pub email_feedback_cfg: Option<EmailFeedbackConfig>,
+    /// Google Calendar sync configuration.
+    pub google_calendar_cfg: Option<GoogleCalendarConfig>,
     /// Image storage provider handle.
     pub image_storage: DynImageStorage,
     /// Meetings configuration.
@@ -122,6 +166,8 @@ pub(crate) struct State {
     pub serde_qs_de: serde_qs::Config,
     /// HTTP server configuration.
     pub server_cfg: HttpServerConfig,
+    /// SMS notifications configuration.
+    pub sms_cfg: Option<SmsConfig>,
 }
 
 /// Configures and returns the application router.
@@ -134,10 +180,13 @@ pub(crate) struct State {
 pub(crate) async fn setup(
     activity_tracker: DynActivityTracker,
     db: DynDB,
+    email_feedback_cfg: Option<EmailFeedbackConfig>,
+    google_calendar_cfg: Option<GoogleCalendarConfig>,
     image_storage: DynImageStorage,
     meetings_cfg: Option<MeetingsConfig>,
     payments_cfg: Option<PaymentsConfig>,
     payments_manager: DynPaymentsManager,
+    sms_cfg: Option<SmsConfig>,
     notifications_manager: DynNotificationsManager,
     server_cfg: &HttpServerConfig,
 ) -> Result<Router> {
@@ -150,10 +199,16 @@ pub(crate) async fn setup(
     // Check whether a payments provider is configured
     let payments_enabled = payments_cfg.is_some();
 
+    // Check whether the email feedback endpoint is configured
+    let email_feedback_enabled = email_feedback_cfg.is_some();
+
     // Setup router state
     let state = State {
         db: db.clone(),
         activity_tracker,
+        custom_domains: Arc::new(CustomDomains::default()),
+        email_feedback_cfg,
+        google_calendar_cfg,
         image_storage,
         meetings_cfg,
         notifications_manager,
@@ -161,6 +216,7 @@ pub(crate) async fn setup(
         payments_manager,
         serde_qs_de: serde_qs_config(),
         server_cfg: server_cfg.clone(),
+        sms_cfg,
     };
 
     // Setup authentication layer
@@ -199,10 +255,34 @@ pub(crate) async fn setup(
             "/{community}/event/{event_id}/refund-request",
             post(event::request_refund),
         )
+        .route(
+            "/{community}/event/{event_id}/reminder-preference",
+            put(event::update_reminder_preference),
+        )
         .route(
             "/{community}/event/{event_id}/cfs-submissions",
             post(event::submit_cfs_submission),
         )
+        .route(
+            "/{community}/event/{event_id}/comments",
+            post(event::add_comment),
+        )
+        .route(
+            "/{community}/event/{event_id}/photos",
+            post(event::submit_photo),
+        )
+        .route(
+            "/{community}/group/{group_id}/discussion/topics",
+            post(group::add_discussion_topic),
+        )
+        .route(
+            "/{community}/group/{group_id}/discussion/topics/{group_discussion_topic_id}/replies",
+            post(group::add_discussion_reply),
+        )
+        .route(
+            "/{community}/group/{group_id}/contact",
+            post(group::contact_organizers),
+        )
         .route(
             "/{community}/group/{group_id}/join",
             post(group::join_group),
@@ -215,7 +295,59 @@ pub(crate) async fn setup(
             "/{community}/group/{group_id}/membership",
             get(group::membership_status),
         )
+        .route(
+            "/{community}/newsletter/subscription",
+            put(community::subscribe_newsletter).delete(community::unsubscribe_newsletter),
+        )
         // Protected dashboard routes
+        .route(
+            "/dashboard/account/google-calendar",
+            delete(auth::disconnect_google_calendar),
+        )
+        .route(
+            "/dashboard/account/google-calendar/callback",
+            get(auth::google_calendar_callback),
+        )
+        .route(
+            "/dashboard/account/google-calendar/connect",
+            get(auth::connect_google_calendar),
+        )
+        .route(
+            "/dashboard/account/link/oauth2/{provider}",
+            get(auth::link_oauth2_redirect),
+        )
+        .route(
+            "/dashboard/account/link/oidc/{provider}",
+            get(auth::link_oidc_redirect),
+        )
+        .route(
+            "/dashboard/account/passkeys/register/finish",
+            post(auth::register_passkey_finish),
+        )
+        .route(
+            "/dashboard/account/passkeys/register/start",
+            post(auth::register_passkey_start),
+        )
+        .route(
+            "/dashboard/account/passkeys/{user_passkey_id}",
+            delete(auth::delete_user_passkey),
+        )
+        .route(
+            "/dashboard/account/providers/{provider}",
+            delete(auth::unlink_user_provider),
+        )
+        .route(
+            "/dashboard/account/sessions",
+            delete(auth::revoke_user_sessions),
+        )
+        .route(
+            "/dashboard/account/sessions/{session_id}",
+            delete(auth::revoke_user_session),
+        )
+        .route(
+            "/dashboard/account/update/calendar-feed",
+            put(auth::update_user_calendar_feed),
+        )
         .route(
             "/dashboard/account/update/details",
             put(auth::update_user_details),
@@ -224,6 +356,22 @@ pub(crate) async fn setup(
             "/dashboard/account/update/password",
             put(auth::update_user_password),
         )
+        .route(
+            "/dashboard/account/update/phone",
+            put(auth::update_user_phone_number),
+        )
+        .route(
+            "/dashboard/account/update/sms",
+            put(auth::update_user_sms_notifications),
+        )
+        .route(
+            "/dashboard/account/update/webhook",
+            put(auth::update_user_webhook),
+        )
+        .route(
+            "/dashboard/account/verify/phone",
+            put(auth::verify_user_phone_number),
+        )
         .nest("/dashboard/community", community_dashboard_router)
         .nest("/dashboard/group", group_dashboard_router)
         .nest("/dashboard/user", user_dashboard_router)
@@ -244,6 +392,11 @@ pub(crate) async fn setup(
             "/apple-touch-icon.png",
             get(|| async { StatusCode::NOT_FOUND }),
         )
+        .route(
+            "/calendar/{calendar_token}/events.ics",
+            get(event::user_calendar),
+        )
+        .route("/custom-domains/check", get(check_custom_domain))
         .route("/docs", get(site::docs::page))
         .route("/explore", get(site::explore::page))
         .route(
@@ -273,10 +426,35 @@ pub(crate) async fn setup(
         // Community-prefixed public routes
         .route("/{community}", get(community::page))
         .route("/{community}/group/{group_slug}", get(group::page))
+        .route(
+            "/{community}/group/{group_slug}/calendar.ics",
+            get(group::calendar),
+        )
+        .route(
+            "/{community}/group/{group_slug}/events.atom",
+            get(group::events_feed),
+        )
+        .route("/{community}/group/{group_id}/contact", get(group::contact))
+        .route(
+            "/{community}/group/{group_id}/discussion",
+            get(group::discussion),
+        )
         .route(
             "/{community}/event/{event_id}/cfs-modal",
             get(event::cfs_modal),
         )
+        .route(
+            "/{community}/event/{event_id}/discussion",
+            get(event::discussion),
+        )
+        .route(
+            "/{community}/event/{event_id}/photos-modal",
+            get(event::photos_modal),
+        )
+        .route(
+            "/{community}/event/{event_id}/resources/{event_resource_id}",
+            get(event::download_resource),
+        )
         .route(
             "/{community}/group/{group_slug}/event/{event_slug}/availability",
             get(event::availability),
@@ -285,6 +463,23 @@ pub(crate) async fn setup(
             "/{community}/group/{group_slug}/event/{event_slug}",
             get(event::page),
         )
+        .route(
+            "/{community}/group/{group_slug}/series/{event_series_id}",
+            get(event::series_page),
+        )
+        .route(
+            "/{community}/newsletter",
+            get(community::newsletter_archive_page),
+        )
+        .route(
+            "/{community}/newsletter/subscription",
+            get(community::newsletter_subscription_section),
+        )
+        .route(
+            "/{community}/newsletter/{community_newsletter_issue_id}",
+            get(community::newsletter_issue_page),
+        )
+        .route("/{community}/speaker/{username}", get(event::speaker_page))
         // Page view tracking routes
         .route(
             "/communities/{community_id}/views",
@@ -297,11 +492,23 @@ pub(crate) async fn setup(
     // Setup some routes based on the login options enabled
     if server_cfg.login.email {
         router = router
+            .route(
+                "/forgot-password",
+                get(auth::forgot_password_page).post(auth::forgot_password),
+            )
             .route("/log-in", post(auth::log_in))
+            .route(
+                "/reset-password/{code}",
+                get(auth::reset_password_page).post(auth::reset_password),
+            )
             .route("/sign-up", post(auth::sign_up))
+            .route(
+                "/verify-email/resend",
+                post(auth::resend_email_verification),
+            )
             .route("/verify-email/{code}", get(auth::verify_email));
     }
-    if server_cfg.login.github {
+    if server_cfg.login.github || server_cfg.login.gitlab || server_cfg.login.google {
         router = router
             .route("/log-in/oauth2/{provider}", get(auth::oauth2_redirect))
             .route(
@@ -309,11 +516,24 @@ pub(crate) async fn setup(
                 get(auth::oauth2_callback),
             );
     }
+    if server_cfg.login.ldap {
+        router = router.route("/log-in/ldap", post(auth::log_in_ldap));
+    }
     if server_cfg.login.linuxfoundation {
         router = router
             .route("/log-in/oidc/{provider}", get(auth::oidc_redirect))
             .route("/log-in/oidc/{provider}/callback", get(auth::oidc_callback));
     }
+    if server_cfg.login.magic_link {
+        router = router
+            .route("/log-in/magic-link", post(auth::request_magic_link))
+            .route("/log-in/magic-link/{token}", get(auth::log_in_magic_link));
+    }
+    if server_cfg.login.passkey {
+        router = router
+            .route("/log-in/passkey/finish", post(auth::log_in_passkey_finish))
+            .route("/log-in/passkey/start", post(auth::log_in_passkey_start));
+    }
 
     router = router
         .route("/log-out", get(auth::log_out))
@@ -330,8 +550,17 @@ pub(crate) async fn setup(
         router = router.route("/webhooks/payments", post(payments::webhook));
     }
 
+    // Setup the email feedback webhook route if enabled in configuration
+    if email_feedback_enabled {
+        router = router.route("/webhooks/email-feedback", post(email_feedback::feedback));
+    }
+
     router = router
         .layer(MessagesManagerLayer)
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::apply_session_lifetime,
+        ))
         .layer(auth_layer)
         .layer(ServiceBuilder::new().layer(TraceLayer::new_for_http()))
         .route("/static/{*file}", get(static_handler))
@@ -345,11 +574,47 @@ pub(crate) async fn setup(
         ))
         .layer(middleware::from_fn(refresh_stale_clients));
 
-    Ok(router.with_state(state))
+    // Serve the community sites from their custom domains. This middleware
+    // wraps the whole router as it must rewrite the requests before routing.
+    let router = router.with_state(state.clone());
+    let router = Router::new()
+        .fallback_service(router)
+        .layer(middleware::from_fn_with_state(state, serve_custom_domains));
+
+    Ok(router)
 }
 
 // Handlers.
 
+/// Checks whether the site can be served from the domain provided.
+///
+/// Intended to be used by the reverse proxy to decide whether a TLS
+/// certificate should be requested on demand for a domain. Returns 200 OK for
+/// the main site host and the verified custom domains, and 404 otherwise.
+#[instrument(skip_all)]
+async fn check_custom_domain(
+    AxumState(custom_domains): AxumState<Arc<CustomDomains>>,
+    AxumState(db): AxumState<DynDB>,
+    AxumState(server_cfg): AxumState<HttpServerConfig>,
+    Query(query): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let Some(domain) = query.get("domain").map(|domain| domain.trim().to_lowercase()) else {
+        return StatusCode::BAD_REQUEST;
+    };
+
+    // Allow the main site host
+    if base_host(&server_cfg.base_url).as_ref() == Some(&domain) {
+        return StatusCode::OK;
+    }
+
+    // Allow the verified custom domains
+    match custom_domains.resolve(&db, &domain).await {
+        Ok(Some(_)) => StatusCode::OK,
+        Ok(None) => StatusCode::NOT_FOUND,
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
 /// Redirects favicon requests to the configured site favicon URL.
 #[instrument(skip_all)]
 async fn favicon(AxumState(db): AxumState<DynDB>) -> impl IntoResponse {
@@ -458,6 +723,73 @@ async fn redirect_old_hosts(
     next.run(request).await.into_response()
 }
 
+/// Middleware that serves the community sites from their verified custom domains.
+///
+/// Requests received on a custom domain are rewritten to the community-prefixed
+/// path, so `/group/my-group` is handled as `/{community}/group/my-group`.
+/// Site-wide pages, like the dashboard or the log-in page, are redirected to the
+/// base URL. Requests to any other host are passed through untouched.
+async fn serve_custom_domains(
+    AxumState(custom_domains): AxumState<Arc<CustomDomains>>,
+    AxumState(db): AxumState<DynDB>,
+    AxumState(server_cfg): AxumState<HttpServerConfig>,
+    mut request: Request,
+    next: Next,
+) -> impl IntoResponse {
+    // Skip requests to the main site host
+    let host = request
+        .headers()
+        .get(HOST)
+        .and_then(|h| h.to_str().ok())
+        .map(|host| host.split(':').next().unwrap_or(host).to_lowercase());
+    let Some(host) = host.filter(|host| base_host(&server_cfg.base_url).as_ref() != Some(host))
+    else {
+        return next.run(request).await.into_response();
+    };
+
+    // Serve shared resources, like static assets or images, as they are
+    let path = request.uri().path().to_string();
+    let first_segment = path.trim_start_matches('/').split('/').next().unwrap_or_default();
+    if CUSTOM_DOMAIN_PASSTHROUGH_SEGMENTS.contains(&first_segment)
+        || first_segment.starts_with("apple-touch-icon")
+    {
+        return next.run(request).await.into_response();
+    }
+
+    // Resolve the community served from the custom domain (cached)
+    let community_name = match custom_domains.resolve(&db, &host).await {
+        Ok(Some(community_name)) => community_name,
+        Ok(None) => return next.run(request).await.into_response(),
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+
+    // Redirect site-wide pages to the main site
+    let query = request
+        .uri()
+        .query()
+        .map(|query| format!("?{query}"))
+        .unwrap_or_default();
+    if CUSTOM_DOMAIN_REDIRECT_SEGMENTS.contains(&first_segment) {
+        let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+        return Redirect::to(&format!("{base_url}{path}{query}")).into_response();
+    }
+
+    // Prefix the path with the community name when needed
+    if first_segment != community_name {
+        let path = if path == "/" {
+            String::new()
+        } else {
+            path.clone()
+        };
+        match Uri::try_from(format!("/{community_name}{path}{query}")) {
+            Ok(uri) => *request.uri_mut() = uri,
+            Err(_) => return StatusCode::BAD_REQUEST.into_response(),
+        }
+    }
+
+    next.run(request).await.into_response()
+}
+
 /// Middleware that refreshes dynamic clients loaded from an older application commit.
 async fn refresh_stale_clients(request: Request, next: Next) -> impl IntoResponse {
     let is_htmx = header_value_is_true(request.headers(), "hx-request");
@@ -509,6 +841,13 @@ fn stale_client_refresh_response(is_htmx: bool, is_ocg_fetch: bool) -> axum::res
 
 // Helpers.
 
+/// Returns the host of the base URL provided, in lowercase.
+fn base_host(base_url: &str) -> Option<String> {
+    Url::parse(base_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_lowercase))
+}
+
 /// Returns the `serde_qs` configuration for query string parsing.
 pub(crate) fn serde_qs_config() -> serde_qs::Config {
     serde_qs::Config::new().max_depth(6).use_form_encoding(true)
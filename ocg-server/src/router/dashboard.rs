@@ -4,12 +4,17 @@
 //! with their respective permission-based middleware layers.
 
 use axum::{
-    Router, middleware,
+    Router,
+    extract::DefaultBodyLimit,
+    middleware,
     routing::{delete, get, post, put},
 };
 
 use crate::{
-    handlers::{auth, dashboard, dashboard::common},
+    handlers::{
+        auth, dashboard,
+        dashboard::{common, group::resources::MAX_EVENT_RESOURCE_SIZE_BYTES},
+    },
     types::permissions::{CommunityPermission, GroupPermission},
 };
 
@@ -41,6 +46,7 @@ pub(super) fn setup_community_dashboard_router(state: &State) -> Router<State> {
     // Read-only community dashboard endpoints
     let dashboard_read = Router::new()
         .route("/analytics", get(dashboard::community::analytics::page))
+        .route("/domains", get(dashboard::community::domains::list_page))
         .route(
             "/event-categories",
             get(dashboard::community::event_categories::list_page),
@@ -53,6 +59,18 @@ pub(super) fn setup_community_dashboard_router(state: &State) -> Router<State> {
             "/event-categories/{event_category_id}/update",
             get(dashboard::community::event_categories::update_page),
         )
+        .route(
+            "/event-approvals",
+            get(dashboard::community::event_approvals::list_page),
+        )
+        .route(
+            "/failed-notifications",
+            get(dashboard::community::failed_notifications::list_page),
+        )
+        .route(
+            "/group-bans",
+            get(dashboard::community::group_bans::list_page),
+        )
         .route(
             "/group-categories",
             get(dashboard::community::group_categories::list_page),
@@ -67,11 +85,31 @@ pub(super) fn setup_community_dashboard_router(state: &State) -> Router<State> {
         )
         .route("/groups", get(dashboard::community::groups::list_page))
         .route("/groups/add", get(dashboard::community::groups::add_page))
+        .route(
+            "/groups/{group_id}/restore",
+            put(dashboard::community::groups::restore),
+        )
         .route(
             "/groups/{group_id}/update",
             get(dashboard::community::groups::update_page),
         )
         .route("/logs", get(dashboard::community::logs::list_page))
+        .route(
+            "/newsletter",
+            get(dashboard::community::newsletter::list_page),
+        )
+        .route(
+            "/newsletter/{community_newsletter_issue_id}/preview",
+            get(dashboard::community::newsletter::preview),
+        )
+        .route(
+            "/notifications",
+            get(dashboard::community::notifications::list_page),
+        )
+        .route(
+            "/notifications/preview/{kind}",
+            get(dashboard::community::notifications::preview),
+        )
         .route(
             "/settings/update",
             get(dashboard::community::settings::update_page),
@@ -83,17 +121,39 @@ pub(super) fn setup_community_dashboard_router(state: &State) -> Router<State> {
             "/regions/{region_id}/update",
             get(dashboard::community::regions::update_page),
         )
+        .route("/topics", get(dashboard::community::topics::list_page))
+        .route("/topics/add", get(dashboard::community::topics::add_page))
+        .route(
+            "/topics/{topic_id}/update",
+            get(dashboard::community::topics::update_page),
+        )
         .route_layer(check_selected_community_permission(
             CommunityPermission::Read,
         ));
 
     // Community groups management endpoints
     let groups_management = Router::new()
+        .route(
+            "/event-approvals/{event_id}/approve",
+            put(dashboard::community::event_approvals::approve),
+        )
+        .route(
+            "/event-approvals/{event_id}/reject",
+            put(dashboard::community::event_approvals::reject),
+        )
+        .route(
+            "/group-bans/{group_id}/{user_id}/unban",
+            put(dashboard::community::group_bans::unban),
+        )
         .route("/groups/add", post(dashboard::community::groups::add))
         .route(
             "/groups/{group_id}/activate",
             put(dashboard::community::groups::activate),
         )
+        .route(
+            "/groups/{group_id}/archive",
+            put(dashboard::community::groups::archive),
+        )
         .route(
             "/groups/{group_id}/deactivate",
             put(dashboard::community::groups::deactivate),
@@ -102,6 +162,14 @@ pub(super) fn setup_community_dashboard_router(state: &State) -> Router<State> {
             "/groups/{group_id}/delete",
             delete(dashboard::community::groups::delete),
         )
+        .route(
+            "/groups/{group_id}/merge",
+            put(dashboard::community::groups::merge),
+        )
+        .route(
+            "/groups/{group_id}/owner",
+            put(dashboard::community::groups::update_owner),
+        )
         .route(
             "/groups/{group_id}/update",
             put(dashboard::community::groups::update),
@@ -112,6 +180,31 @@ pub(super) fn setup_community_dashboard_router(state: &State) -> Router<State> {
 
     // Community settings management endpoints
     let settings_management = Router::new()
+        .route("/domains/add", post(dashboard::community::domains::add))
+        .route(
+            "/domains/{community_domain_id}/delete",
+            delete(dashboard::community::domains::delete),
+        )
+        .route(
+            "/domains/{community_domain_id}/verify",
+            put(dashboard::community::domains::verify),
+        )
+        .route(
+            "/failed-notifications/{notification_id}/requeue",
+            put(dashboard::community::failed_notifications::requeue),
+        )
+        .route(
+            "/newsletter/add",
+            post(dashboard::community::newsletter::add),
+        )
+        .route(
+            "/newsletter/{community_newsletter_issue_id}/delete",
+            delete(dashboard::community::newsletter::delete),
+        )
+        .route(
+            "/newsletter/{community_newsletter_issue_id}/send",
+            put(dashboard::community::newsletter::send),
+        )
         .route(
             "/settings/update",
             put(dashboard::community::settings::update),
@@ -155,6 +248,15 @@ pub(super) fn setup_community_dashboard_router(state: &State) -> Router<State> {
             "/regions/{region_id}/update",
             put(dashboard::community::regions::update),
         )
+        .route("/topics/add", post(dashboard::community::topics::add))
+        .route(
+            "/topics/{topic_id}/delete",
+            delete(dashboard::community::topics::delete),
+        )
+        .route(
+            "/topics/{topic_id}/update",
+            put(dashboard::community::topics::update),
+        )
         .route_layer(check_selected_community_permission(
             CommunityPermission::TaxonomyWrite,
         ));
@@ -210,6 +312,12 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
             auth::user_has_selected_group_permission,
         )
     };
+    let check_selected_group_event_permission = |permission| {
+        middleware::from_fn_with_state(
+            (state.db.clone(), permission),
+            auth::user_has_selected_group_event_permission,
+        )
+    };
 
     // Setup permission-bucket subrouters
 
@@ -218,11 +326,47 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
         .route("/", get(dashboard::group::home::page))
         .route("/analytics", get(dashboard::group::analytics::page))
         .route(
-            "/check-in/{event_id}/qr-code",
-            get(dashboard::group::attendees::generate_check_in_qr_code),
+            "/announcements",
+            get(dashboard::group::announcements::list_page),
+        )
+        .route(
+            "/discussions",
+            get(dashboard::group::discussions::list_page),
         )
         .route("/events", get(dashboard::group::events::list_page))
         .route("/events/add", get(dashboard::group::events::add_page))
+        .route(
+            "/events/templates/{event_template_id}/details",
+            get(dashboard::group::event_templates::details),
+        )
+        .route("/gallery", get(dashboard::group::gallery::list_page))
+        .route("/logs", get(dashboard::group::logs::list_page))
+        .route("/members", get(dashboard::group::members::list_page))
+        .route("/members.csv", get(dashboard::group::members::download_csv))
+        .route(
+            "/notifications",
+            get(dashboard::group::notifications::list_page),
+        )
+        .route("/refunds", get(dashboard::group::refunds::list_page))
+        .route(
+            "/settings/update",
+            get(dashboard::group::settings::update_page),
+        )
+        .route("/sponsors", get(dashboard::group::sponsors::list_page))
+        .route("/sponsors/add", get(dashboard::group::sponsors::add_page))
+        .route(
+            "/sponsors/{group_sponsor_id}/update",
+            get(dashboard::group::sponsors::update_page),
+        )
+        .route("/team", get(dashboard::group::team::list_page))
+        .route_layer(check_selected_group_permission(GroupPermission::Read));
+
+    // Read-only event endpoints, also available to the event hosts
+    let event_read = Router::new()
+        .route(
+            "/check-in/{event_id}/qr-code",
+            get(dashboard::group::attendees::generate_check_in_qr_code),
+        )
         .route(
             "/events/{event_id}/attendees",
             get(dashboard::group::attendees::list_page),
@@ -235,6 +379,14 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
             "/events/{event_id}/attendees-with-answers.csv",
             get(dashboard::group::attendees::download_csv_with_answers),
         )
+        .route(
+            "/events/{event_id}/badges.pdf",
+            get(dashboard::group::attendees::download_badges),
+        )
+        .route(
+            "/events/{event_id}/comments",
+            get(dashboard::group::comments::list_page),
+        )
         .route(
             "/events/{event_id}/invitation-requests",
             get(dashboard::group::invitation_requests::list_page),
@@ -243,6 +395,14 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
             "/events/{event_id}/details",
             get(dashboard::group::events::details),
         )
+        .route(
+            "/events/{event_id}/photos",
+            get(dashboard::group::photos::list_page),
+        )
+        .route(
+            "/events/{event_id}/resources",
+            get(dashboard::group::resources::list_page),
+        )
         .route(
             "/events/{event_id}/submissions",
             get(dashboard::group::submissions::list_page),
@@ -255,26 +415,32 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
             "/events/{event_id}/waitlist",
             get(dashboard::group::waitlist::list_page),
         )
-        .route("/logs", get(dashboard::group::logs::list_page))
-        .route("/members", get(dashboard::group::members::list_page))
-        .route("/refunds", get(dashboard::group::refunds::list_page))
+        .route_layer(check_selected_group_event_permission(GroupPermission::Read));
+
+    // Event check-in management endpoints, also available to the event hosts
+    let check_in_management = Router::new()
         .route(
-            "/settings/update",
-            get(dashboard::group::settings::update_page),
+            "/check-in/{event_id}/kiosk",
+            get(dashboard::group::kiosk::page),
         )
-        .route("/sponsors", get(dashboard::group::sponsors::list_page))
-        .route("/sponsors/add", get(dashboard::group::sponsors::add_page))
         .route(
-            "/sponsors/{group_sponsor_id}/update",
-            get(dashboard::group::sponsors::update_page),
+            "/check-in/{event_id}/kiosk/qr-code",
+            get(dashboard::group::kiosk::qr_code),
         )
-        .route("/team", get(dashboard::group::team::list_page))
-        .route_layer(check_selected_group_permission(GroupPermission::Read));
+        .route(
+            "/check-in/{event_id}/tickets/{ticket_code}",
+            post(dashboard::group::kiosk::check_in_ticket),
+        )
+        .route(
+            "/events/{event_id}/attendees/{user_id}/check-in",
+            post(dashboard::group::attendees::manual_check_in),
+        )
+        .route_layer(check_selected_group_event_permission(
+            GroupPermission::CheckInWrite,
+        ));
 
-    // Group events management endpoints
-    let events_management = Router::new()
-        .route("/events/add", post(dashboard::group::events::add))
-        .route("/events/preview", post(dashboard::group::events::preview))
+    // Event management endpoints, also available to the event hosts
+    let event_management = Router::new()
         .route(
             "/events/{event_id}/attendees/invite",
             post(dashboard::group::attendees::invite_event_attendee),
@@ -283,10 +449,6 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
             "/events/{event_id}/attendees/{user_id}/attendance",
             delete(dashboard::group::attendees::cancel_event_attendee_attendance),
         )
-        .route(
-            "/events/{event_id}/attendees/{user_id}/check-in",
-            post(dashboard::group::attendees::manual_check_in),
-        )
         .route(
             "/events/{event_id}/attendees/{user_id}/invitation/cancel",
             put(dashboard::group::attendees::cancel_event_attendee_invitation),
@@ -299,29 +461,87 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
             "/events/{event_id}/attendees/{user_id}/invitation-request/reject",
             put(dashboard::group::attendees::reject_invitation_request),
         )
+        .route(
+            "/events/{event_id}/update",
+            put(dashboard::group::events::update),
+        )
+        .route(
+            "/events/{event_id}/waitlist/{user_id}",
+            delete(dashboard::group::waitlist::remove),
+        )
+        .route_layer(check_selected_group_event_permission(
+            GroupPermission::EventsWrite,
+        ));
+
+    // Group events management endpoints
+    let events_management = Router::new()
+        .route("/events/add", post(dashboard::group::events::add))
+        .route("/events/preview", post(dashboard::group::events::preview))
+        .route(
+            "/events/templates/{event_template_id}",
+            delete(dashboard::group::event_templates::delete),
+        )
         .route(
             "/events/{event_id}/cancel",
             put(dashboard::group::events::cancel),
         )
+        .route(
+            "/events/{event_id}/comments/{event_comment_id}",
+            delete(dashboard::group::comments::delete),
+        )
+        .route(
+            "/events/{event_id}/comments/{event_comment_id}/pin",
+            put(dashboard::group::comments::pin),
+        )
+        .route(
+            "/events/{event_id}/comments/{event_comment_id}/unpin",
+            put(dashboard::group::comments::unpin),
+        )
         .route(
             "/events/{event_id}/delete",
             delete(dashboard::group::events::delete),
         )
+        .route(
+            "/events/{event_id}/discussion/lock",
+            put(dashboard::group::comments::lock),
+        )
+        .route(
+            "/events/{event_id}/discussion/unlock",
+            put(dashboard::group::comments::unlock),
+        )
+        .route(
+            "/events/{event_id}/photos/{event_photo_id}/approve",
+            put(dashboard::group::photos::approve),
+        )
+        .route(
+            "/events/{event_id}/photos/{event_photo_id}/reject",
+            put(dashboard::group::photos::reject),
+        )
         .route(
             "/events/{event_id}/publish",
             put(dashboard::group::events::publish),
         )
+        .route(
+            "/events/{event_id}/resources",
+            post(dashboard::group::resources::upload).layer(DefaultBodyLimit::max(
+                MAX_EVENT_RESOURCE_SIZE_BYTES + 1024 * 1024,
+            )),
+        )
+        .route(
+            "/events/{event_id}/resources/{event_resource_id}",
+            delete(dashboard::group::resources::delete),
+        )
         .route(
             "/events/{event_id}/submissions/{cfs_submission_id}",
             put(dashboard::group::submissions::update),
         )
         .route(
-            "/events/{event_id}/unpublish",
-            put(dashboard::group::events::unpublish),
+            "/events/{event_id}/template",
+            post(dashboard::group::event_templates::add),
         )
         .route(
-            "/events/{event_id}/update",
-            put(dashboard::group::events::update),
+            "/events/{event_id}/unpublish",
+            put(dashboard::group::events::unpublish),
         )
         .route(
             "/notifications/{event_id}",
@@ -350,6 +570,63 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
 
     // Group member management endpoints
     let members_management = Router::new()
+        .route("/announcements", post(dashboard::group::announcements::add))
+        .route(
+            "/announcements/{group_announcement_id}",
+            delete(dashboard::group::announcements::delete),
+        )
+        .route(
+            "/announcements/{group_announcement_id}/pin",
+            put(dashboard::group::announcements::pin),
+        )
+        .route(
+            "/announcements/{group_announcement_id}/unpin",
+            put(dashboard::group::announcements::unpin),
+        )
+        .route(
+            "/discussions/replies/{group_discussion_reply_id}",
+            delete(dashboard::group::discussions::delete_reply),
+        )
+        .route(
+            "/discussions/topics/{group_discussion_topic_id}",
+            delete(dashboard::group::discussions::delete_topic),
+        )
+        .route(
+            "/discussions/topics/{group_discussion_topic_id}/lock",
+            put(dashboard::group::discussions::lock),
+        )
+        .route(
+            "/discussions/topics/{group_discussion_topic_id}/pin",
+            put(dashboard::group::discussions::pin),
+        )
+        .route(
+            "/discussions/topics/{group_discussion_topic_id}/unlock",
+            put(dashboard::group::discussions::unlock),
+        )
+        .route(
+            "/discussions/topics/{group_discussion_topic_id}/unpin",
+            put(dashboard::group::discussions::unpin),
+        )
+        .route(
+            "/members/{user_id}/ban",
+            put(dashboard::group::members::ban_member),
+        )
+        .route(
+            "/members/{user_id}/join-request/accept",
+            put(dashboard::group::members::accept_join_request),
+        )
+        .route(
+            "/members/{user_id}/join-request/reject",
+            put(dashboard::group::members::reject_join_request),
+        )
+        .route(
+            "/members/{user_id}/role",
+            put(dashboard::group::members::update_member_role),
+        )
+        .route(
+            "/members/{user_id}/unban",
+            put(dashboard::group::members::unban_member),
+        )
         .route(
             "/notifications",
             post(dashboard::group::members::send_group_custom_notification),
@@ -360,7 +637,51 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
 
     // Group settings management endpoints
     let settings_management = Router::new()
+        .route(
+            "/gallery/cover",
+            put(dashboard::group::gallery::update_cover),
+        )
+        .route(
+            "/gallery/photos",
+            put(dashboard::group::gallery::update_photos),
+        )
+        .route(
+            "/gallery/photos/hide",
+            put(dashboard::group::gallery::hide_photo),
+        )
+        .route(
+            "/gallery/photos/show",
+            put(dashboard::group::gallery::show_photo),
+        )
+        .route(
+            "/settings/discord/update",
+            put(dashboard::group::settings::update_discord_webhook),
+        )
+        .route(
+            "/settings/join-questions/update",
+            put(dashboard::group::settings::update_join_questions),
+        )
+        .route(
+            "/settings/ownership/cancel",
+            put(dashboard::group::settings::cancel_ownership_transfer),
+        )
+        .route(
+            "/settings/ownership/transfer",
+            put(dashboard::group::settings::request_ownership_transfer),
+        )
         .route("/settings/update", put(dashboard::group::settings::update))
+        .route(
+            "/settings/welcome-message/update",
+            put(dashboard::group::settings::update_welcome_message),
+        )
+        .route(
+            "/settings/zoom/delete",
+            delete(dashboard::group::settings::delete_zoom_credentials),
+        )
+        .route(
+            "/settings/zoom/update",
+            put(dashboard::group::settings::update_zoom_credentials),
+        )
         .route_layer(check_selected_group_permission(
             GroupPermission::SettingsWrite,
         ));
@@ -380,6 +701,18 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
             "/sponsors/{group_sponsor_id}/update",
             put(dashboard::group::sponsors::update),
         )
+        .route(
+            "/sponsors/tiers/add",
+            post(dashboard::group::sponsors::add_tier),
+        )
+        .route(
+            "/sponsors/tiers/{group_sponsor_tier_id}/delete",
+            delete(dashboard::group::sponsors::delete_tier),
+        )
+        .route(
+            "/sponsors/tiers/{group_sponsor_tier_id}/update",
+            put(dashboard::group::sponsors::update_tier),
+        )
         .route_layer(check_selected_group_permission(
             GroupPermission::SponsorsWrite,
         ));
@@ -400,11 +733,18 @@ pub(super) fn setup_group_dashboard_router(state: &State) -> Router<State> {
     // Setup router
     Router::new()
         .merge(dashboard_read)
+        .merge(event_read)
+        .merge(check_in_management)
+        .merge(event_management)
         .merge(events_management)
         .merge(members_management)
         .merge(settings_management)
         .merge(sponsors_management)
         .merge(team_management)
+        .route(
+            "/events/{event_id}/host",
+            get(dashboard::group::events::host_page),
+        )
         .route(
             "/{group_id}/select",
             put(dashboard::group::select_group)
@@ -421,6 +761,14 @@ pub(super) fn setup_user_dashboard_router() -> Router<State> {
     // Setup router
     Router::new()
         .route("/", get(dashboard::user::home::page))
+        .route(
+            "/certificates",
+            get(dashboard::user::certificates::list_page),
+        )
+        .route(
+            "/certificates/{event_id}",
+            get(dashboard::user::certificates::download),
+        )
         .route("/events", get(dashboard::user::events::list_page))
         .route(
             "/events/{community_name}/{event_id}/attendance",
@@ -447,6 +795,14 @@ pub(super) fn setup_user_dashboard_router() -> Router<State> {
             "/invitations/event/{event_id}/reject",
             put(dashboard::user::invitations::reject_event_attendee_invitation),
         )
+        .route(
+            "/invitations/group/{group_id}/ownership/accept",
+            put(dashboard::user::invitations::accept_group_ownership_transfer),
+        )
+        .route(
+            "/invitations/group/{group_id}/ownership/reject",
+            put(dashboard::user::invitations::reject_group_ownership_transfer),
+        )
         .route(
             "/invitations/group/{group_id}/accept",
             put(dashboard::user::invitations::accept_group_team_invitation),
@@ -455,6 +811,10 @@ pub(super) fn setup_user_dashboard_router() -> Router<State> {
             "/invitations/group/{group_id}/reject",
             put(dashboard::user::invitations::reject_group_team_invitation),
         )
+        .route(
+            "/invitations/group-team/{token}",
+            get(dashboard::user::invitations::accept_group_team_invitation_link),
+        )
         .route("/logs", get(dashboard::user::logs::list_page))
         .route(
             "/session-proposals",
@@ -11,6 +11,75 @@ use crate::{
 
 use super::*;
 
+#[tokio::test]
+async fn test_check_custom_domain_allows_base_host() {
+    // Setup router and send request
+    let router = TestRouterBuilder::new(MockDB::new(), MockNotificationsManager::new())
+        .with_server_cfg(HttpServerConfig {
+            base_url: "https://example.test".to_string(),
+            ..Default::default()
+        })
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/custom-domains/check?domain=example.test")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_check_custom_domain_allows_verified_domain() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_name_by_domain()
+        .times(1)
+        .withf(|domain| domain == "events.example.org")
+        .returning(|_| Ok(Some("test-community".to_string())));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/custom-domains/check?domain=Events.Example.org")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_check_custom_domain_rejects_unknown_domain() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_name_by_domain()
+        .times(1)
+        .withf(|domain| domain == "unknown.example.org")
+        .returning(|_| Ok(None));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/custom-domains/check?domain=unknown.example.org")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn test_current_commit_htmx_request_runs_handler() {
     // Setup router with commit SHA middleware
@@ -302,6 +371,129 @@ async fn test_redirect_old_hosts_redirects_matching_host() {
     );
 }
 
+#[tokio::test]
+async fn test_serve_custom_domains_passes_through_unknown_hosts() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_name_by_domain()
+        .times(1)
+        .withf(|domain| domain == "unknown.example.org")
+        .returning(|_| Ok(None));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .with_server_cfg(HttpServerConfig {
+            base_url: "https://example.test".to_string(),
+            ..Default::default()
+        })
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/log-in")
+        .header(HOST, "unknown.example.org")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_serve_custom_domains_redirects_site_pages() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_name_by_domain()
+        .times(1)
+        .withf(|domain| domain == "events.example.org")
+        .returning(|_| Ok(Some("test-community".to_string())));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .with_server_cfg(HttpServerConfig {
+            base_url: "https://example.test".to_string(),
+            ..Default::default()
+        })
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/dashboard/community?tab=domains")
+        .header(HOST, "events.example.org:443")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap(),
+        &HeaderValue::from_static("https://example.test/dashboard/community?tab=domains")
+    );
+}
+
+#[tokio::test]
+async fn test_serve_custom_domains_rewrites_community_paths() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_name_by_domain()
+        .times(1)
+        .withf(|domain| domain == "events.example.org")
+        .returning(|_| Ok(Some("test-community".to_string())));
+    db.expect_get_community_id_by_name()
+        .times(1)
+        .withf(|name| name == "test-community")
+        .returning(|_| Ok(None));
+    db.expect_get_site_settings()
+        .times(1)
+        .returning(|| Ok(sample_site_settings()));
+
+    // Setup router and send request
+    let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+        .with_server_cfg(HttpServerConfig {
+            base_url: "https://example.test".to_string(),
+            ..Default::default()
+        })
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/")
+        .header(HOST, "events.example.org")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_serve_custom_domains_serves_static_assets_as_they_are() {
+    // Setup router and send request
+    let router = TestRouterBuilder::new(MockDB::new(), MockNotificationsManager::new())
+        .with_server_cfg(HttpServerConfig {
+            base_url: "https://example.test".to_string(),
+            ..Default::default()
+        })
+        .build()
+        .await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/static/missing.js")
+        .header(HOST, "events.example.org")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+
+    // Check response matches expectations
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn test_stale_hx_request_refreshes_without_running_handler() {
     // Setup router with commit SHA middleware
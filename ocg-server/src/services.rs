@@ -1,5 +1,20 @@
 //! Services modules.
 
+/// Attendee badges service module.
+pub(crate) mod badges;
+
+/// CAPTCHA service module.
+pub(crate) mod captcha;
+
+/// Attendance certificates service module.
+pub(crate) mod certificates;
+
+/// Custom domains service module.
+pub(crate) mod domains;
+
+/// Google Calendar service module.
+pub(crate) mod google_calendar;
+
 /// Images service module.
 pub(crate) mod images;
 
@@ -11,3 +26,9 @@ pub(crate) mod notifications;
 
 /// Payments service module.
 pub(crate) mod payments;
+
+/// PDF documents service module.
+pub(crate) mod pdf;
+
+/// Scheduled event publications service module.
+pub(crate) mod scheduled_publications;
@@ -0,0 +1,236 @@
+//! Printable badges for the attendees of events.
+//!
+//! Badges are rendered as PDF documents laid out for 2 x 4 name badge label
+//! sheets on US Letter paper (i.e. Avery 5395 and compatible ones).
+
+use std::fmt::Write as _;
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::pdf::{Color, Font, PdfWriter, fit_text};
+
+#[cfg(test)]
+mod tests;
+
+/// Number of badge columns on each sheet.
+const COLUMNS: usize = 2;
+
+/// Horizontal distance between the left edges of two adjacent labels, in points.
+const HORIZONTAL_PITCH: f64 = 270.0;
+
+/// Height of each label, in points.
+const LABEL_HEIGHT: f64 = 168.0;
+
+/// Padding between the label edges and its content, in points.
+const LABEL_PADDING: f64 = 12.0;
+
+/// Width of each label, in points.
+const LABEL_WIDTH: f64 = 243.0;
+
+/// Distance between the left edge of the sheet and the first column, in points.
+const LEFT_MARGIN: f64 = 49.5;
+
+/// Minimum font size text lines are shrunk to when they are too long.
+const MIN_FONT_SIZE: f64 = 7.0;
+
+/// Height of the sheet, in points (US Letter).
+const PAGE_HEIGHT: f64 = 792.0;
+
+/// Width of the sheet, in points (US Letter).
+const PAGE_WIDTH: f64 = 612.0;
+
+/// Size of the ticket QR code, in points.
+const QR_CODE_SIZE: f64 = 80.0;
+
+/// Number of badge rows on each sheet.
+const ROWS: usize = 4;
+
+/// Color used for the secondary text lines.
+const SECONDARY_TEXT_COLOR: Color = Color(0.34, 0.33, 0.31);
+
+/// Color used for the main text lines.
+const TEXT_COLOR: Color = Color(0.11, 0.1, 0.09);
+
+/// Distance between the top edge of the sheet and the first row, in points.
+const TOP_MARGIN: f64 = 42.0;
+
+/// Vertical distance between the top edges of two adjacent labels, in points.
+const VERTICAL_PITCH: f64 = 180.0;
+
+/// Renders the badges of the attendees provided as a PDF document.
+///
+/// A single blank sheet is rendered when there are no badges to print.
+pub(crate) fn render_attendee_badges_pdf(
+    event_name: &str,
+    badges: &[AttendeeBadge],
+) -> Result<Vec<u8>> {
+    let sheets: Vec<&[AttendeeBadge]> = if badges.is_empty() {
+        vec![&[]]
+    } else {
+        badges.chunks(COLUMNS * ROWS).collect()
+    };
+
+    // Write the document objects, adding a page and its content per sheet
+    let mut pdf = PdfWriter::new();
+    let kids = (0..sheets.len())
+        .map(|index| format!("{} 0 R", 5 + index * 2))
+        .collect::<Vec<_>>()
+        .join(" ");
+    pdf.add_object("<< /Type /Catalog /Pages 2 0 R >>", None);
+    pdf.add_object(
+        &format!("<< /Type /Pages /Kids [{kids}] /Count {} >>", sheets.len()),
+        None,
+    );
+    pdf.add_object(&Font::Regular.dictionary(), None);
+    pdf.add_object(&Font::Bold.dictionary(), None);
+    for (index, sheet) in sheets.into_iter().enumerate() {
+        pdf.add_object(
+            &format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                6 + index * 2
+            ),
+            None,
+        );
+        let content = build_sheet_content(event_name, sheet)?;
+        pdf.add_object(
+            &format!("<< /Length {} >>", content.len()),
+            Some(content.as_bytes()),
+        );
+    }
+
+    Ok(pdf.finish())
+}
+
+/// Builds the content stream that draws a sheet of badges.
+#[allow(clippy::cast_precision_loss)]
+fn build_sheet_content(event_name: &str, badges: &[AttendeeBadge]) -> Result<String> {
+    let mut content = String::new();
+    for (index, badge) in badges.iter().enumerate() {
+        let column = index % COLUMNS;
+        let row = index / COLUMNS;
+        let (x, y) = (
+            LEFT_MARGIN + column as f64 * HORIZONTAL_PITCH,
+            PAGE_HEIGHT - TOP_MARGIN - LABEL_HEIGHT - row as f64 * VERTICAL_PITCH,
+        );
+        write_badge(&mut content, event_name, badge, x, y)?;
+    }
+
+    Ok(content)
+}
+
+/// Writes the operators that draw a badge with its bottom left corner at the
+/// position provided.
+fn write_badge(
+    content: &mut String,
+    event_name: &str,
+    badge: &AttendeeBadge,
+    x: f64,
+    y: f64,
+) -> Result<()> {
+    let text_x = x + LABEL_PADDING;
+    let max_width = LABEL_WIDTH - 2.0 * LABEL_PADDING;
+    let max_ticket_title_width = max_width - QR_CODE_SIZE - LABEL_PADDING;
+
+    // Text lines
+    let mut lines = vec![
+        (
+            Font::Regular,
+            8.0,
+            y + LABEL_HEIGHT - 22.0,
+            max_width,
+            SECONDARY_TEXT_COLOR,
+            event_name,
+        ),
+        (
+            Font::Bold,
+            20.0,
+            y + LABEL_HEIGHT - 48.0,
+            max_width,
+            TEXT_COLOR,
+            badge.name.as_str(),
+        ),
+    ];
+    if let Some(company) = &badge.company {
+        lines.push((
+            Font::Regular,
+            12.0,
+            y + LABEL_HEIGHT - 66.0,
+            max_width,
+            SECONDARY_TEXT_COLOR,
+            company.as_str(),
+        ));
+    }
+    if let Some(ticket_title) = &badge.ticket_title {
+        lines.push((
+            Font::Bold,
+            10.0,
+            y + LABEL_PADDING + 4.0,
+            max_ticket_title_width,
+            TEXT_COLOR,
+            ticket_title.as_str(),
+        ));
+    }
+    for (font, size, text_y, max_width, color, text) in lines {
+        let (text, size) = fit_text(text, font, size, MIN_FONT_SIZE, max_width);
+        let _ = writeln!(
+            content,
+            "BT /{} {size:.2} Tf {color} rg {text_x:.2} {text_y:.2} Td <{}> Tj ET",
+            font.resource_name(),
+            hex::encode_upper(&text)
+        );
+    }
+
+    // Ticket QR code
+    write_qr_code(
+        content,
+        badge.ticket_code,
+        x + LABEL_WIDTH - LABEL_PADDING - QR_CODE_SIZE,
+        y + LABEL_PADDING,
+    )
+}
+
+/// Writes the operators that draw the ticket QR code at the position provided.
+#[allow(clippy::cast_precision_loss)]
+fn write_qr_code(content: &mut String, ticket_code: Uuid, x: f64, y: f64) -> Result<()> {
+    let code = qrcode::QrCode::new(ticket_code.to_string().as_bytes())
+        .map_err(|e| anyhow!("Failed to generate QR code: {e}"))?;
+    let width = code.width();
+    let module_size = QR_CODE_SIZE / width as f64;
+
+    // Draw the dark modules, starting from the top left corner of the code
+    let _ = writeln!(content, "0 0 0 rg");
+    for (index, color) in code.to_colors().into_iter().enumerate() {
+        if color != qrcode::Color::Dark {
+            continue;
+        }
+        let (column, row) = ((index % width) as f64, (index / width) as f64);
+        let _ = writeln!(
+            content,
+            "{:.2} {:.2} {module_size:.2} {module_size:.2} re",
+            x + column * module_size,
+            y + QR_CODE_SIZE - (row + 1.0) * module_size
+        );
+    }
+    let _ = writeln!(content, "f");
+
+    Ok(())
+}
+
+// Types.
+
+/// Details printed on an attendee badge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AttendeeBadge {
+    /// Name of the attendee, or their username when it is not set.
+    pub name: String,
+    /// Code of the attendee ticket, encoded in the QR code.
+    pub ticket_code: Uuid,
+
+    /// Company the attendee works for.
+    pub company: Option<String>,
+    /// Title of the ticket type purchased by the attendee.
+    pub ticket_title: Option<String>,
+}
@@ -0,0 +1,84 @@
+use super::*;
+
+#[test]
+fn test_render_attendee_badges_pdf() {
+    let pdf = render_attendee_badges_pdf("Rust Meetup", &[sample_badge()]).unwrap();
+
+    // Check document structure
+    assert!(pdf.starts_with(b"%PDF-1.4"));
+    assert!(pdf.ends_with(b"%%EOF\n"));
+    assert!(contains(&pdf, b"/Kids [5 0 R] /Count 1"));
+    assert!(contains(&pdf, b"/MediaBox [0 0 612 792]"));
+    assert!(contains(&pdf, b"xref\n0 7\n"));
+
+    // Check the badge text and QR code are included
+    for text in ["Rust Meetup", "Jane Doe", "Acme", "General admission"] {
+        let text = hex::encode_upper(text);
+        assert!(contains(&pdf, format!("<{text}> Tj").as_bytes()));
+    }
+    assert!(contains(&pdf, b" re\n"));
+}
+
+#[test]
+fn test_render_attendee_badges_pdf_multiple_sheets() {
+    let badges = vec![sample_badge(); 9];
+    let pdf = render_attendee_badges_pdf("Rust Meetup", &badges).unwrap();
+
+    assert!(contains(&pdf, b"/Kids [5 0 R 7 0 R] /Count 2"));
+    assert!(contains(&pdf, b"xref\n0 9\n"));
+}
+
+#[test]
+fn test_render_attendee_badges_pdf_no_badges() {
+    let pdf = render_attendee_badges_pdf("Rust Meetup", &[]).unwrap();
+
+    assert!(contains(&pdf, b"/Kids [5 0 R] /Count 1"));
+    assert!(contains(&pdf, b"<< /Length 0 >>"));
+}
+
+#[test]
+fn test_render_attendee_badges_pdf_optional_fields_missing() {
+    let badge = AttendeeBadge {
+        company: None,
+        ticket_title: None,
+        ..sample_badge()
+    };
+    let pdf = render_attendee_badges_pdf("Rust Meetup", &[badge]).unwrap();
+
+    let company = hex::encode_upper("Acme");
+    assert!(!contains(&pdf, format!("<{company}> Tj").as_bytes()));
+    let ticket_title = hex::encode_upper("General admission");
+    assert!(!contains(&pdf, format!("<{ticket_title}> Tj").as_bytes()));
+}
+
+#[test]
+fn test_render_attendee_badges_pdf_xref_offsets() {
+    let badges = vec![sample_badge(); 9];
+    let pdf = render_attendee_badges_pdf("Rust Meetup", &badges).unwrap();
+
+    // Check every object offset in the cross-reference table points to it
+    let xref_start = pdf.windows(5).rposition(|window| window == b"xref\n").unwrap();
+    let xref = String::from_utf8(pdf[xref_start..].to_vec()).unwrap();
+    for (index, line) in xref.lines().skip(3).take(8).enumerate() {
+        let offset: usize = line[..10].parse().unwrap();
+        assert!(pdf[offset..].starts_with(format!("{} 0 obj", index + 1).as_bytes()));
+    }
+}
+
+// Helpers.
+
+/// Returns whether the haystack contains the needle provided.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+/// Sample badge used in tests.
+fn sample_badge() -> AttendeeBadge {
+    AttendeeBadge {
+        name: "Jane Doe".to_string(),
+        ticket_code: Uuid::new_v4(),
+
+        company: Some("Acme".to_string()),
+        ticket_title: Some("General admission".to_string()),
+    }
+}
@@ -0,0 +1,106 @@
+//! CAPTCHA verification used to protect forms from automated submissions.
+
+use std::{net::IpAddr, sync::LazyLock, time::Duration};
+
+use anyhow::Result;
+use reqwest::{Client as HttpClient, header::CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{CaptchaConfig, CaptchaProvider};
+
+#[cfg(test)]
+mod tests;
+
+/// Timeout for HTTP requests to the CAPTCHA provider.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HTTP client used to verify the challenge responses.
+static HTTP_CLIENT: LazyLock<HttpClient> = LazyLock::new(|| {
+    HttpClient::builder()
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .expect("failed to build http client")
+});
+
+impl CaptchaProvider {
+    /// Name of the form field the provider widget submits the response in.
+    pub(crate) fn response_field(self) -> &'static str {
+        match self {
+            CaptchaProvider::HCaptcha => "h-captcha-response",
+            CaptchaProvider::Turnstile => "cf-turnstile-response",
+        }
+    }
+
+    /// URL of the script that renders the provider widget.
+    pub(crate) fn script_url(self) -> &'static str {
+        match self {
+            CaptchaProvider::HCaptcha => "https://js.hcaptcha.com/1/api.js",
+            CaptchaProvider::Turnstile => "https://challenges.cloudflare.com/turnstile/v0/api.js",
+        }
+    }
+
+    /// URL of the endpoint used to verify the challenge responses.
+    fn verify_url(self) -> &'static str {
+        match self {
+            CaptchaProvider::HCaptcha => "https://api.hcaptcha.com/siteverify",
+            CaptchaProvider::Turnstile => {
+                "https://challenges.cloudflare.com/turnstile/v0/siteverify"
+            }
+        }
+    }
+
+    /// CSS class the provider script looks for to render the widget.
+    pub(crate) fn widget_class(self) -> &'static str {
+        match self {
+            CaptchaProvider::HCaptcha => "h-captcha",
+            CaptchaProvider::Turnstile => "cf-turnstile",
+        }
+    }
+}
+
+/// Verifies a challenge response submitted with a form, returning whether the
+/// provider accepted it.
+pub(crate) async fn verify(
+    cfg: &CaptchaConfig,
+    response: &str,
+    remote_ip: Option<IpAddr>,
+) -> Result<bool> {
+    // Responses left empty by the widget are never valid
+    if response.trim().is_empty() {
+        return Ok(false);
+    }
+
+    // Ask the provider to verify the response
+    let body = serde_urlencoded::to_string(VerifyRequest {
+        response,
+        secret: &cfg.secret_key,
+        remoteip: remote_ip.map(|ip| ip.to_string()),
+    })?;
+    let verification: VerifyResponse = HTTP_CLIENT
+        .post(cfg.provider.verify_url())
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(verification.success)
+}
+
+/// Request sent to the provider to verify a challenge response.
+#[derive(Serialize)]
+struct VerifyRequest<'a> {
+    response: &'a str,
+    secret: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remoteip: Option<String>,
+}
+
+/// Verification result returned by the provider.
+#[derive(Deserialize)]
+struct VerifyResponse {
+    success: bool,
+}
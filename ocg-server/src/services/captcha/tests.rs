@@ -0,0 +1,30 @@
+use crate::config::{CaptchaConfig, CaptchaProvider};
+
+use super::*;
+
+#[test]
+fn test_provider_widget_settings() {
+    assert_eq!(
+        CaptchaProvider::HCaptcha.response_field(),
+        "h-captcha-response"
+    );
+    assert_eq!(CaptchaProvider::HCaptcha.widget_class(), "h-captcha");
+    assert_eq!(
+        CaptchaProvider::Turnstile.response_field(),
+        "cf-turnstile-response"
+    );
+    assert_eq!(CaptchaProvider::Turnstile.widget_class(), "cf-turnstile");
+}
+
+#[tokio::test]
+async fn test_verify_rejects_empty_response() {
+    // Setup configuration
+    let cfg = CaptchaConfig {
+        provider: CaptchaProvider::Turnstile,
+        secret_key: "secret".to_string(),
+        site_key: "site".to_string(),
+    };
+
+    // Check empty responses are rejected without calling the provider
+    assert!(!verify(&cfg, " ", None).await.unwrap());
+}
@@ -0,0 +1,414 @@
+//! Certificates of attendance issued to the attendees who checked in to events.
+//!
+//! Certificates are rendered as single page PDF documents that include the
+//! community logo when it can be loaded.
+
+use std::{fmt::Write as _, io::Cursor, sync::LazyLock, time::Duration};
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use image::{DynamicImage, ImageFormat, Rgb, RgbImage};
+use reqwest::{Client as HttpClient, Url, redirect::Policy};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::services::{
+    images::DynImageStorage,
+    notifications::{
+        Attachment,
+        webhook::{PublicAddrsResolver, is_public_ip, parse_ip_host},
+    },
+    pdf::{Color, Font, PdfWriter, fit_text},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Format used to display the event dates on the certificates.
+const DATE_FORMAT: &str = "%B %-d, %Y";
+
+/// Color used when the primary color configured is not a valid hex color.
+const DEFAULT_PRIMARY_COLOR: Color = Color(0.2, 0.2, 0.2);
+
+/// Timeout for HTTP requests used to download the community logos.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum height of the logo on the certificate, in points.
+const LOGO_MAX_HEIGHT: f64 = 70.0;
+
+/// Maximum number of pixels per side of the logo embedded in the certificate.
+const LOGO_MAX_PIXELS: u32 = 600;
+
+/// Maximum size of the logo files downloaded.
+const LOGO_MAX_SIZE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Maximum width of the logo on the certificate, in points.
+const LOGO_MAX_WIDTH: f64 = 220.0;
+
+/// Maximum width of the text lines on the certificate, in points.
+const MAX_TEXT_WIDTH: f64 = 640.0;
+
+/// Minimum font size text lines are shrunk to when they are too long.
+const MIN_FONT_SIZE: f64 = 12.0;
+
+/// Height of the certificate page, in points (A4 landscape).
+const PAGE_HEIGHT: f64 = 595.0;
+
+/// Width of the certificate page, in points (A4 landscape).
+const PAGE_WIDTH: f64 = 842.0;
+
+/// Color used for the secondary text lines.
+const SECONDARY_TEXT_COLOR: Color = Color(0.34, 0.33, 0.31);
+
+/// Color used for the main text lines.
+const TEXT_COLOR: Color = Color(0.11, 0.1, 0.09);
+
+/// HTTP client used to download the community logos hosted externally.
+///
+/// Redirects are not followed and hosts resolving to internal addresses are
+/// refused, as the logo URL is provided by the community.
+static HTTP_CLIENT: LazyLock<HttpClient> = LazyLock::new(|| {
+    HttpClient::builder()
+        .dns_resolver(PublicAddrsResolver)
+        .redirect(Policy::none())
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .expect("failed to build http client")
+});
+
+/// Builds the certificate of attendance PDF as an email attachment.
+pub(crate) async fn build_attendance_certificate_attachment(
+    image_storage: &DynImageStorage,
+    certificate: &AttendanceCertificate,
+) -> Attachment {
+    Attachment {
+        content_type: "application/pdf".to_string(),
+        data: build_attendance_certificate_pdf(image_storage, certificate).await,
+        file_name: certificate.file_name(),
+    }
+}
+
+/// Builds the certificate of attendance PDF.
+///
+/// The community logo is loaded on a best-effort basis: the certificate is
+/// still generated without it when it cannot be loaded.
+pub(crate) async fn build_attendance_certificate_pdf(
+    image_storage: &DynImageStorage,
+    certificate: &AttendanceCertificate,
+) -> Vec<u8> {
+    let logo = match &certificate.logo_url {
+        Some(logo_url) => load_logo(image_storage, logo_url).await.unwrap_or_else(|err| {
+            warn!(?err, %logo_url, "error loading certificate logo");
+            None
+        }),
+        None => None,
+    };
+
+    render_attendance_certificate_pdf(certificate, logo.as_ref())
+}
+
+/// Renders the certificate of attendance PDF.
+pub(crate) fn render_attendance_certificate_pdf(
+    certificate: &AttendanceCertificate,
+    logo: Option<&DynamicImage>,
+) -> Vec<u8> {
+    // Prepare the logo image, skipping it if it cannot be encoded
+    let logo = logo.and_then(|logo| {
+        LogoImage::new(logo)
+            .inspect_err(|err| warn!(?err, "error encoding certificate logo"))
+            .ok()
+    });
+
+    // Write the document objects
+    let mut pdf = PdfWriter::new();
+    pdf.add_object("<< /Type /Catalog /Pages 2 0 R >>", None);
+    pdf.add_object("<< /Type /Pages /Kids [3 0 R] /Count 1 >>", None);
+    let xobjects = if logo.is_some() {
+        "/XObject << /Logo 7 0 R >> "
+    } else {
+        ""
+    };
+    pdf.add_object(
+        &format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+             /Resources << /Font << /F1 4 0 R /F2 5 0 R >> {xobjects}>> /Contents 6 0 R >>"
+        ),
+        None,
+    );
+    pdf.add_object(&Font::Regular.dictionary(), None);
+    pdf.add_object(&Font::Bold.dictionary(), None);
+    let content = build_page_content(certificate, logo.as_ref());
+    pdf.add_object(
+        &format!("<< /Length {} >>", content.len()),
+        Some(content.as_bytes()),
+    );
+    if let Some(logo) = &logo {
+        pdf.add_object(
+            &format!(
+                "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB \
+                 /BitsPerComponent 8 /Filter /DCTDecode /Length {} >>",
+                logo.width,
+                logo.height,
+                logo.jpeg.len()
+            ),
+            Some(&logo.jpeg),
+        );
+    }
+
+    pdf.finish()
+}
+
+/// Builds the content stream that draws the certificate page.
+fn build_page_content(certificate: &AttendanceCertificate, logo: Option<&LogoImage>) -> String {
+    let mut content = String::new();
+    let primary_color =
+        Color::from_hex(&certificate.primary_color).unwrap_or(DEFAULT_PRIMARY_COLOR);
+
+    // Borders
+    let _ = writeln!(
+        content,
+        "{primary_color} RG 6 w 24 24 {} {} re S",
+        PAGE_WIDTH - 48.0,
+        PAGE_HEIGHT - 48.0
+    );
+    let _ = writeln!(
+        content,
+        "1 w 36 36 {} {} re S",
+        PAGE_WIDTH - 72.0,
+        PAGE_HEIGHT - 72.0
+    );
+
+    // Community logo
+    if let Some(logo) = logo {
+        let (width, height) = logo.display_size();
+        let x = (PAGE_WIDTH - width) / 2.0;
+        let y = 450.0 + (LOGO_MAX_HEIGHT - height) / 2.0;
+        let _ = writeln!(
+            content,
+            "q {width:.2} 0 0 {height:.2} {x:.2} {y:.2} cm /Logo Do Q"
+        );
+    }
+
+    // Certificate text lines
+    let organizer = format!(
+        "organized by {}, {}",
+        certificate.group_name, certificate.community_display_name
+    );
+    let date = format!("on {}", certificate.date());
+    let lines = [
+        (
+            Font::Bold,
+            28.0,
+            395.0,
+            primary_color,
+            "CERTIFICATE OF ATTENDANCE",
+        ),
+        (
+            Font::Regular,
+            14.0,
+            350.0,
+            SECONDARY_TEXT_COLOR,
+            "This certifies that",
+        ),
+        (
+            Font::Bold,
+            34.0,
+            300.0,
+            TEXT_COLOR,
+            certificate.attendee_name.as_str(),
+        ),
+        (Font::Regular, 14.0, 250.0, SECONDARY_TEXT_COLOR, "attended"),
+        (
+            Font::Bold,
+            22.0,
+            215.0,
+            TEXT_COLOR,
+            certificate.event_name.as_str(),
+        ),
+        (
+            Font::Regular,
+            14.0,
+            180.0,
+            SECONDARY_TEXT_COLOR,
+            organizer.as_str(),
+        ),
+        (
+            Font::Regular,
+            14.0,
+            158.0,
+            SECONDARY_TEXT_COLOR,
+            date.as_str(),
+        ),
+    ];
+    for (font, size, y, color, text) in lines {
+        let (text, size) = fit_text(text, font, size, MIN_FONT_SIZE, MAX_TEXT_WIDTH);
+        let x = (PAGE_WIDTH - font.text_width(&text, size)) / 2.0;
+        let _ = writeln!(
+            content,
+            "BT /{} {size:.2} Tf {color} rg {x:.2} {y:.2} Td <{}> Tj ET",
+            font.resource_name(),
+            hex::encode_upper(&text)
+        );
+    }
+
+    // Separator below the attendee name
+    let _ = writeln!(
+        content,
+        "{primary_color} RG 1.5 w {} 285 m {} 285 l S",
+        (PAGE_WIDTH - 360.0) / 2.0,
+        (PAGE_WIDTH + 360.0) / 2.0
+    );
+
+    content
+}
+
+/// Loads the community logo from the images storage or its external location.
+async fn load_logo(
+    image_storage: &DynImageStorage,
+    logo_url: &str,
+) -> Result<Option<DynamicImage>> {
+    let bytes = if let Some(file_name) = logo_url.strip_prefix("/images/") {
+        let Some(image) = image_storage.get(file_name).await? else {
+            return Ok(None);
+        };
+        image.bytes
+    } else if logo_url.starts_with("https://") {
+        // Refuse logos hosted on internal addresses
+        let url = Url::parse(logo_url)?;
+        if let Some(ip) = url.host_str().and_then(parse_ip_host)
+            && !is_public_ip(ip)
+        {
+            bail!("logo url cannot point to an internal address");
+        }
+
+        // Download the logo, stopping as soon as it exceeds the size limit
+        let mut response = HTTP_CLIENT.get(url).send().await?.error_for_status()?;
+        if response
+            .content_length()
+            .is_some_and(|len| len > LOGO_MAX_SIZE_BYTES as u64)
+        {
+            bail!("logo file is too large");
+        }
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if bytes.len() + chunk.len() > LOGO_MAX_SIZE_BYTES {
+                bail!("logo file is too large");
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        bytes
+    } else {
+        return Ok(None);
+    };
+
+    // Logos in formats that cannot be embedded (i.e. SVG) are skipped
+    Ok(image::load_from_memory(&bytes).ok())
+}
+
+// Types.
+
+/// Details printed on a certificate of attendance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AttendanceCertificate {
+    /// Name of the attendee the certificate is issued to.
+    pub attendee_name: String,
+    /// Display name of the community the event belongs to.
+    pub community_display_name: String,
+    /// Event identifier.
+    pub event_id: Uuid,
+    /// Event display name.
+    pub event_name: String,
+    /// URL-friendly identifier of the event.
+    pub event_slug: String,
+    /// Name of the group that organized the event.
+    pub group_name: String,
+    /// Primary color used on the certificate, as a hex color.
+    pub primary_color: String,
+    /// Event start time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub starts_at: DateTime<Utc>,
+    /// Timezone in which the event dates are displayed.
+    pub timezone: Tz,
+
+    /// Event end time.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub ends_at: Option<DateTime<Utc>>,
+    /// URL to the community logo.
+    pub logo_url: Option<String>,
+}
+
+impl AttendanceCertificate {
+    /// Returns the event dates in the event timezone.
+    pub(crate) fn date(&self) -> String {
+        let starts_at = self.starts_at.with_timezone(&self.timezone);
+        match self.ends_at.map(|ends_at| ends_at.with_timezone(&self.timezone)) {
+            Some(ends_at) if ends_at.date_naive() != starts_at.date_naive() => format!(
+                "{} - {}",
+                starts_at.format(DATE_FORMAT),
+                ends_at.format(DATE_FORMAT)
+            ),
+            _ => starts_at.format(DATE_FORMAT).to_string(),
+        }
+    }
+
+    /// Returns the file name of the certificate PDF.
+    pub(crate) fn file_name(&self) -> String {
+        format!("certificate-{}.pdf", self.event_slug)
+    }
+}
+
+/// Community logo encoded to be embedded in the certificate.
+#[derive(Debug, Clone)]
+struct LogoImage {
+    /// Height of the image, in pixels.
+    height: u32,
+    /// Image encoded as JPEG.
+    jpeg: Vec<u8>,
+    /// Width of the image, in pixels.
+    width: u32,
+}
+
+impl LogoImage {
+    /// Encodes the logo as a JPEG image with no transparency.
+    fn new(logo: &DynamicImage) -> Result<Self> {
+        // Limit the resolution of large logos
+        let logo = if logo.width() > LOGO_MAX_PIXELS || logo.height() > LOGO_MAX_PIXELS {
+            logo.thumbnail(LOGO_MAX_PIXELS, LOGO_MAX_PIXELS)
+        } else {
+            logo.clone()
+        };
+
+        // Blend transparent pixels with a white background
+        let rgba = logo.to_rgba8();
+        let mut rgb = RgbImage::new(rgba.width(), rgba.height());
+        for (x, y, pixel) in rgba.enumerate_pixels() {
+            let [r, g, b, a] = pixel.0;
+            let alpha = u16::from(a);
+            let blend = |value: u8| {
+                let blended = (u16::from(value) * alpha + 255 * (255 - alpha)) / 255;
+                u8::try_from(blended).unwrap_or(u8::MAX)
+            };
+            rgb.put_pixel(x, y, Rgb([blend(r), blend(g), blend(b)]));
+        }
+
+        // Encode image
+        let mut jpeg = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(rgb).write_to(&mut jpeg, ImageFormat::Jpeg)?;
+
+        Ok(Self {
+            height: logo.height(),
+            jpeg: jpeg.into_inner(),
+            width: logo.width(),
+        })
+    }
+
+    /// Returns the size of the logo on the certificate, keeping its aspect ratio.
+    fn display_size(&self) -> (f64, f64) {
+        let (width, height) = (f64::from(self.width), f64::from(self.height));
+        let scale = (LOGO_MAX_WIDTH / width).min(LOGO_MAX_HEIGHT / height);
+
+        (width * scale, height * scale)
+    }
+}
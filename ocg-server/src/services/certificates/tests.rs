@@ -0,0 +1,188 @@
+use std::{io::Cursor, sync::Arc};
+
+use chrono::TimeZone;
+use image::{ImageFormat, Rgba, RgbaImage};
+
+use crate::services::images::{Image, MockImageStorage};
+
+use super::*;
+
+#[tokio::test]
+async fn test_build_attendance_certificate_attachment() {
+    // Setup image storage mock
+    let mut image_storage = MockImageStorage::new();
+    image_storage
+        .expect_get()
+        .withf(|file_name| file_name == "logo.png")
+        .times(1)
+        .returning(|_| {
+            Box::pin(async {
+                Ok(Some(Image {
+                    bytes: sample_png(),
+                    content_type: "image/png".to_string(),
+                }))
+            })
+        });
+    let image_storage: DynImageStorage = Arc::new(image_storage);
+
+    // Build attachment and check the logo was embedded
+    let certificate = AttendanceCertificate {
+        logo_url: Some("/images/logo.png".to_string()),
+        ..sample_certificate()
+    };
+    let attachment = build_attendance_certificate_attachment(&image_storage, &certificate).await;
+    assert_eq!(attachment.content_type, "application/pdf");
+    assert_eq!(attachment.file_name, "certificate-rust-meetup.pdf");
+    assert!(contains(&attachment.data, b"/XObject << /Logo 7 0 R >>"));
+    assert!(contains(&attachment.data, b"/Filter /DCTDecode"));
+}
+
+#[tokio::test]
+async fn test_build_attendance_certificate_pdf_logo_not_found() {
+    // Setup image storage mock
+    let mut image_storage = MockImageStorage::new();
+    image_storage
+        .expect_get()
+        .times(1)
+        .returning(|_| Box::pin(async { Ok(None) }));
+    let image_storage: DynImageStorage = Arc::new(image_storage);
+
+    // Build certificate and check it was generated without the logo
+    let certificate = AttendanceCertificate {
+        logo_url: Some("/images/logo.png".to_string()),
+        ..sample_certificate()
+    };
+    let pdf = build_attendance_certificate_pdf(&image_storage, &certificate).await;
+    assert!(pdf.starts_with(b"%PDF-1.4"));
+    assert!(!contains(&pdf, b"/Logo"));
+}
+
+#[test]
+fn test_certificate_date_multiple_days() {
+    let certificate = AttendanceCertificate {
+        ends_at: Some(Utc.with_ymd_and_hms(2026, 3, 12, 16, 0, 0).unwrap()),
+        ..sample_certificate()
+    };
+
+    assert_eq!(certificate.date(), "March 10, 2026 - March 12, 2026");
+}
+
+#[test]
+fn test_certificate_date_single_day() {
+    let certificate = sample_certificate();
+
+    assert_eq!(certificate.date(), "March 10, 2026");
+}
+
+#[test]
+fn test_certificate_date_uses_event_timezone() {
+    let certificate = AttendanceCertificate {
+        ends_at: None,
+        starts_at: Utc.with_ymd_and_hms(2026, 3, 11, 2, 0, 0).unwrap(),
+        timezone: chrono_tz::America::New_York,
+        ..sample_certificate()
+    };
+
+    assert_eq!(certificate.date(), "March 10, 2026");
+}
+
+#[test]
+fn test_certificate_file_name() {
+    assert_eq!(
+        sample_certificate().file_name(),
+        "certificate-rust-meetup.pdf"
+    );
+}
+
+#[tokio::test]
+async fn test_load_logo_rejects_internal_addresses() {
+    let image_storage: DynImageStorage = Arc::new(MockImageStorage::new());
+    let result = load_logo(&image_storage, "https://127.0.0.1/logo.png").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_load_logo_skips_non_https_urls() {
+    let image_storage: DynImageStorage = Arc::new(MockImageStorage::new());
+    let logo = load_logo(&image_storage, "http://example.test/logo.png")
+        .await
+        .unwrap();
+    assert!(logo.is_none());
+}
+
+#[test]
+fn test_render_attendance_certificate_pdf() {
+    let pdf = render_attendance_certificate_pdf(&sample_certificate(), None);
+
+    // Check document structure
+    assert!(pdf.starts_with(b"%PDF-1.4"));
+    assert!(pdf.ends_with(b"%%EOF\n"));
+    assert!(contains(&pdf, b"/BaseFont /Helvetica "));
+    assert!(contains(&pdf, b"/BaseFont /Helvetica-Bold "));
+    assert!(contains(&pdf, b"xref\n0 7\n"));
+    assert!(!contains(&pdf, b"/Logo"));
+
+    // Check the certificate text is included
+    let attendee_name = hex::encode_upper("Jane Doe");
+    assert!(contains(&pdf, format!("<{attendee_name}> Tj").as_bytes()));
+    let date = hex::encode_upper("on March 10, 2026");
+    assert!(contains(&pdf, format!("<{date}> Tj").as_bytes()));
+}
+
+#[test]
+fn test_render_attendance_certificate_pdf_xref_offsets() {
+    let pdf = render_attendance_certificate_pdf(&sample_certificate(), None);
+
+    // Check every object offset in the cross-reference table points to it
+    let xref_start = pdf.windows(5).rposition(|window| window == b"xref\n").unwrap();
+    let xref = String::from_utf8(pdf[xref_start..].to_vec()).unwrap();
+    for (index, line) in xref.lines().skip(3).take(6).enumerate() {
+        let offset: usize = line[..10].parse().unwrap();
+        assert!(pdf[offset..].starts_with(format!("{} 0 obj", index + 1).as_bytes()));
+    }
+}
+
+#[test]
+fn test_render_attendance_certificate_pdf_with_logo() {
+    let logo = image::load_from_memory(&sample_png()).unwrap();
+    let pdf = render_attendance_certificate_pdf(&sample_certificate(), Some(&logo));
+
+    assert!(contains(&pdf, b"/XObject << /Logo 7 0 R >>"));
+    assert!(contains(&pdf, b"/Width 40 /Height 20"));
+    assert!(contains(&pdf, b"/Logo Do"));
+    assert!(contains(&pdf, b"xref\n0 8\n"));
+}
+
+// Helpers.
+
+/// Returns whether the haystack contains the needle provided.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+/// Sample certificate used in tests.
+fn sample_certificate() -> AttendanceCertificate {
+    AttendanceCertificate {
+        attendee_name: "Jane Doe".to_string(),
+        community_display_name: "CNCF".to_string(),
+        event_id: Uuid::new_v4(),
+        event_name: "Rust Meetup".to_string(),
+        event_slug: "rust-meetup".to_string(),
+        group_name: "Rust Madrid".to_string(),
+        primary_color: "#D62293".to_string(),
+        starts_at: Utc.with_ymd_and_hms(2026, 3, 10, 18, 0, 0).unwrap(),
+        timezone: chrono_tz::UTC,
+
+        ends_at: Some(Utc.with_ymd_and_hms(2026, 3, 10, 20, 0, 0).unwrap()),
+        logo_url: None,
+    }
+}
+
+/// Sample semi-transparent PNG image used as logo in tests.
+fn sample_png() -> Vec<u8> {
+    let mut bytes = Vec::new();
+    RgbaImage::from_pixel(40, 20, Rgba([214, 34, 147, 128]))
+        .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .unwrap();
+    bytes
+}
@@ -0,0 +1,151 @@
+//! Custom domains ownership verification using DNS TXT records, and
+//! resolution of the communities served from them.
+
+use std::{
+    collections::HashMap,
+    sync::LazyLock,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use reqwest::{Client as HttpClient, Url, header::ACCEPT};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::db::DynDB;
+
+#[cfg(test)]
+mod tests;
+
+/// Time the community served from a host is cached for.
+const CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Maximum number of hosts cached.
+const CACHE_MAX_ENTRIES: usize = 10_000;
+
+/// Timeout for HTTP requests to the DNS resolver.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// DNS record type of TXT records.
+const TXT_RECORD_TYPE: u16 = 16;
+
+/// Label prepended to a domain to build its verification record name.
+const VERIFICATION_RECORD_LABEL: &str = "_ocg-challenge";
+
+/// HTTP client used to query the DNS resolver.
+static HTTP_CLIENT: LazyLock<HttpClient> = LazyLock::new(|| {
+    HttpClient::builder()
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .expect("failed to build http client")
+});
+
+/// Returns the name of the TXT record used to verify the domain provided.
+pub(crate) fn verification_record_name(domain: &str) -> String {
+    format!("{VERIFICATION_RECORD_LABEL}.{domain}")
+}
+
+/// Checks whether the domain's verification record contains the token
+/// provided, proving the domain is controlled by whoever added it.
+pub(crate) async fn verify(
+    dns_resolver_url: &str,
+    domain: &str,
+    verification_token: &str,
+) -> Result<bool> {
+    // Look up the verification record using the DNS over HTTPS resolver
+    let url = Url::parse_with_params(
+        dns_resolver_url,
+        &[
+            ("name", verification_record_name(domain).as_str()),
+            ("type", "TXT"),
+        ],
+    )?;
+    let response: DnsResponse = HTTP_CLIENT
+        .get(url)
+        .header(ACCEPT, "application/dns-json")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(response.contains_txt_record(verification_token))
+}
+
+/// Communities served from custom domains.
+///
+/// The community served from each host is cached for a while, including when
+/// the host is not a verified custom domain, so that resolving it does not hit
+/// the database on every request.
+#[derive(Default)]
+pub(crate) struct CustomDomains {
+    /// Name of the community served from each host, if any, along with the
+    /// time the entry expires.
+    cache: Mutex<HashMap<String, (Option<String>, Instant)>>,
+}
+
+impl CustomDomains {
+    /// Resolve the name of the community served from the host provided.
+    pub(crate) async fn resolve(&self, db: &DynDB, host: &str) -> Result<Option<String>> {
+        // Use the cached entry while it has not expired
+        if let Some((community_name, expires_at)) = self.cache.lock().await.get(host)
+            && *expires_at > Instant::now()
+        {
+            return Ok(community_name.clone());
+        }
+
+        // Look up the community in the database and cache it
+        let community_name = db.get_community_name_by_domain(host).await?;
+        let mut cache = self.cache.lock().await;
+        if cache.len() >= CACHE_MAX_ENTRIES {
+            let now = Instant::now();
+            cache.retain(|_, (_, expires_at)| *expires_at > now);
+            if cache.len() >= CACHE_MAX_ENTRIES {
+                cache.clear();
+            }
+        }
+        cache.insert(
+            host.to_string(),
+            (community_name.clone(), Instant::now() + CACHE_TTL),
+        );
+
+        Ok(community_name)
+    }
+}
+
+/// Response returned by the DNS resolver.
+#[derive(Deserialize)]
+struct DnsResponse {
+    #[serde(default, rename = "Answer")]
+    answer: Vec<DnsAnswer>,
+}
+
+impl DnsResponse {
+    /// Checks whether any of the TXT records in the answer matches the value
+    /// provided.
+    fn contains_txt_record(&self, value: &str) -> bool {
+        self.answer
+            .iter()
+            .filter(|answer| answer.kind == TXT_RECORD_TYPE)
+            .any(|answer| answer.txt_value() == value)
+    }
+}
+
+/// Record included in the resolver answer.
+#[derive(Deserialize)]
+struct DnsAnswer {
+    data: String,
+    #[serde(rename = "type")]
+    kind: u16,
+}
+
+impl DnsAnswer {
+    /// Returns the value of a TXT record, joining the quoted strings it may
+    /// have been split into.
+    fn txt_value(&self) -> String {
+        if !self.data.contains('"') {
+            return self.data.clone();
+        }
+        self.data.split('"').skip(1).step_by(2).collect::<String>()
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::db::mock::MockDB;
+
+use super::*;
+
+#[test]
+fn test_contains_txt_record_joins_split_strings() {
+    let response: DnsResponse = serde_json::from_value(json!({
+        "Status": 0,
+        "Answer": [
+            { "name": "_ocg-challenge.example.org", "type": 16, "TTL": 300, "data": "\"abc\" \"def\"" }
+        ]
+    }))
+    .unwrap();
+
+    assert!(response.contains_txt_record("abcdef"));
+}
+
+#[test]
+fn test_contains_txt_record_ignores_other_records() {
+    let response: DnsResponse = serde_json::from_value(json!({
+        "Status": 0,
+        "Answer": [
+            { "name": "_ocg-challenge.example.org", "type": 5, "TTL": 300, "data": "token" },
+            { "name": "_ocg-challenge.example.org", "type": 16, "TTL": 300, "data": "\"other\"" }
+        ]
+    }))
+    .unwrap();
+
+    assert!(!response.contains_txt_record("token"));
+}
+
+#[test]
+fn test_contains_txt_record_without_answer() {
+    let response: DnsResponse = serde_json::from_value(json!({ "Status": 3 })).unwrap();
+
+    assert!(!response.contains_txt_record("token"));
+}
+
+#[tokio::test]
+async fn test_resolve_caches_community() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_name_by_domain()
+        .times(1)
+        .withf(|domain| domain == "events.example.org")
+        .returning(|_| Ok(Some("test-community".to_string())));
+    let db: DynDB = Arc::new(db);
+
+    // Resolve the host twice and check the database is only queried once
+    let custom_domains = CustomDomains::default();
+    for _ in 0..2 {
+        let community_name = custom_domains.resolve(&db, "events.example.org").await.unwrap();
+        assert_eq!(community_name.as_deref(), Some("test-community"));
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_caches_unknown_hosts() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_name_by_domain()
+        .times(1)
+        .withf(|domain| domain == "unknown.example.org")
+        .returning(|_| Ok(None));
+    let db: DynDB = Arc::new(db);
+
+    // Resolve the host twice and check the database is only queried once
+    let custom_domains = CustomDomains::default();
+    for _ in 0..2 {
+        let community_name = custom_domains.resolve(&db, "unknown.example.org").await.unwrap();
+        assert_eq!(community_name, None);
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_does_not_cache_errors() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_get_community_name_by_domain()
+        .times(2)
+        .withf(|domain| domain == "events.example.org")
+        .returning(|_| Err(anyhow::anyhow!("db error")));
+    let db: DynDB = Arc::new(db);
+
+    // Resolve the host twice and check the database is queried every time
+    let custom_domains = CustomDomains::default();
+    for _ in 0..2 {
+        assert!(custom_domains.resolve(&db, "events.example.org").await.is_err());
+    }
+}
+
+#[test]
+fn test_verification_record_name() {
+    assert_eq!(
+        verification_record_name("community.example.org"),
+        "_ocg-challenge.community.example.org"
+    );
+}
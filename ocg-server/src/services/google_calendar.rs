@@ -0,0 +1,267 @@
+//! This module defines types and logic to sync the events users attend to
+//! their Google Calendars.
+
+use std::{sync::Arc, time::Duration};
+
+use serde::Deserialize;
+use tokio::{sync::Notify, time::sleep};
+use tokio_util::{sync::CancellationToken, task::TaskTracker};
+use tracing::{error, instrument, warn};
+use uuid::Uuid;
+
+use crate::{
+    config::GoogleCalendarConfig,
+    db::{google_calendar::DynDBGoogleCalendar, listener::PgListener},
+    services::google_calendar::client::{
+        CalendarEventRequest, GoogleCalendarClient, GoogleCalendarClientError,
+    },
+    types::event::EventSummary,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) mod client;
+
+/// Postgres channel notified when a Google Calendar entry needs to be synchronized.
+const GOOGLE_CALENDAR_ENTRY_OUT_OF_SYNC_CHANNEL: &str = "google_calendar_entry_out_of_sync";
+
+/// Number of concurrent workers that synchronize Google Calendar entries.
+const NUM_SYNC_WORKERS: usize = 1;
+
+/// Time to wait after a sync error before retrying.
+const PAUSE_ON_SYNC_ERROR: Duration = Duration::from_secs(30);
+
+/// Time to wait when there are no entries to sync.
+const PAUSE_ON_SYNC_NONE: Duration = Duration::from_mins(1);
+
+/// Google Calendar manager implementation.
+pub(crate) struct GoogleCalendarManager;
+
+impl GoogleCalendarManager {
+    /// Create a new `GoogleCalendarManager`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn new(
+        cfg: &GoogleCalendarConfig,
+        base_url: &str,
+        db: DynDBGoogleCalendar,
+        db_listener: PgListener,
+        task_tracker: &TaskTracker,
+        cancellation_token: &CancellationToken,
+    ) -> Self {
+        // Setup and run listener to wake up sync workers when an entry goes
+        // out of sync, polling is still used as a fallback
+        let entry_out_of_sync = Arc::new(Notify::new());
+        let listener_notify = entry_out_of_sync.clone();
+        let listener_cancellation_token = cancellation_token.clone();
+        task_tracker.spawn(async move {
+            db_listener
+                .run(
+                    GOOGLE_CALENDAR_ENTRY_OUT_OF_SYNC_CHANNEL,
+                    &listener_notify,
+                    &listener_cancellation_token,
+                )
+                .await;
+        });
+
+        // Setup and run workers to synchronize entries
+        let client = Arc::new(GoogleCalendarClient::new(cfg.clone()));
+        for _ in 1..=NUM_SYNC_WORKERS {
+            let worker = GoogleCalendarSyncWorker {
+                base_url: base_url.to_string(),
+                cancellation_token: cancellation_token.clone(),
+                client: client.clone(),
+                db: db.clone(),
+                entry_out_of_sync: entry_out_of_sync.clone(),
+            };
+            task_tracker.spawn(async move {
+                worker.run().await;
+            });
+        }
+
+        Self
+    }
+}
+
+/// Worker that syncs the events users attend to their Google Calendars.
+struct GoogleCalendarSyncWorker {
+    /// Base URL used to build the event page links.
+    base_url: String,
+    /// Token to signal worker shutdown.
+    cancellation_token: CancellationToken,
+    /// Google Calendar API client.
+    client: Arc<GoogleCalendarClient>,
+    /// Database handle for Google Calendar queries.
+    db: DynDBGoogleCalendar,
+    /// Notified when an entry needs to be synchronized.
+    entry_out_of_sync: Arc<Notify>,
+}
+
+impl GoogleCalendarSyncWorker {
+    /// Main worker loop: synchronizes entries until cancelled.
+    async fn run(&self) {
+        loop {
+            // Try to sync a pending entry
+            match self.sync_entry().await {
+                Ok(true) => {
+                    // One entry was synced, try to sync another one immediately
+                }
+                Ok(false) => tokio::select! {
+                    // No pending entries to sync, pause until an entry goes
+                    // out of sync unless we've been asked to stop
+                    () = sleep(PAUSE_ON_SYNC_NONE) => {},
+                    () = self.entry_out_of_sync.notified() => {},
+                    () = self.cancellation_token.cancelled() => break,
+                },
+                Err(err) => {
+                    // Something went wrong syncing the entry, pause unless
+                    // we've been asked to stop
+                    error!(%err, "error syncing google calendar entry");
+                    let pause = err.retry_after().unwrap_or(PAUSE_ON_SYNC_ERROR);
+                    tokio::select! {
+                        () = sleep(pause) => {},
+                        () = self.cancellation_token.cancelled() => break,
+                    }
+                }
+            }
+
+            // Exit if the worker has been asked to stop
+            if self.cancellation_token.is_cancelled() {
+                break;
+            }
+        }
+    }
+
+    /// Attempt to sync an out-of-sync entry, if any.
+    #[instrument(skip(self), err)]
+    async fn sync_entry(&self) -> Result<bool, SyncError> {
+        // Claim an out-of-sync entry before calendar side effects
+        let Some(mut entry) = self
+            .db
+            .claim_google_calendar_entry_out_of_sync()
+            .await
+            .map_err(SyncError::Other)?
+        else {
+            return Ok(false);
+        };
+
+        // Apply the entry changes to the user's calendar
+        match self.sync_calendar_event(&entry).await {
+            Ok(google_event_id) => {
+                entry.google_event_id = google_event_id;
+            }
+            Err(GoogleCalendarClientError::AccessRevoked) => {
+                // The user revoked the access, so the connection is removed
+                warn!(user_id = %entry.user_id, "google calendar access revoked");
+                self.db
+                    .delete_user_google_calendar(&entry.user_id)
+                    .await
+                    .map_err(SyncError::Other)?;
+                return Ok(true);
+            }
+            Err(err) if err.is_retryable() => {
+                // Retryable error: release the claim so the entry can be retried
+                self.db
+                    .release_google_calendar_entry_sync_claim(&entry)
+                    .await
+                    .map_err(SyncError::Other)?;
+                return Err(SyncError::Client(err));
+            }
+            Err(err) => {
+                // Non-retryable: log error and complete the claim
+                error!(%err, event_id = %entry.event_id, "error syncing google calendar entry");
+            }
+        }
+
+        self.db
+            .set_google_calendar_entry_synced(&entry)
+            .await
+            .map_err(SyncError::Other)?;
+
+        Ok(true)
+    }
+
+    /// Insert, update or delete the calendar event of an entry, returning the
+    /// identifier of the calendar event that remains in the user's calendar.
+    async fn sync_calendar_event(
+        &self,
+        entry: &GoogleCalendarEntry,
+    ) -> Result<Option<String>, GoogleCalendarClientError> {
+        let access_token = self.client.fetch_access_token(&entry.refresh_token).await?;
+
+        match (entry.event.as_ref(), entry.google_event_id.as_deref()) {
+            (Some(event), Some(google_event_id)) => {
+                // Update the calendar event, adding it again if the user deleted it
+                let req = CalendarEventRequest::new(&self.base_url, event)?;
+                match self.client.update_event(&access_token, google_event_id, &req).await {
+                    Ok(()) => Ok(Some(google_event_id.to_string())),
+                    Err(GoogleCalendarClientError::NotFound) => {
+                        let calendar_event = self.client.insert_event(&access_token, &req).await?;
+                        Ok(Some(calendar_event.id))
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            (Some(event), None) => {
+                // Add the event to the calendar
+                let req = CalendarEventRequest::new(&self.base_url, event)?;
+                let calendar_event = self.client.insert_event(&access_token, &req).await?;
+                Ok(Some(calendar_event.id))
+            }
+            (None, Some(google_event_id)) => {
+                // Remove the event from the calendar
+                match self.client.delete_event(&access_token, google_event_id).await {
+                    Ok(()) | Err(GoogleCalendarClientError::NotFound) => Ok(None),
+                    Err(err) => Err(err),
+                }
+            }
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// Entry of an event in a user's Google Calendar.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct GoogleCalendarEntry {
+    /// Event identifier.
+    pub event_id: Uuid,
+    /// Refresh token used to access the user's calendar.
+    pub refresh_token: String,
+    /// Version of the entry changes being synchronized.
+    pub sync_version: i64,
+    /// User identifier.
+    pub user_id: Uuid,
+
+    /// Event details, when it should be in the user's calendar.
+    pub event: Option<EventSummary>,
+    /// Identifier of the event in the user's calendar, once added.
+    pub google_event_id: Option<String>,
+}
+
+/// Internal error type for sync operations.
+#[derive(Debug)]
+enum SyncError {
+    /// Google Calendar client error.
+    Client(GoogleCalendarClientError),
+    /// Other errors (DB, parsing, etc).
+    Other(anyhow::Error),
+}
+
+impl std::fmt::Display for SyncError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Client(e) => write!(f, "{e}"),
+            Self::Other(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl SyncError {
+    /// Returns the retry delay if this is a rate-limited client error.
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Client(client_err) => client_err.retry_after(),
+            Self::Other(_) => None,
+        }
+    }
+}
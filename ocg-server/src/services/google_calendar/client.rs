@@ -0,0 +1,474 @@
+//! Lightweight Google client used to sync events to users' calendars.
+
+use std::{sync::LazyLock, time::Duration};
+
+use anyhow::Result;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::{Client as HttpClient, header::CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use tracing::{instrument, trace};
+
+use crate::{
+    config::GoogleCalendarConfig,
+    types::event::EventSummary,
+    util::{build_event_calendar_description, build_event_page_link},
+};
+
+/// URL where users authorize access to their calendar.
+const AUTHORIZE_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+
+/// Base URL for the Google Calendar API v3.
+const CALENDAR_BASE_URL: &str = "https://www.googleapis.com/calendar/v3";
+
+/// Calendar events are added to (the user's primary calendar).
+const CALENDAR_ID: &str = "primary";
+
+/// Default retry delay when Google doesn't provide Retry-After header.
+const DEFAULT_RATE_LIMIT_RETRY: Duration = Duration::from_mins(1);
+
+/// OAuth error returned when a refresh token has expired or been revoked.
+const GOOGLE_INVALID_GRANT: &str = "invalid_grant";
+
+/// OAuth token endpoint.
+const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// Timeout for HTTP requests to Google APIs.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Maximum length of the event location.
+const MAX_LOCATION_LEN: usize = 512;
+
+/// OAuth scope requested to manage the events of the user's calendars.
+const SCOPE: &str = "https://www.googleapis.com/auth/calendar.events";
+
+/// HTTP client used for Google API calls.
+static HTTP_CLIENT: LazyLock<HttpClient> = LazyLock::new(|| {
+    HttpClient::builder()
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .expect("failed to build http client")
+});
+
+/// Google client for users' calendars authorization and events operations.
+pub(crate) struct GoogleCalendarClient {
+    /// Google Calendar sync configuration.
+    cfg: GoogleCalendarConfig,
+}
+
+impl GoogleCalendarClient {
+    /// Create a new Google Calendar client.
+    pub(crate) fn new(cfg: GoogleCalendarConfig) -> Self {
+        Self { cfg }
+    }
+
+    /// Build the URL users are redirected to in order to authorize access to
+    /// their calendar.
+    pub(crate) fn authorize_url(&self, state: &str) -> Result<String> {
+        let query = serde_urlencoded::to_string([
+            ("access_type", "offline"),
+            ("client_id", self.cfg.client_id.as_str()),
+            ("include_granted_scopes", "true"),
+            ("prompt", "consent"),
+            ("redirect_uri", self.cfg.redirect_uri.as_str()),
+            ("response_type", "code"),
+            ("scope", SCOPE),
+            ("state", state),
+        ])?;
+
+        Ok(format!("{AUTHORIZE_URL}?{query}"))
+    }
+
+    /// Delete an event from the user's calendar.
+    #[instrument(skip(self, access_token), err)]
+    pub(crate) async fn delete_event(
+        &self,
+        access_token: &str,
+        event_id: &str,
+    ) -> Result<(), GoogleCalendarClientError> {
+        trace!("google calendar client: delete event");
+
+        let url = format!("{}?sendUpdates=none", event_url(event_id));
+        let response = HTTP_CLIENT
+            .delete(&url)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|e| GoogleCalendarClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(GoogleCalendarClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// Exchange an authorization code for a refresh token.
+    #[instrument(skip_all, err)]
+    pub(crate) async fn exchange_code(
+        &self,
+        code: &str,
+    ) -> Result<String, GoogleCalendarClientError> {
+        trace!("google calendar client: exchange code");
+
+        let token_response = request_token(&[
+            ("client_id", self.cfg.client_id.as_str()),
+            ("client_secret", self.cfg.client_secret.as_str()),
+            ("code", code),
+            ("grant_type", "authorization_code"),
+            ("redirect_uri", self.cfg.redirect_uri.as_str()),
+        ])
+        .await?;
+
+        token_response.refresh_token.ok_or_else(|| {
+            GoogleCalendarClientError::Token("google did not return a refresh token".to_string())
+        })
+    }
+
+    /// Fetch a new access token using a user's refresh token.
+    #[instrument(skip_all, err)]
+    pub(crate) async fn fetch_access_token(
+        &self,
+        refresh_token: &str,
+    ) -> Result<String, GoogleCalendarClientError> {
+        trace!("google calendar client: fetch access token");
+
+        let token_response = request_token(&[
+            ("client_id", self.cfg.client_id.as_str()),
+            ("client_secret", self.cfg.client_secret.as_str()),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .await?;
+
+        Ok(token_response.access_token)
+    }
+
+    /// Insert an event into the user's calendar.
+    #[instrument(skip(self, access_token, req), err)]
+    pub(crate) async fn insert_event(
+        &self,
+        access_token: &str,
+        req: &CalendarEventRequest,
+    ) -> Result<CalendarEvent, GoogleCalendarClientError> {
+        trace!("google calendar client: insert event");
+
+        let url = format!("{CALENDAR_BASE_URL}/calendars/{CALENDAR_ID}/events?sendUpdates=none");
+        let response = HTTP_CLIENT
+            .post(&url)
+            .bearer_auth(access_token)
+            .json(req)
+            .send()
+            .await
+            .map_err(|e| GoogleCalendarClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(GoogleCalendarClientError::from_response(response).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| GoogleCalendarClientError::Network(e.to_string()))
+    }
+
+    /// Update an event in the user's calendar.
+    #[instrument(skip(self, access_token, req), err)]
+    pub(crate) async fn update_event(
+        &self,
+        access_token: &str,
+        event_id: &str,
+        req: &CalendarEventRequest,
+    ) -> Result<(), GoogleCalendarClientError> {
+        trace!("google calendar client: update event");
+
+        let url = format!("{}?sendUpdates=none", event_url(event_id));
+        let response = HTTP_CLIENT
+            .patch(&url)
+            .bearer_auth(access_token)
+            .json(req)
+            .send()
+            .await
+            .map_err(|e| GoogleCalendarClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(GoogleCalendarClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+}
+
+/// Calendar event returned by the Google Calendar API.
+#[derive(Debug, Deserialize)]
+pub(crate) struct CalendarEvent {
+    /// Calendar event identifier.
+    pub id: String,
+}
+
+/// Request body used to insert or update a calendar event.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CalendarEventRequest {
+    /// Event description.
+    pub description: String,
+    /// Event end time.
+    pub end: CalendarEventDateTime,
+    /// Event page the calendar event was created from.
+    pub source: CalendarEventSource,
+    /// Event start time.
+    pub start: CalendarEventDateTime,
+    /// Event title.
+    pub summary: String,
+
+    /// Event location.
+    pub location: Option<String>,
+}
+
+impl CalendarEventRequest {
+    /// Build a request with the details of the event, including the meeting
+    /// details only shared with attendees.
+    pub(crate) fn new(
+        base_url: &str,
+        event: &EventSummary,
+    ) -> Result<Self, GoogleCalendarClientError> {
+        let starts_at = event.starts_at.ok_or(GoogleCalendarClientError::InvalidSchedule)?;
+        let ends_at = event.ends_at.unwrap_or(starts_at);
+
+        // Append the event page link to the description
+        let event_page_link = build_event_page_link(base_url, event);
+        let mut description = build_event_calendar_description(event, true);
+        if !description.is_empty() {
+            description.push_str("\n\n");
+        }
+        description.push_str(&event_page_link);
+
+        Ok(Self {
+            description,
+            end: CalendarEventDateTime {
+                date_time: ends_at.to_rfc3339(),
+                time_zone: event.timezone.to_string(),
+            },
+            source: CalendarEventSource {
+                title: format!("{} - {}", event.group_name, event.name),
+                url: event_page_link,
+            },
+            start: CalendarEventDateTime {
+                date_time: starts_at.to_rfc3339(),
+                time_zone: event.timezone.to_string(),
+            },
+            summary: event.name.clone(),
+
+            location: event.location(MAX_LOCATION_LEN),
+        })
+    }
+}
+
+/// Calendar event date and time.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CalendarEventDateTime {
+    /// RFC 3339 timestamp.
+    pub date_time: String,
+    /// IANA timezone used to display the event.
+    pub time_zone: String,
+}
+
+/// Source of a calendar event.
+#[derive(Debug, Serialize)]
+pub(crate) struct CalendarEventSource {
+    /// Title of the source.
+    pub title: String,
+    /// URL of the source.
+    pub url: String,
+}
+
+/// Response from Google's OAuth token endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    /// OAuth access token.
+    access_token: String,
+
+    /// OAuth refresh token (only returned when exchanging a code).
+    refresh_token: Option<String>,
+}
+
+/// Error response from Google's OAuth token endpoint.
+#[derive(Debug, Default, Deserialize)]
+struct GoogleTokenErrorResponse {
+    /// OAuth error code.
+    #[serde(default)]
+    error: String,
+    /// OAuth error description.
+    #[serde(default)]
+    error_description: String,
+}
+
+/// Error types from Google Calendar client calls.
+#[derive(Debug)]
+pub(crate) enum GoogleCalendarClientError {
+    /// The user revoked the access to their calendar.
+    AccessRevoked,
+    /// Non-retryable client errors (4xx except 401, 403, 404 and 429).
+    Client { status: String, message: String },
+    /// Event is missing its start time.
+    InvalidSchedule,
+    /// Network or connection errors (retryable).
+    Network(String),
+    /// Requested resource does not exist.
+    NotFound,
+    /// Rate limit exceeded (retryable after delay).
+    RateLimit { retry_after: Duration },
+    /// Server errors (5xx, retryable).
+    Server { status: String, message: String },
+    /// Token fetch error (retryable).
+    Token(String),
+}
+
+impl std::fmt::Display for GoogleCalendarClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AccessRevoked => write!(f, "google calendar access revoked"),
+            Self::Client { status, message } => {
+                write!(f, "google calendar client error: {status} - {message}")
+            }
+            Self::InvalidSchedule => write!(f, "missing event start time"),
+            Self::Network(msg) => write!(f, "google calendar network error: {msg}"),
+            Self::NotFound => write!(f, "google calendar resource not found"),
+            Self::RateLimit { retry_after } => {
+                write!(
+                    f,
+                    "google calendar rate limit exceeded (retry after {}s)",
+                    retry_after.as_secs()
+                )
+            }
+            Self::Server { status, message } => {
+                write!(f, "google calendar server error: {status} - {message}")
+            }
+            Self::Token(msg) => write!(f, "google calendar token error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GoogleCalendarClientError {}
+
+impl GoogleCalendarClientError {
+    /// Returns true if this error should be retried.
+    pub(crate) fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Network(_) | Self::RateLimit { .. } | Self::Server { .. } | Self::Token(_)
+        )
+    }
+
+    /// Returns the recommended retry delay for rate limit errors.
+    pub(crate) fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimit { retry_after } => Some(*retry_after),
+            _ => None,
+        }
+    }
+
+    /// Create error from HTTP response status and body.
+    async fn from_response(response: reqwest::Response) -> Self {
+        // Parse Retry-After header before consuming response body
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok())
+            .map_or(DEFAULT_RATE_LIMIT_RETRY, Duration::from_secs);
+
+        // Get status and parse error body
+        let status = response.status();
+        let error = response
+            .json::<GoogleApiErrorResponse>()
+            .await
+            .unwrap_or_default()
+            .error;
+
+        // Determine error type based on status code
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Self::RateLimit { retry_after }
+        } else if status == reqwest::StatusCode::NOT_FOUND || status == reqwest::StatusCode::GONE {
+            Self::NotFound
+        } else if status == reqwest::StatusCode::UNAUTHORIZED
+            || status == reqwest::StatusCode::FORBIDDEN
+        {
+            // Auth errors are retryable (token may have expired)
+            Self::Token(format!("{} - {}", error.status, error.message))
+        } else if status.is_client_error() {
+            Self::Client {
+                status: error.status,
+                message: error.message,
+            }
+        } else {
+            Self::Server {
+                status: error.status,
+                message: error.message,
+            }
+        }
+    }
+}
+
+/// Error response envelope returned by Google APIs.
+#[derive(Debug, Default, Deserialize)]
+struct GoogleApiErrorResponse {
+    /// Error details.
+    #[serde(default)]
+    error: GoogleApiError,
+}
+
+/// Error details returned by Google APIs.
+#[derive(Debug, Default, Deserialize)]
+struct GoogleApiError {
+    /// Human readable error message.
+    #[serde(default)]
+    message: String,
+    /// Canonical error status (e.g. `INVALID_ARGUMENT`).
+    #[serde(default)]
+    status: String,
+}
+
+/// Returns the URL of an event on the user's calendar.
+fn event_url(event_id: &str) -> String {
+    let encoded_event_id = utf8_percent_encode(event_id, NON_ALPHANUMERIC).to_string();
+    format!("{CALENDAR_BASE_URL}/calendars/{CALENDAR_ID}/events/{encoded_event_id}")
+}
+
+/// Make a request to the OAuth token endpoint.
+async fn request_token(
+    params: &[(&str, &str)],
+) -> Result<TokenResponse, GoogleCalendarClientError> {
+    // Make the token request
+    let body = serde_urlencoded::to_string(params)
+        .map_err(|e| GoogleCalendarClientError::Token(e.to_string()))?;
+    let response = HTTP_CLIENT
+        .post(GOOGLE_TOKEN_URL)
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| GoogleCalendarClientError::Network(e.to_string()))?;
+
+    // Handle errors, detecting the access revoked by the user
+    let status = response.status();
+    if !status.is_success() {
+        let error: GoogleTokenErrorResponse = response.json().await.unwrap_or_default();
+        if error.error == GOOGLE_INVALID_GRANT {
+            return Err(GoogleCalendarClientError::AccessRevoked);
+        }
+        let message = format!("{} - {}", error.error, error.error_description);
+        if status.is_server_error() {
+            return Err(GoogleCalendarClientError::Server {
+                status: status.to_string(),
+                message,
+            });
+        }
+        return Err(GoogleCalendarClientError::Token(message));
+    }
+
+    // Parse the token response
+    response
+        .json()
+        .await
+        .map_err(|e| GoogleCalendarClientError::Network(e.to_string()))
+}
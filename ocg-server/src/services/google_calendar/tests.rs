@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::handlers::tests::{sample_event_summary, sample_google_calendar_cfg};
+
+use super::client::{CalendarEventRequest, GoogleCalendarClient, GoogleCalendarClientError};
+
+// GoogleCalendarClient tests.
+
+#[test]
+fn test_authorize_url_requests_offline_access() {
+    let client = GoogleCalendarClient::new(sample_google_calendar_cfg());
+    let url = client.authorize_url("state-token").unwrap();
+
+    assert!(url.starts_with("https://accounts.google.com/o/oauth2/v2/auth?"));
+    assert!(url.contains("access_type=offline"));
+    assert!(url.contains("client_id=client-id"));
+    assert!(url.contains("prompt=consent"));
+    assert!(url.contains(
+        "redirect_uri=https%3A%2F%2Fexample.test%2Fdashboard%2Faccount%2Fgoogle-calendar%2Fcallback"
+    ));
+    assert!(url.contains("scope=https%3A%2F%2Fwww.googleapis.com%2Fauth%2Fcalendar.events"));
+    assert!(url.contains("state=state-token"));
+}
+
+// GoogleCalendarClientError tests.
+
+#[test]
+fn test_google_calendar_client_error_is_retryable() {
+    assert!(!GoogleCalendarClientError::AccessRevoked.is_retryable());
+    assert!(
+        !GoogleCalendarClientError::Client {
+            status: "INVALID_ARGUMENT".to_string(),
+            message: "invalid".to_string(),
+        }
+        .is_retryable()
+    );
+    assert!(!GoogleCalendarClientError::InvalidSchedule.is_retryable());
+    assert!(GoogleCalendarClientError::Network("connection refused".to_string()).is_retryable());
+    assert!(!GoogleCalendarClientError::NotFound.is_retryable());
+    assert!(
+        GoogleCalendarClientError::RateLimit {
+            retry_after: Duration::from_mins(1)
+        }
+        .is_retryable()
+    );
+    assert!(GoogleCalendarClientError::Token("expired".to_string()).is_retryable());
+}
+
+// CalendarEventRequest tests.
+
+#[test]
+fn test_calendar_event_request_includes_event_details() {
+    let event = sample_event_summary(Uuid::new_v4(), Uuid::new_v4());
+    let request = CalendarEventRequest::new("https://example.test/", &event).unwrap();
+    let value = serde_json::to_value(request).unwrap();
+
+    let event_page_link = "https://example.test/test-community/group/def5678/event/ghi9abc";
+    assert_eq!(value["summary"], json!("Sample Event"));
+    assert_eq!(
+        value["description"],
+        json!(format!(
+            "A brief summary of the sample event\n\nMeeting link: https://example.test/meeting\n\n{event_page_link}"
+        ))
+    );
+    assert_eq!(value["source"]["url"], json!(event_page_link));
+    assert_eq!(value["start"]["timeZone"], json!("UTC"));
+    assert_eq!(
+        value["start"]["dateTime"],
+        json!(event.starts_at.unwrap().to_rfc3339())
+    );
+    assert_eq!(
+        value["end"]["dateTime"],
+        json!(event.ends_at.unwrap().to_rfc3339())
+    );
+    assert!(value["location"].as_str().unwrap().contains("Sample Venue"));
+}
+
+#[test]
+fn test_calendar_event_request_requires_start_time() {
+    let mut event = sample_event_summary(Uuid::new_v4(), Uuid::new_v4());
+    event.starts_at = None;
+
+    assert!(matches!(
+        CalendarEventRequest::new("https://example.test", &event),
+        Err(GoogleCalendarClientError::InvalidSchedule)
+    ));
+}
@@ -1,4 +1,7 @@
 //! Image storage service abstractions and shared types.
+//!
+//! Providers store opaque blobs along with their content type, so they are
+//! also used to store other uploaded files, like the event resources.
 
 pub(crate) mod db;
 pub(crate) mod s3;
@@ -10,30 +10,59 @@ use mockall::automock;
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnNull, DurationSecondsWithFrac, serde_as, skip_serializing_none};
 use strum::{AsRefStr, Display, EnumString};
-use tokio::time::sleep;
+use tokio::{sync::Notify, time::sleep};
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
-use tracing::{error, instrument};
+use tracing::{debug, error, instrument};
 use uuid::Uuid;
 
-use crate::{config::MeetingsZoomConfig, db::meetings::DynDBMeetings};
+use crate::{
+    config::MeetingsZoomConfig,
+    db::{
+        listener::PgListener,
+        meetings::{DynDBMeetings, MeetingAttendanceCandidate, MeetingRecordingCandidate},
+    },
+    services::meetings::{circuit_breaker::ProviderCircuitBreakers, zoom::GroupZoomProviders},
+};
 
 #[cfg(test)]
 mod tests;
 
+mod circuit_breaker;
+
+pub(crate) mod bigbluebutton;
+pub(crate) mod google_meet;
+pub(crate) mod jitsi;
+pub(crate) mod teams;
+pub(crate) mod webex;
 pub(crate) mod zoom;
 
+/// Postgres channel notified when a meeting needs to be synchronized.
+const MEETING_OUT_OF_SYNC_CHANNEL: &str = "meeting_out_of_sync";
+
 /// Time after which claimed meeting processing requires manual review.
 const MEETING_PROCESSING_TIMEOUT: Duration = Duration::from_mins(15);
 
+/// Number of concurrent workers that sync meeting attendance.
+const NUM_ATTENDANCE_WORKERS: usize = 1;
+
 /// Number of concurrent workers that auto-end meetings.
 const NUM_AUTO_END_WORKERS: usize = 1;
 
 /// Number of workers that recover stale meeting processing claims.
 const NUM_CLAIM_RECOVERY_WORKERS: usize = 1;
 
+/// Number of concurrent workers that sync meeting cloud recordings.
+const NUM_RECORDING_WORKERS: usize = 1;
+
 /// Number of concurrent workers that synchronize meetings.
 const NUM_SYNC_WORKERS: usize = 2;
 
+/// Time to wait after an attendance sync error before retrying.
+const PAUSE_ON_ATTENDANCE_ERROR: Duration = Duration::from_secs(30);
+
+/// Time to wait when there are no meetings to sync attendance for.
+const PAUSE_ON_ATTENDANCE_NONE: Duration = Duration::from_mins(5);
+
 /// Time to wait after an auto-end error before retrying.
 const PAUSE_ON_AUTO_END_ERROR: Duration = Duration::from_secs(30);
 
@@ -46,6 +75,12 @@ const PAUSE_ON_CLAIM_RECOVERY_ERROR: Duration = Duration::from_secs(30);
 /// Time to wait between claim recovery checks.
 const PAUSE_ON_CLAIM_RECOVERY_NONE: Duration = Duration::from_mins(1);
 
+/// Time to wait after a recording sync error before retrying.
+const PAUSE_ON_RECORDING_ERROR: Duration = Duration::from_secs(30);
+
+/// Time to wait when there are no meetings to sync recordings for.
+const PAUSE_ON_RECORDING_NONE: Duration = Duration::from_mins(5);
+
 /// Time to wait after a sync error before retrying.
 const PAUSE_ON_SYNC_ERROR: Duration = Duration::from_secs(30);
 
@@ -77,6 +112,18 @@ pub(crate) trait MeetingsProvider {
         provider_meeting_id: &str,
     ) -> Result<MeetingProviderMeeting, MeetingProviderError>;
 
+    /// List the participants that joined a past meeting.
+    async fn list_participants(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingParticipant>, MeetingProviderError>;
+
+    /// List the cloud recordings available for a past meeting.
+    async fn list_recordings(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingRecording>, MeetingProviderError>;
+
     /// Update a meeting.
     async fn update_meeting(
         &self,
@@ -91,6 +138,20 @@ pub(crate) type DynMeetingsProvider = Arc<dyn MeetingsProvider + Send + Sync>;
 /// Shared map of meetings providers keyed by provider type.
 pub(crate) type DynMeetingsProviders = Arc<HashMap<MeetingProvider, DynMeetingsProvider>>;
 
+/// Participant of a past meeting returned by the provider.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MeetingParticipant {
+    /// Participant email address.
+    pub email: String,
+}
+
+/// Cloud recording of a past meeting returned by the provider.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MeetingRecording {
+    /// URL to watch the recording.
+    pub url: String,
+}
+
 /// Meeting details returned by the provider.
 #[derive(Clone, Debug)]
 pub(crate) struct MeetingProviderMeeting {
@@ -154,6 +215,11 @@ impl MeetingProviderError {
         )
     }
 
+    /// Returns true if this error suggests the provider is unavailable.
+    pub(crate) fn is_unavailable(&self) -> bool {
+        matches!(self, Self::Network(_) | Self::Server(_))
+    }
+
     /// Returns the recommended retry delay for rate limit errors.
     pub(crate) fn retry_after(&self) -> Option<Duration> {
         match self {
@@ -172,16 +238,55 @@ impl MeetingsManager {
     pub(crate) fn new(
         providers: DynMeetingsProviders,
         db: DynDBMeetings,
+        db_listener: PgListener,
         zoom_cfg: Option<MeetingsZoomConfig>,
         task_tracker: &TaskTracker,
         cancellation_token: &CancellationToken,
     ) -> Self {
+        // Providers built from group Zoom credentials are shared by all workers
+        let group_zoom_providers = Arc::new(GroupZoomProviders::default());
+
+        // Providers health is shared by all sync workers, so that they all
+        // back off from a degraded provider
+        let circuit_breakers = Arc::new(ProviderCircuitBreakers::default());
+
+        // Setup and run listener to wake up sync workers when a meeting goes
+        // out of sync, polling is still used as a fallback
+        let meeting_out_of_sync = Arc::new(Notify::new());
+        let listener_notify = meeting_out_of_sync.clone();
+        let listener_cancellation_token = cancellation_token.clone();
+        task_tracker.spawn(async move {
+            db_listener
+                .run(
+                    MEETING_OUT_OF_SYNC_CHANNEL,
+                    &listener_notify,
+                    &listener_cancellation_token,
+                )
+                .await;
+        });
+
+        // Setup and run workers to sync attendance from ended meetings
+        for _ in 1..=NUM_ATTENDANCE_WORKERS {
+            let mut worker = MeetingsAttendanceWorker {
+                cancellation_token: cancellation_token.clone(),
+                db: db.clone(),
+                group_zoom_providers: group_zoom_providers.clone(),
+                providers: providers.clone(),
+                zoom_cfg: zoom_cfg.clone(),
+            };
+            task_tracker.spawn(async move {
+                worker.run().await;
+            });
+        }
+
         // Setup and run workers to auto-end overdue meetings
         for _ in 1..=NUM_AUTO_END_WORKERS {
             let mut worker = MeetingsAutoEndWorker {
                 cancellation_token: cancellation_token.clone(),
                 db: db.clone(),
+                group_zoom_providers: group_zoom_providers.clone(),
                 providers: providers.clone(),
+                zoom_cfg: zoom_cfg.clone(),
             };
             task_tracker.spawn(async move {
                 worker.run().await;
@@ -199,11 +304,28 @@ impl MeetingsManager {
             });
         }
 
+        // Setup and run workers to sync cloud recordings from ended meetings
+        for _ in 1..=NUM_RECORDING_WORKERS {
+            let mut worker = MeetingsRecordingWorker {
+                cancellation_token: cancellation_token.clone(),
+                db: db.clone(),
+                group_zoom_providers: group_zoom_providers.clone(),
+                providers: providers.clone(),
+                zoom_cfg: zoom_cfg.clone(),
+            };
+            task_tracker.spawn(async move {
+                worker.run().await;
+            });
+        }
+
         // Setup and run workers to synchronize meetings
         for _ in 1..=NUM_SYNC_WORKERS {
             let mut worker = MeetingsSyncWorker {
                 cancellation_token: cancellation_token.clone(),
+                circuit_breakers: circuit_breakers.clone(),
                 db: db.clone(),
+                group_zoom_providers: group_zoom_providers.clone(),
+                meeting_out_of_sync: meeting_out_of_sync.clone(),
                 providers: providers.clone(),
                 zoom_cfg: zoom_cfg.clone(),
             };
@@ -216,14 +338,163 @@ impl MeetingsManager {
     }
 }
 
+/// Worker responsible for syncing ended meetings participants into the event
+/// attendance.
+struct MeetingsAttendanceWorker {
+    /// Token to signal worker shutdown.
+    cancellation_token: CancellationToken,
+    /// Database handle for meeting queries.
+    db: DynDBMeetings,
+    /// Zoom providers built from group credentials.
+    group_zoom_providers: Arc<GroupZoomProviders>,
+    /// Providers map for meeting operations.
+    providers: DynMeetingsProviders,
+    /// Zoom configuration.
+    zoom_cfg: Option<MeetingsZoomConfig>,
+}
+
+impl MeetingsAttendanceWorker {
+    /// Main worker loop: syncs meetings attendance until cancelled.
+    async fn run(&mut self) {
+        loop {
+            // Try to sync the attendance of an ended meeting
+            match self.sync_attendance().await {
+                Ok(true) => {
+                    // One meeting was processed, try to process another one immediately
+                }
+                Ok(false) => tokio::select! {
+                    // No ended meetings to process, pause unless we've been asked to stop
+                    () = sleep(PAUSE_ON_ATTENDANCE_NONE) => {},
+                    () = self.cancellation_token.cancelled() => break,
+                },
+                Err(err) => {
+                    // Something went wrong syncing attendance, pause unless we've been asked to stop
+                    error!(%err, "error syncing meeting attendance");
+                    let pause = err.retry_after().unwrap_or(PAUSE_ON_ATTENDANCE_ERROR);
+                    tokio::select! {
+                        () = sleep(pause) => {},
+                        () = self.cancellation_token.cancelled() => break,
+                    }
+                }
+            }
+
+            // Exit if the worker has been asked to stop
+            if self.cancellation_token.is_cancelled() {
+                break;
+            }
+        }
+    }
+
+    /// Attempt to sync the attendance of one ended meeting, if any.
+    #[instrument(skip(self), err)]
+    async fn sync_attendance(&self) -> Result<bool, SyncError> {
+        // Claim an ended meeting candidate before calling the provider
+        let Some(candidate) = self
+            .db
+            .claim_meeting_for_attendance_sync()
+            .await
+            .map_err(SyncError::Other)?
+        else {
+            return Ok(false);
+        };
+
+        // Prefer the group's own Zoom account when it has configured one
+        let group_provider = match self
+            .group_zoom_providers
+            .resolve(
+                &self.db,
+                self.zoom_cfg.as_ref(),
+                candidate.provider,
+                candidate.group_id,
+            )
+            .await
+        {
+            Ok(group_provider) => group_provider.map(|(_, provider)| provider),
+            Err(err) => {
+                self.db
+                    .release_meeting_attendance_sync_claim(&candidate)
+                    .await
+                    .map_err(SyncError::Other)?;
+                return Err(SyncError::Other(err));
+            }
+        };
+
+        // Ensure this meeting provider is configured and supported at runtime
+        let Some(provider) =
+            group_provider.or_else(|| self.providers.get(&candidate.provider).cloned())
+        else {
+            error!(
+                meeting_id = %candidate.meeting_id,
+                provider = %candidate.provider,
+                "provider not configured for attendance sync, skipping meeting",
+            );
+            self.complete_attendance_sync(&candidate, &[]).await?;
+            return Ok(true);
+        };
+
+        // Fetch the participants that joined the meeting
+        let participants_emails =
+            match provider.list_participants(&candidate.provider_meeting_id).await {
+                Ok(participants) => participants.into_iter().map(|p| p.email).collect(),
+                Err(err) if err.is_retryable() => {
+                    self.db
+                        .release_meeting_attendance_sync_claim(&candidate)
+                        .await
+                        .map_err(SyncError::Other)?;
+                    return Err(SyncError::Provider(err));
+                }
+                Err(err) => {
+                    error!(
+                        %err,
+                        meeting_id = %candidate.meeting_id,
+                        provider_meeting_id = %candidate.provider_meeting_id,
+                        "non-retryable attendance sync error, skipping meeting",
+                    );
+                    vec![]
+                }
+            };
+
+        // Check in matching attendees and complete the claim
+        self.complete_attendance_sync(&candidate, &participants_emails)
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Check in the attendees that joined the meeting and complete the claim.
+    async fn complete_attendance_sync(
+        &self,
+        candidate: &MeetingAttendanceCandidate,
+        participants_emails: &[String],
+    ) -> Result<(), SyncError> {
+        let checked_in_count = self
+            .db
+            .set_meeting_attendance_synced(candidate, participants_emails)
+            .await
+            .map_err(SyncError::Other)?;
+
+        debug!(
+            meeting_id = %candidate.meeting_id,
+            event_id = %candidate.event_id,
+            checked_in_count,
+            "synced meeting attendance",
+        );
+        Ok(())
+    }
+}
+
 /// Worker responsible for auto-ending overdue meetings.
 struct MeetingsAutoEndWorker {
     /// Token to signal worker shutdown.
     cancellation_token: CancellationToken,
     /// Database handle for meeting queries.
     db: DynDBMeetings,
+    /// Zoom providers built from group credentials.
+    group_zoom_providers: Arc<GroupZoomProviders>,
     /// Providers map for meeting operations.
     providers: DynMeetingsProviders,
+    /// Zoom configuration.
+    zoom_cfg: Option<MeetingsZoomConfig>,
 }
 
 impl MeetingsAutoEndWorker {
@@ -268,8 +539,31 @@ impl MeetingsAutoEndWorker {
             return Ok(false);
         };
 
+        // Prefer the group's own Zoom account when it has configured one
+        let group_provider = match self
+            .group_zoom_providers
+            .resolve(
+                &self.db,
+                self.zoom_cfg.as_ref(),
+                candidate.provider,
+                candidate.group_id,
+            )
+            .await
+        {
+            Ok(group_provider) => group_provider.map(|(_, provider)| provider),
+            Err(err) => {
+                self.db
+                    .release_meeting_auto_end_check_claim(&candidate)
+                    .await
+                    .map_err(SyncError::Other)?;
+                return Err(SyncError::Other(err));
+            }
+        };
+
         // Ensure this meeting provider is configured and supported at runtime
-        let Some(provider) = self.providers.get(&candidate.provider) else {
+        let Some(provider) =
+            group_provider.or_else(|| self.providers.get(&candidate.provider).cloned())
+        else {
             error!(
                 meeting_id = %candidate.meeting_id,
                 provider = %candidate.provider,
@@ -363,12 +657,171 @@ impl MeetingsClaimRecoveryWorker {
     }
 }
 
+/// Worker responsible for syncing ended meetings cloud recordings into the
+/// event or session recording URL.
+struct MeetingsRecordingWorker {
+    /// Token to signal worker shutdown.
+    cancellation_token: CancellationToken,
+    /// Database handle for meeting queries.
+    db: DynDBMeetings,
+    /// Zoom providers built from group credentials.
+    group_zoom_providers: Arc<GroupZoomProviders>,
+    /// Providers map for meeting operations.
+    providers: DynMeetingsProviders,
+    /// Zoom configuration.
+    zoom_cfg: Option<MeetingsZoomConfig>,
+}
+
+impl MeetingsRecordingWorker {
+    /// Main worker loop: syncs meetings recordings until cancelled.
+    async fn run(&mut self) {
+        loop {
+            // Try to sync the recordings of an ended meeting
+            match self.sync_recordings().await {
+                Ok(true) => {
+                    // One meeting was processed, try to process another one immediately
+                }
+                Ok(false) => tokio::select! {
+                    // No ended meetings to process, pause unless we've been asked to stop
+                    () = sleep(PAUSE_ON_RECORDING_NONE) => {},
+                    () = self.cancellation_token.cancelled() => break,
+                },
+                Err(err) => {
+                    // Something went wrong syncing recordings, pause unless we've been asked to stop
+                    error!(%err, "error syncing meeting recordings");
+                    let pause = err.retry_after().unwrap_or(PAUSE_ON_RECORDING_ERROR);
+                    tokio::select! {
+                        () = sleep(pause) => {},
+                        () = self.cancellation_token.cancelled() => break,
+                    }
+                }
+            }
+
+            // Exit if the worker has been asked to stop
+            if self.cancellation_token.is_cancelled() {
+                break;
+            }
+        }
+    }
+
+    /// Attempt to sync the cloud recordings of one ended meeting, if any.
+    #[instrument(skip(self), err)]
+    async fn sync_recordings(&self) -> Result<bool, SyncError> {
+        // Claim an ended meeting candidate before calling the provider
+        let Some(candidate) = self
+            .db
+            .claim_meeting_for_recording_sync()
+            .await
+            .map_err(SyncError::Other)?
+        else {
+            return Ok(false);
+        };
+
+        // Prefer the group's own Zoom account when it has configured one
+        let group_provider = match self
+            .group_zoom_providers
+            .resolve(
+                &self.db,
+                self.zoom_cfg.as_ref(),
+                candidate.provider,
+                candidate.group_id,
+            )
+            .await
+        {
+            Ok(group_provider) => group_provider.map(|(_, provider)| provider),
+            Err(err) => {
+                self.db
+                    .release_meeting_recording_sync_claim(&candidate)
+                    .await
+                    .map_err(SyncError::Other)?;
+                return Err(SyncError::Other(err));
+            }
+        };
+
+        // Ensure this meeting provider is configured and supported at runtime
+        let Some(provider) =
+            group_provider.or_else(|| self.providers.get(&candidate.provider).cloned())
+        else {
+            error!(
+                meeting_id = %candidate.meeting_id,
+                provider = %candidate.provider,
+                "provider not configured for recording sync, skipping meeting",
+            );
+            self.complete_recording_sync(&candidate, &[]).await?;
+            return Ok(true);
+        };
+
+        // Fetch the cloud recordings of the meeting
+        let recording_urls = match provider.list_recordings(&candidate.provider_meeting_id).await {
+            Ok(recordings) => recordings.into_iter().map(|r| r.url).collect::<Vec<_>>(),
+            Err(err) if err.is_retryable() => {
+                self.db
+                    .release_meeting_recording_sync_claim(&candidate)
+                    .await
+                    .map_err(SyncError::Other)?;
+                return Err(SyncError::Provider(err));
+            }
+            Err(err) => {
+                error!(
+                    %err,
+                    meeting_id = %candidate.meeting_id,
+                    provider_meeting_id = %candidate.provider_meeting_id,
+                    "non-retryable recording sync error, skipping meeting",
+                );
+                self.complete_recording_sync(&candidate, &[]).await?;
+                return Ok(true);
+            }
+        };
+
+        // Keep the claim while the provider is still processing the recordings,
+        // the meeting will be claimed again once the claim expires
+        if recording_urls.is_empty() {
+            debug!(
+                meeting_id = %candidate.meeting_id,
+                "meeting recordings not available yet",
+            );
+            return Ok(true);
+        }
+
+        // Store the recording URLs and complete the claim
+        self.complete_recording_sync(&candidate, &recording_urls).await?;
+
+        Ok(true)
+    }
+
+    /// Store the meeting recording URLs and complete the claim.
+    async fn complete_recording_sync(
+        &self,
+        candidate: &MeetingRecordingCandidate,
+        recording_urls: &[String],
+    ) -> Result<(), SyncError> {
+        self.db
+            .set_meeting_recordings_synced(candidate, recording_urls)
+            .await
+            .map_err(SyncError::Other)?;
+
+        debug!(
+            meeting_id = %candidate.meeting_id,
+            event_id = %candidate.event_id,
+            recordings_count = recording_urls.len(),
+            "synced meeting recordings",
+        );
+        Ok(())
+    }
+}
+
 /// Worker responsible for synchronizing meetings with the provider.
 struct MeetingsSyncWorker {
     /// Token to signal worker shutdown.
     cancellation_token: CancellationToken,
+    /// Circuit breakers tracking the health of each provider.
+    circuit_breakers: Arc<ProviderCircuitBreakers>,
     /// Database handle for meeting queries.
     db: DynDBMeetings,
+    /// Zoom providers built from group credentials.
+    group_zoom_providers: Arc<GroupZoomProviders>,
+    /// Notified when a meeting needs to be synchronized.
+    meeting_out_of_sync: Arc<Notify>,
     /// Providers map for meeting operations.
     providers: DynMeetingsProviders,
     /// Zoom configuration.
@@ -385,9 +838,10 @@ impl MeetingsSyncWorker {
                     // One meeting was synced, try to sync another one immediately
                 }
                 Ok(false) => tokio::select! {
-                    // No pending meetings to sync, pause unless we've been asked
-                    // to stop
+                    // No pending meetings to sync, pause until a meeting goes
+                    // out of sync unless we've been asked to stop
                     () = sleep(PAUSE_ON_SYNC_NONE) => {},
+                    () = self.meeting_out_of_sync.notified() => {},
                     () = self.cancellation_token.cancelled() => break,
                 },
                 Err(err) => {
@@ -412,23 +866,42 @@ impl MeetingsSyncWorker {
     /// Attempt to sync an out-of-sync meeting, if any.
     #[instrument(skip(self), err)]
     async fn sync_meeting(&mut self) -> Result<bool, SyncError> {
-        // Claim an out-of-sync meeting before provider side effects
-        let Some(meeting) = self.db.claim_meeting_out_of_sync().await.map_err(SyncError::Other)?
+        // Claim an out-of-sync meeting before provider side effects, skipping
+        // providers whose circuit is open
+        let paused_providers = self.circuit_breakers.open_providers();
+        let Some(meeting) = self
+            .db
+            .claim_meeting_out_of_sync(&paused_providers)
+            .await
+            .map_err(SyncError::Other)?
         else {
             return Ok(false);
         };
 
-        // Look up the provider for this meeting
-        let provider = self.providers.get(&meeting.provider);
-
-        // Determine action and sync with provider
-        let result = match provider {
-            Some(provider) => match meeting.sync_action() {
-                SyncAction::Create => self.create_meeting(&meeting, provider).await,
-                SyncAction::Delete => self.delete_meeting(&meeting, provider).await,
-                SyncAction::Update => self.update_meeting(&meeting, provider).await,
+        // Look up the provider for this meeting and sync with it
+        let result = match self.resolve_provider(&meeting).await {
+            Ok(Some((provider, zoom_cfg))) => match meeting.sync_action() {
+                SyncAction::Create => {
+                    self.create_meeting(&meeting, &provider, zoom_cfg.as_ref()).await
+                }
+                SyncAction::Delete => self.delete_meeting(&meeting, &provider).await,
+                SyncAction::Update => self.update_meeting(&meeting, &provider).await,
             },
-            None => Err(SyncError::ProviderNotConfigured(meeting.provider)),
+            Ok(None) => Err(SyncError::ProviderNotConfigured(meeting.provider)),
+            Err(err) => Err(err),
+        };
+
+        // Track the provider health, backing off when it looks unavailable
+        let result = match result {
+            Ok(()) => {
+                self.circuit_breakers.record_success(meeting.provider);
+                Ok(())
+            }
+            Err(SyncError::Provider(err)) if err.is_unavailable() => {
+                let retry_after = self.circuit_breakers.record_failure(meeting.provider);
+                Err(SyncError::ProviderUnavailable { err, retry_after })
+            }
+            Err(err) => Err(err),
         };
 
         // Handle errors based on type
@@ -453,24 +926,66 @@ impl MeetingsSyncWorker {
         Ok(true)
     }
 
+    /// Resolve the provider (and Zoom configuration) to use for a meeting.
+    ///
+    /// Zoom meetings of groups with their own credentials use a provider built
+    /// from them, while all other meetings use the globally configured provider.
+    async fn resolve_provider(
+        &self,
+        meeting: &Meeting,
+    ) -> Result<Option<(DynMeetingsProvider, Option<MeetingsZoomConfig>)>, SyncError> {
+        if let Some((zoom_cfg, provider)) = self
+            .group_zoom_providers
+            .resolve(
+                &self.db,
+                self.zoom_cfg.as_ref(),
+                meeting.provider,
+                meeting.group_id,
+            )
+            .await?
+        {
+            return Ok(Some((provider, Some(zoom_cfg))));
+        }
+
+        Ok(self
+            .providers
+            .get(&meeting.provider)
+            .map(|provider| (provider.clone(), self.zoom_cfg.clone())))
+    }
+
     /// Assign provider-specific host user information before meeting creation.
-    async fn assign_provider_host_user(&self, meeting: &Meeting) -> Result<Meeting, SyncError> {
+    async fn assign_provider_host_user(
+        &self,
+        meeting: &Meeting,
+        zoom_cfg: Option<&MeetingsZoomConfig>,
+    ) -> Result<Meeting, SyncError> {
         match meeting.provider {
-            MeetingProvider::Zoom => self.assign_zoom_host_user(meeting).await,
+            // BigBlueButton rooms have no host accounts
+            MeetingProvider::BigBlueButton => Ok(meeting.clone()),
+            // Google Meet meetings are always hosted by the delegated calendar user
+            MeetingProvider::GoogleMeet => Ok(meeting.clone()),
+            // Jitsi rooms have no host accounts
+            MeetingProvider::Jitsi => Ok(meeting.clone()),
+            // Teams meetings are always organized by the configured organizer user
+            MeetingProvider::Teams => Ok(meeting.clone()),
+            // Webex meetings are always hosted by the user that authorized the integration
+            MeetingProvider::Webex => Ok(meeting.clone()),
+            MeetingProvider::Zoom => self.assign_zoom_host_user(meeting, zoom_cfg).await,
         }
     }
 
     /// Assign a Zoom host user from the configured pool based on overlapping load.
-    async fn assign_zoom_host_user(&self, meeting: &Meeting) -> Result<Meeting, SyncError> {
+    async fn assign_zoom_host_user(
+        &self,
+        meeting: &Meeting,
+        zoom_cfg: Option<&MeetingsZoomConfig>,
+    ) -> Result<Meeting, SyncError> {
         if meeting.provider_host_user_id.is_some() {
             return Ok(meeting.clone());
         }
 
         // Ensure Zoom configuration is available
-        let zoom_cfg = self
-            .zoom_cfg
-            .as_ref()
-            .ok_or(SyncError::ProviderNotConfigured(MeetingProvider::Zoom))?;
+        let zoom_cfg = zoom_cfg.ok_or(SyncError::ProviderNotConfigured(MeetingProvider::Zoom))?;
 
         // Ensure meeting has necessary timing information for slot allocation
         let starts_at = meeting.starts_at.ok_or_else(|| {
@@ -509,14 +1024,15 @@ impl MeetingsSyncWorker {
     }
 
     /// Create a meeting on the provider and update local database.
-    #[instrument(skip(self, meeting, provider), err)]
+    #[instrument(skip(self, meeting, provider, zoom_cfg), err)]
     async fn create_meeting(
         &self,
         meeting: &Meeting,
         provider: &DynMeetingsProvider,
+        zoom_cfg: Option<&MeetingsZoomConfig>,
     ) -> Result<(), SyncError> {
         // Assign provider host user ID when needed before creating the provider meeting
-        let meeting = self.assign_provider_host_user(meeting).await?;
+        let meeting = self.assign_provider_host_user(meeting, zoom_cfg).await?;
 
         // Call provider to create meeting
         let provider_meeting = provider.create_meeting(&meeting).await?;
@@ -609,6 +1125,8 @@ pub(crate) struct Meeting {
     pub duration: Option<Duration>,
     /// Owning event identifier for event-level meetings.
     pub event_id: Option<Uuid>,
+    /// Owning group identifier.
+    pub group_id: Option<Uuid>,
     /// Explicit host email addresses.
     pub hosts: Option<Vec<String>>,
     /// Provider join URL.
@@ -638,6 +1156,8 @@ pub(crate) struct Meeting {
     pub timezone: Option<String>,
     /// Meeting topic shown by the provider.
     pub topic: Option<String>,
+    /// Whether the meeting should be hosted as a webinar.
+    pub webinar: Option<bool>,
 }
 
 impl Meeting {
@@ -677,9 +1197,21 @@ impl Meeting {
     PartialEq,
     Serialize,
 )]
-#[serde(rename_all = "lowercase")]
-#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub(crate) enum MeetingProvider {
+    /// BigBlueButton meetings provider.
+    #[serde(rename = "bigbluebutton")]
+    #[strum(serialize = "bigbluebutton")]
+    BigBlueButton,
+    /// Google Meet meetings provider.
+    GoogleMeet,
+    /// Jitsi meetings provider.
+    Jitsi,
+    /// Microsoft Teams meetings provider.
+    Teams,
+    /// Cisco Webex meetings provider.
+    Webex,
     /// Zoom meetings provider.
     #[default]
     Zoom,
@@ -699,6 +1231,21 @@ pub(crate) enum MeetingAutoEndCheckOutcome {
     NotFound,
 }
 
+/// Lifecycle status reported by the provider for a meeting.
+#[derive(
+    AsRefStr, Clone, Copy, Debug, Deserialize, Display, EnumString, Eq, PartialEq, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum MeetingProviderStatus {
+    /// Meeting was deleted on the provider side.
+    Deleted,
+    /// Meeting has ended.
+    Ended,
+    /// Meeting has started.
+    Started,
+}
+
 /// Result returned by providers when trying to end a meeting.
 pub(crate) enum MeetingEndResult {
     /// Meeting had already stopped running.
@@ -724,6 +1271,11 @@ enum SyncError {
     Provider(MeetingProviderError),
     /// Provider not configured.
     ProviderNotConfigured(MeetingProvider),
+    /// Provider unavailable (retryable after the provider backoff).
+    ProviderUnavailable {
+        err: MeetingProviderError,
+        retry_after: Duration,
+    },
     /// Other errors (DB, parsing, etc).
     Other(anyhow::Error),
 }
@@ -733,6 +1285,7 @@ impl std::fmt::Display for SyncError {
         match self {
             Self::Provider(e) => write!(f, "{e}"),
             Self::ProviderNotConfigured(p) => write!(f, "provider not configured: {p}"),
+            Self::ProviderUnavailable { err, .. } => write!(f, "{err}"),
             Self::Other(e) => write!(f, "{e}"),
         }
     }
@@ -756,7 +1309,7 @@ impl SyncError {
         match self {
             Self::Provider(provider_err) => !provider_err.is_retryable(),
             Self::ProviderNotConfigured(_) => true,
-            Self::Other(_) => false,
+            Self::ProviderUnavailable { .. } | Self::Other(_) => false,
         }
     }
 
@@ -764,6 +1317,7 @@ impl SyncError {
     fn retry_after(&self) -> Option<Duration> {
         match self {
             Self::Provider(provider_err) => provider_err.retry_after(),
+            Self::ProviderUnavailable { retry_after, .. } => Some(*retry_after),
             Self::ProviderNotConfigured(_) | Self::Other(_) => None,
         }
     }
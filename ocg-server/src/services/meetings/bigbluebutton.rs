@@ -0,0 +1,125 @@
+//! BigBlueButton-backed meetings provider implementation.
+//!
+//! BigBlueButton meetings only exist on the server while they are running or
+//! waiting for participants, so creating and updating a meeting both issue an
+//! idempotent `create` call that keeps the room available until the scheduled
+//! end time.
+
+use async_trait::async_trait;
+
+use crate::{
+    config::MeetingsBigBlueButtonConfig,
+    services::meetings::bigbluebutton::client::{BigBlueButtonClient, CreateMeetingParams},
+};
+
+use super::{
+    Meeting, MeetingEndResult, MeetingParticipant, MeetingProviderError, MeetingProviderMeeting,
+    MeetingRecording, MeetingsProvider,
+};
+
+pub(crate) mod client;
+
+/// BigBlueButton-backed meetings provider implementation.
+pub(crate) struct BigBlueButtonMeetingsProvider {
+    /// BigBlueButton API client.
+    client: BigBlueButtonClient,
+}
+
+impl BigBlueButtonMeetingsProvider {
+    /// Create a new `BigBlueButtonMeetingsProvider`.
+    pub(crate) fn new(cfg: &MeetingsBigBlueButtonConfig) -> Self {
+        Self {
+            client: BigBlueButtonClient::new(cfg.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl MeetingsProvider for BigBlueButtonMeetingsProvider {
+    /// Create a meeting room in BigBlueButton.
+    async fn create_meeting(
+        &self,
+        meeting: &Meeting,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        let meeting_id = BigBlueButtonClient::new_meeting_id();
+        let params =
+            CreateMeetingParams::new(&meeting_id, meeting).map_err(MeetingProviderError::from)?;
+        self.client
+            .create_meeting(&params)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        Ok(MeetingProviderMeeting {
+            join_url: self.client.join_url(&meeting_id),
+            id: meeting_id,
+            password: None,
+        })
+    }
+
+    /// End the meeting so the room is released.
+    async fn delete_meeting(&self, provider_meeting_id: &str) -> Result<(), MeetingProviderError> {
+        self.client
+            .end_meeting(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+
+    /// End the meeting, if it is still running.
+    async fn end_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingEndResult, MeetingProviderError> {
+        match self.client.end_meeting(provider_meeting_id).await {
+            Ok(()) => Ok(MeetingEndResult::Ended),
+            Err(err) if err.is_not_found() => Ok(MeetingEndResult::AlreadyNotRunning),
+            Err(err) => Err(MeetingProviderError::from(err)),
+        }
+    }
+
+    /// Get meeting details from BigBlueButton.
+    async fn get_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        self.client
+            .get_meeting_info(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        Ok(MeetingProviderMeeting {
+            id: provider_meeting_id.to_string(),
+            join_url: self.client.join_url(provider_meeting_id),
+            password: None,
+        })
+    }
+
+    /// BigBlueButton does not keep participant reports.
+    async fn list_participants(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingParticipant>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// BigBlueButton recordings are not synced yet.
+    async fn list_recordings(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingRecording>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Make sure the room exists until the (possibly new) scheduled end time.
+    async fn update_meeting(
+        &self,
+        provider_meeting_id: &str,
+        meeting: &Meeting,
+    ) -> Result<(), MeetingProviderError> {
+        let params = CreateMeetingParams::new(provider_meeting_id, meeting)
+            .map_err(MeetingProviderError::from)?;
+        self.client
+            .create_meeting(&params)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+}
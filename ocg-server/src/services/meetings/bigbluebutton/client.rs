@@ -0,0 +1,495 @@
+//! Lightweight BigBlueButton API client for meeting operations.
+
+use std::{collections::HashMap, time::Duration};
+
+use chrono::Utc;
+use hmac::{Hmac, KeyInit, Mac};
+use quick_xml::{Reader, events::Event};
+use reqwest::Client as HttpClient;
+use sha2::Sha256;
+use strum::AsRefStr;
+use tracing::{instrument, trace};
+use uuid::Uuid;
+
+use crate::{config::MeetingsBigBlueButtonConfig, services::meetings::Meeting, util::compute_hash};
+
+use super::MeetingProviderError;
+
+/// Name used for attendees joining through the shared join URL.
+const ATTENDEE_NAME: &str = "Attendee";
+
+/// Default retry delay when the server doesn't provide Retry-After header.
+const DEFAULT_RATE_LIMIT_RETRY: Duration = Duration::from_mins(1);
+
+/// Timeout for HTTP requests to the BigBlueButton API.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Prefix used for generated meeting identifiers.
+const MEETING_ID_PREFIX: &str = "ocg-";
+
+/// Message key returned when the shared secret checksum is invalid.
+const MESSAGE_KEY_CHECKSUM_ERROR: &str = "checksumError";
+
+/// Message key returned when the meeting does not exist.
+const MESSAGE_KEY_NOT_FOUND: &str = "notFound";
+
+/// Length of the passwords derived for each meeting role.
+const PASSWORD_LENGTH: usize = 16;
+
+/// Return code of successful API calls.
+const RETURN_CODE_SUCCESS: &str = "SUCCESS";
+
+/// BigBlueButton API client for meeting operations.
+pub(crate) struct BigBlueButtonClient {
+    /// BigBlueButton provider configuration.
+    cfg: MeetingsBigBlueButtonConfig,
+    /// HTTP client used for BigBlueButton API calls.
+    http_client: HttpClient,
+}
+
+impl BigBlueButtonClient {
+    /// Create a new BigBlueButton client.
+    pub(crate) fn new(cfg: MeetingsBigBlueButtonConfig) -> Self {
+        let http_client = HttpClient::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+
+        Self { cfg, http_client }
+    }
+
+    /// Generate a new unguessable meeting identifier.
+    pub(crate) fn new_meeting_id() -> String {
+        format!("{MEETING_ID_PREFIX}{}", Uuid::new_v4().simple())
+    }
+
+    /// Create a meeting (no-op if a meeting with the same ID is already running).
+    #[instrument(skip(self, params), err)]
+    pub(crate) async fn create_meeting(
+        &self,
+        params: &CreateMeetingParams,
+    ) -> Result<(), BigBlueButtonClientError> {
+        trace!("bigbluebutton client: create meeting");
+
+        let record = params.record.to_string();
+        let expire_minutes = params.expire_if_no_user_joined_minutes.to_string();
+        let query = query_string(&[
+            ("name", params.name.as_str()),
+            ("meetingID", params.meeting_id.as_str()),
+            (
+                "attendeePW",
+                &self.password(&params.meeting_id, Role::Attendee),
+            ),
+            (
+                "moderatorPW",
+                &self.password(&params.meeting_id, Role::Moderator),
+            ),
+            ("record", &record),
+            ("autoStartRecording", &record),
+            ("allowStartStopRecording", "true"),
+            ("meetingExpireIfNoUserJoinedInMinutes", &expire_minutes),
+        ])?;
+        self.call("create", &query).await?;
+
+        Ok(())
+    }
+
+    /// End a running meeting.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn end_meeting(
+        &self,
+        meeting_id: &str,
+    ) -> Result<(), BigBlueButtonClientError> {
+        trace!("bigbluebutton client: end meeting");
+
+        let query = query_string(&[
+            ("meetingID", meeting_id),
+            ("password", &self.password(meeting_id, Role::Moderator)),
+        ])?;
+        self.call("end", &query).await?;
+
+        Ok(())
+    }
+
+    /// Get information about a meeting.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn get_meeting_info(
+        &self,
+        meeting_id: &str,
+    ) -> Result<HashMap<String, String>, BigBlueButtonClientError> {
+        trace!("bigbluebutton client: get meeting info");
+
+        let query = query_string(&[("meetingID", meeting_id)])?;
+        self.call("getMeetingInfo", &query).await
+    }
+
+    /// Returns the signed URL attendees use to join a meeting.
+    pub(crate) fn join_url(&self, meeting_id: &str) -> String {
+        let password = self.password(meeting_id, Role::Attendee);
+        let query = serde_urlencoded::to_string([
+            ("fullName", ATTENDEE_NAME),
+            ("meetingID", meeting_id),
+            ("password", password.as_str()),
+            ("redirect", "true"),
+        ])
+        .unwrap_or_default();
+
+        self.signed_url("join", &query)
+    }
+
+    /// Call an API endpoint and return the fields of a successful response.
+    async fn call(
+        &self,
+        call_name: &str,
+        query: &str,
+    ) -> Result<HashMap<String, String>, BigBlueButtonClientError> {
+        // Make the API request
+        let response = self
+            .http_client
+            .get(self.signed_url(call_name, query))
+            .send()
+            .await
+            .map_err(|e| BigBlueButtonClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(BigBlueButtonClientError::from_response(response));
+        }
+
+        // Parse the XML response
+        let body = response
+            .text()
+            .await
+            .map_err(|e| BigBlueButtonClientError::Network(e.to_string()))?;
+        let fields = parse_response(&body);
+        if fields.get("returncode").map(String::as_str) == Some(RETURN_CODE_SUCCESS) {
+            return Ok(fields);
+        }
+
+        Err(BigBlueButtonClientError::from_fields(&fields))
+    }
+
+    /// Derive the password of a meeting role from the shared secret.
+    fn password(&self, meeting_id: &str, role: Role) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.cfg.shared_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(format!("{}:{meeting_id}", role.as_ref()).as_bytes());
+        let mut password = hex::encode(mac.finalize().into_bytes());
+        password.truncate(PASSWORD_LENGTH);
+        password
+    }
+
+    /// Returns the URL of an API call including its checksum.
+    fn signed_url(&self, call_name: &str, query: &str) -> String {
+        let api_url = self.cfg.api_url.trim_end_matches('/');
+        let checksum =
+            compute_hash(format!("{call_name}{query}{}", self.cfg.shared_secret).as_bytes());
+        if query.is_empty() {
+            format!("{api_url}/{call_name}?checksum={checksum}")
+        } else {
+            format!("{api_url}/{call_name}?{query}&checksum={checksum}")
+        }
+    }
+}
+
+/// Parameters used to create a meeting.
+#[derive(Debug)]
+pub(crate) struct CreateMeetingParams {
+    /// Minutes the room stays available if nobody joins.
+    pub expire_if_no_user_joined_minutes: i64,
+    /// Meeting identifier.
+    pub meeting_id: String,
+    /// Meeting name.
+    pub name: String,
+    /// Whether the meeting is recorded.
+    pub record: bool,
+}
+
+impl CreateMeetingParams {
+    /// Build the create parameters for a meeting.
+    pub(crate) fn new(meeting_id: &str, m: &Meeting) -> Result<Self, BigBlueButtonClientError> {
+        let ends_at = m.ends_at().ok_or(BigBlueButtonClientError::InvalidSchedule)?;
+
+        Ok(Self {
+            expire_if_no_user_joined_minutes: (ends_at - Utc::now()).num_minutes().max(1),
+            meeting_id: meeting_id.to_string(),
+            name: m.topic.clone().unwrap_or_default(),
+            record: m.recording_requested.unwrap_or(true),
+        })
+    }
+}
+
+/// Meeting roles with their own password.
+#[derive(AsRefStr, Clone, Copy)]
+#[strum(serialize_all = "snake_case")]
+enum Role {
+    /// Regular participant.
+    Attendee,
+    /// Participant with moderation rights.
+    Moderator,
+}
+
+/// Encode query parameters.
+fn query_string(params: &[(&str, &str)]) -> Result<String, BigBlueButtonClientError> {
+    serde_urlencoded::to_string(params).map_err(|e| BigBlueButtonClientError::Client {
+        key: "invalidParams".to_string(),
+        message: e.to_string(),
+    })
+}
+
+/// Collect the child elements of the response root into a map.
+fn parse_response(body: &str) -> HashMap<String, String> {
+    let mut reader = Reader::from_str(body);
+    reader.config_mut().trim_text(true);
+
+    let mut fields = HashMap::new();
+    let mut depth = 0;
+    let mut current_field: Option<String> = None;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(Event::Start(e)) => {
+                depth += 1;
+                if depth == 2 {
+                    current_field = Some(String::from_utf8_lossy(e.name().as_ref()).into_owned());
+                }
+            }
+            Ok(Event::End(_)) => {
+                if depth == 2 {
+                    current_field = None;
+                }
+                depth -= 1;
+            }
+            Ok(Event::Text(e)) => {
+                if let Some(field) = &current_field {
+                    fields
+                        .entry(field.clone())
+                        .or_insert_with(String::new)
+                        .push_str(&String::from_utf8_lossy(&e));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fields
+}
+
+/// Error types from BigBlueButton client calls.
+#[derive(Debug)]
+pub(crate) enum BigBlueButtonClientError {
+    /// Request checksum was rejected (invalid shared secret).
+    Checksum,
+    /// Non-retryable client errors.
+    Client { key: String, message: String },
+    /// Meeting is missing its start time or duration.
+    InvalidSchedule,
+    /// Network or connection errors (retryable).
+    Network(String),
+    /// Meeting does not exist (or is no longer running).
+    NotFound,
+    /// Rate limit exceeded (retryable after delay).
+    RateLimit { retry_after: Duration },
+    /// Server errors (5xx, retryable).
+    Server { key: String, message: String },
+}
+
+impl std::fmt::Display for BigBlueButtonClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Checksum => write!(f, "bigbluebutton checksum error (check shared secret)"),
+            Self::Client { key, message } => {
+                write!(f, "bigbluebutton client error: {key} - {message}")
+            }
+            Self::InvalidSchedule => write!(f, "missing meeting start time or duration"),
+            Self::Network(msg) => write!(f, "bigbluebutton network error: {msg}"),
+            Self::NotFound => write!(f, "bigbluebutton meeting not found"),
+            Self::RateLimit { retry_after } => {
+                write!(
+                    f,
+                    "bigbluebutton rate limit exceeded (retry after {}s)",
+                    retry_after.as_secs()
+                )
+            }
+            Self::Server { key, message } => {
+                write!(f, "bigbluebutton server error: {key} - {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BigBlueButtonClientError {}
+
+impl From<BigBlueButtonClientError> for MeetingProviderError {
+    fn from(e: BigBlueButtonClientError) -> Self {
+        match e {
+            BigBlueButtonClientError::Checksum => {
+                Self::Client("checksum error (check shared secret)".to_string())
+            }
+            BigBlueButtonClientError::Client { key, message } => {
+                Self::Client(format!("{key}: {message}"))
+            }
+            BigBlueButtonClientError::InvalidSchedule => {
+                Self::Client("missing meeting start time or duration".to_string())
+            }
+            BigBlueButtonClientError::Network(msg) => Self::Network(msg),
+            BigBlueButtonClientError::NotFound => Self::NotFound,
+            BigBlueButtonClientError::RateLimit { retry_after } => Self::RateLimit { retry_after },
+            BigBlueButtonClientError::Server { key, message } => {
+                Self::Server(format!("{key}: {message}"))
+            }
+        }
+    }
+}
+
+impl BigBlueButtonClientError {
+    /// Returns true when the meeting does not exist.
+    pub(crate) fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound)
+    }
+
+    /// Create error from the fields of a failed API response.
+    fn from_fields(fields: &HashMap<String, String>) -> Self {
+        let key = fields.get("messageKey").cloned().unwrap_or_default();
+        let message = fields.get("message").cloned().unwrap_or_default();
+
+        match key.as_str() {
+            MESSAGE_KEY_CHECKSUM_ERROR => Self::Checksum,
+            MESSAGE_KEY_NOT_FOUND => Self::NotFound,
+            _ => Self::Client { key, message },
+        }
+    }
+
+    /// Create error from a non-successful HTTP response.
+    fn from_response(response: reqwest::Response) -> Self {
+        // Parse Retry-After header
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok())
+            .map_or(DEFAULT_RATE_LIMIT_RETRY, Duration::from_secs);
+
+        // Determine error type based on status code
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Self::RateLimit { retry_after }
+        } else if status.is_client_error() {
+            Self::Client {
+                key: status.as_u16().to_string(),
+                message: status.to_string(),
+            }
+        } else {
+            Self::Server {
+                key: status.as_u16().to_string(),
+                message: status.to_string(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use crate::{
+        config::MeetingsBigBlueButtonConfig, services::meetings::Meeting, util::compute_hash,
+    };
+
+    use super::{
+        BigBlueButtonClient, BigBlueButtonClientError, CreateMeetingParams, Role, parse_response,
+    };
+
+    #[test]
+    fn create_meeting_params_require_schedule() {
+        let meeting = Meeting {
+            duration: None,
+            ..sample_meeting()
+        };
+
+        assert!(matches!(
+            CreateMeetingParams::new("ocg-room", &meeting),
+            Err(BigBlueButtonClientError::InvalidSchedule)
+        ));
+    }
+
+    #[test]
+    fn create_meeting_params_keep_room_until_meeting_ends() {
+        let params = CreateMeetingParams::new("ocg-room", &sample_meeting()).unwrap();
+
+        assert!((89..=90).contains(&params.expire_if_no_user_joined_minutes));
+        assert_eq!(params.name, "Community call");
+        assert!(params.record);
+    }
+
+    #[test]
+    fn from_fields_maps_checksum_error() {
+        let fields = parse_response(&failed_response("checksumError"));
+
+        assert!(matches!(
+            BigBlueButtonClientError::from_fields(&fields),
+            BigBlueButtonClientError::Checksum
+        ));
+    }
+
+    #[test]
+    fn from_fields_maps_not_found() {
+        let fields = parse_response(&failed_response("notFound"));
+
+        assert!(BigBlueButtonClientError::from_fields(&fields).is_not_found());
+    }
+
+    #[test]
+    fn join_url_is_signed_with_shared_secret() {
+        let client = BigBlueButtonClient::new(sample_cfg());
+        let join_url = client.join_url("ocg-room");
+
+        // Check the checksum covers the call name and query string
+        let (url, checksum) = join_url.split_once("&checksum=").unwrap();
+        let (base, query) = url.split_once('?').unwrap();
+        assert_eq!(base, "https://bbb.example.test/bigbluebutton/api/join");
+        assert_eq!(
+            checksum,
+            compute_hash(format!("join{query}secret").as_bytes())
+        );
+    }
+
+    #[test]
+    fn passwords_are_stable_and_distinct_per_role() {
+        let client = BigBlueButtonClient::new(sample_cfg());
+        let attendee = client.password("ocg-room", Role::Attendee);
+        let moderator = client.password("ocg-room", Role::Moderator);
+
+        assert_eq!(attendee, client.password("ocg-room", Role::Attendee));
+        assert_ne!(attendee, moderator);
+        assert_eq!(attendee.len(), 16);
+    }
+
+    // Helpers.
+
+    fn failed_response(message_key: &str) -> String {
+        format!(
+            "<response><returncode>FAILED</returncode>\
+             <messageKey>{message_key}</messageKey><message>Failed</message></response>"
+        )
+    }
+
+    fn sample_cfg() -> MeetingsBigBlueButtonConfig {
+        MeetingsBigBlueButtonConfig {
+            api_url: "https://bbb.example.test/bigbluebutton/api/".to_string(),
+            enabled: true,
+            max_participants: 100,
+            shared_secret: "secret".to_string(),
+        }
+    }
+
+    fn sample_meeting() -> Meeting {
+        Meeting {
+            duration: Some(Duration::from_mins(90)),
+            starts_at: Some(Utc::now()),
+            topic: Some("Community call".to_string()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Per-provider circuit breaker used to back off from degraded providers.
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use super::MeetingProvider;
+
+/// Initial backoff applied after a provider failure.
+const BACKOFF_BASE: Duration = Duration::from_secs(30);
+
+/// Maximum backoff applied after consecutive provider failures.
+const BACKOFF_MAX: Duration = Duration::from_mins(10);
+
+/// Number of consecutive failures that opens the provider circuit.
+const FAILURE_THRESHOLD: u32 = 5;
+
+/// Circuit breakers tracking the health of each meetings provider.
+///
+/// Consecutive provider outage errors increase an exponential backoff with
+/// jitter. Once the failures threshold is reached the provider circuit opens,
+/// and meetings handled by that provider are not claimed until the backoff
+/// expires. A successful call closes the circuit again.
+#[derive(Default)]
+pub(crate) struct ProviderCircuitBreakers {
+    /// Circuit state for each provider that has failed recently.
+    circuits: Mutex<HashMap<MeetingProvider, Circuit>>,
+}
+
+impl ProviderCircuitBreakers {
+    /// Returns the providers whose circuit is currently open.
+    pub(crate) fn open_providers(&self) -> Vec<MeetingProvider> {
+        let now = Instant::now();
+        let circuits = self.circuits.lock().expect("circuits lock not poisoned");
+
+        let mut providers: Vec<MeetingProvider> = circuits
+            .iter()
+            .filter(|(_, circuit)| circuit.open_until.is_some_and(|open_until| now < open_until))
+            .map(|(provider, _)| *provider)
+            .collect();
+        providers.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        providers
+    }
+
+    /// Record a provider outage error, returning the backoff to apply.
+    pub(crate) fn record_failure(&self, provider: MeetingProvider) -> Duration {
+        let mut circuits = self.circuits.lock().expect("circuits lock not poisoned");
+        let circuit = circuits.entry(provider).or_default();
+
+        // Increase the backoff with each consecutive failure
+        circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+        let backoff = backoff_with_jitter(circuit.consecutive_failures);
+
+        // Open the circuit once the failures threshold is reached
+        if circuit.consecutive_failures >= FAILURE_THRESHOLD {
+            circuit.open_until = Some(Instant::now() + backoff);
+            warn!(
+                %provider,
+                consecutive_failures = circuit.consecutive_failures,
+                pause_secs = backoff.as_secs(),
+                "meetings provider circuit open, pausing sync"
+            );
+        }
+
+        backoff
+    }
+
+    /// Record a successful provider call, closing its circuit.
+    pub(crate) fn record_success(&self, provider: MeetingProvider) {
+        let mut circuits = self.circuits.lock().expect("circuits lock not poisoned");
+        if let Some(circuit) = circuits.remove(&provider)
+            && circuit.open_until.is_some()
+        {
+            info!(
+                %provider,
+                consecutive_failures = circuit.consecutive_failures,
+                "meetings provider circuit closed, resuming sync"
+            );
+        }
+    }
+}
+
+/// Circuit state of a provider.
+#[derive(Debug, Default)]
+struct Circuit {
+    /// Number of consecutive provider outage errors.
+    consecutive_failures: u32,
+    /// Time until which the provider circuit remains open.
+    open_until: Option<Instant>,
+}
+
+/// Returns the exponential backoff for the failures provided, with jitter.
+///
+/// Half of the backoff is fixed and the other half is random, so that workers
+/// failing at the same time do not retry in lockstep.
+fn backoff_with_jitter(consecutive_failures: u32) -> Duration {
+    let exponent = consecutive_failures.saturating_sub(1).min(16);
+    let backoff = BACKOFF_BASE.saturating_mul(2_u32.pow(exponent)).min(BACKOFF_MAX);
+
+    let half = backoff / 2;
+    let jitter_ms = Uuid::new_v4().as_u128() % (half.as_millis() + 1);
+    half + Duration::from_millis(u64::try_from(jitter_ms).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::services::meetings::MeetingProvider;
+
+    use super::{
+        BACKOFF_BASE, BACKOFF_MAX, FAILURE_THRESHOLD, ProviderCircuitBreakers, backoff_with_jitter,
+    };
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_max() {
+        for (failures, expected) in [
+            (1, BACKOFF_BASE),
+            (2, BACKOFF_BASE * 2),
+            (3, BACKOFF_BASE * 4),
+            (30, BACKOFF_MAX),
+        ] {
+            let backoff = backoff_with_jitter(failures);
+            assert!(backoff >= expected / 2, "{failures}: {backoff:?}");
+            assert!(backoff <= expected, "{failures}: {backoff:?}");
+        }
+    }
+
+    #[test]
+    fn circuit_opens_after_failures_threshold() {
+        let breakers = ProviderCircuitBreakers::default();
+
+        for _ in 1..FAILURE_THRESHOLD {
+            breakers.record_failure(MeetingProvider::Zoom);
+            assert!(breakers.open_providers().is_empty());
+        }
+        breakers.record_failure(MeetingProvider::Zoom);
+
+        assert_eq!(breakers.open_providers(), vec![MeetingProvider::Zoom]);
+    }
+
+    #[test]
+    fn circuit_closes_after_success() {
+        let breakers = ProviderCircuitBreakers::default();
+        for _ in 0..FAILURE_THRESHOLD {
+            breakers.record_failure(MeetingProvider::Zoom);
+        }
+
+        breakers.record_success(MeetingProvider::Zoom);
+
+        assert!(breakers.open_providers().is_empty());
+        assert!(breakers.record_failure(MeetingProvider::Zoom) <= BACKOFF_BASE);
+    }
+
+    #[test]
+    fn circuits_are_tracked_per_provider() {
+        let breakers = ProviderCircuitBreakers::default();
+        for _ in 0..FAILURE_THRESHOLD {
+            breakers.record_failure(MeetingProvider::Zoom);
+        }
+        breakers.record_failure(MeetingProvider::Jitsi);
+
+        assert_eq!(breakers.open_providers(), vec![MeetingProvider::Zoom]);
+        assert!(breakers.record_failure(MeetingProvider::Jitsi) > Duration::ZERO);
+    }
+}
@@ -0,0 +1,162 @@
+//! Google Meet-backed meetings provider implementation.
+
+use async_trait::async_trait;
+
+use crate::{
+    config::MeetingsGoogleMeetConfig,
+    services::meetings::google_meet::client::{
+        CalendarEvent, CalendarEventRequest, GoogleMeetClient,
+    },
+};
+
+use super::{
+    Meeting, MeetingEndResult, MeetingParticipant, MeetingProviderError, MeetingProviderMeeting,
+    MeetingRecording, MeetingsProvider,
+};
+
+pub(crate) mod client;
+
+/// Google Meet-backed meetings provider implementation.
+///
+/// Meetings are stored as events on the delegated user's calendar with a Meet
+/// conference attached, so the provider meeting ID is the calendar event ID.
+pub(crate) struct GoogleMeetMeetingsProvider {
+    /// Google Meet API client.
+    client: GoogleMeetClient,
+}
+
+impl GoogleMeetMeetingsProvider {
+    /// Create a new `GoogleMeetMeetingsProvider`.
+    pub(crate) fn new(cfg: &MeetingsGoogleMeetConfig) -> Self {
+        Self {
+            client: GoogleMeetClient::new(cfg.clone()),
+        }
+    }
+
+    /// Fetch a calendar event, retrying once when the Meet link is still pending.
+    async fn get_event_with_join_url(
+        &self,
+        event: CalendarEvent,
+    ) -> Result<CalendarEvent, MeetingProviderError> {
+        if event.hangout_link.is_some() {
+            return Ok(event);
+        }
+
+        self.client
+            .get_event(&event.id)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+}
+
+#[async_trait]
+impl MeetingsProvider for GoogleMeetMeetingsProvider {
+    /// Create a calendar event with a Google Meet conference.
+    async fn create_meeting(
+        &self,
+        meeting: &Meeting,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        let req = CalendarEventRequest::for_create(meeting).map_err(MeetingProviderError::from)?;
+        let event = self
+            .client
+            .create_event(&req)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        // Conference creation may complete asynchronously
+        let event = self.get_event_with_join_url(event).await?;
+        let Some(join_url) = event.hangout_link else {
+            // Remove the event so the retry does not leave a dangling calendar entry
+            let _ = self.client.delete_event(&event.id).await;
+            return Err(MeetingProviderError::Server(
+                "google meet conference creation is still pending".to_string(),
+            ));
+        };
+
+        Ok(MeetingProviderMeeting {
+            id: event.id,
+            join_url,
+            password: None,
+        })
+    }
+
+    /// Delete a calendar event (and its Meet conference).
+    async fn delete_meeting(&self, provider_meeting_id: &str) -> Result<(), MeetingProviderError> {
+        self.client
+            .delete_event(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+
+    /// End the active Meet conference, if there is one.
+    async fn end_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingEndResult, MeetingProviderError> {
+        // Resolve the Meet meeting code from the calendar event
+        let event = self
+            .client
+            .get_event(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)?;
+        let Some(meeting_code) = event.meeting_code() else {
+            return Ok(MeetingEndResult::AlreadyNotRunning);
+        };
+
+        match self.client.end_active_conference(meeting_code).await {
+            Ok(()) => Ok(MeetingEndResult::Ended),
+            Err(err) if err.is_no_active_conference() => Ok(MeetingEndResult::AlreadyNotRunning),
+            Err(err) => Err(MeetingProviderError::from(err)),
+        }
+    }
+
+    /// Get meeting details from the calendar event.
+    async fn get_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        let event = self
+            .client
+            .get_event(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)?;
+        let join_url = event.hangout_link.ok_or_else(|| {
+            MeetingProviderError::Server("google meet event has no join link".to_string())
+        })?;
+
+        Ok(MeetingProviderMeeting {
+            id: event.id,
+            join_url,
+            password: None,
+        })
+    }
+
+    /// Google Meet participant reports are not synced yet.
+    async fn list_participants(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingParticipant>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Google Meet recordings are not synced yet.
+    async fn list_recordings(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingRecording>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Update the calendar event title and schedule.
+    async fn update_meeting(
+        &self,
+        provider_meeting_id: &str,
+        meeting: &Meeting,
+    ) -> Result<(), MeetingProviderError> {
+        let req = CalendarEventRequest::for_update(meeting).map_err(MeetingProviderError::from)?;
+        self.client
+            .update_event(provider_meeting_id, &req)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+}
@@ -0,0 +1,687 @@
+//! Lightweight Google client for Meet-backed calendar event operations.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL};
+use chrono::{DateTime, Utc};
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Signer};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::Client as HttpClient;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use tokio::sync::Mutex;
+use tracing::{instrument, trace};
+use uuid::Uuid;
+
+use crate::{config::MeetingsGoogleMeetConfig, services::meetings::Meeting};
+
+use super::MeetingProviderError;
+
+/// Base URL for the Google Calendar API v3.
+const CALENDAR_BASE_URL: &str = "https://www.googleapis.com/calendar/v3";
+
+/// Calendar used to host meetings (the delegated user's primary calendar).
+const CALENDAR_ID: &str = "primary";
+
+/// Conference solution type used to request Google Meet links.
+const CONFERENCE_SOLUTION_TYPE: &str = "hangoutsMeet";
+
+/// Default retry delay when Google doesn't provide Retry-After header.
+const DEFAULT_RATE_LIMIT_RETRY: Duration = Duration::from_mins(1);
+
+/// Google status returned when a space has no active conference.
+const GOOGLE_FAILED_PRECONDITION: &str = "FAILED_PRECONDITION";
+
+/// OAuth token endpoint used for service account assertions.
+const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// Timeout for HTTP requests to Google APIs.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Base URL for the Google Meet REST API v2.
+const MEET_BASE_URL: &str = "https://meet.googleapis.com/v2";
+
+/// OAuth scopes requested for the service account.
+const SCOPES: &[&str] = &[
+    "https://www.googleapis.com/auth/calendar.events",
+    "https://www.googleapis.com/auth/meetings.space.created",
+];
+
+/// Lifetime requested for service account assertions.
+const TOKEN_ASSERTION_LIFETIME: Duration = Duration::from_hours(1);
+
+/// Margin before token expiry to trigger refresh.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_mins(5);
+
+/// Google client for Meet-backed calendar event CRUD operations.
+pub(crate) struct GoogleMeetClient {
+    /// Google Meet provider configuration.
+    cfg: MeetingsGoogleMeetConfig,
+    /// HTTP client used for Google API calls.
+    http_client: HttpClient,
+
+    /// Cached OAuth token.
+    token: Mutex<Option<CachedToken>>,
+}
+
+impl GoogleMeetClient {
+    /// Create a new Google Meet client.
+    pub(crate) fn new(cfg: MeetingsGoogleMeetConfig) -> Self {
+        let http_client = HttpClient::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+
+        Self {
+            cfg,
+            http_client,
+            token: Mutex::new(None),
+        }
+    }
+
+    /// Create a calendar event with a Google Meet conference attached.
+    #[instrument(skip(self, req), err)]
+    pub(crate) async fn create_event(
+        &self,
+        req: &CalendarEventRequest,
+    ) -> Result<CalendarEvent, GoogleMeetClientError> {
+        trace!("google meet client: create event");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| GoogleMeetClientError::Token(e.to_string()))?;
+        let url = format!(
+            "{CALENDAR_BASE_URL}/calendars/{CALENDAR_ID}/events?conferenceDataVersion=1&sendUpdates=none"
+        );
+        let response = self
+            .http_client
+            .post(&url)
+            .bearer_auth(token)
+            .json(req)
+            .send()
+            .await
+            .map_err(|e| GoogleMeetClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(GoogleMeetClientError::from_response(response).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| GoogleMeetClientError::Network(e.to_string()))
+    }
+
+    /// Delete a calendar event by ID.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn delete_event(&self, event_id: &str) -> Result<(), GoogleMeetClientError> {
+        trace!("google meet client: delete event");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| GoogleMeetClientError::Token(e.to_string()))?;
+        let url = format!("{}?sendUpdates=none", event_url(event_id));
+        let response = self
+            .http_client
+            .delete(&url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(|e| GoogleMeetClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(GoogleMeetClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// End the active conference of a Meet space, if any.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn end_active_conference(
+        &self,
+        meeting_code: &str,
+    ) -> Result<(), GoogleMeetClientError> {
+        trace!("google meet client: end active conference");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| GoogleMeetClientError::Token(e.to_string()))?;
+        let encoded_meeting_code = utf8_percent_encode(meeting_code, NON_ALPHANUMERIC).to_string();
+        let url = format!("{MEET_BASE_URL}/spaces/{encoded_meeting_code}:endActiveConference");
+        let response = self
+            .http_client
+            .post(&url)
+            .bearer_auth(token)
+            .json(&serde_json::json!({}))
+            .send()
+            .await
+            .map_err(|e| GoogleMeetClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(GoogleMeetClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// Get a calendar event by ID.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn get_event(
+        &self,
+        event_id: &str,
+    ) -> Result<CalendarEvent, GoogleMeetClientError> {
+        trace!("google meet client: get event");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| GoogleMeetClientError::Token(e.to_string()))?;
+        let url = event_url(event_id);
+        let response = self
+            .http_client
+            .get(&url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(|e| GoogleMeetClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(GoogleMeetClientError::from_response(response).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| GoogleMeetClientError::Network(e.to_string()))
+    }
+
+    /// Update an existing calendar event.
+    #[instrument(skip(self, req), err)]
+    pub(crate) async fn update_event(
+        &self,
+        event_id: &str,
+        req: &CalendarEventRequest,
+    ) -> Result<(), GoogleMeetClientError> {
+        trace!("google meet client: update event");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| GoogleMeetClientError::Token(e.to_string()))?;
+        let url = format!("{}?sendUpdates=none", event_url(event_id));
+        let response = self
+            .http_client
+            .patch(&url)
+            .bearer_auth(token)
+            .json(req)
+            .send()
+            .await
+            .map_err(|e| GoogleMeetClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(GoogleMeetClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// Fetch a new access token using a signed service account assertion.
+    #[instrument(skip(self), err)]
+    async fn fetch_token(&self) -> Result<CachedToken> {
+        trace!("google meet client: fetch token");
+
+        // Build and sign the assertion
+        let assertion = self.build_assertion(Utc::now())?;
+        let body = serde_urlencoded::to_string([
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", assertion.as_str()),
+        ])?;
+
+        // Make the token request
+        let response = self
+            .http_client
+            .post(GOOGLE_TOKEN_URL)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let error: GoogleTokenErrorResponse = response.json().await.unwrap_or_default();
+            return Err(anyhow!(
+                "google token error: {} - {}",
+                error.error,
+                error.error_description
+            ));
+        }
+
+        // Parse the token response
+        let token_response: TokenResponse = response.json().await?;
+        let expires_at = Instant::now() + Duration::from_secs(token_response.expires_in);
+
+        Ok(CachedToken {
+            access_token: token_response.access_token,
+            expires_at,
+        })
+    }
+
+    /// Build an RS256-signed JWT assertion for the service account.
+    fn build_assertion(&self, now: DateTime<Utc>) -> Result<String> {
+        // Encode header and claims
+        let header = BASE64_URL.encode(serde_json::to_vec(&AssertionHeader {
+            alg: "RS256",
+            typ: "JWT",
+        })?);
+        let claims = BASE64_URL.encode(serde_json::to_vec(&AssertionClaims {
+            aud: GOOGLE_TOKEN_URL,
+            exp: now.timestamp() + i64::try_from(TOKEN_ASSERTION_LIFETIME.as_secs())?,
+            iat: now.timestamp(),
+            iss: &self.cfg.client_email,
+            scope: SCOPES.join(" "),
+            sub: &self.cfg.delegated_user,
+        })?);
+        let signing_input = format!("{header}.{claims}");
+
+        // Sign the assertion with the service account private key
+        let key = PKey::private_key_from_pem(self.cfg.private_key.as_bytes())?;
+        let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+        signer.update(signing_input.as_bytes())?;
+        let signature = BASE64_URL.encode(signer.sign_to_vec()?);
+
+        Ok(format!("{signing_input}.{signature}"))
+    }
+
+    /// Get a valid access token, fetching a new one if needed.
+    async fn get_token(&self) -> Result<String> {
+        // Check if we have a valid cached token
+        let mut token_guard = self.token.lock().await;
+        if let Some(ref cached) = *token_guard
+            && Instant::now() + TOKEN_EXPIRY_MARGIN < cached.expires_at
+        {
+            return Ok(cached.access_token.clone());
+        }
+
+        // Fetch a new token
+        let new_token = self.fetch_token().await?;
+        let access_token = new_token.access_token.clone();
+        *token_guard = Some(new_token);
+
+        Ok(access_token)
+    }
+}
+
+/// JWT header used in service account assertions.
+#[derive(Serialize)]
+struct AssertionHeader {
+    /// Signing algorithm.
+    alg: &'static str,
+    /// Token type.
+    typ: &'static str,
+}
+
+/// JWT claims used in service account assertions.
+#[derive(Serialize)]
+struct AssertionClaims<'a> {
+    /// Token endpoint the assertion is intended for.
+    aud: &'static str,
+    /// Assertion expiration (unix timestamp).
+    exp: i64,
+    /// Assertion issue time (unix timestamp).
+    iat: i64,
+    /// Service account email issuing the assertion.
+    iss: &'a str,
+    /// Space-separated list of requested scopes.
+    scope: String,
+    /// Workspace user impersonated by the service account.
+    sub: &'a str,
+}
+
+/// Cached OAuth access token with expiry tracking.
+struct CachedToken {
+    /// OAuth access token.
+    access_token: String,
+    /// Time when the token becomes invalid for reuse.
+    expires_at: Instant,
+}
+
+/// Calendar event returned by the Google Calendar API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CalendarEvent {
+    /// Calendar event identifier.
+    pub id: String,
+
+    /// Conference details attached to the event.
+    pub conference_data: Option<ConferenceData>,
+    /// Google Meet join URL.
+    pub hangout_link: Option<String>,
+}
+
+impl CalendarEvent {
+    /// Returns the Meet meeting code attached to this event, if any.
+    pub(crate) fn meeting_code(&self) -> Option<&str> {
+        self.conference_data
+            .as_ref()
+            .and_then(|data| data.conference_id.as_deref())
+    }
+}
+
+/// Request body used to create or update a calendar event.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CalendarEventRequest {
+    /// Event end time.
+    pub end: CalendarEventDateTime,
+    /// Event start time.
+    pub start: CalendarEventDateTime,
+    /// Event title.
+    pub summary: String,
+
+    /// Conference creation request (only sent on create).
+    pub conference_data: Option<ConferenceDataRequest>,
+}
+
+impl CalendarEventRequest {
+    /// Build a create request that asks Google to attach a Meet conference.
+    pub(crate) fn for_create(m: &Meeting) -> Result<Self, GoogleMeetClientError> {
+        let mut req = Self::for_update(m)?;
+        req.conference_data = Some(ConferenceDataRequest {
+            create_request: CreateConferenceRequest {
+                conference_solution_key: ConferenceSolutionKey {
+                    solution_type: CONFERENCE_SOLUTION_TYPE.to_string(),
+                },
+                request_id: Uuid::new_v4().to_string(),
+            },
+        });
+        Ok(req)
+    }
+
+    /// Build an update request with the meeting title and schedule.
+    pub(crate) fn for_update(m: &Meeting) -> Result<Self, GoogleMeetClientError> {
+        let starts_at = m.starts_at.ok_or(GoogleMeetClientError::InvalidSchedule)?;
+        let ends_at = m.ends_at().ok_or(GoogleMeetClientError::InvalidSchedule)?;
+
+        Ok(Self {
+            end: CalendarEventDateTime::new(ends_at, m.timezone.clone()),
+            start: CalendarEventDateTime::new(starts_at, m.timezone.clone()),
+            summary: m.topic.clone().unwrap_or_default(),
+
+            conference_data: None,
+        })
+    }
+}
+
+/// Calendar event date and time.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CalendarEventDateTime {
+    /// RFC 3339 timestamp.
+    pub date_time: DateTime<Utc>,
+
+    /// IANA timezone used to display the event.
+    pub time_zone: Option<String>,
+}
+
+impl CalendarEventDateTime {
+    /// Create a new calendar date time.
+    fn new(date_time: DateTime<Utc>, time_zone: Option<String>) -> Self {
+        Self {
+            date_time,
+            time_zone,
+        }
+    }
+}
+
+/// Conference details returned for a calendar event.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConferenceData {
+    /// Meet meeting code (e.g. `abc-defg-hij`).
+    pub conference_id: Option<String>,
+}
+
+/// Conference data sent when creating a calendar event.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConferenceDataRequest {
+    /// Conference creation request.
+    pub create_request: CreateConferenceRequest,
+}
+
+/// Conference solution key.
+#[derive(Debug, Serialize)]
+pub(crate) struct ConferenceSolutionKey {
+    /// Conference solution type.
+    #[serde(rename = "type")]
+    pub solution_type: String,
+}
+
+/// Request to create a new conference for a calendar event.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateConferenceRequest {
+    /// Conference solution to use.
+    pub conference_solution_key: ConferenceSolutionKey,
+    /// Client-generated unique identifier for this request.
+    pub request_id: String,
+}
+
+/// Response from Google's OAuth token endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    /// OAuth access token.
+    access_token: String,
+    /// Token lifetime in seconds.
+    expires_in: u64,
+}
+
+/// Error response from Google's OAuth token endpoint.
+#[derive(Debug, Default, Deserialize)]
+struct GoogleTokenErrorResponse {
+    /// OAuth error code.
+    #[serde(default)]
+    error: String,
+    /// OAuth error description.
+    #[serde(default)]
+    error_description: String,
+}
+
+/// Error types from Google Meet client calls.
+#[derive(Debug)]
+pub(crate) enum GoogleMeetClientError {
+    /// Non-retryable client errors (4xx except 401, 403, 404 and 429).
+    Client { status: String, message: String },
+    /// Meeting is missing its start time or duration.
+    InvalidSchedule,
+    /// Network or connection errors (retryable).
+    Network(String),
+    /// Requested resource does not exist.
+    NotFound,
+    /// Rate limit exceeded (retryable after delay).
+    RateLimit { retry_after: Duration },
+    /// Server errors (5xx, retryable).
+    Server { status: String, message: String },
+    /// Token fetch error (retryable).
+    Token(String),
+}
+
+impl std::fmt::Display for GoogleMeetClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Client { status, message } => {
+                write!(f, "google meet client error: {status} - {message}")
+            }
+            Self::InvalidSchedule => write!(f, "missing meeting start time or duration"),
+            Self::Network(msg) => write!(f, "google meet network error: {msg}"),
+            Self::NotFound => write!(f, "google meet resource not found"),
+            Self::RateLimit { retry_after } => {
+                write!(
+                    f,
+                    "google meet rate limit exceeded (retry after {}s)",
+                    retry_after.as_secs()
+                )
+            }
+            Self::Server { status, message } => {
+                write!(f, "google meet server error: {status} - {message}")
+            }
+            Self::Token(msg) => write!(f, "google meet token error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GoogleMeetClientError {}
+
+impl From<GoogleMeetClientError> for MeetingProviderError {
+    fn from(e: GoogleMeetClientError) -> Self {
+        match e {
+            GoogleMeetClientError::Client { status, message } => {
+                Self::Client(format!("{status}: {message}"))
+            }
+            GoogleMeetClientError::InvalidSchedule => {
+                Self::Client("missing meeting start time or duration".to_string())
+            }
+            GoogleMeetClientError::Network(msg) => Self::Network(msg),
+            GoogleMeetClientError::NotFound => Self::NotFound,
+            GoogleMeetClientError::RateLimit { retry_after } => Self::RateLimit { retry_after },
+            GoogleMeetClientError::Server { status, message } => {
+                Self::Server(format!("{status}: {message}"))
+            }
+            GoogleMeetClientError::Token(msg) => Self::Token(msg),
+        }
+    }
+}
+
+impl GoogleMeetClientError {
+    /// Returns true if the error reports that no conference is active.
+    pub(crate) fn is_no_active_conference(&self) -> bool {
+        matches!(self, Self::Client { status, .. } if status == GOOGLE_FAILED_PRECONDITION)
+    }
+
+    /// Create error from HTTP response status and body.
+    async fn from_response(response: reqwest::Response) -> Self {
+        // Parse Retry-After header before consuming response body
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok())
+            .map_or(DEFAULT_RATE_LIMIT_RETRY, Duration::from_secs);
+
+        // Get status and parse error body
+        let status = response.status();
+        let error = response
+            .json::<GoogleApiErrorResponse>()
+            .await
+            .unwrap_or_default()
+            .error;
+
+        // Determine error type based on status code
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Self::RateLimit { retry_after }
+        } else if status == reqwest::StatusCode::NOT_FOUND || status == reqwest::StatusCode::GONE {
+            Self::NotFound
+        } else if status == reqwest::StatusCode::UNAUTHORIZED
+            || status == reqwest::StatusCode::FORBIDDEN
+        {
+            // Auth errors are retryable (token may have expired)
+            Self::Token(format!("{} - {}", error.status, error.message))
+        } else if status.is_client_error() {
+            Self::Client {
+                status: error.status,
+                message: error.message,
+            }
+        } else {
+            Self::Server {
+                status: error.status,
+                message: error.message,
+            }
+        }
+    }
+}
+
+/// Error response envelope returned by Google APIs.
+#[derive(Debug, Default, Deserialize)]
+struct GoogleApiErrorResponse {
+    /// Error details.
+    #[serde(default)]
+    error: GoogleApiError,
+}
+
+/// Error details returned by Google APIs.
+#[derive(Debug, Default, Deserialize)]
+struct GoogleApiError {
+    /// Human readable error message.
+    #[serde(default)]
+    message: String,
+    /// Canonical error status (e.g. `FAILED_PRECONDITION`).
+    #[serde(default)]
+    status: String,
+}
+
+/// Returns the URL of a calendar event on the hosting calendar.
+fn event_url(event_id: &str) -> String {
+    let encoded_event_id = utf8_percent_encode(event_id, NON_ALPHANUMERIC).to_string();
+    format!("{CALENDAR_BASE_URL}/calendars/{CALENDAR_ID}/events/{encoded_event_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    use crate::services::meetings::Meeting;
+
+    use super::{CalendarEventRequest, GoogleMeetClientError};
+
+    #[test]
+    fn create_request_includes_meet_conference_request() {
+        let request = CalendarEventRequest::for_create(&sample_meeting()).unwrap();
+        let value = serde_json::to_value(request).unwrap();
+
+        assert_eq!(
+            value["conferenceData"]["createRequest"]["conferenceSolutionKey"]["type"],
+            json!("hangoutsMeet")
+        );
+        assert!(value["conferenceData"]["createRequest"]["requestId"].is_string());
+    }
+
+    #[test]
+    fn create_request_requires_start_time() {
+        let meeting = Meeting {
+            starts_at: None,
+            ..sample_meeting()
+        };
+
+        assert!(matches!(
+            CalendarEventRequest::for_create(&meeting),
+            Err(GoogleMeetClientError::InvalidSchedule)
+        ));
+    }
+
+    #[test]
+    fn update_request_sets_schedule_and_summary() {
+        let request = CalendarEventRequest::for_update(&sample_meeting()).unwrap();
+        let value = serde_json::to_value(request).unwrap();
+
+        assert_eq!(value["summary"], json!("Community call"));
+        assert_eq!(value["start"]["dateTime"], json!("2030-01-01T10:00:00Z"));
+        assert_eq!(value["end"]["dateTime"], json!("2030-01-01T11:00:00Z"));
+        assert_eq!(value["start"]["timeZone"], json!("Europe/Madrid"));
+        assert!(value.get("conferenceData").is_none());
+    }
+
+    // Helpers.
+
+    fn sample_meeting() -> Meeting {
+        Meeting {
+            duration: Some(Duration::from_hours(1)),
+            starts_at: Some(chrono::Utc.with_ymd_and_hms(2030, 1, 1, 10, 0, 0).unwrap()),
+            timezone: Some("Europe/Madrid".to_string()),
+            topic: Some("Community call".to_string()),
+            ..Default::default()
+        }
+    }
+}
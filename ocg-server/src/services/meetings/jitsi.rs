@@ -0,0 +1,264 @@
+//! Jitsi-backed meetings provider implementation.
+//!
+//! Jitsi rooms are created on demand when the first participant joins, so this
+//! provider only generates room URLs. When the deployment is secured with token
+//! authentication, a room-scoped JWT is appended to the join URL.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL};
+use chrono::Utc;
+use hmac::{Hmac, KeyInit, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::config::MeetingsJitsiConfig;
+
+use super::{
+    Meeting, MeetingEndResult, MeetingParticipant, MeetingProviderError, MeetingProviderMeeting,
+    MeetingRecording, MeetingsProvider,
+};
+
+/// Audience expected by Jitsi token authentication.
+const JWT_AUDIENCE: &str = "jitsi";
+
+/// Lifetime of the tokens embedded in join URLs.
+const JWT_LIFETIME: Duration = Duration::from_hours(24 * 365);
+
+/// Prefix used for generated room names.
+const ROOM_PREFIX: &str = "ocg-";
+
+/// Jitsi-backed meetings provider implementation.
+pub(crate) struct JitsiMeetingsProvider {
+    /// Jitsi provider configuration.
+    cfg: MeetingsJitsiConfig,
+}
+
+impl JitsiMeetingsProvider {
+    /// Create a new `JitsiMeetingsProvider`.
+    pub(crate) fn new(cfg: &MeetingsJitsiConfig) -> Self {
+        Self { cfg: cfg.clone() }
+    }
+
+    /// Build the join URL for a room, including a token when JWT auth is enabled.
+    fn join_url(&self, room: &str) -> Result<String, MeetingProviderError> {
+        let base_url = self.cfg.base_url.trim_end_matches('/');
+        let mut join_url = format!("{base_url}/{room}");
+
+        if let (Some(app_id), Some(app_secret)) = (&self.cfg.jwt_app_id, &self.cfg.jwt_app_secret) {
+            let token = self
+                .room_token(room, app_id, app_secret)
+                .map_err(|err| MeetingProviderError::Token(err.to_string()))?;
+            join_url.push_str("?jwt=");
+            join_url.push_str(&token);
+        }
+
+        Ok(join_url)
+    }
+
+    /// Build an HS256-signed token granting access to a single room.
+    fn room_token(&self, room: &str, app_id: &str, app_secret: &str) -> Result<String> {
+        // Encode header and claims
+        let now = Utc::now().timestamp();
+        let header = BASE64_URL.encode(serde_json::to_vec(&JwtHeader {
+            alg: "HS256",
+            typ: "JWT",
+        })?);
+        let claims = BASE64_URL.encode(serde_json::to_vec(&JwtClaims {
+            aud: JWT_AUDIENCE,
+            exp: now + i64::try_from(JWT_LIFETIME.as_secs())?,
+            iat: now,
+            iss: app_id,
+            room,
+            sub: self.domain(),
+        })?);
+        let signing_input = format!("{header}.{claims}");
+
+        // Sign the token with the shared application secret
+        let mut mac = Hmac::<Sha256>::new_from_slice(app_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(signing_input.as_bytes());
+        let signature = BASE64_URL.encode(mac.finalize().into_bytes());
+
+        Ok(format!("{signing_input}.{signature}"))
+    }
+
+    /// Returns the Jitsi domain used as token subject.
+    fn domain(&self) -> &str {
+        let without_scheme = self
+            .cfg
+            .base_url
+            .split_once("://")
+            .map_or(self.cfg.base_url.as_str(), |(_, rest)| rest);
+        without_scheme.split('/').next().unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl MeetingsProvider for JitsiMeetingsProvider {
+    /// Generate a new unguessable room for the meeting.
+    async fn create_meeting(
+        &self,
+        _meeting: &Meeting,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        let room = format!("{ROOM_PREFIX}{}", Uuid::new_v4().simple());
+        let join_url = self.join_url(&room)?;
+
+        Ok(MeetingProviderMeeting {
+            id: room,
+            join_url,
+            password: None,
+        })
+    }
+
+    /// Jitsi rooms are ephemeral, so there is nothing to delete.
+    async fn delete_meeting(&self, _provider_meeting_id: &str) -> Result<(), MeetingProviderError> {
+        Ok(())
+    }
+
+    /// Jitsi rooms close when the last participant leaves.
+    async fn end_meeting(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<MeetingEndResult, MeetingProviderError> {
+        Ok(MeetingEndResult::AlreadyNotRunning)
+    }
+
+    /// Rebuild the join URL for an existing room.
+    async fn get_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        Ok(MeetingProviderMeeting {
+            id: provider_meeting_id.to_string(),
+            join_url: self.join_url(provider_meeting_id)?,
+            password: None,
+        })
+    }
+
+    /// Jitsi does not provide participant reports.
+    async fn list_participants(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingParticipant>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Jitsi recordings are not managed by OCG.
+    async fn list_recordings(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingRecording>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Jitsi rooms have no schedule or title to update.
+    async fn update_meeting(
+        &self,
+        _provider_meeting_id: &str,
+        _meeting: &Meeting,
+    ) -> Result<(), MeetingProviderError> {
+        Ok(())
+    }
+}
+
+/// JWT header used in room tokens.
+#[derive(Serialize)]
+struct JwtHeader {
+    /// Signing algorithm.
+    alg: &'static str,
+    /// Token type.
+    typ: &'static str,
+}
+
+/// JWT claims used in room tokens.
+#[derive(Serialize)]
+struct JwtClaims<'a> {
+    /// Token audience.
+    aud: &'static str,
+    /// Token expiration (unix timestamp).
+    exp: i64,
+    /// Token issue time (unix timestamp).
+    iat: i64,
+    /// Application identifier configured in the Jitsi deployment.
+    iss: &'a str,
+    /// Room the token grants access to.
+    room: &'a str,
+    /// Jitsi domain.
+    sub: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL};
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
+    use crate::{
+        config::MeetingsJitsiConfig,
+        services::meetings::{Meeting, MeetingsProvider},
+    };
+
+    use super::JitsiMeetingsProvider;
+
+    #[tokio::test]
+    async fn create_meeting_generates_room_url() {
+        let provider = JitsiMeetingsProvider::new(&sample_cfg(None));
+        let meeting = provider.create_meeting(&Meeting::default()).await.unwrap();
+
+        assert!(meeting.id.starts_with("ocg-"));
+        assert_eq!(
+            meeting.join_url,
+            format!("https://meet.example.test/{}", meeting.id)
+        );
+        assert!(meeting.password.is_none());
+    }
+
+    #[tokio::test]
+    async fn create_meeting_generates_unique_rooms() {
+        let provider = JitsiMeetingsProvider::new(&sample_cfg(None));
+        let first = provider.create_meeting(&Meeting::default()).await.unwrap();
+        let second = provider.create_meeting(&Meeting::default()).await.unwrap();
+
+        assert_ne!(first.id, second.id);
+    }
+
+    #[tokio::test]
+    async fn get_meeting_appends_signed_room_token() {
+        let provider = JitsiMeetingsProvider::new(&sample_cfg(Some("secret")));
+        let meeting = provider.get_meeting("ocg-room").await.unwrap();
+
+        // Check the token is appended to the room URL
+        let (url, token) = meeting.join_url.split_once("?jwt=").unwrap();
+        assert_eq!(url, "https://meet.example.test/ocg-room");
+
+        // Check the token signature and claims
+        let (signing_input, signature) = token.rsplit_once('.').unwrap();
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+        mac.update(signing_input.as_bytes());
+        assert!(mac.verify_slice(&BASE64_URL.decode(signature).unwrap()).is_ok());
+        let claims = signing_input.split('.').nth(1).unwrap();
+        let claims: serde_json::Value =
+            serde_json::from_slice(&BASE64_URL.decode(claims).unwrap()).unwrap();
+        assert_eq!(claims["aud"], "jitsi");
+        assert_eq!(claims["iss"], "ocg");
+        assert_eq!(claims["room"], "ocg-room");
+        assert_eq!(claims["sub"], "meet.example.test");
+    }
+
+    // Helpers.
+
+    fn sample_cfg(jwt_app_secret: Option<&str>) -> MeetingsJitsiConfig {
+        MeetingsJitsiConfig {
+            base_url: "https://meet.example.test/".to_string(),
+            enabled: true,
+            max_participants: 100,
+
+            jwt_app_id: jwt_app_secret.map(|_| "ocg".to_string()),
+            jwt_app_secret: jwt_app_secret.map(ToString::to_string),
+        }
+    }
+}
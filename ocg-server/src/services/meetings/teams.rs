@@ -0,0 +1,120 @@
+//! Microsoft Teams-backed meetings provider implementation.
+
+use async_trait::async_trait;
+
+use crate::{
+    config::MeetingsTeamsConfig,
+    services::meetings::teams::client::{OnlineMeetingRequest, TeamsClient},
+};
+
+use super::{
+    Meeting, MeetingEndResult, MeetingParticipant, MeetingProviderError, MeetingProviderMeeting,
+    MeetingRecording, MeetingsProvider,
+};
+
+pub(crate) mod client;
+
+/// Microsoft Teams-backed meetings provider implementation.
+pub(crate) struct TeamsMeetingsProvider {
+    /// Microsoft Graph API client.
+    client: TeamsClient,
+}
+
+impl TeamsMeetingsProvider {
+    /// Create a new `TeamsMeetingsProvider`.
+    pub(crate) fn new(cfg: &MeetingsTeamsConfig) -> Self {
+        Self {
+            client: TeamsClient::new(cfg.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl MeetingsProvider for TeamsMeetingsProvider {
+    /// Create an online meeting in Teams.
+    async fn create_meeting(
+        &self,
+        meeting: &Meeting,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        let req = OnlineMeetingRequest::try_from(meeting).map_err(MeetingProviderError::from)?;
+        let online_meeting = self
+            .client
+            .create_meeting(&req)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        Ok(MeetingProviderMeeting {
+            password: online_meeting.passcode(),
+            id: online_meeting.id,
+            join_url: online_meeting.join_web_url,
+        })
+    }
+
+    /// Delete an online meeting from Teams.
+    async fn delete_meeting(&self, provider_meeting_id: &str) -> Result<(), MeetingProviderError> {
+        self.client
+            .delete_meeting(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+
+    /// Teams online meetings cannot be ended through Graph, so only check they exist.
+    async fn end_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingEndResult, MeetingProviderError> {
+        self.client
+            .get_meeting(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        Ok(MeetingEndResult::AlreadyNotRunning)
+    }
+
+    /// Get meeting details from Teams.
+    async fn get_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        let online_meeting = self
+            .client
+            .get_meeting(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        Ok(MeetingProviderMeeting {
+            password: online_meeting.passcode(),
+            id: online_meeting.id,
+            join_url: online_meeting.join_web_url,
+        })
+    }
+
+    /// Teams attendance reports are not synced yet.
+    async fn list_participants(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingParticipant>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Teams recordings are not synced yet.
+    async fn list_recordings(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingRecording>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Update an online meeting in Teams.
+    async fn update_meeting(
+        &self,
+        provider_meeting_id: &str,
+        meeting: &Meeting,
+    ) -> Result<(), MeetingProviderError> {
+        let req = OnlineMeetingRequest::try_from(meeting).map_err(MeetingProviderError::from)?;
+        self.client
+            .update_meeting(provider_meeting_id, &req)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+}
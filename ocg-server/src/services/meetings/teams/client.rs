@@ -0,0 +1,545 @@
+//! Lightweight Microsoft Graph client for Teams online meeting operations.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::Client as HttpClient;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use tokio::sync::Mutex;
+use tracing::{instrument, trace};
+
+use crate::{config::MeetingsTeamsConfig, services::meetings::Meeting};
+
+use super::MeetingProviderError;
+
+/// Base URL for Microsoft Graph v1.0.
+const BASE_URL: &str = "https://graph.microsoft.com/v1.0";
+
+/// Default retry delay when Graph doesn't provide Retry-After header.
+const DEFAULT_RATE_LIMIT_RETRY: Duration = Duration::from_mins(1);
+
+/// Scope requested for application access tokens.
+const GRAPH_SCOPE: &str = "https://graph.microsoft.com/.default";
+
+/// Timeout for HTTP requests to Microsoft Graph.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Margin before token expiry to trigger refresh.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_mins(5);
+
+/// Microsoft identity platform token endpoint base URL.
+const TOKEN_URL_BASE: &str = "https://login.microsoftonline.com";
+
+/// Microsoft Graph client for Teams online meeting CRUD operations.
+pub(crate) struct TeamsClient {
+    /// Teams provider configuration.
+    cfg: MeetingsTeamsConfig,
+    /// HTTP client used for Graph API calls.
+    http_client: HttpClient,
+
+    /// Cached OAuth token.
+    token: Mutex<Option<CachedToken>>,
+}
+
+impl TeamsClient {
+    /// Create a new Teams client.
+    pub(crate) fn new(cfg: MeetingsTeamsConfig) -> Self {
+        let http_client = HttpClient::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+
+        Self {
+            cfg,
+            http_client,
+            token: Mutex::new(None),
+        }
+    }
+
+    /// Create a new online meeting for the configured organizer.
+    #[instrument(skip(self, req), err)]
+    pub(crate) async fn create_meeting(
+        &self,
+        req: &OnlineMeetingRequest,
+    ) -> Result<OnlineMeeting, TeamsClientError> {
+        trace!("teams client: create meeting");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| TeamsClientError::Token(e.to_string()))?;
+        let url = format!("{}/onlineMeetings", self.organizer_url());
+        let response = self
+            .http_client
+            .post(&url)
+            .bearer_auth(token)
+            .json(req)
+            .send()
+            .await
+            .map_err(|e| TeamsClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(TeamsClientError::from_response(response).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| TeamsClientError::Network(e.to_string()))
+    }
+
+    /// Delete an online meeting by ID.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn delete_meeting(&self, meeting_id: &str) -> Result<(), TeamsClientError> {
+        trace!("teams client: delete meeting");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| TeamsClientError::Token(e.to_string()))?;
+        let url = self.meeting_url(meeting_id);
+        let response = self
+            .http_client
+            .delete(&url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(|e| TeamsClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(TeamsClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// Get an online meeting by ID.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn get_meeting(
+        &self,
+        meeting_id: &str,
+    ) -> Result<OnlineMeeting, TeamsClientError> {
+        trace!("teams client: get meeting");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| TeamsClientError::Token(e.to_string()))?;
+        let url = self.meeting_url(meeting_id);
+        let response = self
+            .http_client
+            .get(&url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(|e| TeamsClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(TeamsClientError::from_response(response).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| TeamsClientError::Network(e.to_string()))
+    }
+
+    /// Update an existing online meeting.
+    #[instrument(skip(self, req), err)]
+    pub(crate) async fn update_meeting(
+        &self,
+        meeting_id: &str,
+        req: &OnlineMeetingRequest,
+    ) -> Result<(), TeamsClientError> {
+        trace!("teams client: update meeting");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| TeamsClientError::Token(e.to_string()))?;
+        let url = self.meeting_url(meeting_id);
+        let response = self
+            .http_client
+            .patch(&url)
+            .bearer_auth(token)
+            .json(req)
+            .send()
+            .await
+            .map_err(|e| TeamsClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(TeamsClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// Fetch a new access token using the client credentials grant.
+    #[instrument(skip(self), err)]
+    async fn fetch_token(&self) -> Result<CachedToken> {
+        trace!("teams client: fetch token");
+
+        // Setup token request
+        let url = format!("{TOKEN_URL_BASE}/{}/oauth2/v2.0/token", self.cfg.tenant_id);
+        let body = serde_urlencoded::to_string([
+            ("client_id", self.cfg.client_id.as_str()),
+            ("client_secret", self.cfg.client_secret.as_str()),
+            ("grant_type", "client_credentials"),
+            ("scope", GRAPH_SCOPE),
+        ])?;
+
+        // Make the token request
+        let response = self
+            .http_client
+            .post(&url)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let error: TokenErrorResponse = response.json().await.unwrap_or_default();
+            return Err(anyhow!(
+                "teams token error: {} - {}",
+                error.error,
+                error.error_description
+            ));
+        }
+
+        // Parse the token response
+        let token_response: TokenResponse = response.json().await?;
+        let expires_at = Instant::now() + Duration::from_secs(token_response.expires_in);
+
+        Ok(CachedToken {
+            access_token: token_response.access_token,
+            expires_at,
+        })
+    }
+
+    /// Get a valid access token, fetching a new one if needed.
+    async fn get_token(&self) -> Result<String> {
+        // Check if we have a valid cached token
+        let mut token_guard = self.token.lock().await;
+        if let Some(ref cached) = *token_guard
+            && Instant::now() + TOKEN_EXPIRY_MARGIN < cached.expires_at
+        {
+            return Ok(cached.access_token.clone());
+        }
+
+        // Fetch a new token
+        let new_token = self.fetch_token().await?;
+        let access_token = new_token.access_token.clone();
+        *token_guard = Some(new_token);
+
+        Ok(access_token)
+    }
+
+    /// Returns the URL of an online meeting owned by the organizer.
+    fn meeting_url(&self, meeting_id: &str) -> String {
+        let encoded_meeting_id = utf8_percent_encode(meeting_id, NON_ALPHANUMERIC).to_string();
+        format!(
+            "{}/onlineMeetings/{encoded_meeting_id}",
+            self.organizer_url()
+        )
+    }
+
+    /// Returns the Graph URL of the organizer user.
+    fn organizer_url(&self) -> String {
+        let encoded_user = utf8_percent_encode(&self.cfg.organizer_user, NON_ALPHANUMERIC);
+        format!("{BASE_URL}/users/{encoded_user}")
+    }
+}
+
+/// Cached OAuth access token with expiry tracking.
+struct CachedToken {
+    /// OAuth access token.
+    access_token: String,
+    /// Time when the token becomes invalid for reuse.
+    expires_at: Instant,
+}
+
+/// Online meeting returned by Microsoft Graph.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OnlineMeeting {
+    /// Provider-assigned meeting identifier.
+    pub id: String,
+    /// Meeting join URL.
+    pub join_web_url: String,
+
+    /// Meeting ID and passcode settings.
+    pub join_meeting_id_settings: Option<JoinMeetingIdSettings>,
+}
+
+impl OnlineMeeting {
+    /// Returns the meeting passcode, if any.
+    pub(crate) fn passcode(&self) -> Option<String> {
+        self.join_meeting_id_settings
+            .as_ref()
+            .and_then(|settings| settings.passcode.clone())
+    }
+}
+
+/// Join meeting ID settings returned by Microsoft Graph.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JoinMeetingIdSettings {
+    /// Passcode required to join with the meeting ID.
+    pub passcode: Option<String>,
+}
+
+/// Request to create or update an online meeting.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OnlineMeetingRequest {
+    /// Meeting end time.
+    pub end_date_time: DateTime<Utc>,
+    /// Meeting start time.
+    pub start_date_time: DateTime<Utc>,
+    /// Meeting subject.
+    pub subject: String,
+
+    /// Whether lobby bypass applies to everyone.
+    pub lobby_bypass_settings: Option<LobbyBypassSettings>,
+    /// Whether the meeting is recorded automatically.
+    pub record_automatically: Option<bool>,
+}
+
+impl TryFrom<&Meeting> for OnlineMeetingRequest {
+    type Error = TeamsClientError;
+
+    fn try_from(m: &Meeting) -> Result<Self, Self::Error> {
+        let starts_at = m.starts_at.ok_or(TeamsClientError::InvalidSchedule)?;
+        let ends_at = m.ends_at().ok_or(TeamsClientError::InvalidSchedule)?;
+
+        Ok(Self {
+            end_date_time: ends_at,
+            start_date_time: starts_at,
+            subject: m.topic.clone().unwrap_or_default(),
+
+            lobby_bypass_settings: Some(LobbyBypassSettings {
+                scope: "everyone".to_string(),
+            }),
+            record_automatically: Some(m.recording_requested.unwrap_or(true)),
+        })
+    }
+}
+
+/// Lobby bypass settings for an online meeting.
+#[derive(Debug, Serialize)]
+pub(crate) struct LobbyBypassSettings {
+    /// Participants allowed to bypass the lobby.
+    pub scope: String,
+}
+
+/// Response from the token endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    /// OAuth access token.
+    access_token: String,
+    /// Token lifetime in seconds.
+    expires_in: u64,
+}
+
+/// Error response from the token endpoint.
+#[derive(Debug, Default, Deserialize)]
+struct TokenErrorResponse {
+    /// OAuth error code.
+    #[serde(default)]
+    error: String,
+    /// OAuth error description.
+    #[serde(default)]
+    error_description: String,
+}
+
+/// Error types from Teams client calls.
+#[derive(Debug)]
+pub(crate) enum TeamsClientError {
+    /// Non-retryable client errors (4xx except 401, 403, 404 and 429).
+    Client { code: String, message: String },
+    /// Meeting is missing its start time or duration.
+    InvalidSchedule,
+    /// Network or connection errors (retryable).
+    Network(String),
+    /// Online meeting does not exist.
+    NotFound,
+    /// Rate limit exceeded (retryable after delay).
+    RateLimit { retry_after: Duration },
+    /// Server errors (5xx, retryable).
+    Server { code: String, message: String },
+    /// Token fetch error (retryable).
+    Token(String),
+}
+
+impl std::fmt::Display for TeamsClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Client { code, message } => {
+                write!(f, "teams client error: {code} - {message}")
+            }
+            Self::InvalidSchedule => write!(f, "missing meeting start time or duration"),
+            Self::Network(msg) => write!(f, "teams network error: {msg}"),
+            Self::NotFound => write!(f, "teams meeting not found"),
+            Self::RateLimit { retry_after } => {
+                write!(
+                    f,
+                    "teams rate limit exceeded (retry after {}s)",
+                    retry_after.as_secs()
+                )
+            }
+            Self::Server { code, message } => {
+                write!(f, "teams server error: {code} - {message}")
+            }
+            Self::Token(msg) => write!(f, "teams token error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for TeamsClientError {}
+
+impl From<TeamsClientError> for MeetingProviderError {
+    fn from(e: TeamsClientError) -> Self {
+        match e {
+            TeamsClientError::Client { code, message } => {
+                Self::Client(format!("{code}: {message}"))
+            }
+            TeamsClientError::InvalidSchedule => {
+                Self::Client("missing meeting start time or duration".to_string())
+            }
+            TeamsClientError::Network(msg) => Self::Network(msg),
+            TeamsClientError::NotFound => Self::NotFound,
+            TeamsClientError::RateLimit { retry_after } => Self::RateLimit { retry_after },
+            TeamsClientError::Server { code, message } => {
+                Self::Server(format!("{code}: {message}"))
+            }
+            TeamsClientError::Token(msg) => Self::Token(msg),
+        }
+    }
+}
+
+impl TeamsClientError {
+    /// Create error from HTTP response status and body.
+    async fn from_response(response: reqwest::Response) -> Self {
+        // Parse Retry-After header before consuming response body
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok())
+            .map_or(DEFAULT_RATE_LIMIT_RETRY, Duration::from_secs);
+
+        // Get status and parse error body
+        let status = response.status();
+        let error = response.json::<GraphErrorResponse>().await.unwrap_or_default().error;
+
+        // Determine error type based on status code
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Self::RateLimit { retry_after }
+        } else if status == reqwest::StatusCode::NOT_FOUND {
+            Self::NotFound
+        } else if status == reqwest::StatusCode::UNAUTHORIZED
+            || status == reqwest::StatusCode::FORBIDDEN
+        {
+            // Auth errors are retryable (token may have expired)
+            Self::Token(format!("{} - {}", error.code, error.message))
+        } else if status.is_client_error() {
+            Self::Client {
+                code: error.code,
+                message: error.message,
+            }
+        } else {
+            Self::Server {
+                code: error.code,
+                message: error.message,
+            }
+        }
+    }
+}
+
+/// Error response envelope returned by Microsoft Graph.
+#[derive(Debug, Default, Deserialize)]
+struct GraphErrorResponse {
+    /// Error details.
+    #[serde(default)]
+    error: GraphError,
+}
+
+/// Error details returned by Microsoft Graph.
+#[derive(Debug, Default, Deserialize)]
+struct GraphError {
+    /// Graph error code.
+    #[serde(default)]
+    code: String,
+    /// Graph error message.
+    #[serde(default)]
+    message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    use crate::services::meetings::Meeting;
+
+    use super::{OnlineMeetingRequest, TeamsClientError};
+
+    #[test]
+    fn online_meeting_request_records_automatically_by_default() {
+        let request = OnlineMeetingRequest::try_from(&sample_meeting()).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap()["recordAutomatically"],
+            json!(true)
+        );
+    }
+
+    #[test]
+    fn online_meeting_request_disables_recording_when_not_requested() {
+        let request = OnlineMeetingRequest::try_from(&Meeting {
+            recording_requested: Some(false),
+            ..sample_meeting()
+        })
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap()["recordAutomatically"],
+            json!(false)
+        );
+    }
+
+    #[test]
+    fn online_meeting_request_requires_duration() {
+        let meeting = Meeting {
+            duration: None,
+            ..sample_meeting()
+        };
+
+        assert!(matches!(
+            OnlineMeetingRequest::try_from(&meeting),
+            Err(TeamsClientError::InvalidSchedule)
+        ));
+    }
+
+    #[test]
+    fn online_meeting_request_sets_schedule_and_subject() {
+        let request = OnlineMeetingRequest::try_from(&sample_meeting()).unwrap();
+        let value = serde_json::to_value(request).unwrap();
+
+        assert_eq!(value["subject"], json!("Community call"));
+        assert_eq!(value["startDateTime"], json!("2030-01-01T10:00:00Z"));
+        assert_eq!(value["endDateTime"], json!("2030-01-01T11:30:00Z"));
+    }
+
+    // Helpers.
+
+    fn sample_meeting() -> Meeting {
+        Meeting {
+            duration: Some(Duration::from_mins(90)),
+            starts_at: Some(chrono::Utc.with_ymd_and_hms(2030, 1, 1, 10, 0, 0).unwrap()),
+            topic: Some("Community call".to_string()),
+            ..Default::default()
+        }
+    }
+}
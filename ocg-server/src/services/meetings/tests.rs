@@ -2,6 +2,8 @@ use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use anyhow::anyhow;
 use chrono::Utc;
+use mockall::Sequence;
+use tokio::{sync::Notify, time::timeout};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
@@ -11,9 +13,11 @@ use crate::{
 };
 
 use super::{
-    DynMeetingsProvider, Meeting, MeetingAutoEndCheckOutcome, MeetingEndResult, MeetingProvider,
-    MeetingProviderError, MeetingProviderMeeting, MeetingsAutoEndWorker,
-    MeetingsClaimRecoveryWorker, MeetingsSyncWorker, MockMeetingsProvider, SyncAction, SyncError,
+    DynMeetingsProvider, Meeting, MeetingAutoEndCheckOutcome, MeetingEndResult, MeetingParticipant,
+    MeetingProvider, MeetingProviderError, MeetingProviderMeeting, MeetingRecording,
+    MeetingsAttendanceWorker, MeetingsAutoEndWorker, MeetingsClaimRecoveryWorker,
+    MeetingsRecordingWorker, MeetingsSyncWorker, MockMeetingsProvider, SyncAction, SyncError,
+    circuit_breaker::ProviderCircuitBreakers, zoom::GroupZoomProviders,
 };
 
 // MeetingProviderError tests.
@@ -137,6 +141,224 @@ fn test_meeting_sync_action_update() {
 
 // Meetings workers tests.
 
+#[tokio::test]
+async fn test_worker_sync_attendance_checks_in_participants() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_attendance_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_attendance_candidate(claimed_at, meeting_id))));
+    db.expect_set_meeting_attendance_synced()
+        .times(1)
+        .withf(move |candidate, participants_emails| {
+            candidate.attendance_sync_claimed_at == claimed_at
+                && candidate.meeting_id == meeting_id
+                && participants_emails
+                    == ["one@example.com".to_string(), "two@example.com".to_string()]
+        })
+        .returning(|_, _| Ok(2));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_participants()
+        .times(1)
+        .withf(|provider_meeting_id| provider_meeting_id == "123123123")
+        .returning(|_| {
+            Box::pin(async {
+                Ok(vec![
+                    MeetingParticipant {
+                        email: "one@example.com".to_string(),
+                    },
+                    MeetingParticipant {
+                        email: "two@example.com".to_string(),
+                    },
+                ])
+            })
+        });
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync attendance
+    let worker = sample_attendance_worker(db, mp);
+    let processed = worker.sync_attendance().await.unwrap();
+
+    // Check result matches expectations
+    assert!(processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_attendance_no_pending_meeting() {
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_attendance_sync()
+        .times(1)
+        .returning(|| Ok(None));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_participants().never();
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync attendance
+    let worker = sample_attendance_worker(db, mp);
+    let processed = worker.sync_attendance().await.unwrap();
+
+    // Check result matches expectations
+    assert!(!processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_attendance_non_retryable_error_completes_claim() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_attendance_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_attendance_candidate(claimed_at, meeting_id))));
+    db.expect_release_meeting_attendance_sync_claim().never();
+    db.expect_set_meeting_attendance_synced()
+        .times(1)
+        .withf(move |candidate, participants_emails| {
+            candidate.meeting_id == meeting_id && participants_emails.is_empty()
+        })
+        .returning(|_, _| Ok(0));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_participants()
+        .times(1)
+        .returning(|_| Box::pin(async { Err(MeetingProviderError::NotFound) }));
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync attendance
+    let worker = sample_attendance_worker(db, mp);
+    let processed = worker.sync_attendance().await.unwrap();
+
+    // Check result matches expectations
+    assert!(processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_attendance_provider_not_configured_completes_claim() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_attendance_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_attendance_candidate(claimed_at, meeting_id))));
+    db.expect_set_meeting_attendance_synced()
+        .times(1)
+        .withf(move |candidate, participants_emails| {
+            candidate.meeting_id == meeting_id && participants_emails.is_empty()
+        })
+        .returning(|_, _| Ok(0));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup worker with no providers configured
+    let worker = sample_attendance_worker_no_providers(db);
+    let processed = worker.sync_attendance().await.unwrap();
+
+    // Check result matches expectations
+    assert!(processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_attendance_retryable_error_releases_claim() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_attendance_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_attendance_candidate(claimed_at, meeting_id))));
+    db.expect_set_meeting_attendance_synced().never();
+    db.expect_release_meeting_attendance_sync_claim()
+        .times(1)
+        .withf(move |candidate| {
+            candidate.attendance_sync_claimed_at == claimed_at && candidate.meeting_id == meeting_id
+        })
+        .returning(|_| Ok(()));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_participants().times(1).returning(|_| {
+        Box::pin(async { Err(MeetingProviderError::Network("timeout".to_string())) })
+    });
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync attendance
+    let worker = sample_attendance_worker(db, mp);
+    let result = worker.sync_attendance().await;
+
+    // Check result is a retryable provider error with a backoff
+    assert!(matches!(
+        result,
+        Err(SyncError::ProviderUnavailable {
+            err: MeetingProviderError::Network(_),
+            ..
+        })
+    ));
+    assert!(result.unwrap_err().retry_after().is_some());
+}
+
+#[tokio::test]
+async fn test_worker_sync_meeting_unavailable_provider_opens_circuit() {
+    // Setup identifiers and data structures
+    let meeting = Meeting {
+        meeting_id: Some(Uuid::new_v4()),
+        provider_meeting_id: Some("123456789".to_string()),
+        ..Default::default()
+    };
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    let mut seq = Sequence::new();
+    db.expect_claim_meeting_out_of_sync()
+        .times(5)
+        .in_sequence(&mut seq)
+        .withf(|paused_providers| paused_providers.is_empty())
+        .returning(move |_| Ok(Some(meeting.clone())));
+    db.expect_claim_meeting_out_of_sync()
+        .times(1)
+        .in_sequence(&mut seq)
+        .withf(|paused_providers| paused_providers == [MeetingProvider::Zoom])
+        .returning(|_| Ok(None));
+    db.expect_release_meeting_sync_claim().times(5).returning(|_| Ok(()));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_update_meeting().times(5).returning(|_, _| {
+        Box::pin(async { Err(MeetingProviderError::Server("unavailable".to_string())) })
+    });
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync meetings until the provider circuit opens
+    let mut worker = sample_sync_worker(db, mp);
+    for _ in 0..5 {
+        assert!(worker.sync_meeting().await.is_err());
+    }
+    let result = worker.sync_meeting().await;
+
+    // Check meetings of the paused provider are no longer claimed
+    assert!(matches!(result, Ok(false)));
+}
+
 #[tokio::test]
 async fn test_worker_auto_end_meeting_auto_ended() {
     // Setup identifiers and data structures
@@ -149,6 +371,7 @@ async fn test_worker_auto_end_meeting_auto_ended() {
     db.expect_claim_meeting_for_auto_end().times(1).returning(move || {
         Ok(Some(crate::db::meetings::MeetingAutoEndCandidate {
             auto_end_check_claimed_at: claimed_at,
+            group_id: None,
             meeting_id,
             provider: MeetingProvider::Zoom,
             provider_meeting_id: provider_meeting_id.clone(),
@@ -191,6 +414,7 @@ async fn test_worker_auto_end_meeting_not_found_records_not_found_outcome() {
     db.expect_claim_meeting_for_auto_end().times(1).returning(move || {
         Ok(Some(crate::db::meetings::MeetingAutoEndCandidate {
             auto_end_check_claimed_at: claimed_at,
+            group_id: None,
             meeting_id,
             provider: MeetingProvider::Zoom,
             provider_meeting_id: provider_meeting_id.clone(),
@@ -253,6 +477,7 @@ async fn test_worker_auto_end_meeting_provider_not_configured_records_error_outc
     db.expect_claim_meeting_for_auto_end().times(1).returning(move || {
         Ok(Some(crate::db::meetings::MeetingAutoEndCandidate {
             auto_end_check_claimed_at: claimed_at,
+            group_id: None,
             meeting_id,
             provider: MeetingProvider::Zoom,
             provider_meeting_id: provider_meeting_id.clone(),
@@ -288,6 +513,7 @@ async fn test_worker_auto_end_meeting_retryable_error_releases_claim() {
     db.expect_claim_meeting_for_auto_end().times(1).returning(move || {
         Ok(Some(crate::db::meetings::MeetingAutoEndCandidate {
             auto_end_check_claimed_at: claimed_at,
+            group_id: None,
             meeting_id,
             provider: MeetingProvider::Zoom,
             provider_meeting_id: provider_meeting_id.clone(),
@@ -380,6 +606,201 @@ async fn test_worker_claim_recovery_returns_sync_error() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_worker_sync_recordings_stores_recording_urls() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_recording_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_recording_candidate(claimed_at, meeting_id))));
+    db.expect_set_meeting_recordings_synced()
+        .times(1)
+        .withf(move |candidate, recording_urls| {
+            candidate.recording_sync_claimed_at == claimed_at
+                && candidate.meeting_id == meeting_id
+                && recording_urls == ["https://zoom.us/rec/share/abc".to_string()]
+        })
+        .returning(|_, _| Ok(()));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_recordings()
+        .times(1)
+        .withf(|provider_meeting_id| provider_meeting_id == "123123123")
+        .returning(|_| {
+            Box::pin(async {
+                Ok(vec![MeetingRecording {
+                    url: "https://zoom.us/rec/share/abc".to_string(),
+                }])
+            })
+        });
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync recordings
+    let worker = sample_recording_worker(db, mp);
+    let processed = worker.sync_recordings().await.unwrap();
+
+    // Check result matches expectations
+    assert!(processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_recordings_no_pending_meeting() {
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_recording_sync()
+        .times(1)
+        .returning(|| Ok(None));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_recordings().never();
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync recordings
+    let worker = sample_recording_worker(db, mp);
+    let processed = worker.sync_recordings().await.unwrap();
+
+    // Check result matches expectations
+    assert!(!processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_recordings_non_retryable_error_completes_claim() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_recording_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_recording_candidate(claimed_at, meeting_id))));
+    db.expect_release_meeting_recording_sync_claim().never();
+    db.expect_set_meeting_recordings_synced()
+        .times(1)
+        .withf(move |candidate, recording_urls| {
+            candidate.meeting_id == meeting_id && recording_urls.is_empty()
+        })
+        .returning(|_, _| Ok(()));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_recordings().times(1).returning(|_| {
+        Box::pin(async { Err(MeetingProviderError::Client("invalid scope".to_string())) })
+    });
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync recordings
+    let worker = sample_recording_worker(db, mp);
+    let processed = worker.sync_recordings().await.unwrap();
+
+    // Check result matches expectations
+    assert!(processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_recordings_not_ready_keeps_claim() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_recording_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_recording_candidate(claimed_at, meeting_id))));
+    db.expect_release_meeting_recording_sync_claim().never();
+    db.expect_set_meeting_recordings_synced().never();
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_recordings()
+        .times(1)
+        .returning(|_| Box::pin(async { Ok(vec![]) }));
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync recordings
+    let worker = sample_recording_worker(db, mp);
+    let processed = worker.sync_recordings().await.unwrap();
+
+    // Check result matches expectations
+    assert!(processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_recordings_provider_not_configured_completes_claim() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_recording_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_recording_candidate(claimed_at, meeting_id))));
+    db.expect_set_meeting_recordings_synced()
+        .times(1)
+        .withf(move |candidate, recording_urls| {
+            candidate.meeting_id == meeting_id && recording_urls.is_empty()
+        })
+        .returning(|_, _| Ok(()));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup worker with no providers configured
+    let worker = sample_recording_worker_no_providers(db);
+    let processed = worker.sync_recordings().await.unwrap();
+
+    // Check result matches expectations
+    assert!(processed);
+}
+
+#[tokio::test]
+async fn test_worker_sync_recordings_retryable_error_releases_claim() {
+    // Setup identifiers and data structures
+    let claimed_at = Utc::now();
+    let meeting_id = Uuid::new_v4();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_for_recording_sync()
+        .times(1)
+        .returning(move || Ok(Some(sample_recording_candidate(claimed_at, meeting_id))));
+    db.expect_set_meeting_recordings_synced().never();
+    db.expect_release_meeting_recording_sync_claim()
+        .times(1)
+        .withf(move |candidate| {
+            candidate.recording_sync_claimed_at == claimed_at && candidate.meeting_id == meeting_id
+        })
+        .returning(|_| Ok(()));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_list_recordings().times(1).returning(|_| {
+        Box::pin(async { Err(MeetingProviderError::Network("timeout".to_string())) })
+    });
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync recordings
+    let worker = sample_recording_worker(db, mp);
+    let result = worker.sync_recordings().await;
+
+    // Check result is a retryable provider error
+    assert!(matches!(
+        result,
+        Err(SyncError::Provider(MeetingProviderError::Network(_)))
+    ));
+}
+
 #[tokio::test]
 async fn test_worker_sync_meeting_creates_new_meeting() {
     // Setup identifiers and data structures
@@ -400,7 +821,7 @@ async fn test_worker_sync_meeting_creates_new_meeting() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_assign_zoom_host_user()
         .times(1)
         .withf(move |meeting, pool_users, max, start, end| {
@@ -461,7 +882,7 @@ async fn test_worker_sync_meeting_updates_existing_meeting() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_update_meeting()
         .times(1)
         .withf(move |m| {
@@ -516,7 +937,7 @@ async fn test_worker_sync_meeting_deletes_meeting() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_delete_meeting()
         .times(1)
         .withf(move |m| m.meeting_id == Some(meeting_id))
@@ -539,6 +960,88 @@ async fn test_worker_sync_meeting_deletes_meeting() {
     assert!(synced);
 }
 
+#[tokio::test]
+async fn test_worker_sync_meeting_group_without_zoom_credentials_uses_global_provider() {
+    // Setup identifiers and data structures
+    let group_id = Uuid::new_v4();
+    let meeting_id = Uuid::new_v4();
+    let provider_meeting_id = "zoom-789".to_string();
+    let meeting = Meeting {
+        delete: Some(true),
+        group_id: Some(group_id),
+        meeting_id: Some(meeting_id),
+        provider_meeting_id: Some(provider_meeting_id.clone()),
+        ..Default::default()
+    };
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_out_of_sync()
+        .times(1)
+        .returning(move |_| Ok(Some(meeting.clone())));
+    db.expect_get_group_zoom_credentials()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Ok(None));
+    db.expect_delete_meeting()
+        .times(1)
+        .withf(move |m| m.meeting_id == Some(meeting_id))
+        .returning(|_| Ok(()));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup meetings provider mock
+    let mut mp = MockMeetingsProvider::new();
+    mp.expect_delete_meeting()
+        .times(1)
+        .withf(move |pid| *pid == provider_meeting_id)
+        .returning(|_| Box::pin(async { Ok(()) }));
+    let mp: DynMeetingsProvider = Arc::new(mp);
+
+    // Setup worker and sync meeting
+    let mut worker = sample_sync_worker(db, mp);
+    let synced = worker.sync_meeting().await.unwrap();
+
+    // Check result matches expectations
+    assert!(synced);
+}
+
+#[tokio::test]
+async fn test_worker_sync_meeting_group_zoom_credentials_error_releases_claim() {
+    // Setup identifiers and data structures
+    let group_id = Uuid::new_v4();
+    let meeting_id = Uuid::new_v4();
+    let meeting = Meeting {
+        delete: Some(true),
+        group_id: Some(group_id),
+        meeting_id: Some(meeting_id),
+        provider_meeting_id: Some("zoom-789".to_string()),
+        ..Default::default()
+    };
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    db.expect_claim_meeting_out_of_sync()
+        .times(1)
+        .returning(move |_| Ok(Some(meeting.clone())));
+    db.expect_get_group_zoom_credentials()
+        .times(1)
+        .withf(move |id| *id == group_id)
+        .returning(|_| Err(anyhow!("db error")));
+    db.expect_release_meeting_sync_claim()
+        .times(1)
+        .withf(move |m| m.meeting_id == Some(meeting_id))
+        .returning(|_| Ok(()));
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup worker with a provider that must not be called
+    let mp: DynMeetingsProvider = Arc::new(MockMeetingsProvider::new());
+    let mut worker = sample_sync_worker(db, mp);
+    let result = worker.sync_meeting().await;
+
+    // Check result matches expectations
+    assert!(matches!(result, Err(SyncError::Other(_))));
+}
+
 #[tokio::test]
 async fn test_worker_sync_meeting_delete_not_found_succeeds() {
     // Setup identifiers and data structures
@@ -555,7 +1058,7 @@ async fn test_worker_sync_meeting_delete_not_found_succeeds() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_delete_meeting()
         .times(1)
         .withf(move |m| m.meeting_id == Some(meeting_id))
@@ -582,7 +1085,7 @@ async fn test_worker_sync_meeting_delete_not_found_succeeds() {
 async fn test_worker_sync_meeting_no_pending_meeting() {
     // Setup database mock
     let mut db = MockDBMeetings::new();
-    db.expect_claim_meeting_out_of_sync().times(1).returning(|| Ok(None));
+    db.expect_claim_meeting_out_of_sync().times(1).returning(|_| Ok(None));
     let db: DynDBMeetings = Arc::new(db);
 
     // Setup meetings provider mock
@@ -600,6 +1103,38 @@ async fn test_worker_sync_meeting_no_pending_meeting() {
     assert!(!synced);
 }
 
+#[tokio::test]
+async fn test_worker_sync_meeting_wakes_up_when_meeting_out_of_sync() {
+    // Setup cancellation token
+    let cancellation_token = CancellationToken::new();
+
+    // Setup database mock
+    let mut db = MockDBMeetings::new();
+    let mut seq = Sequence::new();
+    db.expect_claim_meeting_out_of_sync()
+        .times(1)
+        .in_sequence(&mut seq)
+        .returning(|_| Ok(None));
+    let cancellation_token_clone = cancellation_token.clone();
+    db.expect_claim_meeting_out_of_sync()
+        .times(1)
+        .in_sequence(&mut seq)
+        .returning(move |_| {
+            cancellation_token_clone.cancel();
+            Ok(None)
+        });
+    let db: DynDBMeetings = Arc::new(db);
+
+    // Setup worker and notify that a meeting is out of sync
+    let mut worker = sample_sync_worker_no_providers(db);
+    worker.cancellation_token = cancellation_token;
+    worker.meeting_out_of_sync.notify_one();
+
+    // Check the worker tries again without waiting for the polling pause
+    let result = timeout(Duration::from_secs(5), worker.run()).await;
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn test_worker_sync_meeting_retryable_error_releases_claim() {
     // Setup identifiers and data structures
@@ -616,7 +1151,7 @@ async fn test_worker_sync_meeting_retryable_error_releases_claim() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_assign_zoom_host_user()
         .times(1)
         .returning(|_, _, _, _, _| Ok(Some("host@example.com".to_string())));
@@ -658,7 +1193,7 @@ async fn test_worker_sync_meeting_non_retryable_error_records_error() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_assign_zoom_host_user()
         .times(1)
         .returning(|_, _, _, _, _| Ok(Some("host@example.com".to_string())));
@@ -706,7 +1241,7 @@ async fn test_worker_sync_meeting_no_slots_available_records_error() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_assign_zoom_host_user()
         .times(1)
         .returning(|_, _, _, _, _| Ok(None));
@@ -746,7 +1281,7 @@ async fn test_worker_sync_meeting_delete_without_provider_id() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_delete_meeting()
         .times(1)
         .withf(move |m| m.meeting_id == Some(meeting_id))
@@ -780,7 +1315,7 @@ async fn test_worker_sync_meeting_provider_not_configured_records_error() {
     let mut db = MockDBMeetings::new();
     db.expect_claim_meeting_out_of_sync()
         .times(1)
-        .returning(move || Ok(Some(meeting.clone())));
+        .returning(move |_| Ok(Some(meeting.clone())));
     db.expect_set_meeting_error()
         .times(1)
         .withf(move |m, err| {
@@ -799,6 +1334,48 @@ async fn test_worker_sync_meeting_provider_not_configured_records_error() {
 
 // Helpers.
 
+/// Create a sample attendance sync candidate.
+fn sample_attendance_candidate(
+    claimed_at: chrono::DateTime<Utc>,
+    meeting_id: Uuid,
+) -> crate::db::meetings::MeetingAttendanceCandidate {
+    crate::db::meetings::MeetingAttendanceCandidate {
+        attendance_sync_claimed_at: claimed_at,
+        event_id: Uuid::new_v4(),
+        group_id: None,
+        meeting_id,
+        provider: MeetingProvider::Zoom,
+        provider_meeting_id: "123123123".to_string(),
+    }
+}
+
+/// Create a sample attendance worker with mock dependencies.
+fn sample_attendance_worker(
+    db: DynDBMeetings,
+    mp: DynMeetingsProvider,
+) -> MeetingsAttendanceWorker {
+    let mut providers = HashMap::new();
+    providers.insert(MeetingProvider::Zoom, mp);
+    MeetingsAttendanceWorker {
+        cancellation_token: CancellationToken::new(),
+        db,
+        group_zoom_providers: Arc::new(GroupZoomProviders::default()),
+        providers: Arc::new(providers),
+        zoom_cfg: Some(sample_zoom_cfg()),
+    }
+}
+
+/// Create a sample attendance worker with no providers configured.
+fn sample_attendance_worker_no_providers(db: DynDBMeetings) -> MeetingsAttendanceWorker {
+    MeetingsAttendanceWorker {
+        cancellation_token: CancellationToken::new(),
+        db,
+        group_zoom_providers: Arc::new(GroupZoomProviders::default()),
+        providers: Arc::new(HashMap::new()),
+        zoom_cfg: Some(sample_zoom_cfg()),
+    }
+}
+
 /// Create a sample auto-end worker with mock dependencies.
 fn sample_auto_end_worker(db: DynDBMeetings, mp: DynMeetingsProvider) -> MeetingsAutoEndWorker {
     let mut providers = HashMap::new();
@@ -806,7 +1383,9 @@ fn sample_auto_end_worker(db: DynDBMeetings, mp: DynMeetingsProvider) -> Meeting
     MeetingsAutoEndWorker {
         cancellation_token: CancellationToken::new(),
         db,
+        group_zoom_providers: Arc::new(GroupZoomProviders::default()),
         providers: Arc::new(providers),
+        zoom_cfg: Some(sample_zoom_cfg()),
     }
 }
 
@@ -815,7 +1394,9 @@ fn sample_auto_end_worker_no_providers(db: DynDBMeetings) -> MeetingsAutoEndWork
     MeetingsAutoEndWorker {
         cancellation_token: CancellationToken::new(),
         db,
+        group_zoom_providers: Arc::new(GroupZoomProviders::default()),
         providers: Arc::new(HashMap::new()),
+        zoom_cfg: Some(sample_zoom_cfg()),
     }
 }
 
@@ -827,13 +1408,56 @@ fn sample_claim_recovery_worker(db: DynDBMeetings) -> MeetingsClaimRecoveryWorke
     }
 }
 
+/// Create a sample recording sync candidate.
+fn sample_recording_candidate(
+    claimed_at: chrono::DateTime<Utc>,
+    meeting_id: Uuid,
+) -> crate::db::meetings::MeetingRecordingCandidate {
+    crate::db::meetings::MeetingRecordingCandidate {
+        event_id: Uuid::new_v4(),
+        group_id: None,
+        meeting_id,
+        provider: MeetingProvider::Zoom,
+        provider_meeting_id: "123123123".to_string(),
+        recording_sync_claimed_at: claimed_at,
+        session_id: None,
+    }
+}
+
+/// Create a sample recording worker with mock dependencies.
+fn sample_recording_worker(db: DynDBMeetings, mp: DynMeetingsProvider) -> MeetingsRecordingWorker {
+    let mut providers = HashMap::new();
+    providers.insert(MeetingProvider::Zoom, mp);
+    MeetingsRecordingWorker {
+        cancellation_token: CancellationToken::new(),
+        db,
+        group_zoom_providers: Arc::new(GroupZoomProviders::default()),
+        providers: Arc::new(providers),
+        zoom_cfg: Some(sample_zoom_cfg()),
+    }
+}
+
+/// Create a sample recording worker with no providers configured.
+fn sample_recording_worker_no_providers(db: DynDBMeetings) -> MeetingsRecordingWorker {
+    MeetingsRecordingWorker {
+        cancellation_token: CancellationToken::new(),
+        db,
+        group_zoom_providers: Arc::new(GroupZoomProviders::default()),
+        providers: Arc::new(HashMap::new()),
+        zoom_cfg: Some(sample_zoom_cfg()),
+    }
+}
+
 /// Create a sample sync worker with mock dependencies.
 fn sample_sync_worker(db: DynDBMeetings, mp: DynMeetingsProvider) -> MeetingsSyncWorker {
     let mut providers = HashMap::new();
     providers.insert(MeetingProvider::Zoom, mp);
     MeetingsSyncWorker {
         cancellation_token: CancellationToken::new(),
+        circuit_breakers: Arc::new(ProviderCircuitBreakers::default()),
         db,
+        group_zoom_providers: Arc::new(GroupZoomProviders::default()),
+        meeting_out_of_sync: Arc::new(Notify::new()),
         providers: Arc::new(providers),
         zoom_cfg: Some(sample_zoom_cfg()),
     }
@@ -843,7 +1467,10 @@ fn sample_sync_worker(db: DynDBMeetings, mp: DynMeetingsProvider) -> MeetingsSyn
 fn sample_sync_worker_no_providers(db: DynDBMeetings) -> MeetingsSyncWorker {
     MeetingsSyncWorker {
         cancellation_token: CancellationToken::new(),
+        circuit_breakers: Arc::new(ProviderCircuitBreakers::default()),
         db,
+        group_zoom_providers: Arc::new(GroupZoomProviders::default()),
+        meeting_out_of_sync: Arc::new(Notify::new()),
         providers: Arc::new(HashMap::new()),
         zoom_cfg: Some(sample_zoom_cfg()),
     }
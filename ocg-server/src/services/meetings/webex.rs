@@ -0,0 +1,131 @@
+//! Cisco Webex-backed meetings provider implementation.
+
+use async_trait::async_trait;
+
+use crate::{
+    config::MeetingsWebexConfig,
+    services::meetings::webex::client::{WebexClient, WebexMeetingRequest},
+};
+
+use super::{
+    Meeting, MeetingEndResult, MeetingParticipant, MeetingProviderError, MeetingProviderMeeting,
+    MeetingRecording, MeetingsProvider,
+};
+
+pub(crate) mod client;
+
+/// Cisco Webex-backed meetings provider implementation.
+pub(crate) struct WebexMeetingsProvider {
+    /// Webex API client.
+    client: WebexClient,
+}
+
+impl WebexMeetingsProvider {
+    /// Create a new `WebexMeetingsProvider`.
+    pub(crate) fn new(cfg: &MeetingsWebexConfig) -> Self {
+        Self {
+            client: WebexClient::new(cfg.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl MeetingsProvider for WebexMeetingsProvider {
+    /// Schedule a meeting in Webex.
+    async fn create_meeting(
+        &self,
+        meeting: &Meeting,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        let req = WebexMeetingRequest::try_from(meeting).map_err(MeetingProviderError::from)?;
+        let webex_meeting = self
+            .client
+            .create_meeting(&req)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        Ok(MeetingProviderMeeting {
+            id: webex_meeting.id,
+            join_url: webex_meeting.web_link,
+            password: webex_meeting.password,
+        })
+    }
+
+    /// Delete a meeting from Webex.
+    async fn delete_meeting(&self, provider_meeting_id: &str) -> Result<(), MeetingProviderError> {
+        self.client
+            .delete_meeting(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+
+    /// Webex meetings cannot be ended through the API, so only check they exist.
+    async fn end_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingEndResult, MeetingProviderError> {
+        self.client
+            .get_meeting(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        Ok(MeetingEndResult::AlreadyNotRunning)
+    }
+
+    /// Get meeting details from Webex.
+    async fn get_meeting(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<MeetingProviderMeeting, MeetingProviderError> {
+        let webex_meeting = self
+            .client
+            .get_meeting(provider_meeting_id)
+            .await
+            .map_err(MeetingProviderError::from)?;
+
+        Ok(MeetingProviderMeeting {
+            id: webex_meeting.id,
+            join_url: webex_meeting.web_link,
+            password: webex_meeting.password,
+        })
+    }
+
+    /// Webex participant reports are not synced yet.
+    async fn list_participants(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingParticipant>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Webex recordings are not synced yet.
+    async fn list_recordings(
+        &self,
+        _provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingRecording>, MeetingProviderError> {
+        Ok(vec![])
+    }
+
+    /// Update a meeting in Webex.
+    async fn update_meeting(
+        &self,
+        provider_meeting_id: &str,
+        meeting: &Meeting,
+    ) -> Result<(), MeetingProviderError> {
+        let mut req = WebexMeetingRequest::try_from(meeting).map_err(MeetingProviderError::from)?;
+
+        // Webex requires the password on updates, so keep the current one
+        if req.password.is_none() {
+            let webex_meeting = self
+                .client
+                .get_meeting(provider_meeting_id)
+                .await
+                .map_err(MeetingProviderError::from)?;
+            req.password = webex_meeting.password;
+        }
+
+        self.client
+            .update_meeting(provider_meeting_id, &req)
+            .await
+            .map_err(MeetingProviderError::from)
+    }
+}
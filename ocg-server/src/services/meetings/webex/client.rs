@@ -0,0 +1,511 @@
+//! Lightweight Webex API client for meeting operations.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::Client as HttpClient;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use tokio::sync::Mutex;
+use tracing::{instrument, trace};
+
+use crate::{config::MeetingsWebexConfig, services::meetings::Meeting};
+
+use super::MeetingProviderError;
+
+/// Base URL for the Webex REST API.
+const BASE_URL: &str = "https://webexapis.com/v1";
+
+/// Default retry delay when Webex doesn't provide Retry-After header.
+const DEFAULT_RATE_LIMIT_RETRY: Duration = Duration::from_mins(1);
+
+/// Timeout for HTTP requests to the Webex API.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Minutes before the start time attendees can join without the host.
+const JOIN_BEFORE_HOST_MINUTES: u32 = 15;
+
+/// Margin before token expiry to trigger refresh.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_mins(5);
+
+/// Webex API client for meeting CRUD operations.
+pub(crate) struct WebexClient {
+    /// Webex provider configuration.
+    cfg: MeetingsWebexConfig,
+    /// HTTP client used for Webex API calls.
+    http_client: HttpClient,
+
+    /// OAuth token state (cached access token and latest refresh token).
+    token: Mutex<TokenState>,
+}
+
+impl WebexClient {
+    /// Create a new Webex client.
+    pub(crate) fn new(cfg: MeetingsWebexConfig) -> Self {
+        let http_client = HttpClient::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+        let token = Mutex::new(TokenState {
+            cached: None,
+            refresh_token: cfg.refresh_token.clone(),
+        });
+
+        Self {
+            cfg,
+            http_client,
+            token,
+        }
+    }
+
+    /// Schedule a new meeting.
+    #[instrument(skip(self, req), err)]
+    pub(crate) async fn create_meeting(
+        &self,
+        req: &WebexMeetingRequest,
+    ) -> Result<WebexMeeting, WebexClientError> {
+        trace!("webex client: create meeting");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| WebexClientError::Token(e.to_string()))?;
+        let url = format!("{BASE_URL}/meetings");
+        let response = self
+            .http_client
+            .post(&url)
+            .bearer_auth(token)
+            .json(req)
+            .send()
+            .await
+            .map_err(|e| WebexClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(WebexClientError::from_response(response).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| WebexClientError::Network(e.to_string()))
+    }
+
+    /// Delete a meeting by ID.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn delete_meeting(&self, meeting_id: &str) -> Result<(), WebexClientError> {
+        trace!("webex client: delete meeting");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| WebexClientError::Token(e.to_string()))?;
+        let url = meeting_url(meeting_id);
+        let response = self
+            .http_client
+            .delete(&url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(|e| WebexClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(WebexClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// Get a meeting by ID.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn get_meeting(
+        &self,
+        meeting_id: &str,
+    ) -> Result<WebexMeeting, WebexClientError> {
+        trace!("webex client: get meeting");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| WebexClientError::Token(e.to_string()))?;
+        let url = meeting_url(meeting_id);
+        let response = self
+            .http_client
+            .get(&url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(|e| WebexClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(WebexClientError::from_response(response).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| WebexClientError::Network(e.to_string()))
+    }
+
+    /// Update an existing meeting.
+    #[instrument(skip(self, req), err)]
+    pub(crate) async fn update_meeting(
+        &self,
+        meeting_id: &str,
+        req: &WebexMeetingRequest,
+    ) -> Result<(), WebexClientError> {
+        trace!("webex client: update meeting");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| WebexClientError::Token(e.to_string()))?;
+        let url = meeting_url(meeting_id);
+        let response = self
+            .http_client
+            .put(&url)
+            .bearer_auth(token)
+            .json(req)
+            .send()
+            .await
+            .map_err(|e| WebexClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(WebexClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// Fetch a new access token using the refresh token grant.
+    #[instrument(skip(self, refresh_token), err)]
+    async fn fetch_token(&self, refresh_token: &str) -> Result<TokenResponse> {
+        trace!("webex client: fetch token");
+
+        // Setup token request
+        let url = format!("{BASE_URL}/access_token");
+        let body = serde_urlencoded::to_string([
+            ("client_id", self.cfg.client_id.as_str()),
+            ("client_secret", self.cfg.client_secret.as_str()),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])?;
+
+        // Make the token request
+        let response = self
+            .http_client
+            .post(&url)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let error: WebexErrorResponse = response.json().await.unwrap_or_default();
+            return Err(anyhow!("webex token error: {status} - {}", error.message));
+        }
+
+        // Parse the token response
+        Ok(response.json().await?)
+    }
+
+    /// Get a valid access token, refreshing it if needed.
+    async fn get_token(&self) -> Result<String> {
+        // Check if we have a valid cached token
+        let mut token_guard = self.token.lock().await;
+        if let Some(ref cached) = token_guard.cached
+            && Instant::now() + TOKEN_EXPIRY_MARGIN < cached.expires_at
+        {
+            return Ok(cached.access_token.clone());
+        }
+
+        // Refresh the access token
+        let token_response = self.fetch_token(&token_guard.refresh_token).await?;
+        let access_token = token_response.access_token.clone();
+        token_guard.cached = Some(CachedToken {
+            access_token: token_response.access_token,
+            expires_at: Instant::now() + Duration::from_secs(token_response.expires_in),
+        });
+
+        // Webex may rotate the refresh token, so keep the latest one
+        if let Some(refresh_token) = token_response.refresh_token {
+            token_guard.refresh_token = refresh_token;
+        }
+
+        Ok(access_token)
+    }
+}
+
+/// Returns the URL of a meeting.
+fn meeting_url(meeting_id: &str) -> String {
+    let encoded_meeting_id = utf8_percent_encode(meeting_id, NON_ALPHANUMERIC);
+    format!("{BASE_URL}/meetings/{encoded_meeting_id}")
+}
+
+/// Cached OAuth access token with expiry tracking.
+struct CachedToken {
+    /// OAuth access token.
+    access_token: String,
+    /// Time when the token becomes invalid for reuse.
+    expires_at: Instant,
+}
+
+/// OAuth token state shared by all requests.
+struct TokenState {
+    /// Cached access token, if any.
+    cached: Option<CachedToken>,
+    /// Latest refresh token issued by Webex.
+    refresh_token: String,
+}
+
+/// Meeting returned by the Webex API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WebexMeeting {
+    /// Provider-assigned meeting identifier.
+    pub id: String,
+    /// Meeting join URL.
+    pub web_link: String,
+
+    /// Meeting password.
+    pub password: Option<String>,
+}
+
+/// Request to create or update a meeting.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WebexMeetingRequest {
+    /// Whether attendees can join before the host.
+    pub enabled_join_before_host: bool,
+    /// Meeting end time.
+    pub end: DateTime<Utc>,
+    /// Minutes before the start time attendees can join.
+    pub join_before_host_minutes: u32,
+    /// Meeting start time.
+    pub start: DateTime<Utc>,
+    /// Meeting title.
+    pub title: String,
+
+    /// Whether the meeting is recorded automatically.
+    pub enabled_auto_record_meeting: Option<bool>,
+    /// Meeting password (generated by Webex when omitted on create).
+    pub password: Option<String>,
+    /// IANA timezone used to display the meeting.
+    pub timezone: Option<String>,
+}
+
+impl TryFrom<&Meeting> for WebexMeetingRequest {
+    type Error = WebexClientError;
+
+    fn try_from(m: &Meeting) -> Result<Self, Self::Error> {
+        let starts_at = m.starts_at.ok_or(WebexClientError::InvalidSchedule)?;
+        let ends_at = m.ends_at().ok_or(WebexClientError::InvalidSchedule)?;
+
+        Ok(Self {
+            enabled_join_before_host: true,
+            end: ends_at,
+            join_before_host_minutes: JOIN_BEFORE_HOST_MINUTES,
+            start: starts_at,
+            title: m.topic.clone().unwrap_or_default(),
+
+            enabled_auto_record_meeting: Some(m.recording_requested.unwrap_or(true)),
+            password: m.password.clone(),
+            timezone: m.timezone.clone(),
+        })
+    }
+}
+
+/// Response from the token endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    /// OAuth access token.
+    access_token: String,
+    /// Token lifetime in seconds.
+    expires_in: u64,
+
+    /// Refresh token to use for the next refresh, if rotated.
+    refresh_token: Option<String>,
+}
+
+/// Error types from Webex client calls.
+#[derive(Debug)]
+pub(crate) enum WebexClientError {
+    /// Non-retryable client errors (4xx except 401, 403, 404 and 429).
+    Client { status: u16, message: String },
+    /// Meeting is missing its start time or duration.
+    InvalidSchedule,
+    /// Network or connection errors (retryable).
+    Network(String),
+    /// Meeting does not exist.
+    NotFound,
+    /// Rate limit exceeded (retryable after delay).
+    RateLimit { retry_after: Duration },
+    /// Server errors (5xx, retryable).
+    Server { status: u16, message: String },
+    /// Token fetch error (retryable).
+    Token(String),
+}
+
+impl std::fmt::Display for WebexClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Client { status, message } => {
+                write!(f, "webex client error: {status} - {message}")
+            }
+            Self::InvalidSchedule => write!(f, "missing meeting start time or duration"),
+            Self::Network(msg) => write!(f, "webex network error: {msg}"),
+            Self::NotFound => write!(f, "webex meeting not found"),
+            Self::RateLimit { retry_after } => {
+                write!(
+                    f,
+                    "webex rate limit exceeded (retry after {}s)",
+                    retry_after.as_secs()
+                )
+            }
+            Self::Server { status, message } => {
+                write!(f, "webex server error: {status} - {message}")
+            }
+            Self::Token(msg) => write!(f, "webex token error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for WebexClientError {}
+
+impl From<WebexClientError> for MeetingProviderError {
+    fn from(e: WebexClientError) -> Self {
+        match e {
+            WebexClientError::Client { status, message } => {
+                Self::Client(format!("{status}: {message}"))
+            }
+            WebexClientError::InvalidSchedule => {
+                Self::Client("missing meeting start time or duration".to_string())
+            }
+            WebexClientError::Network(msg) => Self::Network(msg),
+            WebexClientError::NotFound => Self::NotFound,
+            WebexClientError::RateLimit { retry_after } => Self::RateLimit { retry_after },
+            WebexClientError::Server { status, message } => {
+                Self::Server(format!("{status}: {message}"))
+            }
+            WebexClientError::Token(msg) => Self::Token(msg),
+        }
+    }
+}
+
+impl WebexClientError {
+    /// Create error from HTTP response status and body.
+    async fn from_response(response: reqwest::Response) -> Self {
+        // Parse Retry-After header before consuming response body
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok())
+            .map_or(DEFAULT_RATE_LIMIT_RETRY, Duration::from_secs);
+
+        // Get status and parse error body
+        let status = response.status();
+        let message = response
+            .json::<WebexErrorResponse>()
+            .await
+            .unwrap_or_default()
+            .message;
+
+        // Determine error type based on status code
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Self::RateLimit { retry_after }
+        } else if status == reqwest::StatusCode::NOT_FOUND {
+            Self::NotFound
+        } else if status == reqwest::StatusCode::UNAUTHORIZED
+            || status == reqwest::StatusCode::FORBIDDEN
+        {
+            // Auth errors are retryable (token may have expired)
+            Self::Token(format!("{} - {message}", status.as_u16()))
+        } else if status.is_client_error() {
+            Self::Client {
+                status: status.as_u16(),
+                message,
+            }
+        } else {
+            Self::Server {
+                status: status.as_u16(),
+                message,
+            }
+        }
+    }
+}
+
+/// Error response returned by the Webex API.
+#[derive(Debug, Default, Deserialize)]
+struct WebexErrorResponse {
+    /// Error message.
+    #[serde(default)]
+    message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    use crate::services::meetings::Meeting;
+
+    use super::{WebexClientError, WebexMeetingRequest};
+
+    #[test]
+    fn webex_meeting_request_omits_missing_password() {
+        let request = WebexMeetingRequest::try_from(&sample_meeting()).unwrap();
+
+        assert!(serde_json::to_value(request).unwrap().get("password").is_none());
+    }
+
+    #[test]
+    fn webex_meeting_request_requires_start_time() {
+        let meeting = Meeting {
+            starts_at: None,
+            ..sample_meeting()
+        };
+
+        assert!(matches!(
+            WebexMeetingRequest::try_from(&meeting),
+            Err(WebexClientError::InvalidSchedule)
+        ));
+    }
+
+    #[test]
+    fn webex_meeting_request_sets_recording_preference() {
+        let request = WebexMeetingRequest::try_from(&Meeting {
+            recording_requested: Some(false),
+            ..sample_meeting()
+        })
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap()["enabledAutoRecordMeeting"],
+            json!(false)
+        );
+    }
+
+    #[test]
+    fn webex_meeting_request_sets_schedule_and_title() {
+        let request = WebexMeetingRequest::try_from(&sample_meeting()).unwrap();
+        let value = serde_json::to_value(request).unwrap();
+
+        assert_eq!(value["title"], json!("Community call"));
+        assert_eq!(value["start"], json!("2030-01-01T10:00:00Z"));
+        assert_eq!(value["end"], json!("2030-01-01T11:00:00Z"));
+        assert_eq!(value["timezone"], json!("Europe/Madrid"));
+    }
+
+    // Helpers.
+
+    fn sample_meeting() -> Meeting {
+        Meeting {
+            duration: Some(Duration::from_hours(1)),
+            starts_at: Some(chrono::Utc.with_ymd_and_hms(2030, 1, 1, 10, 0, 0).unwrap()),
+            timezone: Some("Europe/Madrid".to_string()),
+            topic: Some("Community call".to_string()),
+            ..Default::default()
+        }
+    }
+}
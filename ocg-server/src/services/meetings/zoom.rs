@@ -1,17 +1,24 @@
 //! Zoom-backed meetings provider implementation.
 
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
 use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
 
 use crate::{
     config::MeetingsZoomConfig,
+    db::meetings::{DynDBMeetings, GroupZoomCredentials},
     services::meetings::zoom::client::{
-        CreateMeetingRequest, UpdateMeetingRequest, ZOOM_MEETING_NOT_FOUND, ZoomClient,
-        ZoomClientError,
+        CreateMeetingRequest, UpdateMeetingRequest, ZOOM_MEETING_NOT_FOUND,
+        ZOOM_RECORDING_NOT_FOUND, ZoomClient, ZoomClientError, ZoomMeetingKind,
     },
 };
 
 use super::{
-    Meeting, MeetingEndResult, MeetingProviderError, MeetingProviderMeeting, MeetingsProvider,
+    DynMeetingsProvider, Meeting, MeetingEndResult, MeetingParticipant, MeetingProvider,
+    MeetingProviderError, MeetingProviderMeeting, MeetingRecording, MeetingsProvider,
 };
 
 pub(crate) mod client;
@@ -29,6 +36,19 @@ impl ZoomMeetingsProvider {
             client: ZoomClient::new(cfg.clone()),
         }
     }
+
+    /// Add the meeting hosts as panelists when the meeting is a webinar.
+    async fn add_panelists(
+        &self,
+        webinar_id: i64,
+        meeting: &Meeting,
+    ) -> Result<(), ZoomClientError> {
+        let hosts = meeting.hosts.as_deref().unwrap_or_default();
+        if ZoomMeetingKind::from(meeting) != ZoomMeetingKind::Webinar || hosts.is_empty() {
+            return Ok(());
+        }
+        self.client.add_webinar_panelists(webinar_id, hosts).await
+    }
 }
 
 #[async_trait]
@@ -44,7 +64,10 @@ impl MeetingsProvider for ZoomMeetingsProvider {
         let req = CreateMeetingRequest::try_from(meeting).map_err(MeetingProviderError::from)?;
         let zoom_meeting = self
             .client
-            .create_meeting(host_user_id, &req)
+            .create_meeting(ZoomMeetingKind::from(meeting), host_user_id, &req)
+            .await
+            .map_err(MeetingProviderError::from)?;
+        self.add_panelists(zoom_meeting.id, meeting)
             .await
             .map_err(MeetingProviderError::from)?;
 
@@ -61,7 +84,8 @@ impl MeetingsProvider for ZoomMeetingsProvider {
             .parse()
             .map_err(|e: std::num::ParseIntError| MeetingProviderError::Client(e.to_string()))?;
 
-        match self.client.delete_meeting(meeting_id).await {
+        let result = with_kind_fallback(|kind| self.client.delete_meeting(kind, meeting_id)).await;
+        match result {
             Ok(()) => Ok(()),
             Err(ZoomClientError::Client { code, .. }) if code == ZOOM_MEETING_NOT_FOUND => {
                 Err(MeetingProviderError::NotFound)
@@ -80,11 +104,11 @@ impl MeetingsProvider for ZoomMeetingsProvider {
             .map_err(|e: std::num::ParseIntError| MeetingProviderError::Client(e.to_string()))?;
 
         // Check current meeting status first to avoid unnecessary end calls
-        let zoom_meeting = self
-            .client
-            .get_meeting(meeting_id)
-            .await
-            .map_err(MeetingProviderError::from)?;
+        let (kind, zoom_meeting) = with_kind_fallback(|kind| async move {
+            Ok::<_, ZoomClientError>((kind, self.client.get_meeting(kind, meeting_id).await?))
+        })
+        .await
+        .map_err(MeetingProviderError::from)?;
         let is_started = zoom_meeting
             .status
             .as_deref()
@@ -94,7 +118,7 @@ impl MeetingsProvider for ZoomMeetingsProvider {
         }
 
         self.client
-            .end_meeting(meeting_id)
+            .end_meeting(kind, meeting_id)
             .await
             .map_err(MeetingProviderError::from)?;
 
@@ -110,9 +134,7 @@ impl MeetingsProvider for ZoomMeetingsProvider {
             .parse()
             .map_err(|e: std::num::ParseIntError| MeetingProviderError::Client(e.to_string()))?;
 
-        let zoom_meeting = self
-            .client
-            .get_meeting(meeting_id)
+        let zoom_meeting = with_kind_fallback(|kind| self.client.get_meeting(kind, meeting_id))
             .await
             .map_err(MeetingProviderError::from)?;
 
@@ -123,6 +145,55 @@ impl MeetingsProvider for ZoomMeetingsProvider {
         })
     }
 
+    /// List the participants of a past meeting from the Zoom participants report.
+    async fn list_participants(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingParticipant>, MeetingProviderError> {
+        let meeting_id: i64 = provider_meeting_id
+            .parse()
+            .map_err(|e: std::num::ParseIntError| MeetingProviderError::Client(e.to_string()))?;
+
+        let participants =
+            with_kind_fallback(|kind| self.client.list_report_participants(kind, meeting_id))
+                .await
+                .map_err(MeetingProviderError::from)?;
+
+        // Only signed-in participants can be matched to users
+        Ok(participants
+            .into_iter()
+            .filter_map(|participant| participant.user_email)
+            .filter(|email| !email.trim().is_empty())
+            .map(|email| MeetingParticipant { email })
+            .collect())
+    }
+
+    /// List the cloud recordings of a past meeting on Zoom.
+    async fn list_recordings(
+        &self,
+        provider_meeting_id: &str,
+    ) -> Result<Vec<MeetingRecording>, MeetingProviderError> {
+        let meeting_id: i64 = provider_meeting_id
+            .parse()
+            .map_err(|e: std::num::ParseIntError| MeetingProviderError::Client(e.to_string()))?;
+
+        // Recordings that are still being processed are reported as not found
+        let recordings = match self.client.get_meeting_recordings(meeting_id).await {
+            Ok(recordings) => recordings,
+            Err(ZoomClientError::Client { code, .. }) if code == ZOOM_RECORDING_NOT_FOUND => {
+                return Ok(vec![]);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(recordings
+            .share_url
+            .filter(|url| !url.trim().is_empty())
+            .map(|url| MeetingRecording { url })
+            .into_iter()
+            .collect())
+    }
+
     /// Update a meeting on Zoom.
     async fn update_meeting(
         &self,
@@ -135,10 +206,241 @@ impl MeetingsProvider for ZoomMeetingsProvider {
 
         let req = UpdateMeetingRequest::try_from(meeting).map_err(MeetingProviderError::from)?;
         self.client
-            .update_meeting(meeting_id, &req)
+            .update_meeting(ZoomMeetingKind::from(meeting), meeting_id, &req)
+            .await
+            .map_err(MeetingProviderError::from)?;
+        self.add_panelists(meeting_id, meeting)
             .await
             .map_err(MeetingProviderError::from)?;
 
         Ok(())
     }
 }
+
+/// Zoom providers built from the credentials configured by groups.
+///
+/// Providers are cached per group so their access tokens can be reused, and
+/// rebuilt whenever the group updates its credentials.
+#[derive(Default)]
+pub(crate) struct GroupZoomProviders {
+    /// Cached Zoom configuration and provider for each group, along with the
+    /// credentials used to build them.
+    cache: Mutex<
+        HashMap<
+            Uuid,
+            (
+                GroupZoomCredentials,
+                MeetingsZoomConfig,
+                DynMeetingsProvider,
+            ),
+        >,
+    >,
+}
+
+impl GroupZoomProviders {
+    /// Resolve the Zoom configuration and provider for a group's meeting.
+    ///
+    /// Returns `None` when the meeting should use the globally configured
+    /// provider: it is not a Zoom meeting, Zoom is disabled, or the group has
+    /// not configured credentials of its own.
+    pub(crate) async fn resolve(
+        &self,
+        db: &DynDBMeetings,
+        zoom_cfg: Option<&MeetingsZoomConfig>,
+        provider: MeetingProvider,
+        group_id: Option<Uuid>,
+    ) -> Result<Option<(MeetingsZoomConfig, DynMeetingsProvider)>> {
+        let (MeetingProvider::Zoom, Some(zoom_cfg), Some(group_id)) =
+            (provider, zoom_cfg.filter(|cfg| cfg.enabled), group_id)
+        else {
+            return Ok(None);
+        };
+
+        // Get the group credentials, dropping any cached provider if removed
+        let Some(credentials) = db.get_group_zoom_credentials(group_id).await? else {
+            self.cache.lock().await.remove(&group_id);
+            return Ok(None);
+        };
+
+        // Reuse the cached provider while the credentials are unchanged
+        let mut cache = self.cache.lock().await;
+        if let Some((cached_credentials, cfg, provider)) = cache.get(&group_id)
+            && *cached_credentials == credentials
+        {
+            return Ok(Some((cfg.clone(), provider.clone())));
+        }
+
+        // Build a new provider using the group account and host user
+        let cfg = MeetingsZoomConfig {
+            account_id: credentials.account_id.clone(),
+            client_id: credentials.client_id.clone(),
+            client_secret: credentials.client_secret.clone(),
+            host_pool_users: vec![credentials.host_user.clone()],
+            ..zoom_cfg.clone()
+        };
+        let provider: DynMeetingsProvider = Arc::new(ZoomMeetingsProvider::new(&cfg));
+        cache.insert(group_id, (credentials, cfg.clone(), provider.clone()));
+
+        Ok(Some((cfg, provider)))
+    }
+}
+
+/// Run a Zoom operation that only knows the provider identifier.
+///
+/// Meetings and webinars are served by different endpoints, so the webinar
+/// one is tried when Zoom reports that the meeting does not exist.
+async fn with_kind_fallback<T, F, Fut>(op: F) -> Result<T, ZoomClientError>
+where
+    F: Fn(ZoomMeetingKind) -> Fut,
+    Fut: Future<Output = Result<T, ZoomClientError>>,
+{
+    match op(ZoomMeetingKind::Meeting).await {
+        Err(ZoomClientError::Client { code, .. }) if code == ZOOM_MEETING_NOT_FOUND => {
+            op(ZoomMeetingKind::Webinar).await
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use crate::{
+        config::MeetingsZoomConfig,
+        db::meetings::{DynDBMeetings, GroupZoomCredentials, MockDBMeetings},
+        services::meetings::MeetingProvider,
+    };
+
+    use super::GroupZoomProviders;
+
+    #[tokio::test]
+    async fn resolve_builds_provider_from_group_credentials() {
+        let group_id = Uuid::new_v4();
+        let mut db = MockDBMeetings::new();
+        db.expect_get_group_zoom_credentials()
+            .times(1)
+            .withf(move |id| *id == group_id)
+            .returning(|_| Ok(Some(sample_credentials("secret-1"))));
+        let db: DynDBMeetings = Arc::new(db);
+
+        let providers = GroupZoomProviders::default();
+        let (cfg, _) = providers
+            .resolve(
+                &db,
+                Some(&sample_cfg()),
+                MeetingProvider::Zoom,
+                Some(group_id),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(cfg.account_id, "group-account");
+        assert_eq!(cfg.client_id, "group-client");
+        assert_eq!(cfg.client_secret, "secret-1");
+        assert_eq!(
+            cfg.host_pool_users,
+            vec!["group-host@example.com".to_string()]
+        );
+        assert_eq!(cfg.max_simultaneous_meetings_per_host, 2);
+    }
+
+    #[tokio::test]
+    async fn resolve_reuses_provider_until_credentials_change() {
+        let group_id = Uuid::new_v4();
+        let mut db = MockDBMeetings::new();
+        let mut seq = mockall::Sequence::new();
+        db.expect_get_group_zoom_credentials()
+            .times(2)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(Some(sample_credentials("secret-1"))));
+        db.expect_get_group_zoom_credentials()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(Some(sample_credentials("secret-2"))));
+        let db: DynDBMeetings = Arc::new(db);
+
+        let providers = GroupZoomProviders::default();
+        let cfg = sample_cfg();
+        let mut resolved = Vec::new();
+        for _ in 0..3 {
+            let (_, provider) = providers
+                .resolve(&db, Some(&cfg), MeetingProvider::Zoom, Some(group_id))
+                .await
+                .unwrap()
+                .unwrap();
+            resolved.push(provider);
+        }
+
+        assert!(Arc::ptr_eq(&resolved[0], &resolved[1]));
+        assert!(!Arc::ptr_eq(&resolved[1], &resolved[2]));
+    }
+
+    #[tokio::test]
+    async fn resolve_returns_none_when_group_has_no_credentials() {
+        let mut db = MockDBMeetings::new();
+        db.expect_get_group_zoom_credentials()
+            .times(1)
+            .returning(|_| Ok(None));
+        let db: DynDBMeetings = Arc::new(db);
+
+        let providers = GroupZoomProviders::default();
+        let resolved = providers
+            .resolve(
+                &db,
+                Some(&sample_cfg()),
+                MeetingProvider::Zoom,
+                Some(Uuid::new_v4()),
+            )
+            .await
+            .unwrap();
+
+        assert!(resolved.is_none());
+    }
+
+    #[tokio::test]
+    async fn resolve_skips_lookup_for_other_providers_or_disabled_zoom() {
+        let db: DynDBMeetings = Arc::new(MockDBMeetings::new());
+        let providers = GroupZoomProviders::default();
+        let group_id = Some(Uuid::new_v4());
+
+        let other_provider = providers
+            .resolve(&db, Some(&sample_cfg()), MeetingProvider::Jitsi, group_id)
+            .await
+            .unwrap();
+        let zoom_disabled = providers
+            .resolve(&db, None, MeetingProvider::Zoom, group_id)
+            .await
+            .unwrap();
+
+        assert!(other_provider.is_none());
+        assert!(zoom_disabled.is_none());
+    }
+
+    // Helpers.
+
+    fn sample_cfg() -> MeetingsZoomConfig {
+        MeetingsZoomConfig {
+            account_id: "account-id".to_string(),
+            client_id: "client-id".to_string(),
+            client_secret: "client-secret".to_string(),
+            enabled: true,
+            host_pool_users: vec!["host@example.com".to_string()],
+            max_participants: 100,
+            max_simultaneous_meetings_per_host: 2,
+            webhook_secret_token: "webhook-secret".to_string(),
+        }
+    }
+
+    fn sample_credentials(client_secret: &str) -> GroupZoomCredentials {
+        GroupZoomCredentials {
+            account_id: "group-account".to_string(),
+            client_id: "group-client".to_string(),
+            client_secret: client_secret.to_string(),
+            host_user: "group-host@example.com".to_string(),
+        }
+    }
+}
@@ -19,6 +19,9 @@ use super::MeetingProviderError;
 /// Zoom client error code for "meeting does not exist".
 pub(crate) const ZOOM_MEETING_NOT_FOUND: i32 = 3001;
 
+/// Zoom client error code for "recording does not exist".
+pub(crate) const ZOOM_RECORDING_NOT_FOUND: i32 = 3301;
+
 /// Base URL for Zoom client v2.
 const BASE_URL: &str = "https://api.zoom.us/v2";
 
@@ -37,6 +40,9 @@ const MIN_DURATION_MINUTES: i64 = 5;
 /// Margin before token expiry to trigger refresh.
 const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_mins(5);
 
+/// Number of participants requested per report page.
+const PARTICIPANTS_REPORT_PAGE_SIZE: u32 = 300;
+
 /// Minimum delay between Zoom HTTP requests.
 const ZOOM_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
 
@@ -72,10 +78,50 @@ impl ZoomClient {
         }
     }
 
-    /// Create a new meeting.
+    /// Add panelists to a webinar.
+    #[instrument(skip(self, emails), err)]
+    pub(crate) async fn add_webinar_panelists(
+        &self,
+        webinar_id: i64,
+        emails: &[String],
+    ) -> Result<(), ZoomClientError> {
+        trace!("zoom client: add webinar panelists");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| ZoomClientError::Token(e.to_string()))?;
+        let url = format!("{BASE_URL}/webinars/{webinar_id}/panelists");
+        let req = AddWebinarPanelistsRequest {
+            panelists: emails
+                .iter()
+                .map(|email| WebinarPanelist {
+                    email: email.clone(),
+                    name: email.clone(),
+                })
+                .collect(),
+        };
+        self.wait_for_request_slot().await;
+        let response = self
+            .http_client
+            .post(&url)
+            .bearer_auth(token)
+            .json(&req)
+            .send()
+            .await
+            .map_err(|e| ZoomClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ZoomClientError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+
+    /// Create a new meeting or webinar.
     #[instrument(skip(self, host_user_id, req), err)]
     pub(crate) async fn create_meeting(
         &self,
+        kind: ZoomMeetingKind,
         host_user_id: &str,
         req: &CreateMeetingRequest,
     ) -> Result<ZoomMeeting, ZoomClientError> {
@@ -86,7 +132,7 @@ impl ZoomClient {
             .await
             .map_err(|e| ZoomClientError::Token(e.to_string()))?;
         let encoded_host_user_id = utf8_percent_encode(host_user_id, NON_ALPHANUMERIC).to_string();
-        let url = format!("{BASE_URL}/users/{encoded_host_user_id}/meetings");
+        let url = format!("{BASE_URL}/users/{encoded_host_user_id}/{}", kind.path());
         self.wait_for_request_slot().await;
         let response = self
             .http_client
@@ -106,16 +152,20 @@ impl ZoomClient {
             .map_err(|e| ZoomClientError::Network(e.to_string()))
     }
 
-    /// Delete a meeting by ID.
+    /// Delete a meeting or webinar by ID.
     #[instrument(skip(self), err)]
-    pub(crate) async fn delete_meeting(&self, meeting_id: i64) -> Result<(), ZoomClientError> {
+    pub(crate) async fn delete_meeting(
+        &self,
+        kind: ZoomMeetingKind,
+        meeting_id: i64,
+    ) -> Result<(), ZoomClientError> {
         trace!("zoom client: delete meeting");
 
         let token = self
             .get_token()
             .await
             .map_err(|e| ZoomClientError::Token(e.to_string()))?;
-        let url = format!("{BASE_URL}/meetings/{meeting_id}");
+        let url = format!("{BASE_URL}/{}/{meeting_id}", kind.path());
         self.wait_for_request_slot().await;
         let response = self
             .http_client
@@ -131,16 +181,20 @@ impl ZoomClient {
         Ok(())
     }
 
-    /// End a meeting by ID.
+    /// End a meeting or webinar by ID.
     #[instrument(skip(self), err)]
-    pub(crate) async fn end_meeting(&self, meeting_id: i64) -> Result<(), ZoomClientError> {
+    pub(crate) async fn end_meeting(
+        &self,
+        kind: ZoomMeetingKind,
+        meeting_id: i64,
+    ) -> Result<(), ZoomClientError> {
         trace!("zoom client: end meeting");
 
         let token = self
             .get_token()
             .await
             .map_err(|e| ZoomClientError::Token(e.to_string()))?;
-        let url = format!("{BASE_URL}/meetings/{meeting_id}/status");
+        let url = format!("{BASE_URL}/{}/{meeting_id}/status", kind.path());
         let req = UpdateMeetingStatusRequest { action: "end" };
         self.wait_for_request_slot().await;
         let response = self
@@ -158,10 +212,11 @@ impl ZoomClient {
         Ok(())
     }
 
-    /// Get a meeting by ID.
+    /// Get a meeting or webinar by ID.
     #[instrument(skip(self), err)]
     pub(crate) async fn get_meeting(
         &self,
+        kind: ZoomMeetingKind,
         meeting_id: i64,
     ) -> Result<ZoomMeeting, ZoomClientError> {
         trace!("zoom client: get meeting");
@@ -170,7 +225,38 @@ impl ZoomClient {
             .get_token()
             .await
             .map_err(|e| ZoomClientError::Token(e.to_string()))?;
-        let url = format!("{BASE_URL}/meetings/{meeting_id}");
+        let url = format!("{BASE_URL}/{}/{meeting_id}", kind.path());
+        self.wait_for_request_slot().await;
+        let response = self
+            .http_client
+            .get(&url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(|e| ZoomClientError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ZoomClientError::from_response(response).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ZoomClientError::Network(e.to_string()))
+    }
+
+    /// Get the cloud recordings of a past meeting.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn get_meeting_recordings(
+        &self,
+        meeting_id: i64,
+    ) -> Result<ZoomMeetingRecordings, ZoomClientError> {
+        trace!("zoom client: get meeting recordings");
+
+        let token = self
+            .get_token()
+            .await
+            .map_err(|e| ZoomClientError::Token(e.to_string()))?;
+        let url = format!("{BASE_URL}/meetings/{meeting_id}/recordings");
         self.wait_for_request_slot().await;
         let response = self
             .http_client
@@ -189,10 +275,66 @@ impl ZoomClient {
             .map_err(|e| ZoomClientError::Network(e.to_string()))
     }
 
-    /// Update an existing meeting.
+    /// List the participants of a past meeting or webinar from the
+    /// participants report.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn list_report_participants(
+        &self,
+        kind: ZoomMeetingKind,
+        meeting_id: i64,
+    ) -> Result<Vec<ZoomReportParticipant>, ZoomClientError> {
+        trace!("zoom client: list report participants");
+
+        let mut participants = Vec::new();
+        let mut next_page_token = String::new();
+        loop {
+            // Fetch the next report page
+            let token = self
+                .get_token()
+                .await
+                .map_err(|e| ZoomClientError::Token(e.to_string()))?;
+            let page_size = PARTICIPANTS_REPORT_PAGE_SIZE.to_string();
+            let query = serde_urlencoded::to_string([
+                ("next_page_token", next_page_token.as_str()),
+                ("page_size", page_size.as_str()),
+            ])
+            .map_err(|e| ZoomClientError::Network(e.to_string()))?;
+            let url = format!(
+                "{BASE_URL}/report/{}/{meeting_id}/participants?{query}",
+                kind.path()
+            );
+            self.wait_for_request_slot().await;
+            let response = self
+                .http_client
+                .get(&url)
+                .bearer_auth(token)
+                .send()
+                .await
+                .map_err(|e| ZoomClientError::Network(e.to_string()))?;
+            if !response.status().is_success() {
+                return Err(ZoomClientError::from_response(response).await);
+            }
+            let page: ZoomReportParticipantsPage = response
+                .json()
+                .await
+                .map_err(|e| ZoomClientError::Network(e.to_string()))?;
+            participants.extend(page.participants);
+
+            // Stop when there are no more pages
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => next_page_token = token,
+                _ => break,
+            }
+        }
+
+        Ok(participants)
+    }
+
+    /// Update an existing meeting or webinar.
     #[instrument(skip(self, req), err)]
     pub(crate) async fn update_meeting(
         &self,
+        kind: ZoomMeetingKind,
         meeting_id: i64,
         req: &UpdateMeetingRequest,
     ) -> Result<(), ZoomClientError> {
@@ -202,7 +344,7 @@ impl ZoomClient {
             .get_token()
             .await
             .map_err(|e| ZoomClientError::Token(e.to_string()))?;
-        let url = format!("{BASE_URL}/meetings/{meeting_id}");
+        let url = format!("{BASE_URL}/{}/{meeting_id}", kind.path());
         self.wait_for_request_slot().await;
         let response = self
             .http_client
@@ -296,6 +438,13 @@ impl ZoomClient {
     }
 }
 
+/// Request to add panelists to a webinar.
+#[derive(Debug, Serialize)]
+struct AddWebinarPanelistsRequest {
+    /// Panelists to add.
+    panelists: Vec<WebinarPanelist>,
+}
+
 /// Cached OAuth access token with expiry tracking.
 struct CachedToken {
     /// OAuth access token.
@@ -331,12 +480,15 @@ impl TryFrom<&Meeting> for CreateMeetingRequest {
 
     fn try_from(m: &Meeting) -> Result<Self, Self::Error> {
         Ok(Self {
-            meeting_type: 2, // Scheduled meeting
+            meeting_type: match ZoomMeetingKind::from(m) {
+                ZoomMeetingKind::Meeting => 2, // Scheduled meeting
+                ZoomMeetingKind::Webinar => 5, // Scheduled webinar
+            },
             topic: m.topic.clone().unwrap_or_default(),
 
             default_password: Some(true),
             duration: m.duration.map(Minutes::try_from_duration).transpose()?,
-            settings: Some(default_meeting_settings(m.recording_requested)),
+            settings: Some(default_settings(m)),
             start_time: m.starts_at,
             timezone: m.timezone.clone(),
         })
@@ -355,8 +507,12 @@ pub(crate) struct MeetingSettings {
     pub join_before_host: Option<bool>,
     /// Whether attendees start muted.
     pub mute_upon_entry: Option<bool>,
+    /// Whether panelist video starts enabled (webinars only).
+    pub panelists_video: Option<bool>,
     /// Whether attendee video starts enabled.
     pub participant_video: Option<bool>,
+    /// Whether the webinar practice session is enabled (webinars only).
+    pub practice_session: Option<bool>,
     /// Whether the waiting room is enabled.
     pub waiting_room: Option<bool>,
 }
@@ -408,7 +564,7 @@ impl TryFrom<&Meeting> for UpdateMeetingRequest {
     fn try_from(m: &Meeting) -> Result<Self, Self::Error> {
         Ok(Self {
             duration: m.duration.map(Minutes::try_from_duration).transpose()?,
-            settings: Some(default_meeting_settings(m.recording_requested)),
+            settings: Some(default_settings(m)),
             start_time: m.starts_at,
             timezone: m.timezone.clone(),
             topic: m.topic.clone(),
@@ -423,6 +579,15 @@ struct UpdateMeetingStatusRequest {
     action: &'static str,
 }
 
+/// Webinar panelist entry.
+#[derive(Debug, Serialize)]
+struct WebinarPanelist {
+    /// Panelist email address.
+    email: String,
+    /// Panelist display name.
+    name: String,
+}
+
 /// Error types from Zoom client calls.
 #[derive(Debug)]
 pub(crate) enum ZoomClientError {
@@ -549,19 +714,86 @@ pub(crate) struct ZoomMeeting {
     pub status: Option<String>,
 }
 
-/// Returns the default settings applied to all meetings.
-fn default_meeting_settings(recording_requested: Option<bool>) -> MeetingSettings {
-    MeetingSettings {
-        auto_recording: Some(if recording_requested.unwrap_or(true) {
-            "cloud".to_string()
+/// Kind of Zoom meeting, each one served by its own API endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ZoomMeetingKind {
+    /// Regular Zoom meeting.
+    Meeting,
+    /// Zoom webinar, where hosts join as panelists.
+    Webinar,
+}
+
+impl ZoomMeetingKind {
+    /// Returns the API path segment for this kind.
+    fn path(self) -> &'static str {
+        match self {
+            Self::Meeting => "meetings",
+            Self::Webinar => "webinars",
+        }
+    }
+}
+
+impl From<&Meeting> for ZoomMeetingKind {
+    fn from(m: &Meeting) -> Self {
+        if m.webinar.unwrap_or(false) {
+            Self::Webinar
         } else {
-            "none".to_string()
-        }),
-        jbh_time: Some(15),
-        join_before_host: Some(true),
-        mute_upon_entry: Some(true),
-        participant_video: Some(false),
-        waiting_room: Some(false),
+            Self::Meeting
+        }
+    }
+}
+
+/// Cloud recordings response from Zoom client.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ZoomMeetingRecordings {
+    /// URL to share the meeting recordings.
+    #[serde(default)]
+    pub share_url: Option<String>,
+}
+
+/// Participant entry from Zoom's meeting participants report.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ZoomReportParticipant {
+    /// Participant email, when the participant was signed in.
+    #[serde(default)]
+    pub user_email: Option<String>,
+}
+
+/// Page of Zoom's meeting participants report.
+#[derive(Debug, Deserialize)]
+struct ZoomReportParticipantsPage {
+    /// Token to fetch the next page, empty on the last page.
+    #[serde(default)]
+    next_page_token: Option<String>,
+    /// Participants included in this page.
+    #[serde(default)]
+    participants: Vec<ZoomReportParticipant>,
+}
+
+/// Returns the default settings applied to all meetings and webinars.
+fn default_settings(m: &Meeting) -> MeetingSettings {
+    let auto_recording = Some(if m.recording_requested.unwrap_or(true) {
+        "cloud".to_string()
+    } else {
+        "none".to_string()
+    });
+
+    match ZoomMeetingKind::from(m) {
+        ZoomMeetingKind::Meeting => MeetingSettings {
+            auto_recording,
+            jbh_time: Some(15),
+            join_before_host: Some(true),
+            mute_upon_entry: Some(true),
+            participant_video: Some(false),
+            waiting_room: Some(false),
+            ..Default::default()
+        },
+        ZoomMeetingKind::Webinar => MeetingSettings {
+            auto_recording,
+            panelists_video: Some(true),
+            practice_session: Some(false),
+            ..Default::default()
+        },
     }
 }
 
@@ -615,6 +847,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_meeting_request_uses_meeting_type_by_default() {
+        let request = CreateMeetingRequest::try_from(&Meeting::default()).unwrap();
+        let value = serde_json::to_value(request).unwrap();
+
+        assert_eq!(value["type"], json!(2));
+        assert_eq!(value["settings"]["join_before_host"], json!(true));
+        assert!(value["settings"].get("panelists_video").is_none());
+    }
+
+    #[test]
+    fn create_meeting_request_uses_webinar_type_when_webinar() {
+        let request = CreateMeetingRequest::try_from(&Meeting {
+            webinar: Some(true),
+            ..Default::default()
+        })
+        .unwrap();
+        let value = serde_json::to_value(request).unwrap();
+
+        assert_eq!(value["type"], json!(5));
+        assert_eq!(value["settings"]["auto_recording"], json!("cloud"));
+        assert_eq!(value["settings"]["panelists_video"], json!(true));
+        assert!(value["settings"].get("join_before_host").is_none());
+    }
+
+    #[test]
+    fn update_meeting_request_uses_webinar_settings_when_webinar() {
+        let request = UpdateMeetingRequest::try_from(&Meeting {
+            recording_requested: Some(false),
+            webinar: Some(true),
+            ..Default::default()
+        })
+        .unwrap();
+        let value = serde_json::to_value(request).unwrap();
+
+        assert_eq!(value["settings"]["auto_recording"], json!("none"));
+        assert_eq!(value["settings"]["panelists_video"], json!(true));
+        assert!(value["settings"].get("waiting_room").is_none());
+    }
+
     #[test]
     fn update_meeting_request_sets_cloud_recording_when_requested() {
         let request = UpdateMeetingRequest::try_from(&Meeting {
@@ -1,6 +1,6 @@
 //! This module defines types and logic to manage and send user notifications.
 
-use std::{sync::Arc, time::Duration};
+use std::{borrow::Cow, sync::Arc, time::Duration};
 
 use anyhow::{Result, anyhow};
 use askama::Template;
@@ -19,6 +19,7 @@ use lettre::{
 };
 #[cfg(test)]
 use mockall::automock;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use tokio::time::sleep;
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
@@ -26,21 +27,37 @@ use tracing::{error, instrument, warn};
 use uuid::Uuid;
 
 use crate::{
-    config::EmailConfig,
+    config::{EmailConfig, NotificationsConfig, SmtpConfig},
     db::{DBOperations, DynDB},
+    i18n::Language,
+    services::{certificates::build_attendance_certificate_attachment, images::DynImageStorage},
     templates::notifications::{
-        CfsSubmissionUpdated, CommunityTeamInvitation, EmailVerification, EventAttendanceCanceled,
-        EventCanceled, EventCustom, EventInvitation, EventPublished, EventRefundApproved,
-        EventRefundRejected, EventRefundRequested, EventReminder, EventRescheduled,
-        EventSeriesCanceled, EventSeriesPublished, EventWaitlistJoined, EventWaitlistLeft,
-        EventWaitlistPromoted, EventWelcome, GroupCustom, GroupTeamInvitation, GroupWelcome,
-        SessionProposalCoSpeakerInvitation, SpeakerSeriesWelcome, SpeakerWelcome,
+        CfsSubmissionUpdated, CommunityNewsletter, CommunityTeamInvitation, DormantGroupsReport,
+        EmailVerification, EventApproved, EventAttendanceCanceled, EventAttendanceCertificate,
+        EventCanceled, EventCustom, EventFollowUp, EventInvitation, EventInvitationRequestRejected,
+        EventPublished, EventRefundApproved, EventRefundRejected, EventRefundRequested,
+        EventRejected, EventReminder, EventRescheduled, EventSeriesCanceled, EventSeriesPublished,
+        EventWaitlistJoined, EventWaitlistLeft, EventWaitlistPromoted, EventWelcome,
+        GroupContactMessage, GroupCustom, GroupTeamInvitation, GroupWelcome, MagicLink,
+        PasswordReset, SessionProposalCoSpeakerInvitation, SpeakerSeriesWelcome, SpeakerWelcome,
+        WeeklyDigest,
     },
-    types::{event::EventSummary, site::SiteSettings},
+    types::{
+        community::CommunityEmailBranding, event::EventSummary, site::SiteSettings,
+        user::UserWebhook,
+    },
+    util::build_event_calendar_attachment,
 };
 
+pub(crate) mod discord;
 pub(crate) mod enqueue;
 pub(crate) mod payloads;
+pub(crate) mod preview;
+pub(crate) mod sendgrid;
+pub(crate) mod ses;
+pub(crate) mod slack;
+pub(crate) mod sms;
+pub(crate) mod webhook;
 
 #[cfg(test)]
 mod tests;
@@ -60,6 +77,9 @@ const DELIVERY_REQUEUE_MAX_DELAY: Duration = Duration::from_mins(30);
 /// Maximum number of attempts for one notification delivery claim.
 const DELIVERY_SEND_MAX_ATTEMPTS: usize = 3;
 
+/// Maximum number of community newsletter recipients enqueued per enqueue run.
+const NEWSLETTER_BATCH_SIZE: i32 = 500;
+
 /// Number of workers that recover stale notification delivery claims.
 const NUM_DELIVERY_RECOVERY_WORKERS: usize = 1;
 
@@ -109,20 +129,30 @@ pub(crate) struct PgNotificationsManager {
 
 impl PgNotificationsManager {
     /// Create a new `PgNotificationsManager`.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         db: DynDB,
         cfg: &EmailConfig,
+        notifications_cfg: &NotificationsConfig,
         base_url: &str,
         email_sender: &DynEmailSender,
+        channels: &NotificationChannels,
+        image_storage: &DynImageStorage,
         task_tracker: &TaskTracker,
         cancellation_token: &CancellationToken,
     ) -> Self {
         // Setup and run workers to enqueue due notifications
+        let event_reminder_offsets_minutes: Vec<i32> = notifications_cfg
+            .event_reminder_offsets_minutes
+            .iter()
+            .filter_map(|offset| i32::try_from(*offset).ok())
+            .collect();
         for _ in 1..=NUM_ENQUEUE_WORKERS {
             let worker = EnqueueWorker {
                 base_url: base_url.to_string(),
                 cancellation_token: cancellation_token.clone(),
                 db: db.clone(),
+                event_reminder_offsets_minutes: event_reminder_offsets_minutes.clone(),
             };
             task_tracker.spawn(async move {
                 worker.run().await;
@@ -143,10 +173,13 @@ impl PgNotificationsManager {
         // Setup and run workers to deliver notifications
         for _ in 1..=NUM_DELIVERY_WORKERS {
             let mut worker = DeliveryWorker {
+                base_url: base_url.to_string(),
                 cancellation_token: cancellation_token.clone(),
                 cfg: cfg.clone(),
+                channels: channels.clone(),
                 db: db.clone(),
                 email_sender: email_sender.clone(),
+                image_storage: image_storage.clone(),
             };
             task_tracker.spawn(async move {
                 worker.run().await;
@@ -173,6 +206,8 @@ struct EnqueueWorker {
     cancellation_token: CancellationToken,
     /// Database handle for notification queries.
     db: DynDB,
+    /// Offsets, in minutes before the event starts, of event reminders.
+    event_reminder_offsets_minutes: Vec<i32>,
 }
 
 impl EnqueueWorker {
@@ -199,7 +234,35 @@ impl EnqueueWorker {
     /// Enqueue due notifications and return the number enqueued.
     #[instrument(skip(self), err)]
     async fn enqueue_due_notifications(&self) -> Result<usize> {
-        self.db.enqueue_due_event_reminders(&self.base_url).await
+        let reminders = self
+            .db
+            .enqueue_due_event_reminders(&self.base_url, &self.event_reminder_offsets_minutes)
+            .await?;
+        let follow_ups = self.db.enqueue_due_event_follow_ups(&self.base_url).await?;
+        let certificates = self
+            .db
+            .enqueue_due_event_attendance_certificates(&self.base_url)
+            .await?;
+        let waitlist_promotions =
+            self.db.enqueue_due_event_waitlist_promotions(&self.base_url).await?;
+        let digests = self.db.enqueue_due_weekly_digests(&self.base_url).await?;
+        let newsletters = self
+            .db
+            .enqueue_due_community_newsletter_batches(&self.base_url, NEWSLETTER_BATCH_SIZE)
+            .await?;
+
+        // Refresh the dormant groups before reporting them to the communities admins
+        self.db.update_dormant_groups().await?;
+        let dormant_groups_reports =
+            self.db.enqueue_due_dormant_groups_reports(&self.base_url).await?;
+
+        Ok(reminders
+            + follow_ups
+            + certificates
+            + waitlist_promotions
+            + digests
+            + newsletters
+            + dormant_groups_reports)
     }
 }
 
@@ -248,14 +311,20 @@ impl DeliveryRecoveryWorker {
 
 /// Worker responsible for delivering notifications from the queue.
 struct DeliveryWorker {
+    /// Base URL used for generated links in attachments.
+    base_url: String,
     /// Token to signal worker shutdown.
     cancellation_token: CancellationToken,
     /// Email configuration for sending notifications.
     cfg: EmailConfig,
+    /// External channels for dispatching community and group messages.
+    channels: NotificationChannels,
     /// Database handle for notification queries.
     db: DynDB,
     /// Email sender for dispatching messages.
     email_sender: DynEmailSender,
+    /// Image storage used to load the logos included in attachments.
+    image_storage: DynImageStorage,
 }
 
 impl DeliveryWorker {
@@ -300,30 +369,208 @@ impl DeliveryWorker {
             return Ok(false);
         };
 
-        // Prepare and send the notification
-        match Self::prepare_content(&notification) {
-            Ok((subject, body)) => match self
-                .send_email_with_retries(
-                    &notification.email,
-                    subject.as_str(),
-                    body,
-                    &notification.attachments,
-                )
-                .await
-            {
-                Ok(()) => self.db.update_notification(&notification, None).await?,
-                Err(err) => self.record_delivery_error(&notification, err).await?,
-            },
-            Err(err) => {
-                self.db
-                    .update_notification(&notification, Some(err.to_string()))
+        // Deliver the notification through its channel
+        match &notification.recipient {
+            NotificationRecipient::Discord(webhook_url) => {
+                self.deliver_discord_message(&notification, webhook_url.as_deref())
+                    .await?;
+            }
+            NotificationRecipient::Email(to_address) => {
+                self.deliver_email(&notification, to_address).await?;
+            }
+            NotificationRecipient::Slack(webhook_url) => {
+                self.deliver_slack_message(&notification, webhook_url.as_deref())
                     .await?;
             }
+            NotificationRecipient::Sms(phone_number) => {
+                self.deliver_sms(&notification, phone_number.as_deref()).await?;
+            }
+            NotificationRecipient::Webhook(webhook) => {
+                self.deliver_webhook(&notification, webhook.as_ref()).await?;
+            }
         }
 
         Ok(true)
     }
 
+    /// Prepare and post a claimed notification to a group Discord channel.
+    async fn deliver_discord_message(
+        &self,
+        notification: &Notification,
+        webhook_url: Option<&str>,
+    ) -> Result<()> {
+        // The webhook may have been removed after the notification was enqueued
+        let Some(webhook_url) = webhook_url else {
+            let error = "group discord webhook is not configured".to_string();
+            return self.db.update_notification(notification, Some(error)).await;
+        };
+
+        match discord::prepare_message(notification) {
+            Ok(text) => match self.channels.discord.send(webhook_url, &text).await {
+                Ok(()) => self.db.update_notification(notification, None).await,
+                Err(err) => self.record_delivery_error(notification, err).await,
+            },
+            Err(err) => self.db.update_notification(notification, Some(err.to_string())).await,
+        }
+    }
+
+    /// Prepare and send a claimed notification by email.
+    async fn deliver_email(&self, notification: &Notification, to_address: &str) -> Result<()> {
+        match Self::prepare_content(notification) {
+            Ok((subject, body)) => {
+                let attachments = self.prepare_attachments(notification).await;
+                let from_name = notification
+                    .email_branding
+                    .as_ref()
+                    .and_then(|branding| branding.from_name.as_deref());
+                match self
+                    .send_email_with_retries(
+                        to_address,
+                        from_name,
+                        subject.as_str(),
+                        body,
+                        &attachments,
+                    )
+                    .await
+                {
+                    Ok(()) => self.db.update_notification(notification, None).await,
+                    Err(err) => self.record_delivery_error(notification, err).await,
+                }
+            }
+            Err(err) => self.db.update_notification(notification, Some(err.to_string())).await,
+        }
+    }
+
+    /// Prepare and post a claimed notification to a community Slack channel.
+    async fn deliver_slack_message(
+        &self,
+        notification: &Notification,
+        webhook_url: Option<&str>,
+    ) -> Result<()> {
+        // The webhook may have been removed after the notification was enqueued
+        let Some(webhook_url) = webhook_url else {
+            let error = "community slack webhook is not configured".to_string();
+            return self.db.update_notification(notification, Some(error)).await;
+        };
+
+        match slack::prepare_message(notification) {
+            Ok(text) => match self.channels.slack.send(webhook_url, &text).await {
+                Ok(()) => self.db.update_notification(notification, None).await,
+                Err(err) => self.record_delivery_error(notification, err).await,
+            },
+            Err(err) => self.db.update_notification(notification, Some(err.to_string())).await,
+        }
+    }
+
+    /// Prepare and send a claimed notification to a user by SMS.
+    async fn deliver_sms(
+        &self,
+        notification: &Notification,
+        phone_number: Option<&str>,
+    ) -> Result<()> {
+        // SMS delivery may have been disabled after the notification was enqueued
+        let Some(sms) = &self.channels.sms else {
+            let error = "sms notifications are not configured".to_string();
+            return self.db.update_notification(notification, Some(error)).await;
+        };
+        let Some(phone_number) = phone_number else {
+            let error = "user sms notifications are not enabled".to_string();
+            return self.db.update_notification(notification, Some(error)).await;
+        };
+
+        match sms::prepare_message(notification) {
+            Ok(text) => match sms.send(phone_number, &text).await {
+                Ok(()) => self.db.update_notification(notification, None).await,
+                Err(err) => self.record_delivery_error(notification, err).await,
+            },
+            Err(err) => self.db.update_notification(notification, Some(err.to_string())).await,
+        }
+    }
+
+    /// Prepare and post a claimed notification to a user webhook.
+    async fn deliver_webhook(
+        &self,
+        notification: &Notification,
+        webhook: Option<&UserWebhook>,
+    ) -> Result<()> {
+        // The webhook may have been removed after the notification was enqueued
+        let Some(webhook) = webhook else {
+            let error = "user webhook is not configured".to_string();
+            return self.db.update_notification(notification, Some(error)).await;
+        };
+
+        match webhook::prepare_payload(notification) {
+            Ok(payload) => match self.channels.webhook.send(webhook, &payload).await {
+                Ok(()) => self.db.update_notification(notification, None).await,
+                Err(err) => self.record_delivery_error(notification, err).await,
+            },
+            Err(err) => self.db.update_notification(notification, Some(err.to_string())).await,
+        }
+    }
+
+    /// Apply the community email branding overrides to the template theme.
+    fn apply_email_branding(
+        mut template_data: serde_json::Value,
+        branding: Option<&CommunityEmailBranding>,
+    ) -> serde_json::Value {
+        if let Some(branding) = branding
+            && let Some(theme) =
+                template_data.get_mut("theme").and_then(|theme| theme.as_object_mut())
+        {
+            let overrides = [
+                ("footer_text", &branding.footer_text),
+                ("logo_url", &branding.logo_url),
+                ("primary_color", &branding.primary_color),
+            ];
+            for (key, value) in overrides {
+                if let Some(value) = value {
+                    theme.insert(key.to_string(), serde_json::Value::String(value.clone()));
+                }
+            }
+        }
+        template_data
+    }
+
+    /// Prepare the attachments for a notification email.
+    ///
+    /// Event reminders and attendance certificates are enqueued by the
+    /// database, so their attachments are built at delivery time.
+    async fn prepare_attachments<'a>(
+        &self,
+        notification: &'a Notification,
+    ) -> Cow<'a, [Attachment]> {
+        if !notification.attachments.is_empty() {
+            return Cow::Borrowed(&notification.attachments);
+        }
+
+        match notification.kind {
+            NotificationKind::EventAttendanceCertificate => {
+                if let Some(template_data) = notification.template_data.clone()
+                    && let Ok(template) =
+                        serde_json::from_value::<EventAttendanceCertificate>(template_data)
+                {
+                    let attachment = build_attendance_certificate_attachment(
+                        &self.image_storage,
+                        &template.certificate,
+                    )
+                    .await;
+                    return Cow::Owned(vec![attachment]);
+                }
+            }
+            NotificationKind::EventReminder => {
+                if let Some(template_data) = notification.template_data.clone()
+                    && let Ok(template) = serde_json::from_value::<EventReminder>(template_data)
+                {
+                    let attachment =
+                        build_event_calendar_attachment(&self.base_url, &template.event);
+                    return Cow::Owned(vec![attachment]);
+                }
+            }
+            _ => {}
+        }
+        Cow::Borrowed(&notification.attachments)
+    }
+
     /// Prepare the subject and body for a notification email.
     #[allow(clippy::too_many_lines)]
     fn prepare_content(notification: &Notification) -> Result<(String, String)> {
@@ -331,8 +578,16 @@ impl DeliveryWorker {
             .template_data
             .clone()
             .ok_or_else(|| anyhow!("missing template data"))?;
+        let template_data =
+            Self::apply_email_branding(template_data, notification.email_branding.as_ref());
 
         let (subject, body) = match notification.kind {
+            NotificationKind::CommunityNewsletter => {
+                let template: CommunityNewsletter = serde_json::from_value(template_data)?;
+                let subject = template.subject.clone();
+                let body = template.render()?;
+                (subject, body)
+            }
             NotificationKind::CommunityTeamInvitation => {
                 let subject = "You have been invited to join a community team".to_string();
                 let template: CommunityTeamInvitation = serde_json::from_value(template_data)?;
@@ -345,9 +600,22 @@ impl DeliveryWorker {
                 let body = template.render()?;
                 (subject, body)
             }
+            NotificationKind::DormantGroupsReport => {
+                let template: DormantGroupsReport = serde_json::from_value(template_data)?;
+                let subject = format!("Dormant groups in {}", template.community_display_name);
+                let body = template.render()?;
+                (subject, body)
+            }
             NotificationKind::EmailVerification => {
-                let subject = "Verify your email address".to_string();
-                let template: EmailVerification = serde_json::from_value(template_data)?;
+                let mut template: EmailVerification = serde_json::from_value(template_data)?;
+                template.language = notification.language.unwrap_or(template.language);
+                let subject = template.language.tr("Verify your email address").to_string();
+                let body = template.render()?;
+                (subject, body)
+            }
+            NotificationKind::EventApproved => {
+                let template: EventApproved = serde_json::from_value(template_data)?;
+                let subject = format!("Event approved: {}", template.event.name);
                 let body = template.render()?;
                 (subject, body)
             }
@@ -357,6 +625,12 @@ impl DeliveryWorker {
                 let body = template.render()?;
                 (subject, body)
             }
+            NotificationKind::EventAttendanceCertificate => {
+                let template: EventAttendanceCertificate = serde_json::from_value(template_data)?;
+                let subject = format!("Your certificate of attendance for {}", template.event.name);
+                let body = template.render()?;
+                (subject, body)
+            }
             NotificationKind::EventCanceled => {
                 let subject = "Event canceled".to_string();
                 let template: EventCanceled = serde_json::from_value(template_data)?;
@@ -369,12 +643,25 @@ impl DeliveryWorker {
                 let body = template.render()?;
                 (subject, body)
             }
+            NotificationKind::EventFollowUp => {
+                let template: EventFollowUp = serde_json::from_value(template_data)?;
+                let subject = format!("Thanks for attending {}", template.event.name);
+                let body = template.render()?;
+                (subject, body)
+            }
             NotificationKind::EventInvitation => {
                 let subject = "You have been invited to an event".to_string();
                 let template: EventInvitation = serde_json::from_value(template_data)?;
                 let body = template.render()?;
                 (subject, body)
             }
+            NotificationKind::EventInvitationRequestRejected => {
+                let subject = "Attendance request declined".to_string();
+                let template: EventInvitationRequestRejected =
+                    serde_json::from_value(template_data)?;
+                let body = template.render()?;
+                (subject, body)
+            }
             NotificationKind::EventPublished => {
                 let subject = "New event published".to_string();
                 let template: EventPublished = serde_json::from_value(template_data)?;
@@ -399,15 +686,25 @@ impl DeliveryWorker {
                 let body = template.render()?;
                 (subject, body)
             }
+            NotificationKind::EventRejected => {
+                let template: EventRejected = serde_json::from_value(template_data)?;
+                let subject = format!("Event not approved: {}", template.event.name);
+                let body = template.render()?;
+                (subject, body)
+            }
             NotificationKind::EventReminder => {
                 let template: EventReminder = serde_json::from_value(template_data)?;
-                let subject = format!("Reminder: {} starts in 24 hours", template.event.name);
+                let subject = format!(
+                    "Reminder: {} starts in {}",
+                    template.event.name,
+                    template.starts_in()
+                );
                 let body = template.render()?;
                 (subject, body)
             }
             NotificationKind::EventRescheduled => {
-                let subject = "Event rescheduled".to_string();
                 let template: EventRescheduled = serde_json::from_value(template_data)?;
+                let subject = template.subject();
                 let body = template.render()?;
                 (subject, body)
             }
@@ -442,8 +739,15 @@ impl DeliveryWorker {
                 (subject, body)
             }
             NotificationKind::EventWelcome => {
-                let subject = "Welcome to the event".to_string();
-                let template: EventWelcome = serde_json::from_value(template_data)?;
+                let mut template: EventWelcome = serde_json::from_value(template_data)?;
+                template.language = notification.language.unwrap_or(template.language);
+                let subject = template.language.tr("Welcome to the event").to_string();
+                let body = template.render()?;
+                (subject, body)
+            }
+            NotificationKind::GroupContactMessage => {
+                let template: GroupContactMessage = serde_json::from_value(template_data)?;
+                let subject = format!("[{}] {}", template.group.name, template.subject);
                 let body = template.render()?;
                 (subject, body)
             }
@@ -460,8 +764,23 @@ impl DeliveryWorker {
                 (subject, body)
             }
             NotificationKind::GroupWelcome => {
-                let subject = "Welcome to the group".to_string();
-                let template: GroupWelcome = serde_json::from_value(template_data)?;
+                let mut template: GroupWelcome = serde_json::from_value(template_data)?;
+                template.language = notification.language.unwrap_or(template.language);
+                let subject = template.language.tr("Welcome to the group").to_string();
+                let body = template.render()?;
+                (subject, body)
+            }
+            NotificationKind::MagicLink => {
+                let mut template: MagicLink = serde_json::from_value(template_data)?;
+                template.language = notification.language.unwrap_or(template.language);
+                let subject = template.language.tr("Your log in link").to_string();
+                let body = template.render()?;
+                (subject, body)
+            }
+            NotificationKind::PasswordReset => {
+                let mut template: PasswordReset = serde_json::from_value(template_data)?;
+                template.language = notification.language.unwrap_or(template.language);
+                let subject = template.language.tr("Reset your password").to_string();
                 let body = template.render()?;
                 (subject, body)
             }
@@ -472,6 +791,12 @@ impl DeliveryWorker {
                 let body = template.render()?;
                 (subject, body)
             }
+            NotificationKind::PhoneVerification => {
+                return Err(anyhow!(
+                    "notification kind not supported by email: {}",
+                    notification.kind
+                ));
+            }
             NotificationKind::SpeakerSeriesWelcome => {
                 let subject = "You're speaking at upcoming events".to_string();
                 let template: SpeakerSeriesWelcome = serde_json::from_value(template_data)?;
@@ -484,6 +809,12 @@ impl DeliveryWorker {
                 let body = template.render()?;
                 (subject, body)
             }
+            NotificationKind::WeeklyDigest => {
+                let template: WeeklyDigest = serde_json::from_value(template_data)?;
+                let subject = format!("Upcoming events in {}", template.community_display_name);
+                let body = template.render()?;
+                (subject, body)
+            }
         };
 
         Ok((subject, body))
@@ -493,12 +824,12 @@ impl DeliveryWorker {
     async fn record_delivery_error(
         &self,
         notification: &Notification,
-        err: EmailDeliveryError,
+        err: DeliveryError,
     ) -> Result<()> {
         // Persist the safest recovery action for the classified delivery failure
         let error = err.to_string();
         match err {
-            EmailDeliveryError::Retryable(_) => {
+            DeliveryError::Retryable(_) => {
                 self.db
                     .requeue_notification(
                         notification,
@@ -509,47 +840,36 @@ impl DeliveryWorker {
                     )
                     .await
             }
-            EmailDeliveryError::Terminal(_) => {
+            DeliveryError::Terminal(_) => {
                 self.db.update_notification(notification, Some(error)).await
             }
-            EmailDeliveryError::Unknown(_) => {
+            DeliveryError::Unknown(_) => {
                 self.db.mark_notification_delivery_unknown(notification, &error).await
             }
         }
     }
 
     /// Send an email to the specified address with the given subject and body.
+    ///
+    /// The configured sender name is used unless a community one is provided.
     async fn send_email(
         &self,
         to_address: &str,
+        from_name: Option<&str>,
         subject: &str,
         body: String,
         attachments: &[Attachment],
-    ) -> std::result::Result<(), EmailDeliveryError> {
-        // Prepare email message
-        let body_part = SinglePart::builder().header(ContentType::TEXT_HTML).body(body);
-        let builder = MessageBuilder::new()
-            .from(Mailbox::new(
-                Some(self.cfg.from_name.clone()),
-                self.cfg.from_address.parse().map_err(EmailDeliveryError::terminal)?,
-            ))
-            .to(to_address.parse().map_err(EmailDeliveryError::terminal)?)
-            .subject(subject);
-        let message = if attachments.is_empty() {
-            builder.singlepart(body_part).map_err(EmailDeliveryError::terminal)?
-        } else {
-            let mut multipart = MultiPart::mixed().singlepart(body_part);
-            for attachment in attachments {
-                let attachment_part = SinglePart::builder()
-                    .header(
-                        ContentType::parse(&attachment.content_type)
-                            .map_err(EmailDeliveryError::terminal)?,
-                    )
-                    .header(ContentDisposition::attachment(&attachment.file_name))
-                    .body(attachment.data.clone());
-                multipart = multipart.singlepart(attachment_part);
-            }
-            builder.multipart(multipart).map_err(EmailDeliveryError::terminal)?
+    ) -> std::result::Result<(), DeliveryError> {
+        // Prepare email
+        let email = Email {
+            attachments: attachments.to_vec(),
+            body,
+            from: Mailbox::new(
+                Some(from_name.unwrap_or(&self.cfg.from_name).to_string()),
+                self.cfg.from_address.parse().map_err(DeliveryError::terminal)?,
+            ),
+            subject: subject.to_string(),
+            to: to_address.parse().map_err(DeliveryError::terminal)?,
         };
 
         // Send email
@@ -561,7 +881,7 @@ impl DeliveryWorker {
                 return Ok(());
             }
         }
-        self.email_sender.send(message).await?;
+        self.email_sender.send(email).await?;
 
         Ok(())
     }
@@ -570,13 +890,17 @@ impl DeliveryWorker {
     async fn send_email_with_retries(
         &self,
         to_address: &str,
+        from_name: Option<&str>,
         subject: &str,
         body: String,
         attachments: &[Attachment],
-    ) -> std::result::Result<(), EmailDeliveryError> {
+    ) -> std::result::Result<(), DeliveryError> {
         let mut attempt = 1;
         loop {
-            match self.send_email(to_address, subject, body.clone(), attachments).await {
+            match self
+                .send_email(to_address, from_name, subject, body.clone(), attachments)
+                .await
+            {
                 Ok(()) => return Ok(()),
                 Err(err) if attempt < DELIVERY_SEND_MAX_ATTEMPTS && err.is_retryable() => {
                     warn!(
@@ -600,13 +924,52 @@ impl DeliveryWorker {
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub(crate) trait EmailSender {
-    /// Send an email represented by the provided message.
-    async fn send(&self, message: Message) -> std::result::Result<(), EmailDeliveryError>;
+    /// Send the email provided.
+    async fn send(&self, email: Email) -> std::result::Result<(), DeliveryError>;
 }
 
 /// Shared trait object for an email sender.
 pub(crate) type DynEmailSender = Arc<dyn EmailSender + Send + Sync>;
 
+/// Trait representing an external channel notifications are posted to.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub(crate) trait NotificationChannel {
+    /// Post a text message to the channel destination provided.
+    async fn send(&self, destination: &str, text: &str) -> std::result::Result<(), DeliveryError>;
+}
+
+/// Shared trait object for a notification channel.
+pub(crate) type DynNotificationChannel = Arc<dyn NotificationChannel + Send + Sync>;
+
+/// Trait representing a sender of signed notification payloads to user webhooks.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub(crate) trait WebhookSender {
+    /// Post the payload provided to the user webhook.
+    async fn send(
+        &self,
+        webhook: &UserWebhook,
+        payload: &webhook::WebhookPayload,
+    ) -> std::result::Result<(), DeliveryError>;
+}
+
+/// Shared trait object for a webhook sender.
+pub(crate) type DynWebhookSender = Arc<dyn WebhookSender + Send + Sync>;
+
+/// External channels notifications can be delivered through, besides email.
+#[derive(Clone)]
+pub(crate) struct NotificationChannels {
+    /// Channel used to post group notifications to Discord.
+    pub discord: DynNotificationChannel,
+    /// Channel used to post community notifications to Slack.
+    pub slack: DynNotificationChannel,
+    /// Channel used to send user notifications by SMS, if configured.
+    pub sms: Option<DynNotificationChannel>,
+    /// Sender used to post user notifications to their webhooks.
+    pub webhook: DynWebhookSender,
+}
+
 /// Concrete email sender backed by a Lettre SMTP transport.
 pub(crate) struct LettreEmailSender {
     /// SMTP transport used to deliver messages.
@@ -615,42 +978,83 @@ pub(crate) struct LettreEmailSender {
 
 impl LettreEmailSender {
     /// Create a new `LettreEmailSender` from the provided config.
-    pub(crate) fn new(cfg: &EmailConfig) -> Result<Self> {
+    pub(crate) fn new(cfg: &SmtpConfig) -> Result<Self> {
         let transport = Self::transport_builder(cfg)?
-            .credentials(Credentials::new(
-                cfg.smtp.username.clone(),
-                cfg.smtp.password.clone(),
-            ))
+            .credentials(Credentials::new(cfg.username.clone(), cfg.password.clone()))
             .build();
 
         Ok(Self { transport })
     }
 
     /// Create a SMTP transport builder for the configured server.
-    fn transport_builder(cfg: &EmailConfig) -> Result<AsyncSmtpTransportBuilder> {
+    fn transport_builder(cfg: &SmtpConfig) -> Result<AsyncSmtpTransportBuilder> {
         // Use implicit TLS on port 465 and STARTTLS on other submission ports
-        let builder = if cfg.smtp.port == SUBMISSIONS_PORT {
-            AsyncSmtpTransport::<Tokio1Executor>::relay(&cfg.smtp.host)?
+        let builder = if cfg.port == SUBMISSIONS_PORT {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&cfg.host)?
         } else {
-            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&cfg.smtp.host)?
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&cfg.host)?
         };
 
-        Ok(builder.port(cfg.smtp.port))
+        Ok(builder.port(cfg.port))
     }
 }
 
 #[async_trait]
 impl EmailSender for LettreEmailSender {
     /// [`EmailSender::send`].
-    async fn send(&self, message: Message) -> std::result::Result<(), EmailDeliveryError> {
-        self.transport
-            .send(message)
-            .await
-            .map_err(EmailDeliveryError::from_smtp)?;
+    async fn send(&self, email: Email) -> std::result::Result<(), DeliveryError> {
+        let message = email.to_message()?;
+        self.transport.send(message).await.map_err(DeliveryError::from_smtp)?;
         Ok(())
     }
 }
 
+/// Email ready to be sent by an email sender.
+#[derive(Debug, Clone)]
+pub(crate) struct Email {
+    /// Files attached to the email.
+    pub attachments: Vec<Attachment>,
+    /// HTML body of the email.
+    pub body: String,
+    /// Sender of the email.
+    pub from: Mailbox,
+    /// Subject of the email.
+    pub subject: String,
+    /// Recipient of the email.
+    pub to: Mailbox,
+}
+
+impl Email {
+    /// Build the MIME message used by senders that submit raw messages.
+    pub(crate) fn to_message(&self) -> std::result::Result<Message, DeliveryError> {
+        let body_part = SinglePart::builder()
+            .header(ContentType::TEXT_HTML)
+            .body(self.body.clone());
+        let builder = MessageBuilder::new()
+            .from(self.from.clone())
+            .to(self.to.clone())
+            .subject(&self.subject);
+        let message = if self.attachments.is_empty() {
+            builder.singlepart(body_part).map_err(DeliveryError::terminal)?
+        } else {
+            let mut multipart = MultiPart::mixed().singlepart(body_part);
+            for attachment in &self.attachments {
+                let attachment_part = SinglePart::builder()
+                    .header(
+                        ContentType::parse(&attachment.content_type)
+                            .map_err(DeliveryError::terminal)?,
+                    )
+                    .header(ContentDisposition::attachment(&attachment.file_name))
+                    .body(attachment.data.clone());
+                multipart = multipart.singlepart(attachment_part);
+            }
+            builder.multipart(multipart).map_err(DeliveryError::terminal)?
+        };
+
+        Ok(message)
+    }
+}
+
 /// Represents a file that should be sent with a notification.
 #[derive(Debug, Clone)]
 pub(crate) struct Attachment {
@@ -662,9 +1066,9 @@ pub(crate) struct Attachment {
     pub file_name: String,
 }
 
-/// Error returned while sending an email, classified by its safe recovery action.
+/// Error returned while delivering a notification, classified by its safe recovery action.
 #[derive(Debug)]
-pub(crate) enum EmailDeliveryError {
+pub(crate) enum DeliveryError {
     /// Failure that can be retried without risking duplicate delivery.
     Retryable(anyhow::Error),
     /// Failure that cannot succeed without changing the message or configuration.
@@ -673,7 +1077,7 @@ pub(crate) enum EmailDeliveryError {
     Unknown(anyhow::Error),
 }
 
-impl EmailDeliveryError {
+impl DeliveryError {
     /// Classifies a Lettre SMTP error by the safest recovery action.
     fn from_smtp(err: SmtpError) -> Self {
         // Extract transport metadata before preserving the original error source
@@ -713,6 +1117,26 @@ impl EmailDeliveryError {
         }
     }
 
+    /// Classifies a webhook request error by the safest recovery action.
+    fn from_webhook_request(err: reqwest::Error) -> Self {
+        // Nothing was posted if the connection could not be established
+        if err.is_connect() || err.is_builder() {
+            Self::Retryable(err.into())
+        } else {
+            Self::Unknown(err.into())
+        }
+    }
+
+    /// Classifies a webhook error response by the safest recovery action.
+    fn from_webhook_status(status: StatusCode, err: anyhow::Error) -> Self {
+        // Webhooks do not post the message when they return an error
+        if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+            Self::Retryable(err)
+        } else {
+            Self::Terminal(err)
+        }
+    }
+
     /// Returns whether retrying cannot duplicate a potentially delivered email.
     fn is_retryable(&self) -> bool {
         matches!(self, Self::Retryable(_))
@@ -724,7 +1148,7 @@ impl EmailDeliveryError {
     }
 }
 
-impl std::fmt::Display for EmailDeliveryError {
+impl std::fmt::Display for DeliveryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Retryable(err) | Self::Terminal(err) | Self::Unknown(err) => write!(f, "{err}"),
@@ -732,7 +1156,7 @@ impl std::fmt::Display for EmailDeliveryError {
     }
 }
 
-impl std::error::Error for EmailDeliveryError {
+impl std::error::Error for DeliveryError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Retryable(err) | Self::Terminal(err) | Self::Unknown(err) => Some(err.as_ref()),
@@ -754,43 +1178,74 @@ pub(crate) struct NewNotification {
     pub template_data: Option<serde_json::Value>,
 }
 
-/// Data required to deliver a notification to a user.
+/// Data required to deliver a notification to a user, community or group channel.
 #[derive(Debug, Clone)]
 pub(crate) struct Notification {
     /// Files included with the notification.
     pub attachments: Vec<Attachment>,
     /// Timestamp identifying the active delivery claim.
     pub delivery_claimed_at: DateTime<Utc>,
-    /// Email address to send the notification to.
-    pub email: String,
+    /// Branding of the community the email belongs to, if any.
+    pub email_branding: Option<CommunityEmailBranding>,
     /// The type of notification.
     pub kind: NotificationKind,
+    /// Language preferred by the user the notification is delivered to, if any.
+    pub language: Option<Language>,
     /// Unique identifier for the notification.
     pub notification_id: Uuid,
+    /// Destination the notification is delivered to.
+    pub recipient: NotificationRecipient,
 
     /// Optional template data for the notification content.
     pub template_data: Option<serde_json::Value>,
 }
 
+/// Destination of a notification, depending on its delivery channel.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum NotificationRecipient {
+    /// Discord webhook URL of the group to notify, if still configured.
+    Discord(Option<String>),
+    /// Email address of the user to notify.
+    Email(String),
+    /// Slack incoming webhook URL of the community to notify, if still configured.
+    Slack(Option<String>),
+    /// Phone number of the user to notify, if still verified and opted in.
+    Sms(Option<String>),
+    /// Webhook of the user to notify, if still configured.
+    Webhook(Option<UserWebhook>),
+}
+
 /// Supported notification types.
-#[derive(Debug, Clone, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
 #[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub(crate) enum NotificationKind {
     /// Notification for a CFS submission update.
     CfsSubmissionUpdated,
+    /// Notification delivering a community newsletter issue to its subscribers.
+    CommunityNewsletter,
     /// Notification for a community team invitation.
     CommunityTeamInvitation,
+    /// Notification for the monthly report of dormant groups sent to community admins.
+    DormantGroupsReport,
     /// Notification for email verification.
     EmailVerification,
+    /// Notification for an event approved by the community team.
+    EventApproved,
     /// Notification for a canceled event attendance.
     EventAttendanceCanceled,
+    /// Notification delivering the certificate of attendance to checked-in attendees.
+    EventAttendanceCertificate,
     /// Notification for an event canceled.
     EventCanceled,
     /// Notification for a custom event message.
     EventCustom,
+    /// Notification following up with checked-in attendees after an event ends.
+    EventFollowUp,
     /// Notification for an organizer-created event invitation.
     EventInvitation,
+    /// Notification for a rejected event invitation request.
+    EventInvitationRequestRejected,
     /// Notification for an event published.
     EventPublished,
     /// Notification for an approved refund.
@@ -799,6 +1254,8 @@ pub(crate) enum NotificationKind {
     EventRefundRejected,
     /// Notification for a newly requested refund.
     EventRefundRequested,
+    /// Notification for an event rejected by the community team.
+    EventRejected,
     /// Notification reminding users about an upcoming event.
     EventReminder,
     /// Notification for an event rescheduled.
@@ -815,18 +1272,28 @@ pub(crate) enum NotificationKind {
     EventWaitlistPromoted,
     /// Notification welcoming a new event attendee.
     EventWelcome,
+    /// Notification for a message sent to the group organizers.
+    GroupContactMessage,
     /// Notification for a custom group message.
     GroupCustom,
     /// Notification for a group team invitation.
     GroupTeamInvitation,
     /// Notification welcoming a new group member.
     GroupWelcome,
+    /// Notification with a link the user can log in with.
+    MagicLink,
+    /// Notification with a link the user can reset their password with.
+    PasswordReset,
+    /// Notification with the code to verify a user's phone number.
+    PhoneVerification,
     /// Notification inviting a co-speaker to respond to a session proposal invitation.
     SessionProposalCoSpeakerInvitation,
     /// Notification welcoming a speaker to multiple events in a linked series.
     SpeakerSeriesWelcome,
     /// Notification welcoming a speaker to an event.
     SpeakerWelcome,
+    /// Notification with the upcoming events of the user's groups in a community.
+    WeeklyDigest,
 }
 
 /// SMTP failure category relevant to notification recovery.
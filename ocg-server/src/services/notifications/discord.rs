@@ -0,0 +1,171 @@
+//! Discord channel used to post group notifications.
+
+use std::time::Duration;
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+use reqwest::Client as HttpClient;
+use serde_json::json;
+
+use crate::{
+    templates::notifications::{EventPublished, EventReminder},
+    types::event::EventSummary,
+};
+
+use super::{DeliveryError, Notification, NotificationChannel, NotificationKind};
+
+/// Timeout for HTTP requests to Discord webhooks.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Notification channel that posts messages to Discord webhooks.
+pub(crate) struct DiscordNotificationChannel {
+    /// HTTP client used to call the webhooks.
+    http_client: HttpClient,
+}
+
+impl DiscordNotificationChannel {
+    /// Create a new `DiscordNotificationChannel`.
+    pub(crate) fn new() -> Self {
+        let http_client = HttpClient::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+
+        Self { http_client }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for DiscordNotificationChannel {
+    /// [`NotificationChannel::send`].
+    async fn send(&self, destination: &str, text: &str) -> Result<(), DeliveryError> {
+        // Mentions are disabled so event names cannot ping the channel members
+        let response = self
+            .http_client
+            .post(destination)
+            .json(&json!({
+                "allowed_mentions": { "parse": [] },
+                "content": text,
+            }))
+            .send()
+            .await
+            .map_err(DeliveryError::from_webhook_request)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(DeliveryError::from_webhook_status(
+            status,
+            anyhow!("discord webhook request failed ({status}): {body}"),
+        ))
+    }
+}
+
+/// Prepare the Discord message content for a group notification.
+pub(crate) fn prepare_message(notification: &Notification) -> Result<String> {
+    let template_data = notification
+        .template_data
+        .clone()
+        .ok_or_else(|| anyhow!("missing template data"))?;
+
+    let content = match notification.kind {
+        NotificationKind::EventPublished => {
+            let template: EventPublished = serde_json::from_value(template_data)?;
+            format!(
+                "New event published: [{}](<{}>){}",
+                escape(&template.event.name),
+                template.link,
+                format_starts_at(&template.event),
+            )
+        }
+        NotificationKind::EventReminder => {
+            let template: EventReminder = serde_json::from_value(template_data)?;
+            format!(
+                "Reminder: [{}](<{}>){}",
+                escape(&template.event.name),
+                template.link,
+                format_starts_at(&template.event),
+            )
+        }
+        _ => bail!(
+            "notification kind not supported by discord: {}",
+            notification.kind
+        ),
+    };
+
+    Ok(content)
+}
+
+/// Escape the characters Discord uses for markdown formatting.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '*' | '_' | '~' | '`' | '|' | '>' | '#' | '-' | '[' | ']' | '(' | ')'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Format the event start time so Discord renders it in each reader's timezone.
+fn format_starts_at(event: &EventSummary) -> String {
+    let Some(starts_at) = event.starts_at else {
+        return String::new();
+    };
+    format!(" starts <t:{}:F>", starts_at.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::services::notifications::{Notification, NotificationKind, NotificationRecipient};
+
+    use super::{escape, prepare_message};
+
+    #[test]
+    fn escape_replaces_markdown_characters() {
+        assert_eq!(escape("[K8s] *Day* 2_0"), "\\[K8s\\] \\*Day\\* 2\\_0");
+    }
+
+    #[test]
+    fn prepare_message_rejects_unsupported_kinds() {
+        let notification = sample_notification(NotificationKind::GroupWelcome, json!({}));
+
+        assert!(prepare_message(&notification).is_err());
+    }
+
+    #[test]
+    fn prepare_message_requires_template_data() {
+        let mut notification = sample_notification(NotificationKind::EventReminder, json!({}));
+        notification.template_data = None;
+
+        assert!(prepare_message(&notification).is_err());
+    }
+
+    fn sample_notification(
+        kind: NotificationKind,
+        template_data: serde_json::Value,
+    ) -> Notification {
+        Notification {
+            attachments: vec![],
+            delivery_claimed_at: Utc::now(),
+            email_branding: None,
+            kind,
+            language: None,
+            notification_id: Uuid::new_v4(),
+            recipient: NotificationRecipient::Discord(Some(
+                "https://discord.com/api/webhooks/1/token".to_string(),
+            )),
+            template_data: Some(template_data),
+        }
+    }
+}
@@ -9,26 +9,66 @@ use uuid::Uuid;
 use crate::{
     config::HttpServerConfig,
     db::DBOperations,
+    i18n::Language,
     services::notifications::{
         NewNotification, NotificationKind, load_event_notification_context,
         payloads::{
-            build_event_attendance_canceled_notification, build_event_canceled_notification,
-            build_event_published_notification, build_event_rescheduled_notification,
-            build_event_waitlist_promoted_notification, build_event_welcome_notification,
-            build_speaker_welcome_notification, should_send_waitlist_promoted_notification,
+            build_event_approved_notification, build_event_attendance_canceled_notification,
+            build_event_canceled_notification,
+            build_event_invitation_request_rejected_notification,
+            build_event_published_notification, build_event_rejected_notification,
+            build_event_rescheduled_notification, build_event_waitlist_promoted_notification,
+            build_event_welcome_notification, build_speaker_welcome_notification,
+            should_send_waitlist_promoted_notification,
         },
     },
     templates::notifications::{
-        EventSeriesCanceled, EventSeriesNotificationItem, EventSeriesPublished,
+        EventSeriesCanceled, EventSeriesNotificationItem, EventSeriesPublished, GroupWelcome,
         SpeakerSeriesWelcome,
     },
     types::event::{EventFull, EventSummary},
     util::{base_url_without_trailing_slash, build_event_page_link},
 };
 
+/// Maximum length of the previous location displayed in reschedule notifications.
+const MAX_RESCHEDULE_LOCATION_LEN: usize = 90;
+
 /// Minimum shift required to notify a reschedule.
 const MIN_RESCHEDULE_SHIFT: TimeDelta = TimeDelta::minutes(15);
 
+/// Enqueues the notifications required when the community team approves an event.
+///
+/// The group team is told about the approval and, as the event has just been
+/// published, the usual publication announcements are sent for upcoming events.
+pub(crate) async fn enqueue_event_approved_notifications(
+    db: &dyn DBOperations,
+    server_cfg: &HttpServerConfig,
+    community_id: Uuid,
+    group_id: Uuid,
+    event_id: Uuid,
+) -> Result<()> {
+    // Fetch notification context and group team after the approval
+    let ((event, site_settings), team_member_ids) = tokio::try_join!(
+        load_event_notification_context(db, community_id, event_id),
+        db.list_group_team_members_ids(group_id)
+    )?;
+
+    // Let the group team know the event has been approved
+    if !team_member_ids.is_empty() {
+        let notification =
+            build_event_approved_notification(&event, team_member_ids, server_cfg, &site_settings)?;
+        db.enqueue_notification(&notification).await?;
+    }
+
+    // Announce the publication of upcoming events
+    if matches!(event.starts_at, Some(starts_at) if !event.test_event && starts_at > Utc::now()) {
+        enqueue_event_published_notifications(db, server_cfg, community_id, group_id, event_id)
+            .await?;
+    }
+
+    Ok(())
+}
+
 /// Enqueues notifications required by event attendance cancellation.
 pub(crate) async fn enqueue_event_attendance_cancellation_notifications(
     db: &dyn DBOperations,
@@ -114,6 +154,30 @@ pub(crate) async fn enqueue_event_canceled_notification(
     Ok(())
 }
 
+/// Enqueues the notification sent when an event invitation request is rejected.
+pub(crate) async fn enqueue_event_invitation_request_rejected_notification(
+    db: &dyn DBOperations,
+    server_cfg: &HttpServerConfig,
+    community_id: Uuid,
+    event_id: Uuid,
+    user_id: Uuid,
+) -> Result<()> {
+    // Fetch notification context after the request review
+    let (event, site_settings) =
+        load_event_notification_context(db, community_id, event_id).await?;
+
+    // Build and enqueue the rejection notification
+    let notification = build_event_invitation_request_rejected_notification(
+        &event,
+        user_id,
+        server_cfg,
+        &site_settings,
+    )?;
+    db.enqueue_notification(&notification).await?;
+
+    Ok(())
+}
+
 /// Enqueues event-published notifications to group members, team members, and speakers.
 pub(crate) async fn enqueue_event_published_notifications(
     db: &dyn DBOperations,
@@ -151,22 +215,16 @@ pub(crate) async fn enqueue_event_published_notifications(
         .collect();
     let has_members = !recipients.is_empty();
 
-    if !has_members && !has_speakers {
-        return Ok(());
-    }
-
     // Prepare common notification data
     let site_settings = db.get_site_settings().await?;
     let event_summary = EventSummary::from(&event_full);
 
-    // Enqueue group member notifications about the published event
+    // Enqueue group member, community and group channel notifications about the published event
+    let notification =
+        build_event_published_notification(&event_summary, recipients, server_cfg, &site_settings)?;
+    db.enqueue_community_notification(community_id, &notification).await?;
+    db.enqueue_group_notification(group_id, &notification).await?;
     if has_members {
-        let notification = build_event_published_notification(
-            &event_summary,
-            recipients,
-            server_cfg,
-            &site_settings,
-        )?;
         db.enqueue_notification(&notification).await?;
     }
 
@@ -184,7 +242,41 @@ pub(crate) async fn enqueue_event_published_notifications(
     Ok(())
 }
 
-/// Enqueues reschedule notifications when an update moves a future published event.
+/// Enqueues the notification sent to the group team when the community team rejects
+/// an event.
+pub(crate) async fn enqueue_event_rejected_notification(
+    db: &dyn DBOperations,
+    server_cfg: &HttpServerConfig,
+    community_id: Uuid,
+    group_id: Uuid,
+    event_id: Uuid,
+    reason: Option<String>,
+) -> Result<()> {
+    // Fetch notification context and group team after the rejection
+    let ((event, site_settings), team_member_ids) = tokio::try_join!(
+        load_event_notification_context(db, community_id, event_id),
+        db.list_group_team_members_ids(group_id)
+    )?;
+
+    if team_member_ids.is_empty() {
+        return Ok(());
+    }
+
+    // Build and enqueue the rejection notification
+    let notification = build_event_rejected_notification(
+        &event,
+        reason,
+        team_member_ids,
+        server_cfg,
+        &site_settings,
+    )?;
+    db.enqueue_notification(&notification).await?;
+
+    Ok(())
+}
+
+/// Enqueues reschedule notifications when an update moves a future published event
+/// to a different date or location.
 pub(crate) async fn enqueue_event_rescheduled_notification(
     db: &dyn DBOperations,
     server_cfg: &HttpServerConfig,
@@ -198,15 +290,26 @@ pub(crate) async fn enqueue_event_rescheduled_notification(
         return Ok(());
     }
 
-    // Fetch updated event summary to compare start times and detect reschedule
+    // Fetch updated event summary to compare start times and locations
     let after = db.get_event_summary(community_id, group_id, event_id).await?;
-    let should_notify = match (before.published, before.starts_at, after.starts_at) {
-        (true, Some(b_starts_at), Some(a_starts_at)) if a_starts_at > Utc::now() => {
-            (a_starts_at - b_starts_at).abs() >= MIN_RESCHEDULE_SHIFT
+    let is_upcoming_published =
+        before.published && after.starts_at.is_some_and(|ts| ts > Utc::now());
+    if !is_upcoming_published {
+        return Ok(());
+    }
+    let previous_starts_at = match (before.starts_at, after.starts_at) {
+        (Some(b_starts_at), Some(a_starts_at))
+            if (a_starts_at - b_starts_at).abs() >= MIN_RESCHEDULE_SHIFT =>
+        {
+            Some(b_starts_at)
         }
-        _ => false,
+        _ => None,
     };
-    if !should_notify {
+    let previous_location = before
+        .location(usize::MAX)
+        .filter(|location| Some(location) != after.location(usize::MAX).as_ref())
+        .map(|location| before.location(MAX_RESCHEDULE_LOCATION_LEN).unwrap_or(location));
+    if previous_starts_at.is_none() && previous_location.is_none() {
         return Ok(());
     }
 
@@ -233,6 +336,8 @@ pub(crate) async fn enqueue_event_rescheduled_notification(
     let event_summary = EventSummary::from(&event_full);
     let notification = build_event_rescheduled_notification(
         &event_summary,
+        previous_starts_at,
+        previous_location,
         recipients,
         server_cfg,
         &site_settings,
@@ -460,14 +565,16 @@ pub(crate) async fn enqueue_event_welcome_notification(
     user_id: Uuid,
     include_dashboard_link: bool,
 ) -> Result<()> {
-    // Fetch notification context after the attendance mutation
+    // Fetch notification context and attendee ticket after the attendance mutation
     let (event, site_settings) =
         load_event_notification_context(db, community_id, event_id).await?;
+    let ticket_code = db.get_event_ticket_code(community_id, event_id, user_id).await?;
 
     // Build and enqueue the attendee welcome notification
     let notification = build_event_welcome_notification(
         &event,
         user_id,
+        ticket_code,
         server_cfg,
         &site_settings,
         include_dashboard_link,
@@ -477,6 +584,48 @@ pub(crate) async fn enqueue_event_welcome_notification(
     Ok(())
 }
 
+/// Enqueues the group welcome notification after membership becomes confirmed.
+pub(crate) async fn enqueue_group_welcome_notification(
+    db: &dyn DBOperations,
+    server_cfg: &HttpServerConfig,
+    community_id: Uuid,
+    group_id: Uuid,
+    user_id: Uuid,
+) -> Result<()> {
+    // Fetch notification context after the membership mutation
+    let (site_settings, group, welcome_message) = tokio::try_join!(
+        db.get_site_settings(),
+        db.get_group_summary(community_id, group_id),
+        db.get_group_welcome_message(group_id)
+    )?;
+
+    // Build and enqueue the member welcome notification
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let template_data = GroupWelcome {
+        link: format!(
+            "{}/{}/group/{}",
+            base_url,
+            group.community_name,
+            group.public_slug()
+        ),
+        group,
+        theme: site_settings.theme,
+
+        language: Language::default(),
+        welcome_message,
+    };
+    let notification = NewNotification {
+        attachments: vec![],
+        kind: NotificationKind::GroupWelcome,
+        recipients: vec![user_id],
+        template_data: Some(serde_json::to_value(&template_data)?),
+    };
+    db.enqueue_notification(&notification).await?;
+    db.enqueue_community_notification(community_id, &notification).await?;
+
+    Ok(())
+}
+
 // Types.
 
 /// Recipient group sharing the same event list for one aggregate notification.
@@ -844,6 +993,20 @@ mod tests {
         db.expect_get_site_settings()
             .times(1)
             .returning(|| Ok(sample_site_settings()));
+        db.expect_enqueue_community_notification()
+            .times(1)
+            .withf(move |cid, notification| {
+                *cid == community_id
+                    && notification.kind.to_string() == NotificationKind::EventPublished.to_string()
+            })
+            .returning(|_, _| Ok(()));
+        db.expect_enqueue_group_notification()
+            .times(1)
+            .withf(move |gid, notification| {
+                *gid == group_id
+                    && notification.kind.to_string() == NotificationKind::EventPublished.to_string()
+            })
+            .returning(|_, _| Ok(()));
         let notifications_for_mock = notifications.clone();
         db.expect_enqueue_notification()
             .times(2)
@@ -936,6 +1099,7 @@ mod tests {
         let group_id = Uuid::new_v4();
         let speaker_id = Uuid::new_v4();
         let before = sample_future_event_summary(event_id, group_id);
+        let before_starts_at = before.starts_at;
         let after = EventSummary {
             starts_at: before.starts_at.map(|starts_at| starts_at + Duration::minutes(30)),
             ..before.clone()
@@ -1003,6 +1167,85 @@ mod tests {
             from_value(notification.template_data.clone().expect("template data to exist"))
                 .expect("event rescheduled notification to deserialize");
         assert_eq!(template.event.event_id, event_id);
+        assert_eq!(
+            template.previous_starts_at.map(|ts| ts.timestamp()),
+            before_starts_at.map(|ts| ts.timestamp())
+        );
+        assert!(template.previous_location.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_event_rescheduled_notification_sends_location_change() {
+        // Setup identifiers and data structures
+        let attendee_id = Uuid::new_v4();
+        let community_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        let group_id = Uuid::new_v4();
+        let before = sample_future_event_summary(event_id, group_id);
+        let after = EventSummary {
+            venue_address: Some("789 New Ave".to_string()),
+            venue_name: Some("New Venue".to_string()),
+            ..before.clone()
+        };
+        let event = sample_event_full_with_speakers(community_id, event_id, group_id, &[]);
+        let notifications = Arc::new(Mutex::new(Vec::new()));
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_event_summary()
+            .times(1)
+            .withf(move |cid, gid, eid| {
+                *cid == community_id && *gid == group_id && *eid == event_id
+            })
+            .returning(move |_, _, _| Ok(after.clone()));
+        db.expect_get_event_full()
+            .times(1)
+            .withf(move |cid, gid, eid| {
+                *cid == community_id && *gid == group_id && *eid == event_id
+            })
+            .returning(move |_, _, _| Ok(event.clone()));
+        db.expect_list_event_attendees_ids()
+            .times(1)
+            .withf(move |gid, eid| *gid == group_id && *eid == event_id)
+            .returning(move |_, _| Ok(vec![attendee_id]));
+        db.expect_get_site_settings()
+            .times(1)
+            .returning(|| Ok(sample_site_settings()));
+        let notifications_for_mock = notifications.clone();
+        db.expect_enqueue_notification()
+            .times(1)
+            .returning(move |notification| {
+                notifications_for_mock
+                    .lock()
+                    .expect("notifications lock not to be poisoned")
+                    .push(notification.clone());
+                Ok(())
+            });
+
+        // Run the workflow
+        enqueue_event_rescheduled_notification(
+            &db,
+            &sample_server_cfg(),
+            community_id,
+            group_id,
+            event_id,
+            &before,
+        )
+        .await
+        .unwrap();
+
+        // Check notification includes the previous location only
+        let notifications = notifications
+            .lock()
+            .expect("notifications lock not to be poisoned")
+            .clone();
+        let notification = find_notification(&notifications, &NotificationKind::EventRescheduled);
+        assert_eq!(notification.recipients, vec![attendee_id]);
+        let template: EventRescheduled =
+            from_value(notification.template_data.clone().expect("template data to exist"))
+                .expect("event rescheduled notification to deserialize");
+        assert_eq!(template.previous_location, before.location(90));
+        assert!(template.previous_starts_at.is_none());
     }
 
     // Helpers.
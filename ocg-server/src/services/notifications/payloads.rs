@@ -1,24 +1,50 @@
 //! Notification payload builders.
 
 use anyhow::Result;
+use chrono::{DateTime, TimeDelta, Utc};
 use uuid::Uuid;
 
 use crate::{
     config::HttpServerConfig,
+    i18n::Language,
     templates::notifications::{
-        EventAttendanceCanceled, EventCanceled, EventInvitation, EventPublished,
-        EventRefundApproved, EventRefundRejected, EventRescheduled, EventWaitlistJoined,
-        EventWaitlistLeft, EventWaitlistPromoted, EventWelcome, SpeakerWelcome,
+        EventApproved, EventAttendanceCanceled, EventCanceled, EventInvitation,
+        EventInvitationRequestRejected, EventPublished, EventRefundApproved, EventRefundRejected,
+        EventRejected, EventRescheduled, EventWaitlistJoined, EventWaitlistLeft,
+        EventWaitlistPromoted, EventWelcome, SpeakerWelcome,
     },
     types::{event::EventSummary, site::SiteSettings},
     util::{
         base_url_without_trailing_slash, build_event_calendar_attachment, build_event_page_link,
+        build_event_ticket_attachment, build_group_dashboard_events_link,
         build_user_dashboard_events_link,
     },
 };
 
 use super::{NewNotification, NotificationKind};
 
+/// Builds the notification sent to the group team when an event is approved.
+pub(crate) fn build_event_approved_notification(
+    event: &EventSummary,
+    recipients: Vec<Uuid>,
+    server_cfg: &HttpServerConfig,
+    site_settings: &SiteSettings,
+) -> Result<NewNotification> {
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let template_data = EventApproved {
+        event: event.clone(),
+        link: build_group_dashboard_events_link(base_url),
+        theme: site_settings.theme.clone(),
+    };
+
+    Ok(NewNotification {
+        attachments: vec![],
+        kind: NotificationKind::EventApproved,
+        recipients,
+        template_data: Some(serde_json::to_value(&template_data)?),
+    })
+}
+
 /// Builds an event attendance cancellation notification.
 pub(crate) fn build_event_attendance_canceled_notification(
     event: &EventSummary,
@@ -92,6 +118,28 @@ pub(crate) fn build_event_invitation_notification(
     })
 }
 
+/// Builds a rejected event invitation request notification.
+pub(crate) fn build_event_invitation_request_rejected_notification(
+    event: &EventSummary,
+    recipient_user_id: Uuid,
+    server_cfg: &HttpServerConfig,
+    site_settings: &SiteSettings,
+) -> Result<NewNotification> {
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let template_data = EventInvitationRequestRejected {
+        event: event.clone(),
+        link: build_event_page_link(base_url, event),
+        theme: site_settings.theme.clone(),
+    };
+
+    Ok(NewNotification {
+        attachments: vec![],
+        kind: NotificationKind::EventInvitationRequestRejected,
+        recipients: vec![recipient_user_id],
+        template_data: Some(serde_json::to_value(&template_data)?),
+    })
+}
+
 /// Builds an event publication notification.
 pub(crate) fn build_event_published_notification(
     event: &EventSummary,
@@ -152,9 +200,38 @@ pub(crate) fn build_event_refund_rejected_notification(
     })
 }
 
+/// Builds the notification sent to the group team when an event is rejected.
+pub(crate) fn build_event_rejected_notification(
+    event: &EventSummary,
+    reason: Option<String>,
+    recipients: Vec<Uuid>,
+    server_cfg: &HttpServerConfig,
+    site_settings: &SiteSettings,
+) -> Result<NewNotification> {
+    let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
+    let template_data = EventRejected {
+        event: event.clone(),
+        link: build_group_dashboard_events_link(base_url),
+        theme: site_settings.theme.clone(),
+
+        reason,
+    };
+
+    Ok(NewNotification {
+        attachments: vec![],
+        kind: NotificationKind::EventRejected,
+        recipients,
+        template_data: Some(serde_json::to_value(&template_data)?),
+    })
+}
+
 /// Builds an event rescheduled notification.
+///
+/// The previous start time and location are only provided when they changed.
 pub(crate) fn build_event_rescheduled_notification(
     event: &EventSummary,
+    previous_starts_at: Option<DateTime<Utc>>,
+    previous_location: Option<String>,
     recipients: Vec<Uuid>,
     server_cfg: &HttpServerConfig,
     site_settings: &SiteSettings,
@@ -164,6 +241,9 @@ pub(crate) fn build_event_rescheduled_notification(
         event: event.clone(),
         link: build_event_page_link(base_url, event),
         theme: site_settings.theme.clone(),
+
+        previous_location,
+        previous_starts_at,
     };
 
     Ok(NewNotification {
@@ -226,7 +306,10 @@ pub(crate) fn build_event_waitlist_promoted_notification(
     site_settings: &SiteSettings,
 ) -> Result<NewNotification> {
     let base_url = base_url_without_trailing_slash(&server_cfg.base_url);
-    let attachments = if event.has_registration_questions {
+    let confirmation_expires_at = event
+        .waitlist_confirmation_hours
+        .map(|hours| Utc::now() + TimeDelta::hours(i64::from(hours)));
+    let attachments = if event.has_registration_questions || confirmation_expires_at.is_some() {
         vec![]
     } else {
         vec![build_event_calendar_attachment(base_url, event)]
@@ -237,6 +320,7 @@ pub(crate) fn build_event_waitlist_promoted_notification(
         link: build_event_page_link(base_url, event),
         theme: site_settings.theme.clone(),
 
+        confirmation_expires_at,
         dashboard_link: Some(build_user_dashboard_events_link(base_url)),
     };
 
@@ -248,10 +332,12 @@ pub(crate) fn build_event_waitlist_promoted_notification(
     })
 }
 
-/// Builds an event welcome notification.
+/// Builds an event welcome notification, attaching the attendee's ticket QR
+/// code when available.
 pub(crate) fn build_event_welcome_notification(
     event: &EventSummary,
     recipient_user_id: Uuid,
+    ticket_code: Option<Uuid>,
     server_cfg: &HttpServerConfig,
     site_settings: &SiteSettings,
     include_dashboard_link: bool,
@@ -264,10 +350,18 @@ pub(crate) fn build_event_welcome_notification(
         theme: site_settings.theme.clone(),
 
         dashboard_link,
+        language: Language::default(),
+        ticket_attached: ticket_code.is_some(),
     };
 
+    // Attach the event calendar file and the ticket QR code
+    let mut attachments = vec![build_event_calendar_attachment(base_url, event)];
+    if let Some(ticket_code) = ticket_code {
+        attachments.push(build_event_ticket_attachment(event, ticket_code)?);
+    }
+
     Ok(NewNotification {
-        attachments: vec![build_event_calendar_attachment(base_url, event)],
+        attachments,
         kind: NotificationKind::EventWelcome,
         recipients: vec![recipient_user_id],
         template_data: Some(serde_json::to_value(&template_data)?),
@@ -315,14 +409,64 @@ mod tests {
         handlers::tests::{sample_event_summary, sample_site_settings},
         services::notifications::NotificationKind,
         templates::notifications::{
-            EventAttendanceCanceled, EventCanceled, EventInvitation, EventPublished,
-            EventRefundApproved, EventRefundRejected, EventRescheduled, EventWaitlistJoined,
+            EventApproved, EventAttendanceCanceled, EventCanceled, EventInvitation,
+            EventInvitationRequestRejected, EventPublished, EventRefundApproved,
+            EventRefundRejected, EventRejected, EventRescheduled, EventWaitlistJoined,
             EventWaitlistLeft, EventWaitlistPromoted, EventWelcome, SpeakerWelcome,
         },
     };
 
     use super::*;
 
+    #[test]
+    fn test_build_event_approval_notifications_return_expected_payload() {
+        // Setup identifiers and data structures
+        let event_id = Uuid::new_v4();
+        let recipient_user_id = Uuid::new_v4();
+        let event = sample_event_summary(event_id, Uuid::new_v4());
+        let site_settings = sample_site_settings();
+        let server_cfg = sample_server_cfg();
+
+        // Build notifications
+        let approved = build_event_approved_notification(
+            &event,
+            vec![recipient_user_id],
+            &server_cfg,
+            &site_settings,
+        )
+        .expect("notification to be built");
+        let rejected = build_event_rejected_notification(
+            &event,
+            Some("Missing agenda".to_string()),
+            vec![recipient_user_id],
+            &server_cfg,
+            &site_settings,
+        )
+        .expect("notification to be built");
+
+        // Check notifications match expectations
+        assert!(approved.attachments.is_empty());
+        assert!(matches!(approved.kind, NotificationKind::EventApproved));
+        assert_eq!(approved.recipients, vec![recipient_user_id]);
+        let approved_template: EventApproved =
+            serde_json::from_value(approved.template_data.expect("template data to exist"))
+                .expect("template data to deserialize");
+        assert_eq!(approved_template.event.event_id, event_id);
+        assert_eq!(
+            approved_template.link,
+            "https://example.test/dashboard/group?tab=events"
+        );
+
+        assert!(rejected.attachments.is_empty());
+        assert!(matches!(rejected.kind, NotificationKind::EventRejected));
+        assert_eq!(rejected.recipients, vec![recipient_user_id]);
+        let rejected_template: EventRejected =
+            serde_json::from_value(rejected.template_data.expect("template data to exist"))
+                .expect("template data to deserialize");
+        assert_eq!(rejected_template.event.event_id, event_id);
+        assert_eq!(rejected_template.reason.as_deref(), Some("Missing agenda"));
+    }
+
     #[test]
     fn test_build_event_attendance_canceled_notification_returns_expected_payload() {
         // Setup identifiers and data structures
@@ -393,6 +537,8 @@ mod tests {
         .expect("notification to be built");
         let rescheduled = build_event_rescheduled_notification(
             &event,
+            event.starts_at,
+            Some("Old Venue".to_string()),
             vec![recipient_user_id],
             &server_cfg,
             &site_settings,
@@ -430,6 +576,14 @@ mod tests {
             serde_json::from_value(rescheduled.template_data.expect("template data to exist"))
                 .expect("template data to deserialize");
         assert_eq!(rescheduled_template.event.event_id, event_id);
+        assert_eq!(
+            rescheduled_template.previous_location.as_deref(),
+            Some("Old Venue")
+        );
+        assert_eq!(
+            rescheduled_template.previous_starts_at.map(|ts| ts.timestamp()),
+            event.starts_at.map(|ts| ts.timestamp())
+        );
 
         assert_eq!(speaker.attachments.len(), 1);
         assert!(matches!(speaker.kind, NotificationKind::SpeakerWelcome));
@@ -480,6 +634,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_event_invitation_request_rejected_notification_returns_expected_payload() {
+        // Setup identifiers and data structures
+        let event_id = Uuid::new_v4();
+        let recipient_user_id = Uuid::new_v4();
+        let event = sample_event_summary(event_id, Uuid::new_v4());
+        let site_settings = sample_site_settings();
+        let server_cfg = sample_server_cfg();
+
+        // Build notification
+        let notification = build_event_invitation_request_rejected_notification(
+            &event,
+            recipient_user_id,
+            &server_cfg,
+            &site_settings,
+        )
+        .expect("notification to be built");
+
+        // Check notification matches expectations
+        assert!(notification.attachments.is_empty());
+        assert!(matches!(
+            notification.kind,
+            NotificationKind::EventInvitationRequestRejected
+        ));
+        assert_eq!(notification.recipients, vec![recipient_user_id]);
+        let template: EventInvitationRequestRejected =
+            serde_json::from_value(notification.template_data.expect("template data to exist"))
+                .expect("template data to deserialize");
+        assert_eq!(template.event.event_id, event_id);
+        assert_eq!(
+            template.link,
+            "https://example.test/test-community/group/def5678/event/ghi9abc"
+        );
+        assert_eq!(
+            template.theme.primary_color,
+            site_settings.theme.primary_color
+        );
+    }
+
     #[test]
     fn test_build_event_refund_notifications_return_expected_payload() {
         // Setup identifiers and data structures
@@ -658,6 +851,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_event_waitlist_promoted_notification_includes_confirmation_deadline() {
+        // Setup identifiers and data structures
+        let event_id = Uuid::new_v4();
+        let recipient_user_id = Uuid::new_v4();
+        let mut event = sample_event_summary(event_id, Uuid::new_v4());
+        event.waitlist_confirmation_hours = Some(24);
+        let site_settings = sample_site_settings();
+        let server_cfg = sample_server_cfg();
+
+        // Build notification
+        let before = Utc::now();
+        let notification = build_event_waitlist_promoted_notification(
+            &event,
+            vec![recipient_user_id],
+            &server_cfg,
+            &site_settings,
+        )
+        .expect("notification to be built");
+
+        // Check notification matches expectations
+        assert!(notification.attachments.is_empty());
+        let template: EventWaitlistPromoted =
+            serde_json::from_value(notification.template_data.expect("template data to exist"))
+                .expect("template data to deserialize");
+        let confirmation_expires_at = template
+            .confirmation_expires_at
+            .expect("confirmation deadline to exist");
+        assert!(confirmation_expires_at >= before + TimeDelta::hours(23));
+        assert!(confirmation_expires_at <= Utc::now() + TimeDelta::hours(24));
+        assert!(!template.has_registration_questions);
+    }
+
+    #[test]
+    fn test_build_event_welcome_notification_attaches_ticket() {
+        // Setup identifiers and data structures
+        let event = sample_event_summary(Uuid::new_v4(), Uuid::new_v4());
+        let site_settings = sample_site_settings();
+        let server_cfg = sample_server_cfg();
+
+        // Build notification
+        let notification = build_event_welcome_notification(
+            &event,
+            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
+            &server_cfg,
+            &site_settings,
+            true,
+        )
+        .expect("notification to be built");
+
+        // Check notification matches expectations
+        assert_eq!(notification.attachments.len(), 2);
+        assert_eq!(notification.attachments[1].content_type, "image/png");
+        assert_eq!(
+            notification.attachments[1].file_name,
+            format!("ticket-{}.png", event.slug)
+        );
+        let template: EventWelcome =
+            serde_json::from_value(notification.template_data.expect("template data to exist"))
+                .expect("template data to deserialize");
+        assert!(template.ticket_attached);
+    }
+
     #[test]
     fn test_build_event_welcome_notification_returns_expected_payload() {
         // Setup identifiers and data structures
@@ -671,6 +928,7 @@ mod tests {
         let notification = build_event_welcome_notification(
             &event,
             recipient_user_id,
+            None,
             &server_cfg,
             &site_settings,
             true,
@@ -697,6 +955,7 @@ mod tests {
             template.theme.primary_color,
             site_settings.theme.primary_color
         );
+        assert!(!template.ticket_attached);
     }
 
     #[test]
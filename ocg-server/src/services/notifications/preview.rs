@@ -0,0 +1,442 @@
+//! Previews of the notification emails rendered with sample data.
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::types::{
+    community::{CommunityEmailBranding, CommunityFull},
+    group::GroupSummary,
+    site::Theme,
+};
+
+use super::{DeliveryWorker, Notification, NotificationKind, NotificationRecipient};
+
+/// Notification kinds delivered by email that can be previewed.
+pub(crate) const EMAIL_PREVIEW_KINDS: [NotificationKind; 36] = [
+    NotificationKind::CfsSubmissionUpdated,
+    NotificationKind::CommunityNewsletter,
+    NotificationKind::CommunityTeamInvitation,
+    NotificationKind::DormantGroupsReport,
+    NotificationKind::EmailVerification,
+    NotificationKind::EventApproved,
+    NotificationKind::EventAttendanceCanceled,
+    NotificationKind::EventAttendanceCertificate,
+    NotificationKind::EventCanceled,
+    NotificationKind::EventCustom,
+    NotificationKind::EventFollowUp,
+    NotificationKind::EventInvitation,
+    NotificationKind::EventInvitationRequestRejected,
+    NotificationKind::EventPublished,
+    NotificationKind::EventRefundApproved,
+    NotificationKind::EventRefundRejected,
+    NotificationKind::EventRefundRequested,
+    NotificationKind::EventRejected,
+    NotificationKind::EventReminder,
+    NotificationKind::EventRescheduled,
+    NotificationKind::EventSeriesCanceled,
+    NotificationKind::EventSeriesPublished,
+    NotificationKind::EventWaitlistJoined,
+    NotificationKind::EventWaitlistLeft,
+    NotificationKind::EventWaitlistPromoted,
+    NotificationKind::EventWelcome,
+    NotificationKind::GroupContactMessage,
+    NotificationKind::GroupCustom,
+    NotificationKind::GroupTeamInvitation,
+    NotificationKind::GroupWelcome,
+    NotificationKind::MagicLink,
+    NotificationKind::PasswordReset,
+    NotificationKind::SessionProposalCoSpeakerInvitation,
+    NotificationKind::SpeakerSeriesWelcome,
+    NotificationKind::SpeakerWelcome,
+    NotificationKind::WeeklyDigest,
+];
+
+/// Name of the sample group used in the previews.
+const SAMPLE_GROUP_NAME: &str = "Sample Group";
+
+/// Slug of the sample group used in the previews.
+const SAMPLE_GROUP_SLUG: &str = "sample-group";
+
+/// Data used to render the notification email previews of a community.
+pub(crate) struct EmailPreviewContext<'a> {
+    /// Base URL used to build the sample links.
+    pub base_url: &'a str,
+    /// Community the previews are rendered for.
+    pub community: &'a CommunityFull,
+    /// Email branding configured by the community.
+    pub email_branding: CommunityEmailBranding,
+    /// Site theme the community email branding is applied on top of.
+    pub theme: &'a Theme,
+}
+
+/// Renders the subject and body of a notification email using sample data.
+///
+/// Returns `None` when the notification kind is not delivered by email.
+pub(crate) fn render_email_preview(
+    kind: &NotificationKind,
+    ctx: &EmailPreviewContext<'_>,
+) -> Result<Option<(String, String)>> {
+    if !EMAIL_PREVIEW_KINDS.contains(kind) {
+        return Ok(None);
+    }
+
+    render_email(kind, sample_template_data(kind, ctx), ctx).map(Some)
+}
+
+/// Renders the subject and body of the email delivering a community newsletter issue.
+pub(crate) fn render_community_newsletter_preview(
+    ctx: &EmailPreviewContext<'_>,
+    community_newsletter_issue_id: Uuid,
+    subject: &str,
+    body: &str,
+) -> Result<(String, String)> {
+    let base_url = ctx.base_url;
+    let community_name = &ctx.community.name;
+    let template_data = json!({
+        "body": body,
+        "community_display_name": ctx.community.display_name,
+        "link": format!("{base_url}/{community_name}"),
+        "newsletter_link": format!("{base_url}/{community_name}/newsletter/{community_newsletter_issue_id}"),
+        "subject": subject,
+        "theme": ctx.theme,
+    });
+
+    render_email(&NotificationKind::CommunityNewsletter, template_data, ctx)
+}
+
+/// Renders a notification email with the template data and branding provided.
+fn render_email(
+    kind: &NotificationKind,
+    template_data: Value,
+    ctx: &EmailPreviewContext<'_>,
+) -> Result<(String, String)> {
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: Utc::now(),
+        email_branding: Some(ctx.email_branding.clone()),
+        kind: kind.clone(),
+        language: None,
+        notification_id: Uuid::nil(),
+        recipient: NotificationRecipient::Email(String::new()),
+        template_data: Some(template_data),
+    };
+    DeliveryWorker::prepare_content(&notification)
+}
+
+/// Builds the sample template data of a notification kind.
+fn sample_template_data(kind: &NotificationKind, ctx: &EmailPreviewContext<'_>) -> Value {
+    let base_url = ctx.base_url;
+    let community_name = &ctx.community.name;
+    let dashboard_link = format!("{base_url}/dashboard/user?tab=events");
+    let group_link = format!("{base_url}/{community_name}/group/{SAMPLE_GROUP_SLUG}");
+
+    // Sample events, each one with the link to its page
+    let event = sample_event(ctx, "Sample Event", "sample-event", 7);
+    let event_link = format!("{group_link}/event/sample-event");
+    let series_events = json!([
+        {
+            "event": sample_event(ctx, "Sample Series Event One", "sample-series-event-one", 7),
+            "link": format!("{group_link}/event/sample-series-event-one"),
+        },
+        {
+            "event": sample_event(ctx, "Sample Series Event Two", "sample-series-event-two", 14),
+            "link": format!("{group_link}/event/sample-series-event-two"),
+        },
+    ]);
+
+    let mut template_data = match kind {
+        NotificationKind::CfsSubmissionUpdated => json!({
+            "action_required_message": "Please shorten the session abstract.",
+            "event": event,
+            "link": format!("{base_url}/dashboard/user?tab=submissions"),
+            "status_name": "Information requested",
+        }),
+        NotificationKind::CommunityNewsletter => json!({
+            "body": "Here is what happened in the community this month.\n\n- **3** new groups\n- **12** events",
+            "community_display_name": ctx.community.display_name,
+            "link": format!("{base_url}/{community_name}"),
+            "newsletter_link": format!("{base_url}/{community_name}/newsletter/{}", Uuid::nil()),
+            "subject": "Sample newsletter",
+        }),
+        NotificationKind::CommunityTeamInvitation => json!({
+            "community_name": ctx.community.display_name,
+            "link": format!("{base_url}/dashboard/user?tab=invitations"),
+        }),
+        NotificationKind::DormantGroupsReport => json!({
+            "community_display_name": ctx.community.display_name,
+            "dormant_group_months": 6,
+            "groups": [
+                {
+                    "dormant_since": (Utc::now() - Duration::days(45)).timestamp(),
+                    "link": group_link,
+                    "name": SAMPLE_GROUP_NAME,
+                },
+            ],
+            "link": format!("{base_url}/dashboard/community?tab=groups&dormant=true"),
+        }),
+        NotificationKind::EmailVerification => json!({
+            "link": format!("{base_url}/verify-email/sample"),
+        }),
+        NotificationKind::EventAttendanceCanceled | NotificationKind::EventWelcome => json!({
+            "dashboard_link": dashboard_link,
+            "event": event,
+            "link": event_link,
+        }),
+        NotificationKind::EventAttendanceCertificate => json!({
+            "certificate": {
+                "attendee_name": "Sample Attendee",
+                "community_display_name": ctx.community.display_name,
+                "ends_at": event["ends_at"],
+                "event_id": Uuid::nil(),
+                "event_name": "Sample Event",
+                "event_slug": "sample-event",
+                "group_name": SAMPLE_GROUP_NAME,
+                "logo_url": ctx.community.logo_url,
+                "primary_color": ctx.theme.primary_color,
+                "starts_at": event["starts_at"],
+                "timezone": "UTC",
+            },
+            "event": event,
+            "link": format!("{base_url}/dashboard/user?tab=certificates"),
+        }),
+        NotificationKind::EventCustom => json!({
+            "body": "This is a sample message sent to the event attendees.",
+            "event": event,
+            "link": event_link,
+            "subject": "Sample event message",
+        }),
+        NotificationKind::EventFollowUp => json!({
+            "event": event,
+            "feedback_link": format!("{base_url}/feedback/sample"),
+            "link": event_link,
+            "recording_link": format!("{base_url}/recording/sample"),
+        }),
+        NotificationKind::EventInvitation => json!({
+            "event": event,
+            "has_registration_questions": false,
+            "link": event_link,
+        }),
+        NotificationKind::EventRejected => json!({
+            "event": event,
+            "link": format!("{base_url}/dashboard/group?tab=events"),
+            "reason": "Please add more details about the agenda.",
+        }),
+        NotificationKind::EventReminder => json!({
+            "dashboard_link": dashboard_link,
+            "event": event,
+            "link": event_link,
+            "reminder_offset_minutes": 24 * 60,
+            "show_attendance_cancellation_copy": true,
+        }),
+        NotificationKind::EventRescheduled => json!({
+            "event": event,
+            "link": event_link,
+            "previous_location": "Previous Venue, 1 Old Street",
+            "previous_starts_at": (Utc::now() + Duration::days(5)).timestamp(),
+        }),
+        NotificationKind::EventSeriesCanceled | NotificationKind::SpeakerSeriesWelcome => json!({
+            "event_count": 2,
+            "events": series_events,
+            "group_name": SAMPLE_GROUP_NAME,
+        }),
+        NotificationKind::EventSeriesPublished => json!({
+            "community_display_name": ctx.community.display_name,
+            "event_count": 2,
+            "events": series_events,
+            "group_name": SAMPLE_GROUP_NAME,
+        }),
+        NotificationKind::EventWaitlistPromoted => json!({
+            "confirmation_expires_at": (Utc::now() + Duration::hours(24)).timestamp(),
+            "dashboard_link": dashboard_link,
+            "event": event,
+            "has_registration_questions": false,
+            "link": event_link,
+        }),
+        NotificationKind::GroupContactMessage => json!({
+            "body": "Hi! Would you be interested in a talk about our open source project?",
+            "group": sample_group(ctx),
+            "link": group_link,
+            "sender_email": "sample.user@example.com",
+            "sender_name": "Sample User",
+            "sender_username": "sample-user",
+            "subject": "Talk proposal",
+        }),
+        NotificationKind::GroupCustom => json!({
+            "body": "This is a sample message sent to the group members.",
+            "group": sample_group(ctx),
+            "link": group_link,
+            "subject": "Sample group message",
+        }),
+        NotificationKind::GroupTeamInvitation => json!({
+            "group": sample_group(ctx),
+            "link": format!("{base_url}/dashboard/user?tab=invitations"),
+        }),
+        NotificationKind::GroupWelcome => json!({
+            "group": sample_group(ctx),
+            "link": group_link,
+            "welcome_message": "Join the conversation in our **chat workspace** and say hi!",
+        }),
+        NotificationKind::MagicLink => json!({
+            "link": format!("{base_url}/log-in/magic-link/sample"),
+        }),
+        NotificationKind::PasswordReset => json!({
+            "link": format!("{base_url}/reset-password/sample"),
+        }),
+        NotificationKind::SessionProposalCoSpeakerInvitation => json!({
+            "link": format!("{base_url}/dashboard/user?tab=session-proposals"),
+            "session_proposal_title": "Sample Session",
+            "speaker_name": "Sample Speaker",
+        }),
+        NotificationKind::WeeklyDigest => json!({
+            "community_display_name": ctx.community.display_name,
+            "events": series_events,
+            "link": format!("{base_url}/{community_name}"),
+            "preferences_link": format!("{base_url}/dashboard/user?tab=account"),
+        }),
+        // Remaining event notifications only need the event and its link
+        _ => json!({
+            "event": event,
+            "link": event_link,
+        }),
+    };
+    template_data["theme"] = json!(ctx.theme);
+
+    template_data
+}
+
+/// Builds a sample event starting in the number of days provided.
+fn sample_event(ctx: &EmailPreviewContext<'_>, name: &str, slug: &str, days: i64) -> Value {
+    let starts_at = Utc::now() + Duration::days(days);
+
+    json!({
+        "canceled": false,
+        "community_display_name": ctx.community.display_name,
+        "community_name": ctx.community.name,
+        "ends_at": (starts_at + Duration::hours(2)).timestamp(),
+        "event_id": Uuid::nil(),
+        "group_category_name": "Sample Category",
+        "group_name": SAMPLE_GROUP_NAME,
+        "group_slug": SAMPLE_GROUP_SLUG,
+        "kind": "hybrid",
+        "logo_url": ctx.community.logo_url,
+        "meeting_join_url": format!("{}/meeting/sample", ctx.base_url),
+        "name": name,
+        "published": true,
+        "slug": slug,
+        "starts_at": starts_at.timestamp(),
+        "timezone": "UTC",
+        "venue_address": "123 Sample Street",
+        "venue_city": "Sample City",
+        "venue_name": "Sample Venue",
+    })
+}
+
+/// Builds a sample group of the community.
+fn sample_group(ctx: &EmailPreviewContext<'_>) -> GroupSummary {
+    GroupSummary {
+        active: true,
+        community_display_name: ctx.community.display_name.clone(),
+        community_name: ctx.community.name.clone(),
+        created_at: Utc::now(),
+        logo_url: ctx.community.logo_url.clone(),
+        name: SAMPLE_GROUP_NAME.to_string(),
+        slug: SAMPLE_GROUP_SLUG.to_string(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_community_newsletter_preview() {
+        let community = sample_community();
+        let theme = sample_theme();
+        let ctx = sample_context(&community, &theme);
+
+        let (subject, body) = render_community_newsletter_preview(
+            &ctx,
+            Uuid::nil(),
+            "Monthly news",
+            "Welcome to **the newsletter**",
+        )
+        .unwrap();
+
+        assert_eq!(subject, "Monthly news");
+        assert!(body.contains("<strong>the newsletter</strong>"));
+        assert!(body.contains("#0A3D62"));
+        assert!(body.contains(&format!(
+            "https://example.test/test-community/newsletter/{}",
+            Uuid::nil()
+        )));
+    }
+
+    #[test]
+    fn test_render_email_preview_renders_all_email_kinds() {
+        let community = sample_community();
+        let theme = sample_theme();
+        let ctx = sample_context(&community, &theme);
+
+        for kind in &EMAIL_PREVIEW_KINDS {
+            let (subject, body) = render_email_preview(kind, &ctx)
+                .unwrap_or_else(|err| panic!("{kind} preview should render: {err}"))
+                .unwrap_or_else(|| panic!("{kind} should support email previews"));
+
+            assert!(!subject.is_empty(), "{kind} preview subject is empty");
+            assert!(
+                body.contains("#0A3D62"),
+                "{kind} preview is missing the branding"
+            );
+        }
+    }
+
+    #[test]
+    fn test_render_email_preview_skips_non_email_kinds() {
+        let community = sample_community();
+        let theme = sample_theme();
+        let ctx = sample_context(&community, &theme);
+
+        let preview = render_email_preview(&NotificationKind::PhoneVerification, &ctx).unwrap();
+
+        assert!(preview.is_none());
+    }
+
+    // Helpers.
+
+    /// Create a sample community.
+    fn sample_community() -> CommunityFull {
+        CommunityFull {
+            display_name: "Test Community".to_string(),
+            logo_url: "https://example.test/logo.png".to_string(),
+            name: "test-community".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a sample preview context.
+    fn sample_context<'a>(
+        community: &'a CommunityFull,
+        theme: &'a Theme,
+    ) -> EmailPreviewContext<'a> {
+        EmailPreviewContext {
+            base_url: "https://example.test",
+            community,
+            email_branding: CommunityEmailBranding {
+                primary_color: Some("#0A3D62".to_string()),
+                ..Default::default()
+            },
+            theme,
+        }
+    }
+
+    /// Create a sample site theme.
+    fn sample_theme() -> Theme {
+        Theme {
+            primary_color: "#000000".to_string(),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Email sender that delivers messages through the SendGrid API.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use lettre::message::Mailbox;
+use reqwest::Client as HttpClient;
+use serde::Serialize;
+
+use crate::config::EmailSendgridConfig;
+
+use super::{DeliveryError, Email, EmailSender};
+
+/// URL of the SendGrid mail send API endpoint.
+const SENDGRID_API_URL: &str = "https://api.sendgrid.com/v3/mail/send";
+
+/// Timeout for HTTP requests to the SendGrid API.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Concrete email sender backed by the SendGrid v3 mail send API.
+pub(crate) struct SendgridEmailSender {
+    /// SendGrid configuration.
+    cfg: EmailSendgridConfig,
+    /// HTTP client used to call the SendGrid API.
+    http_client: HttpClient,
+}
+
+impl SendgridEmailSender {
+    /// Create a new `SendgridEmailSender`.
+    pub(crate) fn new(cfg: EmailSendgridConfig) -> Self {
+        let http_client = HttpClient::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+
+        Self { cfg, http_client }
+    }
+}
+
+#[async_trait]
+impl EmailSender for SendgridEmailSender {
+    /// [`EmailSender::send`].
+    async fn send(&self, email: Email) -> Result<(), DeliveryError> {
+        let request = MailSendRequest::new(&email, self.cfg.sandbox_mode.unwrap_or(false));
+
+        let response = self
+            .http_client
+            .post(SENDGRID_API_URL)
+            .bearer_auth(&self.cfg.api_key)
+            .json(&request)
+            .send()
+            .await
+            .map_err(DeliveryError::from_webhook_request)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(DeliveryError::from_webhook_status(
+            status,
+            anyhow!("sendgrid request failed ({status}): {body}"),
+        ))
+    }
+}
+
+/// Body of a SendGrid mail send request.
+#[derive(Debug, Serialize)]
+struct MailSendRequest {
+    /// Body of the email.
+    content: Vec<MailContent>,
+    /// Sender of the email.
+    from: MailAddress,
+    /// Settings applied to the request.
+    mail_settings: MailSettings,
+    /// Recipients of the email.
+    personalizations: Vec<MailPersonalization>,
+    /// Subject of the email.
+    subject: String,
+
+    /// Files attached to the email.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attachments: Vec<MailAttachment>,
+}
+
+impl MailSendRequest {
+    /// Build the mail send request for the email provided.
+    fn new(email: &Email, sandbox_mode: bool) -> Self {
+        Self {
+            content: vec![MailContent {
+                r#type: "text/html".to_string(),
+                value: email.body.clone(),
+            }],
+            from: MailAddress::from(&email.from),
+            mail_settings: MailSettings {
+                sandbox_mode: MailSetting {
+                    enable: sandbox_mode,
+                },
+            },
+            personalizations: vec![MailPersonalization {
+                to: vec![MailAddress::from(&email.to)],
+            }],
+            subject: email.subject.clone(),
+            attachments: email
+                .attachments
+                .iter()
+                .map(|attachment| MailAttachment {
+                    content: BASE64.encode(&attachment.data),
+                    disposition: "attachment".to_string(),
+                    filename: attachment.file_name.clone(),
+                    r#type: attachment.content_type.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Email address with an optional display name.
+#[derive(Debug, Serialize)]
+struct MailAddress {
+    /// Email address.
+    email: String,
+
+    /// Display name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl From<&Mailbox> for MailAddress {
+    fn from(mailbox: &Mailbox) -> Self {
+        Self {
+            email: mailbox.email.to_string(),
+            name: mailbox.name.clone(),
+        }
+    }
+}
+
+/// File attached to the email.
+#[derive(Debug, Serialize)]
+struct MailAttachment {
+    /// Base64 encoded file content.
+    content: String,
+    /// How the file is presented to recipients.
+    disposition: String,
+    /// File name shown to recipients.
+    filename: String,
+    /// MIME type of the file.
+    r#type: String,
+}
+
+/// Content of the email.
+#[derive(Debug, Serialize)]
+struct MailContent {
+    /// MIME type of the content.
+    r#type: String,
+    /// Content value.
+    value: String,
+}
+
+/// Recipients of the email.
+#[derive(Debug, Serialize)]
+struct MailPersonalization {
+    /// Primary recipients.
+    to: Vec<MailAddress>,
+}
+
+/// Toggleable mail setting.
+#[derive(Debug, Serialize)]
+struct MailSetting {
+    /// Whether the setting is enabled.
+    enable: bool,
+}
+
+/// Mail settings applied to the request.
+#[derive(Debug, Serialize)]
+struct MailSettings {
+    /// Validate the request without delivering the email.
+    sandbox_mode: MailSetting,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::services::notifications::{Attachment, Email};
+
+    use super::MailSendRequest;
+
+    #[test]
+    fn mail_send_request_includes_attachments_and_sandbox_mode() {
+        let email = Email {
+            attachments: vec![Attachment {
+                content_type: "text/calendar".to_string(),
+                data: b"BEGIN:VCALENDAR".to_vec(),
+                file_name: "event.ics".to_string(),
+            }],
+            body: "<p>Body content</p>".to_string(),
+            from: "Open Community Groups <no-reply@example.test>".parse().unwrap(),
+            subject: "Subject line".to_string(),
+            to: "notify@example.test".parse().unwrap(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(MailSendRequest::new(&email, true)).unwrap(),
+            json!({
+                "attachments": [{
+                    "content": "QkVHSU46VkNBTEVOREFS",
+                    "disposition": "attachment",
+                    "filename": "event.ics",
+                    "type": "text/calendar"
+                }],
+                "content": [{ "type": "text/html", "value": "<p>Body content</p>" }],
+                "from": { "email": "no-reply@example.test", "name": "Open Community Groups" },
+                "mail_settings": { "sandbox_mode": { "enable": true } },
+                "personalizations": [{ "to": [{ "email": "notify@example.test" }] }],
+                "subject": "Subject line"
+            })
+        );
+    }
+
+    #[test]
+    fn mail_send_request_omits_empty_attachments() {
+        let email = Email {
+            attachments: vec![],
+            body: "<p>Body content</p>".to_string(),
+            from: "no-reply@example.test".parse().unwrap(),
+            subject: "Subject line".to_string(),
+            to: "notify@example.test".parse().unwrap(),
+        };
+
+        let request = serde_json::to_value(MailSendRequest::new(&email, false)).unwrap();
+
+        assert!(request.get("attachments").is_none());
+        assert_eq!(request["mail_settings"]["sandbox_mode"]["enable"], false);
+    }
+}
@@ -0,0 +1,178 @@
+//! Email sender that delivers messages through the Amazon SES API.
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use aws_credential_types::{Credentials, provider::SharedCredentialsProvider};
+use aws_sdk_sesv2::{
+    Client as SesClient,
+    config::{BehaviorVersion, Region, http::HttpResponse},
+    error::SdkError,
+    operation::send_email::SendEmailError,
+    primitives::Blob,
+    types::{Destination, EmailContent, RawMessage},
+};
+
+use crate::config::EmailSesConfig;
+
+use super::{DeliveryError, Email, EmailSender};
+
+/// Concrete email sender backed by the Amazon SES v2 API.
+///
+/// Messages are rendered for each recipient, so they are submitted as raw
+/// messages one at a time instead of using SES bulk templates.
+pub(crate) struct SesEmailSender {
+    /// AWS SDK client configured for the SES endpoint.
+    client: SesClient,
+    /// Configuration set applied to sent emails, if any.
+    configuration_set: Option<String>,
+}
+
+impl SesEmailSender {
+    /// Create a new `SesEmailSender` from the provided config.
+    pub(crate) fn new(cfg: &EmailSesConfig) -> Self {
+        // Setup SES configuration
+        let credentials = Credentials::new(
+            &cfg.access_key_id,
+            &cfg.secret_access_key,
+            None,
+            None,
+            "ocg-email-sender",
+        );
+        let mut builder = aws_sdk_sesv2::Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .credentials_provider(SharedCredentialsProvider::new(credentials))
+            .region(Region::new(cfg.region.clone()));
+        if let Some(endpoint) = &cfg.endpoint {
+            builder = builder.endpoint_url(endpoint);
+        }
+
+        // Create SES client
+        let client = SesClient::from_conf(builder.build());
+
+        Self {
+            client,
+            configuration_set: cfg.configuration_set.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl EmailSender for SesEmailSender {
+    /// [`EmailSender::send`].
+    async fn send(&self, email: Email) -> Result<(), DeliveryError> {
+        // Prepare the raw message
+        let message = email.to_message()?;
+        let raw_message = RawMessage::builder()
+            .data(Blob::new(message.formatted()))
+            .build()
+            .map_err(DeliveryError::terminal)?;
+
+        // Submit the message to SES
+        self.client
+            .send_email()
+            .destination(
+                Destination::builder()
+                    .to_addresses(email.to.email.to_string())
+                    .build(),
+            )
+            .content(EmailContent::builder().raw(raw_message).build())
+            .set_configuration_set_name(self.configuration_set.clone())
+            .send()
+            .await
+            .map_err(classify_error)?;
+
+        Ok(())
+    }
+}
+
+/// Classifies an SES API error by the safest recovery action.
+fn classify_error(err: SdkError<SendEmailError, HttpResponse>) -> DeliveryError {
+    // Extract the error category before preserving the original error source
+    let kind = match &err {
+        SdkError::ConstructionFailure(_) => SesErrorKind::Request,
+        SdkError::ServiceError(service_err) => {
+            let service_err = service_err.err();
+            if service_err.is_too_many_requests_exception()
+                || service_err.is_limit_exceeded_exception()
+            {
+                SesErrorKind::Throttled
+            } else if service_err.is_account_suspended_exception()
+                || service_err.is_bad_request_exception()
+                || service_err.is_mail_from_domain_not_verified_exception()
+                || service_err.is_message_rejected()
+                || service_err.is_not_found_exception()
+                || service_err.is_sending_paused_exception()
+            {
+                SesErrorKind::Rejected
+            } else if err.raw_response().is_some_and(|r| r.status().is_server_error()) {
+                SesErrorKind::Server
+            } else {
+                SesErrorKind::Unknown
+            }
+        }
+        _ => SesErrorKind::Unknown,
+    };
+
+    // Preserve the error source in the classified delivery failure
+    let err = anyhow!(aws_sdk_sesv2::error::DisplayErrorContext(err));
+    from_kind(kind, err)
+}
+
+/// Classifies an SES error category while preserving its error source.
+fn from_kind(kind: SesErrorKind, err: anyhow::Error) -> DeliveryError {
+    match kind {
+        // SES did not accept the message, so it can be submitted again
+        SesErrorKind::Server | SesErrorKind::Throttled => DeliveryError::Retryable(err),
+        // Message or account failures require an external correction
+        SesErrorKind::Rejected | SesErrorKind::Request => DeliveryError::Terminal(err),
+        // Timeouts and dispatch failures may follow submission
+        SesErrorKind::Unknown => DeliveryError::Unknown(err),
+    }
+}
+
+/// SES API error categories relevant to delivery recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SesErrorKind {
+    /// SES rejected the message or the sending account.
+    Rejected,
+    /// The request could not be built.
+    Request,
+    /// SES failed with a server error before accepting the message.
+    Server,
+    /// SES throttled the request due to sending limits.
+    Throttled,
+    /// Failure without a safe automatic recovery action.
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::services::notifications::DeliveryError;
+
+    use super::{SesErrorKind, from_kind};
+
+    #[test]
+    fn from_kind_classifies_rejections_as_terminal() {
+        for kind in [SesErrorKind::Rejected, SesErrorKind::Request] {
+            let err = from_kind(kind, anyhow!("ses failure"));
+            assert!(matches!(&err, DeliveryError::Terminal(_)));
+            assert_eq!(err.to_string(), "ses failure");
+        }
+    }
+
+    #[test]
+    fn from_kind_classifies_throttling_and_server_errors_as_retryable() {
+        for kind in [SesErrorKind::Server, SesErrorKind::Throttled] {
+            let err = from_kind(kind, anyhow!("ses failure"));
+            assert!(matches!(&err, DeliveryError::Retryable(_)));
+        }
+    }
+
+    #[test]
+    fn from_kind_classifies_uncertain_failures_as_unknown() {
+        let err = from_kind(SesErrorKind::Unknown, anyhow!("ses dispatch failure"));
+        assert!(matches!(&err, DeliveryError::Unknown(_)));
+    }
+}
@@ -0,0 +1,145 @@
+//! Slack channel used to post community notifications.
+
+use std::time::Duration;
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+use reqwest::Client as HttpClient;
+use serde_json::json;
+
+use crate::templates::notifications::{EventPublished, GroupWelcome};
+
+use super::{DeliveryError, Notification, NotificationChannel, NotificationKind};
+
+/// Timeout for HTTP requests to Slack incoming webhooks.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Notification channel that posts messages to Slack incoming webhooks.
+pub(crate) struct SlackNotificationChannel {
+    /// HTTP client used to call the webhooks.
+    http_client: HttpClient,
+}
+
+impl SlackNotificationChannel {
+    /// Create a new `SlackNotificationChannel`.
+    pub(crate) fn new() -> Self {
+        let http_client = HttpClient::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+
+        Self { http_client }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for SlackNotificationChannel {
+    /// [`NotificationChannel::send`].
+    async fn send(&self, destination: &str, text: &str) -> Result<(), DeliveryError> {
+        let response = self
+            .http_client
+            .post(destination)
+            .json(&json!({ "text": text }))
+            .send()
+            .await
+            .map_err(DeliveryError::from_webhook_request)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(DeliveryError::from_webhook_status(
+            status,
+            anyhow!("slack webhook request failed ({status}): {body}"),
+        ))
+    }
+}
+
+/// Prepare the Slack message text for a community notification.
+pub(crate) fn prepare_message(notification: &Notification) -> Result<String> {
+    let template_data = notification
+        .template_data
+        .clone()
+        .ok_or_else(|| anyhow!("missing template data"))?;
+
+    let text = match notification.kind {
+        NotificationKind::EventPublished => {
+            let template: EventPublished = serde_json::from_value(template_data)?;
+            format!(
+                "New event published in *{}*: <{}|{}>",
+                escape(&template.event.group_name),
+                template.link,
+                escape(&template.event.name),
+            )
+        }
+        NotificationKind::GroupWelcome => {
+            let template: GroupWelcome = serde_json::from_value(template_data)?;
+            format!(
+                "A new member joined <{}|{}>",
+                template.link,
+                escape(&template.group.name),
+            )
+        }
+        _ => bail!(
+            "notification kind not supported by slack: {}",
+            notification.kind
+        ),
+    };
+
+    Ok(text)
+}
+
+/// Escape the characters Slack uses for message formatting.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::services::notifications::{Notification, NotificationKind, NotificationRecipient};
+
+    use super::{escape, prepare_message};
+
+    #[test]
+    fn escape_replaces_control_characters() {
+        assert_eq!(escape("<Rust & Go>"), "&lt;Rust &amp; Go&gt;");
+    }
+
+    #[test]
+    fn prepare_message_rejects_unsupported_kinds() {
+        let notification = sample_notification(NotificationKind::EventCanceled, json!({}));
+
+        assert!(prepare_message(&notification).is_err());
+    }
+
+    #[test]
+    fn prepare_message_requires_template_data() {
+        let mut notification = sample_notification(NotificationKind::GroupWelcome, json!({}));
+        notification.template_data = None;
+
+        assert!(prepare_message(&notification).is_err());
+    }
+
+    fn sample_notification(
+        kind: NotificationKind,
+        template_data: serde_json::Value,
+    ) -> Notification {
+        Notification {
+            attachments: vec![],
+            delivery_claimed_at: Utc::now(),
+            email_branding: None,
+            kind,
+            language: None,
+            notification_id: Uuid::new_v4(),
+            recipient: NotificationRecipient::Slack(Some(
+                "https://hooks.slack.com/services/T0/B0/secret".to_string(),
+            )),
+            template_data: Some(template_data),
+        }
+    }
+}
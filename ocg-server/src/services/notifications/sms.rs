@@ -0,0 +1,224 @@
+//! SMS channel used to send user notifications through Twilio.
+
+use std::time::Duration;
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+use reqwest::{Client as HttpClient, header::CONTENT_TYPE};
+use serde::Deserialize;
+
+use crate::{
+    config::SmsTwilioConfig, templates::notifications::EventReminder, types::event::EventSummary,
+};
+
+use super::{DeliveryError, Notification, NotificationChannel, NotificationKind};
+
+/// Base URL of the Twilio REST API.
+const TWILIO_API_URL: &str = "https://api.twilio.com/2010-04-01";
+
+/// Timeout for HTTP requests to the Twilio API.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Notification channel that sends text messages using the Twilio API.
+pub(crate) struct TwilioNotificationChannel {
+    /// Twilio configuration.
+    cfg: SmsTwilioConfig,
+    /// HTTP client used to call the Twilio API.
+    http_client: HttpClient,
+}
+
+impl TwilioNotificationChannel {
+    /// Create a new `TwilioNotificationChannel`.
+    pub(crate) fn new(cfg: SmsTwilioConfig) -> Self {
+        let http_client = HttpClient::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+
+        Self { cfg, http_client }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for TwilioNotificationChannel {
+    /// [`NotificationChannel::send`].
+    async fn send(&self, destination: &str, text: &str) -> Result<(), DeliveryError> {
+        let url = format!(
+            "{TWILIO_API_URL}/Accounts/{}/Messages.json",
+            self.cfg.account_sid
+        );
+        let body = serde_urlencoded::to_string([
+            ("Body", text),
+            ("From", self.cfg.from_number.as_str()),
+            ("To", destination),
+        ])
+        .map_err(DeliveryError::terminal)?;
+
+        let response = self
+            .http_client
+            .post(url)
+            .basic_auth(&self.cfg.account_sid, Some(&self.cfg.auth_token))
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await
+            .map_err(DeliveryError::from_webhook_request)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(DeliveryError::from_webhook_status(
+            status,
+            anyhow!("twilio request failed ({status}): {body}"),
+        ))
+    }
+}
+
+/// Template data of phone verification notifications.
+#[derive(Debug, Deserialize)]
+struct PhoneVerification {
+    /// Code the user must enter to verify their phone number.
+    code: String,
+}
+
+/// Prepare the text message for a user notification.
+pub(crate) fn prepare_message(notification: &Notification) -> Result<String> {
+    let template_data = notification
+        .template_data
+        .clone()
+        .ok_or_else(|| anyhow!("missing template data"))?;
+
+    let text = match notification.kind {
+        NotificationKind::EventReminder => {
+            let template: EventReminder = serde_json::from_value(template_data)?;
+            let mut text = format!(
+                "Reminder: {}{}. {}",
+                template.event.name,
+                format_starts_at(&template.event),
+                template.link,
+            );
+            if let Some(meeting_join_url) = &template.event.meeting_join_url {
+                text.push_str(&format!(" Join: {meeting_join_url}"));
+            }
+            text
+        }
+        NotificationKind::PhoneVerification => {
+            let template: PhoneVerification = serde_json::from_value(template_data)?;
+            format!(
+                "Your verification code is {}. It expires in 10 minutes.",
+                template.code
+            )
+        }
+        _ => bail!(
+            "notification kind not supported by sms: {}",
+            notification.kind
+        ),
+    };
+
+    Ok(text)
+}
+
+/// Format the event start time in the event timezone.
+fn format_starts_at(event: &EventSummary) -> String {
+    let Some(starts_at) = event.starts_at else {
+        return String::new();
+    };
+    format!(
+        " starts {}",
+        starts_at.with_timezone(&event.timezone).format("%b %-d at %H:%M %Z")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::services::notifications::{Notification, NotificationKind, NotificationRecipient};
+
+    use super::prepare_message;
+
+    #[test]
+    fn prepare_message_includes_meeting_join_url() {
+        let notification = sample_notification(
+            NotificationKind::EventReminder,
+            json!({
+                "event": {
+                    "canceled": false,
+                    "community_display_name": "Test Community",
+                    "community_name": "test-community",
+                    "event_id": "11111111-1111-1111-1111-111111111111",
+                    "group_category_name": "Community",
+                    "group_name": "Test Group",
+                    "group_slug": "test-group",
+                    "kind": "virtual",
+                    "logo_url": "https://example.test/logo.png",
+                    "meeting_join_url": "https://meet.example.test/abc",
+                    "name": "Test Event",
+                    "published": true,
+                    "slug": "test-event",
+                    "starts_at": 1_914_724_800,
+                    "timezone": "UTC"
+                },
+                "link": "https://example.test/test-community/group/test-group/event/test-event",
+                "reminder_offset_minutes": 60,
+                "show_attendance_cancellation_copy": true,
+                "theme": { "primary_color": "#000000" }
+            }),
+        );
+
+        assert_eq!(
+            prepare_message(&notification).unwrap(),
+            "Reminder: Test Event starts Sep 4 at 04:00 UTC. \
+             https://example.test/test-community/group/test-group/event/test-event \
+             Join: https://meet.example.test/abc"
+        );
+    }
+
+    #[test]
+    fn prepare_message_includes_verification_code() {
+        let notification = sample_notification(
+            NotificationKind::PhoneVerification,
+            json!({ "code": "012345" }),
+        );
+
+        assert_eq!(
+            prepare_message(&notification).unwrap(),
+            "Your verification code is 012345. It expires in 10 minutes."
+        );
+    }
+
+    #[test]
+    fn prepare_message_rejects_unsupported_kinds() {
+        let notification = sample_notification(NotificationKind::GroupWelcome, json!({}));
+
+        assert!(prepare_message(&notification).is_err());
+    }
+
+    #[test]
+    fn prepare_message_requires_template_data() {
+        let mut notification = sample_notification(NotificationKind::EventReminder, json!({}));
+        notification.template_data = None;
+
+        assert!(prepare_message(&notification).is_err());
+    }
+
+    fn sample_notification(
+        kind: NotificationKind,
+        template_data: serde_json::Value,
+    ) -> Notification {
+        Notification {
+            attachments: vec![],
+            delivery_claimed_at: Utc::now(),
+            email_branding: None,
+            kind,
+            language: None,
+            notification_id: Uuid::new_v4(),
+            recipient: NotificationRecipient::Sms(Some("+15551234567".to_string())),
+            template_data: Some(template_data),
+        }
+    }
+}
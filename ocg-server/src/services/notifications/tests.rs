@@ -3,21 +3,27 @@ use std::sync::Arc;
 use anyhow::anyhow;
 use chrono::{DateTime, Utc};
 use mockall::Sequence;
+use reqwest::StatusCode;
 use serde_json::json;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 use crate::{
-    config::{EmailConfig, SmtpConfig},
+    config::{EmailConfig, EmailProvider, SmtpConfig},
     db::{DynDB, mock::MockDB},
+    i18n::Language,
+    services::images::MockImageStorage,
+    types::{community::CommunityEmailBranding, user::UserWebhook},
 };
 
 use super::{
     Attachment, DELIVERY_MAX_CLAIMS, DELIVERY_PROCESSING_TIMEOUT, DELIVERY_REQUEUE_BASE_DELAY,
-    DELIVERY_REQUEUE_MAX_DELAY, DELIVERY_SEND_MAX_ATTEMPTS, DeliveryRecoveryWorker, DeliveryWorker,
-    DynEmailSender, EmailDeliveryError, EnqueueWorker, LettreEmailSender, MockEmailSender,
-    NewNotification, Notification, NotificationKind, NotificationsManager, PgNotificationsManager,
-    SmtpErrorKind,
+    DELIVERY_REQUEUE_MAX_DELAY, DELIVERY_SEND_MAX_ATTEMPTS, DeliveryError, DeliveryRecoveryWorker,
+    DeliveryWorker, DynEmailSender, DynNotificationChannel, Email, EnqueueWorker,
+    LettreEmailSender, MockEmailSender, MockNotificationChannel, MockWebhookSender,
+    NEWSLETTER_BATCH_SIZE, NewNotification, Notification, NotificationChannels, NotificationKind,
+    NotificationRecipient, NotificationsManager, PgNotificationsManager, SmtpErrorKind,
+    webhook::WebhookPayload,
 };
 
 #[tokio::test]
@@ -55,9 +61,38 @@ async fn test_enqueue_worker_enqueue_due_notifications() {
     // Setup database mock
     let mut db = MockDB::new();
     db.expect_enqueue_due_event_reminders()
+        .times(1)
+        .withf(|base_url, offsets_minutes| {
+            base_url == "https://example.test" && offsets_minutes == [1440, 60]
+        })
+        .returning(|_, _| Ok(2));
+    db.expect_enqueue_due_event_follow_ups()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(4));
+    db.expect_enqueue_due_event_attendance_certificates()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(5));
+    db.expect_enqueue_due_event_waitlist_promotions()
         .times(1)
         .withf(|base_url| base_url == "https://example.test")
         .returning(|_| Ok(2));
+    db.expect_enqueue_due_weekly_digests()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(3));
+    db.expect_enqueue_due_community_newsletter_batches()
+        .times(1)
+        .withf(|base_url, batch_size| {
+            base_url == "https://example.test" && *batch_size == NEWSLETTER_BATCH_SIZE
+        })
+        .returning(|_, _| Ok(2));
+    db.expect_update_dormant_groups().times(1).returning(|| Ok(2));
+    db.expect_enqueue_due_dormant_groups_reports()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(1));
     let db: DynDB = Arc::new(db);
 
     // Setup worker and enqueue due notifications
@@ -65,11 +100,12 @@ async fn test_enqueue_worker_enqueue_due_notifications() {
         db,
         base_url: "https://example.test".to_string(),
         cancellation_token: CancellationToken::new(),
+        event_reminder_offsets_minutes: vec![1440, 60],
     };
     let enqueued = worker.enqueue_due_notifications().await.unwrap();
 
     // Check result matches expectations
-    assert_eq!(enqueued, 2);
+    assert_eq!(enqueued, 19);
 }
 
 #[tokio::test]
@@ -78,8 +114,10 @@ async fn test_enqueue_worker_enqueue_due_notifications_error() {
     let mut db = MockDB::new();
     db.expect_enqueue_due_event_reminders()
         .times(1)
-        .withf(|base_url| base_url == "https://example.test")
-        .returning(|_| Err(anyhow!("enqueue error")));
+        .withf(|base_url, offsets_minutes| {
+            base_url == "https://example.test" && offsets_minutes == [1440, 60]
+        })
+        .returning(|_, _| Err(anyhow!("enqueue error")));
     let db: DynDB = Arc::new(db);
 
     // Setup worker and enqueue due notifications
@@ -87,6 +125,7 @@ async fn test_enqueue_worker_enqueue_due_notifications_error() {
         db,
         base_url: "https://example.test".to_string(),
         cancellation_token: CancellationToken::new(),
+        event_reminder_offsets_minutes: vec![1440, 60],
     };
     let err = worker.enqueue_due_notifications().await.unwrap_err();
 
@@ -104,8 +143,10 @@ async fn test_enqueue_worker_run_stops_on_cancellation_after_enqueue_error() {
     let mut db = MockDB::new();
     db.expect_enqueue_due_event_reminders()
         .times(1)
-        .withf(|base_url| base_url == "https://example.test")
-        .returning(move |_| {
+        .withf(|base_url, offsets_minutes| {
+            base_url == "https://example.test" && offsets_minutes == [1440, 60]
+        })
+        .returning(move |_, _| {
             cancellation_token_for_mock.cancel();
             Err(anyhow!("enqueue error"))
         });
@@ -116,6 +157,7 @@ async fn test_enqueue_worker_run_stops_on_cancellation_after_enqueue_error() {
         db,
         base_url: "https://example.test".to_string(),
         cancellation_token: cancellation_token.clone(),
+        event_reminder_offsets_minutes: vec![1440, 60],
     };
     worker.run().await;
 
@@ -133,11 +175,40 @@ async fn test_enqueue_worker_run_stops_on_cancellation_after_enqueue_success() {
     let mut db = MockDB::new();
     db.expect_enqueue_due_event_reminders()
         .times(1)
-        .withf(|base_url| base_url == "https://example.test")
-        .returning(move |_| {
+        .withf(|base_url, offsets_minutes| {
+            base_url == "https://example.test" && offsets_minutes == [1440, 60]
+        })
+        .returning(move |_, _| {
             cancellation_token_for_mock.cancel();
             Ok(1)
         });
+    db.expect_enqueue_due_event_follow_ups()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(0));
+    db.expect_enqueue_due_event_attendance_certificates()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(0));
+    db.expect_enqueue_due_event_waitlist_promotions()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(0));
+    db.expect_enqueue_due_weekly_digests()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(0));
+    db.expect_enqueue_due_community_newsletter_batches()
+        .times(1)
+        .withf(|base_url, batch_size| {
+            base_url == "https://example.test" && *batch_size == NEWSLETTER_BATCH_SIZE
+        })
+        .returning(|_, _| Ok(0));
+    db.expect_update_dormant_groups().times(1).returning(|| Ok(0));
+    db.expect_enqueue_due_dormant_groups_reports()
+        .times(1)
+        .withf(|base_url| base_url == "https://example.test")
+        .returning(|_| Ok(0));
     let db: DynDB = Arc::new(db);
 
     // Setup worker and execute loop
@@ -145,6 +216,7 @@ async fn test_enqueue_worker_run_stops_on_cancellation_after_enqueue_success() {
         db,
         base_url: "https://example.test".to_string(),
         cancellation_token: cancellation_token.clone(),
+        event_reminder_offsets_minutes: vec![1440, 60],
     };
     worker.run().await;
 
@@ -184,7 +256,7 @@ async fn test_delivery_recovery_worker_run_stops_on_cancellation_after_success()
     db.expect_mark_stale_processing_notifications_unknown()
         .times(1)
         .withf(|timeout| *timeout == DELIVERY_PROCESSING_TIMEOUT)
-        .returning(move |_| {
+        .returning(move |_, _| {
             cancellation_token_for_mock.cancel();
             Ok(1)
         });
@@ -207,13 +279,65 @@ async fn test_delivery_worker_deliver_notification_sends_pending_notification()
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "notify@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EmailVerification,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("notify@example.test".to_string()),
         template_data: Some(sample_email_verification_template_data()),
     };
     let notification_id = notification.notification_id;
-    let recipient = notification.email.clone();
+    let recipient = "notify@example.test".to_string();
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| notif.notification_id == notification_id && err.is_none())
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Setup email sender mock
+    let mut es = MockEmailSender::new();
+    es.expect_send()
+        .times(1)
+        .withf(move |email| email.to.email.to_string() == recipient)
+        .returning(|_| Box::pin(async { Ok::<(), DeliveryError>(()) }));
+    let es: DynEmailSender = Arc::new(es);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
+}
+
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_sends_email_with_community_sender_name() {
+    // Setup identifiers and data structures
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: Some(sample_community_email_branding()),
+        kind: NotificationKind::EventPublished,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("notify@example.test".to_string()),
+        template_data: Some(sample_event_reminder_template_data()),
+    };
+    let notification_id = notification.notification_id;
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -230,22 +354,22 @@ async fn test_delivery_worker_deliver_notification_sends_pending_notification()
     let mut es = MockEmailSender::new();
     es.expect_send()
         .times(1)
-        .withf(move |message| {
-            message
-                .envelope()
-                .to()
-                .iter()
-                .any(|rcpt| rcpt.to_string() == recipient)
+        .withf(|email| {
+            email.from.name.as_deref() == Some("Test Community Team")
+                && email.from.email.to_string() == "no-reply@example.test"
         })
-        .returning(|_| Box::pin(async { Ok::<(), EmailDeliveryError>(()) }));
+        .returning(|_| Box::pin(async { Ok::<(), DeliveryError>(()) }));
     let es: DynEmailSender = Arc::new(es);
 
     // Setup worker and deliver notification
     let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         db,
         cfg: sample_email_config(None),
         cancellation_token: CancellationToken::new(),
         email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
     };
     let delivered = worker.deliver_notification().await.unwrap();
 
@@ -264,13 +388,15 @@ async fn test_delivery_worker_deliver_notification_sends_pending_notification_wi
     let notification = Notification {
         attachments: attachments.clone(),
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "notify@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EmailVerification,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("notify@example.test".to_string()),
         template_data: Some(sample_email_verification_template_data()),
     };
     let notification_id = notification.notification_id;
-    let recipient = notification.email.clone();
+    let recipient = "notify@example.test".to_string();
 
     // Setup database mock
     let mut db = MockDB::new();
@@ -287,22 +413,19 @@ async fn test_delivery_worker_deliver_notification_sends_pending_notification_wi
     let mut es = MockEmailSender::new();
     es.expect_send()
         .times(1)
-        .withf(move |message| {
-            message
-                .envelope()
-                .to()
-                .iter()
-                .any(|rcpt| rcpt.to_string() == recipient)
-        })
-        .returning(|_| Box::pin(async { Ok::<(), EmailDeliveryError>(()) }));
+        .withf(move |email| email.to.email.to_string() == recipient)
+        .returning(|_| Box::pin(async { Ok::<(), DeliveryError>(()) }));
     let es: DynEmailSender = Arc::new(es);
 
     // Setup worker and deliver notification
     let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         db,
         cfg: sample_email_config(None),
         cancellation_token: CancellationToken::new(),
         email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
     };
     let delivered = worker.deliver_notification().await.unwrap();
 
@@ -324,10 +447,13 @@ async fn test_delivery_worker_deliver_notification_no_pending_notifications() {
 
     // Setup worker and deliver notification
     let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         db,
         cfg: sample_email_config(None),
         cancellation_token: CancellationToken::new(),
         email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
     };
     let delivered = worker.deliver_notification().await.unwrap();
 
@@ -341,9 +467,11 @@ async fn test_delivery_worker_deliver_notification_records_send_error() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "notify@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EmailVerification,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("notify@example.test".to_string()),
         template_data: Some(sample_email_verification_template_data()),
     };
     let notification_id = notification.notification_id;
@@ -363,17 +491,20 @@ async fn test_delivery_worker_deliver_notification_records_send_error() {
 
     // Setup email sender mock
     let mut es = MockEmailSender::new();
-    es.expect_send().times(1).returning(|_| {
-        Box::pin(async { Err(EmailDeliveryError::Terminal(anyhow!("delivery error"))) })
-    });
+    es.expect_send()
+        .times(1)
+        .returning(|_| Box::pin(async { Err(DeliveryError::Terminal(anyhow!("delivery error"))) }));
     let es: DynEmailSender = Arc::new(es);
 
     // Setup worker and deliver notification
     let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         db,
         cfg: sample_email_config(None),
         cancellation_token: CancellationToken::new(),
         email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
     };
     let delivered = worker.deliver_notification().await.unwrap();
 
@@ -387,9 +518,11 @@ async fn test_delivery_worker_deliver_notification_records_unknown_send_error()
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "notify@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EmailVerification,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("notify@example.test".to_string()),
         template_data: Some(sample_email_verification_template_data()),
     };
     let notification_id = notification.notification_id;
@@ -414,7 +547,7 @@ async fn test_delivery_worker_deliver_notification_records_unknown_send_error()
     let mut es = MockEmailSender::new();
     es.expect_send().times(1).returning(|_| {
         Box::pin(async {
-            Err(EmailDeliveryError::Unknown(anyhow!(
+            Err(DeliveryError::Unknown(anyhow!(
                 "network error: connection reset by peer"
             )))
         })
@@ -423,10 +556,13 @@ async fn test_delivery_worker_deliver_notification_records_unknown_send_error()
 
     // Setup worker and deliver notification
     let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         cancellation_token: CancellationToken::new(),
         cfg: sample_email_config(None),
         db,
         email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
     };
     let delivered = worker.deliver_notification().await.unwrap();
 
@@ -440,9 +576,11 @@ async fn test_delivery_worker_deliver_notification_requeues_retryable_send_error
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "notify@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EmailVerification,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("notify@example.test".to_string()),
         template_data: Some(sample_email_verification_template_data()),
     };
     let notification_id = notification.notification_id;
@@ -471,7 +609,7 @@ async fn test_delivery_worker_deliver_notification_requeues_retryable_send_error
     let mut es = MockEmailSender::new();
     es.expect_send().times(DELIVERY_SEND_MAX_ATTEMPTS).returning(|_| {
         Box::pin(async {
-            Err(EmailDeliveryError::Retryable(anyhow!(
+            Err(DeliveryError::Retryable(anyhow!(
                 "Connection error: smtp unavailable"
             )))
         })
@@ -480,10 +618,13 @@ async fn test_delivery_worker_deliver_notification_requeues_retryable_send_error
 
     // Setup worker and deliver notification
     let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         db,
         cfg: sample_email_config(None),
         cancellation_token: CancellationToken::new(),
         email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
     };
     let delivered = worker.deliver_notification().await.unwrap();
 
@@ -497,9 +638,11 @@ async fn test_delivery_worker_deliver_notification_returns_unknown_update_error(
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "notify@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EmailVerification,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("notify@example.test".to_string()),
         template_data: Some(sample_email_verification_template_data()),
     };
     let notification_id = notification.notification_id;
@@ -524,7 +667,7 @@ async fn test_delivery_worker_deliver_notification_returns_unknown_update_error(
     let mut es = MockEmailSender::new();
     es.expect_send().times(1).returning(|_| {
         Box::pin(async {
-            Err(EmailDeliveryError::Unknown(anyhow!(
+            Err(DeliveryError::Unknown(anyhow!(
                 "network error: connection reset by peer"
             )))
         })
@@ -533,10 +676,13 @@ async fn test_delivery_worker_deliver_notification_returns_unknown_update_error(
 
     // Setup worker and deliver notification
     let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         cancellation_token: CancellationToken::new(),
         cfg: sample_email_config(None),
         db,
         email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
     };
     let err = worker.deliver_notification().await.unwrap_err();
 
@@ -550,9 +696,11 @@ async fn test_delivery_worker_deliver_notification_returns_update_error() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "notify@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EmailVerification,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("notify@example.test".to_string()),
         template_data: Some(sample_email_verification_template_data()),
     };
     let notification_id = notification.notification_id;
@@ -572,15 +720,18 @@ async fn test_delivery_worker_deliver_notification_returns_update_error() {
     let mut es = MockEmailSender::new();
     es.expect_send()
         .times(1)
-        .returning(|_| Box::pin(async { Ok::<(), EmailDeliveryError>(()) }));
+        .returning(|_| Box::pin(async { Ok::<(), DeliveryError>(()) }));
     let es: DynEmailSender = Arc::new(es);
 
     // Setup worker and deliver notification
     let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         db,
         cfg: sample_email_config(None),
         cancellation_token: CancellationToken::new(),
         email_sender: es,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
     };
     let err = worker.deliver_notification().await.unwrap_err();
 
@@ -588,104 +739,829 @@ async fn test_delivery_worker_deliver_notification_returns_update_error() {
     assert!(err.to_string().contains("update error"));
 }
 
-#[test]
-fn test_delivery_worker_prepare_content_email_verification() {
-    // Setup notification
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_posts_slack_message() {
+    // Setup identifiers and data structures
+    let webhook_url = "https://hooks.slack.com/services/T0/B0/secret";
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
-        kind: NotificationKind::EmailVerification,
+        email_branding: None,
+        kind: NotificationKind::GroupWelcome,
+        language: None,
         notification_id: Uuid::new_v4(),
-        template_data: Some(sample_email_verification_template_data()),
+        recipient: NotificationRecipient::Slack(Some(webhook_url.to_string())),
+        template_data: Some(sample_group_custom_template_data()),
     };
+    let notification_id = notification.notification_id;
 
-    // Prepare content
-    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| notif.notification_id == notification_id && err.is_none())
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
 
-    // Check content matches expectations
-    assert_eq!(subject, "Verify your email address");
-    assert!(body.contains("Verify your email"));
-    assert!(body.contains("https://example.test/verify"));
+    // Setup slack channel mock
+    let mut sc = MockNotificationChannel::new();
+    sc.expect_send()
+        .times(1)
+        .withf(move |destination, text| {
+            destination == webhook_url
+                && text
+                    == "A new member joined \
+                        <https://example.test/test-community/group/hello-group|Hello Group>"
+        })
+        .returning(|_, _| Box::pin(async { Ok::<(), DeliveryError>(()) }));
+    let sc: DynNotificationChannel = Arc::new(sc);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: NotificationChannels {
+            discord: Arc::new(MockNotificationChannel::new()),
+            slack: sc,
+            sms: None,
+            webhook: Arc::new(MockWebhookSender::new()),
+        },
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
 }
 
-#[test]
-fn test_delivery_worker_prepare_content_event_attendance_canceled() {
-    // Setup notification
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_fails_slack_message_without_webhook() {
+    // Setup identifiers and data structures
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
-        kind: NotificationKind::EventAttendanceCanceled,
+        email_branding: None,
+        kind: NotificationKind::GroupWelcome,
+        language: None,
         notification_id: Uuid::new_v4(),
-        template_data: Some(sample_event_attendance_canceled_template_data()),
+        recipient: NotificationRecipient::Slack(None),
+        template_data: Some(sample_group_custom_template_data()),
     };
+    let notification_id = notification.notification_id;
 
-    // Prepare content
-    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| {
+            notif.notification_id == notification_id
+                && err.as_deref() == Some("community slack webhook is not configured")
+        })
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
 
-    // Check content matches expectations
-    assert_eq!(subject, "Attendance canceled");
-    assert!(body.contains("Your attendance for"));
-    assert!(body.contains("Reminder Event"));
-    assert!(body.contains("Open My Events"));
-    assert!(body.contains("https://example.test/dashboard/user?tab=events"));
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
 }
 
-#[test]
-fn test_delivery_worker_prepare_content_event_custom() {
-    // Setup notification
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_requeues_retryable_slack_error() {
+    // Setup identifiers and data structures
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
-        kind: NotificationKind::EventCustom,
+        email_branding: None,
+        kind: NotificationKind::GroupWelcome,
+        language: None,
         notification_id: Uuid::new_v4(),
-        template_data: Some(sample_event_custom_template_data()),
+        recipient: NotificationRecipient::Slack(Some(
+            "https://hooks.slack.com/services/T0/B0/secret".to_string(),
+        )),
+        template_data: Some(sample_group_custom_template_data()),
     };
+    let notification_id = notification.notification_id;
 
-    // Prepare content
-    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_requeue_notification()
+        .times(1)
+        .withf(move |notif, error, _, _, max_delivery_attempts| {
+            notif.notification_id == notification_id
+                && error == "rate limited"
+                && *max_delivery_attempts == DELIVERY_MAX_CLAIMS
+        })
+        .returning(|_, _, _, _, _| Ok(()));
+    let db: DynDB = Arc::new(db);
 
-    // Check content matches expectations
-    assert_eq!(subject, "Custom event subject");
-    assert!(body.contains("Custom event subject"));
-    assert!(body.contains("Custom event body"));
-    assert!(body.contains("You received this email notification because you're a member of"));
-    assert!(body.contains("Notification Group"));
-    assert!(body.contains("Test Community community"));
+    // Setup slack channel mock
+    let mut sc = MockNotificationChannel::new();
+    sc.expect_send().times(1).returning(|_, _| {
+        Box::pin(async { Err(DeliveryError::Retryable(anyhow!("rate limited"))) })
+    });
+    let sc: DynNotificationChannel = Arc::new(sc);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: NotificationChannels {
+            discord: Arc::new(MockNotificationChannel::new()),
+            slack: sc,
+            sms: None,
+            webhook: Arc::new(MockWebhookSender::new()),
+        },
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
 }
 
-#[test]
-fn test_delivery_worker_prepare_content_event_custom_legacy_template_data() {
-    // Setup notification
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_posts_discord_message() {
+    // Setup identifiers and data structures
+    let webhook_url = "https://discord.com/api/webhooks/1/token";
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
-        kind: NotificationKind::EventCustom,
+        email_branding: None,
+        kind: NotificationKind::EventReminder,
+        language: None,
         notification_id: Uuid::new_v4(),
-        template_data: Some(sample_event_custom_legacy_template_data()),
+        recipient: NotificationRecipient::Discord(Some(webhook_url.to_string())),
+        template_data: Some(sample_event_reminder_template_data()),
     };
+    let notification_id = notification.notification_id;
 
-    // Prepare content
-    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| notif.notification_id == notification_id && err.is_none())
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
 
-    // Check content matches expectations
-    assert_eq!(subject, "Custom event title");
-    assert!(body.contains("Custom event title"));
-    assert!(body.contains("Custom event body"));
+    // Setup discord channel mock
+    let mut dc = MockNotificationChannel::new();
+    dc.expect_send()
+        .times(1)
+        .withf(move |destination, text| {
+            destination == webhook_url
+                && text
+                    == "Reminder: [Reminder Event]\
+                        (<https://example.test/test-community/group/notification-group/event/reminder-event>) \
+                        starts <t:1914724800:F>"
+        })
+        .returning(|_, _| Box::pin(async { Ok::<(), DeliveryError>(()) }));
+    let dc: DynNotificationChannel = Arc::new(dc);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: NotificationChannels {
+            discord: dc,
+            slack: Arc::new(MockNotificationChannel::new()),
+            sms: None,
+            webhook: Arc::new(MockWebhookSender::new()),
+        },
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
 }
 
-#[test]
-fn test_delivery_worker_prepare_content_event_invitation() {
-    // Setup notification
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_fails_discord_message_without_webhook() {
+    // Setup identifiers and data structures
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
-        kind: NotificationKind::EventInvitation,
+        email_branding: None,
+        kind: NotificationKind::EventReminder,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Discord(None),
+        template_data: Some(sample_event_reminder_template_data()),
+    };
+    let notification_id = notification.notification_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| {
+            notif.notification_id == notification_id
+                && err.as_deref() == Some("group discord webhook is not configured")
+        })
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
+}
+
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_posts_webhook_payload() {
+    // Setup identifiers and data structures
+    let webhook = UserWebhook {
+        secret: "secret".to_string(),
+        url: "https://example.test/webhook".to_string(),
+    };
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::GroupWelcome,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Webhook(Some(webhook.clone())),
+        template_data: Some(sample_group_custom_template_data()),
+    };
+    let notification_id = notification.notification_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| notif.notification_id == notification_id && err.is_none())
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Setup webhook sender mock
+    let mut ws = MockWebhookSender::new();
+    ws.expect_send()
+        .times(1)
+        .withf(move |destination, payload| {
+            *destination == webhook
+                && *payload
+                    == WebhookPayload {
+                        id: notification_id,
+                        kind: NotificationKind::GroupWelcome,
+                        data: Some(sample_group_custom_template_data()),
+                    }
+        })
+        .returning(|_, _| Box::pin(async { Ok::<(), DeliveryError>(()) }));
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: NotificationChannels {
+            webhook: Arc::new(ws),
+            ..sample_notification_channels()
+        },
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
+}
+
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_sends_sms_message() {
+    // Setup identifiers and data structures
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::PhoneVerification,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Sms(Some("+15551234567".to_string())),
+        template_data: Some(json!({ "code": "123456" })),
+    };
+    let notification_id = notification.notification_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| notif.notification_id == notification_id && err.is_none())
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Setup SMS channel mock
+    let mut sc = MockNotificationChannel::new();
+    sc.expect_send()
+        .times(1)
+        .withf(|destination, text| {
+            destination == "+15551234567"
+                && text == "Your verification code is 123456. It expires in 10 minutes."
+        })
+        .returning(|_, _| Box::pin(async { Ok::<(), DeliveryError>(()) }));
+    let sc: DynNotificationChannel = Arc::new(sc);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: NotificationChannels {
+            sms: Some(sc),
+            ..sample_notification_channels()
+        },
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
+}
+
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_fails_sms_message_when_not_configured() {
+    // Setup identifiers and data structures
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::PhoneVerification,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Sms(Some("+15551234567".to_string())),
+        template_data: Some(json!({ "code": "123456" })),
+    };
+    let notification_id = notification.notification_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| {
+            notif.notification_id == notification_id
+                && err.as_deref() == Some("sms notifications are not configured")
+        })
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
+}
+
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_fails_sms_message_without_phone_number() {
+    // Setup identifiers and data structures
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventReminder,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Sms(None),
+        template_data: Some(json!({})),
+    };
+    let notification_id = notification.notification_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| {
+            notif.notification_id == notification_id
+                && err.as_deref() == Some("user sms notifications are not enabled")
+        })
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: NotificationChannels {
+            sms: Some(Arc::new(MockNotificationChannel::new())),
+            ..sample_notification_channels()
+        },
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
+}
+
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_fails_webhook_payload_without_webhook() {
+    // Setup identifiers and data structures
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::GroupWelcome,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Webhook(None),
+        template_data: Some(sample_group_custom_template_data()),
+    };
+    let notification_id = notification.notification_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_update_notification()
+        .times(1)
+        .withf(move |notif, err| {
+            notif.notification_id == notification_id
+                && err.as_deref() == Some("user webhook is not configured")
+        })
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
+}
+
+#[tokio::test]
+async fn test_delivery_worker_deliver_notification_marks_unknown_webhook_error() {
+    // Setup identifiers and data structures
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::GroupWelcome,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Webhook(Some(UserWebhook {
+            secret: "secret".to_string(),
+            url: "https://example.test/webhook".to_string(),
+        })),
+        template_data: Some(sample_group_custom_template_data()),
+    };
+    let notification_id = notification.notification_id;
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    db.expect_claim_pending_notification()
+        .times(1)
+        .returning(move || Ok(Some(notification.clone())));
+    db.expect_mark_notification_delivery_unknown()
+        .times(1)
+        .withf(move |notif, error| notif.notification_id == notification_id && error == "timed out")
+        .returning(|_, _| Ok(()));
+    let db: DynDB = Arc::new(db);
+
+    // Setup webhook sender mock
+    let mut ws = MockWebhookSender::new();
+    ws.expect_send()
+        .times(1)
+        .returning(|_, _| Box::pin(async { Err(DeliveryError::Unknown(anyhow!("timed out"))) }));
+
+    // Setup worker and deliver notification
+    let mut worker = DeliveryWorker {
+        base_url: "https://example.test".to_string(),
+        db,
+        cfg: sample_email_config(None),
+        cancellation_token: CancellationToken::new(),
+        email_sender: Arc::new(MockEmailSender::new()),
+        channels: NotificationChannels {
+            webhook: Arc::new(ws),
+            ..sample_notification_channels()
+        },
+        image_storage: Arc::new(MockImageStorage::new()),
+    };
+    let delivered = worker.deliver_notification().await.unwrap();
+
+    // Check result matches expectations
+    assert!(delivered);
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_community_newsletter() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::CommunityNewsletter,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_community_newsletter_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Community news");
+    assert!(body.contains("<strong>Hello</strong> subscribers"));
+    assert!(body.contains("Test Community"));
+    assert!(body.contains("https://example.test/test-community/newsletter/issue"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_dormant_groups_report() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::DormantGroupsReport,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_dormant_groups_report_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Dormant groups in Test Community");
+    assert!(body.contains("1 group has not held events in the last 6 months."));
+    assert!(body.contains("Quiet Group"));
+    assert!(body.contains("Dormant since July  1, 2024"));
+    assert!(body.contains("https://example.test/test-community/group/quiet-group"));
+    assert!(body.contains("https://example.test/dashboard/community?tab=groups"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_email_verification() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EmailVerification,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_email_verification_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Verify your email address");
+    assert!(body.contains("Verify your email"));
+    assert!(body.contains("https://example.test/verify"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_email_verification_uses_template_language() {
+    // Setup notification
+    let mut template_data = sample_email_verification_template_data();
+    template_data["language"] = json!("fr");
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EmailVerification,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(template_data),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content is rendered in the language requested at sign up
+    assert_eq!(subject, "Vérifiez votre adresse e-mail");
+    assert!(body.contains(r#"<html lang="fr">"#));
+    assert!(body.contains("<strong>n'est valable que 24 heures</strong>"));
+    assert!(body.contains("Vous pouvez aussi copier-coller ce lien :"));
+    assert!(!body.contains("You can unsubscribe from optional email notifications"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_attendance_canceled() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventAttendanceCanceled,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_attendance_canceled_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Attendance canceled");
+    assert!(body.contains("Your attendance for"));
+    assert!(body.contains("Reminder Event"));
+    assert!(body.contains("Open My Events"));
+    assert!(body.contains("https://example.test/dashboard/user?tab=events"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_attendance_certificate() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventAttendanceCertificate,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_attendance_certificate_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Your certificate of attendance for Reminder Event");
+    assert!(body.contains("September 4, 2030"));
+    assert!(body.contains("Your certificate of attendance is attached to this email"));
+    assert!(body.contains("https://example.test/dashboard/user?tab=certificates"));
+    assert!(body.contains("You received this email notification because you checked in to"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_custom() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventCustom,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_custom_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Custom event subject");
+    assert!(body.contains("Custom event subject"));
+    assert!(body.contains("Custom event body"));
+    assert!(body.contains("You received this email notification because you're a member of"));
+    assert!(body.contains("Notification Group"));
+    assert!(body.contains("Test Community community"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_custom_legacy_template_data() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventCustom,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_custom_legacy_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Custom event title");
+    assert!(body.contains("Custom event title"));
+    assert!(body.contains("Custom event body"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_follow_up() {
+    // Setup notification
+    let mut template_data = sample_event_custom_template_data();
+    template_data["feedback_link"] = json!("https://example.test/feedback");
+    template_data["recording_link"] = json!("https://example.test/recording");
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventFollowUp,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(template_data),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Thanks for attending Custom Event");
+    assert!(body.contains("https://example.test/feedback"));
+    assert!(body.contains("https://example.test/recording"));
+    assert!(body.contains("Watch recording"));
+    assert!(body.contains("You received this email notification because you checked in to"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_invitation() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventInvitation,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_invitation_template_data()),
     };
 
@@ -702,25 +1578,74 @@ fn test_delivery_worker_prepare_content_event_invitation() {
 }
 
 #[test]
-fn test_delivery_worker_prepare_content_event_published() {
+fn test_delivery_worker_prepare_content_event_invitation_request_rejected() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventInvitationRequestRejected,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_waitlist_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Attendance request declined");
+    assert!(body.contains("were not able to accept it"));
+    assert!(body.contains("Waitlist Event"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_published() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventPublished,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_reminder_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "New event published");
+    assert!(body.contains("You received this email notification because you're a member of"));
+    assert!(body.contains("Notification Group"));
+    assert!(body.contains("Test Community community"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_published_with_community_branding() {
     // Setup notification
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: Some(sample_community_email_branding()),
         kind: NotificationKind::EventPublished,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_reminder_template_data()),
     };
 
     // Prepare content
-    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+    let (_, body) = DeliveryWorker::prepare_content(&notification).unwrap();
 
-    // Check content matches expectations
-    assert_eq!(subject, "New event published");
-    assert!(body.contains("You received this email notification because you're a member of"));
-    assert!(body.contains("Notification Group"));
-    assert!(body.contains("Test Community community"));
+    // Check the community branding overrides the site theme
+    assert!(body.contains("border-top: 7px solid #0A3D62"));
+    assert!(!body.contains("#000000"));
+    assert!(body.contains("https://example.test/email-logo.png"));
+    assert!(body.contains("Test Community, 123 Main St"));
 }
 
 #[test]
@@ -729,9 +1654,11 @@ fn test_delivery_worker_prepare_content_event_reminder() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventReminder,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_reminder_template_data()),
     };
 
@@ -763,9 +1690,11 @@ fn test_delivery_worker_prepare_content_event_reminder_legacy_template_data() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventReminder,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_reminder_legacy_template_data()),
     };
 
@@ -788,9 +1717,11 @@ fn test_delivery_worker_prepare_content_event_reminder_speaker_only() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventReminder,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(template_data),
     };
 
@@ -804,15 +1735,115 @@ fn test_delivery_worker_prepare_content_event_reminder_speaker_only() {
     assert!(body.contains("https://example.test/dashboard/user?tab=events"));
 }
 
+#[test]
+fn test_delivery_worker_prepare_content_event_reminder_offset() {
+    // Setup notification
+    let mut template_data = sample_event_reminder_template_data();
+    template_data["reminder_offset_minutes"] = json!(60);
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventReminder,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(template_data),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Reminder: Reminder Event starts in 1 hour");
+    assert!(body.contains("starts in 1 hour."));
+}
+
+#[tokio::test]
+async fn test_delivery_worker_prepare_attachments_event_attendance_certificate() {
+    // Setup worker and notification
+    let worker =
+        sample_delivery_worker(sample_email_config(None), Arc::new(MockEmailSender::new()));
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventAttendanceCertificate,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_attendance_certificate_template_data()),
+    };
+
+    // Prepare attachments
+    let attachments = worker.prepare_attachments(&notification).await;
+
+    // Check the certificate attachment was built for the attendee
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].content_type, "application/pdf");
+    assert_eq!(attachments[0].file_name, "certificate-reminder-event.pdf");
+    assert!(attachments[0].data.starts_with(b"%PDF-1.4"));
+}
+
+#[tokio::test]
+async fn test_delivery_worker_prepare_attachments_event_reminder() {
+    // Setup worker and notification
+    let worker =
+        sample_delivery_worker(sample_email_config(None), Arc::new(MockEmailSender::new()));
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventReminder,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_reminder_template_data()),
+    };
+
+    // Prepare attachments
+    let attachments = worker.prepare_attachments(&notification).await;
+
+    // Check the calendar attachment was built for the event
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].file_name, "event-reminder-event.ics");
+    assert!(String::from_utf8_lossy(&attachments[0].data).contains("SUMMARY:Reminder Event"));
+}
+
+#[tokio::test]
+async fn test_delivery_worker_prepare_attachments_keeps_enqueued_attachments() {
+    // Setup worker and notification
+    let worker =
+        sample_delivery_worker(sample_email_config(None), Arc::new(MockEmailSender::new()));
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventWelcome,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_welcome_template_data(None)),
+    };
+
+    // Prepare attachments
+    let attachments = worker.prepare_attachments(&notification).await;
+
+    // Check no attachments were added
+    assert!(attachments.is_empty());
+}
+
 #[test]
 fn test_delivery_worker_prepare_content_event_series_canceled() {
     // Setup notification
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventSeriesCanceled,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_series_template_data()),
     };
 
@@ -832,9 +1863,11 @@ fn test_delivery_worker_prepare_content_event_series_published() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventSeriesPublished,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_series_template_data()),
     };
 
@@ -857,9 +1890,11 @@ fn test_delivery_worker_prepare_content_speaker_series_welcome() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::SpeakerSeriesWelcome,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_series_template_data()),
     };
 
@@ -880,9 +1915,11 @@ fn test_delivery_worker_prepare_content_event_waitlist_joined() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventWaitlistJoined,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_waitlist_template_data()),
     };
 
@@ -901,9 +1938,11 @@ fn test_delivery_worker_prepare_content_event_waitlist_left() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventWaitlistLeft,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_waitlist_template_data()),
     };
 
@@ -922,9 +1961,11 @@ fn test_delivery_worker_prepare_content_event_waitlist_promoted() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventWaitlistPromoted,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_waitlist_template_data()),
     };
 
@@ -947,9 +1988,11 @@ fn test_delivery_worker_prepare_content_event_waitlist_promoted_with_registratio
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventWaitlistPromoted,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_waitlist_template_data_with_registration_questions()),
     };
 
@@ -975,9 +2018,11 @@ fn test_delivery_worker_prepare_content_event_welcome_omits_dashboard_cancellati
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventWelcome,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_welcome_template_data(None)),
     };
 
@@ -988,6 +2033,7 @@ fn test_delivery_worker_prepare_content_event_welcome_omits_dashboard_cancellati
     assert_eq!(subject, "Welcome to the event");
     assert!(!body.contains("cancel your attendance from the My"));
     assert!(!body.contains("Open My Events"));
+    assert!(!body.contains("Your ticket QR code is attached."));
 }
 
 #[test]
@@ -996,9 +2042,11 @@ fn test_delivery_worker_prepare_content_event_welcome_renders_dashboard_cancella
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EventWelcome,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_event_welcome_template_data(Some(
             "https://example.test/dashboard/user?tab=events",
         ))),
@@ -1014,15 +2062,100 @@ fn test_delivery_worker_prepare_content_event_welcome_renders_dashboard_cancella
     assert!(body.contains("https://example.test/dashboard/user?tab=events"));
 }
 
+#[test]
+fn test_delivery_worker_prepare_content_event_welcome_renders_ticket_notice() {
+    // Setup notification
+    let mut template_data = sample_event_welcome_template_data(None);
+    template_data["ticket_attached"] = json!(true);
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventWelcome,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(template_data),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Welcome to the event");
+    assert!(body.contains(
+        "Your ticket QR code is attached. Show it at the entrance so the organizers can check you in."
+    ));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_event_welcome_uses_user_language() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::EventWelcome,
+        language: Some(Language::Es),
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_event_welcome_template_data(Some(
+            "https://example.test/dashboard/user?tab=events",
+        ))),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content is rendered in the user's language
+    assert_eq!(subject, "Te damos la bienvenida al evento");
+    assert!(body.contains(r#"<html lang="es">"#));
+    assert!(body.contains("Inscripción confirmada: Welcome Event"));
+    assert!(body.contains(
+        "Gracias por inscribirte en <strong>Welcome Event</strong> con <strong>Notification Group</strong>"
+    ));
+    assert!(body.contains("Fecha y hora:"));
+    assert!(body.contains("Abrir Mis eventos"));
+    assert!(body.contains("Puedes darte de baja de las notificaciones opcionales"));
+    assert!(!body.contains("Thanks for registering"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_group_contact_message() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::GroupContactMessage,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("organizer@example.test".to_string()),
+        template_data: Some(sample_group_contact_message_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "[Hello Group] Venue offer");
+    assert!(body.contains("Jane Doe"));
+    assert!(body.contains("We can host your next meetup"));
+    assert!(body.contains("mailto:jane@example.test"));
+    assert!(body.contains("Your email address was"));
+}
+
 #[test]
 fn test_delivery_worker_prepare_content_group_custom() {
     // Setup notification
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::GroupCustom,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_group_custom_template_data()),
     };
 
@@ -1044,9 +2177,11 @@ fn test_delivery_worker_prepare_content_group_custom_legacy_template_data() {
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::GroupCustom,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: Some(sample_group_custom_legacy_template_data()),
     };
 
@@ -1059,15 +2194,40 @@ fn test_delivery_worker_prepare_content_group_custom_legacy_template_data() {
     assert!(body.contains("Custom group body"));
 }
 
+#[test]
+fn test_delivery_worker_prepare_content_magic_link() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::MagicLink,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_magic_link_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Your log in link");
+    assert!(body.contains("Log in to Open Community Groups"));
+    assert!(body.contains("https://example.test/log-in/magic-link/token"));
+}
+
 #[test]
 fn test_delivery_worker_prepare_content_missing_data() {
     // Setup notification
     let notification = Notification {
         attachments: vec![],
         delivery_claimed_at: sample_delivery_claimed_at(),
-        email: "user@example.test".to_string(),
+        email_branding: None,
         kind: NotificationKind::EmailVerification,
+        language: None,
         notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
         template_data: None,
     };
 
@@ -1078,6 +2238,58 @@ fn test_delivery_worker_prepare_content_missing_data() {
     assert!(err.to_string().contains("missing template data"));
 }
 
+#[test]
+fn test_delivery_worker_prepare_content_password_reset() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::PasswordReset,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_password_reset_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Reset your password");
+    assert!(body.contains("Reset your Open Community Groups password"));
+    assert!(body.contains("https://example.test/reset-password/code"));
+}
+
+#[test]
+fn test_delivery_worker_prepare_content_weekly_digest() {
+    // Setup notification
+    let notification = Notification {
+        attachments: vec![],
+        delivery_claimed_at: sample_delivery_claimed_at(),
+        email_branding: None,
+        kind: NotificationKind::WeeklyDigest,
+        language: None,
+        notification_id: Uuid::new_v4(),
+        recipient: NotificationRecipient::Email("user@example.test".to_string()),
+        template_data: Some(sample_weekly_digest_template_data()),
+    };
+
+    // Prepare content
+    let (subject, body) = DeliveryWorker::prepare_content(&notification).unwrap();
+
+    // Check content matches expectations
+    assert_eq!(subject, "Upcoming events in Test Community");
+    assert!(body.contains("1 upcoming event from your groups this week."));
+    assert!(body.contains("Reminder Event"));
+    assert!(body.contains("Notification Group"));
+    assert!(body.contains(
+        "https://example.test/test-community/group/notification-group/event/reminder-event"
+    ));
+    assert!(body.contains("https://example.test/test-community"));
+    assert!(body.contains("https://example.test/dashboard/user?tab=account"));
+}
+
 #[tokio::test]
 async fn test_delivery_worker_send_email_allows_whitelisted_recipient() {
     // Setup email config and sender mock
@@ -1085,14 +2297,8 @@ async fn test_delivery_worker_send_email_allows_whitelisted_recipient() {
     let mut es = MockEmailSender::new();
     es.expect_send()
         .times(1)
-        .withf(|message| {
-            message
-                .envelope()
-                .to()
-                .iter()
-                .any(|rcpt| rcpt.to_string() == "notify@example.test")
-        })
-        .returning(|_| Box::pin(async { Ok::<(), EmailDeliveryError>(()) }));
+        .withf(|email| email.to.email.to_string() == "notify@example.test")
+        .returning(|_| Box::pin(async { Ok::<(), DeliveryError>(()) }));
     let es: DynEmailSender = Arc::new(es);
 
     // Setup worker and send email
@@ -1100,6 +2306,7 @@ async fn test_delivery_worker_send_email_allows_whitelisted_recipient() {
     worker
         .send_email(
             "notify@example.test",
+            None,
             "Subject line",
             "<p>Body content</p>".to_string(),
             &[],
@@ -1121,6 +2328,7 @@ async fn test_delivery_worker_send_email_blocks_non_whitelisted_recipient() {
     worker
         .send_email(
             "other@example.test",
+            None,
             "Subject line",
             "<p>Body content</p>".to_string(),
             &[],
@@ -1142,6 +2350,7 @@ async fn test_delivery_worker_send_email_rejects_invalid_recipient_as_terminal()
     let err = worker
         .send_email(
             "invalid recipient",
+            None,
             "Subject line",
             "<p>Body content</p>".to_string(),
             &[],
@@ -1150,7 +2359,7 @@ async fn test_delivery_worker_send_email_rejects_invalid_recipient_as_terminal()
         .unwrap_err();
 
     // Check message construction failures are terminal
-    assert!(matches!(err, EmailDeliveryError::Terminal(_)));
+    assert!(matches!(err, DeliveryError::Terminal(_)));
 }
 
 #[tokio::test(start_paused = true)]
@@ -1161,7 +2370,7 @@ async fn test_delivery_worker_send_email_retries_transient_send_error() {
     let mut seq = Sequence::new();
     es.expect_send().times(1).in_sequence(&mut seq).returning(|_| {
         Box::pin(async {
-            Err(EmailDeliveryError::Retryable(anyhow!(
+            Err(DeliveryError::Retryable(anyhow!(
                 "Connection error: received fatal alert: UnexpectedMessage"
             )))
         })
@@ -1177,6 +2386,7 @@ async fn test_delivery_worker_send_email_retries_transient_send_error() {
     worker
         .send_email_with_retries(
             "notify@example.test",
+            None,
             "Subject line",
             "<p>Body content</p>".to_string(),
             &[],
@@ -1192,7 +2402,7 @@ async fn test_delivery_worker_send_email_does_not_retry_unknown_network_error()
     let mut es = MockEmailSender::new();
     es.expect_send().times(1).returning(|_| {
         Box::pin(async {
-            Err(EmailDeliveryError::Unknown(anyhow!(
+            Err(DeliveryError::Unknown(anyhow!(
                 "network error: connection reset by peer"
             )))
         })
@@ -1204,6 +2414,7 @@ async fn test_delivery_worker_send_email_does_not_retry_unknown_network_error()
     let err = worker
         .send_email_with_retries(
             "notify@example.test",
+            None,
             "Subject line",
             "<p>Body content</p>".to_string(),
             &[],
@@ -1212,7 +2423,7 @@ async fn test_delivery_worker_send_email_does_not_retry_unknown_network_error()
         .unwrap_err();
 
     // Check the unknown network outcome is not retried
-    assert!(matches!(&err, EmailDeliveryError::Unknown(_)));
+    assert!(matches!(&err, DeliveryError::Unknown(_)));
     assert_eq!(err.to_string(), "network error: connection reset by peer");
 }
 
@@ -1227,8 +2438,8 @@ fn test_email_delivery_error_classifies_pre_submission_failures_as_retryable() {
 
     // Classify every failure before submission
     for kind in kinds {
-        let err = EmailDeliveryError::from_smtp_kind(kind, anyhow!("smtp failure"));
-        assert!(matches!(&err, EmailDeliveryError::Retryable(_)));
+        let err = DeliveryError::from_smtp_kind(kind, anyhow!("smtp failure"));
+        assert!(matches!(&err, DeliveryError::Retryable(_)));
         assert_eq!(err.to_string(), "smtp failure");
     }
 }
@@ -1244,8 +2455,8 @@ fn test_email_delivery_error_classifies_provider_failures_as_terminal() {
 
     // Classify failures requiring external correction
     for kind in kinds {
-        let err = EmailDeliveryError::from_smtp_kind(kind, anyhow!("smtp failure"));
-        assert!(matches!(&err, EmailDeliveryError::Terminal(_)));
+        let err = DeliveryError::from_smtp_kind(kind, anyhow!("smtp failure"));
+        assert!(matches!(&err, DeliveryError::Terminal(_)));
         assert_eq!(err.to_string(), "smtp failure");
     }
 }
@@ -1253,23 +2464,93 @@ fn test_email_delivery_error_classifies_provider_failures_as_terminal() {
 #[test]
 fn test_email_delivery_error_classifies_uncertain_failures_as_unknown() {
     // Classify the uncertain delivery outcome for manual review
-    let err = EmailDeliveryError::from_smtp_kind(
+    let err = DeliveryError::from_smtp_kind(
         SmtpErrorKind::Unknown,
         anyhow!("network error: connection reset by peer"),
     );
 
     // Check the uncertain failure requires manual review
-    assert!(matches!(&err, EmailDeliveryError::Unknown(_)));
+    assert!(matches!(&err, DeliveryError::Unknown(_)));
     assert_eq!(err.to_string(), "network error: connection reset by peer");
 }
 
+#[test]
+fn test_webhook_delivery_error_classifies_rate_limits_and_server_errors_as_retryable() {
+    // Setup representative rate limit and server failures
+    let statuses = [
+        StatusCode::TOO_MANY_REQUESTS,
+        StatusCode::INTERNAL_SERVER_ERROR,
+    ];
+
+    // Classify every failure that did not post the message
+    for status in statuses {
+        let err = DeliveryError::from_webhook_status(status, anyhow!("webhook failure"));
+        assert!(matches!(&err, DeliveryError::Retryable(_)), "{status}");
+        assert_eq!(err.to_string(), "webhook failure");
+    }
+}
+
+#[test]
+fn test_webhook_delivery_error_classifies_invalid_webhooks_as_terminal() {
+    // Setup representative revoked and missing webhook failures
+    let statuses = [
+        StatusCode::FORBIDDEN,
+        StatusCode::NOT_FOUND,
+        StatusCode::GONE,
+    ];
+
+    // Classify failures requiring a webhook change
+    for status in statuses {
+        let err = DeliveryError::from_webhook_status(status, anyhow!("webhook failure"));
+        assert!(matches!(&err, DeliveryError::Terminal(_)), "{status}");
+        assert_eq!(err.to_string(), "webhook failure");
+    }
+}
+
+#[test]
+fn test_email_to_message_includes_attachments() {
+    // Setup email with an attachment
+    let email = sample_email(vec![Attachment {
+        content_type: "text/calendar".to_string(),
+        data: b"BEGIN:VCALENDAR".to_vec(),
+        file_name: "event.ics".to_string(),
+    }]);
+
+    // Build message
+    let message = String::from_utf8(email.to_message().unwrap().formatted()).unwrap();
+
+    // Check message matches expectations
+    assert!(message.contains("Subject: Subject line"), "{message}");
+    assert!(
+        message.contains("Content-Type: multipart/mixed"),
+        "{message}"
+    );
+    assert!(message.contains("filename=\"event.ics\""), "{message}");
+}
+
+#[test]
+fn test_email_to_message_rejects_invalid_attachment_content_type_as_terminal() {
+    // Setup email with an invalid attachment content type
+    let email = sample_email(vec![Attachment {
+        content_type: "invalid content type".to_string(),
+        data: vec![],
+        file_name: "file.bin".to_string(),
+    }]);
+
+    // Check message construction failures are terminal
+    assert!(matches!(
+        email.to_message(),
+        Err(DeliveryError::Terminal(_))
+    ));
+}
+
 #[test]
 fn test_lettre_email_sender_uses_starttls_for_submission_port() {
     // Setup email config
     let cfg = sample_email_config(None);
 
     // Build transport configuration
-    let builder = LettreEmailSender::transport_builder(&cfg).unwrap();
+    let builder = LettreEmailSender::transport_builder(cfg.smtp.as_ref().unwrap()).unwrap();
     let debug = format!("{builder:?}");
 
     // Check configured port and TLS mode
@@ -1281,10 +2562,10 @@ fn test_lettre_email_sender_uses_starttls_for_submission_port() {
 fn test_lettre_email_sender_uses_wrapper_tls_for_submissions_port() {
     // Setup email config
     let mut cfg = sample_email_config(None);
-    cfg.smtp.port = 465;
+    cfg.smtp.as_mut().unwrap().port = 465;
 
     // Build transport configuration
-    let builder = LettreEmailSender::transport_builder(&cfg).unwrap();
+    let builder = LettreEmailSender::transport_builder(cfg.smtp.as_ref().unwrap()).unwrap();
     let debug = format!("{builder:?}");
 
     // Check configured port and TLS mode
@@ -1294,6 +2575,16 @@ fn test_lettre_email_sender_uses_wrapper_tls_for_submissions_port() {
 
 // Helpers.
 
+/// Create a sample community email branding.
+fn sample_community_email_branding() -> CommunityEmailBranding {
+    CommunityEmailBranding {
+        footer_text: Some("Test Community, 123 Main St".to_string()),
+        from_name: Some("Test Community Team".to_string()),
+        logo_url: Some("https://example.test/email-logo.png".to_string()),
+        primary_color: Some("#0A3D62".to_string()),
+    }
+}
+
 /// Create a deterministic delivery claim timestamp.
 fn sample_delivery_claimed_at() -> DateTime<Utc> {
     DateTime::from_timestamp(1_735_689_600, 0).unwrap()
@@ -1304,14 +2595,29 @@ fn sample_email_config(rcpts_whitelist: Option<Vec<String>>) -> EmailConfig {
     EmailConfig {
         from_address: "no-reply@example.test".to_string(),
         from_name: "Open Community Groups".to_string(),
-        smtp: SmtpConfig {
+        provider: EmailProvider::Smtp,
+
+        feedback: None,
+        rcpts_whitelist,
+        sendgrid: None,
+        ses: None,
+        smtp: Some(SmtpConfig {
             host: "smtp.example.test".to_string(),
             password: "pass".to_string(),
             port: 587,
             username: "user".to_string(),
-        },
+        }),
+    }
+}
 
-        rcpts_whitelist,
+/// Create a sample email with the provided attachments.
+fn sample_email(attachments: Vec<Attachment>) -> Email {
+    Email {
+        attachments,
+        body: "<p>Body content</p>".to_string(),
+        from: "Open Community Groups <no-reply@example.test>".parse().unwrap(),
+        subject: "Subject line".to_string(),
+        to: "notify@example.test".parse().unwrap(),
     }
 }
 
@@ -1320,13 +2626,59 @@ fn sample_delivery_worker(cfg: EmailConfig, email_sender: DynEmailSender) -> Del
     let db: DynDB = Arc::new(MockDB::new());
 
     DeliveryWorker {
+        base_url: "https://example.test".to_string(),
         cancellation_token: CancellationToken::new(),
         cfg,
         db,
         email_sender,
+        channels: sample_notification_channels(),
+        image_storage: Arc::new(MockImageStorage::new()),
+    }
+}
+
+/// Create sample notification channels with mock senders.
+fn sample_notification_channels() -> NotificationChannels {
+    NotificationChannels {
+        discord: Arc::new(MockNotificationChannel::new()),
+        slack: Arc::new(MockNotificationChannel::new()),
+        sms: None,
+        webhook: Arc::new(MockWebhookSender::new()),
     }
 }
 
+/// Sample template payload for community newsletter notifications.
+fn sample_community_newsletter_template_data() -> serde_json::Value {
+    json!({
+        "body": "**Hello** subscribers",
+        "community_display_name": "Test Community",
+        "link": "https://example.test/test-community",
+        "newsletter_link": "https://example.test/test-community/newsletter/issue",
+        "subject": "Community news",
+        "theme": {
+            "primary_color": "#000000"
+        }
+    })
+}
+
+/// Sample template payload for dormant groups report notifications.
+fn sample_dormant_groups_report_template_data() -> serde_json::Value {
+    json!({
+        "community_display_name": "Test Community",
+        "dormant_group_months": 6,
+        "groups": [
+            {
+                "dormant_since": 1_719_792_000,
+                "link": "https://example.test/test-community/group/quiet-group",
+                "name": "Quiet Group",
+            }
+        ],
+        "link": "https://example.test/dashboard/community?tab=groups&dormant=true",
+        "theme": {
+            "primary_color": "#000000"
+        }
+    })
+}
+
 /// Sample template payload for email verification notifications.
 fn sample_email_verification_template_data() -> serde_json::Value {
     json!({
@@ -1367,6 +2719,24 @@ fn sample_event_attendance_canceled_template_data() -> serde_json::Value {
     })
 }
 
+/// Sample template payload for event attendance certificate notifications.
+fn sample_event_attendance_certificate_template_data() -> serde_json::Value {
+    let mut template_data = sample_event_attendance_canceled_template_data();
+    template_data["certificate"] = json!({
+        "attendee_name": "Jane Doe",
+        "community_display_name": "Test Community",
+        "event_id": "11111111-1111-1111-1111-111111111111",
+        "event_name": "Reminder Event",
+        "event_slug": "reminder-event",
+        "group_name": "Notification Group",
+        "primary_color": "#000000",
+        "starts_at": 1_914_724_800,
+        "timezone": "UTC"
+    });
+    template_data["link"] = json!("https://example.test/dashboard/user?tab=certificates");
+    template_data
+}
+
 /// Sample template payload for custom event notifications.
 fn sample_event_custom_template_data() -> serde_json::Value {
     json!({
@@ -1487,6 +2857,7 @@ fn sample_event_reminder_template_data() -> serde_json::Value {
         },
         "dashboard_link": "https://example.test/dashboard/user?tab=events",
         "link": "https://example.test/test-community/group/notification-group/event/reminder-event",
+        "reminder_offset_minutes": 1440,
         "theme": {
             "primary_color": "#000000"
         }
@@ -1624,6 +2995,18 @@ fn sample_event_welcome_template_data(dashboard_link: Option<&str>) -> serde_jso
     payload
 }
 
+/// Sample template payload for group contact message notifications.
+fn sample_group_contact_message_template_data() -> serde_json::Value {
+    let mut payload = sample_group_custom_template_data();
+    let object = payload.as_object_mut().expect("custom group payload is an object");
+    object.insert("body".to_string(), json!("We can host your next meetup"));
+    object.insert("sender_email".to_string(), json!("jane@example.test"));
+    object.insert("sender_name".to_string(), json!("Jane Doe"));
+    object.insert("sender_username".to_string(), json!("jane"));
+    object.insert("subject".to_string(), json!("Venue offer"));
+    payload
+}
+
 /// Sample template payload for custom group notifications.
 fn sample_group_custom_template_data() -> serde_json::Value {
     json!({
@@ -1659,3 +3042,42 @@ fn sample_group_custom_legacy_template_data() -> serde_json::Value {
     object.insert("title".to_string(), json!("Custom group title"));
     payload
 }
+
+/// Sample template payload for magic link notifications.
+fn sample_magic_link_template_data() -> serde_json::Value {
+    json!({
+        "link": "https://example.test/log-in/magic-link/token",
+        "theme": {
+            "primary_color": "#000000"
+        }
+    })
+}
+
+/// Sample template payload for password reset notifications.
+fn sample_password_reset_template_data() -> serde_json::Value {
+    json!({
+        "link": "https://example.test/reset-password/code",
+        "theme": {
+            "primary_color": "#000000"
+        }
+    })
+}
+
+/// Sample template payload for weekly digest notifications.
+fn sample_weekly_digest_template_data() -> serde_json::Value {
+    let reminder = sample_event_reminder_template_data();
+    json!({
+        "community_display_name": "Test Community",
+        "events": [
+            {
+                "event": reminder["event"],
+                "link": reminder["link"],
+            }
+        ],
+        "link": "https://example.test/test-community",
+        "preferences_link": "https://example.test/dashboard/user?tab=account",
+        "theme": {
+            "primary_color": "#000000"
+        }
+    })
+}
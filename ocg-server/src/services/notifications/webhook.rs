@@ -0,0 +1,353 @@
+//! Outgoing webhooks used to forward user notifications to external systems.
+
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, KeyInit, Mac};
+use reqwest::{
+    Client as HttpClient, Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    header::CONTENT_TYPE,
+    redirect::Policy,
+};
+use serde::Serialize;
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::types::user::UserWebhook;
+
+use super::{DeliveryError, Notification, NotificationKind, WebhookSender};
+
+/// Header containing the notification identifier, shared by delivery retries.
+const DELIVERY_HEADER: &str = "X-OCG-Delivery";
+
+/// Header containing the notification kind.
+const EVENT_HEADER: &str = "X-OCG-Event";
+
+/// Header containing the request signature.
+const SIGNATURE_HEADER: &str = "X-OCG-Signature";
+
+/// Header containing the unix timestamp included in the signature.
+const TIMESTAMP_HEADER: &str = "X-OCG-Timestamp";
+
+/// Timeout for HTTP requests to user webhooks.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Webhook sender that signs and posts JSON payloads over HTTP.
+pub(crate) struct HttpWebhookSender {
+    /// HTTP client used to call the webhooks.
+    http_client: HttpClient,
+}
+
+impl HttpWebhookSender {
+    /// Create a new `HttpWebhookSender`.
+    pub(crate) fn new() -> Self {
+        // Redirects are not followed, as they could point to internal hosts
+        let http_client = HttpClient::builder()
+            .dns_resolver(PublicAddrsResolver)
+            .redirect(Policy::none())
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .expect("failed to build http client");
+
+        Self { http_client }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    /// [`WebhookSender::send`].
+    async fn send(
+        &self,
+        webhook: &UserWebhook,
+        payload: &WebhookPayload,
+    ) -> Result<(), DeliveryError> {
+        // Make sure the webhook does not target an internal address
+        validate_webhook_url(&webhook.url).map_err(DeliveryError::terminal)?;
+
+        // Sign the exact body posted, along with the time it was sent
+        let body = serde_json::to_string(payload).map_err(DeliveryError::terminal)?;
+        let timestamp = Utc::now().timestamp();
+        let signature = sign(&webhook.secret, timestamp, &body);
+
+        let response = self
+            .http_client
+            .post(&webhook.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(DELIVERY_HEADER, payload.id.to_string())
+            .header(EVENT_HEADER, payload.kind.to_string())
+            .header(SIGNATURE_HEADER, format!("sha256={signature}"))
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .body(body)
+            .send()
+            .await
+            .map_err(DeliveryError::from_webhook_request)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        Err(DeliveryError::from_webhook_status(
+            status,
+            anyhow!("user webhook request failed ({status})"),
+        ))
+    }
+}
+
+/// DNS resolver that refuses hosts resolving to internal addresses.
+pub(crate) struct PublicAddrsResolver;
+
+impl Resolve for PublicAddrsResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs = resolve_public_addrs(&host).await?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// JSON payload posted to user webhooks.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct WebhookPayload {
+    /// Notification identifier, shared by delivery retries.
+    pub id: Uuid,
+    /// The type of notification.
+    pub kind: NotificationKind,
+
+    /// Data describing the notification, as used by its email template.
+    pub data: Option<serde_json::Value>,
+}
+
+/// Prepare the payload posted to a user webhook.
+pub(crate) fn prepare_payload(notification: &Notification) -> Result<WebhookPayload> {
+    if !matches!(
+        notification.kind,
+        NotificationKind::EventCanceled
+            | NotificationKind::EventPublished
+            | NotificationKind::EventWelcome
+            | NotificationKind::GroupWelcome
+    ) {
+        bail!(
+            "notification kind not supported by webhooks: {}",
+            notification.kind
+        );
+    }
+
+    Ok(WebhookPayload {
+        id: notification.notification_id,
+        kind: notification.kind.clone(),
+        data: notification.template_data.clone(),
+    })
+}
+
+/// Check that a webhook URL host only resolves to public addresses.
+pub(crate) async fn check_webhook_url_host(url: &str) -> Result<()> {
+    let url = Url::parse(url)?;
+    let Some(host) = url.host_str() else {
+        bail!("webhook url has no host");
+    };
+    if parse_ip_host(host).is_none() {
+        resolve_public_addrs(host).await?;
+    }
+    Ok(())
+}
+
+/// Returns true when the address is not a loopback, private, link-local or
+/// unspecified one.
+pub(crate) fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            let is_shared = a == 100 && (b & 0b1100_0000) == 64;
+            !(ip.is_broadcast()
+                || ip.is_link_local()
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_unspecified()
+                || is_shared)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ip(IpAddr::V4(ip)),
+            None => {
+                !(ip.is_loopback()
+                    || ip.is_unicast_link_local()
+                    || ip.is_unique_local()
+                    || ip.is_unspecified())
+            }
+        },
+    }
+}
+
+/// Check that a webhook URL uses HTTPS and, when its host is an IP address,
+/// that the address is public.
+pub(crate) fn validate_webhook_url(url: &str) -> Result<()> {
+    let url = Url::parse(url)?;
+    if url.scheme() != "https" {
+        bail!("webhook url must use https");
+    }
+    let Some(host) = url.host_str() else {
+        bail!("webhook url has no host");
+    };
+    if let Some(ip) = parse_ip_host(host)
+        && !is_public_ip(ip)
+    {
+        bail!("webhook url cannot point to an internal address");
+    }
+    Ok(())
+}
+
+/// Parse the URL host as an IP address, if it is one.
+pub(crate) fn parse_ip_host(host: &str) -> Option<IpAddr> {
+    host.trim_start_matches('[').trim_end_matches(']').parse().ok()
+}
+
+/// Resolve a host, failing when any of its addresses is an internal one.
+async fn resolve_public_addrs(host: &str) -> Result<Vec<SocketAddr>> {
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, 0)).await?.collect();
+    if addrs.is_empty() {
+        bail!("webhook host {host} did not resolve to any address");
+    }
+    if addrs.iter().any(|addr| !is_public_ip(addr.ip())) {
+        bail!("webhook host {host} resolves to an internal address");
+    }
+    Ok(addrs)
+}
+
+/// Compute the hex encoded HMAC-SHA256 signature of a webhook request.
+fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::{
+        services::notifications::{Notification, NotificationKind, NotificationRecipient},
+        types::user::UserWebhook,
+    };
+
+    use super::{
+        check_webhook_url_host, is_public_ip, prepare_payload, sign, validate_webhook_url,
+    };
+
+    #[tokio::test]
+    async fn check_webhook_url_host_accepts_public_ip_hosts() {
+        assert!(check_webhook_url_host("https://203.0.113.10/hook").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_webhook_url_host_rejects_hosts_resolving_to_loopback() {
+        assert!(check_webhook_url_host("https://localhost/hook").await.is_err());
+    }
+
+    #[test]
+    fn is_public_ip_rejects_internal_addresses() {
+        for ip in [
+            "0.0.0.0",
+            "10.0.0.1",
+            "100.64.0.1",
+            "127.0.0.1",
+            "169.254.169.254",
+            "172.16.0.1",
+            "192.168.1.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "::ffff:127.0.0.1",
+            "fc00::1",
+            "fe80::1",
+        ] {
+            assert!(!is_public_ip(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn is_public_ip_accepts_public_addresses() {
+        for ip in ["1.1.1.1", "203.0.113.10", "2606:4700:4700::1111"] {
+            assert!(is_public_ip(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn prepare_payload_includes_notification_data() {
+        let notification = sample_notification(NotificationKind::GroupWelcome);
+
+        let payload = prepare_payload(&notification).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            json!({
+                "data": { "link": "https://example.test/group" },
+                "id": notification.notification_id,
+                "kind": "group-welcome",
+            })
+        );
+    }
+
+    #[test]
+    fn prepare_payload_rejects_unsupported_kinds() {
+        let notification = sample_notification(NotificationKind::EmailVerification);
+
+        assert!(prepare_payload(&notification).is_err());
+    }
+
+    #[test]
+    fn sign_covers_timestamp_and_body() {
+        let signature = sign("secret", 1_700_000_000, r#"{"id":1}"#);
+
+        assert_eq!(
+            signature,
+            "3dd1b9aef568d75f6790a84bd2e5dfa1f44409eef3cbdbd3f10b837376100c11"
+        );
+        assert_ne!(signature, sign("secret", 1_700_000_001, r#"{"id":1}"#));
+        assert_ne!(signature, sign("secret", 1_700_000_000, r#"{"id":2}"#));
+        assert_ne!(signature, sign("other", 1_700_000_000, r#"{"id":1}"#));
+    }
+
+    #[test]
+    fn validate_webhook_url_accepts_public_https_urls() {
+        assert!(validate_webhook_url("https://hooks.example.test/ocg").is_ok());
+        assert!(validate_webhook_url("https://203.0.113.10/ocg").is_ok());
+    }
+
+    #[test]
+    fn validate_webhook_url_rejects_internal_addresses() {
+        assert!(validate_webhook_url("https://127.0.0.1/ocg").is_err());
+        assert!(validate_webhook_url("https://169.254.169.254/latest/meta-data").is_err());
+        assert!(validate_webhook_url("https://[::1]:8443/ocg").is_err());
+    }
+
+    #[test]
+    fn validate_webhook_url_rejects_non_https_urls() {
+        assert!(validate_webhook_url("http://hooks.example.test/ocg").is_err());
+        assert!(validate_webhook_url("ftp://hooks.example.test/ocg").is_err());
+    }
+
+    fn sample_notification(kind: NotificationKind) -> Notification {
+        Notification {
+            attachments: vec![],
+            delivery_claimed_at: Utc::now(),
+            email_branding: None,
+            kind,
+            language: None,
+            notification_id: Uuid::new_v4(),
+            recipient: NotificationRecipient::Webhook(Some(UserWebhook {
+                secret: "secret".to_string(),
+                url: "https://example.test/webhook".to_string(),
+            })),
+            template_data: Some(json!({ "link": "https://example.test/group" })),
+        }
+    }
+}
@@ -186,9 +186,15 @@ impl PgPaymentsManager {
         // Load the payment provider required to open a fresh checkout session
         let payments_provider = self.payments_provider()?;
 
-        if prepared_checkout.recipient.provider != payments_provider.provider() {
+        let Some(recipient) = prepared_checkout.recipient.clone() else {
+            bail!("group payments recipient is not configured");
+        };
+        if recipient.provider != payments_provider.provider() {
             bail!("group payments recipient is not configured for this provider");
         }
+        let Some(currency_code) = prepared_checkout.purchase.currency_code.clone() else {
+            bail!("event purchase is missing a currency");
+        };
 
         // Create the provider checkout session
         let checkout_session = payments_provider
@@ -196,12 +202,12 @@ impl PgPaymentsManager {
                 amount_minor: prepared_checkout.purchase.amount_minor,
                 base_url: self.server_cfg.base_url.clone(),
                 community_name: prepared_checkout.community_name.clone(),
-                currency_code: prepared_checkout.purchase.currency_code.clone(),
+                currency_code,
                 event_id: prepared_checkout.event_id,
                 event_slug: prepared_checkout.event_slug.clone(),
                 group_slug: prepared_checkout.group_slug.clone(),
                 purchase_id: prepared_checkout.purchase.event_purchase_id,
-                recipient: prepared_checkout.recipient.clone(),
+                recipient,
                 ticket_title: prepared_checkout.purchase.ticket_title.clone(),
                 user_id,
 
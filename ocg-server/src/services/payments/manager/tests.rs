@@ -226,13 +226,17 @@ async fn complete_free_checkout_records_purchase_and_enqueues_notification() {
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(SiteSettings::default()));
+    db.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(Some(Uuid::new_v4())));
 
     // Setup the attendee welcome notification expectation
     let mut notifications_manager = MockNotificationsManager::new();
     notifications_manager
         .expect_enqueue()
         .withf(move |notification| {
-            notification.attachments.len() == 1
+            notification.attachments.len() == 2
                 && matches!(notification.kind, NotificationKind::EventWelcome)
                 && notification.recipients == vec![user_id]
         })
@@ -759,6 +763,7 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
         waitlist_count: 0,
         waitlist_enabled: false,
 
+        approval_status: None,
         capacity: None,
         created_by_display_name: None,
         created_by_username: None,
@@ -786,6 +791,7 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
         venue_country_name: None,
         venue_name: None,
         venue_state: None,
+        waitlist_confirmation_hours: None,
         zip_code: None,
     }
 }
@@ -799,11 +805,11 @@ fn sample_event_purchase_summary(
 ) -> EventPurchaseSummary {
     EventPurchaseSummary {
         amount_minor: 2_500,
-        currency_code: "usd".to_string(),
         event_purchase_id,
         event_ticket_type_id,
         ticket_title: "General admission".to_string(),
 
+        currency_code: Some("usd".to_string()),
         discount_code,
         provider_checkout_url,
         ..EventPurchaseSummary::default()
@@ -847,8 +853,9 @@ fn sample_prepared_event_checkout(
             provider_checkout_url,
             discount_code,
         ),
-        recipient,
+
         group_slug_pretty: None,
+        recipient: Some(recipient),
     }
 }
 
@@ -128,12 +128,23 @@ impl PaymentsNotificationComposer {
             return;
         };
 
-        // Build the attendee-facing welcome notification and calendar attachment
+        // Load the attendee ticket, sending the notification without it on failure
+        let ticket_code = match self.db.get_event_ticket_code(community_id, event_id, user_id).await
+        {
+            Ok(ticket_code) => ticket_code,
+            Err(err) => {
+                warn!(error = %err, "failed to load event welcome ticket code");
+                None
+            }
+        };
+
+        // Build the attendee-facing welcome notification and its attachments
         let include_dashboard_link =
             matches!(self_service, EventWelcomeSelfService::DashboardCancellation);
         let notification = match build_event_welcome_notification(
             &event,
             user_id,
+            ticket_code,
             &self.server_cfg,
             &site_settings,
             include_dashboard_link,
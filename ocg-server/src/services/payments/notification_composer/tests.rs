@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
+use anyhow::anyhow;
 use serde_json::to_value;
 use uuid::Uuid;
 
 use crate::{
     config::HttpServerConfig,
     db::{mock::MockDB, payments::CompletedEventPurchase},
+    i18n::Language,
     services::notifications::{MockNotificationsManager, NotificationKind},
     templates::notifications::{
         EventRefundApproved, EventRefundRejected, EventRefundRequested, EventWelcome,
@@ -106,6 +108,8 @@ async fn enqueue_checkout_completed_notification_omits_dashboard_cancel_guidance
         theme: SiteSettings::default().theme,
 
         dashboard_link: None,
+        language: Language::default(),
+        ticket_attached: false,
     })
     .unwrap();
 
@@ -118,6 +122,10 @@ async fn enqueue_checkout_completed_notification_omits_dashboard_cancel_guidance
     db.expect_get_site_settings()
         .times(1)
         .returning(move || Ok(site_settings.clone()));
+    db.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Err(anyhow!("db error")));
 
     // Setup notifications manager mock
     let mut notifications_manager = MockNotificationsManager::new();
@@ -157,6 +165,8 @@ async fn enqueue_event_welcome_notification_enqueues_expected_payload() {
         theme: SiteSettings::default().theme,
 
         dashboard_link: Some("/dashboard/user?tab=events".to_string()),
+        language: Language::default(),
+        ticket_attached: true,
     })
     .unwrap();
 
@@ -169,6 +179,10 @@ async fn enqueue_event_welcome_notification_enqueues_expected_payload() {
     db.expect_get_site_settings()
         .times(1)
         .returning(move || Ok(site_settings.clone()));
+    db.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(Some(Uuid::new_v4())));
 
     // Setup notifications manager mock
     let mut notifications_manager = MockNotificationsManager::new();
@@ -176,7 +190,7 @@ async fn enqueue_event_welcome_notification_enqueues_expected_payload() {
         .expect_enqueue()
         .times(1)
         .withf(move |notification| {
-            notification.attachments.len() == 1
+            notification.attachments.len() == 2
                 && matches!(notification.kind, NotificationKind::EventWelcome)
                 && notification.recipients == vec![user_id]
                 && notification.template_data.as_ref() == Some(&expected_template_data)
@@ -259,6 +273,7 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
         timezone: chrono_tz::UTC,
         waitlist_count: 0,
         waitlist_enabled: false,
+        approval_status: None,
         capacity: None,
         created_by_display_name: None,
         created_by_username: None,
@@ -286,6 +301,7 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
         venue_country_name: None,
         venue_name: None,
         venue_state: None,
+        waitlist_confirmation_hours: None,
         zip_code: None,
     }
 }
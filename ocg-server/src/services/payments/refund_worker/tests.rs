@@ -992,6 +992,7 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
         waitlist_count: 0,
         waitlist_enabled: false,
 
+        approval_status: None,
         capacity: None,
         created_by_display_name: None,
         created_by_username: None,
@@ -1019,6 +1020,7 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
         venue_country_name: None,
         venue_name: None,
         venue_state: None,
+        waitlist_confirmation_hours: None,
         zip_code: None,
     }
 }
@@ -61,13 +61,17 @@ async fn handle_webhook_event_completes_checkout_and_enqueues_notification() {
     db.expect_get_site_settings()
         .times(1)
         .returning(|| Ok(SiteSettings::default()));
+    db.expect_get_event_ticket_code()
+        .times(1)
+        .withf(move |cid, eid, uid| *cid == community_id && *eid == event_id && *uid == user_id)
+        .returning(|_, _, _| Ok(Some(Uuid::new_v4())));
 
     // Setup the attendee welcome notification expectation
     let mut notifications_manager = MockNotificationsManager::new();
     notifications_manager
         .expect_enqueue()
         .withf(move |notification| {
-            notification.attachments.len() == 1
+            notification.attachments.len() == 2
                 && matches!(notification.kind, NotificationKind::EventWelcome)
                 && notification.recipients == vec![user_id]
         })
@@ -558,6 +562,7 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
         waitlist_count: 0,
         waitlist_enabled: false,
 
+        approval_status: None,
         capacity: None,
         created_by_display_name: None,
         created_by_username: None,
@@ -585,6 +590,7 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
         venue_country_name: None,
         venue_name: None,
         venue_state: None,
+        waitlist_confirmation_hours: None,
         zip_code: None,
     }
 }
@@ -593,12 +599,12 @@ fn sample_event_summary(event_id: Uuid) -> EventSummary {
 fn sample_purchase() -> EventPurchaseSummary {
     EventPurchaseSummary {
         amount_minor: 2_500,
-        currency_code: "USD".to_string(),
         event_purchase_id: Uuid::from_u128(1),
         event_ticket_type_id: Uuid::from_u128(3),
         status: EventPurchaseStatus::RefundPending,
         ticket_title: "General admission".to_string(),
 
+        currency_code: Some("USD".to_string()),
         provider_payment_reference: Some("pi_test_123".to_string()),
         ..EventPurchaseSummary::default()
     }
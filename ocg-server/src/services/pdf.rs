@@ -0,0 +1,213 @@
+//! Building blocks used to render simple PDF documents.
+//!
+//! Documents only use the standard PDF fonts, so no font files have to be
+//! embedded, and their content streams are written by the callers.
+
+use std::fmt::Write as _;
+
+#[cfg(test)]
+mod tests;
+
+/// Encodes the text provided using the `WinAnsiEncoding` of the standard fonts.
+///
+/// Characters that cannot be represented are replaced with a question mark.
+pub(crate) fn encode_win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            c if c.is_whitespace() => b' ',
+            ' '..='~' | '\u{a0}'..='\u{ff}' => u8::try_from(c).unwrap_or(b'?'),
+            '€' => 0x80,
+            '…' => 0x85,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Fits the text in the width available, shrinking and truncating it if needed.
+///
+/// The text is shrunk down to the minimum font size provided, and truncated
+/// with an ellipsis when it still does not fit.
+pub(crate) fn fit_text(
+    text: &str,
+    font: Font,
+    size: f64,
+    min_size: f64,
+    max_width: f64,
+) -> (Vec<u8>, f64) {
+    let mut text = encode_win_ansi(text.trim());
+    let width = font.text_width(&text, size);
+    if width <= max_width {
+        return (text, size);
+    }
+
+    // Shrink the text, truncating it when it is still too long
+    let size = ((size * max_width / width) * 100.0).floor().max(min_size * 100.0) / 100.0;
+    if font.text_width(&text, size) > max_width {
+        let ellipsis_width = font.text_width(b"...", size);
+        while !text.is_empty() && font.text_width(&text, size) + ellipsis_width > max_width {
+            text.pop();
+        }
+        text.extend_from_slice(b"...");
+    }
+
+    (text, size)
+}
+
+// Types.
+
+/// RGB color, with each component in the 0 to 1 range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Color(pub f64, pub f64, pub f64);
+
+impl Color {
+    /// Parses a hex color like `#1A2B3C`.
+    pub(crate) fn from_hex(color: &str) -> Option<Self> {
+        let hex = color.strip_prefix('#')?;
+        if hex.len() != 6 {
+            return None;
+        }
+        let component = |index: usize| {
+            let value = u8::from_str_radix(hex.get(index..index + 2)?, 16).ok()?;
+            Some(f64::from(value) / 255.0)
+        };
+
+        Some(Self(component(0)?, component(2)?, component(4)?))
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.3} {:.3} {:.3}", self.0, self.1, self.2)
+    }
+}
+
+/// Standard PDF fonts available to the documents.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Font {
+    /// Helvetica Bold.
+    Bold,
+    /// Helvetica.
+    Regular,
+}
+
+impl Font {
+    /// Returns the font dictionary.
+    pub(crate) fn dictionary(self) -> String {
+        let base_font = match self {
+            Font::Bold => "Helvetica-Bold",
+            Font::Regular => "Helvetica",
+        };
+        format!(
+            "<< /Type /Font /Subtype /Type1 /BaseFont /{base_font} /Encoding /WinAnsiEncoding >>"
+        )
+    }
+
+    /// Returns the name the font is referenced by in the page resources.
+    pub(crate) fn resource_name(self) -> &'static str {
+        match self {
+            Font::Bold => "F2",
+            Font::Regular => "F1",
+        }
+    }
+
+    /// Returns the width of the encoded text at the font size provided.
+    pub(crate) fn text_width(self, text: &[u8], size: f64) -> f64 {
+        let widths = match self {
+            Font::Bold => &HELVETICA_BOLD_WIDTHS,
+            Font::Regular => &HELVETICA_WIDTHS,
+        };
+        let units: u32 = text
+            .iter()
+            .map(|&byte| {
+                let width = match byte {
+                    b' '..=b'~' => widths[usize::from(byte - b' ')],
+                    // Approximate the width of the remaining characters
+                    _ => 556,
+                };
+                u32::from(width)
+            })
+            .sum();
+
+        f64::from(units) * size / 1000.0
+    }
+}
+
+/// Minimal writer of PDF documents.
+pub(crate) struct PdfWriter {
+    /// Document contents written so far.
+    buf: Vec<u8>,
+    /// Offsets of the objects written, used to build the cross-reference table.
+    offsets: Vec<usize>,
+}
+
+impl PdfWriter {
+    /// Creates a new writer, including the document header.
+    pub(crate) fn new() -> Self {
+        Self {
+            buf: b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec(),
+            offsets: vec![],
+        }
+    }
+
+    /// Adds an object to the document, numbered in the order they are added.
+    pub(crate) fn add_object(&mut self, dictionary: &str, stream: Option<&[u8]>) {
+        self.offsets.push(self.buf.len());
+        let id = self.offsets.len();
+        self.buf
+            .extend_from_slice(format!("{id} 0 obj\n{dictionary}\n").as_bytes());
+        if let Some(stream) = stream {
+            self.buf.extend_from_slice(b"stream\n");
+            self.buf.extend_from_slice(stream);
+            self.buf.extend_from_slice(b"\nendstream\n");
+        }
+        self.buf.extend_from_slice(b"endobj\n");
+    }
+
+    /// Writes the cross-reference table and trailer, returning the document.
+    ///
+    /// The first object added must be the document catalog.
+    pub(crate) fn finish(mut self) -> Vec<u8> {
+        let xref_offset = self.buf.len();
+        let size = self.offsets.len() + 1;
+        let mut trailer = format!("xref\n0 {size}\n0000000000 65535 f \n");
+        for offset in &self.offsets {
+            let _ = writeln!(trailer, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            trailer,
+            "trailer\n<< /Size {size} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n"
+        );
+        self.buf.extend_from_slice(trailer.as_bytes());
+
+        self.buf
+    }
+}
+
+/// Widths of the printable ASCII characters in Helvetica Bold, in 1/1000 em.
+#[rustfmt::skip]
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// Widths of the printable ASCII characters in Helvetica, in 1/1000 em.
+#[rustfmt::skip]
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
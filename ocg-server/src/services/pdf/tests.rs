@@ -0,0 +1,55 @@
+use super::*;
+
+/// Maximum text width used in the text fitting tests, in points.
+const MAX_WIDTH: f64 = 640.0;
+
+/// Minimum font size used in the text fitting tests.
+const MIN_SIZE: f64 = 12.0;
+
+#[test]
+fn test_color_from_hex() {
+    assert_eq!(Color::from_hex("#FF0000"), Some(Color(1.0, 0.0, 0.0)));
+    assert_eq!(Color::from_hex("#00ff00"), Some(Color(0.0, 1.0, 0.0)));
+    assert_eq!(Color::from_hex("FF0000"), None);
+    assert_eq!(Color::from_hex("#FFF"), None);
+    assert_eq!(Color::from_hex("#GG0000"), None);
+}
+
+#[test]
+fn test_encode_win_ansi() {
+    assert_eq!(encode_win_ansi("Café"), b"Caf\xE9".to_vec());
+    assert_eq!(encode_win_ansi("a\tb\nc"), b"a b c".to_vec());
+    assert_eq!(
+        encode_win_ansi("“Rust” — 2026"),
+        b"\x93Rust\x94 \x97 2026".to_vec()
+    );
+    assert_eq!(encode_win_ansi("東京"), b"??".to_vec());
+}
+
+#[test]
+fn test_fit_text_long_text_is_truncated() {
+    let text = "A".repeat(500);
+    let (fitted, size) = fit_text(&text, Font::Bold, 34.0, MIN_SIZE, MAX_WIDTH);
+
+    assert!((size - MIN_SIZE).abs() < f64::EPSILON);
+    assert!(fitted.ends_with(b"..."));
+    assert!(Font::Bold.text_width(&fitted, size) <= MAX_WIDTH);
+}
+
+#[test]
+fn test_fit_text_medium_text_is_shrunk() {
+    let text = "A".repeat(40);
+    let (fitted, size) = fit_text(&text, Font::Bold, 34.0, MIN_SIZE, MAX_WIDTH);
+
+    assert!(size < 34.0);
+    assert_eq!(fitted, text.as_bytes());
+    assert!(Font::Bold.text_width(&fitted, size) <= MAX_WIDTH + 0.001);
+}
+
+#[test]
+fn test_fit_text_short_text_is_kept() {
+    let (fitted, size) = fit_text(" Jane Doe ", Font::Bold, 34.0, MIN_SIZE, MAX_WIDTH);
+
+    assert!((size - 34.0).abs() < f64::EPSILON);
+    assert_eq!(fitted, b"Jane Doe".to_vec());
+}
@@ -0,0 +1,124 @@
+//! Background publication of draft events scheduled by organizers.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use tokio::time::sleep;
+use tokio_util::{sync::CancellationToken, task::TaskTracker};
+use tracing::{error, info, instrument};
+
+use crate::{
+    config::HttpServerConfig,
+    db::{DBExt, DynDB},
+    services::notifications::enqueue::enqueue_event_published_notifications,
+    types::payments::PaymentProvider,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Pause after a worker iteration fails.
+const PAUSE_ON_ERROR: Duration = Duration::from_secs(30);
+/// Pause when no scheduled publication is due.
+const PAUSE_ON_NONE: Duration = Duration::from_secs(30);
+
+/// Starts the worker that publishes events when their scheduled date is due.
+pub(crate) fn start_scheduled_publications_worker(
+    db: DynDB,
+    configured_provider: Option<PaymentProvider>,
+    server_cfg: HttpServerConfig,
+    task_tracker: &TaskTracker,
+    cancellation_token: &CancellationToken,
+) {
+    let worker = ScheduledPublicationsWorker {
+        cancellation_token: cancellation_token.clone(),
+        configured_provider,
+        db,
+        server_cfg,
+    };
+    task_tracker.spawn(async move {
+        worker.run().await;
+    });
+}
+
+/// Publishes draft events whose scheduled publication date is due.
+struct ScheduledPublicationsWorker {
+    /// Coordinates graceful worker shutdown.
+    cancellation_token: CancellationToken,
+    /// Payments provider configured on this server, if any.
+    configured_provider: Option<PaymentProvider>,
+    /// Database handle used to publish events and enqueue announcements.
+    db: DynDB,
+    /// Server configuration used to build the announcements links.
+    server_cfg: HttpServerConfig,
+}
+
+impl ScheduledPublicationsWorker {
+    /// Publishes due events until graceful shutdown.
+    async fn run(&self) {
+        loop {
+            // Publish the next due event, pausing when there is nothing to do
+            let pause = match self.publish_next_event().await {
+                Ok(true) => None,
+                Ok(false) => Some(PAUSE_ON_NONE),
+                Err(err) => {
+                    error!(error = %err, "error publishing scheduled event");
+                    Some(PAUSE_ON_ERROR)
+                }
+            };
+
+            // Apply the selected pause without delaying graceful shutdown
+            if let Some(pause) = pause {
+                tokio::select! {
+                    () = sleep(pause) => {},
+                    () = self.cancellation_token.cancelled() => break,
+                }
+            }
+
+            if self.cancellation_token.is_cancelled() {
+                break;
+            }
+        }
+    }
+
+    /// Publishes the next due event and enqueues its announcements atomically.
+    ///
+    /// Returns whether an event was published.
+    #[instrument(skip(self), err)]
+    async fn publish_next_event(&self) -> Result<bool> {
+        let configured_provider = self.configured_provider;
+        let server_cfg = self.server_cfg.clone();
+
+        let publication = self
+            .db
+            .as_ref()
+            .transaction(|tx| {
+                Box::pin(async move {
+                    let Some(publication) =
+                        tx.publish_due_scheduled_event(configured_provider).await?
+                    else {
+                        return Ok(None);
+                    };
+
+                    enqueue_event_published_notifications(
+                        tx,
+                        &server_cfg,
+                        publication.community_id,
+                        publication.group_id,
+                        publication.event_id,
+                    )
+                    .await?;
+
+                    Ok(Some(publication))
+                })
+            })
+            .await?;
+
+        let Some(publication) = publication else {
+            return Ok(false);
+        };
+        info!(event_id = %publication.event_id, "scheduled event published");
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use mockall::predicate::eq;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::{
+    config::HttpServerConfig,
+    db::{DynDB, dashboard::group::ScheduledEventPublication, mock::MockDB},
+    handlers::tests::{
+        expect_rolled_back_transaction, expect_successful_transaction, sample_event_full,
+    },
+    types::{event::EventFull, payments::PaymentProvider},
+};
+
+use super::ScheduledPublicationsWorker;
+
+#[tokio::test]
+async fn publish_next_event_publishes_due_event() {
+    // Setup identifiers and data structures
+    let community_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let group_id = Uuid::new_v4();
+    let event_full = EventFull {
+        test_event: true,
+        ..sample_event_full(community_id, event_id, group_id)
+    };
+
+    // Setup database mock
+    let mut db = MockDB::new();
+    let mut tx = MockDB::new();
+    tx.expect_publish_due_scheduled_event()
+        .with(eq(Some(PaymentProvider::Stripe)))
+        .times(1)
+        .returning(move |_| {
+            Ok(Some(ScheduledEventPublication {
+                community_id,
+                event_id,
+                group_id,
+            }))
+        });
+    tx.expect_get_event_full()
+        .times(1)
+        .withf(move |cid, gid, eid| *cid == community_id && *gid == group_id && *eid == event_id)
+        .returning(move |_, _, _| Ok(event_full.clone()));
+    tx.expect_list_group_members_ids()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(vec![]));
+    tx.expect_list_group_team_members_ids()
+        .times(1)
+        .withf(move |gid| *gid == group_id)
+        .returning(|_| Ok(vec![]));
+    expect_successful_transaction(&mut db, tx);
+
+    // Publish the next due event
+    let worker = sample_worker(db);
+    let published = worker.publish_next_event().await.unwrap();
+
+    // Check the event was published
+    assert!(published);
+}
+
+#[tokio::test]
+async fn publish_next_event_returns_false_when_nothing_is_due() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    let mut tx = MockDB::new();
+    tx.expect_publish_due_scheduled_event()
+        .times(1)
+        .returning(|_| Ok(None));
+    expect_successful_transaction(&mut db, tx);
+
+    // Publish the next due event
+    let worker = sample_worker(db);
+    let published = worker.publish_next_event().await.unwrap();
+
+    // Check no event was published
+    assert!(!published);
+}
+
+#[tokio::test]
+async fn publish_next_event_rolls_back_when_publication_fails() {
+    // Setup database mock
+    let mut db = MockDB::new();
+    let mut tx = MockDB::new();
+    tx.expect_publish_due_scheduled_event()
+        .times(1)
+        .returning(|_| Err(anyhow!("db error")));
+    expect_rolled_back_transaction(&mut db, tx);
+
+    // Publish the next due event
+    let worker = sample_worker(db);
+    let result = worker.publish_next_event().await;
+
+    // Check the error is reported
+    assert!(result.is_err());
+}
+
+// Helpers.
+
+/// Builds a worker backed by the provided database mock.
+fn sample_worker(db: MockDB) -> ScheduledPublicationsWorker {
+    ScheduledPublicationsWorker {
+        cancellation_token: CancellationToken::new(),
+        configured_provider: Some(PaymentProvider::Stripe),
+        db: Arc::new(db) as DynDB,
+        server_cfg: HttpServerConfig::default(),
+    }
+}
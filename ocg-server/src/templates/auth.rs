@@ -1,27 +1,54 @@
 //! Templates and types for authentication-related pages and user info.
 
+use std::fmt;
+
 use anyhow::Result;
 use askama::Template;
 use axum_messages::Message;
 use garde::Validate;
 use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
+use serde_with::{NoneAsEmptyString, serde_as, skip_serializing_none};
+use webauthn_rs::prelude::RegisterPublicKeyCredential;
 
 use crate::{
     auth::AuthSession,
-    config::LoginOptions,
+    config::{CaptchaProvider, HttpServerConfig, LoginOptions},
     handlers::auth::AUTH_PROVIDER_KEY,
+    i18n::Language,
     templates::{PageId, filters, helpers::user_initials},
-    types::site::SiteSettings,
+    types::{
+        event::EventReminderPreference,
+        site::SiteSettings,
+        user::{UserPasskey, UserPhone, UserProvider, UserSession, UserWebhook},
+    },
     validation::{
         MAX_LEN_BIO, MAX_LEN_DISPLAY_NAME, MAX_LEN_L, MAX_LEN_M, MAX_LEN_S, MAX_LEN_TIMEZONE,
-        MIN_PASSWORD_LEN, image_url_opt, trimmed_non_empty, trimmed_non_empty_opt,
-        trimmed_non_empty_tag_vec,
+        MIN_PASSWORD_LEN, blank_string_as_none, image_url_opt, trimmed_non_empty,
+        trimmed_non_empty_opt, trimmed_non_empty_tag_vec, valid_phone_number_opt,
+        valid_webhook_url_opt,
     },
 };
 
 // Pages and sections templates.
 
+/// Template for the forgot password page.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "auth/forgot_password.html")]
+pub(crate) struct ForgotPasswordPage {
+    /// Login options.
+    pub login: LoginOptions,
+    /// Flash or status messages to display.
+    pub messages: Vec<Message>,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Current request path.
+    pub path: String,
+    /// Global site settings.
+    pub site_settings: SiteSettings,
+    /// Authenticated user information.
+    pub user: User,
+}
+
 /// Template for the log in page.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "auth/log_in.html")]
@@ -43,6 +70,26 @@ pub(crate) struct LogInPage {
     pub next_url: Option<String>,
 }
 
+/// Template for the reset password page.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "auth/reset_password.html")]
+pub(crate) struct ResetPasswordPage {
+    /// Password reset code included in the emailed link.
+    pub code: String,
+    /// Login options.
+    pub login: LoginOptions,
+    /// Flash or status messages to display.
+    pub messages: Vec<Message>,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Current request path.
+    pub path: String,
+    /// Global site settings.
+    pub site_settings: SiteSettings,
+    /// Authenticated user information.
+    pub user: User,
+}
+
 /// Template for the sign up page.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "auth/sign_up.html")]
@@ -60,6 +107,8 @@ pub(crate) struct SignUpPage {
     /// Authenticated user information.
     pub user: User,
 
+    /// CAPTCHA challenge to complete before signing up, if enabled.
+    pub captcha: Option<CaptchaWidget>,
     /// Next URL to redirect to after sign up, if any.
     pub next_url: Option<String>,
 }
@@ -68,12 +117,58 @@ pub(crate) struct SignUpPage {
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "auth/update_user.html")]
 pub(crate) struct UpdateUserPage {
+    /// Whether users can sign in with GitHub.
+    pub github_enabled: bool,
+    /// Whether users can sign in with GitLab.
+    pub gitlab_enabled: bool,
+    /// Whether the user connected their Google Calendar.
+    pub google_calendar_connected: bool,
+    /// Whether users can sync the events they attend to their Google Calendar.
+    pub google_calendar_enabled: bool,
+    /// Whether users can sign in with Google.
+    pub google_enabled: bool,
     /// Whether the user has a password set.
     pub has_password: bool,
+    /// Whether users can sign in with Linux Foundation SSO.
+    pub linuxfoundation_enabled: bool,
+    /// Passkeys registered by the user.
+    pub passkeys: Vec<UserPasskey>,
+    /// Whether users can sign in with passkeys.
+    pub passkeys_enabled: bool,
+    /// External accounts linked to the user.
+    pub provider: UserProvider,
+    /// Active sessions of the user.
+    pub sessions: Vec<UserSession>,
+    /// Whether notifications can be delivered by SMS.
+    pub sms_enabled: bool,
     /// List of available timezones.
     pub timezones: Vec<String>,
     /// User details to be updated.
     pub user: UserDetails,
+
+    /// URL of the user's personal calendar feed, if enabled.
+    pub calendar_feed_url: Option<String>,
+    /// Phone number the user's SMS notifications are sent to, if any.
+    pub phone: Option<UserPhone>,
+    /// Webhook the user's notifications are posted to, if any.
+    pub webhook: Option<UserWebhook>,
+}
+
+impl UpdateUserPage {
+    /// Returns whether a linked account can be unlinked without leaving the
+    /// user without a way to log in.
+    pub(crate) fn can_unlink_provider(&self) -> bool {
+        let linked_providers = [
+            self.provider.github.is_some(),
+            self.provider.gitlab.is_some(),
+            self.provider.google.is_some(),
+            self.provider.linuxfoundation.is_some(),
+        ]
+        .into_iter()
+        .filter(|linked| *linked)
+        .count();
+        self.has_password || linked_providers > 1
+    }
 }
 
 /// Template for the user menu section.
@@ -86,6 +181,28 @@ pub(crate) struct UserMenuSection {
 
 // Types.
 
+/// CAPTCHA widget rendered in forms protected by a challenge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CaptchaWidget {
+    /// Provider rendering the challenge.
+    pub provider: CaptchaProvider,
+    /// Public key identifying the site in the provider.
+    pub site_key: String,
+}
+
+impl CaptchaWidget {
+    /// Returns the widget to render, when CAPTCHA checks are enabled.
+    pub(crate) fn from_server_cfg(server_cfg: &HttpServerConfig) -> Option<Self> {
+        if server_cfg.disable_captcha_checks {
+            return None;
+        }
+        server_cfg.captcha.as_ref().map(|captcha_cfg| Self {
+            provider: captcha_cfg.provider,
+            site_key: captcha_cfg.site_key.clone(),
+        })
+    }
+}
+
 /// User information for authentication templates and session state.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub(crate) struct User {
@@ -123,7 +240,17 @@ impl User {
     }
 }
 
+/// Input for enabling or disabling a user's personal calendar feed.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct UserCalendarFeedUpdate {
+    /// Whether the calendar feed should be available.
+    #[serde(default)]
+    #[garde(skip)]
+    pub calendar_feed_enabled: bool,
+}
+
 /// User details that can be updated.
+#[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub(crate) struct UserDetails {
@@ -133,6 +260,9 @@ pub(crate) struct UserDetails {
     /// Whether the user receives optional notifications.
     #[garde(skip)]
     pub optional_notifications_enabled: bool,
+    /// Whether the user receives the weekly digest of upcoming events.
+    #[garde(skip)]
+    pub weekly_digest_enabled: bool,
 
     /// User's biography.
     #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_BIO))]
@@ -149,6 +279,11 @@ pub(crate) struct UserDetails {
     /// User's country.
     #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_S))]
     pub country: Option<String>,
+    /// When the user wants to be reminded about the events they attend.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    #[garde(skip)]
+    pub event_reminder_preference: Option<EventReminderPreference>,
     /// User's Facebook URL.
     #[garde(url, length(max = MAX_LEN_L))]
     pub facebook_url: Option<String>,
@@ -158,6 +293,11 @@ pub(crate) struct UserDetails {
     /// User's interests.
     #[garde(custom(trimmed_non_empty_tag_vec))]
     pub interests: Option<Vec<String>>,
+    /// Language the user's notifications are delivered in.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    #[garde(skip)]
+    pub language: Option<Language>,
     /// User's `LinkedIn` URL.
     #[garde(url, length(max = MAX_LEN_L))]
     pub linkedin_url: Option<String>,
@@ -183,14 +323,17 @@ impl From<crate::auth::User> for UserDetails {
         Self {
             name: user.name,
             optional_notifications_enabled: user.optional_notifications_enabled,
+            weekly_digest_enabled: user.weekly_digest_enabled,
             bio: user.bio,
             bluesky_url: user.bluesky_url,
             city: user.city,
             company: user.company,
             country: user.country,
+            event_reminder_preference: user.event_reminder_preference,
             facebook_url: user.facebook_url,
             github_url: user.github_url,
             interests: user.interests,
+            language: user.language,
             linkedin_url: user.linkedin_url,
             photo_url: user.photo_url,
             timezone: user.timezone,
@@ -211,3 +354,60 @@ pub(crate) struct UserPassword {
     #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
     pub old_password: String,
 }
+
+/// Input for completing the registration of a passkey.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct UserPasskeyRegistration {
+    /// Credential created by the user's authenticator.
+    #[garde(skip)]
+    pub credential: RegisterPublicKeyCredential,
+    /// Name given to the passkey by the user.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_S))]
+    pub name: String,
+}
+
+/// Input for updating the phone number a user's SMS notifications are sent to.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct UserPhoneNumberUpdate {
+    /// Phone number in E.164 format (the phone number is removed when not provided).
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(custom(valid_phone_number_opt))]
+    pub phone_number: Option<String>,
+}
+
+/// Input for verifying a user's phone number.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct UserPhoneVerification {
+    /// Verification code sent to the phone number by SMS.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_S))]
+    pub code: String,
+}
+
+/// Input for enabling or disabling a user's SMS notifications.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct UserSmsNotificationsUpdate {
+    /// Whether notifications should be sent by SMS.
+    #[serde(default)]
+    #[garde(skip)]
+    pub sms_notifications_enabled: bool,
+}
+
+/// Input for updating the webhook a user's notifications are posted to.
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct UserWebhookUpdate {
+    /// Webhook URL (the webhook is removed when not provided).
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(url, custom(valid_webhook_url_opt), length(max = MAX_LEN_L))]
+    pub webhook_url: Option<String>,
+}
+
+impl fmt::Debug for UserWebhookUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserWebhookUpdate")
+            .field(
+                "webhook_url",
+                &self.webhook_url.as_ref().map(|_| "[redacted]"),
+            )
+            .finish()
+    }
+}
@@ -1,7 +1,8 @@
 //! Templates and data structures for the community site.
 //!
 //! The home page displays an overview of the community including community statistics,
-//! upcoming events (both in-person and virtual), and recently added groups.
+//! upcoming events (both in-person and virtual), and recently added groups. The
+//! newsletter pages list the issues sent by the community and let users subscribe.
 
 use askama::Template;
 use serde::{Deserialize, Serialize};
@@ -14,7 +15,7 @@ use crate::{
         helpers::{self, user_initials},
     },
     types::{
-        community::CommunityFull,
+        community::{CommunityFull, CommunityNewsletterArchiveIssue},
         event::{EventKind, EventSummary},
         group::GroupSummary,
         site::SiteSettings,
@@ -73,6 +74,105 @@ impl Page {
     }
 }
 
+/// Template for the community newsletter archive page.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "community/newsletter_archive.html")]
+pub(crate) struct NewsletterArchivePage {
+    /// Configured public base URL.
+    pub base_url: String,
+    /// Community information.
+    pub community: CommunityFull,
+    /// Newsletter issues sent by the community, newest first.
+    pub issues: Vec<CommunityNewsletterArchiveIssue>,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Current request path.
+    pub path: String,
+    /// Global site settings.
+    pub site_settings: SiteSettings,
+    /// Authenticated user information.
+    pub user: User,
+}
+
+impl NewsletterArchivePage {
+    /// Returns the canonical public URL for the newsletter archive page.
+    pub(crate) fn canonical_url(&self) -> String {
+        helpers::absolute_url(
+            &self.base_url,
+            &format!("/{}/newsletter", self.community.name),
+        )
+    }
+
+    /// Returns the Open Graph image URL for the newsletter archive page.
+    pub(crate) fn open_graph_image_url(&self) -> Option<String> {
+        self.community
+            .og_image_url
+            .as_deref()
+            .map(|image_url| helpers::open_graph_image_url(&self.base_url, image_url))
+    }
+
+    /// Returns the preview title for the newsletter archive page.
+    pub(crate) fn preview_title(&self) -> String {
+        format!("{} newsletter", self.community.display_name)
+    }
+}
+
+/// Template for a community newsletter issue page.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "community/newsletter_issue.html")]
+pub(crate) struct NewsletterIssuePage {
+    /// Configured public base URL.
+    pub base_url: String,
+    /// Community information.
+    pub community: CommunityFull,
+    /// Newsletter issue to display.
+    pub issue: CommunityNewsletterArchiveIssue,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Current request path.
+    pub path: String,
+    /// Global site settings.
+    pub site_settings: SiteSettings,
+    /// Authenticated user information.
+    pub user: User,
+}
+
+impl NewsletterIssuePage {
+    /// Returns the canonical public URL for the newsletter issue page.
+    pub(crate) fn canonical_url(&self) -> String {
+        helpers::absolute_url(
+            &self.base_url,
+            &format!(
+                "/{}/newsletter/{}",
+                self.community.name, self.issue.community_newsletter_issue_id
+            ),
+        )
+    }
+
+    /// Returns the Open Graph image URL for the newsletter issue page.
+    pub(crate) fn open_graph_image_url(&self) -> Option<String> {
+        self.community
+            .og_image_url
+            .as_deref()
+            .map(|image_url| helpers::open_graph_image_url(&self.base_url, image_url))
+    }
+
+    /// Returns the preview title for the newsletter issue page.
+    pub(crate) fn preview_title(&self) -> String {
+        format!("{} - {}", self.issue.subject, self.community.display_name)
+    }
+}
+
+/// Newsletter subscription section displayed on the newsletter pages.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "community/newsletter_subscription.html")]
+pub(crate) struct NewsletterSubscriptionSection {
+    /// Community name used to build the subscription URLs.
+    pub community_name: String,
+    /// Whether the user is subscribed, `None` when nobody is logged in.
+    pub subscribed: Option<bool>,
+}
+
 /// Event card template for home page display.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "common/event_card_small.html")]
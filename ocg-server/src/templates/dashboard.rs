@@ -6,6 +6,8 @@ pub(crate) mod audit;
 pub(crate) mod community;
 /// Group dashboard templates.
 pub(crate) mod group;
+/// Shared dashboard custom notifications templates.
+pub(crate) mod notifications;
 /// User dashboard templates.
 pub(crate) mod user;
 
@@ -115,6 +115,36 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: GROUP_SCOPES,
         value: "cfs_submission_updated",
     },
+    AuditActionDefinition {
+        label: "Community domain added",
+        scopes: COMMUNITY_SCOPES,
+        value: "community_domain_added",
+    },
+    AuditActionDefinition {
+        label: "Community domain deleted",
+        scopes: COMMUNITY_SCOPES,
+        value: "community_domain_deleted",
+    },
+    AuditActionDefinition {
+        label: "Community domain verified",
+        scopes: COMMUNITY_SCOPES,
+        value: "community_domain_verified",
+    },
+    AuditActionDefinition {
+        label: "Community newsletter issue added",
+        scopes: COMMUNITY_SCOPES,
+        value: "community_newsletter_issue_added",
+    },
+    AuditActionDefinition {
+        label: "Community newsletter issue deleted",
+        scopes: COMMUNITY_SCOPES,
+        value: "community_newsletter_issue_deleted",
+    },
+    AuditActionDefinition {
+        label: "Community newsletter issue sent",
+        scopes: COMMUNITY_SCOPES,
+        value: "community_newsletter_issue_sent",
+    },
     AuditActionDefinition {
         label: "Community team invitation accepted",
         scopes: COMMUNITY_USER_SCOPES,
@@ -150,6 +180,16 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: GROUP_SCOPES,
         value: "event_added",
     },
+    AuditActionDefinition {
+        label: "Event approval requested",
+        scopes: COMMUNITY_GROUP_SCOPES,
+        value: "event_approval_requested",
+    },
+    AuditActionDefinition {
+        label: "Event approved",
+        scopes: COMMUNITY_GROUP_SCOPES,
+        value: "event_approved",
+    },
     AuditActionDefinition {
         label: "Event attendance canceled",
         scopes: GROUP_SCOPES,
@@ -200,6 +240,31 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: COMMUNITY_SCOPES,
         value: "event_category_updated",
     },
+    AuditActionDefinition {
+        label: "Event comment deleted",
+        scopes: GROUP_SCOPES,
+        value: "event_comment_deleted",
+    },
+    AuditActionDefinition {
+        label: "Event comment pinned",
+        scopes: GROUP_SCOPES,
+        value: "event_comment_pinned",
+    },
+    AuditActionDefinition {
+        label: "Event comment unpinned",
+        scopes: GROUP_SCOPES,
+        value: "event_comment_unpinned",
+    },
+    AuditActionDefinition {
+        label: "Event discussion locked",
+        scopes: GROUP_SCOPES,
+        value: "event_comments_locked",
+    },
+    AuditActionDefinition {
+        label: "Event discussion unlocked",
+        scopes: GROUP_SCOPES,
+        value: "event_comments_unlocked",
+    },
     AuditActionDefinition {
         label: "Event custom notification sent",
         scopes: GROUP_SCOPES,
@@ -220,6 +285,16 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: GROUP_SCOPES,
         value: "event_invitation_request_rejected",
     },
+    AuditActionDefinition {
+        label: "Event photo approved",
+        scopes: GROUP_SCOPES,
+        value: "event_photo_approved",
+    },
+    AuditActionDefinition {
+        label: "Event photo rejected",
+        scopes: GROUP_SCOPES,
+        value: "event_photo_rejected",
+    },
     AuditActionDefinition {
         label: "Event published",
         scopes: GROUP_SCOPES,
@@ -250,6 +325,31 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: GROUP_SCOPES,
         value: "event_refunded",
     },
+    AuditActionDefinition {
+        label: "Event rejected",
+        scopes: COMMUNITY_GROUP_SCOPES,
+        value: "event_rejected",
+    },
+    AuditActionDefinition {
+        label: "Event resource added",
+        scopes: GROUP_SCOPES,
+        value: "event_resource_added",
+    },
+    AuditActionDefinition {
+        label: "Event resource deleted",
+        scopes: GROUP_SCOPES,
+        value: "event_resource_deleted",
+    },
+    AuditActionDefinition {
+        label: "Event template added",
+        scopes: GROUP_SCOPES,
+        value: "event_template_added",
+    },
+    AuditActionDefinition {
+        label: "Event template deleted",
+        scopes: GROUP_SCOPES,
+        value: "event_template_deleted",
+    },
     AuditActionDefinition {
         label: "Event unpublished",
         scopes: GROUP_SCOPES,
@@ -260,6 +360,11 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: GROUP_SCOPES,
         value: "event_updated",
     },
+    AuditActionDefinition {
+        label: "Event waitlist user removed",
+        scopes: GROUP_SCOPES,
+        value: "event_waitlist_user_removed",
+    },
     AuditActionDefinition {
         label: "Group activated",
         scopes: COMMUNITY_SCOPES,
@@ -270,6 +375,31 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: COMMUNITY_SCOPES,
         value: "group_added",
     },
+    AuditActionDefinition {
+        label: "Group announcement added",
+        scopes: GROUP_SCOPES,
+        value: "group_announcement_added",
+    },
+    AuditActionDefinition {
+        label: "Group announcement deleted",
+        scopes: GROUP_SCOPES,
+        value: "group_announcement_deleted",
+    },
+    AuditActionDefinition {
+        label: "Group announcement pinned",
+        scopes: GROUP_SCOPES,
+        value: "group_announcement_pinned",
+    },
+    AuditActionDefinition {
+        label: "Group announcement unpinned",
+        scopes: GROUP_SCOPES,
+        value: "group_announcement_unpinned",
+    },
+    AuditActionDefinition {
+        label: "Group archived",
+        scopes: COMMUNITY_SCOPES,
+        value: "group_archived",
+    },
     AuditActionDefinition {
         label: "Group category added",
         scopes: COMMUNITY_SCOPES,
@@ -300,11 +430,131 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: COMMUNITY_SCOPES,
         value: "group_deleted",
     },
+    AuditActionDefinition {
+        label: "Group Discord webhook updated",
+        scopes: GROUP_SCOPES,
+        value: "group_discord_webhook_updated",
+    },
+    AuditActionDefinition {
+        label: "Group discussion reply deleted",
+        scopes: GROUP_SCOPES,
+        value: "group_discussion_reply_deleted",
+    },
+    AuditActionDefinition {
+        label: "Group discussion topic deleted",
+        scopes: GROUP_SCOPES,
+        value: "group_discussion_topic_deleted",
+    },
+    AuditActionDefinition {
+        label: "Group discussion topic locked",
+        scopes: GROUP_SCOPES,
+        value: "group_discussion_topic_locked",
+    },
+    AuditActionDefinition {
+        label: "Group discussion topic pinned",
+        scopes: GROUP_SCOPES,
+        value: "group_discussion_topic_pinned",
+    },
+    AuditActionDefinition {
+        label: "Group discussion topic unlocked",
+        scopes: GROUP_SCOPES,
+        value: "group_discussion_topic_unlocked",
+    },
+    AuditActionDefinition {
+        label: "Group discussion topic unpinned",
+        scopes: GROUP_SCOPES,
+        value: "group_discussion_topic_unpinned",
+    },
+    AuditActionDefinition {
+        label: "Group gallery cover updated",
+        scopes: GROUP_SCOPES,
+        value: "group_gallery_cover_updated",
+    },
+    AuditActionDefinition {
+        label: "Group gallery photo hidden",
+        scopes: GROUP_SCOPES,
+        value: "group_gallery_photo_hidden",
+    },
+    AuditActionDefinition {
+        label: "Group gallery photo shown",
+        scopes: GROUP_SCOPES,
+        value: "group_gallery_photo_shown",
+    },
+    AuditActionDefinition {
+        label: "Group gallery photos updated",
+        scopes: GROUP_SCOPES,
+        value: "group_gallery_photos_updated",
+    },
+    AuditActionDefinition {
+        label: "Group join questions updated",
+        scopes: GROUP_SCOPES,
+        value: "group_join_questions_updated",
+    },
+    AuditActionDefinition {
+        label: "Group join request accepted",
+        scopes: GROUP_SCOPES,
+        value: "group_join_request_accepted",
+    },
+    AuditActionDefinition {
+        label: "Group join request rejected",
+        scopes: GROUP_SCOPES,
+        value: "group_join_request_rejected",
+    },
+    AuditActionDefinition {
+        label: "Group member banned",
+        scopes: COMMUNITY_GROUP_SCOPES,
+        value: "group_member_banned",
+    },
+    AuditActionDefinition {
+        label: "Group member role updated",
+        scopes: GROUP_SCOPES,
+        value: "group_member_role_updated",
+    },
+    AuditActionDefinition {
+        label: "Group member unbanned",
+        scopes: COMMUNITY_GROUP_SCOPES,
+        value: "group_member_unbanned",
+    },
+    AuditActionDefinition {
+        label: "Group merged",
+        scopes: COMMUNITY_GROUP_SCOPES,
+        value: "group_merged",
+    },
+    AuditActionDefinition {
+        label: "Group owner updated",
+        scopes: COMMUNITY_GROUP_SCOPES,
+        value: "group_owner_updated",
+    },
+    AuditActionDefinition {
+        label: "Group ownership transfer accepted",
+        scopes: GROUP_USER_SCOPES,
+        value: "group_ownership_transfer_accepted",
+    },
+    AuditActionDefinition {
+        label: "Group ownership transfer canceled",
+        scopes: GROUP_SCOPES,
+        value: "group_ownership_transfer_canceled",
+    },
+    AuditActionDefinition {
+        label: "Group ownership transfer rejected",
+        scopes: GROUP_USER_SCOPES,
+        value: "group_ownership_transfer_rejected",
+    },
+    AuditActionDefinition {
+        label: "Group ownership transfer requested",
+        scopes: GROUP_SCOPES,
+        value: "group_ownership_transfer_requested",
+    },
     AuditActionDefinition {
         label: "Group payment recipient updated",
         scopes: COMMUNITY_GROUP_SCOPES,
         value: "group_payment_recipient_updated",
     },
+    AuditActionDefinition {
+        label: "Group restored",
+        scopes: COMMUNITY_SCOPES,
+        value: "group_restored",
+    },
     AuditActionDefinition {
         label: "Group sponsor added",
         scopes: GROUP_SCOPES,
@@ -315,6 +565,21 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: GROUP_SCOPES,
         value: "group_sponsor_deleted",
     },
+    AuditActionDefinition {
+        label: "Group sponsor tier added",
+        scopes: GROUP_SCOPES,
+        value: "group_sponsor_tier_added",
+    },
+    AuditActionDefinition {
+        label: "Group sponsor tier deleted",
+        scopes: GROUP_SCOPES,
+        value: "group_sponsor_tier_deleted",
+    },
+    AuditActionDefinition {
+        label: "Group sponsor tier updated",
+        scopes: GROUP_SCOPES,
+        value: "group_sponsor_tier_updated",
+    },
     AuditActionDefinition {
         label: "Group sponsor updated",
         scopes: GROUP_SCOPES,
@@ -350,6 +615,26 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: COMMUNITY_GROUP_SCOPES,
         value: "group_updated",
     },
+    AuditActionDefinition {
+        label: "Group welcome message updated",
+        scopes: GROUP_SCOPES,
+        value: "group_welcome_message_updated",
+    },
+    AuditActionDefinition {
+        label: "Group Zoom credentials deleted",
+        scopes: GROUP_SCOPES,
+        value: "group_zoom_credentials_deleted",
+    },
+    AuditActionDefinition {
+        label: "Group Zoom credentials updated",
+        scopes: GROUP_SCOPES,
+        value: "group_zoom_credentials_updated",
+    },
+    AuditActionDefinition {
+        label: "Notification requeued",
+        scopes: COMMUNITY_SCOPES,
+        value: "notification_requeued",
+    },
     AuditActionDefinition {
         label: "Region added",
         scopes: COMMUNITY_SCOPES,
@@ -400,16 +685,101 @@ const AUDIT_ACTION_DEFINITIONS: &[AuditActionDefinition] = &[
         scopes: USER_SCOPES,
         value: "submission_withdrawn",
     },
+    AuditActionDefinition {
+        label: "Topic added",
+        scopes: COMMUNITY_SCOPES,
+        value: "topic_added",
+    },
+    AuditActionDefinition {
+        label: "Topic deleted",
+        scopes: COMMUNITY_SCOPES,
+        value: "topic_deleted",
+    },
+    AuditActionDefinition {
+        label: "Topic updated",
+        scopes: COMMUNITY_SCOPES,
+        value: "topic_updated",
+    },
+    AuditActionDefinition {
+        label: "User calendar feed updated",
+        scopes: USER_SCOPES,
+        value: "user_calendar_feed_updated",
+    },
     AuditActionDefinition {
         label: "User details updated",
         scopes: USER_SCOPES,
         value: "user_details_updated",
     },
+    AuditActionDefinition {
+        label: "User Google Calendar connected",
+        scopes: USER_SCOPES,
+        value: "user_google_calendar_connected",
+    },
+    AuditActionDefinition {
+        label: "User Google Calendar disconnected",
+        scopes: USER_SCOPES,
+        value: "user_google_calendar_disconnected",
+    },
+    AuditActionDefinition {
+        label: "User passkey added",
+        scopes: USER_SCOPES,
+        value: "user_passkey_added",
+    },
+    AuditActionDefinition {
+        label: "User passkey deleted",
+        scopes: USER_SCOPES,
+        value: "user_passkey_deleted",
+    },
+    AuditActionDefinition {
+        label: "User password reset",
+        scopes: USER_SCOPES,
+        value: "user_password_reset",
+    },
     AuditActionDefinition {
         label: "User password updated",
         scopes: USER_SCOPES,
         value: "user_password_updated",
     },
+    AuditActionDefinition {
+        label: "User phone number updated",
+        scopes: USER_SCOPES,
+        value: "user_phone_number_updated",
+    },
+    AuditActionDefinition {
+        label: "User phone number verified",
+        scopes: USER_SCOPES,
+        value: "user_phone_number_verified",
+    },
+    AuditActionDefinition {
+        label: "User provider linked",
+        scopes: USER_SCOPES,
+        value: "user_provider_linked",
+    },
+    AuditActionDefinition {
+        label: "User provider unlinked",
+        scopes: USER_SCOPES,
+        value: "user_provider_unlinked",
+    },
+    AuditActionDefinition {
+        label: "User session revoked",
+        scopes: USER_SCOPES,
+        value: "user_session_revoked",
+    },
+    AuditActionDefinition {
+        label: "User sessions revoked",
+        scopes: USER_SCOPES,
+        value: "user_sessions_revoked",
+    },
+    AuditActionDefinition {
+        label: "User SMS notifications updated",
+        scopes: USER_SCOPES,
+        value: "user_sms_notifications_updated",
+    },
+    AuditActionDefinition {
+        label: "User webhook updated",
+        scopes: USER_SCOPES,
+        value: "user_webhook_updated",
+    },
 ];
 
 // Types.
@@ -691,13 +1061,20 @@ fn resource_type_label(resource_type: &str) -> &'static str {
     match resource_type {
         "cfs_submission" => "CFS submission",
         "community" => "Community",
+        "community_domain" => "Community domain",
+        "community_newsletter_issue" => "Community newsletter issue",
         "event" => "Event",
         "event_category" => "Event category",
+        "event_template" => "Event template",
         "group" => "Group",
+        "group_announcement" => "Group announcement",
         "group_category" => "Group category",
         "group_sponsor" => "Group sponsor",
+        "group_sponsor_tier" => "Group sponsor tier",
+        "notification" => "Notification",
         "region" => "Region",
         "session_proposal" => "Session proposal",
+        "topic" => "Topic",
         "user" => "User",
         _ => "Resource",
     }
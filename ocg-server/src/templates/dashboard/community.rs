@@ -1,10 +1,16 @@
 //! Templates for the community dashboard.
 
 pub(crate) mod analytics;
+pub(crate) mod domains;
+pub(crate) mod event_approvals;
 pub(crate) mod event_categories;
+pub(crate) mod failed_notifications;
+pub(crate) mod group_bans;
 pub(crate) mod group_categories;
 pub(crate) mod groups;
 pub(crate) mod home;
+pub(crate) mod newsletter;
 pub(crate) mod regions;
 pub(crate) mod settings;
 pub(crate) mod team;
+pub(crate) mod topics;
@@ -0,0 +1,60 @@
+//! Templates and types for managing custom domains in the community dashboard.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::{services::domains::verification_record_name, validation::valid_domain};
+
+// Pages templates.
+
+/// Custom domains list page template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/community/domains_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage settings.
+    pub can_manage_settings: bool,
+    /// Custom domains added to the community.
+    pub domains: Vec<CommunityDomain>,
+    /// Host the custom domains must point to.
+    pub target_host: String,
+}
+
+// Types.
+
+/// Custom domain added to the community.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CommunityDomain {
+    /// Unique identifier for the custom domain.
+    pub community_domain_id: Uuid,
+    /// Domain creation time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Domain name, e.g. events.example.com.
+    pub domain: String,
+    /// Token that must be published in the verification TXT record.
+    pub verification_token: String,
+
+    /// Time the domain ownership was verified.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub verified_at: Option<DateTime<Utc>>,
+}
+
+impl CommunityDomain {
+    /// Returns the name of the TXT record used to verify the domain.
+    pub(crate) fn verification_record_name(&self) -> String {
+        verification_record_name(&self.domain)
+    }
+}
+
+/// Custom domain form payload.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct CommunityDomainInput {
+    /// Domain name, e.g. events.example.com.
+    #[garde(custom(valid_domain))]
+    pub domain: String,
+}
@@ -0,0 +1,48 @@
+//! Templates and types for reviewing group events in the community dashboard.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::event::EventSummary,
+    validation::{MAX_LEN_DESCRIPTION_SHORT, trimmed_non_empty_opt},
+};
+
+// Pages templates.
+
+/// Event approvals list page template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/community/event_approvals_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage groups.
+    pub can_manage_groups: bool,
+    /// Events waiting for the community approval.
+    pub events: Vec<PendingEvent>,
+}
+
+// Types.
+
+/// Event waiting for the community approval.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PendingEvent {
+    /// Summary of the event to review.
+    pub event: EventSummary,
+
+    /// When the approval was requested.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub requested_at: Option<DateTime<Utc>>,
+    /// Display name of the user who requested the approval.
+    pub requested_by_display_name: Option<String>,
+    /// Username of the user who requested the approval.
+    pub requested_by_username: Option<String>,
+}
+
+/// Event rejection form payload.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub(crate) struct EventRejectionInput {
+    /// Optional reason shared with the group team.
+    #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_DESCRIPTION_SHORT))]
+    pub reason: Option<String>,
+}
@@ -0,0 +1,88 @@
+//! Templates and types for the community dashboard failed notifications page.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::{
+    templates::dashboard,
+    types::pagination::{self, Pagination, ToRawQuery},
+    validation::MAX_PAGINATION_LIMIT,
+};
+
+// Pages templates.
+
+/// Failed notifications list page template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/community/failed_notifications_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the user can requeue failed notifications.
+    pub can_requeue: bool,
+    /// Pagination navigation links.
+    pub navigation_links: pagination::NavigationLinks,
+    /// Failed notifications to display.
+    pub notifications: Vec<FailedNotification>,
+    /// Total number of failed notifications.
+    pub total: usize,
+
+    /// Number of results per page.
+    pub limit: Option<usize>,
+    /// Pagination offset for results.
+    pub offset: Option<usize>,
+}
+
+// Types.
+
+/// Notification whose delivery failed after exhausting its retries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FailedNotification {
+    /// Channel the notification was delivered through.
+    pub channel: String,
+    /// Time the notification was created.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Number of delivery attempts made.
+    pub delivery_attempts: usize,
+    /// Terminal delivery status, `failed` or `delivery-unknown`.
+    pub delivery_status: String,
+    /// Notification kind.
+    pub kind: String,
+    /// Unique notification identifier.
+    pub notification_id: Uuid,
+
+    /// Last delivery error.
+    pub error: Option<String>,
+    /// Time the delivery was given up.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub processed_at: Option<DateTime<Utc>>,
+    /// Username of the recipient, if the notification was sent to a user.
+    pub username: Option<String>,
+}
+
+/// Filter parameters for failed notifications pagination.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub(crate) struct FailedNotificationsFilters {
+    /// Number of results per page.
+    #[serde(default = "dashboard::default_limit")]
+    #[garde(range(min = 1, max = MAX_PAGINATION_LIMIT))]
+    pub limit: Option<usize>,
+    /// Pagination offset for results.
+    #[serde(default = "dashboard::default_offset")]
+    #[garde(skip)]
+    pub offset: Option<usize>,
+}
+
+crate::impl_pagination_and_raw_query!(FailedNotificationsFilters, limit, offset);
+
+/// Paginated failed notifications response data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FailedNotificationsOutput {
+    /// Failed notifications matching the filters.
+    pub notifications: Vec<FailedNotification>,
+    /// Total number of failed notifications before pagination.
+    pub total: usize,
+}
@@ -0,0 +1,49 @@
+//! Templates and types for reviewing group bans in the community dashboard.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::templates::helpers::user_initials;
+
+// Pages templates.
+
+/// Group bans list page template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/community/group_bans_list.html")]
+pub(crate) struct ListPage {
+    /// Users banned from the community groups.
+    pub bans: Vec<CommunityGroupBan>,
+    /// Whether the current user can manage groups.
+    pub can_manage_groups: bool,
+}
+
+// Types.
+
+/// User banned from a group of the community.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CommunityGroupBan {
+    /// Ban creation time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Identifier of the group the user was banned from.
+    pub group_id: Uuid,
+    /// Name of the group the user was banned from.
+    pub group_name: String,
+    /// Reason provided by the organizer who banned the user.
+    pub reason: String,
+    /// Identifier of the banned user.
+    pub user_id: Uuid,
+    /// Username.
+    pub username: String,
+
+    /// Username of the organizer who banned the user.
+    pub banned_by_username: Option<String>,
+    /// Full name.
+    pub name: Option<String>,
+    /// URL to user's avatar.
+    pub photo_url: Option<String>,
+}
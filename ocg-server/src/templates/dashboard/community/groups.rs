@@ -9,17 +9,23 @@ use serde_with::skip_serializing_none;
 use uuid::Uuid;
 
 use crate::{
-    templates::dashboard,
+    templates::{
+        dashboard::{self, group::settings::GroupOwnership},
+        helpers::DATE_FORMAT_2,
+    },
     types::{
-        group::{GroupCategory, GroupFull, GroupParentOption, GroupRegion, GroupSummary},
+        community::CommunityTopic,
+        group::{
+            GroupCategory, GroupFull, GroupMinimal, GroupParentOption, GroupRegion, GroupSummary,
+        },
         pagination::{self, Pagination, ToRawQuery},
         payments::GroupPaymentRecipient,
     },
     validation::{
-        MAX_LEN_COUNTRY_CODE, MAX_LEN_DESCRIPTION, MAX_LEN_ENTITY_NAME, MAX_LEN_L, MAX_LEN_M,
-        MAX_LEN_S, MAX_PAGINATION_LIMIT, image_url_opt, image_url_vec, trimmed_non_empty,
-        trimmed_non_empty_opt, trimmed_non_empty_tag_vec, url_map_values, valid_group_pretty_slug,
-        valid_latitude, valid_longitude,
+        MAX_ITEMS, MAX_LEN_COUNTRY_CODE, MAX_LEN_DESCRIPTION, MAX_LEN_ENTITY_NAME, MAX_LEN_L,
+        MAX_LEN_M, MAX_LEN_S, MAX_PAGINATION_LIMIT, image_url_opt, image_url_vec,
+        trimmed_non_empty, trimmed_non_empty_opt, trimmed_non_empty_tag_vec, url_map_values,
+        valid_group_pretty_slug, valid_latitude, valid_longitude,
     },
 };
 
@@ -37,6 +43,8 @@ pub(crate) struct AddPage {
     pub parent_options: Vec<GroupParentOption>,
     /// List of available regions.
     pub regions: Vec<GroupRegion>,
+    /// List of available topics.
+    pub topics: Vec<CommunityTopic>,
 }
 
 /// List groups page template.
@@ -52,6 +60,8 @@ pub(crate) struct ListPage {
     /// Total number of groups in the community.
     pub total: usize,
 
+    /// Whether only the dormant groups are listed.
+    pub dormant: Option<bool>,
     /// Number of results per page.
     pub limit: Option<usize>,
     /// Pagination offset for results.
@@ -72,10 +82,16 @@ pub(crate) struct UpdatePage {
     pub group: GroupFull,
     /// Whether this group has non-deleted child links.
     pub has_child_links: bool,
+    /// Groups this group can be merged into.
+    pub merge_targets: Vec<GroupMinimal>,
+    /// Group ownership information.
+    pub ownership: GroupOwnership,
     /// List of groups that can be selected as parents.
     pub parent_options: Vec<GroupParentOption>,
     /// List of available regions.
     pub regions: Vec<GroupRegion>,
+    /// List of available topics.
+    pub topics: Vec<CommunityTopic>,
 }
 
 // Types.
@@ -84,6 +100,10 @@ pub(crate) struct UpdatePage {
 #[skip_serializing_none]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
 pub(crate) struct CommunityGroupsFilters {
+    /// Whether to only list the dormant groups.
+    #[serde(default)]
+    #[garde(skip)]
+    pub dormant: Option<bool>,
     /// Number of results per page.
     #[serde(default = "dashboard::default_limit")]
     #[garde(range(min = 1, max = MAX_PAGINATION_LIMIT))]
@@ -174,6 +194,9 @@ pub(crate) struct Group {
     /// Gallery of photo URLs.
     #[garde(custom(image_url_vec))]
     pub photos_urls: Option<Vec<String>>,
+    /// Whether joining requires an organizer approval.
+    #[garde(skip)]
+    pub private: Option<bool>,
     /// Region this group belongs to.
     #[garde(skip)]
     pub region_id: Option<Uuid>,
@@ -189,6 +212,9 @@ pub(crate) struct Group {
     /// Tags associated with the group.
     #[garde(custom(trimmed_non_empty_tag_vec))]
     pub tags: Option<Vec<String>>,
+    /// Community topics assigned to the group.
+    #[garde(length(max = MAX_ITEMS))]
+    pub topic_ids: Option<Vec<Uuid>>,
     /// Twitter profile URL.
     #[garde(url, length(max = MAX_LEN_L))]
     pub twitter_url: Option<String>,
@@ -202,3 +228,19 @@ pub(crate) struct Group {
     #[garde(url, length(max = MAX_LEN_L))]
     pub youtube_url: Option<String>,
 }
+
+/// Group merge form data.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupMergeInput {
+    /// Group the merged group is folded into.
+    #[garde(skip)]
+    pub target_group_id: Uuid,
+}
+
+/// Group owner override form data.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupOwnerUpdate {
+    /// Username of the new group owner.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
+    pub username: String,
+}
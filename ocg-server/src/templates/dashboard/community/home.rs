@@ -12,8 +12,10 @@ use crate::{
         dashboard::{
             audit,
             community::{
-                analytics, event_categories, group_categories, groups, regions, settings, team,
+                analytics, domains, event_approvals, event_categories, failed_notifications,
+                group_bans, group_categories, groups, newsletter, regions, settings, team, topics,
             },
+            notifications,
         },
         filters,
         helpers::user_initials,
@@ -48,20 +50,34 @@ pub(crate) struct Page {
 pub(crate) enum Content {
     /// Analytics page.
     Analytics(Box<analytics::Page>),
+    /// Custom domains management page.
+    Domains(domains::ListPage),
+    /// Event approvals queue page.
+    EventApprovals(event_approvals::ListPage),
     /// Event categories management page.
     EventCategories(event_categories::ListPage),
+    /// Failed notifications review page.
+    FailedNotifications(failed_notifications::ListPage),
+    /// Group bans review page.
+    GroupBans(group_bans::ListPage),
     /// Group categories management page.
     GroupCategories(group_categories::ListPage),
     /// Groups management page.
     Groups(groups::ListPage),
     /// Audit logs page.
     Logs(audit::ListPage),
+    /// Newsletter issues management page.
+    Newsletter(newsletter::ListPage),
+    /// Custom notifications delivery status page.
+    Notifications(notifications::ListPage),
     /// Regions management page.
     Regions(regions::ListPage),
     /// Settings page.
     Settings(Box<settings::UpdatePage>),
     /// Team management page.
     Team(team::ListPage),
+    /// Topics management page.
+    Topics(topics::ListPage),
 }
 
 impl Content {
@@ -70,11 +86,31 @@ impl Content {
         matches!(self, Content::Analytics(_))
     }
 
+    /// Check if the content is the custom domains page.
+    fn is_domains(&self) -> bool {
+        matches!(self, Content::Domains(_))
+    }
+
+    /// Check if the content is the event approvals page.
+    fn is_event_approvals(&self) -> bool {
+        matches!(self, Content::EventApprovals(_))
+    }
+
     /// Check if the content is the event categories page.
     fn is_event_categories(&self) -> bool {
         matches!(self, Content::EventCategories(_))
     }
 
+    /// Check if the content is the failed notifications page.
+    fn is_failed_notifications(&self) -> bool {
+        matches!(self, Content::FailedNotifications(_))
+    }
+
+    /// Check if the content is the group bans page.
+    fn is_group_bans(&self) -> bool {
+        matches!(self, Content::GroupBans(_))
+    }
+
     /// Check if the content is the group categories page.
     fn is_group_categories(&self) -> bool {
         matches!(self, Content::GroupCategories(_))
@@ -90,6 +126,16 @@ impl Content {
         matches!(self, Content::Logs(_))
     }
 
+    /// Check if the content is the newsletter page.
+    fn is_newsletter(&self) -> bool {
+        matches!(self, Content::Newsletter(_))
+    }
+
+    /// Check if the content is the notifications page.
+    fn is_notifications(&self) -> bool {
+        matches!(self, Content::Notifications(_))
+    }
+
     /// Check if the content is the regions page.
     fn is_regions(&self) -> bool {
         matches!(self, Content::Regions(_))
@@ -104,19 +150,31 @@ impl Content {
     fn is_team(&self) -> bool {
         matches!(self, Content::Team(_))
     }
+
+    /// Check if the content is the topics page.
+    fn is_topics(&self) -> bool {
+        matches!(self, Content::Topics(_))
+    }
 }
 
 impl std::fmt::Display for Content {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Content::Analytics(template) => write!(f, "{}", template.render()?),
+            Content::Domains(template) => write!(f, "{}", template.render()?),
+            Content::EventApprovals(template) => write!(f, "{}", template.render()?),
             Content::EventCategories(template) => write!(f, "{}", template.render()?),
+            Content::FailedNotifications(template) => write!(f, "{}", template.render()?),
+            Content::GroupBans(template) => write!(f, "{}", template.render()?),
             Content::GroupCategories(template) => write!(f, "{}", template.render()?),
             Content::Groups(template) => write!(f, "{}", template.render()?),
             Content::Logs(template) => write!(f, "{}", template.render()?),
+            Content::Newsletter(template) => write!(f, "{}", template.render()?),
+            Content::Notifications(template) => write!(f, "{}", template.render()?),
             Content::Regions(template) => write!(f, "{}", template.render()?),
             Content::Settings(template) => write!(f, "{}", template.render()?),
             Content::Team(template) => write!(f, "{}", template.render()?),
+            Content::Topics(template) => write!(f, "{}", template.render()?),
         }
     }
 }
@@ -131,18 +189,32 @@ pub(crate) enum Tab {
     /// Analytics tab (default).
     #[default]
     Analytics,
+    /// Custom domains management tab.
+    Domains,
+    /// Event approvals queue tab.
+    EventApprovals,
     /// Event categories management tab.
     EventCategories,
+    /// Failed notifications review tab.
+    FailedNotifications,
+    /// Group bans review tab.
+    GroupBans,
     /// Group categories management tab.
     GroupCategories,
     /// Groups management tab.
     Groups,
     /// Audit logs tab.
     Logs,
+    /// Newsletter issues management tab.
+    Newsletter,
+    /// Custom notifications delivery status tab.
+    Notifications,
     /// Regions management tab.
     Regions,
     /// Settings tab.
     Settings,
     /// Team management tab.
     Team,
+    /// Topics management tab.
+    Topics,
 }
@@ -0,0 +1,75 @@
+//! Templates and types for managing the newsletter in the community dashboard.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::validation::{MAX_LEN_DESCRIPTION, MAX_LEN_M, trimmed_non_empty};
+
+// Pages templates.
+
+/// Newsletter issues list page template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/community/newsletter_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage settings.
+    pub can_manage_settings: bool,
+    /// Newsletter issues of the community, drafts first.
+    pub issues: Vec<CommunityNewsletterIssue>,
+    /// Number of users subscribed to the newsletter.
+    pub subscriber_count: usize,
+}
+
+// Types.
+
+/// Newsletter issue of the community, with the delivery status of its emails.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CommunityNewsletterIssue {
+    /// Issue body in markdown.
+    pub body: String,
+    /// Number of emails reported as bounced by the email provider.
+    #[serde(default)]
+    pub bounced: usize,
+    /// Unique identifier for the newsletter issue.
+    pub community_newsletter_issue_id: Uuid,
+    /// Issue creation time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Number of emails that could not be delivered.
+    #[serde(default)]
+    pub failed: usize,
+    /// Number of emails waiting to be delivered.
+    #[serde(default)]
+    pub queued: usize,
+    /// Number of emails delivered to the email provider.
+    #[serde(default)]
+    pub sent: usize,
+    /// Issue subject.
+    pub subject: String,
+    /// Number of emails with an unknown delivery outcome.
+    #[serde(default)]
+    pub unknown: usize,
+
+    /// Username of the team member who composed the issue.
+    pub created_by_username: Option<String>,
+    /// Number of subscribers the issue was sent to.
+    pub recipient_count: Option<usize>,
+    /// Time the issue was sent, if it is not a draft.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+/// Newsletter issue form payload.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct CommunityNewsletterIssueInput {
+    /// Issue body in markdown.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_DESCRIPTION))]
+    pub body: String,
+    /// Issue subject.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
+    pub subject: String,
+}
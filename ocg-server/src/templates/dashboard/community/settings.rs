@@ -8,10 +8,15 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::{
-    types::community::CommunityFull,
+    types::{
+        community::{CommunityEmailBranding, CommunityFull, CommunityTheme},
+        site::ThemeFontFamily,
+    },
     validation::{
-        MAX_LEN_DESCRIPTION, MAX_LEN_DISPLAY_NAME, MAX_LEN_L, image_url, image_url_opt,
+        MAX_DORMANT_GROUP_MONTHS, MAX_LEN_DESCRIPTION, MAX_LEN_DESCRIPTION_SHORT,
+        MAX_LEN_DISPLAY_NAME, MAX_LEN_L, blank_string_as_none, image_url, image_url_opt,
         image_url_vec, trimmed_non_empty, trimmed_non_empty_opt, url_map_values,
+        valid_email_domain_vec, valid_hex_color_opt, valid_oidc_provider_opt,
     },
 };
 
@@ -25,6 +30,13 @@ pub(crate) struct UpdatePage {
     pub can_manage_settings: bool,
     /// Community information.
     pub community: CommunityFull,
+    /// Branding applied to the emails sent on behalf of the community.
+    pub email_branding: CommunityEmailBranding,
+    /// Theme applied to the community pages.
+    pub theme: CommunityTheme,
+
+    /// Slack incoming webhook URL used to post community notifications.
+    pub slack_webhook_url: Option<String>,
 }
 
 // Types.
@@ -45,12 +57,24 @@ pub(crate) struct CommunityUpdate {
     /// Human-readable name shown in the UI (e.g., "CNCF").
     #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_DISPLAY_NAME))]
     pub display_name: String,
+    /// Months without events after which a group is considered dormant.
+    #[garde(range(min = 1, max = MAX_DORMANT_GROUP_MONTHS))]
+    pub dormant_group_months: i32,
+    /// Whether community admins receive a monthly report of the dormant groups.
+    #[garde(skip)]
+    pub dormant_groups_report_enabled: bool,
+    /// Whether group events require a community approval to be published.
+    #[garde(skip)]
+    pub event_approval_required: bool,
     /// Whether group team management is restricted to community roles.
     #[garde(skip)]
     pub group_team_management_restricted: bool,
     /// URL to the logo image.
     #[garde(custom(image_url))]
     pub logo_url: String,
+    /// Whether signing in with a passkey is required to access the dashboard.
+    #[garde(skip)]
+    pub passkey_required: bool,
 
     /// Target URL when users click on the advertisement banner.
     #[garde(url, length(max = MAX_LEN_L))]
@@ -58,9 +82,24 @@ pub(crate) struct CommunityUpdate {
     /// URL to the advertisement banner image.
     #[garde(custom(image_url_opt))]
     pub ad_banner_url: Option<String>,
+    /// Email domains allowed to sign up from the community (any if empty).
+    #[garde(custom(valid_email_domain_vec))]
+    pub allowed_email_domains: Option<Vec<String>>,
     /// Link to the community's Bluesky profile.
     #[garde(url, length(max = MAX_LEN_L))]
     pub bluesky_url: Option<String>,
+    /// Text displayed in the footer of the community emails.
+    #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_DESCRIPTION_SHORT))]
+    pub email_footer_text: Option<String>,
+    /// Name used as the sender of the community emails.
+    #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_DISPLAY_NAME))]
+    pub email_from_name: Option<String>,
+    /// URL to the logo displayed at the top of the community emails.
+    #[garde(custom(image_url_opt))]
+    pub email_logo_url: Option<String>,
+    /// Primary color used in the community emails.
+    #[garde(custom(valid_hex_color_opt))]
+    pub email_primary_color: Option<String>,
     /// Additional custom links displayed in the community navigation.
     #[garde(custom(url_map_values))]
     pub extra_links: Option<BTreeMap<String, String>>,
@@ -88,9 +127,28 @@ pub(crate) struct CommunityUpdate {
     /// Collection of photo URLs for community galleries or slideshows.
     #[garde(custom(image_url_vec))]
     pub photos_urls: Option<Vec<String>>,
+    /// OIDC provider users must log in with (password login is disabled).
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(custom(valid_oidc_provider_opt))]
+    pub required_oidc_provider: Option<String>,
     /// Link to the community's Slack workspace.
     #[garde(url, length(max = MAX_LEN_L))]
     pub slack_url: Option<String>,
+    /// Slack incoming webhook URL used to post community notifications.
+    #[garde(url, length(max = MAX_LEN_L))]
+    pub slack_webhook_url: Option<String>,
+    /// URL to the icon displayed in browser tabs for the community pages.
+    #[garde(custom(image_url_opt))]
+    pub theme_favicon_url: Option<String>,
+    /// Font family used in the community pages.
+    #[garde(skip)]
+    pub theme_font_family: Option<ThemeFontFamily>,
+    /// URL to the logo shown in the header of the community pages.
+    #[garde(custom(image_url_opt))]
+    pub theme_header_logo_url: Option<String>,
+    /// Primary color used in the community pages.
+    #[garde(custom(valid_hex_color_opt))]
+    pub theme_primary_color: Option<String>,
     /// Link to the community's Twitter/X profile.
     #[garde(url, length(max = MAX_LEN_L))]
     pub twitter_url: Option<String>,
@@ -0,0 +1,50 @@
+//! Templates and types for managing topics in the community dashboard.
+
+use askama::Template;
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::community::CommunityTopic,
+    validation::{MAX_LEN_ENTITY_NAME, trimmed_non_empty},
+};
+
+// Pages templates.
+
+/// Topics list page template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/community/topics_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage taxonomy.
+    pub can_manage_taxonomy: bool,
+    /// Topics available in the selected community.
+    pub topics: Vec<CommunityTopic>,
+}
+
+/// Topic add form template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/community/topics_add.html")]
+pub(crate) struct AddPage {
+    /// Whether the current user can manage taxonomy.
+    pub can_manage_taxonomy: bool,
+}
+
+/// Topic update form template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/community/topics_update.html")]
+pub(crate) struct UpdatePage {
+    /// Whether the current user can manage taxonomy.
+    pub can_manage_taxonomy: bool,
+    /// Topic currently being edited.
+    pub topic: CommunityTopic,
+}
+
+// Types.
+
+/// Topic form payload used by create and update operations.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct TopicInput {
+    /// Topic name.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_ENTITY_NAME))]
+    pub name: String,
+}
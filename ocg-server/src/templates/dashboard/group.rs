@@ -3,12 +3,19 @@
 use serde::{Deserialize, Serialize};
 
 pub(crate) mod analytics;
+pub(crate) mod announcements;
 pub(crate) mod attendees;
+pub(crate) mod comments;
+pub(crate) mod discussions;
 pub(crate) mod events;
+pub(crate) mod gallery;
 pub(crate) mod home;
 pub(crate) mod invitation_requests;
+pub(crate) mod kiosk;
 pub(crate) mod members;
+pub(crate) mod photos;
 pub(crate) mod refunds;
+pub(crate) mod resources;
 pub(crate) mod settings;
 pub(crate) mod sponsors;
 pub(crate) mod submissions;
@@ -1,8 +1,10 @@
 //! Templates and data types for the analytics page in the group dashboard.
 
 use askama::Template;
+use chrono::{DateTime, Utc};
 use garde::Validate;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::templates::filters;
 
@@ -12,6 +14,8 @@ use crate::templates::filters;
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "dashboard/group/analytics.html")]
 pub(crate) struct Page {
+    /// Engagement statistics to render.
+    pub engagement: GroupEngagementStats,
     /// Whether statistics include active subgroups.
     pub include_subgroups: bool,
     /// Whether the group has active subgroups.
@@ -54,6 +58,28 @@ pub(crate) struct GroupAttendeesStats {
     pub total: i64,
 }
 
+/// Statistics for event check-ins in a single group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GroupCheckInsStats {
+    /// Monthly check-in counts.
+    pub per_month: Vec<(String, i64)>,
+    /// Running total of check-ins.
+    pub running_total: Vec<(i64, i64)>,
+    /// Total check-ins.
+    pub total: i64,
+}
+
+/// Aggregated group engagement statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GroupEngagementStats {
+    /// Check-ins statistics.
+    pub check_ins: GroupCheckInsStats,
+    /// Attendees retention statistics.
+    pub retention: GroupRetentionStats,
+    /// Events with the most attendees.
+    pub top_events: Vec<GroupTopEvent>,
+}
+
 /// Statistics for events in a single group.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct GroupEventsStats {
@@ -76,6 +102,46 @@ pub(crate) struct GroupMembersStats {
     pub total: i64,
 }
 
+/// Statistics for attendees returning to the group events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GroupRetentionStats {
+    /// Monthly counts of attendees that had attended an earlier event.
+    pub per_month: Vec<(String, i64)>,
+    /// Attendees that attended more than one event.
+    pub repeat_attendees: i64,
+    /// Unique attendees across all events.
+    pub unique_attendees: i64,
+}
+
+impl GroupRetentionStats {
+    /// Returns the percentage of unique attendees that attended more than one
+    /// event, if there are any attendees.
+    pub(crate) fn repeat_rate(&self) -> Option<i64> {
+        (self.unique_attendees > 0).then(|| {
+            (self.repeat_attendees * 100 + self.unique_attendees / 2) / self.unique_attendees
+        })
+    }
+}
+
+/// Event with the most attendees in a single group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GroupTopEvent {
+    /// Number of attendees.
+    pub attendees: i64,
+    /// Number of checked in attendees.
+    pub check_ins: i64,
+    /// Event identifier.
+    pub event_id: Uuid,
+    /// Name of the group hosting the event.
+    pub group_name: String,
+    /// Event name.
+    pub name: String,
+
+    /// Event start time.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub starts_at: Option<DateTime<Utc>>,
+}
+
 /// Statistics for group dashboard page views.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct GroupPageViewsStats {
@@ -0,0 +1,40 @@
+//! Templates for managing the group announcements in the dashboard.
+
+use askama::Template;
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::group::GroupAnnouncement,
+    validation::{MAX_LEN_M, MAX_LEN_NOTIFICATION_BODY, trimmed_non_empty},
+};
+
+// Pages templates.
+
+/// List announcements page template for a group.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/group/announcements_list.html")]
+pub(crate) struct ListPage {
+    /// Announcements posted in the group.
+    pub announcements: Vec<GroupAnnouncement>,
+    /// Whether the current user can manage members.
+    pub can_manage_members: bool,
+}
+
+// Types.
+
+/// Group announcement form data.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupAnnouncementInput {
+    /// Content of the announcement.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_NOTIFICATION_BODY))]
+    pub content: String,
+    /// Title of the announcement.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
+    pub title: String,
+
+    /// Whether the announcement should be emailed to the group members.
+    #[serde(default)]
+    #[garde(skip)]
+    pub notify_members: bool,
+}
@@ -31,6 +31,8 @@ pub(crate) struct ListPage {
     pub attendance: AttendanceFilter,
     /// List of attendees for the selected event.
     pub attendees: Vec<Attendee>,
+    /// Whether the current user can check in attendees.
+    pub can_check_in: bool,
     /// Whether the current user can manage events.
     pub can_manage_events: bool,
     /// Event for which attendees are listed.
@@ -189,13 +191,12 @@ pub(crate) fn format_payment_amount(
     currency_code: Option<&str>,
 ) -> Option<String> {
     let amount_minor = (*amount_minor)?;
-    let currency_code = currency_code?;
 
     if amount_minor == 0 {
         return Some("Free".to_string());
     }
 
-    Some(format_amount_minor(amount_minor, currency_code))
+    Some(format_amount_minor(amount_minor, currency_code?))
 }
 
 /// Returns true when the attendee has a paid event purchase.
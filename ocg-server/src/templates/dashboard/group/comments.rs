@@ -0,0 +1,23 @@
+//! Templates for moderating event discussions in the group dashboard.
+
+use askama::Template;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    templates::helpers::user_initials,
+    types::event::{EventDiscussion, EventSummary},
+};
+
+// Pages templates.
+
+/// List comments page template for a group's event.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/group/comments_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage events.
+    pub can_manage_events: bool,
+    /// Discussion of the selected event.
+    pub discussion: EventDiscussion,
+    /// Event for which the discussion is listed.
+    pub event: EventSummary,
+}
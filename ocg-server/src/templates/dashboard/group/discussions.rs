@@ -0,0 +1,18 @@
+//! Templates for moderating the group discussion in the dashboard.
+
+use askama::Template;
+use serde::{Deserialize, Serialize};
+
+use crate::{templates::helpers::user_initials, types::group::GroupDiscussion};
+
+// Pages templates.
+
+/// List discussion topics page template for a group.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/group/discussions_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage members.
+    pub can_manage_members: bool,
+    /// Discussion of the group.
+    pub discussion: GroupDiscussion,
+}
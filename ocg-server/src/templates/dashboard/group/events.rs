@@ -12,23 +12,26 @@ use uuid::Uuid;
 
 use crate::{
     services::meetings::MeetingProvider,
-    templates::{dashboard, filters, helpers::DATE_FORMAT},
+    templates::{PageId, auth::User, dashboard, filters, helpers::DATE_FORMAT},
     types::{
+        community::CommunityTopic,
         event::{
-            EventCategory, EventCfsLabel, EventFull, EventKindSummary, EventSummary, SessionKind,
-            SessionKindSummary,
+            EventApprovalStatus, EventCategory, EventCfsLabel, EventFull, EventKind,
+            EventKindSummary, EventSummary, SessionKind, SessionKindSummary,
         },
         group::GroupSponsor,
         pagination::{self, Pagination, ToRawQuery},
         payments::EventDiscountType,
         questionnaire::QuestionnaireQuestion,
+        site::SiteSettings,
     },
     validation::{
-        MAX_EVENT_LABELS_PER_EVENT, MAX_LEN_COUNTRY_CODE, MAX_LEN_DESCRIPTION,
+        MAX_EVENT_LABELS_PER_EVENT, MAX_ITEMS, MAX_LEN_COUNTRY_CODE, MAX_LEN_DESCRIPTION,
         MAX_LEN_DESCRIPTION_SHORT, MAX_LEN_ENTITY_NAME, MAX_LEN_L, MAX_LEN_S, MAX_LEN_TIMEZONE,
-        MAX_PAGINATION_LIMIT, MAX_RECURRING_ADDITIONAL_OCCURRENCES, email_vec, image_url_opt,
-        trimmed_non_empty, trimmed_non_empty_opt, trimmed_non_empty_tag_vec, trimmed_non_empty_vec,
-        valid_latitude, valid_longitude,
+        MAX_NO_SHOW_WAITLIST_THRESHOLD, MAX_PAGINATION_LIMIT, MAX_RECURRING_ADDITIONAL_OCCURRENCES,
+        MAX_WAITLIST_CONFIRMATION_HOURS, email_vec, image_url_opt, trimmed_non_empty,
+        trimmed_non_empty_opt, trimmed_non_empty_tag_vec, trimmed_non_empty_vec, valid_latitude,
+        valid_longitude, valid_streaming_url_opt,
     },
 };
 
@@ -45,6 +48,10 @@ pub(crate) struct AddPage {
     pub can_manage_events: bool,
     /// List of available event categories.
     pub categories: Vec<EventCategory>,
+    /// Event to copy the details from when the page is loaded.
+    pub copy_from_event_id: Option<Uuid>,
+    /// Event template to apply when the page is loaded.
+    pub event_template_id: Option<Uuid>,
     /// List of available event kinds.
     pub event_kinds: Vec<EventKindSummary>,
     /// Group identifier.
@@ -53,6 +60,8 @@ pub(crate) struct AddPage {
     pub meetings_enabled: bool,
     /// Maximum participants per meeting provider.
     pub meetings_max_participants: HashMap<MeetingProvider, i32>,
+    /// Meetings providers organizers can choose from.
+    pub meetings_providers: Vec<MeetingProvider>,
     /// Whether payments are globally enabled.
     pub payments_enabled: bool,
     /// Supported payment currency codes.
@@ -65,6 +74,24 @@ pub(crate) struct AddPage {
     pub sponsors: Vec<GroupSponsor>,
     /// List of available timezones.
     pub timezones: Vec<String>,
+    /// List of available topics.
+    pub topics: Vec<CommunityTopic>,
+}
+
+/// Page event hosts use to manage the events they host.
+#[derive(Debug, Clone, Template)]
+#[template(path = "dashboard/group/event_host.html")]
+pub(crate) struct HostPage {
+    /// Event hosted by the user.
+    pub event: EventSummary,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Current URL path.
+    pub path: String,
+    /// Global site settings.
+    pub site_settings: SiteSettings,
+    /// Authenticated user information.
+    pub user: User,
 }
 
 /// List events page template.
@@ -73,10 +100,14 @@ pub(crate) struct AddPage {
 pub(crate) struct ListPage {
     /// Whether the current user can manage events.
     pub can_manage_events: bool,
+    /// Event templates saved in the group.
+    pub event_templates: Vec<EventTemplate>,
     /// Group events split by upcoming and past ones.
     pub events: GroupEvents,
     /// Current events tab selection.
     pub events_tab: EventsTab,
+    /// Whether the group is archived and does not accept new events.
+    pub group_archived: bool,
     /// Pagination links for past events.
     pub past_navigation_links: pagination::NavigationLinks,
     /// Pagination links for upcoming events.
@@ -115,6 +146,8 @@ pub(crate) struct UpdatePage {
     pub meetings_enabled: bool,
     /// Maximum participants per meeting provider.
     pub meetings_max_participants: HashMap<MeetingProvider, i32>,
+    /// Meetings providers organizers can choose from.
+    pub meetings_providers: Vec<MeetingProvider>,
     /// Whether payments are globally enabled.
     pub payments_enabled: bool,
     /// Supported payment currency codes.
@@ -127,6 +160,8 @@ pub(crate) struct UpdatePage {
     pub sponsors: Vec<GroupSponsor>,
     /// List of available timezones.
     pub timezones: Vec<String>,
+    /// List of available topics.
+    pub topics: Vec<CommunityTopic>,
 }
 
 impl UpdatePage {
@@ -239,6 +274,12 @@ pub(crate) struct Event {
     /// Whether attendee requests require organizer approval.
     #[garde(skip)]
     pub attendee_approval_required: Option<bool>,
+    /// Whether checked-in attendees receive a certificate of attendance by email.
+    #[garde(skip)]
+    pub attendance_certificate_emails_enabled: Option<bool>,
+    /// Whether attendees can submit photos to the event gallery.
+    #[garde(skip)]
+    pub attendee_photos_enabled: Option<bool>,
     /// URL to the event banner image optimized for mobile devices.
     #[garde(custom(image_url_opt))]
     pub banner_mobile_url: Option<String>,
@@ -278,6 +319,9 @@ pub(crate) struct Event {
     /// Whether event reminder notifications are enabled.
     #[garde(skip)]
     pub event_reminder_enabled: Option<bool>,
+    /// Feedback survey URL sent to attendees after the event.
+    #[garde(url, length(max = MAX_LEN_L))]
+    pub feedback_url: Option<String>,
     /// User IDs of event hosts.
     #[garde(skip)]
     pub hosts: Option<Vec<Uuid>>,
@@ -318,21 +362,33 @@ pub(crate) struct Event {
     /// Whether a meeting has been requested for the event.
     #[garde(skip)]
     pub meeting_requested: Option<bool>,
+    /// Whether the automatic event meeting should be hosted as a webinar.
+    #[garde(skip)]
+    pub meeting_webinar: Option<bool>,
     /// Meetup.com URL.
     #[garde(url, length(max = MAX_LEN_L))]
     pub meetup_url: Option<String>,
+    /// Missed group events that send an attendee to the waitlist.
+    #[garde(range(min = 1, max = MAX_NO_SHOW_WAITLIST_THRESHOLD))]
+    pub no_show_waitlist_threshold: Option<i32>,
     /// Currency used for ticket purchases.
     #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_S))]
     pub payment_currency_code: Option<String>,
     /// Gallery of photo URLs.
     #[garde(custom(trimmed_non_empty_vec))]
     pub photos_urls: Option<Vec<String>>,
+    /// Date when the draft event will be published automatically.
+    #[garde(skip)]
+    pub publish_scheduled_at: Option<NaiveDateTime>,
     /// Number of additional occurrences to create for recurring events.
     #[garde(range(min = 1, max = MAX_RECURRING_ADDITIONAL_OCCURRENCES))]
     pub recurrence_additional_occurrences: Option<i32>,
     /// Recurrence pattern selected for new event creation.
     #[garde(skip)]
     pub recurrence_pattern: Option<EventRecurrencePattern>,
+    /// Name given to the linked event series for recurring events.
+    #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_ENTITY_NAME))]
+    pub recurrence_series_name: Option<String>,
     /// Registration end time.
     #[garde(skip)]
     pub registration_ends_at: Option<NaiveDateTime>,
@@ -357,6 +413,9 @@ pub(crate) struct Event {
     /// Event start time.
     #[garde(skip)]
     pub starts_at: Option<NaiveDateTime>,
+    /// URL of the event live stream (`YouTube`, Twitch or Vimeo).
+    #[garde(custom(valid_streaming_url_opt), length(max = MAX_LEN_L))]
+    pub streaming_url: Option<String>,
     /// Tags associated with the event.
     #[garde(custom(trimmed_non_empty_tag_vec))]
     pub tags: Option<Vec<String>>,
@@ -369,6 +428,9 @@ pub(crate) struct Event {
     /// Whether the ticket types section was submitted.
     #[garde(skip)]
     pub ticket_types_present: Option<bool>,
+    /// Community topics assigned to the event.
+    #[garde(length(max = MAX_ITEMS))]
+    pub topic_ids: Option<Vec<Uuid>>,
     /// Venue address.
     #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_S))]
     pub venue_address: Option<String>,
@@ -390,6 +452,9 @@ pub(crate) struct Event {
     /// Venue zip code.
     #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_S))]
     pub venue_zip_code: Option<String>,
+    /// Hours a promoted waitlist user has to confirm the seat.
+    #[garde(range(min = 1, max = MAX_WAITLIST_CONFIRMATION_HOURS))]
+    pub waitlist_confirmation_hours: Option<i32>,
     /// Whether the event waiting list is enabled.
     #[garde(skip)]
     pub waitlist_enabled: Option<bool>,
@@ -422,6 +487,7 @@ impl Event {
         payload.remove("discount_codes_present");
         payload.remove("recurrence_additional_occurrences");
         payload.remove("recurrence_pattern");
+        payload.remove("recurrence_series_name");
         payload.remove("registration_questions");
         payload.remove("registration_questions_present");
         payload.remove("ticket_types");
@@ -500,6 +566,41 @@ impl Event {
     }
 }
 
+/// Event overlapping in time with an event being saved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EventConflict {
+    /// Conflicting event identifier.
+    pub event_id: Uuid,
+    /// Name of the group organizing the conflicting event.
+    pub group_name: String,
+    /// Conflicting event name.
+    pub name: String,
+    /// Whether the conflicting event belongs to the same group.
+    pub same_group: bool,
+    /// Conflicting event start time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub starts_at: DateTime<Utc>,
+}
+
+/// Event template saved in the group to create new events from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EventTemplate {
+    /// Time the template was saved.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Event template identifier.
+    pub event_template_id: Uuid,
+    /// Template name.
+    pub name: String,
+    /// Number of sessions in the template agenda.
+    pub sessions_count: usize,
+
+    /// Username of the organizer who saved the template.
+    pub created_by_username: Option<String>,
+    /// Kind of the events created from the template.
+    pub kind: Option<EventKind>,
+}
+
 /// Filter parameters for events list pagination.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
@@ -679,6 +780,9 @@ pub(crate) struct Session {
     /// Session speakers.
     #[garde(dive)]
     pub speakers: Option<Vec<Speaker>>,
+    /// Track the session belongs to.
+    #[garde(custom(trimmed_non_empty_opt), length(max = MAX_LEN_S))]
+    pub track: Option<String>,
 }
 
 /// Speaker selection with optional featured flag.
@@ -255,6 +255,8 @@ pub(crate) struct Session {
     pub speakers: Vec<ContextPerson>,
     /// Session time label.
     pub time_label: String,
+    /// Session track label.
+    pub track: Option<String>,
 }
 
 impl Session {
@@ -370,6 +372,8 @@ pub(crate) struct InputSession {
     pub speakers: Option<Vec<InputSessionSpeaker>>,
     /// Session start time.
     pub starts_at: Option<String>,
+    /// Session track.
+    pub track: Option<String>,
 }
 
 /// Tolerant preview-only session speaker payload.
@@ -654,6 +658,7 @@ fn build_sessions(
                     input.ends_at.as_deref(),
                     timezone,
                 ),
+                track: normalize_text(input.track),
             })
         })
         .collect()
@@ -715,6 +720,7 @@ fn session_has_content(input: &InputSession, context: &ContextSession) -> bool {
         input.starts_at.as_deref(),
         input.ends_at.as_deref(),
         input.location.as_deref(),
+        input.track.as_deref(),
         context.name.as_deref(),
         context.kind_label.as_deref(),
     ])
@@ -0,0 +1,65 @@
+//! Templates for curating the group gallery in the dashboard.
+
+use askama::Template;
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    types::group::{GroupGalleryPhoto, GroupGalleryPhotoSource},
+    validation::{image_url, image_url_opt, image_url_vec},
+};
+
+// Pages templates.
+
+/// List gallery photos page template for a group.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/group/gallery_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage the group settings.
+    pub can_manage_settings: bool,
+    /// Photos of the group gallery, including the hidden ones.
+    pub photos: Vec<GroupGalleryPhoto>,
+    /// Photos uploaded by the organizers to the group.
+    pub photos_urls: Vec<String>,
+}
+
+impl ListPage {
+    /// Returns whether any of the photos is used as the gallery cover.
+    fn has_cover(&self) -> bool {
+        self.photos.iter().any(|photo| photo.cover)
+    }
+
+    /// Returns the number of photos visible on the group page.
+    fn visible_photos_count(&self) -> usize {
+        self.photos.iter().filter(|photo| !photo.hidden).count()
+    }
+}
+
+// Types.
+
+/// Group gallery cover form data.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupGalleryCoverUpdate {
+    /// URL of the photo used as cover, or none to clear it.
+    #[garde(custom(image_url_opt))]
+    pub url: Option<String>,
+}
+
+/// Group gallery photo form data, used to hide or show a photo.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupGalleryPhotoInput {
+    /// Photo URL.
+    #[garde(custom(image_url))]
+    pub url: String,
+}
+
+/// Group gallery photos uploaded by the organizers form data.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupGalleryPhotosUpdate {
+    /// Gallery of photo URLs.
+    #[garde(custom(image_url_vec))]
+    pub photos_urls: Option<Vec<String>>,
+}
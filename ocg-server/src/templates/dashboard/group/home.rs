@@ -11,7 +11,11 @@ use crate::{
         auth::User,
         dashboard::{
             audit,
-            group::{analytics, events, members, refunds, settings, sponsors, team},
+            group::{
+                analytics, announcements, discussions, events, gallery, members, refunds, settings,
+                sponsors, team,
+            },
+            notifications,
         },
         filters,
         helpers::user_initials,
@@ -79,12 +83,20 @@ impl Page {
 pub(crate) enum Content {
     /// Analytics page.
     Analytics(Box<analytics::Page>),
+    /// Announcements management page.
+    Announcements(announcements::ListPage),
+    /// Discussions moderation page.
+    Discussions(discussions::ListPage),
     /// Events management page.
     Events(Box<events::ListPage>),
+    /// Gallery curation page.
+    Gallery(gallery::ListPage),
     /// Audit logs page.
     Logs(audit::ListPage),
     /// Members list page.
     Members(members::ListPage),
+    /// Custom notifications delivery status page.
+    Notifications(notifications::ListPage),
     /// Refund operations page.
     Refunds(refunds::ListPage),
     /// Settings management page.
@@ -101,11 +113,26 @@ impl Content {
         matches!(self, Content::Analytics(_))
     }
 
+    /// Check if the content is the announcements page.
+    fn is_announcements(&self) -> bool {
+        matches!(self, Content::Announcements(_))
+    }
+
+    /// Check if the content is the discussions page.
+    fn is_discussions(&self) -> bool {
+        matches!(self, Content::Discussions(_))
+    }
+
     /// Check if the content is the events page.
     fn is_events(&self) -> bool {
         matches!(self, Content::Events(_))
     }
 
+    /// Check if the content is the gallery page.
+    fn is_gallery(&self) -> bool {
+        matches!(self, Content::Gallery(_))
+    }
+
     /// Check if the content is the logs page.
     fn is_logs(&self) -> bool {
         matches!(self, Content::Logs(_))
@@ -116,6 +143,11 @@ impl Content {
         matches!(self, Content::Members(_))
     }
 
+    /// Check if the content is the notifications page.
+    fn is_notifications(&self) -> bool {
+        matches!(self, Content::Notifications(_))
+    }
+
     /// Checks whether the content is the refunds page.
     fn is_refunds(&self) -> bool {
         matches!(self, Content::Refunds(_))
@@ -141,9 +173,13 @@ impl std::fmt::Display for Content {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Content::Analytics(template) => write!(f, "{}", template.render()?),
+            Content::Announcements(template) => write!(f, "{}", template.render()?),
+            Content::Discussions(template) => write!(f, "{}", template.render()?),
             Content::Events(template) => write!(f, "{}", template.render()?),
+            Content::Gallery(template) => write!(f, "{}", template.render()?),
             Content::Logs(template) => write!(f, "{}", template.render()?),
             Content::Members(template) => write!(f, "{}", template.render()?),
+            Content::Notifications(template) => write!(f, "{}", template.render()?),
             Content::Refunds(template) => write!(f, "{}", template.render()?),
             Content::Settings(template) => write!(f, "{}", template.render()?),
             Content::Sponsors(template) => write!(f, "{}", template.render()?),
@@ -162,12 +198,20 @@ pub(crate) enum Tab {
     /// Analytics tab (default).
     #[default]
     Analytics,
+    /// Announcements management tab.
+    Announcements,
+    /// Discussions moderation tab.
+    Discussions,
     /// Events management tab.
     Events,
+    /// Gallery curation tab.
+    Gallery,
     /// Audit logs tab.
     Logs,
     /// Members list tab.
     Members,
+    /// Custom notifications delivery status tab.
+    Notifications,
     /// Refund operations tab.
     Refunds,
     /// Settings management tab.
@@ -0,0 +1,53 @@
+//! Templates and types for the event check-in kiosk in the group dashboard.
+
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    templates::{PageId, auth::User},
+    types::{event::EventSummary, site::SiteSettings},
+};
+
+// Pages templates.
+
+/// Check-in kiosk page template.
+#[derive(Debug, Clone, Template)]
+#[template(path = "dashboard/group/check_in_kiosk.html")]
+pub(crate) struct Page {
+    /// Event attendees are checking in to.
+    pub event: EventSummary,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Current URL path.
+    pub path: String,
+    /// Global site settings.
+    pub site_settings: SiteSettings,
+    /// Authenticated user information.
+    pub user: User,
+}
+
+// Types.
+
+/// Attendee found when scanning a ticket at the check-in kiosk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct KioskAttendee {
+    /// Whether the attendee was already checked in.
+    pub checked_in: bool,
+    /// Attendee user identifier.
+    pub user_id: Uuid,
+    /// Attendee username.
+    pub username: String,
+
+    /// Attendee display name.
+    pub name: Option<String>,
+    /// Title of the ticket type purchased by the attendee.
+    pub ticket_title: Option<String>,
+}
+
+impl KioskAttendee {
+    /// Returns the name displayed in the kiosk for the attendee.
+    pub(crate) fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.username)
+    }
+}
@@ -5,11 +5,19 @@ use chrono::{DateTime, Utc};
 use garde::Validate;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use uuid::Uuid;
 
 use crate::{
     templates::{dashboard, helpers::user_initials},
-    types::pagination::{self, Pagination, ToRawQuery},
-    validation::MAX_PAGINATION_LIMIT,
+    types::{
+        event::EventSummary,
+        pagination::{self, Pagination, ToRawQuery},
+        questionnaire::{QuestionnaireAnswers, QuestionnaireQuestion},
+    },
+    validation::{
+        MAX_LEN_DESCRIPTION_SHORT, MAX_LEN_GROUP_MEMBER_ROLE, MAX_PAGINATION_LIMIT,
+        blank_string_as_none, trimmed_non_empty,
+    },
 };
 
 // Pages templates.
@@ -18,10 +26,22 @@ use crate::{
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "dashboard/group/members_list.html")]
 pub(crate) struct ListPage {
+    /// Users banned from the group.
+    pub bans: Vec<GroupBan>,
     /// Whether the current user can manage members.
     pub can_manage_members: bool,
     /// Default notification subject.
     pub default_notification_subject: String,
+    /// Upcoming and past group events that can be used to select the email audience.
+    pub events: Vec<EventSummary>,
+    /// Questions asked to users joining the group.
+    pub join_questions: Vec<QuestionnaireQuestion>,
+    /// Pending requests to join the group.
+    pub join_requests: Vec<GroupJoinRequest>,
+    /// Most active members during the last 12 months.
+    pub leaderboard: Vec<GroupMemberLeaderboardEntry>,
+    /// Distinct roles assigned to the group members.
+    pub member_roles: Vec<String>,
     /// List of members in the group.
     pub members: Vec<GroupMember>,
     /// Pagination navigation links.
@@ -37,17 +57,83 @@ pub(crate) struct ListPage {
 
 // Types.
 
+/// User banned from a group.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupBan {
+    /// Ban creation time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Reason provided by the organizer who banned the user.
+    pub reason: String,
+    /// Identifier of the banned user.
+    pub user_id: Uuid,
+    /// Username.
+    pub username: String,
+
+    /// Username of the organizer who banned the user.
+    pub banned_by_username: Option<String>,
+    /// Full name.
+    pub name: Option<String>,
+    /// URL to user's avatar.
+    pub photo_url: Option<String>,
+}
+
+/// Group ban form data.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupBanInput {
+    /// Reason for banning the user, only visible to the group and community teams.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_DESCRIPTION_SHORT))]
+    pub reason: String,
+}
+
+/// Pending request to join a private group.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupJoinRequest {
+    /// Request creation time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Identifier of the user requesting to join.
+    pub user_id: Uuid,
+    /// Username.
+    pub username: String,
+
+    /// Company the user represents.
+    pub company: Option<String>,
+    /// Answers to the group join questions sent with the request.
+    pub join_answers: Option<QuestionnaireAnswers>,
+    /// Full name.
+    pub name: Option<String>,
+    /// URL to user's avatar.
+    pub photo_url: Option<String>,
+    /// Title held by the user.
+    pub title: Option<String>,
+}
+
 /// Group member summary information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupMember {
     /// Membership creation time.
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
+    /// Whether the member's email address was reported as undeliverable.
+    pub email_undeliverable: bool,
+    /// Past group events the member was checked in to.
+    pub events_attended: i32,
+    /// Past group events the member registered for but did not attend.
+    pub events_missed: i32,
+    /// Identifier of the member user.
+    pub user_id: Uuid,
     /// Username.
     pub username: String,
 
     /// Company the user represents.
     pub company: Option<String>,
+    /// Answers to the group join questions.
+    pub join_answers: Option<QuestionnaireAnswers>,
+    /// Role assigned to the member by the group organizers.
+    pub member_role: Option<String>,
     /// Full name.
     pub name: Option<String>,
     /// URL to user's avatar.
@@ -56,6 +142,72 @@ pub struct GroupMember {
     pub title: Option<String>,
 }
 
+impl GroupMember {
+    /// Percentage of the tracked group events the member did not attend.
+    pub(crate) fn no_show_rate(&self) -> Option<i32> {
+        let tracked_events = self.events_attended + self.events_missed;
+        if tracked_events == 0 {
+            return None;
+        }
+        Some(self.events_missed * 100 / tracked_events)
+    }
+}
+
+/// Group member information included in the members CSV export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMemberExport {
+    /// Membership creation time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Email address of the member.
+    pub email: String,
+    /// Username.
+    pub username: String,
+
+    /// Answers to the group join questions.
+    pub join_answers: Option<QuestionnaireAnswers>,
+    /// Start time of the last group event the member was checked in to.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub last_attended_at: Option<DateTime<Utc>>,
+    /// Role assigned to the member by the group organizers.
+    pub member_role: Option<String>,
+    /// Full name.
+    pub name: Option<String>,
+}
+
+/// Member ranked in the group active members leaderboard.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMemberLeaderboardEntry {
+    /// Past group events the member was checked in to during the last 12 months.
+    pub check_ins: i32,
+    /// Position of the member in the leaderboard.
+    pub rank: i32,
+    /// Past group events the member registered for during the last 12 months.
+    pub rsvps: i32,
+    /// Identifier of the member user.
+    pub user_id: Uuid,
+    /// Username.
+    pub username: String,
+
+    /// Start time of the last group event the member was checked in to.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub last_attended_at: Option<DateTime<Utc>>,
+    /// Full name.
+    pub name: Option<String>,
+    /// URL to user's avatar.
+    pub photo_url: Option<String>,
+}
+
+/// Group member role form data.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupMemberRoleUpdate {
+    /// Role displayed for the member (the role is removed when not provided).
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(length(max = MAX_LEN_GROUP_MEMBER_ROLE))]
+    pub member_role: Option<String>,
+}
+
 /// Filter parameters for group members pagination.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
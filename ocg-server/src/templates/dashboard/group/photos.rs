@@ -0,0 +1,62 @@
+//! Templates and types for reviewing attendee event photos in the group dashboard.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    templates::helpers::user_initials,
+    types::{event::EventSummary, user::User},
+};
+
+// Pages templates.
+
+/// List photos page template for a group's event.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/group/photos_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage events.
+    pub can_manage_events: bool,
+    /// Event for which photos are listed.
+    pub event: EventSummary,
+    /// Photos submitted by attendees to the selected event.
+    pub photos: Vec<EventPhoto>,
+}
+
+// Types.
+
+/// Photo submitted by an attendee to an event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPhoto {
+    /// Submission time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Photo identifier.
+    pub event_photo_id: Uuid,
+    /// Review status of the photo.
+    pub status: EventPhotoStatus,
+    /// Photo URL.
+    pub url: String,
+    /// Public profile payload for the attendee who submitted the photo.
+    pub user: User,
+
+    /// Review completion time.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+/// Review status of a photo submitted by an attendee.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum EventPhotoStatus {
+    /// Photo approved and shown in the event gallery.
+    Approved,
+    /// Photo waiting for review.
+    Pending,
+    /// Photo rejected by the organizers.
+    Rejected,
+}
@@ -0,0 +1,38 @@
+//! Templates and types for managing event resources in the group dashboard.
+
+use askama::Template;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    templates::filters,
+    types::event::{EventResource, EventSummary},
+};
+
+// Pages templates.
+
+/// List resources page template for a group's event.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/group/resources_list.html")]
+pub(crate) struct ListPage {
+    /// Whether the current user can manage events.
+    pub can_manage_events: bool,
+    /// Event for which resources are listed.
+    pub event: EventSummary,
+    /// Files attached to the selected event.
+    pub resources: Vec<EventResource>,
+}
+
+// Types.
+
+/// File uploaded by an organizer to be attached to an event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct NewEventResource {
+    /// MIME type of the file.
+    pub content_type: String,
+    /// Name of the file in the storage provider.
+    pub file_name: String,
+    /// Original file name, displayed to attendees.
+    pub name: String,
+    /// File size in bytes.
+    pub size_bytes: i64,
+}
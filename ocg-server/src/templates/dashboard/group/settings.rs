@@ -1,9 +1,26 @@
 //! Templates for the group dashboard settings page.
 
+use std::fmt;
+
 use askama::Template;
+use chrono::{DateTime, Utc};
+use garde::Validate;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
 
-use crate::types::group::{GroupCategory, GroupFull, GroupParentOption, GroupRegion};
+use crate::{
+    templates::helpers::DATE_FORMAT_2,
+    types::{
+        community::CommunityTopic,
+        group::{GroupCategory, GroupFull, GroupParentOption, GroupRegion},
+        questionnaire::QuestionnaireQuestion,
+        user::UserSummary,
+    },
+    validation::{
+        MAX_LEN_L, MAX_LEN_M, MAX_LEN_NOTIFICATION_BODY, blank_string_as_none, trimmed_non_empty,
+    },
+};
 
 // Pages templates.
 
@@ -15,19 +32,166 @@ pub(crate) struct UpdatePage {
     pub can_manage_settings: bool,
     /// List of available group categories.
     pub categories: Vec<GroupCategory>,
+    /// Discord webhook used to post group notifications, if any.
+    pub discord_webhook_url: Option<String>,
     /// Group information.
     pub group: GroupFull,
     /// Whether this group has non-deleted child links.
     pub has_child_links: bool,
+    /// Whether the current user is the group owner.
+    pub is_owner: bool,
+    /// Group ownership information.
+    pub ownership: GroupOwnership,
     /// List of groups that can be selected as parents.
     pub parent_options: Vec<GroupParentOption>,
     /// Whether payments are globally enabled.
     pub payments_enabled: bool,
     /// List of available regions.
     pub regions: Vec<GroupRegion>,
+    /// List of available topics.
+    pub topics: Vec<CommunityTopic>,
+    /// Message included in the welcome email sent to new members, if any.
+    pub welcome_message: Option<String>,
+    /// Zoom account configured by the group, if any.
+    pub zoom_account: Option<GroupZoomAccount>,
+    /// Whether Zoom meetings are globally enabled.
+    pub zoom_enabled: bool,
+}
+
+impl UpdatePage {
+    /// Returns true when the group Zoom account has a webhook secret token stored.
+    pub(crate) fn zoom_webhook_secret_token_set(&self) -> bool {
+        self.zoom_account
+            .as_ref()
+            .is_some_and(|account| account.webhook_secret_token_set)
+    }
 }
 
 // Types.
 
 /// Group update form data (alias for the Group type from community dashboard).
 pub(crate) use crate::templates::dashboard::community::groups::Group as GroupUpdate;
+
+/// Group Discord webhook form data.
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupDiscordWebhookUpdate {
+    /// Discord webhook URL (the webhook is removed when not provided).
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(url, length(max = MAX_LEN_L))]
+    pub webhook_url: Option<String>,
+}
+
+impl fmt::Debug for GroupDiscordWebhookUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupDiscordWebhookUpdate")
+            .field(
+                "webhook_url",
+                &self.webhook_url.as_ref().map(|_| "[redacted]"),
+            )
+            .finish()
+    }
+}
+
+/// Group join questions form data.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupJoinQuestionsUpdate {
+    /// Questions asked to users joining the group (the questions are removed when not provided).
+    #[serde(default)]
+    #[garde(dive)]
+    pub join_questions: Vec<QuestionnaireQuestion>,
+}
+
+/// Group welcome message form data.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupWelcomeMessageUpdate {
+    /// Markdown message included in the welcome email (the message is removed when not provided).
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(length(max = MAX_LEN_NOTIFICATION_BODY))]
+    pub welcome_message: Option<String>,
+}
+
+/// Group ownership information, including the pending transfer if any.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct GroupOwnership {
+    /// Accepted team members the ownership can be transferred to.
+    #[serde(default)]
+    pub candidates: Vec<UserSummary>,
+    /// Current owner of the group.
+    pub owner: Option<UserSummary>,
+    /// Ownership transfer waiting for the new owner to accept it.
+    pub pending_transfer: Option<GroupOwnershipTransfer>,
+}
+
+/// Pending group ownership transfer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GroupOwnershipTransfer {
+    /// When the transfer was requested.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Team member the ownership is being transferred to.
+    pub user: UserSummary,
+}
+
+/// Group ownership transfer form data.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupOwnershipTransferInput {
+    /// Team member the ownership will be transferred to.
+    #[garde(skip)]
+    pub user_id: Uuid,
+}
+
+/// Zoom account configured by a group (the secrets are never exposed).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GroupZoomAccount {
+    /// Zoom account identifier.
+    pub account_id: String,
+    /// Zoom OAuth client identifier.
+    pub client_id: String,
+    /// Zoom user that hosts the group's meetings.
+    pub host_user: String,
+    /// Whether a webhook secret token is stored for the account.
+    pub webhook_secret_token_set: bool,
+}
+
+/// Group Zoom credentials form data.
+#[skip_serializing_none]
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupZoomCredentialsUpdate {
+    /// Zoom account identifier.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
+    pub account_id: String,
+    /// Zoom OAuth client identifier.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_M))]
+    pub client_id: String,
+    /// Zoom user that hosts the group's meetings.
+    #[garde(email, length(max = MAX_LEN_M))]
+    pub host_user: String,
+
+    /// Zoom OAuth client secret (the stored one is kept when not provided).
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(length(max = MAX_LEN_M))]
+    pub client_secret: Option<String>,
+    /// Zoom app webhook secret token (the stored one is kept when not provided).
+    #[serde(default, deserialize_with = "blank_string_as_none")]
+    #[garde(length(max = MAX_LEN_M))]
+    pub webhook_secret_token: Option<String>,
+}
+
+impl fmt::Debug for GroupZoomCredentialsUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupZoomCredentialsUpdate")
+            .field("account_id", &self.account_id)
+            .field("client_id", &self.client_id)
+            .field("host_user", &self.host_user)
+            .field(
+                "client_secret",
+                &self.client_secret.as_ref().map(|_| "[redacted]"),
+            )
+            .field(
+                "webhook_secret_token",
+                &self.webhook_secret_token.as_ref().map(|_| "[redacted]"),
+            )
+            .finish()
+    }
+}
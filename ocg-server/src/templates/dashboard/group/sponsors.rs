@@ -9,7 +9,7 @@ use uuid::Uuid;
 use crate::{
     templates::dashboard,
     types::{
-        group::GroupSponsor,
+        group::{GroupSponsor, GroupSponsorTier, SponsorLogoSize},
         pagination::{self, Pagination, ToRawQuery},
     },
     validation::{
@@ -27,6 +27,8 @@ pub(crate) struct AddPage {
     pub can_manage_sponsors: bool,
     /// Group identifier.
     pub group_id: Uuid,
+    /// Sponsor tiers defined by the group.
+    pub tiers: Vec<GroupSponsorTier>,
 }
 
 /// List sponsors page template.
@@ -39,6 +41,8 @@ pub(crate) struct ListPage {
     pub navigation_links: pagination::NavigationLinks,
     /// List of sponsors in the group.
     pub sponsors: Vec<GroupSponsor>,
+    /// Sponsor tiers defined by the group.
+    pub tiers: Vec<GroupSponsorTier>,
     /// Total number of sponsors in the group.
     pub total: usize,
 
@@ -58,6 +62,8 @@ pub(crate) struct UpdatePage {
     pub group_id: Uuid,
     /// Sponsor information to update.
     pub sponsor: GroupSponsor,
+    /// Sponsor tiers defined by the group.
+    pub tiers: Vec<GroupSponsorTier>,
 }
 
 // Types.
@@ -87,6 +93,22 @@ pub(crate) struct GroupSponsorsOutput {
     pub total: usize,
 }
 
+/// Sponsor tier input for create/update operations.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub(crate) struct GroupSponsorTierInput {
+    /// Size of the logos of the sponsors in this tier.
+    #[serde(default)]
+    #[garde(skip)]
+    pub logo_size: SponsorLogoSize,
+    /// Tier name.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_ENTITY_NAME))]
+    pub name: String,
+    /// Display order of the tier.
+    #[serde(default)]
+    #[garde(range(min = 0))]
+    pub order: i32,
+}
+
 /// Sponsor input for create/update operations.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -102,6 +124,9 @@ pub(crate) struct Sponsor {
     #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_ENTITY_NAME))]
     pub name: String,
 
+    /// Sponsor tier identifier.
+    #[garde(skip)]
+    pub group_sponsor_tier_id: Option<Uuid>,
     /// Sponsor website URL.
     #[garde(url, length(max = MAX_LEN_L))]
     pub website_url: Option<String>,
@@ -0,0 +1,155 @@
+//! Shared templates and types for dashboard custom notifications.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::{
+    templates::dashboard,
+    types::pagination::{self, Pagination, ToRawQuery},
+    validation::MAX_PAGINATION_LIMIT,
+};
+
+// Pages templates.
+
+/// Custom notifications list page template.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/notifications_list.html")]
+pub(crate) struct ListPage {
+    /// Documentation link for the page.
+    pub docs_href: String,
+    /// Pagination navigation links.
+    pub navigation_links: pagination::NavigationLinks,
+    /// Custom notifications to display.
+    pub notifications: Vec<CustomNotificationSummary>,
+    /// Description shown under the page title.
+    pub page_description: String,
+    /// Whether the group column is visible.
+    pub show_group: bool,
+    /// Total number of custom notifications.
+    pub total: usize,
+
+    /// Number of results per page.
+    pub limit: Option<usize>,
+    /// Pagination offset for results.
+    pub offset: Option<usize>,
+}
+
+impl ListPage {
+    /// Builds a page from the custom notifications output for the given scope.
+    pub(crate) fn new(
+        scope: NotificationsScope,
+        filters: &CustomNotificationsFilters,
+        output: CustomNotificationsOutput,
+        navigation_links: pagination::NavigationLinks,
+    ) -> Self {
+        Self {
+            docs_href: scope.docs_href().to_string(),
+            navigation_links,
+            notifications: output.notifications,
+            page_description: scope.page_description().to_string(),
+            show_group: scope.show_group(),
+            total: output.total,
+
+            limit: filters.limit,
+            offset: filters.offset,
+        }
+    }
+}
+
+// Types.
+
+/// Custom notification summary with the delivery status of its emails.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CustomNotificationSummary {
+    /// Number of emails reported as bounced by the email provider.
+    pub bounced: usize,
+    /// Time the custom notification was sent.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Unique custom notification identifier.
+    pub custom_notification_id: Uuid,
+    /// Number of emails that could not be delivered.
+    pub failed: usize,
+    /// Name of the group the notification was sent from.
+    pub group_name: String,
+    /// Number of emails waiting to be delivered.
+    pub queued: usize,
+    /// Number of emails delivered to the email provider.
+    pub sent: usize,
+    /// Notification subject.
+    pub subject: String,
+    /// Number of emails with an unknown delivery outcome.
+    pub unknown: usize,
+
+    /// Username of the organizer who sent the notification.
+    pub created_by_username: Option<String>,
+    /// Name of the event the notification was sent for, if any.
+    pub event_name: Option<String>,
+}
+
+/// Filter parameters for custom notifications pagination.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub(crate) struct CustomNotificationsFilters {
+    /// Number of results per page.
+    #[serde(default = "dashboard::default_limit")]
+    #[garde(range(min = 1, max = MAX_PAGINATION_LIMIT))]
+    pub limit: Option<usize>,
+    /// Pagination offset for results.
+    #[serde(default = "dashboard::default_offset")]
+    #[garde(skip)]
+    pub offset: Option<usize>,
+}
+
+crate::impl_pagination_and_raw_query!(CustomNotificationsFilters, limit, offset);
+
+/// Paginated custom notifications response data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CustomNotificationsOutput {
+    /// Custom notifications matching the filters.
+    pub notifications: Vec<CustomNotificationSummary>,
+    /// Total number of custom notifications before pagination.
+    pub total: usize,
+}
+
+/// Dashboard scope for a custom notifications screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum NotificationsScope {
+    /// Community dashboard notifications.
+    Community,
+    /// Group dashboard notifications.
+    Group,
+}
+
+impl NotificationsScope {
+    /// Returns the documentation link for the scope.
+    fn docs_href(self) -> &'static str {
+        match self {
+            NotificationsScope::Community => {
+                "/docs#/guides/community-dashboard?id=audit-notifications"
+            }
+            NotificationsScope::Group => "/docs#/guides/group-dashboard?id=audit-notifications",
+        }
+    }
+
+    /// Returns the human-readable description for the scope.
+    fn page_description(self) -> &'static str {
+        match self {
+            NotificationsScope::Community => {
+                "Check the delivery status of the emails sent by the community groups."
+            }
+            NotificationsScope::Group => {
+                "Check the delivery status of the emails sent to members and attendees."
+            }
+        }
+    }
+
+    /// Returns whether the group column should be shown.
+    fn show_group(self) -> bool {
+        matches!(self, NotificationsScope::Community)
+    }
+}
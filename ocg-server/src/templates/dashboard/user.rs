@@ -1,5 +1,6 @@
 //! Templates for the user dashboard.
 
+pub(crate) mod certificates;
 pub(crate) mod events;
 pub(crate) mod home;
 pub(crate) mod invitations;
@@ -0,0 +1,16 @@
+//! Templates for the user dashboard certificates tab.
+
+use askama::Template;
+use serde::{Deserialize, Serialize};
+
+use crate::services::certificates::AttendanceCertificate;
+
+// Pages templates.
+
+/// List page showing the certificates of attendance available to the user.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/user/certificates_list.html")]
+pub(crate) struct ListPage {
+    /// Certificates of attendance of the events the user checked in to.
+    pub certificates: Vec<AttendanceCertificate>,
+}
@@ -1,6 +1,7 @@
 //! Templates and types for user upcoming events.
 
 use askama::Template;
+use chrono::{DateTime, Utc};
 use garde::Validate;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -25,6 +26,8 @@ pub(crate) struct ListPage {
     pub events: Vec<UserEvent>,
     /// Pagination links for the events list.
     pub navigation_links: pagination::NavigationLinks,
+    /// Upcoming events suggested from the user interests.
+    pub suggested_events: Vec<EventSummary>,
     /// Total number of events before pagination.
     pub total: usize,
 
@@ -61,6 +64,9 @@ pub(crate) struct UserEvent {
     pub registration_answers: Option<QuestionnaireAnswers>,
     /// Checkout URL where the user can complete payment.
     pub resume_checkout_url: Option<String>,
+    /// Deadline to confirm a seat offered from the waitlist.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub waitlist_confirmation_expires_at: Option<DateTime<Utc>>,
 }
 
 impl UserEvent {
@@ -69,6 +75,7 @@ impl UserEvent {
         match self.attendance_status.as_ref()? {
             EventAttendanceStatus::PendingPayment => Some("Payment pending"),
             EventAttendanceStatus::RegistrationQuestionsPending => Some("Registration pending"),
+            EventAttendanceStatus::WaitlistConfirmationPending => Some("Confirmation pending"),
             _ => None,
         }
     }
@@ -79,6 +86,7 @@ impl UserEvent {
         let cancelable_status = match self.attendance_status.as_ref() {
             Some(EventAttendanceStatus::Attendee) => true,
             Some(EventAttendanceStatus::RegistrationQuestionsPending) => !self.event.is_ticketed(),
+            Some(EventAttendanceStatus::WaitlistConfirmationPending) => true,
             _ => false,
         };
         cancelable_status
@@ -90,6 +98,7 @@ impl UserEvent {
     pub(crate) fn can_complete_registration_questions(&self) -> bool {
         self.has_registration_questions_action()
             && (self.manually_invited
+                || self.waitlist_confirmation_pending()
                 || self.has_active_checkout_hold()
                 || self.event.registration_window_is_open())
     }
@@ -103,10 +112,16 @@ impl UserEvent {
                     EventAttendanceStatus::Attendee
                         | EventAttendanceStatus::PendingPayment
                         | EventAttendanceStatus::RegistrationQuestionsPending
+                        | EventAttendanceStatus::WaitlistConfirmationPending
                 )
             )
     }
 
+    /// Returns true when the user hosts the event.
+    pub(crate) fn is_host(&self) -> bool {
+        self.roles.contains(&UserEventRole::Host)
+    }
+
     /// Returns a disabled tooltip for blocked registration question actions.
     pub(crate) fn registration_questions_disabled_title(&self) -> Option<String> {
         if self.has_registration_questions_action() && !self.can_complete_registration_questions() {
@@ -131,7 +146,18 @@ impl UserEvent {
     pub(crate) fn registration_questions_pending(&self) -> bool {
         matches!(
             self.attendance_status.as_ref(),
-            Some(EventAttendanceStatus::RegistrationQuestionsPending)
+            Some(
+                EventAttendanceStatus::RegistrationQuestionsPending
+                    | EventAttendanceStatus::WaitlistConfirmationPending
+            )
+        )
+    }
+
+    /// Returns true when a seat offered from the waitlist awaits confirmation.
+    pub(crate) fn waitlist_confirmation_pending(&self) -> bool {
+        matches!(
+            self.attendance_status.as_ref(),
+            Some(EventAttendanceStatus::WaitlistConfirmationPending)
         )
     }
 
@@ -275,6 +301,26 @@ mod tests {
         assert!(user_event.registration_questions_disabled_title().is_none());
     }
 
+    #[test]
+    fn can_complete_registration_questions_allows_waitlist_confirmation_after_closed_window() {
+        let mut user_event = sample_user_event();
+        user_event.attendance_status = Some(EventAttendanceStatus::WaitlistConfirmationPending);
+        user_event.registration_questions = vec![sample_question()];
+        user_event.event.registration_ends_at = Some(Utc::now() - Duration::hours(1));
+        user_event.waitlist_confirmation_expires_at = Some(Utc::now() + Duration::hours(12));
+
+        assert!(user_event.can_cancel_attendance());
+        assert!(user_event.can_complete_registration_questions());
+        assert_eq!(
+            user_event.attendance_status_label(),
+            Some("Confirmation pending")
+        );
+        assert_eq!(
+            user_event.registration_questions_label(),
+            "Complete registration"
+        );
+    }
+
     #[test]
     fn can_complete_registration_questions_rejects_closed_window() {
         let mut user_event = sample_user_event();
@@ -285,6 +331,15 @@ mod tests {
         assert!(user_event.registration_questions_disabled_title().is_some());
     }
 
+    #[test]
+    fn is_host_detects_host_role() {
+        let mut user_event = sample_user_event();
+        assert!(!user_event.is_host());
+
+        user_event.roles.push(UserEventRole::Host);
+        assert!(user_event.is_host());
+    }
+
     // Helpers.
 
     /// Sample free-text registration question.
@@ -310,6 +365,7 @@ mod tests {
             attendance_status: Some(EventAttendanceStatus::Attendee),
             registration_answers: None,
             resume_checkout_url: None,
+            waitlist_confirmation_expires_at: None,
         }
     }
 }
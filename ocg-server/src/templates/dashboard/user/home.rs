@@ -10,7 +10,7 @@ use crate::{
         auth::{self, User},
         dashboard::{
             audit,
-            user::{events, invitations, session_proposals, submissions},
+            user::{certificates, events, invitations, session_proposals, submissions},
         },
         filters,
         helpers::user_initials,
@@ -41,6 +41,8 @@ pub(crate) struct Page {
 pub(crate) enum Content {
     /// User account page.
     Account(Box<auth::UpdateUserPage>),
+    /// Certificates of attendance page.
+    Certificates(certificates::ListPage),
     /// User upcoming events page.
     Events(events::ListPage),
     /// Invitations page.
@@ -59,6 +61,11 @@ impl Content {
         matches!(self, Content::Account(_))
     }
 
+    /// Check if the content is the certificates page.
+    fn is_certificates(&self) -> bool {
+        matches!(self, Content::Certificates(_))
+    }
+
     /// Check if the content is the events page.
     fn is_events(&self) -> bool {
         matches!(self, Content::Events(_))
@@ -89,6 +96,7 @@ impl std::fmt::Display for Content {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Content::Account(template) => write!(f, "{}", template.render()?),
+            Content::Certificates(template) => write!(f, "{}", template.render()?),
             Content::Events(template) => write!(f, "{}", template.render()?),
             Content::Invitations(template) => write!(f, "{}", template.render()?),
             Content::Logs(template) => write!(f, "{}", template.render()?),
@@ -108,6 +116,8 @@ pub(crate) enum Tab {
     /// User account tab (default).
     #[default]
     Account,
+    /// Certificates tab.
+    Certificates,
     /// Events tab.
     Events,
     /// Invitations tab.
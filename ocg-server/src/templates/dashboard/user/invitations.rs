@@ -22,6 +22,8 @@ pub(crate) struct ListPage {
     pub event_invitations: Vec<EventInvitation>,
     /// Pending group invitations for the current user.
     pub group_invitations: Vec<GroupTeamInvitation>,
+    /// Pending group ownership transfers for the current user.
+    pub ownership_transfers: Vec<GroupOwnershipTransferInvitation>,
 }
 
 // Types.
@@ -65,6 +67,21 @@ pub(crate) struct EventInvitation {
     pub starts_at: Option<DateTime<Utc>>,
 }
 
+/// Group ownership transfer summary information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GroupOwnershipTransferInvitation {
+    /// Community name (slug).
+    pub community_name: String,
+    /// Group identifier.
+    pub group_id: Uuid,
+    /// Group name.
+    pub group_name: String,
+
+    /// Transfer request time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
 /// Group team invitation summary information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct GroupTeamInvitation {
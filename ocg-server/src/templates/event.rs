@@ -2,6 +2,7 @@
 
 use askama::Template;
 use chrono::{DateTime, Utc};
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use uuid::Uuid;
@@ -11,10 +12,14 @@ use crate::{
         PageId,
         auth::User,
         filters,
+        group::PastEventCard,
         helpers::{self, user_initials},
     },
     types::{
-        event::{EventCfsLabel, EventFull, EventKind, EventSummary},
+        event::{
+            EventCfsLabel, EventDiscussion, EventFull, EventKind, EventSeries, EventSummary,
+            SpeakerProfile,
+        },
         site::SiteSettings,
         user::UserSummary,
     },
@@ -38,6 +43,9 @@ pub(crate) struct Page {
     pub site_settings: SiteSettings,
     /// Authenticated user information.
     pub user: User,
+
+    /// Linked event series the event belongs to.
+    pub series: Option<EventSeries>,
 }
 
 impl Page {
@@ -54,6 +62,13 @@ impl Page {
         )
     }
 
+    /// Returns the URL of the live stream player to embed on the event page.
+    pub(crate) fn live_streaming_player_url(&self) -> Option<String> {
+        let embed = self.event.live_streaming_embed()?;
+
+        Some(embed.player_url(&self.site_host()))
+    }
+
     /// Returns the Open Graph image URL for the event page.
     pub(crate) fn open_graph_image_url(&self) -> Option<String> {
         self.event
@@ -81,12 +96,160 @@ impl Page {
             self.event.name.clone()
         }
     }
+
+    /// Returns the URL of the recording player to embed on the event page.
+    pub(crate) fn recording_player_url(&self) -> Option<String> {
+        let embed = self.event.recording_embed()?;
+
+        Some(embed.player_url(&self.site_host()))
+    }
+
+    /// Returns the host of the site, used as parent of embedded players.
+    fn site_host(&self) -> String {
+        Url::parse(&self.base_url)
+            .ok()
+            .and_then(|url| url.host_str().map(ToString::to_string))
+            .unwrap_or_default()
+    }
+}
+
+/// Event series page template.
+#[derive(Debug, Clone, Template)]
+#[template(path = "event/series_page.html")]
+pub(crate) struct SeriesPage {
+    /// Configured public base URL.
+    pub base_url: String,
+    /// Whether the page can display the series attendance figures.
+    pub can_view_attendees: bool,
+    /// Event cards for the events in the series.
+    pub events: Vec<PastEventCard>,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Current URL path.
+    pub path: String,
+    /// Linked event series information.
+    pub series: EventSeries,
+    /// Global site settings.
+    pub site_settings: SiteSettings,
+    /// Authenticated user information.
+    pub user: User,
+}
+
+impl SeriesPage {
+    /// Returns the canonical public URL for the event series page.
+    pub(crate) fn canonical_url(&self) -> String {
+        helpers::absolute_url(
+            &self.base_url,
+            &format!(
+                "/{}/group/{}/series/{}",
+                self.series.community.name,
+                self.series.group.public_slug(),
+                self.series.event_series_id
+            ),
+        )
+    }
+
+    /// Returns the Open Graph image URL for the event series page.
+    pub(crate) fn open_graph_image_url(&self) -> Option<String> {
+        self.series
+            .group
+            .og_image_url
+            .as_deref()
+            .or(self.series.community.og_image_url.as_deref())
+            .map(|image_url| helpers::open_graph_image_url(&self.base_url, image_url))
+    }
+
+    /// Returns the preview description for the event series page.
+    pub(crate) fn preview_description(&self) -> String {
+        format!(
+            "{} in {} community. Open Community Groups, where Open Source communities thrive.",
+            self.series.group.name, self.series.community.display_name
+        )
+    }
+
+    /// Returns the preview title for the event series page.
+    pub(crate) fn preview_title(&self) -> String {
+        format!(
+            "{} - {}",
+            self.series.display_name(),
+            self.series.group.name
+        )
+    }
+}
+
+/// Speaker page template.
+#[derive(Debug, Clone, Template)]
+#[template(path = "event/speaker_page.html")]
+pub(crate) struct SpeakerPage {
+    /// Configured public base URL.
+    pub base_url: String,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Current URL path.
+    pub path: String,
+    /// Speaker profile with their talks.
+    pub profile: SpeakerProfile,
+    /// Global site settings.
+    pub site_settings: SiteSettings,
+    /// Authenticated user information.
+    pub user: User,
+}
+
+impl SpeakerPage {
+    /// Returns the canonical public URL for the speaker page.
+    pub(crate) fn canonical_url(&self) -> String {
+        helpers::absolute_url(
+            &self.base_url,
+            &format!(
+                "/{}/speaker/{}",
+                self.profile.community.name, self.profile.user.username
+            ),
+        )
+    }
+
+    /// Returns the Open Graph image URL for the speaker page.
+    pub(crate) fn open_graph_image_url(&self) -> Option<String> {
+        self.profile
+            .community
+            .og_image_url
+            .as_deref()
+            .map(|image_url| helpers::open_graph_image_url(&self.base_url, image_url))
+    }
+
+    /// Returns the preview description for the speaker page.
+    pub(crate) fn preview_description(&self) -> String {
+        format!(
+            "Talks by {} in {} community. Open Community Groups, where Open Source communities thrive.",
+            self.speaker_name(),
+            self.profile.community.display_name
+        )
+    }
+
+    /// Returns the preview title for the speaker page.
+    pub(crate) fn preview_title(&self) -> String {
+        format!(
+            "{} - {}",
+            self.speaker_name(),
+            self.profile.community.display_name
+        )
+    }
+
+    /// Returns the name of the speaker to display.
+    pub(crate) fn speaker_name(&self) -> &str {
+        self.profile
+            .user
+            .name
+            .as_deref()
+            .unwrap_or(&self.profile.user.username)
+    }
 }
 
 /// Event check-in page template.
 #[derive(Debug, Clone, Template)]
 #[template(path = "event/check_in_page.html")]
 pub(crate) struct CheckInPage {
+    /// Whether the kiosk check-in code provided has expired.
+    pub check_in_code_expired: bool,
     /// Whether the check-in window is open.
     pub check_in_window_open: bool,
     /// Event summary being checked into.
@@ -103,6 +266,11 @@ pub(crate) struct CheckInPage {
     pub user_is_attendee: bool,
     /// Whether the user is already checked in to the event.
     pub user_is_checked_in: bool,
+
+    /// Kiosk check-in code provided, forwarded when checking in.
+    pub check_in_code: Option<String>,
+    /// Ticket QR code (SVG) attendees can show to be checked in by staff.
+    pub ticket_qr_code: Option<String>,
 }
 
 /// Call for speakers modal template.
@@ -122,6 +290,31 @@ pub(crate) struct CfsModal {
     pub notice: Option<String>,
 }
 
+/// Event discussion section template.
+#[derive(Debug, Clone, Template)]
+#[template(path = "event/discussion.html")]
+pub(crate) struct DiscussionSection {
+    /// Discussion of the event.
+    pub discussion: EventDiscussion,
+    /// Event summary information.
+    pub event: EventSummary,
+    /// Authenticated user information.
+    pub user: User,
+}
+
+/// Attendee photos submission modal template.
+#[derive(Debug, Clone, Template)]
+#[template(path = "event/photos_modal.html")]
+pub(crate) struct PhotosModal {
+    /// Event summary information.
+    pub event: EventSummary,
+    /// Authenticated user information.
+    pub user: User,
+
+    /// Notice message displayed after submissions.
+    pub notice: Option<String>,
+}
+
 /// Session proposal details for CFS modal.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -168,6 +361,29 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_live_streaming_player_url_uses_site_host_as_parent() {
+        let mut page = sample_page(Some(Utc::now()), chrono_tz::UTC);
+        page.event.ends_at = Some(Utc::now() + chrono::Duration::hours(1));
+        page.event.kind = EventKind::Virtual;
+        page.event.streaming_url = Some("https://www.twitch.tv/cncf".to_string());
+
+        assert_eq!(
+            page.live_streaming_player_url().as_deref(),
+            Some("https://player.twitch.tv/?channel=cncf&parent=example.test")
+        );
+    }
+
+    #[test]
+    fn test_live_streaming_player_url_when_event_is_not_live() {
+        let mut page = sample_page(Some(Utc::now() + chrono::Duration::days(1)), chrono_tz::UTC);
+        page.event.ends_at = Some(Utc::now() + chrono::Duration::days(2));
+        page.event.kind = EventKind::Virtual;
+        page.event.streaming_url = Some("https://www.twitch.tv/cncf".to_string());
+
+        assert!(page.live_streaming_player_url().is_none());
+    }
+
     #[test]
     fn test_preview_title_uses_event_date_in_event_timezone() {
         let page = sample_page(
@@ -195,6 +411,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_recording_player_url_uses_site_host_as_parent() {
+        let mut page = sample_page(Some(Utc::now() - chrono::Duration::days(1)), chrono_tz::UTC);
+        page.event.meeting_recording_public_url =
+            Some("https://www.twitch.tv/videos/2001234567".to_string());
+
+        assert_eq!(
+            page.recording_player_url().as_deref(),
+            Some("https://player.twitch.tv/?video=v2001234567&parent=example.test")
+        );
+    }
+
+    #[test]
+    fn test_recording_player_url_when_recording_is_not_embeddable() {
+        let mut page = sample_page(Some(Utc::now() - chrono::Duration::days(1)), chrono_tz::UTC);
+        page.event.meeting_recording_public_url =
+            Some("https://zoom.us/rec/share/abc123".to_string());
+
+        assert!(page.recording_player_url().is_none());
+    }
+
     // Helpers.
 
     fn sample_page(starts_at: Option<DateTime<Utc>>, timezone: Tz) -> Page {
@@ -218,6 +455,7 @@ mod tests {
             path: "/test-community/group/test-group/event/test-event".to_string(),
             site_settings: SiteSettings::default(),
             user: User::default(),
+            series: None,
         }
     }
 }
@@ -43,6 +43,20 @@ pub(crate) fn display_some_datetime_tz(
     })
 }
 
+/// Formats a size in bytes using the most appropriate unit.
+#[askama::filter_fn]
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn file_size(n: &i64, _: &dyn askama::Values) -> askama::Result<String> {
+    const KB: i64 = 1024;
+    const MB: i64 = 1024 * KB;
+
+    Ok(match *n {
+        n if n >= MB => format!("{:.1} MB", n as f64 / MB as f64),
+        n if n >= KB => format!("{:.1} KB", n as f64 / KB as f64),
+        n => format!("{n} B"),
+    })
+}
+
 /// Convert a markdown string to HTML using GitHub Flavored Markdown options.
 #[askama::filter_fn]
 pub(crate) fn md_to_html(s: &str, _: &dyn askama::Values) -> askama::Result<String> {
@@ -128,6 +142,21 @@ mod tests {
         assert_eq!(empty, "");
     }
 
+    #[test]
+    fn test_file_size() {
+        let values = askama::NO_VALUES;
+
+        assert_eq!(file_size::default().execute(&512, values).unwrap(), "512 B");
+        assert_eq!(
+            file_size::default().execute(&1_536, values).unwrap(),
+            "1.5 KB"
+        );
+        assert_eq!(
+            file_size::default().execute(&5_452_595, values).unwrap(),
+            "5.2 MB"
+        );
+    }
+
     #[test]
     fn test_md_to_html() {
         let values = askama::NO_VALUES;
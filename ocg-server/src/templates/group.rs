@@ -8,25 +8,57 @@ use serde::{Deserialize, Serialize};
 use crate::{
     templates::{
         PageId,
-        auth::User,
+        auth::{CaptchaWidget, User},
         filters,
         helpers::{self, user_initials},
     },
     types::{
         event::{EventKind, EventSummary},
-        group::GroupFull,
+        group::{GroupAnnouncement, GroupDiscussion, GroupFull, GroupSummary},
         site::SiteSettings,
     },
 };
 
 // Pages and sections templates.
 
+/// Group contact organizers section template.
+#[derive(Debug, Clone, Template)]
+#[template(path = "group/contact.html")]
+pub(crate) struct ContactSection {
+    /// CAPTCHA widget rendered in the contact form, when enabled.
+    pub captcha: Option<CaptchaWidget>,
+    /// Group summary information.
+    pub group: GroupSummary,
+    /// Whether the message was just sent to the organizers.
+    pub message_sent: bool,
+    /// Authenticated user information.
+    pub user: User,
+}
+
+/// Group discussion section template.
+#[derive(Debug, Clone, Template)]
+#[template(path = "group/discussion.html")]
+pub(crate) struct DiscussionSection {
+    /// Whether the user can view the group discussion.
+    pub can_view: bool,
+    /// Discussion of the group.
+    pub discussion: GroupDiscussion,
+    /// Group summary information.
+    pub group: GroupSummary,
+    /// Authenticated user information.
+    pub user: User,
+}
+
 /// Group page template.
 #[derive(Debug, Clone, Template)]
 #[template(path = "group/page.html")]
 pub(crate) struct Page {
+    /// Pinned announcements of the group.
+    pub announcements: Vec<GroupAnnouncement>,
     /// Configured public base URL.
     pub base_url: String,
+    /// Whether the page can display who takes part in the group.
+    pub can_view_members: bool,
     /// Detailed information about the group.
     pub group: GroupFull,
     /// Identifier for the current page.
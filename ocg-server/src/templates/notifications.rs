@@ -1,9 +1,15 @@
 //! Notifications templates.
 
 use askama::Template;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::types::{event::EventSummary, group::GroupSummary, site::Theme};
+use crate::{
+    i18n::Language,
+    services::certificates::AttendanceCertificate,
+    templates::filters,
+    types::{event::EventSummary, group::GroupSummary, site::Theme},
+};
 
 // Emails templates.
 
@@ -24,6 +30,24 @@ pub(crate) struct CfsSubmissionUpdated {
     pub action_required_message: Option<String>,
 }
 
+/// Template for community newsletter notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/community_newsletter.html")]
+pub(crate) struct CommunityNewsletter {
+    /// Newsletter issue body in markdown.
+    pub body: String,
+    /// Community display name.
+    pub community_display_name: String,
+    /// Link to the community page.
+    pub link: String,
+    /// Link to the newsletter issue in the community archive.
+    pub newsletter_link: String,
+    /// Newsletter issue subject.
+    pub subject: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
+}
+
 /// Template for community team invitation notification.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "notifications/community_team_invitation.html")]
@@ -36,6 +60,34 @@ pub(crate) struct CommunityTeamInvitation {
     pub theme: Theme,
 }
 
+/// Template for the monthly report of dormant groups notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/dormant_groups_report.html")]
+pub(crate) struct DormantGroupsReport {
+    /// Community display name.
+    pub community_display_name: String,
+    /// Months without events after which a group is considered dormant.
+    pub dormant_group_months: i32,
+    /// Dormant groups of the community.
+    pub groups: Vec<DormantGroupsReportItem>,
+    /// Link to the dormant groups in the community dashboard.
+    pub link: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
+}
+
+/// Template group item for the dormant groups report notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DormantGroupsReportItem {
+    /// When the group became dormant.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub dormant_since: DateTime<Utc>,
+    /// Link to the group page.
+    pub link: String,
+    /// Group name.
+    pub name: String,
+}
+
 /// Template for email verification notification.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "notifications/email_verification.html")]
@@ -44,6 +96,22 @@ pub(crate) struct EmailVerification {
     pub link: String,
     /// Theme configuration for the community.
     pub theme: Theme,
+
+    /// Language the email is rendered in.
+    #[serde(default)]
+    pub language: Language,
+}
+
+/// Template for event approved notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/event_approved.html")]
+pub(crate) struct EventApproved {
+    /// Event summary data.
+    pub event: EventSummary,
+    /// Link to the group dashboard events page.
+    pub link: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
 }
 
 /// Template for event attendance canceled notification.
@@ -60,6 +128,20 @@ pub(crate) struct EventAttendanceCanceled {
     pub theme: Theme,
 }
 
+/// Template for event attendance certificate notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/event_attendance_certificate.html")]
+pub(crate) struct EventAttendanceCertificate {
+    /// Certificate of attendance details.
+    pub certificate: AttendanceCertificate,
+    /// Event summary data.
+    pub event: EventSummary,
+    /// Link to the user dashboard certificates page.
+    pub link: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
+}
+
 /// Template for event canceled notification.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "notifications/event_canceled.html")]
@@ -89,6 +171,24 @@ pub(crate) struct EventCustom {
     pub theme: Theme,
 }
 
+/// Template for event follow-up notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/event_follow_up.html")]
+pub(crate) struct EventFollowUp {
+    /// Event summary data.
+    pub event: EventSummary,
+    /// Link to the event feedback survey.
+    pub feedback_link: String,
+    /// Link to the event page.
+    pub link: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
+
+    /// Link to the event recording, when it has been published.
+    #[serde(default)]
+    pub recording_link: Option<String>,
+}
+
 /// Template for event invitation notification.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "notifications/event_invitation.html")]
@@ -103,6 +203,18 @@ pub(crate) struct EventInvitation {
     pub theme: Theme,
 }
 
+/// Template for event invitation request rejected notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/event_invitation_request_rejected.html")]
+pub(crate) struct EventInvitationRequestRejected {
+    /// Event summary data.
+    pub event: EventSummary,
+    /// Link to the event page.
+    pub link: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
+}
+
 /// Template for event published notification.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "notifications/event_published.html")]
@@ -160,6 +272,22 @@ pub(crate) struct EventRefundRequested {
     pub theme: Theme,
 }
 
+/// Template for event rejected notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/event_rejected.html")]
+pub(crate) struct EventRejected {
+    /// Event summary data.
+    pub event: EventSummary,
+    /// Link to the group dashboard events page.
+    pub link: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
+
+    /// Reason provided by the community team.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
 /// Template for event reminder notification.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "notifications/event_reminder.html")]
@@ -176,6 +304,31 @@ pub(crate) struct EventReminder {
     /// Link to the user dashboard events page.
     #[serde(default)]
     pub dashboard_link: Option<String>,
+    /// Minutes before the event starts the reminder was scheduled for.
+    #[serde(default)]
+    pub reminder_offset_minutes: Option<u32>,
+}
+
+impl EventReminder {
+    /// Describe how long before the event starts the reminder was sent.
+    ///
+    /// Reminders enqueued before offsets were configurable were always sent
+    /// 24 hours in advance.
+    pub(crate) fn starts_in(&self) -> String {
+        let minutes = self.reminder_offset_minutes.unwrap_or(24 * 60);
+        let (value, unit) = if minutes % (24 * 60) == 0 && minutes > 24 * 60 {
+            (minutes / (24 * 60), "day")
+        } else if minutes % 60 == 0 {
+            (minutes / 60, "hour")
+        } else {
+            (minutes, "minute")
+        };
+        if value == 1 {
+            format!("1 {unit}")
+        } else {
+            format!("{value} {unit}s")
+        }
+    }
 }
 
 /// Template for event rescheduled notification.
@@ -188,6 +341,27 @@ pub(crate) struct EventRescheduled {
     pub link: String,
     /// Theme configuration for the community.
     pub theme: Theme,
+
+    /// Event location before the update, when it changed.
+    #[serde(default)]
+    pub previous_location: Option<String>,
+    /// Event start time before the update, when it changed.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub previous_starts_at: Option<DateTime<Utc>>,
+}
+
+impl EventRescheduled {
+    /// Build the email subject depending on what changed in the event.
+    ///
+    /// Notifications enqueued before changes were tracked were always sent
+    /// for date changes.
+    pub(crate) fn subject(&self) -> String {
+        if self.previous_starts_at.is_none() && self.previous_location.is_some() {
+            format!("Event location changed: {}", self.event.name)
+        } else {
+            format!("Event rescheduled: {}", self.event.name)
+        }
+    }
 }
 
 /// Template for aggregate event series canceled notification.
@@ -257,6 +431,9 @@ pub(crate) struct EventWaitlistPromoted {
     /// Theme configuration for the community.
     pub theme: Theme,
 
+    /// Deadline to confirm the seat, when the event requires confirmation.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub confirmation_expires_at: Option<DateTime<Utc>>,
     /// Link to the user dashboard events page.
     #[serde(default)]
     pub dashboard_link: Option<String>,
@@ -276,6 +453,72 @@ pub(crate) struct EventWelcome {
     /// Link to the user dashboard events page.
     #[serde(default)]
     pub dashboard_link: Option<String>,
+    /// Language the email is rendered in.
+    #[serde(default)]
+    pub language: Language,
+    /// Whether the attendee's ticket QR code is attached to the email.
+    #[serde(default)]
+    pub ticket_attached: bool,
+}
+
+impl EventWelcome {
+    /// Build the footer explaining why the email was received.
+    pub(crate) fn footer(&self) -> String {
+        self.language.tr_args(
+            "You received this email notification because you registered for {event}, an event from {group} in the {community} community. If this wasn't you, you can ignore this message.",
+            &[
+                ("event", self.event.name.as_str()),
+                ("group", self.event.group_name.as_str()),
+                ("community", self.event.community_display_name.as_str()),
+            ],
+        )
+    }
+
+    /// Build the introduction of the email, including HTML markup.
+    pub(crate) fn intro_html(&self) -> String {
+        self.language.tr_html(
+            "Thanks for registering for <strong>{event}</strong> with <strong>{group}</strong> on <strong>Open Community Groups</strong>.",
+            &[("event", self.event.name.as_str()), ("group", self.event.group_name.as_str())],
+        )
+    }
+
+    /// Build the preheader of the email.
+    pub(crate) fn preheader(&self) -> String {
+        self.language.tr_args(
+            "You are registered for a new event from {group}.",
+            &[("group", self.event.group_name.as_str())],
+        )
+    }
+
+    /// Build the title of the email.
+    pub(crate) fn title(&self) -> String {
+        self.language.tr_args(
+            "You are registered: {event}",
+            &[("event", self.event.name.as_str())],
+        )
+    }
+}
+
+/// Template for group contact message notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/group_contact_message.html")]
+pub(crate) struct GroupContactMessage {
+    /// Message body written by the sender.
+    pub body: String,
+    /// Group summary data.
+    pub group: GroupSummary,
+    /// Link to the group page.
+    pub link: String,
+    /// Email address organizers can reply to.
+    pub sender_email: String,
+    /// Display name of the user who sent the message.
+    pub sender_name: String,
+    /// Username of the user who sent the message.
+    pub sender_username: String,
+    /// Message subject written by the sender.
+    pub subject: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
 }
 
 /// Template for group custom notification.
@@ -317,6 +560,75 @@ pub(crate) struct GroupWelcome {
     pub link: String,
     /// Theme configuration for the community.
     pub theme: Theme,
+
+    /// Language the email is rendered in.
+    #[serde(default)]
+    pub language: Language,
+    /// Markdown message set by the group organizers for new members.
+    pub welcome_message: Option<String>,
+}
+
+impl GroupWelcome {
+    /// Build the footer explaining why the email was received.
+    pub(crate) fn footer(&self) -> String {
+        self.language.tr_args(
+            "You received this email notification because you joined {group} in the {community} community. If this wasn't you, you can ignore this message.",
+            &[
+                ("group", self.group.name.as_str()),
+                ("community", self.group.community_display_name.as_str()),
+            ],
+        )
+    }
+
+    /// Build the introduction of the email, including HTML markup.
+    pub(crate) fn intro_html(&self) -> String {
+        self.language.tr_html(
+            "Welcome to <strong>{group}</strong> on <strong>Open Community Groups</strong>!",
+            &[("group", self.group.name.as_str())],
+        )
+    }
+
+    /// Build the preheader of the email.
+    pub(crate) fn preheader(&self) -> String {
+        self.language.tr_args(
+            "You have joined {group}. Visit your group page.",
+            &[("group", self.group.name.as_str())],
+        )
+    }
+
+    /// Build the title of the email.
+    pub(crate) fn title(&self) -> String {
+        self.language
+            .tr_args("Welcome to {group}", &[("group", self.group.name.as_str())])
+    }
+}
+
+/// Template for magic link notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/magic_link.html")]
+pub(crate) struct MagicLink {
+    /// Link the user can log in with.
+    pub link: String,
+    /// Theme configuration for the site.
+    pub theme: Theme,
+
+    /// Language the email is rendered in.
+    #[serde(default)]
+    pub language: Language,
+}
+
+/// Template for password reset notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/password_reset.html")]
+pub(crate) struct PasswordReset {
+    /// Link the user can reset their password with.
+    pub link: String,
+    /// Theme configuration for the site.
+    pub theme: Theme,
+
+    /// Language the email is rendered in.
+    #[serde(default)]
+    pub language: Language,
 }
 
 /// Template for session proposal co-speaker invitation notification.
@@ -358,3 +670,28 @@ pub(crate) struct SpeakerSeriesWelcome {
     /// Theme configuration for the community.
     pub theme: Theme,
 }
+
+/// Template for the weekly digest of upcoming events notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/weekly_digest.html")]
+pub(crate) struct WeeklyDigest {
+    /// Community display name for the events.
+    pub community_display_name: String,
+    /// Upcoming events of the user's groups.
+    pub events: Vec<WeeklyDigestItem>,
+    /// Link to the community page.
+    pub link: String,
+    /// Link to the user account settings, where the digest can be disabled.
+    pub preferences_link: String,
+    /// Theme configuration for the community.
+    pub theme: Theme,
+}
+
+/// Template event item for the weekly digest notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct WeeklyDigestItem {
+    /// Event summary data.
+    pub event: EventSummary,
+    /// Link to the event page.
+    pub link: String,
+}
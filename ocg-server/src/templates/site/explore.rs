@@ -214,6 +214,9 @@ pub(crate) struct FiltersOptions {
     /// Available geographic regions.
     #[serde(default)]
     pub region: Option<Vec<FilterOption>>,
+    /// Available community topics.
+    #[serde(default)]
+    pub topic: Option<Vec<FilterOption>>,
 }
 
 /// Individual filter option with display name and value.
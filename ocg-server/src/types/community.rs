@@ -6,8 +6,36 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::types::site::ThemeFontFamily;
+
 // Community types.
 
+/// Branding overrides applied to the emails sent on behalf of a community.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommunityEmailBranding {
+    /// Text displayed in the footer of the emails.
+    pub footer_text: Option<String>,
+    /// Name used as the sender of the emails.
+    pub from_name: Option<String>,
+    /// URL to the logo displayed at the top of the emails.
+    pub logo_url: Option<String>,
+    /// Primary color used in the emails, as a hex color.
+    pub primary_color: Option<String>,
+}
+
+/// Theme overrides applied to the pages of a community.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommunityTheme {
+    /// URL to the icon displayed in browser tabs and bookmarks.
+    pub favicon_url: Option<String>,
+    /// Font family used in the pages.
+    pub font_family: Option<ThemeFontFamily>,
+    /// URL to the logo image shown in the page header.
+    pub header_logo_url: Option<String>,
+    /// Primary color used in the pages, as a hex color.
+    pub primary_color: Option<String>,
+}
+
 /// Full community information.
 #[allow(clippy::struct_field_names)]
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -29,17 +57,27 @@ pub struct CommunityFull {
     pub description: String,
     /// Human-readable name shown in the UI (e.g., "CNCF").
     pub display_name: String,
+    /// Months without events after which a group is considered dormant.
+    pub dormant_group_months: i32,
+    /// Whether community admins receive a monthly report of the dormant groups.
+    pub dormant_groups_report_enabled: bool,
+    /// Whether group events require a community approval to be published.
+    pub event_approval_required: bool,
     /// Whether group team management is restricted to community roles.
     pub group_team_management_restricted: bool,
     /// URL to the logo image shown in the page header.
     pub logo_url: String,
     /// Unique identifier used in URLs and database references.
     pub name: String,
+    /// Whether signing in with a passkey is required to access the dashboard.
+    pub passkey_required: bool,
 
     /// Target URL when users click on the advertisement banner.
     pub ad_banner_link_url: Option<String>,
     /// URL to the advertisement banner image.
     pub ad_banner_url: Option<String>,
+    /// Email domains allowed to sign up from the community (any if empty).
+    pub allowed_email_domains: Option<Vec<String>>,
     /// Link to the community's Bluesky profile.
     pub bluesky_url: Option<String>,
     /// Additional custom links displayed in the community navigation.
@@ -60,6 +98,8 @@ pub struct CommunityFull {
     pub og_image_url: Option<String>,
     /// Collection of photo URLs for community galleries or slideshows.
     pub photos_urls: Option<Vec<String>>,
+    /// OIDC provider users must log in with (password login is disabled).
+    pub required_oidc_provider: Option<String>,
     /// Link to the community's Slack workspace.
     pub slack_url: Option<String>,
     /// Link to the community's Twitter/X profile.
@@ -86,6 +126,21 @@ pub enum CommunityRole {
     Viewer,
 }
 
+/// Newsletter issue published in the archive of a community.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityNewsletterArchiveIssue {
+    /// Unique identifier for the newsletter issue.
+    pub community_newsletter_issue_id: Uuid,
+    /// Time the issue was sent.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub sent_at: DateTime<Utc>,
+    /// Issue subject.
+    pub subject: String,
+
+    /// Issue body in markdown, only included when retrieving a single issue.
+    pub body: Option<String>,
+}
+
 /// Community role summary information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommunityRoleSummary {
@@ -118,3 +173,19 @@ pub struct CommunitySummary {
     /// URL to the Open Graph image used for link previews.
     pub og_image_url: Option<String>,
 }
+
+/// Topic defined by a community to classify its groups and events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommunityTopic {
+    /// Display name of the topic.
+    pub name: String,
+    /// URL-friendly normalized name.
+    pub normalized_name: String,
+    /// Unique identifier for the topic.
+    pub topic_id: Uuid,
+
+    /// Number of events currently tagged with this topic.
+    pub events_count: Option<usize>,
+    /// Number of groups currently tagged with this topic.
+    pub groups_count: Option<usize>,
+}
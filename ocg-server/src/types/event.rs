@@ -1,19 +1,20 @@
 //! Event type definitions.
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use chrono::{DateTime, Duration, NaiveDate, Utc};
 use chrono_tz::Tz;
 use garde::Validate;
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use uuid::Uuid;
 
 use crate::{
-    services::meetings::MeetingProvider,
+    services::meetings::{MeetingProvider, MeetingProviderStatus},
     types::{
-        community::CommunitySummary,
-        group::GroupSummary,
+        community::{CommunitySummary, CommunityTopic},
+        group::{GroupSummary, SponsorLogoSize},
         location::{LocationParts, build_location},
         payments::{
             EventDiscountCode, EventRefundRequestStatus, EventTicketType, format_amount_minor,
@@ -82,6 +83,8 @@ pub struct EventSummary {
     #[serde(default)]
     pub waitlist_enabled: bool,
 
+    /// Community approval status of the event, when it was sent for approval.
+    pub approval_status: Option<EventApprovalStatus>,
     /// Maximum capacity for the event.
     pub capacity: Option<i32>,
     /// Display name for the user who created the event, in dashboard views.
@@ -128,6 +131,8 @@ pub struct EventSummary {
     pub starts_at: Option<DateTime<Utc>>,
     /// Ticket types available for the event.
     pub ticket_types: Option<Vec<EventTicketType>>,
+    /// Community topics assigned to the event.
+    pub topics: Option<Vec<CommunityTopic>>,
     /// Street address of the venue.
     pub venue_address: Option<String>,
     /// City where the event venue is located (for in-person events).
@@ -140,6 +145,8 @@ pub struct EventSummary {
     pub venue_name: Option<String>,
     /// State or province where the venue is located.
     pub venue_state: Option<String>,
+    /// Hours a promoted waitlist user has to confirm the seat, when required.
+    pub waitlist_confirmation_hours: Option<i32>,
     /// Venue zip code.
     pub zip_code: Option<String>,
 }
@@ -254,12 +261,18 @@ impl EventSummary {
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EventFull {
+    /// Whether checked-in attendees receive a certificate of attendance by email.
+    #[serde(default)]
+    pub attendance_certificate_emails_enabled: bool,
     /// Whether attendance requests require organizer approval.
     #[serde(default)]
     pub attendee_approval_required: bool,
     /// Current number of event attendees.
     #[serde(default)]
     pub attendee_count: i32,
+    /// Whether attendees can submit photos to the event gallery.
+    #[serde(default)]
+    pub attendee_photos_enabled: bool,
     /// Whether the event has been canceled.
     pub canceled: bool,
     /// Event category information.
@@ -322,6 +335,8 @@ pub struct EventFull {
     /// Whether joining the waiting list is enabled for the event.
     pub waitlist_enabled: bool,
 
+    /// URLs to the attendee photos approved by the organizers.
+    pub attendee_photos_urls: Option<Vec<String>>,
     /// URL to the event banner image optimized for mobile devices.
     pub banner_mobile_url: Option<String>,
     /// URL to the event banner image.
@@ -349,6 +364,8 @@ pub struct EventFull {
     pub event_reminder_enabled: Option<bool>,
     /// Linked event series identifier, when the event was created as recurring.
     pub event_series_id: Option<Uuid>,
+    /// Feedback survey URL sent to attendees after the event.
+    pub feedback_url: Option<String>,
     /// Latitude of the event's location.
     pub latitude: Option<f64>,
     /// Legacy event hosts.
@@ -369,10 +386,14 @@ pub struct EventFull {
     pub meeting_join_instructions: Option<String>,
     /// URL to join the meeting.
     pub meeting_join_url: Option<String>,
+    /// Number of participant joins reported by the meetings provider.
+    pub meeting_participants_joined_count: Option<i32>,
     /// Password required to join the event meeting.
     pub meeting_password: Option<String>,
     /// Desired meeting provider for this event.
     pub meeting_provider: Option<MeetingProvider>,
+    /// Lifecycle status reported by the meetings provider.
+    pub meeting_provider_status: Option<MeetingProviderStatus>,
     /// Public URL for the meeting recording.
     pub meeting_recording_public_url: Option<String>,
     /// Whether the meeting recording is publicly visible.
@@ -385,12 +406,19 @@ pub struct EventFull {
     pub meeting_recording_url: Option<String>,
     /// Whether the event requests a meeting.
     pub meeting_requested: Option<bool>,
+    /// Whether the automatic event meeting is hosted as a webinar.
+    pub meeting_webinar: Option<bool>,
     /// Meetup.com URL for the event.
     pub meetup_url: Option<String>,
+    /// Missed group events that send an attendee to the waitlist.
+    pub no_show_waitlist_threshold: Option<i32>,
     /// Currency used for event ticket purchases.
     pub payment_currency_code: Option<String>,
     /// URLs to event photos.
     pub photos_urls: Option<Vec<String>>,
+    /// When the draft event will be published automatically.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub publish_scheduled_at: Option<DateTime<Utc>>,
     /// When the event was published.
     #[serde(default, with = "chrono::serde::ts_seconds_option")]
     pub published_at: Option<DateTime<Utc>>,
@@ -404,9 +432,13 @@ pub struct EventFull {
     pub registration_starts_at: Option<DateTime<Utc>>,
     /// Remaining capacity after subtracting registered attendees.
     pub remaining_capacity: Option<i32>,
+    /// Files attached to the event by the organizers.
+    pub resources: Option<Vec<EventResource>>,
     /// Event start time in UTC.
     #[serde(default, with = "chrono::serde::ts_seconds_option")]
     pub starts_at: Option<DateTime<Utc>>,
+    /// URL of the event live stream, embedded on the event page while live.
+    pub streaming_url: Option<String>,
     /// Event tags for categorization.
     pub tags: Option<Vec<String>>,
     /// Ticket types available for the event.
@@ -425,9 +457,16 @@ pub struct EventFull {
     pub venue_state: Option<String>,
     /// Venue zip code.
     pub venue_zip_code: Option<String>,
+    /// Hours a promoted waitlist user has to confirm the seat, when required.
+    pub waitlist_confirmation_hours: Option<i32>,
 }
 
 impl EventFull {
+    /// Check if attendees can share photos of the event.
+    pub fn attendee_photos_are_open(&self) -> bool {
+        self.attendee_photos_enabled && !self.canceled && self.is_past()
+    }
+
     /// Check if call for speakers has closed.
     pub fn cfs_is_closed(&self) -> bool {
         if self.cfs_enabled.unwrap_or(false)
@@ -482,6 +521,13 @@ impl EventFull {
         has_sellable_ticket_types(self.ticket_types.as_deref())
     }
 
+    /// Check if the event has been assigned the topic provided.
+    pub fn has_topic(&self, topic_id: Uuid) -> bool {
+        self.topics
+            .as_ref()
+            .is_some_and(|topics| topics.iter().any(|topic| topic.topic_id == topic_id))
+    }
+
     /// Check if the event is currently live, including attendee access lead time.
     pub fn is_live(&self) -> bool {
         match (self.starts_at, self.ends_at) {
@@ -509,6 +555,17 @@ impl EventFull {
         has_ticket_types(self.ticket_types.as_deref())
     }
 
+    /// Returns the live stream to embed on the event page, if any.
+    ///
+    /// Streams are only embedded for virtual and hybrid events while they are
+    /// live.
+    pub fn live_streaming_embed(&self) -> Option<StreamingEmbed> {
+        if self.kind == EventKind::InPerson || self.canceled || !self.is_live() {
+            return None;
+        }
+        self.streaming_url.as_deref().and_then(StreamingEmbed::from_url)
+    }
+
     /// Build a display-friendly location string from available location data.
     pub fn location(&self, max_len: usize) -> Option<String> {
         let parts = LocationParts::new()
@@ -522,6 +579,20 @@ impl EventFull {
         build_location(&parts, max_len)
     }
 
+    /// Returns the player to embed for the published recording of the event.
+    ///
+    /// Recordings are only embedded once the event is over, and Twitch
+    /// channels are skipped as they only play the live broadcast.
+    pub fn recording_embed(&self) -> Option<StreamingEmbed> {
+        if !self.is_past() {
+            return None;
+        }
+        self.meeting_recording_public_url
+            .as_deref()
+            .and_then(StreamingEmbed::from_url)
+            .filter(|embed| !matches!(embed, StreamingEmbed::Twitch { .. }))
+    }
+
     /// Returns true when attendee registration is currently open.
     pub fn registration_window_is_open(&self) -> bool {
         registration_window_is_open(
@@ -564,6 +635,17 @@ impl EventFull {
             .unwrap_or_default()
     }
 
+    /// Returns the unique session tracks of the event, sorted by name.
+    pub fn session_tracks(&self) -> Vec<&str> {
+        self.sessions
+            .values()
+            .flatten()
+            .filter_map(|session| session.track.as_deref())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
     /// Collect all unique speaker user IDs (event-level + session-level).
     pub fn speakers_ids(&self) -> Vec<Uuid> {
         // Event-level speakers
@@ -628,6 +710,7 @@ impl From<&EventFull> for EventSummary {
             waitlist_count: event.waitlist_count,
             waitlist_enabled: event.waitlist_enabled,
 
+            approval_status: None,
             capacity: event.capacity,
             created_by_display_name: None,
             created_by_username: None,
@@ -655,6 +738,7 @@ impl From<&EventFull> for EventSummary {
             venue_country_name: event.venue_country_name.clone(),
             venue_name: event.venue_name.clone(),
             venue_state: event.venue_state.clone(),
+            waitlist_confirmation_hours: event.waitlist_confirmation_hours,
             zip_code: event.venue_zip_code.clone(),
         }
     }
@@ -662,6 +746,18 @@ impl From<&EventFull> for EventSummary {
 
 // Other related types.
 
+/// Community approval status of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EventApprovalStatus {
+    /// The event was approved and can be published.
+    Approved,
+    /// The event is waiting for a community admin review.
+    Pending,
+    /// The event was rejected by a community admin.
+    Rejected,
+}
+
 /// Attendance details for a user's relationship to an event.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventAttendanceInfo {
@@ -677,6 +773,8 @@ pub struct EventAttendanceInfo {
     pub purchase_amount_minor: Option<i64>,
     /// Refund request state associated with the user purchase.
     pub refund_request_status: Option<EventRefundRequestStatus>,
+    /// Reminder preference chosen by the attendee for this event.
+    pub reminder_preference: Option<EventReminderPreference>,
     /// Provider URL for resuming a pending checkout.
     pub resume_checkout_url: Option<String>,
 }
@@ -714,6 +812,8 @@ pub enum EventAttendanceStatus {
     Rejected,
     /// The user joined the waiting list.
     Waitlisted,
+    /// The user's seat is reserved until the promotion from the waitlist is confirmed.
+    WaitlistConfirmationPending,
 }
 
 /// Event category information.
@@ -747,6 +847,26 @@ pub struct EventCfsLabel {
     pub event_cfs_label_id: Option<Uuid>,
 }
 
+/// Comment posted in an event's discussion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventComment {
+    /// Comment content.
+    pub content: String,
+    /// Publication time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Comment identifier.
+    pub event_comment_id: Uuid,
+    /// Whether the author belongs to the group team.
+    pub organizer: bool,
+    /// Whether the comment has been pinned by the organizers.
+    pub pinned: bool,
+    /// Replies to the comment, pinned ones first.
+    pub replies: Vec<EventComment>,
+    /// Public profile of the comment author.
+    pub user: User,
+}
+
 /// Dashboard eligibility for deleting an event.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -759,6 +879,15 @@ pub enum EventDeleteEligibility {
     RefundsPending,
 }
 
+/// Discussion of an event.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventDiscussion {
+    /// Top level comments, pinned ones first.
+    pub comments: Vec<EventComment>,
+    /// Whether the discussion is locked to new comments.
+    pub locked: bool,
+}
+
 /// Status of an event invitation request.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
 #[serde(rename_all = "kebab-case")]
@@ -807,6 +936,114 @@ pub struct EventLeaveOutcome {
     pub promoted_user_ids: Vec<Uuid>,
 }
 
+/// When an attendee wants to be reminded about the events they attend.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+pub enum EventReminderPreference {
+    /// Remind one day before the event starts.
+    #[serde(rename = "1-day")]
+    #[strum(serialize = "1-day")]
+    OneDay,
+    /// Remind one hour before the event starts.
+    #[serde(rename = "1-hour")]
+    #[strum(serialize = "1-hour")]
+    OneHour,
+    /// Remind one week before the event starts.
+    #[serde(rename = "1-week")]
+    #[strum(serialize = "1-week")]
+    OneWeek,
+    /// Do not send any reminders.
+    #[serde(rename = "none")]
+    #[strum(serialize = "none")]
+    Disabled,
+}
+
+impl EventReminderPreference {
+    /// All reminder preferences, in the order they are offered to users.
+    pub const ALL: [EventReminderPreference; 4] = [
+        EventReminderPreference::OneWeek,
+        EventReminderPreference::OneDay,
+        EventReminderPreference::OneHour,
+        EventReminderPreference::Disabled,
+    ];
+
+    /// Returns the label used to display the preference.
+    pub fn label(self) -> &'static str {
+        match self {
+            EventReminderPreference::OneDay => "1 day before",
+            EventReminderPreference::OneHour => "1 hour before",
+            EventReminderPreference::OneWeek => "1 week before",
+            EventReminderPreference::Disabled => "No reminders",
+        }
+    }
+
+    /// Returns the value stored for the preference.
+    pub fn value(self) -> &'static str {
+        match self {
+            EventReminderPreference::OneDay => "1-day",
+            EventReminderPreference::OneHour => "1-hour",
+            EventReminderPreference::OneWeek => "1-week",
+            EventReminderPreference::Disabled => "none",
+        }
+    }
+}
+
+/// File attached to an event by the organizers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventResource {
+    /// MIME type of the file.
+    pub content_type: String,
+    /// Upload time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Resource identifier.
+    pub event_resource_id: Uuid,
+    /// Original file name, displayed to attendees.
+    pub name: String,
+    /// File size in bytes.
+    pub size_bytes: i64,
+}
+
+/// Details needed to download a file attached to an event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventResourceFile {
+    /// MIME type of the file.
+    pub content_type: String,
+    /// Name of the file in the storage provider.
+    pub file_name: String,
+    /// Original file name, used for the downloaded file.
+    pub name: String,
+}
+
+/// Linked event series with its published events.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSeries {
+    /// Confirmed attendees across all the events in the series.
+    pub attendee_count: i64,
+    /// Confirmed attendees checked in across all the events in the series.
+    pub checked_in_count: i64,
+    /// Community the series belongs to.
+    pub community: CommunitySummary,
+    /// Unique identifier for the event series.
+    pub event_series_id: Uuid,
+    /// Published events in the series, in chronological order.
+    pub events: Vec<EventSummary>,
+    /// Group hosting the series.
+    pub group: GroupSummary,
+
+    /// Name given to the series by the organizers.
+    pub name: Option<String>,
+}
+
+impl EventSeries {
+    /// Returns the name of the series to display.
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("Event series")
+    }
+}
+
 /// Event sponsor information.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -815,6 +1052,9 @@ pub struct EventSponsor {
     pub group_sponsor_id: Uuid,
     /// Sponsor level for this event.
     pub level: String,
+    /// Size of the sponsor logo, taken from the tier matching the level.
+    #[serde(default)]
+    pub logo_size: SponsorLogoSize,
     /// URL to sponsor logo.
     pub logo_url: String,
     /// Sponsor name.
@@ -873,10 +1113,14 @@ pub struct Session {
     pub meeting_join_instructions: Option<String>,
     /// URL to join the meeting.
     pub meeting_join_url: Option<String>,
+    /// Number of participant joins reported by the meetings provider.
+    pub meeting_participants_joined_count: Option<i32>,
     /// Password required to join the session meeting.
     pub meeting_password: Option<String>,
     /// Desired meeting provider for this session.
     pub meeting_provider: Option<MeetingProvider>,
+    /// Lifecycle status reported by the meetings provider.
+    pub meeting_provider_status: Option<MeetingProviderStatus>,
     /// Public URL for the meeting recording.
     pub meeting_recording_public_url: Option<String>,
     /// Whether the meeting recording is publicly visible.
@@ -887,6 +1131,8 @@ pub struct Session {
     pub meeting_recording_url: Option<String>,
     /// Whether the session requests a meeting.
     pub meeting_requested: Option<bool>,
+    /// Track the session belongs to.
+    pub track: Option<String>,
 }
 
 /// Categorization of session attendance modes.
@@ -924,6 +1170,121 @@ pub struct Speaker {
     pub user: User,
 }
 
+/// Public speaker profile with the talks given in a community's events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeakerProfile {
+    /// Community the talks belong to.
+    pub community: CommunitySummary,
+    /// Talks given by the speaker, most recent first.
+    pub talks: Vec<SpeakerTalk>,
+    /// Speaker's public profile information.
+    pub user: User,
+}
+
+/// Talk given by a speaker at an event.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeakerTalk {
+    /// Event where the talk was given.
+    pub event: EventSummary,
+
+    /// Name of the session, when the speaker presented a specific one.
+    pub session_name: Option<String>,
+}
+
+/// Live stream or recording player that can be embedded on the event page.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamingEmbed {
+    /// Twitch channel.
+    Twitch { channel: String },
+    /// Twitch video.
+    TwitchVideo { video_id: String },
+    /// Vimeo live event.
+    VimeoEvent { event_id: String },
+    /// Vimeo video.
+    VimeoVideo { video_id: String },
+    /// `YouTube` video or live stream.
+    YouTube { video_id: String },
+}
+
+impl StreamingEmbed {
+    /// Parses a `YouTube`, Twitch or Vimeo URL into the player to embed.
+    pub fn from_url(url: &str) -> Option<Self> {
+        let url = Url::parse(url.trim()).ok()?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return None;
+        }
+        let host = url.host_str()?;
+        let host = host.strip_prefix("www.").or(host.strip_prefix("m.")).unwrap_or(host);
+        let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+
+        let embed = match (host, segments.as_slice()) {
+            ("twitch.tv", [channel]) => Self::Twitch {
+                channel: (*channel).to_string(),
+            },
+            ("twitch.tv", ["videos", video_id]) => Self::TwitchVideo {
+                video_id: (*video_id).to_string(),
+            },
+            ("vimeo.com", ["event", event_id]) => Self::VimeoEvent {
+                event_id: (*event_id).to_string(),
+            },
+            ("vimeo.com", [video_id]) => Self::VimeoVideo {
+                video_id: (*video_id).to_string(),
+            },
+            ("youtu.be", [video_id]) | ("youtube.com", ["embed" | "live", video_id]) => {
+                Self::YouTube {
+                    video_id: (*video_id).to_string(),
+                }
+            }
+            ("youtube.com", ["watch"]) => Self::YouTube {
+                video_id: url.query_pairs().find(|(key, _)| key == "v")?.1.into_owned(),
+            },
+            _ => return None,
+        };
+
+        embed.has_valid_id().then_some(embed)
+    }
+
+    /// Returns the URL of the player, embedded in the site host provided.
+    pub fn player_url(&self, parent_host: &str) -> String {
+        match self {
+            Self::Twitch { channel } => {
+                format!("https://player.twitch.tv/?channel={channel}&parent={parent_host}")
+            }
+            Self::TwitchVideo { video_id } => {
+                format!("https://player.twitch.tv/?video=v{video_id}&parent={parent_host}")
+            }
+            Self::VimeoEvent { event_id } => format!("https://vimeo.com/event/{event_id}/embed"),
+            Self::VimeoVideo { video_id } => format!("https://player.vimeo.com/video/{video_id}"),
+            Self::YouTube { video_id } => {
+                format!("https://www.youtube-nocookie.com/embed/{video_id}")
+            }
+        }
+    }
+
+    /// Check if the stream identifier only uses the characters allowed by
+    /// the platform.
+    fn has_valid_id(&self) -> bool {
+        match self {
+            Self::Twitch { channel } => {
+                !channel.is_empty()
+                    && channel.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            }
+            Self::TwitchVideo { video_id: id }
+            | Self::VimeoEvent { event_id: id }
+            | Self::VimeoVideo { video_id: id } => {
+                !id.is_empty() && id.chars().all(|c| c.is_ascii_digit())
+            }
+            Self::YouTube { video_id } => {
+                !video_id.is_empty()
+                    && video_id
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            }
+        }
+    }
+}
+
 // Helpers.
 
 /// Returns a local datetime label for registration window copy.
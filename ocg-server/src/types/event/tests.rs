@@ -15,6 +15,7 @@ fn event_attendance_info_can_request_refund_allows_tbd_events() {
 
         purchase_amount_minor: Some(2_500),
         refund_request_status: None,
+        reminder_preference: None,
         resume_checkout_url: None,
     };
 
@@ -113,6 +114,40 @@ fn event_full_to_summary_maps_event_fields() {
     assert!(summary.waitlist_enabled);
 }
 
+#[test]
+fn event_full_attendee_photos_are_open_returns_false_when_canceled() {
+    let event = EventFull {
+        attendee_photos_enabled: true,
+        canceled: true,
+        ends_at: Some(Utc::now() - Duration::hours(1)),
+        starts_at: Some(Utc::now() - Duration::hours(2)),
+        ..Default::default()
+    };
+    assert!(!event.attendee_photos_are_open());
+}
+
+#[test]
+fn event_full_attendee_photos_are_open_returns_false_when_event_not_ended() {
+    let event = EventFull {
+        attendee_photos_enabled: true,
+        ends_at: Some(Utc::now() + Duration::hours(1)),
+        starts_at: Some(Utc::now() - Duration::hours(1)),
+        ..Default::default()
+    };
+    assert!(!event.attendee_photos_are_open());
+}
+
+#[test]
+fn event_full_attendee_photos_are_open_returns_true_when_enabled_and_past() {
+    let event = EventFull {
+        attendee_photos_enabled: true,
+        ends_at: Some(Utc::now() - Duration::hours(1)),
+        starts_at: Some(Utc::now() - Duration::hours(2)),
+        ..Default::default()
+    };
+    assert!(event.attendee_photos_are_open());
+}
+
 #[test]
 fn event_full_cfs_is_enabled_returns_false_when_flag_missing() {
     let event = EventFull {
@@ -310,6 +345,105 @@ fn event_full_is_past_returns_true_when_starts_at_is_in_past_and_no_ends_at() {
     assert!(event.is_past());
 }
 
+#[test]
+fn event_full_live_streaming_embed_returns_none_for_in_person_events() {
+    let event = EventFull {
+        ends_at: Some(Utc::now() + Duration::hours(1)),
+        kind: EventKind::InPerson,
+        starts_at: Some(Utc::now() - Duration::hours(1)),
+        streaming_url: Some("https://www.youtube.com/watch?v=abc123".to_string()),
+        ..Default::default()
+    };
+    assert!(event.live_streaming_embed().is_none());
+}
+
+#[test]
+fn event_full_live_streaming_embed_returns_none_when_event_not_live() {
+    let event = EventFull {
+        ends_at: Some(Utc::now() - Duration::hours(1)),
+        kind: EventKind::Virtual,
+        starts_at: Some(Utc::now() - Duration::hours(2)),
+        streaming_url: Some("https://www.youtube.com/watch?v=abc123".to_string()),
+        ..Default::default()
+    };
+    assert!(event.live_streaming_embed().is_none());
+}
+
+#[test]
+fn event_full_live_streaming_embed_returns_stream_when_event_is_live() {
+    let event = EventFull {
+        ends_at: Some(Utc::now() + Duration::hours(1)),
+        kind: EventKind::Hybrid,
+        starts_at: Some(Utc::now() - Duration::hours(1)),
+        streaming_url: Some("https://www.youtube.com/watch?v=abc123".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        event.live_streaming_embed(),
+        Some(StreamingEmbed::YouTube {
+            video_id: "abc123".to_string()
+        })
+    );
+}
+
+#[test]
+fn event_full_recording_embed_returns_none_for_twitch_channels() {
+    let event = EventFull {
+        meeting_recording_public_url: Some("https://www.twitch.tv/cncf".to_string()),
+        starts_at: Some(Utc::now() - Duration::hours(2)),
+        ..Default::default()
+    };
+    assert!(event.recording_embed().is_none());
+}
+
+#[test]
+fn event_full_recording_embed_returns_none_when_event_not_past() {
+    let event = EventFull {
+        meeting_recording_public_url: Some("https://vimeo.com/123456".to_string()),
+        starts_at: Some(Utc::now() + Duration::hours(2)),
+        ..Default::default()
+    };
+    assert!(event.recording_embed().is_none());
+}
+
+#[test]
+fn event_full_recording_embed_returns_recording_when_event_is_past() {
+    let event = EventFull {
+        meeting_recording_public_url: Some("https://vimeo.com/123456".to_string()),
+        starts_at: Some(Utc::now() - Duration::hours(2)),
+        ..Default::default()
+    };
+    assert_eq!(
+        event.recording_embed(),
+        Some(StreamingEmbed::VimeoVideo {
+            video_id: "123456".to_string()
+        })
+    );
+}
+
+#[test]
+fn event_full_session_tracks_returns_sorted_unique_tracks() {
+    let date = Utc::now().date_naive();
+    let session = |track: Option<&str>| Session {
+        starts_at: Utc::now(),
+        track: track.map(ToString::to_string),
+        ..Default::default()
+    };
+
+    let event = EventFull {
+        sessions: BTreeMap::from([
+            (date, vec![session(Some("Security")), session(None)]),
+            (
+                date + Duration::days(1),
+                vec![session(Some("Platform")), session(Some("Security"))],
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_eq!(event.session_tracks(), vec!["Platform", "Security"]);
+}
+
 #[test]
 fn event_full_speakers_ids_collects_both_event_and_session_level_speakers() {
     let event_speaker_id = Uuid::from_u128(1);
@@ -536,6 +670,61 @@ fn event_summary_formatted_ticket_price_badge_returns_free_and_up_when_mixed() {
     );
 }
 
+#[test]
+fn streaming_embed_from_url_parses_supported_platforms() {
+    let cases = [
+        (
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=10",
+            "https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ",
+        ),
+        (
+            "https://youtu.be/dQw4w9WgXcQ",
+            "https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ",
+        ),
+        (
+            "https://youtube.com/live/dQw4w9WgXcQ",
+            "https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ",
+        ),
+        (
+            "https://www.twitch.tv/cncf",
+            "https://player.twitch.tv/?channel=cncf&parent=example.test",
+        ),
+        (
+            "https://www.twitch.tv/videos/2001234567",
+            "https://player.twitch.tv/?video=v2001234567&parent=example.test",
+        ),
+        (
+            "https://vimeo.com/123456",
+            "https://player.vimeo.com/video/123456",
+        ),
+        (
+            "https://vimeo.com/event/987654",
+            "https://vimeo.com/event/987654/embed",
+        ),
+    ];
+
+    for (url, player_url) in cases {
+        let embed = StreamingEmbed::from_url(url).unwrap();
+        assert_eq!(embed.player_url("example.test"), player_url, "{url}");
+    }
+}
+
+#[test]
+fn streaming_embed_from_url_rejects_unsupported_urls() {
+    let urls = [
+        "not a url",
+        "ftp://youtube.com/live/dQw4w9WgXcQ",
+        "https://example.com/live/dQw4w9WgXcQ",
+        "https://www.twitch.tv/cncf/videos",
+        "https://www.youtube.com/watch",
+        "https://vimeo.com/channel",
+    ];
+
+    for url in urls {
+        assert!(StreamingEmbed::from_url(url).is_none(), "{url}");
+    }
+}
+
 // Helpers.
 
 /// Build a sample ticket type with specified properties for testing.
@@ -590,6 +779,7 @@ fn sample_event_summary(ticket_types: Vec<EventTicketType>) -> EventSummary {
         waitlist_count: 0,
         waitlist_enabled: false,
 
+        approval_status: None,
         capacity: None,
         created_by_display_name: None,
         created_by_username: None,
@@ -613,6 +803,7 @@ fn sample_event_summary(ticket_types: Vec<EventTicketType>) -> EventSummary {
         venue_country_name: None,
         venue_name: None,
         venue_state: None,
+        waitlist_confirmation_hours: None,
         zip_code: None,
     }
 }
@@ -8,9 +8,10 @@ use serde_with::skip_serializing_none;
 use uuid::Uuid;
 
 use crate::types::{
-    community::CommunitySummary,
+    community::{CommunitySummary, CommunityTopic},
     location::{LocationParts, build_location},
     payments::GroupPaymentRecipient,
+    questionnaire::QuestionnaireQuestion,
     user::User,
 };
 
@@ -63,6 +64,9 @@ pub struct GroupSummary {
     /// URL-friendly identifier for this group.
     pub slug: String,
 
+    /// When the group was archived, if it is archived.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub archived_at: Option<DateTime<Utc>>,
     /// URL to the group's banner image optimized for mobile devices.
     pub banner_mobile_url: Option<String>,
     /// URL to the group's banner image.
@@ -75,6 +79,9 @@ pub struct GroupSummary {
     pub country_name: Option<String>,
     /// Short group description text.
     pub description_short: Option<String>,
+    /// When the group became dormant, if it has not held events in a while.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub dormant_since: Option<DateTime<Utc>>,
     /// Latitude for map display.
     pub latitude: Option<f64>,
     /// Longitude for map display.
@@ -124,6 +131,9 @@ pub struct GroupFull {
     pub created_at: DateTime<Utc>,
     /// Unique identifier for the group.
     pub group_id: Uuid,
+    /// Questions asked to users joining the group.
+    #[serde(default)]
+    pub join_questions: Vec<QuestionnaireQuestion>,
     /// URL to the group logo.
     pub logo_url: String,
     /// Total number of group members.
@@ -132,6 +142,8 @@ pub struct GroupFull {
     pub name: String,
     /// List of group organizers.
     pub organizers: Vec<User>,
+    /// Whether joining requires an organizer approval and member content is hidden.
+    pub private: bool,
     /// URL slug of the group.
     pub slug: String,
     /// List of group sponsors.
@@ -140,6 +152,9 @@ pub struct GroupFull {
     #[serde(default)]
     pub subgroups: Vec<GroupSummary>,
 
+    /// When the group was archived, if it is archived.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub archived_at: Option<DateTime<Utc>>,
     /// URL to the group's banner image optimized for mobile devices.
     pub banner_mobile_url: Option<String>,
     /// Banner image URL for the group page.
@@ -190,6 +205,8 @@ pub struct GroupFull {
     pub state: Option<String>,
     /// Tags associated with the group.
     pub tags: Option<Vec<String>>,
+    /// Community topics assigned to the group.
+    pub topics: Option<Vec<CommunityTopic>>,
     /// Twitter profile URL.
     pub twitter_url: Option<String>,
     /// Group website URL.
@@ -201,6 +218,13 @@ pub struct GroupFull {
 }
 
 impl GroupFull {
+    /// Check if the group has been assigned the topic provided.
+    pub fn has_topic(&self, topic_id: Uuid) -> bool {
+        self.topics
+            .as_ref()
+            .is_some_and(|topics| topics.iter().any(|topic| topic.topic_id == topic_id))
+    }
+
     /// Build a display-friendly location string from available location data.
     pub fn location(&self, max_len: usize) -> Option<String> {
         let parts = LocationParts::new()
@@ -220,6 +244,22 @@ impl GroupFull {
 
 // Other related types.
 
+/// Announcement posted by the group organizers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupAnnouncement {
+    /// Announcement content.
+    pub content: String,
+    /// Publication time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Announcement identifier.
+    pub group_announcement_id: Uuid,
+    /// Whether the announcement is pinned on the group page.
+    pub pinned: bool,
+    /// Announcement title.
+    pub title: String,
+}
+
 /// Group category information.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GroupCategory {
@@ -237,6 +277,98 @@ pub struct GroupCategory {
     pub order: Option<i32>,
 }
 
+/// Discussion board of a group.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GroupDiscussion {
+    /// Discussion topics, pinned ones first and then the most recent.
+    pub topics: Vec<GroupDiscussionTopic>,
+}
+
+/// Reply posted to a group discussion topic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupDiscussionReply {
+    /// Reply content.
+    pub content: String,
+    /// Publication time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Reply identifier.
+    pub group_discussion_reply_id: Uuid,
+    /// Whether the author belongs to the group team.
+    pub organizer: bool,
+    /// Public profile of the reply author.
+    pub user: User,
+}
+
+/// Topic started in a group discussion board.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupDiscussionTopic {
+    /// Topic content.
+    pub content: String,
+    /// Publication time.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Topic identifier.
+    pub group_discussion_topic_id: Uuid,
+    /// Whether the topic is locked to new replies.
+    pub locked: bool,
+    /// Whether the author belongs to the group team.
+    pub organizer: bool,
+    /// Whether the topic has been pinned by the organizers.
+    pub pinned: bool,
+    /// Replies to the topic, oldest first.
+    pub replies: Vec<GroupDiscussionReply>,
+    /// Topic title.
+    pub title: String,
+    /// Public profile of the topic author.
+    pub user: User,
+}
+
+/// Photo displayed in the group gallery.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupGalleryPhoto {
+    /// Whether the photo is the cover of the gallery.
+    pub cover: bool,
+    /// Whether the photo has been hidden from the gallery by the organizers.
+    pub hidden: bool,
+    /// Where the photo comes from.
+    pub source: GroupGalleryPhotoSource,
+    /// Photo URL.
+    pub url: String,
+
+    /// Name of the event the photo was taken at.
+    pub event_name: Option<String>,
+}
+
+/// Source of a photo in the group gallery.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum GroupGalleryPhotoSource {
+    /// Photo added to an event of the group or shared by its attendees.
+    Event,
+    /// Photo uploaded by the organizers to the group.
+    Group,
+}
+
+/// Membership status of a user in a group.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum GroupMembershipStatus {
+    /// The user is not a member of the group.
+    None,
+    /// The user is a member of the group.
+    Member,
+    /// The user requested to join the group and is waiting for review.
+    PendingApproval,
+}
+
 /// Parent group selector option for dashboard forms.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GroupParentOption {
@@ -275,6 +407,8 @@ pub struct GroupRegion {
 pub enum GroupRole {
     /// Full group administrator.
     Admin,
+    /// Volunteer helping with attendee check-in.
+    CheckInVolunteer,
     /// Group event manager.
     EventsManager,
     /// Read-only group viewer.
@@ -300,11 +434,56 @@ pub struct GroupSponsor {
     pub featured: bool,
     /// Group sponsor identifier.
     pub group_sponsor_id: Uuid,
+    /// Size of the sponsor logo, taken from its tier.
+    #[serde(default)]
+    pub logo_size: SponsorLogoSize,
     /// URL to sponsor logo.
     pub logo_url: String,
     /// Sponsor name.
     pub name: String,
 
+    /// Sponsor tier identifier.
+    pub group_sponsor_tier_id: Option<Uuid>,
+    /// Sponsor tier name.
+    pub tier_name: Option<String>,
     /// Sponsor website URL.
     pub website_url: Option<String>,
 }
+
+/// Group sponsor tier, used to rank and size sponsors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupSponsorTier {
+    /// Group sponsor tier identifier.
+    pub group_sponsor_tier_id: Uuid,
+    /// Size of the logos of the sponsors in this tier.
+    pub logo_size: SponsorLogoSize,
+    /// Tier name.
+    pub name: String,
+    /// Display order of the tier.
+    pub order: i32,
+}
+
+/// Size of the sponsor logos displayed on group and event pages.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SponsorLogoSize {
+    /// Large logo.
+    Large,
+    /// Medium logo.
+    #[default]
+    Medium,
+    /// Small logo.
+    Small,
+}
@@ -165,8 +165,6 @@ pub struct EventDiscountCode {
 pub struct EventPurchaseSummary {
     /// Recorded purchase amount after discounts.
     pub amount_minor: i64,
-    /// Currency used for the purchase.
-    pub currency_code: String,
     /// Discount amount applied to the purchase.
     pub discount_amount_minor: i64,
     /// Purchase identifier.
@@ -181,6 +179,8 @@ pub struct EventPurchaseSummary {
     /// Time when the purchase was completed.
     #[serde(default, with = "chrono::serde::ts_seconds_option")]
     pub completed_at: Option<DateTime<Utc>>,
+    /// Currency used for the purchase, not set for free-only events.
+    pub currency_code: Option<String>,
     /// Discount code used for the purchase.
     pub discount_code: Option<String>,
     /// Time when the payment hold expires.
@@ -294,14 +294,14 @@ impl EventTicketType {
     }
 
     /// Returns the attendee-facing current price formatted for display.
-    pub fn formatted_current_price(&self, currency_code: &str) -> Option<String> {
+    pub fn formatted_current_price(&self, currency_code: Option<&str>) -> Option<String> {
         let amount_minor = self.current_amount_minor()?;
 
         if amount_minor == 0 {
             return Some("Free".to_string());
         }
 
-        Some(format_amount_minor(amount_minor, currency_code))
+        Some(format_amount_minor(amount_minor, currency_code?))
     }
 
     /// Returns true when attendees can currently select this ticket type.
@@ -335,11 +335,11 @@ pub struct PreparedEventCheckout {
     /// Prepared purchase summary for the attendee.
     #[serde(flatten)]
     pub purchase: EventPurchaseSummary,
-    /// Recipient account configured for the event's group.
-    pub recipient: GroupPaymentRecipient,
 
     /// Admin-managed group slug used in attendee-facing routes.
     pub group_slug_pretty: Option<String>,
+    /// Recipient account configured for the event's group.
+    pub recipient: Option<GroupPaymentRecipient>,
 }
 
 // Helpers.
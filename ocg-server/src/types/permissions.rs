@@ -37,6 +37,8 @@ impl PartialEq<CommunityPermission> for &CommunityPermission {
 /// Group-scoped permission identifiers.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum GroupPermission {
+    /// Permission to check in event attendees in a group.
+    CheckInWrite,
     /// Permission to manage events in a group.
     EventsWrite,
     /// Permission to manage group members.
@@ -55,6 +57,7 @@ impl GroupPermission {
     /// Returns the canonical string identifier used in SQL checks.
     pub(crate) const fn as_str(self) -> &'static str {
         match self {
+            Self::CheckInWrite => "group.check-in.write",
             Self::EventsWrite => "group.events.write",
             Self::MembersWrite => "group.members.write",
             Self::Read => "group.read",
@@ -63,6 +66,14 @@ impl GroupPermission {
             Self::TeamWrite => "group.team.write",
         }
     }
+
+    /// Returns whether the permission is granted to the hosts of an event.
+    ///
+    /// Hosts can edit their event, manage its attendees and run check-in, but
+    /// cannot access the rest of the group dashboard.
+    pub(crate) const fn granted_to_event_hosts(self) -> bool {
+        matches!(self, Self::CheckInWrite | Self::EventsWrite | Self::Read)
+    }
 }
 
 impl PartialEq<GroupPermission> for &GroupPermission {
@@ -55,6 +55,10 @@ pub(crate) struct SearchEventsFilters {
     #[serde(default)]
     #[garde(length(max = MAX_ITEMS), inner(length(max = MAX_LEN_M)))]
     pub region: Vec<String>,
+    /// Community topics to filter by.
+    #[serde(default)]
+    #[garde(length(max = MAX_ITEMS), inner(length(max = MAX_LEN_M)))]
+    pub topic: Vec<String>,
 
     /// Northeast latitude of bounding box for map view.
     #[garde(custom(valid_latitude))]
@@ -120,6 +124,7 @@ impl SearchEventsFilters {
         filters.group.retain(|g| !g.is_empty());
         filters.group_category.retain(|c| !c.is_empty());
         filters.region.retain(|r| !r.is_empty());
+        filters.topic.retain(|t| !t.is_empty());
 
         // Populate the latitude and longitude fields from the headers provided
         (filters.latitude, filters.longitude) = extract_location(headers);
@@ -236,6 +241,10 @@ pub(crate) struct SearchGroupsFilters {
     #[serde(default)]
     #[garde(length(max = MAX_ITEMS), inner(length(max = MAX_LEN_M)))]
     pub region: Vec<String>,
+    /// Community topics to filter by.
+    #[serde(default)]
+    #[garde(length(max = MAX_ITEMS), inner(length(max = MAX_LEN_M)))]
+    pub topic: Vec<String>,
 
     /// Northeast latitude of bounding box for map view.
     #[garde(custom(valid_latitude))]
@@ -252,6 +261,10 @@ pub(crate) struct SearchGroupsFilters {
     /// Maximum distance in meters from user's location.
     #[garde(skip)]
     pub distance: Option<f64>,
+    /// Whether to only include dormant groups in results.
+    #[serde(default, skip_deserializing)]
+    #[garde(skip)]
+    pub dormant: Option<bool>,
     /// Whether to include bounding box in results.
     #[garde(skip)]
     pub include_bbox: Option<bool>,
@@ -295,6 +308,7 @@ impl SearchGroupsFilters {
         // Clean up entries that are empty strings
         filters.group_category.retain(|c| !c.is_empty());
         filters.region.retain(|r| !r.is_empty());
+        filters.topic.retain(|t| !t.is_empty());
 
         // Populate the latitude and longitude fields from the headers provided
         (filters.latitude, filters.longitude) = extract_location(headers);
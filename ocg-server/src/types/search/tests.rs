@@ -12,6 +12,8 @@ fn test_events_filters_new_list_cleans_empty_entries() {
         "group_category[1]=rust",
         "region[0]=",
         "region[1]=europe",
+        "topic[0]=",
+        "topic[1]=kubernetes",
         "view_mode=list",
     ]
     .join("&");
@@ -24,6 +26,7 @@ fn test_events_filters_new_list_cleans_empty_entries() {
     assert_eq!(filters.event_category, vec!["conference".to_string()]);
     assert_eq!(filters.group_category, vec!["rust".to_string()]);
     assert_eq!(filters.region, vec!["europe".to_string()]);
+    assert_eq!(filters.topic, vec!["kubernetes".to_string()]);
     assert_eq!(filters.view_mode, Some(ViewMode::List));
 }
 
@@ -269,6 +272,8 @@ fn test_groups_filters_new_list_cleans_empty_entries() {
         "group_category[1]=rust",
         "region[0]=",
         "region[1]=europe",
+        "topic[0]=",
+        "topic[1]=kubernetes",
         "view_mode=list",
     ]
     .join("&");
@@ -280,6 +285,7 @@ fn test_groups_filters_new_list_cleans_empty_entries() {
     // Check filters match expected values
     assert_eq!(filters.group_category, vec!["rust".to_string()]);
     assert_eq!(filters.region, vec!["europe".to_string()]);
+    assert_eq!(filters.topic, vec!["kubernetes".to_string()]);
     assert_eq!(filters.view_mode, Some(ViewMode::List));
 }
 
@@ -5,6 +5,24 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::types::community::CommunityTheme;
+
+/// Mix applied to the primary color to build each palette intensity, as a
+/// percentage. Positive values mix it with white, negative ones with black.
+const PALETTE_MIX: [(u32, i32); 11] = [
+    (50, 95),
+    (100, 90),
+    (200, 75),
+    (300, 55),
+    (400, 30),
+    (500, 0),
+    (600, -15),
+    (700, -30),
+    (800, -45),
+    (900, -60),
+    (950, -75),
+];
+
 // Site types.
 
 /// Statistics for the site home page.
@@ -36,6 +54,8 @@ pub struct SiteSettings {
     pub copyright_notice: Option<String>,
     /// URL to the small icon displayed in browser tabs and bookmarks.
     pub favicon_url: Option<String>,
+    /// Font family used in the pages.
+    pub font_family: Option<ThemeFontFamily>,
     /// URL to the logo image shown in the page footer.
     pub footer_logo_url: Option<String>,
     /// URL to the logo image shown in the page header.
@@ -44,6 +64,27 @@ pub struct SiteSettings {
     pub og_image_url: Option<String>,
 }
 
+impl SiteSettings {
+    /// Applies the theme overrides of a community to the site settings.
+    pub fn apply_community_theme(&mut self, community_theme: &CommunityTheme) {
+        if let Some(favicon_url) = &community_theme.favicon_url {
+            self.favicon_url = Some(favicon_url.clone());
+        }
+        if let Some(font_family) = community_theme.font_family {
+            self.font_family = Some(font_family);
+        }
+        if let Some(header_logo_url) = &community_theme.header_logo_url {
+            self.header_logo_url = Some(header_logo_url.clone());
+        }
+        if let Some(primary_color) = &community_theme.primary_color
+            && let Some(palette) = build_palette(primary_color)
+        {
+            self.theme.palette = palette;
+            self.theme.primary_color.clone_from(primary_color);
+        }
+    }
+}
+
 // Other related types.
 
 /// Theme information used to customize the site layout.
@@ -54,7 +95,198 @@ pub struct Theme {
     pub palette: Palette,
     /// Primary theme color.
     pub primary_color: String,
+
+    /// Footer text displayed in emails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footer_text: Option<String>,
+    /// Logo displayed at the top of emails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+}
+
+/// Font families available to customize the pages of a community.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ThemeFontFamily {
+    /// Default Inter font.
+    Inter,
+    /// Monospaced fonts.
+    Monospace,
+    /// Serif fonts.
+    Serif,
+    /// Fonts of the user's operating system.
+    System,
+}
+
+impl ThemeFontFamily {
+    /// All font families, in the order they are offered to communities.
+    pub const ALL: [ThemeFontFamily; 4] = [
+        ThemeFontFamily::Inter,
+        ThemeFontFamily::System,
+        ThemeFontFamily::Serif,
+        ThemeFontFamily::Monospace,
+    ];
+
+    /// Returns the CSS font stack for the font family.
+    pub fn css_value(self) -> &'static str {
+        match self {
+            ThemeFontFamily::Inter => {
+                r#""Inter", -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif"#
+            }
+            ThemeFontFamily::Monospace => {
+                r#"ui-monospace, SFMono-Regular, Menlo, Consolas, "Liberation Mono", monospace"#
+            }
+            ThemeFontFamily::Serif => r#"Georgia, Cambria, "Times New Roman", Times, serif"#,
+            ThemeFontFamily::System => {
+                r#"system-ui, -apple-system, "Segoe UI", Roboto, Ubuntu, sans-serif"#
+            }
+        }
+    }
+
+    /// Returns the name of the font family displayed in the UI.
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeFontFamily::Inter => "Inter",
+            ThemeFontFamily::Monospace => "Monospace",
+            ThemeFontFamily::Serif => "Serif",
+            ThemeFontFamily::System => "System",
+        }
+    }
 }
 
-/// Color palette mapping intensity levels (50-900) to hex color values.
+/// Color palette mapping intensity levels (50-950) to hex color values.
 pub type Palette = BTreeMap<u32, String>;
+
+/// Builds a color palette from a hex color, using it as the 500 intensity.
+///
+/// Returns `None` when the color provided is not a valid hex color.
+pub fn build_palette(hex_color: &str) -> Option<Palette> {
+    let digits = hex_color.strip_prefix('#')?;
+    if digits.len() != 6 || !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u32::from_str_radix(digits, 16).ok()?;
+    let channels = [(value >> 16) & 0xFF, (value >> 8) & 0xFF, value & 0xFF];
+
+    let palette = PALETTE_MIX
+        .iter()
+        .map(|&(intensity, mix)| {
+            let [r, g, b] = channels.map(|channel| mix_channel(channel, mix));
+            (intensity, format!("#{r:02X}{g:02X}{b:02X}"))
+        })
+        .collect();
+
+    Some(palette)
+}
+
+/// Mixes a color channel with white (positive mix) or black (negative mix).
+fn mix_channel(channel: u32, mix: i32) -> u32 {
+    let (target, percentage) = if mix >= 0 {
+        (255, mix.unsigned_abs())
+    } else {
+        (0, mix.unsigned_abs())
+    };
+    (channel * (100 - percentage) + target * percentage + 50) / 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_community_theme() {
+        let mut site_settings = SiteSettings {
+            favicon_url: Some("https://example.com/site-favicon.ico".to_string()),
+            header_logo_url: Some("https://example.com/site-logo.png".to_string()),
+            theme: Theme {
+                primary_color: "#0066CC".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let community_theme = CommunityTheme {
+            favicon_url: Some("https://example.com/favicon.ico".to_string()),
+            font_family: Some(ThemeFontFamily::Serif),
+            header_logo_url: None,
+            primary_color: Some("#1B4F72".to_string()),
+        };
+
+        site_settings.apply_community_theme(&community_theme);
+
+        assert_eq!(
+            site_settings.favicon_url.as_deref(),
+            Some("https://example.com/favicon.ico")
+        );
+        assert_eq!(site_settings.font_family, Some(ThemeFontFamily::Serif));
+        assert_eq!(
+            site_settings.header_logo_url.as_deref(),
+            Some("https://example.com/site-logo.png")
+        );
+        assert_eq!(site_settings.theme.primary_color, "#1B4F72");
+        assert_eq!(
+            site_settings.theme.palette.get(&500).map(String::as_str),
+            Some("#1B4F72")
+        );
+    }
+
+    #[test]
+    fn test_apply_community_theme_empty() {
+        let mut site_settings = SiteSettings {
+            favicon_url: Some("https://example.com/site-favicon.ico".to_string()),
+            theme: Theme {
+                primary_color: "#0066CC".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        site_settings.apply_community_theme(&CommunityTheme::default());
+
+        assert_eq!(
+            site_settings.favicon_url.as_deref(),
+            Some("https://example.com/site-favicon.ico")
+        );
+        assert_eq!(site_settings.font_family, None);
+        assert_eq!(site_settings.theme.primary_color, "#0066CC");
+        assert!(site_settings.theme.palette.is_empty());
+    }
+
+    #[test]
+    fn test_build_palette() {
+        let palette = build_palette("#000000").unwrap();
+
+        assert_eq!(palette.len(), 11);
+        assert_eq!(palette.get(&50).map(String::as_str), Some("#F2F2F2"));
+        assert_eq!(palette.get(&500).map(String::as_str), Some("#000000"));
+        assert_eq!(palette.get(&950).map(String::as_str), Some("#000000"));
+    }
+
+    #[test]
+    fn test_build_palette_mixes_with_black_and_white() {
+        let palette = build_palette("#FF0000").unwrap();
+
+        assert_eq!(palette.get(&100).map(String::as_str), Some("#FFE6E6"));
+        assert_eq!(palette.get(&500).map(String::as_str), Some("#FF0000"));
+        assert_eq!(palette.get(&900).map(String::as_str), Some("#660000"));
+    }
+
+    #[test]
+    fn test_build_palette_invalid_color() {
+        assert!(build_palette("0066CC").is_none());
+        assert!(build_palette("#0066C").is_none());
+        assert!(build_palette("#+066CC").is_none());
+        assert!(build_palette("#GG66CC").is_none());
+    }
+
+    #[test]
+    fn test_theme_font_family_from_str() {
+        assert_eq!(
+            "system".parse::<ThemeFontFamily>().ok(),
+            Some(ThemeFontFamily::System)
+        );
+        assert!("comic-sans".parse::<ThemeFontFamily>().is_err());
+    }
+}
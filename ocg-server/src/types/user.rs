@@ -1,7 +1,11 @@
 //! Shared user types used across the application.
 
+use std::fmt;
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use strum::Display;
 use uuid::Uuid;
 
 /// Full user information.
@@ -65,16 +69,45 @@ pub(crate) struct UserSummary {
 pub(crate) struct UserProvider {
     /// GitHub metadata.
     pub github: Option<GitHubUserProvider>,
+    /// GitLab metadata.
+    pub gitlab: Option<GitLabUserProvider>,
+    /// Google metadata.
+    pub google: Option<GoogleUserProvider>,
     /// Linux Foundation SSO metadata.
     pub linuxfoundation: Option<LinuxFoundationUserProvider>,
 }
 
 impl UserProvider {
     /// Build provider metadata for a GitHub account.
-    pub(crate) fn from_github_username(username: String) -> Self {
+    pub(crate) fn from_github_account(id: i64, username: String) -> Self {
+        Self {
+            github: Some(GitHubUserProvider {
+                username,
+                id: Some(id),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Build provider metadata for a GitLab account.
+    pub(crate) fn from_gitlab_account(id: i64, username: String) -> Self {
+        Self {
+            gitlab: Some(GitLabUserProvider {
+                username,
+                id: Some(id),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Build provider metadata for a Google account.
+    pub(crate) fn from_google_account(id: String, username: String) -> Self {
         Self {
-            github: Some(GitHubUserProvider { username }),
-            linuxfoundation: None,
+            google: Some(GoogleUserProvider {
+                username,
+                id: Some(id),
+            }),
+            ..Default::default()
         }
     }
 
@@ -85,13 +118,13 @@ impl UserProvider {
         username: String,
     ) -> Self {
         Self {
-            github: None,
             linuxfoundation: Some(LinuxFoundationUserProvider {
                 username,
 
                 issuer: Some(issuer),
                 subject: Some(subject),
             }),
+            ..Default::default()
         }
     }
 
@@ -100,17 +133,60 @@ impl UserProvider {
         if let Some(github) = other.github {
             self.github = Some(github);
         }
+        if let Some(gitlab) = other.gitlab {
+            self.gitlab = Some(gitlab);
+        }
+        if let Some(google) = other.google {
+            self.google = Some(google);
+        }
         if let Some(linuxfoundation) = other.linuxfoundation {
             self.linuxfoundation = Some(linuxfoundation);
         }
     }
 }
 
+/// Reason an email address was reported as undeliverable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum EmailUndeliverableReason {
+    /// Messages to the address bounced permanently.
+    Bounce,
+    /// The recipient reported the messages as spam.
+    Complaint,
+}
+
 /// GitHub-specific user metadata.
+#[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub(crate) struct GitHubUserProvider {
     /// Username on GitHub.
     pub username: String,
+
+    /// Immutable identifier of the GitHub account.
+    pub id: Option<i64>,
+}
+
+/// GitLab-specific user metadata.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct GitLabUserProvider {
+    /// Username on GitLab.
+    pub username: String,
+
+    /// Immutable identifier of the GitLab account.
+    pub id: Option<i64>,
+}
+
+/// Google-specific user metadata.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct GoogleUserProvider {
+    /// Username derived from the Google account email.
+    pub username: String,
+
+    /// Immutable subject identifier of the Google account.
+    pub id: Option<String>,
 }
 
 /// Linux Foundation-specific user metadata.
@@ -125,3 +201,70 @@ pub(crate) struct LinuxFoundationUserProvider {
     /// OIDC subject for the Linux Foundation SSO account.
     pub subject: Option<String>,
 }
+
+/// Passkey registered by a user to sign in.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UserPasskey {
+    /// When the passkey was registered.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Name given to the passkey by the user.
+    pub name: String,
+    /// Unique identifier for the passkey.
+    pub user_passkey_id: Uuid,
+
+    /// When the passkey was last used to sign in.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// Phone number a user's SMS notifications are sent to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UserPhone {
+    /// Phone number in E.164 format.
+    pub number: String,
+    /// Whether the user opted in to receive notifications by SMS.
+    pub sms_notifications_enabled: bool,
+    /// Whether the user confirmed they own the phone number.
+    pub verified: bool,
+}
+
+/// Active session of a user, started when they logged in from a device.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UserSession {
+    /// When the session was started.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Whether this is the session used by the current request.
+    pub current: bool,
+    /// When the session was last used.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub last_seen_at: DateTime<Utc>,
+    /// Public identifier for the session, used to revoke it.
+    pub session_id: Uuid,
+
+    /// IP address the user logged in from.
+    pub ip_address: Option<String>,
+    /// User agent of the browser the user logged in with.
+    pub user_agent: Option<String>,
+}
+
+/// Outgoing webhook a user's notifications are posted to.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UserWebhook {
+    /// Secret used to sign the webhook requests.
+    pub secret: String,
+    /// URL the notifications are posted to.
+    pub url: String,
+}
+
+impl fmt::Debug for UserWebhook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserWebhook")
+            .field("secret", &"[redacted]")
+            .field("url", &self.url)
+            .finish()
+    }
+}
@@ -1,8 +1,26 @@
 //! Utility functions shared across modules.
 
-use chrono::{DateTime, Datelike, Timelike, Utc};
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    io::{self, Cursor},
+};
+
+use anyhow::{Result, anyhow};
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, Offset as _, SecondsFormat, TimeDelta,
+    TimeZone as _, Timelike, Utc,
+};
+use chrono_tz::{OffsetComponents as _, OffsetName as _};
 use icalendar::{Calendar, Component as _, Event, EventLike as _, EventStatus, Property};
+use image::{ImageFormat, Luma};
+use qrcode::render::svg;
+use quick_xml::{
+    Writer,
+    events::{BytesDecl, BytesText, Event as XmlEvent},
+};
 use sha2::{Digest, Sha256};
+use uuid::Uuid;
 
 use crate::{services::notifications::Attachment, types::event::EventSummary};
 
@@ -13,8 +31,111 @@ pub(crate) fn base_url_without_trailing_slash(base_url: &str) -> &str {
 
 /// Build an iCalendar (ICS) attachment for the specified event.
 pub(crate) fn build_event_calendar_attachment(base_url: &str, event: &EventSummary) -> Attachment {
+    // Setup calendar and add ical event
+    let description = build_event_calendar_description(event, true);
+    let calendar_name = format!("{} - {}", event.group_name, event.name);
+    let mut calendar = Calendar::new();
+    calendar
+        .name(&calendar_name)
+        .description(&description)
+        .append_property(Property::new("X-WR-TIMEZONE", event.timezone.to_string()))
+        .push(build_event_calendar_entry(base_url, event, &description));
+
+    // Setup attachment and return it
+    Attachment {
+        data: render_calendar(&calendar, std::slice::from_ref(event)).into_bytes(),
+        file_name: format!("event-{}.ics", event.slug),
+        content_type: "text/calendar; charset=utf-8".to_string(),
+    }
+}
+
+/// Build an Atom feed with the provided events.
+pub(crate) fn build_events_atom_feed(
+    base_url: &str,
+    feed_name: &str,
+    feed_url: &str,
+    page_url: &str,
+    events: &[EventSummary],
+) -> Result<String> {
+    // Events do not track modifications, so entries are stamped at build time
+    let updated = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    // Write feed metadata and an entry per event
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(XmlEvent::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
+    writer
+        .create_element("feed")
+        .with_attribute(("xmlns", "http://www.w3.org/2005/Atom"))
+        .write_inner_content(|writer| {
+            writer
+                .create_element("id")
+                .write_text_content(BytesText::new(feed_url))?;
+            writer
+                .create_element("title")
+                .write_text_content(BytesText::new(feed_name))?;
+            writer
+                .create_element("updated")
+                .write_text_content(BytesText::new(&updated))?;
+            writer
+                .create_element("link")
+                .with_attributes([
+                    ("href", feed_url),
+                    ("rel", "self"),
+                    ("type", "application/atom+xml"),
+                ])
+                .write_empty()?;
+            writer
+                .create_element("link")
+                .with_attributes([
+                    ("href", page_url),
+                    ("rel", "alternate"),
+                    ("type", "text/html"),
+                ])
+                .write_empty()?;
+            writer.create_element("author").write_inner_content(|writer| {
+                writer
+                    .create_element("name")
+                    .write_text_content(BytesText::new(feed_name))?;
+                Ok(())
+            })?;
+            for event in events {
+                write_event_atom_entry(writer, base_url, event, &updated)?;
+            }
+            Ok(())
+        })?;
+
+    Ok(String::from_utf8(writer.into_inner())?)
+}
+
+/// Build an iCalendar (ICS) feed with the provided events, optionally
+/// including the meeting details only shared with attendees.
+pub(crate) fn build_events_calendar_feed(
+    base_url: &str,
+    calendar_name: &str,
+    events: &[EventSummary],
+    include_meeting: bool,
+) -> String {
+    // Setup calendar, asking subscribers to refresh it periodically
+    let mut calendar = Calendar::new();
+    let mut refresh_interval = Property::new("REFRESH-INTERVAL", "PT1H");
+    refresh_interval.add_parameter("VALUE", "DURATION");
+    calendar
+        .name(calendar_name)
+        .append_property(refresh_interval)
+        .append_property(Property::new("X-PUBLISHED-TTL", "PT1H"));
+
+    // Add an entry per event
+    for event in events {
+        let description = build_event_calendar_description(event, include_meeting);
+        calendar.push(build_event_calendar_entry(base_url, event, &description));
+    }
+
+    render_calendar(&calendar, events)
+}
+
+/// Build the calendar entry for the specified event.
+fn build_event_calendar_entry(base_url: &str, event: &EventSummary, description: &str) -> Event {
     // Prepare some event data
-    let description = build_event_calendar_description(event);
     let location = event.location(512);
     let uid = format!("{}", event.event_id);
     let tz_string = event.timezone.to_string();
@@ -28,7 +149,7 @@ pub(crate) fn build_event_calendar_attachment(base_url: &str, event: &EventSumma
         .created(Utc::now())
         .append_property(Property::new("URL", build_event_page_link(base_url, event)));
     if !description.is_empty() {
-        ical_event.description(&description);
+        ical_event.description(description);
     }
     if event.canceled {
         ical_event.status(EventStatus::Cancelled);
@@ -78,25 +199,15 @@ pub(crate) fn build_event_calendar_attachment(base_url: &str, event: &EventSumma
         ical_event.append_property(apple_loc);
     }
 
-    // Setup calendar and add ical event
-    let calendar_name = format!("{} - {}", event.group_name, event.name);
-    let mut calendar = Calendar::new();
-    calendar
-        .name(&calendar_name)
-        .description(&description)
-        .append_property(Property::new("X-WR-TIMEZONE", event.timezone.to_string()))
-        .push(ical_event.done());
-
-    // Setup attachment and return it
-    Attachment {
-        data: calendar.to_string().into_bytes(),
-        file_name: format!("event-{}.ics", event.slug),
-        content_type: "text/calendar; charset=utf-8".to_string(),
-    }
+    ical_event.done()
 }
 
-/// Build the event description for the calendar entry.
-fn build_event_calendar_description(event: &EventSummary) -> String {
+/// Build the event description for the calendar entry, optionally including
+/// the meeting details only shared with attendees.
+pub(crate) fn build_event_calendar_description(
+    event: &EventSummary,
+    include_meeting: bool,
+) -> String {
     let mut description = Vec::new();
 
     // Add cancellation notice on top if applicable
@@ -113,6 +224,10 @@ fn build_event_calendar_description(event: &EventSummary) -> String {
         description.push(description_short.trim().to_string());
     }
 
+    if !include_meeting {
+        return description.join("\n\n");
+    }
+
     // Meeting URL if available
     if let Some(meeting_join_url) =
         event.meeting_join_url.as_deref().filter(|url| !url.trim().is_empty())
@@ -140,6 +255,124 @@ pub(crate) fn build_event_page_link(base_url: &str, event: &EventSummary) -> Str
     )
 }
 
+/// Build a PNG attachment with the QR code of an attendee's event ticket.
+pub(crate) fn build_event_ticket_attachment(
+    event: &EventSummary,
+    ticket_code: Uuid,
+) -> Result<Attachment> {
+    // Render the ticket code as a QR code image
+    let code = qrcode::QrCode::new(ticket_code.to_string().as_bytes())
+        .map_err(|e| anyhow!("Failed to generate QR code: {e}"))?;
+    let image = code.render::<Luma<u8>>().min_dimensions(400, 400).build();
+
+    // Encode the image as PNG
+    let mut data = Vec::new();
+    image.write_to(&mut Cursor::new(&mut data), ImageFormat::Png)?;
+
+    Ok(Attachment {
+        data,
+        file_name: format!("ticket-{}.png", event.slug),
+        content_type: "image/png".to_string(),
+    })
+}
+
+/// Build the group dashboard events link.
+pub(crate) fn build_group_dashboard_events_link(base_url: &str) -> String {
+    let base = base_url_without_trailing_slash(base_url);
+    format!("{base}/dashboard/group?tab=events")
+}
+
+/// Build an SVG QR code encoding the provided contents.
+pub(crate) fn build_qr_code_svg(contents: &str, min_size: u32) -> Result<String> {
+    let code = qrcode::QrCode::new(contents.as_bytes())
+        .map_err(|e| anyhow!("Failed to generate QR code: {e}"))?;
+    let svg = code
+        .render()
+        .min_dimensions(min_size, min_size)
+        .dark_color(svg::Color("#000000"))
+        .light_color(svg::Color("#ffffff"))
+        .build();
+
+    Ok(svg)
+}
+
+/// Build the VTIMEZONE component describing the offsets used by the timezone
+/// provided during the years between the instants specified.
+fn build_timezone_component(tz: chrono_tz::Tz, from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    let start_of_year = |year: i32| {
+        NaiveDate::from_ymd_opt(year, 1, 1)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|datetime| datetime.and_utc())
+    };
+    let (Some(start), Some(end)) = (start_of_year(from.year()), start_of_year(to.year() + 1))
+    else {
+        return String::new();
+    };
+    let offset_at = |instant: DateTime<Utc>| tz.offset_from_utc_datetime(&instant.naive_utc());
+
+    // Add the observance in effect at the start of the period
+    let mut component = format!("BEGIN:VTIMEZONE\r\nTZID:{tz}\r\n");
+    let mut previous = offset_at(start);
+    write_timezone_observance(&mut component, start, previous.fix(), &previous);
+
+    // Add an observance per offset transition, looking for them day by day
+    let mut day = start;
+    while day < end {
+        let next_day = day + TimeDelta::days(1);
+        let offset = offset_at(next_day);
+        if offset.fix() != previous.fix() {
+            // Narrow down the exact instant the transition takes place
+            let (mut before, mut after) = (day, next_day);
+            while after - before > TimeDelta::seconds(1) {
+                let middle = before + (after - before) / 2;
+                if offset_at(middle).fix() == previous.fix() {
+                    before = middle;
+                } else {
+                    after = middle;
+                }
+            }
+            write_timezone_observance(&mut component, after, previous.fix(), &offset);
+            previous = offset;
+        }
+        day = next_day;
+    }
+    component.push_str("END:VTIMEZONE\r\n");
+
+    component
+}
+
+/// Build the VTIMEZONE components of the timezones used by the events
+/// provided, covering the period each of them is used in.
+fn build_timezone_components(events: &[EventSummary]) -> String {
+    // Collect the period each timezone is used in
+    let mut periods: BTreeMap<String, (chrono_tz::Tz, DateTime<Utc>, DateTime<Utc>)> =
+        BTreeMap::new();
+    for event in events {
+        let Some(starts_at) = event.starts_at else {
+            continue;
+        };
+        let ends_at = event.ends_at.unwrap_or(starts_at).max(starts_at);
+        periods
+            .entry(event.timezone.to_string())
+            .and_modify(|(_, from, to)| {
+                *from = (*from).min(starts_at);
+                *to = (*to).max(ends_at);
+            })
+            .or_insert((event.timezone, starts_at, ends_at));
+    }
+
+    periods
+        .into_values()
+        .map(|(tz, from, to)| build_timezone_component(tz, from, to))
+        .collect()
+}
+
+/// Build the link of a user's personal calendar feed.
+pub(crate) fn build_user_calendar_feed_link(base_url: &str, calendar_token: &str) -> String {
+    let base = base_url_without_trailing_slash(base_url);
+    format!("{base}/calendar/{calendar_token}/events.ics")
+}
+
 /// Build the user dashboard events link.
 pub(crate) fn build_user_dashboard_events_link(base_url: &str) -> String {
     let base = base_url_without_trailing_slash(base_url);
@@ -166,6 +399,20 @@ fn format_datetime_for_ics<Tz: chrono::TimeZone>(dt: &DateTime<Tz>) -> String {
     )
 }
 
+/// Format a UTC offset for ICS format (+HHMM, or +HHMMSS when needed).
+fn format_utc_offset_for_ics(offset: FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+    if seconds == 0 {
+        format!("{sign}{hours:02}{minutes:02}")
+    } else {
+        format!("{sign}{hours:02}{minutes:02}{seconds:02}")
+    }
+}
+
 /// Quote parameter value for ICS output according to RFC 5545 section 3.2.
 fn quote_ics_parameter_value(input: &str) -> String {
     // Remove characters not allowed
@@ -179,11 +426,109 @@ fn quote_ics_parameter_value(input: &str) -> String {
     }
 }
 
+/// Render the calendar provided, including the VTIMEZONE components needed
+/// to resolve the timezones referenced by the events' dates.
+fn render_calendar(calendar: &Calendar, events: &[EventSummary]) -> String {
+    let calendar = calendar.to_string();
+    let timezones = build_timezone_components(events);
+
+    // Timezone components must be defined alongside the events using them
+    match calendar.find("BEGIN:VEVENT\r\n") {
+        Some(position) if !timezones.is_empty() => {
+            format!(
+                "{}{timezones}{}",
+                &calendar[..position],
+                &calendar[position..]
+            )
+        }
+        _ => calendar,
+    }
+}
+
+/// Write the Atom feed entry for the specified event.
+fn write_event_atom_entry(
+    writer: &mut Writer<Vec<u8>>,
+    base_url: &str,
+    event: &EventSummary,
+    updated: &str,
+) -> io::Result<()> {
+    // Prepare some event data
+    let id = format!("urn:uuid:{}", event.event_id);
+    let link = build_event_page_link(base_url, event);
+    let kind = event.kind.to_string();
+
+    // Summarize the event, leaving out the attendee-only meeting details
+    let mut summary = vec![build_event_calendar_description(event, false)];
+    if let Some(starts_at) = event.starts_at {
+        let starts_at = starts_at.with_timezone(&event.timezone);
+        summary.push(format!("Date: {}", starts_at.format("%B %-d, %Y %H:%M %Z")));
+    }
+    if let Some(location) = event.location(512) {
+        summary.push(format!("Location: {location}"));
+    }
+    summary.retain(|part| !part.is_empty());
+    let summary = summary.join("\n\n");
+
+    // Write entry
+    writer.create_element("entry").write_inner_content(|writer| {
+        writer.create_element("id").write_text_content(BytesText::new(&id))?;
+        writer
+            .create_element("title")
+            .write_text_content(BytesText::new(&event.name))?;
+        writer
+            .create_element("updated")
+            .write_text_content(BytesText::new(updated))?;
+        writer
+            .create_element("link")
+            .with_attributes([
+                ("href", link.as_str()),
+                ("rel", "alternate"),
+                ("type", "text/html"),
+            ])
+            .write_empty()?;
+        writer
+            .create_element("category")
+            .with_attribute(("term", kind.as_str()))
+            .write_empty()?;
+        writer
+            .create_element("summary")
+            .with_attribute(("type", "text"))
+            .write_text_content(BytesText::new(&summary))?;
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+/// Write a timezone observance starting at the instant provided.
+fn write_timezone_observance(
+    component: &mut String,
+    starts_at: DateTime<Utc>,
+    offset_from: FixedOffset,
+    offset: &chrono_tz::TzOffset,
+) {
+    let kind = if offset.dst_offset().is_zero() {
+        "STANDARD"
+    } else {
+        "DAYLIGHT"
+    };
+    let _ = write!(
+        component,
+        "BEGIN:{kind}\r\nDTSTART:{}\r\nTZOFFSETFROM:{}\r\nTZOFFSETTO:{}\r\n",
+        format_datetime_for_ics(&starts_at.with_timezone(&offset_from)),
+        format_utc_offset_for_ics(offset_from),
+        format_utc_offset_for_ics(offset.fix()),
+    );
+    if let Some(abbreviation) = offset.abbreviation() {
+        let _ = write!(component, "TZNAME:{abbreviation}\r\n");
+    }
+    let _ = write!(component, "END:{kind}\r\n");
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
     use chrono_tz::America::Los_Angeles;
-    use uuid::Uuid;
 
     use crate::types::event::EventKind;
 
@@ -239,6 +584,8 @@ mod tests {
         assert!(unfolded.contains(
             "X-APPLE-TITLE=\"Test Venue, 123 Main St, San Francisco, CA, United States\""
         ));
+        assert!(unfolded.contains("BEGIN:VTIMEZONE\r\nTZID:America/Los_Angeles\r\n"));
+        assert!(unfolded.find("END:VTIMEZONE") < unfolded.find("BEGIN:VEVENT"));
     }
 
     #[test]
@@ -283,6 +630,130 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_build_event_ticket_attachment() {
+        let event = sample_event(false);
+        let attachment = build_event_ticket_attachment(&event, Uuid::new_v4()).unwrap();
+
+        assert_eq!(attachment.content_type, "image/png");
+        assert_eq!(attachment.file_name, format!("ticket-{}.png", event.slug));
+        assert_eq!(
+            image::guess_format(&attachment.data).unwrap(),
+            ImageFormat::Png
+        );
+    }
+
+    #[test]
+    fn test_build_events_atom_feed() {
+        let mut event = sample_event(true);
+        event.name = "Rust & Friends".to_string();
+        let feed = build_events_atom_feed(
+            BASE_URL,
+            "Test Group",
+            "https://example.test/test-community/group/test-group/events.atom",
+            "https://example.test/test-community/group/test-group",
+            &[event],
+        )
+        .unwrap();
+
+        assert!(feed.starts_with(r#"<?xml version="1.0" encoding="utf-8"?>"#));
+        assert!(feed.contains(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#));
+        assert!(
+            feed.contains(
+                "<id>https://example.test/test-community/group/test-group/events.atom</id>"
+            )
+        );
+        assert!(feed.contains(
+            r#"<link href="https://example.test/test-community/group/test-group" rel="alternate" type="text/html"/>"#
+        ));
+        assert!(feed.contains("<name>Test Group</name>"));
+        assert!(feed.contains("<id>urn:uuid:00000000-0000-0000-0000-000000000001</id>"));
+        assert!(feed.contains("<title>Rust &amp; Friends</title>"));
+        assert!(feed.contains(
+            r#"<link href="https://example.test/test-community/group/test-group/event/test-event" rel="alternate" type="text/html"/>"#
+        ));
+        assert!(feed.contains(r#"<category term="in-person"/>"#));
+        assert!(feed.contains("** This event has been canceled **"));
+        assert!(feed.contains("Date: January 12, 2026 11:00 PST"));
+        assert!(!feed.contains("https://example.test/live"));
+        assert!(!feed.contains("secret123"));
+    }
+
+    #[test]
+    fn test_build_events_calendar_feed_without_meeting_details() {
+        let events = vec![sample_event(false)];
+        let feed = build_events_calendar_feed(BASE_URL, "Test Group", &events, false);
+        let unfolded = feed.replace("\r\n ", "").replace("\n ", "");
+
+        assert!(unfolded.contains("NAME:Test Group"));
+        assert!(unfolded.contains("REFRESH-INTERVAL;VALUE=DURATION:PT1H"));
+        assert!(unfolded.contains("X-PUBLISHED-TTL:PT1H"));
+        assert!(unfolded.contains("DESCRIPTION:Short description"));
+        assert!(unfolded.contains("DTSTART;TZID=America/Los_Angeles:20260112T110000"));
+        assert!(unfolded.contains("STATUS:CONFIRMED"));
+        assert!(unfolded.contains("SUMMARY:Test Event"));
+        assert!(unfolded.contains("UID:00000000-0000-0000-0000-000000000001"));
+        assert!(!unfolded.contains("https://example.test/live"));
+        assert!(!unfolded.contains("secret123"));
+    }
+
+    #[test]
+    fn test_build_events_calendar_feed_with_meeting_details() {
+        let events = vec![sample_event(true)];
+        let feed = build_events_calendar_feed(BASE_URL, "My events", &events, true);
+        let unfolded = feed.replace("\r\n ", "").replace("\n ", "");
+
+        assert!(unfolded.contains("NAME:My events"));
+        assert!(unfolded.contains("Meeting link: https://example.test/live"));
+        assert!(unfolded.contains("Meeting password: secret123"));
+        assert!(unfolded.contains("STATUS:CANCELLED"));
+    }
+
+    #[test]
+    fn test_build_timezone_component_with_transitions() {
+        let starts_at = Utc.with_ymd_and_hms(2026, 1, 12, 19, 0, 0).unwrap();
+        let component = build_timezone_component(Los_Angeles, starts_at, starts_at);
+
+        assert!(component.starts_with("BEGIN:VTIMEZONE\r\nTZID:America/Los_Angeles\r\n"));
+        assert!(component.contains(
+            "BEGIN:STANDARD\r\nDTSTART:20251231T160000\r\nTZOFFSETFROM:-0800\r\nTZOFFSETTO:-0800\r\nTZNAME:PST\r\nEND:STANDARD\r\n"
+        ));
+        assert!(component.contains(
+            "BEGIN:DAYLIGHT\r\nDTSTART:20260308T020000\r\nTZOFFSETFROM:-0800\r\nTZOFFSETTO:-0700\r\nTZNAME:PDT\r\nEND:DAYLIGHT\r\n"
+        ));
+        assert!(component.contains(
+            "BEGIN:STANDARD\r\nDTSTART:20261101T020000\r\nTZOFFSETFROM:-0700\r\nTZOFFSETTO:-0800\r\nTZNAME:PST\r\nEND:STANDARD\r\n"
+        ));
+        assert!(component.ends_with("END:VTIMEZONE\r\n"));
+    }
+
+    #[test]
+    fn test_build_timezone_component_without_transitions() {
+        let starts_at = Utc.with_ymd_and_hms(2026, 1, 12, 19, 0, 0).unwrap();
+        let component = build_timezone_component(chrono_tz::Asia::Kolkata, starts_at, starts_at);
+
+        assert_eq!(
+            component,
+            "BEGIN:VTIMEZONE\r\nTZID:Asia/Kolkata\r\nBEGIN:STANDARD\r\nDTSTART:20260101T053000\r\nTZOFFSETFROM:+0530\r\nTZOFFSETTO:+0530\r\nTZNAME:IST\r\nEND:STANDARD\r\nEND:VTIMEZONE\r\n"
+        );
+    }
+
+    #[test]
+    fn test_build_user_calendar_feed_link() {
+        assert_eq!(
+            build_user_calendar_feed_link("https://example.test/", "token"),
+            "https://example.test/calendar/token/events.ics"
+        );
+    }
+
+    #[test]
+    fn test_build_qr_code_svg() {
+        let svg = build_qr_code_svg("https://example.test/check-in", 200).unwrap();
+
+        assert!(svg.contains("<svg"));
+        assert!(svg.contains("#000000"));
+    }
+
     #[test]
     fn test_format_datetime_for_ics() {
         let dt = Utc.with_ymd_and_hms(2026, 1, 12, 19, 0, 0).unwrap();
@@ -294,6 +765,16 @@ mod tests {
         assert_eq!(formatted_la, "20260112T110000");
     }
 
+    #[test]
+    fn test_format_utc_offset_for_ics() {
+        let offset = |seconds| FixedOffset::east_opt(seconds).unwrap();
+
+        assert_eq!(format_utc_offset_for_ics(offset(0)), "+0000");
+        assert_eq!(format_utc_offset_for_ics(offset(19_800)), "+0530");
+        assert_eq!(format_utc_offset_for_ics(offset(-28_800)), "-0800");
+        assert_eq!(format_utc_offset_for_ics(offset(-3_675)), "-010115");
+    }
+
     #[test]
     fn test_quote_ics_parameter_value_removes_double_quotes() {
         let input = r#"Joe's "Best" Venue, 123 Main St"#;
@@ -337,6 +818,7 @@ mod tests {
             test_event: false,
             timezone: Los_Angeles,
 
+            approval_status: None,
             capacity: None,
             created_by_display_name: None,
             created_by_username: None,
@@ -366,6 +848,7 @@ mod tests {
             venue_country_name: Some("United States".to_string()),
             venue_name: Some("Test Venue".to_string()),
             venue_state: Some("CA".to_string()),
+            waitlist_confirmation_hours: None,
             zip_code: Some("94105".to_string()),
         }
     }
@@ -9,12 +9,17 @@
 #![allow(clippy::trivially_copy_pass_by_ref)]
 #![allow(clippy::ref_option)]
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, str::FromStr};
 
 use garde::rules::email::parse_email;
 use reqwest::Url;
 use serde::{Deserialize, Deserializer};
 
+use crate::{
+    config::OidcProvider, services::notifications::webhook::validate_webhook_url,
+    types::event::StreamingEmbed,
+};
+
 /// Allowed CFS label colors.
 pub const CFS_LABEL_COLORS: [&str; 10] = [
     "#FFD866", "#FC9867", "#FF6188", "#AB9DF2", "#78DCE8", "#A9DC76", "#A88F6A", "#9DA5B4",
@@ -36,12 +41,21 @@ pub const MAX_LEN_S: usize = 100;
 
 // Purpose-specific limits
 
+/// Maximum number of months without events used to find dormant groups.
+pub const MAX_DORMANT_GROUP_MONTHS: i32 = 60;
+
 /// Maximum number of labels allowed per event.
 pub const MAX_EVENT_LABELS_PER_EVENT: usize = 200;
 
 /// Maximum number of labels allowed per submission.
 pub const MAX_EVENT_LABELS_PER_SUBMISSION: usize = 10;
 
+/// Maximum number of photos each attendee can share per event.
+pub const MAX_EVENT_PHOTOS_PER_ATTENDEE: usize = 10;
+
+/// Maximum number of months used to find inactive group members.
+pub const MAX_INACTIVE_MONTHS: i32 = 36;
+
 /// Maximum number of elements in a collection (filters, tags, etc.).
 pub const MAX_ITEMS: usize = 25;
 
@@ -69,6 +83,9 @@ pub const MAX_LEN_ENTITY_NAME: usize = 120;
 /// Maximum length for CFS label names.
 pub const MAX_LEN_EVENT_LABEL_NAME: usize = 80;
 
+/// Maximum length for the roles assigned to group members.
+pub const MAX_LEN_GROUP_MEMBER_ROLE: usize = 50;
+
 /// Maximum length for group pretty slugs.
 pub const MAX_LEN_GROUP_PRETTY_SLUG: usize = 50;
 
@@ -87,6 +104,9 @@ pub const MAX_LEN_TAG: usize = 50;
 /// Maximum length for timezone identifiers.
 pub const MAX_LEN_TIMEZONE: usize = 64;
 
+/// Maximum number of missed events that can send an attendee to the waitlist.
+pub const MAX_NO_SHOW_WAITLIST_THRESHOLD: i32 = 20;
+
 /// Maximum pagination limit for results per page.
 pub const MAX_PAGINATION_LIMIT: usize = 100;
 
@@ -96,6 +116,9 @@ pub const MAX_RECURRING_ADDITIONAL_OCCURRENCES: i32 = 12;
 /// Maximum duration for session proposals (minutes).
 pub const MAX_SESSION_PROPOSAL_DURATION_MINUTES: i32 = 480;
 
+/// Maximum hours a promoted waitlist user can be given to confirm the seat.
+pub const MAX_WAITLIST_CONFIRMATION_HOURS: i32 = 168;
+
 /// Minimum length for passwords.
 pub const MIN_PASSWORD_LEN: usize = 8;
 
@@ -222,6 +245,49 @@ pub fn valid_cfs_label_color(value: &impl AsRef<str>, _ctx: &()) -> garde::Resul
     Ok(())
 }
 
+/// Validates that a string is a domain name, e.g. events.example.com.
+pub fn valid_domain(value: &impl AsRef<str>, _ctx: &()) -> garde::Result {
+    let domain = value.as_ref().trim();
+    if domain.len() > MAX_LEN_M {
+        return Err(garde::Error::new(format!(
+            "domain exceeds max length of {MAX_LEN_M}"
+        )));
+    }
+    if !is_domain_name(domain) {
+        return Err(garde::Error::new(format!(
+            "invalid domain: {domain} (use the host name only, e.g. events.example.com)"
+        )));
+    }
+    Ok(())
+}
+
+/// Validates that each string in a vector is an email domain, e.g. example.com.
+pub fn valid_email_domain_vec(value: &Option<Vec<String>>, _ctx: &()) -> garde::Result {
+    let Some(vec) = value else {
+        return Ok(());
+    };
+
+    if vec.len() > MAX_ITEMS {
+        return Err(garde::Error::new(format!(
+            "value exceeds max items of {MAX_ITEMS}"
+        )));
+    }
+    for domain in vec {
+        if domain.len() > MAX_LEN_S {
+            return Err(garde::Error::new(format!(
+                "email domain exceeds max length of {MAX_LEN_S}"
+            )));
+        }
+
+        if !is_domain_name(domain) {
+            return Err(garde::Error::new(format!(
+                "invalid email domain: {domain} (use the part after the @, e.g. example.com)"
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Validates an optional group pretty slug.
 pub fn valid_group_pretty_slug(value: &Option<String>, _ctx: &()) -> garde::Result {
     // Normalize optional form input
@@ -277,6 +343,19 @@ pub fn valid_group_pretty_slug(value: &Option<String>, _ctx: &()) -> garde::Resu
     Ok(())
 }
 
+/// Validates an optional hex color, e.g. #0A3D62.
+pub fn valid_hex_color_opt(value: &Option<String>, _ctx: &()) -> garde::Result {
+    let Some(value) = value.as_deref() else {
+        return Ok(());
+    };
+
+    let digits = value.strip_prefix('#').unwrap_or_default();
+    if digits.len() != 6 || !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(garde::Error::new("color must be a hex color, e.g. #0A3D62"));
+    }
+    Ok(())
+}
+
 /// Validates that a latitude value is within valid range (-90 to 90).
 pub fn valid_latitude(value: &Option<f64>, _ctx: &()) -> garde::Result {
     if let Some(lat) = value
@@ -297,6 +376,69 @@ pub fn valid_longitude(value: &Option<f64>, _ctx: &()) -> garde::Result {
     Ok(())
 }
 
+/// Validates that an optional value is a supported OIDC provider.
+pub fn valid_oidc_provider_opt(value: &Option<String>, _ctx: &()) -> garde::Result {
+    if let Some(value) = value.as_deref()
+        && OidcProvider::from_str(value).is_err()
+    {
+        return Err(garde::Error::new("unsupported OIDC provider"));
+    }
+    Ok(())
+}
+
+/// Validates that an optional phone number uses the E.164 format (e.g. +15551234567).
+pub fn valid_phone_number_opt(value: &Option<String>, _ctx: &()) -> garde::Result {
+    let Some(value) = value.as_deref() else {
+        return Ok(());
+    };
+
+    let digits = value.strip_prefix('+').unwrap_or_default();
+    if !(7..=15).contains(&digits.len())
+        || !digits.bytes().all(|byte| byte.is_ascii_digit())
+        || digits.starts_with('0')
+    {
+        return Err(garde::Error::new(
+            "phone number must use the international format, e.g. +15551234567",
+        ));
+    }
+    Ok(())
+}
+
+/// Validates that an optional URL is a supported live stream link.
+pub fn valid_streaming_url_opt(value: &Option<String>, _ctx: &()) -> garde::Result {
+    if let Some(value) = value.as_deref()
+        && StreamingEmbed::from_url(value).is_none()
+    {
+        return Err(garde::Error::new(
+            "streaming URL must be a YouTube, Twitch or Vimeo link",
+        ));
+    }
+    Ok(())
+}
+
+/// Validates that an optional webhook URL uses HTTPS and does not point to an
+/// internal address.
+pub fn valid_webhook_url_opt(value: &Option<String>, _ctx: &()) -> garde::Result {
+    if let Some(value) = value.as_deref()
+        && let Err(err) = validate_webhook_url(value)
+    {
+        return Err(garde::Error::new(err.to_string()));
+    }
+    Ok(())
+}
+
+// Checks that a string has at least two labels of ASCII letters, numbers and hyphens
+fn is_domain_name(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid_labels = labels.iter().all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+    });
+    labels.len() >= 2 && valid_labels
+}
+
 // Validates a single image URL string (absolute or relative)
 fn validate_image_url(url: &str) -> garde::Result {
     if url.trim().is_empty() {
@@ -548,6 +690,53 @@ mod tests {
         assert!(trimmed_non_empty(&"a", &()).is_ok());
     }
 
+    #[test]
+    fn test_valid_domain_invalid() {
+        assert!(valid_domain(&"example", &()).is_err());
+        assert!(valid_domain(&"https://events.example.com", &()).is_err());
+        assert!(valid_domain(&"events.example.com/path", &()).is_err());
+        assert!(valid_domain(&"events..example.com", &()).is_err());
+        assert!(valid_domain(&"-events.example.com", &()).is_err());
+        assert!(valid_domain(&"", &()).is_err());
+        assert!(valid_domain(&format!("{}.com", "a".repeat(MAX_LEN_M)), &()).is_err());
+    }
+
+    #[test]
+    fn test_valid_domain_valid() {
+        assert!(valid_domain(&"example.com", &()).is_ok());
+        assert!(valid_domain(&" events.my-community.org ", &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_email_domain_vec_invalid() {
+        assert!(valid_email_domain_vec(&Some(vec!["@example.com".to_string()]), &()).is_err());
+        assert!(valid_email_domain_vec(&Some(vec!["example".to_string()]), &()).is_err());
+        assert!(valid_email_domain_vec(&Some(vec!["example..com".to_string()]), &()).is_err());
+        assert!(valid_email_domain_vec(&Some(vec!["-example.com".to_string()]), &()).is_err());
+        assert!(valid_email_domain_vec(&Some(vec!["exa mple.com".to_string()]), &()).is_err());
+        assert!(valid_email_domain_vec(&Some(vec![String::new()]), &()).is_err());
+        assert!(
+            valid_email_domain_vec(&Some(vec!["example.com".to_string(); MAX_ITEMS + 1]), &())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_valid_email_domain_vec_valid() {
+        assert!(valid_email_domain_vec(&None, &()).is_ok());
+        assert!(valid_email_domain_vec(&Some(vec![]), &()).is_ok());
+        assert!(
+            valid_email_domain_vec(
+                &Some(vec![
+                    "example.com".to_string(),
+                    "mail.my-company.co.uk".to_string()
+                ]),
+                &()
+            )
+            .is_ok()
+        );
+    }
+
     #[test]
     fn test_valid_group_pretty_slug_invalid() {
         assert!(valid_group_pretty_slug(&Some("Pretty-Group".to_string()), &()).is_err());
@@ -688,6 +877,26 @@ mod tests {
         assert!(url_map_values(&Some(BTreeMap::new()), &()).is_ok());
     }
 
+    #[test]
+    fn test_valid_hex_color_opt_invalid() {
+        assert!(valid_hex_color_opt(&Some("0A3D62".to_string()), &()).is_err());
+        assert!(valid_hex_color_opt(&Some("#0A3D6".to_string()), &()).is_err());
+        assert!(valid_hex_color_opt(&Some("#0A3D62FF".to_string()), &()).is_err());
+        assert!(valid_hex_color_opt(&Some("#0G3D62".to_string()), &()).is_err());
+        assert!(valid_hex_color_opt(&Some("red".to_string()), &()).is_err());
+    }
+
+    #[test]
+    fn test_valid_hex_color_opt_none() {
+        assert!(valid_hex_color_opt(&None, &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_hex_color_opt_valid() {
+        assert!(valid_hex_color_opt(&Some("#0A3D62".to_string()), &()).is_ok());
+        assert!(valid_hex_color_opt(&Some("#ffffff".to_string()), &()).is_ok());
+    }
+
     #[test]
     fn test_valid_latitude_invalid() {
         assert!(valid_latitude(&Some(90.1), &()).is_err());
@@ -731,6 +940,86 @@ mod tests {
         assert!(valid_longitude(&Some(-90.0), &()).is_ok());
     }
 
+    #[test]
+    fn test_valid_oidc_provider_opt_invalid() {
+        assert!(valid_oidc_provider_opt(&Some("github".to_string()), &()).is_err());
+        assert!(valid_oidc_provider_opt(&Some("LinuxFoundation".to_string()), &()).is_err());
+    }
+
+    #[test]
+    fn test_valid_oidc_provider_opt_none() {
+        assert!(valid_oidc_provider_opt(&None, &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_oidc_provider_opt_valid() {
+        assert!(valid_oidc_provider_opt(&Some("linuxfoundation".to_string()), &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_phone_number_opt_invalid() {
+        assert!(valid_phone_number_opt(&Some("15551234567".to_string()), &()).is_err());
+        assert!(valid_phone_number_opt(&Some("+05551234567".to_string()), &()).is_err());
+        assert!(valid_phone_number_opt(&Some("+1 555 123 4567".to_string()), &()).is_err());
+        assert!(valid_phone_number_opt(&Some("+123456".to_string()), &()).is_err());
+        assert!(valid_phone_number_opt(&Some("+1234567890123456".to_string()), &()).is_err());
+    }
+
+    #[test]
+    fn test_valid_phone_number_opt_none() {
+        assert!(valid_phone_number_opt(&None, &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_phone_number_opt_valid() {
+        assert!(valid_phone_number_opt(&Some("+15551234567".to_string()), &()).is_ok());
+        assert!(valid_phone_number_opt(&Some("+34600123456".to_string()), &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_streaming_url_opt_invalid() {
+        let url = Some("https://example.com/live".to_string());
+        assert!(valid_streaming_url_opt(&url, &()).is_err());
+    }
+
+    #[test]
+    fn test_valid_streaming_url_opt_none() {
+        assert!(valid_streaming_url_opt(&None, &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_streaming_url_opt_valid() {
+        let url = Some("https://www.twitch.tv/cncf".to_string());
+        assert!(valid_streaming_url_opt(&url, &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_webhook_url_opt_invalid() {
+        for url in [
+            "http://hooks.example.com/ocg",
+            "https://10.0.0.1/ocg",
+            "https://127.0.0.1/ocg",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/ocg",
+        ] {
+            assert!(
+                valid_webhook_url_opt(&Some(url.to_string()), &()).is_err(),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_valid_webhook_url_opt_none() {
+        assert!(valid_webhook_url_opt(&None, &()).is_ok());
+    }
+
+    #[test]
+    fn test_valid_webhook_url_opt_valid() {
+        let url = Some("https://hooks.example.com/ocg".to_string());
+        assert!(valid_webhook_url_opt(&url, &()).is_ok());
+    }
+
     // Deserializers.
 
     #[derive(Debug, Deserialize)]
@@ -4,6 +4,16 @@ import {
   reloadIfDeploymentChanged,
 } from "/static/js/common/deployment-version.js";
 
+const BROWSER_REDIRECT_ERROR = "BrowserRedirectError";
+
+/**
+ * Returns whether the error was raised because the server requested a redirect.
+ *
+ * @param {unknown} error Error thrown by ocgFetch
+ * @returns {boolean}
+ */
+export const isBrowserRedirectError = (error) => error?.name === BROWSER_REDIRECT_ERROR;
+
 /**
  * Fetches a resource and follows server-provided browser redirects.
  *
@@ -32,7 +42,9 @@ export const ocgFetch = async (input, init = {}) => {
   const redirectUrl = response.headers?.get?.("X-OCG-Redirect");
   if (redirectUrl) {
     window.location.assign(redirectUrl);
-    throw new Error("Browser redirect requested by server.");
+    const error = new Error("Browser redirect requested by server.");
+    error.name = BROWSER_REDIRECT_ERROR;
+    throw error;
   }
 
   return response;
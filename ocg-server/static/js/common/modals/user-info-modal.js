@@ -18,6 +18,7 @@ import "/static/js/common/media/logo-image.js";
  * Features:
  * - Shows user avatar, name, jobTitle, company, bio
  * - Displays social media links if available
+ * - Links to the user's talks when a talks URL is provided
  * - Keyboard navigation (Escape to close)
  * - Click outside to close
  * - ARIA attributes for accessibility
@@ -216,6 +217,20 @@ export class UserInfoModal extends LitWrapper {
     `;
   }
 
+  _renderTalksLink() {
+    if (!this._userData?.talksUrl) {
+      return "";
+    }
+
+    return html`
+      <div class="border-t border-stone-200 pt-6 mt-6">
+        <a href=${this._userData.talksUrl} class="btn-secondary-anchor inline-flex items-center gap-2">
+          <span>View talks</span>
+        </a>
+      </div>
+    `;
+  }
+
   _renderProfilePlaceholder(bio, socialLinks) {
     if (this._hasProfileDetails(bio, socialLinks)) {
       return "";
@@ -309,6 +324,7 @@ export class UserInfoModal extends LitWrapper {
                   : ""
               }
               ${this._renderProfilePlaceholder(bio, socialLinks)} ${this._renderSocialLinks(socialLinks)}
+              ${this._renderTalksLink()}
             </div>
           </div>
         </div>
@@ -17,6 +17,23 @@ import { clearTimeoutId, replaceTimeout } from "/static/js/common/timers.js";
 import { parseJsonAttribute } from "/static/js/common/utils.js";
 import "/static/js/common/multiple-inputs.js";
 
+/** Display labels for the supported meeting providers. */
+const MEETING_PROVIDER_LABELS = {
+  bigbluebutton: "BigBlueButton",
+  google_meet: "Google Meet",
+  jitsi: "Jitsi",
+  teams: "Microsoft Teams",
+  webex: "Webex",
+  zoom: "Zoom",
+};
+
+/** Display labels for the meeting statuses reported by providers. */
+const MEETING_PROVIDER_STATUS_LABELS = {
+  deleted: "Deleted in the provider",
+  ended: "Ended",
+  started: "In progress",
+};
+
 /**
  * Online event details component for managing meeting information. Supports
  * manual URL entry and automatic meeting creation modes.
@@ -42,6 +59,7 @@ export class OnlineEventDetails extends LitWrapper {
       },
     },
     meetingRequested: { type: Boolean, attribute: "meeting-requested" },
+    meetingWebinar: { type: Boolean, attribute: "meeting-webinar" },
     eventPast: { type: Boolean, attribute: "event-past" },
     meetingHosts: {
       type: Array,
@@ -55,8 +73,17 @@ export class OnlineEventDetails extends LitWrapper {
     meetingInSync: { type: Boolean, attribute: "meeting-in-sync" },
     meetingPassword: { type: String, attribute: "meeting-password" },
     meetingError: { type: String, attribute: "meeting-error" },
+    meetingProviderStatus: { type: String, attribute: "meeting-provider-status" },
+    meetingParticipantsJoinedCount: { type: Number, attribute: "meeting-participants-joined-count" },
     fieldNamePrefix: { type: String, attribute: "field-name-prefix" },
     meetingProviderId: { type: String, attribute: "meeting-provider-id" },
+    meetingProviders: {
+      type: Array,
+      attribute: "meeting-providers",
+      converter: {
+        fromAttribute: (value) => parseJsonAttribute(value, []),
+      },
+    },
     meetingRecordingRawUrls: {
       type: Array,
       attribute: "meeting-recording-raw-urls",
@@ -85,6 +112,7 @@ export class OnlineEventDetails extends LitWrapper {
     _recordingPublished: { type: Boolean, state: true },
     _rawRecordingUrls: { type: Array, state: true },
     _recordingRequested: { type: Boolean, state: true },
+    _webinar: { type: Boolean, state: true },
     _createMeeting: { type: Boolean, state: true },
     _providerId: { type: String, state: true },
     _hosts: { type: Array, state: true },
@@ -100,6 +128,7 @@ export class OnlineEventDetails extends LitWrapper {
     this.meetingRecordingUrl = "";
     this.meetingRecordingRequested = true;
     this.meetingRequested = false;
+    this.meetingWebinar = false;
     this.eventPast = false;
     this.meetingHosts = [];
     this.startsAt = "";
@@ -107,8 +136,11 @@ export class OnlineEventDetails extends LitWrapper {
     this.meetingInSync = false;
     this.meetingPassword = "";
     this.meetingError = "";
+    this.meetingProviderStatus = "";
+    this.meetingParticipantsJoinedCount = 0;
     this.fieldNamePrefix = "";
     this.meetingProviderId = DEFAULT_MEETING_PROVIDER;
+    this.meetingProviders = [];
     this.meetingRecordingRawUrls = [];
     this.meetingRecordingPublished = false;
     this.meetingMaxParticipants = {};
@@ -120,6 +152,7 @@ export class OnlineEventDetails extends LitWrapper {
     this._recordingPublished = false;
     this._rawRecordingUrls = [];
     this._recordingRequested = true;
+    this._webinar = false;
     this._createMeeting = false;
     this._providerId = DEFAULT_MEETING_PROVIDER;
     this._hosts = [];
@@ -165,8 +198,11 @@ export class OnlineEventDetails extends LitWrapper {
       ? [...this.meetingRecordingRawUrls]
       : [];
     this._recordingRequested = this.meetingRecordingRequested !== false;
+    this._webinar = this.meetingWebinar === true;
     this._createMeeting = this.meetingRequested;
-    this._providerId = this.meetingProviderId || DEFAULT_MEETING_PROVIDER;
+    this._providerId = this._getAvailableProviders().includes(this.meetingProviderId)
+      ? this.meetingProviderId
+      : this._getDefaultProviderId();
     this._hosts = Array.isArray(this.meetingHosts) ? [...this.meetingHosts] : [];
     this._mode = startsInAutomaticMode ? "automatic" : "manual";
     this._checkMeetingCapacity();
@@ -685,6 +721,40 @@ export class OnlineEventDetails extends LitWrapper {
     this._recordingRequested = event.target.checked;
   }
 
+  /**
+   * Handles the webinar mode toggle.
+   * @param {Event} event - Change event
+   */
+  _handleWebinarChange(event) {
+    if (this.disabled || this._isWebinarLocked()) return;
+    this._webinar = event.target.checked;
+    this._checkMeetingCapacity();
+  }
+
+  /**
+   * Checks if webinar mode can be offered for the current context.
+   * @returns {boolean} True for Zoom event meetings
+   */
+  _supportsWebinar() {
+    return !this._isSession() && this._providerId === "zoom";
+  }
+
+  /**
+   * Checks if webinar mode can no longer be changed.
+   * @returns {boolean} True once the provider meeting has been created
+   */
+  _isWebinarLocked() {
+    return this.meetingRequested === true && Boolean(this.meetingJoinUrl);
+  }
+
+  /**
+   * Checks if the automatic meeting will be hosted as a webinar.
+   * @returns {boolean} True when webinar mode is active
+   */
+  _isWebinarActive() {
+    return this._supportsWebinar() && this._webinar === true;
+  }
+
   /**
    * Evaluates whether an automatic meeting can be requested from current fields.
    * @returns {{allowed: boolean, reasons: Array<string>}} Availability state
@@ -779,6 +849,10 @@ export class OnlineEventDetails extends LitWrapper {
       meeting_provider_id: isAutomatic ? (this._providerId || DEFAULT_MEETING_PROVIDER).trim() : "",
     };
 
+    if (!this._isSession()) {
+      data.meeting_webinar = isAutomatic && this._isWebinarActive();
+    }
+
     if (this._supportsJoinInstructions()) {
       data.meeting_join_instructions = isAutomatic ? "" : (this._joinInstructions || "").trim();
     }
@@ -797,8 +871,9 @@ export class OnlineEventDetails extends LitWrapper {
     this._recordingPublished = false;
     this._rawRecordingUrls = [];
     this._recordingRequested = true;
+    this._webinar = false;
     this._createMeeting = false;
-    this._providerId = DEFAULT_MEETING_PROVIDER;
+    this._providerId = this._getDefaultProviderId();
     this._hosts = [];
     this.requestUpdate();
   }
@@ -835,6 +910,7 @@ export class OnlineEventDetails extends LitWrapper {
       meeting_recording_url: recordingUrlValue,
       meeting_requested: isAutomatic,
       meeting_provider_id: providerIdValue,
+      meeting_webinar: webinarValue,
     } = this.getMeetingData();
 
     return html`
@@ -867,9 +943,34 @@ export class OnlineEventDetails extends LitWrapper {
       />
       <input type="hidden" name="${this._getFieldName("meeting_requested")}" value="${isAutomatic}" />
       <input type="hidden" name="${this._getFieldName("meeting_provider_id")}" value="${providerIdValue}" />
+      ${
+        !this._isSession()
+          ? html`<input type="hidden" name="${this._getFieldName("meeting_webinar")}" value="${webinarValue}" />`
+          : ""
+      }
     `;
   }
 
+  /**
+   * Returns the meeting providers organizers can choose from.
+   * @returns {string[]} Enabled provider identifiers
+   */
+  _getAvailableProviders() {
+    const providers = Array.isArray(this.meetingProviders)
+      ? this.meetingProviders.filter((provider) => provider in MEETING_PROVIDER_LABELS)
+      : [];
+    return providers.length > 0 ? providers : [DEFAULT_MEETING_PROVIDER];
+  }
+
+  /**
+   * Returns the provider preselected for new automatic meetings.
+   * @returns {string} Provider identifier
+   */
+  _getDefaultProviderId() {
+    const providers = this._getAvailableProviders();
+    return providers.includes(DEFAULT_MEETING_PROVIDER) ? DEFAULT_MEETING_PROVIDER : providers[0];
+  }
+
   _getCapacityValue() {
     const capacityField = getElementById(document, "capacity");
     const value = parseInt(capacityField?.value, 10);
@@ -877,6 +978,11 @@ export class OnlineEventDetails extends LitWrapper {
   }
 
   _getCapacityLimit() {
+    // Webinars are sized by the organizer's Zoom webinar license
+    if (this._isWebinarActive()) {
+      return null;
+    }
+
     if (!this.meetingMaxParticipants || typeof this.meetingMaxParticipants !== "object") {
       return null;
     }
@@ -997,6 +1103,26 @@ export class OnlineEventDetails extends LitWrapper {
               `
             : ""
         }
+        ${
+          MEETING_PROVIDER_STATUS_LABELS[this.meetingProviderStatus]
+            ? html`
+                <div class="text-sm text-stone-700">
+                  <span class="font-medium">Provider status:</span>
+                  ${MEETING_PROVIDER_STATUS_LABELS[this.meetingProviderStatus]}
+                </div>
+              `
+            : ""
+        }
+        ${
+          this.meetingParticipantsJoinedCount > 0
+            ? html`
+                <div class="text-sm text-stone-700">
+                  <span class="font-medium">Participant joins:</span>
+                  ${this.meetingParticipantsJoinedCount}
+                </div>
+              `
+            : ""
+        }
         ${
           this.meetingError
             ? html`
@@ -1013,6 +1139,43 @@ export class OnlineEventDetails extends LitWrapper {
     `;
   }
 
+  /**
+   * Renders the webinar mode toggle for Zoom event meetings.
+   * @returns {import('lit').TemplateResult|string} Webinar control
+   */
+  _renderWebinarControl() {
+    if (!this._supportsWebinar()) {
+      return "";
+    }
+
+    const locked = this._isWebinarLocked();
+
+    return html`
+      <div class="space-y-2">
+        <label class="inline-flex items-center ${locked ? "cursor-not-allowed" : "cursor-pointer"}">
+          <input
+            type="checkbox"
+            class="sr-only peer"
+            .checked="${this._webinar}"
+            @change="${this._handleWebinarChange}"
+            ?disabled=${this.disabled || locked}
+          />
+          <span
+            class="relative w-11 h-6 bg-stone-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-primary-300 rounded-full peer peer-checked:after:translate-x-full rtl:peer-checked:after:-translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:start-[2px] after:bg-white after:border-stone-300 after:border after:border-stone-200 after:rounded-full after:h-5 after:w-5 after:transition-all peer-checked:bg-primary-500"
+          ></span>
+          <span class="ms-3 text-sm font-medium text-stone-900">Host as webinar</span>
+        </label>
+        <p class="form-legend">
+          ${
+            locked
+              ? "Webinar mode cannot be changed once the meeting has been created."
+              : "Use a Zoom webinar for large audiences. Host emails join as panelists. Requires a Zoom webinar license."
+          }
+        </p>
+      </div>
+    `;
+  }
+
   _renderRecordingVisibilityControl() {
     return html`
       <div class="space-y-2">
@@ -1202,14 +1365,18 @@ export class OnlineEventDetails extends LitWrapper {
                         this.disabled ? "bg-stone-100 text-stone-500 cursor-not-allowed" : ""
                       }"
                       @change="${(event) => {
-                        this._providerId = event.target.value || DEFAULT_MEETING_PROVIDER;
+                        this._providerId = event.target.value || this._getDefaultProviderId();
                         this._checkMeetingCapacity();
                       }}"
                       ?disabled=${this.disabled}
                     >
-                      <option value="zoom" .selected="${this._providerId === DEFAULT_MEETING_PROVIDER}">
-                        Zoom
-                      </option>
+                      ${this._getAvailableProviders().map(
+                        (provider) => html`
+                          <option value="${provider}" .selected="${this._providerId === provider}">
+                            ${MEETING_PROVIDER_LABELS[provider]}
+                          </option>
+                        `,
+                      )}
                     </select>
                   </div>
                   <div class="space-y-2 lg:w-1/2">
@@ -1246,6 +1413,7 @@ export class OnlineEventDetails extends LitWrapper {
                               </label>
                               <p class="form-legend">Enable automatic recording for this meeting.</p>
                             </div>
+                            ${this._renderWebinarControl()}
                             ${this._renderRecordingVisibilityControl()}
                           </div>
                         `
@@ -0,0 +1,225 @@
+import { showErrorAlert } from "/static/js/common/alerts.js";
+import { initializeOnReadyAndHtmxLoad, markDatasetReady } from "/static/js/common/dom.js";
+import { isBrowserRedirectError, ocgFetch } from "/static/js/common/fetch.js";
+
+const PASSKEYS_READY_KEY = "passkeysReady";
+
+/**
+ * Decodes a base64url string into an ArrayBuffer.
+ * @param {string} value - Base64url encoded value
+ * @returns {ArrayBuffer}
+ */
+export const base64UrlToBuffer = (value) => {
+  const base64 = value.replace(/-/g, "+").replace(/_/g, "/");
+  const padded = base64.padEnd(base64.length + ((4 - (base64.length % 4)) % 4), "=");
+  const binary = atob(padded);
+  const bytes = new Uint8Array(binary.length);
+  for (let i = 0; i < binary.length; i += 1) {
+    bytes[i] = binary.charCodeAt(i);
+  }
+  return bytes.buffer;
+};
+
+/**
+ * Encodes an ArrayBuffer as an unpadded base64url string.
+ * @param {ArrayBuffer|null} buffer - Buffer to encode
+ * @returns {string|null}
+ */
+export const bufferToBase64Url = (buffer) => {
+  if (!buffer) {
+    return null;
+  }
+  const bytes = new Uint8Array(buffer);
+  let binary = "";
+  bytes.forEach((byte) => {
+    binary += String.fromCharCode(byte);
+  });
+  return btoa(binary).replace(/\+/g, "-").replace(/\//g, "_").replace(/=+$/, "");
+};
+
+/**
+ * Converts a registration challenge into credential creation options.
+ * @param {Object} challenge - Challenge returned by the server
+ * @returns {CredentialCreationOptions}
+ */
+export const toCreationOptions = (challenge) => {
+  const publicKey = challenge.publicKey;
+  return {
+    publicKey: {
+      ...publicKey,
+      challenge: base64UrlToBuffer(publicKey.challenge),
+      excludeCredentials: (publicKey.excludeCredentials || []).map((credential) => ({
+        ...credential,
+        id: base64UrlToBuffer(credential.id),
+      })),
+      user: { ...publicKey.user, id: base64UrlToBuffer(publicKey.user.id) },
+    },
+  };
+};
+
+/**
+ * Converts an authentication challenge into credential request options.
+ * @param {Object} challenge - Challenge returned by the server
+ * @returns {CredentialRequestOptions}
+ */
+export const toRequestOptions = (challenge) => {
+  const publicKey = challenge.publicKey;
+  return {
+    publicKey: {
+      ...publicKey,
+      allowCredentials: (publicKey.allowCredentials || []).map((credential) => ({
+        ...credential,
+        id: base64UrlToBuffer(credential.id),
+      })),
+      challenge: base64UrlToBuffer(publicKey.challenge),
+    },
+  };
+};
+
+/**
+ * Serializes a credential so that it can be sent to the server as JSON.
+ * @param {PublicKeyCredential} credential - Credential returned by the browser
+ * @returns {Object}
+ */
+export const serializeCredential = (credential) => {
+  const response = {};
+  ["attestationObject", "authenticatorData", "clientDataJSON", "signature", "userHandle"].forEach(
+    (field) => {
+      if (credential.response[field] !== undefined) {
+        response[field] = bufferToBase64Url(credential.response[field]);
+      }
+    },
+  );
+  return {
+    extensions: credential.getClientExtensionResults?.() || {},
+    id: credential.id,
+    rawId: bufferToBase64Url(credential.rawId),
+    response,
+    type: credential.type,
+  };
+};
+
+/**
+ * Posts a JSON body using the shared fetch helper.
+ * @param {string} url - Request URL
+ * @param {Object} body - Request body
+ * @returns {Promise<Response>}
+ */
+const postJson = (url, body) =>
+  ocgFetch(url, {
+    body: JSON.stringify(body),
+    headers: { "Content-Type": "application/json" },
+    method: "POST",
+  });
+
+/**
+ * Registers a new passkey for the current user.
+ * @param {string} name - Name of the passkey
+ * @returns {Promise<void>}
+ */
+export const registerPasskey = async (name) => {
+  const startResponse = await postJson("/dashboard/account/passkeys/register/start", {});
+  if (!startResponse.ok) {
+    throw new Error("Passkey registration could not be started.");
+  }
+  const challenge = await startResponse.json();
+  const credential = await navigator.credentials.create(toCreationOptions(challenge));
+
+  const finishResponse = await postJson("/dashboard/account/passkeys/register/finish", {
+    credential: serializeCredential(credential),
+    name,
+  });
+  if (!finishResponse.ok) {
+    throw new Error("Passkey registration could not be completed.");
+  }
+};
+
+/**
+ * Logs a user in with one of their passkeys.
+ * @param {string} username - Username of the user logging in
+ * @param {string} [nextUrl] - URL to redirect to after logging in
+ * @returns {Promise<void>}
+ */
+export const logInWithPasskey = async (username, nextUrl = "") => {
+  const startResponse = await postJson("/log-in/passkey/start", { username });
+  if (!startResponse.ok) {
+    throw new Error("Passkey log in could not be started.");
+  }
+  const challenge = await startResponse.json();
+  const credential = await navigator.credentials.get(toRequestOptions(challenge));
+
+  const query = nextUrl ? `?next_url=${encodeURIComponent(nextUrl)}` : "";
+  const finishResponse = await postJson(`/log-in/passkey/finish${query}`, serializeCredential(credential));
+  if (!finishResponse.ok) {
+    throw new Error("Passkey log in could not be completed.");
+  }
+};
+
+/**
+ * Handles the passkey registration form submission.
+ * @param {SubmitEvent} event - Submit event
+ */
+const handlePasskeyFormSubmit = async (event) => {
+  const form = event.target;
+  if (!(form instanceof HTMLFormElement) || form.id !== "passkey-form") {
+    return;
+  }
+  event.preventDefault();
+
+  const name = form.elements.namedItem("name")?.value.trim() || "";
+  const submitButton = form.querySelector("button[type=submit]");
+  submitButton?.setAttribute("disabled", "");
+  try {
+    await registerPasskey(name);
+  } catch (error) {
+    if (isBrowserRedirectError(error)) {
+      return;
+    }
+    showErrorAlert("Something went wrong adding the passkey. Please try again later.");
+  } finally {
+    submitButton?.removeAttribute("disabled");
+  }
+};
+
+/**
+ * Handles clicks on the passkey log in button.
+ * @param {MouseEvent} event - Click event
+ */
+const handlePasskeyLogInClick = async (event) => {
+  const button = event.target instanceof Element ? event.target.closest("#passkey-login-button") : null;
+  if (!button) {
+    return;
+  }
+
+  const username = document.getElementById("username")?.value.trim() || "";
+  if (!username) {
+    showErrorAlert("Please enter your username to sign in with a passkey.");
+    return;
+  }
+
+  button.setAttribute("disabled", "");
+  try {
+    await logInWithPasskey(username, button.dataset.nextUrl);
+  } catch (error) {
+    if (isBrowserRedirectError(error)) {
+      return;
+    }
+    showErrorAlert("Passkey sign in failed. Please try again.");
+  } finally {
+    button.removeAttribute("disabled");
+  }
+};
+
+/**
+ * Initializes the passkey registration and log in controls.
+ */
+export const initializePasskeys = () => {
+  if (!markDatasetReady(document.documentElement, PASSKEYS_READY_KEY)) {
+    return;
+  }
+
+  document.addEventListener("submit", handlePasskeyFormSubmit);
+  document.addEventListener("click", handlePasskeyLogInClick);
+};
+
+initializeOnReadyAndHtmxLoad(initializePasskeys);
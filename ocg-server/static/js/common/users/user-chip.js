@@ -14,6 +14,7 @@ import "/static/js/common/media/logo-image.js";
  * - bio-is-html: boolean (optional) - When true, bio is rendered as HTML
  * - display-modal: boolean (optional) - When true, clicking opens the user modal
  * - small: boolean (optional) - When true, renders a compact version
+ * - talks-url: string (optional) - URL of the page listing the user's talks
  */
 export class UserChip extends LitWrapper {
   static get properties() {
@@ -23,6 +24,7 @@ export class UserChip extends LitWrapper {
       displayModal: { type: Boolean, attribute: "display-modal" },
       small: { type: Boolean },
       featured: { type: Boolean },
+      talksUrl: { type: String, attribute: "talks-url" },
     };
   }
 
@@ -33,6 +35,7 @@ export class UserChip extends LitWrapper {
     this.displayModal = false;
     this.small = false;
     this.featured = false;
+    this.talksUrl = "";
     this._handleClick = this._handleClick.bind(this);
     this._handleKeydown = this._handleKeydown.bind(this);
   }
@@ -51,7 +54,10 @@ export class UserChip extends LitWrapper {
 
     event.preventDefault();
 
-    dispatchUserModalOpenEvent(this, this.user, { bioIsHtml: this.bioIsHtml });
+    dispatchUserModalOpenEvent(this, this.user, {
+      bioIsHtml: this.bioIsHtml,
+      talksUrl: this.talksUrl || undefined,
+    });
   }
 
   _handleKeydown(event) {
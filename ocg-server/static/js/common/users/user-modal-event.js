@@ -3,9 +3,10 @@
  * @param {Object} user User profile payload.
  * @param {Object} options Modal rendering options.
  * @param {boolean} options.bioIsHtml Whether the bio should render as trusted HTML.
+ * @param {string} options.talksUrl URL of the page listing the user's talks.
  * @returns {Object} User modal event detail.
  */
-const buildUserModalEventDetail = (user, { bioIsHtml = false } = {}) => ({
+const buildUserModalEventDetail = (user, { bioIsHtml = false, talksUrl } = {}) => ({
   name: user?.name,
   username: user?.username,
   imageUrl: user?.photo_url,
@@ -18,6 +19,7 @@ const buildUserModalEventDetail = (user, { bioIsHtml = false } = {}) => ({
   githubUrl: user?.github_url,
   linkedinUrl: user?.linkedin_url,
   provider: user?.provider,
+  talksUrl,
   twitterUrl: user?.twitter_url,
   websiteUrl: user?.website_url,
 });
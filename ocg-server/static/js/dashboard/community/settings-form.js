@@ -2,9 +2,35 @@ import { getElementById, initializeOnReadyAndHtmxLoad, markDatasetReady } from "
 import { bindBooleanToggle } from "/static/js/dashboard/group/page-form-state.js";
 
 const SETTINGS_FORM_ID = "settings-form";
+const DORMANT_GROUPS_REPORT_TOGGLE_ID = "toggle_dormant_groups_report_enabled";
+const DORMANT_GROUPS_REPORT_INPUT_ID = "dormant_groups_report_enabled";
+const EVENT_APPROVAL_TOGGLE_ID = "toggle_event_approval_required";
+const EVENT_APPROVAL_INPUT_ID = "event_approval_required";
 const GROUP_TEAM_RESTRICTION_TOGGLE_ID = "toggle_group_team_management_restricted";
 const GROUP_TEAM_RESTRICTION_INPUT_ID = "group_team_management_restricted";
+const PASSKEY_REQUIRED_TOGGLE_ID = "toggle_passkey_required";
+const PASSKEY_REQUIRED_INPUT_ID = "passkey_required";
 const SETTINGS_BOUND_KEY = "communitySettingsBound";
+const EMAIL_PREVIEW_KIND_ID = "email_preview_kind";
+const EMAIL_PREVIEW_LINK_ID = "email-preview-link";
+const EMAIL_PREVIEW_URL = "/dashboard/community/notifications/preview";
+
+/**
+ * Keeps the email preview link pointing to the selected notification kind.
+ * @param {Document|Element} root - Root element to search from.
+ * @returns {void}
+ */
+const bindEmailPreview = (root) => {
+  const previewKind = getElementById(root, EMAIL_PREVIEW_KIND_ID);
+  const previewLink = getElementById(root, EMAIL_PREVIEW_LINK_ID);
+  if (!previewKind || !previewLink) {
+    return;
+  }
+
+  previewKind.addEventListener("change", () => {
+    previewLink.href = `${EMAIL_PREVIEW_URL}/${encodeURIComponent(previewKind.value)}`;
+  });
+};
 
 /**
  * Initializes community settings form behavior.
@@ -17,14 +43,36 @@ export const initializeCommunitySettings = (root = document) => {
     return;
   }
 
+  const dormantGroupsReportToggle = getElementById(root, DORMANT_GROUPS_REPORT_TOGGLE_ID);
+  const dormantGroupsReportInput = getElementById(root, DORMANT_GROUPS_REPORT_INPUT_ID);
+  const eventApprovalToggle = getElementById(root, EVENT_APPROVAL_TOGGLE_ID);
+  const eventApprovalInput = getElementById(root, EVENT_APPROVAL_INPUT_ID);
   const groupTeamRestrictionToggle = getElementById(root, GROUP_TEAM_RESTRICTION_TOGGLE_ID);
   const groupTeamRestrictionInput = getElementById(root, GROUP_TEAM_RESTRICTION_INPUT_ID);
+  const passkeyRequiredToggle = getElementById(root, PASSKEY_REQUIRED_TOGGLE_ID);
+  const passkeyRequiredInput = getElementById(root, PASSKEY_REQUIRED_INPUT_ID);
 
+  bindBooleanToggle({
+    toggle: dormantGroupsReportToggle,
+    hiddenInput: dormantGroupsReportInput,
+    syncOnInit: true,
+  });
+  bindBooleanToggle({
+    toggle: eventApprovalToggle,
+    hiddenInput: eventApprovalInput,
+    syncOnInit: true,
+  });
   bindBooleanToggle({
     toggle: groupTeamRestrictionToggle,
     hiddenInput: groupTeamRestrictionInput,
     syncOnInit: true,
   });
+  bindBooleanToggle({
+    toggle: passkeyRequiredToggle,
+    hiddenInput: passkeyRequiredInput,
+    syncOnInit: true,
+  });
+  bindEmailPreview(root);
 };
 
 initializeOnReadyAndHtmxLoad(initializeCommunitySettings);
@@ -17,8 +17,9 @@ import { parseJsonAttribute } from "/static/js/common/utils.js";
  * TeamAddMember component for inviting team members.
  *
  * Displays a compact "Add member" control. Clicking it opens a modal that
- * contains a search field to find users. Selecting a user shows a confirmation
- * badge and enables submission. Submits via HTMX to keep server flow and
+ * contains a search field to find users. Selecting a user (or an email address,
+ * when email invites are enabled) shows a confirmation badge and enables
+ * submission. Submits via HTMX to keep server flow and
  * auto-refresh the team table using the backend HX-Trigger.
  *
  * Uses light DOM (via LitWrapper) so Tailwind and HTMX selectors apply to the
@@ -30,17 +31,21 @@ export class TeamAddMember extends LitWrapper {
    * Component properties definition
    * @property {string} dashboardType - Dashboard context ("community").
    * @property {string} disabledTooltip - Tooltip shown when inviting is disabled.
+   * @property {boolean} emailInvitesEnabled - Whether users can be invited by email.
    * @property {boolean} _isOpen - Internal modal visibility state.
+   * @property {string} _selectedEmail - Selected email address.
    * @property {Object|null} _selectedUser - Selected user object.
    */
   static properties = {
     dashboardType: { type: String, attribute: "dashboard-type" },
     canManageTeam: { attribute: "can-manage-team", reflect: true },
     disabledTooltip: { type: String, attribute: "disabled-tooltip" },
+    emailInvitesEnabled: { type: Boolean, attribute: "email-invites-enabled" },
     selectedUsers: { type: Array, attribute: false },
     roleOptions: { type: Array, attribute: false },
     disabledUserIds: { type: Array, attribute: false },
     _isOpen: { type: Boolean },
+    _selectedEmail: { type: String },
     _selectedRole: { type: String },
     _selectedUser: { type: Object },
   };
@@ -50,10 +55,12 @@ export class TeamAddMember extends LitWrapper {
     this.canManageTeam = true;
     this.dashboardType = "community";
     this.disabledTooltip = "Your role cannot invite team members.";
+    this.emailInvitesEnabled = false;
     this.selectedUsers = [];
     this.roleOptions = [];
     this.disabledUserIds = [];
     this._isOpen = false;
+    this._selectedEmail = "";
     this._selectedRole = "";
     this._selectedUser = null;
     this._handleKeydown = this._handleKeydown.bind(this);
@@ -141,18 +148,38 @@ export class TeamAddMember extends LitWrapper {
   }
 
   /**
-   * Receives selected user and updates hidden input + submit button state.
+   * Returns whether a user or an email address has been selected.
+   * @returns {boolean} Whether an invite target is selected
+   * @private
+   */
+  _hasTarget() {
+    return Boolean(this._selectedUser || this._selectedEmail);
+  }
+
+  /**
+   * Receives selected email and updates hidden inputs + submit button state.
+   * @param {CustomEvent} event - Event with detail.email
+   * @private
+   */
+  _onEmailSelected(event) {
+    const email = event.detail?.email;
+    if (!email) return;
+    this._selectedEmail = email;
+    this._selectedUser = null;
+    this._syncTargetInputs();
+  }
+
+  /**
+   * Receives selected user and updates hidden inputs + submit button state.
    * @param {CustomEvent} event - Event with detail.user
    * @private
    */
   _onUserSelected(event) {
     const user = event.detail?.user;
     if (!user) return;
+    this._selectedEmail = "";
     this._selectedUser = user;
-    const userIdInput = getElementById(this, "team-add-user-id");
-    const submitBtn = getElementById(this, "team-add-submit");
-    if (userIdInput) userIdInput.value = user.user_id;
-    if (submitBtn) submitBtn.disabled = !(this._selectedRole && this._selectedUser);
+    this._syncTargetInputs();
   }
 
   /**
@@ -163,7 +190,26 @@ export class TeamAddMember extends LitWrapper {
   _onRoleChanged(event) {
     this._selectedRole = event.target?.value || "";
     const submitBtn = getElementById(this, "team-add-submit");
-    if (submitBtn) submitBtn.disabled = !(this._selectedRole && this._selectedUser);
+    if (submitBtn) submitBtn.disabled = !(this._selectedRole && this._hasTarget());
+  }
+
+  /**
+   * Updates the hidden inputs so only the selected target is submitted.
+   * @private
+   */
+  _syncTargetInputs() {
+    const userIdInput = getElementById(this, "team-add-user-id");
+    const emailInput = getElementById(this, "team-add-email");
+    const submitBtn = getElementById(this, "team-add-submit");
+    if (userIdInput) {
+      userIdInput.value = this._selectedUser?.user_id || "";
+      userIdInput.disabled = !this._selectedUser;
+    }
+    if (emailInput) {
+      emailInput.value = this._selectedEmail;
+      emailInput.disabled = !this._selectedEmail;
+    }
+    if (submitBtn) submitBtn.disabled = !(this._selectedRole && this._hasTarget());
   }
 
   /**
@@ -190,7 +236,9 @@ export class TeamAddMember extends LitWrapper {
           const xhr = event.detail?.xhr;
           const ok = handleHtmxResponse({
             xhr,
-            successMessage: "Invitation sent to the selected user.",
+            successMessage: this._selectedEmail
+              ? "Invitation sent to the email address provided."
+              : "Invitation sent to the selected user.",
             errorMessage: "Something went wrong adding this team member. Please try again later.",
           });
           if (ok) {
@@ -214,14 +262,12 @@ export class TeamAddMember extends LitWrapper {
    * @private
    */
   _resetSelection() {
+    this._selectedEmail = "";
     this._selectedUser = null;
     this._selectedRole = "";
-    const userIdInput = getElementById(this, "team-add-user-id");
     const roleSelect = getElementById(this, "team-add-role");
-    const submitBtn = getElementById(this, "team-add-submit");
-    if (userIdInput) userIdInput.value = "";
     if (roleSelect) roleSelect.value = "";
-    if (submitBtn) submitBtn.disabled = !(this._selectedRole && this._selectedUser);
+    this._syncTargetInputs();
   }
 
   /**
@@ -230,6 +276,16 @@ export class TeamAddMember extends LitWrapper {
    * @private
    */
   _renderSelectedBadge() {
+    if (this._selectedEmail) {
+      return html`
+        <div class="inline-flex items-center gap-2 bg-stone-100 rounded-full ps-1 pe-2 py-1">
+          <span class="inline-flex size-[24px] shrink-0 items-center justify-center rounded-full bg-stone-200">
+            <div class="svg-icon size-3.5 icon-email bg-stone-600"></div>
+          </span>
+          <span class="text-sm text-stone-700">${this._selectedEmail}</span>
+        </div>
+      `;
+    }
     const u = this._selectedUser;
     if (!u) return html``;
     const initials = computeUserInitials(u.name, u.username, 2);
@@ -284,11 +340,17 @@ export class TeamAddMember extends LitWrapper {
                   <user-search-field
                     dashboard-type=${this.dashboardType}
                     label="team member"
-                    legend="Search for users by their name or username"
+                    legend=${this.emailInvitesEnabled
+                      ? "Search for users by their name or username, or enter an email address"
+                      : "Search for users by their name or username"}
+                    ?email-action-enabled=${this.emailInvitesEnabled}
+                    email-action-text="Invite by email"
                     .disabledUserIds=${this.disabledUserIds || []}
+                    @email-action-selected=${(event) => this._onEmailSelected(event)}
                     @user-selected=${(event) => this._onUserSelected(event)}
                   ></user-search-field>
-                  <input type="hidden" name="user_id" id="team-add-user-id" />
+                  <input type="hidden" name="user_id" id="team-add-user-id" disabled />
+                  <input type="hidden" name="email" id="team-add-email" disabled />
                 </div>
                 <div class="mb-6">
                   <label for="team-add-role" class="form-label">Role</label>
@@ -309,7 +371,7 @@ export class TeamAddMember extends LitWrapper {
                     id="team-add-submit"
                     type="submit"
                     class="btn-primary"
-                    ?disabled=${!this._selectedUser || !this._selectedRole}
+                    ?disabled=${!this._hasTarget() || !this._selectedRole}
                   >
                     Add
                   </button>
@@ -112,7 +112,7 @@ class SessionCard extends LitWrapper {
             ${this._renderSpeakerAvatars()}
           </div>
           <div class="text-sm text-stone-500 truncate w-full">
-            ${kindName}${session.location ? html` · ${session.location}` : ""}
+            ${[kindName, session.track, session.location].filter(Boolean).join(" · ")}
           </div>
         </div>
 
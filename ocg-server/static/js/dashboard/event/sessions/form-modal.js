@@ -24,10 +24,12 @@ class SessionFormModal extends LitWrapper {
    * @property {Array} approvedSubmissions - Approved CFS submissions.
    * @property {Array} usedSubmissionIds - Submission ids used by other sessions.
    * @property {Object} meetingMaxParticipants - Limits per meeting provider.
+   * @property {Array} meetingProviders - Meeting providers enabled on the server.
    * @property {boolean} meetingsEnabled - Whether meetings can be configured.
    * @property {number} descriptionMaxLength - Max session description length.
    * @property {number} sessionNameMaxLength - Max session title length.
    * @property {number} locationMaxLength - Max session location length.
+   * @property {number} trackMaxLength - Max session track length.
    * @property {boolean} disabled - Whether editing controls are disabled.
    * @property {boolean} eventPast - Whether the parent event is in the past.
    * @property {boolean} _isOpen - Whether the modal is visible.
@@ -40,10 +42,12 @@ class SessionFormModal extends LitWrapper {
     approvedSubmissions: { type: Array },
     usedSubmissionIds: { type: Array },
     meetingMaxParticipants: { type: Object },
+    meetingProviders: { type: Array },
     meetingsEnabled: { type: Boolean },
     descriptionMaxLength: { type: Number },
     sessionNameMaxLength: { type: Number },
     locationMaxLength: { type: Number },
+    trackMaxLength: { type: Number },
     disabled: { type: Boolean },
     eventPast: { type: Boolean },
     _isOpen: { type: Boolean },
@@ -58,10 +62,12 @@ class SessionFormModal extends LitWrapper {
     this.approvedSubmissions = [];
     this.usedSubmissionIds = [];
     this.meetingMaxParticipants = {};
+    this.meetingProviders = [];
     this.meetingsEnabled = false;
     this.descriptionMaxLength = undefined;
     this.sessionNameMaxLength = undefined;
     this.locationMaxLength = undefined;
+    this.trackMaxLength = undefined;
     this.disabled = false;
     this.eventPast = false;
     this._isOpen = false;
@@ -254,10 +260,12 @@ class SessionFormModal extends LitWrapper {
                 .approvedSubmissions=${this.approvedSubmissions}
                 .usedSubmissionIds=${currentUsedIds}
                 .meetingMaxParticipants=${this.meetingMaxParticipants}
+                .meetingProviders=${this.meetingProviders}
                 .meetingsEnabled=${this.meetingsEnabled}
                 .descriptionMaxLength=${this.descriptionMaxLength}
                 .sessionNameMaxLength=${this.sessionNameMaxLength}
                 .locationMaxLength=${this.locationMaxLength}
+                .trackMaxLength=${this.trackMaxLength}
                 .onDataChange=${this._onDataChange}
                 .disabled=${this.disabled}
                 .eventPast=${this.eventPast}
@@ -69,6 +69,7 @@ const renderSessionHiddenInputs = (session, index) => {
     <input type="hidden" name="sessions[${index}][starts_at]" value=${session.starts_at || ""} />
     <input type="hidden" name="sessions[${index}][ends_at]" value=${session.ends_at || ""} />
     <input type="hidden" name="sessions[${index}][location]" value=${session.location || ""} />
+    <input type="hidden" name="sessions[${index}][track]" value=${session.track || ""} />
     <input type="hidden" name="sessions[${index}][description]" value=${values.description} />
     <input
       type="hidden"
@@ -37,10 +37,12 @@ class SessionItem extends LitWrapper {
    * @property {Array} approvedSubmissions - Approved CFS submissions.
    * @property {Array} usedSubmissionIds - Submission ids used by other sessions.
    * @property {Object} meetingMaxParticipants - Limits per meeting provider.
+   * @property {Array} meetingProviders - Meeting providers enabled on the server.
    * @property {boolean} meetingsEnabled - Whether meetings can be configured.
    * @property {number} descriptionMaxLength - Max session description length.
    * @property {number} sessionNameMaxLength - Max session title length.
    * @property {number} locationMaxLength - Max session location length.
+   * @property {number} trackMaxLength - Max session track length.
    * @property {boolean} disabled - Whether editing controls are disabled.
    * @property {string} inputMode - Entry mode, manual or cfs.
    * @property {string} prefilledDate - Date used to pre-fill time fields.
@@ -55,10 +57,12 @@ class SessionItem extends LitWrapper {
     approvedSubmissions: { type: Array },
     usedSubmissionIds: { type: Array },
     meetingMaxParticipants: { type: Object, attribute: "meeting-max-participants" },
+    meetingProviders: { type: Array },
     meetingsEnabled: { type: Boolean },
     descriptionMaxLength: { type: Number, attribute: "description-max-length" },
     sessionNameMaxLength: { type: Number, attribute: "session-name-max-length" },
     locationMaxLength: { type: Number, attribute: "location-max-length" },
+    trackMaxLength: { type: Number, attribute: "track-max-length" },
     disabled: { type: Boolean },
     inputMode: { type: String },
     prefilledDate: { type: String },
@@ -84,6 +88,7 @@ class SessionItem extends LitWrapper {
       meeting_provider_id: "",
       meeting_hosts: [],
       speakers: [],
+      track: "",
     };
     this.index = 0;
     this.isObjectEmpty = true;
@@ -92,10 +97,12 @@ class SessionItem extends LitWrapper {
     this.approvedSubmissions = [];
     this.usedSubmissionIds = [];
     this.meetingMaxParticipants = {};
+    this.meetingProviders = [];
     this.meetingsEnabled = false;
     this.descriptionMaxLength = undefined;
     this.sessionNameMaxLength = undefined;
     this.locationMaxLength = undefined;
+    this.trackMaxLength = undefined;
     this.disabled = false;
     this.inputMode = "manual";
     this.prefilledDate = "";
@@ -226,11 +233,13 @@ class SessionItem extends LitWrapper {
     const endTime = extractTimePart(this.data.ends_at);
     const sessionNameMaxLength = normalizeMaxLength(this.sessionNameMaxLength);
     const locationMaxLength = normalizeMaxLength(this.locationMaxLength);
+    const trackMaxLength = normalizeMaxLength(this.trackMaxLength);
     const sessionNameId = `session-${this.index}-name`;
     const sessionKindId = `session-${this.index}-kind`;
     const sessionStartsAtId = `session-${this.index}-starts-at`;
     const sessionEndsAtId = `session-${this.index}-ends-at`;
     const sessionLocationId = `session-${this.index}-location`;
+    const sessionTrackId = `session-${this.index}-track`;
     const sessionCfsSubmissionId = `session-${this.index}-cfs-submission`;
     const sessionDescriptionId = `session-${this.index}-description`;
     const meetingJoinUrlId = `session-${this.index}-meeting-join-url`;
@@ -379,6 +388,28 @@ class SessionItem extends LitWrapper {
         </div>
       </div>
 
+      <div class="col-span-full">
+        <label for=${sessionTrackId} class="form-label"> Track </label>
+        <div class="mt-2">
+          <input
+            @input=${(event) => this._onInputChange(event)}
+            data-name="track"
+            id=${sessionTrackId}
+            type="text"
+            name="sessions[${this.index}][track]"
+            class="input-primary"
+            value=${this.data.track || ""}
+            placeholder="Optional - groups parallel sessions in the agenda"
+            autocomplete="off"
+            autocorrect="off"
+            autocapitalize="off"
+            spellcheck="false"
+            maxlength=${trackMaxLength}
+            ?disabled=${this.disabled}
+          />
+        </div>
+      </div>
+
       ${
         this.approvedSubmissions?.length
           ? html`
@@ -566,12 +597,16 @@ class SessionItem extends LitWrapper {
                             ?meeting-in-sync=${this.data.meeting_in_sync}
                             meeting-password=${this.data.meeting_password || ""}
                             meeting-error=${this.data.meeting_error || ""}
+                            meeting-provider-id=${this.data.meeting_provider_id || ""}
+                            meeting-provider-status=${this.data.meeting_provider_status || ""}
+                            meeting-participants-joined-count=${this.data.meeting_participants_joined_count || 0}
                             starts-at=${this.data.starts_at || ""}
                             ends-at=${this.data.ends_at || ""}
                             .meetingRecordingRawUrls=${this.data.meeting_recording_raw_urls || []}
                             .meetingHosts=${this.data.meeting_hosts || {}}
                             .meetingJoinInstructions=${this.data.meeting_join_instructions || ""}
                             .meetingMaxParticipants=${this.meetingMaxParticipants || {}}
+                            .meetingProviders=${this.meetingProviders || []}
                             .eventPast=${this.eventPast}
                             field-name-prefix="sessions[${this.index}]"
                             ?disabled=${this.disabled}
@@ -26,6 +26,7 @@ export const createEmptySession = (id = 0) => ({
   meeting_recording_url: "",
   meeting_hosts: [],
   speakers: [],
+  track: "",
 });
 
 /**
@@ -36,10 +36,12 @@ export class SessionsSection extends LitWrapper {
    * @property {Array} approvedSubmissions - Approved CFS submissions.
    * @property {string} timezone - Timezone used for datetime conversion.
    * @property {Object} meetingMaxParticipants - Limits per meeting provider.
+   * @property {Array} meetingProviders - Meeting providers enabled on the server.
    * @property {boolean} meetingsEnabled - Whether meetings can be configured.
    * @property {number} descriptionMaxLength - Max session description length.
    * @property {number} sessionNameMaxLength - Max session title length.
    * @property {number} locationMaxLength - Max session location length.
+   * @property {number} trackMaxLength - Max session track length.
    * @property {boolean} disabled - Whether editing controls are disabled.
    * @property {string} eventStartsAt - Event start datetime.
    * @property {string} eventEndsAt - Event end datetime.
@@ -51,10 +53,12 @@ export class SessionsSection extends LitWrapper {
     approvedSubmissions: { type: Array, attribute: "approved-submissions" },
     timezone: { type: String, attribute: "timezone" },
     meetingMaxParticipants: { type: Object, attribute: "meeting-max-participants" },
+    meetingProviders: { type: Array, attribute: "meeting-providers" },
     meetingsEnabled: { type: Boolean, attribute: "meetings-enabled" },
     descriptionMaxLength: { type: Number, attribute: "description-max-length" },
     sessionNameMaxLength: { type: Number, attribute: "session-name-max-length" },
     locationMaxLength: { type: Number, attribute: "location-max-length" },
+    trackMaxLength: { type: Number, attribute: "track-max-length" },
     disabled: { type: Boolean },
     eventStartsAt: { type: String, attribute: "event-starts-at" },
     eventEndsAt: { type: String, attribute: "event-ends-at" },
@@ -67,10 +71,12 @@ export class SessionsSection extends LitWrapper {
     this.sessionKinds = [];
     this.approvedSubmissions = [];
     this.meetingMaxParticipants = {};
+    this.meetingProviders = [];
     this.meetingsEnabled = false;
     this.descriptionMaxLength = undefined;
     this.sessionNameMaxLength = undefined;
     this.locationMaxLength = undefined;
+    this.trackMaxLength = undefined;
     this.disabled = false;
     this.eventStartsAt = "";
     this.eventEndsAt = "";
@@ -94,6 +100,7 @@ export class SessionsSection extends LitWrapper {
     this.sessionKinds = parseArrayAttribute(this.sessionKinds);
     this.approvedSubmissions = parseArrayAttribute(this.approvedSubmissions);
     this.meetingMaxParticipants = parseObjectAttribute(this.meetingMaxParticipants);
+    this.meetingProviders = parseArrayAttribute(this.meetingProviders);
   }
 
   /**
@@ -362,10 +369,12 @@ export class SessionsSection extends LitWrapper {
         .approvedSubmissions=${this.approvedSubmissions}
         .usedSubmissionIds=${usedSubmissionIds}
         .meetingMaxParticipants=${this.meetingMaxParticipants}
+        .meetingProviders=${this.meetingProviders}
         .meetingsEnabled=${this.meetingsEnabled}
         .descriptionMaxLength=${this.descriptionMaxLength}
         .sessionNameMaxLength=${this.sessionNameMaxLength}
         .locationMaxLength=${this.locationMaxLength}
+        .trackMaxLength=${this.trackMaxLength}
         .disabled=${this.disabled}
         .eventPast=${this.eventPast}
         @session-saved=${this._handleSessionSaved}
@@ -211,9 +211,9 @@ export class SponsorsSection extends LitWrapper {
     if (this.disabled) return;
     const exists = (this.selectedSponsors || []).some((s) => s.group_sponsor_id === sponsor.group_sponsor_id);
     if (!exists) {
-      // Open level modal for the selected sponsor
+      // Open level modal for the selected sponsor, suggesting its tier
       this.pendingSponsor = sponsor;
-      this.pendingLevel = "";
+      this.pendingLevel = sponsor.tier_name || "";
       this.showLevelModal = true;
     } else {
       this._cleanEnteredValue();
@@ -120,6 +120,7 @@ const serializeTicketTypes = ({ currencyCode, fieldNamePrefix, rows, timezone })
  */
 class TicketTypesEditor extends TicketingEditorBase {
   static properties = {
+    freeOnly: { type: Boolean, attribute: "free-only" },
     ticketTypes: {
       type: Array,
       attribute: "ticket-types",
@@ -134,6 +135,7 @@ class TicketTypesEditor extends TicketingEditorBase {
   constructor() {
     super();
     this.fieldNamePrefix = "ticket_types";
+    this.freeOnly = false;
     this.presenceFieldName = "ticket_types_present";
     this.ticketTypes = [];
   }
@@ -245,7 +247,7 @@ class TicketTypesEditor extends TicketingEditorBase {
   _createEmptyPriceWindow() {
     return {
       _row_id: this._nextRowId(),
-      amount: "",
+      amount: this.freeOnly ? "0" : "",
       ends_at: "",
       event_ticket_price_window_id: "",
       starts_at: "",
@@ -465,6 +467,7 @@ class TicketTypesEditor extends TicketingEditorBase {
 
   /**
    * Applies Stripe amount limit validation to draft price inputs.
+   * Only free tickets are allowed when the editor is in free-only mode.
    * @returns {void}
    */
   _syncPriceWindowAmountValidity() {
@@ -476,6 +479,14 @@ class TicketTypesEditor extends TicketingEditorBase {
       }
 
       const amountMinor = parseCurrencyInputToMinorUnits(field.value, currencyCode);
+      if (this.freeOnly) {
+        field.setCustomValidity(
+          amountMinor !== null && amountMinor > 0
+            ? "Only free tickets are available until payments are configured for this group."
+            : "",
+        );
+        return;
+      }
       field.setCustomValidity(validateStripePaymentAmountMinor(amountMinor, currencyCode));
     });
   }
@@ -611,7 +622,7 @@ class TicketTypesEditor extends TicketingEditorBase {
             <div class="mt-4 grid gap-4 md:grid-cols-3">
               <div>
                 <label class="form-label" for=${`ticket-price-${windowRow._row_id}`}>
-                  Price ${this._currencyLabelSuffix()} <span class="asterisk">*</span>
+                  Price ${this.freeOnly ? "" : this._currencyLabelSuffix()} <span class="asterisk">*</span>
                 </label>
                 <div class="mt-2">
                   <input
@@ -620,7 +631,7 @@ class TicketTypesEditor extends TicketingEditorBase {
                     data-ticket-window-field="amount"
                     data-window-row-id=${String(windowRow._row_id)}
                     type="number"
-                    max=${this._currencyInputMax()}
+                    max=${this.freeOnly ? "0" : this._currencyInputMax()}
                     min="0"
                     step=${this._currencyInputStep()}
                     class="input-primary"
@@ -634,7 +645,11 @@ class TicketTypesEditor extends TicketingEditorBase {
                     }}
                   />
                 </div>
-                <p class="form-legend">Use <span class="font-semibold">0</span> for free tickets.</p>
+                ${this.freeOnly
+                  ? html`<p class="form-legend">
+                      Only free tickets are available until payments are configured for this group.
+                    </p>`
+                  : html`<p class="form-legend">Use <span class="font-semibold">0</span> for free tickets.</p>`}
               </div>
 
               <div>
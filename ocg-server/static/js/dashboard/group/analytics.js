@@ -178,11 +178,56 @@ const initAttendeesCharts = (stats = {}, palette) =>
     monthlyDescription: "Event RSVPs created each month",
   });
 
+/**
+ * Build charts for check-ins metrics.
+ * @param {Object} stats - Check-ins stats payload.
+ * @param {Object} palette - Theme palette.
+ * @returns {Array<echarts.ECharts>} Initialized charts.
+ */
+const initCheckInsCharts = (stats = {}, palette) =>
+  buildTrendCharts({
+    stats,
+    palette,
+    runningChartId: "check-ins-running-chart",
+    monthlyChartId: "check-ins-monthly-chart",
+    runningTitle: "Check-ins over time",
+    monthlyTitle: "Check-ins per Month",
+    label: "Check-ins",
+    runningDescription: "Cumulative event check-ins over time",
+    monthlyDescription: "Attendees checked in each month",
+  });
+
+/**
+ * Build charts for retention metrics.
+ * @param {Object} stats - Retention stats payload.
+ * @param {Object} palette - Theme palette.
+ * @returns {Array<echarts.ECharts>} Initialized charts.
+ */
+const initRetentionCharts = (stats = {}, palette) => {
+  const charts = [];
+  const monthlyData = stats.per_month || [];
+
+  addRenderedChart(
+    charts,
+    "retention-monthly-chart",
+    createMonthlyBarChart("Returning Attendees per Month", "Returning attendees", monthlyData, palette, {
+      description: "Attendees that had already attended an earlier event",
+      reservePeriodStart: true,
+    }),
+    hasChartData(monthlyData),
+  );
+
+  return charts;
+};
+
 /**
  * Initialize all analytics charts for the group dashboard.
- * @param {Object} stats - Group analytics payload from the server.
+ * @param {Object} payload - Group analytics payload from the server.
+ * @param {Object} payload.engagement - Engagement stats payload.
+ * @param {Object} payload.stats - Group stats payload.
  */
-export const initAnalyticsCharts = async (stats) => {
+export const initAnalyticsCharts = async (payload) => {
+  const { engagement = {}, stats } = payload || {};
   if (!stats) {
     return;
   }
@@ -196,6 +241,8 @@ export const initAnalyticsCharts = async (stats) => {
       ...initMembersCharts(stats.members, palette),
       ...initEventsCharts(stats.events, palette),
       ...initAttendeesCharts(stats.attendees, palette),
+      ...initCheckInsCharts(engagement.check_ins, palette),
+      ...initRetentionCharts(engagement.retention, palette),
     ];
 
     const hydratedCharts = charts.filter(Boolean);
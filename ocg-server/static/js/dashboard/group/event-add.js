@@ -41,6 +41,8 @@ const DRAFT_EVENT_TIME_OPTIONS = {
  * @param {HTMLElement|null} config.recurrenceAdditionalOccurrencesContainer Wrapper element
  * @param {HTMLInputElement|null} config.recurrenceAdditionalOccurrencesInput Additional count input
  * @param {HTMLSelectElement|null} config.recurrencePatternSelect Recurrence select
+ * @param {HTMLElement|null} config.recurrenceSeriesNameContainer Series name wrapper element
+ * @param {HTMLInputElement|null} config.recurrenceSeriesNameInput Series name input
  * @param {HTMLInputElement|null} config.startsAtInput Event start input
  * @returns {void}
  */
@@ -48,6 +50,8 @@ const initializeRecurrenceFields = ({
   recurrenceAdditionalOccurrencesContainer,
   recurrenceAdditionalOccurrencesInput,
   recurrencePatternSelect,
+  recurrenceSeriesNameContainer,
+  recurrenceSeriesNameInput,
   startsAtInput,
 }) => {
   if (!recurrencePatternSelect) {
@@ -61,6 +65,11 @@ const initializeRecurrenceFields = ({
       recurrenceAdditionalOccurrencesInput,
       recurrencePatternSelect,
     });
+    updateRecurrenceSeriesNameState({
+      recurrencePatternSelect,
+      recurrenceSeriesNameContainer,
+      recurrenceSeriesNameInput,
+    });
   };
 
   recurrencePatternSelect.addEventListener("change", update);
@@ -231,6 +240,32 @@ const updateRecurrenceAdditionalOccurrencesState = ({
   }
 };
 
+/**
+ * Toggles the series name input when recurring creation is selected.
+ * @param {Object} config Series name configuration
+ * @param {HTMLSelectElement} config.recurrencePatternSelect Recurrence select
+ * @param {HTMLElement|null} config.recurrenceSeriesNameContainer Series name wrapper element
+ * @param {HTMLInputElement|null} config.recurrenceSeriesNameInput Series name input
+ * @returns {void}
+ */
+const updateRecurrenceSeriesNameState = ({
+  recurrencePatternSelect,
+  recurrenceSeriesNameContainer,
+  recurrenceSeriesNameInput,
+}) => {
+  const recurring = recurrencePatternSelect.value !== "just-once";
+  setElementHidden(recurrenceSeriesNameContainer, !recurring);
+
+  if (!recurrenceSeriesNameInput) {
+    return;
+  }
+
+  recurrenceSeriesNameInput.disabled = !recurring;
+  if (!recurring) {
+    recurrenceSeriesNameInput.value = "";
+  }
+};
+
 /**
  * Initializes the event add page behavior for the active form fragment.
  * @param {Document|Element} [root=document] Root page container
@@ -262,6 +297,8 @@ export const initializeEventAddPage = (root = document) => {
   );
   const recurrenceAdditionalOccurrencesInput = getElementById(pageRoot, "recurrence_additional_occurrences");
   const recurrencePatternSelect = getElementById(pageRoot, "recurrence_pattern");
+  const recurrenceSeriesNameContainer = getElementById(pageRoot, "recurrence-series-name-container");
+  const recurrenceSeriesNameInput = getElementById(pageRoot, "recurrence_series_name");
 
   // Sessions need the parent event date range before their own validation runs.
   const syncSessionsDateRange = createSessionsDateRangeSync({
@@ -294,6 +331,8 @@ export const initializeEventAddPage = (root = document) => {
     recurrenceAdditionalOccurrencesContainer,
     recurrenceAdditionalOccurrencesInput,
     recurrencePatternSelect,
+    recurrenceSeriesNameContainer,
+    recurrenceSeriesNameInput,
     startsAtInput,
   });
   initializeDraftEventReminder({
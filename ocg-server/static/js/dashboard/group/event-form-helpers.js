@@ -153,6 +153,36 @@ const setAttendeeApprovalRequired = (isRequired) => {
   }
 };
 
+/**
+ * Sets attendance certificate emails toggle and hidden input.
+ * @param {boolean} isEnabled Whether checked-in attendees receive certificates by email
+ */
+const setAttendanceCertificateEmailsEnabled = (isEnabled) => {
+  const toggle = getElementById(document, "toggle_attendance_certificate_emails_enabled");
+  const hidden = getElementById(document, "attendance_certificate_emails_enabled");
+  if (toggle) {
+    toggle.checked = !!isEnabled;
+  }
+  if (hidden) {
+    hidden.value = isEnabled ? "true" : "false";
+  }
+};
+
+/**
+ * Sets attendee photos toggle and hidden input.
+ * @param {boolean} isEnabled Whether attendees can share event photos
+ */
+const setAttendeePhotosEnabled = (isEnabled) => {
+  const toggle = getElementById(document, "toggle_attendee_photos_enabled");
+  const hidden = getElementById(document, "attendee_photos_enabled");
+  if (toggle) {
+    toggle.checked = !!isEnabled;
+  }
+  if (hidden) {
+    hidden.value = isEnabled ? "true" : "false";
+  }
+};
+
 /**
  * Sets waitlist toggle and hidden input.
  * @param {boolean} isEnabled Whether waitlist is enabled
@@ -494,7 +524,9 @@ export {
   buildSessionEntries,
   initializeSessionsRemovalWarning,
   normalizeSpeakers,
+  setAttendanceCertificateEmailsEnabled,
   setAttendeeApprovalRequired,
+  setAttendeePhotosEnabled,
   setCategoryValue,
   setDiscountCodes,
   setEventReminderEnabled,
@@ -311,6 +311,16 @@ const initializeCommonEventPageToggles = ({
     hiddenInput: getElementById(pageRoot, "event_reminder_enabled"),
   });
 
+  bindBooleanToggle({
+    toggle: getElementById(pageRoot, "toggle_attendee_photos_enabled"),
+    hiddenInput: getElementById(pageRoot, "attendee_photos_enabled"),
+  });
+
+  bindBooleanToggle({
+    toggle: getElementById(pageRoot, "toggle_attendance_certificate_emails_enabled"),
+    hiddenInput: getElementById(pageRoot, "attendance_certificate_emails_enabled"),
+  });
+
   bindBooleanToggle({
     toggle: getElementById(pageRoot, "toggle_meeting_recording_published"),
     hiddenInput: getElementById(pageRoot, "meeting_recording_published"),
@@ -1,6 +1,9 @@
-import { handleHtmxResponse } from "/static/js/common/alerts.js";
+import { handleHtmxResponse, showInfoAlert } from "/static/js/common/alerts.js";
 import { convertDateTimeLocalToISO } from "/static/js/common/datetime.js";
 
+// Response header carrying the warning about events overlapping the saved one.
+const EVENT_CONFLICTS_HEADER = "X-OCG-Event-Conflicts";
+
 /**
  * Converts shared event and session datetime request parameters to ISO format.
  * @param {Record<string, string>} parameters HTMX request parameters.
@@ -9,7 +12,7 @@ import { convertDateTimeLocalToISO } from "/static/js/common/datetime.js";
 const convertSharedEventDateParameters = (parameters) => {
   Object.keys(parameters).forEach((key) => {
     const isEventDate = key.match(
-      /^(starts_at|ends_at|registration_starts_at|registration_ends_at|cfs_starts_at|cfs_ends_at)$/,
+      /^(starts_at|ends_at|publish_scheduled_at|registration_starts_at|registration_ends_at|cfs_starts_at|cfs_ends_at)$/,
     );
     const isSessionDate = key.match(/^sessions\[\d+\]\[(starts_at|ends_at)\]$/);
     if ((isEventDate || isSessionDate) && parameters[key]) {
@@ -18,6 +21,28 @@ const convertSharedEventDateParameters = (parameters) => {
   });
 };
 
+/**
+ * Reads the warning about overlapping events sent with a save response.
+ * @param {XMLHttpRequest|undefined|null} xhr HTMX response XHR.
+ * @returns {string} Decoded warning, or an empty string when there is none.
+ */
+export const getEventConflictsWarning = (xhr) => {
+  if (!xhr || typeof xhr.getResponseHeader !== "function") {
+    return "";
+  }
+
+  const warning = xhr.getResponseHeader(EVENT_CONFLICTS_HEADER);
+  if (!warning) {
+    return "";
+  }
+
+  try {
+    return decodeURIComponent(warning);
+  } catch {
+    return "";
+  }
+};
+
 /**
  * Attaches the shared HTMX config-request datetime normalization flow.
  * @param {Object} config Config-request configuration.
@@ -72,13 +97,19 @@ export const attachEventSaveAfterRequest = ({
       return;
     }
 
+    // Overlapping events do not prevent saving, but organizers are warned.
+    const xhr = event.detail?.xhr;
+    const conflictsWarning = getEventConflictsWarning(xhr);
     const ok = handleHtmxResponse({
-      xhr: event.detail?.xhr,
-      successMessage,
+      xhr,
+      successMessage: conflictsWarning ? "" : successMessage,
       errorMessage,
     });
 
     if (ok) {
+      if (conflictsWarning) {
+        showInfoAlert(`${successMessage} ${conflictsWarning}`);
+      }
       onSuccess();
     }
   });
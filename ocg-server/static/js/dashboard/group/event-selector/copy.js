@@ -1,7 +1,9 @@
 import { setImageFieldValue, setSelectValue, setTextValue } from "/static/js/common/utils.js";
 import {
   appendCopySuffix,
+  setAttendanceCertificateEmailsEnabled,
   setAttendeeApprovalRequired,
+  setAttendeePhotosEnabled,
   setCategoryValue,
   setDiscountCodes,
   setEventReminderEnabled,
@@ -69,6 +71,7 @@ export const applyCopiedEventDetails = async (details) => {
 
   resetCopiedMeetingFields();
   setTextValue("name", appendCopySuffix(details.name));
+  setTextValue("publish_scheduled_at", "");
   setTextValue("registration_ends_at", "");
   setTextValue("registration_starts_at", "");
   setCategoryValue(details);
@@ -88,6 +91,8 @@ export const applyCopiedEventDetails = async (details) => {
   setTextValue("meetup_url", details.meetup_url);
   setTextValue("luma_url", details.luma_url);
   setGalleryImages(details.photos_urls);
+  setAttendeePhotosEnabled(details.attendee_photos_enabled === true);
+  setAttendanceCertificateEmailsEnabled(details.attendance_certificate_emails_enabled === true);
   setTags(details.tags);
   setPaymentCurrencyCode(details.payment_currency_code);
   await setTicketTypes(details.ticket_types);
@@ -104,3 +109,18 @@ export const applyCopiedEventDetails = async (details) => {
   setSponsors(details.sponsors);
   setSessions([]);
 };
+
+/**
+ * Applies the event details saved in a template into the event form.
+ * @param {object} details Event template details payload
+ * @returns {Promise<void>}
+ */
+export const applyEventTemplateDetails = async (details) => {
+  if (!details || typeof details !== "object") {
+    return;
+  }
+
+  await applyCopiedEventDetails(details);
+  setTextValue("name", details.name);
+  setSessions([details.sessions || []]);
+};
@@ -25,7 +25,10 @@ import {
   renderEventSelectorDropdownContent,
   renderEventSelectorPreview,
 } from "/static/js/dashboard/group/event-selector/dropdown.js";
-import { applyCopiedEventDetails } from "/static/js/dashboard/group/event-selector/copy.js";
+import {
+  applyCopiedEventDetails,
+  applyEventTemplateDetails,
+} from "/static/js/dashboard/group/event-selector/copy.js";
 import "/static/js/common/svg-spinner.js";
 
 /**
@@ -43,6 +46,8 @@ class EventSelector extends LitWrapper {
    * - community: community slug for event search
    * - groupSlug: group slug for event search
    * - buttonId: optional button id to control focus interactions
+   * - copyEventId: event uuid to copy into the form once rendered
+   * - templateId: event template uuid to apply to the form once rendered
    * - _isOpen: dropdown visibility flag
    * - _query: current search term
    * - _results: fetched events list
@@ -65,6 +70,8 @@ class EventSelector extends LitWrapper {
     community: { type: String, attribute: "community" },
     groupSlug: { type: String, attribute: "group-slug" },
     buttonId: { type: String, attribute: "button-id" },
+    copyEventId: { type: String, attribute: "copy-event-id" },
+    templateId: { type: String, attribute: "template-id" },
     _isOpen: { state: true },
     _query: { state: true },
     _results: { state: true },
@@ -82,6 +89,8 @@ class EventSelector extends LitWrapper {
     this.community = "";
     this.groupSlug = "";
     this.buttonId = "";
+    this.copyEventId = "";
+    this.templateId = "";
     this._isOpen = false;
     this._query = "";
     this._results = [];
@@ -99,6 +108,17 @@ class EventSelector extends LitWrapper {
     };
   }
 
+  /**
+   * Copies the requested event or template into the form after the first render.
+   */
+  firstUpdated() {
+    if (this.copyEventId) {
+      this._handleCopyMode({ event_id: this.copyEventId });
+    } else if (this.templateId) {
+      this._handleTemplateMode(this.templateId);
+    }
+  }
+
   /**
    * Cleans listeners and pending work when detached.
    */
@@ -252,6 +272,28 @@ class EventSelector extends LitWrapper {
     }
   }
 
+  /**
+   * Executes template mode flow: fetch template details and populate the form.
+   * @param {string} templateId Event template identifier
+   */
+  async _handleTemplateMode(templateId) {
+    if (!templateId || this._copyLoading) {
+      return;
+    }
+    this._setCopyLoading(true);
+    try {
+      const details = await this._fetchTemplateDetails(templateId);
+      await this._applyTemplateDetails(details);
+      this._scrollToTop();
+      this._showTemplateSuccess();
+    } catch (error) {
+      console.error("Failed to apply event template", error);
+      this._showTemplateError();
+    } finally {
+      this._setCopyLoading(false);
+    }
+  }
+
   /**
    * Shows or hides the copy loading indicator.
    * @param {boolean} loading Loading state
@@ -287,6 +329,23 @@ class EventSelector extends LitWrapper {
     return response.json();
   }
 
+  /**
+   * Fetches the event details saved in a template.
+   * @param {string} templateId Event template identifier
+   * @returns {Promise<object>}
+   */
+  async _fetchTemplateDetails(templateId) {
+    const url = `/dashboard/group/events/templates/${encodeURIComponent(templateId)}/details`;
+    const response = await ocgFetch(url, {
+      headers: { Accept: "application/json" },
+      credentials: "same-origin",
+    });
+    if (!response.ok) {
+      throw new Error(`Failed to fetch event template ${templateId}`);
+    }
+    return response.json();
+  }
+
   /**
    * Applies copied event details into the form.
    * @param {object} details Event details payload
@@ -296,6 +355,15 @@ class EventSelector extends LitWrapper {
     await applyCopiedEventDetails(details);
   }
 
+  /**
+   * Applies event template details into the form.
+   * @param {object} details Event template details payload
+   * @returns {Promise<void>}
+   */
+  async _applyTemplateDetails(details) {
+    await applyEventTemplateDetails(details);
+  }
+
   /**
    * Updates selector state after copying.
    * @param {object} details Copied event details
@@ -332,6 +400,20 @@ class EventSelector extends LitWrapper {
     showErrorAlert("Unable to copy that event right now. Please try again.");
   }
 
+  /**
+   * Displays a success alert after applying a template.
+   */
+  _showTemplateSuccess() {
+    showInfoAlert("Event template applied. Set the schedule before publishing.");
+  }
+
+  /**
+   * Displays an error alert if applying a template fails.
+   */
+  _showTemplateError() {
+    showErrorAlert("Unable to apply that event template right now. Please try again.");
+  }
+
   /**
    * Keeps selected event id aligned with provided event payload.
    */
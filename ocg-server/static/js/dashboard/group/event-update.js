@@ -29,7 +29,7 @@ import { initializeSectionTabs } from "/static/js/dashboard/group/page-form-stat
  */
 const readBooleanDataAttribute = (element, attributeName) => element?.dataset?.[attributeName] === "true";
 
-const canceledEventReviewSections = new Set(["submissions", "attendees", "invitation-requests", "waitlist"]);
+const canceledEventReviewSections = new Set(["submissions", "attendees", "invitation-requests", "waitlist", "photos", "comments"]);
 const EVENT_UPDATE_PAGE_SELECTOR = '[data-event-page="update"]';
 
 /**
@@ -0,0 +1,176 @@
+import { initializeOnReady, markDatasetReady, setElementHidden } from "/static/js/common/dom.js";
+import { ocgFetch } from "/static/js/common/fetch.js";
+
+const KIOSK_SELECTOR = "[data-check-in-kiosk]";
+const QR_CODE_ID = "kiosk-qr-code";
+const SCAN_RESULT_ID = "kiosk-scan-result";
+const SCANNER_VIDEO_ID = "kiosk-scanner-video";
+const START_SCANNER_BUTTON_ID = "kiosk-start-scanner";
+const KIOSK_READY_KEY = "checkInKioskReady";
+const QR_CODE_REFRESH_INTERVAL_MS = 30 * 1000;
+const SCAN_INTERVAL_MS = 500;
+const SCAN_REPEAT_DELAY_MS = 5 * 1000;
+const TICKET_CODE_PATTERN = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$/i;
+const DEFAULT_ERROR_MESSAGE = "Something went wrong checking in this ticket. Please try again.";
+
+const RESULT_TONE_CLASSES = {
+  error: ["border-red-200", "bg-red-50", "text-red-800"],
+  success: ["border-green-200", "bg-green-50", "text-green-800"],
+  warning: ["border-amber-200", "bg-amber-50", "text-amber-900"],
+};
+
+/**
+ * Returns whether the scanned value looks like an attendee ticket code.
+ * @param {string} value Scanned value.
+ * @returns {boolean}
+ */
+export const isTicketCode = (value) => TICKET_CODE_PATTERN.test(String(value ?? "").trim());
+
+/**
+ * Builds the QR code URL, adding a timestamp so browsers fetch the rotated code.
+ * @param {string} qrCodeUrl Kiosk QR code endpoint.
+ * @param {number} timestamp Current time in milliseconds.
+ * @returns {string}
+ */
+export const buildQrCodeUrl = (qrCodeUrl, timestamp) => `${qrCodeUrl}?t=${timestamp}`;
+
+/**
+ * Checks in the attendee owning a ticket and returns the result to display.
+ * @param {string} ticketsUrl Kiosk tickets endpoint.
+ * @param {string} ticketCode Scanned ticket code.
+ * @returns {Promise<{tone: string, message: string}>}
+ */
+export const checkInTicket = async (ticketsUrl, ticketCode) => {
+  try {
+    const response = await ocgFetch(`${ticketsUrl}/${ticketCode.trim()}`, {
+      credentials: "same-origin",
+      method: "POST",
+    });
+
+    if (response.ok) {
+      const attendee = await response.json();
+      const attendeeLabel = attendee.ticket_title
+        ? `${attendee.name} (${attendee.ticket_title})`
+        : attendee.name;
+      if (attendee.already_checked_in) {
+        return { tone: "warning", message: `${attendeeLabel} is already checked in.` };
+      }
+      return { tone: "success", message: `${attendeeLabel} is checked in. Welcome!` };
+    }
+
+    if (response.status === 404) {
+      return { tone: "error", message: "This ticket is not valid for this event." };
+    }
+
+    const serverError = response.status === 422 ? await response.text() : "";
+    return { tone: "error", message: serverError || DEFAULT_ERROR_MESSAGE };
+  } catch {
+    return { tone: "error", message: DEFAULT_ERROR_MESSAGE };
+  }
+};
+
+/**
+ * Displays the result of a ticket check-in.
+ * @param {HTMLElement|null} element Result container.
+ * @param {{tone: string, message: string}} result Result to display.
+ * @returns {void}
+ */
+export const showScanResult = (element, { tone, message }) => {
+  if (!element) {
+    return;
+  }
+
+  Object.values(RESULT_TONE_CLASSES).forEach((classes) => element.classList.remove(...classes));
+  element.classList.add(...(RESULT_TONE_CLASSES[tone] || RESULT_TONE_CLASSES.error));
+  element.textContent = message;
+  setElementHidden(element, false);
+};
+
+/**
+ * Starts the camera and checks in the tickets detected in the video stream.
+ * @param {HTMLElement} kiosk Kiosk root element.
+ * @returns {Promise<void>}
+ */
+const startTicketScanner = async (kiosk) => {
+  const video = kiosk.querySelector(`#${SCANNER_VIDEO_ID}`);
+  const button = kiosk.querySelector(`#${START_SCANNER_BUTTON_ID}`);
+  const result = kiosk.querySelector(`#${SCAN_RESULT_ID}`);
+
+  if (!("BarcodeDetector" in window) || !navigator.mediaDevices?.getUserMedia) {
+    showScanResult(result, {
+      tone: "error",
+      message: "This browser cannot scan tickets. Try a recent version of Chrome or Edge.",
+    });
+    return;
+  }
+
+  try {
+    video.srcObject = await navigator.mediaDevices.getUserMedia({
+      video: { facingMode: "environment" },
+    });
+    await video.play();
+  } catch {
+    showScanResult(result, {
+      tone: "error",
+      message: "Unable to access the camera. Please check the camera permissions.",
+    });
+    return;
+  }
+
+  setElementHidden(video, false);
+  setElementHidden(button, true);
+
+  // Detect ticket codes periodically, skipping repeated scans of the same ticket
+  const detector = new window.BarcodeDetector({ formats: ["qr_code"] });
+  const lastScans = new Map();
+  let checkingIn = false;
+  window.setInterval(async () => {
+    if (checkingIn) {
+      return;
+    }
+
+    let codes = [];
+    try {
+      codes = await detector.detect(video);
+    } catch {
+      return;
+    }
+
+    const ticketCode = codes.map((code) => code.rawValue).find(isTicketCode);
+    const now = Date.now();
+    if (!ticketCode || now - (lastScans.get(ticketCode) || 0) < SCAN_REPEAT_DELAY_MS) {
+      return;
+    }
+    lastScans.set(ticketCode, now);
+
+    checkingIn = true;
+    showScanResult(result, await checkInTicket(kiosk.dataset.ticketsUrl, ticketCode));
+    checkingIn = false;
+  }, SCAN_INTERVAL_MS);
+};
+
+/**
+ * Initializes the check-in kiosk: rotates the QR code and sets up the ticket scanner.
+ * @param {Document|Element} [root=document] Query root.
+ * @returns {void}
+ */
+export const initializeCheckInKiosk = (root = document) => {
+  const kiosk = root.querySelector(KIOSK_SELECTOR);
+  if (!markDatasetReady(kiosk, KIOSK_READY_KEY)) {
+    return;
+  }
+
+  // Refresh the QR code periodically so it always shows the current code
+  const qrCode = kiosk.querySelector(`#${QR_CODE_ID}`);
+  if (qrCode) {
+    window.setInterval(() => {
+      qrCode.setAttribute("src", buildQrCodeUrl(kiosk.dataset.qrCodeUrl, Date.now()));
+    }, QR_CODE_REFRESH_INTERVAL_MS);
+  }
+
+  kiosk
+    .querySelector(`#${START_SCANNER_BUTTON_ID}`)
+    ?.addEventListener("click", () => startTicketScanner(kiosk));
+};
+
+initializeOnReady(() => initializeCheckInKiosk());
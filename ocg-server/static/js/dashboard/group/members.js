@@ -1,9 +1,35 @@
 import { createNotificationModal } from "/static/js/dashboard/group/notification-modal.js";
-import { initializeOnReadyAndHtmxLoad } from "/static/js/common/dom.js";
+import { getElementById, initializeOnReadyAndHtmxLoad, markDatasetReady } from "/static/js/common/dom.js";
 
 const modalId = "notification-modal";
 const formId = "notification-form";
 const dataKey = "membersNotificationReady";
+const audienceDataKey = "membersNotificationAudienceReady";
+const eventAudiences = ["checked-in-attendees", "event-attendees"];
+
+/**
+ * Show only the fields required by the selected audience. Hidden fields are
+ * disabled so they are not submitted with the form.
+ * @param {Document|Element} root Query root.
+ */
+const syncAudienceFields = (root) => {
+  const audience = getElementById(root, "audience")?.value;
+  const fields = [
+    ["audience-event-field", "audience-event-id", eventAudiences.includes(audience)],
+    ["audience-inactive-months-field", "audience-inactive-months", audience === "inactive-members"],
+    ["audience-member-role-field", "audience-member-role", audience === "members-with-role"],
+  ];
+
+  fields.forEach(([fieldId, inputId, visible]) => {
+    const field = getElementById(root, fieldId);
+    const input = getElementById(root, inputId);
+    if (!field || !input) {
+      return;
+    }
+    field.classList.toggle("hidden", !visible);
+    input.disabled = !visible;
+  });
+};
 
 // Reuse the shared helper for the members notification modal.
 const initializeMembersNotification = (root = document) => {
@@ -15,9 +41,20 @@ const initializeMembersNotification = (root = document) => {
     closeButtonId: "close-notification-modal",
     cancelButtonId: "cancel-notification",
     overlayId: "overlay-notification-modal",
-    successMessage: "Email sent successfully to all group members.",
+    successMessage: "Email sent successfully to the selected group members.",
     root,
   });
+
+  // Toggle the audience fields when the selected audience changes
+  const audienceSelect = getElementById(root, "audience");
+  if (!audienceSelect || !markDatasetReady(audienceSelect, audienceDataKey)) {
+    return;
+  }
+  audienceSelect.addEventListener("change", () => syncAudienceFields(root));
+  getElementById(root, formId)?.addEventListener("reset", () => {
+    setTimeout(() => syncAudienceFields(root), 0);
+  });
+  syncAudienceFields(root);
 };
 
 initializeOnReadyAndHtmxLoad(initializeMembersNotification);
@@ -11,7 +11,7 @@ import {
 import { renderQuestionsEditor } from "/static/js/dashboard/group/questions-editor/render.js";
 
 /**
- * Renders the event registration questions editor and its form payload fields.
+ * Renders the event registration and group join questions editor and its form payload fields.
  * @extends LitWrapper
  */
 class QuestionsEditor extends LitWrapper {
@@ -1,16 +1,18 @@
 import { getElementById, initializeOnReadyAndHtmxLoad, markDatasetReady } from "/static/js/common/dom.js";
 
-const OPTIONAL_NOTIFICATIONS_INPUT_ID = "optional_notifications_enabled";
-const OPTIONAL_NOTIFICATIONS_TOGGLE_ID = "toggle_optional_notifications_enabled";
+const NOTIFICATION_TOGGLES = {
+  toggle_optional_notifications_enabled: "optional_notifications_enabled",
+  toggle_weekly_digest_enabled: "weekly_digest_enabled",
+};
 const ACCOUNT_SETTINGS_READY_KEY = "userAccountSettingsReady";
 
 /**
- * Syncs the hidden optional notifications input with the checkbox state.
- * @param {HTMLInputElement} toggle - Optional notifications checkbox
+ * Syncs the hidden notification preference input with the checkbox state.
+ * @param {HTMLInputElement} toggle - Notification preference checkbox
  * @param {Document|Element} [root=document] Root page container
  */
-const syncOptionalNotificationsInput = (toggle, root = document) => {
-  const input = getElementById(root, OPTIONAL_NOTIFICATIONS_INPUT_ID);
+const syncNotificationInput = (toggle, root = document) => {
+  const input = getElementById(root, NOTIFICATION_TOGGLES[toggle.id]);
   if (input instanceof HTMLInputElement) {
     input.value = String(toggle.checked);
   }
@@ -22,8 +24,8 @@ const syncOptionalNotificationsInput = (toggle, root = document) => {
  */
 const handleAccountSettingsChange = (event) => {
   const target = event.target;
-  if (target instanceof HTMLInputElement && target.id === OPTIONAL_NOTIFICATIONS_TOGGLE_ID) {
-    syncOptionalNotificationsInput(target, target.form || document);
+  if (target instanceof HTMLInputElement && Object.hasOwn(NOTIFICATION_TOGGLES, target.id)) {
+    syncNotificationInput(target, target.form || document);
   }
 };
 
@@ -32,10 +34,12 @@ const handleAccountSettingsChange = (event) => {
  * @param {Document|Element} [root=document] Root page container
  */
 export const initializeUserAccountSettings = (root = document) => {
-  const toggle = getElementById(root, OPTIONAL_NOTIFICATIONS_TOGGLE_ID);
-  if (toggle instanceof HTMLInputElement) {
-    syncOptionalNotificationsInput(toggle, root);
-  }
+  Object.keys(NOTIFICATION_TOGGLES).forEach((toggleId) => {
+    const toggle = getElementById(root, toggleId);
+    if (toggle instanceof HTMLInputElement) {
+      syncNotificationInput(toggle, root);
+    }
+  });
 
   if (!markDatasetReady(document.documentElement, ACCOUNT_SETTINGS_READY_KEY)) {
     return;
@@ -3,6 +3,9 @@ import { initializeOnReadyAndHtmxLoad, markDatasetReady } from "/static/js/commo
 const DAY_TAB_SELECTOR = "[data-day-tab]";
 const DAY_PANEL_SELECTOR = "[data-day-content]";
 const DAY_TAB_READY_KEY = "dayTabReady";
+const TRACK_FILTER_SELECTOR = "[data-track-filter]";
+const TRACK_SESSION_SELECTOR = "[data-session-track]";
+const TRACK_FILTER_READY_KEY = "trackFilterReady";
 
 /**
  * Shows the agenda panel matching the selected day tab.
@@ -21,11 +24,53 @@ const selectAgendaDay = (selectedTab, dayTabs, dayPanels) => {
   });
 };
 
+/**
+ * Shows the agenda sessions matching the selected track filter.
+ * Sessions without a track are shared by all tracks and stay visible.
+ * @param {HTMLElement} selectedFilter - Selected track filter button
+ * @param {NodeListOf<HTMLElement>} trackFilters - Track filter buttons
+ * @param {NodeListOf<HTMLElement>} trackSessions - Sessions assigned to a track
+ */
+const selectAgendaTrack = (selectedFilter, trackFilters, trackSessions) => {
+  const track = selectedFilter.getAttribute("data-track-filter");
+  trackFilters.forEach((filter) => {
+    filter.setAttribute("data-active", filter === selectedFilter ? "true" : "false");
+  });
+  trackSessions.forEach((session) => {
+    const isVisible = !track || session.getAttribute("data-session-track") === track;
+    session.toggleAttribute("hidden", !isVisible);
+  });
+};
+
+/**
+ * Initializes agenda track filters on event pages.
+ * @param {Document|Element} root - Root element containing agenda track filters
+ */
+const initializeAgendaTrackFilters = (root) => {
+  const trackFilters = root.querySelectorAll(TRACK_FILTER_SELECTOR);
+  const trackSessions = root.querySelectorAll(TRACK_SESSION_SELECTOR);
+  if (trackFilters.length === 0) {
+    return;
+  }
+
+  trackFilters.forEach((filter) => {
+    if (!markDatasetReady(filter, TRACK_FILTER_READY_KEY)) {
+      return;
+    }
+
+    filter.addEventListener("click", () => {
+      selectAgendaTrack(filter, trackFilters, trackSessions);
+    });
+  });
+};
+
 /**
  * Initializes agenda day tabs on event pages.
  * @param {Document|Element} root - Root element containing agenda tabs
  */
 export const initializeAgendaTabs = (root = document) => {
+  initializeAgendaTrackFilters(root);
+
   const dayTabs = root.querySelectorAll(DAY_TAB_SELECTOR);
   const dayPanels = root.querySelectorAll(DAY_PANEL_SELECTOR);
   if (dayTabs.length === 0 || dayPanels.length === 0) {
@@ -23,6 +23,7 @@ export const JOIN_WAITLIST_LABEL = "Join waiting list";
 export const REQUEST_INVITATION_LABEL = "Request invitation";
 const COMPLETE_REGISTRATION_LABEL = "Complete registration";
 const COMPLETE_PAYMENT_LABEL = "Complete payment";
+const CONFIRM_SPOT_LABEL = "Confirm your spot";
 export const CANCEL_ATTENDANCE_LABEL = "Cancel attendance";
 const CANCEL_CHECKOUT_LABEL = "Cancel checkout";
 export const CANCEL_INVITATION_REQUEST_LABEL = "Cancel request";
@@ -55,7 +56,7 @@ const CANCEL_CHECKOUT_TITLE = "Release this ticket hold and choose again.";
 /**
  * Returns the attendee refund-control state for the current response.
  * @param {{isPastEvent: boolean}} meta - Attendance metadata
- * @param {{can_request_refund?: boolean, purchase_amount_minor?: number, refund_request_status?: string}} response - Attendance response
+ * @param {{can_request_refund?: boolean, purchase_amount_minor?: number, refund_request_status?: string, reminder_preference?: string}} response - Attendance response
  * @returns {{disabled?: boolean, label?: string|null, title?: string|null}} Render state
  */
 const getRefundState = (meta, response) => {
@@ -411,6 +412,23 @@ const resetPrimaryControls = (container) => {
   if (actionsMenu instanceof HTMLDetailsElement) {
     actionsMenu.open = false;
   }
+  renderReminderPreference(false);
+};
+
+/**
+ * Toggles the reminder preference form based on attendance status.
+ * @param {boolean} isAttendee - Whether the user is attending
+ * @param {string|null} [preference] - Reminder preference chosen for the event
+ */
+export const renderReminderPreference = (isAttendee, preference = "") => {
+  document.querySelectorAll("[data-reminder-preference]").forEach((form) => {
+    setElementHidden(form, !isAttendee);
+
+    const select = form.querySelector("select[name='reminder_preference']");
+    if (isAttendee && select instanceof HTMLSelectElement) {
+      select.value = preference || "";
+    }
+  });
 };
 
 /**
@@ -523,6 +541,29 @@ export const showRegistrationQuestionsPendingState = (container, meta, response
   }
 };
 
+/**
+ * Shows the state for a seat offered from the waitlist awaiting confirmation.
+ * @param {HTMLElement} container - Attendance container element
+ * @param {{canceled: boolean, isPastEvent: boolean}} meta - Attendance metadata
+ */
+export const showWaitlistConfirmationPendingState = (container, meta) => {
+  // Promoted users can confirm even after public registration closes
+  showPrimaryAttendanceState(
+    container,
+    meta,
+    "attendButton",
+    withEventActionState(meta, {
+      icon: ATTEND_EVENT_ICON,
+      label: CONFIRM_SPOT_LABEL,
+    }),
+  );
+
+  const { attendButton } = getPrimaryControls(container);
+  if (attendButton instanceof HTMLButtonElement) {
+    attendButton.dataset.registrationQuestionsPending = "true";
+  }
+};
+
 /**
  * Shows the approved invitation state for an attendee.
  * @param {HTMLElement} container - Attendance container element
@@ -686,6 +727,7 @@ export const showAttendeeState = (container, meta, response) => {
   const { leaveButton, refundButton } = getPrimaryControls(container);
 
   resetPrimaryControls(container);
+  renderReminderPreference(true, response.reminder_preference);
 
   if (
     response.refund_request_status ||
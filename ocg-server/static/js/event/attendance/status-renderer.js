@@ -8,6 +8,7 @@ import {
   showPendingPaymentState,
   showRegistrationQuestionsPendingState,
   showRejectedInvitationState,
+  showWaitlistConfirmationPendingState,
   showWaitlistedAttendanceState,
 } from "/static/js/event/attendance-view.js";
 import { parseJsonResponse, showSignedOutFallback } from "/static/js/event/attendance/shared.js";
@@ -54,6 +55,11 @@ export const renderAttendanceCheckResponse = (container, event) => {
     return;
   }
 
+  if (response.status === "waitlist-confirmation-pending") {
+    showWaitlistConfirmationPendingState(container, meta);
+    return;
+  }
+
   if (response.status === "pending-approval") {
     showPendingApprovalAttendanceState(container, meta);
     return;
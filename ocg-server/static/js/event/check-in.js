@@ -10,6 +10,7 @@ const CHECK_IN_FORM_ID = "event-check-in-form";
 const SUCCESS_CARD_ID = "check-in-success-card";
 const FORM_CONTAINER_ID = "check-in-form-container";
 const VIEW_DETAILS_BUTTON_ID = "view-event-details-button";
+const TICKET_ID = "check-in-ticket";
 const CHECK_IN_READY_KEY = "checkInReady";
 
 /**
@@ -19,6 +20,7 @@ const showCheckedInState = () => {
   setElementHidden(getElementById(document, SUCCESS_CARD_ID), false);
   setElementHidden(getElementById(document, FORM_CONTAINER_ID), true);
   setElementHidden(getElementById(document, CHECK_IN_FORM_ID), true);
+  setElementHidden(getElementById(document, TICKET_ID), true);
   setElementHidden(getElementById(document, VIEW_DETAILS_BUTTON_ID), false);
 };
 
@@ -0,0 +1,95 @@
+import { initializeOnReadyAndHtmxLoad, markDatasetReady } from "/static/js/common/dom.js";
+
+const LOCAL_TIME_SELECTOR = "[data-viewer-local-time]";
+const LOCAL_TIME_READY_KEY = "viewerLocalTimeReady";
+
+/**
+ * Resolves the timezone configured in the viewer's browser.
+ * @returns {string} IANA timezone identifier, or "" when unavailable
+ */
+export const resolveViewerTimezone = () => {
+  try {
+    return Intl.DateTimeFormat().resolvedOptions().timeZone || "";
+  } catch (_) {
+    return "";
+  }
+};
+
+/**
+ * Formats the event dates in the timezone provided.
+ * @param {Date} startsAt - Event start date
+ * @param {Date|null} endsAt - Event end date
+ * @param {string} timezone - IANA timezone identifier
+ * @returns {string} Formatted date range
+ */
+const formatEventDates = (startsAt, endsAt, timezone) => {
+  const formatter = new Intl.DateTimeFormat("en-US", {
+    day: "numeric",
+    hour: "numeric",
+    minute: "2-digit",
+    month: "short",
+    timeZone: timezone,
+    timeZoneName: "short",
+  });
+
+  return endsAt ? formatter.formatRange(startsAt, endsAt) : formatter.format(startsAt);
+};
+
+/**
+ * Builds the label with the event dates converted to the viewer timezone.
+ * Returns an empty label when the viewer already uses the event local time.
+ * @param {Object} options - Label options
+ * @param {number} options.startsAt - Event start Unix timestamp in seconds
+ * @param {number|null} [options.endsAt] - Event end Unix timestamp in seconds
+ * @param {string} options.eventTimezone - Event IANA timezone identifier
+ * @param {string} options.viewerTimezone - Viewer IANA timezone identifier
+ * @returns {string} Label to display
+ */
+export const buildViewerLocalTimeLabel = ({
+  startsAt,
+  endsAt = null,
+  eventTimezone,
+  viewerTimezone,
+}) => {
+  if (!Number.isFinite(startsAt) || !eventTimezone || !viewerTimezone) {
+    return "";
+  }
+
+  const start = new Date(startsAt * 1000);
+  const end = Number.isFinite(endsAt) && endsAt > startsAt ? new Date(endsAt * 1000) : null;
+  try {
+    const viewerDates = formatEventDates(start, end, viewerTimezone);
+    if (viewerDates === formatEventDates(start, end, eventTimezone)) {
+      return "";
+    }
+
+    return `Your time: ${viewerDates}`;
+  } catch (_) {
+    return "";
+  }
+};
+
+/**
+ * Displays the event dates converted to the viewer timezone.
+ * @param {Document|Element} root - Root element containing local time placeholders
+ */
+export const initializeViewerLocalTimes = (root = document) => {
+  const viewerTimezone = resolveViewerTimezone();
+
+  root.querySelectorAll(LOCAL_TIME_SELECTOR).forEach((element) => {
+    if (!markDatasetReady(element, LOCAL_TIME_READY_KEY)) {
+      return;
+    }
+
+    const label = buildViewerLocalTimeLabel({
+      startsAt: Number.parseInt(element.dataset.startsAt || "", 10),
+      endsAt: Number.parseInt(element.dataset.endsAt || "", 10),
+      eventTimezone: element.dataset.eventTimezone || "",
+      viewerTimezone,
+    });
+    element.textContent = label;
+    element.classList.toggle("hidden", label === "");
+  });
+};
+
+initializeOnReadyAndHtmxLoad(initializeViewerLocalTimes);
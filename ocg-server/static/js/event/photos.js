@@ -0,0 +1,65 @@
+import { toggleModalVisibility } from "/static/js/common/modals/modal-lifecycle.js";
+import { closestElement, getElementById, isElementHidden, markDatasetReady } from "/static/js/common/dom.js";
+
+const ROOT_ID = "photos-modal-root";
+const MODAL_ID = "photos-modal";
+const DATA_KEY = "photosModalReady";
+
+const initializeSubmitControls = (modal) => {
+  const form = getElementById(modal, "photo-submission-form");
+  const submit = getElementById(modal, "photo-submit-button");
+  const imageField = form?.querySelector("image-field");
+  if (!imageField || !submit) {
+    return;
+  }
+
+  // The submit button stays disabled until a photo has been uploaded
+  const syncSubmitState = () => {
+    const disabled = !imageField.value;
+    submit.disabled = disabled;
+    submit.classList.toggle("opacity-50", disabled);
+    submit.classList.toggle("cursor-not-allowed", disabled);
+  };
+
+  syncSubmitState();
+  form.addEventListener("image-change", syncSubmitState);
+};
+
+const initializePhotosModal = () => {
+  const modal = getElementById(document, MODAL_ID);
+  if (!modal) {
+    return;
+  }
+
+  if (markDatasetReady(modal, DATA_KEY)) {
+    const closeButton = getElementById(modal, "close-photos-modal");
+    const overlay = getElementById(modal, "overlay-photos-modal");
+    const toggleModal = () => toggleModalVisibility(MODAL_ID);
+
+    closeButton?.addEventListener("click", toggleModal);
+    overlay?.addEventListener("click", toggleModal);
+    modal.addEventListener("click", (event) => {
+      if (closestElement(event.target, "#cancel-photos-modal")) {
+        toggleModal();
+      }
+    });
+  }
+
+  initializeSubmitControls(modal);
+};
+
+const handleModalSwap = (event) => {
+  if (event?.target?.id !== ROOT_ID) {
+    return;
+  }
+  initializePhotosModal();
+
+  const modal = getElementById(document, MODAL_ID);
+  if (isElementHidden(modal)) {
+    toggleModalVisibility(MODAL_ID);
+  }
+};
+
+if (markDatasetReady(document.documentElement, "photosModalSwapReady")) {
+  document.addEventListener("htmx:afterSwap", handleModalSwap);
+}
@@ -4,13 +4,19 @@ import {
   closestElement,
   getElementById,
   initializeOnReadyAndHtmxLoad,
+  isDatasetReady,
+  isElementHidden,
   markDatasetReady,
   setElementHidden,
 } from "/static/js/common/dom.js";
+import { toggleModalVisibility } from "/static/js/common/modals/modal-lifecycle.js";
+import { collectQuestionAnswers } from "/static/js/common/question-answers.js";
 import { parseJsonText } from "/static/js/common/utils.js";
 
 const MEMBERSHIP_CONTAINER_SELECTOR = "#membership-container";
 const GROUP_ACTIONS_MENU_SELECTOR = "[data-group-actions-menu]";
+const LEAVE_BUTTON_IDS = ["leave-btn", "cancel-request-btn"];
+const JOIN_QUESTIONS_MODAL_ID = "join-questions-modal";
 
 /**
  * Returns all membership containers within a root node.
@@ -61,8 +67,9 @@ const handleMembershipCheckResponse = (event) => {
   const signinButton = getElementById(container, "signin-btn");
   const joinButton = getElementById(container, "join-btn");
   const leaveButton = getElementById(container, "leave-btn");
+  const cancelRequestButton = getElementById(container, "cancel-request-btn");
 
-  if (!loadingButton || !signinButton || !joinButton || !leaveButton) {
+  if (!loadingButton || !signinButton || !joinButton || !leaveButton || !cancelRequestButton) {
     return;
   }
 
@@ -70,6 +77,7 @@ const handleMembershipCheckResponse = (event) => {
   setElementHidden(signinButton, true);
   setElementHidden(joinButton, true);
   setElementHidden(leaveButton, true);
+  setElementHidden(cancelRequestButton, true);
 
   const xhr = event.detail?.xhr;
 
@@ -80,8 +88,10 @@ const handleMembershipCheckResponse = (event) => {
       return;
     }
 
-    if (response.is_member) {
+    if (response.status === "member") {
       setElementHidden(leaveButton, false);
+    } else if (response.status === "pending-approval") {
+      setElementHidden(cancelRequestButton, false);
     } else {
       setElementHidden(joinButton, false);
     }
@@ -92,15 +102,41 @@ const handleMembershipCheckResponse = (event) => {
 };
 
 /**
- * Handles join button beforeRequest state.
+ * Opens or closes the join questions modal.
+ * @param {HTMLElement} container - Membership container element
+ * @param {boolean} open - Whether the modal should be open
+ */
+const setJoinQuestionsModalOpen = (container, open) => {
+  const modal = getElementById(container, JOIN_QUESTIONS_MODAL_ID);
+  if (!modal || isElementHidden(modal) !== open) {
+    return;
+  }
+
+  toggleModalVisibility(JOIN_QUESTIONS_MODAL_ID);
+};
+
+/**
+ * Handles join button beforeRequest state, asking the join questions first
+ * when the group has any.
+ * @param {Event} event - htmx:beforeRequest event
  * @param {HTMLElement} target - Event target
  */
-const handleJoinBeforeRequest = (target) => {
+const handleJoinBeforeRequest = (event, target) => {
   if (target.id !== "join-btn") {
     return;
   }
 
   const container = closestElement(target, MEMBERSHIP_CONTAINER_SELECTOR);
+  if (
+    container &&
+    getElementById(container, JOIN_QUESTIONS_MODAL_ID) &&
+    !isDatasetReady(container, "joinAnswersReady")
+  ) {
+    event.preventDefault();
+    setJoinQuestionsModalOpen(container, true);
+    return;
+  }
+
   const loadingButton = container ? getElementById(container, "loading-btn") : null;
   if (!loadingButton) {
     return;
@@ -111,11 +147,11 @@ const handleJoinBeforeRequest = (target) => {
 };
 
 /**
- * Handles leave button beforeRequest state.
+ * Handles leave and cancel request buttons beforeRequest state.
  * @param {HTMLElement} target - Event target
  */
 const handleLeaveBeforeRequest = (target) => {
-  if (target.id !== "leave-btn") {
+  if (!LEAVE_BUTTON_IDS.includes(target.id)) {
     return;
   }
 
@@ -151,11 +187,16 @@ const handleJoinAfterRequest = (event) => {
   }
 
   const xhr = event.detail?.xhr;
+  const response = isSuccessfulXHRStatus(xhr?.status) ? parseJsonText(xhr.responseText, null) : null;
   const ok = handleHtmxResponse({
     xhr,
-    successMessage: "You have successfully joined this group.",
+    successMessage:
+      response?.status === "pending-approval"
+        ? "Your request to join this group has been sent to the organizers."
+        : "You have successfully joined this group.",
     errorMessage: "Something went wrong joining this group. Please try again later.",
   });
+  delete container.dataset.joinAnswersReady;
   if (ok) {
     document.body.dispatchEvent(new Event("membership-changed"));
   } else {
@@ -165,12 +206,12 @@ const handleJoinAfterRequest = (event) => {
 };
 
 /**
- * Handles leave button afterRequest state.
+ * Handles leave and cancel request buttons afterRequest state.
  * @param {Event} event - htmx:afterRequest event
  */
 const handleLeaveAfterRequest = (event) => {
   const target = event.target;
-  if (!(target instanceof HTMLElement) || target.id !== "leave-btn") {
+  if (!(target instanceof HTMLElement) || !LEAVE_BUTTON_IDS.includes(target.id)) {
     return;
   }
 
@@ -180,22 +221,26 @@ const handleLeaveAfterRequest = (event) => {
   }
 
   const loadingButton = getElementById(container, "loading-btn");
-  const leaveButton = getElementById(container, "leave-btn");
-  if (!loadingButton || !leaveButton) {
+  if (!loadingButton) {
     return;
   }
 
+  const isCancelRequest = target.id === "cancel-request-btn";
   const xhr = event.detail?.xhr;
   const ok = handleHtmxResponse({
     xhr,
-    successMessage: "You have successfully left this group.",
-    errorMessage: "Something went wrong leaving this group. Please try again later.",
+    successMessage: isCancelRequest
+      ? "Your request to join this group has been canceled."
+      : "You have successfully left this group.",
+    errorMessage: isCancelRequest
+      ? "Something went wrong canceling your request. Please try again later."
+      : "Something went wrong leaving this group. Please try again later.",
   });
   if (ok) {
     document.body.dispatchEvent(new Event("membership-changed"));
   } else {
     setElementHidden(loadingButton, true);
-    setElementHidden(leaveButton, false);
+    setElementHidden(target, false);
   }
 };
 
@@ -213,7 +258,7 @@ const handleBeforeRequest = (event) => {
     return;
   }
 
-  handleJoinBeforeRequest(target);
+  handleJoinBeforeRequest(event, target);
   handleLeaveBeforeRequest(target);
 };
 
@@ -227,6 +272,36 @@ const handleAfterRequest = (event) => {
   handleLeaveAfterRequest(event);
 };
 
+/**
+ * Handles the join questions form submit, storing the answers and resuming
+ * the join request.
+ * @param {SubmitEvent} event - Submit event
+ */
+const handleJoinQuestionsSubmit = (event) => {
+  const form = event.target;
+  if (!(form instanceof HTMLFormElement) || form.id !== "join-questions-form") {
+    return;
+  }
+
+  event.preventDefault();
+  const container = closestElement(form, MEMBERSHIP_CONTAINER_SELECTOR);
+  const answersInput = container ? getElementById(container, "join-answers-input") : null;
+  const joinButton = container ? getElementById(container, "join-btn") : null;
+  if (!(answersInput instanceof HTMLInputElement) || !(joinButton instanceof HTMLButtonElement)) {
+    return;
+  }
+
+  const answersPayload = collectQuestionAnswers(form, { answerSelector: "[data-question-answer]" });
+  if (!answersPayload) {
+    return;
+  }
+
+  answersInput.value = JSON.stringify(answersPayload);
+  markDatasetReady(container, "joinAnswersReady");
+  setJoinQuestionsModalOpen(container, false);
+  joinButton.click();
+};
+
 /**
  * Handles click events for membership actions.
  * @param {MouseEvent} event - Click event
@@ -247,6 +322,13 @@ const handleMembershipClick = (event) => {
     return;
   }
 
+  const joinQuestionsClose = closestElement(event.target, "[data-join-questions-close]");
+  if (joinQuestionsClose) {
+    const container = closestElement(joinQuestionsClose, MEMBERSHIP_CONTAINER_SELECTOR);
+    setJoinQuestionsModalOpen(container, false);
+    return;
+  }
+
   const signinButton = closestElement(event.target, "#signin-btn");
   if (signinButton) {
     const path = signinButton.dataset.path || window.location.pathname;
@@ -261,6 +343,16 @@ const handleMembershipClick = (event) => {
   const leaveButton = closestElement(event.target, "#leave-btn");
   if (leaveButton) {
     showConfirmAlert("Are you sure you want to leave this group?", "leave-btn", "Yes");
+    return;
+  }
+
+  const cancelRequestButton = closestElement(event.target, "#cancel-request-btn");
+  if (cancelRequestButton) {
+    showConfirmAlert(
+      "Are you sure you want to cancel your request to join this group?",
+      "cancel-request-btn",
+      "Yes",
+    );
   }
 };
 
@@ -278,6 +370,7 @@ const initializeMembership = (root = document) => {
   document.addEventListener("htmx:beforeRequest", handleBeforeRequest);
   document.addEventListener("htmx:afterRequest", handleAfterRequest);
   document.addEventListener("click", handleMembershipClick);
+  document.addEventListener("submit", handleJoinQuestionsSubmit);
 };
 
 initializeOnReadyAndHtmxLoad(initializeMembership);
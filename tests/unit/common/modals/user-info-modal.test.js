@@ -116,6 +116,29 @@ describe("user-info-modal", () => {
     );
   });
 
+  it("renders the talks link when a talks url is provided", async () => {
+    // Render the user-info-modal fixture.
+    const element = await mountLitComponent("user-info-modal");
+
+    // Dispatch the open-user-modal event.
+    document.dispatchEvent(
+      new CustomEvent("open-user-modal", {
+        bubbles: true,
+        detail: {
+          name: "Katherine Johnson",
+          username: "katherine",
+          talksUrl: "/test/speaker/katherine",
+        },
+      }),
+    );
+    await element.updateComplete;
+
+    // The talks link points to the speaker page.
+    const talksLink = element.querySelector('a[href="/test/speaker/katherine"]');
+    expect(talksLink).to.not.equal(null);
+    expect(talksLink?.textContent).to.include("View talks");
+  });
+
   it("closes from escape and overlay interactions", async () => {
     // Render the user-info-modal fixture.
     const element = await mountLitComponent("user-info-modal");
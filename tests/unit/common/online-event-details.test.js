@@ -28,6 +28,7 @@ describe("online-event-details", () => {
       meeting_recording_url: "https://example.com/recording",
       meeting_requested: false,
       meeting_provider_id: "",
+      meeting_webinar: false,
     });
 
     // Reset the fixture state.
@@ -383,6 +384,7 @@ describe("online-event-details", () => {
       meeting_recording_url: "",
       meeting_requested: false,
       meeting_provider_id: "",
+      meeting_webinar: false,
     });
   });
 
@@ -428,6 +430,7 @@ describe("online-event-details", () => {
       meeting_recording_url: "",
       meeting_requested: true,
       meeting_provider_id: "zoom",
+      meeting_webinar: false,
     });
   });
 
@@ -466,6 +469,7 @@ describe("online-event-details", () => {
       meeting_recording_url: "https://youtube.com/watch?v=processed",
       meeting_requested: false,
       meeting_provider_id: "",
+      meeting_webinar: false,
     });
   });
 
@@ -505,6 +509,7 @@ describe("online-event-details", () => {
       meeting_recording_url: "https://youtube.com/watch?v=processed",
       meeting_requested: true,
       meeting_provider_id: "zoom",
+      meeting_webinar: false,
     });
   });
 
@@ -534,4 +539,101 @@ describe("online-event-details", () => {
       meeting_provider_id: "zoom",
     });
   });
+
+  it("lists the enabled meeting providers and keeps the stored selection", async () => {
+    // Render the online-event-details fixture with several enabled providers.
+    const element = await mountLitComponent("online-event-details", {
+      kind: "virtual",
+      meetingProviderId: "jitsi",
+      meetingProviders: ["google_meet", "jitsi", "zoom"],
+      meetingRequested: true,
+    });
+
+    // The provider select offers every enabled provider with the stored one selected.
+    const select = element.renderRoot.querySelector("select");
+    expect([...select.options].map((option) => option.value)).to.deep.equal([
+      "google_meet",
+      "jitsi",
+      "zoom",
+    ]);
+    expect([...select.options].map((option) => option.textContent.trim())).to.deep.equal([
+      "Google Meet",
+      "Jitsi",
+      "Zoom",
+    ]);
+    expect(select.value).to.equal("jitsi");
+    expect(element.getMeetingData()).to.include({
+      meeting_requested: true,
+      meeting_provider_id: "jitsi",
+    });
+  });
+
+  it("falls back to an enabled provider when the stored one is unavailable", async () => {
+    // Render the online-event-details fixture with a provider that is no longer enabled.
+    const element = await mountLitComponent("online-event-details", {
+      kind: "virtual",
+      meetingProviderId: "teams",
+      meetingProviders: ["webex", "jitsi"],
+      meetingRequested: true,
+    });
+
+    // The first enabled provider is selected instead.
+    expect(element.getMeetingData()).to.include({
+      meeting_provider_id: "webex",
+    });
+
+    // Resetting keeps the fallback provider.
+    element.reset();
+    expect(element._providerId).to.equal("webex");
+  });
+
+  it("submits webinar mode for zoom event meetings and ignores the capacity limit", async () => {
+    // Create the input fixture.
+    const capacity = document.createElement("input");
+    capacity.id = "capacity";
+    capacity.value = "500";
+    document.body.append(capacity);
+
+    // Render the online-event-details fixture with webinar mode requested.
+    const element = await mountLitComponentWithAttributes("online-event-details", {
+      attributes: {
+        "meeting-max-participants": JSON.stringify({ zoom: 100 }),
+        "meeting-requested": "",
+        "meeting-webinar": "",
+      },
+    });
+    element._providerId = "zoom";
+    element._checkMeetingCapacity();
+
+    // Webinars are submitted and are not bound by the meeting capacity limit.
+    expect(element.getMeetingData()).to.include({
+      meeting_requested: true,
+      meeting_webinar: true,
+    });
+    expect(element._getCapacityLimit()).to.equal(null);
+    expect(element._capacityWarning).to.equal("");
+
+    // Switching to another provider drops webinar mode.
+    element._providerId = "jitsi";
+    expect(element.getMeetingData()).to.include({ meeting_webinar: false });
+  });
+
+  it("locks webinar mode once the meeting has been created", async () => {
+    // Render the online-event-details fixture with a synced meeting.
+    const element = await mountLitComponent("online-event-details", {
+      kind: "virtual",
+      meetingInSync: true,
+      meetingJoinUrl: "https://zoom.us/j/123",
+      meetingProviderId: "zoom",
+      meetingProviders: ["zoom"],
+      meetingRequested: true,
+    });
+
+    // The webinar toggle is rendered disabled.
+    const webinarToggle = [...element.renderRoot.querySelectorAll('input[type="checkbox"]')].find(
+      (input) => input.closest("label")?.textContent.includes("Host as webinar"),
+    );
+    expect(webinarToggle.disabled).to.equal(true);
+    expect(element.textContent).to.include("Webinar mode cannot be changed");
+  });
 });
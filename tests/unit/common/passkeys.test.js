@@ -0,0 +1,133 @@
+import { expect } from "@open-wc/testing";
+
+import {
+  base64UrlToBuffer,
+  bufferToBase64Url,
+  logInWithPasskey,
+  serializeCredential,
+  toCreationOptions,
+  toRequestOptions,
+} from "/static/js/common/passkeys.js";
+import { mockFetch } from "/tests/unit/test-utils/network.js";
+
+// Encode text as an ArrayBuffer for the test.
+const textBuffer = (text) => new TextEncoder().encode(text).buffer;
+
+describe("passkeys", () => {
+  let fetchMock;
+
+  beforeEach(() => {
+    fetchMock = mockFetch();
+  });
+
+  afterEach(() => {
+    fetchMock.restore();
+  });
+
+  it("round trips buffers through unpadded base64url", () => {
+    const encoded = bufferToBase64Url(new Uint8Array([251, 255, 191]).buffer);
+
+    expect(encoded).to.equal("-_-_");
+    expect([...new Uint8Array(base64UrlToBuffer(encoded))]).to.deep.equal([251, 255, 191]);
+    expect(bufferToBase64Url(textBuffer("ab"))).to.equal("YWI");
+    expect(bufferToBase64Url(null)).to.equal(null);
+  });
+
+  it("decodes binary fields of the registration challenge", () => {
+    const options = toCreationOptions({
+      publicKey: {
+        challenge: "YWI",
+        excludeCredentials: [{ id: "YWI", type: "public-key" }],
+        rp: { id: "localhost", name: "Open Community Groups" },
+        user: { displayName: "User", id: "YWI", name: "user" },
+      },
+    });
+
+    expect(options.publicKey.challenge).to.be.instanceOf(ArrayBuffer);
+    expect(options.publicKey.excludeCredentials[0].id).to.be.instanceOf(ArrayBuffer);
+    expect(options.publicKey.excludeCredentials[0].type).to.equal("public-key");
+    expect(options.publicKey.user.id).to.be.instanceOf(ArrayBuffer);
+    expect(options.publicKey.user.name).to.equal("user");
+    expect(options.publicKey.rp.name).to.equal("Open Community Groups");
+  });
+
+  it("decodes binary fields of the authentication challenge", () => {
+    const options = toRequestOptions({
+      publicKey: { allowCredentials: [{ id: "YWI", type: "public-key" }], challenge: "YWI" },
+    });
+
+    expect(options.publicKey.challenge).to.be.instanceOf(ArrayBuffer);
+    expect(options.publicKey.allowCredentials[0].id).to.be.instanceOf(ArrayBuffer);
+  });
+
+  it("serializes credential responses as base64url", () => {
+    const credential = {
+      getClientExtensionResults: () => ({}),
+      id: "YWI",
+      rawId: textBuffer("ab"),
+      response: {
+        authenticatorData: textBuffer("ab"),
+        clientDataJSON: textBuffer("ab"),
+        signature: textBuffer("ab"),
+        userHandle: null,
+      },
+      type: "public-key",
+    };
+
+    expect(serializeCredential(credential)).to.deep.equal({
+      extensions: {},
+      id: "YWI",
+      rawId: "YWI",
+      response: {
+        authenticatorData: "YWI",
+        clientDataJSON: "YWI",
+        signature: "YWI",
+        userHandle: null,
+      },
+      type: "public-key",
+    });
+  });
+
+  it("logs in posting the signed challenge with the next url", async () => {
+    // Mock the browser credentials API.
+    const originalGet = navigator.credentials.get;
+    navigator.credentials.get = async () => ({
+      id: "YWI",
+      rawId: textBuffer("ab"),
+      response: { clientDataJSON: textBuffer("ab") },
+      type: "public-key",
+    });
+    fetchMock.setImpl(async (url) => ({
+      headers: new Headers(),
+      json: async () => ({ publicKey: { allowCredentials: [], challenge: "YWI" } }),
+      ok: true,
+      status: url.startsWith("/log-in/passkey/start") ? 200 : 204,
+    }));
+
+    try {
+      await logInWithPasskey("user", "/dashboard");
+    } finally {
+      navigator.credentials.get = originalGet;
+    }
+
+    expect(fetchMock.calls).to.have.length(2);
+    expect(fetchMock.calls[0][0]).to.equal("/log-in/passkey/start");
+    expect(JSON.parse(fetchMock.calls[0][1].body)).to.deep.equal({ username: "user" });
+    expect(fetchMock.calls[1][0]).to.equal("/log-in/passkey/finish?next_url=%2Fdashboard");
+    expect(JSON.parse(fetchMock.calls[1][1].body).rawId).to.equal("YWI");
+  });
+
+  it("fails when the passkey log in cannot be started", async () => {
+    fetchMock.setImpl(async () => ({ headers: new Headers(), ok: false, status: 401 }));
+
+    let error;
+    try {
+      await logInWithPasskey("user");
+    } catch (e) {
+      error = e;
+    }
+
+    expect(error?.message).to.equal("Passkey log in could not be started.");
+    expect(fetchMock.calls).to.have.length(1);
+  });
+});
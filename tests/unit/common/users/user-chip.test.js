@@ -76,11 +76,36 @@ describe("user-chip", () => {
       githubUrl: "https://github.com/grace",
       linkedinUrl: undefined,
       provider: undefined,
+      talksUrl: undefined,
       twitterUrl: undefined,
       websiteUrl: "https://example.com/grace",
     });
   });
 
+  it("includes the talks url in the user modal event when provided", async () => {
+    // Mount a clickable chip linked to the user's talks.
+    const element = await mountLitComponent("user-chip", {
+      user: {
+        name: "Katherine Johnson",
+        username: "katherine",
+      },
+      displayModal: true,
+      talksUrl: "/test/speaker/katherine",
+    });
+
+    // Capture the modal event emitted by the chip.
+    let eventDetail = null;
+    element.addEventListener("open-user-modal", (event) => {
+      eventDetail = event.detail;
+    });
+
+    // Click the user chip.
+    element.querySelector('[role="button"]')?.click();
+
+    // The modal payload links to the user's talks.
+    expect(eventDetail?.talksUrl).to.equal("/test/speaker/katherine");
+  });
+
   it("opens the modal from keyboard interactions when clickable", async () => {
     // Mount a clickable chip before sending keyboard events.
     const element = await mountLitComponent("user-chip", {
@@ -58,6 +58,7 @@ describe("user profile modal triggers", () => {
       githubUrl: "https://github.com/ada",
       linkedinUrl: undefined,
       provider: { linuxfoundation: { username: "ada-lf" } },
+      talksUrl: undefined,
       twitterUrl: undefined,
       websiteUrl: "https://example.com/ada",
     });
@@ -5,9 +5,38 @@ import { dispatchHtmxLoad } from "/tests/unit/test-utils/htmx.js";
 import { resetDom } from "/tests/unit/test-utils/dom.js";
 
 describe("dashboard community settings page", () => {
-  const renderSettingsForm = ({ checked = false } = {}) => {
+  const renderSettingsForm = ({
+    checked = false,
+    dormantGroupsReportEnabled = false,
+    eventApprovalRequired = false,
+    passkeyRequired = false,
+  } = {}) => {
     document.body.innerHTML = `
       <form id="settings-form">
+        <input
+          id="toggle_dormant_groups_report_enabled"
+          name="toggle_dormant_groups_report_enabled"
+          type="checkbox"
+          ${dormantGroupsReportEnabled ? "checked" : ""}
+        >
+        <input
+          id="dormant_groups_report_enabled"
+          name="dormant_groups_report_enabled"
+          type="hidden"
+          value="stale"
+        >
+        <input
+          id="toggle_event_approval_required"
+          name="toggle_event_approval_required"
+          type="checkbox"
+          ${eventApprovalRequired ? "checked" : ""}
+        >
+        <input
+          id="event_approval_required"
+          name="event_approval_required"
+          type="hidden"
+          value="stale"
+        >
         <input
           id="toggle_group_team_management_restricted"
           name="toggle_group_team_management_restricted"
@@ -20,6 +49,18 @@ describe("dashboard community settings page", () => {
           type="hidden"
           value="stale"
         >
+        <input
+          id="toggle_passkey_required"
+          name="toggle_passkey_required"
+          type="checkbox"
+          ${passkeyRequired ? "checked" : ""}
+        >
+        <input
+          id="passkey_required"
+          name="passkey_required"
+          type="hidden"
+          value="stale"
+        >
       </form>
     `;
   };
@@ -54,6 +95,69 @@ describe("dashboard community settings page", () => {
     expect(hiddenInput.value).to.equal("false");
   });
 
+  it("syncs the passkey requirement value independently", () => {
+    // Prepare the settings form with only the passkey requirement enabled.
+    renderSettingsForm({ passkeyRequired: true });
+
+    initializeCommunitySettings();
+
+    const toggle = document.getElementById("toggle_passkey_required");
+    const hiddenInput = document.getElementById("passkey_required");
+
+    // Verify initialization mirrors each toggle state.
+    expect(hiddenInput.value).to.equal("true");
+    expect(document.getElementById("group_team_management_restricted").value).to.equal("false");
+
+    // Disable the passkey requirement.
+    toggle.checked = false;
+    toggle.dispatchEvent(new Event("change", { bubbles: true }));
+
+    // Verify the hidden input follows the toggle state.
+    expect(hiddenInput.value).to.equal("false");
+  });
+
+  it("syncs the event approval requirement value independently", () => {
+    // Prepare the settings form with only the event approval requirement enabled.
+    renderSettingsForm({ eventApprovalRequired: true });
+
+    initializeCommunitySettings();
+
+    const toggle = document.getElementById("toggle_event_approval_required");
+    const hiddenInput = document.getElementById("event_approval_required");
+
+    // Verify initialization mirrors each toggle state.
+    expect(hiddenInput.value).to.equal("true");
+    expect(document.getElementById("passkey_required").value).to.equal("false");
+
+    // Disable the event approval requirement.
+    toggle.checked = false;
+    toggle.dispatchEvent(new Event("change", { bubbles: true }));
+
+    // Verify the hidden input follows the toggle state.
+    expect(hiddenInput.value).to.equal("false");
+  });
+
+  it("syncs the dormant groups report value independently", () => {
+    // Prepare the settings form with only the dormant groups report enabled.
+    renderSettingsForm({ dormantGroupsReportEnabled: true });
+
+    initializeCommunitySettings();
+
+    const toggle = document.getElementById("toggle_dormant_groups_report_enabled");
+    const hiddenInput = document.getElementById("dormant_groups_report_enabled");
+
+    // Verify initialization mirrors each toggle state.
+    expect(hiddenInput.value).to.equal("true");
+    expect(document.getElementById("event_approval_required").value).to.equal("false");
+
+    // Disable the dormant groups report.
+    toggle.checked = false;
+    toggle.dispatchEvent(new Event("change", { bubbles: true }));
+
+    // Verify the hidden input follows the toggle state.
+    expect(hiddenInput.value).to.equal("false");
+  });
+
   it("initializes swapped settings content on htmx load", () => {
     // Prepare the settings form as swapped dashboard content.
     renderSettingsForm({ checked: true });
@@ -208,6 +208,48 @@ describe("team-add-member", () => {
     expect(element.textContent).to.include("Ada Lovelace");
   });
 
+  it("submits the email address when inviting by email", async () => {
+    // Call mount lit component with attributes.
+    const element = await mountLitComponentWithAttributes("team-add-member", {
+      attributes: {
+        "email-invites-enabled": "",
+        "role-options": JSON.stringify([
+          { display_name: "Viewer", group_role_id: "viewer" },
+        ]),
+      },
+    });
+
+    // Call open.
+    element._open();
+    await element.updateComplete;
+
+    // Select an email address and a role.
+    element._onEmailSelected({
+      detail: {
+        email: "new@example.com",
+      },
+    });
+    element._onRoleChanged({
+      target: {
+        value: "viewer",
+      },
+    });
+    await element.updateComplete;
+
+    // Read the hidden inputs and submit button.
+    const userIdInput = element.querySelector("#team-add-user-id");
+    const emailInput = element.querySelector("#team-add-email");
+    const submitButton = element.querySelector("#team-add-submit");
+
+    // Verify only the email address is submitted.
+    expect(element.querySelector("user-search-field").emailActionEnabled).to.equal(true);
+    expect(emailInput.value).to.equal("new@example.com");
+    expect(emailInput.disabled).to.equal(false);
+    expect(userIdInput.disabled).to.equal(true);
+    expect(submitButton.disabled).to.equal(false);
+    expect(element.textContent).to.include("new@example.com");
+  });
+
   it("closes and resets the form after a successful htmx request", async () => {
     // Call mount lit component with attributes.
     const element = await mountLitComponentWithAttributes("team-add-member", {
@@ -18,6 +18,7 @@ describe("session-card", () => {
         starts_at: "2025-05-10T09:00",
         ends_at: "2025-05-10T10:00",
         location: "Main Hall",
+        track: "Keynotes",
         speakers: [
           { name: "Ada Lovelace", username: "ada", featured: true },
           { name: "Grace Hopper", username: "grace" },
@@ -41,7 +42,7 @@ describe("session-card", () => {
     expect(element.textContent).to.include("09:00");
     expect(element.textContent).to.include("10:00");
     expect(element.textContent).to.include("Opening Keynote");
-    expect(element.textContent).to.include("Talk · Main Hall");
+    expect(element.textContent).to.include("Talk · Keynotes · Main Hall");
     expect(element.textContent).to.include("+1");
     expect(element.firstElementChild.classList.contains("hover:border-primary-300")).to.equal(true);
     expect(element.firstElementChild.classList.contains("hover:shadow-sm")).to.equal(true);
@@ -25,6 +25,7 @@ describe("sessions schedule", () => {
       ends_at: "",
       meeting_requested: false,
       meeting_recording_published: false,
+      track: "",
     });
     expect(session.meeting_recording_raw_urls).to.deep.equal([]);
     expect(session.meeting_hosts).to.deep.equal([]);
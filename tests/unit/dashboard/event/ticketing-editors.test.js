@@ -403,6 +403,43 @@ describe("ticketing editors", () => {
     ).to.equal("0");
   });
 
+  it("restricts free-only ticket types to free prices", async () => {
+    // Prepare a free-only UI root, used when payments are not configured.
+    const uiRoot = mountTicketTypesUi();
+    uiRoot.setAttribute("free-only", "");
+    await uiRoot.updateComplete;
+
+    // Open the modal and check new price windows default to free.
+    uiRoot._openTicketModal();
+    await uiRoot.updateComplete;
+    const priceInput = uiRoot.querySelector("#ticket-price-1");
+    expect(priceInput.value).to.equal("0");
+    expect(priceInput.max).to.equal("0");
+
+    // Try saving a paid ticket.
+    await setInputValue(uiRoot, "#ticket-title-draft", "Student");
+    await setInputValue(uiRoot, "#ticket-seats-draft", "20");
+    await setInputValue(uiRoot, "#ticket-price-1", "5");
+    uiRoot.querySelector('[data-ticketing-action="save-ticket"]')?.click();
+    await uiRoot.updateComplete;
+
+    // Verify paid tickets are rejected.
+    expect(priceInput.validationMessage).to.equal(
+      "Only free tickets are available until payments are configured for this group.",
+    );
+    expect(uiRoot.querySelector('input[name="ticket_types[0][title]"]')).to.equal(null);
+
+    // Save the ticket once it is free again.
+    await setInputValue(uiRoot, "#ticket-price-1", "0");
+    uiRoot.querySelector('[data-ticketing-action="save-ticket"]')?.click();
+    await uiRoot.updateComplete;
+
+    // Verify the free ticket is serialized.
+    expect(
+      uiRoot.querySelector('input[name="ticket_types[0][price_windows][0][amount_minor]"]')?.value,
+    ).to.equal("0");
+  });
+
   it("renders scheduled ticket windows with compact dates", async () => {
     // Prepare ui root for rendering scheduled ticket windows with compact dates.
     const uiRoot = mountTicketTypesUi();
@@ -231,6 +231,9 @@ describe("dashboard group attendees list template", () => {
     expect(actionsMenu).to.include('id="open-event-qr-code-modal"');
     expect(actionsMenu).to.include("icon-qr-code");
     expect(actionsMenu).to.include("Show check-in QR code");
+    expect(actionsMenu).to.include('id="open-check-in-kiosk"');
+    expect(actionsMenu).to.include('href="/dashboard/group/check-in/{{ event.event_id }}/kiosk"');
+    expect(actionsMenu).to.include("Open check-in kiosk");
     expect(actionsMenu).to.include('id="open-attendee-invitation-modal"');
     expect(actionsMenu).to.include("Invite attendee");
     expect(actionsMenu).to.include("border-t border-stone-100");
@@ -1121,4 +1121,33 @@ describe("event page modules", () => {
     expect(refreshEvents).to.deep.equal(["page"]);
     expect(bodyEvents).to.deep.equal([]);
   });
+
+  it("warns about overlapping events after a successful save", () => {
+    // Mount the update page shell.
+    mountUpdatePageShell({ canManageEvents: true, waitlistCount: "0" });
+
+    // Initialize the update page behavior.
+    initializeEventUpdatePage();
+
+    // Dispatch a successful update response reporting overlapping events.
+    const warning = "This event overlaps with other events: Group Meetup.";
+    document.getElementById("update-event-button").dispatchEvent(
+      new CustomEvent("htmx:afterRequest", {
+        bubbles: true,
+        detail: {
+          elt: document.getElementById("update-event-button"),
+          xhr: {
+            status: 204,
+            getResponseHeader: (name) =>
+              name === "X-OCG-Event-Conflicts" ? encodeURIComponent(warning) : null,
+          },
+        },
+      }),
+    );
+
+    // A single alert includes the overlapping events warning.
+    expect(swal.calls).to.have.length(1);
+    expect(swal.calls[0].icon).to.equal("info");
+    expect(swal.calls[0].text).to.contain(warning);
+  });
 });
@@ -510,4 +510,106 @@ describe("event-selector", () => {
     expect(manualMeetingDetails).to.equal(null);
     expect(resetCalls).to.equal(2);
   });
+
+  it("applies event template details, keeping the name and the agenda skeleton", async () => {
+    // Render the DOM fixture for applying template details into the form.
+    document.body.innerHTML = `
+      <input id="name" />
+      <sessions-section></sessions-section>
+    `;
+    const element = await renderSelector();
+
+    // Stub session updates while template sessions are applied.
+    const sessionsSection = document.querySelector("sessions-section");
+    sessionsSection.requestUpdate = () => {};
+
+    // Apply the template details.
+    await element._applyTemplateDetails({
+      name: "Monthly Meetup",
+      kind: "in-person",
+      sessions: [
+        { name: "Welcome", kind: "in-person", description: "Opening remarks" },
+        { name: "Lightning talks", kind: "in-person" },
+      ],
+      timezone: "Europe/Madrid",
+    });
+
+    // Template details keep the event name and the sessions without dates.
+    expect(document.getElementById("name")?.value).to.equal("Monthly Meetup");
+    expect(
+      sessionsSection.sessions.map(({ name, kind, description, starts_at, ends_at }) => ({
+        name,
+        kind,
+        description,
+        starts_at,
+        ends_at,
+      })),
+    ).to.deep.equal([
+      {
+        name: "Welcome",
+        kind: "in-person",
+        description: "Opening remarks",
+        starts_at: "",
+        ends_at: "",
+      },
+      {
+        name: "Lightning talks",
+        kind: "in-person",
+        description: "",
+        starts_at: "",
+        ends_at: "",
+      },
+    ]);
+  });
+
+  it("applies the requested event template and shows success feedback", async () => {
+    // Render the selector fixture.
+    const element = await renderSelector();
+    const appliedDetails = [];
+    const requestedTemplates = [];
+
+    // Stub template-detail loading and application.
+    element._applyTemplateDetails = (details) => {
+      appliedDetails.push(details);
+    };
+    element._fetchTemplateDetails = async (templateId) => {
+      requestedTemplates.push(templateId);
+      return { name: "Monthly Meetup", kind: "in-person" };
+    };
+
+    // Apply the template.
+    await element._handleTemplateMode("template-1");
+
+    // Template details are applied and success feedback is shown.
+    expect(requestedTemplates).to.deep.equal(["template-1"]);
+    expect(appliedDetails).to.deep.equal([
+      { name: "Monthly Meetup", kind: "in-person" },
+    ]);
+    expect(element._copyLoading).to.equal(false);
+    expect(scrollToMock.calls).to.deep.equal([{ top: 0, behavior: "smooth" }]);
+    expect(swal.calls.at(-1)).to.include({
+      text: "Event template applied. Set the schedule before publishing.",
+      icon: "info",
+    });
+  });
+
+  it("shows error feedback when the event template cannot be applied", async () => {
+    // Render the selector fixture.
+    const element = await renderSelector();
+
+    // Stub a failing template-detail request.
+    element._fetchTemplateDetails = async () => {
+      throw new Error("not found");
+    };
+
+    // Apply the template.
+    await element._handleTemplateMode("template-1");
+
+    // Error feedback is shown.
+    expect(element._copyLoading).to.equal(false);
+    expect(swal.calls.at(-1)).to.include({
+      text: "Unable to apply that event template right now. Please try again.",
+      icon: "error",
+    });
+  });
 });
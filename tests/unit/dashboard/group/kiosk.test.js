@@ -0,0 +1,133 @@
+import { expect } from "@open-wc/testing";
+
+import {
+  buildQrCodeUrl,
+  checkInTicket,
+  isTicketCode,
+  showScanResult,
+} from "/static/js/dashboard/group/kiosk.js";
+import { resetDom } from "/tests/unit/test-utils/dom.js";
+import { mockFetch } from "/tests/unit/test-utils/network.js";
+
+describe("dashboard group check-in kiosk", () => {
+  const ticketCode = "0f8fad5b-d9cb-469f-a165-70867728950e";
+  let fetchMock;
+
+  beforeEach(() => {
+    resetDom();
+    fetchMock = mockFetch();
+  });
+
+  afterEach(() => {
+    resetDom();
+    fetchMock.restore();
+  });
+
+  it("recognizes ticket codes", () => {
+    expect(isTicketCode(ticketCode)).to.equal(true);
+    expect(isTicketCode(` ${ticketCode.toUpperCase()} `)).to.equal(true);
+    expect(isTicketCode("https://example.test/check-in")).to.equal(false);
+    expect(isTicketCode(undefined)).to.equal(false);
+  });
+
+  it("builds rotating QR code URLs", () => {
+    expect(buildQrCodeUrl("/dashboard/group/check-in/1/kiosk/qr-code", 42)).to.equal(
+      "/dashboard/group/check-in/1/kiosk/qr-code?t=42",
+    );
+  });
+
+  it("checks in the attendee owning a ticket", async () => {
+    // Mock the successful check-in response.
+    fetchMock.setImpl(async () => ({
+      ok: true,
+      status: 200,
+      async json() {
+        return { already_checked_in: false, name: "Jane Doe" };
+      },
+    }));
+
+    const result = await checkInTicket("/dashboard/group/check-in/1/tickets", ticketCode);
+
+    // The ticket is posted and the attendee name is reported.
+    const [url, options] = fetchMock.calls[0];
+    expect(url).to.equal(`/dashboard/group/check-in/1/tickets/${ticketCode}`);
+    expect(options.method).to.equal("POST");
+    expect(result).to.deep.equal({ tone: "success", message: "Jane Doe is checked in. Welcome!" });
+  });
+
+  it("includes the attendee ticket type when available", async () => {
+    // Mock the check-in response for an attendee with a ticket type.
+    fetchMock.setImpl(async () => ({
+      ok: true,
+      status: 200,
+      async json() {
+        return { already_checked_in: false, name: "Jane Doe", ticket_title: "Student" };
+      },
+    }));
+
+    const result = await checkInTicket("/dashboard/group/check-in/1/tickets", ticketCode);
+
+    expect(result).to.deep.equal({
+      tone: "success",
+      message: "Jane Doe (Student) is checked in. Welcome!",
+    });
+  });
+
+  it("reports attendees already checked in", async () => {
+    // Mock the response for an attendee already checked in.
+    fetchMock.setImpl(async () => ({
+      ok: true,
+      status: 200,
+      async json() {
+        return { already_checked_in: true, name: "Jane Doe" };
+      },
+    }));
+
+    const result = await checkInTicket("/dashboard/group/check-in/1/tickets", ticketCode);
+
+    expect(result).to.deep.equal({ tone: "warning", message: "Jane Doe is already checked in." });
+  });
+
+  it("reports unknown tickets and server errors", async () => {
+    // Unknown tickets are reported as not valid for the event.
+    fetchMock.setImpl(async () => ({ ok: false, status: 404 }));
+    expect(await checkInTicket("/tickets", ticketCode)).to.deep.equal({
+      tone: "error",
+      message: "This ticket is not valid for this event.",
+    });
+
+    // Validation errors display the server message.
+    fetchMock.setImpl(async () => ({
+      ok: false,
+      status: 422,
+      async text() {
+        return "event not found or inactive";
+      },
+    }));
+    expect(await checkInTicket("/tickets", ticketCode)).to.deep.equal({
+      tone: "error",
+      message: "event not found or inactive",
+    });
+
+    // Other errors display a generic message.
+    fetchMock.setImpl(async () => ({ ok: false, status: 500 }));
+    expect((await checkInTicket("/tickets", ticketCode)).message).to.equal(
+      "Something went wrong checking in this ticket. Please try again.",
+    );
+  });
+
+  it("shows scan results with the tone styles", () => {
+    // Render the result container.
+    document.body.innerHTML = `<div id="kiosk-scan-result" class="hidden"></div>`;
+    const element = document.getElementById("kiosk-scan-result");
+
+    showScanResult(element, { tone: "success", message: "Jane Doe is checked in. Welcome!" });
+    expect(element.classList.contains("hidden")).to.equal(false);
+    expect(element.classList.contains("bg-green-50")).to.equal(true);
+    expect(element.textContent).to.equal("Jane Doe is checked in. Welcome!");
+
+    showScanResult(element, { tone: "error", message: "This ticket is not valid for this event." });
+    expect(element.classList.contains("bg-green-50")).to.equal(false);
+    expect(element.classList.contains("bg-red-50")).to.equal(true);
+  });
+});
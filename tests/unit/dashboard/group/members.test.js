@@ -37,7 +37,7 @@ describe("dashboard group members", () => {
 
     // Verify initializes the members notification modal with the members success.
     expect(env.current.swal.calls[0]).to.include({
-      text: "Email sent successfully to all group members.",
+      text: "Email sent successfully to the selected group members.",
       icon: "success",
     });
   });
@@ -68,4 +68,71 @@ describe("dashboard group members", () => {
         ?.classList.contains("hidden"),
     ).to.equal(false);
   });
+
+  it("shows only the fields required by the selected audience", () => {
+    // Render the DOM fixture with the audience controls.
+    document.body.innerHTML = `
+      <div id="notification-modal" class="hidden"></div>
+      <form id="notification-form">
+        <select id="audience" name="audience">
+          <option value="all-members" selected>All group members</option>
+          <option value="event-attendees">Attendees of an event</option>
+          <option value="inactive-members">Inactive members</option>
+          <option value="members-with-role">Members with a role</option>
+        </select>
+        <div id="audience-event-field" class="hidden">
+          <select id="audience-event-id" name="event_id" disabled>
+            <option value="event-1">Event 1</option>
+          </select>
+        </div>
+        <div id="audience-inactive-months-field" class="hidden">
+          <input id="audience-inactive-months" name="inactive_months" value="6" disabled />
+        </div>
+        <div id="audience-member-role-field" class="hidden">
+          <select id="audience-member-role" name="member_role" disabled>
+            <option value="Mentor">Mentor</option>
+          </select>
+        </div>
+      </form>
+    `;
+
+    // Dispatch the HTMX load event for the current body.
+    dispatchHtmxLoad();
+
+    // Select the event attendees audience.
+    const audience = document.getElementById("audience");
+    audience.value = "event-attendees";
+    audience.dispatchEvent(new Event("change"));
+
+    // Verify only the event field is visible and enabled.
+    expect(document.getElementById("audience-event-field").classList.contains("hidden")).to.equal(false);
+    expect(document.getElementById("audience-event-id").disabled).to.equal(false);
+    expect(
+      document.getElementById("audience-inactive-months-field").classList.contains("hidden"),
+    ).to.equal(true);
+    expect(document.getElementById("audience-inactive-months").disabled).to.equal(true);
+
+    // Select the inactive members audience.
+    audience.value = "inactive-members";
+    audience.dispatchEvent(new Event("change"));
+
+    // Verify only the inactive months field is visible and enabled.
+    expect(document.getElementById("audience-event-field").classList.contains("hidden")).to.equal(true);
+    expect(document.getElementById("audience-event-id").disabled).to.equal(true);
+    expect(
+      document.getElementById("audience-inactive-months-field").classList.contains("hidden"),
+    ).to.equal(false);
+    expect(document.getElementById("audience-inactive-months").disabled).to.equal(false);
+
+    // Select the members with a role audience.
+    audience.value = "members-with-role";
+    audience.dispatchEvent(new Event("change"));
+
+    // Verify only the member role field is visible and enabled.
+    expect(
+      document.getElementById("audience-inactive-months-field").classList.contains("hidden"),
+    ).to.equal(true);
+    expect(document.getElementById("audience-member-role-field").classList.contains("hidden")).to.equal(false);
+    expect(document.getElementById("audience-member-role").disabled).to.equal(false);
+  });
 });
@@ -27,6 +27,27 @@ describe("dashboard user account settings", () => {
     expect(document.getElementById("optional_notifications_enabled")?.value).to.equal("true");
   });
 
+  it("syncs weekly digest toggle changes to the hidden input", () => {
+    // Build the DOM fixture with both notification hidden inputs and toggles.
+    document.body.innerHTML = `
+      <input type="hidden" id="optional_notifications_enabled" value="true" />
+      <input type="checkbox" id="toggle_optional_notifications_enabled" checked />
+      <input type="hidden" id="weekly_digest_enabled" value="true" />
+      <input type="checkbox" id="toggle_weekly_digest_enabled" checked />
+    `;
+    delete document.documentElement.dataset.userAccountSettingsReady;
+
+    // Initialize account settings and turn the weekly digest off.
+    initializeUserAccountSettings();
+    const toggle = document.getElementById("toggle_weekly_digest_enabled");
+    toggle.checked = false;
+    toggle.dispatchEvent(new Event("change", { bubbles: true }));
+
+    // Only the weekly digest hidden input changes.
+    expect(document.getElementById("weekly_digest_enabled")?.value).to.equal("false");
+    expect(document.getElementById("optional_notifications_enabled")?.value).to.equal("true");
+  });
+
   it("syncs optional notification state when a swapped root initializes", () => {
     // Build a swapped account settings fixture with the toggle already checked.
     const pageRoot = document.createElement("section");
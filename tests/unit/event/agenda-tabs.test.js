@@ -31,4 +31,32 @@ describe("event agenda tabs", () => {
       false,
     );
   });
+
+  it("filters agenda sessions by track", () => {
+    // Build the DOM fixture with two tracks and a shared session.
+    document.body.innerHTML = `
+      <button type="button" data-track-filter="" data-active="true"></button>
+      <button type="button" data-track-filter="Platform" data-active="false"></button>
+      <button type="button" data-track-filter="Security" data-active="false"></button>
+      <ol>
+        <li id="platform" data-session-track="Platform"></li>
+        <li id="security" data-session-track="Security"></li>
+        <li id="shared"></li>
+      </ol>
+    `;
+
+    // Initialize the agenda and select the security track.
+    initializeAgendaTabs();
+    document.querySelector('[data-track-filter="Security"]').click();
+
+    // Only sessions from the selected track and shared sessions are visible.
+    expect(document.querySelector('[data-track-filter="Security"]')?.dataset.active).to.equal("true");
+    expect(document.getElementById("platform")?.hasAttribute("hidden")).to.equal(true);
+    expect(document.getElementById("security")?.hasAttribute("hidden")).to.equal(false);
+    expect(document.getElementById("shared")?.hasAttribute("hidden")).to.equal(false);
+
+    // Selecting all tracks shows every session again.
+    document.querySelector('[data-track-filter=""]').click();
+    expect(document.getElementById("platform")?.hasAttribute("hidden")).to.equal(false);
+  });
 });
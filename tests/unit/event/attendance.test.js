@@ -120,6 +120,13 @@ const renderAttendanceDom = ({
       <a data-join-link-always class="hidden"></a>
     </div>
     <div data-meeting-details data-has-recording="true" class="hidden"></div>
+    <form data-reminder-preference class="hidden">
+      <select name="reminder_preference">
+        <option value="">Use my profile setting</option>
+        <option value="1-week">1 week before</option>
+        <option value="1-hour">1 hour before</option>
+      </select>
+    </form>
     <a data-join-link class="hidden"></a>
     <a data-join-link-menu class="hidden xl:hidden"></a>
     <span data-availability-caption="capacity">
@@ -156,6 +163,7 @@ const renderAttendanceDom = ({
     alwaysJoinLink: document.querySelector("[data-join-link-always]"),
     liveJoinLink: document.querySelector("[data-join-link]"),
     menuJoinLink: document.querySelector("[data-join-link-menu]"),
+    reminderPreference: document.querySelector("[data-reminder-preference]"),
     availabilityCaptions: {
       capacity: document.querySelector('[data-availability-caption="capacity"]'),
       attendees: document.querySelector('[data-availability-caption="attendees"]'),
@@ -221,6 +229,33 @@ describe("event attendance", () => {
     expect(meetingDetails[0].classList.contains("hidden")).to.equal(false);
   });
 
+  it("shows the attendee reminder preference after a successful attendance check", () => {
+    // Keep a reference to the reminder preference form.
+    const { checker, reminderPreference } = renderAttendanceDom();
+
+    // Dispatch the HTMX after-request event.
+    dispatchHtmxAfterRequest(checker, {
+      responseText: JSON.stringify({ reminder_preference: "1-hour", status: "attendee" }),
+    });
+
+    // Confirm the form is visible and reflects the attendee preference.
+    expect(reminderPreference.classList.contains("hidden")).to.equal(false);
+    expect(reminderPreference.querySelector("select")?.value).to.equal("1-hour");
+  });
+
+  it("keeps the reminder preference hidden for users not attending", () => {
+    // Keep a reference to the reminder preference form.
+    const { checker, reminderPreference } = renderAttendanceDom();
+
+    // Dispatch the HTMX after-request event.
+    dispatchHtmxAfterRequest(checker, {
+      responseText: JSON.stringify({ status: "none" }),
+    });
+
+    // Confirm the form stays hidden for guests.
+    expect(reminderPreference.classList.contains("hidden")).to.equal(true);
+  });
+
   it("allows manually invited users to complete registration questions after the window closes", () => {
     // Render an event after the public registration window closed.
     const { attendButton, container } = renderAttendanceDom({
@@ -528,6 +563,30 @@ describe("event attendance", () => {
     expect(loadingButton.classList.contains("hidden")).to.equal(true);
   });
 
+  it("offers seat confirmation for promoted waitlist attendees", () => {
+    // Render sold-out waitlist controls without registration questions.
+    const { attendButton, checker } = renderAttendanceDom({
+      capacity: "10",
+      remainingCapacity: "0",
+      waitlistEnabled: "true",
+    });
+
+    // Apply the pending confirmation state from the attendance check.
+    dispatchHtmxAfterRequest(checker, {
+      responseText: JSON.stringify({
+        status: "waitlist-confirmation-pending",
+      }),
+    });
+
+    // Verify the attend button confirms the held seat instead of joining the waitlist.
+    expect(attendButton.classList.contains("hidden")).to.equal(false);
+    expect(attendButton.disabled).to.equal(false);
+    expect(attendButton.querySelector("[data-attendance-label]")?.textContent).to.equal(
+      "Confirm your spot",
+    );
+    expect(attendButton.dataset.registrationQuestionsPending).to.equal("true");
+  });
+
   it("blocks promoted waitlist completion until registration questions are answered", () => {
     // Render waitlist controls before the attendee is promoted.
     const { attendButton, checker, questionsModal } = renderAttendanceDom({
@@ -26,6 +26,7 @@ describe("event check-in", () => {
       <div id="check-in-success-card" class="hidden"></div>
       <div id="check-in-form-container"></div>
       <form id="event-check-in-form"></form>
+      <div id="check-in-ticket"></div>
       <a id="view-event-details-button" class="hidden"></a>
     `;
   };
@@ -48,6 +49,7 @@ describe("event check-in", () => {
     expect(document.getElementById("event-check-in-form")?.classList.contains("hidden")).to.equal(
       true,
     );
+    expect(document.getElementById("check-in-ticket")?.classList.contains("hidden")).to.equal(true);
     expect(
       document.getElementById("view-event-details-button")?.classList.contains("hidden"),
     ).to.equal(false);
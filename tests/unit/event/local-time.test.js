@@ -0,0 +1,71 @@
+import { expect } from "@open-wc/testing";
+
+import {
+  buildViewerLocalTimeLabel,
+  initializeViewerLocalTimes,
+  resolveViewerTimezone,
+} from "/static/js/event/local-time.js";
+import { resetDom } from "/tests/unit/test-utils/dom.js";
+
+// 2026-01-12 19:00 UTC (11:00 AM in Los Angeles, 8:00 PM in Madrid).
+const STARTS_AT = 1768244400;
+const ENDS_AT = STARTS_AT + 2 * 60 * 60;
+
+describe("event local time", () => {
+  afterEach(() => {
+    resetDom();
+  });
+
+  it("converts the event dates to the viewer timezone", () => {
+    const label = buildViewerLocalTimeLabel({
+      startsAt: STARTS_AT,
+      endsAt: ENDS_AT,
+      eventTimezone: "America/Los_Angeles",
+      viewerTimezone: "Europe/Madrid",
+    });
+
+    expect(label).to.match(/^Your time: Jan 12/);
+    expect(label).to.include("8:00");
+    expect(label).to.include("10:00");
+  });
+
+  it("skips the label when the viewer uses the event local time", () => {
+    const label = buildViewerLocalTimeLabel({
+      startsAt: STARTS_AT,
+      endsAt: ENDS_AT,
+      eventTimezone: "Europe/Madrid",
+      viewerTimezone: "Europe/Madrid",
+    });
+
+    expect(label).to.equal("");
+  });
+
+  it("skips the label when the event timezone is invalid", () => {
+    const label = buildViewerLocalTimeLabel({
+      startsAt: STARTS_AT,
+      eventTimezone: "Invalid/Timezone",
+      viewerTimezone: "Europe/Madrid",
+    });
+
+    expect(label).to.equal("");
+  });
+
+  it("keeps the placeholder hidden for events in the viewer timezone", () => {
+    // Build the DOM fixture using the browser timezone as the event timezone.
+    document.body.innerHTML = `
+      <div id="local-time"
+           data-viewer-local-time
+           data-starts-at="${STARTS_AT}"
+           data-event-timezone="${resolveViewerTimezone()}"
+           class="hidden"></div>
+    `;
+
+    // Initialize the local times.
+    initializeViewerLocalTimes();
+
+    // The placeholder stays hidden and empty.
+    const element = document.getElementById("local-time");
+    expect(element?.classList.contains("hidden")).to.equal(true);
+    expect(element?.textContent).to.equal("");
+  });
+});
@@ -17,6 +17,7 @@ const renderMembershipDom = () => {
       <button id="signin-btn" class="hidden" data-path="/groups/test-group">Sign in</button>
       <button id="join-btn" class="hidden">Join</button>
       <button id="leave-btn" class="hidden">Leave</button>
+      <button id="cancel-request-btn" class="hidden">Cancel request</button>
     </div>
   `;
 
@@ -26,6 +27,7 @@ const renderMembershipDom = () => {
     signinButton: document.getElementById("signin-btn"),
     joinButton: document.getElementById("join-btn"),
     leaveButton: document.getElementById("leave-btn"),
+    cancelRequestButton: document.getElementById("cancel-request-btn"),
   };
 };
 
@@ -45,7 +47,7 @@ describe("group membership", () => {
 
     // Dispatch the HTMX after-request event.
     dispatchHtmxAfterRequest(checker, {
-      responseText: JSON.stringify({ is_member: true }),
+      responseText: JSON.stringify({ is_member: true, status: "member" }),
     });
 
     // Verify shows the leave action after a successful membership check.
@@ -54,6 +56,22 @@ describe("group membership", () => {
     expect(joinButton.classList.contains("hidden")).to.equal(true);
   });
 
+  it("shows the cancel request action while a join request is pending", () => {
+    // Read controls after a membership check for a pending join request.
+    const { cancelRequestButton, checker, joinButton, leaveButton } =
+      renderMembershipDom();
+
+    // Dispatch the HTMX after-request event.
+    dispatchHtmxAfterRequest(checker, {
+      responseText: JSON.stringify({ is_member: false, status: "pending-approval" }),
+    });
+
+    // Verify only the cancel request action is displayed.
+    expect(cancelRequestButton.classList.contains("hidden")).to.equal(false);
+    expect(joinButton.classList.contains("hidden")).to.equal(true);
+    expect(leaveButton.classList.contains("hidden")).to.equal(true);
+  });
+
   it("falls back to the sign-in action when the membership response is invalid", () => {
     // Keep references to the fixture controls under assertion.
     const { checker, signinButton, joinButton, leaveButton } =
@@ -189,6 +207,27 @@ describe("group membership", () => {
     });
   });
 
+  it("tells the user when a join request is pending approval", () => {
+    // Render the membership fixture.
+    const { joinButton } = renderMembershipDom();
+    let changedEvents = 0;
+    document.body.addEventListener("membership-changed", () => {
+      changedEvents += 1;
+    });
+
+    // Dispatch the HTMX after-request event for a private group.
+    dispatchHtmxAfterRequest(joinButton, {
+      responseText: JSON.stringify({ status: "pending-approval" }),
+    });
+
+    // Requesting to join emits membership-changed with a pending message.
+    expect(changedEvents).to.equal(1);
+    expect(env.current.swal.calls.at(-1)).to.include({
+      text: "Your request to join this group has been sent to the organizers.",
+      icon: "success",
+    });
+  });
+
   it("emits membership-changed after leaving and restores the leave button on failure", () => {
     // Render the membership fixture.
     const { leaveButton, loadingButton } = renderMembershipDom();